@@ -0,0 +1,259 @@
+//! Benchmarks for the extraction pipeline and the generated lookup tables
+//!
+//! Run with `cargo bench --bench extraction`. The file fixtures are the
+//! representative test images (small JPEG, maker-note-heavy CR2, HEIC,
+//! QuickTime movie) and are skipped when `test-images/` isn't checked out; a
+//! synthetic JPEG always runs.
+//!
+//! The `print_conv` group extracts maker-note-heavy files with and without
+//! `-n`, which skips the PrintConv of the maker note tags.
+//!
+//! A counting global allocator reports the allocations of one extraction of
+//! each fixture before the timing runs, and fails the run when the synthetic
+//! JPEG exceeds [`SYNTHETIC_JPEG_ALLOCATION_BUDGET`]. Timing regressions are
+//! caught by comparing against a saved baseline: `make bench-baseline` on the
+//! main branch, then `make bench-compare` on the change.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use exif_oxide::formats::extract_metadata;
+use exif_oxide::generated::Canon_pm::canon_lens_types::lookup_canon_lens_types;
+use exif_oxide::generated::ExifTool_pm::file_type_ext::lookup_file_type_ext;
+use exif_oxide::generated::ExifTool_pm::mime_type::lookup_mime_types;
+use exif_oxide::generated::Exif_pm::main_tags::EXIF_MAIN_TAGS;
+use exif_oxide::generated::Nikon_pm::nikon_lens_ids::lookup_nikon_lens_ids;
+use exif_oxide::types::FilterOptions;
+use tempfile::NamedTempFile;
+
+/// Counts heap allocations so benchmarks can report them
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size as u64, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Allocations and allocated bytes while running `f`
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (u64, u64) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    black_box(f());
+    (
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes,
+    )
+}
+
+/// Most allocations one extraction of the synthetic JPEG may make (about
+/// 18,500 when this was set)
+const SYNTHETIC_JPEG_ALLOCATION_BUDGET: u64 = 25_000;
+
+/// Test images, by benchmark name
+const FIXTURES: &[(&str, &str)] = &[
+    ("jpeg", "test-images/canon/eos_rebel_t3i.jpg"),
+    ("cr2", "test-images/canon/eos_rebel_t3i.cr2"),
+    ("heic", "test-images/apple/IMG_9757.heic"),
+    ("mov", "test-images/apple/IMG_3755.MOV"),
+];
+
+/// A JPEG with an EXIF APP1 segment (Make, Model, Orientation, DateTime)
+/// and 64KB of scan data
+fn synthetic_jpeg() -> Vec<u8> {
+    let ascii = |tag: u16, offset: u32, text: &[u8]| {
+        let mut entry = tag.to_be_bytes().to_vec();
+        entry.extend([0, 2]);
+        entry.extend((text.len() as u32).to_be_bytes());
+        entry.extend(offset.to_be_bytes());
+        entry
+    };
+    let (make, model, date) = (b"Canon\0", b"Canon EOS 600D\0", b"2024:05:01 12:00:00\0");
+    // Header, entry count, 4 entries and the next IFD offset, then the strings
+    let data_start = 8 + 2 + 4 * 12 + 4;
+    let mut tiff = b"MM\0\x2a\0\0\0\x08".to_vec();
+    tiff.extend(4u16.to_be_bytes());
+    tiff.extend(ascii(0x010f, data_start, make));
+    tiff.extend(ascii(0x0110, data_start + 6, model));
+    tiff.extend([0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, 6, 0, 0]);
+    tiff.extend(ascii(0x0132, data_start + 21, date));
+    tiff.extend([0; 4]);
+    tiff.extend(make);
+    tiff.extend(model);
+    tiff.extend(date);
+
+    let mut jpeg = b"\xff\xd8\xff\xe1".to_vec();
+    jpeg.extend(((tiff.len() + 8) as u16).to_be_bytes());
+    jpeg.extend(b"Exif\0\0");
+    jpeg.extend(&tiff);
+    jpeg.extend(b"\xff\xda\x00\x04\x01\x00");
+    jpeg.extend((0..64 * 1024).map(|i| (i % 251) as u8));
+    jpeg.extend(b"\xff\xd9");
+    jpeg
+}
+
+/// A JPEG whose only EXIF is a Casio maker note of `entries` int16u tags
+fn casio_jpeg(entries: u16) -> Vec<u8> {
+    let entry = |tag: u16, format: u16, count: u32, value: u32| {
+        let mut entry = tag.to_be_bytes().to_vec();
+        entry.extend(format.to_be_bytes());
+        entry.extend(count.to_be_bytes());
+        entry.extend(value.to_be_bytes());
+        entry
+    };
+    let make = b"CASIO COMPUTER CO.,LTD.\0";
+    // Header, IFD0 (Make and the ExifIFD pointer), Make, then ExifIFD
+    let make_start = 8 + 2 + 2 * 12 + 4;
+    let exif_start = make_start + make.len() as u32;
+    let maker_note_start = exif_start + 2 + 12 + 4;
+    let maker_note_len = 2 + 12 * entries as u32 + 4;
+
+    let mut tiff = b"MM\0\x2a\0\0\0\x08".to_vec();
+    tiff.extend(2u16.to_be_bytes());
+    tiff.extend(entry(0x010f, 2, make.len() as u32, make_start));
+    tiff.extend(entry(0x8769, 4, 1, exif_start));
+    tiff.extend([0; 4]);
+    tiff.extend(make);
+    tiff.extend(1u16.to_be_bytes());
+    tiff.extend(entry(0x927c, 7, maker_note_len, maker_note_start));
+    tiff.extend([0; 4]);
+    // Casio type 1: a bare IFD of RecordingMode, Quality, FocusMode, ...
+    tiff.extend(entries.to_be_bytes());
+    for tag in 1..=entries {
+        tiff.extend(entry(tag, 3, 1, 2 << 16));
+    }
+    tiff.extend([0; 4]);
+
+    let mut jpeg = b"\xff\xd8\xff\xe1".to_vec();
+    jpeg.extend(((tiff.len() + 8) as u16).to_be_bytes());
+    jpeg.extend(b"Exif\0\0");
+    jpeg.extend(&tiff);
+    jpeg.extend(b"\xff\xd9");
+    jpeg
+}
+
+/// The synthetic JPEG and the test images that are present
+fn fixtures(synthetic: &Path) -> Vec<(&'static str, &Path)> {
+    let mut fixtures = vec![("synthetic_jpeg", synthetic)];
+    for (name, path) in FIXTURES {
+        let path = Path::new(path);
+        if path.exists() {
+            fixtures.push((name, path));
+        } else {
+            eprintln!("Skipping {name}: {} not found", path.display());
+        }
+    }
+    fixtures
+}
+
+fn bench_extract_metadata(c: &mut Criterion) {
+    let mut synthetic = NamedTempFile::with_suffix(".jpg").unwrap();
+    synthetic.write_all(&synthetic_jpeg()).unwrap();
+    let fixtures = fixtures(synthetic.path());
+
+    for (name, path) in &fixtures {
+        // The first extraction initializes the lookup tables
+        extract_metadata(path, false, false, None).unwrap();
+        let (allocations, bytes) =
+            count_allocations(|| extract_metadata(path, false, false, None).unwrap());
+        eprintln!("{name}: {allocations} allocations, {bytes} bytes allocated per extraction");
+        if *name == "synthetic_jpeg" {
+            assert!(
+                allocations <= SYNTHETIC_JPEG_ALLOCATION_BUDGET,
+                "{name}: {allocations} allocations exceed the budget of \
+                 {SYNTHETIC_JPEG_ALLOCATION_BUDGET}"
+            );
+        }
+    }
+
+    let mut group = c.benchmark_group("extract_metadata");
+    for (name, path) in &fixtures {
+        group.throughput(Throughput::Bytes(std::fs::metadata(path).unwrap().len()));
+        group.bench_with_input(BenchmarkId::from_parameter(name), path, |b, path| {
+            b.iter(|| extract_metadata(black_box(path), false, false, None).unwrap())
+        });
+    }
+    group.finish();
+}
+
+/// Extraction with and without `-n`, which skips maker note PrintConvs
+fn bench_print_conv(c: &mut Criterion) {
+    let mut casio = NamedTempFile::with_suffix(".jpg").unwrap();
+    casio.write_all(&casio_jpeg(20)).unwrap();
+    let mut fixtures = vec![("casio_jpeg", casio.path())];
+    for (name, path) in FIXTURES.iter().take(2) {
+        let path = Path::new(path);
+        if path.exists() {
+            fixtures.push((name, path));
+        }
+    }
+    let numeric = FilterOptions {
+        numeric: true,
+        ..FilterOptions::default()
+    };
+
+    let mut group = c.benchmark_group("print_conv");
+    for (name, path) in &fixtures {
+        for (variant, filter) in [
+            ("print", FilterOptions::default()),
+            ("numeric", numeric.clone()),
+        ] {
+            group.bench_with_input(BenchmarkId::new(variant, name), path, |b, path| {
+                b.iter(|| {
+                    extract_metadata(black_box(path), false, false, Some(filter.clone())).unwrap()
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_generated_lookups(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generated_lookups");
+    group.bench_function("exif_main_tags", |b| {
+        b.iter(|| EXIF_MAIN_TAGS.get(&black_box(0x9003)))
+    });
+    group.bench_function("canon_lens_types", |b| {
+        b.iter(|| lookup_canon_lens_types(black_box("94")))
+    });
+    group.bench_function("nikon_lens_ids", |b| {
+        b.iter(|| lookup_nikon_lens_ids(black_box("00 54 62 62 0C 0C 00 00")))
+    });
+    group.bench_function("file_type_ext", |b| {
+        b.iter(|| lookup_file_type_ext(black_box("JPEG")))
+    });
+    group.bench_function("mime_types", |b| {
+        b.iter(|| lookup_mime_types(black_box("HEIC")))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_extract_metadata,
+    bench_print_conv,
+    bench_generated_lookups
+);
+criterion_main!(benches);
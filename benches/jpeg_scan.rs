@@ -0,0 +1,60 @@
+//! Benchmarks for the JPEG scan-data and magic-number scanning hot paths
+//!
+//! Run with `cargo bench --bench jpeg_scan`. Inputs are synthetic so the
+//! benchmarks don't depend on test images.
+
+use std::hint::black_box;
+use std::io::Cursor;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use exif_oxide::file_detection::scan_for_embedded_signatures;
+use exif_oxide::formats::hash_jpeg_scan_data;
+use exif_oxide::hash::{ImageDataHasher, ImageHashType};
+
+/// A JPEG with `scan_len` bytes of entropy-coded data, byte-stuffed like a real encoder
+fn synthetic_jpeg(scan_len: usize) -> Vec<u8> {
+    let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xDA, 0x00, 0x04, 0x01, 0x00];
+    let mut state = 0x2545_F491u32;
+    for _ in 0..scan_len {
+        // xorshift: cheap pseudo-random bytes, so 0xFF shows up about every 256 bytes
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        let byte = state as u8;
+        jpeg.push(byte);
+        if byte == 0xFF {
+            jpeg.push(0x00);
+        }
+    }
+    jpeg.extend_from_slice(&[0xFF, 0xD9]);
+    jpeg
+}
+
+fn bench_hash_scan_data(c: &mut Criterion) {
+    let jpeg = synthetic_jpeg(16 * 1024 * 1024);
+    let mut group = c.benchmark_group("hash_jpeg_scan_data");
+    group.throughput(Throughput::Bytes(jpeg.len() as u64));
+    group.sample_size(20);
+    group.bench_function("16MB", |b| {
+        b.iter(|| {
+            let mut hasher = ImageDataHasher::new(ImageHashType::Md5);
+            hash_jpeg_scan_data(&mut Cursor::new(black_box(&jpeg)), &mut hasher).unwrap();
+            hasher.finalize()
+        })
+    });
+    group.finish();
+}
+
+fn bench_embedded_signatures(c: &mut Criterion) {
+    // Worst case: no signature, so the whole buffer is searched for each pattern
+    let buffer = vec![0x55u8; 1024 * 1024];
+    let mut group = c.benchmark_group("scan_for_embedded_signatures");
+    group.throughput(Throughput::Bytes(buffer.len() as u64));
+    group.bench_function("1MB", |b| {
+        b.iter(|| scan_for_embedded_signatures(black_box(&buffer)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_hash_scan_data, bench_embedded_signatures);
+criterion_main!(benches);
@@ -110,7 +110,7 @@ static FUNCTION_CALL_REGISTRY: LazyLock<HashMap<&'static str, FunctionImplementa
         m.insert(
             "Image::ExifTool::Canon::CanonEv",
             FunctionImplementation::ExifToolModule(ModuleFunction {
-                module_path: "crate::implementations::canon",
+                module_path: "crate::implementations::value_conv",
                 function_name: "canon_ev",
                 exiftool_module: "Canon",
             }),
@@ -123,6 +123,35 @@ static PRINTCONV_REGISTRY: LazyLock<HashMap<&'static str, (&'static str, &'stati
             "Image::ExifTool::Exif::PrintFraction($val)",
             ("crate::implementations::print_conv", "print_fraction"),
         );
+        m.insert(
+            "Image::ExifTool::Nikon::PrintPC($val)",
+            ("crate::implementations::print_conv", "nikon_pc_print_conv"),
+        );
+        m.insert(
+            "Image::ExifTool::Nikon::PrintPC($val,\"No Sharpening\",\"%d\")",
+            (
+                "crate::implementations::print_conv",
+                "nikon_pc_sharpness_print_conv",
+            ),
+        );
+        m.insert(
+            "Image::ExifTool::Nikon::PrintPC($val,\"None\")",
+            (
+                "crate::implementations::print_conv",
+                "nikon_pc_hue_print_conv",
+            ),
+        );
+        m.insert(
+            "Image::ExifTool::Nikon::PrintPC($val,\"None\",\"%.2f\",4)",
+            ("crate::implementations::print_conv", "nikon_pc2_print_conv"),
+        );
+        m.insert(
+            "Image::ExifTool::Nikon::PrintPC($val,undef,\"%.2f\",4)",
+            (
+                "crate::implementations::print_conv",
+                "nikon_pc2_brightness_print_conv",
+            ),
+        );
 
         // Manual function mappings (these come through as Manual type with function names)
         m.insert(
@@ -302,6 +331,13 @@ static TAG_SPECIFIC_PRINTCONV: LazyLock<HashMap<&'static str, (&'static str, &'s
             "DNGLensInfo",
             ("crate::implementations::print_conv", "lensinfo_print_conv"),
         );
+        m.insert(
+            "CompositeImageExposureTimes",
+            (
+                "crate::implementations::print_conv",
+                "composite_image_exposure_times_print_conv",
+            ),
+        );
 
         // GPS reference tags (ComplexHash types)
         m.insert(
@@ -176,7 +176,10 @@ fn test_function_registry_exiftool_module_lookup() {
     assert!(canon_ev_result.is_some());
 
     if let Some(FunctionImplementation::ExifToolModule(module_func)) = canon_ev_result {
-        assert_eq!(module_func.module_path, "crate::implementations::canon");
+        assert_eq!(
+            module_func.module_path,
+            "crate::implementations::value_conv"
+        );
         assert_eq!(module_func.function_name, "canon_ev");
         assert_eq!(module_func.exiftool_module, "Canon");
     } else {
@@ -318,7 +321,7 @@ fn test_get_function_details() {
     assert!(canon_ev_details.is_some());
     if let Some(details) = canon_ev_details {
         assert_eq!(details.category, "exiftool_module");
-        assert_eq!(details.module_path, "crate::implementations::canon");
+        assert_eq!(details.module_path, "crate::implementations::value_conv");
         assert_eq!(details.function_name, "canon_ev");
         assert!(details.description.contains("Canon"));
     }
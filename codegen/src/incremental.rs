@@ -0,0 +1,291 @@
+//! Incremental regeneration
+//!
+//! Most of a `make codegen` run is spent in the Perl field extractor, run once
+//! per ExifTool module. The symbols it extracts only depend on the module
+//! source, so they are cached in `codegen/.codegen-cache/`, keyed by a hash of
+//! the module, of `ExifTool.pm` (which every module loads, and whose version
+//! changes on every ExifTool sync) and of the extractor script.
+//!
+//! Strategies combine symbols across modules (composite tags, the shared PPI
+//! function files), so when any module changes every module's symbols go
+//! through strategy processing again; the cache makes that cheap to feed.
+//! When nothing changed at all (same symbols and the same codegen binary as
+//! the last run that wrote the output directory) strategy processing is
+//! skipped. Either way, a generated file is only rewritten when its content
+//! differs from what the last run generated for it. The output is reformatted
+//! by `cargo fmt` afterwards, so that comparison is against a hash of the
+//! unformatted content recorded in the manifest, not against the file.
+//!
+//! `--force` ignores the cache.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use tracing::{debug, warn};
+use xxhash_rust::xxh3::Xxh3;
+
+use crate::field_extractor::FieldSymbol;
+
+/// What the last run saw, stored as `manifest.json` in the cache directory
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Manifest {
+    /// Hash of the codegen binary and its scripts
+    codegen_hash: String,
+    /// Output directory of the last completed run
+    output_dir: String,
+    /// Module name to its cache entry
+    modules: BTreeMap<String, ModuleEntry>,
+    /// Generated file path to the hash of its unformatted content
+    #[serde(default)]
+    outputs: BTreeMap<String, String>,
+}
+
+/// Output hashes of the last run and of this one
+///
+/// Global because files are written from the strategies, which don't see the
+/// [`CodegenCache`].
+#[derive(Default)]
+struct OutputHashes {
+    previous: BTreeMap<String, String>,
+    current: BTreeMap<String, String>,
+}
+
+static OUTPUT_HASHES: LazyLock<Mutex<OutputHashes>> = LazyLock::new(Mutex::default);
+
+/// Write a generated file unless the last run generated the same content
+///
+/// Returns whether the file was written.
+pub fn write_output(path: &Path, content: &str) -> Result<bool> {
+    let key = path.to_string_lossy().into_owned();
+    let hash = format!("{:032x}", xxhash_rust::xxh3::xxh3_128(content.as_bytes()));
+    let mut hashes = OUTPUT_HASHES.lock().unwrap_or_else(|e| e.into_inner());
+    let unchanged = hashes.previous.get(&key) == Some(&hash) && path.exists();
+    hashes.current.insert(key, hash);
+    drop(hashes);
+
+    if unchanged {
+        return Ok(false);
+    }
+    fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(true)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ModuleEntry {
+    /// Hash of the inputs the symbols were extracted from
+    source_hash: String,
+    /// Hash of the extracted symbols
+    symbols_hash: String,
+}
+
+/// Symbol cache and change tracking for one codegen run
+pub struct CodegenCache {
+    dir: PathBuf,
+    force: bool,
+    previous: Manifest,
+    current: Manifest,
+    /// Hash of the inputs shared by every module (ExifTool.pm, the extractor)
+    shared_hash: String,
+    /// Only some modules were extracted
+    partial: bool,
+}
+
+impl CodegenCache {
+    /// Load the cache, or start an empty one with `force`
+    pub fn load(exiftool_pm: &Path, force: bool) -> Result<Self> {
+        let codegen_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let dir = codegen_dir.join(".codegen-cache");
+
+        let previous = if force {
+            Manifest::default()
+        } else {
+            fs::read_to_string(dir.join("manifest.json"))
+                .ok()
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default()
+        };
+
+        OUTPUT_HASHES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .previous = previous.outputs.clone();
+
+        let mut shared = Xxh3::new();
+        shared.update(&read_input(exiftool_pm)?);
+        shared.update(&read_input(
+            &codegen_dir.join("scripts/field_extractor.pl"),
+        )?);
+
+        let mut codegen = Xxh3::new();
+        codegen.update(&read_input(&std::env::current_exe()?)?);
+        for script in ["scripts/field_extractor.pl", "scripts/ppi_ast.pl"] {
+            if let Ok(content) = fs::read(codegen_dir.join(script)) {
+                codegen.update(&content);
+            }
+        }
+
+        Ok(Self {
+            dir,
+            force,
+            previous,
+            current: Manifest {
+                codegen_hash: format!("{:032x}", codegen.digest128()),
+                ..Manifest::default()
+            },
+            shared_hash: format!("{:032x}", shared.digest128()),
+            partial: false,
+        })
+    }
+
+    /// Hash of a module's extraction inputs
+    pub fn source_hash(&self, module_path: &Path) -> Result<String> {
+        let mut hasher = Xxh3::new();
+        hasher.update(self.shared_hash.as_bytes());
+        hasher.update(&read_input(module_path)?);
+        Ok(format!("{:032x}", hasher.digest128()))
+    }
+
+    /// Symbols cached for a module, if its inputs are unchanged
+    pub fn cached_symbols(&mut self, module: &str, source_hash: &str) -> Option<Vec<FieldSymbol>> {
+        if self.force {
+            return None;
+        }
+        let entry = self.previous.modules.get(module)?;
+        if entry.source_hash != source_hash {
+            return None;
+        }
+        let json = fs::read_to_string(self.symbols_path(module)).ok()?;
+        let symbols: Vec<FieldSymbol> = serde_json::from_str(&json).ok()?;
+        self.current
+            .modules
+            .insert(module.to_string(), entry.clone());
+        debug!("♻️  {}: using cached symbols", module);
+        Some(symbols)
+    }
+
+    /// Cache freshly extracted symbols
+    pub fn store(
+        &mut self,
+        module: &str,
+        source_hash: &str,
+        symbols: &[FieldSymbol],
+    ) -> Result<()> {
+        let json = serde_json::to_string(symbols)?;
+        fs::create_dir_all(self.dir.join("symbols"))
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        fs::write(self.symbols_path(module), &json)?;
+        self.current.modules.insert(
+            module.to_string(),
+            ModuleEntry {
+                source_hash: source_hash.to_string(),
+                symbols_hash: format!("{:032x}", xxhash_rust::xxh3::xxh3_128(json.as_bytes())),
+            },
+        );
+        Ok(())
+    }
+
+    /// Modules whose symbols differ from the last run (new, changed or gone)
+    pub fn changed_modules(&self) -> Vec<&str> {
+        let previous = &self.previous.modules;
+        let current = &self.current.modules;
+        let mut changed: Vec<&str> = current
+            .iter()
+            .filter(|(module, entry)| {
+                previous.get(*module).map(|old| &old.symbols_hash) != Some(&entry.symbols_hash)
+            })
+            .map(|(module, _)| module.as_str())
+            .collect();
+        changed.extend(
+            previous
+                .keys()
+                .filter(|module| !current.contains_key(*module))
+                .map(String::as_str),
+        );
+        changed
+    }
+
+    /// Whether the output directory already holds what this run would generate
+    pub fn output_up_to_date(&self, output_dir: &str) -> bool {
+        !self.force
+            && self.changed_modules().is_empty()
+            && self.previous.codegen_hash == self.current.codegen_hash
+            && self.previous.output_dir == output_dir
+            && Path::new(output_dir).join("mod.rs").exists()
+    }
+
+    /// Keep the entries of modules this run didn't extract (`--modules`)
+    ///
+    /// A partial run doesn't regenerate the whole output directory, so the
+    /// next full run must not consider it up to date.
+    pub fn carry_over_unvisited(&mut self) {
+        for (module, entry) in &self.previous.modules {
+            self.current
+                .modules
+                .entry(module.clone())
+                .or_insert_with(|| entry.clone());
+        }
+        self.partial = true;
+    }
+
+    /// Record a completed run that wrote `output_dir`
+    pub fn save(mut self, output_dir: &str) -> Result<()> {
+        if !self.partial {
+            self.current.output_dir = output_dir.to_string();
+        }
+        let mut hashes = OUTPUT_HASHES.lock().unwrap_or_else(|e| e.into_inner());
+        let mut outputs = std::mem::take(&mut hashes.previous);
+        outputs.append(&mut hashes.current);
+        self.current.outputs = outputs;
+        drop(hashes);
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let json = serde_json::to_string_pretty(&self.current)?;
+        if let Err(e) = fs::write(self.dir.join("manifest.json"), json) {
+            warn!("Failed to save codegen cache manifest: {}", e);
+        }
+        Ok(())
+    }
+
+    fn symbols_path(&self, module: &str) -> PathBuf {
+        self.dir.join("symbols").join(format!("{module}.json"))
+    }
+}
+
+fn read_input(path: &Path) -> Result<Vec<u8>> {
+    fs::read(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(hash: &str) -> ModuleEntry {
+        ModuleEntry {
+            source_hash: String::new(),
+            symbols_hash: hash.to_string(),
+        }
+    }
+
+    #[test]
+    fn reports_changed_new_and_removed_modules() {
+        let mut cache = CodegenCache {
+            dir: PathBuf::new(),
+            force: false,
+            previous: Manifest::default(),
+            current: Manifest::default(),
+            shared_hash: String::new(),
+            partial: false,
+        };
+        cache.previous.modules.insert("Canon".into(), entry("a"));
+        cache.previous.modules.insert("Nikon".into(), entry("b"));
+        cache.previous.modules.insert("Sony".into(), entry("c"));
+        cache.current.modules.insert("Canon".into(), entry("a"));
+        cache.current.modules.insert("Nikon".into(), entry("x"));
+        cache.current.modules.insert("GPS".into(), entry("d"));
+
+        assert_eq!(cache.changed_modules(), ["GPS", "Nikon", "Sony"]);
+    }
+}
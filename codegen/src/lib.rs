@@ -9,6 +9,7 @@ pub mod field_extractor;
 pub mod file_operations;
 // pub mod generate_unsupported_tests; // Module file doesn't exist yet
 pub mod impl_registry;
+pub mod incremental;
 pub mod ppi; // PPI JSON parsing for codegen-time AST processing
 pub mod strategies;
 pub mod types;
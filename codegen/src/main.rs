@@ -17,12 +17,14 @@ mod common;
 mod field_extractor;
 mod file_operations;
 mod impl_registry;
+mod incremental;
 mod ppi; // PPI JSON parsing for codegen-time AST processing
 mod schemas;
 mod strategies;
 
 use field_extractor::FieldExtractor;
 use file_operations::create_directories;
+use incremental::{write_output, CodegenCache};
 use strategies::StrategyDispatcher;
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -67,6 +69,18 @@ fn main() -> Result<()> {
                 .action(clap::ArgAction::Append)
                 .required(false),
         )
+        .arg(
+            Arg::new("force")
+                .long("force")
+                .help("Regenerate everything, ignoring the incremental cache")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("mod-files-only")
+                .long("mod-files-only")
+                .help("Only regenerate the mod.rs files from the existing output")
+                .action(clap::ArgAction::SetTrue),
+        )
         .get_matches();
 
     let output_dir_raw = matches.get_one::<String>("output").unwrap();
@@ -94,12 +108,23 @@ fn main() -> Result<()> {
     // Output directory should already exist from canonicalize above
     create_directories(Path::new(&output_dir))?;
 
+    if matches.get_flag("mod-files-only") {
+        update_mod_files(&output_dir)?;
+        info!("✅ mod.rs files regenerated");
+        return Ok(());
+    }
+
     info!("🔧 exif-oxide Code Generation");
     debug!("=============================");
 
     // Universal symbol table extraction is now the default approach
     info!("🔄 Using universal symbol table extraction");
-    run_universal_extraction(&current_dir, &output_dir, selected_modules.as_ref())?;
+    run_universal_extraction(
+        &current_dir,
+        &output_dir,
+        selected_modules.as_ref(),
+        matches.get_flag("force"),
+    )?;
 
     info!("✅ Code generation complete!");
 
@@ -135,6 +160,7 @@ fn run_universal_extraction(
     current_dir: &Path,
     output_dir: &str,
     selected_modules: Option<&Vec<String>>,
+    force: bool,
 ) -> Result<()> {
     let extractor = FieldExtractor::new();
     let mut dispatcher = StrategyDispatcher::new();
@@ -215,25 +241,28 @@ fn run_universal_extraction(
         all_paths
     };
 
+    let mut cache = CodegenCache::load(&exiftool_base_dir.join("lib/Image/ExifTool.pm"), force)?;
+
     let start = Instant::now();
     let mut all_symbols = Vec::new();
 
-    // Extract symbols from selected modules
+    // Extract symbols from selected modules, reusing cached symbols for
+    // modules whose source is unchanged
     for module_path in selected_paths {
+        let module_name = module_path
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .unwrap_or("unknown");
+        let source_hash = cache.source_hash(module_path)?;
+        if let Some(symbols) = cache.cached_symbols(module_name, &source_hash) {
+            info!("♻️  {}: {} symbols (unchanged)", module_name, symbols.len());
+            all_symbols.extend(symbols);
+            continue;
+        }
+
         match extractor.extract_module(module_path) {
             Ok(symbols) => {
-                let module_name = module_path
-                    .file_stem()
-                    .and_then(|name| name.to_str())
-                    .unwrap_or("unknown")
-                    .strip_suffix(".pm")
-                    .unwrap_or_else(|| {
-                        module_path
-                            .file_stem()
-                            .and_then(|name| name.to_str())
-                            .unwrap_or("unknown")
-                    });
-
+                cache.store(module_name, &source_hash, &symbols)?;
                 info!("✅ {}: {} symbols extracted", module_name, symbols.len());
 
                 debug!(
@@ -254,6 +283,15 @@ fn run_universal_extraction(
         extraction_time.as_secs_f64()
     );
 
+    if selected_modules.is_some() {
+        cache.carry_over_unvisited();
+    } else if cache.output_up_to_date(output_dir) {
+        info!("✅ No module or codegen changes since the last run; output is up to date (use --force to regenerate)");
+        return Ok(());
+    } else {
+        info!("🔁 Changed modules: {:?}", cache.changed_modules());
+    }
+
     // Process extracted symbols through strategy system
     if !all_symbols.is_empty() {
         let strategy_start = Instant::now();
@@ -276,6 +314,7 @@ fn run_universal_extraction(
                 info!("📄 Updating mod.rs files after file generation");
                 update_mod_files(output_dir)?;
                 let mod_update_time = mod_update_start.elapsed();
+                cache.save(output_dir)?;
 
                 info!(
                     "📝 mod.rs files updated in {:.2}s",
@@ -299,6 +338,42 @@ fn run_universal_extraction(
 }
 
 /// Update all mod.rs files by scanning the filesystem after files are written
+/// Cargo feature of exif-oxide that gates a generated module, if any
+///
+/// Must stay in sync with the `[features]` table in the root Cargo.toml.
+fn module_feature(module_dir: &str) -> Option<&'static str> {
+    Some(match module_dir {
+        "Canon_pm" | "CanonCustom_pm" | "CanonRaw_pm" => "canon",
+        "Nikon_pm" => "nikon",
+        "Sony_pm" | "SonyIDC_pm" => "sony",
+        "Olympus_pm" => "olympus",
+        "FujiFilm_pm" => "fujifilm",
+        "Casio_pm" | "DJI_pm" | "HP_pm" | "Kodak_pm" | "Motorola_pm" | "Nintendo_pm"
+        | "Panasonic_pm" | "Pentax_pm" | "Ricoh_pm" | "Samsung_pm" | "Sanyo_pm" | "Sigma_pm" => {
+            "other-makers"
+        }
+        "QuickTime_pm" | "RIFF_pm" | "Matroska_pm" | "H264_pm" | "Ogg_pm" | "GoPro_pm"
+        | "Red_pm" => "video",
+        "XMP_pm" | "MWG_pm" => "xmp",
+        "MinoltaRaw_pm" | "PanasonicRaw_pm" | "KyoceraRaw_pm" | "SigmaRaw_pm" => "raw-formats",
+        _ => return None,
+    })
+}
+
+/// Names of the `pub static NAME: LazyLock<...>` tables in a generated file
+fn lazy_statics(content: &str) -> Vec<&str> {
+    content
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub static "))
+        .filter_map(|rest| {
+            let (name, ty) = rest.split_once(':')?;
+            ty.trim_start()
+                .starts_with("LazyLock<")
+                .then_some(name.trim())
+        })
+        .collect()
+}
+
 fn update_mod_files(output_dir: &str) -> Result<()> {
     use std::collections::{BTreeSet, HashMap};
     use std::fs;
@@ -347,6 +422,9 @@ fn update_mod_files(output_dir: &str) -> Result<()> {
         modules_with_files.len()
     );
 
+    // Modules that got a TABLES list
+    let mut modules_with_tables = BTreeSet::new();
+
     // Create mod.rs files for each module directory
     for (module_dir, file_set) in &modules_with_files {
         let module_dir_path = Path::new(output_dir).join(module_dir);
@@ -434,7 +512,28 @@ fn update_mod_files(output_dir: &str) -> Result<()> {
             }
         }
 
-        if let Err(e) = fs::write(&module_mod_path, content) {
+        // Every lookup table in the module, so the runtime can warm them and
+        // report their memory (src/tables.rs)
+        let mut tables = Vec::new();
+        for filename in file_set {
+            let file_content = fs::read_to_string(module_dir_path.join(format!("{filename}.rs")))?;
+            for static_name in lazy_statics(&file_content) {
+                tables.push(format!("{filename}::{static_name}"));
+            }
+        }
+        if !tables.is_empty() {
+            content.push_str(
+                "\n/// Lookup tables in this module, for `crate::tables`\n\
+                 pub static TABLES: &[crate::tables::LazyTable] = &[\n",
+            );
+            for table in &tables {
+                content.push_str(&format!("    crate::tables::lazy_table!({table}),\n"));
+            }
+            content.push_str("];\n");
+            modules_with_tables.insert(module_dir.clone());
+        }
+
+        if let Err(e) = write_output(&module_mod_path, &content) {
             return Err(anyhow::anyhow!(
                 "Failed to write mod.rs file for module '{}' at path '{}': {}",
                 module_dir,
@@ -479,7 +578,9 @@ fn update_mod_files(output_dir: &str) -> Result<()> {
 
     // Generate module declarations in sorted order
     for module_dir in &all_modules {
-        // Add special attribute for non-snake-case modules
+        if let Some(feature) = module_feature(module_dir) {
+            main_content.push_str(&format!("#[cfg(feature = \"{feature}\")]\n"));
+        }
         main_content.push_str(&format!("pub mod {};\n", module_dir));
     }
 
@@ -497,20 +598,70 @@ fn update_mod_files(output_dir: &str) -> Result<()> {
             "});
     }
     if Path::new(output_dir).join("composite_tags.rs").exists() {
-        main_content.push_str("pub use composite_tags::{CompositeTagDef, COMPOSITE_TAGS, lookup_composite_tag, all_composite_tag_names, composite_tag_count};\n");
+        main_content.push_str("pub use composite_tags::{CompositeTagDef, COMPOSITE_TAGS, COMPOSITE_TAG_DEFS, COMPOSITE_TAG_LOOKUP, lookup_composite_tag, lookup_composite_definitions, all_composite_tag_names, composite_tag_count};\n");
+    }
+
+    // Lookup tables of every module, grouped by ExifTool module name
+    main_content.push_str(
+        "\n/// Lookup tables of every generated module, by ExifTool module name\n\
+         pub static TABLE_GROUPS: &[(&str, &[crate::tables::LazyTable])] = &[\n",
+    );
+    for module_dir in &modules_with_tables {
+        if let Some(feature) = module_feature(module_dir) {
+            main_content.push_str(&format!("    #[cfg(feature = \"{feature}\")]\n"));
+        }
+        main_content.push_str(&format!(
+            "    (\"{}\", {module_dir}::TABLES),\n",
+            module_dir.strip_suffix("_pm").unwrap_or(module_dir)
+        ));
+    }
+    let composite_tags_path = Path::new(output_dir).join("composite_tags.rs");
+    if let Ok(composite_content) = fs::read_to_string(&composite_tags_path) {
+        main_content.push_str("    (\n        \"Composite\",\n        &[\n");
+        for static_name in lazy_statics(&composite_content) {
+            main_content.push_str(&format!(
+                "            crate::tables::lazy_table!(composite_tags::{static_name}),\n"
+            ));
+        }
+        main_content.push_str("        ],\n    ),\n");
+    }
+    main_content.push_str("];\n");
+
+    // ProcessBinaryData tables for the generic binary data processor
+    main_content.push_str(
+        "\n/// ProcessBinaryData tables of every generated module, by ExifTool module name\n\
+         pub static BINARY_TABLE_GROUPS: &[(&str, &[crate::types::GeneratedBinaryTable])] = &[\n",
+    );
+    for module_dir in &all_modules {
+        if !Path::new(output_dir)
+            .join(module_dir)
+            .join("binary_tables.rs")
+            .exists()
+        {
+            continue;
+        }
+        if let Some(feature) = module_feature(module_dir) {
+            main_content.push_str(&format!("    #[cfg(feature = \"{feature}\")]\n"));
+        }
+        main_content.push_str(&format!(
+            "    (\"{}\", {module_dir}::binary_tables::BINARY_TABLES),\n",
+            module_dir.strip_suffix("_pm").unwrap_or(module_dir)
+        ));
     }
+    main_content.push_str("];\n");
 
     main_content.push_str(&formatdoc! {"
 
         /// Initialize all lazy static data structures
         /// This can be called during startup to avoid lazy initialization costs later
         pub fn initialize_all() {{
+            crate::tables::initialize_all();
         }}
         "});
 
     let modules_added = all_modules.len();
 
-    if let Err(e) = fs::write(&main_mod_path, main_content) {
+    if let Err(e) = write_output(&main_mod_path, &main_content) {
         return Err(anyhow::anyhow!(
             "Failed to write main mod.rs file at path '{}': {}",
             main_mod_path.display(),
@@ -0,0 +1,335 @@
+//! Conversion coverage report
+//!
+//! Lists every expression that neither the PPI generator nor impl_registry
+//! could translate, and which therefore got a placeholder function. The
+//! report groups them by ExifTool module and by expression pattern (literals
+//! replaced with placeholders), each ranked by how many tags depend on it, so
+//! impl_registry work can start where it fixes the most tags.
+//!
+//! Written next to `strategy_selection.log` as `conversion_coverage.json` and
+//! the human-readable `conversion_coverage.txt`.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::registry::UsageContext;
+use super::stats::ConversionStats;
+use crate::ppi::ExpressionType;
+
+/// Expressions listed per pattern in the text summary
+const TEXT_EXAMPLES: usize = 3;
+
+/// Patterns listed in the text summary
+const TEXT_PATTERNS: usize = 50;
+
+/// An expression that fell back to a placeholder function
+#[derive(Debug, Clone)]
+pub struct UntranslatedExpression {
+    pub expression_type: ExpressionType,
+    pub expression: String,
+    pub function_name: String,
+    pub usages: BTreeSet<UsageContext>,
+}
+
+/// Everything written to `conversion_coverage.json`
+#[derive(Debug, Serialize)]
+pub struct CoverageReport {
+    summary: BTreeMap<&'static str, TypeSummary>,
+    modules: BTreeMap<String, ModuleCoverage>,
+    patterns: Vec<PatternCoverage>,
+}
+
+#[derive(Debug, Serialize)]
+struct TypeSummary {
+    attempts: usize,
+    ppi: usize,
+    registry: usize,
+    placeholder: usize,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct ModuleCoverage {
+    /// Tags in the module using an untranslated expression
+    tags: usize,
+    expressions: Vec<ModuleExpression>,
+}
+
+#[derive(Debug, Serialize)]
+struct ModuleExpression {
+    expression_type: ExpressionType,
+    expression: String,
+    function: String,
+    /// `TABLE.Tag` of each tag in the module using the expression
+    tags: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PatternCoverage {
+    expression_type: ExpressionType,
+    pattern: String,
+    /// Tags using an expression with this pattern, across all modules
+    tags: usize,
+    expressions: Vec<String>,
+}
+
+impl CoverageReport {
+    pub fn new(stats: &ConversionStats, untranslated: &[UntranslatedExpression]) -> Self {
+        let mut summary = BTreeMap::new();
+        summary.insert(
+            "PrintConv",
+            TypeSummary {
+                attempts: stats.print_conv_attempts,
+                ppi: stats.print_conv_ppi_successes,
+                registry: stats.print_conv_registry_successes,
+                placeholder: stats.print_conv_placeholder_fallbacks,
+            },
+        );
+        summary.insert(
+            "ValueConv",
+            TypeSummary {
+                attempts: stats.value_conv_attempts,
+                ppi: stats.value_conv_ppi_successes,
+                registry: stats.value_conv_registry_successes,
+                placeholder: stats.value_conv_placeholder_fallbacks,
+            },
+        );
+        summary.insert(
+            "Condition",
+            TypeSummary {
+                attempts: stats.condition_attempts,
+                ppi: stats.condition_ppi_successes,
+                registry: stats.condition_registry_successes,
+                placeholder: stats.condition_placeholder_fallbacks,
+            },
+        );
+
+        let mut modules: BTreeMap<String, ModuleCoverage> = BTreeMap::new();
+        let mut patterns: BTreeMap<(String, String), PatternCoverage> = BTreeMap::new();
+
+        for entry in untranslated {
+            let mut by_module: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+            for usage in &entry.usages {
+                by_module
+                    .entry(&usage.module)
+                    .or_default()
+                    .push(format!("{}.{}", usage.table, usage.tag));
+            }
+            for (module, tags) in by_module {
+                let coverage = modules.entry(module.to_string()).or_default();
+                coverage.tags += tags.len();
+                coverage.expressions.push(ModuleExpression {
+                    expression_type: entry.expression_type,
+                    expression: entry.expression.clone(),
+                    function: entry.function_name.clone(),
+                    tags,
+                });
+            }
+
+            let pattern = expression_pattern(&entry.expression);
+            let type_name = format!("{:?}", entry.expression_type);
+            let coverage = patterns
+                .entry((type_name, pattern.clone()))
+                .or_insert_with(|| PatternCoverage {
+                    expression_type: entry.expression_type,
+                    pattern,
+                    tags: 0,
+                    expressions: Vec::new(),
+                });
+            coverage.tags += entry.usages.len();
+            coverage.expressions.push(entry.expression.clone());
+        }
+
+        for coverage in modules.values_mut() {
+            coverage.expressions.sort_by(|a, b| {
+                b.tags
+                    .len()
+                    .cmp(&a.tags.len())
+                    .then_with(|| a.expression.cmp(&b.expression))
+            });
+        }
+        let mut patterns: Vec<PatternCoverage> = patterns.into_values().collect();
+        for pattern in &mut patterns {
+            pattern.expressions.sort();
+            pattern.expressions.dedup();
+        }
+        // Stable sort keeps the (type, pattern) order among equal counts
+        patterns.sort_by_key(|pattern| std::cmp::Reverse(pattern.tags));
+
+        Self {
+            summary,
+            modules,
+            patterns,
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Summary for people: totals, the most common patterns, then modules
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# Conversion Coverage\n");
+        text.push_str("# Expressions neither PPI nor impl_registry could translate\n\n");
+
+        for (name, summary) in &self.summary {
+            text.push_str(&format!(
+                "{name}: {} attempts, {} PPI, {} impl_registry, {} placeholders\n",
+                summary.attempts, summary.ppi, summary.registry, summary.placeholder
+            ));
+        }
+
+        text.push_str("\n# Most common patterns (tags affected, pattern)\n\n");
+        for pattern in self.patterns.iter().take(TEXT_PATTERNS) {
+            text.push_str(&format!(
+                "{:>5}  {:?}  {}\n",
+                pattern.tags, pattern.expression_type, pattern.pattern
+            ));
+            for expression in pattern.expressions.iter().take(TEXT_EXAMPLES) {
+                text.push_str(&format!("         e.g. {}\n", one_line(expression)));
+            }
+        }
+        if self.patterns.len() > TEXT_PATTERNS {
+            text.push_str(&format!(
+                "  ... {} more in conversion_coverage.json\n",
+                self.patterns.len() - TEXT_PATTERNS
+            ));
+        }
+
+        let mut modules: Vec<_> = self.modules.iter().collect();
+        modules.sort_by_key(|(_, coverage)| std::cmp::Reverse(coverage.tags));
+        text.push_str("\n# Modules (tags affected, untranslated expressions)\n\n");
+        for (module, coverage) in modules {
+            text.push_str(&format!(
+                "{:>5}  {:>5}  {module}\n",
+                coverage.tags,
+                coverage.expressions.len()
+            ));
+        }
+
+        text
+    }
+}
+
+/// Reduce an expression to its shape, so expressions differing only in
+/// literals group together
+///
+/// String literals become `'…'`, numbers `N`, and whitespace is collapsed.
+pub fn expression_pattern(expression: &str) -> String {
+    let mut pattern = String::with_capacity(expression.len());
+    let mut chars = expression.chars().peekable();
+    let mut previous: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' | '\'' => {
+                let mut escaped = false;
+                for next in chars.by_ref() {
+                    if escaped {
+                        escaped = false;
+                    } else if next == '\\' {
+                        escaped = true;
+                    } else if next == c {
+                        break;
+                    }
+                }
+                pattern.push_str("'…'");
+            }
+            c if c.is_ascii_digit()
+                && !previous.is_some_and(|p| p.is_alphanumeric() || p == '_' || p == '$') =>
+            {
+                while chars
+                    .peek()
+                    .is_some_and(|n| n.is_ascii_hexdigit() || matches!(n, '.' | 'x' | 'e' | '_'))
+                {
+                    chars.next();
+                }
+                pattern.push('N');
+            }
+            c if c.is_whitespace() => {
+                while chars.peek().is_some_and(|n| n.is_whitespace()) {
+                    chars.next();
+                }
+                if !pattern.is_empty() {
+                    pattern.push(' ');
+                }
+            }
+            c => pattern.push(c),
+        }
+        previous = pattern.chars().next_back();
+    }
+
+    pattern.trim_end().to_string()
+}
+
+fn one_line(expression: &str) -> String {
+    expression.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(module: &str, tag: &str) -> UsageContext {
+        UsageContext {
+            module: module.to_string(),
+            table: "MAIN_TAGS".to_string(),
+            tag: tag.to_string(),
+        }
+    }
+
+    #[test]
+    fn normalizes_literals_and_whitespace() {
+        assert_eq!(
+            expression_pattern("sprintf(\"%.1f mm\",  $val / 100)"),
+            "sprintf('…', $val / N)"
+        );
+        assert_eq!(
+            expression_pattern("$val[1] ? 0x10 : 'n\\'a'"),
+            "$val[N] ? N : '…'"
+        );
+        assert_eq!(
+            expression_pattern("Image::ExifTool::Exif2"),
+            "Image::ExifTool::Exif2"
+        );
+    }
+
+    #[test]
+    fn groups_by_module_and_pattern() {
+        let untranslated = vec![
+            UntranslatedExpression {
+                expression_type: ExpressionType::PrintConv,
+                expression: "foo($val, 1)".to_string(),
+                function_name: "ast_print_a".to_string(),
+                usages: [usage("Canon_pm", "A"), usage("Nikon_pm", "B")].into(),
+            },
+            UntranslatedExpression {
+                expression_type: ExpressionType::PrintConv,
+                expression: "foo($val, 2)".to_string(),
+                function_name: "ast_print_b".to_string(),
+                usages: [usage("Canon_pm", "C")].into(),
+            },
+            UntranslatedExpression {
+                expression_type: ExpressionType::ValueConv,
+                expression: "bar($val)".to_string(),
+                function_name: "ast_value_c".to_string(),
+                usages: [usage("Canon_pm", "D")].into(),
+            },
+        ];
+
+        let report = CoverageReport::new(&ConversionStats::new(), &untranslated);
+
+        assert_eq!(report.patterns[0].pattern, "foo($val, N)");
+        assert_eq!(report.patterns[0].tags, 3);
+        assert_eq!(report.patterns[0].expressions.len(), 2);
+        assert_eq!(report.modules["Canon_pm"].tags, 3);
+        assert_eq!(report.modules["Canon_pm"].expressions.len(), 3);
+        assert_eq!(
+            report.modules["Nikon_pm"].expressions[0].tags,
+            ["MAIN_TAGS.B"]
+        );
+
+        let text = report.to_text();
+        assert!(text.contains("foo($val, N)"));
+        assert!(report.to_json().unwrap().contains("\"Nikon_pm\""));
+    }
+}
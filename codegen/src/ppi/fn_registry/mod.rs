@@ -4,6 +4,7 @@
 //! duplicates across modules. Functions are deduplicated based on AST structure hash,
 //! ensuring that semantically equivalent expressions share the same implementation.
 
+mod coverage;
 mod registry;
 mod stats;
 
@@ -266,6 +267,7 @@ impl PpiFunctionRegistry {
         // Registry lookup failed or no source module - fall back to placeholder
         self.conversion_stats_mut()
             .record_placeholder_fallback(function_spec.expression_type);
+        self.record_untranslated(function_spec, ast_hash);
         Ok(self.generate_placeholder_function(function_spec, ast_hash))
     }
 
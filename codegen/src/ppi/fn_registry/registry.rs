@@ -8,6 +8,7 @@ use std::collections::hash_map::DefaultHasher;
 use std::collections::{BTreeSet, HashMap};
 use std::hash::{Hash, Hasher};
 
+use super::coverage::{CoverageReport, UntranslatedExpression};
 use super::stats::{ConversionStats, RegistryStats};
 use crate::ppi::{ExpressionType, PpiNode};
 
@@ -57,6 +58,8 @@ pub struct PpiFunctionRegistry {
     usage_contexts: HashMap<String, BTreeSet<UsageContext>>,
     /// Statistics tracking for conversion processing
     conversion_stats: ConversionStats,
+    /// Expressions that fell back to placeholder functions, for the coverage report
+    untranslated: Vec<UntranslatedExpression>,
 }
 
 impl Default for PpiFunctionRegistry {
@@ -73,6 +76,7 @@ impl PpiFunctionRegistry {
             ast_nodes: HashMap::new(),
             usage_contexts: HashMap::new(),
             conversion_stats: ConversionStats::new(),
+            untranslated: Vec::new(),
         }
     }
 
@@ -223,6 +227,25 @@ impl PpiFunctionRegistry {
         &self.usage_contexts
     }
 
+    /// Record an expression that fell back to a placeholder function
+    pub fn record_untranslated(&mut self, function_spec: &FunctionSpec, ast_hash: &str) {
+        self.untranslated.push(UntranslatedExpression {
+            expression_type: function_spec.expression_type,
+            expression: function_spec.original_expression.clone(),
+            function_name: function_spec.function_name.clone(),
+            usages: self
+                .usage_contexts
+                .get(ast_hash)
+                .cloned()
+                .unwrap_or_default(),
+        });
+    }
+
+    /// Report of the expressions that fell back to placeholder functions
+    pub fn coverage_report(&self) -> CoverageReport {
+        CoverageReport::new(&self.conversion_stats, &self.untranslated)
+    }
+
     /// Get mutable reference to conversion stats
     pub fn conversion_stats_mut(&mut self) -> &mut ConversionStats {
         &mut self.conversion_stats
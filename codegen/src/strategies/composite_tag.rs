@@ -613,6 +613,46 @@ impl CompositeTagStrategy {
         code.push_str("});\n");
         code.push('\n');
 
+        // Generate COMPOSITE_TAG_DEFS, which keeps same-named definitions
+        // from different modules that COMPOSITE_TAGS collapses
+        let mut all_defs: Vec<(&str, &str, String)> = definitions
+            .iter()
+            .map(|def| {
+                let safe_module = def.module.to_uppercase();
+                let safe_tag = def.name.replace([':', '-'], "_").to_uppercase();
+                (
+                    def.name.as_str(),
+                    def.module.as_str(),
+                    format!("{safe_module}_{safe_tag}"),
+                )
+            })
+            .collect();
+        all_defs.sort();
+        all_defs.dedup();
+
+        code.push_str("/// Every composite tag definition, sorted by name then module\n");
+        code.push_str("///\n");
+        code.push_str(
+            "/// Unlike `COMPOSITE_TAGS`, keeps each module's definition of a name (e.g.\n",
+        );
+        code.push_str("/// both the Canon and the Samsung WB_RGGBLevels).\n");
+        code.push_str("pub static COMPOSITE_TAG_DEFS: &[&CompositeTagDef] = &[\n");
+        for (_, _, safe_name) in &all_defs {
+            code.push_str(&format!("    &COMPOSITE_{safe_name},\n"));
+        }
+        code.push_str("];\n");
+        code.push('\n');
+
+        code.push_str("/// Composite tag definitions by name, every module's definition of each\n");
+        code.push_str("pub static COMPOSITE_TAG_LOOKUP: LazyLock<HashMap<&'static str, Vec<&'static CompositeTagDef>>> = LazyLock::new(|| {\n");
+        code.push_str("    let mut lookup: HashMap<&'static str, Vec<&'static CompositeTagDef>> = HashMap::new();\n");
+        code.push_str("    for def in COMPOSITE_TAG_DEFS {\n");
+        code.push_str("        lookup.entry(def.name).or_default().push(def);\n");
+        code.push_str("    }\n");
+        code.push_str("    lookup\n");
+        code.push_str("});\n");
+        code.push('\n');
+
         // Helper functions
         code.push_str("/// Look up a composite tag definition by name\n");
         code.push_str(
@@ -622,6 +662,14 @@ impl CompositeTagStrategy {
         code.push_str("}\n");
         code.push('\n');
 
+        code.push_str("/// Every module's definition of a composite tag\n");
+        code.push_str(
+            "pub fn lookup_composite_definitions(name: &str) -> &'static [&'static CompositeTagDef] {\n",
+        );
+        code.push_str("    COMPOSITE_TAG_LOOKUP.get(name).map_or(&[], Vec::as_slice)\n");
+        code.push_str("}\n");
+        code.push('\n');
+
         code.push_str("/// Get all composite tag names\n");
         code.push_str("pub fn all_composite_tag_names() -> Vec<&'static str> {\n");
         code.push_str("    COMPOSITE_TAGS.keys().copied().collect()\n");
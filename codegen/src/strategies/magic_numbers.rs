@@ -119,6 +119,8 @@ impl MagicNumberStrategy {
                 "    if let Ok(regex) = RegexBuilder::new({raw_pattern})\n"
             ));
             code.push_str("        .unicode(false)\n");
+            // ExifTool tests magic numbers with /s, so `.` also matches "\n"
+            code.push_str("        .dot_matches_new_line(true)\n");
             code.push_str("        .build() {\n");
             code.push_str(&format!("        map.insert(\"{file_type}\", regex);\n"));
             code.push_str("    }\n\n");
@@ -267,3 +269,24 @@ impl Default for MagicNumberStrategy {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regex_dot_matches_newline() {
+        let data = MagicNumberData {
+            patterns: HashMap::new(),
+            literal_patterns: HashMap::new(),
+            regex_patterns: HashMap::from([(
+                "JXL".to_string(),
+                r"\0\0\0\x0cJXL \x0d\x0a......ftypjxl ".to_string(),
+            )]),
+        };
+        let code = MagicNumberStrategy::new().generate_magic_number_code(&data);
+        assert!(code.contains(
+            "RegexBuilder::new(r\"\\x00\\x00\\x00\\x0cJXL \\x0d\\x0a......ftypjxl \")\n        .unicode(false)\n        .dot_matches_new_line(true)\n"
+        ));
+    }
+}
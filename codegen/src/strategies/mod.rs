@@ -26,11 +26,15 @@ pub fn write_generated_file(output_dir: &str, relative_path: &str, content: &str
             .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
     }
 
-    // Write the file
-    fs::write(&full_path, content)
+    // Write the file, unless it's unchanged
+    let written = crate::incremental::write_output(&full_path, content)
         .with_context(|| format!("Failed to write generated file: {}", full_path.display()))?;
 
-    debug!("📝 Written: {} ({} bytes)", relative_path, content.len());
+    if written {
+        debug!("📝 Written: {} ({} bytes)", relative_path, content.len());
+    } else {
+        trace!("✓ Unchanged: {}", relative_path);
+    }
     Ok(())
 }
 
@@ -490,6 +494,17 @@ impl StrategyDispatcher {
         fs::write(log_path, log_content)?;
         debug!("📋 Strategy selection log written to strategy_selection.log");
 
+        let coverage = context.ppi_registry.coverage_report();
+        fs::write(
+            Path::new(output_dir).join("conversion_coverage.json"),
+            coverage.to_json()?,
+        )?;
+        fs::write(
+            Path::new(output_dir).join("conversion_coverage.txt"),
+            coverage.to_text(),
+        )?;
+        debug!("📋 Conversion coverage written to conversion_coverage.json/.txt");
+
         Ok(())
     }
 
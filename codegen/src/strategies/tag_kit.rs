@@ -23,6 +23,28 @@ pub struct TagKitStrategy {
     processed_symbols: Vec<ProcessedTagTable>,
     /// Track imports needed for generated functions (per-file state)
     imports: std::collections::HashSet<(String, String)>, // (module_path, function_name)
+    /// ProcessBinaryData tables seen, for each module's `binary_tables.rs`
+    binary_tables: Vec<BinaryTableSpec>,
+}
+
+/// Table-level attributes of a ProcessBinaryData table
+/// ExifTool: FORMAT, FIRST_ENTRY and PROCESS_PROC keys, and the Hook and
+/// DataMember keys of its entries
+#[derive(Debug, Clone)]
+struct BinaryTableSpec {
+    module_name: String,
+    table_name: String,
+    /// Generated tags file (without `.rs`) and its TagInfo map
+    file_stem: String,
+    constant_name: String,
+    format: String,
+    first_entry: u32,
+    /// `BinaryTableProc` variant
+    proc: &'static str,
+    /// Hook code by entry index
+    hooks: Vec<(u16, String)>,
+    /// DataMember names by entry index
+    data_members: Vec<(u16, String)>,
 }
 
 #[derive(Debug, Clone)]
@@ -45,6 +67,16 @@ struct ConditionalTagVariant {
     data: serde_json::Map<String, JsonValue>,
 }
 
+impl ConditionalTagVariant {
+    /// The variant's Condition, if it has a non-empty one
+    fn condition(&self) -> Option<&str> {
+        self.data
+            .get("Condition")
+            .and_then(|v| v.as_str())
+            .filter(|condition| !condition.is_empty())
+    }
+}
+
 impl Default for TagKitStrategy {
     fn default() -> Self {
         Self::new()
@@ -56,9 +88,120 @@ impl TagKitStrategy {
         Self {
             processed_symbols: Vec::new(),
             imports: std::collections::HashSet::new(),
+            binary_tables: Vec::new(),
         }
     }
 
+    /// The table's ProcessBinaryData attributes, if it is processed that way
+    fn binary_table_spec(
+        symbol: &ProcessedTagTable,
+        table_data: &serde_json::Map<String, JsonValue>,
+        constant_name: &str,
+    ) -> Option<BinaryTableSpec> {
+        let process_proc = table_data.get("PROCESS_PROC")?.as_str()?;
+        let proc = if process_proc.contains("ProcessBinaryData") {
+            "BinaryData"
+        } else if process_proc.contains("ProcessEnciphered") {
+            "Enciphered"
+        } else {
+            return None;
+        };
+
+        let first_entry = match table_data.get("FIRST_ENTRY") {
+            Some(JsonValue::Number(n)) => n.as_u64().and_then(|n| u32::try_from(n).ok()),
+            Some(JsonValue::String(s)) => s.parse().ok(),
+            _ => None,
+        };
+        // A key of the entry, or of the first variant that has it
+        let entry_keys = |key: &str| -> Vec<(u16, String)> {
+            let mut entries: Vec<(u16, String)> = table_data
+                .iter()
+                .filter_map(|(index, tag)| {
+                    let value = match tag {
+                        JsonValue::Array(variants) => {
+                            variants.iter().find_map(|variant| variant.get(key))
+                        }
+                        tag => tag.get(key),
+                    }?;
+                    Some((
+                        Self::parse_numeric_tag_key(index)?,
+                        value.as_str()?.to_string(),
+                    ))
+                })
+                .collect();
+            entries.sort();
+            entries
+        };
+
+        Some(BinaryTableSpec {
+            module_name: symbol.module_name.clone(),
+            table_name: symbol.table_name.clone(),
+            file_stem: crate::strategies::output_locations::to_snake_case(&format!(
+                "{}_tags",
+                symbol.table_name
+            )),
+            constant_name: constant_name.to_string(),
+            format: table_data
+                .get("FORMAT")
+                .and_then(|f| f.as_str())
+                .unwrap_or("int8u")
+                .to_string(),
+            first_entry: first_entry.unwrap_or(0),
+            proc,
+            hooks: entry_keys("Hook"),
+            data_members: entry_keys("DataMember"),
+        })
+    }
+
+    /// A module's `binary_tables.rs`: every ProcessBinaryData table in it, for
+    /// the generic processor (src/processor_registry/processors/binary_table.rs)
+    fn generate_binary_tables_code(module_name: &str, tables: &[&BinaryTableSpec]) -> String {
+        let mut code = formatdoc! {"
+            //! ProcessBinaryData tables of {module_name}, for the generic binary data processor
+            //!
+            //! This file is auto-generated by codegen/src/strategies/tag_kit.rs. Do not edit manually.
+
+            use crate::types::{{BinaryTableProc, GeneratedBinaryTable}};
+
+            /// Every ProcessBinaryData table in {module_name}
+            pub static BINARY_TABLES: &[GeneratedBinaryTable] = &[
+        "};
+        for table in tables {
+            // Debug formatting gives escaped Rust string literals
+            let entries = |entries: &[(u16, String)]| {
+                entries
+                    .iter()
+                    .map(|(index, value)| format!("({index}, {value:?})"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            code.push_str(&formatdoc! {"
+                    GeneratedBinaryTable {{
+                        table: \"{table_name}\",
+                        tags: &super::{file}::{constant},
+                        format: \"{format}\",
+                        first_entry: {first_entry},
+                        proc: BinaryTableProc::{proc},
+                        hooks: &[{hooks}],
+                        data_members: &[{data_members}],
+                        value_conv: super::{file}::apply_value_conv,
+                        print_conv: super::{file}::apply_print_conv,
+                    }},
+                ",
+                table_name = table.table_name,
+                file = table.file_stem,
+                constant = table.constant_name,
+                format = table.format,
+                first_entry = table.first_entry,
+                proc = table.proc,
+                hooks = entries(&table.hooks),
+                data_members = entries(&table.data_members),
+            });
+        }
+        code.push_str("];\n");
+        code
+    }
+
     /// Register an import for a conversion function
     fn register_import(&mut self, module_path: &str, function_name: &str) {
         self.imports
@@ -133,13 +276,21 @@ impl TagKitStrategy {
         // First collect all valid tag entries with their tag IDs for sorting
         // This will populate self.imports through processing
         let mut tag_entries = Vec::new();
-        // String (atom-ID) keyed entries for the parallel *_BY_NAME map (QuickTime
-        // only, see emits_by_name_map). Kept alongside the u16 map; never replaces it.
+        // String (atom-ID) keyed entries for the parallel *_BY_NAME map (gated per
+        // module, see emits_by_name_map). Kept alongside the u16 map; never replaces it.
         let mut string_tag_entries: Vec<(String, String)> = Vec::new();
         let want_by_name = Self::emits_by_name_map(&symbol.module_name);
+        // Names of numeric-keyed tags with `Writable => 'string'` (see emits_string_tags)
+        let want_string_tags = Self::emits_string_tags(&symbol.module_name);
+        let mut string_tag_names: Vec<(u16, String)> = Vec::new();
+        // IDs of tags with `List => 1` (see emits_list_tags)
+        let want_list_tags = Self::emits_list_tags(&symbol.module_name, &symbol.table_name);
+        let mut list_tag_ids: Vec<u16> = Vec::new();
         // Track conditional tag overrides per context (DIR_NAME -> Vec<(tag_id, entry)>)
         let mut context_overrides: std::collections::HashMap<String, Vec<(u16, String)>> =
             std::collections::HashMap::new();
+        // Condition-selected variants (tag_id -> TagVariant literals, in ExifTool's order)
+        let mut variant_entries: Vec<(u16, Vec<String>)> = Vec::new();
 
         for (tag_key, tag_data) in table_data {
             let is_numeric = Self::parse_numeric_tag_key(tag_key).is_some();
@@ -157,6 +308,13 @@ impl TagKitStrategy {
                         &symbol.table_name,
                         context,
                     )? {
+                        if want_string_tags {
+                            string_tag_names
+                                .extend(Self::string_tag_name(tag_id, tag_key, tag_obj));
+                        }
+                        if want_list_tags && Self::is_list_tag(tag_obj) {
+                            list_tag_ids.push(tag_id);
+                        }
                         tag_entries.push((tag_id, entry));
                     }
                 } else if want_by_name {
@@ -200,6 +358,27 @@ impl TagKitStrategy {
                         variants.len()
                     );
 
+                    // Variants chosen by something other than DIR_NAME (model, count, ...)
+                    // are selected at runtime by evaluating their Conditions
+                    if let Some(tag_id) = Self::parse_numeric_tag_key(tag_key) {
+                        if variants.len() > 1
+                            && variants
+                                .iter()
+                                .any(|v| v.dir_name.is_none() && v.condition().is_some())
+                        {
+                            let mut literals = Vec::with_capacity(variants.len());
+                            for variant in &variants {
+                                literals.push(self.build_tag_variant_literal(
+                                    variant,
+                                    &symbol.module_name,
+                                    &symbol.table_name,
+                                    context,
+                                )?);
+                            }
+                            variant_entries.push((tag_id, literals));
+                        }
+                    }
+
                     // Find the default variant (last one without condition) for main table
                     let default_variant = variants
                         .iter()
@@ -215,6 +394,16 @@ impl TagKitStrategy {
                             &symbol.table_name,
                             context,
                         )? {
+                            if want_string_tags {
+                                string_tag_names.extend(Self::string_tag_name(
+                                    tag_id,
+                                    tag_key,
+                                    &default.data,
+                                ));
+                            }
+                            if want_list_tags && Self::is_list_tag(&default.data) {
+                                list_tag_ids.push(tag_id);
+                            }
                             tag_entries.push((tag_id, entry));
 
                             // Generate context-specific overrides for non-default variants
@@ -251,6 +440,9 @@ impl TagKitStrategy {
 
         // Sort entries by tag ID for deterministic output
         tag_entries.sort_by_key(|(tag_id, _)| *tag_id);
+        variant_entries.sort_by_key(|(tag_id, _)| *tag_id);
+        string_tag_names.sort();
+        list_tag_ids.sort();
 
         // Now we can start building the output with the correct imports
         let mut code = String::new();
@@ -266,6 +458,11 @@ impl TagKitStrategy {
         code.push_str("use std::sync::LazyLock;\n");
         code.push_str("use std::collections::HashMap;\n");
         code.push_str("use crate::types::{TagInfo, PrintConv, ValueConv};\n");
+        if !variant_entries.is_empty() {
+            code.push_str(
+                "use crate::types::{select_tag_variant, ExifContext, TagValue, TagVariant};\n",
+            );
+        }
 
         // Add imports for conversion functions (after processing tags)
         if !self.imports.is_empty() {
@@ -311,6 +508,10 @@ impl TagKitStrategy {
             symbol.table_name.to_uppercase()
         );
 
+        if let Some(spec) = Self::binary_table_spec(symbol, table_data, &constant_name) {
+            self.binary_tables.push(spec);
+        }
+
         code.push_str(&format!(
             "/// Tag definitions for {}::{} table\n",
             symbol.module_name, symbol.table_name
@@ -333,6 +534,41 @@ impl TagKitStrategy {
             code.push_str("});\n\n");
         }
 
+        // Emit the names of ASCII tags. Most of them have no explicit Format, so
+        // their TagInfo format is "unknown" even though the value is always a
+        // string (ExifTool's Writable is the format used when writing the tag).
+        if !string_tag_names.is_empty() {
+            code.push_str(&format!(
+                "/// Names of tags in {}::{} with `Writable => 'string'`, in tag ID order\n",
+                symbol.module_name, symbol.table_name
+            ));
+            code.push_str(&format!(
+                "pub static {}_{}_STRING_TAGS: &[&str] = &[\n",
+                module_snake_case.to_uppercase(),
+                symbol.table_name.to_uppercase()
+            ));
+            for (_, name) in &string_tag_names {
+                code.push_str(&format!("    \"{name}\",\n"));
+            }
+            code.push_str("];\n\n");
+        }
+
+        // Emit the IDs of list tags, which may hold several values
+        if !list_tag_ids.is_empty() {
+            code.push_str(&format!(
+                "/// IDs of tags in {}::{} with `List => 1`, in tag ID order\n",
+                symbol.module_name, symbol.table_name
+            ));
+            code.push_str(&format!(
+                "pub static {}_{}_LIST_TAGS: &[u16] = &[",
+                module_snake_case.to_uppercase(),
+                symbol.table_name.to_uppercase()
+            ));
+            let ids: Vec<String> = list_tag_ids.iter().map(|id| id.to_string()).collect();
+            code.push_str(&ids.join(", "));
+            code.push_str("];\n\n");
+        }
+
         // Generate context-specific override maps for conditional tags
         // ExifTool Reference: Tags like 0x201 have different names based on DIR_NAME context
         if !context_overrides.is_empty() {
@@ -397,24 +633,67 @@ impl TagKitStrategy {
             code.push_str("}\n\n");
         }
 
-        // Emit the parallel string-keyed (atom-ID) map for non-numeric tables.
-        // ExifTool matches QuickTime atom tags byte-for-byte (ProcessMOV,
-        // QuickTime.pm), so keys are raw &[u8] FourCC / dotted IDs. This is emitted
-        // IN ADDITION TO (never in place of) the u16 map, so existing consumers are
-        // untouched. Gated to QuickTime via emits_by_name_map.
-        if !string_tag_entries.is_empty() {
-            string_tag_entries.sort_by(|a, b| a.0.cmp(&b.0));
-            let by_name_const = format!("{constant_name}_BY_NAME");
+        // Generate the Condition-selected variants and a value-aware lookup
+        // ExifTool Reference: GetTagInfo evaluates each variant's Condition in order
+        if !variant_entries.is_empty() {
+            let variants_const = format!("{constant_name}_VARIANTS");
             code.push_str(&format!(
-                "/// Atom-ID (byte-string) keyed tag definitions for {}::{} table\n",
+                "/// Condition-selected variants of tags in {}::{}, in ExifTool's order\n",
                 symbol.module_name, symbol.table_name
             ));
+            code.push_str(&format!(
+                "pub static {variants_const}: LazyLock<HashMap<u16, Vec<TagVariant>>> = LazyLock::new(|| {{\n"
+            ));
+            code.push_str("    HashMap::from([\n");
+            for (tag_id, literals) in &variant_entries {
+                code.push_str(&format!("        ({tag_id}, vec![\n"));
+                for literal in literals {
+                    code.push_str(&format!("            {literal},\n"));
+                }
+                code.push_str("        ]),\n");
+            }
+            code.push_str("    ])\n");
+            code.push_str("});\n\n");
+
+            code.push_str("/// Get tag info for a value, resolving Condition-selected variants\n");
             code.push_str(
-                "/// Keys are the exact bytes ExifTool matches against the 4-byte atom tag\n",
+                "/// Falls back to the default definition when no variant's Condition holds\n",
             );
             code.push_str(
-                "/// (copyright-prefixed IDs keep the raw 0xA9 byte, e.g. b\"\\xa9ART\").\n",
+                "pub fn get_tag_info_for_value(tag_id: u16, val: &TagValue, ctx: Option<&ExifContext>) -> Option<&'static TagInfo> {\n",
             );
+            code.push_str(&format!(
+                "    {variants_const}.get(&tag_id).and_then(|variants| select_tag_variant(variants, val, ctx)).or_else(|| {constant_name}.get(&tag_id))\n"
+            ));
+            code.push_str("}\n\n");
+        }
+
+        // Emit the parallel string-keyed map for non-numeric tables. ExifTool
+        // matches QuickTime atom tags, ID3 frame IDs, Vorbis comment fields and
+        // Apple RunTime plist keys byte-for-byte, so keys are raw &[u8] IDs. This
+        // is emitted IN ADDITION TO (never in place of) the u16 map, so existing
+        // consumers are untouched. Gated per module via emits_by_name_map.
+        if !string_tag_entries.is_empty() {
+            string_tag_entries.sort_by(|a, b| a.0.cmp(&b.0));
+            let by_name_const = format!("{constant_name}_BY_NAME");
+            if symbol.module_name == "QuickTime" {
+                code.push_str(&format!(
+                    "/// Atom-ID (byte-string) keyed tag definitions for {}::{} table\n",
+                    symbol.module_name, symbol.table_name
+                ));
+                code.push_str(
+                    "/// Keys are the exact bytes ExifTool matches against the 4-byte atom tag\n",
+                );
+                code.push_str(
+                    "/// (copyright-prefixed IDs keep the raw 0xA9 byte, e.g. b\"\\xa9ART\").\n",
+                );
+            } else {
+                code.push_str(&format!(
+                    "/// Byte-string keyed tag definitions for {}::{} table\n",
+                    symbol.module_name, symbol.table_name
+                ));
+                code.push_str("/// Keys are the exact tag IDs ExifTool matches, case included.\n");
+            }
             code.push_str(&format!(
                 "pub static {by_name_const}: LazyLock<HashMap<&'static [u8], TagInfo>> = LazyLock::new(|| {{\n"
             ));
@@ -577,13 +856,58 @@ impl TagKitStrategy {
     }
 
     /// Whether this module's string-keyed tables should ALSO emit a parallel
-    /// `<CONST>_BY_NAME: HashMap<&'static [u8], TagInfo>` map. Only QuickTime's
-    /// atom-ID tables are consumed today (by the video walker, TPP
-    /// 20260703-P1-quicktime-video-read), so we gate on the module to keep
-    /// generated churn confined to QuickTime_pm. Widen this to generalize
-    /// string-keyed tables to other container formats (JPEG, H264, RIFF, ...).
+    /// `<CONST>_BY_NAME: HashMap<&'static [u8], TagInfo>` map. Consumed today by
+    /// the QuickTime video walker (atom IDs, TPP 20260703-P1-quicktime-video-read),
+    /// the audio readers (ID3v2 frame IDs, Vorbis comment fields) and the Apple
+    /// maker note (RunTime plist keys), so we gate on the module to keep generated
+    /// churn confined to those modules. Widen this to generalize string-keyed
+    /// tables to other container formats (JPEG, H264, RIFF, ...).
     fn emits_by_name_map(module: &str) -> bool {
-        module == "QuickTime"
+        matches!(module, "QuickTime" | "ID3" | "Vorbis" | "Apple")
+    }
+
+    /// Whether this module's tag tables should ALSO emit a `<CONST>_STRING_TAGS`
+    /// list of the tags with `Writable => 'string'`. Only the EXIF and GPS tables
+    /// are consumed today (by the JSON Schema, src/schema.rs), so we gate on the
+    /// module to keep generated churn confined to Exif_pm and GPS_pm.
+    fn emits_string_tags(module: &str) -> bool {
+        matches!(module, "Exif" | "GPS")
+    }
+
+    /// Whether this table should ALSO emit a `<CONST>_LIST_TAGS` list of the IDs
+    /// of tags with `List => 1`. Only IPTC's ApplicationRecord is consumed today
+    /// (by the IPTC writer, src/write/iptc.rs, to tell which datasets may
+    /// repeat), so we gate on the table to keep generated churn confined to it.
+    fn emits_list_tags(module: &str, table: &str) -> bool {
+        matches!((module, table), ("IPTC", "ApplicationRecord"))
+    }
+
+    /// Whether a tag holds a list of values
+    /// ExifTool: List => 1 (e.g. IPTC.pm Keywords)
+    fn is_list_tag(tag_data: &serde_json::Map<String, JsonValue>) -> bool {
+        match tag_data.get("List") {
+            Some(JsonValue::Bool(list)) => *list,
+            Some(JsonValue::Number(list)) => list.as_f64() != Some(0.0),
+            Some(JsonValue::String(list)) => !list.is_empty() && list != "0",
+            _ => false,
+        }
+    }
+
+    /// The tag's name if it is written as an ASCII string
+    /// ExifTool: Writable => 'string' (e.g. Exif.pm Make, GPS.pm GPSLatitudeRef)
+    fn string_tag_name(
+        tag_id: u16,
+        tag_key: &str,
+        tag_data: &serde_json::Map<String, JsonValue>,
+    ) -> Option<(u16, String)> {
+        if tag_data.get("Writable").and_then(|v| v.as_str()) != Some("string") {
+            return None;
+        }
+        let name = tag_data
+            .get("Name")
+            .and_then(|v| v.as_str())
+            .unwrap_or(tag_key);
+        Some((tag_id, name.to_string()))
     }
 
     /// ExifTool %specialTags (ExifTool.pm:1230): table directives (PROCESS_PROC,
@@ -694,6 +1018,73 @@ impl TagKitStrategy {
         (tag_key.to_string(), entry)
     }
 
+    /// Build a `TagVariant { ... }` literal for one conditional variant
+    ///
+    /// The Condition becomes a generated predicate when PPI can translate it.
+    /// Otherwise only the expression is kept, and the variant never matches.
+    fn build_tag_variant_literal(
+        &mut self,
+        variant: &ConditionalTagVariant,
+        module: &str,
+        table_name: &str,
+        context: &mut ExtractionContext,
+    ) -> Result<String> {
+        let info =
+            self.build_tag_info_literal(&variant.name, &variant.data, module, table_name, context)?;
+
+        let Some(condition_str) = variant.condition() else {
+            return Ok(format!(
+                "TagVariant {{\n                condition: None,\n                condition_expr: None,\n                info: {info},\n            }}"
+            ));
+        };
+
+        let mut condition = "None".to_string();
+        if let Some(ast_value) = variant.data.get("Condition_ast") {
+            if Self::uses_condition_only_variables(condition_str) {
+                debug!(
+                    "Condition '{}' reads the raw directory entry, leaving it untranslated",
+                    condition_str
+                );
+            } else {
+                context
+                    .ppi_registry
+                    .record_conversion_attempt(ExpressionType::Condition);
+                match self.process_ast_expression(
+                    ast_value,
+                    ExpressionType::Condition,
+                    condition_str,
+                    module,
+                    &variant.name,
+                    table_name,
+                    context,
+                ) {
+                    Ok(function_name) => condition = format!("Some({function_name})"),
+                    Err(e) => {
+                        debug!(
+                            "PPI generation failed for Condition '{}': {}",
+                            condition_str, e
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(format!(
+            "TagVariant {{\n                condition: {condition},\n                condition_expr: Some(\"{}\"),\n                info: {info},\n            }}",
+            escape_string(condition_str)
+        ))
+    }
+
+    /// Whether a Condition uses the variables ExifTool only sets while reading
+    /// a directory entry (`$count`, `$format`, `$$valPt`), which generated
+    /// functions don't receive
+    fn uses_condition_only_variables(condition: &str) -> bool {
+        static CONDITION_ONLY: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(|| {
+            regex::Regex::new(r"\$(count|format|valPt)\b").expect("Invalid regex")
+        });
+        CONDITION_ONLY.is_match(condition)
+    }
+
     /// Process PrintConv field using PPI AST when available, falling back to existing impl_registry
     fn process_print_conv(
         &mut self,
@@ -766,6 +1157,24 @@ impl TagKitStrategy {
                     escape_string(print_conv_str)
                 ));
             } else if let Some(print_conv_obj) = print_conv_value.as_object() {
+                // A hash holding only a BITMASK names the set bits (ExifTool DecodeBits)
+                if let (1, Some(bitmask)) = (
+                    print_conv_obj.len(),
+                    print_conv_obj.get("BITMASK").and_then(|v| v.as_object()),
+                ) {
+                    let mut bits: Vec<(u32, &str)> = bitmask
+                        .iter()
+                        .filter_map(|(bit, name)| Some((bit.parse().ok()?, name.as_str()?)))
+                        .collect();
+                    bits.sort_by_key(|(bit, _)| *bit);
+                    let bit_entries = bits
+                        .iter()
+                        .map(|(bit, name)| format!("({bit}, \"{}\")", escape_string(name)))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    return Ok(format!("Some(PrintConv::Bitmask(&[{bit_entries}]))"));
+                }
+
                 // This is an inline hash lookup table - generate PrintConv::Simple
                 // Filter out special entries like "BITMASK" that are not simple lookups
                 let entries: Vec<_> = print_conv_obj
@@ -1122,6 +1531,19 @@ impl ExtractionStrategy for TagKitStrategy {
             }
         }
 
+        // One binary_tables.rs per module, tables sorted by name
+        let mut binary_tables = std::mem::take(&mut self.binary_tables);
+        binary_tables
+            .sort_by(|a, b| (&a.module_name, &a.table_name).cmp(&(&b.module_name, &b.table_name)));
+        for module_tables in binary_tables.chunk_by(|a, b| a.module_name == b.module_name) {
+            let module_name = &module_tables[0].module_name;
+            let tables: Vec<&BinaryTableSpec> = module_tables.iter().collect();
+            files.push(GeneratedFile {
+                path: generate_module_path(module_name, "binary_tables"),
+                content: Self::generate_binary_tables_code(module_name, &tables),
+            });
+        }
+
         // Clear processed symbols
         self.processed_symbols.clear();
 
@@ -1129,3 +1551,86 @@ impl ExtractionStrategy for TagKitStrategy {
         Ok(files)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_condition_selected_variants() {
+        let mut strategy = TagKitStrategy::new();
+        let mut context = ExtractionContext::new("output".to_string());
+        let table = ProcessedTagTable {
+            module_name: "Canon".to_string(),
+            table_name: "ShotInfo".to_string(),
+            symbol_data: json!({
+                "22": [
+                    {"Name": "ExposureTime", "Condition": "$count == 4", "Format": "int16s"},
+                    {"Name": "ExposureTime", "Format": "int16u"}
+                ],
+                "23": [
+                    {"Name": "ThumbnailOffset", "Condition": "$$self{DIR_NAME} eq 'IFD1'"},
+                    {"Name": "OtherImageStart"}
+                ]
+            }),
+        };
+
+        let code = strategy
+            .generate_tag_table_code(&table, &mut context)
+            .unwrap();
+
+        assert!(code.contains("pub static CANON_SHOTINFO_TAGS_VARIANTS"));
+        assert!(code.contains("(22, vec!["));
+        assert!(code.contains("condition_expr: Some(\"$count == 4\")"));
+        assert!(code.contains("pub fn get_tag_info_for_value("));
+        // DIR_NAME variants keep using the per-directory override maps
+        assert!(!code.contains("(23, vec!["));
+        assert!(code.contains("pub static CANON_SHOTINFO_IFD1_TAGS"));
+    }
+
+    #[test]
+    fn test_binary_table_specs() {
+        let mut strategy = TagKitStrategy::new();
+        let mut context = ExtractionContext::new("output".to_string());
+        let table = ProcessedTagTable {
+            module_name: "Canon".to_string(),
+            table_name: "ShotInfo".to_string(),
+            symbol_data: json!({
+                "PROCESS_PROC": "[Function: Image::ExifTool::ProcessBinaryData]",
+                "FORMAT": "int16s",
+                "FIRST_ENTRY": 1,
+                "1": {"Name": "AutoISO", "DataMember": "AutoISO"},
+                "7": {"Name": "WhiteBalance", "Hook": "$format = 'int32u'"},
+                "9": [{"Name": "SequenceNumber", "Hook": "$varSize += 2"}, {"Name": "Other"}]
+            }),
+        };
+        strategy
+            .generate_tag_table_code(&table, &mut context)
+            .unwrap();
+
+        let [spec] = strategy.binary_tables.as_slice() else {
+            panic!(
+                "expected one binary table, got {:?}",
+                strategy.binary_tables
+            );
+        };
+        assert_eq!(spec.file_stem, "shot_info_tags");
+        assert_eq!(spec.format, "int16s");
+        assert_eq!(spec.first_entry, 1);
+        assert_eq!(spec.proc, "BinaryData");
+        assert_eq!(
+            spec.hooks,
+            [
+                (7, "$format = 'int32u'".to_string()),
+                (9, "$varSize += 2".to_string())
+            ]
+        );
+        assert_eq!(spec.data_members, [(1, "AutoISO".to_string())]);
+
+        let code = TagKitStrategy::generate_binary_tables_code("Canon", &[spec]);
+        assert!(code.contains("tags: &super::shot_info_tags::CANON_SHOTINFO_TAGS,"));
+        assert!(code.contains(r#"hooks: &[(7, "$format = 'int32u'"), (9, "$varSize += 2")],"#));
+        assert!(code.contains(r#"data_members: &[(1, "AutoISO")],"#));
+    }
+}
@@ -0,0 +1,47 @@
+//! Error type shared by the TIFF parser and the exif-oxide runtime
+
+use alloc::string::{String, ToString};
+
+/// Error types for runtime operations
+///
+/// Non-exhaustive: the `std` feature adds the [`ExifError::Io`] variant.
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum ExifError {
+    #[error("Parse error: {0}")]
+    ParseError(String),
+
+    #[error("IO error: {0}")]
+    IoError(String),
+
+    #[cfg(feature = "std")]
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Invalid file format: {0}")]
+    InvalidFormat(String),
+
+    #[error("Format error: {0}")]
+    FormatError(String),
+
+    #[error("Unsupported feature: {0}")]
+    Unsupported(String),
+
+    #[error("Not implemented: {0}")]
+    NotImplemented(String),
+
+    #[error("File detection error: {0}")]
+    FileDetection(String),
+}
+
+impl ExifError {
+    /// Create a new parse error
+    pub fn new(msg: &str) -> Self {
+        ExifError::ParseError(msg.to_string())
+    }
+}
+
+// Note: From<std::io::Error> is automatically derived via #[from] on ExifError::Io
+
+/// Result type alias for convenience
+pub type Result<T> = core::result::Result<T, ExifError>;
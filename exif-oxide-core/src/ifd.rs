@@ -0,0 +1,166 @@
+//! Allocation-light IFD walking for `no_std` users
+//!
+//! exif-oxide's `ExifReader` layers tag tables, PrintConv, maker notes and
+//! subdirectory recursion on top of this same entry layout. Firmware that only
+//! needs raw values (e.g. a tethering device reading Make/Model/Orientation)
+//! can walk IFDs here without any of that.
+
+use crate::tiff::{ByteOrder, IfdEntry, TiffFormat};
+use crate::value_extraction::{
+    extract_ascii_value, extract_byte_array_value, extract_long_array, extract_rational_value,
+    extract_short_array_value, extract_srational_value,
+};
+use crate::{ExifError, Result, TagValue};
+use alloc::format;
+use alloc::vec::Vec;
+use tracing::debug;
+
+/// One parsed Image File Directory
+#[derive(Debug, Clone)]
+pub struct Ifd {
+    /// Entries in directory order. Entries with an invalid format are skipped.
+    pub entries: Vec<IfdEntry>,
+    /// Offset of the next IFD in the chain (IFD0 -> IFD1), if any
+    pub next_ifd_offset: Option<u32>,
+}
+
+impl Ifd {
+    /// Parse the IFD at `offset`
+    ///
+    /// Like ExifTool, a directory cut short by the end of `data` yields the
+    /// entries that fit rather than an error.
+    /// ExifTool: lib/Image/ExifTool/Exif.pm:6235-6349 ProcessExif directory loop
+    pub fn parse(data: &[u8], offset: usize, byte_order: ByteOrder) -> Result<Self> {
+        if offset + 2 > data.len() {
+            return Err(ExifError::ParseError(format!(
+                "IFD offset {offset:#x} beyond data bounds"
+            )));
+        }
+
+        let num_entries = byte_order.read_u16(data, offset)? as usize;
+        let mut entries = Vec::with_capacity(num_entries.min((data.len() - offset) / 12));
+
+        for index in 0..num_entries {
+            let entry_offset = offset + 2 + 12 * index;
+            if entry_offset + 12 > data.len() {
+                debug!("IFD entry {index} at {entry_offset:#x} beyond data bounds");
+                break;
+            }
+            match IfdEntry::parse(data, entry_offset, byte_order) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => debug!("Skipping IFD entry {index}: {e}"),
+            }
+        }
+
+        // ExifTool: lib/Image/ExifTool/Exif.pm:6236 dirSize = 2 + 12 * numEntries + 4
+        let next_ifd_offset = byte_order
+            .read_u32(data, offset + 2 + 12 * num_entries)
+            .ok()
+            .filter(|&next| next != 0);
+
+        Ok(Ifd {
+            entries,
+            next_ifd_offset,
+        })
+    }
+
+    /// Find the entry for `tag_id`
+    pub fn entry(&self, tag_id: u16) -> Option<&IfdEntry> {
+        self.entries.iter().find(|entry| entry.tag_id == tag_id)
+    }
+}
+
+/// Extract the raw (pre-ValueConv) value of an IFD entry
+///
+/// Single values come back as scalars and counts > 1 as arrays, matching the
+/// `TagValue` shapes exif-oxide produces for the same entries.
+pub fn extract_value(data: &[u8], entry: &IfdEntry, byte_order: ByteOrder) -> Result<TagValue> {
+    match entry.format {
+        TiffFormat::Ascii => {
+            extract_ascii_value(data, entry, byte_order, entry.tag_id).map(TagValue::String)
+        }
+        TiffFormat::Byte => {
+            let mut values = extract_byte_array_value(data, entry, byte_order)?;
+            Ok(if values.len() == 1 {
+                TagValue::U8(values.remove(0))
+            } else {
+                TagValue::U8Array(values)
+            })
+        }
+        TiffFormat::Short => {
+            let mut values = extract_short_array_value(data, entry, byte_order)?;
+            Ok(if values.len() == 1 {
+                TagValue::U16(values.remove(0))
+            } else {
+                TagValue::U16Array(values)
+            })
+        }
+        TiffFormat::Long | TiffFormat::Ifd => {
+            let mut values = extract_long_array(data, entry, byte_order)?;
+            Ok(if values.len() == 1 {
+                TagValue::U32(values.remove(0))
+            } else {
+                TagValue::U32Array(values)
+            })
+        }
+        TiffFormat::Rational => extract_rational_value(data, entry, byte_order),
+        TiffFormat::SRational => extract_srational_value(data, entry, byte_order),
+        TiffFormat::Undefined => {
+            extract_byte_array_value(data, entry, byte_order).map(TagValue::Binary)
+        }
+        format => Err(ExifError::NotImplemented(format!(
+            "Value extraction for {format:?} entries"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tiff::TiffHeader;
+
+    /// Little-endian TIFF with IFD0 = { Make: "Cam", Orientation: 6 }
+    fn sample_tiff() -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(b"II\x2a\x00\x08\x00\x00\x00");
+        data.extend_from_slice(&2u16.to_le_bytes());
+        // Make (0x010f), ASCII, count 4, inline "Cam\0"
+        data.extend_from_slice(&[0x0f, 0x01, 0x02, 0x00, 0x04, 0x00, 0x00, 0x00]);
+        data.extend_from_slice(b"Cam\0");
+        // Orientation (0x0112), SHORT, count 1, inline 6
+        data.extend_from_slice(&[0x12, 0x01, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00]);
+        data.extend_from_slice(&[0x06, 0x00, 0x00, 0x00]);
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_parse_ifd0_and_extract_values() {
+        let data = sample_tiff();
+        let header = TiffHeader::parse(&data).unwrap();
+        let ifd = Ifd::parse(&data, header.ifd0_offset as usize, header.byte_order).unwrap();
+
+        assert_eq!(ifd.entries.len(), 2);
+        assert_eq!(ifd.next_ifd_offset, None);
+
+        let make = ifd.entry(0x010f).unwrap();
+        assert_eq!(
+            extract_value(&data, make, header.byte_order).unwrap(),
+            TagValue::String("Cam".into())
+        );
+        let orientation = ifd.entry(0x0112).unwrap();
+        assert_eq!(
+            extract_value(&data, orientation, header.byte_order).unwrap(),
+            TagValue::U16(6)
+        );
+    }
+
+    #[test]
+    fn test_truncated_ifd_keeps_complete_entries() {
+        let data = sample_tiff();
+        let truncated = &data[..8 + 2 + 12 + 6];
+        let ifd = Ifd::parse(truncated, 8, ByteOrder::LittleEndian).unwrap();
+        assert_eq!(ifd.entries.len(), 1);
+        assert_eq!(ifd.next_ifd_offset, None);
+    }
+}
@@ -0,0 +1,55 @@
+//! `no_std` TIFF/EXIF parsing layer for exif-oxide
+//!
+//! This crate holds the parts of exif-oxide that need nothing beyond `alloc`:
+//! the [`TagValue`] type, TIFF header/IFD entry parsing, typed value
+//! extraction and the shared [`ExifError`]. It never touches the filesystem
+//! or the local clock, so embedded firmware (camera tethering devices, for
+//! example) can reuse the same parser that exif-oxide runs on the desktop.
+//!
+//! exif-oxide re-exports everything here under its historical paths
+//! (`exif_oxide::TagValue`, `exif_oxide::tiff_types`, ...), so most users
+//! never depend on this crate directly.
+//!
+//! ## Features
+//!
+//! - `std` (default): an `ExifError::Io` variant wrapping `std::io::Error`
+//!   (`ExifError` is `#[non_exhaustive]`, so matches don't depend on it).
+//!   Build with `default-features = false` for `no_std` + `alloc` targets.
+//!
+//! ```
+//! use exif_oxide_core::ifd::{extract_value, Ifd};
+//! use exif_oxide_core::tiff::TiffHeader;
+//! use exif_oxide_core::TagValue;
+//!
+//! // Little-endian TIFF whose IFD0 holds one entry: Orientation = 6
+//! let data = [
+//!     b'I', b'I', 0x2a, 0x00, 0x08, 0x00, 0x00, 0x00, // header
+//!     0x01, 0x00, // 1 entry
+//!     0x12, 0x01, 0x03, 0x00, 0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, // no IFD1
+//! ];
+//!
+//! let header = TiffHeader::parse(&data)?;
+//! let ifd0 = Ifd::parse(&data, header.ifd0_offset as usize, header.byte_order)?;
+//! let orientation = ifd0.entry(0x0112).unwrap();
+//! assert_eq!(
+//!     extract_value(&data, orientation, header.byte_order)?,
+//!     TagValue::U16(6)
+//! );
+//! # Ok::<(), exif_oxide_core::ExifError>(())
+//! ```
+
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod error;
+pub mod ifd;
+pub mod tag_value;
+pub mod tiff;
+pub mod value_extraction;
+
+pub use error::{ExifError, Result};
+pub use tag_value::TagValue;
@@ -1,9 +1,10 @@
 //! Conversion methods and From trait implementations for TagValue
 
-use crate::core::TagValue;
-use regex::Regex;
-use std::collections::HashMap;
-use std::sync::LazyLock;
+use super::serialization::matches_numeric_pattern;
+use crate::TagValue;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 impl TagValue {
     /// Convert to u8 if possible
@@ -115,16 +116,16 @@ impl TagValue {
         }
     }
 
-    /// Get as object (HashMap) if this is an Object variant
-    pub fn as_object(&self) -> Option<&HashMap<String, TagValue>> {
+    /// Get as object (map) if this is an Object variant
+    pub fn as_object(&self) -> Option<&BTreeMap<String, TagValue>> {
         match self {
             TagValue::Object(map) => Some(map),
             _ => None,
         }
     }
 
-    /// Get as mutable object (HashMap) if this is an Object variant
-    pub fn as_object_mut(&mut self) -> Option<&mut HashMap<String, TagValue>> {
+    /// Get as mutable object (map) if this is an Object variant
+    pub fn as_object_mut(&mut self) -> Option<&mut BTreeMap<String, TagValue>> {
         match self {
             TagValue::Object(map) => Some(map),
             _ => None,
@@ -147,6 +148,24 @@ impl TagValue {
         }
     }
 
+    /// Add another occurrence of a repeating tag's value
+    ///
+    /// A scalar becomes a two-item `Array`; an `Array` grows. Array items of
+    /// `value` are added individually, so appending lists concatenates them.
+    /// ExifTool: List tags collect every occurrence (e.g. IPTC Keywords)
+    pub fn append(&mut self, value: TagValue) {
+        if !matches!(self, TagValue::Array(_)) {
+            let first = core::mem::replace(self, TagValue::Empty);
+            *self = TagValue::Array(alloc::vec![first]);
+        }
+        if let TagValue::Array(items) = self {
+            match value {
+                TagValue::Array(more) => items.extend(more),
+                value => items.push(value),
+            }
+        }
+    }
+
     /// Check if value is "truthy" following Perl semantics
     ///
     /// In Perl:
@@ -279,7 +298,7 @@ impl TagValue {
     /// # Examples
     ///
     /// ```
-    /// use exif_oxide::core::TagValue;
+    /// use exif_oxide_core::TagValue;
     ///
     /// let tag_value = TagValue::string("Hello");
     /// assert_eq!(tag_value, TagValue::String("Hello".to_string()));
@@ -300,7 +319,7 @@ impl TagValue {
     /// # Examples
     ///
     /// ```
-    /// use exif_oxide::core::TagValue;
+    /// use exif_oxide_core::TagValue;
     ///
     /// // Numeric strings become F64 values
     /// let numeric = TagValue::string_with_numeric_detection("14.0");
@@ -311,17 +330,10 @@ impl TagValue {
     /// assert_eq!(text, TagValue::String("24.0 mm".to_string()));
     /// ```
     pub fn string_with_numeric_detection<S: Into<String>>(s: S) -> Self {
-        // ExifTool numeric detection regex - matches valid JSON numbers
-        // From exiftool:3762: ^-?(\d|[1-9]\d{1,14})(\.\d{1,16})?(e[-+]?\d{1,3})?$
-        static NUMERIC_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-            Regex::new(r"^-?(\d|[1-9]\d{1,14})(\.\d{1,16})?(e[-+]?\d{1,3})?$")
-                .expect("Invalid numeric detection regex")
-        });
-
         let string_val = s.into();
 
         // Check if string matches ExifTool's numeric pattern
-        if NUMERIC_REGEX.is_match(&string_val) {
+        if matches_numeric_pattern(&string_val) {
             // First try to parse as integer to preserve integer types
             // This matches ExifTool's JSON output format where integers are integers, not floats
             if let Ok(int_val) = string_val.parse::<i64>() {
@@ -1,7 +1,7 @@
 //! Display formatting implementation for TagValue
 
-use crate::core::TagValue;
-use std::fmt;
+use crate::TagValue;
+use core::fmt;
 
 impl fmt::Display for TagValue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -9,11 +9,15 @@ mod display;
 mod ops;
 mod serialization;
 
+pub use serialization::BASE64_PREFIX;
+
 #[cfg(test)]
 mod tests;
 
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
 use serde::Deserialize;
-use std::collections::HashMap;
 
 /// Represents a tag value that can be of various types
 ///
@@ -25,7 +29,7 @@ use std::collections::HashMap;
 /// There are several convenient ways to create string TagValues:
 ///
 /// ```
-/// use exif_oxide::core::TagValue;
+/// use exif_oxide_core::TagValue;
 ///
 /// // Most ergonomic - using From trait
 /// let tag1: TagValue = "Hello".into();
@@ -86,8 +90,9 @@ pub enum TagValue {
     /// Raw binary data when type is unknown
     Binary(Vec<u8>),
     /// Nested object for structured data (e.g., XMP structures)
-    /// Used for hierarchical metadata like ContactInfo, LocationCreated
-    Object(HashMap<String, TagValue>),
+    /// Used for hierarchical metadata like ContactInfo, LocationCreated.
+    /// Keys are kept sorted, so output doesn't depend on hashing
+    Object(BTreeMap<String, TagValue>),
     /// Array of heterogeneous values (e.g., XMP RDF containers)
     /// Used for RDF Bag/Seq containers and mixed-type arrays
     Array(Vec<TagValue>),
@@ -3,9 +3,9 @@
 //! Implements std::ops traits to enable arithmetic operations on TagValue.
 //! Follows ExifTool's type coercion rules for numeric operations.
 
-use crate::core::TagValue;
-use std::cmp::Ordering;
-use std::ops::{Add, BitAnd, Div, Mul, Neg, Shr, Sub};
+use crate::TagValue;
+use core::cmp::Ordering;
+use core::ops::{Add, BitAnd, Div, Mul, Neg, Shr, Sub};
 
 // Helper macro to implement arithmetic ops for TagValue
 macro_rules! impl_arithmetic_op {
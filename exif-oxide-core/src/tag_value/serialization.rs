@@ -1,9 +1,11 @@
 //! Serialization support for TagValue, including ExifTool-compatible JSON numeric detection
 
-use crate::core::TagValue;
-use regex::Regex;
+use crate::TagValue;
+use alloc::format;
+use alloc::string::String;
+use base64::prelude::{Engine, BASE64_STANDARD};
+use serde::ser::SerializeSeq;
 use serde::{Serialize, Serializer};
-use std::sync::LazyLock;
 
 /// Check if a string matches ExifTool's JSON numeric pattern
 /// ExifTool: exiftool:3762 EscapeJSON function
@@ -31,15 +33,105 @@ use std::sync::LazyLock;
 /// }
 /// ```
 pub fn is_json_numeric_string(s: &str) -> bool {
-    // ExifTool: exiftool:3762 - exact regex from EscapeJSON function
-    // JSON/PHP number format validation per json.org specification
-    static NUMERIC_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-        Regex::new(r"^-?(\d|[1-9]\d{1,14})(\.\d{1,16})?(e[-+]?\d{1,3})?$")
-            .expect("Invalid ExifTool numeric regex")
-    });
-
     // ExifTool: Case-insensitive matching (note the 'i' flag in ExifTool regex)
-    NUMERIC_REGEX.is_match(&s.to_lowercase())
+    matches_numeric_pattern(&s.to_lowercase())
+}
+
+/// Case-sensitive match of `^-?(\d|[1-9]\d{1,14})(\.\d{1,16})?(e[-+]?\d{1,3})?$`
+///
+/// Hand-rolled rather than a `Regex` so this crate stays `no_std`. Every group
+/// is followed by something a digit can't match, so taking the longest digit
+/// run at each step is equivalent to the regex's backtracking.
+pub(crate) fn matches_numeric_pattern(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let digits_from = |start: usize| {
+        bytes[start.min(bytes.len())..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+
+    let mut pos = usize::from(bytes.first() == Some(&b'-'));
+
+    // (\d|[1-9]\d{1,14})
+    let int_digits = digits_from(pos);
+    if int_digits == 0 || int_digits > 15 || (int_digits > 1 && bytes[pos] == b'0') {
+        return false;
+    }
+    pos += int_digits;
+
+    // (\.\d{1,16})?
+    if bytes.get(pos) == Some(&b'.') {
+        let frac_digits = digits_from(pos + 1);
+        if frac_digits == 0 || frac_digits > 16 {
+            return false;
+        }
+        pos += 1 + frac_digits;
+    }
+
+    // (e[-+]?\d{1,3})?
+    if bytes.get(pos) == Some(&b'e') {
+        pos += 1;
+        if matches!(bytes.get(pos), Some(b'-' | b'+')) {
+            pos += 1;
+        }
+        let exp_digits = digits_from(pos);
+        if exp_digits == 0 || exp_digits > 3 {
+            return false;
+        }
+        pos += exp_digits;
+    }
+
+    pos == bytes.len()
+}
+
+/// Serializes rational arrays element-wise the way ExifTool's GetRational64u/s
+/// does: `undef` for 0/0, `inf` for n/0, otherwise the quotient
+struct RationalSeq<'a, T>(&'a [(T, T)]);
+
+impl<T: Copy + Into<f64> + PartialEq + Default> Serialize for RationalSeq<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for &(num, denom) in self.0 {
+            if denom == T::default() {
+                if num == T::default() {
+                    seq.serialize_element("undef")?;
+                } else {
+                    seq.serialize_element("inf")?;
+                }
+            } else {
+                seq.serialize_element(&(num.into() / denom.into()))?;
+            }
+        }
+        seq.end()
+    }
+}
+
+/// Prefix marking a JSON string as base64-encoded binary data
+/// ExifTool: exiftool:3733 FormatJSON - binary values output as "base64:..." with -b -j
+pub const BASE64_PREFIX: &str = "base64:";
+
+impl TagValue {
+    /// Encode binary data as an ExifTool-style `base64:...` string
+    ///
+    /// Returns `None` for non-binary values.
+    pub fn to_base64_marker(&self) -> Option<String> {
+        match self {
+            TagValue::Binary(data) => {
+                Some(format!("{BASE64_PREFIX}{}", BASE64_STANDARD.encode(data)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Decode a `base64:...` string back into `TagValue::Binary`
+    ///
+    /// Returns `None` if the string lacks the prefix or isn't valid base64, so
+    /// JSON consumers can round-trip thumbnails and other binary tags.
+    pub fn from_base64_marker(s: &str) -> Option<TagValue> {
+        let encoded = s.strip_prefix(BASE64_PREFIX)?;
+        BASE64_STANDARD.decode(encoded).ok().map(TagValue::Binary)
+    }
 }
 
 impl Serialize for TagValue {
@@ -112,48 +204,9 @@ impl Serialize for TagValue {
                     serializer.serialize_f64(result)
                 }
             }
-            TagValue::RationalArray(arr) => {
-                // ExifTool: Convert each rational to decimal like GetRational64u
-                let converted: Vec<serde_json::Value> = arr
-                    .iter()
-                    .map(|(num, denom)| {
-                        if *denom == 0 {
-                            if *num == 0 {
-                                serde_json::Value::String("undef".to_string())
-                            } else {
-                                serde_json::Value::String("inf".to_string())
-                            }
-                        } else {
-                            serde_json::Value::Number(
-                                serde_json::Number::from_f64(*num as f64 / *denom as f64)
-                                    .unwrap_or_else(|| serde_json::Number::from(0)),
-                            )
-                        }
-                    })
-                    .collect();
-                converted.serialize(serializer)
-            }
-            TagValue::SRationalArray(arr) => {
-                // ExifTool: Convert each signed rational to decimal like GetRational64s
-                let converted: Vec<serde_json::Value> = arr
-                    .iter()
-                    .map(|(num, denom)| {
-                        if *denom == 0 {
-                            if *num == 0 {
-                                serde_json::Value::String("undef".to_string())
-                            } else {
-                                serde_json::Value::String("inf".to_string())
-                            }
-                        } else {
-                            serde_json::Value::Number(
-                                serde_json::Number::from_f64(*num as f64 / *denom as f64)
-                                    .unwrap_or_else(|| serde_json::Number::from(0)),
-                            )
-                        }
-                    })
-                    .collect();
-                converted.serialize(serializer)
-            }
+            // ExifTool: Convert each rational to decimal like GetRational64u/s
+            TagValue::RationalArray(arr) => RationalSeq(arr).serialize(serializer),
+            TagValue::SRationalArray(arr) => RationalSeq(arr).serialize(serializer),
             TagValue::Binary(data) => data.serialize(serializer),
             TagValue::Object(map) => map.serialize(serializer),
             TagValue::Array(values) => values.serialize(serializer),
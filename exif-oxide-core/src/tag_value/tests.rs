@@ -1,6 +1,8 @@
 //! Tests for TagValue functionality
 
-use crate::core::TagValue;
+use crate::TagValue;
+use alloc::string::ToString;
+use alloc::vec;
 
 #[test]
 fn test_string_creation() {
@@ -72,3 +74,68 @@ fn test_string_with_numeric_detection() {
         TagValue::String("Hello".to_string())
     );
 }
+
+#[test]
+fn test_base64_marker_round_trip() {
+    let thumbnail = TagValue::Binary(vec![0xFF, 0xD8, 0x00, 0x80, 0xFF, 0xD9]);
+    let marker = thumbnail.to_base64_marker().unwrap();
+    assert_eq!(marker, "base64:/9gAgP/Z");
+    assert_eq!(TagValue::from_base64_marker(&marker), Some(thumbnail));
+
+    assert_eq!(TagValue::string("text").to_base64_marker(), None);
+    assert_eq!(TagValue::from_base64_marker("/9gAgP/Z"), None);
+    assert_eq!(TagValue::from_base64_marker("base64:not base64!"), None);
+}
+
+#[test]
+fn test_json_numeric_pattern() {
+    use super::serialization::is_json_numeric_string;
+
+    for numeric in ["0", "-0", "7", "42", "-3.25", "1.5e10", "2E-3", "6.0e+100"] {
+        assert!(
+            is_json_numeric_string(numeric),
+            "{numeric} should be numeric"
+        );
+    }
+    for text in [
+        "",
+        "-",
+        "01",
+        "1.",
+        ".5",
+        "1e",
+        "1e1234",
+        "+1",
+        "1 ",
+        "24.0 mm",
+        "0x10",
+        "1234567890123456",    // 16 integer digits
+        "1.12345678901234567", // 17 fraction digits
+    ] {
+        assert!(
+            !is_json_numeric_string(text),
+            "{text:?} should not be numeric"
+        );
+    }
+
+    // Only JSON output matching is case-insensitive
+    assert_eq!(
+        TagValue::string_with_numeric_detection("1E5"),
+        TagValue::String("1E5".to_string())
+    );
+}
+
+#[test]
+fn test_append_repeated_values() {
+    let mut keywords = TagValue::string("sunset");
+    keywords.append(TagValue::string("beach"));
+    keywords.append(TagValue::Array(vec![TagValue::string("sea")]));
+    assert_eq!(
+        keywords,
+        TagValue::Array(vec![
+            TagValue::string("sunset"),
+            TagValue::string("beach"),
+            TagValue::string("sea"),
+        ])
+    );
+}
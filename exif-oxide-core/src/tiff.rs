@@ -4,7 +4,9 @@
 //! process, translating ExifTool's TIFF format definitions from lib/Image/ExifTool/Exif.pm.
 //! These types handle byte order detection, format validation, and IFD entry parsing.
 
-use crate::types::{ExifError, Result};
+use crate::{ExifError, Result};
+use alloc::format;
+use alloc::string::ToString;
 
 /// TIFF format types mapping to ExifTool's format system
 /// ExifTool: lib/Image/ExifTool/Exif.pm @formatName array
@@ -38,6 +40,26 @@ impl TiffFormat {
         }
     }
 
+    /// ExifTool format name for this type (as shown by `exiftool -v`)
+    /// ExifTool: lib/Image/ExifTool/Exif.pm @formatName array
+    pub fn name(self) -> &'static str {
+        match self {
+            TiffFormat::Byte => "int8u",
+            TiffFormat::Ascii => "string",
+            TiffFormat::Short => "int16u",
+            TiffFormat::Long => "int32u",
+            TiffFormat::Rational => "rational64u",
+            TiffFormat::SByte => "int8s",
+            TiffFormat::Undefined => "undef",
+            TiffFormat::SShort => "int16s",
+            TiffFormat::SLong => "int32s",
+            TiffFormat::SRational => "rational64s",
+            TiffFormat::Float => "float",
+            TiffFormat::Double => "double",
+            TiffFormat::Ifd => "ifd",
+        }
+    }
+
     /// Create from format number, following ExifTool's validation
     /// ExifTool: lib/Image/ExifTool/Exif.pm:6352 format validation
     pub fn from_u16(format: u16) -> Result<Self> {
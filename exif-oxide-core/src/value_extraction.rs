@@ -4,8 +4,12 @@
 //! translating ExifTool's value extraction logic from lib/Image/ExifTool/Exif.pm.
 //! These functions handle inline vs offset storage, byte order conversion, and array processing.
 
-use crate::tiff_types::{ByteOrder, IfdEntry};
-use crate::types::{ExifError, Result, TagValue};
+use crate::tiff::{ByteOrder, IfdEntry};
+use crate::{ExifError, Result, TagValue};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 use tracing::debug;
 
 /// Extract ASCII string value from IFD entry
@@ -190,9 +194,12 @@ pub fn extract_short_array_value(
         // Value stored inline - up to 2 SHORT values can fit in 4 bytes
         // ExifTool: lib/Image/ExifTool/Exif.pm:6372 inline value handling
         let mut values = Vec::with_capacity(count);
-        let bytes = entry.value_or_offset.to_le_bytes();
-
-        // Convert bytes based on IFD byte order (not necessarily LE)
+        // value_or_offset was read in the file's byte order; get the
+        // original bytes back in the same order
+        let bytes = match byte_order {
+            ByteOrder::LittleEndian => entry.value_or_offset.to_le_bytes(),
+            ByteOrder::BigEndian => entry.value_or_offset.to_be_bytes(),
+        };
         for i in 0..count {
             let offset = i * 2;
             let value = match byte_order {
@@ -412,7 +419,7 @@ pub fn extract_srational_value(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tiff_types::TiffFormat;
+    use crate::tiff::TiffFormat;
 
     #[test]
     fn test_extract_ascii_inline() {
@@ -485,6 +492,19 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_inline_short_array_big_endian() {
+        // YCbCrSubSampling [2, 1] stored inline as 00 02 00 01
+        let entry = IfdEntry {
+            tag_id: 0x0212,
+            format: TiffFormat::Short,
+            count: 2,
+            value_or_offset: 0x0002_0001,
+        };
+        let result = extract_short_array_value(&[], &entry, ByteOrder::BigEndian).unwrap();
+        assert_eq!(result, [2, 1]);
+    }
+
     #[test]
     fn test_long_array_huge_count_does_not_oom() {
         // Same allocation-bomb class as above, for the LONG-array extractor.
@@ -10,21 +10,32 @@
 //! - Shows meaningful differences with proper tolerance handling
 //! - Supports group filtering (File:, EXIF:, MakerNotes:, etc.)
 //! - Uses the same comparison logic as `make compat` tests
+//! - Given a directory, compares every file under it and tallies differences
+//!   per tag; `--history` records each run and reports what changed since the
+//!   last one (exits non-zero when a tag regressed)
 
 use clap::{Arg, Command};
 use exif_oxide::compat::{
-    analyze_all_tag_differences, apply_exiftool_filter, filter_to_groups, filter_to_supported_tags,
-    normalize_for_comparison, parse_exiftool_filters, run_exif_oxide, run_exiftool,
-    CompatibilityReport, DifferenceType,
+    analyze_all_tag_differences, append_run, apply_exiftool_filter, corpus_files, filter_to_groups,
+    filter_to_supported_tags, load_last_run, normalize_for_comparison, parse_exiftool_filters,
+    run_exif_oxide, run_exiftool, CompatibilityReport, CorpusReport, DifferenceType, TagDifference,
 };
+use std::path::Path;
 use std::process;
 
+/// Which tags to compare
+struct CompareOptions<'a> {
+    groups: Vec<&'a str>,
+    filters: Vec<&'a str>,
+    supported_only: bool,
+}
+
 fn main() {
     let matches = Command::new("compare-with-exiftool")
         .about("Compare exif-oxide output with ExifTool using normalization")
         .arg(
             Arg::new("file")
-                .help("Image file to analyze")
+                .help("Image file to analyze, or a directory to compare every file under")
                 .required(true)
                 .index(1),
         )
@@ -57,6 +68,12 @@ fn main() {
                 .long("json")
                 .action(clap::ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("history")
+                .help("With a directory: append the run to this JSON-lines file and report changes since the previous run")
+                .long("history")
+                .value_name("FILE"),
+        )
         .arg(
             Arg::new("verbose")
                 .help("Show detailed comparison report")
@@ -79,6 +96,17 @@ fn main() {
     let supported_only = !all_tags; // Default to supported tags only
     let json_output = matches.get_flag("json");
     let verbose = matches.get_flag("verbose");
+    let options = CompareOptions {
+        groups,
+        filters,
+        supported_only,
+    };
+
+    if Path::new(file_path).is_dir() {
+        let history = matches.get_one::<String>("history").map(Path::new);
+        compare_corpus(Path::new(file_path), &options, history, json_output);
+        return;
+    }
 
     // Run both tools
     if supported_only {
@@ -90,36 +118,77 @@ fn main() {
         println!("🔍 Comparing {} with ExifTool (all tags)...", file_path);
     }
 
+    let differences = match compare_file(file_path, &options) {
+        Ok(differences) => differences,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            process::exit(1);
+        }
+    };
+
+    // Generate report
+    let mut report = CompatibilityReport::new();
+    report.total_files_tested = 1;
+
+    for diff in differences {
+        match diff.difference_type {
+            DifferenceType::Working => report.working_tags.push(diff.tag),
+            DifferenceType::ValueFormatMismatch => report.value_format_mismatches.push(diff),
+            DifferenceType::Missing => report.missing_tags.push(diff),
+            DifferenceType::TypeMismatch => report.type_mismatches.push(diff),
+            DifferenceType::OnlyInOurs => report.only_in_ours.push(diff),
+            DifferenceType::DependencyFailure => report.missing_tags.push(diff), // Treat as missing
+        }
+    }
+
+    report.total_tags_tested = report.working_tags.len()
+        + report.value_format_mismatches.len()
+        + report.missing_tags.len()
+        + report.type_mismatches.len()
+        + report.only_in_ours.len();
+
+    // Output results
+    if json_output {
+        output_json_report(&report);
+    } else if verbose {
+        report.print_summary();
+    } else {
+        report.print_simple_differences(10);
+    }
+
+    // Exit with appropriate code
+    let critical_issues = report.missing_tags.len() + report.type_mismatches.len();
+    if critical_issues > 0 {
+        process::exit(1);
+    }
+}
+
+/// Run both tools on a file and analyze the differences
+fn compare_file(file_path: &str, options: &CompareOptions) -> Result<Vec<TagDifference>, String> {
     let exiftool_result = run_exiftool(file_path);
     let exif_oxide_result = run_exif_oxide(file_path);
 
     let (mut exiftool_data, mut exif_oxide_data) = match (exiftool_result, exif_oxide_result) {
         (Ok(et), Ok(eo)) => (et, eo),
-        (Err(e), _) => {
-            eprintln!("❌ ExifTool failed: {}", e);
-            process::exit(1);
-        }
-        (_, Err(e)) => {
-            eprintln!("❌ exif-oxide failed: {}", e);
-            process::exit(1);
-        }
+        (Err(e), _) => return Err(format!("ExifTool failed: {e}")),
+        (_, Err(e)) => return Err(format!("exif-oxide failed: {e}")),
     };
 
     // Apply filtering - ExifTool-style filters take precedence
-    if !filters.is_empty() {
-        let filter_options = parse_exiftool_filters(&filters);
+    if !options.filters.is_empty() {
+        let filter_options = parse_exiftool_filters(&options.filters);
         exiftool_data = apply_exiftool_filter(&exiftool_data, &filter_options);
         exif_oxide_data = apply_exiftool_filter(&exif_oxide_data, &filter_options);
     } else {
         // Fallback to legacy filtering options
-        if supported_only {
+        if options.supported_only {
             exiftool_data = filter_to_supported_tags(&exiftool_data);
             exif_oxide_data = filter_to_supported_tags(&exif_oxide_data);
         }
 
-        if !groups.is_empty() {
-            exiftool_data = filter_to_groups(&exiftool_data, &groups);
-            exif_oxide_data = filter_to_groups(&exif_oxide_data, &groups);
+        if !options.groups.is_empty() {
+            exiftool_data = filter_to_groups(&exiftool_data, &options.groups);
+            exif_oxide_data = filter_to_groups(&exif_oxide_data, &options.groups);
         }
     }
 
@@ -128,7 +197,7 @@ fn main() {
     let normalized_exif_oxide = normalize_for_comparison(exif_oxide_data, false);
 
     // Analyze differences
-    let differences = if supported_only {
+    let differences = if options.supported_only {
         // Use supported tags analysis when filtering is enabled
         use exif_oxide::compat::analyze_tag_differences;
 
@@ -142,39 +211,49 @@ fn main() {
         analyze_all_tag_differences(file_path, &normalized_exiftool, &normalized_exif_oxide)
     };
 
-    // Generate report
-    let mut report = CompatibilityReport::new();
-    report.total_files_tested = 1;
+    Ok(differences)
+}
 
-    for diff in differences {
-        match diff.difference_type {
-            DifferenceType::Working => report.working_tags.push(diff.tag),
-            DifferenceType::ValueFormatMismatch => report.value_format_mismatches.push(diff),
-            DifferenceType::Missing => report.missing_tags.push(diff),
-            DifferenceType::TypeMismatch => report.type_mismatches.push(diff),
-            DifferenceType::OnlyInOurs => report.only_in_ours.push(diff),
-            DifferenceType::DependencyFailure => report.missing_tags.push(diff), // Treat as missing
+/// Compare every file under a directory, tallying differences per tag
+fn compare_corpus(dir: &Path, options: &CompareOptions, history: Option<&Path>, json_output: bool) {
+    let files = corpus_files(dir).unwrap_or_else(|e| {
+        eprintln!("❌ Failed to read {}: {}", dir.display(), e);
+        process::exit(1);
+    });
+    eprintln!(
+        "🔍 Comparing {} files under {} with ExifTool...",
+        files.len(),
+        dir.display()
+    );
+
+    let mut report = CorpusReport::new();
+    for file in &files {
+        let file_path = file.to_string_lossy();
+        match compare_file(&file_path, options) {
+            Ok(differences) => report.add_file(&differences),
+            Err(e) => report.add_failure(&file_path, e),
         }
     }
+    report.finish();
 
-    report.total_tags_tested = report.working_tags.len()
-        + report.value_format_mismatches.len()
-        + report.missing_tags.len()
-        + report.type_mismatches.len()
-        + report.only_in_ours.len();
-
-    // Output results
+    let previous = history.and_then(load_last_run);
     if json_output {
-        output_json_report(&report);
-    } else if verbose {
-        report.print_summary();
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
     } else {
-        report.print_simple_differences(10);
+        report.print_summary(25);
+        if let Some(previous) = &previous {
+            report.print_change_from(previous);
+        }
     }
 
-    // Exit with appropriate code
-    let critical_issues = report.missing_tags.len() + report.type_mismatches.len();
-    if critical_issues > 0 {
+    if let Some(history) = history {
+        if let Err(e) = append_run(history, &report) {
+            eprintln!("❌ Failed to record run in {}: {}", history.display(), e);
+            process::exit(1);
+        }
+    }
+
+    if previous.is_some_and(|previous| !report.regressions_from(&previous).is_empty()) {
         process::exit(1);
     }
 }
@@ -0,0 +1,103 @@
+//! HTTP extraction server
+//!
+//! Serves [`exif_oxide::server`]'s endpoints, so deployments don't need to
+//! wrap the CLI in their own HTTP shim:
+//!
+//! ```text
+//! exif-oxide-server --listen 127.0.0.1:8080 --path-root /srv/photos
+//! curl --data-binary @photo.jpg 'http://127.0.0.1:8080/extract?filename=photo.jpg'
+//! printf 'a.jpg\nb.jpg\n' | curl --data-binary @- http://127.0.0.1:8080/extract/paths
+//! ```
+
+use clap::{Arg, Command};
+use exif_oxide::server::{serve, ServerConfig};
+use exif_oxide::session::ExifSession;
+use exif_oxide::types::FilterOptions;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
+        .init();
+
+    let defaults = ServerConfig::default();
+    let matches = Command::new("exif-oxide-server")
+        .about("Serve exif-oxide metadata extraction over HTTP")
+        .arg(
+            Arg::new("listen")
+                .long("listen")
+                .help("Address to listen on")
+                .value_name("ADDR")
+                .default_value("127.0.0.1:8080"),
+        )
+        .arg(
+            Arg::new("max-concurrent")
+                .long("max-concurrent")
+                .help("Extractions to run at once (default: one per CPU)")
+                .value_name("N")
+                .value_parser(clap::value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .help("Seconds an extraction may wait for its turn and run")
+                .value_name("SECS")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .default_value("30"),
+        )
+        .arg(
+            Arg::new("max-size")
+                .long("max-size")
+                .help("Largest file to extract, uploaded or not, in megabytes")
+                .value_name("MB")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .default_value("256"),
+        )
+        .arg(
+            Arg::new("path-root")
+                .long("path-root")
+                .help("Serve POST /extract/paths for files in this directory")
+                .value_name("DIR"),
+        )
+        .arg(
+            Arg::new("numeric")
+                .short('n')
+                .help("Numeric values for all tags (skips PrintConv)")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .get_matches();
+
+    let max_size = matches.get_one::<u64>("max-size").copied().unwrap_or(256) << 20;
+    let config = ServerConfig {
+        max_concurrent: matches
+            .get_one::<usize>("max-concurrent")
+            .copied()
+            .unwrap_or(defaults.max_concurrent),
+        timeout: Duration::from_secs(matches.get_one::<u64>("timeout").copied().unwrap_or(30)),
+        max_body_size: usize::try_from(max_size).unwrap_or(usize::MAX),
+        path_root: matches.get_one::<String>("path-root").map(PathBuf::from),
+    };
+    let session = ExifSession::new()
+        .with_options(FilterOptions {
+            numeric: matches.get_flag("numeric"),
+            ..FilterOptions::default()
+        })
+        .with_max_file_size(max_size);
+
+    let address = matches.get_one::<String>("listen").unwrap();
+    let listener = match tokio::net::TcpListener::bind(address).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Error: Can't listen on {address}: {e}");
+            std::process::exit(1);
+        }
+    };
+    eprintln!("Listening on http://{address}");
+    if let Err(e) = serve(listener, session, config).await {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
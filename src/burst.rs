@@ -0,0 +1,271 @@
+//! Burst grouping helpers
+//!
+//! Culling tools show the frames of a continuous-drive burst as one stack.
+//! [`group_bursts`] finds those stacks in a list of extracted files, using the
+//! composites built in `composite_tags/burst.rs` and the capture time:
+//!
+//! 1. Files with the same `Composite:BurstID` (iPhone, Pixel) are one burst.
+//! 2. Other files from the same camera (Make, Model and Composite:SerialNumber)
+//!    are sorted by Composite:BestDateTime, and consecutive frames at most
+//!    [`MAX_FRAME_GAP_MS`] apart belong to the same burst if their
+//!    `Composite:BurstIndex` values follow each other or, without frame
+//!    numbers, both times have sub-seconds (whole seconds can't tell a burst
+//!    from two separate shots).
+//!
+//! File system dates aren't capture times, so files whose BestDateTime comes
+//! from File:FileModifyDate are only grouped by BurstID.
+
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDateTime};
+
+use crate::composite_tags::parse_date;
+use crate::types::{ExifData, TagValue};
+
+/// Largest time between consecutive frames of a burst, in milliseconds
+pub const MAX_FRAME_GAP_MS: i64 = 1000;
+
+/// Frames of one burst
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Burst {
+    /// Indices into the files passed to [`group_bursts`], in capture order
+    pub files: Vec<usize>,
+    /// Identifier written by the camera (`Composite:BurstID`), if any
+    pub burst_id: Option<String>,
+}
+
+/// Return the camera's burst identifier (`Composite:BurstID`)
+pub fn burst_id(exif_data: &ExifData) -> Option<&str> {
+    exif_data
+        .get_tag_by_group("Composite", "BurstID")
+        .and_then(|tag| tag.value.as_string())
+}
+
+/// Return the frame number within a burst (`Composite:BurstIndex`)
+pub fn burst_index(exif_data: &ExifData) -> Option<u32> {
+    exif_data
+        .get_tag_by_group("Composite", "BurstIndex")
+        .and_then(|tag| tag.value.as_u32())
+}
+
+/// What grouping needs from one file
+struct Frame {
+    file: usize,
+    time: NaiveDateTime,
+    has_sub_seconds: bool,
+    index: Option<u32>,
+}
+
+impl Frame {
+    /// Whether `next` (taken after `self`) continues the same burst
+    fn continues_with(&self, next: &Frame) -> bool {
+        if next.time - self.time > Duration::milliseconds(MAX_FRAME_GAP_MS) {
+            return false;
+        }
+        match (self.index, next.index) {
+            (Some(index), Some(next_index)) => next_index == index + 1,
+            _ => self.has_sub_seconds && next.has_sub_seconds,
+        }
+    }
+}
+
+/// Capture time of a file, with sub-seconds
+fn capture_time(exif_data: &ExifData) -> Option<(NaiveDateTime, bool)> {
+    let source = exif_data
+        .get_tag_by_group("Composite", "BestDateTimeSource")
+        .and_then(|tag| tag.value.as_string());
+    if source == Some("File:FileModifyDate") {
+        return None;
+    }
+    let date = exif_data
+        .get_tag_by_group("Composite", "BestDateTime")
+        .and_then(|tag| tag.value.as_string())?;
+    let (date_time, time, _) = parse_date(date)?;
+    let sub_seconds = date_time.get(19..).unwrap_or("");
+    let fraction: f64 = format!("0{sub_seconds}").parse().ok()?;
+    Some((
+        time + Duration::microseconds((fraction * 1e6).round() as i64),
+        !sub_seconds.is_empty(),
+    ))
+}
+
+/// Camera body that took a file
+fn camera(exif_data: &ExifData) -> [Option<String>; 3] {
+    [
+        ("EXIF", "Make"),
+        ("EXIF", "Model"),
+        ("Composite", "SerialNumber"),
+    ]
+    .map(|(group, name)| {
+        exif_data
+            .get_tag_by_group(group, name)
+            .map(|tag| match &tag.value {
+                TagValue::String(s) => s.trim().to_string(),
+                value => value.to_string(),
+            })
+    })
+}
+
+/// Group files into bursts (see the module documentation)
+///
+/// Only bursts of two or more files are returned, ordered by their first
+/// file's position in `files`. Files in no burst were shot on their own.
+pub fn group_bursts(files: &[ExifData]) -> Vec<Burst> {
+    let mut by_id: HashMap<&str, Vec<usize>> = HashMap::new();
+    let mut by_camera: HashMap<[Option<String>; 3], Vec<Frame>> = HashMap::new();
+    for (file, exif_data) in files.iter().enumerate() {
+        if let Some(id) = burst_id(exif_data) {
+            by_id.entry(id).or_default().push(file);
+        } else if let Some((time, has_sub_seconds)) = capture_time(exif_data) {
+            by_camera.entry(camera(exif_data)).or_default().push(Frame {
+                file,
+                time,
+                has_sub_seconds,
+                index: burst_index(exif_data),
+            });
+        }
+    }
+
+    let mut bursts = Vec::new();
+    for (id, mut members) in by_id {
+        let time = |file: &usize| capture_time(&files[*file]).map(|(time, _)| time);
+        members.sort_by_key(|file| (time(file), burst_index(&files[*file]), *file));
+        bursts.push(Burst {
+            files: members,
+            burst_id: Some(id.to_string()),
+        });
+    }
+    for mut frames in by_camera.into_values() {
+        // Frames shot within the same (whole) second stay in file order
+        frames.sort_by_key(|frame| (frame.time, frame.file));
+        let mut current: Vec<&Frame> = Vec::new();
+        for frame in &frames {
+            if current
+                .last()
+                .is_some_and(|last| !last.continues_with(frame))
+            {
+                bursts.push(Burst {
+                    files: current.iter().map(|frame| frame.file).collect(),
+                    burst_id: None,
+                });
+                current.clear();
+            }
+            current.push(frame);
+        }
+        bursts.push(Burst {
+            files: current.iter().map(|frame| frame.file).collect(),
+            burst_id: None,
+        });
+    }
+
+    bursts.retain(|burst| burst.files.len() > 1);
+    bursts.sort_by_key(|burst| burst.files.iter().min().copied());
+    bursts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TagEntry;
+
+    fn composite(name: &str, value: TagValue) -> TagEntry {
+        TagEntry {
+            group: "Composite".into(),
+            group1: "Composite".into(),
+            name: name.into(),
+            print: value.clone().into(),
+            value,
+            source: None,
+        }
+    }
+
+    fn frame(date: &str, index: Option<u32>, id: Option<&str>) -> ExifData {
+        let mut data = ExifData::new("test".to_string(), String::new());
+        data.tags
+            .push(composite("BestDateTime", TagValue::string(date)));
+        data.tags.push(composite(
+            "BestDateTimeSource",
+            TagValue::string("EXIF:DateTimeOriginal"),
+        ));
+        if let Some(index) = index {
+            data.tags
+                .push(composite("BurstIndex", TagValue::U32(index)));
+        }
+        if let Some(id) = id {
+            data.tags.push(composite("BurstID", TagValue::string(id)));
+        }
+        data
+    }
+
+    fn groups(bursts: &[Burst]) -> Vec<Vec<usize>> {
+        bursts.iter().map(|burst| burst.files.clone()).collect()
+    }
+
+    #[test]
+    fn test_group_by_sub_seconds() {
+        let files = [
+            frame("2024:05:06 07:08:09.90", None, None),
+            frame("2024:05:06 07:08:09.10", None, None),
+            frame("2024:05:06 07:08:09.50", None, None),
+            // Next shot, 5 s later
+            frame("2024:05:06 07:08:14.00", None, None),
+            // Whole seconds: not enough to tell
+            frame("2024:05:06 09:00:00", None, None),
+            frame("2024:05:06 09:00:00", None, None),
+        ];
+        let bursts = group_bursts(&files);
+        assert_eq!(groups(&bursts), [vec![1, 2, 0]]);
+        assert_eq!(bursts[0].burst_id, None);
+    }
+
+    #[test]
+    fn test_group_by_frame_number() {
+        let files = [
+            frame("2024:05:06 07:08:09", Some(1), None),
+            frame("2024:05:06 07:08:09", Some(2), None),
+            frame("2024:05:06 07:08:10", Some(3), None),
+            // A new sequence starts within the gap
+            frame("2024:05:06 07:08:10", Some(1), None),
+            frame("2024:05:06 07:08:11", Some(2), None),
+        ];
+        assert_eq!(groups(&group_bursts(&files)), [vec![0, 1, 2], vec![3, 4]]);
+    }
+
+    #[test]
+    fn test_group_by_burst_id() {
+        let files = [
+            frame("2024:05:06 07:08:09", None, Some("A")),
+            frame("2024:05:06 07:08:09.5", None, None),
+            frame("2024:05:06 07:08:12", None, Some("A")),
+            frame("2024:05:06 07:08:09.6", None, None),
+            frame("2024:05:06 07:08:09.7", None, Some("B")),
+        ];
+        let bursts = group_bursts(&files);
+        assert_eq!(groups(&bursts), [vec![0, 2], vec![1, 3]]);
+        assert_eq!(bursts[0].burst_id.as_deref(), Some("A"));
+    }
+
+    #[test]
+    fn test_other_cameras_and_file_dates_are_separate() {
+        let mut other_camera = frame("2024:05:06 07:08:09.2", None, None);
+        other_camera.tags.push(TagEntry {
+            group: "EXIF".into(),
+            group1: "IFD0".into(),
+            name: "Model".into(),
+            value: TagValue::string("Other"),
+            print: TagValue::string("Other").into(),
+            source: None,
+        });
+        let mut file_date = frame("2024:05:06 07:08:09.3", None, None);
+        file_date.tags[1] = composite(
+            "BestDateTimeSource",
+            TagValue::string("File:FileModifyDate"),
+        );
+        let files = [
+            frame("2024:05:06 07:08:09.1", None, None),
+            other_camera,
+            file_date,
+        ];
+        assert!(group_bursts(&files).is_empty());
+    }
+}
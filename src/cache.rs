@@ -0,0 +1,636 @@
+//! Persistent metadata cache
+//!
+//! Scanning a large photo library repeatedly re-parses files that haven't
+//! changed since the last scan. [`MetadataCache`] stores extraction results in a
+//! [`CacheStore`] so that unchanged files are answered from the cache instead.
+//!
+//! Entries are keyed by the extraction options, the installed
+//! [user config](crate::user_config) and either
+//!
+//! - [`CacheKeyMode::FileStat`]: the file path and its identity on disk
+//!   (device, inode, mtime, ctime and size), or
+//! - [`CacheKeyMode::ContentHash`]: a SHA-256 of the file content, its
+//!   extension and its modification time, but not its path.
+//!
+//! A changed file produces a new key, so stale entries are never returned; they
+//! are only left behind until [`MetadataCache::clear`] removes them.
+//!
+//! With [`CacheKeyMode::FileStat`], file system tags like File:FileAccessDate
+//! are stored along with everything else, so a cache hit reports them as of
+//! the time the entry was written. With [`CacheKeyMode::ContentHash`] one entry
+//! serves every file with the same content, extension and modification time,
+//! so the per-file tags (group 1 `System` and `XAttr`: FileName, Directory,
+//! file times, permissions and extended attributes) are read from the file on
+//! each hit. Everything else, including the `System:*DetectionStatus`
+//! diagnostics, only depends on the key. The modification time is part of the
+//! key because Composite:BestDateTime can fall back to File:FileModifyDate and
+//! `QuickTimeUtc::Auto` compares QuickTime dates with it.
+//!
+//! ```no_run
+//! use exif_oxide::cache::MetadataCache;
+//! use std::path::Path;
+//!
+//! let cache = MetadataCache::open_dir("/tmp/exif-oxide-cache")?;
+//! // Parses the file and stores the result
+//! let first = cache.extract_metadata(Path::new("image.jpg"), false, false, None)?;
+//! // Answered from the cache while image.jpg is unchanged
+//! let second = cache.extract_metadata(Path::new("image.jpg"), false, false, None)?;
+//! assert_eq!(first.tags.len(), second.tags.len());
+//! # Ok::<(), exif_oxide::ExifError>(())
+//! ```
+
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::{debug, warn};
+
+use crate::formats;
+use crate::types::{
+    ExifData, ExtractionWarning, FilterOptions, Result, SymlinkPolicy, TagEntry, TagValue,
+    Truncation, XmpPacketLocation, XmpProvenance,
+};
+
+/// Storage backend for cached extraction results
+///
+/// Keys are lowercase hex strings; values are opaque bytes. Implementations
+/// must be safe to share between threads.
+pub trait CacheStore: Send + Sync {
+    /// Return the value stored under `key`, if any
+    fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>>;
+
+    /// Store `value` under `key`, replacing any previous value
+    fn put(&self, key: &str, value: &[u8]) -> io::Result<()>;
+
+    /// Remove the value stored under `key`, if any
+    fn remove(&self, key: &str) -> io::Result<()>;
+
+    /// Remove all stored values
+    fn clear(&self) -> io::Result<()>;
+}
+
+/// Default [`CacheStore`]: one file per entry in a directory
+///
+/// Entries are written to a temporary file and renamed into place, so
+/// concurrent readers never see a partially written entry.
+#[derive(Debug, Clone)]
+pub struct FlatFileStore {
+    dir: PathBuf,
+}
+
+/// Extension of entry files, so `clear` only removes files this store wrote
+const ENTRY_EXTENSION: &str = "json";
+
+impl FlatFileStore {
+    /// Use `dir` as the cache directory, creating it if needed
+    pub fn open(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// The cache directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.{ENTRY_EXTENSION}"))
+    }
+}
+
+impl CacheStore for FlatFileStore {
+    fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        match fs::read(self.entry_path(key)) {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn put(&self, key: &str, value: &[u8]) -> io::Result<()> {
+        // Unique per process and call, so parallel writers don't share a temp file
+        static NEXT_TEMP: AtomicU64 = AtomicU64::new(0);
+        let temp = self.dir.join(format!(
+            ".{key}.{}.{}.tmp",
+            std::process::id(),
+            NEXT_TEMP.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&temp, value)?;
+        fs::rename(&temp, self.entry_path(key)).inspect_err(|_| {
+            let _ = fs::remove_file(&temp);
+        })
+    }
+
+    fn remove(&self, key: &str) -> io::Result<()> {
+        match fs::remove_file(self.entry_path(key)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    fn clear(&self) -> io::Result<()> {
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == ENTRY_EXTENSION) {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How a file is identified when looking it up in the cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CacheKeyMode {
+    /// Device, inode, modification time, change time and size
+    ///
+    /// Only needs a `stat`, but misses edits that preserve all of them.
+    #[default]
+    FileStat,
+    /// SHA-256 of the file content, the extension detection tries first, and
+    /// the modification time
+    ///
+    /// Reads the whole file, but is unaffected by renames, moves or file
+    /// systems without stable inodes: a copy with the same extension and
+    /// modification time (`cp -p`, `rsync -a`) is answered from the
+    /// original's entry, with the System and XAttr tags read from the copy.
+    ContentHash,
+}
+
+/// What is stored for each file
+///
+/// This is the [`ExifData`] before `prepare_for_serialization`, so cached
+/// results can be serialized with the same options as fresh ones.
+#[derive(Serialize, Deserialize)]
+struct CachedMetadata {
+    exif_tool_version: String,
+    tags: Vec<TagEntry>,
+    legacy_tags: IndexMap<String, TagValue>,
+    errors: Vec<String>,
+    #[serde(default)]
+    warnings: Vec<ExtractionWarning>,
+    #[serde(default)]
+    truncation: Option<Truncation>,
+    #[serde(default)]
+    xmp_packet: Option<XmpPacketLocation>,
+    #[serde(default)]
+    xmp_provenance: Option<XmpProvenance>,
+    missing_implementations: Option<Vec<String>>,
+}
+
+/// Extraction front-end that reuses results for unchanged files
+pub struct MetadataCache {
+    store: Box<dyn CacheStore>,
+    key_mode: CacheKeyMode,
+}
+
+impl MetadataCache {
+    /// Cache results in `store`
+    pub fn new(store: impl CacheStore + 'static) -> Self {
+        Self {
+            store: Box::new(store),
+            key_mode: CacheKeyMode::default(),
+        }
+    }
+
+    /// Cache results in a [`FlatFileStore`] at `dir`
+    pub fn open_dir(dir: impl Into<PathBuf>) -> Result<Self> {
+        Ok(Self::new(FlatFileStore::open(dir)?))
+    }
+
+    /// Identify files by `key_mode` instead of [`CacheKeyMode::FileStat`]
+    pub fn with_key_mode(mut self, key_mode: CacheKeyMode) -> Self {
+        self.key_mode = key_mode;
+        self
+    }
+
+    pub fn key_mode(&self) -> CacheKeyMode {
+        self.key_mode
+    }
+
+    /// Remove all cached results
+    pub fn clear(&self) -> Result<()> {
+        Ok(self.store.clear()?)
+    }
+
+    /// Same as [`formats::extract_metadata`], answered from the cache when possible
+    ///
+    /// Failed extractions are not cached. Problems reading or writing the
+    /// cache itself are logged and fall back to a normal extraction.
+    pub fn extract_metadata(
+        &self,
+        path: &Path,
+        show_missing: bool,
+        show_warnings: bool,
+        filter_options: Option<FilterOptions>,
+    ) -> Result<ExifData> {
+        let key = self.key(path, show_missing, show_warnings, filter_options.as_ref())?;
+
+        match self.lookup(&key) {
+            Some(mut cached) => {
+                debug!("Metadata cache hit for {}", path.display());
+                if self.key_mode == CacheKeyMode::ContentHash {
+                    let filter = filter_options.clone().unwrap_or_default();
+                    refresh_file_tags(&mut cached.tags, path, &filter)?;
+                }
+                return Ok(ExifData {
+                    source_file: path.to_string_lossy().to_string(),
+                    exif_tool_version: cached.exif_tool_version,
+                    tags: cached.tags,
+                    legacy_tags: cached.legacy_tags,
+                    errors: cached.errors,
+                    warnings: cached.warnings,
+                    truncation: cached.truncation,
+                    missing_implementations: cached.missing_implementations,
+                    tag_sources: IndexMap::new(),
+                    xmp_packet: cached.xmp_packet,
+                    xmp_provenance: cached.xmp_provenance,
+                    stats: None,
+                });
+            }
+            None => debug!("Metadata cache miss for {}", path.display()),
+        }
+
+        let exif_data =
+            formats::extract_metadata(path, show_missing, show_warnings, filter_options)?;
+
+        let cached = CachedMetadata {
+            exif_tool_version: exif_data.exif_tool_version.clone(),
+            tags: exif_data.tags.clone(),
+            legacy_tags: exif_data.legacy_tags.clone(),
+            errors: exif_data.errors.clone(),
+            warnings: exif_data.warnings.clone(),
+            truncation: exif_data.truncation.clone(),
+            xmp_packet: exif_data.xmp_packet.clone(),
+            xmp_provenance: exif_data.xmp_provenance.clone(),
+            missing_implementations: exif_data.missing_implementations.clone(),
+        };
+        match serde_json::to_vec(&cached) {
+            Ok(bytes) => {
+                if let Err(e) = self.store.put(&key, &bytes) {
+                    warn!("Failed to write metadata cache entry: {e}");
+                }
+            }
+            Err(e) => warn!("Failed to serialize metadata cache entry: {e}"),
+        }
+
+        Ok(exif_data)
+    }
+
+    fn lookup(&self, key: &str) -> Option<CachedMetadata> {
+        let bytes = match self.store.get(key) {
+            Ok(bytes) => bytes?,
+            Err(e) => {
+                warn!("Failed to read metadata cache entry: {e}");
+                return None;
+            }
+        };
+        match serde_json::from_slice(&bytes) {
+            Ok(cached) => Some(cached),
+            Err(e) => {
+                // Written by an incompatible version, or truncated by a crash
+                warn!("Discarding unreadable metadata cache entry: {e}");
+                let _ = self.store.remove(key);
+                None
+            }
+        }
+    }
+
+    /// Cache key for extracting `path` with the given options
+    fn key(
+        &self,
+        path: &Path,
+        show_missing: bool,
+        show_warnings: bool,
+        filter_options: Option<&FilterOptions>,
+    ) -> Result<String> {
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+        hasher.update([0]);
+
+        match self.key_mode {
+            CacheKeyMode::FileStat => {
+                hasher.update(path.to_string_lossy().as_bytes());
+                hasher.update([0]);
+                // A reported symbolic link's output only depends on the link
+                let reports_links =
+                    filter_options.is_some_and(|filter| filter.symlinks == SymlinkPolicy::Report);
+                let metadata = if reports_links {
+                    fs::symlink_metadata(path)?
+                } else {
+                    fs::metadata(path)?
+                };
+                hasher.update(file_stat_key(&metadata).as_bytes());
+            }
+            CacheKeyMode::ContentHash => {
+                // File type detection tries the extension first; the rest of
+                // the path only shows in the per-file tags, refreshed on a hit
+                let extension = path.extension().unwrap_or_default();
+                hasher.update(extension.to_string_lossy().to_uppercase().as_bytes());
+                hasher.update([0]);
+                formats::check_regular_file(path)?;
+                hasher.update(modified_key(&fs::metadata(path)?).as_bytes());
+                hasher.update([0]);
+                let mut file = File::open(path)?;
+                let mut buffer = vec![0u8; 64 * 1024];
+                loop {
+                    let n = file.read(&mut buffer)?;
+                    if n == 0 {
+                        break;
+                    }
+                    hasher.update(&buffer[..n]);
+                }
+            }
+        }
+        hasher.update([0]);
+
+        hasher.update(format!("{show_missing} {show_warnings} ").as_bytes());
+        match filter_options {
+            Some(filter) => hasher.update(filter_fingerprint(filter).as_bytes()),
+            None => hasher.update(b"None"),
+        }
+        // User-defined tags change the output too
+        if let Some(config) = crate::user_config::config() {
+            hasher.update([0]);
+            hasher.update(config.source().as_bytes());
+        }
+
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+}
+
+/// Family 1 groups of the tags read from the file system rather than the
+/// file content
+const PER_FILE_GROUPS: &[&str] = &["System", "XAttr"];
+
+/// Replace the per-file tags of a result cached for another file with the
+/// same content by those of `path`
+fn refresh_file_tags(tags: &mut Vec<TagEntry>, path: &Path, filter: &FilterOptions) -> Result<()> {
+    let is_per_file = |tag: &TagEntry| PER_FILE_GROUPS.contains(&tag.group1.as_str());
+    let fresh: Vec<TagEntry> = formats::extract_file_tags_only(path, false, filter)?
+        .tags
+        .into_iter()
+        .filter(is_per_file)
+        .collect();
+    let at = tags.iter().position(is_per_file).unwrap_or(0);
+    tags.retain(|tag| !is_per_file(tag));
+    tags.splice(at..at, fresh);
+    Ok(())
+}
+
+/// File modification time used by [`CacheKeyMode::ContentHash`]
+fn modified_key(metadata: &fs::Metadata) -> String {
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .unwrap_or_default();
+    modified.as_nanos().to_string()
+}
+
+/// File identity used by [`CacheKeyMode::FileStat`]
+#[cfg(unix)]
+fn file_stat_key(metadata: &fs::Metadata) -> String {
+    use std::os::unix::fs::MetadataExt;
+    format!(
+        "{} {} {}.{} {}.{} {}",
+        metadata.dev(),
+        metadata.ino(),
+        metadata.mtime(),
+        metadata.mtime_nsec(),
+        metadata.ctime(),
+        metadata.ctime_nsec(),
+        metadata.size()
+    )
+}
+
+/// File identity used by [`CacheKeyMode::FileStat`]
+#[cfg(not(unix))]
+fn file_stat_key(metadata: &fs::Metadata) -> String {
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .unwrap_or_default();
+    format!("{} {}", modified.as_nanos(), metadata.len())
+}
+
+/// Stable text form of `filter` for cache keys
+///
+/// `numeric_tags` is a `HashSet`, whose iteration order differs between
+/// processes, so it is sorted separately.
+fn filter_fingerprint(filter: &FilterOptions) -> String {
+    let mut filter = filter.clone();
+    let numeric_tags: BTreeSet<String> = filter.numeric_tags.drain().collect();
+    format!("{numeric_tags:?} {filter:?}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+
+    /// FlatFileStore that counts writes, to tell hits from misses
+    struct CountingStore {
+        inner: FlatFileStore,
+        puts: Arc<AtomicUsize>,
+    }
+
+    impl CacheStore for CountingStore {
+        fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+            self.inner.get(key)
+        }
+        fn put(&self, key: &str, value: &[u8]) -> io::Result<()> {
+            self.puts.fetch_add(1, Ordering::SeqCst);
+            self.inner.put(key, value)
+        }
+        fn remove(&self, key: &str) -> io::Result<()> {
+            self.inner.remove(key)
+        }
+        fn clear(&self) -> io::Result<()> {
+            self.inner.clear()
+        }
+    }
+
+    /// Little-endian TIFF whose IFD0 holds only Make (0x010f)
+    fn tiff_with_make(make: &[u8; 5]) -> Vec<u8> {
+        let mut data = b"II*\0".to_vec();
+        data.extend_from_slice(&8u32.to_le_bytes());
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data.extend_from_slice(&0x010fu16.to_le_bytes());
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend_from_slice(&6u32.to_le_bytes());
+        data.extend_from_slice(&26u32.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(make);
+        data.push(0);
+        data
+    }
+
+    fn make(exif_data: &ExifData) -> Option<TagValue> {
+        exif_data
+            .tags
+            .iter()
+            .find(|t| t.name == "Make")
+            .map(|t| t.print().clone())
+    }
+
+    fn counting_cache(dir: &Path, key_mode: CacheKeyMode) -> (MetadataCache, Arc<AtomicUsize>) {
+        let puts = Arc::new(AtomicUsize::new(0));
+        let store = CountingStore {
+            inner: FlatFileStore::open(dir).unwrap(),
+            puts: puts.clone(),
+        };
+        (MetadataCache::new(store).with_key_mode(key_mode), puts)
+    }
+
+    #[test]
+    fn test_unchanged_file_is_served_from_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = dir.path().join("image.tif");
+        fs::write(&image, tiff_with_make(b"Canon")).unwrap();
+        let (cache, puts) = counting_cache(&dir.path().join("cache"), CacheKeyMode::FileStat);
+
+        let first = cache.extract_metadata(&image, false, false, None).unwrap();
+        let second = cache.extract_metadata(&image, false, false, None).unwrap();
+        assert_eq!(puts.load(Ordering::SeqCst), 1);
+        assert_eq!(make(&first), Some(TagValue::String("Canon".to_string())));
+        assert_eq!(make(&second), make(&first));
+        assert_eq!(second.tags.len(), first.tags.len());
+        assert_eq!(second.source_file, first.source_file);
+
+        // Different options are cached separately
+        let filter = FilterOptions::tags_only(vec!["Make".to_string()]);
+        let filtered = cache
+            .extract_metadata(&image, false, false, Some(filter))
+            .unwrap();
+        assert_eq!(puts.load(Ordering::SeqCst), 2);
+        assert_eq!(filtered.tags.len(), 1);
+    }
+
+    #[test]
+    fn test_modified_file_is_extracted_again() {
+        for key_mode in [CacheKeyMode::FileStat, CacheKeyMode::ContentHash] {
+            let dir = tempfile::tempdir().unwrap();
+            let image = dir.path().join("image.tif");
+            fs::write(&image, tiff_with_make(b"Canon")).unwrap();
+            let (cache, puts) = counting_cache(&dir.path().join("cache"), key_mode);
+
+            cache.extract_metadata(&image, false, false, None).unwrap();
+            // Same size, so only the mtime/ctime or the content hash tell them apart
+            fs::write(&image, tiff_with_make(b"Nikon")).unwrap();
+            let updated = cache.extract_metadata(&image, false, false, None).unwrap();
+            assert_eq!(puts.load(Ordering::SeqCst), 2, "{key_mode:?}");
+            assert_eq!(make(&updated), Some(TagValue::String("Nikon".to_string())));
+        }
+    }
+
+    #[test]
+    fn test_content_hash_serves_copies() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = dir.path().join("image.tif");
+        fs::write(&image, tiff_with_make(b"Canon")).unwrap();
+        fs::create_dir(dir.path().join("copies")).unwrap();
+        let copy = dir.path().join("copies").join("copy.tif");
+        fs::copy(&image, &copy).unwrap();
+        let modified = fs::metadata(&image).unwrap().modified().unwrap();
+        let set_modified = |path: &Path, time| {
+            File::options()
+                .write(true)
+                .open(path)
+                .unwrap()
+                .set_modified(time)
+                .unwrap()
+        };
+        set_modified(&copy, modified);
+        let (cache, puts) = counting_cache(&dir.path().join("cache"), CacheKeyMode::ContentHash);
+
+        let original = cache.extract_metadata(&image, false, false, None).unwrap();
+        let copied = cache.extract_metadata(&copy, false, false, None).unwrap();
+        assert_eq!(puts.load(Ordering::SeqCst), 1);
+        assert_eq!(make(&copied), make(&original));
+        assert_eq!(copied.tags.len(), original.tags.len());
+
+        // The System tags are the copy's own
+        let system = |exif_data: &ExifData, name: &str| {
+            exif_data
+                .tags
+                .iter()
+                .find(|t| t.group1.as_str() == "System" && t.name == name)
+                .map(|t| t.print().clone())
+        };
+        assert_eq!(
+            system(&copied, "FileName"),
+            Some(TagValue::string("copy.tif"))
+        );
+        assert_eq!(
+            system(&copied, "Directory"),
+            Some(TagValue::string(
+                dir.path().join("copies").to_string_lossy()
+            ))
+        );
+        assert_eq!(copied.source_file, copy.to_string_lossy());
+
+        // File:FileModifyDate can feed Composite:BestDateTime, so a copy with
+        // another modification time gets its own entry
+        set_modified(&copy, modified - std::time::Duration::from_secs(3600));
+        cache.extract_metadata(&copy, false, false, None).unwrap();
+        assert_eq!(puts.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_clear_and_unreadable_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let image = dir.path().join("image.tif");
+        fs::write(&image, tiff_with_make(b"Canon")).unwrap();
+        let store = FlatFileStore::open(dir.path().join("cache")).unwrap();
+        let cache = MetadataCache::new(store.clone());
+
+        cache.extract_metadata(&image, false, false, None).unwrap();
+        let entries: Vec<_> = fs::read_dir(store.dir()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+
+        // A corrupt entry is discarded and replaced
+        let entry = entries[0].as_ref().unwrap().path();
+        fs::write(&entry, b"{").unwrap();
+        let exif_data = cache.extract_metadata(&image, false, false, None).unwrap();
+        assert_eq!(
+            make(&exif_data),
+            Some(TagValue::String("Canon".to_string()))
+        );
+        assert!(serde_json::from_slice::<CachedMetadata>(&fs::read(&entry).unwrap()).is_ok());
+
+        cache.clear().unwrap();
+        assert_eq!(fs::read_dir(store.dir()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_filter_fingerprint_ignores_numeric_tag_order() {
+        let tags = ["Orientation", "FNumber", "ISO", "Flash", "ExposureTime"];
+        let a = FilterOptions {
+            numeric_tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..Default::default()
+        };
+        let b = FilterOptions {
+            numeric_tags: tags
+                .iter()
+                .rev()
+                .map(|t| t.to_string())
+                .collect::<HashSet<_>>(),
+            ..Default::default()
+        };
+        assert_eq!(filter_fingerprint(&a), filter_fingerprint(&b));
+        assert_ne!(
+            filter_fingerprint(&a),
+            filter_fingerprint(&FilterOptions::default())
+        );
+    }
+}
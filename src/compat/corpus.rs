@@ -0,0 +1,297 @@
+//! Regression comparison over a corpus of sample files
+//!
+//! `compare-with-exiftool <DIR>` runs ExifTool and exif-oxide over every file
+//! under a directory and tallies the differences per tag, so a format PR can
+//! show which tags it fixed or broke across a whole sample collection. With
+//! `--history FILE`, every run is appended to a JSON-lines file and compared
+//! with the previous one.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use super::{DifferenceType, TagDifference};
+
+/// Every file under `dir`, recursively and sorted, skipping hidden entries
+pub fn corpus_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() {
+                files.push(entry.path());
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// How one tag compared across the corpus
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagTally {
+    pub working: usize,
+    pub value_format_mismatch: usize,
+    pub type_mismatch: usize,
+    pub missing: usize,
+    pub only_in_ours: usize,
+    /// First file where the tag didn't match, to start debugging from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sample_file: Option<String>,
+}
+
+impl TagTally {
+    /// Files where the tag didn't match
+    pub fn failing(&self) -> usize {
+        self.value_format_mismatch + self.type_mismatch + self.missing + self.only_in_ours
+    }
+}
+
+/// A file one of the tools couldn't read
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorpusFailure {
+    pub file: String,
+    pub error: String,
+}
+
+/// Per-tag differences over a corpus
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CorpusReport {
+    /// When the run finished, RFC 3339
+    pub timestamp: String,
+    pub files_compared: usize,
+    pub failures: Vec<CorpusFailure>,
+    pub tags: BTreeMap<String, TagTally>,
+}
+
+impl CorpusReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tally the differences found in one file
+    pub fn add_file(&mut self, differences: &[TagDifference]) {
+        self.files_compared += 1;
+        for diff in differences {
+            let tally = self.tags.entry(diff.tag.clone()).or_default();
+            match diff.difference_type {
+                DifferenceType::Working => {
+                    tally.working += 1;
+                    continue;
+                }
+                DifferenceType::ValueFormatMismatch => tally.value_format_mismatch += 1,
+                DifferenceType::TypeMismatch => tally.type_mismatch += 1,
+                // Treat as missing, as for single files
+                DifferenceType::Missing | DifferenceType::DependencyFailure => tally.missing += 1,
+                DifferenceType::OnlyInOurs => tally.only_in_ours += 1,
+            }
+            tally
+                .sample_file
+                .get_or_insert_with(|| diff.sample_file.clone());
+        }
+    }
+
+    /// Record a file that couldn't be compared
+    pub fn add_failure(&mut self, file: &str, error: impl ToString) {
+        self.failures.push(CorpusFailure {
+            file: file.to_string(),
+            error: error.to_string(),
+        });
+    }
+
+    /// Mark the run as finished now
+    pub fn finish(&mut self) {
+        self.timestamp = chrono::Local::now().to_rfc3339();
+    }
+
+    /// Sum of every tag's tally, without a sample file
+    pub fn totals(&self) -> TagTally {
+        self.tags
+            .values()
+            .fold(TagTally::default(), |sum, tally| TagTally {
+                working: sum.working + tally.working,
+                value_format_mismatch: sum.value_format_mismatch + tally.value_format_mismatch,
+                type_mismatch: sum.type_mismatch + tally.type_mismatch,
+                missing: sum.missing + tally.missing,
+                only_in_ours: sum.only_in_ours + tally.only_in_ours,
+                sample_file: None,
+            })
+    }
+
+    /// Tags that match in fewer files than in `previous`, with both counts
+    pub fn regressions_from<'a>(&self, previous: &'a CorpusReport) -> Vec<(&'a str, usize, usize)> {
+        previous
+            .tags
+            .iter()
+            .filter_map(|(tag, before)| {
+                let now = self.tags.get(tag).map_or(0, |tally| tally.working);
+                (now < before.working).then_some((tag.as_str(), before.working, now))
+            })
+            .collect()
+    }
+
+    /// Tags that match in more files than in `previous`, with both counts
+    pub fn improvements_from(&self, previous: &CorpusReport) -> Vec<(&str, usize, usize)> {
+        self.tags
+            .iter()
+            .filter_map(|(tag, now)| {
+                let before = previous.tags.get(tag).map_or(0, |tally| tally.working);
+                (now.working > before).then_some((tag.as_str(), before, now.working))
+            })
+            .collect()
+    }
+
+    /// Print totals and the `limit` tags failing in the most files
+    pub fn print_summary(&self, limit: usize) {
+        let totals = self.totals();
+        println!("\n📊 Corpus comparison: {} files", self.files_compared);
+        println!("  ✅ Working:              {}", totals.working);
+        println!(
+            "  🔧 Value format:         {}",
+            totals.value_format_mismatch
+        );
+        println!("  ⚠️  Type mismatch:        {}", totals.type_mismatch);
+        println!("  ❌ Missing:              {}", totals.missing);
+        println!("  ➕ Only in exif-oxide:   {}", totals.only_in_ours);
+        if !self.failures.is_empty() {
+            println!("  💥 Files not compared:   {}", self.failures.len());
+        }
+
+        let mut failing: Vec<(&String, &TagTally)> = self
+            .tags
+            .iter()
+            .filter(|(_, tally)| tally.failing() > 0)
+            .collect();
+        failing.sort_by_key(|(_, tally)| std::cmp::Reverse(tally.failing()));
+        if failing.is_empty() {
+            return;
+        }
+
+        println!("\nTags failing in the most files:");
+        for (tag, tally) in failing.iter().take(limit) {
+            println!(
+                "  {:>5} of {:<5} {tag} (missing {}, type {}, format {}, extra {}) e.g. {}",
+                tally.failing(),
+                tally.failing() + tally.working,
+                tally.missing,
+                tally.type_mismatch,
+                tally.value_format_mismatch,
+                tally.only_in_ours,
+                tally.sample_file.as_deref().unwrap_or("-")
+            );
+        }
+        if failing.len() > limit {
+            println!("  ... and {} more", failing.len() - limit);
+        }
+    }
+
+    /// Print what changed since `previous`
+    pub fn print_change_from(&self, previous: &CorpusReport) {
+        let (now, before) = (self.totals(), previous.totals());
+        println!(
+            "\n📈 Since {}: working {} → {}, missing {} → {}, type mismatches {} → {}",
+            previous.timestamp,
+            before.working,
+            now.working,
+            before.missing,
+            now.missing,
+            before.type_mismatch,
+            now.type_mismatch
+        );
+        for (tag, was, is) in self.improvements_from(previous) {
+            println!("  ✅ {tag}: working in {was} → {is} files");
+        }
+        for (tag, was, is) in self.regressions_from(previous) {
+            println!("  ❌ {tag}: working in {was} → {is} files");
+        }
+    }
+}
+
+/// The last run recorded in a history file, if any
+pub fn load_last_run(history: &Path) -> Option<CorpusReport> {
+    let file = fs::File::open(history).ok()?;
+    BufReader::new(file)
+        .lines()
+        .map_while(Result::ok)
+        .filter(|line| !line.trim().is_empty())
+        .last()
+        .and_then(|line| serde_json::from_str(&line).ok())
+}
+
+/// Append a run to a history file, one JSON object per line
+pub fn append_run(history: &Path, report: &CorpusReport) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(history)?;
+    let line = serde_json::to_string(report).map_err(io::Error::other)?;
+    writeln!(file, "{line}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diff(tag: &str, difference_type: DifferenceType, file: &str) -> TagDifference {
+        TagDifference {
+            tag: tag.to_string(),
+            expected: None,
+            actual: None,
+            difference_type,
+            sample_file: file.to_string(),
+        }
+    }
+
+    #[test]
+    fn tallies_files_and_tracks_changes() {
+        let mut before = CorpusReport::new();
+        before.add_file(&[
+            diff("EXIF:Make", DifferenceType::Working, "a.jpg"),
+            diff("EXIF:Model", DifferenceType::Working, "a.jpg"),
+        ]);
+        before.add_file(&[
+            diff("EXIF:Make", DifferenceType::Missing, "b.jpg"),
+            diff("EXIF:Model", DifferenceType::Working, "b.jpg"),
+        ]);
+        assert_eq!(before.tags["EXIF:Make"].missing, 1);
+        assert_eq!(
+            before.tags["EXIF:Make"].sample_file.as_deref(),
+            Some("b.jpg")
+        );
+        assert_eq!(before.totals().working, 3);
+
+        let mut after = CorpusReport::new();
+        after.add_file(&[
+            diff("EXIF:Make", DifferenceType::Working, "a.jpg"),
+            diff("EXIF:Model", DifferenceType::TypeMismatch, "a.jpg"),
+        ]);
+        after.add_file(&[
+            diff("EXIF:Make", DifferenceType::Working, "b.jpg"),
+            diff("EXIF:Model", DifferenceType::Working, "b.jpg"),
+        ]);
+        assert_eq!(after.improvements_from(&before), [("EXIF:Make", 1, 2)]);
+        assert_eq!(after.regressions_from(&before), [("EXIF:Model", 2, 1)]);
+
+        let dir = tempfile::tempdir().unwrap();
+        let history = dir.path().join("history.jsonl");
+        assert!(load_last_run(&history).is_none());
+        before.finish();
+        append_run(&history, &before).unwrap();
+        after.finish();
+        append_run(&history, &after).unwrap();
+        let last = load_last_run(&history).unwrap();
+        assert_eq!(last.tags, after.tags);
+
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+        std::fs::write(dir.path().join("sub/b.jpg"), b"").unwrap();
+        std::fs::write(dir.path().join(".hidden"), b"").unwrap();
+        let files = corpus_files(dir.path()).unwrap();
+        assert_eq!(files, [history, dir.path().join("sub/b.jpg")]);
+    }
+}
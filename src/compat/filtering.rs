@@ -4,7 +4,10 @@
 //! like `-EXIF:all`, `-Orientation#`, `-GPS*`, etc.
 
 use crate::hash::ImageHashType;
-use crate::types::FilterOptions;
+use crate::types::{
+    expand_tag_name, ExifCharset, FilterOptions, GroupFamily, QuickTimeUtc, ScanDepth,
+    SymlinkPolicy, TagOrder, UnknownTags,
+};
 use serde_json::Value;
 use std::collections::{HashMap, HashSet};
 
@@ -13,6 +16,8 @@ use std::collections::{HashMap, HashSet};
 /// Supports all the patterns that the main CLI supports:
 /// - `-TagName` - extract specific tag
 /// - `-TagName#` - extract tag with numeric value (ValueConv)  
+/// - `-n`, `-all#` - numeric values for all tags
+/// - `-sort`, `-sort0` - output order (see [`TagOrder`])
 /// - `-GroupName:all` - extract all tags from group
 /// - `-GPS*` - glob patterns
 /// - `-all` - extract all tags
@@ -38,11 +43,52 @@ pub fn parse_exiftool_filters(args: &[&str]) -> FilterOptions {
     let mut glob_patterns = Vec::new();
     let mut numeric_tags = HashSet::new();
     let mut extract_all = false;
+    let mut numeric = false;
+    let mut tag_order = TagOrder::default();
+    let mut group_family = GroupFamily::default();
+    let mut unknown_tags = UnknownTags::default();
+    let mut allow_duplicates = false;
+    let mut composites = true;
 
     for arg in args {
         if *arg == "-all" || *arg == "--all" {
             // Special case: extract all tags
             extract_all = true;
+        } else if *arg == "-n" || *arg == "-all#" {
+            // Numeric output for every tag (ValueConv instead of PrintConv)
+            // ExifTool: -n (--printConv) disables print conversion; -all# is -TAG# for all tags
+            if *arg == "-all#" {
+                extract_all = true;
+            }
+            numeric = true;
+        } else if *arg == "-sort" || *arg == "-sort0" {
+            // Sorted output instead of ExifTool's usual group order
+            // ExifTool: -sort sorts by tag name (with -s), -sort0 by family 0 group first
+            tag_order = if *arg == "-sort0" {
+                TagOrder::Group
+            } else {
+                TagOrder::Name
+            };
+        } else if *arg == "-G0" || *arg == "-G1" {
+            // ExifTool: -G1 prefixes tags with their family 1 group
+            group_family = if *arg == "-G1" {
+                GroupFamily::Family1
+            } else {
+                GroupFamily::Family0
+            };
+        } else if *arg == "-a" {
+            // ExifTool: -a keeps duplicate tags
+            allow_duplicates = true;
+        } else if *arg == "-e" {
+            // ExifTool: -e (--composite) doesn't generate composite tags
+            composites = false;
+        } else if *arg == "-u" || *arg == "-U" {
+            // ExifTool: -u extracts unknown tags, -U unknown binary data too
+            unknown_tags = if *arg == "-U" {
+                UnknownTags::IncludeBinary
+            } else {
+                UnknownTags::Include
+            };
         } else if arg.starts_with('-') && arg.len() > 1 {
             // Process tag/group filters
             let filter_arg = &arg[1..]; // Remove leading '-'
@@ -57,7 +103,7 @@ pub fn parse_exiftool_filters(args: &[&str]) -> FilterOptions {
                 } else {
                     // Regular numeric tag: -TagName#
                     requested_tags.push(tag_name.to_string());
-                    numeric_tags.insert(tag_name.to_string());
+                    numeric_tags.extend(expand_tag_name(tag_name));
                 }
             } else if filter_arg.ends_with(":all") {
                 // Group all pattern: -GroupName:all
@@ -88,6 +134,24 @@ pub fn parse_exiftool_filters(args: &[&str]) -> FilterOptions {
             glob_patterns: Vec::new(),
             compute_image_hash: false,
             image_hash_type: ImageHashType::default(),
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(FilterOptions::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric,
+            tag_order,
+            group_family,
+            unknown_tags,
+            allow_duplicates,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth: ScanDepth::default(),
+            symlinks: SymlinkPolicy::default(),
+            composites,
         }
     } else if requested_tags.is_empty()
         && requested_groups.is_empty()
@@ -104,6 +168,24 @@ pub fn parse_exiftool_filters(args: &[&str]) -> FilterOptions {
             glob_patterns: Vec::new(),
             compute_image_hash: false,
             image_hash_type: ImageHashType::default(),
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(FilterOptions::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric,
+            tag_order,
+            group_family,
+            unknown_tags,
+            allow_duplicates,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth: ScanDepth::default(),
+            symlinks: SymlinkPolicy::default(),
+            composites,
         }
     } else {
         // Specific filters requested
@@ -116,6 +198,24 @@ pub fn parse_exiftool_filters(args: &[&str]) -> FilterOptions {
             glob_patterns,
             compute_image_hash: false,
             image_hash_type: ImageHashType::default(),
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(FilterOptions::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric,
+            tag_order,
+            group_family,
+            unknown_tags,
+            allow_duplicates,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth: ScanDepth::default(),
+            symlinks: SymlinkPolicy::default(),
+            composites,
         }
     }
 }
@@ -186,6 +286,31 @@ mod tests {
         assert!(filter.numeric_tags.contains("Orientation"));
     }
 
+    #[test]
+    fn test_parse_sort_options() {
+        assert_eq!(
+            parse_exiftool_filters(&["-all"]).tag_order,
+            TagOrder::GroupPriority
+        );
+        assert_eq!(parse_exiftool_filters(&["-sort"]).tag_order, TagOrder::Name);
+        assert_eq!(
+            parse_exiftool_filters(&["-sort0"]).tag_order,
+            TagOrder::Group
+        );
+    }
+
+    #[test]
+    fn test_parse_group_family() {
+        assert_eq!(
+            parse_exiftool_filters(&["-all"]).group_family,
+            GroupFamily::Family0
+        );
+        assert_eq!(
+            parse_exiftool_filters(&["-G1", "-Make"]).group_family,
+            GroupFamily::Family1
+        );
+    }
+
     #[test]
     fn test_parse_glob_pattern() {
         let filter = parse_exiftool_filters(&["-GPS*"]);
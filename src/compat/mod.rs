@@ -8,17 +8,22 @@
 //! - Tolerance-based comparison for GPS coordinates and numeric values
 //! - Structured difference reporting
 //! - Support for group-based filtering (File:, EXIF:, etc.)
+//! - [`ParityChecker`] for checking any file against an ExifTool JSON reference
 
 pub mod comparison;
+pub mod corpus;
 pub mod filtering;
 pub mod known_gaps;
 pub mod normalization;
+pub mod parity;
 pub mod reporting;
 
 pub use comparison::*;
+pub use corpus::*;
 pub use filtering::*;
 pub use known_gaps::*;
 pub use normalization::*;
+pub use parity::*;
 pub use reporting::*;
 
 use serde_json::Value;
@@ -107,11 +112,27 @@ pub fn run_exif_oxide(file_path: &str) -> Result<Value, Box<dyn std::error::Erro
     Ok(crate::extract_metadata_json(file_path)?)
 }
 
+/// The ExifTool to compare against: `$EXIFTOOL`, else the submodule's
+/// (the version codegen was generated from) when checked out, else `exiftool`
+/// from the PATH
+pub fn exiftool_command() -> std::path::PathBuf {
+    if let Some(exiftool) = std::env::var_os("EXIFTOOL") {
+        return exiftool.into();
+    }
+    let submodule =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("third-party/exiftool/exiftool");
+    if submodule.is_file() {
+        submodule
+    } else {
+        "exiftool".into()
+    }
+}
+
 /// Run ExifTool with the standard flags and return parsed JSON
 pub fn run_exiftool(file_path: &str) -> Result<Value, Box<dyn std::error::Error>> {
     use std::process::Command;
 
-    let output = Command::new("exiftool")
+    let output = Command::new(exiftool_command())
         .args([
             "-j",
             "-struct",
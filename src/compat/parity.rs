@@ -0,0 +1,354 @@
+//! Parity checks against an ExifTool JSON reference
+//!
+//! [`ParityChecker`] compares an [`ExifData`] (or exif-oxide JSON) with the
+//! output of `exiftool -j -G` for the same file, so applications can run their
+//! own compatibility checks over their own corpora:
+//!
+//! ```no_run
+//! use exif_oxide::compat::ParityChecker;
+//!
+//! let exif_data = exif_oxide::formats::extract_metadata(
+//!     std::path::Path::new("photo.jpg"), false, false, None,
+//! ).unwrap();
+//! let reference: serde_json::Value =
+//!     serde_json::from_str(&std::fs::read_to_string("photo.json").unwrap()).unwrap();
+//!
+//! let report = ParityChecker::new()
+//!     .with_float_epsilon(1e-6)
+//!     .with_known_differences(["MakerNotes:*", "EXIF:ThumbnailImage"])
+//!     .compare(&exif_data, &reference);
+//! assert!(report.is_clean(), "{:#?}", report.differences);
+//! ```
+//!
+//! Values go through the same normalization as the compatibility tests
+//! ([`normalize_for_comparison`]) before the tolerance rules apply.
+
+use regex::Regex;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use super::{
+    normalize_for_comparison, same_data_different_format_with_tag, DifferenceType, KnownGaps,
+    TagDifference,
+};
+use crate::types::{ExifData, FilterOptions};
+
+/// Serialized ExifData fields that aren't tags
+const NON_TAG_KEYS: &[&str] = &[
+    "SourceFile",
+    "TagSources",
+    "MissingImplementations",
+    "errors",
+];
+
+/// `YYYY:MM:DD[ HH:MM[:SS[.frac]]][zone]`, with `-` or `:` in the date and
+/// ` ` or `T` before the time
+static DATE_TIME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^(\d{4})[:-](\d{2})[:-](\d{2})(?:[ T](\d{2}):(\d{2})(?::(\d{2}))?(\.\d+)?)?\s*(Z|[+-]\d{2}:?\d{2})?$",
+    )
+    .unwrap()
+});
+
+/// Compares exif-oxide output with an ExifTool reference using configurable
+/// tolerance rules
+#[derive(Debug, Clone)]
+pub struct ParityChecker {
+    float_epsilon: f64,
+    normalize_dates: bool,
+    ignore_extra_tags: bool,
+    known_differences: HashSet<String>,
+}
+
+impl Default for ParityChecker {
+    fn default() -> Self {
+        Self {
+            // Same tolerance as `values_match_with_tolerance`
+            float_epsilon: 0.001,
+            normalize_dates: true,
+            ignore_extra_tags: false,
+            known_differences: HashSet::new(),
+        }
+    }
+}
+
+impl ParityChecker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Numbers (and numeric strings) within `epsilon` of each other match
+    pub fn with_float_epsilon(mut self, epsilon: f64) -> Self {
+        self.float_epsilon = epsilon;
+        self
+    }
+
+    /// Whether dates that differ only in format match, e.g.
+    /// `2024:01:02 03:04:05Z` and `2024-01-02T03:04:05+00:00` (default on)
+    pub fn with_date_normalization(mut self, normalize: bool) -> Self {
+        self.normalize_dates = normalize;
+        self
+    }
+
+    /// Whether tags only exif-oxide extracted count as differences (default
+    /// they do)
+    pub fn with_extra_tags_ignored(mut self, ignore: bool) -> Self {
+        self.ignore_extra_tags = ignore;
+        self
+    }
+
+    /// Tags whose differences are expected: `Group:Tag`, or `Group:*` for a
+    /// whole group
+    pub fn with_known_differences<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.known_differences
+            .extend(tags.into_iter().map(Into::into));
+        self
+    }
+
+    /// Allow every tag in a known-gaps allowlist
+    pub fn with_known_gaps(self, gaps: &KnownGaps) -> Self {
+        self.with_known_differences(gaps.tags())
+    }
+
+    /// Compare extracted metadata with the ExifTool reference
+    ///
+    /// `reference` is `exiftool -j -G` output: a tag object, or an array
+    /// holding one.
+    pub fn compare(&self, exif_data: &ExifData, reference: &Value) -> ParityReport {
+        let mut exif_data = exif_data.clone();
+        exif_data.prepare_for_serialization(None);
+        exif_data.format_binary_values(&FilterOptions::default());
+        let actual = serde_json::to_value(&exif_data).unwrap_or(Value::Null);
+        self.compare_json(&exif_data.source_file, &actual, reference)
+    }
+
+    /// Compare exif-oxide JSON output with the ExifTool reference
+    pub fn compare_json(&self, file: &str, actual: &Value, reference: &Value) -> ParityReport {
+        let expected = normalize_for_comparison(single_object(reference), true);
+        let actual = normalize_for_comparison(single_object(actual), false);
+
+        let empty_map = serde_json::Map::new();
+        let expected = expected.as_object().unwrap_or(&empty_map);
+        let actual = actual.as_object().unwrap_or(&empty_map);
+
+        let mut tags: Vec<&String> = expected.keys().chain(actual.keys()).collect();
+        tags.sort();
+        tags.dedup();
+
+        let mut report = ParityReport::default();
+        for tag in tags {
+            if NON_TAG_KEYS.contains(&tag.as_str()) {
+                continue;
+            }
+            let (exp, act) = (expected.get(tag), actual.get(tag));
+            let difference_type = match (exp, act) {
+                (Some(exp), Some(act)) => {
+                    if self.values_match(exp, act) {
+                        report.matched.push(tag.clone());
+                        continue;
+                    } else if same_data_different_format_with_tag(tag, exp, act) {
+                        DifferenceType::ValueFormatMismatch
+                    } else {
+                        DifferenceType::TypeMismatch
+                    }
+                }
+                (Some(_), None) => DifferenceType::Missing,
+                (None, Some(_)) if self.ignore_extra_tags => continue,
+                (None, Some(_)) => DifferenceType::OnlyInOurs,
+                (None, None) => continue,
+            };
+
+            let difference = TagDifference {
+                tag: tag.clone(),
+                expected: exp.cloned(),
+                actual: act.cloned(),
+                difference_type,
+                sample_file: file.to_string(),
+            };
+            if self.is_known_difference(tag) {
+                report.known_differences.push(difference);
+            } else {
+                report.differences.push(difference);
+            }
+        }
+        report
+    }
+
+    /// Whether two normalized values match under the tolerance rules
+    pub fn values_match(&self, expected: &Value, actual: &Value) -> bool {
+        if expected == actual {
+            return true;
+        }
+        if let (Some(exp), Some(act)) = (as_number(expected), as_number(actual)) {
+            return (exp - act).abs() <= self.float_epsilon;
+        }
+        if self.normalize_dates {
+            if let (Value::String(exp), Value::String(act)) = (expected, actual) {
+                if let (Some(exp), Some(act)) = (canonical_date(exp), canonical_date(act)) {
+                    return exp == act;
+                }
+            }
+        }
+        match (expected, actual) {
+            (Value::Array(exp), Value::Array(act)) => {
+                exp.len() == act.len() && exp.iter().zip(act).all(|(e, a)| self.values_match(e, a))
+            }
+            _ => false,
+        }
+    }
+
+    fn is_known_difference(&self, tag: &str) -> bool {
+        self.known_differences.contains(tag)
+            || tag
+                .split_once(':')
+                .is_some_and(|(group, _)| self.known_differences.contains(&format!("{group}:*")))
+    }
+}
+
+/// Result of a [`ParityChecker`] comparison
+#[derive(Debug, Default)]
+pub struct ParityReport {
+    /// Tags whose values match
+    pub matched: Vec<String>,
+    /// Differences in tags allowed by the known-differences list
+    pub known_differences: Vec<TagDifference>,
+    /// All other differences
+    pub differences: Vec<TagDifference>,
+}
+
+impl ParityReport {
+    /// No differences beyond the known ones
+    pub fn is_clean(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Differences of one kind
+    pub fn differences_of(&self, kind: DifferenceType) -> impl Iterator<Item = &TagDifference> {
+        self.differences
+            .iter()
+            .filter(move |diff| diff.difference_type == kind)
+    }
+}
+
+/// ExifTool's `-j` output is an array with one object per file
+fn single_object(value: &Value) -> Value {
+    match value {
+        Value::Array(items) => items.first().cloned().unwrap_or(Value::Null),
+        other => other.clone(),
+    }
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// `YYYY:MM:DD HH:MM:SS[.frac][+HH:MM]`, with `Z` as `+00:00`
+fn canonical_date(value: &str) -> Option<String> {
+    let caps = DATE_TIME_REGEX.captures(value.trim())?;
+    let mut date = format!("{}:{}:{}", &caps[1], &caps[2], &caps[3]);
+    if let (Some(hour), Some(minute)) = (caps.get(4), caps.get(5)) {
+        let second = caps.get(6).map_or("00", |s| s.as_str());
+        date.push_str(&format!(" {}:{}:{second}", hour.as_str(), minute.as_str()));
+        if let Some(frac) = caps.get(7) {
+            date.push_str(frac.as_str().trim_end_matches('0').trim_end_matches('.'));
+        }
+    }
+    match caps.get(8).map(|zone| zone.as_str()) {
+        Some("Z") => date.push_str("+00:00"),
+        Some(zone) if zone.len() == 5 => date.push_str(&format!("{}:{}", &zone[..3], &zone[3..])),
+        Some(zone) => date.push_str(zone),
+        None => {}
+    }
+    Some(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{TagEntry, TagValue};
+    use serde_json::json;
+
+    fn exif_data() -> ExifData {
+        let mut data = ExifData::new("photo.jpg".to_string(), String::new());
+        for (name, print) in [
+            ("Make", TagValue::String("Canon".to_string())),
+            ("ExposureCompensation", TagValue::F64(0.3333)),
+            (
+                "DateTimeOriginal",
+                TagValue::String("2024-01-02T03:04:05Z".to_string()),
+            ),
+            ("Software", TagValue::String("1.0".to_string())),
+        ] {
+            data.tags.push(TagEntry {
+                group: "EXIF".into(),
+                group1: "IFD0".into(),
+                name: name.into(),
+                value: print.clone(),
+                print: print.into(),
+                source: None,
+            });
+        }
+        data
+    }
+
+    #[test]
+    fn applies_tolerance_rules() {
+        let reference = json!([{
+            "SourceFile": "/somewhere/photo.jpg",
+            "EXIF:Make": "Canon",
+            "EXIF:ExposureCompensation": 0.33333,
+            "EXIF:DateTimeOriginal": "2024:01:02 03:04:05+00:00",
+            "EXIF:Software": "1.1",
+            "MakerNotes:LensType": "EF 50mm",
+        }]);
+
+        let report = ParityChecker::new().compare(&exif_data(), &reference);
+        assert_eq!(
+            report.matched,
+            [
+                "EXIF:DateTimeOriginal",
+                "EXIF:ExposureCompensation",
+                "EXIF:Make"
+            ]
+        );
+        let failing: Vec<&str> = report.differences.iter().map(|d| d.tag.as_str()).collect();
+        assert_eq!(failing, ["EXIF:Software", "MakerNotes:LensType"]);
+        assert_eq!(report.differences_of(DifferenceType::Missing).count(), 1);
+
+        let report = ParityChecker::new()
+            .with_float_epsilon(1e-9)
+            .with_date_normalization(false)
+            .with_known_differences(["MakerNotes:*", "EXIF:Software"])
+            .compare(&exif_data(), &reference);
+        assert_eq!(report.matched, ["EXIF:Make"]);
+        assert_eq!(report.known_differences.len(), 2);
+        let failing: Vec<&str> = report.differences.iter().map(|d| d.tag.as_str()).collect();
+        assert_eq!(
+            failing,
+            ["EXIF:DateTimeOriginal", "EXIF:ExposureCompensation"]
+        );
+    }
+
+    #[test]
+    fn canonicalizes_dates() {
+        assert_eq!(
+            canonical_date("2024-01-02T03:04:05.500Z").as_deref(),
+            Some("2024:01:02 03:04:05.5+00:00")
+        );
+        assert_eq!(
+            canonical_date("2024:01:02 03:04:05-0700").as_deref(),
+            Some("2024:01:02 03:04:05-07:00")
+        );
+        assert_eq!(canonical_date("2024:01:02").as_deref(), Some("2024:01:02"));
+        assert_eq!(canonical_date("Canon EOS"), None);
+    }
+}
@@ -4,7 +4,7 @@
 //! ExifTool and exif-oxide output.
 
 use crate::compat::{comparison::*, load_supported_tags};
-// use crate::generated::composite_tags::lookup_composite_tag;
+use crate::generated::composite_tags::lookup_composite_definitions;
 use serde_json::Value;
 use std::collections::HashSet;
 
@@ -363,54 +363,32 @@ pub fn analyze_tag_differences(
 
 /// Check if a composite tag has unmet dependencies
 /// Returns true if the tag is a composite tag and its required dependencies are not available
-#[allow(dead_code)]
-fn is_composite_dependency_failure(tag: &str, _our_obj: &serde_json::Map<String, Value>) -> bool {
+fn is_composite_dependency_failure(tag: &str, our_obj: &serde_json::Map<String, Value>) -> bool {
     // Only check composite tags
-    if !tag.starts_with("Composite:") {
+    let Some(tag_name) = tag.strip_prefix("Composite:") else {
         return false;
-    }
-
-    // Strip the "Composite:" prefix to get the tag name
-    let _tag_name = &tag[10..];
-
-    // Look up the composite tag definition
-    // TODO: Re-enable when composite_tags is generated
-    /*
-    if let Some(composite_def) = lookup_composite_tag(tag_name) {
-        // Check if all required dependencies are available in our output
-        for required_tag in &composite_def.require {
-            // Check various possible tag name formats
-            let possible_names = [
-                required_tag.to_string(),
-                format!(
-                    "{}:{}",
-                    required_tag.split(':').next().unwrap_or(required_tag),
-                    required_tag.split(':').next_back().unwrap_or(required_tag)
-                ),
-                required_tag
-                    .split(':')
-                    .next_back()
-                    .unwrap_or(required_tag)
-                    .to_string(),
-            ];
-
-            let mut found = false;
-            for name in &possible_names {
-                if our_obj.contains_key(name) {
-                    found = true;
-                    break;
-                }
-            }
-
-            if !found {
-                // Missing a required dependency
-                return true;
-            }
-        }
-    }
-    */
-
-    false
+    };
+
+    // A dependency failure when no module's definition has all its required
+    // tags in our output
+    let definitions = lookup_composite_definitions(tag_name);
+    !definitions.is_empty()
+        && definitions.iter().all(|composite_def| {
+            composite_def.require.iter().any(|required_tag| {
+                // Check various possible tag name formats
+                let bare = required_tag.split(':').next_back().unwrap_or(required_tag);
+                let possible_names = [
+                    required_tag.to_string(),
+                    format!(
+                        "{}:{}",
+                        required_tag.split(':').next().unwrap_or(required_tag),
+                        bare
+                    ),
+                    bare.to_string(),
+                ];
+                !possible_names.iter().any(|name| our_obj.contains_key(name))
+            })
+        })
 }
 
 /// Analyze differences between two JSON objects without filtering to supported tags
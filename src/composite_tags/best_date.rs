@@ -0,0 +1,504 @@
+//! Best capture date composite
+//!
+//! Photo managers all need "when was this taken", and each reimplements the
+//! same fallbacks over the date tags. ExifTool has no composite for it; this
+//! hand-written one walks a fixed precedence ladder and resolves the time zone.
+//!
+//! ## Composite:BestDateTime
+//!
+//! | Rung | Source                                   | Time zone                    |
+//! |------|------------------------------------------|------------------------------|
+//! | 1    | `EXIF:DateTimeOriginal` (+ `SubSecTimeOriginal`) | `OffsetTimeOriginal`, `OffsetTime`, GPS |
+//! | 2    | `XMP:DateTimeOriginal`                   | embedded, GPS                |
+//! | 3    | `EXIF:CreateDate` (+ `SubSecTimeDigitized`) | `OffsetTimeDigitized`, `OffsetTime`, GPS |
+//! | 4    | `QuickTime:CreateDate`                   | UTC (QuickTime spec)         |
+//! | 5    | `XMP:CreateDate`                         | embedded, GPS                |
+//! | 6    | `GPS:GPSDateStamp` + `GPS:GPSTimeStamp`  | UTC                          |
+//! | 7    | `File:FileModifyDate`                    | embedded (local file system) |
+//!
+//! Rung 1 with sub-seconds is ExifTool's Composite:SubSecDateTimeOriginal, and
+//! rung 6 is Composite:GPSDateTime; they are rebuilt here from their source
+//! tags so the result doesn't depend on composite build order. Empty, all-zero
+//! (`0000:00:00 00:00:00`) and unparseable dates are skipped.
+//!
+//! "GPS" time zones compare the local time with the GPS (UTC) time stamp,
+//! rounded to the nearest 15 minutes, if they are less than 14 hours apart.
+//!
+//! The value is `YYYY:mm:dd HH:MM:SS[.ss][+HH:MM]`, without a zone if none
+//! could be determined.
+//!
+//! ## Diagnostics
+//!
+//! - `Composite:BestDateTimeSource`: the rung used, e.g. `EXIF:DateTimeOriginal`,
+//!   or `Composite:SubSecDateTimeOriginal` when sub-seconds were added
+//! - `Composite:BestDateTimeZoneSource`: where the zone came from, e.g.
+//!   `EXIF:OffsetTimeOriginal` or `GPS:GPSDateStamp+GPSTimeStamp` (not built
+//!   without a zone)
+//!
+//! With the `timezone` feature, files whose zone wasn't recorded with the date
+//! also get a zone inferred from their GPS position (see
+//! `crate::geolocation::infer_time_zone`).
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::core::types::{ExifContext, ExifError, Result};
+use crate::core::TagValue;
+use crate::generated::composite_tags::CompositeTagDef;
+
+/// Desire list of the BestDateTime composites (indices below)
+const BEST_DATE_SOURCES: &[&str] = &[
+    "EXIF:DateTimeOriginal",
+    "EXIF:SubSecTimeOriginal",
+    "EXIF:OffsetTimeOriginal",
+    "XMP:DateTimeOriginal",
+    "EXIF:CreateDate",
+    "EXIF:SubSecTimeDigitized",
+    "EXIF:OffsetTimeDigitized",
+    "QuickTime:CreateDate",
+    "XMP:CreateDate",
+    "GPS:GPSDateStamp",
+    "GPS:GPSTimeStamp",
+    "File:FileModifyDate",
+    "EXIF:OffsetTime",
+];
+
+const DATE_TIME_ORIGINAL: usize = 0;
+const SUB_SEC_TIME_ORIGINAL: usize = 1;
+const OFFSET_TIME_ORIGINAL: usize = 2;
+const XMP_DATE_TIME_ORIGINAL: usize = 3;
+const CREATE_DATE: usize = 4;
+const SUB_SEC_TIME_DIGITIZED: usize = 5;
+const OFFSET_TIME_DIGITIZED: usize = 6;
+const QUICKTIME_CREATE_DATE: usize = 7;
+const XMP_CREATE_DATE: usize = 8;
+const GPS_DATE_STAMP: usize = 9;
+const GPS_TIME_STAMP: usize = 10;
+const FILE_MODIFY_DATE: usize = 11;
+const OFFSET_TIME: usize = 12;
+
+/// Zone source for offsets derived from the GPS time stamp
+const GPS_ZONE_SOURCE: &str = "GPS:GPSDateStamp+GPSTimeStamp";
+
+/// Zone sources that aren't an offset recorded with the date: derived from
+/// the GPS time, assumed UTC, or the file system's zone
+#[cfg(feature = "timezone")]
+const DERIVED_ZONE_SOURCES: &[&str] = &[
+    GPS_ZONE_SOURCE,
+    "QuickTime:CreateDate",
+    "Composite:GPSDateTime",
+    "File:FileModifyDate",
+];
+
+/// Largest difference between local and GPS time taken as a time zone
+const MAX_GPS_OFFSET_MINUTES: i64 = 14 * 60;
+
+/// Composite:BestDateTime — capture date from the best available source
+pub static COMPOSITE_BEST_DATE_TIME: CompositeTagDef = CompositeTagDef {
+    name: "BestDateTime",
+    module: "Composite",
+    require: &[],
+    desire: BEST_DATE_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_best_date_time),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Capture date and time from the best available source"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Time")],
+};
+
+/// Composite:BestDateTimeSource — which tag Composite:BestDateTime came from
+pub static COMPOSITE_BEST_DATE_TIME_SOURCE: CompositeTagDef = CompositeTagDef {
+    name: "BestDateTimeSource",
+    module: "Composite",
+    require: &[],
+    desire: BEST_DATE_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_best_date_time_source),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Tag used for Composite:BestDateTime"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Time")],
+};
+
+/// Composite:BestDateTimeZoneSource — where the time zone of
+/// Composite:BestDateTime came from
+pub static COMPOSITE_BEST_DATE_TIME_ZONE_SOURCE: CompositeTagDef = CompositeTagDef {
+    name: "BestDateTimeZoneSource",
+    module: "Composite",
+    require: &[],
+    desire: BEST_DATE_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_best_date_time_zone_source),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Tag used for the time zone of Composite:BestDateTime"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Time")],
+};
+
+/// An EXIF date with its sub-second and offset tags
+struct ExifRung {
+    date: usize,
+    sub_sec: usize,
+    offset: usize,
+    source: &'static str,
+    /// Source when sub-seconds were added (the ExifTool composite name)
+    sub_sec_source: &'static str,
+    offset_source: &'static str,
+}
+
+const DATE_TIME_ORIGINAL_RUNG: ExifRung = ExifRung {
+    date: DATE_TIME_ORIGINAL,
+    sub_sec: SUB_SEC_TIME_ORIGINAL,
+    offset: OFFSET_TIME_ORIGINAL,
+    source: "EXIF:DateTimeOriginal",
+    sub_sec_source: "Composite:SubSecDateTimeOriginal",
+    offset_source: "EXIF:OffsetTimeOriginal",
+};
+
+const CREATE_DATE_RUNG: ExifRung = ExifRung {
+    date: CREATE_DATE,
+    sub_sec: SUB_SEC_TIME_DIGITIZED,
+    offset: OFFSET_TIME_DIGITIZED,
+    source: "EXIF:CreateDate",
+    sub_sec_source: "Composite:SubSecCreateDate",
+    offset_source: "EXIF:OffsetTimeDigitized",
+};
+
+/// The selected date and where its parts came from
+#[derive(Debug, Clone, PartialEq)]
+struct BestDate {
+    /// `YYYY:mm:dd HH:MM:SS[.ss]`
+    date_time: String,
+    /// `+HH:MM`
+    zone: Option<String>,
+    source: &'static str,
+    zone_source: Option<&'static str>,
+}
+
+impl BestDate {
+    fn value(&self) -> String {
+        format!("{}{}", self.date_time, self.zone.as_deref().unwrap_or(""))
+    }
+}
+
+/// Non-empty string form of a tag value
+fn text(vals: &[TagValue], index: usize) -> Option<String> {
+    let text = match vals.get(index)? {
+        TagValue::String(s) => s.trim().to_string(),
+        TagValue::Empty | TagValue::Binary(_) => return None,
+        value => value.to_string(),
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+/// Split an ExifTool date into the date/time and a zone in minutes
+///
+/// Returns `None` for dates that don't parse (including all-zero dates).
+pub(crate) fn parse_date(date: &str) -> Option<(String, NaiveDateTime, Option<i32>)> {
+    let date = date.trim();
+    let (date_time, zone) = if let Some(date_time) = date.strip_suffix('Z') {
+        (date_time, Some(0))
+    } else {
+        match date.get(19..).and_then(|rest| rest.find(['+', '-'])) {
+            Some(pos) => {
+                let (date_time, zone) = date.split_at(19 + pos);
+                (date_time, Some(parse_offset(zone)?))
+            }
+            None => (date, None),
+        }
+    };
+    let naive = NaiveDateTime::parse_from_str(date_time.get(..19)?, "%Y:%m:%d %H:%M:%S").ok()?;
+    Some((date_time.to_string(), naive, zone))
+}
+
+/// Parse a `+HH:MM` offset into minutes
+fn parse_offset(offset: &str) -> Option<i32> {
+    let offset = offset.trim();
+    let (sign, rest) = match offset.as_bytes().first()? {
+        b'+' => (1, &offset[1..]),
+        b'-' => (-1, &offset[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = rest.split_once(':').unwrap_or((rest, "0"));
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    (hours <= 14 && minutes < 60).then_some(sign * (hours * 60 + minutes))
+}
+
+pub(crate) fn format_offset(minutes: i32) -> String {
+    let sign = if minutes < 0 { '-' } else { '+' };
+    let minutes = minutes.abs();
+    format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// GPS date and time stamps as a UTC date/time string and value
+fn gps_date_time(vals: &[TagValue]) -> Option<(String, NaiveDateTime)> {
+    let date_text = text(vals, GPS_DATE_STAMP)?;
+    let time_text = text(vals, GPS_TIME_STAMP)?;
+    let date = NaiveDate::parse_from_str(&date_text, "%Y:%m:%d").ok()?;
+    let time = NaiveTime::parse_from_str(&time_text, "%H:%M:%S%.f").ok()?;
+    Some((format!("{date_text} {time_text}"), date.and_time(time)))
+}
+
+/// Time zone of `local` from the GPS time stamp, if plausible
+fn gps_offset(local: NaiveDateTime, vals: &[TagValue]) -> Option<i32> {
+    let (_, utc) = gps_date_time(vals)?;
+    let minutes = (local - utc).num_minutes();
+    let rounded = ((minutes as f64 / 15.0).round() * 15.0) as i64;
+    (rounded.abs() <= MAX_GPS_OFFSET_MINUTES).then_some(rounded as i32)
+}
+
+/// Append sub-seconds unless the date already has them
+fn with_sub_seconds(date_time: &str, sub_sec: Option<String>) -> (String, bool) {
+    let digits = sub_sec.filter(|s| s.chars().all(|c| c.is_ascii_digit()));
+    match digits {
+        Some(digits) if date_time.len() == 19 => (format!("{date_time}.{digits}"), true),
+        _ => (date_time.to_string(), false),
+    }
+}
+
+/// Walk the precedence ladder (see the module documentation)
+fn select_best_date(vals: &[TagValue]) -> Option<BestDate> {
+    let exif_date = |rung: &ExifRung| {
+        let (date_time, local, zone) = parse_date(&text(vals, rung.date)?)?;
+        let (date_time, has_sub_sec) = with_sub_seconds(&date_time, text(vals, rung.sub_sec));
+        let source = if has_sub_sec {
+            rung.sub_sec_source
+        } else {
+            rung.source
+        };
+        let (zone, zone_source) = if zone.is_some() {
+            (zone, Some(source))
+        } else if let Some(zone) = text(vals, rung.offset).and_then(|s| parse_offset(&s)) {
+            (Some(zone), Some(rung.offset_source))
+        } else if let Some(zone) = text(vals, OFFSET_TIME).and_then(|s| parse_offset(&s)) {
+            (Some(zone), Some("EXIF:OffsetTime"))
+        } else {
+            let zone = gps_offset(local, vals);
+            (zone, zone.map(|_| GPS_ZONE_SOURCE))
+        };
+        Some(BestDate {
+            date_time,
+            zone: zone.map(format_offset),
+            source,
+            zone_source,
+        })
+    };
+    // Dates with an embedded zone (or none); `default_zone` for UTC sources
+    let plain_date = |index: usize, source: &'static str, default_zone: Option<i32>| {
+        let (date_time, local, zone) = parse_date(&text(vals, index)?)?;
+        let (zone, zone_source) = match zone.or(default_zone) {
+            Some(zone) => (Some(zone), Some(source)),
+            None => {
+                let zone = gps_offset(local, vals);
+                (zone, zone.map(|_| GPS_ZONE_SOURCE))
+            }
+        };
+        Some(BestDate {
+            date_time,
+            zone: zone.map(format_offset),
+            source,
+            zone_source,
+        })
+    };
+
+    exif_date(&DATE_TIME_ORIGINAL_RUNG)
+        .or_else(|| plain_date(XMP_DATE_TIME_ORIGINAL, "XMP:DateTimeOriginal", None))
+        .or_else(|| exif_date(&CREATE_DATE_RUNG))
+        .or_else(|| plain_date(QUICKTIME_CREATE_DATE, "QuickTime:CreateDate", Some(0)))
+        .or_else(|| plain_date(XMP_CREATE_DATE, "XMP:CreateDate", None))
+        .or_else(|| {
+            let (date_time, _) = gps_date_time(vals)?;
+            Some(BestDate {
+                date_time,
+                zone: Some(format_offset(0)),
+                source: "Composite:GPSDateTime",
+                zone_source: Some("Composite:GPSDateTime"),
+            })
+        })
+        .or_else(|| {
+            let (date_time, _, zone) = parse_date(&text(vals, FILE_MODIFY_DATE)?)?;
+            Some(BestDate {
+                date_time,
+                zone: zone.map(format_offset),
+                source: "File:FileModifyDate",
+                zone_source: zone.map(|_| "File:FileModifyDate"),
+            })
+        })
+}
+
+/// Whether a Composite:BestDateTimeZoneSource value is an offset recorded
+/// with the date (as opposed to derived or assumed)
+#[cfg(feature = "timezone")]
+pub(crate) fn is_recorded_zone(zone_source: &str) -> bool {
+    !DERIVED_ZONE_SOURCES.contains(&zone_source)
+}
+
+fn no_date() -> ExifError {
+    ExifError::ParseError("No date available for BestDateTime".to_string())
+}
+
+/// ValueConv for Composite:BestDateTime
+fn composite_best_date_time(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let best = select_best_date(vals).ok_or_else(no_date)?;
+    Ok(TagValue::String(best.value()))
+}
+
+/// ValueConv for Composite:BestDateTimeSource
+fn composite_best_date_time_source(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let best = select_best_date(vals).ok_or_else(no_date)?;
+    Ok(TagValue::string(best.source))
+}
+
+/// ValueConv for Composite:BestDateTimeZoneSource
+fn composite_best_date_time_zone_source(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let best = select_best_date(vals).ok_or_else(no_date)?;
+    let zone_source = best.zone_source.ok_or_else(|| {
+        ExifError::ParseError("No time zone available for BestDateTime".to_string())
+    })?;
+    Ok(TagValue::string(zone_source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composite_tags::{resolve_and_compute_composites, TagDependencyValues};
+    use std::collections::HashMap;
+
+    fn available(tags: &[(&str, TagValue)]) -> HashMap<String, TagDependencyValues> {
+        tags.iter()
+            .map(|(key, value)| {
+                (
+                    key.to_string(),
+                    TagDependencyValues {
+                        raw: value.clone(),
+                        val: value.clone(),
+                        prt: value.clone(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn best_date(tags: &[(&str, &str)]) -> HashMap<String, TagValue> {
+        let tags: Vec<(&str, TagValue)> = tags
+            .iter()
+            .map(|(key, value)| (*key, TagValue::string(*value)))
+            .collect();
+        let mut composites = resolve_and_compute_composites(available(&tags));
+        composites.retain(|key, _| key.starts_with("Composite:BestDateTime"));
+        composites
+    }
+
+    fn expected(tags: &[(&str, &str)]) -> HashMap<String, TagValue> {
+        tags.iter()
+            .map(|(name, value)| (format!("Composite:{name}"), TagValue::string(*value)))
+            .collect()
+    }
+
+    #[test]
+    fn test_date_time_original_with_sub_seconds_and_offset() {
+        assert_eq!(
+            best_date(&[
+                ("EXIF:DateTimeOriginal", "2024:05:06 07:08:09"),
+                ("EXIF:SubSecTimeOriginal", "123"),
+                ("EXIF:OffsetTimeOriginal", "+02:00"),
+                ("EXIF:CreateDate", "2024:05:06 07:08:10"),
+                ("File:FileModifyDate", "2024:06:01 00:00:00+00:00"),
+            ]),
+            expected(&[
+                ("BestDateTime", "2024:05:06 07:08:09.123+02:00"),
+                ("BestDateTimeSource", "Composite:SubSecDateTimeOriginal"),
+                ("BestDateTimeZoneSource", "EXIF:OffsetTimeOriginal"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_zone_from_gps_time_stamp() {
+        assert_eq!(
+            best_date(&[
+                ("EXIF:DateTimeOriginal", "2024:05:06 07:08:09"),
+                ("GPS:GPSDateStamp", "2024:05:06"),
+                ("GPS:GPSTimeStamp", "14:07:58"),
+            ]),
+            expected(&[
+                ("BestDateTime", "2024:05:06 07:08:09-07:00"),
+                ("BestDateTimeSource", "EXIF:DateTimeOriginal"),
+                ("BestDateTimeZoneSource", "GPS:GPSDateStamp+GPSTimeStamp"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_skips_zero_dates_and_falls_back() {
+        assert_eq!(
+            best_date(&[
+                ("EXIF:DateTimeOriginal", "0000:00:00 00:00:00"),
+                ("QuickTime:CreateDate", "2023:12:31 23:59:59"),
+                ("File:FileModifyDate", "2024:01:01 10:00:00-08:00"),
+            ]),
+            expected(&[
+                ("BestDateTime", "2023:12:31 23:59:59+00:00"),
+                ("BestDateTimeSource", "QuickTime:CreateDate"),
+                ("BestDateTimeZoneSource", "QuickTime:CreateDate"),
+            ])
+        );
+        assert_eq!(
+            best_date(&[("File:FileModifyDate", "2024:01:01 10:00:00-08:00")]),
+            expected(&[
+                ("BestDateTime", "2024:01:01 10:00:00-08:00"),
+                ("BestDateTimeSource", "File:FileModifyDate"),
+                ("BestDateTimeZoneSource", "File:FileModifyDate"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_no_zone_without_offset_or_gps() {
+        assert_eq!(
+            best_date(&[("EXIF:CreateDate", "2024:05:06 07:08:09")]),
+            expected(&[
+                ("BestDateTime", "2024:05:06 07:08:09"),
+                ("BestDateTimeSource", "EXIF:CreateDate"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let (date_time, _, zone) = parse_date("2024:05:06 07:08:09.5-03:30").unwrap();
+        assert_eq!(date_time, "2024:05:06 07:08:09.5");
+        assert_eq!(zone, Some(-210));
+        assert_eq!(parse_date("2024:05:06 07:08:09Z").unwrap().2, Some(0));
+        assert!(parse_date("    :  :     :  :  ").is_none());
+        assert_eq!(format_offset(-210), "-03:30");
+    }
+
+    #[cfg(feature = "timezone")]
+    #[test]
+    fn test_recorded_zones() {
+        assert!(is_recorded_zone("EXIF:OffsetTimeOriginal"));
+        assert!(is_recorded_zone("XMP:DateTimeOriginal"));
+        assert!(!is_recorded_zone(GPS_ZONE_SOURCE));
+        assert!(!is_recorded_zone("QuickTime:CreateDate"));
+    }
+}
@@ -0,0 +1,248 @@
+//! Bracketing and in-camera stacking composite
+//!
+//! Bracketed frames (exposure, flash, ISO, white balance, focus) are shot to
+//! be merged or picked from later, so catalogs want to group them. Each maker
+//! records the drive mode differently; Composite:BracketMode maps the first
+//! source that reports bracketing to one of a few normalized values:
+//!
+//! | Value          | Meaning                                         |
+//! |----------------|-------------------------------------------------|
+//! | `Exposure`     | auto exposure bracketing (AEB)                  |
+//! | `Flash`        | flash exposure bracketing                       |
+//! | `ISO`          | ISO bracketing                                  |
+//! | `WhiteBalance` | white balance bracketing                        |
+//! | `Focus`        | focus bracketing, or an in-camera focus stack   |
+//! | `DRO`          | Sony dynamic range optimizer bracketing         |
+//! | `HDR`          | in-camera HDR (several exposures merged)        |
+//!
+//! Olympus drive modes can combine several brackets; those are joined with
+//! `+` (e.g. `Exposure+WhiteBalance`). Files with bracketing off get no
+//! Composite:BracketMode.
+//!
+//! ## Sources
+//!
+//! | Source                     | Bracketing values                          |
+//! |----------------------------|--------------------------------------------|
+//! | `Canon:BracketMode`        | 1 AEB, 2 FEB, 3 ISO, 4 WB (FileInfo)       |
+//! | `Canon:AutoExposureBracketing` | non-zero (ShotInfo)                    |
+//! | `Canon:WBBracketMode`      | non-zero (FileInfo)                        |
+//! | `Canon:FocusBracketing`    | 1 (CameraSettings, FocusBracketingInfo)    |
+//! | `Canon:HDR`                | non-zero (HDRInfo)                         |
+//! | `Sony:ReleaseMode`         | 5 exposure, 6 WB, 8 DRO bracketing         |
+//! | `Sony:HDR`                 | first value non-zero                       |
+//! | `Olympus:DriveMode`        | see [`olympus_drive_mode`]                 |
+//! | `Olympus:StackedImage`     | 9 focus-stacked, 5/6 HDR1/HDR2             |
+//! | `Fujifilm:AutoBracketing`  | 1 On, 2 flash                              |
+//!
+//! `Olympus:FocusBracketStepSize` and `Fujifilm:WhiteBalanceBracketing` hold
+//! the configured bracket step rather than the drive mode, so they aren't
+//! used to detect bracketing.
+
+use crate::core::types::{ExifContext, ExifError, Result};
+use crate::core::TagValue;
+use crate::generated::composite_tags::CompositeTagDef;
+
+/// Bracketing sources, in priority order (decoded in [`bracket_mode`])
+const BRACKET_MODE_SOURCES: &[&str] = &[
+    "Canon:BracketMode",
+    "Canon:AutoExposureBracketing",
+    "Canon:WBBracketMode",
+    "Canon:FocusBracketing",
+    "Canon:HDR",
+    "Sony:ReleaseMode",
+    "Sony:HDR",
+    "Olympus:DriveMode",
+    "Olympus:StackedImage",
+    "Fujifilm:AutoBracketing",
+];
+
+/// Composite:BracketMode — normalized bracketing / stacking mode
+pub static COMPOSITE_BRACKET_MODE: CompositeTagDef = CompositeTagDef {
+    name: "BracketMode",
+    module: "Composite",
+    require: &[],
+    desire: BRACKET_MODE_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_bracket_mode),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Bracketing or in-camera stacking mode"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// The integers in a value ("5 0 1 0 4", arrays, or a single number)
+fn numbers(value: &TagValue) -> Vec<i64> {
+    match value {
+        TagValue::U8(v) => vec![*v as i64],
+        TagValue::U16(v) => vec![*v as i64],
+        TagValue::U32(v) => vec![*v as i64],
+        TagValue::I16(v) => vec![*v as i64],
+        TagValue::I32(v) => vec![*v as i64],
+        TagValue::F64(v) if v.fract() == 0.0 => vec![*v as i64],
+        TagValue::String(s) => s.split_whitespace().map_while(|n| n.parse().ok()).collect(),
+        TagValue::U8Array(v) => v.iter().map(|n| *n as i64).collect(),
+        TagValue::U16Array(v) => v.iter().map(|n| *n as i64).collect(),
+        TagValue::U32Array(v) => v.iter().map(|n| *n as i64).collect(),
+        TagValue::Array(v) => v.iter().flat_map(numbers).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Olympus CameraSettings DriveMode (Olympus.pm 0x600)
+///
+/// The first value is the mode; with 5 the third is a bit mask of the
+/// brackets in use (0 AE, 1 WB, 2 FL, 3 MF, 4 ISO, 5 AE Auto, 6 Focus). The
+/// 2/3/4 bracketing modes only apply to models without the sixth (E-M1 and
+/// later shooting mode) value.
+fn olympus_drive_mode(values: &[i64]) -> Option<String> {
+    let mode = *values.first()?;
+    if mode == 5 {
+        let bits = *values.get(2)?;
+        let mut modes: Vec<&str> = Vec::new();
+        for (bit, name) in [
+            (0, "Exposure"),
+            (1, "WhiteBalance"),
+            (2, "Flash"),
+            (3, "Focus"),
+            (4, "ISO"),
+            (5, "Exposure"),
+            (6, "Focus"),
+        ] {
+            if bits & (1 << bit) != 0 && !modes.contains(&name) {
+                modes.push(name);
+            }
+        }
+        return (!modes.is_empty()).then(|| modes.join("+"));
+    }
+    if values
+        .get(5)
+        .is_some_and(|&shooting_mode| shooting_mode != 0)
+    {
+        return None;
+    }
+    match mode {
+        2 => Some("Exposure".to_string()),
+        3 => Some("WhiteBalance".to_string()),
+        4 => Some("Exposure+WhiteBalance".to_string()),
+        _ => None,
+    }
+}
+
+/// The normalized mode one source reports, if it reports bracketing
+fn bracket_mode(source: &str, value: &TagValue) -> Option<String> {
+    let values = numbers(value);
+    let first = *values.first()?;
+    let mode = match (source, first) {
+        ("Olympus:DriveMode", _) => return olympus_drive_mode(&values),
+        ("Canon:BracketMode", 1) => "Exposure",
+        ("Canon:BracketMode", 2) => "Flash",
+        ("Canon:BracketMode", 3) => "ISO",
+        ("Canon:BracketMode", 4) => "WhiteBalance",
+        ("Canon:AutoExposureBracketing", 1..) | ("Canon:AutoExposureBracketing", -1) => "Exposure",
+        ("Canon:WBBracketMode", 1..) => "WhiteBalance",
+        ("Canon:FocusBracketing", 1) => "Focus",
+        ("Canon:HDR", 1..) | ("Sony:HDR", 1..) => "HDR",
+        ("Sony:ReleaseMode", 5) => "Exposure",
+        ("Sony:ReleaseMode", 6) => "WhiteBalance",
+        ("Sony:ReleaseMode", 8) => "DRO",
+        ("Olympus:StackedImage", 9) => "Focus",
+        ("Olympus:StackedImage", 5 | 6) => "HDR",
+        ("Fujifilm:AutoBracketing", 1) => "Exposure",
+        ("Fujifilm:AutoBracketing", 2) => "Flash",
+        _ => return None,
+    };
+    Some(mode.to_string())
+}
+
+/// ValueConv for Composite:BracketMode
+fn composite_bracket_mode(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    vals.iter()
+        .zip(BRACKET_MODE_SOURCES)
+        .find_map(|(value, source)| bracket_mode(source, value))
+        .map(TagValue::String)
+        .ok_or_else(|| ExifError::ParseError("No bracketing mode".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composite_tags::{resolve_and_compute_composites, TagDependencyValues};
+    use std::collections::HashMap;
+
+    fn available(tags: &[(&str, TagValue)]) -> HashMap<String, TagDependencyValues> {
+        tags.iter()
+            .map(|(key, value)| {
+                (
+                    key.to_string(),
+                    TagDependencyValues {
+                        raw: value.clone(),
+                        val: value.clone(),
+                        prt: value.clone(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn mode(tags: &[(&str, TagValue)]) -> Option<TagValue> {
+        resolve_and_compute_composites(available(tags))
+            .get("Composite:BracketMode")
+            .cloned()
+    }
+
+    #[test]
+    fn test_maker_bracket_modes() {
+        assert_eq!(
+            mode(&[
+                ("Canon:BracketMode", TagValue::I16(0)),
+                ("Canon:FocusBracketing", TagValue::I16(1)),
+            ]),
+            Some(TagValue::string("Focus"))
+        );
+        assert_eq!(
+            mode(&[("Sony:ReleaseMode", TagValue::U16(8))]),
+            Some(TagValue::string("DRO"))
+        );
+        assert_eq!(
+            mode(&[("Sony:HDR", TagValue::string("16 1"))]),
+            Some(TagValue::string("HDR"))
+        );
+        assert_eq!(
+            mode(&[("Fujifilm:AutoBracketing", TagValue::U16(2))]),
+            Some(TagValue::string("Flash"))
+        );
+        // Bracketing off
+        assert_eq!(
+            mode(&[
+                ("Canon:BracketMode", TagValue::I16(0)),
+                ("Sony:ReleaseMode", TagValue::U16(65535)),
+            ]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_olympus_drive_mode() {
+        assert_eq!(
+            olympus_drive_mode(&[5, 1, 0b11, 0, 4]).as_deref(),
+            Some("Exposure+WhiteBalance")
+        );
+        assert_eq!(
+            olympus_drive_mode(&[5, 3, 0b100_0000, 0, 4]).as_deref(),
+            Some("Focus")
+        );
+        assert_eq!(olympus_drive_mode(&[2, 1]).as_deref(), Some("Exposure"));
+        // Newer models: the sixth value is the shooting mode instead
+        assert_eq!(olympus_drive_mode(&[2, 0, 0, 0, 4, 0x22]), None);
+        assert_eq!(
+            mode(&[("Olympus:StackedImage", TagValue::string("9 8"))]),
+            Some(TagValue::string("Focus"))
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! Burst sequence composites
+//!
+//! Cameras mark frames shot in continuous drive in different ways: iPhones
+//! and Pixels write a UUID shared by the whole burst, most other makers a
+//! frame number within the sequence. These hand-written composites expose
+//! both under one name each; `crate::burst` uses them (with the capture time)
+//! to group files into bursts.
+//!
+//! ## Composite:BurstID
+//!
+//! `Apple:BurstUUID` (Apple.pm tag 0x000b), then `XMP:BurstID` (Google
+//! Camera's `GCamera:BurstID`).
+//!
+//! ## Composite:BurstIndex
+//!
+//! Frame number within the sequence, from the first of `Sony:SequenceImageNumber`,
+//! `Sony:SequenceNumber`, `Canon:SequenceNumber`, `Fujifilm:SequenceNumber`,
+//! `Panasonic:SequenceNumber`, `Casio:SequenceNumber` and `Sigma:BurstShot`
+//! with a positive value. All of these write 0 for single shots; Sony's
+//! 65535 ("n/a") is skipped as well.
+
+use crate::core::types::{ExifContext, ExifError, Result};
+use crate::core::TagValue;
+use crate::generated::composite_tags::CompositeTagDef;
+
+/// Burst identifier sources, in priority order
+const BURST_ID_SOURCES: &[&str] = &["Apple:BurstUUID", "XMP:BurstID"];
+
+/// Frame number sources, in priority order
+const BURST_INDEX_SOURCES: &[&str] = &[
+    "Sony:SequenceImageNumber",
+    "Sony:SequenceNumber",
+    "Canon:SequenceNumber",
+    "Fujifilm:SequenceNumber",
+    "Panasonic:SequenceNumber",
+    "Casio:SequenceNumber",
+    "Sigma:BurstShot",
+];
+
+/// Sony's "n/a" SequenceNumber
+const SONY_NOT_APPLICABLE: u32 = 65535;
+
+/// Composite:BurstID — identifier shared by the frames of a burst
+pub static COMPOSITE_BURST_ID: CompositeTagDef = CompositeTagDef {
+    name: "BurstID",
+    module: "Composite",
+    require: &[],
+    desire: BURST_ID_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_burst_id),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Burst identifier written by the camera"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Image")],
+};
+
+/// Composite:BurstIndex — frame number within a continuous-drive sequence
+pub static COMPOSITE_BURST_INDEX: CompositeTagDef = CompositeTagDef {
+    name: "BurstIndex",
+    module: "Composite",
+    require: &[],
+    desire: BURST_INDEX_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_burst_index),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Frame number within a burst sequence"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Image")],
+};
+
+/// Interpret a sequence tag as a frame number (positive, not "n/a")
+fn frame_number(value: &TagValue) -> Option<u32> {
+    let number = match value {
+        TagValue::U8(v) => *v as u32,
+        TagValue::U16(v) => *v as u32,
+        TagValue::U32(v) => *v,
+        TagValue::I16(v) => u32::try_from(*v).ok()?,
+        TagValue::I32(v) => u32::try_from(*v).ok()?,
+        TagValue::F64(v) if *v >= 0.0 && v.fract() == 0.0 => *v as u32,
+        TagValue::String(s) => s.trim().parse().ok()?,
+        _ => return None,
+    };
+    (number > 0 && number != SONY_NOT_APPLICABLE).then_some(number)
+}
+
+/// ValueConv for Composite:BurstID: the first non-empty identifier
+fn composite_burst_id(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    vals.iter()
+        .find_map(|v| match v.as_string() {
+            Some(s) if !s.trim().is_empty() => Some(TagValue::string(s.trim())),
+            _ => None,
+        })
+        .ok_or_else(|| ExifError::ParseError("No BurstID available".to_string()))
+}
+
+/// ValueConv for Composite:BurstIndex
+fn composite_burst_index(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    vals.iter()
+        .find_map(frame_number)
+        .map(TagValue::U32)
+        .ok_or_else(|| ExifError::ParseError("No BurstIndex available".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composite_tags::{resolve_and_compute_composites, TagDependencyValues};
+    use std::collections::HashMap;
+
+    fn available(tags: &[(&str, TagValue)]) -> HashMap<String, TagDependencyValues> {
+        tags.iter()
+            .map(|(key, value)| {
+                (
+                    key.to_string(),
+                    TagDependencyValues {
+                        raw: value.clone(),
+                        val: value.clone(),
+                        prt: value.clone(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_burst_id() {
+        let composites = resolve_and_compute_composites(available(&[(
+            "Apple:BurstUUID",
+            TagValue::string(" 0F1E2D3C-4B5A-6978-8796-A5B4C3D2E1F0 "),
+        )]));
+        assert_eq!(
+            composites.get("Composite:BurstID"),
+            Some(&TagValue::string("0F1E2D3C-4B5A-6978-8796-A5B4C3D2E1F0"))
+        );
+    }
+
+    #[test]
+    fn test_burst_index_skips_single_shots() {
+        let composites = resolve_and_compute_composites(available(&[
+            ("Sony:SequenceNumber", TagValue::U32(SONY_NOT_APPLICABLE)),
+            ("Canon:SequenceNumber", TagValue::U16(3)),
+        ]));
+        assert_eq!(
+            composites.get("Composite:BurstIndex"),
+            Some(&TagValue::U32(3))
+        );
+
+        let composites =
+            resolve_and_compute_composites(available(&[("Sony:SequenceNumber", TagValue::U32(0))]));
+        assert_eq!(composites.get("Composite:BurstIndex"), None);
+    }
+}
@@ -0,0 +1,398 @@
+//! Normalized shutter count and body serial number composites
+//!
+//! Every maker stores these in a different place (and often several), so
+//! fleet-management tools would otherwise have to know five tag names per
+//! field. ExifTool has no composite for either; these hand-written composites
+//! pick the first available source in a fixed priority order and expose it as
+//! a single tag, plus a `*Source` companion naming the tag that was used.
+//!
+//! ## Composite:ShutterCount
+//!
+//! | Priority | Source                                   | Notes                              |
+//! |----------|------------------------------------------|------------------------------------|
+//! | 1        | `Nikon:ShutterCount`                     | Nikon 0x00a7 / ShotInfo            |
+//! | 2        | `Canon:ShutterCount`                     | FileInfo / CameraInfo (1D, R6...)  |
+//! | 3        | `Sony:ShutterCount`                      | Tag9050, MoreInfo0201              |
+//! | 4        | `Pentax:ShutterCount`                    | Pentax 0x005d                      |
+//! | 5        | `Sony:ImageCount`                        | images recorded, not actuations    |
+//! | 6        | `Canon:ImageCount`                       | R6 Mark III CameraInfo             |
+//! | 7        | `Nikon:ImageCount`                       | Nikon 0x00a5                       |
+//! | 8        | `Canon:DirectoryIndex` + `Canon:FileIndex` | estimate, see below              |
+//! | 9        | `Canon:FileNumber`                       | estimate, see below                |
+//!
+//! Canon bodies without a true counter only record the file number. With
+//! continuous file numbering the camera moves to the next folder after 9999
+//! files, so the estimate is `(DirectoryIndex - 100) * 9999 + FileIndex`.
+//! Older bodies pack both into FileNumber as `DirectoryIndex * 10000 + FileIndex`
+//! (ExifTool Canon.pm Composite:FileNumber `sprintf("%.3d%.4d",@val)`). The
+//! estimate is only meaningful if numbering was never reset; the Source tag
+//! says `(estimated)` so callers can tell.
+//!
+//! ## Composite:SerialNumber
+//!
+//! Body serial numbers first, then the maker-internal ones (which are
+//! different numbers on Canon and Sony bodies, but stable per body):
+//! `EXIF:SerialNumber`, `EXIF:CameraSerialNumber`, `Nikon:SerialNumber`,
+//! `Canon:SerialNumber`, `Sony:SerialNumber`, `Pentax:SerialNumber`,
+//! `Olympus:SerialNumber`, `Panasonic:InternalSerialNumber`,
+//! `Fujifilm:InternalSerialNumber`, `Canon:InternalSerialNumber`,
+//! `Sony:InternalSerialNumber`, `Olympus:InternalSerialNumber`,
+//! `XMP:SerialNumber`.
+//!
+//! Values are trimmed of whitespace and NUL padding; numeric serials are
+//! written in plain decimal (Canon's `%.10d` PrintConv padding is dropped).
+//! Empty and all-zero values are skipped.
+
+use crate::core::types::{ExifContext, ExifError, Result};
+use crate::core::TagValue;
+use crate::generated::composite_tags::CompositeTagDef;
+
+/// Tags that hold an actual shutter/image count, in priority order
+const SHUTTER_COUNT_DIRECT: &[&str] = &[
+    "Nikon:ShutterCount",
+    "Canon:ShutterCount",
+    "Sony:ShutterCount",
+    "Pentax:ShutterCount",
+    "Sony:ImageCount",
+    "Canon:ImageCount",
+    "Nikon:ImageCount",
+];
+
+/// Desire list for the ShutterCount composites: the direct sources followed by
+/// the Canon file-number inputs (indices must match [`select_shutter_count`])
+const SHUTTER_COUNT_SOURCES: &[&str] = &[
+    "Nikon:ShutterCount",
+    "Canon:ShutterCount",
+    "Sony:ShutterCount",
+    "Pentax:ShutterCount",
+    "Sony:ImageCount",
+    "Canon:ImageCount",
+    "Nikon:ImageCount",
+    "Canon:DirectoryIndex",
+    "Canon:FileIndex",
+    "Canon:FileNumber",
+];
+
+/// Serial number sources, in priority order
+const SERIAL_NUMBER_SOURCES: &[&str] = &[
+    "EXIF:SerialNumber",
+    "EXIF:CameraSerialNumber",
+    "Nikon:SerialNumber",
+    "Canon:SerialNumber",
+    "Sony:SerialNumber",
+    "Pentax:SerialNumber",
+    "Olympus:SerialNumber",
+    "Panasonic:InternalSerialNumber",
+    "Fujifilm:InternalSerialNumber",
+    "Canon:InternalSerialNumber",
+    "Sony:InternalSerialNumber",
+    "Olympus:InternalSerialNumber",
+    "XMP:SerialNumber",
+];
+
+/// Files per Canon folder before continuous numbering moves to the next one
+const CANON_FILES_PER_DIRECTORY: u64 = 9999;
+
+/// First Canon folder number (100CANON)
+const CANON_FIRST_DIRECTORY: u64 = 100;
+
+/// Composite:ShutterCount — normalized shutter actuation count
+pub static COMPOSITE_SHUTTER_COUNT: CompositeTagDef = CompositeTagDef {
+    name: "ShutterCount",
+    module: "Composite",
+    require: &[],
+    desire: SHUTTER_COUNT_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_shutter_count),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Shutter count from the best available maker-specific source"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:ShutterCountSource — which tag Composite:ShutterCount came from
+pub static COMPOSITE_SHUTTER_COUNT_SOURCE: CompositeTagDef = CompositeTagDef {
+    name: "ShutterCountSource",
+    module: "Composite",
+    require: &[],
+    desire: SHUTTER_COUNT_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_shutter_count_source),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Tag used for Composite:ShutterCount"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:SerialNumber — normalized camera body serial number
+pub static COMPOSITE_SERIAL_NUMBER: CompositeTagDef = CompositeTagDef {
+    name: "SerialNumber",
+    module: "Composite",
+    require: &[],
+    desire: SERIAL_NUMBER_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_serial_number),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Camera serial number from the best available source"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:SerialNumberSource — which tag Composite:SerialNumber came from
+pub static COMPOSITE_SERIAL_NUMBER_SOURCE: CompositeTagDef = CompositeTagDef {
+    name: "SerialNumberSource",
+    module: "Composite",
+    require: &[],
+    desire: SERIAL_NUMBER_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_serial_number_source),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Tag used for Composite:SerialNumber"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Interpret a count tag as a positive integer
+fn count_value(value: &TagValue) -> Option<u64> {
+    let count = match value {
+        TagValue::U8(v) => *v as u64,
+        TagValue::U16(v) => *v as u64,
+        TagValue::U32(v) => *v as u64,
+        TagValue::U64(v) => *v,
+        TagValue::I16(v) => u64::try_from(*v).ok()?,
+        TagValue::I32(v) => u64::try_from(*v).ok()?,
+        TagValue::F64(v) if *v >= 0.0 && v.fract() == 0.0 => *v as u64,
+        TagValue::String(s) => s.trim().parse().ok()?,
+        _ => return None,
+    };
+    (count > 0).then_some(count)
+}
+
+/// Pick the shutter count and describe its source
+///
+/// `vals` follows [`SHUTTER_COUNT_SOURCES`]: the direct sources first, then
+/// DirectoryIndex, FileIndex and FileNumber.
+fn select_shutter_count(vals: &[TagValue]) -> Option<(u64, String)> {
+    let direct = SHUTTER_COUNT_DIRECT.len();
+    for (value, source) in vals.iter().zip(SHUTTER_COUNT_DIRECT) {
+        if let Some(count) = count_value(value) {
+            return Some((count, (*source).to_string()));
+        }
+    }
+
+    let canon = vals.get(direct..)?;
+    let directory = canon.first().and_then(count_value);
+    let file = canon.get(1).and_then(count_value);
+    if let (Some(directory), Some(file)) = (directory, file) {
+        if directory >= CANON_FIRST_DIRECTORY {
+            let count = (directory - CANON_FIRST_DIRECTORY) * CANON_FILES_PER_DIRECTORY + file;
+            return Some((
+                count,
+                "Canon:DirectoryIndex+FileIndex (estimated)".to_string(),
+            ));
+        }
+    }
+
+    let file_number = canon.get(2).and_then(count_value)?;
+    let directory = file_number / 10000;
+    let file = file_number % 10000;
+    (directory >= CANON_FIRST_DIRECTORY && file > 0).then(|| {
+        (
+            (directory - CANON_FIRST_DIRECTORY) * CANON_FILES_PER_DIRECTORY + file,
+            "Canon:FileNumber (estimated)".to_string(),
+        )
+    })
+}
+
+/// Normalize a serial number value, skipping empty and all-zero values
+fn serial_value(value: &TagValue) -> Option<String> {
+    let serial = match value {
+        TagValue::String(s) => s
+            .trim_matches(|c: char| c == '\0' || c.is_whitespace())
+            .to_string(),
+        TagValue::U8(_)
+        | TagValue::U16(_)
+        | TagValue::U32(_)
+        | TagValue::U64(_)
+        | TagValue::I16(_)
+        | TagValue::I32(_) => value.to_string(),
+        _ => return None,
+    };
+    (!serial.is_empty() && !serial.chars().all(|c| c == '0')).then_some(serial)
+}
+
+/// Pick the serial number and its source
+fn select_serial_number(vals: &[TagValue]) -> Option<(String, &'static str)> {
+    vals.iter()
+        .zip(SERIAL_NUMBER_SOURCES)
+        .find_map(|(value, source)| serial_value(value).map(|serial| (serial, *source)))
+}
+
+fn no_source(name: &str) -> ExifError {
+    ExifError::ParseError(format!("No source available for {name}"))
+}
+
+/// ValueConv for Composite:ShutterCount
+fn composite_shutter_count(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let (count, _) = select_shutter_count(vals).ok_or_else(|| no_source("ShutterCount"))?;
+    Ok(u32::try_from(count)
+        .map(TagValue::U32)
+        .unwrap_or(TagValue::U64(count)))
+}
+
+/// ValueConv for Composite:ShutterCountSource
+fn composite_shutter_count_source(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let (_, source) = select_shutter_count(vals).ok_or_else(|| no_source("ShutterCount"))?;
+    Ok(TagValue::String(source))
+}
+
+/// ValueConv for Composite:SerialNumber
+fn composite_serial_number(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let (serial, _) = select_serial_number(vals).ok_or_else(|| no_source("SerialNumber"))?;
+    Ok(TagValue::String(serial))
+}
+
+/// ValueConv for Composite:SerialNumberSource
+fn composite_serial_number_source(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let (_, source) = select_serial_number(vals).ok_or_else(|| no_source("SerialNumber"))?;
+    Ok(TagValue::string(source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composite_tags::{resolve_and_compute_composites, TagDependencyValues};
+    use std::collections::HashMap;
+
+    fn available(tags: &[(&str, TagValue)]) -> HashMap<String, TagDependencyValues> {
+        tags.iter()
+            .map(|(key, value)| {
+                (
+                    key.to_string(),
+                    TagDependencyValues {
+                        raw: value.clone(),
+                        val: value.clone(),
+                        prt: value.clone(),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sources_start_with_direct_sources() {
+        assert_eq!(
+            &SHUTTER_COUNT_SOURCES[..SHUTTER_COUNT_DIRECT.len()],
+            SHUTTER_COUNT_DIRECT
+        );
+    }
+
+    #[test]
+    fn test_direct_shutter_count_priority() {
+        let composites = resolve_and_compute_composites(available(&[
+            ("Sony:ImageCount", TagValue::U32(900)),
+            ("Sony:ShutterCount", TagValue::U32(1234)),
+        ]));
+        assert_eq!(
+            composites.get("Composite:ShutterCount"),
+            Some(&TagValue::U32(1234))
+        );
+        assert_eq!(
+            composites.get("Composite:ShutterCountSource"),
+            Some(&TagValue::string("Sony:ShutterCount"))
+        );
+    }
+
+    #[test]
+    fn test_canon_file_index_estimate() {
+        let composites = resolve_and_compute_composites(available(&[
+            ("Canon:DirectoryIndex", TagValue::U32(102)),
+            ("Canon:FileIndex", TagValue::U32(42)),
+        ]));
+        assert_eq!(
+            composites.get("Composite:ShutterCount"),
+            Some(&TagValue::U32(2 * 9999 + 42))
+        );
+        assert_eq!(
+            composites.get("Composite:ShutterCountSource"),
+            Some(&TagValue::string(
+                "Canon:DirectoryIndex+FileIndex (estimated)"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_canon_file_number_estimate() {
+        let vals = [
+            vec![TagValue::Empty; SHUTTER_COUNT_DIRECT.len() + 2],
+            vec![TagValue::U32(100_1234)],
+        ]
+        .concat();
+        assert_eq!(
+            select_shutter_count(&vals),
+            Some((1234, "Canon:FileNumber (estimated)".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_zero_count_skipped() {
+        let vals = [TagValue::U32(0), TagValue::U32(77)];
+        assert_eq!(
+            select_shutter_count(&vals),
+            Some((77, "Canon:ShutterCount".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_serial_number_normalization() {
+        let composites = resolve_and_compute_composites(available(&[
+            ("Canon:SerialNumber", TagValue::U32(12345678)),
+            ("Canon:InternalSerialNumber", TagValue::string("XA1234567")),
+        ]));
+        assert_eq!(
+            composites.get("Composite:SerialNumber"),
+            Some(&TagValue::string("12345678"))
+        );
+        assert_eq!(
+            composites.get("Composite:SerialNumberSource"),
+            Some(&TagValue::string("Canon:SerialNumber"))
+        );
+    }
+
+    #[test]
+    fn test_serial_number_skips_blank_values() {
+        let vals = [
+            TagValue::string("   "),
+            TagValue::string("0000000"),
+            TagValue::string("3012345\0\0"),
+        ];
+        assert_eq!(
+            select_serial_number(&vals),
+            Some(("3012345".to_string(), "Nikon:SerialNumber"))
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Color space composite
+//!
+//! The color space of an image is recorded in several places that often
+//! disagree: EXIF ColorSpace can only say sRGB or "Uncalibrated", Adobe RGB
+//! cameras signal it through the Interoperability index instead, and an
+//! embedded ICC profile or PNG color chunk overrides both. ExifTool reports
+//! each one in its own group; this hand-written composite reconciles them.
+//!
+//! ## Composite:ColorSpace
+//!
+//! | Rung | Source                                          | Value                      |
+//! |------|-------------------------------------------------|----------------------------|
+//! | 1    | `ICC_Profile:ProfileDescription`                | normalized profile name    |
+//! | 2    | `PNG:SRGBRendering` (sRGB chunk)                | `sRGB`                     |
+//! | 3    | `EXIF:ColorSpace` 1 or 2                        | `sRGB`, `Adobe RGB`        |
+//! | 4    | `EXIF:InteropIndex` R98 or R03                  | `sRGB`, `Adobe RGB`        |
+//! | 5    | `PNG:Gamma` of 2.2                              | `sRGB`                     |
+//! | 6    | `EXIF:ColorSpace` 0xFFFF                        | `Uncalibrated`             |
+//!
+//! Well-known profile descriptions become `sRGB`, `Adobe RGB`, `Display P3`,
+//! `ProPhoto RGB` or `Rec. 2020`; others are reported as written.
+//!
+//! `Composite:ColorSpaceSource` is the tag used, e.g. `EXIF:ColorSpace` or
+//! `EXIF:InteropIndex`.
+
+use crate::core::types::{ExifContext, ExifError, Result};
+use crate::core::TagValue;
+use crate::generated::composite_tags::CompositeTagDef;
+
+/// Desire list of the ColorSpace composites (indices below)
+const COLOR_SPACE_SOURCES: &[&str] = &[
+    "ICC_Profile:ProfileDescription",
+    "PNG:SRGBRendering",
+    "EXIF:ColorSpace",
+    "EXIF:InteropIndex",
+    "PNG:Gamma",
+];
+
+const PROFILE_DESCRIPTION: usize = 0;
+const SRGB_RENDERING: usize = 1;
+const EXIF_COLOR_SPACE: usize = 2;
+const INTEROP_INDEX: usize = 3;
+const PNG_GAMMA: usize = 4;
+
+/// Composite:ColorSpace — the image color space, reconciled across groups
+pub static COMPOSITE_COLOR_SPACE: CompositeTagDef = CompositeTagDef {
+    name: "ColorSpace",
+    module: "Composite",
+    require: &[],
+    desire: COLOR_SPACE_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_color_space),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Color space from ICC profile, PNG chunks, EXIF ColorSpace or InteropIndex"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Image")],
+};
+
+/// Composite:ColorSpaceSource — the tag Composite:ColorSpace came from
+pub static COMPOSITE_COLOR_SPACE_SOURCE: CompositeTagDef = CompositeTagDef {
+    name: "ColorSpaceSource",
+    module: "Composite",
+    require: &[],
+    desire: COLOR_SPACE_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_color_space_source),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Tag used for Composite:ColorSpace"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Image")],
+};
+
+/// Trimmed, non-empty text of a desired value
+fn text(vals: &[TagValue], index: usize) -> Option<String> {
+    let text = match vals.get(index)? {
+        TagValue::String(s) => s.trim().to_string(),
+        TagValue::Empty | TagValue::Binary(_) => return None,
+        value => value.to_string(),
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+/// Common name for an ICC profile description
+pub(crate) fn normalize_profile(description: &str) -> String {
+    let lower = description.to_ascii_lowercase();
+    let name = if lower.contains("srgb") || lower.contains("61966-2") {
+        "sRGB"
+    } else if lower.contains("adobe rgb") || lower.contains("adobergb") {
+        "Adobe RGB"
+    } else if lower.contains("display p3") {
+        "Display P3"
+    } else if lower.contains("prophoto") || lower.contains("romm") {
+        "ProPhoto RGB"
+    } else if lower.contains("2020") {
+        "Rec. 2020"
+    } else {
+        return description.to_string();
+    };
+    name.to_string()
+}
+
+/// EXIF ColorSpace, as the raw number or its PrintConv
+///
+/// ExifTool: Exif.pm ColorSpace PrintConv (1 sRGB, 2 Adobe RGB, 0xffff Uncalibrated)
+fn exif_color_space(vals: &[TagValue]) -> Option<u16> {
+    let value = vals.get(EXIF_COLOR_SPACE)?;
+    if let Some(number) = value.as_u16() {
+        return Some(number);
+    }
+    match text(vals, EXIF_COLOR_SPACE)?.as_str() {
+        "sRGB" => Some(1),
+        "Adobe RGB" => Some(2),
+        "Uncalibrated" => Some(0xffff),
+        other => other.parse().ok(),
+    }
+}
+
+/// DCF Interoperability index: R98 is sRGB, R03 is the Adobe RGB option file
+fn interop_color_space(vals: &[TagValue]) -> Option<&'static str> {
+    let index = text(vals, INTEROP_INDEX)?;
+    if index.starts_with("R98") {
+        Some("sRGB")
+    } else if index.starts_with("R03") {
+        Some("Adobe RGB")
+    } else {
+        None
+    }
+}
+
+/// Walk the precedence ladder, returning the value and its source tag
+fn select_color_space(vals: &[TagValue]) -> Option<(String, &'static str)> {
+    if let Some(description) = text(vals, PROFILE_DESCRIPTION) {
+        return Some((
+            normalize_profile(&description),
+            COLOR_SPACE_SOURCES[PROFILE_DESCRIPTION],
+        ));
+    }
+    if text(vals, SRGB_RENDERING).is_some() {
+        return Some(("sRGB".to_string(), COLOR_SPACE_SOURCES[SRGB_RENDERING]));
+    }
+    let exif = exif_color_space(vals);
+    match exif {
+        Some(1) => return Some(("sRGB".to_string(), COLOR_SPACE_SOURCES[EXIF_COLOR_SPACE])),
+        Some(2) => {
+            return Some((
+                "Adobe RGB".to_string(),
+                COLOR_SPACE_SOURCES[EXIF_COLOR_SPACE],
+            ))
+        }
+        _ => {}
+    }
+    if let Some(space) = interop_color_space(vals) {
+        return Some((space.to_string(), COLOR_SPACE_SOURCES[INTEROP_INDEX]));
+    }
+    let gamma = vals.get(PNG_GAMMA).and_then(TagValue::as_f64);
+    if gamma.is_some_and(|gamma| (gamma - 2.2).abs() < 0.05) {
+        return Some(("sRGB".to_string(), COLOR_SPACE_SOURCES[PNG_GAMMA]));
+    }
+    exif.map(|_| {
+        (
+            "Uncalibrated".to_string(),
+            COLOR_SPACE_SOURCES[EXIF_COLOR_SPACE],
+        )
+    })
+}
+
+fn no_color_space() -> ExifError {
+    ExifError::ParseError("No color space information available".to_string())
+}
+
+/// ValueConv for Composite:ColorSpace
+fn composite_color_space(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let (space, _) = select_color_space(vals).ok_or_else(no_color_space)?;
+    Ok(TagValue::string(space))
+}
+
+/// ValueConv for Composite:ColorSpaceSource
+fn composite_color_space_source(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let (_, source) = select_color_space(vals).ok_or_else(no_color_space)?;
+    Ok(TagValue::string(source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composite_tags::{resolve_and_compute_composites, TagDependencyValues};
+    use std::collections::HashMap;
+
+    fn color_space(tags: &[(&str, TagValue)]) -> Option<(TagValue, TagValue)> {
+        let available = tags
+            .iter()
+            .map(|(key, value)| {
+                let values = TagDependencyValues {
+                    raw: value.clone(),
+                    val: value.clone(),
+                    prt: value.clone(),
+                };
+                (key.to_string(), values)
+            })
+            .collect::<HashMap<_, _>>();
+        let composites = resolve_and_compute_composites(available);
+        Some((
+            composites.get("Composite:ColorSpace")?.clone(),
+            composites.get("Composite:ColorSpaceSource")?.clone(),
+        ))
+    }
+
+    fn expect(space: &str, source: &str) -> Option<(TagValue, TagValue)> {
+        Some((TagValue::string(space), TagValue::string(source)))
+    }
+
+    #[test]
+    fn test_color_space_precedence() {
+        assert_eq!(
+            color_space(&[("EXIF:ColorSpace", TagValue::U16(1))]),
+            expect("sRGB", "EXIF:ColorSpace")
+        );
+        // Adobe RGB cameras write Uncalibrated plus the R03 option file index
+        assert_eq!(
+            color_space(&[
+                ("EXIF:ColorSpace", TagValue::U16(0xffff)),
+                (
+                    "EXIF:InteropIndex",
+                    TagValue::string("R03 - DCF option file (Adobe RGB)")
+                ),
+            ]),
+            expect("Adobe RGB", "EXIF:InteropIndex")
+        );
+        assert_eq!(
+            color_space(&[("EXIF:ColorSpace", TagValue::string("Uncalibrated"))]),
+            expect("Uncalibrated", "EXIF:ColorSpace")
+        );
+        // An embedded profile wins over EXIF
+        assert_eq!(
+            color_space(&[
+                ("EXIF:ColorSpace", TagValue::U16(1)),
+                (
+                    "ICC_Profile:ProfileDescription",
+                    TagValue::string("Display P3")
+                ),
+            ]),
+            expect("Display P3", "ICC_Profile:ProfileDescription")
+        );
+        assert_eq!(
+            color_space(&[("PNG:Gamma", TagValue::F64(2.2))]),
+            expect("sRGB", "PNG:Gamma")
+        );
+        assert_eq!(color_space(&[("PNG:Gamma", TagValue::F64(1.0))]), None);
+    }
+
+    #[test]
+    fn test_normalize_profile() {
+        assert_eq!(normalize_profile("sRGB IEC61966-2.1"), "sRGB");
+        assert_eq!(normalize_profile("Adobe RGB (1998)"), "Adobe RGB");
+        assert_eq!(normalize_profile("ProPhoto RGB"), "ProPhoto RGB");
+        assert_eq!(normalize_profile("ITU-R BT.2020"), "Rec. 2020");
+        assert_eq!(normalize_profile("Custom Monitor"), "Custom Monitor");
+    }
+}
@@ -0,0 +1,202 @@
+//! Public view of the composite dependency graph
+//!
+//! Composite tags are computed from tags that were already extracted, so
+//! computing `Composite:ImageSize` needs ImageWidth and ImageHeight even when
+//! only the composite is wanted. [`crate::formats::extract_metadata`] handles
+//! this itself (see
+//! [`FilterOptions::with_composite_sources`](crate::types::FilterOptions::with_composite_sources));
+//! these functions expose which source tags each composite reads for callers
+//! that build their own filters or pipelines.
+
+use std::collections::HashSet;
+
+use super::all_composite_defs;
+use crate::generated::composite_tags::CompositeTagDef;
+
+/// Source tags read by one composite definition
+///
+/// Names are as ExifTool declares them: usually bare tag names, sometimes
+/// group-qualified (`"QuickTime:GPSCoordinates"`, `"Composite:LensID"`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompositeDependencies {
+    /// Composite tag name, without the `Composite:` group
+    pub name: &'static str,
+    /// Module that defines it. Several modules can define composites with the
+    /// same name (e.g. Canon and Samsung WB_RGGBLevels)
+    pub module: &'static str,
+    /// Tags that must all be present (`$val[0..]` in ExifTool's Require)
+    pub require: &'static [&'static str],
+    /// Tags used when present (ExifTool's Desire)
+    pub desire: &'static [&'static str],
+    /// Tags whose presence suppresses the composite (ExifTool's Inhibit)
+    pub inhibit: &'static [&'static str],
+}
+
+impl From<&CompositeTagDef> for CompositeDependencies {
+    fn from(def: &CompositeTagDef) -> Self {
+        Self {
+            name: def.name,
+            module: def.module,
+            require: def.require,
+            desire: def.desire,
+            inhibit: def.inhibit,
+        }
+    }
+}
+
+/// Dependencies of every definition of the named composite
+///
+/// `name` is case-insensitive and may carry the `Composite:` group. Returns an
+/// empty vector for names that aren't composites.
+pub fn composite_dependencies(name: &str) -> Vec<CompositeDependencies> {
+    let name = strip_group(name);
+    all_composite_defs()
+        .filter(|def| def.name.eq_ignore_ascii_case(name))
+        .map(CompositeDependencies::from)
+        .collect()
+}
+
+/// Dependencies of every composite tag exif-oxide can compute
+pub fn all_composite_dependencies() -> Vec<CompositeDependencies> {
+    all_composite_defs()
+        .map(CompositeDependencies::from)
+        .collect()
+}
+
+/// Tag names to extract so that `composites` can be computed
+///
+/// Follows the graph transitively: DOF needs CircleOfConfusion, itself a
+/// composite, so CircleOfConfusion and its own sources are included. Inhibit
+/// tags are included too, so a filtered extraction suppresses the same
+/// composites an unfiltered one would. Group qualifiers are dropped, since
+/// [`FilterOptions::requested_tags`](crate::types::FilterOptions::requested_tags)
+/// matches on tag name.
+///
+/// The requested composites themselves are only listed when they are also a
+/// source (of another requested composite, or a same-named source tag), so
+/// callers append the result to their own tag list.
+///
+/// ```
+/// use exif_oxide::composite_tags::composite_source_tags;
+/// use exif_oxide::FilterOptions;
+///
+/// let mut tags = vec!["Composite:ImageSize".to_string()];
+/// let sources = composite_source_tags(&tags);
+/// assert!(sources.iter().any(|t| t == "ImageWidth"));
+///
+/// tags.extend(sources);
+/// let filter = FilterOptions::tags_only(tags);
+/// # let _ = filter;
+/// ```
+pub fn composite_source_tags<S: AsRef<str>>(composites: &[S]) -> Vec<String> {
+    let mut sources = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut visited: HashSet<String> = HashSet::new();
+    let mut pending: Vec<&str> = composites
+        .iter()
+        .map(|name| strip_group(name.as_ref()))
+        .collect();
+
+    while let Some(composite) = pending.pop() {
+        if !visited.insert(composite.to_lowercase()) {
+            continue;
+        }
+        for deps in composite_dependencies(composite) {
+            for &dep in deps.require.iter().chain(deps.desire).chain(deps.inhibit) {
+                let dep = strip_group(dep);
+                if seen.insert(dep.to_lowercase()) {
+                    sources.push(dep.to_string());
+                    pending.push(dep);
+                }
+            }
+        }
+    }
+
+    sources
+}
+
+/// Lowercase names of `composites` and every composite they are computed from
+///
+/// The set to pass to
+/// [`resolve_and_compute_requested_composites`](super::resolve_and_compute_requested_composites).
+/// Names of source tags that aren't composites may be included; they match
+/// no definition.
+pub fn composites_to_build<S: AsRef<str>>(composites: &[S]) -> HashSet<String> {
+    composites
+        .iter()
+        .map(|name| strip_group(name.as_ref()).to_string())
+        .chain(composite_source_tags(composites))
+        .map(|name| name.to_lowercase())
+        .collect()
+}
+
+/// `Group:Name` → `Name`
+fn strip_group(tag: &str) -> &str {
+    tag.rsplit(':').next().unwrap_or(tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_size_sources() {
+        let deps = composite_dependencies("Composite:imagesize");
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].require, &["ImageWidth", "ImageHeight"]);
+
+        let sources = composite_source_tags(&["ImageSize"]);
+        for tag in ["ImageWidth", "ImageHeight", "ExifImageWidth"] {
+            assert!(sources.iter().any(|t| t == tag), "missing {tag}");
+        }
+        assert!(!sources.iter().any(|t| t == "ImageSize"));
+    }
+
+    #[test]
+    fn test_sources_follow_composite_chain() {
+        // DOF -> CircleOfConfusion -> ScaleFactor35efl -> FocalLengthIn35mmFormat
+        let sources = composite_source_tags(&["DOF"]);
+        for tag in [
+            "CircleOfConfusion",
+            "ScaleFactor35efl",
+            "FocalLengthIn35mmFormat",
+        ] {
+            assert!(sources.iter().any(|t| t == tag), "missing {tag}");
+        }
+
+        // A requested composite stays listed when another one needs it
+        let sources = composite_source_tags(&["DOF", "CircleOfConfusion"]);
+        assert!(sources.iter().any(|t| t == "CircleOfConfusion"));
+    }
+
+    #[test]
+    fn test_composites_to_build() {
+        let composites = composites_to_build(&["Composite:DOF"]);
+        for name in ["dof", "circleofconfusion", "scalefactor35efl"] {
+            assert!(composites.contains(name), "missing {name}");
+        }
+        assert!(!composites.contains("imagesize"));
+    }
+
+    #[test]
+    fn test_manual_composites_and_unknown_names() {
+        let deps = composite_dependencies("ContentIdentifier");
+        assert_eq!(deps.len(), 1);
+        assert_eq!(
+            composite_source_tags(&["ContentIdentifier"]),
+            vec!["ContentIdentifier".to_string()]
+        );
+
+        assert!(composite_dependencies("NotAComposite").is_empty());
+        assert!(composite_source_tags(&["NotAComposite"]).is_empty());
+    }
+
+    #[test]
+    fn test_same_named_composites_from_each_module() {
+        let modules: Vec<_> = composite_dependencies("WB_RGGBLevels")
+            .iter()
+            .map(|deps| deps.module)
+            .collect();
+        assert_eq!(modules, ["Canon", "Samsung"]);
+    }
+}
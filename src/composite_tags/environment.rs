@@ -0,0 +1,333 @@
+//! Normalized temperature, battery and environmental sensor composites
+//!
+//! Makers record the same readings under different tags, and the EXIF 2.31
+//! environment tags (Humidity, Pressure, WaterDepth) have no PrintConv, so
+//! consumers get bare numbers for some files and `"35 C"` strings for others.
+//! These hand-written composites take the first available source in priority
+//! order and always use the same unit:
+//!
+//! | Composite            | Unit | Sources                                                              |
+//! |----------------------|------|----------------------------------------------------------------------|
+//! | `CameraTemperature`  | °C   | Canon, Sony, Pentax, Panasonic, Samsung `CameraTemperature`          |
+//! | `AmbientTemperature` | °C   | `EXIF:AmbientTemperature`, `XMP:AmbientTemperature`, Sony Tag9402    |
+//! | `BatteryTemperature` | °C   | Sony ExtraInfo/Tag9406, GoPro KBAT                                   |
+//! | `BatteryLevel`       | %    | `EXIF:BatteryLevel`, Sony, GoPro                                     |
+//! | `Humidity`           | %    | `EXIF:Humidity`, `XMP:Humidity`                                      |
+//! | `Pressure`           | hPa  | `EXIF:Pressure`, `XMP:Pressure`                                      |
+//! | `WaterDepth`         | m    | `EXIF:WaterDepth`, `XMP:WaterDepth` (negative above water)           |
+//!
+//! Values are numbers in that unit. Temperature PrintConvs add the
+//! Fahrenheit equivalent, e.g. `35 C (95 F)`.
+//!
+//! Sources whose unit ExifTool doesn't document (Olympus ImageProcessing and
+//! Kodak CameraTemperature, DJI ThermalParams) and Panasonic's categorical
+//! BatteryLevel (`Full`, `Medium`, ...) are not used. Olympus and Pentax
+//! pressure and altitude readings aren't decoded yet.
+
+use crate::core::types::{ExifContext, ExifError, Result};
+use crate::core::TagValue;
+use crate::generated::composite_tags::CompositeTagDef;
+
+const CAMERA_TEMPERATURE_SOURCES: &[&str] = &[
+    "Canon:CameraTemperature",
+    "Sony:CameraTemperature",
+    "Pentax:CameraTemperature",
+    "Panasonic:CameraTemperature",
+    "Samsung:CameraTemperature",
+];
+
+const AMBIENT_TEMPERATURE_SOURCES: &[&str] = &[
+    "EXIF:AmbientTemperature",
+    "XMP:AmbientTemperature",
+    "Sony:AmbientTemperature",
+];
+
+const BATTERY_TEMPERATURE_SOURCES: &[&str] =
+    &["Sony:BatteryTemperature", "GoPro:BatteryTemperature"];
+
+const BATTERY_LEVEL_SOURCES: &[&str] = &[
+    "EXIF:BatteryLevel",
+    "Sony:BatteryLevel",
+    "GoPro:BatteryLevel",
+];
+
+const HUMIDITY_SOURCES: &[&str] = &["EXIF:Humidity", "XMP:Humidity"];
+
+const PRESSURE_SOURCES: &[&str] = &["EXIF:Pressure", "XMP:Pressure"];
+
+const WATER_DEPTH_SOURCES: &[&str] = &["EXIF:WaterDepth", "XMP:WaterDepth"];
+
+/// Composite:CameraTemperature — internal camera temperature in °C
+pub static COMPOSITE_CAMERA_TEMPERATURE: CompositeTagDef = CompositeTagDef {
+    name: "CameraTemperature",
+    module: "Composite",
+    require: &[],
+    desire: CAMERA_TEMPERATURE_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_first_reading),
+    print_conv: Some(print_temperature),
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Camera temperature in degrees C from the maker notes"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:AmbientTemperature — ambient temperature in °C
+pub static COMPOSITE_AMBIENT_TEMPERATURE: CompositeTagDef = CompositeTagDef {
+    name: "AmbientTemperature",
+    module: "Composite",
+    require: &[],
+    desire: AMBIENT_TEMPERATURE_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_first_reading),
+    print_conv: Some(print_temperature),
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Ambient temperature in degrees C"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:BatteryTemperature — battery temperature in °C
+pub static COMPOSITE_BATTERY_TEMPERATURE: CompositeTagDef = CompositeTagDef {
+    name: "BatteryTemperature",
+    module: "Composite",
+    require: &[],
+    desire: BATTERY_TEMPERATURE_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_first_reading),
+    print_conv: Some(print_temperature),
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Battery temperature in degrees C"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:BatteryLevel — remaining battery charge in percent
+pub static COMPOSITE_BATTERY_LEVEL: CompositeTagDef = CompositeTagDef {
+    name: "BatteryLevel",
+    module: "Composite",
+    require: &[],
+    desire: BATTERY_LEVEL_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_first_reading),
+    print_conv: Some(print_percent),
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Battery level in percent"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:Humidity — ambient relative humidity in percent
+pub static COMPOSITE_HUMIDITY: CompositeTagDef = CompositeTagDef {
+    name: "Humidity",
+    module: "Composite",
+    require: &[],
+    desire: HUMIDITY_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_first_reading),
+    print_conv: Some(print_percent),
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Ambient relative humidity in percent"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:Pressure — air pressure in hPa
+pub static COMPOSITE_PRESSURE: CompositeTagDef = CompositeTagDef {
+    name: "Pressure",
+    module: "Composite",
+    require: &[],
+    desire: PRESSURE_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_first_reading),
+    print_conv: Some(print_hectopascals),
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Air pressure in hPa"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:WaterDepth — depth under water in metres
+pub static COMPOSITE_WATER_DEPTH: CompositeTagDef = CompositeTagDef {
+    name: "WaterDepth",
+    module: "Composite",
+    require: &[],
+    desire: WATER_DEPTH_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_first_reading),
+    print_conv: Some(print_metres),
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Depth under water in metres, negative above water"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// A reading as a number, from a numeric value or a string like `"35 C"` or
+/// `"80%"`
+fn reading(value: &TagValue) -> Option<f64> {
+    let number = match value {
+        TagValue::String(s) => {
+            let s = s.trim();
+            let end = s
+                .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+')))
+                .unwrap_or(s.len());
+            s[..end].parse().ok()?
+        }
+        value => value.as_f64()?,
+    };
+    number.is_finite().then_some(number)
+}
+
+/// The first source with a reading
+fn first_reading(vals: &[TagValue]) -> Option<f64> {
+    vals.iter().find_map(reading)
+}
+
+fn no_reading() -> ExifError {
+    ExifError::ParseError("No sensor reading available".to_string())
+}
+
+/// Round to one decimal and drop a trailing `.0`
+fn format_reading(value: f64) -> String {
+    let rounded = (value * 10.0).round() / 10.0;
+    if rounded.fract() == 0.0 {
+        format!("{rounded:.0}")
+    } else {
+        format!("{rounded:.1}")
+    }
+}
+
+/// ValueConv shared by all the composites here
+fn composite_first_reading(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    first_reading(vals)
+        .map(TagValue::F64)
+        .ok_or_else(no_reading)
+}
+
+/// `35 C (95 F)`
+fn print_temperature(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let celsius = first_reading(vals).ok_or_else(no_reading)?;
+    let fahrenheit = celsius * 1.8 + 32.0;
+    Ok(TagValue::string(format!(
+        "{} C ({} F)",
+        format_reading(celsius),
+        format_reading(fahrenheit)
+    )))
+}
+
+/// `80 %`
+fn print_percent(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let percent = first_reading(vals).ok_or_else(no_reading)?;
+    Ok(TagValue::string(format!("{} %", format_reading(percent))))
+}
+
+/// `1013.2 hPa`
+fn print_hectopascals(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let pressure = first_reading(vals).ok_or_else(no_reading)?;
+    Ok(TagValue::string(format!(
+        "{} hPa",
+        format_reading(pressure)
+    )))
+}
+
+/// `12.5 m`
+fn print_metres(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let depth = first_reading(vals).ok_or_else(no_reading)?;
+    Ok(TagValue::string(format!("{} m", format_reading(depth))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composite_tags::{resolve_and_compute_composites, TagDependencyValues};
+    use std::collections::HashMap;
+
+    fn available(tags: &[(&str, TagValue, &str)]) -> HashMap<String, TagDependencyValues> {
+        tags.iter()
+            .map(|(key, val, prt)| {
+                let values = TagDependencyValues {
+                    raw: val.clone(),
+                    val: val.clone(),
+                    prt: TagValue::string(*prt),
+                };
+                (key.to_string(), values)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_readings_are_printed_with_units() {
+        let composites = resolve_and_compute_composites(available(&[
+            ("Canon:CameraTemperature", TagValue::I32(35), "35 C"),
+            (
+                "EXIF:AmbientTemperature",
+                TagValue::SRational(-25, 10),
+                "-2.5 C",
+            ),
+            ("Sony:BatteryLevel", TagValue::U8(80), "80%"),
+            ("EXIF:Pressure", TagValue::Rational(101325, 100), "1013.25"),
+            ("EXIF:WaterDepth", TagValue::SRational(125, 10), "12.5"),
+        ]));
+        assert_eq!(
+            composites.get("Composite:CameraTemperature"),
+            Some(&TagValue::string("35 C (95 F)"))
+        );
+        assert_eq!(
+            composites.get("Composite:AmbientTemperature"),
+            Some(&TagValue::string("-2.5 C (27.5 F)"))
+        );
+        assert_eq!(
+            composites.get("Composite:BatteryLevel"),
+            Some(&TagValue::string("80 %"))
+        );
+        assert_eq!(
+            composites.get("Composite:WaterDepth"),
+            Some(&TagValue::string("12.5 m"))
+        );
+        assert!(!composites.contains_key("Composite:Humidity"));
+    }
+
+    #[test]
+    fn test_print_convs() {
+        let vals = [TagValue::Empty, TagValue::I32(35)];
+        assert_eq!(
+            print_temperature(&vals, &[], &[], None).unwrap(),
+            TagValue::string("35 C (95 F)")
+        );
+        assert_eq!(
+            print_hectopascals(&[TagValue::Rational(101325, 100)], &[], &[], None).unwrap(),
+            TagValue::string("1013.3 hPa")
+        );
+        // EXIF:BatteryLevel may be a string
+        assert_eq!(
+            print_percent(&[TagValue::string("62%")], &[], &[], None).unwrap(),
+            TagValue::string("62 %")
+        );
+        assert!(print_metres(&[TagValue::string("n/a")], &[], &[], None).is_err());
+    }
+}
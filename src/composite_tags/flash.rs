@@ -0,0 +1,257 @@
+//! Flash bitfield composites
+//!
+//! `EXIF:Flash` is a bitmask whose PrintConv is one phrase per combination,
+//! e.g. `Auto, Fired, Red-eye reduction, Return detected`. Matching on that
+//! string to ask "did the flash fire?" is fragile, so these hand-written
+//! composites split the bits into the fields of the XMP `exif:Flash`
+//! structure:
+//!
+//! | Composite         | Bits | Value                                     |
+//! |-------------------|------|-------------------------------------------|
+//! | `FlashFired`      | 0    | `True` / `False`                          |
+//! | `FlashReturn`     | 1-2  | 0 no detection, 2 not detected, 3 detected |
+//! | `FlashMode`       | 3-4  | 0 unknown, 1 on, 2 off, 3 auto            |
+//! | `FlashFunction`   | 5    | `True` when the camera has no flash       |
+//! | `FlashRedEyeMode` | 6    | `True` / `False`                          |
+//!
+//! ExifTool: lib/Image/ExifTool/Exif.pm %flash, lib/Image/ExifTool/XMP.pm
+//! %Image::ExifTool::XMP::Flash (the PrintConvs used here)
+
+use crate::core::types::{ExifContext, ExifError, Result};
+use crate::core::TagValue;
+use crate::generated::composite_tags::CompositeTagDef;
+
+const FLASH_SOURCE: &[&str] = &["EXIF:Flash"];
+
+/// Composite:FlashFired — whether the flash fired
+pub static COMPOSITE_FLASH_FIRED: CompositeTagDef = CompositeTagDef {
+    name: "FlashFired",
+    module: "Composite",
+    require: FLASH_SOURCE,
+    desire: &[],
+    inhibit: &[],
+    value_conv: Some(composite_flash_fired),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Whether the flash fired, from EXIF:Flash bit 0"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:FlashReturn — strobe return light detection
+pub static COMPOSITE_FLASH_RETURN: CompositeTagDef = CompositeTagDef {
+    name: "FlashReturn",
+    module: "Composite",
+    require: FLASH_SOURCE,
+    desire: &[],
+    inhibit: &[],
+    value_conv: Some(composite_flash_return),
+    print_conv: Some(print_flash_return),
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Strobe return light detection, from EXIF:Flash bits 1-2"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:FlashMode — compulsory on, compulsory off or auto
+pub static COMPOSITE_FLASH_MODE: CompositeTagDef = CompositeTagDef {
+    name: "FlashMode",
+    module: "Composite",
+    require: FLASH_SOURCE,
+    desire: &[],
+    inhibit: &[],
+    value_conv: Some(composite_flash_mode),
+    print_conv: Some(print_flash_mode),
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Flash mode, from EXIF:Flash bits 3-4"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:FlashFunction — true when the camera has no flash function
+pub static COMPOSITE_FLASH_FUNCTION: CompositeTagDef = CompositeTagDef {
+    name: "FlashFunction",
+    module: "Composite",
+    require: FLASH_SOURCE,
+    desire: &[],
+    inhibit: &[],
+    value_conv: Some(composite_flash_function),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("True if the camera has no flash function, from EXIF:Flash bit 5"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:FlashRedEyeMode — whether red-eye reduction was used
+pub static COMPOSITE_FLASH_RED_EYE_MODE: CompositeTagDef = CompositeTagDef {
+    name: "FlashRedEyeMode",
+    module: "Composite",
+    require: FLASH_SOURCE,
+    desire: &[],
+    inhibit: &[],
+    value_conv: Some(composite_flash_red_eye_mode),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Whether red-eye reduction was used, from EXIF:Flash bit 6"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// The numeric EXIF:Flash value
+///
+/// The raw value is used so `-n` and PrintConv'd inputs decode the same.
+fn flash_bits(vals: &[TagValue], raws: &[TagValue]) -> Result<u16> {
+    raws.first()
+        .and_then(TagValue::as_u16)
+        .or_else(|| vals.first().and_then(TagValue::as_u16))
+        .ok_or_else(|| ExifError::ParseError("EXIF:Flash is not a number".to_string()))
+}
+
+/// XMP Boolean, as in the exif:Flash structure
+fn boolean(set: bool) -> TagValue {
+    TagValue::string(if set { "True" } else { "False" })
+}
+
+fn composite_flash_fired(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    Ok(boolean(flash_bits(vals, raws)? & 0x01 != 0))
+}
+
+fn composite_flash_return(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    Ok(TagValue::U8(((flash_bits(vals, raws)? >> 1) & 0x03) as u8))
+}
+
+fn composite_flash_mode(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    Ok(TagValue::U8(((flash_bits(vals, raws)? >> 3) & 0x03) as u8))
+}
+
+fn composite_flash_function(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    Ok(boolean(flash_bits(vals, raws)? & 0x20 != 0))
+}
+
+fn composite_flash_red_eye_mode(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    Ok(boolean(flash_bits(vals, raws)? & 0x40 != 0))
+}
+
+/// ExifTool: XMP.pm Flash Return PrintConv
+fn print_flash_return(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let text = match (flash_bits(vals, raws)? >> 1) & 0x03 {
+        0 => "No return detection",
+        2 => "Return not detected",
+        3 => "Return detected",
+        _ => "Unknown (1)",
+    };
+    Ok(TagValue::string(text))
+}
+
+/// ExifTool: XMP.pm Flash Mode PrintConv
+fn print_flash_mode(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let text = match (flash_bits(vals, raws)? >> 3) & 0x03 {
+        0 => "Unknown",
+        1 => "On",
+        2 => "Off",
+        _ => "Auto",
+    };
+    Ok(TagValue::string(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::composite_tags::{resolve_and_compute_composites, TagDependencyValues};
+    use crate::core::TagValue;
+    use std::collections::HashMap;
+
+    fn flash_composites(flash: u16, printed: &str) -> HashMap<String, TagValue> {
+        let values = TagDependencyValues {
+            raw: TagValue::U16(flash),
+            val: TagValue::U16(flash),
+            prt: TagValue::string(printed),
+        };
+        let available = HashMap::from([("EXIF:Flash".to_string(), values)]);
+        resolve_and_compute_composites(available)
+    }
+
+    fn get<'a>(composites: &'a HashMap<String, TagValue>, name: &str) -> Option<&'a TagValue> {
+        composites.get(&format!("Composite:{name}"))
+    }
+
+    #[test]
+    fn test_flash_bits() {
+        let composites = flash_composites(0x5f, "Auto, Fired, Red-eye reduction, Return detected");
+        let expected = [
+            ("FlashFired", "True"),
+            ("FlashReturn", "Return detected"),
+            ("FlashMode", "Auto"),
+            ("FlashFunction", "False"),
+            ("FlashRedEyeMode", "True"),
+        ];
+        for (name, value) in expected {
+            assert_eq!(
+                get(&composites, name),
+                Some(&TagValue::string(value)),
+                "{name}"
+            );
+        }
+
+        let composites = flash_composites(0x10, "Off, Did not fire");
+        assert_eq!(
+            get(&composites, "FlashFired"),
+            Some(&TagValue::string("False"))
+        );
+        assert_eq!(
+            get(&composites, "FlashMode"),
+            Some(&TagValue::string("Off"))
+        );
+        assert_eq!(
+            get(&composites, "FlashReturn"),
+            Some(&TagValue::string("No return detection"))
+        );
+
+        let composites = flash_composites(0x20, "No flash function");
+        assert_eq!(
+            get(&composites, "FlashFunction"),
+            Some(&TagValue::string("True"))
+        );
+    }
+
+    #[test]
+    fn test_no_flash_tag() {
+        let composites = resolve_and_compute_composites(HashMap::new());
+        assert_eq!(get(&composites, "FlashFired"), None);
+    }
+}
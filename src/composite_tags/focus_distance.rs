@@ -0,0 +1,210 @@
+//! Normalized focus distance composite
+//!
+//! Each maker encodes the focus distance differently, so searching by depth
+//! needs one field with one unit. Composite:FocusDistance is the distance in
+//! metres, or `"inf"` when the lens was focused at infinity, taken from the
+//! first available source:
+//!
+//! | Source                                   | Encoding                         | Precision                                          |
+//! |------------------------------------------|----------------------------------|----------------------------------------------------|
+//! | Canon `FocusDistanceUpper`/`Lower`       | two int16u in cm, 65535 is inf   | bounds of the lens encoder step; midpoint is used  |
+//! | Nikon LensData `FocusDistance`           | one byte, `0.01 * 10**($val/40)` | log scale, each step is about 6% of the distance   |
+//! | `EXIF:SubjectDistance`                   | rational metres                  | whatever the camera wrote; often missing or 0      |
+//!
+//! Sony's FocusPosition isn't listed: ExifTool's own Sony FocusDistance
+//! composite (`FocusPosition * FocalLength / 1000`, `"inf"` from 128) is
+//! generated, takes precedence over this one and uses the same unit. It's
+//! the roughest of the lot, as FocusPosition is only a lens position scaled
+//! by focal length.
+//!
+//! None of these are measurements of the subject: they come from the focus
+//! ring or motor position, and are least reliable for close focus and
+//! long lenses.
+
+use crate::core::types::{ExifContext, ExifError, Result};
+use crate::core::TagValue;
+use crate::generated::composite_tags::CompositeTagDef;
+
+/// Indices into the `desire` list
+const CANON_UPPER: usize = 0;
+const CANON_LOWER: usize = 1;
+const NIKON: usize = 2;
+const SUBJECT_DISTANCE: usize = 3;
+
+/// Canon FocusDistanceUpper above this is the raw 65535 "infinity"
+/// ExifTool: Canon.pm FocusDistanceUpper PrintConv `$val > 655.345 ? "inf"`
+const CANON_INFINITY: f64 = 655.345;
+
+/// Composite:FocusDistance — focus distance in metres
+pub static COMPOSITE_FOCUS_DISTANCE: CompositeTagDef = CompositeTagDef {
+    name: "FocusDistance",
+    module: "Composite",
+    require: &[],
+    desire: &[
+        "Canon:FocusDistanceUpper",
+        "Canon:FocusDistanceLower",
+        "Nikon:FocusDistance",
+        "EXIF:SubjectDistance",
+    ],
+    inhibit: &[],
+    value_conv: Some(composite_focus_distance),
+    print_conv: Some(print_focus_distance),
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Focus distance in metres, or inf"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// A distance in metres
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Distance {
+    Metres(f64),
+    Infinity,
+}
+
+/// A distance from a number, or a string like `"1.50 m"` or `"inf"`
+fn distance(value: &TagValue) -> Option<Distance> {
+    let metres = match value {
+        TagValue::String(s) => {
+            let s = s.trim();
+            if s == "inf" {
+                return Some(Distance::Infinity);
+            }
+            s.trim_end_matches('m').trim_end().parse().ok()?
+        }
+        value => value.as_f64()?,
+    };
+    (metres.is_finite() && metres >= 0.0).then_some(Distance::Metres(metres))
+}
+
+/// The focus distance from the first source that has one
+fn focus_distance(vals: &[TagValue]) -> Option<Distance> {
+    let val = |i: usize| vals.get(i).and_then(distance);
+
+    // Canon records the range the lens encoder reports
+    if let Some(upper) = val(CANON_UPPER) {
+        return Some(match (upper, val(CANON_LOWER)) {
+            (Distance::Metres(upper), _) if upper > CANON_INFINITY => Distance::Infinity,
+            (Distance::Metres(upper), Some(Distance::Metres(lower))) if lower > 0.0 => {
+                Distance::Metres((upper + lower) / 2.0)
+            }
+            (upper, _) => upper,
+        });
+    }
+
+    if let Some(nikon) = val(NIKON) {
+        return Some(nikon);
+    }
+
+    // ExifTool: SubjectDistance 0 is unknown, 0xffffffff is infinity
+    match vals.get(SUBJECT_DISTANCE)? {
+        TagValue::Rational(0xffffffff, _) => Some(Distance::Infinity),
+        _ => val(SUBJECT_DISTANCE).filter(|d| *d != Distance::Metres(0.0)),
+    }
+}
+
+fn no_distance() -> ExifError {
+    ExifError::ParseError("No focus distance available".to_string())
+}
+
+fn composite_focus_distance(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    match focus_distance(vals).ok_or_else(no_distance)? {
+        Distance::Metres(metres) => Ok(TagValue::F64(metres)),
+        Distance::Infinity => Ok(TagValue::string("inf")),
+    }
+}
+
+/// `1.25 m` or `inf`
+fn print_focus_distance(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    match focus_distance(vals).ok_or_else(no_distance)? {
+        Distance::Metres(metres) => Ok(TagValue::string(format!("{metres:.2} m"))),
+        Distance::Infinity => Ok(TagValue::string("inf")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composite_tags::{resolve_and_compute_composites, TagDependencyValues};
+    use std::collections::HashMap;
+
+    fn available(tags: &[(&str, TagValue)]) -> HashMap<String, TagDependencyValues> {
+        tags.iter()
+            .map(|(key, val)| {
+                let values = TagDependencyValues {
+                    raw: val.clone(),
+                    val: val.clone(),
+                    prt: val.clone(),
+                };
+                (key.to_string(), values)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_canon_midpoint() {
+        let composites = resolve_and_compute_composites(available(&[
+            ("Canon:FocusDistanceUpper", TagValue::F64(1.6)),
+            ("Canon:FocusDistanceLower", TagValue::F64(1.4)),
+            ("EXIF:SubjectDistance", TagValue::Rational(3, 1)),
+        ]));
+        assert_eq!(
+            composites.get("Composite:FocusDistance"),
+            Some(&TagValue::string("1.50 m"))
+        );
+    }
+
+    #[test]
+    fn test_value_conv() {
+        let value = |vals: &[TagValue]| composite_focus_distance(vals, &[], &[], None).unwrap();
+
+        // Canon infinity
+        assert_eq!(
+            value(&[TagValue::F64(655.35), TagValue::F64(10.0)]),
+            TagValue::string("inf")
+        );
+        // Canon upper bound only
+        assert_eq!(
+            value(&[TagValue::F64(2.5), TagValue::F64(0.0)]),
+            TagValue::F64(2.5)
+        );
+        // Nikon LensData, stored printed
+        assert_eq!(
+            value(&[TagValue::Empty, TagValue::Empty, TagValue::string("3.98 m")]),
+            TagValue::F64(3.98)
+        );
+        // EXIF SubjectDistance infinity
+        assert_eq!(
+            value(&[
+                TagValue::Empty,
+                TagValue::Empty,
+                TagValue::Empty,
+                TagValue::Rational(0xffffffff, 1)
+            ]),
+            TagValue::string("inf")
+        );
+        // SubjectDistance 0 is unknown
+        assert!(composite_focus_distance(
+            &[
+                TagValue::Empty,
+                TagValue::Empty,
+                TagValue::Empty,
+                TagValue::Rational(0, 1)
+            ],
+            &[],
+            &[],
+            None
+        )
+        .is_err());
+    }
+}
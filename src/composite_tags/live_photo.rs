@@ -0,0 +1,96 @@
+//! Live Photo pairing composite
+//!
+//! An iPhone Live Photo is a still (JPEG/HEIC) plus a short MOV. Both halves
+//! carry the same UUID: the still in its Apple maker notes
+//! (`Apple:ContentIdentifier`, Apple.pm tag 0x0011) and the video in its
+//! QuickTime Keys (`Keys:ContentIdentifier`,
+//! `com.apple.quicktime.content.identifier`). ExifTool has no composite that
+//! unifies them, so library software has to know which group to ask for
+//! depending on the file type. `Composite:ContentIdentifier` exposes whichever
+//! one is present under a single name.
+//!
+//! Like the other entries in [`super::MANUAL_COMPOSITES`], this definition does
+//! not come from `crate::generated::composite_tags`.
+
+use crate::core::types::{ExifContext, ExifError, Result};
+use crate::core::TagValue;
+use crate::generated::composite_tags::CompositeTagDef;
+
+/// Composite:ContentIdentifier — the Live Photo pairing UUID from either half
+pub static COMPOSITE_CONTENT_IDENTIFIER: CompositeTagDef = CompositeTagDef {
+    name: "ContentIdentifier",
+    module: "Apple",
+    require: &[],
+    desire: &["Apple:ContentIdentifier", "Keys:ContentIdentifier"],
+    inhibit: &[],
+    value_conv: Some(composite_content_identifier),
+    print_conv: None,
+    value_conv_expr: Some("$val[0] || $val[1]"),
+    print_conv_expr: None,
+    description: Some("Live Photo pairing identifier from the still or the video"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Image")],
+};
+
+/// ValueConv for Composite:ContentIdentifier: the first non-empty identifier
+fn composite_content_identifier(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    vals.iter()
+        .find_map(|v| match v.as_string() {
+            Some(s) if !s.trim().is_empty() => Some(TagValue::string(s.trim())),
+            _ => None,
+        })
+        .ok_or_else(|| ExifError::ParseError("No ContentIdentifier available".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composite_tags::{resolve_and_compute_composites, TagDependencyValues};
+    use std::collections::HashMap;
+
+    fn available(key: &str, value: &str) -> HashMap<String, TagDependencyValues> {
+        let value = TagValue::string(value);
+        HashMap::from([(
+            key.to_string(),
+            TagDependencyValues {
+                raw: value.clone(),
+                val: value.clone(),
+                prt: value,
+            },
+        )])
+    }
+
+    #[test]
+    fn test_content_identifier_from_still() {
+        let composites = resolve_and_compute_composites(available(
+            "Apple:ContentIdentifier",
+            "8D3C7C70-4E37-4B3D-A3A0-8AB51C0C8F43",
+        ));
+        assert_eq!(
+            composites.get("Composite:ContentIdentifier"),
+            Some(&TagValue::string("8D3C7C70-4E37-4B3D-A3A0-8AB51C0C8F43"))
+        );
+    }
+
+    #[test]
+    fn test_content_identifier_from_video() {
+        let composites = resolve_and_compute_composites(available(
+            "Keys:ContentIdentifier",
+            "8D3C7C70-4E37-4B3D-A3A0-8AB51C0C8F43",
+        ));
+        assert_eq!(
+            composites.get("Composite:ContentIdentifier"),
+            Some(&TagValue::string("8D3C7C70-4E37-4B3D-A3A0-8AB51C0C8F43"))
+        );
+    }
+
+    #[test]
+    fn test_no_content_identifier() {
+        let composites = resolve_and_compute_composites(available("EXIF:Make", "Apple"));
+        assert!(!composites.contains_key("Composite:ContentIdentifier"));
+    }
+}
@@ -10,16 +10,143 @@
 //!
 //! - **orchestration.rs**: Multi-pass loop that iterates through composite tags
 //! - **resolution.rs**: Dependency checking and array building for function calls
+//! - **dependencies.rs**: Public dependency graph for building minimal filters
+//! - **live_photo.rs**: Hand-written Composite:ContentIdentifier (Live Photo pairing)
+//! - **camera_counters.rs**: Hand-written Composite:ShutterCount and Composite:SerialNumber
+//! - **best_date.rs**: Hand-written Composite:BestDateTime (capture date precedence ladder)
+//! - **burst.rs**: Hand-written Composite:BurstID and Composite:BurstIndex (burst sequences)
+//! - **bracketing.rs**: Hand-written Composite:BracketMode (bracketing and in-camera stacking)
+//! - **orientation.rs**: Hand-written Composite:DisplayImageSize (ImageSize after Orientation)
+//! - **color_space.rs**: Hand-written Composite:ColorSpace (EXIF, Interop, ICC and PNG reconciled)
+//! - **environment.rs**: Hand-written temperature, battery and environmental sensor composites
+//! - **focus_distance.rs**: Hand-written Composite:FocusDistance (Canon, Nikon and EXIF in metres)
+//! - **raw_levels.rs**: Hand-written raw black levels, white level and as-shot WB multipliers
+//! - **flash.rs**: Hand-written Composite:FlashFired, FlashMode, etc. (EXIF:Flash bitfields)
 //! - **crate::core::composite_fallbacks**: Manual fallback implementations for complex composites
 //!
 //! Generated composite functions are in `src/generated/composite_tags.rs`
 
+mod best_date;
+mod bracketing;
+mod burst;
+mod camera_counters;
+mod color_space;
+mod dependencies;
+mod environment;
+mod flash;
+mod focus_distance;
+mod live_photo;
 mod orchestration;
+mod orientation;
+mod raw_levels;
 mod resolution;
 
 // Re-export the main public API
-pub use orchestration::{handle_unresolved_composites, resolve_and_compute_composites};
+#[cfg(feature = "timezone")]
+pub(crate) use best_date::is_recorded_zone;
+pub(crate) use best_date::{format_offset, parse_date};
+pub(crate) use color_space::normalize_profile;
+pub use dependencies::{
+    all_composite_dependencies, composite_dependencies, composite_source_tags, composites_to_build,
+    CompositeDependencies,
+};
+pub use orchestration::{
+    handle_unresolved_composites, resolve_and_compute_composites,
+    resolve_and_compute_requested_composites,
+};
 pub use resolution::{
     build_available_tags_map, build_available_tags_map_with_conversions, can_build_composite,
     is_dependency_available, resolve_dependency_arrays, TagDependencyValues,
 };
+
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use crate::generated::composite_tags::{lookup_composite_tag, CompositeTagDef, COMPOSITE_TAG_DEFS};
+
+/// Composite definitions maintained by hand rather than generated from ExifTool.
+/// These are appended to `COMPOSITE_TAGS` by the orchestration loop.
+pub(crate) static MANUAL_COMPOSITES: &[&CompositeTagDef] = &[
+    &live_photo::COMPOSITE_CONTENT_IDENTIFIER,
+    &camera_counters::COMPOSITE_SHUTTER_COUNT,
+    &camera_counters::COMPOSITE_SHUTTER_COUNT_SOURCE,
+    &camera_counters::COMPOSITE_SERIAL_NUMBER,
+    &camera_counters::COMPOSITE_SERIAL_NUMBER_SOURCE,
+    &best_date::COMPOSITE_BEST_DATE_TIME,
+    &best_date::COMPOSITE_BEST_DATE_TIME_SOURCE,
+    &best_date::COMPOSITE_BEST_DATE_TIME_ZONE_SOURCE,
+    &burst::COMPOSITE_BURST_ID,
+    &burst::COMPOSITE_BURST_INDEX,
+    &bracketing::COMPOSITE_BRACKET_MODE,
+    &orientation::COMPOSITE_DISPLAY_IMAGE_SIZE,
+    &color_space::COMPOSITE_COLOR_SPACE,
+    &color_space::COMPOSITE_COLOR_SPACE_SOURCE,
+    &environment::COMPOSITE_CAMERA_TEMPERATURE,
+    &environment::COMPOSITE_AMBIENT_TEMPERATURE,
+    &environment::COMPOSITE_BATTERY_TEMPERATURE,
+    &environment::COMPOSITE_BATTERY_LEVEL,
+    &environment::COMPOSITE_HUMIDITY,
+    &environment::COMPOSITE_PRESSURE,
+    &environment::COMPOSITE_WATER_DEPTH,
+    &flash::COMPOSITE_FLASH_FIRED,
+    &flash::COMPOSITE_FLASH_RETURN,
+    &flash::COMPOSITE_FLASH_MODE,
+    &flash::COMPOSITE_FLASH_FUNCTION,
+    &flash::COMPOSITE_FLASH_RED_EYE_MODE,
+    &focus_distance::COMPOSITE_FOCUS_DISTANCE,
+    &raw_levels::COMPOSITE_RAW_BLACK_LEVELS,
+    &raw_levels::COMPOSITE_RAW_WHITE_LEVEL,
+    &raw_levels::COMPOSITE_RAW_WB_MULTIPLIERS,
+];
+
+/// Every composite definition, generated and hand-written
+///
+/// Includes every module's definition of a name (Canon and Samsung both
+/// define WB_RGGBLevels), so the dependency graph covers all of them.
+pub(crate) fn all_composite_defs() -> impl Iterator<Item = &'static CompositeTagDef> {
+    COMPOSITE_TAG_DEFS
+        .iter()
+        .copied()
+        .chain(MANUAL_COMPOSITES.iter().copied())
+}
+
+/// Look up a composite definition by tag name, generated or hand-written
+///
+/// Of several modules' definitions of a name, this is the one the
+/// orchestration loop builds.
+pub fn composite_tag_by_name(name: &str) -> Option<&'static CompositeTagDef> {
+    lookup_composite_tag(name).or_else(|| {
+        MANUAL_COMPOSITES
+            .iter()
+            .copied()
+            .find(|def| def.name == name)
+    })
+}
+
+/// Names (without group) of every tag a composite requires or desires
+static DEPENDENCY_NAMES: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    all_composite_defs()
+        .flat_map(|def| def.require.iter().chain(def.desire.iter()))
+        .map(|dep| dep.rsplit(':').next().unwrap_or(dep))
+        .collect()
+});
+
+/// Whether any composite reads this tag, so its `$val`/`$prt` must be available
+pub(crate) fn is_dependency(tag_name: &str) -> bool {
+    DEPENDENCY_NAMES.contains(tag_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_composite_tag_by_name() {
+        assert_eq!(composite_tag_by_name("Aperture").unwrap().name, "Aperture");
+        assert_eq!(
+            composite_tag_by_name("BestDateTime").unwrap().name,
+            "BestDateTime"
+        );
+        assert!(composite_tag_by_name("NotAComposite").is_none());
+    }
+}
@@ -8,10 +8,14 @@
 use std::collections::{HashMap, HashSet};
 use tracing::{debug, trace, warn};
 
+use crate::core::{ExifContext, COMPOSITE_PRINT_FALLBACKS};
 use crate::generated::composite_tags::{CompositeTagDef, COMPOSITE_TAGS};
 use crate::types::TagValue;
 
-use super::resolution::{can_build_composite, resolve_dependency_arrays, TagDependencyValues};
+use super::resolution::{
+    can_build_composite, is_dependency_available, resolve_dependency_arrays, TagDependencyValues,
+};
+use super::MANUAL_COMPOSITES;
 
 /// Handle unresolved composite tags (circular dependencies or missing base tags)
 /// This provides diagnostic information and graceful degradation
@@ -50,6 +54,7 @@ fn compute_composite_value(
     composite_def: &CompositeTagDef,
     available_tags: &HashMap<String, TagDependencyValues>,
     built_composites: &HashSet<String>,
+    ctx: &ExifContext,
 ) -> Option<TagValue> {
     use crate::core::COMPOSITE_FALLBACKS;
 
@@ -75,7 +80,7 @@ fn compute_composite_value(
 
     // Priority 1: Call the generated ValueConv function if available
     if let Some(value_conv_fn) = composite_def.value_conv {
-        match value_conv_fn(&vals, &prts, &raws, None) {
+        match value_conv_fn(&vals, &prts, &raws, Some(ctx)) {
             Ok(value) => {
                 trace!(
                     "Computed composite {} via generated function: {:?}",
@@ -97,7 +102,7 @@ fn compute_composite_value(
 
     // Priority 2: Check COMPOSITE_FALLBACKS registry
     if let Some(fallback_fn) = COMPOSITE_FALLBACKS.get(composite_def.name) {
-        match fallback_fn(&vals, &prts, &raws, None) {
+        match fallback_fn(&vals, &prts, &raws, Some(ctx)) {
             Ok(value) => {
                 trace!(
                     "Computed composite {} via fallback registry: {:?}",
@@ -155,6 +160,11 @@ fn apply_composite_print_conv(
         return computed_value.clone();
     }
 
+    // Hand-written PrintConvs take the computed value as $val
+    if let Some(print_fn) = COMPOSITE_PRINT_FALLBACKS.get(composite_def.name) {
+        return print_fn(computed_value, vals);
+    }
+
     // Skip PrintConv for tags computed via COMPOSITE_FALLBACKS
     // These fallback functions already apply appropriate formatting, and the
     // generated PrintConv has a bug: it uses vals[0] for $val instead of the
@@ -181,6 +191,66 @@ fn apply_composite_print_conv(
     computed_value.clone()
 }
 
+/// Context for composite functions, with the `$$self{Make}` and
+/// `$$self{Model}` data members some ExifTool calculations consult
+fn composite_context(available_tags: &HashMap<String, TagDependencyValues>) -> ExifContext {
+    let mut ctx = ExifContext::new();
+    for name in ["Make", "Model"] {
+        if let Some(value) = available_tags.get(name) {
+            ctx.set_data_member(name, value.val.clone());
+        }
+    }
+    ctx
+}
+
+/// Names of pending composites that may still be built
+///
+/// ExifTool stops waiting for a composite once it is abandoned, either
+/// because a Require is missing or because its ValueConv returned undef.
+/// A Require naming another buildable composite keeps it alive.
+fn buildable_composite_names<'a>(
+    pending_composites: &[&'a CompositeTagDef],
+    available_tags: &HashMap<String, TagDependencyValues>,
+    built_composites: &HashSet<String>,
+    failed_composites: &HashSet<&str>,
+) -> HashSet<&'a str> {
+    let mut candidates: Vec<&CompositeTagDef> = pending_composites
+        .iter()
+        .copied()
+        .filter(|def| !built_composites.contains(def.name) && !failed_composites.contains(def.name))
+        .collect();
+
+    loop {
+        let names: HashSet<&str> = candidates.iter().map(|def| def.name).collect();
+        let before = candidates.len();
+        candidates.retain(|def| {
+            can_build_composite(def, available_tags, built_composites)
+                || def.require.iter().any(|tag_name| {
+                    let name = tag_name.strip_prefix("Composite:").unwrap_or(tag_name);
+                    name != def.name && names.contains(name)
+                })
+        });
+        if candidates.len() == before {
+            return names;
+        }
+    }
+}
+
+/// Whether a composite Desires another composite that has not been built yet
+fn waits_for_desired_composite(
+    composite_def: &CompositeTagDef,
+    pending_names: &HashSet<&str>,
+    available_tags: &HashMap<String, TagDependencyValues>,
+    built_composites: &HashSet<String>,
+) -> bool {
+    composite_def.desire.iter().any(|tag_name| {
+        let name = tag_name.strip_prefix("Composite:").unwrap_or(tag_name);
+        name != composite_def.name
+            && pending_names.contains(name)
+            && !is_dependency_available(tag_name, available_tags, built_composites)
+    })
+}
+
 /// Multi-pass composite tag resolution and computation
 /// This is the main entry point for building all composite tags
 ///
@@ -189,7 +259,26 @@ fn apply_composite_print_conv(
 /// Takes a map of available tags with their raw/val/prt values and returns
 /// computed composite tags as simple TagValue (the print value).
 pub fn resolve_and_compute_composites(
+    available_tags: HashMap<String, TagDependencyValues>,
+) -> HashMap<String, TagValue> {
+    compute_composites(available_tags, None)
+}
+
+/// [`resolve_and_compute_composites`] for only the named composites
+///
+/// `requested` holds lowercase composite names, including every composite
+/// the requested ones are computed from; see
+/// [`composites_to_build`](super::composites_to_build).
+pub fn resolve_and_compute_requested_composites(
+    available_tags: HashMap<String, TagDependencyValues>,
+    requested: &HashSet<String>,
+) -> HashMap<String, TagValue> {
+    compute_composites(available_tags, Some(requested))
+}
+
+fn compute_composites(
     mut available_tags: HashMap<String, TagDependencyValues>,
+    requested: Option<&HashSet<String>>,
 ) -> HashMap<String, TagValue> {
     const MAX_PASSES: usize = 10; // Reasonable limit to prevent infinite loops
 
@@ -200,17 +289,35 @@ pub fn resolve_and_compute_composites(
     // Note: COMPOSITE_TAGS is a HashMap which loses duplicates for same-named tags
     // ExifTool uses first-successful-match semantics
     let mut pending_composites: Vec<&CompositeTagDef> = COMPOSITE_TAGS.values().copied().collect();
+    pending_composites.extend(MANUAL_COMPOSITES.iter().copied());
+    if let Some(requested) = requested {
+        pending_composites.retain(|def| requested.contains(&def.name.to_lowercase()));
+    }
 
     debug!(
         "Starting multi-pass composite building with {} pending composites",
         pending_composites.len()
     );
 
+    let ctx = composite_context(&available_tags);
+
+    // While set, a composite waits for the unbuilt composites it Desires.
+    // Cleared once a pass makes no progress, so composites whose desired
+    // composites can never be built still get built without them.
+    let mut wait_for_desired = true;
+    let mut failed_composites: HashSet<&str> = HashSet::new();
+
     // Multi-pass loop to handle composite-on-composite dependencies
     for pass in 1..=MAX_PASSES {
         let mut progress_made = false;
         let mut deferred_composites = Vec::new();
         let initial_pending_count = pending_composites.len();
+        let pending_names = buildable_composite_names(
+            &pending_composites,
+            &available_tags,
+            &built_composites,
+            &failed_composites,
+        );
 
         trace!(
             "Pass {}: Processing {} pending composites",
@@ -236,6 +343,20 @@ pub fn resolve_and_compute_composites(
                 );
             }
 
+            // ExifTool: lib/Image/ExifTool.pm BuildCompositeTags defers a composite
+            // until the composites it Desires have been built
+            if wait_for_desired
+                && waits_for_desired_composite(
+                    composite_def,
+                    &pending_names,
+                    &available_tags,
+                    &built_composites,
+                )
+            {
+                deferred_composites.push(composite_def);
+                continue;
+            }
+
             let can_build = can_build_composite(composite_def, &available_tags, &built_composites);
             if composite_def.name.starts_with("GPS") {
                 trace!(
@@ -253,7 +374,7 @@ pub fn resolve_and_compute_composites(
 
                 // All dependencies available - build the composite
                 if let Some(computed_value) =
-                    compute_composite_value(composite_def, &available_tags, &built_composites)
+                    compute_composite_value(composite_def, &available_tags, &built_composites, &ctx)
                 {
                     // Apply PrintConv to the computed value, reusing pre-computed arrays
                     let print_value = apply_composite_print_conv(
@@ -289,6 +410,7 @@ pub fn resolve_and_compute_composites(
                         "Failed to compute {} - will try next definition if available",
                         composite_def.name
                     );
+                    failed_composites.insert(composite_def.name);
                     deferred_composites.push(composite_def);
                 }
             } else {
@@ -311,6 +433,13 @@ pub fn resolve_and_compute_composites(
             break;
         }
 
+        if !progress_made && wait_for_desired {
+            // Build the rest with whatever desired tags are available
+            wait_for_desired = false;
+            pending_composites = deferred_composites;
+            continue;
+        }
+
         if !progress_made {
             // No progress made - either circular dependency or unresolvable dependencies
             trace!(
@@ -0,0 +1,95 @@
+//! Display size composite
+//!
+//! `Composite:ImageSize` is the size of the stored pixels. A portrait photo
+//! from a camera held upright is usually stored landscape with Orientation 6
+//! or 8, so every viewer and thumbnailer swaps width and height itself.
+//! `Composite:DisplayImageSize` is the size after the Orientation transform
+//! ([`crate::orientation::OrientationTransform`]); without an Orientation it
+//! equals ImageSize.
+//!
+//! Like the other entries in [`super::MANUAL_COMPOSITES`], this definition does
+//! not come from `crate::generated::composite_tags`.
+
+use crate::core::types::{ExifContext, ExifError, Result};
+use crate::core::TagValue;
+use crate::generated::composite_tags::CompositeTagDef;
+use crate::orientation::{parse_image_size, OrientationTransform};
+
+/// Composite:DisplayImageSize — ImageSize after the Orientation transform
+pub static COMPOSITE_DISPLAY_IMAGE_SIZE: CompositeTagDef = CompositeTagDef {
+    name: "DisplayImageSize",
+    module: "Composite",
+    require: &["ImageSize"],
+    desire: &["Orientation"],
+    inhibit: &[],
+    value_conv: Some(composite_display_image_size),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Image size as displayed, after the Orientation rotation"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Image")],
+};
+
+/// ValueConv for Composite:DisplayImageSize, "WxH" like ImageSize
+fn composite_display_image_size(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    let (width, height) = vals
+        .first()
+        .and_then(parse_image_size)
+        .ok_or_else(|| ExifError::ParseError("DisplayImageSize requires ImageSize".to_string()))?;
+    let transform = vals
+        .get(1)
+        .and_then(TagValue::as_u16)
+        .and_then(OrientationTransform::from_orientation)
+        .unwrap_or_default();
+    let (width, height) = transform.display_size(width, height);
+    Ok(TagValue::string(format!("{width}x{height}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composite_tags::{resolve_and_compute_composites, TagDependencyValues};
+    use std::collections::HashMap;
+
+    fn available(tags: &[(&str, TagValue)]) -> HashMap<String, TagDependencyValues> {
+        tags.iter()
+            .map(|(key, value)| {
+                let values = TagDependencyValues {
+                    raw: value.clone(),
+                    val: value.clone(),
+                    prt: value.clone(),
+                };
+                (key.to_string(), values)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_display_image_size() {
+        let size = [
+            ("EXIF:ImageWidth", TagValue::U32(6000)),
+            ("EXIF:ImageHeight", TagValue::U32(4000)),
+        ];
+        let composites = resolve_and_compute_composites(available(&size));
+        assert_eq!(
+            composites.get("Composite:DisplayImageSize"),
+            Some(&TagValue::string("6000x4000"))
+        );
+
+        let rotated = [
+            size[0].clone(),
+            size[1].clone(),
+            ("EXIF:Orientation", TagValue::U16(8)),
+        ];
+        let composites = resolve_and_compute_composites(available(&rotated));
+        assert_eq!(
+            composites.get("Composite:DisplayImageSize"),
+            Some(&TagValue::string("4000x6000"))
+        );
+    }
+}
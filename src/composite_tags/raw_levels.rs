@@ -0,0 +1,400 @@
+//! Raw processing levels: black levels, white level and as-shot white balance
+//!
+//! Raw developers need the same three sets of numbers from every raw file,
+//! but each format records them differently: DNG has `BlackLevel`,
+//! `WhiteLevel` and `AsShotNeutral`, Canon keeps them in ColorData, Nikon
+//! stores red and blue multipliers only, and Panasonic writes one tag per
+//! channel. These hand-written composites report them in one layout:
+//!
+//! | Composite          | Value                                        | Sources                                              |
+//! |--------------------|----------------------------------------------|------------------------------------------------------|
+//! | `RawBlackLevels`   | 4 levels, R G G B                            | DNG/ARW `BlackLevel`, Canon `PerChannelBlackLevel`, Nikon `BlackLevel`, Panasonic `BlackLevelRed/Green/Blue` |
+//! | `RawWhiteLevel`    | saturation level                             | DNG `WhiteLevel`, Canon `SpecularWhiteLevel`/`NormalWhiteLevel`, Panasonic `LinearityLimit*` |
+//! | `RawWBMultipliers` | 4 as-shot multipliers, R G G B, green = 1    | DNG `AsShotNeutral`, Canon `WB_RGGBLevelsAsShot`, ARW `WB_RGGBLevels`, Nikon `WB_RBLevels`, Panasonic `WB*Level`/`RedBalance` |
+//!
+//! DNG black levels are reordered from the `CFAPattern2` layout to R G G B;
+//! DNGs with a black level per row or column (`BlackLevelDeltaH`/`V`) or a
+//! repeat pattern other than 1x1 or 2x2 are left out. Levels are reported as
+//! recorded: Panasonic black levels don't include the offset some decoders
+//! add, and NEF and ARW files have no white level tag we can read (Sony's is
+//! in the encrypted SR2SubIFD), so use BitsPerSample there.
+//!
+//! LibRaw reference: `cblack`, `maximum` and `cam_mul` in libraw_internal
+
+use crate::core::types::{ExifContext, ExifError, Result};
+use crate::core::TagValue;
+use crate::generated::composite_tags::CompositeTagDef;
+
+const BLACK_LEVEL_SOURCES: &[&str] = &[
+    "EXIF:BlackLevel",
+    "EXIF:CFAPattern2",
+    "PerChannelBlackLevel",
+    "Nikon:BlackLevel",
+    "BlackLevelRed",
+    "BlackLevelGreen",
+    "BlackLevelBlue",
+];
+
+const WHITE_LEVEL_SOURCES: &[&str] = &[
+    "EXIF:WhiteLevel",
+    "SpecularWhiteLevel",
+    "NormalWhiteLevel",
+    "LinearityLimitRed",
+    "LinearityLimitGreen",
+    "LinearityLimitBlue",
+];
+
+const WB_MULTIPLIER_SOURCES: &[&str] = &[
+    "EXIF:AsShotNeutral",
+    "WB_RGGBLevelsAsShot",
+    "EXIF:WB_RGGBLevels",
+    "Nikon:WB_RBLevels",
+    "WBRedLevel",
+    "WBGreenLevel",
+    "WBBlueLevel",
+    "RedBalance",
+    "BlueBalance",
+];
+
+/// Composite:RawBlackLevels — per-channel black levels, R G G B
+pub static COMPOSITE_RAW_BLACK_LEVELS: CompositeTagDef = CompositeTagDef {
+    name: "RawBlackLevels",
+    module: "Composite",
+    require: &[],
+    desire: BLACK_LEVEL_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_raw_black_levels),
+    print_conv: Some(print_raw_black_levels),
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Black level of each CFA channel, in R G G B order"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:RawWhiteLevel — raw value at which the sensor saturates
+pub static COMPOSITE_RAW_WHITE_LEVEL: CompositeTagDef = CompositeTagDef {
+    name: "RawWhiteLevel",
+    module: "Composite",
+    require: &[],
+    desire: WHITE_LEVEL_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_raw_white_level),
+    print_conv: None,
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("Raw value at which the sensor saturates"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// Composite:RawWBMultipliers — as-shot white balance, R G G B, green = 1
+pub static COMPOSITE_RAW_WB_MULTIPLIERS: CompositeTagDef = CompositeTagDef {
+    name: "RawWBMultipliers",
+    module: "Composite",
+    require: &[],
+    desire: WB_MULTIPLIER_SOURCES,
+    inhibit: &[],
+    value_conv: Some(composite_raw_wb_multipliers),
+    print_conv: Some(print_raw_wb_multipliers),
+    value_conv_expr: None,
+    print_conv_expr: None,
+    description: Some("As-shot white balance multipliers in R G G B order, green = 1"),
+    groups: &[(0, "Composite"), (1, "Composite"), (2, "Camera")],
+};
+
+/// The numbers in a value ("2241 1024 1024 1689", arrays, or a single number)
+fn numbers(value: &TagValue) -> Vec<f64> {
+    let numbers = match value {
+        TagValue::String(s) => s.split_whitespace().map_while(|n| n.parse().ok()).collect(),
+        TagValue::U8Array(v) => v.iter().map(|n| *n as f64).collect(),
+        TagValue::U16Array(v) => v.iter().map(|n| *n as f64).collect(),
+        TagValue::U32Array(v) => v.iter().map(|n| *n as f64).collect(),
+        TagValue::F64Array(v) => v.clone(),
+        TagValue::RationalArray(v) => v
+            .iter()
+            .map(|(n, d)| {
+                if *d == 0 {
+                    f64::NAN
+                } else {
+                    *n as f64 / *d as f64
+                }
+            })
+            .collect(),
+        TagValue::SRationalArray(v) => v
+            .iter()
+            .map(|(n, d)| {
+                if *d == 0 {
+                    f64::NAN
+                } else {
+                    *n as f64 / *d as f64
+                }
+            })
+            .collect(),
+        TagValue::Array(v) => v.iter().flat_map(numbers).collect(),
+        value => value.as_f64().into_iter().collect(),
+    };
+    if numbers.iter().all(|n: &f64| n.is_finite()) {
+        numbers
+    } else {
+        Vec::new()
+    }
+}
+
+/// The first number of each value, if all of them have one
+fn channels(vals: &[TagValue]) -> Option<Vec<f64>> {
+    vals.iter()
+        .map(|value| numbers(value).first().copied())
+        .collect()
+}
+
+fn no_levels() -> ExifError {
+    ExifError::ParseError("No raw levels available".to_string())
+}
+
+/// Four 2x2 CFA values in R G G B order, using a DNG CFAPattern2 (0 red,
+/// 1 green, 2 blue) when there is one
+fn rggb(values: &[f64], cfa_pattern: &[f64]) -> Option<[f64; 4]> {
+    let values: [f64; 4] = values.try_into().ok()?;
+    if cfa_pattern.is_empty() {
+        return Some(values);
+    }
+    let mut ordered = [None; 4];
+    let mut greens = 1..3;
+    for (&value, &color) in values.iter().zip(cfa_pattern) {
+        let slot = match color as u8 {
+            0 => 0,
+            1 => greens.next()?,
+            2 => 3,
+            _ => return None,
+        };
+        ordered[slot] = Some(value);
+    }
+    let [r, g1, g2, b] = ordered;
+    Some([r?, g1?, g2?, b?])
+}
+
+fn black_levels(vals: &[TagValue]) -> Option<[f64; 4]> {
+    let get = |index: usize| vals.get(index).map(numbers).unwrap_or_default();
+
+    // DNG (repeat dim 1x1 or 2x2) and ARW
+    let dng = get(0);
+    match dng.len() {
+        1 => return Some([dng[0]; 4]),
+        4 => return rggb(&dng, &get(1)),
+        _ => {}
+    }
+    // Canon ColorData, Nikon 0x003d
+    for index in [2, 3] {
+        if let Some(levels) = rggb(&get(index), &[]) {
+            return Some(levels);
+        }
+    }
+    // Panasonic
+    let [r, g, b] = channels(vals.get(4..7)?)?.try_into().ok()?;
+    Some([r, g, g, b])
+}
+
+fn white_level(vals: &[TagValue]) -> Option<f64> {
+    let get = |index: usize| vals.get(index).and_then(|v| numbers(v).first().copied());
+
+    if let Some(level) = (0..3).find_map(get) {
+        return Some(level);
+    }
+    // Panasonic: the lowest channel limit saturates first
+    channels(vals.get(3..6)?)?.into_iter().reduce(f64::min)
+}
+
+/// Scale to green = 1
+fn normalize(r: f64, g: f64, b: f64) -> Option<[f64; 4]> {
+    (r > 0.0 && g > 0.0 && b > 0.0).then(|| [r / g, 1.0, 1.0, b / g])
+}
+
+fn wb_multipliers(vals: &[TagValue]) -> Option<[f64; 4]> {
+    let get = |index: usize| vals.get(index).map(numbers).unwrap_or_default();
+
+    // DNG: the neutral is the camera's response to white, so the
+    // multipliers are its inverse
+    if let [r, g, b] = get(0)[..] {
+        if r > 0.0 && g > 0.0 && b > 0.0 {
+            return normalize(1.0 / r, 1.0 / g, 1.0 / b);
+        }
+    }
+    // Canon ColorData, ARW
+    for index in [1, 2] {
+        if let [r, g1, g2, b] = get(index)[..] {
+            return normalize(r, (g1 + g2) / 2.0, b);
+        }
+    }
+    // Nikon: red and blue relative to green
+    if let [r, b, ..] = get(3)[..] {
+        return normalize(r, 1.0, b);
+    }
+    // Panasonic
+    if let Some([r, g, b]) = vals
+        .get(4..7)
+        .and_then(channels)
+        .and_then(|c| <[f64; 3]>::try_from(c).ok())
+    {
+        return normalize(r, g, b);
+    }
+    let [r, b] = channels(vals.get(7..9)?)?.try_into().ok()?;
+    normalize(r, 1.0, b)
+}
+
+fn composite_raw_black_levels(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    black_levels(vals)
+        .map(|levels| TagValue::F64Array(levels.to_vec()))
+        .ok_or_else(no_levels)
+}
+
+fn composite_raw_white_level(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    white_level(vals).map(TagValue::F64).ok_or_else(no_levels)
+}
+
+fn composite_raw_wb_multipliers(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    wb_multipliers(vals)
+        .map(|multipliers| TagValue::F64Array(multipliers.to_vec()))
+        .ok_or_else(no_levels)
+}
+
+/// Up to 4 decimals, without trailing zeros
+fn format_level(value: f64) -> String {
+    let formatted = format!("{value:.4}");
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// `2047 2048 2048 2047`
+fn print_levels(levels: Option<[f64; 4]>) -> Result<TagValue> {
+    let levels = levels.ok_or_else(no_levels)?;
+    let printed: Vec<String> = levels.into_iter().map(format_level).collect();
+    Ok(TagValue::string(printed.join(" ")))
+}
+
+fn print_raw_black_levels(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    print_levels(black_levels(vals))
+}
+
+/// `2.1885 1 1 1.6494`
+fn print_raw_wb_multipliers(
+    vals: &[TagValue],
+    _prts: &[TagValue],
+    _raws: &[TagValue],
+    _ctx: Option<&ExifContext>,
+) -> Result<TagValue> {
+    print_levels(wb_multipliers(vals))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::composite_tags::{resolve_and_compute_composites, TagDependencyValues};
+    use std::collections::HashMap;
+
+    fn available(tags: &[(&str, TagValue)]) -> HashMap<String, TagDependencyValues> {
+        tags.iter()
+            .map(|(key, val)| {
+                let values = TagDependencyValues {
+                    raw: val.clone(),
+                    val: val.clone(),
+                    prt: val.clone(),
+                };
+                (key.to_string(), values)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_canon_levels() {
+        let composites = resolve_and_compute_composites(available(&[
+            (
+                "PerChannelBlackLevel",
+                TagValue::string("2047 2048 2048 2047"),
+            ),
+            ("SpecularWhiteLevel", TagValue::U16(16383)),
+            ("NormalWhiteLevel", TagValue::U16(14000)),
+            (
+                "WB_RGGBLevelsAsShot",
+                TagValue::string("2241 1024 1024 1689"),
+            ),
+        ]));
+        assert_eq!(
+            composites.get("Composite:RawBlackLevels"),
+            Some(&TagValue::string("2047 2048 2048 2047"))
+        );
+        assert_eq!(
+            composites.get("Composite:RawWhiteLevel"),
+            Some(&TagValue::F64(16383.0))
+        );
+        assert_eq!(
+            composites.get("Composite:RawWBMultipliers"),
+            Some(&TagValue::string("2.1885 1 1 1.6494"))
+        );
+    }
+
+    #[test]
+    fn test_dng_levels() {
+        // GRBG sensor with a 2x2 black level pattern
+        let vals = [
+            TagValue::RationalArray(vec![(257, 1), (256, 1), (258, 1), (255, 1)]),
+            TagValue::U8Array(vec![1, 0, 2, 1]),
+        ];
+        assert_eq!(black_levels(&vals), Some([256.0, 257.0, 255.0, 258.0]));
+        assert_eq!(
+            black_levels(&[TagValue::Rational(512, 1)]),
+            Some([512.0; 4])
+        );
+
+        let vals = [TagValue::RationalArray(vec![(1, 2), (1, 1), (4, 5)])];
+        assert_eq!(wb_multipliers(&vals), Some([2.0, 1.0, 1.0, 1.25]));
+    }
+
+    #[test]
+    fn test_nikon_and_panasonic_levels() {
+        let mut vals = vec![TagValue::Empty; WB_MULTIPLIER_SOURCES.len()];
+        vals[3] = TagValue::F64Array(vec![1.984375, 1.3984375, 1.0, 1.0]);
+        assert_eq!(wb_multipliers(&vals), Some([1.984375, 1.0, 1.0, 1.3984375]));
+
+        let mut vals = vec![TagValue::Empty; WB_MULTIPLIER_SOURCES.len()];
+        vals[4] = TagValue::U16(1800);
+        vals[5] = TagValue::U16(900);
+        vals[6] = TagValue::U16(1350);
+        assert_eq!(wb_multipliers(&vals), Some([2.0, 1.0, 1.0, 1.5]));
+
+        let mut vals = vec![TagValue::Empty; BLACK_LEVEL_SOURCES.len()];
+        vals[4] = TagValue::U16(142);
+        vals[5] = TagValue::U16(143);
+        vals[6] = TagValue::U16(141);
+        assert_eq!(black_levels(&vals), Some([142.0, 143.0, 143.0, 141.0]));
+        // One channel missing
+        vals[6] = TagValue::Empty;
+        assert_eq!(black_levels(&vals), None);
+
+        let mut vals = vec![TagValue::Empty; WHITE_LEVEL_SOURCES.len()];
+        vals[3] = TagValue::U16(4095);
+        vals[4] = TagValue::U16(4000);
+        vals[5] = TagValue::U16(4095);
+        assert_eq!(white_level(&vals), Some(4000.0));
+    }
+}
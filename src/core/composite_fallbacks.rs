@@ -139,6 +139,170 @@ pub static COMPOSITE_FALLBACKS: LazyLock<HashMap<&'static str, CompositeFallback
         ])
     });
 
+// =============================================================================
+// COMPOSITE_PRINT_FALLBACKS Registry
+// =============================================================================
+//
+// PrintConv counterparts for composites whose ValueConv lives in
+// COMPOSITE_FALLBACKS. The generated PrintConv functions read `$val` as
+// `vals[0]` (the first dependency) rather than the computed value, so these
+// take the computed value explicitly.
+
+/// Type alias for composite PrintConv fallbacks: (computed value, dependency vals)
+pub type CompositePrintFallbackFn = fn(&TagValue, &[TagValue]) -> TagValue;
+
+/// Registry of manually-implemented composite PrintConvs
+pub static COMPOSITE_PRINT_FALLBACKS: LazyLock<HashMap<&'static str, CompositePrintFallbackFn>> =
+    LazyLock::new(|| {
+        HashMap::from([
+            ("Megapixels", print_megapixels as CompositePrintFallbackFn),
+            ("Aperture", print_aperture as CompositePrintFallbackFn),
+            (
+                "ShutterSpeed",
+                print_shutter_speed as CompositePrintFallbackFn,
+            ),
+            (
+                "ScaleFactor35efl",
+                print_scale_factor_35efl as CompositePrintFallbackFn,
+            ),
+            (
+                "FocalLength35efl",
+                print_focal_length_35efl as CompositePrintFallbackFn,
+            ),
+            (
+                "CircleOfConfusion",
+                print_circle_of_confusion as CompositePrintFallbackFn,
+            ),
+            (
+                "HyperfocalDistance",
+                print_hyperfocal_distance as CompositePrintFallbackFn,
+            ),
+            ("DOF", print_dof as CompositePrintFallbackFn),
+            ("FOV", print_fov as CompositePrintFallbackFn),
+            ("LightValue", print_light_value as CompositePrintFallbackFn),
+        ])
+    });
+
+/// Megapixels PrintConv: sprintf("%.*f", ($val >= 1 ? 1 : ($val >= 0.001 ? 3 : 6)), $val)
+fn print_megapixels(val: &TagValue, _vals: &[TagValue]) -> TagValue {
+    let Some(megapixels) = to_float(val) else {
+        return val.clone();
+    };
+    let precision = if megapixels >= 1.0 {
+        1
+    } else if megapixels >= 0.001 {
+        3
+    } else {
+        6
+    };
+    TagValue::string(format!("{megapixels:.precision$}"))
+}
+
+/// Aperture PrintConv: Image::ExifTool::Exif::PrintFNumber($val)
+fn print_aperture(val: &TagValue, _vals: &[TagValue]) -> TagValue {
+    crate::implementations::print_conv::fnumber_print_conv(val, None)
+}
+
+/// ShutterSpeed PrintConv: Image::ExifTool::Exif::PrintExposureTime($val)
+fn print_shutter_speed(val: &TagValue, _vals: &[TagValue]) -> TagValue {
+    crate::implementations::print_conv::exposuretime_print_conv(val, None)
+}
+
+/// ScaleFactor35efl PrintConv: sprintf("%.1f", $val)
+fn print_scale_factor_35efl(val: &TagValue, _vals: &[TagValue]) -> TagValue {
+    match to_float(val) {
+        Some(scale) => TagValue::string(format!("{scale:.1}")),
+        None => val.clone(),
+    }
+}
+
+/// FocalLength35efl PrintConv:
+/// $val[1] ? sprintf("%.1f mm (35 mm equivalent: %.1f mm)", $val[0], $val) : sprintf("%.1f mm", $val)
+fn print_focal_length_35efl(val: &TagValue, vals: &[TagValue]) -> TagValue {
+    let Some(focal_35efl) = to_float(val) else {
+        return val.clone();
+    };
+    let has_scale = vals.get(1).and_then(to_float).is_some_and(|s| s != 0.0);
+    match vals.first().and_then(to_float) {
+        Some(focal_length) if has_scale => TagValue::string(format!(
+            "{focal_length:.1} mm (35 mm equivalent: {focal_35efl:.1} mm)"
+        )),
+        _ => TagValue::string(format!("{focal_35efl:.1} mm")),
+    }
+}
+
+/// CircleOfConfusion PrintConv: sprintf("%.3f mm",$val)
+fn print_circle_of_confusion(val: &TagValue, _vals: &[TagValue]) -> TagValue {
+    match to_float(val) {
+        Some(coc) => TagValue::string(format!("{coc:.3} mm")),
+        None => val.clone(),
+    }
+}
+
+/// HyperfocalDistance PrintConv: sprintf("%.2f m", $val)
+fn print_hyperfocal_distance(val: &TagValue, _vals: &[TagValue]) -> TagValue {
+    match to_float(val) {
+        Some(distance) => TagValue::string(format!("{distance:.2} m")),
+        // ValueConv returns 'inf', which Perl's %.2f renders as "Inf"
+        None => TagValue::string("Inf m"),
+    }
+}
+
+/// DOF PrintConv: "inf (%.2f m - inf)" without a far limit, otherwise
+/// "$fmt m ($fmt - $fmt m)" with 3 decimals for depths under 2 cm
+fn print_dof(val: &TagValue, _vals: &[TagValue]) -> TagValue {
+    let Some(dof) = val.as_string() else {
+        return val.clone();
+    };
+    // ExifTool: $val =~ tr/,/./;    # in case locale is whacky
+    let limits: Vec<f64> = dof
+        .replace(',', ".")
+        .split_whitespace()
+        .filter_map(|v| v.parse().ok())
+        .collect();
+    let Some(&near) = limits.first() else {
+        return val.clone();
+    };
+    match limits.get(1) {
+        Some(&far) if far != 0.0 => {
+            let depth = far - near;
+            if depth > 0.0 && depth < 0.02 {
+                TagValue::String(format!("{depth:.3} m ({near:.3} - {far:.3} m)"))
+            } else {
+                TagValue::String(format!("{depth:.2} m ({near:.2} - {far:.2} m)"))
+            }
+        }
+        _ => TagValue::String(format!("inf ({near:.2} m - inf)")),
+    }
+}
+
+/// FOV PrintConv: "%.1f deg" for the angle, plus " (%.2f m)" for the field width
+fn print_fov(val: &TagValue, _vals: &[TagValue]) -> TagValue {
+    let Some(fov) = val.as_string() else {
+        return val.clone();
+    };
+    let parts: Vec<f64> = fov
+        .split_whitespace()
+        .filter_map(|v| v.parse().ok())
+        .collect();
+    let Some(angle) = parts.first() else {
+        return val.clone();
+    };
+    let mut print = format!("{angle:.1} deg");
+    if let Some(width) = parts.get(1).filter(|&&w| w != 0.0) {
+        print.push_str(&format!(" ({width:.2} m)"));
+    }
+    TagValue::String(print)
+}
+
+/// LightValue PrintConv: sprintf("%.1f",$val)
+fn print_light_value(val: &TagValue, _vals: &[TagValue]) -> TagValue {
+    match to_float(val) {
+        Some(lv) => TagValue::string(format!("{lv:.1}")),
+        None => val.clone(),
+    }
+}
+
 // =============================================================================
 // Array-Based Fallback Functions (CompositeValueConvFn signature)
 // =============================================================================
@@ -193,7 +357,7 @@ pub fn composite_image_size(
 }
 
 /// Megapixels composite - uses regex extraction
-/// PLACEHOLDER: lib/Image/ExifTool/Composite.pm
+/// ExifTool: lib/Image/ExifTool/Exif.pm Composite Megapixels
 /// ValueConv: my @d = ($val =~ /\d+/g); $d[0] * $d[1] / 1000000
 ///
 /// Require indices: 0: ImageSize
@@ -229,19 +393,7 @@ pub fn composite_megapixels(
     // ExifTool ValueConv: $d[0] * $d[1] / 1000000
     let width = digits[0] as f64;
     let height = digits[1] as f64;
-    let megapixels = (width * height) / 1_000_000.0;
-
-    // ExifTool PrintConv: sprintf("%.*f", ($val >= 1 ? 1 : ($val >= 0.001 ? 3 : 6)), $val)
-    // Adaptive precision: 1 decimal for >=1MP, 3 for >=0.001MP, 6 for tiny sensors
-    let precision = if megapixels >= 1.0 {
-        1
-    } else if megapixels >= 0.001 {
-        3
-    } else {
-        6
-    };
-    let formatted = format!("{:.prec$}", megapixels, prec = precision);
-    Ok(TagValue::string(formatted))
+    Ok(TagValue::F64((width * height) / 1_000_000.0))
 }
 
 /// LensID composite - complex lookup tables
@@ -662,45 +814,30 @@ pub fn composite_lens_type(
 }
 
 /// ShutterSpeed composite
-/// ExifTool: lib/Image/ExifTool/Composite.pm
+/// ExifTool: lib/Image/ExifTool/Exif.pm Composite ShutterSpeed
+/// ValueConv: ($val[2] and $val[2]>0) ? $val[2] : (defined($val[0]) ? $val[0] : $val[1])
+///
+/// Desire indices: 0: ExposureTime, 1: ShutterSpeedValue, 2: BulbDuration
+///
+/// ShutterSpeedValue has already been converted from APEX to seconds by its
+/// own ValueConv, so all three sources are in seconds.
 pub fn composite_shutter_speed(
     vals: &[TagValue],
     _prts: &[TagValue],
     _raws: &[TagValue],
     _ctx: Option<&ExifContext>,
 ) -> Result<TagValue> {
-    // Check BulbDuration first (index 2)
-    if let Some(bulb) = vals.get(2) {
-        if let Some(duration) = bulb.as_f64() {
-            if duration > 0.0 {
-                return Ok(format_shutter_speed(duration));
-            }
-        }
-    }
-
-    // Try ExposureTime (index 0)
-    if let Some(exposure) = vals.first() {
-        if let Some(time) = exposure.as_f64() {
-            return Ok(format_shutter_speed(time));
-        }
-        if let Some((num, den)) = exposure.as_rational() {
-            if den != 0 {
-                return Ok(format_shutter_speed(num as f64 / den as f64));
-            }
+    if let Some(duration) = vals.get(2).and_then(to_float) {
+        if duration > 0.0 {
+            return Ok(TagValue::F64(duration));
         }
     }
 
-    // Try ShutterSpeedValue (index 1) - APEX units
-    if let Some(ssv) = vals.get(1) {
-        if let Some(apex_val) = ssv.as_f64() {
-            let time = 2.0_f64.powf(-apex_val);
-            return Ok(format_shutter_speed(time));
-        }
-    }
-
-    Err(ExifError::ParseError(
-        "Cannot compute ShutterSpeed".to_string(),
-    ))
+    vals.iter()
+        .take(2)
+        .find_map(to_float)
+        .map(TagValue::F64)
+        .ok_or_else(|| ExifError::ParseError("Cannot compute ShutterSpeed".to_string()))
 }
 
 /// Format shutter speed as '1/x' or decimal seconds
@@ -716,26 +853,22 @@ fn format_shutter_speed(time_seconds: f64) -> TagValue {
 }
 
 /// Aperture composite
-/// ExifTool: lib/Image/ExifTool/Composite.pm
+/// ExifTool: lib/Image/ExifTool/Exif.pm Composite Aperture
+/// ValueConv: $val[0] || $val[1]
+///
+/// Desire indices: 0: FNumber, 1: ApertureValue
 pub fn composite_aperture(
     vals: &[TagValue],
     _prts: &[TagValue],
     _raws: &[TagValue],
     _ctx: Option<&ExifContext>,
 ) -> Result<TagValue> {
-    // Try FNumber first (index 0), then ApertureValue (index 1)
-    for val in vals {
-        if let Some(f) = val.as_f64() {
-            let formatted = if f.fract() < 0.01 {
-                format!("{:.0}", f)
-            } else {
-                format!("{:.1}", f)
-            };
-            return Ok(TagValue::string(formatted));
-        }
-    }
-
-    Err(ExifError::ParseError("Cannot compute Aperture".to_string()))
+    vals.iter()
+        .take(2)
+        .filter_map(to_float)
+        .find(|&f| f != 0.0)
+        .map(TagValue::F64)
+        .ok_or_else(|| ExifError::ParseError("Cannot compute Aperture".to_string()))
 }
 
 /// ISO composite
@@ -777,57 +910,131 @@ fn get_numeric_iso_value(tag_value: &TagValue) -> Option<u32> {
 }
 
 /// ScaleFactor35efl composite
-/// ExifTool: lib/Image/ExifTool/Composite.pm:583-596
+/// ExifTool: lib/Image/ExifTool/Exif.pm CalcScaleFactor35efl
+///
+/// Desire indices (in the generated definition's order):
+/// 0: FocalLength, 1: FocalLengthIn35mmFormat, 2: ExifImageWidth, 3: ExifImageHeight,
+/// 4: CanonImageWidth, 5: CanonImageHeight, 6: ImageWidth, 7: ImageHeight,
+/// 8: Composite:DigitalZoom, 9: FocalPlaneDiagonal, 10: SensorSize, 11: FocalPlaneXSize,
+/// 12: FocalPlaneYSize, 13: FocalPlaneResolutionUnit, 14: FocalPlaneXResolution,
+/// 15: FocalPlaneYResolution
+///
+/// Uses the `Make` data member from `ctx` for Canon's sensor size encoding.
+/// Like ExifTool, there is no scale factor when nothing in the file sizes the
+/// sensor.
 pub fn composite_scale_factor_35efl(
     vals: &[TagValue],
     _prts: &[TagValue],
-    _raws: &[TagValue],
-    _ctx: Option<&ExifContext>,
+    raws: &[TagValue],
+    ctx: Option<&ExifContext>,
 ) -> Result<TagValue> {
-    // FocalLength is required (index 0)
-    let focal_length = vals.first().ok_or_else(|| {
-        ExifError::ParseError("ScaleFactor35efl requires FocalLength".to_string())
-    })?;
+    let val = |i: usize| vals.get(i).and_then(to_float).filter(|&v| v != 0.0);
+    let data_member = |name: &str| {
+        ctx.and_then(|ctx| ctx.get_data_member(name))
+            .and_then(|v| v.as_string())
+            .unwrap_or("")
+    };
 
-    let _fl = focal_length
-        .as_f64()
-        .ok_or_else(|| ExifError::ParseError("FocalLength must be numeric".to_string()))?;
-
-    // Try FocalLengthIn35mmFormat (index 1)
-    if let Some(fl_35mm) = vals.get(1) {
-        if let Some(fl_35mm_val) = fl_35mm.as_f64() {
-            if let Some(fl_val) = vals.first().and_then(|v| v.as_f64()) {
-                if fl_val > 0.0 && fl_35mm_val > 0.0 {
-                    return Ok(TagValue::F64(fl_35mm_val / fl_val));
-                }
-            }
-        }
+    // ExifTool: return $foc35 / $focal if $focal and $foc35;
+    if let (Some(focal), Some(foc35)) = (val(0), val(1)) {
+        return Ok(TagValue::F64(foc35 / focal));
     }
 
-    // Default fallback: assume APS-C
-    Ok(TagValue::F64(1.5))
+    let digital_zoom = val(8).unwrap_or(1.0);
+    let mut diagonal = val(9);
+    // ExifTool: calculate Canon sensor size using a dedicated algorithm
+    if data_member("Make").starts_with("Canon") {
+        let rational = |i: usize| {
+            vals.get(i)
+                .and_then(|v| v.as_rational())
+                .or_else(|| raws.get(i).and_then(|v| v.as_rational()))
+        };
+        if let Some(canon_diagonal) = canon_sensor_diagonal(rational(14), rational(15)) {
+            diagonal = Some(canon_diagonal);
+        }
+    }
+
+    let diagonal = diagonal
+        .or_else(|| {
+            // SensorSize is "W H" in mm
+            let size = vals.get(10)?.as_string()?;
+            let mut dims = size
+                .split_whitespace()
+                .filter_map(|d| d.parse::<f64>().ok());
+            let (w, h) = (dims.next()?, dims.next()?);
+            Some((w * w + h * h).sqrt())
+        })
+        .or_else(|| {
+            // FocalPlaneX/YSize are unreliable, so only trust a 4:3 or 3:2 aspect ratio
+            let (x, y) = (val(11)?, val(12)?);
+            let aspect = x / y;
+            ((aspect - 1.3333).abs() < 0.1 || (aspect - 1.5).abs() < 0.1)
+                .then(|| (x * x + y * y).sqrt())
+        })
+        .or_else(|| {
+            // Sensor size from the focal plane resolution and the image size
+            let units = match val(13).map(|u| u as u32) {
+                Some(3) => 10.0,
+                Some(4) => 1.0,
+                Some(5) => 0.001,
+                _ => 25.4,
+            };
+            let x_res = val(14)?;
+            let y_res = val(15).unwrap_or(x_res);
+            let (w, h) = [(2, 3), (4, 5), (6, 7)].iter().find_map(|&(w, h)| {
+                let (w, h) = (val(w)?, val(h)?);
+                let aspect = w / h;
+                (aspect > 0.5 && aspect < 2.0).then_some((w, h))
+            })?;
+            let (w, h) = (w * units / x_res, h * units / y_res);
+            let diagonal = (w * w + h * h).sqrt();
+            // ExifTool: return undef unless $diag > 1 and $diag < 100;
+            (diagonal > 1.0 && diagonal < 100.0).then_some(diagonal)
+        })
+        .ok_or_else(|| {
+            ExifError::ParseError("ScaleFactor35efl requires a sensor size".to_string())
+        })?;
+
+    Ok(TagValue::F64(
+        (36.0_f64 * 36.0 + 24.0 * 24.0).sqrt() * digital_zoom / diagonal,
+    ))
+}
+
+/// Canon sensor diagonal from the FocalPlaneX/YResolution rationals
+/// ExifTool: lib/Image/ExifTool/Canon.pm CalcSensorDiag
+///
+/// Most Canon models store the image size * 1000 in the numerator and the
+/// sensor size in inches * 1000 in the denominator.
+fn canon_sensor_diagonal(x_res: Option<(u32, u32)>, y_res: Option<(u32, u32)>) -> Option<f64> {
+    let ((x_num, x_den), (y_num, y_den)) = (x_res?, y_res?);
+    let plausible = |num: u32, den: u32| num.is_multiple_of(1000) && den > 200 && den < 1000;
+    if !(plausible(x_num, x_den) && plausible(y_num, y_den)) {
+        return None;
+    }
+    let x = x_den as f64 * 25.4 / 1000.0;
+    let y = y_den as f64 * 25.4 / 1000.0;
+    Some((x * x + y * y).sqrt())
 }
 
 /// FocalLength35efl composite
-/// ExifTool: lib/Image/ExifTool/Composite.pm
+/// ExifTool: lib/Image/ExifTool/Exif.pm Composite FocalLength35efl
+/// ValueConv: ToFloat(@val); ($val[0] || 0) * ($val[1] || 1)
+///
+/// Require indices: 0: FocalLength; Desire indices: 1: ScaleFactor35efl
 pub fn composite_focal_length_35efl(
     vals: &[TagValue],
     _prts: &[TagValue],
     _raws: &[TagValue],
     _ctx: Option<&ExifContext>,
 ) -> Result<TagValue> {
-    let focal_length = vals.first().ok_or_else(|| {
-        ExifError::ParseError("FocalLength35efl requires FocalLength".to_string())
-    })?;
-
-    let fl = focal_length
-        .as_f64()
-        .ok_or_else(|| ExifError::ParseError("FocalLength must be numeric".to_string()))?;
-
-    // Get ScaleFactor35efl (index 1)
-    let scale = vals.get(1).and_then(|v| v.as_f64()).unwrap_or(1.0);
+    let focal_length = vals.first().and_then(to_float).unwrap_or(0.0);
+    let scale = vals
+        .get(1)
+        .and_then(to_float)
+        .filter(|&s| s != 0.0)
+        .unwrap_or(1.0);
 
-    Ok(TagValue::F64(fl * scale))
+    Ok(TagValue::F64(focal_length * scale))
 }
 
 /// CircleOfConfusion composite
@@ -889,75 +1096,77 @@ pub fn composite_hyperfocal_distance(
 }
 
 /// DOF composite
-/// ExifTool: lib/Image/ExifTool/Composite.pm
+/// ExifTool: lib/Image/ExifTool/Exif.pm Composite DOF
+///
+/// Require indices: 0: FocalLength, 1: Aperture, 2: CircleOfConfusion
+/// Desire indices: 3: FocusDistance, 4: SubjectDistance, 5: ObjectDistance,
+/// 6: ApproximateFocusDistance, 7: FocusDistanceLower, 8: FocusDistanceUpper
+///
+/// The value is the near and far limits in metres joined with a space, with
+/// a far limit of 0 meaning infinity.
 pub fn composite_dof(
     vals: &[TagValue],
     _prts: &[TagValue],
     _raws: &[TagValue],
     _ctx: Option<&ExifContext>,
 ) -> Result<TagValue> {
-    let focal_length = vals
-        .first()
-        .and_then(|v| v.as_f64())
-        .ok_or_else(|| ExifError::ParseError("DOF requires FocalLength".to_string()))?;
-
-    let aperture = vals
-        .get(1)
-        .and_then(|v| v.as_f64())
-        .ok_or_else(|| ExifError::ParseError("DOF requires Aperture".to_string()))?;
-
-    let coc = vals
-        .get(2)
-        .and_then(|v| v.as_f64())
-        .ok_or_else(|| ExifError::ParseError("DOF requires CircleOfConfusion".to_string()))?;
+    let val = |i: usize| vals.get(i).and_then(to_float);
+
+    // A FocusDistance of 0 means infinity
+    let distance = match val(3) {
+        Some(d) if d != 0.0 => d,
+        Some(_) => 1e10,
+        None => match [4, 5, 6]
+            .into_iter()
+            .find_map(|i| val(i).filter(|&d| d != 0.0))
+        {
+            Some(d) => d,
+            None => match (val(7), val(8)) {
+                (Some(lower), Some(upper)) => (lower + upper) / 2.0,
+                _ => {
+                    return Err(ExifError::ParseError(
+                        "DOF requires a focus distance".to_string(),
+                    ))
+                }
+            },
+        },
+    };
 
+    let focal_length = val(0).unwrap_or(0.0);
+    let aperture = val(1).unwrap_or(0.0);
+    let coc = val(2).unwrap_or(0.0);
     if focal_length == 0.0 || coc == 0.0 {
-        return Ok("0".into());
+        return Ok(TagValue::string("0"));
     }
 
-    // Get focus distance (indices 3-6)
-    let mut distance = None;
-    for i in 3..=6 {
-        if let Some(d) = vals.get(i).and_then(|v| v.as_f64()) {
-            if d > 0.0 {
-                distance = Some(d);
-                break;
-            }
-        }
-    }
-
-    let d = distance.unwrap_or(1e10); // Use large number for infinity
-
     #[allow(clippy::suspicious_operation_groupings)]
-    let t = aperture * coc * (d * 1000.0 - focal_length) / (focal_length * focal_length);
-
-    let near = d / (1.0 + t);
-    let mut far = d / (1.0 - t);
+    let t = aperture * coc * (distance * 1000.0 - focal_length) / (focal_length * focal_length);
+    let near = distance / (1.0 + t);
+    // 0 means infinity
+    let far = (distance / (1.0 - t)).max(0.0);
 
-    if far < 0.0 {
-        far = 0.0; // 0 means infinity
-    }
-
-    Ok(TagValue::string(format!("{:.3} {:.3}", near, far)))
+    Ok(TagValue::String(format!(
+        "{} {}",
+        format_perl_number(near),
+        format_perl_number(far)
+    )))
 }
 
 /// FOV composite
-/// ExifTool: lib/Image/ExifTool/Composite.pm
+/// ExifTool: lib/Image/ExifTool/Exif.pm Composite FOV
+///
+/// Require indices: 0: FocalLength, 1: ScaleFactor35efl; Desire indices: 2: FocusDistance
+///
+/// The value is the field of view in degrees, followed by the field width in
+/// metres when the focus distance is known, joined with a space.
 pub fn composite_fov(
     vals: &[TagValue],
     _prts: &[TagValue],
     _raws: &[TagValue],
     _ctx: Option<&ExifContext>,
 ) -> Result<TagValue> {
-    let focal_length = vals
-        .first()
-        .and_then(|v| v.as_f64())
-        .ok_or_else(|| ExifError::ParseError("FOV requires FocalLength".to_string()))?;
-
-    let scale_factor = vals
-        .get(1)
-        .and_then(|v| v.as_f64())
-        .ok_or_else(|| ExifError::ParseError("FOV requires ScaleFactor35efl".to_string()))?;
+    let focal_length = vals.first().and_then(to_float).unwrap_or(0.0);
+    let scale_factor = vals.get(1).and_then(to_float).unwrap_or(0.0);
 
     if focal_length == 0.0 || scale_factor == 0.0 {
         return Err(ExifError::ParseError(
@@ -965,71 +1174,52 @@ pub fn composite_fov(
         ));
     }
 
-    let focus_distance = vals.get(2).and_then(|v| v.as_f64());
+    let focus_distance = vals.get(2).and_then(to_float).filter(|&d| d != 0.0);
 
     let mut corr = 1.0;
     if let Some(focus_dist) = focus_distance {
-        if focus_dist > 0.0 {
-            let d = 1000.0 * focus_dist - focal_length;
-            if d > 0.0 {
-                corr += focal_length / d;
-            }
+        let d = 1000.0 * focus_dist - focal_length;
+        if d > 0.0 {
+            corr += focal_length / d;
         }
     }
 
-    let fd2 = (36.0 / (2.0 * focal_length * scale_factor * corr)).atan();
-    let fov_angle = fd2 * 360.0 / std::f64::consts::PI;
-
-    let mut fov_values = vec![fov_angle];
-    if let Some(focus_dist) = focus_distance {
-        if focus_dist > 0.0 && focus_dist < 10000.0 {
-            let field_width = 2.0 * focus_dist * fd2.sin() / fd2.cos();
-            fov_values.push(field_width);
-        }
+    let fd2 = 36.0_f64.atan2(2.0 * focal_length * scale_factor * corr);
+    // ExifTool uses 3.14159 here, not a full-precision pi
+    #[allow(clippy::approx_constant)]
+    let mut fov = vec![format_perl_number(fd2 * 360.0 / 3.14159)];
+    if let Some(focus_dist) = focus_distance.filter(|&d| d > 0.0 && d < 10000.0) {
+        fov.push(format_perl_number(2.0 * focus_dist * fd2.sin() / fd2.cos()));
     }
 
-    let fov_str = fov_values
-        .iter()
-        .map(|v| format!("{:.1}", v))
-        .collect::<Vec<_>>()
-        .join(" ");
-
-    Ok(TagValue::String(fov_str))
+    Ok(TagValue::String(fov.join(" ")))
 }
 
 /// LightValue composite
-/// ExifTool: lib/Image/ExifTool/Exif.pm:4685-4697
+/// ExifTool: lib/Image/ExifTool/Exif.pm CalculateLV
+/// ValueConv: Image::ExifTool::Exif::CalculateLV($val[0],$val[1],$prt[2])
+///
+/// Require indices: 0: Aperture, 1: ShutterSpeed, 2: ISO (print value)
 pub fn composite_light_value(
     vals: &[TagValue],
     prts: &[TagValue],
     _raws: &[TagValue],
     _ctx: Option<&ExifContext>,
 ) -> Result<TagValue> {
-    // Aperture (index 0), ShutterSpeed (index 1), ISO (index 2 from prt)
-    let aperture = vals
-        .first()
-        .and_then(|v| v.as_f64())
-        .ok_or_else(|| ExifError::ParseError("LightValue requires Aperture".to_string()))?;
-
-    let shutter_speed = vals
-        .get(1)
-        .and_then(|v| v.as_f64())
-        .ok_or_else(|| ExifError::ParseError("LightValue requires ShutterSpeed".to_string()))?;
-
+    let aperture = vals.first().and_then(to_float);
+    let shutter_speed = vals.get(1).and_then(to_float);
     // ExifTool uses $prt[2] for ISO
-    let iso = prts
-        .get(2)
-        .and_then(|v| v.as_f64())
-        .ok_or_else(|| ExifError::ParseError("LightValue requires ISO".to_string()))?;
+    let iso = prts.get(2).and_then(to_float);
 
-    if aperture <= 0.0 || shutter_speed <= 0.0 || iso <= 0.0 {
-        return Err(ExifError::ParseError(
-            "LightValue requires positive values".to_string(),
-        ));
+    match (aperture, shutter_speed, iso) {
+        (Some(n), Some(t), Some(iso)) if n != 0.0 && t != 0.0 && iso != 0.0 => {
+            // A light value of 0 is f/1.0 at 1 second with ISO 100
+            Ok(TagValue::F64((n * n * 100.0 / (t * iso)).log2()))
+        }
+        _ => Err(ExifError::ParseError(
+            "LightValue requires Aperture, ShutterSpeed and ISO".to_string(),
+        )),
     }
-
-    let light_value = (aperture * aperture * 100.0 / (shutter_speed * iso)).log2();
-    Ok(TagValue::F64(light_value))
 }
 
 /// ImageWidth composite
@@ -2083,6 +2273,25 @@ fn parse_float_from_string(s: &str) -> Option<f64> {
     s.parse::<f64>().ok()
 }
 
+/// ExifTool's `ToFloat`: the leading floating point number of a value
+///
+/// Numeric values convert directly; strings yield the first float they
+/// contain (so "400" and "5.6 mm" both work), as ExifTool's regex does.
+fn to_float(value: &TagValue) -> Option<f64> {
+    if let Some(f) = value.as_f64() {
+        return Some(f);
+    }
+    let s = value.as_string()?;
+    let start = s.find(|c: char| c.is_ascii_digit() || c == '.' || c == '-' || c == '+')?;
+    let rest = &s[start..];
+    let end = rest
+        .char_indices()
+        .skip(1)
+        .find(|&(_, c)| !(c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E'))
+        .map_or(rest.len(), |(i, _)| i);
+    rest[..end].parse::<f64>().ok()
+}
+
 /// Compute Lens composite (HashMap interface)
 pub fn compute_lens(available_tags: &HashMap<String, TagValue>) -> Option<TagValue> {
     if let (Some(min_focal), Some(max_focal)) = (
@@ -2281,4 +2490,150 @@ mod tests {
         let pos = compute_gps_position(&tags).expect("one valid side should still render");
         assert_eq!(pos.as_string().unwrap(), " 122.5");
     }
+
+    fn print(name: &str, val: &TagValue, vals: &[TagValue]) -> TagValue {
+        COMPOSITE_PRINT_FALLBACKS[name](val, vals)
+    }
+
+    #[test]
+    fn light_value_uses_numeric_shutter_speed_and_printed_iso() {
+        // f/5.6, 1/250 s, ISO 400: log2(5.6^2 * 100 / (0.004 * 400))
+        let shutter = composite_shutter_speed(
+            &[TagValue::Rational(1, 250), TagValue::Empty, TagValue::Empty],
+            &[],
+            &[],
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            print("ShutterSpeed", &shutter, &[]),
+            TagValue::string("1/250")
+        );
+
+        let lv = composite_light_value(
+            &[TagValue::F64(5.6), shutter, TagValue::U16(400)],
+            &[TagValue::Empty, TagValue::Empty, TagValue::string("400")],
+            &[],
+            None,
+        )
+        .unwrap();
+        assert_eq!(print("LightValue", &lv, &[]), TagValue::string("10.9"));
+    }
+
+    #[test]
+    fn scale_factor_from_focal_plane_resolution() {
+        // 5760x3840 at 6199.3 pixels/inch is a 23.6x15.7 mm sensor
+        let mut vals = vec![TagValue::Empty; 16];
+        vals[0] = TagValue::Rational(50, 1);
+        vals[2] = TagValue::U32(5760);
+        vals[3] = TagValue::U32(3840);
+        vals[13] = TagValue::U16(2);
+        vals[14] = TagValue::Rational(61993, 10);
+        let scale = composite_scale_factor_35efl(&vals, &[], &[], None).unwrap();
+        assert_eq!(
+            print("ScaleFactor35efl", &scale, &[]),
+            TagValue::string("1.5")
+        );
+
+        let focal = composite_focal_length_35efl(
+            &[TagValue::Rational(50, 1), scale.clone()],
+            &[],
+            &[],
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            print("FocalLength35efl", &focal, &[TagValue::F64(50.0), scale]),
+            TagValue::string("50.0 mm (35 mm equivalent: 76.3 mm)")
+        );
+
+        // Without a sensor size or 35 mm focal length there is no scale factor
+        vals[14] = TagValue::Empty;
+        assert!(composite_scale_factor_35efl(&vals, &[], &[], None).is_err());
+    }
+
+    #[test]
+    fn fov_prints_field_width_with_focus_distance() {
+        let vals = [TagValue::F64(50.0), TagValue::F64(1.0), TagValue::F64(3.0)];
+        let fov = composite_fov(&vals, &[], &[], None).unwrap();
+        assert_eq!(
+            print("FOV", &fov, &vals),
+            TagValue::string("39.0 deg (2.12 m)")
+        );
+
+        let fov = composite_fov(&vals[..2], &[], &[], None).unwrap();
+        assert_eq!(print("FOV", &fov, &vals[..2]), TagValue::string("39.6 deg"));
+    }
+
+    #[test]
+    fn lens_calculations_print_computed_value() {
+        let coc = composite_circle_of_confusion(&[TagValue::F64(1.0)], &[], &[], None).unwrap();
+        assert_eq!(
+            print("CircleOfConfusion", &coc, &[TagValue::F64(1.0)]),
+            TagValue::string("0.030 mm")
+        );
+
+        let vals = [TagValue::F64(50.0), TagValue::F64(5.6), coc];
+        let hyperfocal = composite_hyperfocal_distance(&vals, &[], &[], None).unwrap();
+        assert_eq!(
+            print("HyperfocalDistance", &hyperfocal, &vals),
+            TagValue::string("14.86 m")
+        );
+
+        let megapixels =
+            composite_megapixels(&[TagValue::string("5760x3840")], &[], &[], None).unwrap();
+        assert_eq!(
+            print("Megapixels", &megapixels, &[]),
+            TagValue::string("22.1")
+        );
+    }
+
+    #[test]
+    fn scale_factor_from_canon_resolution() {
+        // EOS 90D: 6960x4640 image on a 22.3x14.8 mm (0.878x0.583 in) sensor
+        let mut vals = vec![TagValue::Empty; 16];
+        vals[0] = TagValue::Rational(50, 1);
+        vals[14] = TagValue::Rational(6_960_000, 878);
+        vals[15] = TagValue::Rational(4_640_000, 583);
+        let mut ctx = ExifContext::new();
+        ctx.set_data_member("Make", TagValue::string("Canon"));
+        let scale = composite_scale_factor_35efl(&vals, &[], &[], Some(&ctx)).unwrap();
+        assert_eq!(
+            print("ScaleFactor35efl", &scale, &[]),
+            TagValue::string("1.6")
+        );
+
+        // A known model alone doesn't size the sensor
+        let vals = vec![TagValue::F64(4.5)];
+        let mut ctx = ExifContext::new();
+        ctx.set_data_member("Make", TagValue::string("DJI"));
+        ctx.set_data_member("Model", TagValue::string("FC3170"));
+        assert!(composite_scale_factor_35efl(&vals, &[], &[], Some(&ctx)).is_err());
+    }
+
+    #[test]
+    fn dof_uses_first_available_focus_distance() {
+        let mut vals = vec![
+            TagValue::F64(50.0),
+            TagValue::F64(5.6),
+            TagValue::F64(0.03),
+            TagValue::Empty,
+            TagValue::F64(3.5),
+        ];
+        let dof = composite_dof(&vals, &[], &[], None).unwrap();
+        assert_eq!(
+            print("DOF", &dof, &vals),
+            TagValue::string("1.72 m (2.84 - 4.56 m)")
+        );
+
+        // Beyond the hyperfocal distance the far limit is infinity
+        vals[3] = TagValue::F64(0.0);
+        let dof = composite_dof(&vals, &[], &[], None).unwrap();
+        assert_eq!(
+            print("DOF", &dof, &vals),
+            TagValue::string("inf (14.88 m - inf)")
+        );
+
+        assert!(composite_dof(&vals[..3], &[], &[], None).is_err());
+    }
 }
@@ -5,7 +5,7 @@
 //! exif-oxide crate and the codegen system.
 //!
 //! Key components:
-//! - `TagValue` - Universal value type for EXIF data
+//! - `TagValue` - Universal value type for EXIF data (from `exif-oxide-core`)
 //! - `ExifContext` - Expression evaluation context
 //! - `fmt` module - Runtime functions for sprintf, unpack, arithmetic, etc.
 //! - `test_support` - Utilities for testing generated code
@@ -17,8 +17,10 @@ pub mod fmt;
 pub mod math;
 pub mod missing;
 pub mod string;
-pub mod tag_value;
 pub mod types;
+
+// TagValue lives in the no_std exif-oxide-core crate
+pub use exif_oxide_core::tag_value;
 pub mod xmp_tag_info;
 
 // Re-export core types for convenience
@@ -51,7 +53,7 @@ pub use string::{
 };
 
 // Re-export composite fallbacks registry for codegen and orchestration
-pub use composite_fallbacks::COMPOSITE_FALLBACKS;
+pub use composite_fallbacks::{COMPOSITE_FALLBACKS, COMPOSITE_PRINT_FALLBACKS};
 
 // Test support module - only available with test-helpers feature
 #[cfg(feature = "test-helpers")]
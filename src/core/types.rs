@@ -63,45 +63,9 @@ impl Default for ExifContext {
     }
 }
 
-/// Error types for runtime operations
-#[derive(thiserror::Error, Debug)]
-pub enum ExifError {
-    #[error("Parse error: {0}")]
-    ParseError(String),
-
-    #[error("IO error: {0}")]
-    IoError(String),
-
-    #[error("IO error: {0}")]
-    Io(#[from] std::io::Error),
-
-    #[error("Invalid file format: {0}")]
-    InvalidFormat(String),
-
-    #[error("Format error: {0}")]
-    FormatError(String),
-
-    #[error("Unsupported feature: {0}")]
-    Unsupported(String),
-
-    #[error("Not implemented: {0}")]
-    NotImplemented(String),
-
-    #[error("File detection error: {0}")]
-    FileDetection(String),
-}
-
-impl ExifError {
-    /// Create a new parse error
-    pub fn new(msg: &str) -> Self {
-        ExifError::ParseError(msg.to_string())
-    }
-}
-
-// Note: From<std::io::Error> is automatically derived via #[from] on ExifError::Io
-
-/// Result type alias for convenience
-pub type Result<T> = std::result::Result<T, ExifError>;
+// ExifError and Result live in exif-oxide-core so its no_std TIFF parser can
+// return them; re-exported here under their historical paths
+pub use exif_oxide_core::{ExifError, Result};
 
 // =============================================================================
 // Composite Tag Function Signatures
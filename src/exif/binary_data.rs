@@ -45,6 +45,7 @@ impl ExifReader {
         // Create expression evaluator with current DataMember context
         let val_hash = std::collections::HashMap::new();
         let mut evaluator = ExpressionEvaluator::new(val_hash, &self.data_members);
+        let mut new_data_members = Vec::new();
 
         // Track cumulative offset for variable-length entries
         // ExifTool: ProcessBinaryData processes entries sequentially, accounting for variable sizes
@@ -105,34 +106,26 @@ impl ExifReader {
                     };
 
                 // Convert to DataMember value for $val hash
-                let data_member_value = match &raw_value {
-                    TagValue::U8(v) => DataMemberValue::U8(*v),
-                    TagValue::U16(v) => DataMemberValue::U16(*v),
-                    TagValue::U32(v) => DataMemberValue::U32(*v),
-                    TagValue::String(s) => DataMemberValue::String(s.clone()),
-                    // Convert other types to appropriate DataMember types
-                    TagValue::I16(v) => DataMemberValue::U16(*v as u16),
-                    TagValue::I32(v) => DataMemberValue::U32(*v as u32),
-                    _ => {
-                        if let Some(data_member_name) = &tag_def.data_member {
-                            debug!(
-                                "Cannot convert tag value {:?} to DataMember for {}",
-                                raw_value, data_member_name
-                            );
-                        }
-                        // Still store in $val hash as U16 for index reference
-                        DataMemberValue::U16(0)
+                let data_member_value = DataMemberValue::from_tag_value(&raw_value);
+                if data_member_value.is_none() {
+                    if let Some(data_member_name) = &tag_def.data_member {
+                        debug!(
+                            "Cannot convert tag value {:?} to DataMember for {}",
+                            raw_value, data_member_name
+                        );
                     }
-                };
+                }
+                // Still store in $val hash as U16 for index reference
+                let data_member_value = data_member_value.unwrap_or(DataMemberValue::U16(0));
 
-                // Store in DataMember system if this tag is a DataMember
+                // Store in DataMember system if this tag is a DataMember, once
+                // the evaluator no longer borrows it
                 if let Some(data_member_name) = &tag_def.data_member {
-                    // Store in global DataMember collection (need to refactor to avoid borrow conflicts)
                     debug!(
-                        "Would store DataMember '{}' = {:?} from tag {}",
+                        "Storing DataMember '{}' = {:?} from tag {}",
                         data_member_name, raw_value, tag_def.name
                     );
-                    // TODO: Fix borrowing issue - need to restructure the evaluation
+                    new_data_members.push((data_member_name.clone(), data_member_value.clone()));
                 }
 
                 // Store in $val hash for current block references
@@ -219,8 +212,14 @@ impl ExifReader {
                 } else {
                     group_0.clone()
                 };
-                let source_info =
-                    TagSourceInfo::new(group_0, "BinaryData".to_string(), "BinaryData".to_string());
+                // The table's family 1 group (e.g. "Canon") is the IFD name
+                // that TagSourceInfo::get_group1 resolves
+                let ifd_name = table
+                    .groups
+                    .get(&1)
+                    .cloned()
+                    .unwrap_or_else(|| group_0.clone());
+                let source_info = TagSourceInfo::new(group_0, ifd_name, "BinaryData".to_string());
                 let key = (index as u16, namespace);
                 self.extracted_tags.insert(key.clone(), final_value);
                 self.tag_sources.insert(key.clone(), source_info);
@@ -234,6 +233,7 @@ impl ExifReader {
             }
         }
 
+        self.data_members.extend(new_data_members);
         Ok(())
     }
 
@@ -289,8 +289,118 @@ impl ExifReader {
         format: BinaryDataFormat,
         _byte_order: ByteOrder,
     ) -> Result<TagValue> {
-        // Use existing extract_binary_value from Canon module
-        crate::implementations::canon::extract_binary_value(self, offset, format, 1)
+        self.extract_binary_value(offset, format)
+    }
+
+    /// Extract one value of the given format at `offset` in the EXIF data
+    /// Used by binary data processing to extract individual values
+    pub(crate) fn extract_binary_value(
+        &self,
+        offset: usize,
+        format: BinaryDataFormat,
+    ) -> Result<TagValue> {
+        let data = self.get_data();
+        let byte_order = if let Some(header) = self.get_header() {
+            header.byte_order
+        } else {
+            // Default to little-endian when no header is available (common for test scenarios)
+            ByteOrder::LittleEndian
+        };
+
+        match format {
+            BinaryDataFormat::Int8u => {
+                if offset >= data.len() {
+                    return Err(ExifError::ParseError(
+                        "Offset beyond data bounds".to_string(),
+                    ));
+                }
+                Ok(TagValue::U8(data[offset]))
+            }
+            BinaryDataFormat::Int8s => {
+                if offset >= data.len() {
+                    return Err(ExifError::ParseError(
+                        "Offset beyond data bounds".to_string(),
+                    ));
+                }
+                // TagValue doesn't have I8, so store as I16
+                Ok(TagValue::I16(data[offset] as i8 as i16))
+            }
+            BinaryDataFormat::Int16u => {
+                if offset + 2 > data.len() {
+                    return Err(ExifError::ParseError(
+                        "Offset beyond data bounds for int16u".to_string(),
+                    ));
+                }
+                let value = byte_order.read_u16(data, offset)?;
+                Ok(TagValue::U16(value))
+            }
+            BinaryDataFormat::Int16s => {
+                if offset + 2 > data.len() {
+                    return Err(ExifError::ParseError(
+                        "Offset beyond data bounds for int16s".to_string(),
+                    ));
+                }
+                let value = byte_order.read_u16(data, offset)? as i16;
+                Ok(TagValue::I16(value))
+            }
+            BinaryDataFormat::Int32u => {
+                if offset + 4 > data.len() {
+                    return Err(ExifError::ParseError(
+                        "Offset beyond data bounds for int32u".to_string(),
+                    ));
+                }
+                let value = byte_order.read_u32(data, offset)?;
+                Ok(TagValue::U32(value))
+            }
+            BinaryDataFormat::Int32s => {
+                if offset + 4 > data.len() {
+                    return Err(ExifError::ParseError(
+                        "Offset beyond data bounds for int32s".to_string(),
+                    ));
+                }
+                let value = byte_order.read_u32(data, offset)? as i32;
+                Ok(TagValue::I32(value))
+            }
+            BinaryDataFormat::String => {
+                // Extract null-terminated string
+                if offset >= data.len() {
+                    return Err(ExifError::ParseError(
+                        "Offset beyond data bounds for string".to_string(),
+                    ));
+                }
+
+                let mut end = offset;
+                while end < data.len() && data[end] != 0 {
+                    end += 1;
+                }
+
+                let string_bytes = &data[offset..end];
+                let string_value = String::from_utf8_lossy(string_bytes).to_string();
+                Ok(TagValue::String(string_value))
+            }
+            BinaryDataFormat::PString => {
+                // Pascal string: first byte is length
+                if offset >= data.len() {
+                    return Err(ExifError::ParseError(
+                        "Offset beyond data bounds for pstring".to_string(),
+                    ));
+                }
+
+                let length = data[offset] as usize;
+                if offset + 1 + length > data.len() {
+                    return Err(ExifError::ParseError(
+                        "Pascal string length exceeds data bounds".to_string(),
+                    ));
+                }
+
+                let string_bytes = &data[offset + 1..offset + 1 + length];
+                let string_value = String::from_utf8_lossy(string_bytes).to_string();
+                Ok(TagValue::String(string_value))
+            }
+            _ => Err(ExifError::ParseError(format!(
+                "Binary format {format:?} not yet implemented"
+            ))),
+        }
     }
 
     /// Extract an array of binary values
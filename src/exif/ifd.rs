@@ -10,14 +10,80 @@
 //!
 //! ExifTool Reference: lib/Image/ExifTool/Exif.pm IFD processing
 
+#[cfg(feature = "olympus")]
 use crate::implementations::olympus;
+use crate::implementations::raw_conv;
+use crate::implementations::ricoh::{detect_ricoh_signature, is_ricoh_makernote};
 use crate::tiff_types::{ByteOrder, IfdEntry, TiffFormat};
-use crate::types::{DirectoryInfo, ExifError, Result, TagValue};
+use crate::types::{DirectoryInfo, ExifCharset, ExifError, Result, TagValue};
 use crate::value_extraction;
 use tracing::{debug, trace, warn};
 
 use super::ExifReader;
 
+/// Decode an ASCII value's bytes in `charset`, trimmed like
+/// [`value_extraction::extract_ascii_value`] does
+/// ExifTool: Exif.pm ProcessExif, CharsetEXIF recoding of string values
+fn decode_ascii(bytes: &[u8], charset: ExifCharset, tag_id: u16) -> String {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    let value = charset.decode(&bytes[..end]);
+    // ImageDescription (0x010E) keeps its whitespace
+    if tag_id == 0x010E {
+        value
+    } else {
+        value.trim().to_string()
+    }
+}
+
+/// Size of the manufacturer header in front of the MakerNotes IFD
+/// ExifTool: MakerNotes.pm signature conditions (MakerNoteOlympus*, MakerNoteRicoh*, MakerNoteSony*)
+fn maker_note_header_size(make: &str, maker_notes_data: &[u8]) -> usize {
+    #[cfg(feature = "olympus")]
+    if let Some(signature) = olympus::detect_olympus_signature(make, maker_notes_data) {
+        debug!(
+            "Detected Olympus signature: {:?}, data_offset: {}, base_offset: {}",
+            signature,
+            signature.data_offset(),
+            signature.base_offset()
+        );
+        return signature.data_offset();
+    }
+    if let Some(signature) = detect_ricoh_signature(make, maker_notes_data) {
+        debug!(
+            "Detected RICOH signature: {:?}, data_offset: {}, base_offset: {}",
+            signature,
+            signature.data_offset(),
+            signature.base_offset()
+        );
+        return signature.data_offset();
+    }
+    #[cfg(feature = "olympus")]
+    if olympus::is_olympus_makernote(make) {
+        // Fallback for Olympus cameras without proper signature
+        debug!(
+            "Olympus camera detected via Make field but no signature found, using default offset"
+        );
+        return 0;
+    }
+    if is_ricoh_makernote(make) {
+        // Fallback for RICOH cameras without recognized signature
+        debug!("RICOH camera detected via Make field but no signature found, using default offset");
+        return 0;
+    }
+    #[cfg(feature = "sony")]
+    if let Some(signature) =
+        crate::implementations::sony::detect_sony_signature(make, maker_notes_data)
+    {
+        debug!(
+            "Detected Sony signature: {:?}, data_offset: {}",
+            signature,
+            signature.data_offset()
+        );
+        return signature.data_offset();
+    }
+    0
+}
+
 impl ExifReader {
     /// Process MakerNotes with manufacturer signature detection and offset adjustment
     /// ExifTool: MakerNotes.pm manufacturer-specific processing
@@ -27,12 +93,10 @@ impl ExifReader {
         _byte_order: ByteOrder,
         ifd_name: &str,
     ) -> Result<()> {
-        use crate::implementations::olympus::{detect_olympus_signature, is_olympus_makernote};
-        use crate::implementations::ricoh::{detect_ricoh_signature, is_ricoh_makernote};
-        use crate::implementations::sony::{detect_sony_signature, is_sony_makernote};
-
         let offset = entry.value_or_offset as usize;
         let size = entry.count as usize;
+        let _stage = crate::stats::stage("MakerNotes");
+        crate::stats::add_parsed_bytes(size as u64);
 
         // Store the original MakerNotes offset for subdirectory calculations
         // ExifTool: Subdirectory offsets are relative to this position
@@ -45,13 +109,6 @@ impl ExifReader {
             .unwrap_or_default()
             .to_string();
 
-        // Extract model from Model tag for signature detection
-        let model = self
-            .get_tag_across_namespaces(0x0110) // Model tag
-            .and_then(|v| v.as_string())
-            .unwrap_or_default()
-            .to_string();
-
         debug!(
             "Processing UNDEFINED subdirectory tag {:#x} (MakerNotes) from {}: offset={:#x}, size={}, make='{}'",
             entry.tag_id, ifd_name, offset, size, make
@@ -68,56 +125,9 @@ impl ExifReader {
         }
 
         let maker_notes_data = self.data[offset..offset + size].to_vec();
-        let mut adjusted_offset = offset;
-        let mut _adjusted_base = 0i64;
 
         // Detect manufacturer signatures and apply offset adjustments
-        if let Some(signature) = detect_olympus_signature(&make, &maker_notes_data) {
-            let data_offset = signature.data_offset();
-            let base_offset = signature.base_offset();
-
-            adjusted_offset = offset + data_offset;
-            _adjusted_base = base_offset as i64;
-
-            debug!(
-                "Detected Olympus signature: {:?}, data_offset: {}, base_offset: {}, adjusted_offset: {:#x}",
-                signature, data_offset, base_offset, adjusted_offset
-            );
-        } else if let Some(signature) = detect_ricoh_signature(&make, &maker_notes_data) {
-            let data_offset = signature.data_offset();
-            let base_offset = signature.base_offset();
-
-            adjusted_offset = offset + data_offset;
-            _adjusted_base = base_offset;
-
-            debug!(
-                "Detected RICOH signature: {:?}, data_offset: {}, base_offset: {}, adjusted_offset: {:#x}",
-                signature, data_offset, base_offset, adjusted_offset
-            );
-        } else if is_olympus_makernote(&make) {
-            // Fallback for Olympus cameras without proper signature
-            debug!("Olympus camera detected via Make field but no signature found, using default offset");
-        } else if is_ricoh_makernote(&make) {
-            // Fallback for RICOH cameras without recognized signature
-            debug!(
-                "RICOH camera detected via Make field but no signature found, using default offset"
-            );
-        } else if let Some(signature) = detect_sony_signature(&make, &maker_notes_data) {
-            let data_offset = signature.data_offset();
-
-            adjusted_offset = offset + data_offset;
-            _adjusted_base = 0; // Sony signatures don't use base offset
-
-            debug!(
-                "Detected Sony signature: {:?}, data_offset: {}, adjusted_offset: {:#x}",
-                signature, data_offset, adjusted_offset
-            );
-        } else if is_sony_makernote(&make, &model) {
-            // Fallback for Sony cameras without recognized signature
-            debug!(
-                "Sony camera detected via Make field but no signature found, using default offset"
-            );
-        }
+        let adjusted_offset = offset + maker_note_header_size(&make, &maker_notes_data);
 
         // Validate adjusted offset
         if adjusted_offset >= self.data.len() {
@@ -167,15 +177,25 @@ impl ExifReader {
 
         // Manufacturer-specific MakerNotes processing based on Make field
         // ExifTool: MakerNotes.pm conditional dispatch based on $$self{Make}
-        if make.starts_with("Canon") {
+        // Makers whose cargo feature is disabled get the generic processing
+        if cfg!(feature = "canon") && make.starts_with("Canon") {
             debug!("Detected Canon camera, calling Canon-specific MakerNotes processing");
             // Register before descending: parse_ifd below re-enters
             // parse_ifd_entry -> this function for nested 0x927C tags.
             self.processed.insert(addr, "MakerNotes".to_string());
             // Call Canon-specific processing directly
             // ExifTool: Canon.pm Main table processing
+            #[cfg(feature = "canon")]
             crate::implementations::canon::process_canon_makernotes(self, adjusted_offset, size)?;
-        } else if make.starts_with("OLYMPUS") || make == "OM Digital Solutions" {
+        } else if crate::implementations::apple::is_apple_makernote(&maker_notes_data) {
+            debug!("Detected Apple signature, calling Apple-specific MakerNotes processing");
+            // ExifTool: MakerNoteApple uses Base => '$start - 14', so hand over the
+            // whole maker note (header included) rather than the IFD start.
+            self.processed.insert(addr, "MakerNotes".to_string());
+            crate::implementations::apple::process_apple_makernotes(self, offset, size)?;
+        } else if cfg!(feature = "olympus")
+            && (make.starts_with("OLYMPUS") || make == "OM Digital Solutions")
+        {
             debug!("Detected Olympus camera, calling MakerNotes conditional dispatch");
 
             // Use the MakerNotes conditional dispatch system instead of generic processing
@@ -200,7 +220,7 @@ impl ExifReader {
                 let source_info = self.create_tag_source_info("MakerNotes");
                 self.store_tag_with_precedence(synthetic_tag_id, tag_value, source_info);
             }
-        } else if make.starts_with("SONY") || is_sony_makernote(&make, &model) {
+        } else if cfg!(feature = "sony") && make.starts_with("SONY") {
             debug!("Detected Sony camera, calling Sony-specific MakerNotes processing");
             // Register before descending (see Canon branch above).
             self.processed.insert(addr, "MakerNotes".to_string());
@@ -218,7 +238,10 @@ impl ExifReader {
             debug!(
                 "Calling Sony subdirectory processing for Tag2010, Tag9050, AFInfo and other binary sections"
             );
+            #[cfg(feature = "sony")]
             crate::implementations::sony::process_sony_subdirectory_tags(self)?;
+        } else if self.process_table_makernotes(&make, &maker_notes_data, offset, size)? {
+            self.processed.insert(addr, "MakerNotes".to_string());
         } else {
             // Fall back to generic tag kit processing for other manufacturers
             debug!("Non-Canon/Olympus camera, using generic MakerNotes processing");
@@ -237,6 +260,63 @@ impl ExifReader {
 
         Ok(())
     }
+
+    /// Process Kodak, Casio, Leica, Ricoh, FujiFilm and Minolta maker notes, which
+    /// are read straight from a single generated table
+    ///
+    /// Returns false when the maker note isn't one of these, or when the
+    /// maker's feature is disabled.
+    /// ExifTool: MakerNotes.pm MakerNoteCasio*, MakerNoteKodak*, MakerNoteLeica*,
+    /// MakerNoteRicoh, MakerNoteFujiFilm, MakerNoteMinolta2
+    #[cfg_attr(not(feature = "other-makers"), allow(unused_variables))]
+    fn process_table_makernotes(
+        &mut self,
+        make: &str,
+        maker_notes_data: &[u8],
+        offset: usize,
+        size: usize,
+    ) -> Result<bool> {
+        #[cfg(feature = "other-makers")]
+        {
+            use crate::implementations::{casio, kodak, leica, ricoh};
+
+            if let Some(layout) = casio::detect_casio_makernote(make, maker_notes_data) {
+                casio::process_casio_makernotes(self, offset, layout)?;
+                return Ok(true);
+            }
+            let model = self
+                .get_tag_across_namespaces(0x0110) // Model tag
+                .and_then(|v| v.as_string())
+                .unwrap_or_default()
+                .to_string();
+            if let Some(layout) = kodak::detect_kodak_makernote(make, &model, maker_notes_data) {
+                kodak::process_kodak_makernotes(self, offset, size, layout)?;
+                return Ok(true);
+            }
+            if let Some(layout) = leica::detect_leica_makernote(make, &model, maker_notes_data) {
+                leica::process_leica_makernotes(self, offset, size, layout)?;
+                return Ok(true);
+            }
+            if detect_ricoh_signature(make, maker_notes_data)
+                == Some(ricoh::RicohSignature::StandardIfd)
+            {
+                ricoh::process_ricoh_makernotes(self, offset)?;
+                return Ok(true);
+            }
+        }
+        #[cfg(feature = "fujifilm")]
+        if crate::implementations::fujifilm::detect_fujifilm_makernote(maker_notes_data) {
+            crate::implementations::fujifilm::process_fujifilm_makernotes(self, offset, size)?;
+            return Ok(true);
+        }
+        #[cfg(feature = "olympus")]
+        if crate::implementations::minolta::is_minolta_olympus_makernote(maker_notes_data) {
+            crate::implementations::minolta::process_minolta_makernotes(self, offset)?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
     /// Process a subdirectory with recursion prevention
     /// ExifTool: ProcessDirectory with PROCESSED tracking
     pub(crate) fn process_subdirectory(&mut self, dir_info: &DirectoryInfo) -> Result<()> {
@@ -321,6 +401,7 @@ impl ExifReader {
                 "Short directory size for {ifd_name} (missing {} bytes)",
                 dir_end - self.data.len()
             ));
+            self.note_truncation(format!("{ifd_name} directory"), ifd_offset, dir_size);
         }
 
         // Process each IFD entry
@@ -391,7 +472,7 @@ impl ExifReader {
 
     /// Parse a single IFD entry and extract tag value
     /// ExifTool: lib/Image/ExifTool/Exif.pm:6347-6570 entry processing
-    fn parse_ifd_entry(
+    pub(super) fn parse_ifd_entry(
         &mut self,
         entry_offset: usize,
         byte_order: ByteOrder,
@@ -427,12 +508,20 @@ impl ExifReader {
         // ExifTool: lib/Image/ExifTool/Exif.pm:6390-6570 value extraction
         match entry.format {
             TiffFormat::Ascii => {
-                let value = value_extraction::extract_ascii_value(
-                    &self.data,
-                    &entry,
-                    byte_order,
-                    entry.tag_id,
-                )?;
+                let value = match self.charset_exif {
+                    ExifCharset::Utf8 => value_extraction::extract_ascii_value(
+                        &self.data,
+                        &entry,
+                        byte_order,
+                        entry.tag_id,
+                    )?,
+                    charset => {
+                        let bytes = value_extraction::extract_byte_array_value(
+                            &self.data, &entry, byte_order,
+                        )?;
+                        decode_ascii(&bytes, charset, entry.tag_id)
+                    }
+                };
                 // debug!("ASCII tag {:#x} extracted value: {:?} (length: {})", entry.tag_id, value, value.len());
 
                 // Store ASCII strings including empty ones - ExifTool behavior
@@ -444,7 +533,7 @@ impl ExifReader {
                     ifd_name,
                     tag_value
                 );
-                let source_info = self.create_tag_source_info(ifd_name);
+                let source_info = self.create_entry_source_info(ifd_name, &entry, entry_offset);
                 self.store_tag_with_precedence(entry.tag_id, tag_value, source_info);
             }
             TiffFormat::Byte => {
@@ -483,7 +572,7 @@ impl ExifReader {
                     entry.count,
                     tag_value
                 );
-                let source_info = self.create_tag_source_info(ifd_name);
+                let source_info = self.create_entry_source_info(ifd_name, &entry, entry_offset);
                 self.store_tag_with_precedence(entry.tag_id, tag_value, source_info);
             }
             TiffFormat::Short => {
@@ -501,7 +590,7 @@ impl ExifReader {
                         tag_value
                     );
 
-                    let source_info = self.create_tag_source_info(ifd_name);
+                    let source_info = self.create_entry_source_info(ifd_name, &entry, entry_offset);
                     self.store_tag_with_precedence(entry.tag_id, tag_value.clone(), source_info);
 
                     // Check for NEF -> NRW conversion
@@ -531,7 +620,7 @@ impl ExifReader {
                         entry.count
                     );
 
-                    let source_info = self.create_tag_source_info(ifd_name);
+                    let source_info = self.create_entry_source_info(ifd_name, &entry, entry_offset);
                     self.store_tag_with_precedence(entry.tag_id, tag_value, source_info);
                 }
             }
@@ -564,7 +653,7 @@ impl ExifReader {
                         ifd_name,
                         tag_value
                     );
-                    let source_info = self.create_tag_source_info(ifd_name);
+                    let source_info = self.create_entry_source_info(ifd_name, &entry, entry_offset);
                     self.store_tag_with_precedence(entry.tag_id, tag_value, source_info);
                 } else {
                     // Multiple LONG values - extract as array
@@ -604,7 +693,7 @@ impl ExifReader {
                         entry.count,
                         tag_value
                     );
-                    let source_info = self.create_tag_source_info(ifd_name);
+                    let source_info = self.create_entry_source_info(ifd_name, &entry, entry_offset);
                     self.store_tag_with_precedence(entry.tag_id, tag_value, source_info);
                 }
             }
@@ -620,7 +709,7 @@ impl ExifReader {
                     ifd_name,
                     value
                 );
-                let source_info = self.create_tag_source_info(ifd_name);
+                let source_info = self.create_entry_source_info(ifd_name, &entry, entry_offset);
                 self.store_tag_with_precedence(entry.tag_id, value, source_info);
             }
             TiffFormat::SRational => {
@@ -635,7 +724,7 @@ impl ExifReader {
                     ifd_name,
                     value
                 );
-                let source_info = self.create_tag_source_info(ifd_name);
+                let source_info = self.create_entry_source_info(ifd_name, &entry, entry_offset);
                 self.store_tag_with_precedence(entry.tag_id, value, source_info);
             }
             TiffFormat::Undefined => {
@@ -714,7 +803,8 @@ impl ExifReader {
                             &self.data, &entry, byte_order,
                         ) {
                             let tag_value = TagValue::Binary(binary_data);
-                            let source_info = self.create_tag_source_info(ifd_name);
+                            let source_info =
+                                self.create_entry_source_info(ifd_name, &entry, entry_offset);
                             self.store_tag_with_precedence(entry.tag_id, tag_value, source_info);
                             debug!(
                                 "Extracted UNDEFINED tag {:#x} ({}) as binary data ({} bytes)",
@@ -769,10 +859,30 @@ impl ExifReader {
                                     );
                                     TagValue::String(ascii_string)
                                 }
+                                0x0002 if ifd_name == "InteropIFD" => {
+                                    // InteropVersion - same conversion as ExifVersion
+                                    // ExifTool: lib/Image/ExifTool/Exif.pm InteropIFD 0x0002 RawConv
+                                    let ascii_string: String = binary_data
+                                        .iter()
+                                        .map(|&b| b as char)
+                                        .collect::<String>()
+                                        .trim_end_matches('\0')
+                                        .to_string();
+                                    TagValue::String(ascii_string)
+                                }
+                                0xA462 => {
+                                    // CompositeImageExposureTimes - rational list with two int16u counts
+                                    // ExifTool: lib/Image/ExifTool/Exif.pm 0xa462 RawConv
+                                    raw_conv::composite_image_exposure_times(
+                                        &binary_data,
+                                        byte_order,
+                                    )
+                                }
                                 _ => TagValue::Binary(binary_data),
                             };
 
-                            let source_info = self.create_tag_source_info(ifd_name);
+                            let source_info =
+                                self.create_entry_source_info(ifd_name, &entry, entry_offset);
                             self.store_tag_with_precedence(entry.tag_id, tag_value, source_info);
                             trace!(
                                 "Extracted UNDEFINED tag {:#x} from {}: {} bytes",
@@ -859,7 +969,7 @@ impl ExifReader {
                 }
 
                 // Store raw value to avoid double conversion - conversions are applied in get_all_tag_entries
-                let source_info = self.create_tag_source_info(ifd_name);
+                let source_info = self.create_entry_source_info(ifd_name, &entry, entry_offset);
                 self.store_tag_with_precedence(entry.tag_id, tag_value, source_info);
             }
             _ => {
@@ -927,6 +1037,10 @@ impl ExifReader {
             ifd_name: ifd_name.to_string(),
             priority: crate::types::SourcePriority::Exif,
             processor_name: "IFD".to_string(),
+            value_offset: None,
+            format: None,
+            count: None,
+            print_conv: None,
         };
         Self::generate_tag_prefix_name(tag_id, Some(&source_info))
     }
@@ -964,6 +1078,7 @@ impl ExifReader {
 
     /// Check if we're currently processing Olympus MakerNotes
     /// ExifTool: lib/Image/ExifTool/Olympus.pm FixFormat processing context
+    #[cfg(feature = "olympus")]
     fn is_olympus_makernotes_context(&self, ifd_name: &str) -> bool {
         // Check if the IFD name indicates Olympus MakerNotes
         if ifd_name.contains("MakerNotes") || ifd_name.starts_with("Olympus") {
@@ -976,4 +1091,10 @@ impl ExifReader {
         }
         false
     }
+
+    /// Olympus MakerNotes are only decoded with the `olympus` feature
+    #[cfg(not(feature = "olympus"))]
+    fn is_olympus_makernotes_context(&self, _ifd_name: &str) -> bool {
+        false
+    }
 }
@@ -0,0 +1,470 @@
+//! Incremental EXIF reading
+//!
+//! [`ExifReader::parse_exif_data`] decodes and converts every tag in every
+//! directory, including maker notes. Catalog software that only needs a handful
+//! of tags per file pays for all of them. [`LazyExifReader`] parses the TIFF
+//! header, indexes the standard directories (IFD0, ExifIFD, GPS, InteropIFD,
+//! IFD1) by reading only their 12-byte entries, and decodes a tag's value and
+//! conversions when it is requested. A TIFF file is read the same way: only
+//! the header, the directories and the requested values come off disk, and
+//! only the value being decoded is held in memory.
+//!
+//! Maker note tags are not available through this API; use
+//! [`crate::formats::extract_metadata`] for those.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::sync::LazyLock;
+
+use super::ExifReader;
+use crate::generated::Exif_pm::main_tags::EXIF_MAIN_TAGS;
+use crate::generated::GPS_pm::main_tags::GPS_MAIN_TAGS;
+use crate::tiff_types::{IfdEntry, TiffHeader};
+use crate::types::{ExifError, Result, TagEntry};
+
+/// Subdirectory pointers followed when indexing: (tag ID, parent, child)
+/// ExifTool: lib/Image/ExifTool/Exif.pm ExifOffset, GPSInfo and InteropOffset SubDirectory tags
+const POINTERS: &[(u16, &str, &str)] = &[
+    (0x8769, "IFD0", "ExifIFD"),
+    (0x8825, "IFD0", "GPS"),
+    (0xA005, "ExifIFD", "InteropIFD"),
+];
+
+/// Directories searched, in order, for an Exif::Main tag requested by name
+const EXIF_DIRECTORIES: &[&str] = &["IFD0", "ExifIFD", "InteropIFD", "IFD1"];
+
+static EXIF_TAG_IDS: LazyLock<HashMap<&'static str, u16>> =
+    LazyLock::new(|| tag_ids_by_name(&EXIF_MAIN_TAGS));
+static GPS_TAG_IDS: LazyLock<HashMap<&'static str, u16>> =
+    LazyLock::new(|| tag_ids_by_name(&GPS_MAIN_TAGS));
+
+fn tag_ids_by_name(tags: &HashMap<u16, crate::types::TagInfo>) -> HashMap<&'static str, u16> {
+    let mut ids = HashMap::new();
+    for (&id, info) in tags {
+        // Keep the lowest ID when several share a name, for deterministic lookups
+        ids.entry(info.name)
+            .and_modify(|existing: &mut u16| *existing = (*existing).min(id))
+            .or_insert(id);
+    }
+    ids
+}
+
+/// An IFD entry as indexed: its offset and its 12 bytes, value not decoded
+#[derive(Debug, Clone, Copy)]
+struct RawEntry {
+    offset: usize,
+    bytes: [u8; 12],
+}
+
+/// (tag ID, entry) for each entry of an IFD
+type EntryIndex = Vec<(u16, RawEntry)>;
+
+/// (directory, tag ID) -> (directory offset, entry)
+type TagIndex = HashMap<(&'static str, u16), (usize, RawEntry)>;
+
+/// An indexed IFD: where each entry is, without its value decoded
+#[derive(Debug)]
+struct Directory {
+    name: &'static str,
+    offset: usize,
+    entries: EntryIndex,
+}
+
+/// Where the TIFF data is read from
+#[derive(Debug)]
+enum Source {
+    /// EXIF block already in memory
+    Memory(Vec<u8>),
+    /// TIFF file read on demand, and its length
+    File(File, usize),
+}
+
+impl Source {
+    fn len(&self) -> usize {
+        match self {
+            Source::Memory(data) => data.len(),
+            Source::File(_, len) => *len,
+        }
+    }
+
+    /// Read `len` bytes at `offset`, or fewer where the data ends first
+    fn read_at(&mut self, offset: usize, len: usize) -> Result<Vec<u8>> {
+        let end = offset.saturating_add(len).min(self.len());
+        if offset >= end {
+            return Ok(Vec::new());
+        }
+        match self {
+            Source::Memory(data) => Ok(data[offset..end].to_vec()),
+            Source::File(file, _) => {
+                let mut data = vec![0u8; end - offset];
+                file.seek(SeekFrom::Start(offset as u64))?;
+                file.read_exact(&mut data)?;
+                Ok(data)
+            }
+        }
+    }
+}
+
+/// EXIF reader that decodes tags on demand
+///
+/// # Example
+/// ```no_run
+/// use exif_oxide::exif::LazyExifReader;
+///
+/// let mut reader = LazyExifReader::open(std::path::Path::new("photo.jpg"))?;
+/// if let Some(date) = reader.get("DateTimeOriginal")? {
+///     println!("Taken {}", date.print());
+/// }
+/// # Ok::<(), exif_oxide::ExifError>(())
+/// ```
+#[derive(Debug)]
+pub struct LazyExifReader {
+    /// Decodes one entry at a time: `reader.data` holds only the entry being
+    /// decoded and its value
+    reader: ExifReader,
+    source: Source,
+    directories: Option<Vec<Directory>>,
+    tag_index: TagIndex,
+    cache: HashMap<(&'static str, u16), Option<TagEntry>>,
+}
+
+impl LazyExifReader {
+    /// Create a reader over TIFF-structured EXIF data (starting at the TIFF header)
+    ///
+    /// Only the header is parsed here.
+    pub fn new(tiff_data: Vec<u8>) -> Result<Self> {
+        Self::with_source(Source::Memory(tiff_data))
+    }
+
+    fn with_source(mut source: Source) -> Result<Self> {
+        let header = TiffHeader::parse(&source.read_at(0, 8)?)?;
+        let mut reader = ExifReader::new();
+        reader.header = Some(header);
+        Ok(Self {
+            reader,
+            source,
+            directories: None,
+            tag_index: HashMap::new(),
+            cache: HashMap::new(),
+        })
+    }
+
+    /// Open a JPEG or TIFF-based file, reading only its EXIF block
+    ///
+    /// A TIFF-based file isn't read up front: its directories are read when
+    /// first indexed, and each value when its tag is requested.
+    pub fn open(path: &Path) -> Result<Self> {
+        let mut file = BufReader::new(File::open(path)?);
+        let mut magic = [0u8; 2];
+        file.read_exact(&mut magic)?;
+        file.seek(SeekFrom::Start(0))?;
+
+        if magic == [0xFF, 0xD8] {
+            let (segment, _) = crate::formats::scan_jpeg_segments(&mut file)?;
+            let segment = segment.filter(|segment| segment.has_exif).ok_or_else(|| {
+                ExifError::InvalidFormat("No EXIF data found in JPEG file".to_string())
+            })?;
+            file.seek(SeekFrom::Start(segment.offset))?;
+            let mut exif_data = vec![0u8; segment.length as usize];
+            file.read_exact(&mut exif_data)?;
+            let mut lazy = Self::new(exif_data)?;
+            lazy.reader.set_base_offset(segment.offset);
+            Ok(lazy)
+        } else {
+            let file = file.into_inner();
+            let len = file.metadata()?.len() as usize;
+            Self::with_source(Source::File(file, len))
+        }
+    }
+
+    /// The parsed TIFF header
+    pub fn header(&self) -> &TiffHeader {
+        self.reader
+            .header
+            .as_ref()
+            .expect("header is parsed in LazyExifReader::new")
+    }
+
+    /// Names of the directories present, in indexing order
+    pub fn directories(&mut self) -> Vec<&'static str> {
+        self.index().iter().map(|dir| dir.name).collect()
+    }
+
+    /// Tag IDs present in a directory, without decoding their values
+    pub fn tag_ids(&mut self, directory: &str) -> Vec<u16> {
+        self.index()
+            .iter()
+            .find(|dir| dir.name == directory)
+            .map(|dir| dir.entries.iter().map(|&(id, _)| id).collect())
+            .unwrap_or_default()
+    }
+
+    /// Decode a tag by its ExifTool name (e.g., "DateTimeOriginal", "GPSLatitude")
+    ///
+    /// Exif::Main tags are looked up in IFD0, ExifIFD, InteropIFD and IFD1, in
+    /// that order; GPS tags in the GPS directory. Returns `None` if the tag
+    /// isn't present.
+    pub fn get(&mut self, tag_name: &str) -> Result<Option<TagEntry>> {
+        if let Some(&id) = GPS_TAG_IDS.get(tag_name) {
+            if let Some(entry) = self.get_by_id("GPS", id)? {
+                return Ok(Some(entry));
+            }
+        }
+        let Some(&id) = EXIF_TAG_IDS.get(tag_name) else {
+            return Ok(None);
+        };
+        for directory in EXIF_DIRECTORIES {
+            if let Some(entry) = self.get_by_id(directory, id)? {
+                return Ok(Some(entry));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Decode several tags by name, skipping those that aren't present
+    pub fn get_many(&mut self, tag_names: &[&str]) -> Result<Vec<TagEntry>> {
+        let mut entries = Vec::new();
+        for name in tag_names {
+            entries.extend(self.get(name)?);
+        }
+        Ok(entries)
+    }
+
+    /// Decode the tag with this ID from a directory
+    ///
+    /// Subdirectory pointers (ExifOffset, GPSInfo, InteropOffset) aren't tags
+    /// of their own and return `None`.
+    pub fn get_by_id(&mut self, directory: &str, tag_id: u16) -> Result<Option<TagEntry>> {
+        if POINTERS
+            .iter()
+            .any(|&(id, parent, _)| id == tag_id && parent == directory)
+        {
+            return Ok(None);
+        }
+        let Some(name) = self
+            .index()
+            .iter()
+            .find(|dir| dir.name == directory)
+            .map(|dir| dir.name)
+        else {
+            return Ok(None);
+        };
+        let Some(&(dir_offset, raw)) = self.tag_index.get(&(name, tag_id)) else {
+            return Ok(None);
+        };
+
+        if let Some(cached) = self.cache.get(&(name, tag_id)) {
+            return Ok(cached.clone());
+        }
+
+        // Decode from a buffer holding just the value and the entry, pointed at
+        // the value's new position; `value_pos` is where the value is in the file
+        let byte_order = self.header().byte_order;
+        let entry = IfdEntry::parse(&raw.bytes, 0, byte_order)?;
+        let (entry_offset, value_pos) = if entry.is_inline() {
+            self.reader.data = raw.bytes.to_vec();
+            (0, raw.offset + 8)
+        } else {
+            let value_pos = entry.value_or_offset as usize;
+            let mut data = self.source.read_at(value_pos, entry.data_size() as usize)?;
+            let entry_offset = data.len();
+            data.extend_from_slice(&raw.bytes[..8]);
+            data.extend_from_slice(&[0; 4]);
+            self.reader.data = data;
+            (entry_offset, value_pos)
+        };
+        self.reader
+            .parse_ifd_entry(entry_offset, byte_order, name, 0, dir_offset)?;
+        let file_pos = self.reader.base + value_pos as u64;
+        for source in self.reader.tag_sources.values_mut() {
+            if source.value_offset.is_some() {
+                source.value_offset = Some(file_pos);
+            }
+        }
+
+        // Convert the one tag just stored, then drop it: the cache keeps the entry
+        let entry = self.reader.get_all_tag_entries().into_iter().next();
+        self.reader.extracted_tags.clear();
+        self.reader.tag_sources.clear();
+        self.reader.duplicate_tags.clear();
+        self.cache.insert((name, tag_id), entry.clone());
+        Ok(entry)
+    }
+
+    /// Index the standard directories on first use
+    fn index(&mut self) -> &[Directory] {
+        if self.directories.is_none() {
+            let directories = self.build_index();
+            self.tag_index = directories
+                .iter()
+                .flat_map(|dir| {
+                    dir.entries
+                        .iter()
+                        .map(move |&(id, raw)| ((dir.name, id), (dir.offset, raw)))
+                })
+                .collect();
+            self.directories = Some(directories);
+        }
+        self.directories.as_deref().unwrap_or_default()
+    }
+
+    fn build_index(&mut self) -> Vec<Directory> {
+        let mut directories = Vec::new();
+        let ifd0_offset = self.header().ifd0_offset as usize;
+        let byte_order = self.header().byte_order;
+        let mut ifd1_offset = None;
+        let mut pending = vec![("IFD0", ifd0_offset)];
+
+        while let Some((name, offset)) = pending.pop() {
+            // Graceful degradation: skip directories that can't be read
+            let Some((entries, next)) = self.read_directory(offset) else {
+                self.reader
+                    .warnings
+                    .push(format!("Could not index {name} at offset {offset:#x}"));
+                continue;
+            };
+            if name == "IFD0" {
+                ifd1_offset = next;
+            }
+            for &(pointer, parent, child) in POINTERS {
+                if parent != name || directories.iter().any(|d: &Directory| d.name == child) {
+                    continue;
+                }
+                if let Some((_, raw)) = entries.iter().find(|&&(id, _)| id == pointer) {
+                    if let Ok(child_offset) = byte_order.read_u32(&raw.bytes, 8) {
+                        pending.push((child, child_offset as usize));
+                    }
+                }
+            }
+            directories.push(Directory {
+                name,
+                offset,
+                entries,
+            });
+            if pending.is_empty() {
+                if let Some(offset) = ifd1_offset.take() {
+                    pending.push(("IFD1", offset));
+                }
+            }
+        }
+        directories
+    }
+
+    /// Read the entries of an IFD and its next-IFD offset
+    /// ExifTool: lib/Image/ExifTool/Exif.pm:6235-6349 directory entry loop
+    fn read_directory(&mut self, offset: usize) -> Option<(EntryIndex, Option<usize>)> {
+        let byte_order = self.header().byte_order;
+        let count = byte_order
+            .read_u16(&self.source.read_at(offset, 2).ok()?, 0)
+            .ok()? as usize;
+        let data = self.source.read_at(offset + 2, 12 * count + 4).ok()?;
+        let entries = data
+            .chunks_exact(12)
+            .take(count)
+            .enumerate()
+            .filter_map(|(index, bytes)| {
+                let id = byte_order.read_u16(bytes, 0).ok()?;
+                let raw = RawEntry {
+                    offset: offset + 2 + 12 * index,
+                    bytes: bytes.try_into().ok()?,
+                };
+                Some((id, raw))
+            })
+            .collect();
+        let next = byte_order
+            .read_u32(&data, 12 * count)
+            .ok()
+            .map(|next| next as usize)
+            .filter(|&next| next != 0 && next < self.source.len());
+        Some((entries, next))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TagValue;
+
+    /// Little-endian TIFF: IFD0 {Make, Model, ExifOffset}, ExifIFD {DateTimeOriginal}
+    fn tiff() -> Vec<u8> {
+        fn entry(tiff: &mut Vec<u8>, id: u16, format: u16, count: u32, value: u32) {
+            tiff.extend_from_slice(&id.to_le_bytes());
+            tiff.extend_from_slice(&format.to_le_bytes());
+            tiff.extend_from_slice(&count.to_le_bytes());
+            tiff.extend_from_slice(&value.to_le_bytes());
+        }
+        let mut tiff = b"II*\0".to_vec();
+        tiff.extend_from_slice(&8u32.to_le_bytes());
+        // IFD0 at 8: 3 entries, ends at 8 + 2 + 36 + 4 = 50
+        tiff.extend_from_slice(&3u16.to_le_bytes());
+        entry(&mut tiff, 0x010f, 2, 6, 50); // Make -> 50
+        entry(&mut tiff, 0x0110, 2, 4, u32::from_le_bytes(*b"X10\0")); // Model (inline)
+        entry(&mut tiff, 0x8769, 4, 1, 56); // ExifOffset -> 56
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+        tiff.extend_from_slice(b"Canon\0");
+        // ExifIFD at 56: 1 entry, ends at 56 + 2 + 12 + 4 = 74
+        tiff.extend_from_slice(&1u16.to_le_bytes());
+        entry(&mut tiff, 0x9003, 2, 20, 74); // DateTimeOriginal -> 74
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+        tiff.extend_from_slice(b"2024:06:01 12:34:56\0");
+        tiff
+    }
+
+    #[test]
+    fn test_lazy_get() {
+        let mut reader = LazyExifReader::new(tiff()).unwrap();
+        assert_eq!(reader.directories(), vec!["IFD0", "ExifIFD"]);
+        assert_eq!(reader.tag_ids("ExifIFD"), vec![0x9003]);
+
+        let date = reader.get("DateTimeOriginal").unwrap().unwrap();
+        assert_eq!(date.group1, "ExifIFD");
+        assert_eq!(date.value, TagValue::string("2024:06:01 12:34:56"));
+        // Nothing else has been decoded
+        assert_eq!(reader.cache.len(), 1);
+
+        let make = reader.get("Make").unwrap().unwrap();
+        assert_eq!(make.value, TagValue::string("Canon"));
+        assert_eq!(
+            reader.get("Model").unwrap().unwrap().print(),
+            &TagValue::string("X10")
+        );
+        assert_eq!(reader.cache.len(), 3);
+        assert!(reader.reader.extracted_tags.is_empty());
+
+        assert!(reader.get("Software").unwrap().is_none());
+        assert!(reader.get("GPSLatitude").unwrap().is_none());
+        assert!(reader.get("NotATag").unwrap().is_none());
+        assert!(reader.get_by_id("IFD0", 0x8769).unwrap().is_none());
+
+        let many = reader
+            .get_many(&["Make", "Software", "DateTimeOriginal"])
+            .unwrap();
+        let names: Vec<&str> = many.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, vec!["Make", "DateTimeOriginal"]);
+    }
+
+    #[test]
+    fn test_open_reads_on_demand() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut file, &tiff()).unwrap();
+
+        let mut reader = LazyExifReader::open(file.path()).unwrap();
+        let make = reader.get("Make").unwrap().unwrap();
+        assert_eq!(make.value, TagValue::string("Canon"));
+        // Only Make's value and entry are in memory, not the file
+        assert_eq!(reader.reader.data.len(), 6 + 12);
+
+        let date = reader.get("DateTimeOriginal").unwrap().unwrap();
+        assert_eq!(date.value, TagValue::string("2024:06:01 12:34:56"));
+        assert_eq!(&reader.reader.data[..20], b"2024:06:01 12:34:56\0");
+        // The tag's source still gives the value's position in the file
+        assert_eq!(date.source.and_then(|source| source.offset), Some(74));
+    }
+
+    #[test]
+    fn test_invalid_header() {
+        assert!(LazyExifReader::new(b"not a tiff".to_vec()).is_err());
+    }
+}
@@ -13,11 +13,13 @@
 
 mod binary_data;
 mod ifd;
+mod lazy;
 mod processors;
 pub mod subdirectory_processing;
 mod tags;
 
 // Only re-export what needs to be public - most functionality is internal
+pub use lazy::LazyExifReader;
 
 // use crate::generated::Canon_pm::main_conditional_tags::{CanonConditionalTags, ConditionalContext}; // TODO: Generate conditional tags
 // use crate::generated::FujiFilm_pm::main_model_detection::{
@@ -26,7 +28,8 @@ mod tags;
 use crate::hash::ImageDataHasher;
 use crate::tiff_types::TiffHeader;
 use crate::types::{
-    DataMemberValue, DirectoryInfo, ExifError, ProcessorDispatch, Result, TagSourceInfo, TagValue,
+    DataMemberValue, DirectoryInfo, ExifCharset, ExifError, FilterOptions, LazyPrint,
+    ProcessorDispatch, Result, TagSourceInfo, TagValue, Truncation, UnknownTags,
 };
 use std::collections::HashMap;
 use tracing::debug;
@@ -47,6 +50,8 @@ pub struct ExifReader {
     pub(crate) data: Vec<u8>,
     /// Parse errors (non-fatal, for graceful degradation)
     pub(crate) warnings: Vec<String>,
+    /// First directory found cut off by the end of the data
+    pub(crate) truncation: Option<Truncation>,
 
     // Milestone 5: Stateful processing features
     /// PROCESSED hash for recursion prevention
@@ -84,11 +89,59 @@ pub struct ExifReader {
     /// Created when FilterOptions.compute_image_hash is true
     /// ExifTool: $$self{ImageDataHash} in lib/Image/ExifTool.pm:2766-2780
     pub(crate) image_data_hasher: Option<ImageDataHasher>,
+    /// Which tags missing from the EXIF and maker note tables are output
+    /// ExifTool: Unknown option (-u, -U)
+    pub(crate) unknown_tags: UnknownTags,
+    /// Keep the copies of a tag that lose to a higher priority one
+    /// ExifTool: Duplicates option (-a)
+    pub(crate) allow_duplicates: bool,
+    /// Tags replaced in or kept out of `extracted_tags` by precedence, with
+    /// their IDs, when `allow_duplicates` is set
+    pub(crate) duplicate_tags: Vec<(u16, TagValue, TagSourceInfo)>,
+    /// Encoding of ASCII string values
+    /// ExifTool: CharsetEXIF option
+    pub(crate) charset_exif: ExifCharset,
+}
+
+/// Look up a tag name in a manufacturer's MakerNotes table
+///
+/// Makers whose cargo feature is disabled have no table, so their tags fall
+/// back to TAG_PREFIX names like any unknown maker's.
+fn maker_tag_name(maker: &str, tag_id: u16) -> Option<String> {
+    match maker {
+        #[cfg(feature = "canon")]
+        "Canon" => crate::implementations::canon::get_canon_tag_name(tag_id),
+        #[cfg(feature = "sony")]
+        "Sony" => crate::implementations::sony::get_sony_tag_name(tag_id),
+        #[cfg(feature = "olympus")]
+        "Olympus" => crate::implementations::olympus::get_olympus_tag_name(tag_id),
+        "Apple" => crate::implementations::apple::get_apple_tag_name(tag_id),
+        _ => None,
+    }
+}
+
+/// Name of an EXIF IFD entry missing from the tag tables
+/// ExifTool: Exif::Main TAG_PREFIX 'Exif', named by GetTagInfo with -u
+fn unknown_exif_tag_name(tag_id: u16) -> String {
+    format!("Exif_0x{tag_id:04x}")
+}
+
+/// Look up a tag name in a RAW format's table (KyoceraRaw, PanasonicRaw)
+#[cfg_attr(not(feature = "raw-formats"), allow(unused_variables))]
+fn raw_tag_name(table: &str, tag_id: u16) -> Option<&'static str> {
+    match table {
+        #[cfg(feature = "raw-formats")]
+        "KyoceraRaw" => crate::raw::get_kyocera_tag_name(tag_id),
+        #[cfg(feature = "raw-formats")]
+        "PanasonicRaw" => crate::raw::formats::panasonic::get_panasonic_tag_name(tag_id),
+        _ => None,
+    }
 }
 
 impl ExifReader {
     /// Get current base offset for pointer calculations
     /// ExifTool: $$dirInfo{Base} + $$self{BASE}  
+    #[cfg(all(feature = "raw-formats", feature = "olympus"))]
     pub(crate) fn get_base(&self) -> u64 {
         self.base
     }
@@ -100,6 +153,7 @@ impl ExifReader {
             header: None,
             data: Vec::new(),
             warnings: Vec::new(),
+            truncation: None,
             // Milestone 5: Initialize stateful features
             processed: HashMap::new(),
             path: Vec::new(),
@@ -113,9 +167,21 @@ impl ExifReader {
             synthetic_tag_names: HashMap::new(),
             ifd0_next_offset: None,
             image_data_hasher: None,
+            unknown_tags: UnknownTags::default(),
+            allow_duplicates: false,
+            duplicate_tags: Vec::new(),
+            charset_exif: ExifCharset::default(),
         }
     }
 
+    /// Keep the unknown and duplicate tags `filter` asks for, decoding strings
+    /// in its character set
+    pub fn set_filter_options(&mut self, filter: &FilterOptions) {
+        self.unknown_tags = filter.unknown_tags;
+        self.allow_duplicates = filter.allow_duplicates;
+        self.charset_exif = filter.charset_exif;
+    }
+
     /// Set the ImageDataHash hasher for computing image data hash during extraction
     /// ExifTool: $$self{ImageDataHash} = Digest::MD5/SHA->new (lib/Image/ExifTool.pm:2766-2780)
     pub fn set_image_data_hasher(&mut self, hasher: ImageDataHasher) {
@@ -170,6 +236,9 @@ impl ExifReader {
     /// Parse EXIF data from JPEG APP1 segment after "Exif\0\0"
     /// ExifTool: lib/Image/ExifTool/Exif.pm:6172 ProcessExif entry point
     pub fn parse_exif_data(&mut self, exif_data: &[u8]) -> Result<()> {
+        let _stage = crate::stats::stage("EXIF");
+        crate::stats::add_parsed_bytes(exif_data.len() as u64);
+
         if exif_data.len() < 8 {
             return Err(ExifError::ParseError(
                 "EXIF data too short for TIFF header".to_string(),
@@ -278,6 +347,7 @@ impl ExifReader {
                 "Olympus" => format!("Olympus_0x{tag_id:04X}"),
                 "Panasonic" => format!("Panasonic_0x{tag_id:04X}"),
                 "Fujifilm" => format!("Fujifilm_0x{tag_id:04X}"),
+                "Apple" => format!("Apple_0x{tag_id:04X}"),
                 _ => format!("Tag_{tag_id:04X}"),
             };
             tracing::debug!(
@@ -303,6 +373,7 @@ impl ExifReader {
         use crate::generated::Exif_pm::main_tags::get_tag_info_with_context;
         use crate::generated::Exif_pm::main_tags::EXIF_MAIN_TAGS as EXIF_PM_TAG_KITS;
         use crate::generated::GPS_pm::main_tags::GPS_MAIN_TAGS as GPS_PM_TAG_KITS;
+        #[cfg(feature = "sony")]
         use crate::generated::Sony_pm::main_tags::SONY_MAIN_TAGS as SONY_PM_TAG_KITS;
 
         tracing::debug!(
@@ -322,6 +393,7 @@ impl ExifReader {
 
             // Check for Canon MakerNotes tags
             // Canon tags use the Canon namespace from parse_ifd("Canon")
+            #[cfg(feature = "canon")]
             if source.namespace == "Canon" {
                 use crate::generated::Canon_pm::main_tags::CANON_MAIN_TAGS;
                 if let Some(tag_def) = CANON_MAIN_TAGS.get(&tag_id) {
@@ -334,6 +406,7 @@ impl ExifReader {
 
             // Check for Sony MakerNotes tags
             // Sony tags may have namespace "Sony" or "MakerNotes" depending on extraction path
+            #[cfg(feature = "sony")]
             if source.namespace == "Sony" || source.namespace == "MakerNotes" {
                 // For Sony or MakerNotes tags, check Sony tag kit first
                 if let Some(tag_def) = SONY_PM_TAG_KITS.get(&tag_id) {
@@ -381,8 +454,6 @@ impl ExifReader {
     pub fn get_all_tags(&self) -> HashMap<String, TagValue> {
         use crate::generated::Exif_pm::main_tags::EXIF_MAIN_TAGS as EXIF_PM_TAG_KITS;
         use crate::generated::GPS_pm::main_tags::GPS_MAIN_TAGS as GPS_PM_TAG_KITS;
-        use crate::implementations::canon;
-        use crate::implementations::sony;
 
         let mut result = HashMap::new();
 
@@ -409,17 +480,17 @@ impl ExifReader {
                     let tag_name = if let Some(_source_info) = source_info {
                         // Try manufacturer-specific lookup first, then TAG_PREFIX fallback
                         match namespace.as_str() {
-                            "Canon" => canon::get_canon_tag_name(tag_id).unwrap_or_else(|| {
+                            "Canon" => maker_tag_name("Canon", tag_id).unwrap_or_else(|| {
                                 Self::generate_tag_prefix_name(tag_id, source_info)
                             }),
-                            "Sony" => sony::get_sony_tag_name(tag_id).unwrap_or_else(|| {
+                            "Sony" => maker_tag_name("Sony", tag_id).unwrap_or_else(|| {
                                 Self::generate_tag_prefix_name(tag_id, source_info)
                             }),
                             _ => Self::generate_tag_prefix_name(tag_id, source_info),
                         }
                     } else {
                         // No source info, try Canon as fallback (historical behavior), then generic TAG_PREFIX
-                        canon::get_canon_tag_name(tag_id)
+                        maker_tag_name("Canon", tag_id)
                             .unwrap_or_else(|| Self::generate_tag_prefix_name(tag_id, None))
                     };
 
@@ -434,9 +505,7 @@ impl ExifReader {
                     if file_type == "RW2" && namespace == "EXIF" {
                         // Use Panasonic-specific tag definitions for RW2 files
                         // ExifTool: PanasonicRaw.pm %Image::ExifTool::PanasonicRaw::Main hash
-                        if let Some(panasonic_name) =
-                            crate::raw::formats::panasonic::get_panasonic_tag_name(tag_id)
-                        {
+                        if let Some(panasonic_name) = raw_tag_name("PanasonicRaw", tag_id) {
                             panasonic_name.to_string()
                         } else {
                             // Fall through to standard lookup if not a known Panasonic tag
@@ -467,8 +536,9 @@ impl ExifReader {
                 // Manufacturer MakerNotes use manufacturer namespace internally but display as Group0="MakerNotes"
                 let display_group = match namespace.as_str() {
                     "GPS" => "EXIF", // GPS tags have Group0="EXIF" per ExifTool GPS.pm:52
+                    "IFD1" | "InteropIFD" => "EXIF",
                     // Manufacturer MakerNotes tags display as "MakerNotes" group per ExifTool output
-                    "Canon" | "Nikon" | "Sony" | "Olympus" | "Panasonic" | "Fujifilm" => {
+                    "Canon" | "Nikon" | "Sony" | "Olympus" | "Panasonic" | "Fujifilm" | "Apple" => {
                         "MakerNotes"
                     }
                     other => other, // Keep other namespaces as-is
@@ -516,17 +586,29 @@ impl ExifReader {
         use crate::generated::GPS_pm::main_tags::GPS_MAIN_TAGS as GPS_PM_TAG_KITS;
         // TODO: Re-enable when COMPOSITE_TAGS registry is available
         // use crate::generated::COMPOSITE_TAGS;
-        use crate::implementations::canon;
-        use crate::implementations::olympus;
-        use crate::implementations::sony;
+        use crate::implementations::apple;
         use crate::types::TagEntry;
 
         let mut entries = Vec::new();
 
-        // Process extracted tags
-        for (&(tag_id, ref namespace), raw_value) in &self.extracted_tags {
-            // Get the enhanced source info for this tag
-            let source_info = self.tag_sources.get(&(tag_id, namespace.clone()));
+        // Process extracted tags, then the copies precedence kept out of them
+        let tags = self
+            .extracted_tags
+            .iter()
+            .map(|(&(tag_id, ref namespace), raw_value)| {
+                let source_info = self.tag_sources.get(&(tag_id, namespace.clone()));
+                (tag_id, namespace, raw_value, source_info)
+            })
+            .chain(
+                self.duplicate_tags
+                    .iter()
+                    .map(|(tag_id, raw_value, source)| {
+                        (*tag_id, &source.namespace, raw_value, Some(source))
+                    }),
+            );
+        for (tag_id, namespace, raw_value, source_info) in tags {
+            // Set for EXIF IFD entries missing from the EXIF and GPS tables
+            let mut unknown = false;
 
             // Look up tag name and group, handling synthetic tags properly
             let (raw_group_name, base_tag_name, _tag_def) = if tag_id >= 0xC000 {
@@ -559,21 +641,22 @@ impl ExifReader {
                         // Fall back to manufacturer-specific tag names using TAG_PREFIX mechanism
                         let tag_name = if let Some(_source_info) = source_info {
                             match namespace.as_str() {
-                                "Canon" => canon::get_canon_tag_name(tag_id).unwrap_or_else(|| {
+                                "Canon" => maker_tag_name("Canon", tag_id).unwrap_or_else(|| {
                                     Self::generate_tag_prefix_name(tag_id, source_info)
                                 }),
-                                "Sony" => sony::get_sony_tag_name(tag_id).unwrap_or_else(|| {
+                                "Sony" => maker_tag_name("Sony", tag_id).unwrap_or_else(|| {
                                     Self::generate_tag_prefix_name(tag_id, source_info)
                                 }),
-                                "MakerNotes" => olympus::get_olympus_tag_name(tag_id)
-                                    .unwrap_or_else(|| {
+                                "MakerNotes" => {
+                                    maker_tag_name("Olympus", tag_id).unwrap_or_else(|| {
                                         Self::generate_tag_prefix_name(tag_id, source_info)
-                                    }),
+                                    })
+                                }
                                 _ => Self::generate_tag_prefix_name(tag_id, source_info),
                             }
                         } else {
                             // No source info, try Canon as fallback, then use TAG_PREFIX
-                            canon::get_canon_tag_name(tag_id)
+                            maker_tag_name("Canon", tag_id)
                                 .unwrap_or_else(|| Self::generate_tag_prefix_name(tag_id, None))
                         };
 
@@ -594,6 +677,7 @@ impl ExifReader {
                             name if name.starts_with("Canon") => false, // Canon maker notes - don't lookup GPS/EXIF tags
                             name if name.starts_with("Nikon") => false, // Nikon maker notes - don't lookup GPS/EXIF tags
                             name if name.starts_with("Olympus") => false, // Olympus maker notes - don't lookup GPS/EXIF tags
+                            name if name.starts_with("Apple") => false, // Apple maker notes - don't lookup GPS/EXIF tags
                             "MakerNotes" => false, // Generic maker notes - don't lookup GPS/EXIF tags
                             "KyoceraRaw" => false, // Kyocera RAW - don't lookup GPS/EXIF tags
                             "IFD0" if self.original_file_type.as_deref() == Some("RW2") => {
@@ -623,15 +707,6 @@ impl ExifReader {
                             // Context-aware tag lookup using namespace from key
                             // ExifTool: lib/Image/ExifTool/Exif.pm:8968 GPS vs InteropIFD conflict handling
                             let (name, _tag_def) = match (namespace.as_str(), tag_id) {
-                                // InteropIFD-specific tags
-                                // ExifTool: lib/Image/ExifTool/Exif.pm InteropIFD table
-                                ("InteropIFD", 0x0001) => {
-                                    ("InteroperabilityIndex".to_string(), None::<()>)
-                                }
-                                ("InteropIFD", 0x0002) => {
-                                    ("InteroperabilityVersion".to_string(), None::<()>)
-                                }
-
                                 // GPS IFD tags - check GPS tag kit first to avoid conflicts
                                 // (e.g., tag 0x0002 is GPSLatitude in GPS IFD, InteropVersion in InteropIFD)
                                 ("GPS", _) => {
@@ -644,9 +719,10 @@ impl ExifReader {
                                                 .map(|def| def.name.to_string())
                                         })
                                         .unwrap_or_else(|| {
-                                            Self::generate_tag_prefix_name(tag_id, source_info)
+                                            unknown = true;
+                                            unknown_exif_tag_name(tag_id)
                                         });
-                                    (name, None)
+                                    (name, None::<()>)
                                 }
 
                                 // All other contexts use context-aware lookup for IFD-specific tags
@@ -663,7 +739,8 @@ impl ExifReader {
                                                 .map(|def| def.name.to_string())
                                         })
                                         .unwrap_or_else(|| {
-                                            Self::generate_tag_prefix_name(tag_id, source_info)
+                                            unknown = true;
+                                            unknown_exif_tag_name(tag_id)
                                         });
                                     (name, None)
                                 }
@@ -678,7 +755,7 @@ impl ExifReader {
                         if let Some(source_info) = source_info {
                             if source_info.ifd_name == "KyoceraRaw" {
                                 // Use Kyocera-specific tag name lookup
-                                let kyocera_tag_name = crate::raw::get_kyocera_tag_name(tag_id)
+                                let kyocera_tag_name = raw_tag_name("KyoceraRaw", tag_id)
                                     .map(|name| name.to_string())
                                     .unwrap_or_else(|| {
                                         Self::generate_tag_prefix_name(tag_id, Some(source_info))
@@ -689,15 +766,11 @@ impl ExifReader {
                             {
                                 // Use Panasonic RW2-specific tag name lookup
                                 // ExifTool: PanasonicRaw.pm Main table for RW2 IFD0
-                                let panasonic_tag_name =
-                                    crate::raw::formats::panasonic::get_panasonic_tag_name(tag_id)
-                                        .map(|name| name.to_string())
-                                        .unwrap_or_else(|| {
-                                            Self::generate_tag_prefix_name(
-                                                tag_id,
-                                                Some(source_info),
-                                            )
-                                        });
+                                let panasonic_tag_name = raw_tag_name("PanasonicRaw", tag_id)
+                                    .map(|name| name.to_string())
+                                    .unwrap_or_else(|| {
+                                        Self::generate_tag_prefix_name(tag_id, Some(source_info))
+                                    });
                                 (panasonic_tag_name, None)
                             } else {
                                 // Check for manufacturer-specific maker note tags
@@ -705,7 +778,7 @@ impl ExifReader {
                                     || source_info.ifd_name == "MakerNotes"
                                 {
                                     // Use Canon-specific tag name lookup for Canon maker note tags
-                                    let canon_tag_name = canon::get_canon_tag_name(tag_id)
+                                    let canon_tag_name = maker_tag_name("Canon", tag_id)
                                         .unwrap_or_else(|| {
                                             Self::generate_tag_prefix_name(
                                                 tag_id,
@@ -713,9 +786,19 @@ impl ExifReader {
                                             )
                                         });
                                     (canon_tag_name, None)
+                                } else if source_info.ifd_name.starts_with("Apple") {
+                                    // Apple::Main tag names
+                                    let apple_tag_name = apple::get_apple_tag_name(tag_id)
+                                        .unwrap_or_else(|| {
+                                            Self::generate_tag_prefix_name(
+                                                tag_id,
+                                                Some(source_info),
+                                            )
+                                        });
+                                    (apple_tag_name, None)
                                 } else if source_info.ifd_name.starts_with("Sony") {
                                     // Use Sony-specific tag name lookup for Sony maker note tags
-                                    let sony_tag_name = sony::get_sony_tag_name(tag_id)
+                                    let sony_tag_name = maker_tag_name("Sony", tag_id)
                                         .unwrap_or_else(|| {
                                             Self::generate_tag_prefix_name(
                                                 tag_id,
@@ -740,6 +823,12 @@ impl ExifReader {
                 (namespace.as_str(), name, def)
             };
 
+            // ExifTool: unknown tags are only extracted with the Unknown option
+            if unknown && !self.unknown_tags.includes(raw_value) {
+                debug!("Skipping unknown tag {}", base_tag_name);
+                continue;
+            }
+
             // Map internal namespace to ExifTool-compatible Group0 for TagEntry
             // ExifTool: GPS.pm:52 GROUPS => { 0 => 'EXIF', 1 => 'GPS', 2 => 'Location' }
             // Manufacturer MakerNotes use manufacturer namespace internally but display as Group0="MakerNotes"
@@ -750,21 +839,26 @@ impl ExifReader {
                 0x9000 | 0xA000 | 0xA001 | 0xA002 | 0xA003 | 0xA005 => "EXIF",
                 _ => match raw_group_name {
                     "GPS" => "EXIF", // GPS tags have Group0="EXIF" per ExifTool GPS.pm:52
+                    "IFD1" | "InteropIFD" => "EXIF",
                     // Manufacturer MakerNotes tags display as "MakerNotes" group per ExifTool output
-                    "Canon" | "Nikon" | "Sony" | "Olympus" | "Panasonic" | "Fujifilm" => {
+                    "Canon" | "Nikon" | "Sony" | "Olympus" | "Panasonic" | "Fujifilm" | "Apple" => {
                         "MakerNotes"
                     }
                     other => other, // Keep other namespaces as-is
                 },
             };
 
-            // Apply conversions to get both value and print
+            // Apply ValueConv now; PrintConv runs when the display value is read
             let ifd_name = source_info
                 .as_ref()
                 .map(|s| s.ifd_name.as_str())
                 .unwrap_or("");
 
-            let (value, print) = self.apply_conversions(raw_value, tag_id, ifd_name, source_info);
+            let value = self.apply_value_conv(raw_value, tag_id, ifd_name, source_info);
+            let print = match Self::deferred_print_conv(tag_id, ifd_name, source_info) {
+                Some(print_conv) => LazyPrint::deferred(print_conv),
+                None => LazyPrint::default(),
+            };
 
             // Get group1 value using TagSourceInfo with tag-specific overrides for correct context assignment
             let group1_name = if let Some(source_info) = source_info {
@@ -777,16 +871,19 @@ impl ExifReader {
 
             // Debug logging for ColorSpace and WhiteBalance
             if tag_id == 0xa001 || tag_id == 0xa403 {
-                debug!("Creating TagEntry for 0x{:04x}: group={}, group1={}, name={}, value={:?}, print={:?}", 
-                    tag_id, group_name, group1_name, base_tag_name, value, print);
+                debug!(
+                    "Creating TagEntry for 0x{:04x}: group={}, group1={}, name={}, value={:?}",
+                    tag_id, group_name, group1_name, base_tag_name, value
+                );
             }
 
             let entry = TagEntry {
-                group: group_name.to_string(),
-                group1: group1_name,
-                name: base_tag_name,
+                group: group_name.into(),
+                group1: group1_name.into(),
+                name: base_tag_name.into(),
                 value,
                 print,
+                source: source_info.map(|info| info.to_tag_source(tag_id)),
             };
 
             entries.push(entry);
@@ -810,9 +907,9 @@ impl ExifReader {
             //         crate::composite_tags::apply_composite_conversions(raw_value, &def);
 
             //     let entry = TagEntry {
-            //         group: "Composite".to_string(),
-            //         group1: "Composite".to_string(),
-            //         name: name.to_string(),
+            //         group: "Composite".into(),
+            //         group1: "Composite".into(),
+            //         name: name.into(),
             //         value,
             //         print,
             //     };
@@ -821,11 +918,12 @@ impl ExifReader {
             // } else {
             // Fallback if definition not found
             let entry = TagEntry {
-                group: "Composite".to_string(),
-                group1: "Composite".to_string(),
-                name: name.to_string(),
+                group: "Composite".into(),
+                group1: "Composite".into(),
+                name: name.into(),
                 value: raw_value.clone(),
-                print: raw_value.clone(),
+                print: raw_value.clone().into(),
+                source: None,
             };
 
             entries.push(entry);
@@ -840,6 +938,25 @@ impl ExifReader {
         &self.warnings
     }
 
+    /// Where the data ran out mid-directory, if it did
+    pub fn truncation(&self) -> Option<&Truncation> {
+        self.truncation.as_ref()
+    }
+
+    /// Record a directory cut off at `offset` (relative to the data); only
+    /// the first is kept, as that is where parsing stopped
+    pub(crate) fn note_truncation(&mut self, context: String, offset: usize, expected: usize) {
+        if self.truncation.is_none() {
+            let available = self.data.len().saturating_sub(offset);
+            self.truncation = Some(Truncation::new(
+                context,
+                self.base + offset as u64,
+                expected as u64,
+                available as u64,
+            ));
+        }
+    }
+
     /// Get TIFF header information
     pub fn get_header(&self) -> Option<&TiffHeader> {
         self.header.as_ref()
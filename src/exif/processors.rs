@@ -5,7 +5,14 @@
 //!
 //! ExifTool Reference: PROCESS_PROC system and ProcessDirectory dispatch
 
-use crate::implementations::{canon, nikon, olympus, sony};
+#[cfg(feature = "canon")]
+use crate::implementations::canon;
+#[cfg(feature = "nikon")]
+use crate::implementations::nikon;
+#[cfg(feature = "olympus")]
+use crate::implementations::olympus;
+#[cfg(feature = "sony")]
+use crate::implementations::sony;
 use crate::processor_registry::{get_global_registry, ProcessorContext};
 use crate::types::{DirectoryInfo, Result};
 use std::collections::HashMap;
@@ -208,11 +215,14 @@ impl ExifReader {
                     // Merge extracted tags into ExifReader state
                     for (tag_name, tag_value) in result.extracted_tags {
                         debug!("  Raw tag: '{}' = {:?}", tag_name, tag_value);
+                        let print_conv = result.print_convs.get(&tag_name).copied();
 
                         // Convert tag_name to tag_id and store in extracted_tags
                         if let Some(tag_id) = self.resolve_tag_name_to_id(&tag_name) {
                             debug!("    → Resolved to ID: 0x{:04X}", tag_id);
-                            let source_info = self.create_tag_source_info("ProcessedData");
+                            let source_info = self
+                                .create_tag_source_info("ProcessedData")
+                                .with_print_conv(print_conv);
                             self.store_tag_with_precedence(tag_id, tag_value.clone(), source_info);
                             debug!(
                                 "Stored tag: {} (0x{:04X}) = {:?}",
@@ -222,7 +232,9 @@ impl ExifReader {
                             debug!("    → FAILED to resolve tag name");
                             // For unknown tag names, try to parse as hex if it looks like Tag_XXXX format
                             if let Some(tag_id) = self.parse_hex_tag_name(&tag_name) {
-                                let source_info = self.create_tag_source_info("ProcessedData");
+                                let source_info = self
+                                    .create_tag_source_info("ProcessedData")
+                                    .with_print_conv(print_conv);
                                 self.store_tag_with_precedence(
                                     tag_id,
                                     tag_value.clone(),
@@ -235,7 +247,9 @@ impl ExifReader {
                             } else {
                                 // Store manufacturer-specific tags with synthetic IDs to preserve them
                                 let synthetic_id = self.generate_synthetic_tag_id(&tag_name);
-                                let source_info = self.create_tag_source_info("ProcessedData");
+                                let source_info = self
+                                    .create_tag_source_info("ProcessedData")
+                                    .with_print_conv(print_conv);
                                 self.store_tag_with_precedence(
                                     synthetic_id,
                                     tag_value.clone(),
@@ -489,6 +503,7 @@ impl ExifReader {
         );
 
         // ExifTool: lib/Image/ExifTool/MakerNotes.pm:60-68 Canon detection
+        #[cfg(feature = "canon")]
         if canon::detect_canon_signature(make) {
             debug!(
                 "Detected Canon MakerNote signature - using fallback to direct Canon processing"
@@ -499,12 +514,14 @@ impl ExifReader {
         }
 
         // ExifTool: lib/Image/ExifTool/MakerNotes.pm:152-163 Nikon detection
+        #[cfg(feature = "nikon")]
         if nikon::detect_nikon_signature(make) {
             debug!("Detected Nikon MakerNote signature: '{}'", make);
             return Some("Nikon::Main".to_string());
         }
 
         // ExifTool: lib/Image/ExifTool/MakerNotes.pm:1007-1075 Sony detection
+        #[cfg(feature = "sony")]
         if sony::is_sony_makernote(make, model) {
             debug!("Detected Sony MakerNote (Make field: {})", make);
             return Some("Sony::Main".to_string());
@@ -522,6 +539,7 @@ impl ExifReader {
         // ExifTool: lib/Image/ExifTool/MakerNotes.pm:515-533 Olympus detection
         // For Olympus MakerNotes, use standard IFD parsing to discover subdirectories like Equipment (0x2010)
         // ExifTool: Olympus MakerNotes are processed as standard IFD first to find subdirectory tags
+        #[cfg(feature = "olympus")]
         if olympus::is_olympus_makernote(make) {
             debug!("Detected Olympus MakerNote (Make field: {})", make);
             debug!("Using standard IFD parsing for Olympus MakerNotes to discover Equipment subdirectory");
@@ -594,7 +612,10 @@ impl ExifReader {
         // Check if the Make field indicates this is an Olympus camera
         if let Some(make_tag) = self.get_tag_across_namespaces(0x010F) {
             if let Some(make_str) = make_tag.as_string() {
+                #[cfg(feature = "olympus")]
                 let is_olympus = olympus::is_olympus_makernote(make_str);
+                #[cfg(not(feature = "olympus"))]
+                let is_olympus = false;
                 debug!(
                     "is_olympus_subdirectory_context - Make: '{}', is_olympus: {}",
                     make_str, is_olympus
@@ -707,11 +728,22 @@ impl ExifReader {
                     .and_then(|v| v.as_string())
                     .unwrap_or("");
 
+                // Makers whose cargo feature is disabled fall through to
+                // standard IFD parsing
+                #[cfg(feature = "canon")]
                 if canon::detect_canon_signature(make) {
-                    canon::process_canon_makernotes(self, dir_info.dir_start, dir_info.dir_len)
-                } else if nikon::detect_nikon_signature(make) {
-                    nikon::process_nikon_makernotes(self, dir_info.dir_start)
-                } else if sony::is_sony_makernote(make, "") {
+                    return canon::process_canon_makernotes(
+                        self,
+                        dir_info.dir_start,
+                        dir_info.dir_len,
+                    );
+                }
+                #[cfg(feature = "nikon")]
+                if nikon::detect_nikon_signature(make) {
+                    return nikon::process_nikon_makernotes(self, dir_info.dir_start);
+                }
+                #[cfg(feature = "sony")]
+                if sony::is_sony_makernote(make, "") {
                     // Sony MakerNotes processing - call Sony subdirectory processing
                     debug!("Detected Sony MakerNotes for Make: '{}' - calling Sony subdirectory processing", make);
                     debug!(
@@ -723,8 +755,9 @@ impl ExifReader {
                     if let Err(ref e) = result {
                         debug!("Sony subdirectory processing error: {}", e);
                     }
-                    result
-                } else if make.to_lowercase().starts_with("minolta")
+                    return result;
+                }
+                if make.to_lowercase().starts_with("minolta")
                     || make.to_lowercase().starts_with("konica minolta")
                 {
                     debug!("Processing Minolta MakerNotes using standard IFD parsing");
@@ -862,7 +895,7 @@ impl ExifReader {
 
         let source_info = crate::types::TagSourceInfo::new(
             namespace.to_string(),
-            "MakerNotes".to_string(),
+            namespace.to_string(),
             format!("{namespace}::BinaryData"),
         );
         self.tag_sources
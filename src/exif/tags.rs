@@ -5,7 +5,8 @@
 //!
 //! ExifTool Reference: Tag storage and conflict resolution logic
 
-use crate::types::{TagSourceInfo, TagValue};
+use crate::tiff_types::IfdEntry;
+use crate::types::{DeferredPrintConv, TablePrintConvFn, TagSourceInfo, TagValue};
 use tracing::debug;
 
 use super::ExifReader;
@@ -33,8 +34,13 @@ impl ExifReader {
                     "Tag 0x{:04x} ({}): Replacing lower priority with higher priority",
                     tag_id, source_info.namespace
                 );
-                self.extracted_tags.insert(key.clone(), value);
-                self.tag_sources.insert(key, source_info);
+                let old_value = self.extracted_tags.insert(key.clone(), value);
+                let old_source = self.tag_sources.insert(key, source_info);
+                if let (true, Some(value), Some(source)) =
+                    (self.allow_duplicates, old_value, old_source)
+                {
+                    self.duplicate_tags.push((tag_id, value, source));
+                }
             } else if source_info.priority == existing_source.priority {
                 // Same priority - keep first encountered (ExifTool behavior)
                 debug!(
@@ -42,12 +48,18 @@ impl ExifReader {
                     tag_id, source_info.namespace
                 );
                 // Do not overwrite - keep existing
+                if self.allow_duplicates {
+                    self.duplicate_tags.push((tag_id, value, source_info));
+                }
             } else {
                 // Lower priority - ignore
                 debug!(
                     "Tag 0x{:04x} ({}): Ignoring lower priority",
                     tag_id, source_info.namespace
                 );
+                if self.allow_duplicates {
+                    self.duplicate_tags.push((tag_id, value, source_info));
+                }
             }
         } else {
             // New tag - store it with namespace-aware key
@@ -89,6 +101,22 @@ impl ExifReader {
         self.store_tag_with_precedence(tag_id, value, source_info);
     }
 
+    /// Store a tag from the full-resolution image IFD, replacing the value read
+    /// from an earlier IFD (the IFD0 thumbnail dimensions of a TIFF-based RAW)
+    /// ExifTool: Exif.pm SubfileType RawConv sets $$self{PRIORITY_DIR}, and
+    /// ExifTool.pm FoundTag gives tags in that directory precedence
+    #[cfg(feature = "raw-formats")]
+    pub(crate) fn insert_priority_dir_tag(&mut self, tag_id: u16, value: TagValue, ifd_name: &str) {
+        let source_info = self.create_tag_source_info(ifd_name);
+        let key = (tag_id, source_info.namespace.clone());
+        debug!(
+            "Tag 0x{:04x} ({}): Replacing with value from priority directory {}",
+            tag_id, source_info.namespace, ifd_name
+        );
+        self.extracted_tags.insert(key.clone(), value);
+        self.tag_sources.insert(key, source_info);
+    }
+
     /// Apply IsOffset adjustment if this tag has IsOffset=>1
     /// ExifTool: Exif.pm:7052-7066 - when IsOffset=>1, add base to convert TIFF-relative to absolute
     fn apply_is_offset_adjustment(
@@ -165,10 +193,14 @@ impl ExifReader {
         // Map IFD names to ExifTool group names
         // ExifTool: lib/Image/ExifTool/Exif.pm group mappings
         let namespace = match ifd_name {
-            "Root" | "IFD0" | "IFD1" => "EXIF",
+            "Root" | "IFD0" => "EXIF",
+            // IFD1 repeats IFD0 tag IDs (Compression, XResolution, ResolutionUnit),
+            // and InteropIFD's 0x0001/0x0002 clash with maker note tags read before it,
+            // so both need a distinct namespace like GPS
+            "IFD1" => "IFD1",
+            "InteropIFD" => "InteropIFD",
             "GPS" => "GPS", // GPS tags need distinct namespace to avoid tag ID collisions
             "ExifIFD" => "EXIF", // ExifIFD tags belong to EXIF group (Group0) in ExifTool
-            "InteropIFD" => "EXIF",
             "MakerNotes" => "MakerNotes",
             // Manufacturer-specific MakerNotes IFDs should use manufacturer namespace for Group1 assignment
             // ExifTool: Canon.pm tags get group1="Canon", Nikon.pm tags get group1="Nikon", etc.
@@ -178,6 +210,7 @@ impl ExifReader {
             name if name.starts_with("Olympus") => "Olympus",
             name if name.starts_with("Panasonic") => "Panasonic",
             name if name.starts_with("Fujifilm") => "Fujifilm",
+            name if name.starts_with("Apple") => "Apple",
             // RAW format-specific IFDs (maintain existing behavior)
             "KyoceraRaw" => "EXIF", // Kyocera RAW uses EXIF group
             _ => "EXIF",            // Default to EXIF for unknown IFDs
@@ -192,26 +225,46 @@ impl ExifReader {
             "Olympus" => "Olympus".to_string(),
             "Panasonic" => "Panasonic".to_string(),
             "Fujifilm" => "Fujifilm".to_string(),
+            "Apple" => "Apple".to_string(),
             _ => "Exif".to_string(),
         };
 
         TagSourceInfo::new(namespace.to_string(), ifd_name.to_string(), processor_name)
     }
 
-    /// Apply ValueConv and PrintConv conversions to a raw tag value
-    /// ExifTool: lib/Image/ExifTool.pm conversion pipeline
-    /// Returns tuple of (value, print) where:
-    /// - value: The result after ValueConv (or raw if no ValueConv)
-    /// - print: The result after PrintConv (or value if no PrintConv)
-    pub(crate) fn apply_conversions(
+    /// Create TagSourceInfo for a tag read from an IFD entry, recording where its value lives
+    /// ExifTool: lib/Image/ExifTool/Exif.pm:6390-6420 - values of 4 bytes or less are stored
+    /// in the entry itself, larger values at an offset relative to the TIFF header
+    pub(crate) fn create_entry_source_info(
+        &self,
+        ifd_name: &str,
+        entry: &IfdEntry,
+        entry_offset: usize,
+    ) -> TagSourceInfo {
+        let value_pos = if entry.is_inline() {
+            entry_offset as u64 + 8
+        } else {
+            entry.value_or_offset as u64
+        };
+        self.create_tag_source_info(ifd_name).with_entry(
+            self.base + value_pos,
+            entry.format,
+            entry.count,
+        )
+    }
+
+    /// Apply RawConv and ValueConv to a raw tag value
+    /// ExifTool: lib/Image/ExifTool.pm GetValue 'ValueConv'
+    pub(crate) fn apply_value_conv(
         &self,
         raw_value: &TagValue,
         tag_id: u16,
         ifd_name: &str,
         source_info: Option<&TagSourceInfo>,
-    ) -> (TagValue, TagValue) {
+    ) -> TagValue {
         use crate::generated::Exif_pm::main_tags;
         use crate::generated::GPS_pm::main_tags as gps_tag_kit;
+        #[cfg(feature = "sony")]
         use crate::generated::Sony_pm::main_tags as sony_tag_kit;
 
         let mut value = raw_value.clone();
@@ -225,242 +278,337 @@ impl ExifReader {
         }
 
         // Process based on IFD context
-        if ifd_name == "GPS" {
-            // For GPS IFD, check GPS tag kit
-            if let Some(tag_def) = gps_tag_kit::GPS_MAIN_TAGS.get(&tag_id) {
-                // Apply ValueConv first (if present) using generated function
-                if tag_def.value_conv.is_some() {
-                    let mut value_conv_errors = Vec::new();
-                    match gps_tag_kit::apply_value_conv(
-                        tag_id as u32,
-                        &value,
-                        &mut value_conv_errors,
-                    ) {
-                        Ok(converted) => {
-                            debug!(
-                                "Applied ValueConv to GPS tag 0x{:04x}: {:?} -> {:?}",
-                                tag_id, value, converted
-                            );
-                            value = converted;
-                        }
-                        Err(e) => {
-                            debug!(
-                                "Failed to apply ValueConv to GPS tag 0x{:04x}: {}",
-                                tag_id, e
-                            );
-                        }
-                    }
-                }
-
-                // Apply PrintConv second (if present) to get display value
-                // For GPS coordinates, we want to use our manual registry functions
-                let print = match tag_def.name {
-                    "GPSLatitude" => {
-                        // Note: Keep raw EXIF GPS coordinates unsigned - sign handling happens in composite tags
-                        // ExifTool: Raw GPS coordinates in EXIF are always positive rationals
-                        // Convert rational to decimal if needed (unsigned, for composite tag consumption)
-                        if let Some(rational) = value.as_rational() {
-                            TagValue::F64(rational.0 as f64 / rational.1 as f64)
-                        } else {
-                            value.clone()
-                        }
-                    }
-                    "GPSLongitude" => {
-                        // Note: Keep raw EXIF GPS coordinates unsigned - sign handling happens in composite tags
-                        // ExifTool: Raw GPS coordinates in EXIF are always positive rationals
-                        // Convert rational to decimal if needed (unsigned, for composite tag consumption)
-                        if let Some(rational) = value.as_rational() {
-                            TagValue::F64(rational.0 as f64 / rational.1 as f64)
-                        } else {
-                            value.clone()
-                        }
-                    }
-                    "GPSDestLatitude" => {
-                        use crate::registry;
-                        registry::apply_print_conv("gpsdestlatitude_print_conv", &value)
-                    }
-                    "GPSDestLongitude" => {
-                        use crate::registry;
-                        registry::apply_print_conv("gpsdestlongitude_print_conv", &value)
-                    }
-                    "GPSAltitude" => {
-                        // Note: Skip PrintConv for GPSAltitude because our test snapshots use
-                        // ExifTool's -GPSAltitude# flag which outputs converted decimal values
-                        // without PrintConv units to match PhotoStructure DAM requirements
-
-                        // Convert rational to decimal if needed (matching ExifTool -GPSAltitude# behavior)
-                        if let Some(rational) = value.as_rational() {
-                            TagValue::F64(rational.0 as f64 / rational.1 as f64)
-                        } else {
-                            value.clone()
-                        }
-                    }
-                    "GPSHPositioningError" => {
-                        // Expression: "\"$val m\""
-                        TagValue::String(format!("{} m", value))
-                    }
-                    _ => {
-                        // Use the generic tag kit apply_print_conv for other GPS tags
-                        let mut errors = Vec::new();
-                        let mut warnings = Vec::new();
-
-                        let result = gps_tag_kit::apply_print_conv(
+        match ConversionContext::new(ifd_name, source_info) {
+            ConversionContext::Gps => {
+                // For GPS IFD, check GPS tag kit
+                if let Some(tag_def) = gps_tag_kit::GPS_MAIN_TAGS.get(&tag_id) {
+                    if tag_def.value_conv.is_some() {
+                        let mut value_conv_errors = Vec::new();
+                        match gps_tag_kit::apply_value_conv(
                             tag_id as u32,
                             &value,
-                            &mut errors,
-                            &mut warnings,
-                        );
-
-                        // Log any warnings
-                        for warning in warnings {
-                            debug!("PrintConv warning for tag 0x{:04x}: {}", tag_id, warning);
+                            &mut value_conv_errors,
+                        ) {
+                            Ok(converted) => {
+                                debug!(
+                                    "Applied ValueConv to GPS tag 0x{:04x}: {:?} -> {:?}",
+                                    tag_id, value, converted
+                                );
+                                value = converted;
+                            }
+                            Err(e) => {
+                                debug!(
+                                    "Failed to apply ValueConv to GPS tag 0x{:04x}: {}",
+                                    tag_id, e
+                                );
+                            }
                         }
-
-                        result
                     }
-                };
+                }
+                value
+            }
+            ConversionContext::Apple => {
+                // Apple::Main conversions (Apple::RunTime entries use synthetic IDs)
+                // ExifTool: lib/Image/ExifTool/Apple.pm
+                use crate::generated::Apple_pm::main_tags as apple_tag_kit;
 
-                (value, print)
-            } else {
-                // No tag definition found, return raw value for both
-                (value.clone(), value)
+                if tag_id >= 0xC000 || !apple_tag_kit::APPLE_MAIN_TAGS.contains_key(&tag_id) {
+                    return value;
+                }
+                let mut errors = Vec::new();
+                apple_tag_kit::apply_value_conv(tag_id as u32, &value, &mut errors).unwrap_or(value)
             }
-        } else if ifd_name == "Sony"
-            || (source_info.is_some() && source_info.unwrap().namespace == "Sony")
-        {
-            // Debug logging for Sony context detection
-            debug!(
-                "Sony context check - ifd_name: '{}', source_info: {:?}",
-                ifd_name,
-                source_info.map(|si| format!(
-                    "namespace: '{}', ifd_name: '{}'",
-                    si.namespace, si.ifd_name
-                ))
-            );
-            // For Sony IFD, check Sony tag kit
-            if let Some(tag_def) = sony_tag_kit::SONY_MAIN_TAGS.get(&tag_id) {
+            #[cfg(feature = "sony")]
+            ConversionContext::Sony => {
+                // Debug logging for Sony context detection
                 debug!(
-                    "Found Sony tag definition for tag 0x{:04x}: {}",
-                    tag_id, tag_def.name
+                    "Sony context check - ifd_name: '{}', source_info: {:?}",
+                    ifd_name,
+                    source_info.map(|si| format!(
+                        "namespace: '{}', ifd_name: '{}'",
+                        si.namespace, si.ifd_name
+                    ))
                 );
-                // Apply ValueConv first (if present) using generated function
-                if tag_def.value_conv.is_some() {
-                    let mut value_conv_errors = Vec::new();
-                    match sony_tag_kit::apply_value_conv(
-                        tag_id as u32,
-                        &value,
-                        &mut value_conv_errors,
-                    ) {
-                        Ok(converted) => {
-                            debug!(
-                                "Applied ValueConv to Sony tag 0x{:04x}: {:?} -> {:?}",
-                                tag_id, value, converted
-                            );
-                            value = converted;
+                // For Sony IFD, check Sony tag kit
+                if let Some(tag_def) = sony_tag_kit::SONY_MAIN_TAGS.get(&tag_id) {
+                    debug!(
+                        "Found Sony tag definition for tag 0x{:04x}: {}",
+                        tag_id, tag_def.name
+                    );
+                    if tag_def.value_conv.is_some() {
+                        let mut value_conv_errors = Vec::new();
+                        match sony_tag_kit::apply_value_conv(
+                            tag_id as u32,
+                            &value,
+                            &mut value_conv_errors,
+                        ) {
+                            Ok(converted) => {
+                                debug!(
+                                    "Applied ValueConv to Sony tag 0x{:04x}: {:?} -> {:?}",
+                                    tag_id, value, converted
+                                );
+                                value = converted;
+                            }
+                            Err(e) => {
+                                debug!(
+                                    "Failed to apply ValueConv to Sony tag 0x{:04x}: {}",
+                                    tag_id, e
+                                );
+                            }
                         }
-                        Err(e) => {
-                            debug!(
-                                "Failed to apply ValueConv to Sony tag 0x{:04x}: {}",
-                                tag_id, e
-                            );
+                    }
+                } else {
+                    debug!("Sony tag 0x{:04x} not found in SONY_PM_TAG_KITS", tag_id);
+                }
+                value
+            }
+            ConversionContext::Exif => {
+                // For other IFDs, check EXIF tag kit
+                if let Some(tag_def) = main_tags::EXIF_MAIN_TAGS.get(&tag_id) {
+                    debug!(
+                        "Found tag definition for tag 0x{:04x}: {}",
+                        tag_id, tag_def.name
+                    );
+                    if tag_def.value_conv.is_some() {
+                        let mut value_conv_errors = Vec::new();
+                        match main_tags::apply_value_conv(
+                            tag_id as u32,
+                            &value,
+                            &mut value_conv_errors,
+                        ) {
+                            Ok(converted) => {
+                                debug!(
+                                    "Applied ValueConv to EXIF tag 0x{:04x}: {:?} -> {:?}",
+                                    tag_id, value, converted
+                                );
+                                value = converted;
+                            }
+                            Err(e) => {
+                                debug!(
+                                    "Failed to apply ValueConv to EXIF tag 0x{:04x}: {}",
+                                    tag_id, e
+                                );
+                            }
                         }
                     }
                 }
+                value
+            }
+        }
+    }
 
-                // Apply PrintConv second (if present) to get display value
-                let mut errors = Vec::new();
-                let mut warnings = Vec::new();
+    /// The PrintConv of a tag, run when its display value is first read
+    /// ExifTool: lib/Image/ExifTool.pm GetValue 'PrintConv'
+    ///
+    /// A PrintConv recorded in the tag's source wins over the one of the
+    /// table picked by where the tag was read. `None` means the display value
+    /// is the value itself.
+    pub(crate) fn deferred_print_conv(
+        tag_id: u16,
+        ifd_name: &str,
+        source_info: Option<&TagSourceInfo>,
+    ) -> Option<DeferredPrintConv> {
+        use crate::generated::Apple_pm::main_tags as apple_tag_kit;
+        use crate::generated::Exif_pm::main_tags;
+        use crate::generated::GPS_pm::main_tags as gps_tag_kit;
+        #[cfg(feature = "sony")]
+        use crate::generated::Sony_pm::main_tags as sony_tag_kit;
+        use crate::implementations::apple;
 
-                let print = sony_tag_kit::apply_print_conv(
-                    tag_id as u32,
-                    &value,
-                    &mut errors,
-                    &mut warnings,
-                );
+        if let Some(print_conv) = source_info.and_then(|info| info.print_conv) {
+            return Some(print_conv);
+        }
 
-                // Log any warnings (but suppress if we successfully applied fallback)
-                if print == value {
-                    for warning in warnings {
-                        debug!(
-                            "PrintConv warning for Sony tag 0x{:04x}: {}",
-                            tag_id, warning
-                        );
-                    }
-                }
+        let print_conv: TablePrintConvFn = match ConversionContext::new(ifd_name, source_info) {
+            ConversionContext::Gps if gps_tag_kit::GPS_MAIN_TAGS.contains_key(&tag_id) => {
+                gps_print_conv
+            }
+            ConversionContext::Apple
+                if apple::run_time_tag(tag_id).is_some()
+                    || (tag_id < 0xC000
+                        && apple_tag_kit::APPLE_MAIN_TAGS.contains_key(&tag_id)) =>
+            {
+                apple_print_conv
+            }
+            #[cfg(feature = "sony")]
+            ConversionContext::Sony if sony_tag_kit::SONY_MAIN_TAGS.contains_key(&tag_id) => {
+                sony_print_conv
+            }
+            ConversionContext::Exif if main_tags::EXIF_MAIN_TAGS.contains_key(&tag_id) => {
+                exif_print_conv
+            }
+            // No tag definition found, print is the value
+            _ => return None,
+        };
+        Some(DeferredPrintConv::new(print_conv, tag_id as u32))
+    }
+}
 
-                (value, print)
+/// GPS::Main PrintConv
+fn gps_print_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> TagValue {
+    use crate::generated::GPS_pm::main_tags as gps_tag_kit;
+
+    let Some(tag_def) = gps_tag_kit::GPS_MAIN_TAGS.get(&(tag_id as u16)) else {
+        return value.clone();
+    };
+    // For GPS coordinates, we want to use our manual registry functions
+    match tag_def.name {
+        "GPSLatitude" | "GPSLongitude" => {
+            // Note: Keep raw EXIF GPS coordinates unsigned - sign handling happens in composite tags
+            // ExifTool: Raw GPS coordinates in EXIF are always positive rationals
+            // Convert rational to decimal if needed (unsigned, for composite tag consumption)
+            if let Some(rational) = value.as_rational() {
+                TagValue::F64(rational.0 as f64 / rational.1 as f64)
             } else {
-                // No Sony tag definition found, return raw value for both
-                debug!("Sony tag 0x{:04x} not found in SONY_PM_TAG_KITS", tag_id);
-                (value.clone(), value)
+                value.clone()
             }
-        } else {
-            // For other IFDs, check EXIF tag kit
-            if let Some(tag_def) = main_tags::EXIF_MAIN_TAGS.get(&tag_id) {
-                debug!(
-                    "Found tag definition for tag 0x{:04x}: {}",
-                    tag_id, tag_def.name
-                );
-                // Apply ValueConv first (if present) using generated function
-                if tag_def.value_conv.is_some() {
-                    let mut value_conv_errors = Vec::new();
-                    match main_tags::apply_value_conv(tag_id as u32, &value, &mut value_conv_errors)
-                    {
-                        Ok(converted) => {
-                            debug!(
-                                "Applied ValueConv to EXIF tag 0x{:04x}: {:?} -> {:?}",
-                                tag_id, value, converted
-                            );
-                            value = converted;
-                        }
-                        Err(e) => {
-                            debug!(
-                                "Failed to apply ValueConv to EXIF tag 0x{:04x}: {}",
-                                tag_id, e
-                            );
-                        }
-                    }
-                }
+        }
+        "GPSDestLatitude" => {
+            use crate::registry;
+            registry::apply_print_conv("gpsdestlatitude_print_conv", value)
+        }
+        "GPSDestLongitude" => {
+            use crate::registry;
+            registry::apply_print_conv("gpsdestlongitude_print_conv", value)
+        }
+        "GPSAltitude" => {
+            // Note: Skip PrintConv for GPSAltitude because our test snapshots use
+            // ExifTool's -GPSAltitude# flag which outputs converted decimal values
+            // without PrintConv units to match PhotoStructure DAM requirements
+
+            // Convert rational to decimal if needed (matching ExifTool -GPSAltitude# behavior)
+            if let Some(rational) = value.as_rational() {
+                TagValue::F64(rational.0 as f64 / rational.1 as f64)
+            } else {
+                value.clone()
+            }
+        }
+        "GPSHPositioningError" => {
+            // Expression: "\"$val m\""
+            TagValue::String(format!("{} m", value))
+        }
+        _ => {
+            // Use the generic tag kit apply_print_conv for other GPS tags
+            let result = gps_tag_kit::apply_print_conv(tag_id, value, errors, warnings);
 
-                // Apply PrintConv second (if present) to get display value
-                let mut errors = Vec::new();
-                let mut warnings = Vec::new();
-
-                let print = match tag_def.name {
-                    "SubSecTime" | "SubSecTimeOriginal" | "SubSecTimeDigitized" => {
-                        // Note: These are stored as strings in TIFF but ExifTool outputs them as
-                        // numbers in JSON when they contain only digits (matching ExifTool's JSON behavior)
-                        if let Some(s) = value.as_string() {
-                            // Apply ValueConv: trim trailing whitespace like ExifTool
-                            let trimmed = s.trim_end();
-                            TagValue::string_with_numeric_detection(trimmed)
-                        } else {
-                            value.clone()
-                        }
-                    }
-                    _ => {
-                        // Use EXIF tag kit PrintConv - call the specific function from generated EXIF module
-                        main_tags::apply_print_conv(
-                            tag_id as u32,
-                            &value,
-                            &mut errors,
-                            &mut warnings,
-                        )
-                    }
-                };
+            // Log any warnings
+            for warning in warnings.iter() {
+                debug!("PrintConv warning for tag 0x{:04x}: {}", tag_id, warning);
+            }
 
-                // Log any warnings (but suppress if we successfully applied fallback)
-                if print == value {
-                    for warning in warnings {
-                        debug!("PrintConv warning for tag 0x{:04x}: {}", tag_id, warning);
-                    }
-                }
+            result
+        }
+    }
+}
+
+/// Apple::Main PrintConv
+fn apple_print_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> TagValue {
+    use crate::generated::Apple_pm::main_tags as apple_tag_kit;
+    use crate::implementations::apple;
+
+    if let Some(info) = apple::run_time_tag(tag_id as u16) {
+        return apple::run_time_print_conv(info, value);
+    }
+    apple_tag_kit::apply_print_conv(tag_id, value, errors, warnings)
+}
+
+/// Sony::Main PrintConv
+#[cfg(feature = "sony")]
+fn sony_print_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> TagValue {
+    use crate::generated::Sony_pm::main_tags as sony_tag_kit;
+
+    let print = sony_tag_kit::apply_print_conv(tag_id, value, errors, warnings);
+
+    // Log any warnings (but suppress if we successfully applied fallback)
+    if print == *value {
+        for warning in warnings.iter() {
+            debug!(
+                "PrintConv warning for Sony tag 0x{:04x}: {}",
+                tag_id, warning
+            );
+        }
+    }
+
+    print
+}
 
-                (value, print)
+/// Exif::Main PrintConv
+fn exif_print_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> TagValue {
+    use crate::generated::Exif_pm::main_tags;
+
+    let Some(tag_def) = main_tags::EXIF_MAIN_TAGS.get(&(tag_id as u16)) else {
+        return value.clone();
+    };
+    let print = match tag_def.name {
+        "SubSecTime" | "SubSecTimeOriginal" | "SubSecTimeDigitized" => {
+            // Note: These are stored as strings in TIFF but ExifTool outputs them as
+            // numbers in JSON when they contain only digits (matching ExifTool's JSON behavior)
+            if let Some(s) = value.as_string() {
+                // Apply ValueConv: trim trailing whitespace like ExifTool
+                let trimmed = s.trim_end();
+                TagValue::string_with_numeric_detection(trimmed)
             } else {
-                // No tag definition found, return raw value for both
-                (value.clone(), value)
+                value.clone()
             }
         }
+        _ => {
+            // Use EXIF tag kit PrintConv - call the specific function from generated EXIF module
+            main_tags::apply_print_conv(tag_id, value, errors, warnings)
+        }
+    };
+
+    // Log any warnings (but suppress if we successfully applied fallback)
+    if print == *value {
+        for warning in warnings.iter() {
+            debug!("PrintConv warning for tag 0x{:04x}: {}", tag_id, warning);
+        }
+    }
+
+    print
+}
+
+/// Which generated tag table converts a tag, based on where it was read
+enum ConversionContext {
+    Gps,
+    Apple,
+    #[cfg(feature = "sony")]
+    Sony,
+    Exif,
+}
+
+impl ConversionContext {
+    fn new(ifd_name: &str, source_info: Option<&TagSourceInfo>) -> Self {
+        let namespace = source_info.map(|si| si.namespace.as_str());
+        if ifd_name == "GPS" {
+            return Self::Gps;
+        }
+        if namespace == Some("Apple") {
+            return Self::Apple;
+        }
+        #[cfg(feature = "sony")]
+        if ifd_name == "Sony" || namespace == Some("Sony") {
+            return Self::Sony;
+        }
+        Self::Exif
     }
 }
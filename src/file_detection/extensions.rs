@@ -2,7 +2,7 @@
 //!
 //! Handles extension-based file type detection following ExifTool's rules.
 
-use super::FileDetectionError;
+use super::{FileDetectionError, FileTypeDetectionResult};
 use std::path::Path;
 
 /// Get file type candidates based on file extension
@@ -25,7 +25,11 @@ pub fn get_candidates_from_extension(path: &Path) -> Result<Vec<String>, FileDet
 
     // For HEIC/HEIF, we need special handling
     // Even if not in the generated lookup, these are valid extensions
-    let is_heif_extension = matches!(normalized_ext.as_str(), "HEIC" | "HEIF" | "HIF");
+    // (as are the image sequence extensions HEICS, HEIFS and AVIFS)
+    let is_heif_extension = matches!(
+        normalized_ext.as_str(),
+        "HEIC" | "HEIF" | "HIF" | "HEICS" | "HEIFS" | "AVIFS"
+    );
 
     if is_known_extension || is_heif_extension {
         // For most formats, the extension itself is the file type candidate
@@ -39,7 +43,7 @@ pub fn get_candidates_from_extension(path: &Path) -> Result<Vec<String>, FileDet
             "MTS" => Ok(vec!["M2TS".to_string()]), // ExifTool.pm alias
             // HEIC/HEIF/HIF extensions should use MOV format for detection
             // ExifTool QuickTime.pm handles these as MOV-based formats
-            "HEIC" | "HEIF" | "HIF" => Ok(vec!["MOV".to_string()]),
+            "HEIC" | "HEIF" | "HIF" | "HEICS" | "HEIFS" | "AVIFS" => Ok(vec!["MOV".to_string()]),
             _ => Ok(vec![normalized_ext.clone()]), // Use the extension as the type
         }
     } else {
@@ -49,6 +53,36 @@ pub fn get_candidates_from_extension(path: &Path) -> Result<Vec<String>, FileDet
     }
 }
 
+/// File type a file's extension claims, when its content was detected as
+/// something else
+///
+/// Returns `None` for unknown extensions and for extensions that cover the
+/// detected type through its format (a QuickTime `.mp4`, a `.nef` read as
+/// TIFF, ...). Upload pipelines use this to flag mislabeled files.
+///
+/// ```
+/// use exif_oxide::file_detection::{detect_file_type_from_bytes, extension_mismatch};
+/// use std::path::Path;
+///
+/// let detected = detect_file_type_from_bytes(b"\xff\xd8\xff\xe0\0\x10JFIF\0").unwrap();
+/// assert_eq!(extension_mismatch(Path::new("photo.png"), &detected).as_deref(), Some("PNG"));
+/// assert_eq!(extension_mismatch(Path::new("photo.jpg"), &detected), None);
+/// ```
+pub fn extension_mismatch(path: &Path, detected: &FileTypeDetectionResult) -> Option<String> {
+    use crate::generated::ExifTool_pm::file_type_lookup::resolve_file_type;
+
+    let candidates = get_candidates_from_extension(path).ok()?;
+    let covers_detected = |candidate: &String| {
+        candidate == &detected.file_type
+            || resolve_file_type(candidate)
+                .is_some_and(|(formats, _)| formats[0] == detected.format)
+    };
+    if candidates.iter().any(covers_detected) {
+        return None;
+    }
+    candidates.into_iter().next()
+}
+
 /// Normalize file extension following ExifTool's rules
 /// ExifTool equivalent: GetFileExtension() in ExifTool.pm:9013-9040
 pub fn normalize_extension(extension: &str) -> String {
@@ -76,3 +110,37 @@ pub fn has_processing_module(file_type: &str) -> bool {
     // If resolve_file_type returns Some, it means ExifTool knows how to process this type
     resolve_file_type(file_type).is_some()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_detection::FileTypeDetector;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_wrong_extension_detected_by_content() {
+        let detector = FileTypeDetector::new();
+        let jpeg = b"\xff\xd8\xff\xe0\0\x10JFIF\0";
+
+        let result = detector
+            .detect_file_type(Path::new("photo.png"), &mut Cursor::new(jpeg))
+            .unwrap();
+        assert_eq!(result.file_type, "JPEG");
+        assert_eq!(result.mime_type, "image/jpeg");
+        assert_eq!(
+            extension_mismatch(Path::new("photo.png"), &result).as_deref(),
+            Some("PNG")
+        );
+
+        // Unknown extensions, and extensions sharing the detected format, match
+        assert_eq!(extension_mismatch(Path::new("photo.jpeg"), &result), None);
+        assert_eq!(extension_mismatch(Path::new("photo.xyz"), &result), None);
+        let quicktime = detector
+            .detect_file_type(
+                Path::new("clip.mp4"),
+                &mut Cursor::new(b"\0\0\0\x18ftypqt  \0\0\0\0qt  "),
+            )
+            .unwrap();
+        assert_eq!(extension_mismatch(Path::new("clip.mp4"), &quicktime), None);
+    }
+}
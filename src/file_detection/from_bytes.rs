@@ -0,0 +1,178 @@
+//! Content-only file type detection
+//!
+//! Detects a file type from its leading bytes alone, for callers that have no
+//! trustworthy file name (upload services routing by content, for example).
+//! Only the generated magic number, file type and MIME tables are touched: no
+//! tag registries or format processors are initialized.
+
+use super::{
+    build_result, detect_riff_type, determine_mov_subtype, matches_magic_number,
+    scan_for_embedded_signatures, validate_tiff_raw_format, FileDetectionError,
+    FileTypeDetectionResult, MAGIC_TEST_BUFFER_SIZE,
+};
+use crate::generated::ExifTool_pm::file_type_lookup::resolve_file_type;
+use crate::generated::ExifTool_pm::file_types::FILE_TYPES;
+use std::path::Path;
+
+/// Types whose magic numbers are loose enough to match other formats' headers
+/// (`II`/`MM`, a leading `<`, two-byte markers, any printable text, ...). With a file name these
+/// only compete with the extension's candidates; here they are tested after
+/// every other type, in this order.
+const LOOSE_MAGIC_TYPES: &[&str] = &[
+    "TIFF", "RAW", "XMP", "HTML", "PLIST", "BMP", "ICO", "Font", "RSRC", "PICT", "M2TS", "MOI",
+    "AAC", "APE", "FLAC", "MPC", "OGG", "TXT",
+];
+
+/// TIFF-based RAW formats that can be told apart from plain TIFF by their
+/// header alone. NEF, ARW, DNG etc. need the file name (or the IFDs) and are
+/// reported as TIFF.
+/// ExifTool.pm:8531-8555 DoProcessTIFF() header checks
+const TIFF_RAW_BY_HEADER: &[&str] = &["CR2", "RW2", "ORF"];
+
+/// Detect a file type from the start of its content
+///
+/// Looks at (up to) the first 1024 bytes, the same test buffer ExifTool
+/// uses. Every type with a magic number is tried, then, like
+/// [`FileTypeDetector::detect_file_type`](super::FileTypeDetector::detect_file_type),
+/// embedded JPEG/TIFF signatures are used as a last resort.
+///
+/// Empty input, and types ExifTool identifies only by extension (MP3 without
+/// an ID3 tag, ISO, ...), return [`FileDetectionError::UnknownFileType`].
+///
+/// ```
+/// use exif_oxide::file_detection::detect_file_type_from_bytes;
+///
+/// let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+/// let detected = detect_file_type_from_bytes(png).unwrap();
+/// assert_eq!(detected.file_type, "PNG");
+/// assert_eq!(detected.mime_type, "image/png");
+/// ```
+pub fn detect_file_type_from_bytes(
+    data: &[u8],
+) -> Result<FileTypeDetectionResult, FileDetectionError> {
+    if data.is_empty() {
+        return Err(FileDetectionError::UnknownFileType);
+    }
+    let buffer = &data[..data.len().min(MAGIC_TEST_BUFFER_SIZE)];
+
+    if let Some(detected_type) = detect_file_type_by_magic(buffer) {
+        return build_result(&detected_type, Path::new(""));
+    }
+
+    // ExifTool.pm:2976-2983 - Look for JPEG/TIFF embedded in unknown data
+    if let Some(embedded_type) = scan_for_embedded_signatures(buffer) {
+        return build_result(&embedded_type, Path::new(""));
+    }
+
+    Err(FileDetectionError::UnknownFileType)
+}
+
+/// File type of the first magic number that matches `buffer`, without the
+/// embedded JPEG/TIFF signature scan
+pub(super) fn detect_file_type_by_magic(buffer: &[u8]) -> Option<String> {
+    match_magic_number(buffer).map(|file_type| refine_file_type(file_type, buffer))
+}
+
+/// First file type whose magic number matches, loose patterns last
+fn match_magic_number(buffer: &[u8]) -> Option<&'static str> {
+    // MP3 has no magic number (ExifTool.pm %weakMagic), but an ID3v2 tag in
+    // front of audio is far more likely MP3 than the APE/FLAC/MPC/OGG
+    // patterns that also accept it
+    if buffer.starts_with(b"ID3") {
+        return Some("MP3");
+    }
+
+    FILE_TYPES
+        .iter()
+        .copied()
+        .filter(|file_type| !LOOSE_MAGIC_TYPES.contains(file_type))
+        .chain(LOOSE_MAGIC_TYPES.iter().copied())
+        .find(|file_type| matches_magic_number(file_type, buffer))
+}
+
+/// Narrow a container-level match to the specific type, as the extension-based
+/// detector does after its magic number check
+fn refine_file_type(file_type: &str, buffer: &[u8]) -> String {
+    let format = resolve_file_type(file_type).map_or(file_type, |(formats, _)| formats[0]);
+
+    let refined = match format {
+        // ExifTool QuickTime.pm:9868-9877 - ftyp brand determines actual file type
+        "MOV" => determine_mov_subtype(buffer),
+        // ExifTool RIFF.pm:2038-2039 - RIFF format identifier
+        "RIFF" => detect_riff_type(buffer),
+        "TIFF" => TIFF_RAW_BY_HEADER
+            .iter()
+            .find(|raw_type| validate_tiff_raw_format(raw_type, buffer))
+            .map(|raw_type| raw_type.to_string()),
+        _ => None,
+    };
+    refined.unwrap_or_else(|| file_type.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(data: &[u8]) -> String {
+        detect_file_type_from_bytes(data).unwrap().file_type
+    }
+
+    #[test]
+    fn test_detects_common_upload_types() {
+        assert_eq!(detect(b"\xff\xd8\xff\xe1\0\x10Exif\0\0"), "JPEG");
+        assert_eq!(detect(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"), "PNG");
+        assert_eq!(detect(b"GIF89a\x01\0\x01\0"), "GIF");
+        assert_eq!(detect(b"\0\0\0\x18ftypheic\0\0\0\0mif1heic"), "HEIC");
+        assert_eq!(detect(b"\0\0\0\x18ftypqt  \0\0\0\0qt  "), "MOV");
+        assert_eq!(detect(b"RIFF\x24\0\0\0WEBPVP8 "), "WEBP");
+        assert_eq!(detect(b"ID3\x04\0\0\0\0\0\0"), "MP3");
+
+        let result = detect_file_type_from_bytes(b"\0\0\0\x18ftypheic").unwrap();
+        assert_eq!(result.format, "MOV");
+        assert_eq!(result.mime_type, "image/heic");
+    }
+
+    #[test]
+    fn test_detects_image_sequences() {
+        assert_eq!(detect(b"\0\0\0\x18ftypavis\0\0\0\0avifmsf1"), "AVIFS");
+        assert_eq!(detect(b"\0\0\0\x18ftypmsf1\0\0\0\0msf1hevc"), "HEIFS");
+
+        let result = detect_file_type_from_bytes(b"\0\0\0\x18ftyphevc\0\0\0\0msf1hevc").unwrap();
+        assert_eq!(result.file_type, "HEICS");
+        assert_eq!(result.format, "MOV");
+        assert_eq!(result.mime_type, "image/heic-sequence");
+    }
+
+    #[test]
+    fn test_tiff_raw_only_when_header_says_so() {
+        let mut cr2 = b"II\x2a\0\x10\0\0\0CR\x02\0".to_vec();
+        cr2.resize(32, 0);
+        assert_eq!(detect(&cr2), "CR2");
+
+        assert_eq!(detect(b"IIRO\x08\0\0\0\0\0\0\0\0\0\0\0"), "ORF");
+
+        // NEF/ARW/DNG headers are plain TIFF without a file name
+        let mut tiff = b"MM\0\x2a\0\0\0\x08".to_vec();
+        tiff.resize(32, 0);
+        assert_eq!(detect(&tiff), "TIFF");
+    }
+
+    #[test]
+    fn test_magic_numbers_are_anchored() {
+        // "ID3" and AAC's \xff\xf1 only count at the start of the file
+        assert!(matches!(
+            detect_file_type_from_bytes(b"\x01\x02 ID3 \xff\xf1 \x01"),
+            Err(FileDetectionError::UnknownFileType)
+        ));
+        assert_eq!(detect(b"hello ID3 world"), "TXT");
+        assert!(matches!(
+            detect_file_type_from_bytes(b""),
+            Err(FileDetectionError::UnknownFileType)
+        ));
+    }
+
+    #[test]
+    fn test_embedded_jpeg_fallback() {
+        assert_eq!(detect(b"\0\0\0\0garbage\xff\xd8\xff\xe0"), "JPEG");
+    }
+}
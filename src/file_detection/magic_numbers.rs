@@ -4,6 +4,7 @@
 //! and regex fallback for complex patterns.
 
 use crate::generated::ExifTool_pm::magic_numbers::{LITERAL_MAGIC_NUMBERS, REGEX_MAGIC_NUMBERS};
+use memchr::memmem;
 
 /// Match magic number patterns using the two-HashMap system
 /// First tries literal patterns (fast), then falls back to regex patterns (slower)
@@ -13,9 +14,11 @@ pub fn matches_magic_number(file_type: &str, buffer: &[u8]) -> bool {
         return buffer.starts_with(pattern);
     }
 
-    // Fall back to regex patterns for complex cases
+    // Fall back to regex patterns for complex cases. The generated patterns are
+    // unanchored, but ExifTool tests them at the start of the file
+    // ExifTool.pm:2960 - "$buff !~ /^$magicNumber{$type}/s"
     if let Some(regex) = REGEX_MAGIC_NUMBERS.get(file_type) {
-        return regex.is_match(buffer);
+        return regex.find(buffer).is_some_and(|m| m.start() == 0);
     }
 
     false
@@ -25,7 +28,7 @@ pub fn matches_magic_number(file_type: &str, buffer: &[u8]) -> bool {
 /// ExifTool equivalent: ExifTool.pm:2976-2983
 pub fn scan_for_embedded_signatures(buffer: &[u8]) -> Option<String> {
     // Look for JPEG signature: \xff\xd8\xff
-    if let Some(pos) = buffer.windows(3).position(|w| w == b"\xff\xd8\xff") {
+    if let Some(pos) = memmem::find(buffer, b"\xff\xd8\xff") {
         if pos > 0 {
             eprintln!("Warning: Processing JPEG-like data after unknown {pos}-byte header");
         }
@@ -33,10 +36,9 @@ pub fn scan_for_embedded_signatures(buffer: &[u8]) -> Option<String> {
     }
 
     // Look for TIFF signatures: II*\0 or MM\0*
-    if let Some(pos) = buffer
-        .windows(4)
-        .position(|w| w == b"II*\0" || w == b"MM\0*")
-    {
+    let little_endian = memmem::find(buffer, b"II*\0");
+    let big_endian = memmem::find(buffer, b"MM\0*");
+    if let Some(pos) = little_endian.into_iter().chain(big_endian).min() {
         if pos > 0 {
             eprintln!("Warning: Processing TIFF-like data after unknown {pos}-byte header");
         }
@@ -15,6 +15,10 @@ pub fn build_result(
     use crate::generated::ExifTool_pm::file_type_lookup::resolve_file_type;
     let (format, description) = if let Some((formats, desc)) = resolve_file_type(file_type) {
         (formats[0].to_string(), desc.to_string())
+    } else if let Some((formats, desc)) =
+        super::image_sequence_still_type(file_type).and_then(resolve_file_type)
+    {
+        (formats[0].to_string(), format!("{desc} sequence"))
     } else {
         (file_type.to_string(), format!("{file_type} file"))
     };
@@ -65,6 +69,9 @@ pub fn get_fallback_mime_type(file_type: &str) -> Option<&'static str> {
         "AVIF" => Some("image/avif"), // AV1 Image File Format - from ExifTool QuickTime.pm %mimeLookup
         "HEIC" => Some("image/heic"), // HEIC gets its own MIME type
         "HEIF" => Some("image/heif"), // High Efficiency Image Format (general)
+        "HEICS" => Some("image/heic-sequence"), // From ExifTool QuickTime.pm %mimeLookup
+        "HEIFS" => Some("image/heif-sequence"), // From ExifTool QuickTime.pm %mimeLookup
+        "AVIFS" => Some("image/avif"), // AVIF sequences share the still image MIME type
         "JP2" => Some("image/jp2"),   // JPEG 2000 Part 1 (ISO/IEC 15444-1)
         "J2C" => Some("image/x-j2c"), // JPEG 2000 Code Stream
 
@@ -13,8 +13,12 @@
 //! - Extension normalization rules
 //! - Conflict resolution patterns
 //! - Error recovery mechanisms
+//!
+//! For content-only detection (no file name, no EXIF machinery), see
+//! [`detect_file_type_from_bytes`].
 
 pub mod extensions;
+pub mod from_bytes;
 pub mod magic_numbers;
 pub mod mime_types;
 pub mod mov_video;
@@ -24,10 +28,13 @@ pub mod tiff_raw;
 #[cfg(test)]
 mod mimetypes_validation;
 
-pub use extensions::{get_candidates_from_extension, has_processing_module, normalize_extension};
+pub use extensions::{
+    extension_mismatch, get_candidates_from_extension, has_processing_module, normalize_extension,
+};
+pub use from_bytes::detect_file_type_from_bytes;
 pub use magic_numbers::{matches_magic_number, scan_for_embedded_signatures, validate_xmp_pattern};
 pub use mime_types::{build_result, get_fallback_mime_type};
-pub use mov_video::determine_mov_subtype;
+pub use mov_video::{determine_mov_subtype, image_sequence_still_type, image_sequence_type};
 pub use riff::{detect_riff_type, is_riff_based_format, validate_riff_format};
 pub use tiff_raw::{is_tiff_based_raw_format, validate_tiff_raw_format};
 
@@ -145,6 +152,18 @@ impl FileTypeDetector {
             }
         }
 
+        // ExifTool.pm:2960-2975 - when the extension's type fails its magic
+        // number test, the other types' magic numbers are tried before
+        // settling for the extension. A match here means the extension is
+        // wrong (see [`extension_mismatch`]). The embedded JPEG/TIFF scan
+        // comes after the extension, since a valid file (a JXL with an Exif
+        // box, say) can hold either signature
+        if matched_type.is_none() && !candidates.is_empty() {
+            if let Some(detected_type) = from_bytes::detect_file_type_by_magic(&buffer) {
+                return build_result(&detected_type, path);
+            }
+        }
+
         // If no magic match but we have a recognized extension with a module,
         // use that as fallback (mimics ExifTool's behavior for JXL and others)
         if matched_type.is_none() && recognized_ext.is_some() {
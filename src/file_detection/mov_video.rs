@@ -11,9 +11,15 @@ pub fn determine_mov_subtype(buffer: &[u8]) -> Option<String> {
         // Map ftyp brand to specific file type
         // ExifTool QuickTime.pm:227-232 - %ftypLookup entries
         match brand {
-            b"heic" | b"hevc" => Some("HEIC".to_string()),
-            b"mif1" | b"msf1" | b"heix" => Some("HEIF".to_string()),
+            b"heic" => Some("HEIC".to_string()),
+            b"mif1" | b"heix" => Some("HEIF".to_string()),
             b"avif" => Some("AVIF".to_string()),
+            // Image sequences: ExifTool takes the type from the %ftypLookup
+            // description, "(.HEICS)" and "(.HEIFS)". 'avis' is "(.AVIF)" there;
+            // exif-oxide reports AVIFS so animated AVIFs can be told apart
+            b"hevc" => Some("HEICS".to_string()),
+            b"msf1" => Some("HEIFS".to_string()),
+            b"avis" => Some("AVIFS".to_string()),
             b"crx " => Some("CR3".to_string()), // Canon RAW 3 format
             // Common MP4 brands
             b"mp41" | b"mp42" | b"mp4v" | b"isom" | b"M4A " | b"M4V " | b"dash" | b"avc1" => {
@@ -25,3 +31,23 @@ pub fn determine_mov_subtype(buffer: &[u8]) -> Option<String> {
         None
     }
 }
+
+/// Image sequence file types and the still image type of each
+const IMAGE_SEQUENCE_TYPES: &[(&str, &str)] =
+    &[("HEICS", "HEIC"), ("HEIFS", "HEIF"), ("AVIFS", "AVIF")];
+
+/// Still image type of an image sequence file type (`HEICS` → `HEIC`)
+pub fn image_sequence_still_type(file_type: &str) -> Option<&'static str> {
+    IMAGE_SEQUENCE_TYPES
+        .iter()
+        .find(|(sequence, _)| *sequence == file_type)
+        .map(|(_, still)| *still)
+}
+
+/// Image sequence file type of a still image type (`HEIC` → `HEICS`)
+pub fn image_sequence_type(file_type: &str) -> Option<&'static str> {
+    IMAGE_SEQUENCE_TYPES
+        .iter()
+        .find(|(_, still)| *still == file_type)
+        .map(|(sequence, _)| *sequence)
+}
@@ -96,6 +96,20 @@ mod tests {
         assert_eq!(result.file_type, "JPEG");
     }
 
+    #[test]
+    fn test_embedded_signature_scan() {
+        assert_eq!(
+            scan_for_embedded_signatures(b"junk\xff\xd8\xffdata"),
+            Some("JPEG".to_string())
+        );
+        // The earliest TIFF signature wins, whichever byte order it is
+        assert_eq!(
+            scan_for_embedded_signatures(b"junkMM\0*\0\0\0\x08II*\0"),
+            Some("TIFF".to_string())
+        );
+        assert_eq!(scan_for_embedded_signatures(b"no signature here"), None);
+    }
+
     #[test]
     fn test_weak_magic_mp3() {
         let detector = FileTypeDetector::new();
@@ -0,0 +1,276 @@
+//! FLAC metadata blocks
+//!
+//! After the `fLaC` signature comes a chain of metadata blocks, each with a
+//! 4-byte header: a last-block flag, a 7-bit block type and a 24-bit length.
+//! StreamInfo (type 0), VorbisComment (4) and Picture (6) are decoded; the
+//! others (padding, seek table, cue sheet, ...) are skipped.
+//!
+//! ExifTool reference: FLAC.pm ProcessFLAC, %Image::ExifTool::FLAC::StreamInfo
+//! and %Image::ExifTool::FLAC::Picture.
+
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+
+use tracing::debug;
+
+use super::{entry, picture_type_name, vorbis, MAX_BLOCK_SIZE};
+use crate::types::{ExifError, Result, TagEntry, TagValue};
+
+/// ExifTool group (G0/G1) of FLAC tags
+const GROUP: &str = "FLAC";
+
+const STREAM_INFO: u8 = 0;
+const VORBIS_COMMENT: u8 = 4;
+const PICTURE: u8 = 6;
+
+/// Read the metadata blocks of a FLAC stream starting at the current position
+pub(super) fn read_flac<R: Read + Seek>(reader: &mut R) -> Result<Vec<TagEntry>> {
+    let mut signature = [0u8; 4];
+    if reader.read_exact(&mut signature).is_err() || &signature != b"fLaC" {
+        return Err(ExifError::ParseError("Missing fLaC signature".to_string()));
+    }
+
+    let mut entries = Vec::new();
+    loop {
+        let mut header = [0u8; 4];
+        match reader.read_exact(&mut header) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(e.into()),
+        }
+        let last = header[0] & 0x80 != 0;
+        let block_type = header[0] & 0x7f;
+        let size = u32::from_be_bytes([0, header[1], header[2], header[3]]) as u64;
+
+        if matches!(block_type, STREAM_INFO | VORBIS_COMMENT | PICTURE) {
+            let mut data = Vec::new();
+            reader
+                .by_ref()
+                .take(size.min(MAX_BLOCK_SIZE))
+                .read_to_end(&mut data)?;
+            if (data.len() as u64) < size {
+                debug!("Truncated FLAC metadata block type {block_type}");
+                break;
+            }
+            match block_type {
+                STREAM_INFO => entries.extend(parse_stream_info(&data)),
+                VORBIS_COMMENT => entries.extend(vorbis::parse_comments(&data)),
+                _ => entries.extend(parse_picture(&data)),
+            }
+        } else {
+            reader.seek(SeekFrom::Current(size as i64))?;
+        }
+        if last {
+            break;
+        }
+    }
+    Ok(entries)
+}
+
+/// `len` bits starting at bit `start`, most significant bit first
+fn bits(data: &[u8], start: usize, len: usize) -> u64 {
+    (start..start + len).fold(0, |value, bit| {
+        (value << 1) | ((data[bit / 8] >> (7 - bit % 8)) & 1) as u64
+    })
+}
+
+/// ExifTool: FLAC.pm %Image::ExifTool::FLAC::StreamInfo (Bit000-015 ...)
+fn parse_stream_info(data: &[u8]) -> Vec<TagEntry> {
+    if data.len() < 34 {
+        return Vec::new();
+    }
+    let number = |name: &str, value: u64| {
+        let value = match u32::try_from(value) {
+            Ok(value) => TagValue::U32(value),
+            Err(_) => TagValue::U64(value),
+        };
+        entry(GROUP, GROUP, name, value.clone(), value)
+    };
+    let md5 = data[18..34]
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect::<String>();
+    vec![
+        number("BlockSizeMin", bits(data, 0, 16)),
+        number("BlockSizeMax", bits(data, 16, 16)),
+        number("FrameSizeMin", bits(data, 32, 24)),
+        number("FrameSizeMax", bits(data, 56, 24)),
+        number("SampleRate", bits(data, 80, 20)),
+        // Channels and BitsPerSample: ValueConv '$val + 1'
+        number("Channels", bits(data, 100, 3) + 1),
+        number("BitsPerSample", bits(data, 103, 5) + 1),
+        number("TotalSamples", bits(data, 108, 36)),
+        entry(
+            GROUP,
+            GROUP,
+            "MD5Signature",
+            TagValue::string(md5.clone()),
+            TagValue::string(md5),
+        ),
+    ]
+}
+
+/// Decode a Picture block, also used for Vorbis METADATA_BLOCK_PICTURE
+///
+/// ExifTool: FLAC.pm %Image::ExifTool::FLAC::Picture
+pub(super) fn parse_picture(data: &[u8]) -> Vec<TagEntry> {
+    let mut entries = Vec::new();
+    let mut pos = 0;
+    let u32_at = |pos: &mut usize| -> Option<u32> {
+        let value = data.get(*pos..*pos + 4)?;
+        *pos += 4;
+        Some(u32::from_be_bytes(value.try_into().unwrap()))
+    };
+    let string_at = |pos: &mut usize| -> Option<String> {
+        let len = u32::from_be_bytes(data.get(*pos..*pos + 4)?.try_into().unwrap()) as usize;
+        let value = data.get(*pos + 4..*pos + 4 + len)?;
+        *pos += 4 + len;
+        Some(String::from_utf8_lossy(value).into_owned())
+    };
+
+    let Some(picture_type) = u32_at(&mut pos) else {
+        return entries;
+    };
+    entries.push(entry(
+        GROUP,
+        GROUP,
+        "PictureType",
+        TagValue::U32(picture_type),
+        TagValue::string(picture_type_name(picture_type)),
+    ));
+    for name in ["PictureMIMEType", "PictureDescription"] {
+        let Some(text) = string_at(&mut pos) else {
+            return entries;
+        };
+        entries.push(entry(
+            GROUP,
+            GROUP,
+            name,
+            TagValue::string(text.clone()),
+            TagValue::string(text),
+        ));
+    }
+    for name in [
+        "PictureWidth",
+        "PictureHeight",
+        "PictureBitsPerPixel",
+        "PictureIndexedColors",
+        "PictureLength",
+    ] {
+        let Some(value) = u32_at(&mut pos) else {
+            return entries;
+        };
+        entries.push(entry(
+            GROUP,
+            GROUP,
+            name,
+            TagValue::U32(value),
+            TagValue::U32(value),
+        ));
+    }
+    // PictureLength is the last value read
+    let length = match entries.last().map(|e| &e.value) {
+        Some(TagValue::U32(length)) => *length as usize,
+        _ => 0,
+    };
+    if let Some(image) = data.get(pos..pos + length) {
+        let image = TagValue::Binary(image.to_vec());
+        entries.push(entry(GROUP, GROUP, "Picture", image.clone(), image));
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn block(block_type: u8, last: bool, data: &[u8]) -> Vec<u8> {
+        let mut block = vec![block_type | if last { 0x80 } else { 0 }];
+        block.extend(&(data.len() as u32).to_be_bytes()[1..]);
+        block.extend(data);
+        block
+    }
+
+    /// 44.1 kHz stereo 16-bit, 441000 samples (10 s)
+    fn stream_info() -> Vec<u8> {
+        let mut data = vec![0x10, 0x00, 0x10, 0x00, 0, 0, 0x0e, 0, 0x20, 0];
+        // 20-bit sample rate, 3-bit channels - 1, 5-bit bits/sample - 1,
+        // 36-bit total samples
+        let packed: u64 = (44100 << 44) | (1 << 41) | (15 << 36) | 441000;
+        data.extend(packed.to_be_bytes());
+        data.extend([0xab; 16]);
+        data
+    }
+
+    fn picture() -> Vec<u8> {
+        let mut data = 3u32.to_be_bytes().to_vec();
+        for text in ["image/jpeg", ""] {
+            data.extend((text.len() as u32).to_be_bytes());
+            data.extend(text.as_bytes());
+        }
+        for value in [600u32, 600, 24, 0, 4] {
+            data.extend(value.to_be_bytes());
+        }
+        data.extend([0xff, 0xd8, 0xff, 0xd9]);
+        data
+    }
+
+    #[test]
+    fn test_read_flac() {
+        let mut comments = 6u32.to_le_bytes().to_vec();
+        comments.extend(b"vendor");
+        comments.extend(1u32.to_le_bytes());
+        comments.extend(10u32.to_le_bytes());
+        comments.extend(b"DATE=2024 ");
+
+        let mut file = b"fLaC".to_vec();
+        file.extend(block(STREAM_INFO, false, &stream_info()));
+        file.extend(block(1, false, &[0; 8])); // padding
+        file.extend(block(VORBIS_COMMENT, false, &comments));
+        file.extend(block(PICTURE, true, &picture()));
+        file.extend([0xff, 0xf8]); // first audio frame
+
+        let entries = read_flac(&mut Cursor::new(file)).unwrap();
+        let get = |name: &str| {
+            &entries
+                .iter()
+                .find(|e| e.name == name)
+                .unwrap_or_else(|| panic!("{name} missing"))
+                .value
+        };
+        assert_eq!(get("BlockSizeMin"), &TagValue::U32(4096));
+        assert_eq!(get("FrameSizeMin"), &TagValue::U32(14));
+        assert_eq!(get("FrameSizeMax"), &TagValue::U32(0x2000));
+        assert_eq!(get("SampleRate"), &TagValue::U32(44100));
+        assert_eq!(get("Channels"), &TagValue::U32(2));
+        assert_eq!(get("BitsPerSample"), &TagValue::U32(16));
+        assert_eq!(get("TotalSamples"), &TagValue::U32(441000));
+        assert_eq!(get("MD5Signature"), &TagValue::string("ab".repeat(16)));
+        assert_eq!(get("Date"), &TagValue::string("2024 "));
+        assert_eq!(get("PictureMIMEType"), &TagValue::string("image/jpeg"));
+        assert_eq!(get("PictureWidth"), &TagValue::U32(600));
+        assert_eq!(
+            get("Picture"),
+            &TagValue::Binary(vec![0xff, 0xd8, 0xff, 0xd9])
+        );
+    }
+
+    #[test]
+    fn test_metadata_block_picture_comment() {
+        use base64::prelude::{Engine, BASE64_STANDARD};
+
+        let field = format!(
+            "METADATA_BLOCK_PICTURE={}",
+            BASE64_STANDARD.encode(picture())
+        );
+        let mut comments = 0u32.to_le_bytes().to_vec();
+        comments.extend(1u32.to_le_bytes());
+        comments.extend((field.len() as u32).to_le_bytes());
+        comments.extend(field.as_bytes());
+
+        let entries = vorbis::parse_comments(&comments);
+        let picture_type = entries.iter().find(|e| e.name == "PictureType").unwrap();
+        assert_eq!(picture_type.group1, "FLAC");
+        assert_eq!(picture_type.print(), &TagValue::string("Front Cover"));
+    }
+}
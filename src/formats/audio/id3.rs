@@ -0,0 +1,518 @@
+//! ID3v1 and ID3v2 tags
+//!
+//! ExifTool reference: ID3.pm ProcessID3, ProcessID3v2 and the v1, v2_2,
+//! v2_3 and v2_4 tables. ID3v2 versions 2.2 (three-character frame IDs),
+//! 2.3 and 2.4 are read; compressed and encrypted frames are skipped.
+
+use std::collections::HashMap;
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+
+use tracing::debug;
+
+use super::{entry, picture_type_name, MAX_BLOCK_SIZE};
+use crate::generated::ID3_pm::genre::lookup_genre;
+use crate::generated::ID3_pm::v2_2_tags::ID3_V2_2_TAGS_BY_NAME;
+use crate::generated::ID3_pm::v2_3_tags::ID3_V2_3_TAGS_BY_NAME;
+use crate::generated::ID3_pm::v2_4_tags::ID3_V2_4_TAGS_BY_NAME;
+use crate::types::{Result, TagEntry, TagInfo, TagValue};
+
+/// ExifTool family 0 group of ID3 tags
+const GROUP: &str = "ID3";
+
+/// Size of an ID3v1 trailer
+const ID3V1_SIZE: u64 = 128;
+
+/// Tag definitions of the ID3v2 frames, keyed by frame ID, for a tag
+/// version. v2.2 IDs are three characters, v2.3/2.4 IDs four.
+///
+/// ExifTool: ID3.pm %Image::ExifTool::ID3::v2_2, v2_3 and v2_4
+fn frame_table(version: u8) -> &'static HashMap<&'static [u8], TagInfo> {
+    match version {
+        2 => &ID3_V2_2_TAGS_BY_NAME,
+        3 => &ID3_V2_3_TAGS_BY_NAME,
+        _ => &ID3_V2_4_TAGS_BY_NAME,
+    }
+}
+
+/// Read the ID3v2 tag at the start of the file
+///
+/// Returns the tag's entries and the offset of the audio data after it, or
+/// `None` if the file doesn't start with an ID3v2 header.
+pub(super) fn read_id3v2<R: Read + Seek>(reader: &mut R) -> Result<Option<(Vec<TagEntry>, u64)>> {
+    let mut header = [0u8; 10];
+    match reader.read_exact(&mut header) {
+        Ok(()) => {}
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e.into()),
+    }
+    if &header[..3] != b"ID3" || !(2..=4).contains(&header[3]) {
+        return Ok(None);
+    }
+    let version = header[3];
+    let flags = header[5];
+    let size = syncsafe(&header[6..10]) as u64;
+    // ID3v2.4 footer flag: a 10-byte copy of the header follows the frames
+    let footer = if version == 4 && flags & 0x10 != 0 {
+        10
+    } else {
+        0
+    };
+    let end = 10 + size + footer;
+
+    let mut data = Vec::new();
+    reader
+        .take(size.min(MAX_BLOCK_SIZE))
+        .read_to_end(&mut data)?;
+    // ID3v2.2/2.3 unsynchronise the whole tag; 2.4 does it per frame
+    if flags & 0x80 != 0 && version < 4 {
+        data = remove_unsync(&data);
+    }
+
+    let group1 = format!("ID3v2_{version}");
+    let mut entries = Vec::new();
+    let mut pos = 0;
+    if flags & 0x40 != 0 && version > 2 {
+        // Extended header: 2.3 size excludes its own 4 bytes, 2.4 includes them
+        let Some(size) = data.get(..4) else {
+            return Ok(Some((entries, end)));
+        };
+        pos = if version == 3 {
+            4 + u32::from_be_bytes(size.try_into().unwrap()) as usize
+        } else {
+            syncsafe(size) as usize
+        };
+    }
+
+    let header_size = if version == 2 { 6 } else { 10 };
+    while pos + header_size <= data.len() {
+        let frame_header = &data[pos..pos + header_size];
+        if frame_header[0] == 0 {
+            // Padding
+            break;
+        }
+        let (id, size, frame_flags) = match version {
+            2 => (
+                &frame_header[..3],
+                u32::from_be_bytes([0, frame_header[3], frame_header[4], frame_header[5]]),
+                0,
+            ),
+            3 => (
+                &frame_header[..4],
+                u32::from_be_bytes(frame_header[4..8].try_into().unwrap()),
+                u16::from_be_bytes([frame_header[8], frame_header[9]]),
+            ),
+            _ => (
+                &frame_header[..4],
+                syncsafe(&frame_header[4..8]),
+                u16::from_be_bytes([frame_header[8], frame_header[9]]),
+            ),
+        };
+        let start = pos + header_size;
+        let Some(frame) = data.get(start..start + size as usize) else {
+            debug!("Truncated ID3v2 frame {}", String::from_utf8_lossy(id));
+            break;
+        };
+        pos = start + size as usize;
+
+        let id = String::from_utf8_lossy(id);
+        match frame_data(version, frame_flags, frame) {
+            Some(frame) => entries.extend(decode_frame(version, &id, &frame, &group1)),
+            None => debug!("Skipping compressed or encrypted ID3v2 frame {id}"),
+        }
+    }
+    Ok(Some((entries, end)))
+}
+
+/// Read the 128-byte ID3v1 trailer, if there is one
+///
+/// ExifTool: ID3.pm %Image::ExifTool::ID3::v1
+pub(super) fn read_id3v1<R: Read + Seek>(reader: &mut R) -> Result<Vec<TagEntry>> {
+    let file_size = reader.seek(SeekFrom::End(0))?;
+    if file_size < ID3V1_SIZE {
+        return Ok(Vec::new());
+    }
+    reader.seek(SeekFrom::Start(file_size - ID3V1_SIZE))?;
+    let mut tag = [0u8; ID3V1_SIZE as usize];
+    reader.read_exact(&mut tag)?;
+    if &tag[..3] != b"TAG" {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = Vec::new();
+    let mut add_text = |name: &str, bytes: &[u8]| {
+        let text = latin1(bytes);
+        let text = text.trim_end_matches(['\0', ' ']);
+        if !text.is_empty() {
+            entries.push(entry(
+                GROUP,
+                "ID3v1",
+                name,
+                TagValue::string(text),
+                TagValue::string(text),
+            ));
+        }
+    };
+    add_text("Title", &tag[3..33]);
+    add_text("Artist", &tag[33..63]);
+    add_text("Album", &tag[63..93]);
+    add_text("Year", &tag[93..97]);
+    // ID3v1.1: a zero byte before the last comment byte makes it the track
+    let track = (tag[125] == 0 && tag[126] != 0).then_some(tag[126]);
+    add_text("Comment", &tag[97..if track.is_some() { 125 } else { 127 }]);
+    if let Some(track) = track {
+        entries.push(entry(
+            GROUP,
+            "ID3v1",
+            "Track",
+            TagValue::U8(track),
+            TagValue::U8(track),
+        ));
+    }
+    entries.push(entry(
+        GROUP,
+        "ID3v1",
+        "Genre",
+        TagValue::U8(tag[127]),
+        TagValue::string(genre_name(tag[127])),
+    ));
+    Ok(entries)
+}
+
+/// 28-bit integer stored 7 bits per byte
+fn syncsafe(bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .fold(0, |value, byte| (value << 7) | (*byte as u32 & 0x7f))
+}
+
+/// Undo ID3 unsynchronisation: every `FF 00` was written for an `FF`
+fn remove_unsync(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    let mut previous = 0u8;
+    for &byte in data {
+        if !(previous == 0xff && byte == 0) {
+            out.push(byte);
+        }
+        previous = byte;
+    }
+    out
+}
+
+/// The frame payload with the extra bytes its flags announce removed, or
+/// `None` for compressed and encrypted frames
+fn frame_data(version: u8, flags: u16, frame: &[u8]) -> Option<Vec<u8>> {
+    let mut skip = 0;
+    match version {
+        3 => {
+            // compression 0x0080, encryption 0x0040, grouping 0x0020
+            if flags & 0x00c0 != 0 {
+                return None;
+            }
+            if flags & 0x0020 != 0 {
+                skip += 1;
+            }
+        }
+        4 => {
+            // grouping 0x0040, compression 0x0008, encryption 0x0004,
+            // unsynchronisation 0x0002, data length indicator 0x0001
+            if flags & 0x000c != 0 {
+                return None;
+            }
+            if flags & 0x0040 != 0 {
+                skip += 1;
+            }
+            if flags & 0x0001 != 0 {
+                skip += 4;
+            }
+        }
+        _ => {}
+    }
+    let data = frame.get(skip..)?;
+    if version == 4 && flags & 0x0002 != 0 {
+        Some(remove_unsync(data))
+    } else {
+        Some(data.to_vec())
+    }
+}
+
+/// Entries for one ID3v2 frame; frames outside the supported set are ignored
+fn decode_frame(version: u8, id: &str, frame: &[u8], group1: &str) -> Vec<TagEntry> {
+    let Some((&encoding, body)) = frame.split_first() else {
+        return Vec::new();
+    };
+    let text_entry = |name: &str, text: String| {
+        let print = if name == "Genre" {
+            TagValue::string(print_genre(&text))
+        } else {
+            TagValue::string(text.clone())
+        };
+        entry(GROUP, group1, name, TagValue::string(text), print)
+    };
+
+    let text_frame = id.starts_with('T') && id != "TXX" && id != "TXXX";
+    if let Some(info) = frame_table(version)
+        .get(id.as_bytes())
+        .filter(|_| text_frame)
+    {
+        let values = decode_strings(encoding, body);
+        let text = values
+            .into_iter()
+            .filter(|value| !value.is_empty())
+            .collect::<Vec<_>>()
+            .join(", ");
+        return vec![text_entry(info.name, text)];
+    }
+
+    match id {
+        // ExifTool: ID3.pm ProcessID3v2 COMM handling, "(description) text"
+        "COMM" | "COM" => {
+            let Some(text) = body.get(3..) else {
+                return Vec::new();
+            };
+            let mut values = decode_strings(encoding, text).into_iter();
+            let description = values.next().unwrap_or_default();
+            let comment = values.next().unwrap_or_default();
+            let comment = if description.is_empty() {
+                comment
+            } else {
+                format!("({description}) {comment}")
+            };
+            vec![text_entry("Comment", comment)]
+        }
+        "APIC" | "PIC" => decode_picture(id, encoding, body, group1),
+        _ => Vec::new(),
+    }
+}
+
+/// Attached picture: MIME type (v2.3/2.4) or image format (v2.2), picture
+/// type, description and the image itself
+///
+/// ExifTool: ID3.pm %Image::ExifTool::ID3::APIC and PIC
+fn decode_picture(id: &str, encoding: u8, body: &[u8], group1: &str) -> Vec<TagEntry> {
+    let mut entries = Vec::new();
+    let (format_name, format, rest) = if id == "PIC" {
+        let Some(format) = body.get(..3) else {
+            return entries;
+        };
+        ("PictureFormat", latin1(format), &body[3..])
+    } else {
+        let Some(end) = body.iter().position(|&b| b == 0) else {
+            return entries;
+        };
+        ("PictureMIMEType", latin1(&body[..end]), &body[end + 1..])
+    };
+    let Some((&picture_type, rest)) = rest.split_first() else {
+        return entries;
+    };
+    let (description, image) = split_string(encoding, rest);
+
+    entries.push(entry(
+        GROUP,
+        group1,
+        format_name,
+        TagValue::string(format.clone()),
+        TagValue::string(format),
+    ));
+    entries.push(entry(
+        GROUP,
+        group1,
+        "PictureType",
+        TagValue::U8(picture_type),
+        TagValue::string(picture_type_name(picture_type as u32)),
+    ));
+    if !description.is_empty() {
+        entries.push(entry(
+            GROUP,
+            group1,
+            "PictureDescription",
+            TagValue::string(description.clone()),
+            TagValue::string(description),
+        ));
+    }
+    let image = TagValue::Binary(image.to_vec());
+    entries.push(entry(GROUP, group1, "Picture", image.clone(), image));
+    entries
+}
+
+/// Split off the first string of an encoded frame body, returning it and the
+/// bytes after its terminator
+fn split_string(encoding: u8, data: &[u8]) -> (String, &[u8]) {
+    let wide = matches!(encoding, 1 | 2);
+    let end = if wide {
+        data.chunks_exact(2)
+            .position(|pair| pair == [0, 0])
+            .map(|index| index * 2)
+    } else {
+        data.iter().position(|&b| b == 0)
+    };
+    match end {
+        Some(end) => {
+            let terminator = if wide { 2 } else { 1 };
+            (
+                decode_string(encoding, &data[..end]),
+                &data[end + terminator..],
+            )
+        }
+        None => (decode_string(encoding, data), &[]),
+    }
+}
+
+/// All null-separated strings of an encoded frame body
+fn decode_strings(encoding: u8, mut data: &[u8]) -> Vec<String> {
+    let mut strings = Vec::new();
+    while !data.is_empty() {
+        let (string, rest) = split_string(encoding, data);
+        strings.push(string);
+        data = rest;
+    }
+    strings
+}
+
+/// Decode one ID3v2 string
+///
+/// Encodings: 0 ISO-8859-1, 1 UTF-16 with BOM, 2 UTF-16BE, 3 UTF-8
+fn decode_string(encoding: u8, data: &[u8]) -> String {
+    match encoding {
+        1 | 2 => {
+            let (little_endian, data) = match data {
+                [0xff, 0xfe, rest @ ..] => (true, rest),
+                [0xfe, 0xff, rest @ ..] => (false, rest),
+                _ => (false, data),
+            };
+            let units = data
+                .chunks_exact(2)
+                .map(|pair| {
+                    let pair = [pair[0], pair[1]];
+                    if little_endian {
+                        u16::from_le_bytes(pair)
+                    } else {
+                        u16::from_be_bytes(pair)
+                    }
+                })
+                .collect::<Vec<_>>();
+            String::from_utf16_lossy(&units)
+        }
+        3 => String::from_utf8_lossy(data).into_owned(),
+        _ => latin1(data),
+    }
+}
+
+fn latin1(data: &[u8]) -> String {
+    data.iter().map(|&b| b as char).collect()
+}
+
+/// Genre names for ID3v2 content types like `(17)`, `17` or `(13)Pop`
+///
+/// ExifTool: ID3.pm PrintGenre. Numeric references become genre names, and
+/// text after a reference (a refinement) is kept as written.
+fn print_genre(genre: &str) -> String {
+    if let Ok(number) = genre.trim().parse::<u8>() {
+        return genre_name(number);
+    }
+    let mut names = Vec::new();
+    let mut rest = genre;
+    while let Some(inner) = rest.strip_prefix('(') {
+        // "((" escapes a literal parenthesis
+        if inner.starts_with('(') {
+            break;
+        }
+        let Some(close) = inner.find(')') else {
+            break;
+        };
+        let reference = &inner[..close];
+        let name = match reference.parse::<u8>() {
+            Ok(number) => genre_name(number),
+            // "(RX)" remix and "(CR)" cover references
+            Err(_) => match lookup_genre(reference) {
+                Some(name) => name.to_string(),
+                None => break,
+            },
+        };
+        names.push(name);
+        rest = &inner[close + 1..];
+    }
+    if !rest.is_empty() {
+        return rest.to_string();
+    }
+    names.join(", ")
+}
+
+/// ID3v1 genre (with the Winamp extensions), also referenced by number from
+/// ID3v2 content types
+///
+/// ExifTool: ID3.pm %genre
+fn genre_name(genre: u8) -> String {
+    match lookup_genre(&genre.to_string()) {
+        Some(name) => name.to_string(),
+        None => format!("Unknown ({genre})"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_print_genre() {
+        assert_eq!(print_genre("17"), "Rock");
+        assert_eq!(print_genre("(13)"), "Pop");
+        assert_eq!(print_genre("(17)(RX)"), "Rock, Remix");
+        assert_eq!(print_genre("(9)Thrash"), "Thrash");
+        assert_eq!(print_genre("Shoegaze"), "Shoegaze");
+        assert_eq!(genre_name(255), "None");
+        assert_eq!(genre_name(200), "Unknown (200)");
+    }
+
+    #[test]
+    fn test_id3v2_4_picture_and_utf16() {
+        // APIC: UTF-16 description, PNG front cover
+        let mut apic = vec![1];
+        apic.extend(b"image/png\0");
+        apic.push(3);
+        apic.extend([0xff, 0xfe, b'C', 0, 0, 0]);
+        apic.extend(b"\x89PNG");
+        // TIT2: UTF-8 title
+        let mut tit2 = vec![3];
+        tit2.extend("Café".as_bytes());
+
+        let mut body = Vec::new();
+        for (id, data) in [("APIC", &apic), ("TIT2", &tit2)] {
+            body.extend(id.as_bytes());
+            body.extend([0, 0, 0, data.len() as u8, 0, 0]);
+            body.extend(data);
+        }
+        body.extend([0; 16]); // padding
+        let mut tag = b"ID3\x04\x00\x00\x00\x00\x00".to_vec();
+        tag.push(body.len() as u8);
+        tag.extend(body);
+
+        let (entries, end) = read_id3v2(&mut Cursor::new(&tag)).unwrap().unwrap();
+        assert_eq!(end, tag.len() as u64);
+        let get = |name: &str| {
+            entries
+                .iter()
+                .find(|e| e.name == name)
+                .unwrap_or_else(|| panic!("{name} missing"))
+        };
+        assert_eq!(get("Title").group1, "ID3v2_4");
+        assert_eq!(get("Title").value, TagValue::string("Café"));
+        assert_eq!(get("PictureMIMEType").value, TagValue::string("image/png"));
+        assert_eq!(get("PictureType").print(), &TagValue::string("Front Cover"));
+        assert_eq!(get("PictureDescription").value, TagValue::string("C"));
+        assert_eq!(get("Picture").value, TagValue::Binary(b"\x89PNG".to_vec()));
+    }
+
+    #[test]
+    fn test_not_id3() {
+        assert!(read_id3v2(&mut Cursor::new(b"fLaC")).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_remove_unsync() {
+        assert_eq!(
+            remove_unsync(&[0xff, 0x00, 0xe0, 0x01, 0xff, 0x00, 0x00]),
+            [0xff, 0xe0, 0x01, 0xff, 0x00]
+        );
+    }
+}
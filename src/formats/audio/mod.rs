@@ -0,0 +1,185 @@
+//! Audio file metadata: ID3 tags and FLAC metadata blocks
+//!
+//! MP3 files carry their tags in an ID3v2 header at the start of the file
+//! and/or a 128-byte ID3v1 trailer. FLAC files start with `fLaC` and a chain
+//! of metadata blocks, the tags living in a Vorbis comment block; some
+//! encoders also put an ID3v2 header in front of FLAC, and ExifTool reads
+//! both.
+//!
+//! ExifTool reference: ID3.pm ProcessID3 (called for MP3 and FLAC alike),
+//! FLAC.pm ProcessFLAC and Vorbis.pm ProcessComments. Scope: the ID3 text
+//! frames (title, artist, album, year, genre, ...), comments and attached
+//! pictures, FLAC StreamInfo and Picture blocks and the Vorbis comments. The
+//! MPEG audio frame header (bitrate, sample rate) is not decoded.
+
+mod flac;
+mod id3;
+mod vorbis;
+
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::types::{Result, TagEntry, TagValue};
+
+/// Largest ID3v2 tag or FLAC metadata block read into memory
+const MAX_BLOCK_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Read the metadata of an MP3 or FLAC file as `ID3:*`, `FLAC:*` and
+/// `Vorbis:*` TagEntries
+///
+/// Best-effort like the other container walkers: truncated tags end the walk
+/// and whatever was decoded so far is returned. A FLAC file without the
+/// `fLaC` signature is an error.
+pub fn extract_audio_metadata<R: Read + Seek>(
+    reader: &mut R,
+    format: &str,
+) -> Result<Vec<TagEntry>> {
+    reader.seek(SeekFrom::Start(0))?;
+    let (mut entries, audio_start) = match id3::read_id3v2(reader)? {
+        Some((entries, end)) => (entries, end),
+        None => (Vec::new(), 0),
+    };
+
+    if format == "FLAC" {
+        reader.seek(SeekFrom::Start(audio_start))?;
+        entries.extend(flac::read_flac(reader)?);
+    }
+
+    // ID3v1 has PRIORITY => 0 in ExifTool, so its tags only fill in names
+    // the ID3v2 tag didn't have
+    for entry in id3::read_id3v1(reader)? {
+        let duplicate = entries
+            .iter()
+            .any(|e| e.group == entry.group && e.name == entry.name);
+        if !duplicate {
+            entries.push(entry);
+        }
+    }
+    Ok(entries)
+}
+
+/// A TagEntry in an audio group
+fn entry(group: &str, group1: &str, name: &str, value: TagValue, print: TagValue) -> TagEntry {
+    TagEntry {
+        group: group.into(),
+        group1: group1.into(),
+        name: name.into(),
+        value,
+        print: print.into(),
+        source: None,
+    }
+}
+
+/// Attached picture types, shared by ID3 APIC/PIC frames and FLAC Picture
+/// blocks
+///
+/// ExifTool: ID3.pm %Image::ExifTool::ID3::APIC PictureType PrintConv
+fn picture_type_name(picture_type: u32) -> String {
+    let name = match picture_type {
+        0 => "Other",
+        1 => "32x32 PNG Icon",
+        2 => "Other Icon",
+        3 => "Front Cover",
+        4 => "Back Cover",
+        5 => "Leaflet",
+        6 => "Media",
+        7 => "Lead Artist",
+        8 => "Artist",
+        9 => "Conductor",
+        10 => "Band",
+        11 => "Composer",
+        12 => "Lyricist",
+        13 => "Recording Studio or Location",
+        14 => "Recording Session",
+        15 => "Performance",
+        16 => "Capture from Movie or Video",
+        17 => "Bright(ly) Colored Fish",
+        18 => "Illustration",
+        19 => "Band Logo",
+        20 => "Publisher Logo",
+        other => return format!("Unknown ({other})"),
+    };
+    name.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn find<'a>(entries: &'a [TagEntry], group1: &str, name: &str) -> Option<&'a TagEntry> {
+        entries
+            .iter()
+            .find(|e| e.group1 == group1 && e.name == name)
+    }
+
+    /// ID3v2.3 text frame with ISO-8859-1 encoding
+    fn text_frame(id: &str, text: &str) -> Vec<u8> {
+        let mut frame = id.as_bytes().to_vec();
+        frame.extend((text.len() as u32 + 1).to_be_bytes());
+        frame.extend([0, 0, 0]);
+        frame.extend(text.as_bytes());
+        frame
+    }
+
+    fn id3v2_3(frames: &[Vec<u8>]) -> Vec<u8> {
+        let body = frames.concat();
+        let size = body.len() as u32;
+        let mut tag = b"ID3\x03\x00\x00".to_vec();
+        // syncsafe size
+        tag.extend((0..4).rev().map(|i| ((size >> (7 * i)) & 0x7f) as u8));
+        tag.extend(body);
+        tag
+    }
+
+    fn id3v1(title: &str, track: u8, genre: u8) -> Vec<u8> {
+        let field = |text: &str, len: usize| {
+            let mut bytes = text.as_bytes().to_vec();
+            bytes.resize(len, 0);
+            bytes
+        };
+        let mut tag = b"TAG".to_vec();
+        tag.extend(field(title, 30));
+        tag.extend(field("V1 Artist", 30));
+        tag.extend(field("", 30));
+        tag.extend(b"1999");
+        tag.extend(field("Nice", 28));
+        tag.extend([0, track, genre]);
+        tag
+    }
+
+    #[test]
+    fn test_mp3_id3v2_and_id3v1() {
+        let mut file = id3v2_3(&[
+            text_frame("TIT2", "Song"),
+            text_frame("TPE1", "Band"),
+            text_frame("TCON", "(17)"),
+        ]);
+        file.extend([0xff, 0xfb, 0x90, 0x00]);
+        file.extend(id3v1("V1 Title", 7, 13));
+
+        let entries = extract_audio_metadata(&mut Cursor::new(file), "MP3").unwrap();
+        let title = find(&entries, "ID3v2_3", "Title").unwrap();
+        assert_eq!(title.group, "ID3");
+        assert_eq!(title.value, TagValue::string("Song"));
+        assert_eq!(
+            find(&entries, "ID3v2_3", "Genre").unwrap().print(),
+            &TagValue::string("Rock")
+        );
+        // ID3v2 wins over ID3v1 for the same tag name
+        assert!(find(&entries, "ID3v1", "Title").is_none());
+        assert_eq!(
+            find(&entries, "ID3v1", "Year").unwrap().value,
+            TagValue::string("1999")
+        );
+        assert_eq!(
+            find(&entries, "ID3v1", "Track").unwrap().value,
+            TagValue::U8(7)
+        );
+    }
+
+    #[test]
+    fn test_flac_without_signature() {
+        let result = extract_audio_metadata(&mut Cursor::new(b"RIFF....".to_vec()), "FLAC");
+        assert!(result.is_err());
+    }
+}
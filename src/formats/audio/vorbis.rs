@@ -0,0 +1,163 @@
+//! Vorbis comments, as found in FLAC VORBIS_COMMENT blocks
+//!
+//! A vendor string followed by `NAME=value` pairs, all little-endian
+//! length-prefixed UTF-8. Names are case-insensitive.
+//!
+//! ExifTool reference: Vorbis.pm ProcessComments and
+//! %Image::ExifTool::Vorbis::Comments, generated as
+//! `VORBIS_COMMENTS_TAGS_BY_NAME` keyed by the upper-case field name.
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use tracing::debug;
+
+use super::{entry, flac};
+use crate::generated::Vorbis_pm::comments_tags::VORBIS_COMMENTS_TAGS_BY_NAME;
+use crate::types::{TagEntry, TagValue};
+
+/// ExifTool group (G0/G1) of Vorbis comments
+const GROUP: &str = "Vorbis";
+
+/// Decode a Vorbis comment packet
+///
+/// Repeated fields keep their first value, as ExifTool shows without `-a`.
+/// METADATA_BLOCK_PICTURE holds a base64 FLAC picture block, decoded into
+/// the same `FLAC:Picture*` tags as a Picture metadata block.
+pub(super) fn parse_comments(data: &[u8]) -> Vec<TagEntry> {
+    let mut entries: Vec<TagEntry> = Vec::new();
+    let mut fields = LengthPrefixed { data };
+    let Some(vendor) = fields.next() else {
+        return entries;
+    };
+    let vendor = String::from_utf8_lossy(vendor);
+    entries.push(text_entry("Vendor", &vendor));
+
+    let Some(count) = fields.u32() else {
+        return entries;
+    };
+    for _ in 0..count {
+        let Some(field) = fields.next() else {
+            debug!("Truncated Vorbis comment list");
+            break;
+        };
+        let field = String::from_utf8_lossy(field);
+        let Some((key, value)) = field.split_once('=') else {
+            continue;
+        };
+        let key = key.to_ascii_uppercase();
+        if key == "METADATA_BLOCK_PICTURE" {
+            match BASE64_STANDARD.decode(value.trim()) {
+                Ok(block) => entries.extend(flac::parse_picture(&block)),
+                Err(e) => debug!("Invalid METADATA_BLOCK_PICTURE: {e}"),
+            }
+            continue;
+        }
+        let name = tag_name(&key);
+        if !entries.iter().any(|e| e.group1 == GROUP && e.name == name) {
+            entries.push(text_entry(&name, value));
+        }
+    }
+    entries
+}
+
+fn text_entry(name: &str, text: &str) -> TagEntry {
+    entry(
+        GROUP,
+        GROUP,
+        name,
+        TagValue::string(text),
+        TagValue::string(text),
+    )
+}
+
+/// Tag name for a comment field; unknown fields are named like ExifTool's
+/// dynamically added tags: `ALBUMARTIST` becomes `Albumartist` and
+/// `MUSICBRAINZ_TRACKID` `Musicbrainz_trackid`
+fn tag_name(key: &str) -> String {
+    if let Some(info) = VORBIS_COMMENTS_TAGS_BY_NAME.get(key.as_bytes()) {
+        return info.name.to_string();
+    }
+    let key = key
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let mut chars = key.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => "Unknown".to_string(),
+    }
+}
+
+/// Reader of little-endian u32 lengths and the byte strings they prefix
+struct LengthPrefixed<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> LengthPrefixed<'a> {
+    fn u32(&mut self) -> Option<u32> {
+        let (value, rest) = self.data.split_first_chunk::<4>()?;
+        self.data = rest;
+        Some(u32::from_le_bytes(*value))
+    }
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        let len = self.u32()? as usize;
+        let data = self.data;
+        let value = data.get(..len)?;
+        self.data = &data[len..];
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comments(vendor: &str, fields: &[&str]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend((vendor.len() as u32).to_le_bytes());
+        data.extend(vendor.as_bytes());
+        data.extend((fields.len() as u32).to_le_bytes());
+        for field in fields {
+            data.extend((field.len() as u32).to_le_bytes());
+            data.extend(field.as_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn test_parse_comments() {
+        let data = comments(
+            "reference libFLAC 1.4.3",
+            &[
+                "title=Song",
+                "ARTIST=First",
+                "ARTIST=Second",
+                "ALBUMARTIST=Band",
+                "no separator",
+            ],
+        );
+        let entries = parse_comments(&data);
+        let values = entries
+            .iter()
+            .map(|e| (e.name.as_str(), e.value.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            [
+                ("Vendor", "reference libFLAC 1.4.3".to_string()),
+                ("Title", "Song".to_string()),
+                ("Artist", "First".to_string()),
+                ("Albumartist", "Band".to_string()),
+            ]
+        );
+        assert!(entries.iter().all(|e| e.group == "Vorbis"));
+    }
+
+    #[test]
+    fn test_truncated_comments() {
+        let mut data = comments("vendor", &["TITLE=Song"]);
+        data.truncate(data.len() - 2);
+        assert_eq!(parse_comments(&data).len(), 1);
+    }
+}
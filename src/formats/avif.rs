@@ -795,23 +795,197 @@ pub fn create_avif_tag_entries(props: &AvifImageProperties) -> Vec<TagEntry> {
     vec![
         // File:ImageWidth - ExifTool creates this for AVIF files
         TagEntry {
-            group: "File".to_string(),
-            group1: "File".to_string(),
-            name: "ImageWidth".to_string(),
+            group: "File".into(),
+            group1: "File".into(),
+            name: "ImageWidth".into(),
             value: TagValue::U32(props.width),
-            print: TagValue::U32(props.width),
+            print: TagValue::U32(props.width).into(),
+            source: None,
         },
         // File:ImageHeight - ExifTool creates this for AVIF files
         TagEntry {
-            group: "File".to_string(),
-            group1: "File".to_string(),
-            name: "ImageHeight".to_string(),
+            group: "File".into(),
+            group1: "File".into(),
+            name: "ImageHeight".into(),
             value: TagValue::U32(props.height),
-            print: TagValue::U32(props.height),
+            print: TagValue::U32(props.height).into(),
+            source: None,
         },
     ]
 }
 
+/// Image sequence (animation) track of an AVIF/HEIF file
+///
+/// Sequences ('avis', 'hevc' and 'msf1' brands) store their frames as samples
+/// of a 'pict' (or 'vide') track in 'moov', like a video, next to the
+/// 'meta' box describing the still image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSequenceInfo {
+    pub frame_count: u32,
+    /// Seconds, from the track 'mdhd' (or the movie 'mvhd' when it has none)
+    pub duration: f64,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ImageSequenceInfo {
+    /// Frames per second, rounded to 3 decimals like ExifTool's VideoFrameRate
+    pub fn frame_rate(&self) -> Option<f64> {
+        if self.duration <= 0.0 || self.frame_count == 0 {
+            return None;
+        }
+        Some((self.frame_count as f64 / self.duration * 1000.0 + 0.5).floor() / 1000.0)
+    }
+}
+
+/// Find the image sequence track in the 'moov' box of an AVIF/HEIF file
+///
+/// Files may hold several tracks: an AVIF sequence with transparency has an
+/// 'auxv' alpha track next to the color track. The first 'pict' or 'vide'
+/// track with samples is the animation; auxiliary tracks are ignored.
+/// Returns `None` for still images (no 'moov', or no such track).
+///
+/// ExifTool reference: QuickTime.pm mvhd/tkhd/mdhd/hdlr/stsz handling
+pub fn extract_image_sequence_info(data: &[u8]) -> Option<ImageSequenceInfo> {
+    let moov = child_boxes(data)
+        .into_iter()
+        .find(|b| &b.box_type == b"moov")?;
+    let moov_children = child_boxes(&moov.data);
+    let movie_duration = moov_children
+        .iter()
+        .find(|b| &b.box_type == b"mvhd")
+        .and_then(|mvhd| parse_header_duration(&mvhd.data));
+
+    moov_children
+        .iter()
+        .filter(|b| &b.box_type == b"trak")
+        .find_map(|trak| parse_sequence_track(&trak.data, movie_duration))
+}
+
+/// AnimationFrameCount, Duration and FrameRate tags for an image sequence
+///
+/// exif-oxide tags in the QuickTime group; Duration is printed with
+/// ConvertDuration like the QuickTime movie duration.
+pub fn create_image_sequence_tag_entries(info: &ImageSequenceInfo) -> Vec<TagEntry> {
+    let mut entries = vec![
+        TagEntry {
+            group: "QuickTime".into(),
+            group1: "QuickTime".into(),
+            name: "AnimationFrameCount".into(),
+            value: TagValue::U32(info.frame_count),
+            print: TagValue::U32(info.frame_count).into(),
+            source: None,
+        },
+        TagEntry {
+            group: "QuickTime".into(),
+            group1: "QuickTime".into(),
+            name: "Duration".into(),
+            value: TagValue::F64(info.duration),
+            print: TagValue::String(crate::implementations::quicktime::convert_duration(
+                info.duration,
+            ))
+            .into(),
+            source: None,
+        },
+    ];
+    if let Some(frame_rate) = info.frame_rate() {
+        entries.push(TagEntry {
+            group: "QuickTime".into(),
+            group1: "QuickTime".into(),
+            name: "FrameRate".into(),
+            value: TagValue::F64(frame_rate),
+            print: TagValue::F64(frame_rate).into(),
+            source: None,
+        });
+    }
+    entries
+}
+
+/// All boxes directly inside `data`, stopping at the first malformed one
+fn child_boxes(data: &[u8]) -> Vec<IsoBox> {
+    let mut boxes = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() && boxes.len() < MAX_BOXES_TO_SCAN {
+        match parse_box_header(data, offset) {
+            Ok((iso_box, next_offset)) => {
+                boxes.push(iso_box);
+                offset = next_offset;
+            }
+            Err(_) => break,
+        }
+    }
+    boxes
+}
+
+fn child_box<'a>(boxes: &'a [IsoBox], box_type: &[u8; 4]) -> Option<&'a IsoBox> {
+    boxes.iter().find(|b| &b.box_type == box_type)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    let bytes = data.get(offset..offset + 8)?;
+    Some(u64::from_be_bytes(bytes.try_into().ok()?))
+}
+
+/// Duration in seconds from an 'mvhd' or 'mdhd' box, which share the
+/// version 0 (32-bit) and version 1 (64-bit) time field layout
+fn parse_header_duration(data: &[u8]) -> Option<f64> {
+    let (time_scale, duration) = match data.first()? {
+        0 => (read_u32(data, 12)?, read_u32(data, 16)? as u64),
+        1 => (read_u32(data, 20)?, read_u64(data, 24)?),
+        _ => return None,
+    };
+    if time_scale == 0 || duration == u32::MAX as u64 || duration == u64::MAX {
+        return None;
+    }
+    Some(duration as f64 / time_scale as f64)
+}
+
+fn parse_sequence_track(trak: &[u8], movie_duration: Option<f64>) -> Option<ImageSequenceInfo> {
+    let trak_children = child_boxes(trak);
+    let mdia = child_boxes(&child_box(&trak_children, b"mdia")?.data);
+    let handler = child_box(&mdia, b"hdlr")?.data.get(8..12)?;
+    if handler != b"pict" && handler != b"vide" {
+        return None;
+    }
+    let minf = child_boxes(&child_box(&mdia, b"minf")?.data);
+    let stbl = child_boxes(&child_box(&minf, b"stbl")?.data);
+    let frame_count = child_box(&stbl, b"stsz")
+        .or_else(|| child_box(&stbl, b"stz2"))
+        .and_then(|stsz| read_u32(&stsz.data, 8))
+        .filter(|&count| count > 0)?;
+    let duration = child_box(&mdia, b"mdhd")
+        .and_then(|mdhd| parse_header_duration(&mdhd.data))
+        .or(movie_duration)
+        .unwrap_or(0.0);
+
+    // tkhd width and height are 16.16 fixed point, after the matrix
+    let (width, height) = child_box(&trak_children, b"tkhd")
+        .and_then(|tkhd| {
+            let offset = if tkhd.data.first() == Some(&1) {
+                88
+            } else {
+                76
+            };
+            Some((
+                read_u32(&tkhd.data, offset)? >> 16,
+                read_u32(&tkhd.data, offset + 4)? >> 16,
+            ))
+        })
+        .unwrap_or((0, 0));
+
+    Some(ImageSequenceInfo {
+        frame_count,
+        duration,
+        width,
+        height,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -898,4 +1072,56 @@ mod tests {
         assert_eq!(height_entry.group, "File");
         assert_eq!(height_entry.value, TagValue::U32(1080));
     }
+
+    fn iso_box(box_type: &[u8; 4], content: &[u8]) -> Vec<u8> {
+        let mut data = ((content.len() + 8) as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(box_type);
+        data.extend_from_slice(content);
+        data
+    }
+
+    fn sequence_track(handler: &[u8; 4], frames: u32) -> Vec<u8> {
+        let mut tkhd = vec![0u8; 84];
+        tkhd[76..80].copy_from_slice(&(640u32 << 16).to_be_bytes());
+        tkhd[80..84].copy_from_slice(&(480u32 << 16).to_be_bytes());
+        let mut mdhd = vec![0u8; 24];
+        mdhd[12..16].copy_from_slice(&1000u32.to_be_bytes()); // time scale
+        mdhd[16..20].copy_from_slice(&2500u32.to_be_bytes()); // 2.5 s
+        let mut hdlr = vec![0u8; 24];
+        hdlr[8..12].copy_from_slice(handler);
+        let mut stsz = vec![0u8; 12];
+        stsz[8..12].copy_from_slice(&frames.to_be_bytes());
+        let stbl = iso_box(b"stbl", &iso_box(b"stsz", &stsz));
+        let minf = iso_box(b"minf", &stbl);
+        let mdia = [iso_box(b"mdhd", &mdhd), iso_box(b"hdlr", &hdlr), minf].concat();
+        iso_box(
+            b"trak",
+            &[iso_box(b"tkhd", &tkhd), iso_box(b"mdia", &mdia)].concat(),
+        )
+    }
+
+    #[test]
+    fn test_image_sequence_skips_alpha_track() {
+        let moov = [sequence_track(b"auxv", 3), sequence_track(b"pict", 30)].concat();
+        let file = [iso_box(b"ftyp", b"avis\0\0\0\0"), iso_box(b"moov", &moov)].concat();
+
+        let info = extract_image_sequence_info(&file).unwrap();
+        assert_eq!(info.frame_count, 30);
+        assert_eq!(info.duration, 2.5);
+        assert_eq!((info.width, info.height), (640, 480));
+        assert_eq!(info.frame_rate(), Some(12.0));
+
+        let entries = create_image_sequence_tag_entries(&info);
+        let names: Vec<_> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["AnimationFrameCount", "Duration", "FrameRate"]);
+        assert_eq!(entries[1].print(), &TagValue::string("2.50 s"));
+    }
+
+    #[test]
+    fn test_still_image_has_no_sequence() {
+        let file = iso_box(b"ftyp", b"avif\0\0\0\0");
+        assert_eq!(extract_image_sequence_info(&file), None);
+        let moov = iso_box(b"moov", &sequence_track(b"auxv", 3));
+        assert_eq!(extract_image_sequence_info(&[file, moov].concat()), None);
+    }
 }
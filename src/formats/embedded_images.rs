@@ -0,0 +1,551 @@
+//! Embedded image loading for binary-safe output
+//!
+//! The ThumbnailImage/PreviewImage/OtherImage composites only carry ExifTool's
+//! `(Binary data N bytes, use -b option to extract)` placeholder. When binary
+//! output is requested (ExifTool `-b -j`), the image bytes are read from the
+//! file using the offset/length tags so they can be emitted as `base64:...`.
+//!
+//! [`extract_embedded_image`] returns an image's bytes together with what a
+//! viewer needs to show it like the main image (see [`EmbeddedImageInfo`]).
+//! Previews are stored unrotated, like the main image's pixels, so without
+//! the orientation they display on their side.
+//!
+//! [`extract_binary_tag`] is the library equivalent of `-b -TAG`: it returns
+//! the bytes of an embedded image or of a binary tag like ICC_Profile.
+
+use crate::composite_tags::normalize_profile;
+use crate::formats::jpeg::extract_jpeg_icc_profile;
+use crate::orientation;
+use crate::types::{ExifData, ExifError, FilterOptions, Result, TagEntry, TagValue};
+use exif_oxide_core::ifd::{extract_value, Ifd};
+use exif_oxide_core::tiff::TiffHeader;
+use std::fs::File;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::path::Path;
+use tracing::debug;
+
+/// Composite image tags and the offset/length tag pairs they are built from
+/// ExifTool: lib/Image/ExifTool/Exif.pm Composite ThumbnailImage, PreviewImage, OtherImage
+const EMBEDDED_IMAGES: &[(&str, &[(&str, &str)])] = &[
+    (
+        "ThumbnailImage",
+        &[
+            ("ThumbnailOffset", "ThumbnailLength"),
+            ("OtherImageStart", "OtherImageLength"),
+        ],
+    ),
+    (
+        "PreviewImage",
+        &[
+            ("PreviewImageStart", "PreviewImageLength"),
+            ("OtherImageStart", "OtherImageLength"),
+        ],
+    ),
+    ("OtherImage", &[("OtherImageStart", "OtherImageLength")]),
+];
+
+/// The offset/length tag pairs `image` (e.g. "PreviewImage", any case) is
+/// read from, most preferred first, or `None` for tags that aren't embedded
+/// images
+///
+/// Requesting these tags along with the image keeps them in a filtered
+/// extraction, so [`embedded_image_location`] can find the image.
+pub fn embedded_image_source_tags(image: &str) -> Option<&'static [(&'static str, &'static str)]> {
+    EMBEDDED_IMAGES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(image))
+        .map(|(_, pairs)| *pairs)
+}
+
+/// File offset and length of the embedded `image`, from the first of its
+/// offset/length tag pairs present in `entries` with a non-zero length
+///
+/// Offsets are absolute file positions after IsOffset adjustment in parsing.
+pub fn embedded_image_location(entries: &[TagEntry], image: &str) -> Option<(u32, u32)> {
+    embedded_image_source_tags(image)?
+        .iter()
+        .find_map(|(offset_name, length_name)| {
+            let offset = find_u32(entries, offset_name)?;
+            let length = find_u32(entries, length_name)?;
+            (length > 0).then_some((offset, length))
+        })
+}
+
+/// Replace embedded image placeholders with the image bytes read from `reader`
+///
+/// Entries whose bytes can't be read in full keep their placeholder.
+pub(crate) fn load_embedded_images<R: Read + Seek>(reader: &mut R, entries: &mut [TagEntry]) {
+    let Ok(file_len) = reader.seek(SeekFrom::End(0)) else {
+        return;
+    };
+    let locations: Vec<(usize, u32, u32)> = entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.group == "Composite")
+        .filter_map(|(index, entry)| {
+            let (offset, length) = embedded_image_location(entries, &entry.name)?;
+            if !fits_in_file(offset, length, file_len) {
+                debug!(
+                    "{} ({length} bytes at offset {offset}) extends past the end of the file",
+                    entry.name
+                );
+                return None;
+            }
+            Some((index, offset, length))
+        })
+        .collect();
+
+    for (index, offset, length) in locations {
+        // NOTE: Offsets are absolute file positions after IsOffset adjustment in parsing
+        let mut data = vec![0u8; length as usize];
+        let read = reader
+            .seek(SeekFrom::Start(offset as u64))
+            .and_then(|_| reader.read_exact(&mut data));
+        match read {
+            Ok(()) => {
+                let entry = &mut entries[index];
+                entry.value = TagValue::Binary(data);
+                entry.set_print(entry.value.clone());
+            }
+            Err(e) => debug!(
+                "Failed to read {} ({} bytes at offset {}): {}",
+                entries[index].name, length, offset, e
+            ),
+        }
+    }
+}
+
+/// Whether `length` bytes at `offset` lie within a file of `file_len` bytes,
+/// checked before allocating a buffer of the length a file claims
+fn fits_in_file(offset: u32, length: u32, file_len: u64) -> bool {
+    offset as u64 + length as u64 <= file_len
+}
+
+/// An embedded image and how to display it
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddedImage {
+    /// The image bytes, usually a JPEG
+    pub data: Vec<u8>,
+    pub info: EmbeddedImageInfo,
+}
+
+/// Display metadata for an embedded image
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EmbeddedImageInfo {
+    /// File offset of the image
+    pub offset: u32,
+    /// Length of the image in bytes
+    pub length: u32,
+    /// EXIF Orientation (1-8) to apply for display, from the image's own
+    /// EXIF, else IFD1 for the thumbnail, else the main image
+    pub orientation: Option<u16>,
+    /// ICC profile embedded in the image itself
+    pub icc_profile: Option<Vec<u8>>,
+    /// The image's color space (e.g. `sRGB`), when its own ICC profile or
+    /// EXIF ColorSpace names one that differs from the main image's
+    /// Composite:ColorSpace
+    pub color_space: Option<String>,
+}
+
+impl EmbeddedImageInfo {
+    /// Transform to apply for display; the identity without an orientation
+    pub fn display_transform(&self) -> orientation::OrientationTransform {
+        self.orientation
+            .and_then(orientation::OrientationTransform::from_orientation)
+            .unwrap_or_default()
+    }
+}
+
+/// Read an embedded image (e.g. "PreviewImage") with its display metadata
+///
+/// ```no_run
+/// use exif_oxide::formats::extract_embedded_image;
+///
+/// let preview = extract_embedded_image(std::path::Path::new("photo.cr2"), "PreviewImage")?;
+/// let transform = preview.info.display_transform();
+/// # Ok::<(), exif_oxide::ExifError>(())
+/// ```
+pub fn extract_embedded_image(path: &Path, image: &str) -> Result<EmbeddedImage> {
+    if embedded_image_source_tags(image).is_none() {
+        return Err(ExifError::Unsupported(format!(
+            "{image} isn't an embedded image"
+        )));
+    }
+    let metadata = crate::formats::extract_metadata(path, false, false, None)?;
+    let (offset, length) = embedded_image_location(&metadata.tags, image)
+        .ok_or_else(|| ExifError::ParseError(format!("No {image} in {}", path.display())))?;
+
+    let mut file = File::open(path)?;
+    if !fits_in_file(offset, length, file.metadata()?.len()) {
+        return Err(ExifError::ParseError(format!(
+            "{image} ({length} bytes at offset {offset}) extends past the end of {}",
+            path.display()
+        )));
+    }
+    file.seek(SeekFrom::Start(offset as u64))?;
+    let mut data = vec![0u8; length as usize];
+    file.read_exact(&mut data)?;
+
+    let info = embedded_image_info(&metadata, image, &data);
+    Ok(EmbeddedImage { data, info })
+}
+
+/// The bytes of a binary tag, like `exiftool -b -TAG`
+///
+/// Embedded images (e.g. "PreviewImage") are read with
+/// [`extract_embedded_image`]; other tags, like ICC_Profile, must have a
+/// binary value.
+///
+/// ```no_run
+/// use exif_oxide::formats::extract_binary_tag;
+///
+/// let icc = extract_binary_tag(std::path::Path::new("photo.jpg"), "ICC_Profile")?;
+/// # Ok::<(), exif_oxide::ExifError>(())
+/// ```
+pub fn extract_binary_tag(path: &Path, tag: &str) -> Result<Vec<u8>> {
+    if embedded_image_source_tags(tag).is_some() {
+        return Ok(extract_embedded_image(path, tag)?.data);
+    }
+    let filter = FilterOptions::tags_only(vec![tag.to_string()]);
+    let metadata = crate::formats::extract_metadata(path, false, false, Some(filter))?;
+    binary_tag_value(&metadata, tag)
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| ExifError::ParseError(format!("No binary {tag} in {}", path.display())))
+}
+
+/// The binary value of `tag` (optionally group-qualified) in `metadata`
+pub fn binary_tag_value<'a>(metadata: &'a ExifData, tag: &str) -> Option<&'a [u8]> {
+    let (group, name) = match tag.split_once(':') {
+        Some((group, name)) => (Some(group), name),
+        None => (None, tag),
+    };
+    metadata.tags.iter().find_map(|entry| {
+        let matches = entry.name.eq_ignore_ascii_case(name)
+            && group.is_none_or(|group| {
+                entry.group.eq_ignore_ascii_case(group) || entry.group1.eq_ignore_ascii_case(group)
+            });
+        match &entry.value {
+            TagValue::Binary(data) if matches => Some(data.as_slice()),
+            _ => None,
+        }
+    })
+}
+
+/// Display metadata for `image`, whose bytes are `data`, in `metadata`
+pub fn embedded_image_info(metadata: &ExifData, image: &str, data: &[u8]) -> EmbeddedImageInfo {
+    let (offset, length) = embedded_image_location(&metadata.tags, image).unwrap_or_default();
+    let own = JpegColorInfo::scan(data);
+
+    // ExifTool: Exif.pm IFD1 Orientation describes the thumbnail
+    let ifd1_orientation = || {
+        image
+            .eq_ignore_ascii_case("ThumbnailImage")
+            .then(|| metadata.get_tag_by_group("IFD1", "Orientation"))??
+            .value
+            .as_u16()
+    };
+    let main_orientation = || {
+        metadata
+            .get_tag_by_group("IFD0", "Orientation")
+            .and_then(|tag| tag.value.as_u16())
+            .or_else(|| orientation::orientation(metadata))
+    };
+    let orientation = own
+        .orientation
+        .or_else(ifd1_orientation)
+        .or_else(main_orientation);
+
+    let main_color_space = metadata
+        .get_tag_by_group("Composite", "ColorSpace")
+        .and_then(|tag| tag.value.as_string());
+    let color_space = own
+        .color_space()
+        .filter(|color_space| main_color_space != Some(color_space.as_str()));
+
+    EmbeddedImageInfo {
+        offset,
+        length,
+        orientation,
+        icc_profile: own.icc_profile,
+        color_space,
+    }
+}
+
+/// What an embedded JPEG says about itself
+#[derive(Debug, Default)]
+struct JpegColorInfo {
+    orientation: Option<u16>,
+    exif_color_space: Option<u16>,
+    icc_profile: Option<Vec<u8>>,
+}
+
+impl JpegColorInfo {
+    /// Scan the JPEG's APP1 EXIF and APP2 ICC_PROFILE segments
+    fn scan(data: &[u8]) -> Self {
+        let mut info = Self::default();
+        if !data.starts_with(&[0xFF, 0xD8]) {
+            return info;
+        }
+        let mut pos = 2;
+        while pos + 4 <= data.len() && data[pos] == 0xFF {
+            let marker = data[pos + 1];
+            if marker == 0xDA || marker == 0xD9 {
+                break;
+            }
+            let length = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+            let Some(segment) = data.get(pos + 4..pos + 2 + length) else {
+                break;
+            };
+            match marker {
+                0xE1 if segment.starts_with(b"Exif\0\0") => info.read_exif(&segment[6..]),
+                _ => {}
+            }
+            pos += 2 + length;
+        }
+        info.icc_profile = extract_jpeg_icc_profile(Cursor::new(data)).ok().flatten();
+        info
+    }
+
+    /// Orientation from IFD0 and ColorSpace from the ExifIFD
+    fn read_exif(&mut self, tiff: &[u8]) {
+        let Ok(header) = TiffHeader::parse(tiff) else {
+            return;
+        };
+        let order = header.byte_order;
+        let Ok(ifd0) = Ifd::parse(tiff, header.ifd0_offset as usize, order) else {
+            return;
+        };
+        let u16_value = |ifd: &Ifd, tag_id: u16| {
+            extract_value(tiff, ifd.entry(tag_id)?, order)
+                .ok()?
+                .as_u16()
+        };
+        self.orientation = u16_value(&ifd0, 0x0112);
+        let exif_ifd = ifd0
+            .entry(0x8769)
+            .and_then(|entry| Ifd::parse(tiff, entry.value_or_offset as usize, order).ok());
+        self.exif_color_space = exif_ifd.and_then(|ifd| u16_value(&ifd, 0xA001));
+    }
+
+    /// Color space named by the ICC profile, else by EXIF ColorSpace
+    /// ExifTool: Exif.pm ColorSpace PrintConv
+    fn color_space(&self) -> Option<String> {
+        if let Some(description) = self.icc_profile.as_deref().and_then(icc_description) {
+            return Some(normalize_profile(&description));
+        }
+        match self.exif_color_space? {
+            1 => Some("sRGB".to_string()),
+            2 => Some("Adobe RGB".to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// The ICC profile's description ('desc' tag, v2 text or v4 'mluc')
+/// ExifTool: ICC_Profile.pm ProfileDescription
+fn icc_description(profile: &[u8]) -> Option<String> {
+    let read_u32 = |pos: usize| -> Option<usize> {
+        Some(u32::from_be_bytes(profile.get(pos..pos + 4)?.try_into().ok()?) as usize)
+    };
+    let tag_count = read_u32(128)?;
+    let (offset, size) = (0..tag_count.min(256)).find_map(|i| {
+        let entry = 132 + i * 12;
+        (profile.get(entry..entry + 4)? == b"desc")
+            .then(|| (read_u32(entry + 4), read_u32(entry + 8)))
+    })?;
+    let (offset, size) = (offset?, size?);
+    let tag = profile.get(offset..offset.checked_add(size)?)?;
+    let text = match tag.get(..4)? {
+        // textDescriptionType: ASCII count, then NUL-terminated ASCII
+        b"desc" => {
+            let count = u32::from_be_bytes(tag.get(8..12)?.try_into().ok()?) as usize;
+            let ascii = tag.get(12..12 + count)?;
+            String::from_utf8_lossy(ascii)
+                .trim_end_matches('\0')
+                .to_string()
+        }
+        // multiLocalizedUnicodeType: the first record's UTF-16BE text
+        b"mluc" => {
+            let length = u32::from_be_bytes(tag.get(20..24)?.try_into().ok()?) as usize;
+            let start = u32::from_be_bytes(tag.get(24..28)?.try_into().ok()?) as usize;
+            let utf16: Vec<u16> = tag
+                .get(start..start + length)?
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            String::from_utf16_lossy(&utf16)
+        }
+        _ => return None,
+    };
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Find the first non-composite tag with this name and a u32 value
+fn find_u32(entries: &[TagEntry], name: &str) -> Option<u32> {
+    entries
+        .iter()
+        .filter(|entry| entry.name == name && entry.group != "Composite")
+        .find_map(|entry| entry.value.as_u32())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn entry(group: &str, name: &str, value: TagValue) -> TagEntry {
+        TagEntry {
+            group: group.into(),
+            group1: group.into(),
+            name: name.into(),
+            value: value.clone(),
+            print: value.into(),
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_load_thumbnail_image() {
+        let placeholder = TagValue::string("(Binary data 4 bytes, use -b option to extract)");
+        let mut entries = vec![
+            entry("EXIF", "ThumbnailOffset", TagValue::U32(2)),
+            entry("EXIF", "ThumbnailLength", TagValue::U32(4)),
+            entry("Composite", "ThumbnailImage", placeholder.clone()),
+            entry("Composite", "PreviewImage", placeholder.clone()),
+        ];
+        let mut file = Cursor::new(vec![0x00, 0x00, 0xFF, 0xD8, 0xFF, 0xD9]);
+
+        load_embedded_images(&mut file, &mut entries);
+
+        let thumbnail = TagValue::Binary(vec![0xFF, 0xD8, 0xFF, 0xD9]);
+        assert_eq!(entries[2].value, thumbnail);
+        assert_eq!(entries[2].print(), &thumbnail);
+        // No PreviewImageStart/Length: placeholder is kept
+        assert_eq!(entries[3].value, placeholder);
+    }
+
+    #[test]
+    fn test_embedded_image_location_falls_back() {
+        let entries = vec![
+            entry("EXIF", "PreviewImageStart", TagValue::U32(100)),
+            entry("EXIF", "PreviewImageLength", TagValue::U32(0)),
+            entry("EXIF", "OtherImageStart", TagValue::U32(200)),
+            entry("EXIF", "OtherImageLength", TagValue::U32(50)),
+        ];
+        // The empty PreviewImageStart/Length pair is skipped
+        assert_eq!(
+            embedded_image_location(&entries, "previewimage"),
+            Some((200, 50))
+        );
+        assert_eq!(
+            embedded_image_location(&entries, "ThumbnailImage"),
+            Some((200, 50))
+        );
+        assert_eq!(embedded_image_location(&entries[..2], "PreviewImage"), None);
+        assert_eq!(embedded_image_location(&entries, "Make"), None);
+        assert!(embedded_image_source_tags("Make").is_none());
+    }
+
+    /// A JPEG with its own EXIF Orientation and a v2 ICC profile
+    fn preview_jpeg(orientation: u16, profile_description: &str) -> Vec<u8> {
+        // Big-endian TIFF, IFD0 with just Orientation
+        let mut tiff = b"MM\0\x2a\0\0\0\x08\0\x01".to_vec();
+        tiff.extend([0x01, 0x12, 0, 3, 0, 0, 0, 1]);
+        tiff.extend(orientation.to_be_bytes());
+        tiff.extend([0, 0, 0, 0, 0, 0]);
+
+        let mut desc = b"desc\0\0\0\0".to_vec();
+        desc.extend((profile_description.len() as u32 + 1).to_be_bytes());
+        desc.extend(profile_description.as_bytes());
+        desc.push(0);
+        let mut icc = vec![0u8; 128];
+        icc.extend(1u32.to_be_bytes());
+        icc.extend(b"desc");
+        icc.extend(144u32.to_be_bytes());
+        icc.extend((desc.len() as u32).to_be_bytes());
+        icc.extend(desc);
+
+        let segment = |marker: u8, header: &[u8], payload: &[u8]| {
+            let length = (2 + header.len() + payload.len()) as u16;
+            [&[0xFF, marker][..], &length.to_be_bytes(), header, payload].concat()
+        };
+        [
+            &[0xFF, 0xD8][..],
+            &segment(0xE1, b"Exif\0\0", &tiff),
+            &segment(0xE2, b"ICC_PROFILE\0\x01\x01", &icc),
+            &[0xFF, 0xD9],
+        ]
+        .concat()
+    }
+
+    fn main_image(entries: Vec<TagEntry>) -> ExifData {
+        let mut metadata = ExifData::new("photo.cr2".to_string(), "13.30".to_string());
+        metadata.tags = entries;
+        metadata
+    }
+
+    #[test]
+    fn test_preview_with_own_metadata() {
+        let metadata = main_image(vec![
+            entry("EXIF", "PreviewImageStart", TagValue::U32(1000)),
+            entry("EXIF", "PreviewImageLength", TagValue::U32(200)),
+            entry("IFD0", "Orientation", TagValue::U16(6)),
+            entry("Composite", "ColorSpace", TagValue::string("sRGB")),
+        ]);
+        let preview = preview_jpeg(8, "Adobe RGB (1998)");
+
+        let info = embedded_image_info(&metadata, "PreviewImage", &preview);
+        assert_eq!((info.offset, info.length), (1000, 200));
+        // The preview's own EXIF wins over the main image's
+        assert_eq!(info.orientation, Some(8));
+        assert_eq!(info.display_transform().rotation, 270);
+        assert_eq!(info.color_space.as_deref(), Some("Adobe RGB"));
+        assert!(info.icc_profile.is_some_and(|icc| icc.len() > 128));
+
+        // Same color space as the main image: not repeated
+        let preview = preview_jpeg(1, "sRGB IEC61966-2.1");
+        let info = embedded_image_info(&metadata, "PreviewImage", &preview);
+        assert_eq!(info.orientation, Some(1));
+        assert_eq!(info.color_space, None);
+    }
+
+    #[test]
+    fn test_thumbnail_orientation_fallbacks() {
+        let thumbnail = [0xFF, 0xD8, 0xFF, 0xD9];
+        let mut entries = vec![entry("IFD0", "Orientation", TagValue::U16(6))];
+        let info = embedded_image_info(&main_image(entries.clone()), "ThumbnailImage", &thumbnail);
+        assert_eq!(info.orientation, Some(6));
+        assert_eq!(info.icc_profile, None);
+
+        entries.push(entry("IFD1", "Orientation", TagValue::U16(3)));
+        let metadata = main_image(entries);
+        let info = embedded_image_info(&metadata, "ThumbnailImage", &thumbnail);
+        assert_eq!(info.orientation, Some(3));
+        // IFD1 only describes the thumbnail
+        let info = embedded_image_info(&metadata, "PreviewImage", &thumbnail);
+        assert_eq!(info.orientation, Some(6));
+    }
+
+    #[test]
+    fn test_truncated_image_keeps_placeholder() {
+        let placeholder = TagValue::string("(Binary data 100 bytes, use -b option to extract)");
+        let mut entries = vec![
+            entry("EXIF", "ThumbnailOffset", TagValue::U32(2)),
+            entry("EXIF", "ThumbnailLength", TagValue::U32(100)),
+            entry("Composite", "ThumbnailImage", placeholder.clone()),
+        ];
+        let mut file = Cursor::new(vec![0u8; 10]);
+
+        load_embedded_images(&mut file, &mut entries);
+
+        assert_eq!(entries[2].value, placeholder);
+
+        // A length near 4 GB is refused without allocating it
+        entries[1] = entry("EXIF", "ThumbnailLength", TagValue::U32(u32::MAX - 1));
+        load_embedded_images(&mut file, &mut entries);
+        assert_eq!(entries[2].value, placeholder);
+        // The sum doesn't overflow
+        assert!(!fits_in_file(u32::MAX, u32::MAX, u32::MAX as u64));
+    }
+}
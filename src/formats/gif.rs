@@ -115,25 +115,27 @@ pub fn create_gif_tag_entries(screen_desc: &ScreenDescriptor) -> Vec<TagEntry> {
     let mut entries = vec![
         // GIF:ImageWidth - ExifTool GIF.pm:109-112
         TagEntry {
-            group: "GIF".to_string(),
-            group1: "GIF".to_string(),
-            name: "ImageWidth".to_string(),
+            group: "GIF".into(),
+            group1: "GIF".into(),
+            name: "ImageWidth".into(),
             value: TagValue::U16(screen_desc.image_width),
-            print: TagValue::U16(screen_desc.image_width),
+            print: TagValue::U16(screen_desc.image_width).into(),
+            source: None,
         },
         // GIF:ImageHeight - ExifTool GIF.pm:113-116
         TagEntry {
-            group: "GIF".to_string(),
-            group1: "GIF".to_string(),
-            name: "ImageHeight".to_string(),
+            group: "GIF".into(),
+            group1: "GIF".into(),
+            name: "ImageHeight".into(),
             value: TagValue::U16(screen_desc.image_height),
-            print: TagValue::U16(screen_desc.image_height),
+            print: TagValue::U16(screen_desc.image_height).into(),
+            source: None,
         },
         // GIF:HasColorMap - ExifTool GIF.pm:117-121 (with PrintConv)
         TagEntry {
-            group: "GIF".to_string(),
-            group1: "GIF".to_string(),
-            name: "HasColorMap".to_string(),
+            group: "GIF".into(),
+            group1: "GIF".into(),
+            name: "HasColorMap".into(),
             value: TagValue::String(
                 if screen_desc.has_color_map() {
                     "1"
@@ -149,42 +151,48 @@ pub fn create_gif_tag_entries(screen_desc: &ScreenDescriptor) -> Vec<TagEntry> {
                     "No"
                 }
                 .to_string(),
-            ),
+            )
+            .into(),
+            source: None,
         },
         // GIF:ColorResolutionDepth - ExifTool GIF.pm:122-126
         TagEntry {
-            group: "GIF".to_string(),
-            group1: "GIF".to_string(),
-            name: "ColorResolutionDepth".to_string(),
+            group: "GIF".into(),
+            group1: "GIF".into(),
+            name: "ColorResolutionDepth".into(),
             value: TagValue::U8(screen_desc.color_resolution_depth()),
-            print: TagValue::U8(screen_desc.color_resolution_depth()),
+            print: TagValue::U8(screen_desc.color_resolution_depth()).into(),
+            source: None,
         },
         // GIF:BitsPerPixel - ExifTool GIF.pm:127-131
         TagEntry {
-            group: "GIF".to_string(),
-            group1: "GIF".to_string(),
-            name: "BitsPerPixel".to_string(),
+            group: "GIF".into(),
+            group1: "GIF".into(),
+            name: "BitsPerPixel".into(),
             value: TagValue::U8(screen_desc.bits_per_pixel()),
-            print: TagValue::U8(screen_desc.bits_per_pixel()),
+            print: TagValue::U8(screen_desc.bits_per_pixel()).into(),
+            source: None,
         },
         // GIF:BackgroundColor - ExifTool GIF.pm:132
         TagEntry {
-            group: "GIF".to_string(),
-            group1: "GIF".to_string(),
-            name: "BackgroundColor".to_string(),
+            group: "GIF".into(),
+            group1: "GIF".into(),
+            name: "BackgroundColor".into(),
             value: TagValue::U8(screen_desc.background_color),
-            print: TagValue::U8(screen_desc.background_color),
+            print: TagValue::U8(screen_desc.background_color).into(),
+            source: None,
         },
     ];
 
     // GIF:PixelAspectRatio - ExifTool GIF.pm:133-137 (with conversion)
     if let Some(aspect_ratio) = screen_desc.pixel_aspect_ratio_float() {
         entries.push(TagEntry {
-            group: "GIF".to_string(),
-            group1: "GIF".to_string(),
-            name: "PixelAspectRatio".to_string(),
+            group: "GIF".into(),
+            group1: "GIF".into(),
+            name: "PixelAspectRatio".into(),
             value: TagValue::F64(aspect_ratio as f64),
-            print: TagValue::F64(aspect_ratio as f64),
+            print: TagValue::F64(aspect_ratio as f64).into(),
+            source: None,
         });
     }
 
@@ -260,6 +268,9 @@ mod tests {
 
         // Check HasColorMap entry (should have PrintConv applied)
         let color_map_entry = entries.iter().find(|e| e.name == "HasColorMap").unwrap();
-        assert_eq!(color_map_entry.print, TagValue::String("Yes".to_string()));
+        assert_eq!(
+            color_map_entry.print(),
+            &TagValue::String("Yes".to_string())
+        );
     }
 }
@@ -0,0 +1,255 @@
+//! Format handlers registered at runtime
+//!
+//! [`extract_metadata`](super::extract_metadata) dispatches on the detected
+//! format with a closed `match`. Crates that need a format exif-oxide doesn't
+//! support (or want to replace a built-in parser) implement [`FormatHandler`]
+//! and install it with [`register_format_handler`]:
+//!
+//! ```no_run
+//! use exif_oxide::formats::{register_format_handler, FormatHandler, ReadSeek};
+//! use exif_oxide::file_detection::FileTypeDetectionResult;
+//! use exif_oxide::types::{FilterOptions, Result, TagEntry, TagValue};
+//! use std::path::Path;
+//!
+//! struct Pgm;
+//!
+//! impl FormatHandler for Pgm {
+//!     fn name(&self) -> &str {
+//!         "PGM"
+//!     }
+//!
+//!     fn detect(&self, _path: &Path, header: &[u8]) -> Option<FileTypeDetectionResult> {
+//!         header.starts_with(b"P5").then(|| FileTypeDetectionResult {
+//!             file_type: "PGM".to_string(),
+//!             format: "PGM".to_string(),
+//!             mime_type: "image/x-portable-graymap".to_string(),
+//!             description: "Portable Graymap".to_string(),
+//!         })
+//!     }
+//!
+//!     fn extract(
+//!         &self,
+//!         _reader: &mut dyn ReadSeek,
+//!         _detection: &FileTypeDetectionResult,
+//!         _options: &FilterOptions,
+//!     ) -> Result<Vec<TagEntry>> {
+//!         Ok(vec![TagEntry::new(
+//!             "PGM",
+//!             "PGM",
+//!             "Magic",
+//!             TagValue::string("P5"),
+//!             TagValue::string("P5"),
+//!         )])
+//!     }
+//! }
+//!
+//! register_format_handler(Pgm);
+//! ```
+//!
+//! Handlers are consulted in two places:
+//!
+//! - **Detection**: when the built-in detector doesn't recognize a file, each
+//!   handler's [`FormatHandler::detect`] is offered the first 1024 bytes.
+//! - **Extraction**: a handler whose [`FormatHandler::handles`] accepts the
+//!   detection result replaces the built-in parser for that file. File group
+//!   tags, composites, filtering and output formatting still apply.
+//!
+//! The most recently registered handler wins, so a handler can be overridden
+//! by registering another with the same name. An
+//! [`ExifSession`](crate::session::ExifSession) carries its own handlers
+//! instead of these.
+
+use std::io::{Read, Seek};
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use crate::file_detection::{FileDetectionError, FileTypeDetectionResult, FileTypeDetector};
+use crate::types::{FilterOptions, Result, TagEntry};
+
+/// Bytes offered to [`FormatHandler::detect`], as for the built-in detector
+const DETECT_BUFFER_SIZE: usize = 1024;
+
+/// A reader handlers can seek around in
+pub trait ReadSeek: Read + Seek {}
+
+impl<T: Read + Seek> ReadSeek for T {}
+
+/// A metadata parser for one file format
+pub trait FormatHandler: Send + Sync {
+    /// Format name, matched against the detected format or file type
+    /// (e.g. "JPEG", "MOV", "HEIC")
+    fn name(&self) -> &str;
+
+    /// Recognize a file the built-in detector doesn't know
+    ///
+    /// `header` holds up to the first 1024 bytes of the file.
+    fn detect(&self, _path: &Path, _header: &[u8]) -> Option<FileTypeDetectionResult> {
+        None
+    }
+
+    /// Whether this handler extracts metadata for a detected file
+    fn handles(&self, detection: &FileTypeDetectionResult) -> bool {
+        detection.format == self.name() || detection.file_type == self.name()
+    }
+
+    /// Extract the format's tags
+    ///
+    /// The reader is positioned at the start of the file. `options` is the
+    /// filter in effect; handlers may use it to skip work, but needn't filter
+    /// their output.
+    fn extract(
+        &self,
+        reader: &mut dyn ReadSeek,
+        detection: &FileTypeDetectionResult,
+        options: &FilterOptions,
+    ) -> Result<Vec<TagEntry>>;
+}
+
+static HANDLERS: RwLock<Vec<Arc<dyn FormatHandler>>> = RwLock::new(Vec::new());
+
+/// Register a format handler, replacing any with the same name
+pub fn register_format_handler(handler: impl FormatHandler + 'static) {
+    let mut handlers = HANDLERS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    handlers.retain(|registered| registered.name() != handler.name());
+    handlers.push(Arc::new(handler));
+}
+
+/// Remove a registered format handler, returning whether one was removed
+pub fn unregister_format_handler(name: &str) -> bool {
+    let mut handlers = HANDLERS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let before = handlers.len();
+    handlers.retain(|registered| registered.name() != name);
+    handlers.len() != before
+}
+
+/// Names of the registered format handlers, in registration order
+pub fn registered_format_handlers() -> Vec<String> {
+    HANDLERS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .iter()
+        .map(|handler| handler.name().to_string())
+        .collect()
+}
+
+/// The handlers in effect: the active session's, else the registered ones
+fn active_handlers() -> Vec<Arc<dyn FormatHandler>> {
+    match crate::session::current() {
+        Some(session) => session.format_handlers.clone(),
+        None => HANDLERS
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone(),
+    }
+}
+
+/// The handler that should extract a detected file, if one is registered
+pub(crate) fn handler_for(detection: &FileTypeDetectionResult) -> Option<Arc<dyn FormatHandler>> {
+    active_handlers()
+        .iter()
+        .rev()
+        .find(|handler| handler.handles(detection))
+        .cloned()
+}
+
+/// Detect a file's type, falling back to the registered handlers
+pub(crate) fn detect_file_type<R: Read + Seek>(
+    path: &Path,
+    reader: &mut R,
+) -> std::result::Result<FileTypeDetectionResult, FileDetectionError> {
+    let error = match FileTypeDetector::new().detect_file_type(path, reader) {
+        Ok(detection) => return Ok(detection),
+        Err(FileDetectionError::IoError(e)) => return Err(FileDetectionError::IoError(e)),
+        Err(error) => error,
+    };
+
+    let handlers = active_handlers();
+    if handlers.is_empty() {
+        return Err(error);
+    }
+
+    let mut header = Vec::with_capacity(DETECT_BUFFER_SIZE);
+    reader.rewind()?;
+    reader
+        .by_ref()
+        .take(DETECT_BUFFER_SIZE as u64)
+        .read_to_end(&mut header)?;
+    reader.rewind()?;
+
+    handlers
+        .iter()
+        .rev()
+        .find_map(|handler| handler.detect(path, &header))
+        .ok_or(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TagValue;
+    use std::io::Cursor;
+
+    struct Magic;
+
+    impl FormatHandler for Magic {
+        fn name(&self) -> &str {
+            "MAGICTEST"
+        }
+
+        fn detect(&self, _path: &Path, header: &[u8]) -> Option<FileTypeDetectionResult> {
+            header
+                .starts_with(b"MAGIC")
+                .then(|| FileTypeDetectionResult {
+                    file_type: "MAGICTEST".to_string(),
+                    format: "MAGICTEST".to_string(),
+                    mime_type: "application/x-magic".to_string(),
+                    description: "Test format".to_string(),
+                })
+        }
+
+        fn extract(
+            &self,
+            reader: &mut dyn ReadSeek,
+            _detection: &FileTypeDetectionResult,
+            _options: &FilterOptions,
+        ) -> Result<Vec<TagEntry>> {
+            let mut data = String::new();
+            reader.read_to_string(&mut data)?;
+            Ok(vec![TagEntry {
+                group: "Magic".into(),
+                group1: "Magic".into(),
+                name: "Payload".into(),
+                value: TagValue::string(data.clone()),
+                print: TagValue::string(data).into(),
+                source: None,
+            }])
+        }
+    }
+
+    #[test]
+    fn detects_and_extracts_registered_format() {
+        let path = Path::new("file.magictest");
+        let mut reader = Cursor::new(b"MAGIC payload".to_vec());
+        assert!(detect_file_type(path, &mut reader).is_err());
+
+        register_format_handler(Magic);
+        let detection = detect_file_type(path, &mut reader).unwrap();
+        assert_eq!(detection.format, "MAGICTEST");
+        assert_eq!(reader.position(), 0);
+
+        let handler = handler_for(&detection).unwrap();
+        let tags = handler
+            .extract(&mut reader, &detection, &FilterOptions::default())
+            .unwrap();
+        assert_eq!(tags[0].print(), &TagValue::string("MAGIC payload"));
+
+        assert!(registered_format_handlers().contains(&"MAGICTEST".to_string()));
+        assert!(unregister_format_handler("MAGICTEST"));
+        assert!(handler_for(&detection).is_none());
+        assert!(!unregister_format_handler("MAGICTEST"));
+    }
+}
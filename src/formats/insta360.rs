@@ -0,0 +1,240 @@
+//! Insta360 trailer
+//!
+//! Insta360 cameras append a trailer of their own to both their unstitched
+//! photos (.insp, a JPEG) and videos (.insv, an MP4). It ends with a fixed
+//! 78-byte block:
+//!
+//! - the footer of the last record (int16u ID, int32u data length)
+//! - 32 reserved bytes
+//! - int32u length of the whole trailer, int32u version
+//! - the 32-character magic number
+//!
+//! Records run backwards from there: each record's data is followed by its
+//! footer. Only the Insta360Info record (0x101) is decoded; it is a
+//! protobuf message with the camera identification and the lens
+//! calibration that stitching software needs to join the two fisheye
+//! images.
+//!
+//! ExifTool Reference: lib/Image/ExifTool/QuickTimeStream.pl
+//! ProcessInsta360()
+
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::types::{ExifError, Result, TagEntry, TagValue};
+
+/// Magic number at the very end of the trailer
+const INSTA360_MAGIC: &[u8; 32] = b"8db42d694ccc418790edff439fe026bf";
+
+/// Size of the block ending the trailer
+const TAIL_SIZE: u64 = 78;
+
+/// Size of each record footer (int16u ID, int32u length)
+const FOOTER_SIZE: u64 = 6;
+
+/// Offset of the trailer length within the tail block
+const TRAILER_LENGTH_OFFSET: usize = 38;
+
+/// Record holding the camera identification and lens parameters
+const INSTA360_INFO: u16 = 0x101;
+
+/// Insta360Info protobuf fields, by field number
+/// ExifTool: QuickTimeStream.pl %insvTags 0x0a, 0x12, 0x1a, 0x2a
+const INFO_FIELDS: &[(u64, &str)] = &[
+    (1, "SerialNumber"),
+    (2, "Model"),
+    (3, "Firmware"),
+    // Lens count, then each lens's calibration (center, radius and
+    // orientation), then the sensor image size, "_"-separated
+    (5, "Parameters"),
+];
+
+/// Read the tags from an Insta360 trailer at the end of the file
+///
+/// Files without the trailer give no tags.
+pub fn extract_insta360_trailer<R: Read + Seek>(reader: &mut R) -> Result<Vec<TagEntry>> {
+    let file_size = reader.seek(SeekFrom::End(0))?;
+    if file_size < TAIL_SIZE {
+        return Ok(Vec::new());
+    }
+    let mut tail = [0u8; TAIL_SIZE as usize];
+    let tail_start = file_size - TAIL_SIZE;
+    reader.seek(SeekFrom::Start(tail_start))?;
+    reader.read_exact(&mut tail)?;
+    if &tail[TAIL_SIZE as usize - INSTA360_MAGIC.len()..] != INSTA360_MAGIC {
+        return Ok(Vec::new());
+    }
+    let trailer_length = u32::from_le_bytes(
+        tail[TRAILER_LENGTH_OFFSET..TRAILER_LENGTH_OFFSET + 4]
+            .try_into()
+            .unwrap(),
+    ) as u64;
+    if trailer_length < TAIL_SIZE || trailer_length > file_size {
+        return Err(ExifError::InvalidFormat(
+            "Bad Insta360 trailer size".to_string(),
+        ));
+    }
+    let trailer_start = file_size - trailer_length;
+
+    let mut entries = Vec::new();
+    let mut footer_start = tail_start;
+    let mut footer = [0u8; FOOTER_SIZE as usize];
+    loop {
+        reader.seek(SeekFrom::Start(footer_start))?;
+        reader.read_exact(&mut footer)?;
+        let id = u16::from_le_bytes([footer[0], footer[1]]);
+        let length = u32::from_le_bytes([footer[2], footer[3], footer[4], footer[5]]) as u64;
+        let Some(data_start) = footer_start
+            .checked_sub(length)
+            .filter(|&start| start >= trailer_start)
+        else {
+            break;
+        };
+        if id == INSTA360_INFO {
+            let mut data = vec![0u8; length as usize];
+            reader.seek(SeekFrom::Start(data_start))?;
+            reader.read_exact(&mut data)?;
+            entries.extend(parse_insta360_info(&data));
+        }
+        match data_start.checked_sub(FOOTER_SIZE) {
+            Some(start) if start >= trailer_start => footer_start = start,
+            _ => break,
+        }
+    }
+    Ok(entries)
+}
+
+/// Decode the string fields of the Insta360Info protobuf message
+fn parse_insta360_info(data: &[u8]) -> Vec<TagEntry> {
+    let mut entries = Vec::new();
+    let mut pos = 0;
+    while pos < data.len() {
+        let Some(key) = read_varint(data, &mut pos) else {
+            break;
+        };
+        let (field, wire_type) = (key >> 3, key & 0x7);
+        let value = match wire_type {
+            0 => {
+                if read_varint(data, &mut pos).is_none() {
+                    break;
+                }
+                continue;
+            }
+            1 => {
+                pos += 8;
+                continue;
+            }
+            5 => {
+                pos += 4;
+                continue;
+            }
+            2 => {
+                let Some(length) = read_varint(data, &mut pos) else {
+                    break;
+                };
+                let Some(bytes) = usize::try_from(length)
+                    .ok()
+                    .and_then(|length| data.get(pos..pos.checked_add(length)?))
+                else {
+                    break;
+                };
+                pos += bytes.len();
+                bytes
+            }
+            _ => break,
+        };
+        let Some(&(_, name)) = INFO_FIELDS.iter().find(|&&(number, _)| number == field) else {
+            continue;
+        };
+        let value = TagValue::String(String::from_utf8_lossy(value).into_owned());
+        entries.push(TagEntry {
+            group: "Trailer".into(),
+            group1: "Insta360".into(),
+            name: name.into(),
+            value: value.clone(),
+            print: value.into(),
+            source: None,
+        });
+    }
+    entries
+}
+
+/// Read a protobuf base-128 varint
+fn read_varint(data: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *data.get(*pos)?;
+        *pos += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// A protobuf string field
+    fn field(number: u8, value: &str) -> Vec<u8> {
+        let mut bytes = vec![number << 3 | 2, value.len() as u8];
+        bytes.extend_from_slice(value.as_bytes());
+        bytes
+    }
+
+    /// `image` followed by a trailer holding `records` in file order
+    fn with_trailer(image: &[u8], records: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut trailer = Vec::new();
+        for (id, data) in records {
+            trailer.extend_from_slice(data);
+            trailer.extend_from_slice(&id.to_le_bytes());
+            trailer.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        }
+        trailer.extend_from_slice(&[0; 32]);
+        let length = trailer.len() + 8 + INSTA360_MAGIC.len();
+        trailer.extend_from_slice(&(length as u32).to_le_bytes());
+        trailer.extend_from_slice(&3u32.to_le_bytes());
+        trailer.extend_from_slice(INSTA360_MAGIC);
+        [image, &trailer].concat()
+    }
+
+    #[test]
+    fn test_insta360_trailer() {
+        let info = [
+            field(1, "IXSE42ABCDEF"),
+            field(2, "Insta360 X3"),
+            field(3, "v1.0.04"),
+            vec![4 << 3, 0x96, 0x01], // varint field, skipped
+            field(5, "2_1468.450_1497.680_1498.940_0.000_0.000_90.000"),
+        ]
+        .concat();
+        let file = with_trailer(
+            b"\xff\xd8\xff\xd9",
+            &[(INSTA360_INFO, info), (0x300, vec![0; 12])],
+        );
+        let entries = extract_insta360_trailer(&mut Cursor::new(file)).unwrap();
+        let values: Vec<_> = entries
+            .iter()
+            .map(|e| (e.name.as_str(), e.value.as_string().unwrap()))
+            .collect();
+        assert_eq!(
+            values,
+            [
+                ("SerialNumber", "IXSE42ABCDEF"),
+                ("Model", "Insta360 X3"),
+                ("Firmware", "v1.0.04"),
+                (
+                    "Parameters",
+                    "2_1468.450_1497.680_1498.940_0.000_0.000_90.000"
+                ),
+            ]
+        );
+        assert!(entries.iter().all(|e| e.group1 == "Insta360"));
+
+        // No trailer
+        let entries = extract_insta360_trailer(&mut Cursor::new(b"\xff\xd8\xff\xd9")).unwrap();
+        assert!(entries.is_empty());
+    }
+}
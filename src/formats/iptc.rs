@@ -16,9 +16,14 @@
 use crate::generated::IPTC_pm::application_record_tags::IPTC_APPLICATIONRECORD_TAGS;
 use crate::generated::IPTC_pm::envelope_record_tags::IPTC_ENVELOPERECORD_TAGS;
 use crate::types::{ExifError, Result, TagInfo, TagValue};
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use tracing::{debug, warn};
 
+/// Signatures of Photoshop image resource blocks
+/// ExifTool: Photoshop.pm ProcessPhotoshop() /^(8BIM|MeSa|PHUT|AgHg|DCSR)$/
+pub(crate) const RESOURCE_SIGNATURES: &[&[u8; 4]] = &[b"8BIM", b"MeSa", b"PHUT", b"AgHg", b"DCSR"];
+
 /// IPTC DataSet structure
 /// ExifTool: IPTC.pm ProcessIPTC function parsing logic
 #[derive(Debug)]
@@ -69,7 +74,7 @@ impl IptcParser {
     /// Parse IPTC binary data into tag values
     /// ExifTool: IPTC.pm ProcessIPTC function (lines 1050-1200)
     fn parse_iptc_data(&mut self, data: &[u8]) -> Result<HashMap<String, TagValue>> {
-        let mut extracted_tags = HashMap::new();
+        let mut extracted_tags: HashMap<String, TagValue> = HashMap::new();
         let mut pos = 0;
         let data_len = data.len();
 
@@ -158,7 +163,14 @@ impl IptcParser {
                 };
 
                 debug!("Extracted IPTC tag: {} = {:?}", tag_name, tag_value);
-                extracted_tags.insert(tag_name, tag_value);
+                // Repeatable datasets (Keywords, SupplementalCategories, By-line,
+                // ...) occur once per value; ExifTool lists them all (List tags)
+                match extracted_tags.entry(tag_name) {
+                    Entry::Occupied(mut entry) => entry.get_mut().append(tag_value),
+                    Entry::Vacant(entry) => {
+                        entry.insert(tag_value);
+                    }
+                }
             } else {
                 debug!("Unknown IPTC tag: Record {}, DataSet {}", record, dataset);
             }
@@ -172,16 +184,11 @@ impl IptcParser {
     fn convert_tag_value(&self, data: &[u8], tag_def: &TagInfo) -> Result<TagValue> {
         match tag_def.format {
             format if format.starts_with("string") => {
-                // Convert bytes to string with proper encoding
+                // Convert bytes to string with proper encoding. Each occurrence
+                // of a repeatable dataset (Keywords) holds one value;
+                // parse_iptc_data() collects them
                 let text = self.decode_string(data)?;
-
-                // Handle list-type tags (Keywords can have multiple values)
-                if tag_def.name == "Keywords" {
-                    // For now, treat as single value - multi-value handling needs more work
-                    Ok(TagValue::string(text))
-                } else {
-                    Ok(TagValue::string(text))
-                }
+                Ok(TagValue::string(text))
             }
             "int16u" => {
                 if data.len() >= 2 {
@@ -257,11 +264,13 @@ pub fn parse_iptc_from_app13(app13_data: &[u8]) -> Result<HashMap<String, TagVal
         // Parse Image Resource Block header
         // Format: "8BIM" (4 bytes) + Resource ID (2 bytes) + Name Length (2 bytes) + Name + Data Length (4 bytes) + Data
 
-        // Check for "8BIM" signature
-        if pos + 4 > app13_data.len() || &app13_data[pos..pos + 4] != b"8BIM" {
+        // Check for "8BIM" (or another block) signature
+        let signature = &app13_data[pos..pos + 4];
+        if !RESOURCE_SIGNATURES.iter().any(|&known| known == signature) {
             debug!("No more Image Resource Blocks found at offset {}", pos);
             break;
         }
+        let is_8bim = signature == b"8BIM";
         pos += 4;
 
         // Read Resource ID (2 bytes, big-endian)
@@ -307,7 +316,7 @@ pub fn parse_iptc_from_app13(app13_data: &[u8]) -> Result<HashMap<String, TagVal
         );
 
         // Check if this is the IPTC resource (Resource ID 0x0404)
-        if resource_id == 0x0404 {
+        if is_8bim && resource_id == 0x0404 {
             debug!(
                 "Found IPTC Image Resource Block (0x0404) with {} bytes",
                 data_length
@@ -423,6 +432,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_repeated_keywords_are_all_kept() {
+        let mut data = Vec::new();
+        for keyword in [&b"sunset"[..], b"beach", b"sea"] {
+            data.extend_from_slice(&[0x1c, 0x02, 0x19, 0x00, keyword.len() as u8]);
+            data.extend_from_slice(keyword);
+        }
+
+        let tags = parse_iptc_metadata(&data).unwrap();
+        assert_eq!(
+            tags.get("IPTC:Keywords"),
+            Some(&TagValue::Array(vec![
+                TagValue::string("sunset"),
+                TagValue::string("beach"),
+                TagValue::string("sea"),
+            ]))
+        );
+    }
+
     #[test]
     fn test_extended_iptc_entry() {
         // Test extended IPTC entry with variable-length size field
@@ -5,8 +5,9 @@
 //! and EXIF data extraction.
 
 use crate::hash::ImageDataHasher;
-use crate::types::{ExifError, Result};
-use std::collections::{BTreeMap, HashMap};
+use crate::types::{ExifError, Result, XmpPacketLocation};
+use md5::{Digest, Md5};
+use std::collections::BTreeMap;
 use std::io::{Read, Seek, SeekFrom};
 
 /// JPEG segment types
@@ -237,9 +238,11 @@ pub fn scan_jpeg_segments<R: Read + Seek>(
                 break;
             }
             JpegSegment::App(app_num) => {
-                // Read segment length
+                // Read segment length; a file cut off here has nothing more to find
                 let mut length_bytes = [0u8; 2];
-                reader.read_exact(&mut length_bytes)?;
+                if reader.read_exact(&mut length_bytes).is_err() {
+                    break;
+                }
                 let length = u16::from_be_bytes(length_bytes);
                 current_pos += 2;
 
@@ -299,9 +302,11 @@ pub fn scan_jpeg_segments<R: Read + Seek>(
                 current_pos += segment_data_length;
             }
             JpegSegment::Sof(marker) => {
-                // Read segment length
+                // Read segment length; a file cut off here has nothing more to find
                 let mut length_bytes = [0u8; 2];
-                reader.read_exact(&mut length_bytes)?;
+                if reader.read_exact(&mut length_bytes).is_err() {
+                    break;
+                }
                 let length = u16::from_be_bytes(length_bytes);
                 current_pos += 2;
 
@@ -596,152 +601,195 @@ fn extract_has_extended_xmp_guid(xmp_data: &[u8]) -> Option<String> {
     None
 }
 
+/// Locate the standard XMP packet of a JPEG file
+///
+/// Extended XMP segments don't have their own location; they only set
+/// [`XmpPacketLocation::extended`] on the main packet.
+pub fn locate_jpeg_xmp<R: Read + Seek>(mut reader: R) -> Result<Option<XmpPacketLocation>> {
+    let scan_result = scan_jpeg_xmp_segments(&mut reader)?;
+    let Some(regular_xmp) = scan_result.regular_xmp else {
+        return Ok(None);
+    };
+    reader.seek(SeekFrom::Start(regular_xmp.offset))?;
+    let mut packet = vec![0u8; regular_xmp.length as usize];
+    reader.read_exact(&mut packet)?;
+    let location = XmpPacketLocation::new("JPEG APP1", regular_xmp.offset, &packet)
+        .with_extended(!scan_result.extended_xmp.is_empty());
+    Ok(Some(location))
+}
+
+/// XMP read from a JPEG, with any problems found reassembling Extended XMP
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JpegXmp {
+    /// The standard XMP packet, followed by the Extended XMP if it was complete
+    pub data: Vec<u8>,
+    /// Extended XMP diagnostics (missing chunks, ignored GUIDs, bad MD5)
+    pub warnings: Vec<String>,
+}
+
 /// Extract XMP data from JPEG file
 ///
 /// This function scans for APP1 segments containing XMP data and returns
 /// the raw XMP packet(s). For Extended XMP, multiple segments are reassembled.
+/// See [`extract_jpeg_xmp_with_warnings`] for why Extended XMP was left out.
 ///
 /// ExifTool: lib/Image/ExifTool.pm:7482-7524 (Extended XMP reassembly)
-pub fn extract_jpeg_xmp<R: Read + Seek>(mut reader: R) -> Result<Vec<u8>> {
+pub fn extract_jpeg_xmp<R: Read + Seek>(reader: R) -> Result<Vec<u8>> {
+    extract_jpeg_xmp_with_warnings(reader).map(|xmp| xmp.data)
+}
+
+/// Extract XMP data from JPEG file, reporting Extended XMP problems
+///
+/// Extended XMP is only used when the standard packet's
+/// xmpNote:HasExtendedXMP names its GUID, every byte of the declared total
+/// size is present, and no chunk runs past it. Otherwise the standard packet
+/// is returned alone and the reason is added to [`JpegXmp::warnings`]. The
+/// Adobe XMP spec makes the GUID the MD5 of the full Extended XMP; a mismatch
+/// is reported but the data is still used, since ExifTool doesn't check it.
+///
+/// ExifTool: lib/Image/ExifTool.pm:7482-7524 (Extended XMP reassembly)
+pub fn extract_jpeg_xmp_with_warnings<R: Read + Seek>(mut reader: R) -> Result<JpegXmp> {
     let scan_result = scan_jpeg_xmp_segments(&mut reader)?;
 
+    // Group Extended XMP chunks by GUID
+    let mut by_guid: BTreeMap<&str, Vec<&ExtendedXmpInfo>> = BTreeMap::new();
+    for ext_info in &scan_result.extended_xmp {
+        by_guid.entry(&ext_info.guid).or_default().push(ext_info);
+    }
+
     // First, check if we have regular XMP
     if let Some(regular_xmp) = &scan_result.regular_xmp {
         // Read regular XMP data
         reader.seek(SeekFrom::Start(regular_xmp.offset))?;
         let mut xmp_data = vec![0u8; regular_xmp.length as usize];
         reader.read_exact(&mut xmp_data)?;
+        let mut warnings = Vec::new();
 
         // Check if this XMP contains HasExtendedXMP property
-        if let Some(has_extended_guid) = extract_has_extended_xmp_guid(&xmp_data) {
-            // We have Extended XMP to reassemble
-            // ExifTool: lib/Image/ExifTool.pm:7487-7488
-            if !scan_result.extended_xmp.is_empty() {
-                // Group Extended XMP chunks by GUID
-                let mut guid_chunks: HashMap<String, BTreeMap<u32, Vec<u8>>> = HashMap::new();
-
-                for ext_info in &scan_result.extended_xmp {
-                    // Only process chunks matching the HasExtendedXMP GUID
-                    if ext_info.guid == has_extended_guid {
-                        // Read chunk data
-                        reader.seek(SeekFrom::Start(ext_info.segment_offset))?;
-                        let mut chunk_data = vec![0u8; ext_info.chunk_length as usize];
-                        reader.read_exact(&mut chunk_data)?;
-
-                        // Store chunk indexed by offset for ordered reassembly
-                        // ExifTool: lib/Image/ExifTool.pm:7751 - $$extXMP{$off} = substr($$segDataPt, 75)
-                        guid_chunks
-                            .entry(ext_info.guid.clone())
-                            .or_default()
-                            .insert(ext_info.chunk_offset, chunk_data);
-                    }
-                }
-
-                // Reassemble Extended XMP with matching GUID
-                if let Some(chunks) = guid_chunks.get(&has_extended_guid) {
-                    // Check if we have all chunks
-                    let mut expected_offset = 0u32;
-                    let mut total_size = 0u32;
-                    let mut is_complete = true;
-
-                    // Get total size from first matching segment
-                    for ext_info in &scan_result.extended_xmp {
-                        if ext_info.guid == has_extended_guid {
-                            total_size = ext_info.total_size;
-                            break;
-                        }
-                    }
-
-                    // Verify we have all chunks in sequence
-                    // ExifTool: lib/Image/ExifTool.pm:7494-7498 - check for missing chunks
-                    for (offset, chunk) in chunks {
-                        if *offset != expected_offset {
-                            is_complete = false;
-                            break;
-                        }
-                        expected_offset += chunk.len() as u32;
-                    }
+        // ExifTool: lib/Image/ExifTool.pm:7485-7488
+        let has_extended_guid = extract_has_extended_xmp_guid(&xmp_data);
+        for guid in by_guid.keys() {
+            if has_extended_guid.as_deref() != Some(*guid) {
+                warnings.push(format!("Ignored extended XMP with unexpected GUID {guid}"));
+            }
+        }
 
-                    if is_complete && expected_offset == total_size {
-                        // Combine regular XMP with Extended XMP
+        if let Some(guid) = has_extended_guid {
+            match by_guid.get(guid.as_str()) {
+                Some(segments) => {
+                    if let Some(extended) =
+                        reassemble_extended_xmp(&mut reader, &guid, segments, &mut warnings)?
+                    {
                         // The Extended XMP is appended after the regular XMP
                         // ExifTool: lib/Image/ExifTool.pm:7506-7507
-                        let mut combined_xmp = xmp_data;
-                        for chunk in chunks.values() {
-                            combined_xmp.extend_from_slice(chunk);
-                        }
-                        return Ok(combined_xmp);
+                        xmp_data.extend_from_slice(&extended);
                     }
                 }
+                None => warnings.push(format!("Missing extended XMP (GUID {guid})")),
             }
         }
 
-        // Return just regular XMP if no Extended XMP or incomplete
-        return Ok(xmp_data);
+        return Ok(JpegXmp {
+            data: xmp_data,
+            warnings,
+        });
     }
 
-    // No regular XMP - check for Extended XMP only (unusual but possible)
-    if !scan_result.extended_xmp.is_empty() {
-        // Group Extended XMP chunks by GUID
-        let mut guid_chunks: HashMap<String, BTreeMap<u32, Vec<u8>>> = HashMap::new();
-
-        for ext_info in &scan_result.extended_xmp {
-            // Read chunk data
-            reader.seek(SeekFrom::Start(ext_info.segment_offset))?;
-            let mut chunk_data = vec![0u8; ext_info.chunk_length as usize];
-            reader.read_exact(&mut chunk_data)?;
-
-            // Store chunk indexed by offset for ordered reassembly
-            guid_chunks
-                .entry(ext_info.guid.clone())
-                .or_default()
-                .insert(ext_info.chunk_offset, chunk_data);
+    // No regular XMP - use the first complete Extended XMP (unusual but possible)
+    // ExifTool: lib/Image/ExifTool.pm:7493-7500
+    if !by_guid.is_empty() {
+        let mut warnings = Vec::new();
+        for (guid, segments) in &by_guid {
+            if let Some(data) = reassemble_extended_xmp(&mut reader, guid, segments, &mut warnings)?
+            {
+                return Ok(JpegXmp { data, warnings });
+            }
         }
 
-        // Find the first complete Extended XMP
-        // ExifTool: lib/Image/ExifTool.pm:7493-7500
-        for (guid, chunks) in guid_chunks {
-            // Check if we have all chunks
-            let mut expected_offset = 0u32;
-            let mut total_size = 0u32;
-            let mut is_complete = true;
-
-            // Get total size from first matching segment
-            for ext_info in &scan_result.extended_xmp {
-                if ext_info.guid == guid {
-                    total_size = ext_info.total_size;
-                    break;
-                }
-            }
+        return Err(ExifError::InvalidFormat(format!(
+            "Incomplete Extended XMP data: {}",
+            warnings.join("; ")
+        )));
+    }
 
-            // Verify we have all chunks in sequence
-            for (offset, chunk) in &chunks {
-                if *offset != expected_offset {
-                    is_complete = false;
-                    break;
-                }
-                expected_offset += chunk.len() as u32;
-            }
+    Err(ExifError::InvalidFormat(
+        "No XMP data found in JPEG file".to_string(),
+    ))
+}
 
-            if is_complete && expected_offset == total_size {
-                // Reassemble complete Extended XMP
-                // ExifTool: lib/Image/ExifTool.pm:7506-7507
-                let mut reassembled = Vec::with_capacity(total_size as usize);
-                for (_, chunk) in chunks {
-                    reassembled.extend_from_slice(&chunk);
-                }
-                return Ok(reassembled);
-            }
+/// Reassemble the Extended XMP chunks sharing one GUID
+///
+/// Returns `None`, with the reason in `warnings`, when chunks are missing.
+/// Chunks that disagree with the first one's total size, or that don't fit
+/// in it, are skipped with a warning rather than trusted.
+fn reassemble_extended_xmp<R: Read + Seek>(
+    reader: &mut R,
+    guid: &str,
+    segments: &[&ExtendedXmpInfo],
+    warnings: &mut Vec<String>,
+) -> Result<Option<Vec<u8>>> {
+    let Some(total_size) = segments.first().map(|ext_info| ext_info.total_size) else {
+        return Ok(None);
+    };
+
+    // Keep the first chunk at each offset, indexed for ordered reassembly
+    // ExifTool: lib/Image/ExifTool.pm:7751 - $$extXMP{$off} = substr($$segDataPt, 75)
+    let mut chunks: BTreeMap<u32, &ExtendedXmpInfo> = BTreeMap::new();
+    for ext_info in segments {
+        let chunk_end = u64::from(ext_info.chunk_offset) + u64::from(ext_info.chunk_length);
+        if ext_info.total_size != total_size {
+            warnings.push(format!(
+                "Ignored extended XMP chunk at offset {} with total size {} instead of {total_size} (GUID {guid})",
+                ext_info.chunk_offset, ext_info.total_size
+            ));
+        } else if chunk_end > u64::from(total_size) {
+            warnings.push(format!(
+                "Ignored extended XMP chunk at offset {} running past total size {total_size} (GUID {guid})",
+                ext_info.chunk_offset
+            ));
+        } else {
+            chunks.entry(ext_info.chunk_offset).or_insert(ext_info);
         }
+    }
 
-        return Err(ExifError::InvalidFormat(
-            "Incomplete Extended XMP data".to_string(),
+    // Every byte of the total size must be covered; overlaps are harmless
+    // ExifTool: lib/Image/ExifTool.pm:7494-7498 - check for missing chunks
+    let mut missing = Vec::new();
+    let mut covered = 0u64;
+    for (offset, ext_info) in &chunks {
+        let offset = u64::from(*offset);
+        if offset > covered {
+            missing.push(format!("{covered}-{}", offset - 1));
+        }
+        covered = covered.max(offset + u64::from(ext_info.chunk_length));
+    }
+    if covered < u64::from(total_size) {
+        missing.push(format!("{covered}-{}", total_size - 1));
+    }
+    if !missing.is_empty() {
+        warnings.push(format!(
+            "Incomplete extended XMP (GUID {guid}): missing bytes {} of {total_size}",
+            missing.join(", ")
         ));
+        return Ok(None);
     }
 
-    Err(ExifError::InvalidFormat(
-        "No XMP data found in JPEG file".to_string(),
-    ))
+    let mut extended = vec![0u8; total_size as usize];
+    for (offset, ext_info) in &chunks {
+        let start = *offset as usize;
+        reader.seek(SeekFrom::Start(ext_info.segment_offset))?;
+        reader.read_exact(&mut extended[start..start + ext_info.chunk_length as usize])?;
+    }
+
+    let md5 = format!("{:x}", Md5::digest(&extended));
+    if !md5.eq_ignore_ascii_case(guid) {
+        warnings.push(format!(
+            "Extended XMP GUID {guid} doesn't match its MD5 {}",
+            md5.to_uppercase()
+        ));
+    }
+
+    Ok(Some(extended))
 }
 
 /// Extract EXIF data from JPEG file
@@ -869,6 +917,87 @@ pub fn extract_jpeg_iptc<R: Read + Seek>(
     }
 }
 
+/// Extract the ICC profile from JPEG APP2 segments
+///
+/// Profiles larger than a segment are split across several APP2 segments,
+/// each with `ICC_PROFILE\0`, a 1-based chunk number and the chunk count.
+/// The chunks are joined in chunk number order. Returns `None` without an
+/// ICC profile, and an error when chunks are missing.
+///
+/// ExifTool: lib/Image/ExifTool.pm ProcessJPEG APP2 ICC_Profile (%iccChunk)
+pub fn extract_jpeg_icc_profile<R: Read + Seek>(mut reader: R) -> Result<Option<Vec<u8>>> {
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut header = [0u8; 2];
+    reader.read_exact(&mut header)?;
+    if header != [0xFF, 0xD8] {
+        return Err(ExifError::InvalidFormat(
+            "Not a valid JPEG file".to_string(),
+        ));
+    }
+
+    let mut chunks: BTreeMap<u8, Vec<u8>> = BTreeMap::new();
+    let mut chunk_count = 0u8;
+    loop {
+        let mut marker_bytes = [0u8; 2];
+        if reader.read_exact(&mut marker_bytes).is_err() {
+            break;
+        }
+        if marker_bytes[0] != 0xFF {
+            return Err(ExifError::InvalidFormat("Invalid JPEG marker".to_string()));
+        }
+        let marker = marker_bytes[1];
+        if marker == 0xD9 || marker == 0xDA {
+            break;
+        }
+        // Standalone markers have no length
+        if (0xD0..=0xD7).contains(&marker) || marker == 0x01 {
+            continue;
+        }
+
+        let mut length_bytes = [0u8; 2];
+        if reader.read_exact(&mut length_bytes).is_err() {
+            break;
+        }
+        let length = u16::from_be_bytes(length_bytes) as usize;
+        if length < 2 {
+            return Err(ExifError::InvalidFormat(
+                "Invalid segment length".to_string(),
+            ));
+        }
+
+        if marker != 0xE2 {
+            reader.seek(SeekFrom::Current((length - 2) as i64))?;
+            continue;
+        }
+        let mut segment = vec![0u8; length - 2];
+        reader.read_exact(&mut segment)?;
+        // "ICC_PROFILE\0" + chunk number + chunk count
+        if segment.len() < 14 || !segment.starts_with(b"ICC_PROFILE\0") {
+            continue;
+        }
+        let (number, count) = (segment[12], segment[13]);
+        if number == 0 || count == 0 || number > count {
+            return Err(ExifError::InvalidFormat(format!(
+                "Invalid ICC_Profile chunk {number} of {count}"
+            )));
+        }
+        chunk_count = chunk_count.max(count);
+        chunks.insert(number, segment.split_off(14));
+    }
+
+    if chunks.is_empty() {
+        return Ok(None);
+    }
+    if chunks.len() < chunk_count as usize {
+        return Err(ExifError::InvalidFormat(format!(
+            "Incomplete ICC_Profile ({} of {chunk_count} chunks)",
+            chunks.len()
+        )));
+    }
+    Ok(Some(chunks.into_values().flatten().collect()))
+}
+
 /// Hash JPEG image data (scan data from SOS to EOI)
 ///
 /// ExifTool Reference: lib/Image/ExifTool.pm:7217-7406
@@ -886,6 +1015,7 @@ pub fn hash_jpeg_scan_data<R: Read + Seek>(
     reader: &mut R,
     hasher: &mut ImageDataHasher,
 ) -> Result<u64> {
+    let _stage = crate::stats::stage("ImageDataHash");
     // Verify JPEG magic bytes and scan to SOS
     reader.seek(SeekFrom::Start(0))?;
     let mut magic = [0u8; 2];
@@ -967,7 +1097,7 @@ pub fn hash_jpeg_scan_data<R: Read + Seek>(
     // ExifTool: lib/Image/ExifTool.pm:7366-7406
     let mut bytes_hashed = 4u64; // Already hashed 0xFF 0xDA + length (2 bytes)
 
-    // Read in chunks for efficiency, but process byte-by-byte for marker detection
+    // Read in chunks, using SIMD search (memchr) to jump between 0xFF marker candidates
     const CHUNK_SIZE: usize = 65536;
     let mut buffer = vec![0u8; CHUNK_SIZE];
     let mut pending_ff = false;
@@ -1044,9 +1174,12 @@ pub fn hash_jpeg_scan_data<R: Read + Seek>(
             } else if byte == 0xFF {
                 pending_ff = true;
             } else {
-                // Regular data byte - hash it
-                hasher.update(&[byte]);
-                bytes_hashed += 1;
+                // Regular data bytes - hash the whole run up to the next 0xFF at once
+                let run = memchr::memchr(0xFF, &buffer[i..bytes_read]).unwrap_or(bytes_read - i);
+                hasher.update(&buffer[i..i + run]);
+                bytes_hashed += run as u64;
+                i += run;
+                continue;
             }
 
             i += 1;
@@ -1291,6 +1424,129 @@ mod tests {
         assert_eq!(xmp_data, xmp_packet);
     }
 
+    /// An Extended XMP APP1 segment holding `chunk` at `offset` of `total_size`
+    fn extended_xmp_segment(guid: &str, total_size: usize, offset: usize, chunk: &[u8]) -> Vec<u8> {
+        let length = 2 + 75 + chunk.len();
+        let mut segment = vec![0xFF, 0xE1, (length >> 8) as u8, length as u8];
+        segment.extend_from_slice(b"http://ns.adobe.com/xmp/extension/\0");
+        segment.extend_from_slice(guid.as_bytes());
+        segment.extend_from_slice(&(total_size as u32).to_be_bytes());
+        segment.extend_from_slice(&(offset as u32).to_be_bytes());
+        segment.extend_from_slice(chunk);
+        segment
+    }
+
+    /// A JPEG with a standard XMP packet naming `guid` as its HasExtendedXMP,
+    /// followed by `segments`
+    fn extended_xmp_jpeg(guid: &str, segments: &[Vec<u8>]) -> Vec<u8> {
+        let packet = format!("<x:xmpmeta xmpNote:HasExtendedXMP=\"{guid}\"/>");
+        let length = 2 + 29 + packet.len();
+        let mut jpeg_data = vec![0xFF, 0xD8, 0xFF, 0xE1, (length >> 8) as u8, length as u8];
+        jpeg_data.extend_from_slice(b"http://ns.adobe.com/xap/1.0/\0");
+        jpeg_data.extend_from_slice(packet.as_bytes());
+        jpeg_data.extend(segments.concat());
+        jpeg_data.extend_from_slice(&[0xFF, 0xD9]);
+        jpeg_data
+    }
+
+    #[test]
+    fn test_extended_xmp_reassembly() {
+        let extended = b"<x:xmpmeta><rdf:RDF/></x:xmpmeta>";
+        let guid = format!("{:X}", Md5::digest(extended));
+        let size = extended.len();
+        // Chunks out of order, with a repeated chunk
+        let jpeg_data = extended_xmp_jpeg(
+            &guid,
+            &[
+                extended_xmp_segment(&guid, size, 20, &extended[20..]),
+                extended_xmp_segment(&guid, size, 0, &extended[..20]),
+                extended_xmp_segment(&guid, size, 20, &extended[20..]),
+            ],
+        );
+        let xmp = extract_jpeg_xmp_with_warnings(Cursor::new(&jpeg_data)).unwrap();
+        assert!(xmp.warnings.is_empty(), "{:?}", xmp.warnings);
+        assert!(xmp.data.ends_with(extended));
+
+        // A missing chunk leaves just the standard packet
+        let jpeg_data = extended_xmp_jpeg(
+            &guid,
+            &[
+                extended_xmp_segment(&guid, size, 0, &extended[..10]),
+                extended_xmp_segment(&guid, size, 20, &extended[20..]),
+            ],
+        );
+        let xmp = extract_jpeg_xmp_with_warnings(Cursor::new(&jpeg_data)).unwrap();
+        assert!(!xmp.data.ends_with(extended));
+        assert_eq!(
+            xmp.warnings,
+            [format!(
+                "Incomplete extended XMP (GUID {guid}): missing bytes 10-19 of {size}"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_extended_xmp_guid_checks() {
+        let extended = b"<x:xmpmeta/>";
+        let guid = "0123456789ABCDEF0123456789ABCDEF";
+        let segment = extended_xmp_segment(guid, extended.len(), 0, extended);
+        let xmp = extract_jpeg_xmp_with_warnings(Cursor::new(extended_xmp_jpeg(guid, &[segment])))
+            .unwrap();
+        assert!(xmp.data.ends_with(extended));
+        assert_eq!(xmp.warnings.len(), 1);
+        assert!(xmp.warnings[0].contains("doesn't match its MD5"));
+
+        // Chunks for another GUID are ignored, and ours is missing
+        let other = "F".repeat(32);
+        let segment = extended_xmp_segment(&other, extended.len(), 0, extended);
+        let xmp = extract_jpeg_xmp_with_warnings(Cursor::new(extended_xmp_jpeg(guid, &[segment])))
+            .unwrap();
+        assert!(!xmp.data.ends_with(extended));
+        assert_eq!(
+            xmp.warnings,
+            [
+                format!("Ignored extended XMP with unexpected GUID {other}"),
+                format!("Missing extended XMP (GUID {guid})"),
+            ]
+        );
+    }
+
+    /// An APP2 ICC_PROFILE segment with chunk `number` of `count`
+    fn icc_segment(number: u8, count: u8, chunk: &[u8]) -> Vec<u8> {
+        let length = 2 + 14 + chunk.len();
+        let mut segment = vec![0xFF, 0xE2, (length >> 8) as u8, length as u8];
+        segment.extend_from_slice(b"ICC_PROFILE\0");
+        segment.extend_from_slice(&[number, count]);
+        segment.extend_from_slice(chunk);
+        segment
+    }
+
+    #[test]
+    fn test_extract_jpeg_icc_profile_chunks() {
+        // Chunks out of order, with another APP2 segment in between
+        let mut jpeg_data = vec![0xFF, 0xD8];
+        jpeg_data.extend(icc_segment(2, 3, b"middle "));
+        jpeg_data.extend_from_slice(&[0xFF, 0xE2, 0x00, 0x06, b'M', b'P', b'F', 0]);
+        jpeg_data.extend(icc_segment(1, 3, b"first "));
+        jpeg_data.extend(icc_segment(3, 3, b"last"));
+        jpeg_data.extend_from_slice(&[0xFF, 0xD9]);
+        assert_eq!(
+            extract_jpeg_icc_profile(Cursor::new(&jpeg_data)).unwrap(),
+            Some(b"first middle last".to_vec())
+        );
+
+        let mut incomplete = vec![0xFF, 0xD8];
+        incomplete.extend(icc_segment(1, 2, b"first"));
+        incomplete.extend_from_slice(&[0xFF, 0xD9]);
+        assert!(extract_jpeg_icc_profile(Cursor::new(&incomplete)).is_err());
+
+        let no_profile = [0xFF, 0xD8, 0xFF, 0xD9];
+        assert_eq!(
+            extract_jpeg_icc_profile(Cursor::new(&no_profile)).unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn test_hash_jpeg_scan_data_minimal() {
         use crate::hash::{ImageDataHasher, ImageHashType};
@@ -1402,4 +1658,34 @@ mod tests {
         // Should skip APP1 and only hash SOS + scan data
         assert!(bytes_hashed > 0);
     }
+
+    #[test]
+    fn test_hash_jpeg_scan_data_spans_chunks() {
+        use crate::hash::{ImageDataHasher, ImageHashType};
+
+        // Scan data larger than one read chunk, with stuffed bytes and RST markers
+        let mut scan = Vec::new();
+        for i in 0..200_000u32 {
+            let byte = (i % 251) as u8;
+            scan.push(byte);
+            if byte == 0xFF {
+                scan.push(0x00);
+            }
+            if i % 50_000 == 49_999 {
+                scan.extend_from_slice(&[0xFF, 0xD0 + (i / 50_000) as u8]);
+            }
+        }
+        let mut jpeg_data = vec![0xFF, 0xD8, 0xFF, 0xDA, 0x00, 0x04, 0x01, 0x00];
+        jpeg_data.extend_from_slice(&scan);
+        jpeg_data.extend_from_slice(&[0xFF, 0xD9]);
+
+        let mut hasher = ImageDataHasher::new(ImageHashType::Md5);
+        hash_jpeg_scan_data(&mut Cursor::new(&jpeg_data), &mut hasher).unwrap();
+
+        // Everything from the SOS marker up to EOI is hashed unchanged
+        let hashed = &jpeg_data[2..jpeg_data.len() - 2];
+        let mut expected = ImageDataHasher::new(ImageHashType::Md5);
+        expected.update(hashed);
+        assert_eq!(hasher.finalize(), expected.finalize());
+    }
 }
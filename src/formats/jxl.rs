@@ -0,0 +1,398 @@
+//! JPEG XL file format processing
+//!
+//! JPEG XL files come in two forms: a bare codestream starting with
+//! `\xff\x0a`, or an ISO BMFF container (`JXL ` signature box, `ftyp` brand
+//! `jxl `) holding the codestream in `jxlc` or `jxlp` boxes beside `Exif` and
+//! `xml ` metadata boxes. Image size comes from the codestream SizeHeader.
+//!
+//! The animation tags (Animated, AnimationTicksPerSecond, AnimationLoopCount)
+//! come from the codestream AnimationHeader and are exif-oxide additions.
+//! Brotli-compressed `brob` metadata boxes are not decoded.
+//!
+//! Reference: third-party/exiftool/lib/Image/ExifTool/Jpeg2000.pm (ProcessJXL,
+//! ProcessJXLCodestream); ISO/IEC 18181-1 for the header bit fields
+
+use super::avif::parse_box_header;
+use crate::types::{ExifError, Result, TagEntry, TagValue};
+
+/// Bare codestream signature
+const CODESTREAM_SIGNATURE: &[u8] = b"\xff\x0a";
+
+/// Container signature box: size 12, type 'JXL ', content \r\n\x87\n
+const CONTAINER_SIGNATURE: &[u8] = b"\0\0\0\x0cJXL \x0d\x0a\x87\x0a";
+
+/// Codestream bytes collected from 'jxlp' boxes; the headers are far smaller
+const MAX_CODESTREAM_HEADER: usize = 4096;
+
+/// Maximum number of container boxes to scan
+const MAX_BOXES_TO_SCAN: usize = 1000;
+
+/// Width/height ratios selected by the 3-bit SizeHeader ratio field
+const SIZE_RATIOS: [(u64, u64); 7] = [(1, 1), (12, 10), (4, 3), (3, 2), (16, 9), (5, 4), (2, 1)];
+
+/// AnimationHeader of an animated codestream
+#[derive(Debug, Clone, PartialEq)]
+pub struct JxlAnimation {
+    pub ticks_per_second_numerator: u32,
+    pub ticks_per_second_denominator: u32,
+    /// 0 means the animation loops forever
+    pub loop_count: u32,
+}
+
+/// Codestream header values and metadata boxes of a JPEG XL file
+#[derive(Debug, Clone, Default)]
+pub struct JxlInfo {
+    pub width: u32,
+    pub height: u32,
+    pub animation: Option<JxlAnimation>,
+    /// Codestream level from the 'jxll' box (container files only)
+    pub codestream_level: Option<u8>,
+    /// TIFF data of the 'Exif' box and its offset in the file
+    pub exif: Option<(Vec<u8>, u64)>,
+    /// XMP packet of the 'xml ' box and its offset in the file
+    pub xmp: Option<(Vec<u8>, u64)>,
+}
+
+/// Parse a bare JPEG XL codestream or container
+pub fn parse_jxl(data: &[u8]) -> Result<JxlInfo> {
+    if data.starts_with(CODESTREAM_SIGNATURE) {
+        let mut info = JxlInfo::default();
+        parse_codestream_header(data, &mut info)?;
+        return Ok(info);
+    }
+    if !data.starts_with(CONTAINER_SIGNATURE) {
+        return Err(ExifError::InvalidFormat(
+            "Not a JPEG XL codestream or container".to_string(),
+        ));
+    }
+
+    let mut info = JxlInfo::default();
+    let mut codestream = Vec::new();
+    let mut offset = 0;
+    let mut boxes_scanned = 0;
+    while offset < data.len() && boxes_scanned < MAX_BOXES_TO_SCAN {
+        // A final box with size 0 extends to the end of the file
+        let parsed = match data.get(offset..offset + 4) {
+            Some([0, 0, 0, 0]) => {
+                let mut last = data[offset..].to_vec();
+                let len = last.len() as u32;
+                last[..4].copy_from_slice(&len.to_be_bytes());
+                parse_box_header(&last, 0).map(|(b, _)| (b, data.len()))
+            }
+            _ => parse_box_header(data, offset),
+        };
+        // Stop at a truncated box, keeping what was found before it
+        let Ok((iso_box, next_offset)) = parsed else {
+            break;
+        };
+        let content_offset = (next_offset - iso_box.data.len()) as u64;
+        match &iso_box.box_type {
+            b"jxll" => info.codestream_level = iso_box.data.first().copied(),
+            b"jxlc" => codestream = iso_box.data,
+            b"jxlp" if codestream.len() < MAX_CODESTREAM_HEADER => {
+                // 4-byte part index precedes each partial codestream
+                codestream.extend_from_slice(iso_box.data.get(4..).unwrap_or_default());
+            }
+            b"Exif" if info.exif.is_none() && iso_box.data.len() > 4 => {
+                // The box starts with the offset of the TIFF header
+                let tiff_offset = u32::from_be_bytes([
+                    iso_box.data[0],
+                    iso_box.data[1],
+                    iso_box.data[2],
+                    iso_box.data[3],
+                ]) as usize;
+                if let Some(tiff) = iso_box.data.get(4 + tiff_offset..) {
+                    let tiff_start = content_offset + 4 + tiff_offset as u64;
+                    info.exif = Some((tiff.to_vec(), tiff_start));
+                }
+            }
+            b"xml " if info.xmp.is_none() => info.xmp = Some((iso_box.data, content_offset)),
+            _ => {}
+        }
+        offset = next_offset;
+        boxes_scanned += 1;
+    }
+
+    if !codestream.starts_with(CODESTREAM_SIGNATURE) {
+        return Err(ExifError::InvalidFormat(
+            "No codestream found in JPEG XL container".to_string(),
+        ));
+    }
+    parse_codestream_header(&codestream, &mut info)?;
+    Ok(info)
+}
+
+/// JXL ImageWidth/ImageHeight, the container JXLCodestreamLevel, and the
+/// animation tags of an animated image
+pub fn create_jxl_tag_entries(info: &JxlInfo) -> Vec<TagEntry> {
+    let mut entries = vec![
+        tag("JXL", "ImageWidth", TagValue::U32(info.width)),
+        tag("JXL", "ImageHeight", TagValue::U32(info.height)),
+    ];
+    if let Some(level) = info.codestream_level {
+        entries.push(tag("Jpeg2000", "JXLCodestreamLevel", TagValue::U8(level)));
+    }
+    let animated = if info.animation.is_some() {
+        "Yes"
+    } else {
+        "No"
+    };
+    entries.push(tag("JXL", "Animated", TagValue::string(animated)));
+    if let Some(animation) = &info.animation {
+        if animation.ticks_per_second_denominator > 0 {
+            let ticks = animation.ticks_per_second_numerator as f64
+                / animation.ticks_per_second_denominator as f64;
+            entries.push(tag("JXL", "AnimationTicksPerSecond", TagValue::F64(ticks)));
+        }
+        let mut loop_count = tag(
+            "JXL",
+            "AnimationLoopCount",
+            TagValue::U32(animation.loop_count),
+        );
+        if animation.loop_count == 0 {
+            loop_count.set_print(TagValue::string("Infinite"));
+        }
+        entries.push(loop_count);
+    }
+    entries
+}
+
+fn tag(group: &str, name: &str, value: TagValue) -> TagEntry {
+    TagEntry {
+        group: group.into(),
+        group1: if group == "Jpeg2000" { "JP2" } else { group }.into(),
+        name: name.into(),
+        print: value.clone().into(),
+        value,
+        source: None,
+    }
+}
+
+/// Parse SizeHeader and the start of ImageMetadata, up to the AnimationHeader
+fn parse_codestream_header(codestream: &[u8], info: &mut JxlInfo) -> Result<()> {
+    let mut bits = BitReader::new(&codestream[CODESTREAM_SIGNATURE.len()..]);
+    let (width, height) = parse_size_header(&mut bits).ok_or_else(truncated)?;
+    info.width = width;
+    info.height = height;
+
+    // ImageMetadata: all_default, then the optional extra fields
+    let all_default = bits.read_bool().ok_or_else(truncated)?;
+    if all_default || !bits.read_bool().ok_or_else(truncated)? {
+        return Ok(());
+    }
+    let _orientation = bits.read(3).ok_or_else(truncated)?;
+    if bits.read_bool().ok_or_else(truncated)? {
+        // Intrinsic size: same layout as SizeHeader, not reported
+        parse_size_header(&mut bits).ok_or_else(truncated)?;
+    }
+    if bits.read_bool().ok_or_else(truncated)? {
+        parse_preview_header(&mut bits).ok_or_else(truncated)?;
+    }
+    if bits.read_bool().ok_or_else(truncated)? {
+        info.animation = Some(parse_animation_header(&mut bits).ok_or_else(truncated)?);
+    }
+    Ok(())
+}
+
+fn truncated() -> ExifError {
+    ExifError::InvalidFormat("Truncated JPEG XL codestream header".to_string())
+}
+
+fn parse_size_header(bits: &mut BitReader) -> Option<(u32, u32)> {
+    let small = bits.read_bool()?;
+    let read_dimension = |bits: &mut BitReader| -> Option<u64> {
+        if small {
+            Some((bits.read(5)? as u64 + 1) * 8)
+        } else {
+            bits.read_u32([(1, 9), (1, 13), (1, 18), (1, 30)])
+                .map(u64::from)
+        }
+    };
+    let height = read_dimension(bits)?;
+    let width = match bits.read(3)? {
+        0 => read_dimension(bits)?,
+        ratio => {
+            let (numerator, denominator) = SIZE_RATIOS[ratio as usize - 1];
+            height * numerator / denominator
+        }
+    };
+    Some((u32::try_from(width).ok()?, u32::try_from(height).ok()?))
+}
+
+fn parse_preview_header(bits: &mut BitReader) -> Option<()> {
+    let div8 = bits.read_bool()?;
+    let read_dimension = |bits: &mut BitReader| -> Option<u32> {
+        if div8 {
+            bits.read_u32([(16, 0), (32, 0), (1, 5), (33, 9)])
+        } else {
+            bits.read_u32([(1, 6), (65, 8), (321, 10), (1345, 12)])
+        }
+    };
+    read_dimension(bits)?;
+    if bits.read(3)? == 0 {
+        read_dimension(bits)?;
+    }
+    Some(())
+}
+
+fn parse_animation_header(bits: &mut BitReader) -> Option<JxlAnimation> {
+    Some(JxlAnimation {
+        ticks_per_second_numerator: bits.read_u32([(100, 0), (1000, 0), (1, 10), (1, 30)])?,
+        ticks_per_second_denominator: bits.read_u32([(1, 0), (1001, 0), (1, 8), (1, 10)])?,
+        loop_count: bits.read_u32([(0, 0), (0, 3), (0, 16), (0, 32)])?,
+    })
+}
+
+/// Least-significant-bit-first reader for codestream header fields
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn read(&mut self, count: u32) -> Option<u32> {
+        let mut value = 0u64;
+        for i in 0..count {
+            let byte = *self.data.get(self.position / 8)?;
+            let bit = (byte >> (self.position % 8)) & 1;
+            value |= (bit as u64) << i;
+            self.position += 1;
+        }
+        u32::try_from(value).ok()
+    }
+
+    fn read_bool(&mut self) -> Option<bool> {
+        self.read(1).map(|bit| bit == 1)
+    }
+
+    /// U32 field: a 2-bit selector picks one of four (offset, bit count)
+    /// distributions; a bit count of 0 is the constant offset
+    fn read_u32(&mut self, distributions: [(u32, u32); 4]) -> Option<u32> {
+        let (offset, count) = distributions[self.read(2)? as usize];
+        offset.checked_add(self.read(count)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pack (value, bit count) fields least significant bit first
+    fn pack_bits(fields: &[(u32, u32)]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut position = 0;
+        for &(value, count) in fields {
+            for i in 0..count {
+                if position % 8 == 0 {
+                    bytes.push(0);
+                }
+                if (value >> i) & 1 == 1 {
+                    *bytes.last_mut().unwrap() |= 1 << (position % 8);
+                }
+                position += 1;
+            }
+        }
+        bytes
+    }
+
+    fn iso_box(box_type: &[u8; 4], content: &[u8]) -> Vec<u8> {
+        let mut data = ((content.len() + 8) as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(box_type);
+        data.extend_from_slice(content);
+        data
+    }
+
+    #[test]
+    fn test_bare_codestream_small_size_with_ratio() {
+        // small, height (4+1)*8 = 40, ratio 16:9, all_default metadata
+        let mut data = CODESTREAM_SIGNATURE.to_vec();
+        data.extend(pack_bits(&[(1, 1), (4, 5), (5, 3), (1, 1)]));
+
+        let info = parse_jxl(&data).unwrap();
+        assert_eq!((info.width, info.height), (71, 40));
+        assert_eq!(info.animation, None);
+
+        let entries = create_jxl_tag_entries(&info);
+        assert_eq!(entries[0].group, "JXL");
+        assert_eq!(entries[0].value, TagValue::U32(71));
+        assert_eq!(entries[2].value, TagValue::string("No"));
+    }
+
+    #[test]
+    fn test_animated_container_with_metadata() {
+        // large sizes: height 1+u(13) = 1080, width 1+u(13) = 1920, then
+        // extra fields with orientation 1 and an animation at 1000/1001 ticks
+        // per second looping forever
+        let mut codestream = CODESTREAM_SIGNATURE.to_vec();
+        codestream.extend(pack_bits(&[
+            (0, 1),
+            (1, 2),
+            (1079, 13),
+            (0, 3),
+            (1, 2),
+            (1919, 13),
+            (0, 1),
+            (1, 1),
+            (0, 3),
+            (0, 1),
+            (0, 1),
+            (1, 1),
+            (1, 2),
+            (1, 2),
+            (0, 2),
+            (0, 1),
+        ]));
+        let mut exif = vec![0, 0, 0, 0];
+        exif.extend_from_slice(b"II*\0\x08\0\0\0\0\0");
+        let file = [
+            CONTAINER_SIGNATURE.to_vec(),
+            iso_box(b"ftyp", b"jxl \0\0\0\0jxl "),
+            iso_box(b"jxll", &[5]),
+            iso_box(b"Exif", &exif),
+            iso_box(b"xml ", b"<x:xmpmeta/>"),
+            iso_box(b"jxlp", &[&[0, 0, 0, 0][..], &codestream[..4]].concat()),
+            iso_box(b"jxlp", &[&[0x80, 0, 0, 1][..], &codestream[4..]].concat()),
+        ]
+        .concat();
+
+        let info = parse_jxl(&file).unwrap();
+        assert_eq!((info.width, info.height), (1920, 1080));
+        assert_eq!(info.codestream_level, Some(5));
+        let (xmp, xmp_offset) = info.xmp.clone().unwrap();
+        assert_eq!(xmp, b"<x:xmpmeta/>");
+        assert_eq!(&file[xmp_offset as usize..][..xmp.len()], xmp);
+        let (tiff, tiff_offset) = info.exif.clone().unwrap();
+        assert!(tiff.starts_with(b"II*\0"));
+        assert_eq!(&file[tiff_offset as usize..][..4], b"II*\0");
+        assert_eq!(
+            info.animation,
+            Some(JxlAnimation {
+                ticks_per_second_numerator: 1000,
+                ticks_per_second_denominator: 1001,
+                loop_count: 0,
+            })
+        );
+
+        let entries = create_jxl_tag_entries(&info);
+        let loops = entries
+            .iter()
+            .find(|e| e.name == "AnimationLoopCount")
+            .unwrap();
+        assert_eq!(loops.print(), &TagValue::string("Infinite"));
+        let level = entries
+            .iter()
+            .find(|e| e.name == "JXLCodestreamLevel")
+            .unwrap();
+        assert_eq!(level.group1, "JP2");
+    }
+
+    #[test]
+    fn test_rejects_other_data() {
+        assert!(parse_jxl(b"\xff\xd8\xff\xe0").is_err());
+        assert!(parse_jxl(CODESTREAM_SIGNATURE).is_err());
+    }
+}
@@ -0,0 +1,582 @@
+//! Matroska / WebM (MKV) EBML reader
+//!
+//! Walks the EBML element tree with `Seek`, reading the small Info, Tracks and
+//! Chapters masters into memory and seeking past Clusters (the media data).
+//! Element IDs are the full EBML IDs, marker bits included; ExifTool's
+//! Matroska::Main table keys them without the length marker (TimecodeScale is
+//! 0x2ad7b1 here, 0xad7b1 in Matroska.pm), which is why the generated table
+//! isn't used for routing.
+//!
+//! ExifTool reference: Matroska.pm ProcessMKV. Scope: DocType, TimecodeScale,
+//! Title, MuxingApp, WritingApp, Duration and the first video track's
+//! ImageWidth/ImageHeight, plus the chapter and subtitle summary of
+//! [`super::media_tracks`]. Chapters come from the first EditionEntry; hidden
+//! and nested chapters are skipped.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use tracing::trace;
+
+use super::media_tracks::{self, Chapter, MAX_CHAPTERS};
+use crate::implementations::quicktime::convert_duration;
+use crate::types::{Result, TagEntry, TagValue};
+
+/// ExifTool group (G0/G1) of Matroska tags
+const GROUP: &str = "Matroska";
+
+/// Largest master element (Info, Tracks, Chapters) read into memory
+const MAX_MASTER_SIZE: u64 = 4 * 1024 * 1024;
+
+/// Track language when a TrackEntry has no Language element (Matroska spec)
+const DEFAULT_LANGUAGE: &str = "eng";
+
+// EBML element IDs
+const EBML: u32 = 0x1a45_dfa3;
+const DOC_TYPE: u32 = 0x4282;
+const SEGMENT: u32 = 0x1853_8067;
+const CLUSTER: u32 = 0x1f43_b675;
+const INFO: u32 = 0x1549_a966;
+const TIMECODE_SCALE: u32 = 0x2a_d7b1;
+const DURATION: u32 = 0x4489;
+const TITLE: u32 = 0x7ba9;
+const MUXING_APP: u32 = 0x4d80;
+const WRITING_APP: u32 = 0x5741;
+const TRACKS: u32 = 0x1654_ae6b;
+const TRACK_ENTRY: u32 = 0xae;
+const TRACK_TYPE: u32 = 0x83;
+const TRACK_LANGUAGE: u32 = 0x22_b59c;
+const TRACK_LANGUAGE_BCP47: u32 = 0x22_b59d;
+const VIDEO: u32 = 0xe0;
+const PIXEL_WIDTH: u32 = 0xb0;
+const PIXEL_HEIGHT: u32 = 0xba;
+const CHAPTERS: u32 = 0x1043_a770;
+const EDITION_ENTRY: u32 = 0x45b9;
+const CHAPTER_ATOM: u32 = 0xb6;
+const CHAPTER_TIME_START: u32 = 0x91;
+const CHAPTER_FLAG_HIDDEN: u32 = 0x98;
+const CHAPTER_DISPLAY: u32 = 0x80;
+const CHAPTER_STRING: u32 = 0x85;
+
+/// Matroska TrackType values (Matroska::Main TrackType PrintConv)
+const TRACK_TYPE_VIDEO: u64 = 1;
+const TRACK_TYPE_SUBTITLE: u64 = 17;
+
+/// Read the metadata of a Matroska or WebM file as `Matroska:*` TagEntries
+///
+/// Best-effort like the QuickTime walker: malformed elements end the walk and
+/// whatever was decoded so far is returned. Only reader I/O errors propagate.
+pub fn extract_matroska_metadata<R: Read + Seek>(reader: &mut R) -> Result<Vec<TagEntry>> {
+    let file_end = reader.seek(SeekFrom::End(0))?;
+    let mut mkv = MatroskaReader {
+        reader,
+        info: Info::default(),
+        tracks: Vec::new(),
+        chapters: Vec::new(),
+        doc_type: None,
+    };
+    mkv.process_top_level(file_end)?;
+    Ok(mkv.into_entries())
+}
+
+/// Segment Info values
+#[derive(Debug, Default)]
+struct Info {
+    timecode_scale: Option<u64>,
+    duration: Option<f64>,
+    title: Option<String>,
+    muxing_app: Option<String>,
+    writing_app: Option<String>,
+}
+
+/// What the summary needs from one TrackEntry
+#[derive(Debug, Default)]
+struct Track {
+    track_type: u64,
+    language: Option<String>,
+    language_bcp47: Option<String>,
+    width: Option<u64>,
+    height: Option<u64>,
+}
+
+struct MatroskaReader<'a, R: Read + Seek> {
+    reader: &'a mut R,
+    info: Info,
+    tracks: Vec<Track>,
+    chapters: Vec<Chapter>,
+    doc_type: Option<String>,
+}
+
+impl<R: Read + Seek> MatroskaReader<'_, R> {
+    /// The EBML header, then the Segment's children
+    fn process_top_level(&mut self, file_end: u64) -> Result<()> {
+        let mut pos = 0;
+        while let Some(header) = self.read_header(pos, file_end)? {
+            match header.id {
+                EBML => {
+                    let data = self.read_body(&header)?;
+                    self.doc_type = children(&data)
+                        .find(|(id, _)| *id == DOC_TYPE)
+                        .map(|(_, body)| text(body));
+                }
+                SEGMENT => return self.process_segment(header.body_start, header.end),
+                _ => {}
+            }
+            if header.unknown_size {
+                break;
+            }
+            pos = header.end;
+        }
+        Ok(())
+    }
+
+    /// Segment children: decode Info, Tracks and Chapters, seek past the rest
+    fn process_segment(&mut self, start: u64, end: u64) -> Result<()> {
+        let mut pos = start;
+        while let Some(header) = self.read_header(pos, end)? {
+            match header.id {
+                INFO | TRACKS | CHAPTERS if !header.unknown_size => {
+                    let data = self.read_body(&header)?;
+                    match header.id {
+                        INFO => self.decode_info(&data),
+                        TRACKS => self.decode_tracks(&data),
+                        _ if self.chapters.is_empty() => self.decode_chapters(&data),
+                        _ => {}
+                    }
+                }
+                CLUSTER => trace!("matroska: seeking past Cluster at {}", header.body_start),
+                _ => trace!("matroska: skipping element 0x{:x}", header.id),
+            }
+            if header.unknown_size {
+                // A live-streamed Cluster has no size: the rest can't be skipped
+                break;
+            }
+            pos = header.end;
+        }
+        Ok(())
+    }
+
+    fn decode_info(&mut self, data: &[u8]) {
+        for (id, body) in children(data) {
+            match id {
+                TIMECODE_SCALE => self.info.timecode_scale = Some(unsigned(body)),
+                DURATION => self.info.duration = float(body),
+                TITLE => self.info.title = Some(text(body)),
+                MUXING_APP => self.info.muxing_app = Some(text(body)),
+                WRITING_APP => self.info.writing_app = Some(text(body)),
+                _ => {}
+            }
+        }
+    }
+
+    fn decode_tracks(&mut self, data: &[u8]) {
+        for (_, entry) in children(data).filter(|(id, _)| *id == TRACK_ENTRY) {
+            let mut track = Track::default();
+            for (id, body) in children(entry) {
+                match id {
+                    TRACK_TYPE => track.track_type = unsigned(body),
+                    TRACK_LANGUAGE => track.language = Some(text(body)),
+                    TRACK_LANGUAGE_BCP47 => track.language_bcp47 = Some(text(body)),
+                    VIDEO => {
+                        for (id, body) in children(body) {
+                            match id {
+                                PIXEL_WIDTH => track.width = Some(unsigned(body)),
+                                PIXEL_HEIGHT => track.height = Some(unsigned(body)),
+                                _ => {}
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            self.tracks.push(track);
+        }
+    }
+
+    /// Top-level chapters of the first EditionEntry
+    fn decode_chapters(&mut self, data: &[u8]) {
+        let Some((_, edition)) = children(data).find(|(id, _)| *id == EDITION_ENTRY) else {
+            return;
+        };
+        for (_, atom) in children(edition).filter(|(id, _)| *id == CHAPTER_ATOM) {
+            let mut start = None;
+            let mut title = None;
+            let mut hidden = false;
+            for (id, body) in children(atom) {
+                match id {
+                    // Nanoseconds, independent of TimecodeScale
+                    CHAPTER_TIME_START => start = Some(unsigned(body) as f64 / 1e9),
+                    CHAPTER_FLAG_HIDDEN => hidden = unsigned(body) != 0,
+                    CHAPTER_DISPLAY if title.is_none() => {
+                        title = children(body)
+                            .find(|(id, _)| *id == CHAPTER_STRING)
+                            .map(|(_, body)| text(body));
+                    }
+                    _ => {}
+                }
+            }
+            if let (Some(start), false) = (start, hidden) {
+                self.chapters.push(Chapter {
+                    start,
+                    title: title.unwrap_or_default(),
+                });
+            }
+            if self.chapters.len() >= MAX_CHAPTERS {
+                break;
+            }
+        }
+    }
+
+    /// Read the element header at `pos`, or `None` at the end or on a malformed header
+    fn read_header(&mut self, pos: u64, end: u64) -> Result<Option<Header>> {
+        if pos >= end {
+            return Ok(None);
+        }
+        self.reader.seek(SeekFrom::Start(pos))?;
+        let mut buf = [0u8; 12];
+        let available = (end - pos).min(buf.len() as u64) as usize;
+        let mut filled = 0;
+        while filled < available {
+            match self.reader.read(&mut buf[filled..available])? {
+                0 => break,
+                n => filled += n,
+            }
+        }
+        let Some((id, id_len)) = read_vint(&buf[..filled], true) else {
+            return Ok(None);
+        };
+        let Some((size, size_len)) = read_vint(&buf[id_len..filled], false) else {
+            return Ok(None);
+        };
+        let body_start = pos + (id_len + size_len) as u64;
+        let unknown_size = size == (1u64 << (7 * size_len)) - 1;
+        let body_end = if unknown_size {
+            end
+        } else {
+            match body_start.checked_add(size) {
+                Some(body_end) if body_end <= end => body_end,
+                _ => {
+                    trace!("matroska: element 0x{:x} extends past its parent", id);
+                    return Ok(None);
+                }
+            }
+        };
+        Ok(Some(Header {
+            id: id as u32,
+            body_start,
+            end: body_end,
+            unknown_size,
+        }))
+    }
+
+    /// Read an element body into memory; oversized bodies read as empty
+    fn read_body(&mut self, header: &Header) -> Result<Vec<u8>> {
+        let len = header.end - header.body_start;
+        if len > MAX_MASTER_SIZE {
+            trace!(
+                "matroska: element 0x{:x} too large ({len} bytes)",
+                header.id
+            );
+            return Ok(Vec::new());
+        }
+        self.reader.seek(SeekFrom::Start(header.body_start))?;
+        let mut buf = vec![0u8; len as usize];
+        match self.reader.read_exact(&mut buf) {
+            Ok(()) => Ok(buf),
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn into_entries(self) -> Vec<TagEntry> {
+        let mut entries = Vec::new();
+        let mut add = |name: &str, value: TagValue, print: TagValue| {
+            entries.push(TagEntry {
+                group: GROUP.into(),
+                group1: GROUP.into(),
+                name: name.into(),
+                value,
+                print: print.into(),
+                source: None,
+            });
+        };
+        if let Some(doc_type) = self.doc_type {
+            add("DocType", doc_type.clone().into(), doc_type.into());
+        }
+        // Matroska.pm TimecodeScale: ValueConv '$val / 1e9',
+        // PrintConv '($val * 1000) . " ms"'
+        let scale = self.info.timecode_scale;
+        if let Some(scale) = scale {
+            let seconds = scale as f64 / 1e9;
+            add(
+                "TimecodeScale",
+                TagValue::F64(seconds),
+                format!("{} ms", seconds * 1000.0).into(),
+            );
+        }
+        for (name, value) in [
+            ("Title", self.info.title),
+            ("MuxingApp", self.info.muxing_app),
+            ("WritingApp", self.info.writing_app),
+        ] {
+            if let Some(value) = value {
+                add(name, value.clone().into(), value.into());
+            }
+        }
+        // Matroska.pm Duration: ValueConv
+        // '$$self{TimecodeScale} ? $val * $$self{TimecodeScale} / 1e9 : $val / 1000',
+        // PrintConv '$$self{TimecodeScale} ? ConvertDuration($val) : $val'
+        if let Some(duration) = self.info.duration {
+            match scale {
+                Some(scale) => {
+                    let seconds = duration * scale as f64 / 1e9;
+                    add(
+                        "Duration",
+                        TagValue::F64(seconds),
+                        convert_duration(seconds).into(),
+                    );
+                }
+                None => {
+                    let value = TagValue::F64(duration / 1000.0);
+                    add("Duration", value.clone(), value);
+                }
+            }
+        }
+        if let Some(video) = self
+            .tracks
+            .iter()
+            .find(|track| track.track_type == TRACK_TYPE_VIDEO)
+        {
+            for (name, value) in [("ImageWidth", video.width), ("ImageHeight", video.height)] {
+                if let Some(value) = value {
+                    add(name, TagValue::U64(value), TagValue::U64(value));
+                }
+            }
+        }
+
+        let subtitle_languages: Vec<String> = self
+            .tracks
+            .into_iter()
+            .filter(|track| track.track_type == TRACK_TYPE_SUBTITLE)
+            .map(|track| {
+                track
+                    .language_bcp47
+                    .or(track.language)
+                    .filter(|language| !language.is_empty())
+                    .unwrap_or_else(|| DEFAULT_LANGUAGE.to_string())
+            })
+            .collect();
+        entries.extend(media_tracks::chapter_tags(GROUP, &self.chapters));
+        entries.extend(media_tracks::subtitle_tags(GROUP, &subtitle_languages));
+        entries
+    }
+}
+
+/// Position of one EBML element
+struct Header {
+    id: u32,
+    body_start: u64,
+    end: u64,
+    /// All size bits set: the element runs to the end of its parent
+    unknown_size: bool,
+}
+
+/// Decode an EBML variable-length integer, returning it with its length
+///
+/// IDs keep the length marker bit; sizes have it removed. IDs are at most 4
+/// bytes long and sizes 8.
+fn read_vint(data: &[u8], keep_marker: bool) -> Option<(u64, usize)> {
+    let first = *data.first()?;
+    let len = first.leading_zeros() as usize + 1;
+    if len > if keep_marker { 4 } else { 8 } {
+        return None;
+    }
+    let bytes = data.get(..len)?;
+    let first = if keep_marker {
+        first as u64
+    } else {
+        (first as u64) & (0xff >> len)
+    };
+    let value = bytes[1..]
+        .iter()
+        .fold(first, |value, &byte| (value << 8) | byte as u64);
+    Some((value, len))
+}
+
+/// Child elements of an in-memory master element; stops at malformed data
+fn children(data: &[u8]) -> impl Iterator<Item = (u32, &[u8])> {
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        let (id, id_len) = read_vint(data.get(pos..)?, true)?;
+        let (size, size_len) = read_vint(data.get(pos + id_len..)?, false)?;
+        let start = pos + id_len + size_len;
+        let end = start.checked_add(usize::try_from(size).ok()?)?;
+        let body = data.get(start..end)?;
+        pos = end;
+        Some((id as u32, body))
+    })
+}
+
+/// Big-endian unsigned integer of up to 8 bytes
+fn unsigned(body: &[u8]) -> u64 {
+    body.iter()
+        .take(8)
+        .fold(0, |value, &byte| (value << 8) | byte as u64)
+}
+
+/// 4- or 8-byte big-endian float
+fn float(body: &[u8]) -> Option<f64> {
+    match body.len() {
+        4 => Some(f32::from_be_bytes(body.try_into().ok()?) as f64),
+        8 => Some(f64::from_be_bytes(body.try_into().ok()?)),
+        _ => None,
+    }
+}
+
+/// String or UTF-8 value, without trailing NUL padding
+fn text(body: &[u8]) -> String {
+    String::from_utf8_lossy(body)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Encode an element with a 1-byte size (bodies under 127 bytes) or an
+    /// 8-byte size
+    fn element(id: u32, body: &[u8]) -> Vec<u8> {
+        let mut data: Vec<u8> = id
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|&byte| byte == 0)
+            .collect();
+        if body.len() < 127 {
+            data.push(0x80 | body.len() as u8);
+        } else {
+            data.push(0x01);
+            data.extend_from_slice(&(body.len() as u64).to_be_bytes()[1..]);
+        }
+        data.extend_from_slice(body);
+        data
+    }
+
+    fn chapter(start_ns: u64, title: &str, hidden: bool) -> Vec<u8> {
+        let display = element(CHAPTER_DISPLAY, &element(CHAPTER_STRING, title.as_bytes()));
+        let mut body = element(CHAPTER_TIME_START, &start_ns.to_be_bytes());
+        body.extend(element(CHAPTER_FLAG_HIDDEN, &[hidden as u8]));
+        body.extend(display);
+        element(CHAPTER_ATOM, &body)
+    }
+
+    fn track(track_type: u8, extra: &[u8]) -> Vec<u8> {
+        element(
+            TRACK_ENTRY,
+            &[element(TRACK_TYPE, &[track_type]), extra.to_vec()].concat(),
+        )
+    }
+
+    fn sample_file() -> Vec<u8> {
+        let header = element(EBML, &element(DOC_TYPE, b"webm"));
+        let info = element(
+            INFO,
+            &[
+                element(TIMECODE_SCALE, &[0x0f, 0x42, 0x40]),
+                element(DURATION, &90_500.0f64.to_be_bytes()),
+                element(MUXING_APP, b"libebml"),
+            ]
+            .concat(),
+        );
+        let video = element(
+            VIDEO,
+            &[
+                element(PIXEL_WIDTH, &[0x07, 0x80]),
+                element(PIXEL_HEIGHT, &[0x04, 0x38]),
+            ]
+            .concat(),
+        );
+        let tracks = element(
+            TRACKS,
+            &[
+                track(1, &video),
+                track(2, &element(TRACK_LANGUAGE, b"jpn")),
+                track(17, &[]),
+                track(17, &element(TRACK_LANGUAGE_BCP47, b"pt-BR")),
+            ]
+            .concat(),
+        );
+        let cluster = element(CLUSTER, &[0u8; 200]);
+        let chapters = element(
+            CHAPTERS,
+            &element(
+                EDITION_ENTRY,
+                &[
+                    chapter(0, "Opening", false),
+                    chapter(5_000_000_000, "Hidden", true),
+                    chapter(61_000_000_000, "Part 2", false),
+                ]
+                .concat(),
+            ),
+        );
+        let segment = element(SEGMENT, &[info, tracks, cluster, chapters].concat());
+        [header, segment].concat()
+    }
+
+    #[test]
+    fn reads_info_tracks_and_chapters_after_clusters() {
+        let tags = extract_matroska_metadata(&mut Cursor::new(sample_file())).unwrap();
+        let get = |name: &str| {
+            tags.iter()
+                .find(|t| t.name == name)
+                .unwrap_or_else(|| panic!("missing {name}"))
+        };
+        assert_eq!(get("DocType").value, TagValue::string("webm"));
+        assert_eq!(get("TimecodeScale").print(), &TagValue::string("1 ms"));
+        assert_eq!(get("MuxingApp").value, TagValue::string("libebml"));
+        assert_eq!(get("Duration").value, TagValue::F64(90.5));
+        assert_eq!(get("Duration").print(), &TagValue::string("0:01:31"));
+        assert_eq!(get("ImageWidth").value, TagValue::U64(1920));
+        assert_eq!(get("ImageHeight").value, TagValue::U64(1080));
+
+        assert_eq!(get("ChapterCount").value, TagValue::U32(2));
+        assert_eq!(
+            get("ChapterTitles").value,
+            TagValue::Array(vec![
+                TagValue::string("Opening"),
+                TagValue::string("Part 2")
+            ])
+        );
+        assert_eq!(
+            get("ChapterStartTimes").value,
+            TagValue::F64Array(vec![0.0, 61.0])
+        );
+
+        assert_eq!(get("SubtitleTrackCount").value, TagValue::U32(2));
+        assert_eq!(
+            get("SubtitleLanguages").value,
+            TagValue::Array(vec![TagValue::string("eng"), TagValue::string("pt-BR")])
+        );
+        assert!(tags.iter().all(|t| t.group == "Matroska"));
+    }
+
+    #[test]
+    fn truncated_file_does_not_panic() {
+        let data = sample_file();
+        for len in 0..data.len() {
+            extract_matroska_metadata(&mut Cursor::new(&data[..len])).unwrap();
+        }
+    }
+
+    #[test]
+    fn vint_decoding() {
+        assert_eq!(read_vint(&[0x81], false), Some((1, 1)));
+        assert_eq!(read_vint(&[0x40, 0x02], false), Some((2, 2)));
+        assert_eq!(
+            read_vint(&[0x1a, 0x45, 0xdf, 0xa3], true),
+            Some((0x1a45_dfa3, 4))
+        );
+        assert_eq!(read_vint(&[0x00], false), None);
+        assert_eq!(read_vint(&[0x40], false), None);
+    }
+}
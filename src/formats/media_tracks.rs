@@ -0,0 +1,127 @@
+//! Chapter and subtitle track summary tags for video containers
+//!
+//! ExifTool has no single tag for either: QuickTime chapter titles are text
+//! samples only extracted with `-ee`, and Matroska chapters and tracks come out
+//! as one tag per element. Media libraries want the list in one place, so the
+//! QuickTime and Matroska readers summarize them with these exif-oxide tags:
+//!
+//! - `ChapterCount`, `ChapterTitles` and `ChapterStartTimes` (seconds, printed
+//!   with ConvertDuration)
+//! - `SubtitleTrackCount` and `SubtitleLanguages` (ISO 639-2 codes, or BCP 47
+//!   tags in Matroska files that have them, `und` when unknown)
+//!
+//! No tags are added for containers without chapters or subtitle tracks.
+
+use crate::implementations::quicktime::convert_duration;
+use crate::types::{TagEntry, TagValue};
+
+/// Largest number of chapters read from one file
+pub(crate) const MAX_CHAPTERS: usize = 1000;
+
+/// Language code of a track that doesn't record one
+pub(crate) const UNDETERMINED_LANGUAGE: &str = "und";
+
+/// One chapter: start time in seconds and title
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Chapter {
+    pub start: f64,
+    pub title: String,
+}
+
+/// ChapterCount, ChapterTitles and ChapterStartTimes tags in `group`
+pub(crate) fn chapter_tags(group: &str, chapters: &[Chapter]) -> Vec<TagEntry> {
+    if chapters.is_empty() {
+        return Vec::new();
+    }
+    let titles = chapters
+        .iter()
+        .map(|chapter| TagValue::String(chapter.title.clone()))
+        .collect();
+    let starts = chapters.iter().map(|chapter| chapter.start).collect();
+    let printed_starts = chapters
+        .iter()
+        .map(|chapter| TagValue::String(convert_duration(chapter.start)))
+        .collect();
+    vec![
+        tag(group, "ChapterCount", TagValue::U32(chapters.len() as u32)),
+        tag(group, "ChapterTitles", TagValue::Array(titles)),
+        TagEntry {
+            group: group.into(),
+            group1: group.into(),
+            name: "ChapterStartTimes".into(),
+            value: TagValue::F64Array(starts),
+            print: TagValue::Array(printed_starts).into(),
+            source: None,
+        },
+    ]
+}
+
+/// SubtitleTrackCount and SubtitleLanguages tags in `group`, one language per track
+pub(crate) fn subtitle_tags(group: &str, languages: &[String]) -> Vec<TagEntry> {
+    if languages.is_empty() {
+        return Vec::new();
+    }
+    let list = languages
+        .iter()
+        .map(|language| TagValue::String(language.clone()))
+        .collect();
+    vec![
+        tag(
+            group,
+            "SubtitleTrackCount",
+            TagValue::U32(languages.len() as u32),
+        ),
+        tag(group, "SubtitleLanguages", TagValue::Array(list)),
+    ]
+}
+
+fn tag(group: &str, name: &str, value: TagValue) -> TagEntry {
+    TagEntry {
+        group: group.into(),
+        group1: group.into(),
+        name: name.into(),
+        print: value.clone().into(),
+        value,
+        source: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chapter_tags() {
+        let chapters = [
+            Chapter {
+                start: 0.0,
+                title: "Intro".into(),
+            },
+            Chapter {
+                start: 95.0,
+                title: "Main".into(),
+            },
+        ];
+        let tags = chapter_tags("QuickTime", &chapters);
+        assert_eq!(tags[0].value, TagValue::U32(2));
+        assert_eq!(
+            tags[1].value,
+            TagValue::Array(vec![TagValue::string("Intro"), TagValue::string("Main")])
+        );
+        assert_eq!(tags[2].value, TagValue::F64Array(vec![0.0, 95.0]));
+        assert_eq!(
+            tags[2].print(),
+            &TagValue::Array(vec![TagValue::string("0 s"), TagValue::string("0:01:35")])
+        );
+        assert!(chapter_tags("QuickTime", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_subtitle_tags() {
+        let tags = subtitle_tags("Matroska", &["eng".into(), "fre".into()]);
+        assert_eq!(tags[0].name, "SubtitleTrackCount");
+        assert_eq!(tags[0].value, TagValue::U32(2));
+        assert_eq!(tags[1].group, "Matroska");
+        assert!(subtitle_tags("Matroska", &[]).is_empty());
+    }
+}
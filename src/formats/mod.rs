@@ -3,14 +3,38 @@
 //! This module handles different image file formats and extracts
 //! metadata from each according to format-specific requirements.
 
+#[cfg(feature = "video")]
+mod audio;
 mod avif;
 mod detection;
+mod embedded_images;
 mod gif;
+mod handler;
+mod insta360;
 mod iptc;
 mod jpeg;
+mod jxl;
+#[cfg(feature = "video")]
+mod matroska;
+#[cfg(feature = "video")]
+mod media_tracks;
+#[cfg(feature = "xmp")]
+mod motion_photo;
+#[cfg(feature = "documents")]
+mod ooxml;
+mod plist;
 mod png;
+#[cfg(feature = "video")]
 mod quicktime;
+mod quicktime_dates;
+mod special_files;
 mod tiff;
+#[cfg(windows)]
+mod windows;
+#[cfg(feature = "xattr")]
+mod xattr;
+#[cfg(feature = "documents")]
+mod zip;
 
 pub use avif::{
     create_avif_tag_entries, extract_avif_dimensions, extract_heic_dimensions_primary_item,
@@ -19,21 +43,44 @@ pub use avif::{
 pub use detection::{
     detect_file_format, detect_file_format_from_path, get_format_properties, FileFormat,
 };
+pub use embedded_images::{
+    binary_tag_value, embedded_image_info, embedded_image_location, embedded_image_source_tags,
+    extract_binary_tag, extract_embedded_image, EmbeddedImage, EmbeddedImageInfo,
+};
 pub use gif::{create_gif_tag_entries, parse_gif_screen_descriptor, ScreenDescriptor};
+pub use handler::{
+    register_format_handler, registered_format_handlers, unregister_format_handler, FormatHandler,
+    ReadSeek,
+};
+pub use insta360::extract_insta360_trailer;
+pub(crate) use iptc::RESOURCE_SIGNATURES;
 pub use iptc::{parse_iptc_from_app13, parse_iptc_metadata};
 pub use jpeg::{
-    extract_jpeg_exif, extract_jpeg_iptc, extract_jpeg_xmp, hash_jpeg_scan_data,
-    scan_jpeg_segments, JpegSegment, JpegSegmentInfo, SofData,
+    extract_jpeg_exif, extract_jpeg_icc_profile, extract_jpeg_iptc, extract_jpeg_xmp,
+    extract_jpeg_xmp_with_warnings, hash_jpeg_scan_data, locate_jpeg_xmp, scan_jpeg_segments,
+    JpegSegment, JpegSegmentInfo, JpegXmp, SofData,
 };
+pub use jxl::{create_jxl_tag_entries, parse_jxl, JxlAnimation, JxlInfo};
+#[cfg(feature = "xmp")]
+pub use motion_photo::{motion_photo_video_location, MOTION_PHOTO_VIDEO};
+pub use plist::parse_binary_plist;
 pub use png::{parse_png_ihdr, IhdrData};
-pub use tiff::{extract_tiff_exif, extract_tiff_xmp, get_tiff_endianness, validate_tiff_format};
+pub use special_files::check_regular_file;
+pub use tiff::{
+    extract_tiff_exif, extract_tiff_xmp, get_tiff_endianness, locate_tiff_xmp, validate_tiff_format,
+};
+#[cfg(feature = "xattr")]
+pub use xattr::{read_xattr_tags, xattr_tag};
 
 use crate::exif::ExifReader;
-use crate::file_detection::FileTypeDetector;
-use crate::types::{ExifData, FilterOptions, Result, TagEntry, TagValue};
+use crate::types::{
+    ExifData, ExtractionWarning, FilterOptions, Result, ScanDepth, SymlinkPolicy, TagEntry,
+    TagValue, Truncation, XmpPacketLocation, XmpProvenance,
+};
+#[cfg(feature = "xmp")]
 use crate::xmp::XmpProcessor;
 use indexmap::IndexMap;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::{BufReader, Read, Seek, SeekFrom};
 use std::path::Path;
@@ -49,9 +96,22 @@ use tracing::debug;
 /// # Arguments
 /// * `path` - Path to the image file
 /// * `show_missing` - Include missing implementation information in output
-/// * `show_warnings` - Include parsing warnings in output  
+/// * `show_warnings` - Also report the parsers' diagnostic warnings in [`ExifData::warnings`]
+///   (parse failures are always reported)
 /// * `filter_options` - Optional filtering and formatting configuration (None = extract all tags)
 ///
+/// # Performance
+///
+/// One-time setup happens on the first call and is shared afterwards: the
+/// conversion registry ([`crate::init`], a no-op once done), the generated
+/// lookup tables, the RAW handlers ([`RawProcessor::shared`](crate::raw::RawProcessor::shared))
+/// and the XMP namespace tables. Per call, the fixed cost is opening the
+/// file, reading the 1KB detection buffer ([`FileTypeDetector`](crate::FileTypeDetector)
+/// holds no state), cloning the filter options, and looking up the user
+/// config and registered format handlers; an [`XmpProcessor`](crate::xmp::XmpProcessor)
+/// copies only the user config's XMP namespaces. Everything else scales with
+/// the metadata in the file.
+///
 /// # Examples
 /// ```no_run
 /// use std::path::Path;
@@ -73,9 +133,29 @@ pub fn extract_metadata(
 ) -> Result<ExifData> {
     // Ensure conversions are registered
     crate::init();
+    let _span = tracing::info_span!("extract_metadata", path = %path.display()).entered();
+    let metrics = crate::metrics::ExtractionTimer::start();
 
     // Use default filter options if none provided (backward compatibility)
     let filter_opts = filter_options.clone().unwrap_or_default();
+    // Requested composites need their source tags extracted even when the
+    // filter doesn't name them; the output filter below still drops them
+    let extraction_opts = filter_opts.with_composite_sources();
+    // Per-stage timing, recorded until the collector is finished or dropped
+    let stats = filter_opts.stats.then(crate::stats::StatsCollector::start);
+
+    if filter_opts.symlinks == SymlinkPolicy::Report {
+        if let Ok(link_metadata) = std::fs::symlink_metadata(path) {
+            if link_metadata.file_type().is_symlink() {
+                let exif_data =
+                    special_files::symlink_exif_data(path, &link_metadata, &filter_opts);
+                metrics.finish("LINK", &exif_data.warnings);
+                return Ok(exif_data);
+            }
+        }
+    }
+    // Named pipes and devices would block or never end
+    check_regular_file(path)?;
 
     // Create image data hasher if requested
     // ExifTool: lib/Image/ExifTool.pm:2766-2780 - hash object creation
@@ -85,27 +165,38 @@ pub fn extract_metadata(
             "Creating ImageDataHasher with algorithm {:?}",
             filter_opts.image_hash_type
         );
-        Some(crate::hash::ImageDataHasher::new(
-            filter_opts.image_hash_type,
-        ))
+        let hasher = crate::hash::ImageDataHasher::new(filter_opts.image_hash_type);
+        Some(match filter_opts.image_hash_chunk_size {
+            Some(chunk_size) => hasher.with_chunk_size(chunk_size),
+            None => hasher,
+        })
     } else {
         None
     };
 
     // PERFORMANCE OPTIMIZATION: Check if only File group tags are requested
     // This allows early return without expensive format-specific parsing
-    if filter_opts.is_file_group_only() {
+    if extraction_opts.is_file_group_only() {
         debug!("File-only request detected - skipping format-specific parsing for performance");
-        return extract_file_tags_only(path, show_missing, &filter_opts);
+        let mut exif_data = extract_file_tags_only(path, show_missing, &extraction_opts)?;
+        exif_data.stats = stats.map(|stats| stats.finish(&exif_data.tags));
+        let file_type = exif_data
+            .get_tag_by_group("File", "FileType")
+            .and_then(|tag| tag.value.as_string())
+            .unwrap_or("unknown");
+        metrics.finish(file_type, &exif_data.warnings);
+        return Ok(exif_data);
     }
 
     // Open file with buffered reading for performance
     let file = File::open(path)?;
-    let mut reader = BufReader::new(file);
+    let mut reader = BufReader::new(crate::stats::CountingReader::new(file));
 
-    // Detect file type using the new ExifTool-compatible detector
-    let detector = FileTypeDetector::new();
-    let detection_result = detector.detect_file_type(path, &mut reader)?;
+    // Detect file type using the new ExifTool-compatible detector, then any
+    // registered format handlers
+    let detection_stage = crate::stats::stage("Detection");
+    let detection_result = handler::detect_file_type(path, &mut reader)?;
+    drop(detection_stage);
 
     // Get actual file metadata
     let file_metadata = std::fs::metadata(path)?;
@@ -113,80 +204,96 @@ pub fn extract_metadata(
 
     let mut tags = IndexMap::new();
     let mut tag_entries = Vec::new();
+    let mut warnings: Vec<ExtractionWarning> = extension_mismatch_warning(path, &detection_result)
+        .into_iter()
+        .collect();
+    // Where the file ended mid-structure, if it did
+    let mut truncation: Option<Truncation> = None;
+    // Where the XMP packet is stored, reported with the tag sources
+    #[cfg_attr(not(feature = "xmp"), allow(unused_mut))]
+    let mut xmp_packet: Option<XmpPacketLocation> = None;
+    // xmpMM document IDs and history, kept out of the flattened XMP tags
+    #[cfg_attr(not(feature = "xmp"), allow(unused_mut))]
+    let mut xmp_provenance: Option<XmpProvenance> = None;
 
     // Basic file information (now real data) - create as TagEntry objects with filtering
-    if filter_opts.should_extract_tag("FileName", "File") {
+    if extraction_opts.should_extract_tag("FileName", "File") {
         let filename = path
             .file_name()
             .unwrap_or_default()
             .to_string_lossy()
             .to_string();
         tag_entries.push(TagEntry {
-            group: "File".to_string(),
-            group1: "System".to_string(),
-            name: "FileName".to_string(),
+            group: "File".into(),
+            group1: "System".into(),
+            name: "FileName".into(),
             value: TagValue::String(filename.clone()),
-            print: TagValue::String(filename),
+            print: TagValue::String(filename).into(),
+            source: None,
         });
     }
 
-    if filter_opts.should_extract_tag("Directory", "File") {
+    if extraction_opts.should_extract_tag("Directory", "File") {
         let directory = path
             .parent()
             .unwrap_or_else(|| Path::new("."))
             .to_string_lossy()
             .to_string();
         tag_entries.push(TagEntry {
-            group: "File".to_string(),
-            group1: "System".to_string(),
-            name: "Directory".to_string(),
+            group: "File".into(),
+            group1: "System".into(),
+            name: "Directory".into(),
             value: TagValue::String(directory.clone()),
-            print: TagValue::String(directory),
+            print: TagValue::String(directory).into(),
+            source: None,
         });
     }
 
     // FileSize - return raw bytes as per user requirement
     // Store as string for the numeric value (ExifTool compatibility)
-    if filter_opts.should_extract_tag("FileSize", "File") {
+    if extraction_opts.should_extract_tag("FileSize", "File") {
         tag_entries.push(TagEntry {
-            group: "File".to_string(),
-            group1: "System".to_string(),
-            name: "FileSize".to_string(),
+            group: "File".into(),
+            group1: "System".into(),
+            name: "FileSize".into(),
             value: TagValue::U64(file_size),
-            print: TagValue::U64(file_size),
+            print: TagValue::U64(file_size).into(),
+            source: None,
         });
     }
 
     // Format file modification time to match ExifTool format: "YYYY:MM:DD HH:MM:SS±TZ:TZ"
     // ExifTool.pm formats this as local time with timezone offset
-    if filter_opts.should_extract_tag("FileModifyDate", "File") {
+    if extraction_opts.should_extract_tag("FileModifyDate", "File") {
         if let Ok(modified) = file_metadata.modified() {
             use chrono::{DateTime, Local};
             let datetime: DateTime<Local> = modified.into();
             // Format to match ExifTool exactly: "2025:06:30 10:16:40-07:00"
             let formatted = datetime.format("%Y:%m:%d %H:%M:%S%:z").to_string();
             tag_entries.push(TagEntry {
-                group: "File".to_string(),
-                group1: "System".to_string(),
-                name: "FileModifyDate".to_string(),
+                group: "File".into(),
+                group1: "System".into(),
+                name: "FileModifyDate".into(),
                 value: TagValue::String(formatted.clone()),
-                print: TagValue::String(formatted),
+                print: TagValue::String(formatted).into(),
+                source: None,
             });
         }
     }
 
     // Add FileAccessDate - ExifTool.pm:1427
-    if filter_opts.should_extract_tag("FileAccessDate", "File") {
+    if extraction_opts.should_extract_tag("FileAccessDate", "File") {
         if let Ok(accessed) = file_metadata.accessed() {
             use chrono::{DateTime, Local};
             let datetime: DateTime<Local> = accessed.into();
             let formatted = datetime.format("%Y:%m:%d %H:%M:%S%:z").to_string();
             tag_entries.push(TagEntry {
-                group: "File".to_string(),
-                group1: "System".to_string(),
-                name: "FileAccessDate".to_string(),
+                group: "File".into(),
+                group1: "System".into(),
+                name: "FileAccessDate".into(),
                 value: TagValue::String(formatted.clone()),
-                print: TagValue::String(formatted),
+                print: TagValue::String(formatted).into(),
+                source: None,
             });
         }
     }
@@ -195,17 +302,18 @@ pub fn extract_metadata(
     // ExifTool.pm:1437 and 1463
     #[cfg(any(target_os = "windows", target_os = "macos"))]
     {
-        if filter_opts.should_extract_tag("FileCreateDate", "File") {
+        if extraction_opts.should_extract_tag("FileCreateDate", "File") {
             if let Ok(created) = file_metadata.created() {
                 use chrono::{DateTime, Local};
                 let datetime: DateTime<Local> = created.into();
                 let formatted = datetime.format("%Y:%m:%d %H:%M:%S%:z").to_string();
                 tag_entries.push(TagEntry {
-                    group: "File".to_string(),
-                    group1: "System".to_string(),
-                    name: "FileCreateDate".to_string(),
+                    group: "File".into(),
+                    group1: "System".into(),
+                    name: "FileCreateDate".into(),
                     value: TagValue::String(formatted.clone()),
-                    print: TagValue::String(formatted),
+                    print: TagValue::String(formatted).into(),
+                    source: None,
                 });
             }
         }
@@ -216,18 +324,19 @@ pub fn extract_metadata(
         // On Unix systems, use ctime as FileInodeChangeDate
         // This represents when the inode was last changed (not creation time)
         // ExifTool.pm:2860-2861 uses stat[10] which is ctime
-        if filter_opts.should_extract_tag("FileInodeChangeDate", "File") {
+        if extraction_opts.should_extract_tag("FileInodeChangeDate", "File") {
             if let Some(ctime) = get_unix_ctime(path) {
                 use chrono::{Local, TimeZone};
                 let datetime = Local.timestamp_opt(ctime as i64, 0).single();
                 if let Some(datetime) = datetime {
                     let formatted = datetime.format("%Y:%m:%d %H:%M:%S%:z").to_string();
                     tag_entries.push(TagEntry {
-                        group: "File".to_string(),
-                        group1: "System".to_string(),
-                        name: "FileInodeChangeDate".to_string(),
+                        group: "File".into(),
+                        group1: "System".into(),
+                        name: "FileInodeChangeDate".into(),
                         value: TagValue::String(formatted.clone()),
-                        print: TagValue::String(formatted),
+                        print: TagValue::String(formatted).into(),
+                        source: None,
                     });
                 }
             }
@@ -238,38 +347,44 @@ pub fn extract_metadata(
     // Format as Unix permissions string like "-rw-rw-r--"
     #[cfg(unix)]
     {
-        if filter_opts.should_extract_tag("FilePermissions", "File") {
+        if extraction_opts.should_extract_tag("FilePermissions", "File") {
             use std::os::unix::fs::PermissionsExt;
             let mode = file_metadata.permissions().mode();
             let permissions_str = format_unix_permissions(mode);
             tag_entries.push(TagEntry {
-                group: "File".to_string(),
-                group1: "System".to_string(),
-                name: "FilePermissions".to_string(),
+                group: "File".into(),
+                group1: "System".into(),
+                name: "FilePermissions".into(),
                 value: TagValue::String(permissions_str.clone()),
-                print: TagValue::String(permissions_str),
+                print: TagValue::String(permissions_str).into(),
+                source: None,
             });
         }
     }
 
-    #[cfg(not(unix))]
-    {
-        // On non-Unix systems (like Windows), ExifTool shows different attributes
-        // For now, we'll skip this as it requires Win32API::File
-        // TODO: Implement Windows file attributes when Win32 API is available
-    }
+    #[cfg(feature = "xattr")]
+    tag_entries.extend(requested_xattr_tags(path, &extraction_opts));
+
+    // Windows has FileAttributes (and FileOwner) instead
+    #[cfg(windows)]
+    tag_entries.extend(windows::windows_file_tags(
+        path,
+        &file_metadata,
+        &extraction_opts,
+    ));
 
     // Add FileType and FileTypeExtension using ExifTool-compatible values
     // Note: We'll store the initial file type here, but it may be overridden later
     // (e.g., NEF -> NRW during TIFF processing)
     let mut file_type = detection_result.file_type.clone();
-    if filter_opts.should_extract_tag("FileType", "File") {
+    if extraction_opts.should_extract_tag("FileType", "File") {
         tag_entries.push(TagEntry {
-            group: "File".to_string(),
-            group1: "File".to_string(),
-            name: "FileType".to_string(),
+            group: "File".into(),
+            group1: "File".into(),
+            name: "FileType".into(),
             value: TagValue::String(file_type.clone()),
-            print: TagValue::String(file_type.clone()),
+            print: TagValue::String(file_type.clone()).into(),
+            source: None,
         });
     }
 
@@ -277,7 +392,7 @@ pub fn extract_metadata(
     // Source: ExifTool.pm:9583 - $self->FoundTag('FileTypeExtension', uc $normExt);
     // Source: ExifTool.pm:9623 - $normExt = $fileTypeExt{$fileType} for special extensions
     // Raw value: uppercase, PrintConv: lowercase (PrintConv => 'lc $val')
-    if filter_opts.should_extract_tag("FileTypeExtension", "File") {
+    if extraction_opts.should_extract_tag("FileTypeExtension", "File") {
         let (file_type_ext_raw, file_type_ext_print) = {
             use crate::generated::ExifTool_pm::file_type_ext::lookup_file_type_ext;
 
@@ -297,42 +412,44 @@ pub fn extract_metadata(
         };
 
         tag_entries.push(TagEntry {
-            group: "File".to_string(),
-            group1: "File".to_string(),
-            name: "FileTypeExtension".to_string(),
+            group: "File".into(),
+            group1: "File".into(),
+            name: "FileTypeExtension".into(),
             value: TagValue::String(file_type_ext_raw),
-            print: TagValue::String(file_type_ext_print),
+            print: TagValue::String(file_type_ext_print).into(),
+            source: None,
         });
     }
 
-    if filter_opts.should_extract_tag("MIMEType", "File") {
+    if extraction_opts.should_extract_tag("MIMEType", "File") {
         let mime_type = detection_result.mime_type.clone();
         tag_entries.push(TagEntry {
-            group: "File".to_string(),
-            group1: "File".to_string(),
-            name: "MIMEType".to_string(),
+            group: "File".into(),
+            group1: "File".into(),
+            name: "MIMEType".into(),
             value: TagValue::String(mime_type.clone()),
-            print: TagValue::String(mime_type),
+            print: TagValue::String(mime_type).into(),
+            source: None,
         });
     }
 
     // PERFORMANCE OPTIMIZATION: Skip format-specific processing if only File group tags are requested
     // Check if any non-File group tags are needed (EXIF, MakerNotes, etc.)
-    let needs_format_processing = !filter_opts.extract_all
-        && filter_opts.has_specific_requests()
-        && (filter_opts
+    let needs_format_processing = !extraction_opts.extract_all
+        && extraction_opts.has_specific_requests()
+        && (extraction_opts
             .requested_tags
             .iter()
             .any(|tag| !is_file_group_tag(tag))
-            || filter_opts
+            || extraction_opts
                 .requested_groups
                 .iter()
                 .any(|group| group.to_lowercase() != "file")
-            || filter_opts
+            || extraction_opts
                 .group_all_patterns
                 .iter()
                 .any(|pattern| !pattern.to_lowercase().starts_with("file:"))
-            || filter_opts.glob_patterns.iter().any(|pattern| {
+            || extraction_opts.glob_patterns.iter().any(|pattern| {
                 // Check if glob pattern could match non-File group tags
                 let pattern_lower = pattern.to_lowercase();
                 !(pattern_lower == "file:*"
@@ -354,14 +471,32 @@ pub fn extract_metadata(
             }));
 
     // Only do format-specific processing if needed
-    if filter_opts.extract_all || needs_format_processing {
-        debug!(
-            "Format-specific processing required - extracting {} format data",
-            detection_result.format
-        );
+    if extraction_opts.extract_all || needs_format_processing {
+        let _format_stage = crate::stats::stage("Format");
+
+        // A registered format handler replaces the built-in parser
+        let registered_handler = handler::handler_for(&detection_result);
+        if let Some(handler) = &registered_handler {
+            reader.seek(SeekFrom::Start(0))?;
+            match handler.extract(&mut reader, &detection_result, &extraction_opts) {
+                Ok(mut handler_entries) => tag_entries.append(&mut handler_entries),
+                Err(e) => {
+                    warnings.push(
+                        ExtractionWarning::new(
+                            "FormatHandlerError",
+                            format!("{} handler failed: {e}", handler.name()),
+                        )
+                        .with_context(&detection_result.file_type),
+                    );
+                }
+            }
+        }
 
         // Format-specific processing based on the detected format
         match detection_result.format.as_str() {
+            // Extracted above by the registered handler
+            _ if registered_handler.is_some() => {}
+            #[cfg(feature = "raw-formats")]
             "RAW" => {
                 // RAW format processing (Milestone 17a: Kyocera RAW support)
                 // Reset reader to start of file
@@ -372,13 +507,19 @@ pub fn extract_metadata(
                 reader.read_to_end(&mut raw_data)?;
 
                 // Process RAW data using RAW processor
-                let raw_processor = crate::raw::RawProcessor::new();
+                let raw_processor = crate::raw::RawProcessor::shared();
                 let mut exif_reader = ExifReader::new();
+                exif_reader.set_filter_options(&filter_opts);
 
                 // Store the original file type for format detection
                 exif_reader.set_file_type(detection_result.file_type.clone());
 
-                match raw_processor.process_raw(&mut exif_reader, &raw_data, &detection_result) {
+                let result =
+                    raw_processor.process_raw(&mut exif_reader, &raw_data, &detection_result);
+
+                truncation = truncation.or_else(|| exif_reader.truncation().cloned());
+
+                match result {
                     Ok(()) => {
                         // Successfully processed RAW - extract all found tags using new TagEntry API
                         let mut raw_tag_entries = exif_reader.get_all_tag_entries();
@@ -395,21 +536,22 @@ pub fn extract_metadata(
                         // Add ExifByteOrder tag if EXIF data was present
                         add_exif_byte_order_tag(&exif_reader, &mut tag_entries);
 
-                        // Add RAW processing warnings as tags for debugging
+                        // Add RAW processing warnings for debugging
                         if show_warnings {
-                            for (i, warning) in exif_reader.get_warnings().iter().enumerate() {
-                                tags.insert(
-                                    format!("Warning:RawWarning{i}"),
-                                    TagValue::String(warning.clone()),
-                                );
-                            }
+                            warnings.extend(exif_reader.get_warnings().iter().map(|warning| {
+                                ExtractionWarning::new("RawWarning", warning.as_str())
+                                    .with_context(&detection_result.file_type)
+                            }));
                         }
                     }
                     Err(e) => {
                         // Failed to parse RAW - add error information
-                        tags.insert(
-                            "Warning:RawParseError".to_string(),
-                            TagValue::string(format!("Failed to parse RAW: {e}")),
+                        warnings.push(
+                            ExtractionWarning::new(
+                                "RawParseError",
+                                format!("Failed to parse RAW: {e}"),
+                            )
+                            .with_context(&detection_result.file_type),
                         );
                     }
                 }
@@ -422,59 +564,65 @@ pub fn extract_metadata(
                 if let Some(sof) = sof_data_opt {
                     // Add ImageWidth from SOF
                     tag_entries.push(TagEntry {
-                        group: "File".to_string(),
-                        group1: "File".to_string(),
-                        name: "ImageWidth".to_string(),
+                        group: "File".into(),
+                        group1: "File".into(),
+                        name: "ImageWidth".into(),
                         value: TagValue::U16(sof.image_width),
-                        print: TagValue::U16(sof.image_width),
+                        print: TagValue::U16(sof.image_width).into(),
+                        source: None,
                     });
 
                     // Add ImageHeight from SOF
                     tag_entries.push(TagEntry {
-                        group: "File".to_string(),
-                        group1: "File".to_string(),
-                        name: "ImageHeight".to_string(),
+                        group: "File".into(),
+                        group1: "File".into(),
+                        name: "ImageHeight".into(),
                         value: TagValue::U16(sof.image_height),
-                        print: TagValue::U16(sof.image_height),
+                        print: TagValue::U16(sof.image_height).into(),
+                        source: None,
                     });
 
                     // Add BitsPerSample from SOF
                     tag_entries.push(TagEntry {
-                        group: "File".to_string(),
-                        group1: "File".to_string(),
-                        name: "BitsPerSample".to_string(),
+                        group: "File".into(),
+                        group1: "File".into(),
+                        name: "BitsPerSample".into(),
                         value: TagValue::U16(sof.bits_per_sample as u16),
-                        print: TagValue::String(sof.bits_per_sample.to_string()),
+                        print: TagValue::String(sof.bits_per_sample.to_string()).into(),
+                        source: None,
                     });
 
                     // Add ColorComponents from SOF
                     tag_entries.push(TagEntry {
-                        group: "File".to_string(),
-                        group1: "File".to_string(),
-                        name: "ColorComponents".to_string(),
+                        group: "File".into(),
+                        group1: "File".into(),
+                        name: "ColorComponents".into(),
                         value: TagValue::U16(sof.color_components as u16),
-                        print: TagValue::String(sof.color_components.to_string()),
+                        print: TagValue::String(sof.color_components.to_string()).into(),
+                        source: None,
                     });
 
                     // Add YCbCrSubSampling if available
                     if let Some(subsampling) = sof.ycbcr_subsampling {
                         tag_entries.push(TagEntry {
-                            group: "File".to_string(),
-                            group1: "File".to_string(),
-                            name: "YCbCrSubSampling".to_string(),
+                            group: "File".into(),
+                            group1: "File".into(),
+                            name: "YCbCrSubSampling".into(),
                             value: TagValue::String(subsampling.clone()),
-                            print: TagValue::String(subsampling),
+                            print: TagValue::String(subsampling).into(),
+                            source: None,
                         });
                     }
 
                     // Add EncodingProcess
                     // Note: ExifTool uses a PrintConv for this, but for now we'll use the raw value
                     tag_entries.push(TagEntry {
-                        group: "File".to_string(),
-                        group1: "File".to_string(),
-                        name: "EncodingProcess".to_string(),
+                        group: "File".into(),
+                        group1: "File".into(),
+                        name: "EncodingProcess".into(),
                         value: TagValue::U16(sof.encoding_process as u16),
-                        print: TagValue::String(sof.encoding_process.to_string()),
+                        print: TagValue::String(sof.encoding_process.to_string()).into(),
+                        source: None,
                     });
                 }
 
@@ -492,12 +640,24 @@ pub fn extract_metadata(
                         );
 
                         // Extract actual EXIF data using our new ExifReader
+                        // A truncated segment still gets parsed as far as it goes
                         reader.seek(SeekFrom::Start(segment_info.offset))?;
-                        let mut exif_data = vec![0u8; segment_info.length as usize];
-                        reader.read_exact(&mut exif_data)?;
+                        let mut exif_data = Vec::new();
+                        (&mut reader)
+                            .take(segment_info.length as u64)
+                            .read_to_end(&mut exif_data)?;
+                        if exif_data.len() < segment_info.length as usize {
+                            truncation.get_or_insert(Truncation::new(
+                                "JPEG APP1 EXIF segment",
+                                segment_info.offset,
+                                segment_info.length as u64,
+                                exif_data.len() as u64,
+                            ));
+                        }
 
                         // Parse EXIF data
                         let mut exif_reader = ExifReader::new();
+                        exif_reader.set_filter_options(&filter_opts);
 
                         // Set the TIFF base offset for IsOffset tag adjustment
                         // ExifTool: Exif.pm:7052-7066 - tags with IsOffset=>1 need their values
@@ -506,7 +666,11 @@ pub fn extract_metadata(
                         // starts in the JPEG file (after "Exif\0\0" marker in APP1).
                         exif_reader.set_base_offset(segment_info.offset);
 
-                        match exif_reader.parse_exif_data(&exif_data) {
+                        let result = exif_reader.parse_exif_data(&exif_data);
+
+                        truncation = truncation.or_else(|| exif_reader.truncation().cloned());
+
+                        match result {
                             Ok(()) => {
                                 // Successfully parsed EXIF - extract all found tags using new TagEntry API
                                 let mut exif_tag_entries = exif_reader.get_all_tag_entries();
@@ -523,23 +687,24 @@ pub fn extract_metadata(
                                 // Add ExifByteOrder tag
                                 add_exif_byte_order_tag(&exif_reader, &mut tag_entries);
 
-                                // Add EXIF processing warnings as tags for debugging
+                                // Add EXIF processing warnings for debugging
                                 if show_warnings {
-                                    for (i, warning) in
-                                        exif_reader.get_warnings().iter().enumerate()
-                                    {
-                                        tags.insert(
-                                            format!("Warning:ExifWarning{i}"),
-                                            TagValue::String(warning.clone()),
-                                        );
-                                    }
+                                    warnings.extend(exif_reader.get_warnings().iter().map(
+                                        |warning| {
+                                            ExtractionWarning::new("ExifWarning", warning.as_str())
+                                                .with_context(&detection_result.file_type)
+                                        },
+                                    ));
                                 }
                             }
                             Err(e) => {
                                 // Failed to parse EXIF - add error information
-                                tags.insert(
-                                    "Warning:ExifParseError".to_string(),
-                                    TagValue::string(format!("Failed to parse EXIF: {e}")),
+                                warnings.push(
+                                    ExtractionWarning::new(
+                                        "ExifParseError",
+                                        format!("Failed to parse EXIF: {e}"),
+                                    )
+                                    .with_context(&detection_result.file_type),
                                 );
                             }
                         }
@@ -562,14 +727,15 @@ pub fn extract_metadata(
                             // Convert IPTC tags to TagEntry format and add to collection
                             for (tag_name, tag_value) in iptc_tags {
                                 tag_entries.push(TagEntry {
-                                    group: "IPTC".to_string(),
-                                    group1: "IPTC".to_string(),
+                                    group: "IPTC".into(),
+                                    group1: "IPTC".into(),
                                     name: tag_name
                                         .strip_prefix("IPTC:")
                                         .unwrap_or(&tag_name)
-                                        .to_string(),
+                                        .into(),
                                     value: tag_value.clone(),
-                                    print: tag_value,
+                                    print: tag_value.into(),
+                                    source: None,
                                 });
                             }
 
@@ -598,58 +764,132 @@ pub fn extract_metadata(
                     }
                     Err(e) => {
                         // Real error scanning for IPTC
-                        tags.insert(
-                            "Warning:IptcScanError".to_string(),
-                            TagValue::string(format!("Error scanning for IPTC: {e}")),
+                        warnings.push(
+                            ExtractionWarning::new(
+                                "IptcScanError",
+                                format!("Error scanning for IPTC: {e}"),
+                            )
+                            .with_context(&detection_result.file_type),
                         );
                     }
                 }
 
-                // Extract XMP data (handles both regular and Extended XMP)
-                reader.seek(SeekFrom::Start(0))?;
-                match extract_jpeg_xmp(&mut reader) {
-                    Ok(xmp_data) => {
-                        // Process XMP data with XmpProcessor - individual tag extraction
-                        let mut xmp_processor = XmpProcessor::new();
-                        match xmp_processor.process_xmp_data_individual(&xmp_data) {
-                            Ok(xmp_tag_entries) => {
-                                // Add individual XMP TagEntry objects ("XMP:TagName" format)
-                                tag_entries.extend(xmp_tag_entries);
+                // The ICC profile itself, joined from its APP2 chunks, for -b
+                // ExifTool: Extra.pm ICC_Profile, only generated on request
+                if filter_opts.requests_tag("ICC_Profile") {
+                    match extract_jpeg_icc_profile(&mut reader) {
+                        Ok(Some(profile)) => tag_entries.push(TagEntry {
+                            group: "ICC_Profile".into(),
+                            group1: "ICC_Profile".into(),
+                            name: "ICC_Profile".into(),
+                            value: TagValue::Binary(profile.clone()),
+                            print: TagValue::Binary(profile).into(),
+                            source: None,
+                        }),
+                        Ok(None) => {}
+                        Err(e) => warnings.push(
+                            ExtractionWarning::new("IccProfileError", e.to_string())
+                                .with_context(&detection_result.file_type),
+                        ),
+                    }
+                }
 
-                                // Add XMP detection status
-                                tags.insert(
-                                    "System:XmpDetectionStatus".to_string(),
-                                    TagValue::String(format!(
-                                        "XMP data found ({} bytes total)",
-                                        xmp_data.len()
-                                    )),
-                                );
-                            }
-                            Err(e) => {
-                                // Failed to parse XMP - add error information
-                                tags.insert(
-                                    "Warning:XmpParseError".to_string(),
-                                    TagValue::string(format!("Failed to parse XMP: {e}")),
-                                );
+                // Extract XMP data (handles both regular and Extended XMP)
+                #[cfg(feature = "xmp")]
+                {
+                    if filter_opts.include_tag_sources {
+                        reader.seek(SeekFrom::Start(0))?;
+                        xmp_packet = locate_jpeg_xmp(&mut reader).ok().flatten();
+                    }
+                    reader.seek(SeekFrom::Start(0))?;
+                    match extract_jpeg_xmp_with_warnings(&mut reader) {
+                        Ok(JpegXmp {
+                            data: xmp_data,
+                            warnings: xmp_warnings,
+                        }) => {
+                            warnings.extend(xmp_warnings.into_iter().map(|warning| {
+                                ExtractionWarning::new("ExtendedXmpWarning", warning)
+                                    .with_context(&detection_result.file_type)
+                            }));
+                            // Process XMP data with XmpProcessor - individual tag extraction
+                            let mut xmp_processor = XmpProcessor::new();
+                            match xmp_processor.process_xmp_data_individual(&xmp_data) {
+                                Ok(xmp_tag_entries) => {
+                                    // Add individual XMP TagEntry objects ("XMP:TagName" format)
+                                    tag_entries.extend(xmp_tag_entries);
+                                    xmp_provenance = xmp_processor.take_provenance();
+
+                                    // Add XMP detection status
+                                    tags.insert(
+                                        "System:XmpDetectionStatus".to_string(),
+                                        TagValue::String(format!(
+                                            "XMP data found ({} bytes total)",
+                                            xmp_data.len()
+                                        )),
+                                    );
+                                }
+                                Err(e) => {
+                                    // Failed to parse XMP - add error information
+                                    warnings.push(
+                                        ExtractionWarning::new(
+                                            "XmpParseError",
+                                            format!("Failed to parse XMP: {e}"),
+                                        )
+                                        .with_context(&detection_result.file_type),
+                                    );
+                                }
                             }
                         }
+                        Err(e) if e.to_string().contains("No XMP data found") => {
+                            // No XMP data found (not an error)
+                            tags.insert(
+                                "System:XmpDetectionStatus".to_string(),
+                                "No XMP data found in JPEG".into(),
+                            );
+                        }
+                        Err(e) => {
+                            // Real error scanning for XMP
+                            warnings.push(
+                                ExtractionWarning::new(
+                                    "XmpScanError",
+                                    format!("Error scanning for XMP: {e}"),
+                                )
+                                .with_context(&detection_result.file_type),
+                            );
+                        }
                     }
-                    Err(e) if e.to_string().contains("No XMP data found") => {
-                        // No XMP data found (not an error)
-                        tags.insert(
-                            "System:XmpDetectionStatus".to_string(),
-                            "No XMP data found in JPEG".into(),
-                        );
-                    }
-                    Err(e) => {
-                        // Real error scanning for XMP
-                        tags.insert(
-                            "Warning:XmpScanError".to_string(),
-                            TagValue::string(format!("Error scanning for XMP: {e}")),
-                        );
+
+                    // Android motion photo video, located from the XMP
+                    let file_size = reader.seek(SeekFrom::End(0))?;
+                    if let Some(location) = motion_photo_video_location(&tag_entries, file_size)
+                        .filter(|_| filter_opts.should_extract_tag(MOTION_PHOTO_VIDEO, "Trailer"))
+                    {
+                        // Like ExifTool, the video is only read when it is
+                        // asked for (by name, group or -b), not for -all
+                        let read_data = filter_opts.include_binary || !filter_opts.extract_all;
+                        match motion_photo::motion_photo_video_entry(
+                            &mut reader,
+                            location,
+                            read_data,
+                        ) {
+                            Ok(entry) => tag_entries.push(entry),
+                            Err(e) => warnings.push(
+                                ExtractionWarning::new("MotionPhotoError", e.to_string())
+                                    .with_context(&detection_result.file_type),
+                            ),
+                        }
                     }
                 }
 
+                // Insta360 trailer (.insp photos)
+                match extract_insta360_trailer(&mut reader) {
+                    Ok(mut insta360_entries) => tag_entries.append(&mut insta360_entries),
+                    Err(e) => warnings.push(
+                        ExtractionWarning::new("Insta360Error", e.to_string())
+                            .with_context(&detection_result.file_type),
+                    ),
+                }
+
                 // Hash JPEG image data if requested
                 // ExifTool: lib/Image/ExifTool.pm:7217-7406 - JPEG scan data hashing
                 if let Some(ref mut hasher) = image_data_hasher {
@@ -675,11 +915,16 @@ pub fn extract_metadata(
 
                 // Parse TIFF/EXIF data
                 let mut exif_reader = ExifReader::new();
+                exif_reader.set_filter_options(&filter_opts);
 
                 // Store the original file type for NEF/NRW detection
                 exif_reader.set_file_type(detection_result.file_type.clone());
 
-                match exif_reader.parse_exif_data(&tiff_data) {
+                let result = exif_reader.parse_exif_data(&tiff_data);
+
+                truncation = truncation.or_else(|| exif_reader.truncation().cloned());
+
+                match result {
                     Ok(()) => {
                         // Extract TIFF dimensions for TIFF-based RAW files (ARW, CR2, etc.)
                         // This extracts ImageWidth/ImageHeight from TIFF IFD0 tags 0x0100/0x0101
@@ -688,6 +933,7 @@ pub fn extract_metadata(
                             detection_result.file_type.as_str(),
                             "ARW" | "CR2" | "NEF" | "NRW" | "DNG" | "ORF" | "RW2"
                         ) {
+                            #[cfg(feature = "raw-formats")]
                             if let Err(e) = crate::raw::utils::extract_tiff_dimensions(
                                 &mut exif_reader,
                                 &tiff_data,
@@ -698,7 +944,9 @@ pub fn extract_metadata(
 
                             // For RW2 files: Extract JPEG preview dimensions to create File:ImageWidth/ImageHeight tags
                             // This must be done after TIFF processing to access the JpgFromRaw binary data
-                            if detection_result.file_type == "RW2" {
+                            if detection_result.file_type == "RW2"
+                                && extraction_opts.scan_depth < ScanDepth::Fast2
+                            {
                                 tracing::debug!("Processing RW2 file: attempting to extract JPEG preview dimensions");
                                 if let Some(jpeg_preview_dimensions) =
                                     extract_rw2_jpeg_preview_dimensions(&exif_reader, &tiff_data)
@@ -706,18 +954,20 @@ pub fn extract_metadata(
                                     // Create File:ImageWidth and File:ImageHeight tags from JPEG preview
                                     // ExifTool: File group tags come from embedded JPEG SOF data, not sensor borders
                                     tag_entries.push(TagEntry {
-                                        group: "File".to_string(),
-                                        group1: "File".to_string(),
-                                        name: "ImageWidth".to_string(),
+                                        group: "File".into(),
+                                        group1: "File".into(),
+                                        name: "ImageWidth".into(),
                                         value: TagValue::U16(jpeg_preview_dimensions.0),
-                                        print: TagValue::U16(jpeg_preview_dimensions.0),
+                                        print: TagValue::U16(jpeg_preview_dimensions.0).into(),
+                                        source: None,
                                     });
                                     tag_entries.push(TagEntry {
-                                        group: "File".to_string(),
-                                        group1: "File".to_string(),
-                                        name: "ImageHeight".to_string(),
+                                        group: "File".into(),
+                                        group1: "File".into(),
+                                        name: "ImageHeight".into(),
                                         value: TagValue::U16(jpeg_preview_dimensions.1),
-                                        print: TagValue::U16(jpeg_preview_dimensions.1),
+                                        print: TagValue::U16(jpeg_preview_dimensions.1).into(),
+                                        source: None,
                                     });
                                     tracing::debug!("Added File:ImageWidth/ImageHeight tags from JPEG preview: {}x{}", 
                                               jpeg_preview_dimensions.0, jpeg_preview_dimensions.1);
@@ -738,15 +988,15 @@ pub fn extract_metadata(
                             for entry in &mut tag_entries {
                                 if entry.name == "FileType" {
                                     entry.value = TagValue::String(file_type.clone());
-                                    entry.print = TagValue::String(file_type.clone());
+                                    entry.set_print(TagValue::String(file_type.clone()));
                                 } else if entry.name == "FileTypeExtension" {
                                     entry.value = TagValue::String(file_type.to_lowercase());
-                                    entry.print = TagValue::String(file_type.to_lowercase());
+                                    entry.set_print(TagValue::String(file_type.to_lowercase()));
                                 } else if entry.name == "MIMEType" {
                                     // Update MIME type for NRW
                                     if file_type == "NRW" {
                                         entry.value = "image/x-nikon-nrw".into();
-                                        entry.print = "image/x-nikon-nrw".into();
+                                        entry.set_print(TagValue::string("image/x-nikon-nrw"));
                                     }
                                 }
                             }
@@ -767,26 +1017,35 @@ pub fn extract_metadata(
                         // Add ExifByteOrder tag
                         add_exif_byte_order_tag(&exif_reader, &mut tag_entries);
 
-                        // Add EXIF processing warnings as tags for debugging
+                        // Add EXIF processing warnings for debugging
                         if show_warnings {
-                            for (i, warning) in exif_reader.get_warnings().iter().enumerate() {
-                                tags.insert(
-                                    format!("Warning:ExifWarning{i}"),
-                                    TagValue::String(warning.clone()),
-                                );
-                            }
+                            warnings.extend(exif_reader.get_warnings().iter().map(|warning| {
+                                ExtractionWarning::new("ExifWarning", warning.as_str())
+                                    .with_context(&detection_result.file_type)
+                            }));
                         }
                     }
                     Err(e) => {
                         // Failed to parse TIFF - add error information
-                        tags.insert(
-                            "Warning:TiffParseError".to_string(),
-                            TagValue::string(format!("Failed to parse TIFF: {e}")),
+                        warnings.push(
+                            ExtractionWarning::new(
+                                "TiffParseError",
+                                format!("Failed to parse TIFF: {e}"),
+                            )
+                            .with_context(&detection_result.file_type),
                         );
                     }
                 }
 
                 // Check for XMP data in TIFF IFD0
+                #[cfg(feature = "xmp")]
+                if filter_opts.include_tag_sources {
+                    xmp_packet = locate_tiff_xmp(&tiff_data).map(|range| {
+                        let offset = range.start as u64;
+                        XmpPacketLocation::new("IFD0 ApplicationNotes", offset, &tiff_data[range])
+                    });
+                }
+                #[cfg(feature = "xmp")]
                 match extract_tiff_xmp(&tiff_data) {
                     Ok(Some(xmp_data)) => {
                         // Process XMP data with XmpProcessor - individual tag extraction
@@ -795,6 +1054,7 @@ pub fn extract_metadata(
                             Ok(xmp_tag_entries) => {
                                 // Add individual XMP TagEntry objects ("XMP:TagName" format)
                                 tag_entries.extend(xmp_tag_entries);
+                                xmp_provenance = xmp_processor.take_provenance();
 
                                 // Add XMP detection status
                                 tags.insert(
@@ -807,9 +1067,12 @@ pub fn extract_metadata(
                             }
                             Err(e) => {
                                 // Failed to parse XMP - add error information
-                                tags.insert(
-                                    "Warning:XmpParseError".to_string(),
-                                    TagValue::string(format!("Failed to parse XMP: {e}")),
+                                warnings.push(
+                                    ExtractionWarning::new(
+                                        "XmpParseError",
+                                        format!("Failed to parse XMP: {e}"),
+                                    )
+                                    .with_context(&detection_result.file_type),
                                 );
                             }
                         }
@@ -823,18 +1086,25 @@ pub fn extract_metadata(
                     }
                     Err(e) => {
                         // Error extracting XMP
-                        tags.insert(
-                            "Warning:XmpExtractionError".to_string(),
-                            TagValue::string(format!("Error extracting XMP: {e}")),
+                        warnings.push(
+                            ExtractionWarning::new(
+                                "XmpExtractionError",
+                                format!("Error extracting XMP: {e}"),
+                            )
+                            .with_context(&detection_result.file_type),
                         );
                     }
                 }
             }
+            #[cfg(feature = "xmp")]
             "XMP" => {
                 // Standalone XMP file processing
                 reader.seek(SeekFrom::Start(0))?;
                 let mut xmp_data = Vec::new();
                 reader.read_to_end(&mut xmp_data)?;
+                if filter_opts.include_tag_sources {
+                    xmp_packet = Some(XmpPacketLocation::new("XMP file", 0, &xmp_data));
+                }
 
                 // Process XMP data with XmpProcessor - individual tag extraction
                 let mut xmp_processor = XmpProcessor::new();
@@ -842,6 +1112,7 @@ pub fn extract_metadata(
                     Ok(xmp_tag_entries) => {
                         // Add individual XMP TagEntry objects ("XMP:TagName" format)
                         tag_entries.extend(xmp_tag_entries);
+                        xmp_provenance = xmp_processor.take_provenance();
 
                         // Add XMP detection status
                         tags.insert(
@@ -854,14 +1125,18 @@ pub fn extract_metadata(
                     }
                     Err(e) => {
                         // Failed to parse XMP - add error information
-                        tags.insert(
-                            "Warning:XmpParseError".to_string(),
-                            TagValue::string(format!("Failed to parse XMP: {e}")),
+                        warnings.push(
+                            ExtractionWarning::new(
+                                "XmpParseError",
+                                format!("Failed to parse XMP: {e}"),
+                            )
+                            .with_context(&detection_result.file_type),
                         );
                     }
                 }
             }
-            "MRW" | "RW2" | "RWL" => {
+            #[cfg(feature = "raw-formats")]
+            "MRW" | "RW2" | "RWL" | "RAF" => {
                 // RAW format processing (Milestone 17b: Minolta MRW and Panasonic RW2 support)
                 tracing::debug!(
                     "Processing RAW file with type: {}",
@@ -873,11 +1148,15 @@ pub fn extract_metadata(
                 let mut raw_data = Vec::new();
                 reader.read_to_end(&mut raw_data)?;
                 // Process RAW data using RAW processor
-                let raw_processor = crate::raw::RawProcessor::new();
+                let raw_processor = crate::raw::RawProcessor::shared();
                 let mut exif_reader = ExifReader::new();
+                exif_reader.set_filter_options(&filter_opts);
                 // Store the original file type for format detection
                 exif_reader.set_file_type(detection_result.file_type.clone());
-                match raw_processor.process_raw(&mut exif_reader, &raw_data, &detection_result) {
+                let result =
+                    raw_processor.process_raw(&mut exif_reader, &raw_data, &detection_result);
+                truncation = truncation.or_else(|| exif_reader.truncation().cloned());
+                match result {
                     Ok(()) => {
                         // Successfully processed RAW - extract all found tags using new TagEntry API
                         let mut raw_tag_entries = exif_reader.get_all_tag_entries();
@@ -886,7 +1165,9 @@ pub fn extract_metadata(
 
                         // For RW2 files: Extract JPEG preview dimensions to create File:ImageWidth/ImageHeight tags
                         // ExifTool creates File group tags from embedded JPEG preview (JpgFromRaw tag)
-                        if detection_result.file_type == "RW2" {
+                        if detection_result.file_type == "RW2"
+                            && extraction_opts.scan_depth < ScanDepth::Fast2
+                        {
                             tracing::debug!(
                             "Processing RW2 file: attempting to extract JPEG preview dimensions"
                         );
@@ -896,18 +1177,20 @@ pub fn extract_metadata(
                                 // Create File:ImageWidth and File:ImageHeight tags from JPEG preview
                                 // ExifTool: File group tags come from embedded JPEG SOF data, not sensor borders
                                 tag_entries.push(TagEntry {
-                                    group: "File".to_string(),
-                                    group1: "File".to_string(),
-                                    name: "ImageWidth".to_string(),
+                                    group: "File".into(),
+                                    group1: "File".into(),
+                                    name: "ImageWidth".into(),
                                     value: TagValue::U16(jpeg_preview_dimensions.0),
-                                    print: TagValue::U16(jpeg_preview_dimensions.0),
+                                    print: TagValue::U16(jpeg_preview_dimensions.0).into(),
+                                    source: None,
                                 });
                                 tag_entries.push(TagEntry {
-                                    group: "File".to_string(),
-                                    group1: "File".to_string(),
-                                    name: "ImageHeight".to_string(),
+                                    group: "File".into(),
+                                    group1: "File".into(),
+                                    name: "ImageHeight".into(),
                                     value: TagValue::U16(jpeg_preview_dimensions.1),
-                                    print: TagValue::U16(jpeg_preview_dimensions.1),
+                                    print: TagValue::U16(jpeg_preview_dimensions.1).into(),
+                                    source: None,
                                 });
                             }
                         }
@@ -919,24 +1202,22 @@ pub fn extract_metadata(
 
                         // Add ExifByteOrder tag if EXIF data was present
                         add_exif_byte_order_tag(&exif_reader, &mut tag_entries);
-                        // Add RAW processing warnings as tags for debugging
+                        // Add RAW processing warnings for debugging
                         if show_warnings {
-                            for (i, warning) in exif_reader.get_warnings().iter().enumerate() {
-                                tags.insert(
-                                    format!("Warning:RawWarning{i}"),
-                                    TagValue::String(warning.clone()),
-                                );
-                            }
+                            warnings.extend(exif_reader.get_warnings().iter().map(|warning| {
+                                ExtractionWarning::new("RawWarning", warning.as_str())
+                                    .with_context(&detection_result.file_type)
+                            }));
                         }
                     }
                     Err(e) => {
                         // Failed to parse RAW - add error information
-                        tags.insert(
-                            "Warning:RawParseError".to_string(),
-                            TagValue::string(format!(
-                                "Failed to parse {} RAW: {e}",
-                                detection_result.format
-                            )),
+                        warnings.push(
+                            ExtractionWarning::new(
+                                "RawParseError",
+                                format!("Failed to parse {} RAW: {e}", detection_result.format),
+                            )
+                            .with_context(&detection_result.file_type),
                         );
                     }
                 }
@@ -954,9 +1235,14 @@ pub fn extract_metadata(
                     reader.read_to_end(&mut tiff_data)?;
 
                     let mut exif_reader = ExifReader::new();
+                    exif_reader.set_filter_options(&filter_opts);
                     exif_reader.set_file_type(detection_result.file_type.clone());
 
-                    match exif_reader.parse_exif_data(&tiff_data) {
+                    let result = exif_reader.parse_exif_data(&tiff_data);
+
+                    truncation = truncation.or_else(|| exif_reader.truncation().cloned());
+
+                    match result {
                         Ok(()) => {
                             // Extract all found tags using new TagEntry API
                             let mut exif_tag_entries = exif_reader.get_all_tag_entries();
@@ -971,64 +1257,79 @@ pub fn extract_metadata(
                             // Add ExifByteOrder tag
                             add_exif_byte_order_tag(&exif_reader, &mut tag_entries);
 
-                            // Add EXIF processing warnings as tags for debugging
+                            // Add EXIF processing warnings for debugging
                             if show_warnings {
-                                for (i, warning) in exif_reader.get_warnings().iter().enumerate() {
-                                    tags.insert(
-                                        format!("Warning:ExifWarning{i}"),
-                                        TagValue::String(warning.clone()),
-                                    );
-                                }
+                                warnings.extend(exif_reader.get_warnings().iter().map(|warning| {
+                                    ExtractionWarning::new("ExifWarning", warning.as_str())
+                                        .with_context(&detection_result.file_type)
+                                }));
                             }
                         }
                         Err(e) => {
-                            tags.insert(
-                                "Warning:CR2ParseError".to_string(),
-                                TagValue::string(format!("Failed to parse CR2: {e}")),
+                            warnings.push(
+                                ExtractionWarning::new(
+                                    "CR2ParseError",
+                                    format!("Failed to parse CR2: {e}"),
+                                )
+                                .with_context(&detection_result.file_type),
                             );
                         }
                     }
                 } else {
                     // CRW and CR3 are non-TIFF formats - use RAW processor
-                    reader.seek(SeekFrom::Start(0))?;
-                    let mut raw_data = Vec::new();
-                    reader.read_to_end(&mut raw_data)?;
+                    #[cfg(feature = "raw-formats")]
+                    {
+                        reader.seek(SeekFrom::Start(0))?;
+                        let mut raw_data = Vec::new();
+                        reader.read_to_end(&mut raw_data)?;
 
-                    let raw_processor = crate::raw::RawProcessor::new();
-                    let mut exif_reader = ExifReader::new();
-                    exif_reader.set_file_type(detection_result.file_type.clone());
+                        let raw_processor = crate::raw::RawProcessor::shared();
+                        let mut exif_reader = ExifReader::new();
+                        exif_reader.set_filter_options(&filter_opts);
+                        exif_reader.set_file_type(detection_result.file_type.clone());
 
-                    match raw_processor.process_raw(&mut exif_reader, &raw_data, &detection_result)
-                    {
-                        Ok(()) => {
-                            let mut raw_tag_entries = exif_reader.get_all_tag_entries();
-                            tag_entries.append(&mut raw_tag_entries);
+                        let result = raw_processor.process_raw(
+                            &mut exif_reader,
+                            &raw_data,
+                            &detection_result,
+                        );
 
-                            let raw_tags = exif_reader.get_all_tags();
-                            for (key, value) in raw_tags {
-                                tags.insert(key, value);
-                            }
+                        truncation = truncation.or_else(|| exif_reader.truncation().cloned());
 
-                            // Add ExifByteOrder tag if EXIF data was present
-                            add_exif_byte_order_tag(&exif_reader, &mut tag_entries);
+                        match result {
+                            Ok(()) => {
+                                let mut raw_tag_entries = exif_reader.get_all_tag_entries();
+                                tag_entries.append(&mut raw_tag_entries);
 
-                            if show_warnings {
-                                for (i, warning) in exif_reader.get_warnings().iter().enumerate() {
-                                    tags.insert(
-                                        format!("Warning:RawWarning{i}"),
-                                        TagValue::String(warning.clone()),
-                                    );
+                                let raw_tags = exif_reader.get_all_tags();
+                                for (key, value) in raw_tags {
+                                    tags.insert(key, value);
+                                }
+
+                                // Add ExifByteOrder tag if EXIF data was present
+                                add_exif_byte_order_tag(&exif_reader, &mut tag_entries);
+
+                                if show_warnings {
+                                    warnings.extend(exif_reader.get_warnings().iter().map(
+                                        |warning| {
+                                            ExtractionWarning::new("RawWarning", warning.as_str())
+                                                .with_context(&detection_result.file_type)
+                                        },
+                                    ));
                                 }
                             }
-                        }
-                        Err(e) => {
-                            tags.insert(
-                                "Warning:CanonRawParseError".to_string(),
-                                TagValue::string(format!(
-                                    "Failed to parse Canon {} RAW: {e}",
-                                    detection_result.file_type
-                                )),
-                            );
+                            Err(e) => {
+                                warnings.push(
+                                    ExtractionWarning::new(
+                                        "CanonRawParseError",
+                                        format!(
+                                            "Failed to parse Canon {} RAW: {e}",
+                                            detection_result.file_type
+                                        ),
+                                    )
+                                    .with_context(&detection_result.file_type),
+                                );
+                            }
                         }
                     }
                 }
@@ -1048,6 +1349,7 @@ pub fn extract_metadata(
                         // Create PNG tag entries using ExifTool-compatible structure
                         // PNG tags are assigned to "PNG" group (not "File" group like JPEG)
                         let mut png_tag_entries = png::create_png_tag_entries(&ihdr);
+                        png_tag_entries.extend(png::create_png_color_entries(&png_data));
 
                         // Append PNG tag entries to our collection
                         tag_entries.append(&mut png_tag_entries);
@@ -1066,9 +1368,12 @@ pub fn extract_metadata(
                     }
                     Err(e) => {
                         // Failed to parse PNG IHDR - add error information
-                        tags.insert(
-                            "Warning:PngParseError".to_string(),
-                            TagValue::string(format!("Failed to parse PNG IHDR: {e}")),
+                        warnings.push(
+                            ExtractionWarning::new(
+                                "PngParseError",
+                                format!("Failed to parse PNG IHDR: {e}"),
+                            )
+                            .with_context(&detection_result.file_type),
                         );
                     }
                 }
@@ -1122,9 +1427,148 @@ pub fn extract_metadata(
                     }
                     Err(e) => {
                         // Failed to parse GIF screen descriptor - add error information
+                        warnings.push(
+                            ExtractionWarning::new(
+                                "GifParseError",
+                                format!("Failed to parse GIF screen descriptor: {e}"),
+                            )
+                            .with_context(&detection_result.file_type),
+                        );
+                    }
+                }
+            }
+            "JXL" => {
+                // JPEG XL: bare codestream or ISO BMFF container
+                // Reference: Jpeg2000.pm ProcessJXL
+                reader.seek(SeekFrom::Start(0))?;
+                let mut jxl_data = Vec::new();
+                reader.read_to_end(&mut jxl_data)?;
+
+                match jxl::parse_jxl(&jxl_data) {
+                    Ok(info) => {
+                        tag_entries.extend(jxl::create_jxl_tag_entries(&info));
                         tags.insert(
-                            "Warning:GifParseError".to_string(),
-                            TagValue::string(format!("Failed to parse GIF screen descriptor: {e}")),
+                            "System:JxlDetectionStatus".to_string(),
+                            TagValue::String(format!(
+                                "JXL codestream processed: {}x{}",
+                                info.width, info.height
+                            )),
+                        );
+
+                        // Exif box: TIFF data like a JPEG APP1 segment
+                        if let Some((exif_data, exif_offset)) = &info.exif {
+                            let mut exif_reader = ExifReader::new();
+                            exif_reader.set_filter_options(&filter_opts);
+                            exif_reader.set_base_offset(*exif_offset);
+                            let result = exif_reader.parse_exif_data(exif_data);
+                            truncation = truncation.or_else(|| exif_reader.truncation().cloned());
+                            match result {
+                                Ok(()) => {
+                                    tag_entries.append(&mut exif_reader.get_all_tag_entries());
+                                    for (key, value) in exif_reader.get_all_tags() {
+                                        tags.insert(key, value);
+                                    }
+                                    add_exif_byte_order_tag(&exif_reader, &mut tag_entries);
+                                }
+                                Err(e) => {
+                                    warnings.push(
+                                        ExtractionWarning::new(
+                                            "ExifParseError",
+                                            format!("Failed to parse EXIF: {e}"),
+                                        )
+                                        .with_context(&detection_result.file_type),
+                                    );
+                                }
+                            }
+                        }
+
+                        // xml box: XMP packet
+                        #[cfg(feature = "xmp")]
+                        if let Some((xmp_data, xmp_offset)) = &info.xmp {
+                            if filter_opts.include_tag_sources {
+                                xmp_packet = Some(XmpPacketLocation::new(
+                                    "JXL xml box",
+                                    *xmp_offset,
+                                    xmp_data,
+                                ));
+                            }
+                            let mut xmp_processor = XmpProcessor::new();
+                            match xmp_processor.process_xmp_data_individual(xmp_data) {
+                                Ok(xmp_tag_entries) => {
+                                    tag_entries.extend(xmp_tag_entries);
+                                    xmp_provenance = xmp_processor.take_provenance();
+                                }
+                                Err(e) => {
+                                    warnings.push(
+                                        ExtractionWarning::new(
+                                            "XmpParseError",
+                                            format!("Failed to parse XMP: {e}"),
+                                        )
+                                        .with_context(&detection_result.file_type),
+                                    );
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        warnings.push(
+                            ExtractionWarning::new(
+                                "JxlParseError",
+                                format!("Failed to parse JPEG XL header: {e}"),
+                            )
+                            .with_context(&detection_result.file_type),
+                        );
+                    }
+                }
+            }
+            #[cfg(feature = "documents")]
+            "ZIP" => {
+                // Office Open XML document properties (DOCX, XLSX, PPTX, ...)
+                match ooxml::extract_ooxml_metadata(&mut reader) {
+                    Ok(mut ooxml_entries) => tag_entries.append(&mut ooxml_entries),
+                    Err(e) => {
+                        warnings.push(
+                            ExtractionWarning::new(
+                                "ZipParseError",
+                                format!("Failed to read ZIP document properties: {e}"),
+                            )
+                            .with_context(&detection_result.file_type),
+                        );
+                    }
+                }
+            }
+            #[cfg(feature = "video")]
+            "MP3" | "FLAC" => {
+                // ID3v1/v2 tags, FLAC metadata blocks and Vorbis comments
+                match audio::extract_audio_metadata(&mut reader, &detection_result.format) {
+                    Ok(mut audio_entries) => tag_entries.append(&mut audio_entries),
+                    Err(e) => {
+                        warnings.push(
+                            ExtractionWarning::new(
+                                "AudioParseError",
+                                format!(
+                                    "Failed to parse {} metadata: {e}",
+                                    detection_result.format
+                                ),
+                            )
+                            .with_context(&detection_result.file_type),
+                        );
+                    }
+                }
+            }
+            #[cfg(feature = "video")]
+            "MKV" => {
+                // Matroska / WebM: streaming EBML walker
+                reader.seek(SeekFrom::Start(0))?;
+                match matroska::extract_matroska_metadata(&mut reader) {
+                    Ok(mut mkv_entries) => tag_entries.append(&mut mkv_entries),
+                    Err(e) => {
+                        warnings.push(
+                            ExtractionWarning::new(
+                                "MatroskaParseError",
+                                format!("Failed to parse Matroska container: {e}"),
+                            )
+                            .with_context(&detection_result.file_type),
                         );
                     }
                 }
@@ -1133,7 +1577,7 @@ pub fn extract_metadata(
                 // ISO Base Media File Format processing (QuickTime, MP4, AVIF, HEIF, etc.)
                 // Check file type to determine specific processing
                 match detection_result.file_type.as_str() {
-                    "AVIF" => {
+                    "AVIF" | "AVIFS" => {
                         // AVIF format processing - extract dimensions from ispe box
                         // Reset reader to start of file
                         reader.seek(SeekFrom::Start(0))?;
@@ -1142,9 +1586,13 @@ pub fn extract_metadata(
                         let mut avif_data = Vec::new();
                         reader.read_to_end(&mut avif_data)?;
 
+                        let sequence = add_image_sequence_tags(&avif_data, &mut tag_entries);
+
                         // Extract AVIF dimensions from ispe box following ExifTool's implementation
                         // Reference: QuickTime.pm:2946-2959 (ispe box processing)
-                        match avif::extract_avif_dimensions(&avif_data) {
+                        match avif::extract_avif_dimensions(&avif_data)
+                            .or_else(|e| sequence_dimensions(sequence.as_ref()).ok_or(e))
+                        {
                             Ok(props) => {
                                 // Create AVIF tag entries using ExifTool-compatible structure
                                 // AVIF image dimensions are assigned to "File" group in ExifTool
@@ -1164,14 +1612,17 @@ pub fn extract_metadata(
                             }
                             Err(e) => {
                                 // Failed to parse AVIF ispe box - add error information
-                                tags.insert(
-                                    "Warning:AvifParseError".to_string(),
-                                    TagValue::string(format!("Failed to parse AVIF ispe box: {e}")),
+                                warnings.push(
+                                    ExtractionWarning::new(
+                                        "AvifParseError",
+                                        format!("Failed to parse AVIF ispe box: {e}"),
+                                    )
+                                    .with_context(&detection_result.file_type),
                                 );
                             }
                         }
                     }
-                    "HEIC" | "HEIF" => {
+                    "HEIC" | "HEIF" | "HEICS" | "HEIFS" => {
                         // HEIC/HEIF format processing - extract dimensions from ispe box
                         // HEIC and HEIF use the same ISO Base Media File Format and ispe box structure as AVIF
                         // Reference: QuickTime.pm:2946-2959 (ispe box processing)
@@ -1181,6 +1632,8 @@ pub fn extract_metadata(
                         let mut file_data = Vec::new();
                         reader.read_to_end(&mut file_data)?;
 
+                        let sequence = add_image_sequence_tags(&file_data, &mut tag_entries);
+
                         // Extract dimensions using ExifTool's primary item detection logic
                         // Implements complete pitm/iinf/ipma box processing to identify the main image
                         // 1. Parse pitm box to get primary item ID (QuickTime.pm:3550-3557)
@@ -1189,25 +1642,30 @@ pub fn extract_metadata(
                         // 4. Use DOC_NUM logic to determine primary vs sub-document ispe boxes (QuickTime.pm:6450-6460)
                         //
                         // ExifTool reference: QuickTime.pm:2946-2959 (ispe processing with DOC_NUM check)
-                        match avif::extract_heic_dimensions_primary_item(&file_data) {
+                        // Pure sequences without a still image take the track size
+                        match avif::extract_heic_dimensions_primary_item(&file_data)
+                            .or_else(|e| sequence_dimensions(sequence.as_ref()).ok_or(e))
+                        {
                             Ok(props) => {
                                 // Create File:ImageWidth and File:ImageHeight tag entries
                                 // ExifTool creates File group tags for HEIC/HEIF dimensions
                                 let file_type = &detection_result.file_type;
                                 let mut heic_tag_entries = vec![
                                     TagEntry {
-                                        group: "File".to_string(),
-                                        group1: "File".to_string(),
-                                        name: "ImageWidth".to_string(),
+                                        group: "File".into(),
+                                        group1: "File".into(),
+                                        name: "ImageWidth".into(),
                                         value: TagValue::U32(props.width),
-                                        print: TagValue::U32(props.width),
+                                        print: TagValue::U32(props.width).into(),
+                                        source: None,
                                     },
                                     TagEntry {
-                                        group: "File".to_string(),
-                                        group1: "File".to_string(),
-                                        name: "ImageHeight".to_string(),
+                                        group: "File".into(),
+                                        group1: "File".into(),
+                                        name: "ImageHeight".into(),
                                         value: TagValue::U32(props.height),
-                                        print: TagValue::U32(props.height),
+                                        print: TagValue::U32(props.height).into(),
+                                        source: None,
                                     },
                                 ];
 
@@ -1225,34 +1683,94 @@ pub fn extract_metadata(
                             }
                             Err(e) => {
                                 // Failed to parse HEIC/HEIF ispe box - add error information
-                                tags.insert(
-                                    format!("Warning:{}ParseError", detection_result.file_type),
-                                    TagValue::string(format!(
-                                        "Failed to parse {} ispe box: {e}",
-                                        detection_result.file_type
-                                    )),
+                                warnings.push(
+                                    ExtractionWarning::new(
+                                        format!("{}ParseError", detection_result.file_type),
+                                        format!(
+                                            "Failed to parse {} ispe box: {e}",
+                                            detection_result.file_type
+                                        ),
+                                    )
+                                    .with_context(&detection_result.file_type),
                                 );
                             }
                         }
                     }
+                    #[cfg(feature = "video")]
                     "MOV" | "MP4" => {
-                        // QuickTime / MP4 container: streaming atom walker.
-                        // TPP: _todo/20260703-P1-quicktime-video-read.md (Task 2).
-                        // CR3/HEIC are handled elsewhere / out of scope (TZ trap).
-                        reader.seek(SeekFrom::Start(0))?;
-                        match quicktime::extract_quicktime_metadata(&mut reader) {
-                            Ok(mut qt_entries) => {
-                                tag_entries.append(&mut qt_entries);
+                        // Large videos: hash the mdat on its own thread, with
+                        // its own file handle, while the atoms are parsed
+                        let parallel_hash = extraction_opts
+                            .parallel_hash_min_size
+                            .is_some_and(|min_size| file_size >= min_size);
+                        let background_hash = std::thread::scope(|scope| -> Result<_> {
+                            let hash_task = image_data_hasher
+                                .as_mut()
+                                .filter(|_| parallel_hash)
+                                .map(|hasher| {
+                                    debug!("QuickTime: hashing mdat on a separate thread");
+                                    scope.spawn(move || {
+                                        let mut file = BufReader::new(File::open(path)?);
+                                        quicktime::hash_quicktime_media_data(&mut file, hasher)
+                                    })
+                                });
+
+                            // QuickTime / MP4 container: streaming atom walker.
+                            // TPP: _todo/20260703-P1-quicktime-video-read.md (Task 2).
+                            // CR3/HEIC are handled elsewhere / out of scope (TZ trap).
+                            reader.seek(SeekFrom::Start(0))?;
+                            match quicktime::extract_quicktime_metadata(
+                                &mut reader,
+                                extraction_opts.scan_depth,
+                            ) {
+                                Ok((mut qt_entries, qt_truncation)) => {
+                                    tag_entries.append(&mut qt_entries);
+                                    truncation = truncation.take().or(qt_truncation);
+                                }
+                                Err(e) => {
+                                    // Corrupt container: keep File: tags, note the failure.
+                                    warnings.push(
+                                        ExtractionWarning::new(
+                                            "QuickTimeParseError",
+                                            format!("Failed to parse QuickTime container: {e}"),
+                                        )
+                                        .with_context(&detection_result.file_type),
+                                    );
+                                }
                             }
-                            Err(e) => {
-                                // Corrupt container: keep File: tags, note the failure.
-                                tags.insert(
-                                    "Warning:QuickTimeParseError".to_string(),
-                                    TagValue::string(format!(
-                                        "Failed to parse QuickTime container: {e}"
-                                    )),
-                                );
+
+                            Ok(hash_task.map(|task| {
+                                task.join()
+                                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                            }))
+                        })?;
+
+                        // QuickTime ImageDataHash: stream the mdat contents
+                        // ExifTool: QuickTime.pm ProcessMOV ImageDataHash of 'mdat'
+                        let hashed = match (background_hash, image_data_hasher.as_mut()) {
+                            (Some(hashed), _) => Some(hashed),
+                            (None, Some(hasher)) => {
+                                Some(quicktime::hash_quicktime_media_data(&mut reader, hasher))
+                            }
+                            (None, None) => None,
+                        };
+                        match hashed {
+                            Some(Ok(bytes_hashed)) => {
+                                debug!("QuickTime: hashed {} bytes of mdat", bytes_hashed);
+                            }
+                            Some(Err(e)) => {
+                                debug!("QuickTime: failed to hash mdat: {}", e);
                             }
+                            None => {}
+                        }
+
+                        // Insta360 trailer (.insv videos)
+                        match extract_insta360_trailer(&mut reader) {
+                            Ok(mut insta360_entries) => tag_entries.append(&mut insta360_entries),
+                            Err(e) => warnings.push(
+                                ExtractionWarning::new("Insta360Error", e.to_string())
+                                    .with_context(&detection_result.file_type),
+                            ),
                         }
                     }
                     _ => {
@@ -1284,6 +1802,11 @@ pub fn extract_metadata(
 
     // Collect any missing PrintConv/ValueConv implementations for --show-missing functionality
     let missing_implementations = if show_missing {
+        // PrintConvs are deferred until the display value is read; run them
+        // now so they record their missing implementations for this file
+        for entry in &tag_entries {
+            entry.print();
+        }
         let missing_convs = crate::core::missing::get_missing_conversions();
 
         if missing_convs.is_empty() {
@@ -1345,22 +1868,70 @@ pub fn extract_metadata(
     // This ensures File:ImageWidth/ImageHeight are available for Composite:ImageSize dependency resolution
     // DO NOT move this back to EXIF processing - it will break composite tag dependency resolution
     // See: docs/todo/P10a-exif-required-tags.md "Composite:ImageSize Architecture Fix"
-    let composite_tags = build_composite_tags_from_entries(&tag_entries);
+    let composites_stage = crate::stats::stage("Composites");
+    let mut composite_tags = quicktime_dates::resolve_quicktime_dates(
+        &mut tag_entries,
+        filter_opts.quicktime_utc,
+        file_metadata
+            .modified()
+            .ok()
+            .map(|modified| chrono::DateTime::<chrono::Local>::from(modified).fixed_offset()),
+    );
+    let user_config = crate::user_config::config();
+    if filter_opts.composites {
+        let requested = composites_to_build(&filter_opts, user_config.is_some());
+        composite_tags.extend(build_composite_tags_from_entries(
+            &tag_entries,
+            requested.as_ref(),
+        ));
+    } else {
+        // ExifTool: -e
+        composite_tags.clear();
+    }
 
     // Add composite tags to the tag_entries collection
     let mut all_tag_entries = tag_entries;
     all_tag_entries.extend(composite_tags);
 
+    // User-defined composites can use any tag, including the built-in composites
+    if let Some(config) = user_config.as_ref().filter(|_| filter_opts.composites) {
+        let user_composites = config.build_composites(&all_tag_entries);
+        all_tag_entries.extend(user_composites);
+    }
+    drop(composites_stage);
+
+    // Precedence, conversions, hash finalization, filtering and formatting
+    let output_stage = crate::stats::stage("Output");
+
     // Apply XMP/EXIF precedence rules following ExifTool's Priority system
     // This must happen after all tag extraction but before filtering
-    all_tag_entries = apply_exiftool_precedence_rules(all_tag_entries);
+    all_tag_entries =
+        apply_exiftool_precedence_rules(all_tag_entries, filter_opts.allow_duplicates);
+
+    // Renamed tags can be requested by their new names
+    if let Some(config) = &user_config {
+        config.apply_renames(&mut all_tag_entries);
+    }
+
+    // Caller-supplied PrintConvs replace the built-in ones
+    if let Some(session) = crate::session::current() {
+        session.apply_print_convs(&mut all_tag_entries);
+    }
+
+    // With binary output requested, replace the embedded image placeholders with
+    // the actual bytes so they can be emitted as base64 (ExifTool -b -j)
+    if filter_opts.include_binary {
+        let mut file = File::open(path)?;
+        embedded_images::load_embedded_images(&mut file, &mut all_tag_entries);
+    }
 
     // Finalize ImageDataHash if computed
     // ExifTool: lib/Image/ExifTool.pm:4378-4386 - DoneExtract() finalizes hash
     if let Some(hasher) = image_data_hasher.take() {
         let bytes_hashed = hasher.bytes_hashed();
         let hash_type = hasher.hash_type();
-        if let Some(hash_value) = hasher.finalize() {
+        let (hash_value, chunks) = hasher.finalize_chunked();
+        if let Some(hash_value) = hash_value {
             debug!(
                 "ImageDataHash finalized: {} ({} bytes of image data, algorithm {:?})",
                 hash_value, bytes_hashed, hash_type
@@ -1368,12 +1939,32 @@ pub fn extract_metadata(
             // Add ImageDataHash tag to File group
             // ExifTool: ExifTool.pm:4382 - $self->FoundTag(ImageDataHash => $digest)
             all_tag_entries.push(TagEntry {
-                group: "File".to_string(),
-                group1: "File".to_string(),
-                name: "ImageDataHash".to_string(),
+                group: "File".into(),
+                group1: "File".into(),
+                name: "ImageDataHash".into(),
                 value: TagValue::String(hash_value.clone()),
-                print: TagValue::String(hash_value),
+                print: TagValue::String(hash_value).into(),
+                source: None,
             });
+            // Chunk digests for partial verification (exif-oxide extension)
+            if let Some(chunks) = chunks {
+                let chunk_size = TagValue::U64(chunks.chunk_size);
+                let digests =
+                    TagValue::Array(chunks.digests.into_iter().map(TagValue::String).collect());
+                for (name, value) in [
+                    ("ImageDataHashChunkSize", chunk_size),
+                    ("ImageDataHashChunks", digests),
+                ] {
+                    all_tag_entries.push(TagEntry {
+                        group: "File".into(),
+                        group1: "File".into(),
+                        name: name.into(),
+                        value: value.clone(),
+                        print: value.into(),
+                        source: None,
+                    });
+                }
+            }
         } else {
             debug!(
                 "ImageDataHash suppressed: empty hash (no image data found, {} bytes checked)",
@@ -1382,6 +1973,27 @@ pub fn extract_metadata(
         }
     }
 
+    // Reverse geocode the GPS position. Added before filtering so that
+    // individual Geolocation tags can be requested, as in ExifTool
+    if filter_opts.geolocation {
+        let geolocation_tags = crate::geolocation::geolocate(&all_tag_entries);
+        all_tag_entries.extend(geolocation_tags);
+    }
+
+    // Local time zone from the GPS position, for files without a recorded offset
+    #[cfg(feature = "timezone")]
+    {
+        let time_zone_tags = crate::geolocation::infer_time_zone(&all_tag_entries);
+        all_tag_entries.extend(time_zone_tags);
+    }
+
+    // Forensic consistency checks run over every tag, before filtering
+    let validation_tags = if filter_opts.validate {
+        crate::validation::validate(&all_tag_entries)
+    } else {
+        Vec::new()
+    };
+
     // P12: CENTRAL FILTERING CHOKEPOINT - Apply ExifTool-style filtering
     // Matches ExifTool's FoundTag architecture: all tags go through single filtering point
     let filtered_tag_entries = if let Some(filter_opts) = &filter_options {
@@ -1392,16 +2004,8 @@ pub fn extract_metadata(
             // Apply allowlist filtering like ExifTool's REQ_TAG_LOOKUP + IgnoreTags={all => 1}
             all_tag_entries
                 .into_iter()
-                .filter_map(|mut tag_entry| {
-                    if filter_opts.should_extract_tag(&tag_entry.name, &tag_entry.group) {
-                        // P12: Apply numeric value control (# suffix) - use ValueConv instead of PrintConv
-                        if filter_opts.should_use_numeric(&tag_entry.name) {
-                            tag_entry.print = tag_entry.value.clone();
-                        }
-                        Some(tag_entry)
-                    } else {
-                        None
-                    }
+                .filter(|tag_entry| {
+                    filter_opts.should_extract_tag(&tag_entry.name, &tag_entry.group)
                 })
                 .collect()
         }
@@ -1410,6 +2014,25 @@ pub fn extract_metadata(
         all_tag_entries
     };
 
+    let mut filtered_tag_entries = filtered_tag_entries;
+    filtered_tag_entries.extend(validation_tags);
+
+    // Tag provenance is dropped unless it was requested. Binary values are
+    // kept; they are summarized when serialized (ExifData::format_binary_values)
+    let filtered_tag_entries = filtered_tag_entries
+        .into_iter()
+        .map(|mut tag_entry| {
+            // P12: Apply numeric value control (-n, # suffix) - use ValueConv instead of PrintConv
+            if filter_opts.should_use_numeric(&tag_entry.name) {
+                tag_entry.set_print(tag_entry.value.clone());
+            }
+            if !filter_opts.include_tag_sources {
+                tag_entry.source = None;
+            }
+            tag_entry
+        })
+        .collect();
+
     // Set tag entries (new API)
     exif_data.tags = filtered_tag_entries;
 
@@ -1438,12 +2061,76 @@ pub fn extract_metadata(
     // Set legacy tags for backward compatibility
     exif_data.legacy_tags = filtered_legacy_tags;
 
+    // Warnings aren't tags, so the tag filters don't apply to them
+    if let Some(truncation) = &truncation {
+        warnings.push(
+            truncation
+                .to_warning()
+                .with_context(&detection_result.file_type),
+        );
+    }
+    exif_data.warnings = warnings;
+    exif_data.truncation = truncation;
+    exif_data.xmp_packet = xmp_packet;
+    exif_data.xmp_provenance = xmp_provenance;
+
     // Set missing implementations if requested
     exif_data.missing_implementations = missing_implementations;
 
+    drop(output_stage);
+    exif_data.stats = stats.map(|stats| stats.finish(&exif_data.tags));
+    metrics.finish(&file_type, &exif_data.warnings);
+
     Ok(exif_data)
 }
 
+/// Hash the image data of a file read from any seekable source
+///
+/// Computes the same value [`extract_metadata`] outputs as `ImageDataHash`,
+/// without extracting any metadata or needing a path. The file type is
+/// detected from the content; JPEG, PNG and (with the `video` feature)
+/// MOV/MP4 are supported. Returns the number of bytes hashed, which is 0 for
+/// unsupported types. Set a progress callback on the hasher with
+/// [`ImageDataHasher::with_progress`](crate::hash::ImageDataHasher::with_progress)
+/// to follow long video scans.
+///
+/// # Examples
+/// ```no_run
+/// use exif_oxide::formats::hash_image_data;
+/// use exif_oxide::hash::{ImageDataHasher, ImageHashType};
+/// use std::io::Cursor;
+///
+/// let bytes = std::fs::read("video.mp4").unwrap();
+/// let mut hasher = ImageDataHasher::new(ImageHashType::Xxh3)
+///     .with_progress(|done| eprintln!("{done} bytes hashed"));
+/// hash_image_data(&mut Cursor::new(bytes), &mut hasher).unwrap();
+/// println!("{:?}", hasher.finalize());
+/// ```
+pub fn hash_image_data<R: Read + Seek>(
+    reader: &mut R,
+    hasher: &mut crate::hash::ImageDataHasher,
+) -> Result<u64> {
+    reader.seek(SeekFrom::Start(0))?;
+    let mut magic = Vec::with_capacity(1024);
+    reader.by_ref().take(1024).read_to_end(&mut magic)?;
+    let Ok(detected) = crate::file_detection::detect_file_type_from_bytes(&magic) else {
+        return Ok(0);
+    };
+
+    reader.seek(SeekFrom::Start(0))?;
+    match detected.file_type.as_str() {
+        "JPEG" => hash_jpeg_scan_data(reader, hasher),
+        "PNG" => {
+            let mut png_data = Vec::new();
+            reader.read_to_end(&mut png_data)?;
+            png::hash_png_image_data(&png_data, hasher).map(|bytes| bytes as u64)
+        }
+        #[cfg(feature = "video")]
+        "MOV" | "MP4" => quicktime::hash_quicktime_media_data(reader, hasher),
+        _ => Ok(0),
+    }
+}
+
 /// Add ExifByteOrder tag based on TIFF header information
 /// ExifTool.pm:1795-1805 - ExifByteOrder tag
 fn add_exif_byte_order_tag(exif_reader: &ExifReader, tag_entries: &mut Vec<TagEntry>) {
@@ -1456,15 +2143,90 @@ fn add_exif_byte_order_tag(exif_reader: &ExifReader, tag_entries: &mut Vec<TagEn
         };
 
         tag_entries.push(TagEntry {
-            group: "File".to_string(),
-            group1: "File".to_string(),
-            name: "ExifByteOrder".to_string(),
+            group: "File".into(),
+            group1: "File".into(),
+            name: "ExifByteOrder".into(),
             value: TagValue::String(byte_order_str.to_string()),
-            print: TagValue::String(byte_order_str.to_string()),
+            print: TagValue::String(byte_order_str.to_string()).into(),
+            source: None,
         });
     }
 }
 
+/// Add AnimationFrameCount, Duration and FrameRate tags for an AVIF/HEIF image
+/// sequence, and mark the FileType as a sequence when the major brand names
+/// the still image type (an 'avif' file that also holds an animation track)
+fn add_image_sequence_tags(
+    data: &[u8],
+    tag_entries: &mut Vec<TagEntry>,
+) -> Option<avif::ImageSequenceInfo> {
+    let sequence = avif::extract_image_sequence_info(data)?;
+    tag_entries.extend(avif::create_image_sequence_tag_entries(&sequence));
+
+    let still_type = tag_entries
+        .iter()
+        .find(|entry| entry.name == "FileType" && entry.group == "File")
+        .and_then(|entry| entry.value.as_string().map(str::to_string));
+    let sequence_type = still_type
+        .as_deref()
+        .and_then(crate::file_detection::image_sequence_type);
+    if let Some(sequence_type) = sequence_type {
+        let mime_type = crate::file_detection::build_result(sequence_type, Path::new(""))
+            .map(|result| result.mime_type)
+            .ok();
+        for entry in tag_entries.iter_mut().filter(|entry| entry.group == "File") {
+            let (value, print) = match entry.name.as_str() {
+                "FileType" => (sequence_type.to_string(), sequence_type.to_string()),
+                "FileTypeExtension" => (sequence_type.to_string(), sequence_type.to_lowercase()),
+                "MIMEType" => match &mime_type {
+                    Some(mime_type) => (mime_type.clone(), mime_type.clone()),
+                    None => continue,
+                },
+                _ => continue,
+            };
+            entry.value = TagValue::String(value);
+            entry.set_print(TagValue::String(print));
+        }
+    }
+    Some(sequence)
+}
+
+/// Image size of a sequence track, for sequences without an 'ispe' still image
+fn sequence_dimensions(
+    sequence: Option<&avif::ImageSequenceInfo>,
+) -> Option<avif::AvifImageProperties> {
+    sequence
+        .filter(|sequence| sequence.width > 0 && sequence.height > 0)
+        .map(|sequence| avif::AvifImageProperties {
+            width: sequence.width,
+            height: sequence.height,
+        })
+}
+
+/// XAttr tags of the file, if any are requested
+///
+/// Like ExifTool, extended attributes are only read when tags are requested
+/// by name, wildcard or the File group, not for everything (-all).
+#[cfg(feature = "xattr")]
+fn requested_xattr_tags(path: &Path, filter_opts: &FilterOptions) -> Vec<TagEntry> {
+    if filter_opts.extract_all || !filter_opts.has_specific_requests() {
+        return Vec::new();
+    }
+    match xattr::read_xattr_tags(path) {
+        Ok(entries) => entries
+            .into_iter()
+            .filter(|entry| filter_opts.should_extract_tag(&entry.name, &entry.group))
+            .collect(),
+        Err(e) => {
+            tracing::debug!(
+                "Could not read extended attributes of {}: {e}",
+                path.display()
+            );
+            Vec::new()
+        }
+    }
+}
+
 /// Format Unix file permissions to match ExifTool's format
 /// ExifTool.pm:1486-1517 - Converts octal mode to rwx string
 #[cfg(unix)]
@@ -1559,9 +2321,42 @@ fn extract_rw2_jpeg_preview_dimensions(
     }
 }
 
+/// Lowercase names of the built-in composites to build, or `None` for all
+///
+/// Filtered extractions only build the composites they output and those that
+/// other output tags are computed from: user-defined composites may read any
+/// tag, and geolocation and time zone inference read the GPS position (and
+/// BestDateTime).
+fn composites_to_build(
+    filter_opts: &FilterOptions,
+    user_composites: bool,
+) -> Option<HashSet<String>> {
+    if user_composites {
+        return None;
+    }
+    let mut composites = filter_opts.requested_composites()?;
+    let infer_time_zone = cfg!(feature = "timezone")
+        && ["InferredTimeZone", "InferredDateTime"]
+            .iter()
+            .any(|name| filter_opts.should_extract_tag(name, "Composite"));
+    if filter_opts.geolocation || infer_time_zone {
+        composites.extend(["GPSLatitude", "GPSLongitude"]);
+    }
+    if infer_time_zone {
+        composites.push("BestDateTime");
+    }
+    Some(crate::composite_tags::composites_to_build(&composites))
+}
+
 /// Build composite tags from a collection of TagEntry objects
 /// This enables composite tag processing after all tags (including File group tags) are available
-fn build_composite_tags_from_entries(tag_entries: &[TagEntry]) -> Vec<TagEntry> {
+///
+/// Only the composites in `requested` are built when given; see
+/// [`composites_to_build`].
+fn build_composite_tags_from_entries(
+    tag_entries: &[TagEntry],
+    requested: Option<&HashSet<String>>,
+) -> Vec<TagEntry> {
     use crate::composite_tags::TagDependencyValues;
 
     // Convert TagEntry collection to the format expected by composite tag processing
@@ -1571,23 +2366,43 @@ fn build_composite_tags_from_entries(tag_entries: &[TagEntry]) -> Vec<TagEntry>
     for entry in tag_entries {
         // Create TagDependencyValues from TagEntry
         // raw = val = entry.value (ValueConv'd value, we don't have true raw here)
-        // prt = entry.print (PrintConv'd value)
+        // prt = entry.print() (PrintConv'd value), only run for tags a composite reads
+        let prt = if crate::composite_tags::is_dependency(&entry.name) {
+            entry.print().clone()
+        } else {
+            entry.value.clone()
+        };
         let dep_values = TagDependencyValues {
             raw: entry.value.clone(),
             val: entry.value.clone(),
-            prt: entry.print.clone(),
+            prt,
         };
 
         // Add with group prefix (e.g., "File:ImageWidth")
         let prefixed_name = format!("{}:{}", entry.group, entry.name);
         available_tags.insert(prefixed_name, dep_values.clone());
 
+        // Require/Desire may also qualify by family 1 group (e.g., "Apple:RunTimeValue")
+        // ExifTool: lib/Image/ExifTool.pm BuildCompositeTags matches any group family
+        if entry.group1 != entry.group {
+            let group1_name = format!("{}:{}", entry.group1, entry.name);
+            available_tags
+                .entry(group1_name)
+                .or_insert_with(|| dep_values.clone());
+        }
+
         // Also add without group prefix for broader matching (e.g., "ImageWidth")
-        available_tags.insert(entry.name.clone(), dep_values);
+        available_tags.insert(entry.name.to_string(), dep_values);
     }
 
     // Delegate to the composite tag processing system
-    let computed_composites = crate::composite_tags::resolve_and_compute_composites(available_tags);
+    let computed_composites = match requested {
+        Some(requested) => crate::composite_tags::resolve_and_compute_requested_composites(
+            available_tags,
+            requested,
+        ),
+        None => crate::composite_tags::resolve_and_compute_composites(available_tags),
+    };
 
     // Convert results back to TagEntry format
     let mut composite_tag_entries = Vec::new();
@@ -1604,11 +2419,12 @@ fn build_composite_tags_from_entries(tag_entries: &[TagEntry]) -> Vec<TagEntry>
         // Apply PrintConv to get human-readable representation
         // For now, assume value and print are the same - PrintConv will be applied during tag processing
         composite_tag_entries.push(TagEntry {
-            group: "Composite".to_string(),
-            group1: "Composite".to_string(),
-            name: tag_name.to_string(),
+            group: "Composite".into(),
+            group1: "Composite".into(),
+            name: tag_name.into(),
             value: value.clone(),
-            print: value, // PrintConv already applied by composite processing
+            print: value.into(), // PrintConv already applied by composite processing
+            source: None,
         });
     }
 
@@ -1617,7 +2433,7 @@ fn build_composite_tags_from_entries(tag_entries: &[TagEntry]) -> Vec<TagEntry>
 
 /// Extract only File group tags for performance optimization
 /// This function handles requests for File-only tags without expensive format parsing
-fn extract_file_tags_only(
+pub(crate) fn extract_file_tags_only(
     path: &Path,
     show_missing: bool,
     filter_opts: &FilterOptions,
@@ -1641,11 +2457,12 @@ fn extract_file_tags_only(
 
     if filter_opts.should_extract_tag("FileName", "File") {
         tag_entries.push(TagEntry {
-            group: "File".to_string(),
-            group1: "System".to_string(),
-            name: "FileName".to_string(),
+            group: "File".into(),
+            group1: "System".into(),
+            name: "FileName".into(),
             value: TagValue::String(filename.clone()),
-            print: TagValue::String(filename),
+            print: TagValue::String(filename).into(),
+            source: None,
         });
     }
 
@@ -1657,21 +2474,23 @@ fn extract_file_tags_only(
 
     if filter_opts.should_extract_tag("Directory", "File") {
         tag_entries.push(TagEntry {
-            group: "File".to_string(),
-            group1: "System".to_string(),
-            name: "Directory".to_string(),
+            group: "File".into(),
+            group1: "System".into(),
+            name: "Directory".into(),
             value: TagValue::String(directory.clone()),
-            print: TagValue::String(directory),
+            print: TagValue::String(directory).into(),
+            source: None,
         });
     }
 
     if filter_opts.should_extract_tag("FileSize", "File") {
         tag_entries.push(TagEntry {
-            group: "File".to_string(),
-            group1: "System".to_string(),
-            name: "FileSize".to_string(),
+            group: "File".into(),
+            group1: "System".into(),
+            name: "FileSize".into(),
             value: TagValue::U64(file_size),
-            print: TagValue::U64(file_size),
+            print: TagValue::U64(file_size).into(),
+            source: None,
         });
     }
 
@@ -1682,11 +2501,12 @@ fn extract_file_tags_only(
             let datetime: DateTime<Local> = modified.into();
             let formatted = datetime.format("%Y:%m:%d %H:%M:%S%:z").to_string();
             tag_entries.push(TagEntry {
-                group: "File".to_string(),
-                group1: "System".to_string(),
-                name: "FileModifyDate".to_string(),
+                group: "File".into(),
+                group1: "System".into(),
+                name: "FileModifyDate".into(),
                 value: TagValue::String(formatted.clone()),
-                print: TagValue::String(formatted),
+                print: TagValue::String(formatted).into(),
+                source: None,
             });
         }
     }
@@ -1697,11 +2517,47 @@ fn extract_file_tags_only(
             let datetime: DateTime<Local> = accessed.into();
             let formatted = datetime.format("%Y:%m:%d %H:%M:%S%:z").to_string();
             tag_entries.push(TagEntry {
-                group: "File".to_string(),
-                group1: "System".to_string(),
-                name: "FileAccessDate".to_string(),
+                group: "File".into(),
+                group1: "System".into(),
+                name: "FileAccessDate".into(),
+                value: TagValue::String(formatted.clone()),
+                print: TagValue::String(formatted).into(),
+                source: None,
+            });
+        }
+    }
+
+    #[cfg(any(target_os = "windows", target_os = "macos"))]
+    if filter_opts.should_extract_tag("FileCreateDate", "File") {
+        if let Ok(created) = file_metadata.created() {
+            use chrono::{DateTime, Local};
+            let datetime: DateTime<Local> = created.into();
+            let formatted = datetime.format("%Y:%m:%d %H:%M:%S%:z").to_string();
+            tag_entries.push(TagEntry {
+                group: "File".into(),
+                group1: "System".into(),
+                name: "FileCreateDate".into(),
                 value: TagValue::String(formatted.clone()),
-                print: TagValue::String(formatted),
+                print: TagValue::String(formatted).into(),
+                source: None,
+            });
+        }
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    if filter_opts.should_extract_tag("FileInodeChangeDate", "File") {
+        use chrono::{Local, TimeZone};
+        if let Some(datetime) =
+            get_unix_ctime(path).and_then(|ctime| Local.timestamp_opt(ctime as i64, 0).single())
+        {
+            let formatted = datetime.format("%Y:%m:%d %H:%M:%S%:z").to_string();
+            tag_entries.push(TagEntry {
+                group: "File".into(),
+                group1: "System".into(),
+                name: "FileInodeChangeDate".into(),
+                value: TagValue::String(formatted.clone()),
+                print: TagValue::String(formatted).into(),
+                source: None,
             });
         }
     }
@@ -1713,16 +2569,28 @@ fn extract_file_tags_only(
         let mode = file_metadata.permissions().mode();
         let permissions_str = format_unix_permissions(mode);
         tag_entries.push(TagEntry {
-            group: "File".to_string(),
-            group1: "System".to_string(),
-            name: "FilePermissions".to_string(),
+            group: "File".into(),
+            group1: "System".into(),
+            name: "FilePermissions".into(),
             value: TagValue::String(permissions_str.clone()),
-            print: TagValue::String(permissions_str),
+            print: TagValue::String(permissions_str).into(),
+            source: None,
         });
     }
 
+    #[cfg(windows)]
+    tag_entries.extend(windows::windows_file_tags(
+        path,
+        &file_metadata,
+        filter_opts,
+    ));
+
+    #[cfg(feature = "xattr")]
+    tag_entries.extend(requested_xattr_tags(path, filter_opts));
+
     // For FileType, FileTypeExtension, and MIMEType, we need basic file detection
     // This is much lighter than full format parsing
+    let mut warning = None;
     if filter_opts.should_extract_tag("FileType", "File")
         || filter_opts.should_extract_tag("FileTypeExtension", "File")
         || filter_opts.should_extract_tag("MIMEType", "File")
@@ -1730,17 +2598,18 @@ fn extract_file_tags_only(
         // We need to open the file briefly for magic number detection
         let file = File::open(path)?;
         let mut reader = BufReader::new(file);
-        let detector = FileTypeDetector::new();
-        let detection_result = detector.detect_file_type(path, &mut reader)?;
+        let detection_result = handler::detect_file_type(path, &mut reader)?;
+        warning = extension_mismatch_warning(path, &detection_result);
 
         if filter_opts.should_extract_tag("FileType", "File") {
             let file_type = detection_result.file_type.clone();
             tag_entries.push(TagEntry {
-                group: "File".to_string(),
-                group1: "File".to_string(),
-                name: "FileType".to_string(),
+                group: "File".into(),
+                group1: "File".into(),
+                name: "FileType".into(),
                 value: TagValue::String(file_type.clone()),
-                print: TagValue::String(file_type),
+                print: TagValue::String(file_type).into(),
+                source: None,
             });
         }
 
@@ -1754,22 +2623,24 @@ fn extract_file_tags_only(
             let print_value = norm_ext.to_lowercase();
 
             tag_entries.push(TagEntry {
-                group: "File".to_string(),
-                group1: "File".to_string(),
-                name: "FileTypeExtension".to_string(),
+                group: "File".into(),
+                group1: "File".into(),
+                name: "FileTypeExtension".into(),
                 value: TagValue::String(raw_value),
-                print: TagValue::String(print_value),
+                print: TagValue::String(print_value).into(),
+                source: None,
             });
         }
 
         if filter_opts.should_extract_tag("MIMEType", "File") {
             let mime_type = detection_result.mime_type.clone();
             tag_entries.push(TagEntry {
-                group: "File".to_string(),
-                group1: "File".to_string(),
-                name: "MIMEType".to_string(),
+                group: "File".into(),
+                group1: "File".into(),
+                name: "MIMEType".into(),
                 value: TagValue::String(mime_type.clone()),
-                print: TagValue::String(mime_type),
+                print: TagValue::String(mime_type).into(),
+                source: None,
             });
         }
     }
@@ -1780,6 +2651,7 @@ fn extract_file_tags_only(
 
     // Set tag entries
     exif_data.tags = tag_entries;
+    exif_data.warnings.extend(warning);
 
     // Handle missing implementations if requested
     if show_missing {
@@ -1796,6 +2668,34 @@ fn extract_file_tags_only(
     Ok(exif_data)
 }
 
+/// Warning for a file whose content is a different type than its extension
+/// says. FileType, FileTypeExtension and MIMEType already report the type the
+/// content was detected as.
+fn extension_mismatch_warning(
+    path: &Path,
+    detection: &crate::file_detection::FileTypeDetectionResult,
+) -> Option<ExtractionWarning> {
+    use crate::generated::ExifTool_pm::file_type_ext::lookup_file_type_ext;
+
+    let extension_type = crate::file_detection::extension_mismatch(path, detection)?;
+    let expected_ext = lookup_file_type_ext(&detection.file_type)
+        .map(|ext| ext.to_lowercase())
+        .unwrap_or_else(|| detection.file_type.to_lowercase());
+    tracing::warn!(
+        "{}: {} file has the extension of a {} file",
+        path.display(),
+        detection.file_type,
+        extension_type
+    );
+    Some(
+        ExtractionWarning::new(
+            "FileExtensionMismatch",
+            format!("File has wrong extension (should be {expected_ext})"),
+        )
+        .with_context(detection.file_type.clone()),
+    )
+}
+
 /// Check if a tag name belongs to the File group (for performance optimization)
 fn is_file_group_tag(tag_name: &str) -> bool {
     matches!(
@@ -1808,6 +2708,8 @@ fn is_file_group_tag(tag_name: &str) -> bool {
             | "fileinodechangedate"
             | "filecreatedate"
             | "filepermissions"
+            | "fileattributes"
+            | "fileowner"
             | "filetype"
             | "filetypeextension"
             | "mimetype"
@@ -1848,7 +2750,13 @@ fn get_unix_ctime(path: &Path) -> Option<u64> {
 /// - File group tags have highest priority
 ///
 /// This ensures that EXIF:Make beats XMP:Make, EXIF:CreateDate beats XMP:CreateDate, etc.
-fn apply_exiftool_precedence_rules(tag_entries: Vec<TagEntry>) -> Vec<TagEntry> {
+///
+/// With `allow_duplicates` (ExifTool -a) the losing copies are kept after
+/// the winner, and serialization numbers them.
+fn apply_exiftool_precedence_rules(
+    tag_entries: Vec<TagEntry>,
+    allow_duplicates: bool,
+) -> Vec<TagEntry> {
     use std::collections::HashMap;
 
     // Group tags by "Group:Name" (the JSON output key) to find actual conflicts
@@ -1885,24 +2793,28 @@ fn apply_exiftool_precedence_rules(tag_entries: Vec<TagEntry>) -> Vec<TagEntry>
             // This handles rare cases where the same Group:Name appears multiple times
             // (e.g., from different processing paths)
 
-            // Sort by priority (highest first)
+            // Sort by priority (highest first). IFD1 describes the thumbnail, so
+            // the main image's IFD0 Compression/XResolution/... win over it
             conflicting_tags.sort_by(|a, b| {
                 get_tag_priority(&a.group, &a.name)
                     .cmp(&get_tag_priority(&b.group, &b.name))
                     .reverse()
+                    .then_with(|| (a.group1 == "IFD1").cmp(&(b.group1 == "IFD1")))
             });
 
             // Take the highest priority tag as the winner
-            if let Some(winner) = conflicting_tags.first() {
+            if allow_duplicates {
+                resolved_tags.extend(conflicting_tags.iter().cloned());
+            } else if let Some(winner) = conflicting_tags.first() {
                 resolved_tags.push(winner.clone());
             }
 
             // Debug logging for precedence decisions
             if conflicting_tags.len() > 1 {
                 let winner_group = &conflicting_tags[0].group;
-                let loser_groups: Vec<String> = conflicting_tags[1..]
+                let loser_groups: Vec<&str> = conflicting_tags[1..]
                     .iter()
-                    .map(|t| t.group.clone())
+                    .map(|t| t.group.as_str())
                     .collect();
                 tracing::debug!(
                     "Tag precedence: {} - winner: {}, overridden: {:?}",
@@ -1999,45 +2911,672 @@ fn is_xmp_high_priority_tag(tag_name: &str) -> bool {
 mod tests {
     use super::*;
 
+    /// Little-endian TIFF with IFD0 Make="Canon" (at offset 38) and Orientation=1 (inline)
+    fn minimal_tiff() -> Vec<u8> {
+        let mut tiff = b"II*\0".to_vec();
+        tiff.extend_from_slice(&8u32.to_le_bytes());
+        tiff.extend_from_slice(&2u16.to_le_bytes());
+        // Make: ASCII, count 6, value at offset 38
+        tiff.extend_from_slice(&[0x0f, 0x01, 0x02, 0x00]);
+        tiff.extend_from_slice(&6u32.to_le_bytes());
+        tiff.extend_from_slice(&38u32.to_le_bytes());
+        // Orientation: SHORT, count 1, inline value 1
+        tiff.extend_from_slice(&[0x12, 0x01, 0x03, 0x00]);
+        tiff.extend_from_slice(&1u32.to_le_bytes());
+        tiff.extend_from_slice(&[0x01, 0x00, 0x00, 0x00]);
+        // No next IFD
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+        tiff.extend_from_slice(b"Canon\0");
+        tiff
+    }
+
+    #[test]
+    fn test_hash_image_data_png() {
+        use crate::hash::{ImageDataHasher, ImageHashType};
+        use std::io::Cursor;
+
+        let chunk = |kind: &[u8], data: &[u8]| {
+            let mut chunk = (data.len() as u32).to_be_bytes().to_vec();
+            chunk.extend_from_slice(kind);
+            chunk.extend_from_slice(data);
+            chunk.extend_from_slice(&[0; 4]); // CRC isn't checked
+            chunk
+        };
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png.extend(chunk(b"IHDR", &[0, 0, 0, 1, 0, 0, 0, 1, 8, 0, 0, 0, 0]));
+        png.extend(chunk(b"IDAT", b"pixels"));
+        png.extend(chunk(b"IEND", b""));
+
+        let mut hasher = ImageDataHasher::new(ImageHashType::Sha1);
+        let bytes = hash_image_data(&mut Cursor::new(png), &mut hasher).unwrap();
+        assert_eq!(bytes, 6);
+
+        let mut expected = ImageDataHasher::new(ImageHashType::Sha1);
+        expected.update(b"pixels");
+        assert_eq!(hasher.finalize(), expected.finalize());
+
+        let mut hasher = ImageDataHasher::new(ImageHashType::Md5);
+        let text = b"not an image".to_vec();
+        assert_eq!(
+            hash_image_data(&mut Cursor::new(text), &mut hasher).unwrap(),
+            0
+        );
+    }
+
+    #[cfg(feature = "video")]
+    #[test]
+    fn test_parallel_video_hash() {
+        use std::io::Write;
+
+        let mut mp4 = 20u32.to_be_bytes().to_vec();
+        mp4.extend_from_slice(b"ftypisom\0\0\0\0isom");
+        mp4.extend_from_slice(&13u32.to_be_bytes());
+        mp4.extend_from_slice(b"mdatvideo");
+        let mut file = tempfile::Builder::new().suffix(".mp4").tempfile().unwrap();
+        file.write_all(&mp4).unwrap();
+
+        let image_data_hash = |parallel_hash_min_size| {
+            let filter = FilterOptions {
+                compute_image_hash: true,
+                parallel_hash_min_size,
+                ..FilterOptions::default()
+            };
+            let exif_data = extract_metadata(file.path(), false, false, Some(filter)).unwrap();
+            exif_data
+                .get_tag_by_group("File", "ImageDataHash")
+                .map(|tag| tag.value.clone())
+        };
+
+        let sequential = image_data_hash(None);
+        assert!(sequential.is_some());
+        // A minimum size of 0 hashes every video on its own thread
+        assert_eq!(image_data_hash(Some(0)), sequential);
+    }
+
+    #[test]
+    fn test_tag_sources() {
+        use std::io::Write;
+
+        let mut file = tempfile::Builder::new().suffix(".tif").tempfile().unwrap();
+        file.write_all(&minimal_tiff()).unwrap();
+
+        let filter = FilterOptions {
+            include_tag_sources: true,
+            ..FilterOptions::default()
+        };
+        let exif_data = extract_metadata(file.path(), false, false, Some(filter)).unwrap();
+
+        let make = exif_data.get_tag_by_group("EXIF", "Make").unwrap();
+        let source = make.source.as_ref().unwrap();
+        assert_eq!(source.table, "IFD0");
+        assert_eq!(source.tag_id.as_deref(), Some("0x010f"));
+        assert_eq!(source.offset, Some(38));
+        assert_eq!(source.format.as_deref(), Some("string"));
+        assert_eq!(source.count, Some(6));
+
+        // Values of 4 bytes or less live in the IFD entry itself
+        let orientation = exif_data.get_tag_by_group("EXIF", "Orientation").unwrap();
+        let source = orientation.source.as_ref().unwrap();
+        assert_eq!(source.offset, Some(30));
+        assert_eq!(source.format.as_deref(), Some("int16u"));
+
+        // Provenance is only kept when requested
+        let exif_data = extract_metadata(file.path(), false, false, None).unwrap();
+        assert!(exif_data.tags.iter().all(|tag| tag.source.is_none()));
+    }
+
+    #[test]
+    fn test_show_missing_reports_deferred_print_conv() {
+        use std::io::Write;
+
+        // IFD0 ModifyDate, whose PrintConv (ConvertDateTime) isn't implemented
+        let mut tiff = b"II*\0".to_vec();
+        tiff.extend_from_slice(&8u32.to_le_bytes());
+        tiff.extend_from_slice(&1u16.to_le_bytes());
+        tiff.extend_from_slice(&[0x32, 0x01, 0x02, 0x00]);
+        tiff.extend_from_slice(&20u32.to_le_bytes());
+        tiff.extend_from_slice(&26u32.to_le_bytes());
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+        tiff.extend_from_slice(b"2020:01:02 03:04:05\0");
+        let mut file = tempfile::Builder::new().suffix(".tif").tempfile().unwrap();
+        file.write_all(&tiff).unwrap();
+
+        crate::core::missing::clear_missing_conversions();
+        let exif_data = extract_metadata(file.path(), true, false, None).unwrap();
+        let missing = exif_data.missing_implementations.unwrap_or_default();
+        assert!(
+            missing.iter().any(|m| m.contains("ConvertDateTime")),
+            "{missing:?}"
+        );
+    }
+
+    #[test]
+    fn test_requested_composite_extracts_its_sources() {
+        use std::io::Write;
+
+        let mut file = tempfile::Builder::new().suffix(".tif").tempfile().unwrap();
+        file.write_all(&minimal_tiff()).unwrap();
+
+        // The TIFF has no dates, so BestDateTime can only come from
+        // File:FileModifyDate, which the filter doesn't name
+        let filter = FilterOptions::tags_only(vec!["Composite:BestDateTime".to_string()]);
+        let exif_data = extract_metadata(file.path(), false, false, Some(filter)).unwrap();
+
+        let names: Vec<&str> = exif_data.tags.iter().map(|tag| &*tag.name).collect();
+        assert_eq!(names, ["BestDateTime"]);
+    }
+
+    #[test]
+    fn test_composites_disabled_or_limited_to_requested() {
+        use std::io::Write;
+
+        let mut file = tempfile::Builder::new().suffix(".tif").tempfile().unwrap();
+        file.write_all(&minimal_tiff()).unwrap();
+
+        let exif_data = extract_metadata(file.path(), false, false, None).unwrap();
+        assert!(exif_data.tags.iter().any(|tag| tag.group == "Composite"));
+
+        let filter = FilterOptions {
+            composites: false,
+            ..FilterOptions::default()
+        };
+        let exif_data = extract_metadata(file.path(), false, false, Some(filter)).unwrap();
+        assert!(exif_data.tags.iter().all(|tag| tag.group != "Composite"));
+
+        let entry = |group: &str, name: &str, value: TagValue| TagEntry {
+            group: group.into(),
+            group1: group.into(),
+            name: name.into(),
+            value: value.clone(),
+            print: value.into(),
+            source: None,
+        };
+        let entries = [
+            entry("File", "ImageWidth", TagValue::U32(640)),
+            entry("File", "ImageHeight", TagValue::U32(480)),
+            entry("File", "FileModifyDate", "2024:01:02 03:04:05".into()),
+        ];
+        let all = build_composite_tags_from_entries(&entries, None);
+        assert!(all.iter().any(|tag| tag.name == "BestDateTime"));
+
+        let requested = crate::composite_tags::composites_to_build(&["ImageSize"]);
+        let names: Vec<String> = build_composite_tags_from_entries(&entries, Some(&requested))
+            .into_iter()
+            .map(|tag| tag.name.to_string())
+            .collect();
+        assert_eq!(names, ["ImageSize"]);
+    }
+
+    #[test]
+    fn test_stats_report_stages_and_tag_counts() {
+        use std::io::Write;
+
+        let tiff = minimal_tiff();
+        let mut file = tempfile::Builder::new().suffix(".tif").tempfile().unwrap();
+        file.write_all(&tiff).unwrap();
+
+        let filter = FilterOptions {
+            stats: true,
+            ..FilterOptions::default()
+        };
+        let exif_data = extract_metadata(file.path(), false, false, Some(filter)).unwrap();
+        let stats = exif_data.stats.unwrap();
+        let stage = |name: &str| {
+            stats
+                .stages
+                .iter()
+                .find(|stage| stage.stage == name)
+                .unwrap_or_else(|| panic!("{name} missing"))
+        };
+        assert_eq!(stage("Detection").calls, 1);
+        assert_eq!(stage("EXIF").bytes_read, tiff.len() as u64);
+        assert_eq!(stage("Composites").calls, 1);
+        assert!(stats.bytes_read >= tiff.len() as u64);
+        let exif_tags = exif_data
+            .tags
+            .iter()
+            .filter(|tag| tag.group == "EXIF")
+            .count();
+        assert_eq!(stats.tag_counts["EXIF"], exif_tags);
+
+        // Not reported unless requested
+        let exif_data = extract_metadata(file.path(), false, false, None).unwrap();
+        assert_eq!(exif_data.stats, None);
+    }
+
+    #[test]
+    fn test_interop_and_ifd1_thumbnail_tags() {
+        use std::io::Write;
+
+        let entry = |tag: u16, format: u16, count: u32, value: [u8; 4]| {
+            let mut entry = tag.to_le_bytes().to_vec();
+            entry.extend_from_slice(&format.to_le_bytes());
+            entry.extend_from_slice(&count.to_le_bytes());
+            entry.extend_from_slice(&value);
+            entry
+        };
+        let short = |value: u16| u32::from(value).to_le_bytes();
+        let mut tiff = b"II*\0".to_vec();
+        tiff.extend_from_slice(&8u32.to_le_bytes());
+        // IFD0 at 8, followed by IFD1 at 86
+        tiff.extend_from_slice(&2u16.to_le_bytes());
+        tiff.extend(entry(0x0128, 3, 1, short(2)));
+        tiff.extend(entry(0x8769, 4, 1, 38u32.to_le_bytes()));
+        tiff.extend_from_slice(&86u32.to_le_bytes());
+        // ExifIFD at 38
+        tiff.extend_from_slice(&1u16.to_le_bytes());
+        tiff.extend(entry(0xa005, 4, 1, 56u32.to_le_bytes()));
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+        // InteropIFD at 56
+        tiff.extend_from_slice(&2u16.to_le_bytes());
+        tiff.extend(entry(0x0001, 2, 4, *b"R98\0"));
+        tiff.extend(entry(0x0002, 7, 4, *b"0100"));
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+        // IFD1 at 86 repeats ResolutionUnit, which must not shadow IFD0's
+        tiff.extend_from_slice(&4u16.to_le_bytes());
+        tiff.extend(entry(0x0103, 3, 1, short(6)));
+        tiff.extend(entry(0x0128, 3, 1, short(3)));
+        tiff.extend(entry(0x0201, 4, 1, 140u32.to_le_bytes()));
+        tiff.extend(entry(0x0202, 4, 1, 4u32.to_le_bytes()));
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+        tiff.extend_from_slice(b"\xff\xd8\xff\xd9");
+
+        let mut jpeg = b"\xff\xd8\xff\xe1".to_vec();
+        jpeg.extend_from_slice(&((tiff.len() + 8) as u16).to_be_bytes());
+        jpeg.extend_from_slice(b"Exif\0\0");
+        jpeg.extend_from_slice(&tiff);
+        jpeg.extend_from_slice(b"\xff\xd9");
+        let mut file = tempfile::Builder::new().suffix(".jpg").tempfile().unwrap();
+        file.write_all(&jpeg).unwrap();
+
+        let exif_data = extract_metadata(file.path(), false, false, None).unwrap();
+        let tag = |name: &str| exif_data.get_tag_by_group("EXIF", name).unwrap();
+        let attributed = |name: &str| (&*tag(name).group1, tag(name).value.clone());
+
+        assert_eq!(
+            attributed("InteropIndex"),
+            ("InteropIFD", TagValue::string("R98"))
+        );
+        assert_eq!(
+            attributed("InteropVersion"),
+            ("InteropIFD", TagValue::string("0100"))
+        );
+        assert_eq!(attributed("Compression"), ("IFD1", TagValue::U16(6)));
+        // Thumbnail offsets are relative to the file, past the APP1 header
+        assert_eq!(attributed("ThumbnailOffset"), ("IFD1", TagValue::U32(152)));
+        assert_eq!(attributed("ThumbnailLength"), ("IFD1", TagValue::U32(4)));
+        assert_eq!(attributed("ResolutionUnit"), ("IFD0", TagValue::U16(2)));
+    }
+
+    #[cfg(feature = "xmp")]
+    #[test]
+    fn test_xmp_packet_location_is_reported_with_tag_sources() {
+        use std::io::Write;
+
+        let packet = b"<?xpacket begin='' id='W5M0MpCehiHzreSzNTczkc9d'?>\
+            <x:xmpmeta xmlns:x='adobe:ns:meta/'/>    <?xpacket end='w'?>";
+        let mut app1 = b"http://ns.adobe.com/xap/1.0/\0".to_vec();
+        app1.extend_from_slice(packet);
+        let mut jpeg = b"\xff\xd8\xff\xe1".to_vec();
+        jpeg.extend_from_slice(&((app1.len() + 2) as u16).to_be_bytes());
+        jpeg.extend_from_slice(&app1);
+        jpeg.extend_from_slice(b"\xff\xd9");
+        let mut file = tempfile::Builder::new().suffix(".jpg").tempfile().unwrap();
+        file.write_all(&jpeg).unwrap();
+
+        let filter = FilterOptions {
+            include_tag_sources: true,
+            ..FilterOptions::default()
+        };
+        let exif_data = extract_metadata(file.path(), false, false, Some(filter)).unwrap();
+        let location = exif_data.xmp_packet.unwrap();
+        assert_eq!(location.container, "JPEG APP1");
+        assert_eq!(location.offset, 35);
+        assert_eq!(location.length, packet.len() as u64);
+        assert_eq!(location.padding, 4);
+        assert!(location.can_rewrite_in_place(packet.len() as u64));
+
+        // Like the tag sources, only reported when requested
+        let exif_data = extract_metadata(file.path(), false, false, None).unwrap();
+        assert_eq!(exif_data.xmp_packet, None);
+    }
+
+    #[test]
+    fn test_truncated_jpeg_returns_partial_results() {
+        use std::io::Write;
+
+        // APP1 declares 256 bytes, but the file ends partway through IFD0's
+        // second entry
+        let mut jpeg = b"\xff\xd8\xff\xe1\x01\x00Exif\x00\x00MM\x00\x2a\x00\x00\x00\x08".to_vec();
+        jpeg.extend_from_slice(b"\x00\x02\x01\x0f\x00\x02\x00\x00\x00\x04Cam\x00");
+        jpeg.extend_from_slice(b"\x01\x10\x00\x02\x00\x00");
+        let mut file = tempfile::Builder::new().suffix(".jpg").tempfile().unwrap();
+        file.write_all(&jpeg).unwrap();
+
+        let exif_data = extract_metadata(file.path(), false, false, None).unwrap();
+        assert_eq!(
+            exif_data.get_tag_by_group("EXIF", "Make").unwrap().value,
+            TagValue::string("Cam")
+        );
+        let truncation = exif_data.truncation.unwrap();
+        assert_eq!(
+            truncation,
+            Truncation::new("JPEG APP1 EXIF segment", 12, 248, 28)
+        );
+        assert!(exif_data
+            .warnings
+            .iter()
+            .any(|warning| warning.code == "FileTruncated"));
+    }
+
+    #[test]
+    fn test_parse_errors_are_structured_warnings() {
+        use std::io::Write;
+
+        // APP1 Exif segment whose TIFF header has no valid byte order
+        let mut file = tempfile::Builder::new().suffix(".jpg").tempfile().unwrap();
+        file.write_all(b"\xff\xd8\xff\xe1\x00\x10Exif\x00\x00XX\x2a\x00\x08\x00\x00\x00\xff\xd9")
+            .unwrap();
+
+        // Reported even when the filter selects no warning tag
+        let filter = FilterOptions::tags_only(vec!["Make".to_string()]);
+        let mut exif_data = extract_metadata(file.path(), false, false, Some(filter)).unwrap();
+        assert_eq!(exif_data.warnings.len(), 1);
+        assert_eq!(exif_data.warnings[0].code, "ExifParseError");
+        assert_eq!(exif_data.warnings[0].context.as_deref(), Some("JPEG"));
+        assert!(!exif_data.legacy_tags.keys().any(|k| k.contains("Warning")));
+
+        exif_data.prepare_for_serialization(None);
+        assert!(exif_data
+            .legacy_tags
+            .contains_key(crate::types::WARNING_TAG_KEY));
+    }
+
+    #[test]
+    fn test_numeric_output() {
+        use std::io::Write;
+
+        let mut file = tempfile::Builder::new().suffix(".tif").tempfile().unwrap();
+        file.write_all(&minimal_tiff()).unwrap();
+
+        let exif_data = extract_metadata(file.path(), false, false, None).unwrap();
+        let orientation = exif_data.get_tag_by_group("EXIF", "Orientation").unwrap();
+        assert_eq!(
+            orientation.print(),
+            &TagValue::string("Horizontal (normal)")
+        );
+
+        let filter = FilterOptions {
+            numeric: true,
+            ..FilterOptions::default()
+        };
+        let exif_data = extract_metadata(file.path(), false, false, Some(filter)).unwrap();
+        let orientation = exif_data.get_tag_by_group("EXIF", "Orientation").unwrap();
+        assert_eq!(orientation.print(), &TagValue::U16(1));
+    }
+
+    #[test]
+    fn test_duplicate_tags() {
+        use std::io::Write;
+
+        let entry = |tag: u16, format: u16, value: u32| {
+            let mut entry = tag.to_le_bytes().to_vec();
+            entry.extend_from_slice(&format.to_le_bytes());
+            entry.extend_from_slice(&1u32.to_le_bytes());
+            entry.extend_from_slice(&value.to_le_bytes());
+            entry
+        };
+        // ResolutionUnit in IFD0 (inches), ExifIFD (cm) and IFD1 (none)
+        let mut tiff = b"II*\0".to_vec();
+        tiff.extend_from_slice(&8u32.to_le_bytes());
+        // IFD0 at 8, ExifIFD at 38, IFD1 at 56
+        tiff.extend_from_slice(&2u16.to_le_bytes());
+        tiff.extend(entry(0x0128, 3, 2));
+        tiff.extend(entry(0x8769, 4, 38));
+        tiff.extend_from_slice(&56u32.to_le_bytes());
+        tiff.extend_from_slice(&1u16.to_le_bytes());
+        tiff.extend(entry(0x0128, 3, 3));
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+        tiff.extend_from_slice(&1u16.to_le_bytes());
+        tiff.extend(entry(0x0128, 3, 1));
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+        let mut file = tempfile::Builder::new().suffix(".tif").tempfile().unwrap();
+        file.write_all(&tiff).unwrap();
+
+        let resolution_units = |allow_duplicates| {
+            let filter = FilterOptions {
+                allow_duplicates,
+                ..FilterOptions::default()
+            };
+            let mut exif_data = extract_metadata(file.path(), false, false, Some(filter)).unwrap();
+            exif_data.prepare_for_serialization(None);
+            exif_data
+                .legacy_tags
+                .iter()
+                .filter(|(key, _)| key.starts_with("EXIF:ResolutionUnit"))
+                .map(|(key, value)| (key.clone(), value.to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            resolution_units(false),
+            [("EXIF:ResolutionUnit".to_string(), "inches".to_string())]
+        );
+        let duplicates = resolution_units(true);
+        let keys: Vec<_> = duplicates.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(
+            keys,
+            [
+                "EXIF:ResolutionUnit",
+                "EXIF:ResolutionUnit (1)",
+                "EXIF:ResolutionUnit (2)"
+            ]
+        );
+        // The highest priority copy keeps the plain key, IFD1's comes last
+        assert_eq!(duplicates[0].1, "inches");
+        assert_eq!(duplicates[2].1, "None");
+    }
+
+    #[test]
+    fn test_extension_mismatch_warning() {
+        use std::io::Write;
+
+        // A JPEG (no metadata) saved with a .png extension
+        let mut file = tempfile::Builder::new().suffix(".png").tempfile().unwrap();
+        file.write_all(b"\xff\xd8\xff\xe0\0\x10JFIF\0\x01\x01\0\0\x01\0\x01\0\0\xff\xd9")
+            .unwrap();
+
+        let exif_data = extract_metadata(file.path(), false, false, None).unwrap();
+        let file_type = exif_data.get_tag_by_group("File", "FileType").unwrap();
+        assert_eq!(file_type.value, TagValue::string("JPEG"));
+        let mime_type = exif_data.get_tag_by_group("File", "MIMEType").unwrap();
+        assert_eq!(mime_type.value, TagValue::string("image/jpeg"));
+        assert_eq!(exif_data.warnings.len(), 1);
+        assert_eq!(exif_data.warnings[0].code, "FileExtensionMismatch");
+        assert_eq!(
+            exif_data.warnings[0].message,
+            "File has wrong extension (should be jpg)"
+        );
+
+        // File-group-only requests take the shortcut path
+        let filter = FilterOptions {
+            requested_tags: vec!["FileType".to_string()],
+            ..FilterOptions::default()
+        };
+        let exif_data = extract_metadata(file.path(), false, false, Some(filter)).unwrap();
+        assert_eq!(exif_data.warnings[0].code, "FileExtensionMismatch");
+    }
+
+    /// JPEG XL container with a 71x40 codestream, an Exif box holding
+    /// [`minimal_tiff`] and an xml box with an XMP packet
+    fn container_jxl() -> Vec<u8> {
+        let iso_box = |box_type: &[u8], content: &[u8]| {
+            let mut data = ((content.len() + 8) as u32).to_be_bytes().to_vec();
+            data.extend_from_slice(box_type);
+            data.extend_from_slice(content);
+            data
+        };
+        let mut exif = vec![0; 4];
+        exif.extend(minimal_tiff());
+        let xmp = b"<x:xmpmeta xmlns:x='adobe:ns:meta/'><rdf:RDF \
+            xmlns:rdf='http://www.w3.org/1999/02/22-rdf-syntax-ns#'><rdf:Description \
+            xmlns:xmp='http://ns.adobe.com/xap/1.0/' xmp:CreatorTool='Test'/>\
+            </rdf:RDF></x:xmpmeta>";
+        [
+            b"\0\0\0\x0cJXL \x0d\x0a\x87\x0a".to_vec(),
+            iso_box(b"ftyp", b"jxl \0\0\0\0jxl "),
+            iso_box(b"Exif", &exif),
+            iso_box(b"xml ", xmp),
+            // Small size: height (4+1)*8, 16:9 ratio, all_default metadata
+            iso_box(b"jxlc", b"\xff\x0a\x49\x03"),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn test_container_jxl_with_exif_and_xmp() {
+        use std::io::Write;
+
+        let jxl = container_jxl();
+        let mut file = tempfile::Builder::new().suffix(".jxl").tempfile().unwrap();
+        file.write_all(&jxl).unwrap();
+
+        let exif_data = extract_metadata(file.path(), false, false, None).unwrap();
+        assert!(exif_data.warnings.is_empty(), "{:?}", exif_data.warnings);
+        let file_type = exif_data.get_tag_by_group("File", "FileType").unwrap();
+        assert_eq!(file_type.value, TagValue::string("JXL"));
+        let width = exif_data.get_tag_by_group("JXL", "ImageWidth").unwrap();
+        assert_eq!(width.value, TagValue::U32(71));
+        let make = exif_data.get_tag_by_group("EXIF", "Make").unwrap();
+        assert_eq!(make.value, TagValue::string("Canon"));
+        #[cfg(feature = "xmp")]
+        {
+            let creator_tool = exif_data.get_tag_by_group("XMP", "CreatorTool").unwrap();
+            assert_eq!(creator_tool.value, TagValue::string("Test"));
+        }
+    }
+
+    #[test]
+    fn test_recognized_extension_wins_over_embedded_signature() {
+        use crate::file_detection::FileTypeDetector;
+        use std::io::Cursor;
+
+        // A JXL container that fails its magic number test still holds a
+        // TIFF signature in its Exif box, which must not override the .jxl
+        let mut jxl = container_jxl();
+        jxl[4..8].copy_from_slice(b"jxl ");
+        let detected = FileTypeDetector::new()
+            .detect_file_type(Path::new("a.jxl"), &mut Cursor::new(jxl))
+            .unwrap();
+        assert_eq!(detected.file_type, "JXL");
+    }
+
+    #[test]
+    fn test_unknown_tags() {
+        use crate::types::UnknownTags;
+        use std::io::Write;
+
+        // Turn Make into an unknown UNDEFINED tag and Orientation into an
+        // unknown SHORT
+        let mut tiff = minimal_tiff();
+        tiff[10..14].copy_from_slice(&[0xce, 0xab, 0x07, 0x00]);
+        tiff[22..24].copy_from_slice(&0xabcdu16.to_le_bytes());
+        let mut file = tempfile::Builder::new().suffix(".tif").tempfile().unwrap();
+        file.write_all(&tiff).unwrap();
+
+        let unknown_names = |unknown_tags| {
+            let filter = FilterOptions {
+                unknown_tags,
+                ..FilterOptions::default()
+            };
+            let exif_data = extract_metadata(file.path(), false, false, Some(filter)).unwrap();
+            let mut names: Vec<_> = exif_data
+                .tags
+                .iter()
+                .filter(|tag| tag.name.starts_with("Exif_0x"))
+                .map(|tag| (tag.name.to_string(), &*tag.group1 == "IFD0"))
+                .collect();
+            names.sort();
+            names
+        };
+
+        assert!(unknown_names(UnknownTags::Hide).is_empty());
+        assert_eq!(
+            unknown_names(UnknownTags::Include),
+            [("Exif_0xabcd".to_string(), true)]
+        );
+        assert_eq!(
+            unknown_names(UnknownTags::IncludeBinary),
+            [
+                ("Exif_0xabcd".to_string(), true),
+                ("Exif_0xabce".to_string(), true)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_print_conv_deferred_until_read() {
+        // Swap Orientation for YCbCrPositioning, which no composite reads
+        let mut tiff = minimal_tiff();
+        tiff[22..24].copy_from_slice(&0x0213u16.to_le_bytes());
+
+        let mut reader = ExifReader::new();
+        reader.parse_exif_data(&tiff).unwrap();
+        let entry = reader
+            .get_all_tag_entries()
+            .into_iter()
+            .find(|entry| entry.name == "YCbCrPositioning")
+            .unwrap();
+        assert_eq!(entry.value, TagValue::U16(1));
+        assert_eq!(entry.print(), &TagValue::string("Centered"));
+
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["print"], "Centered");
+    }
+
     #[test]
     fn test_xmp_exif_precedence_rules() {
         // Test that tags with different Group0 values are ALL kept (they produce different JSON keys)
         // This matches ExifTool's -j -G output behavior where "EXIF:Make" and "XMP:Make" are
         // separate keys in the JSON output, not conflicts.
         let exif_tag = TagEntry {
-            group: "EXIF".to_string(),
-            group1: "EXIF".to_string(),
-            name: "Make".to_string(),
+            group: "EXIF".into(),
+            group1: "EXIF".into(),
+            name: "Make".into(),
             value: TagValue::String("Canon".to_string()),
-            print: TagValue::String("Canon".to_string()),
+            print: TagValue::String("Canon".to_string()).into(),
+            source: None,
         };
 
         let xmp_tag = TagEntry {
-            group: "XMP".to_string(),
-            group1: "XMP".to_string(),
-            name: "Make".to_string(),
+            group: "XMP".into(),
+            group1: "XMP".into(),
+            name: "Make".into(),
             value: TagValue::String("Nikon".to_string()),
-            print: TagValue::String("Nikon".to_string()),
+            print: TagValue::String("Nikon".to_string()).into(),
+            source: None,
         };
 
         let file_tag = TagEntry {
-            group: "File".to_string(),
-            group1: "File".to_string(),
-            name: "ImageWidth".to_string(),
+            group: "File".into(),
+            group1: "File".into(),
+            name: "ImageWidth".into(),
             value: TagValue::U16(3000),
-            print: TagValue::U16(3000),
+            print: TagValue::U16(3000).into(),
+            source: None,
         };
 
         let xmp_imagewidth = TagEntry {
-            group: "XMP".to_string(),
-            group1: "XMP".to_string(),
-            name: "ImageWidth".to_string(),
+            group: "XMP".into(),
+            group1: "XMP".into(),
+            name: "ImageWidth".into(),
             value: TagValue::U16(2000),
-            print: TagValue::U16(2000),
+            print: TagValue::U16(2000).into(),
+            source: None,
         };
 
         let tags = vec![exif_tag, xmp_tag, file_tag, xmp_imagewidth];
-        let resolved = apply_exiftool_precedence_rules(tags);
+        let resolved = apply_exiftool_precedence_rules(tags, false);
 
         // Should have 4 tags total - all are kept because they have different "Group:Name" keys
         // ExifTool with -G outputs "EXIF:Make", "XMP:Make", "File:ImageWidth", "XMP:ImageWidth"
@@ -2077,23 +3616,25 @@ mod tests {
     fn test_xmp_high_priority_tags() {
         // Test that certain XMP tags have higher priority due to being more authoritative
         let exif_keywords = TagEntry {
-            group: "EXIF".to_string(),
-            group1: "EXIF".to_string(),
-            name: "Keywords".to_string(),
+            group: "EXIF".into(),
+            group1: "EXIF".into(),
+            name: "Keywords".into(),
             value: TagValue::String("simple keywords".to_string()),
-            print: TagValue::String("simple keywords".to_string()),
+            print: TagValue::String("simple keywords".to_string()).into(),
+            source: None,
         };
 
         let xmp_hierarchical = TagEntry {
-            group: "XMP".to_string(),
-            group1: "XMP".to_string(),
-            name: "HierarchicalKeywords".to_string(),
+            group: "XMP".into(),
+            group1: "XMP".into(),
+            name: "HierarchicalKeywords".into(),
             value: TagValue::Array(vec![TagValue::String("Nature|Wildlife".to_string())]),
-            print: TagValue::Array(vec![TagValue::String("Nature|Wildlife".to_string())]),
+            print: TagValue::Array(vec![TagValue::String("Nature|Wildlife".to_string())]).into(),
+            source: None,
         };
 
         let tags = vec![exif_keywords, xmp_hierarchical];
-        let resolved = apply_exiftool_precedence_rules(tags);
+        let resolved = apply_exiftool_precedence_rules(tags, false);
 
         // Should have both tags since they have different names
         assert_eq!(resolved.len(), 2);
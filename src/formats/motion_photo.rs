@@ -0,0 +1,201 @@
+//! Android motion photo video
+//!
+//! Google motion photos (also written by Samsung and others) are a JPEG with
+//! an MP4 appended after the image. The GCamera/GContainer XMP says how long
+//! the video is, so it can be split off without scanning the trailer:
+//!
+//! - Motion Photo v1: `MicroVideo` is 1 and `MicroVideoOffset` is the video's
+//!   distance from the end of the file.
+//! - Motion Photo v2: `MotionPhoto` is 1 and the container directory lists
+//!   the primary image, then each appended item in file order with its
+//!   `Length` (and optional `Padding` after it). The video is the item whose
+//!   `Semantic` is "MotionPhoto"; the last item ends at the end of the file.
+//!
+//! The video is reported as the binary `Trailer:MotionPhotoVideo` tag, so
+//! `-b -MotionPhotoVideo` (or [`super::extract_binary_tag`]) writes it out.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::types::{ExifError, Result, TagEntry, TagValue};
+
+/// Name of the tag holding the video
+pub const MOTION_PHOTO_VIDEO: &str = "MotionPhotoVideo";
+
+/// File offset and length of the motion photo video described by the XMP
+/// tags in `entries`, for a file of `file_size` bytes
+///
+/// The v2 container directory is preferred over the v1 MicroVideoOffset,
+/// since files converted between the two often keep both.
+pub fn motion_photo_video_location(entries: &[TagEntry], file_size: u64) -> Option<(u64, u64)> {
+    container_video_location(entries, file_size).or_else(|| {
+        let is_micro_video = xmp_values(entries, "MicroVideo")
+            .first()
+            .and_then(|value| tag_u64(value))
+            == Some(1);
+        let offset = xmp_values(entries, "MicroVideoOffset")
+            .first()
+            .and_then(|value| tag_u64(value))?;
+        (is_micro_video && offset > 0 && offset < file_size).then(|| (file_size - offset, offset))
+    })
+}
+
+/// Video location from the Motion Photo v2 container directory
+fn container_video_location(entries: &[TagEntry], file_size: u64) -> Option<(u64, u64)> {
+    let semantics = xmp_values(entries, "DirectoryItemSemantic");
+    let lengths = xmp_values(entries, "DirectoryItemLength");
+    let paddings = xmp_values(entries, "DirectoryItemPadding");
+
+    // The flattened lists skip items without the field. Only the primary
+    // image (always first) may go without a Length, and Padding is only
+    // used when every item has one.
+    let lengths: Vec<u64> = lengths
+        .iter()
+        .map(|value| tag_u64(value))
+        .collect::<Option<_>>()?;
+    let secondary_lengths = if lengths.len() == semantics.len() {
+        lengths.get(1..)?
+    } else if lengths.len() + 1 == semantics.len() {
+        &lengths[..]
+    } else {
+        return None;
+    };
+    let secondary_paddings: Vec<u64> = if paddings.len() == semantics.len() {
+        paddings[1..]
+            .iter()
+            .map(|value| tag_u64(value))
+            .collect::<Option<_>>()?
+    } else {
+        vec![0; secondary_lengths.len()]
+    };
+
+    let video = semantics
+        .iter()
+        .skip(1)
+        .position(|semantic| semantic.as_string() == Some("MotionPhoto"))?;
+    let length = secondary_lengths[video];
+    let tail = secondary_lengths[video..]
+        .iter()
+        .zip(&secondary_paddings[video..])
+        .try_fold(0u64, |tail, (length, padding)| {
+            tail.checked_add(*length)?.checked_add(*padding)
+        })?;
+    let offset = file_size.checked_sub(tail)?;
+    (length > 0 && offset > 0).then_some((offset, length))
+}
+
+/// Values of the XMP tag `name`; list tags give one value per item
+fn xmp_values<'a>(entries: &'a [TagEntry], name: &str) -> Vec<&'a TagValue> {
+    match entries
+        .iter()
+        .find(|entry| entry.group == "XMP" && entry.name == name)
+        .map(|entry| &entry.value)
+    {
+        Some(TagValue::Array(values)) => values.iter().collect(),
+        Some(value) => vec![value],
+        None => Vec::new(),
+    }
+}
+
+/// XMP integers are kept as strings
+fn tag_u64(value: &TagValue) -> Option<u64> {
+    match value.as_string() {
+        Some(text) => text.trim().parse().ok(),
+        None => value.as_i64().and_then(|v| u64::try_from(v).ok()),
+    }
+}
+
+/// The `Trailer:MotionPhotoVideo` tag for the video at `offset`
+///
+/// The video is only read when `read_data` is set; otherwise the value is
+/// ExifTool's binary placeholder. Either way the location must hold an MP4
+/// (an ISO BMFF `ftyp` box), so a stale offset isn't reported as a video.
+pub(crate) fn motion_photo_video_entry<R: Read + Seek>(
+    reader: &mut R,
+    (offset, length): (u64, u64),
+    read_data: bool,
+) -> Result<TagEntry> {
+    let mut box_header = [0u8; 8];
+    reader.seek(SeekFrom::Start(offset))?;
+    reader.read_exact(&mut box_header)?;
+    if &box_header[4..8] != b"ftyp" {
+        return Err(ExifError::InvalidFormat(format!(
+            "No MP4 video at motion photo offset {offset}"
+        )));
+    }
+
+    let value = if read_data {
+        let mut data = vec![0u8; length as usize];
+        reader.seek(SeekFrom::Start(offset))?;
+        reader.read_exact(&mut data)?;
+        TagValue::Binary(data)
+    } else {
+        TagValue::String(format!(
+            "(Binary data {length} bytes, use -b option to extract)"
+        ))
+    };
+    Ok(TagEntry {
+        group: "Trailer".into(),
+        group1: "Trailer".into(),
+        name: MOTION_PHOTO_VIDEO.into(),
+        value: value.clone(),
+        print: value.into(),
+        source: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xmp(name: &str, value: TagValue) -> TagEntry {
+        TagEntry {
+            group: "XMP".into(),
+            group1: "XMP".into(),
+            name: name.into(),
+            value: value.clone(),
+            print: value.into(),
+            source: None,
+        }
+    }
+
+    fn list(values: &[&str]) -> TagValue {
+        TagValue::Array(values.iter().map(|v| TagValue::string(*v)).collect())
+    }
+
+    #[test]
+    fn test_motion_photo_video_location() {
+        // v1: offset from the end of the file
+        let entries = [
+            xmp("MicroVideo", "1".into()),
+            xmp("MicroVideoOffset", "3000".into()),
+        ];
+        assert_eq!(
+            motion_photo_video_location(&entries, 10_000),
+            Some((7000, 3000))
+        );
+
+        // v2: the video is followed by another item, and the primary image
+        // has no Length
+        let entries = [
+            xmp(
+                "DirectoryItemSemantic",
+                list(&["Primary", "MotionPhoto", "GainMap"]),
+            ),
+            xmp("DirectoryItemLength", list(&["3000", "500"])),
+        ];
+        assert_eq!(
+            motion_photo_video_location(&entries, 10_000),
+            Some((6500, 3000))
+        );
+
+        // Lengths that can't be matched to their items
+        let entries = [
+            xmp(
+                "DirectoryItemSemantic",
+                list(&["Primary", "MotionPhoto", "GainMap"]),
+            ),
+            xmp("DirectoryItemLength", list(&["3000"])),
+        ];
+        assert_eq!(motion_photo_video_location(&entries, 10_000), None);
+    }
+}
@@ -0,0 +1,293 @@
+//! Office Open XML (DOCX, XLSX, PPTX, ...) document properties
+//!
+//! OOXML documents are ZIP files whose `docProps/core.xml` holds the Dublin
+//! Core style properties (title, creator, dates, ...) and `docProps/app.xml`
+//! the application statistics (pages, words, slides, ...). Both are flat
+//! lists of elements under the root, a few of which (HeadingPairs,
+//! TitlesOfParts) wrap a `vt:vector` of values.
+//!
+//! ExifTool reference: OOXML.pm ProcessDOCX and %Image::ExifTool::OOXML::Main.
+//! ExifTool parses these parts with its XMP reader, so tag names are the
+//! element names with the first letter upper-cased, except for the renames
+//! in [`tag_name`]. Custom properties (`docProps/custom.xml`) are not read.
+
+use std::io::{Read, Seek};
+
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use super::zip::ZipArchive;
+use crate::types::{ExifError, Result, TagEntry, TagValue};
+use crate::xmp::value_conversion::convert_xmp_date;
+
+/// ExifTool group (G0/G1) of OOXML properties
+const GROUP: &str = "XML";
+
+/// The property parts, in the order ExifTool reads them
+const PROPERTY_PARTS: &[&str] = &["docProps/core.xml", "docProps/app.xml"];
+
+/// Read the document properties of an OOXML file as `XML:*` TagEntries
+///
+/// ZIP files that aren't OOXML documents (no `docProps/core.xml` or
+/// `docProps/app.xml`) have no entries.
+pub fn extract_ooxml_metadata<R: Read + Seek>(reader: &mut R) -> Result<Vec<TagEntry>> {
+    let mut archive = ZipArchive::open(reader)?;
+    let mut entries = Vec::new();
+    for part in PROPERTY_PARTS {
+        if let Some(xml) = archive.read(part)? {
+            entries.extend(parse_properties(&String::from_utf8_lossy(&xml))?);
+        }
+    }
+    Ok(entries)
+}
+
+/// Entries for the child elements of a property part's root element
+fn parse_properties(xml: &str) -> Result<Vec<TagEntry>> {
+    let mut reader = Reader::from_str(xml);
+    let mut entries = Vec::new();
+    let mut depth = 0;
+    // Name of the current property and the text of its value(s)
+    let mut property: Option<(String, Vec<String>)> = None;
+    let mut text = String::new();
+    let xml_error = |e: quick_xml::Error| ExifError::ParseError(format!("OOXML properties: {e}"));
+
+    loop {
+        match reader.read_event().map_err(xml_error)? {
+            Event::Start(e) => {
+                depth += 1;
+                if depth == 2 {
+                    let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+                    property = Some((name, Vec::new()));
+                }
+                text.clear();
+            }
+            Event::Text(e) => text.push_str(&e.decode().map_err(|e| xml_error(e.into()))?),
+            Event::GeneralRef(e) => {
+                let name = e.decode().map_err(|e| xml_error(e.into()))?;
+                if let Ok(Some(ch)) = e.resolve_char_ref() {
+                    text.push(ch);
+                } else if let Some(entity) = resolve_predefined_entity(&name) {
+                    text.push_str(entity);
+                }
+            }
+            Event::End(_) => {
+                if let Some((_, values)) = property.as_mut() {
+                    let value = text.trim();
+                    if !value.is_empty() {
+                        values.push(value.to_string());
+                    }
+                }
+                text.clear();
+                if depth == 2 {
+                    if let Some((name, values)) = property.take() {
+                        entries.extend(property_entry(&name, values));
+                    }
+                }
+                depth -= 1;
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(entries)
+}
+
+/// ExifTool tag name for a property element
+///
+/// ExifTool: OOXML.pm %Image::ExifTool::OOXML::Main
+fn tag_name(element: &str) -> String {
+    let name = match element {
+        "created" => "CreateDate",
+        "modified" => "ModifyDate",
+        "revision" => "RevisionNumber",
+        "TotalTime" => "TotalEditTime",
+        _ => {
+            let mut chars = element.chars();
+            return match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            };
+        }
+    };
+    name.to_string()
+}
+
+/// The entry for one property, with its ExifTool conversions
+fn property_entry(element: &str, values: Vec<String>) -> Option<TagEntry> {
+    let name = tag_name(element);
+    let (value, print) = match (name.as_str(), values.as_slice()) {
+        (_, []) => return None,
+        // Format => 'date': ConvertXMPDate
+        ("CreateDate" | "ModifyDate" | "LastPrinted", [date]) => {
+            let value = convert_xmp_date(date);
+            (value.clone(), value)
+        }
+        // PrintConv => 'ConvertTimeSpan($val, 60)'
+        ("TotalEditTime", [minutes]) => (
+            number(minutes),
+            TagValue::string(convert_time_span(minutes, 60.0)),
+        ),
+        ("DocSecurity", [security]) => (number(security), TagValue::string(doc_security(security))),
+        ("ScaleCrop" | "LinksUpToDate" | "SharedDoc" | "HyperlinksChanged", [flag]) => {
+            let print = match flag.as_str() {
+                "true" | "1" => "Yes",
+                "false" | "0" => "No",
+                other => other,
+            };
+            (TagValue::string(flag.as_str()), TagValue::string(print))
+        }
+        (_, [single]) => {
+            let value = number(single);
+            (value.clone(), value)
+        }
+        // vt:vector lists, e.g. HeadingPairs and TitlesOfParts
+        (_, list) => {
+            let value = TagValue::Array(list.iter().map(|item| number(item)).collect());
+            (value.clone(), value)
+        }
+    };
+    Some(TagEntry {
+        group: GROUP.into(),
+        group1: GROUP.into(),
+        name: name.into(),
+        value,
+        print: print.into(),
+        source: None,
+    })
+}
+
+/// Counts like Pages and Words as numbers, everything else as text
+fn number(text: &str) -> TagValue {
+    let canonical = text == "0" || !text.starts_with('0');
+    match text.parse::<u32>() {
+        Ok(number) if canonical => TagValue::U32(number),
+        _ => TagValue::string(text),
+    }
+}
+
+/// ExifTool: OOXML.pm DocSecurity PrintConv
+fn doc_security(security: &str) -> String {
+    let name = match security {
+        "0" => "None",
+        "1" => "Password protected",
+        "2" => "Read-only recommended",
+        "4" => "Read-only enforced",
+        "8" => "Locked for annotations",
+        other => return format!("Unknown ({other})"),
+    };
+    name.to_string()
+}
+
+/// Human-readable time span
+///
+/// ExifTool: ExifTool.pm ConvertTimeSpan($val, $mult)
+fn convert_time_span(text: &str, mult: f64) -> String {
+    let Ok(value) = text.parse::<f64>() else {
+        return text.to_string();
+    };
+    if value == 0.0 {
+        return text.to_string();
+    }
+    let seconds = value * mult;
+    if seconds < 60.0 {
+        format!("{seconds} seconds")
+    } else if seconds < 3600.0 {
+        // Whole minutes when the input was in minutes or coarser
+        let minutes = seconds / 60.0;
+        let plural = if seconds == 60.0 { "" } else { "s" };
+        if mult >= 60.0 {
+            format!("{} minute{plural}", minutes as i64)
+        } else {
+            format!("{minutes:.1} minute{plural}")
+        }
+    } else if seconds < 24.0 * 3600.0 {
+        format!("{:.1} hours", seconds / 3600.0)
+    } else {
+        format!("{:.1} days", seconds / (24.0 * 3600.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::zip::stored_zip;
+    use std::io::Cursor;
+
+    const CORE: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+  <dc:title>Report &amp; Plan</dc:title>
+  <dc:creator>Alice</dc:creator>
+  <cp:keywords></cp:keywords>
+  <cp:lastModifiedBy>Bob</cp:lastModifiedBy>
+  <cp:revision>3</cp:revision>
+  <dcterms:created xsi:type="dcterms:W3CDTF">2023-05-01T10:20:30Z</dcterms:created>
+  <dcterms:modified xsi:type="dcterms:W3CDTF">2023-05-02T11:00:00Z</dcterms:modified>
+</cp:coreProperties>"#;
+
+    const APP: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">
+  <Template>Normal.dotm</Template>
+  <TotalTime>95</TotalTime>
+  <Pages>2</Pages>
+  <Words>350</Words>
+  <Application>Microsoft Office Word</Application>
+  <DocSecurity>0</DocSecurity>
+  <ScaleCrop>false</ScaleCrop>
+  <HeadingPairs><vt:vector size="2" baseType="variant"><vt:variant><vt:lpstr>Title</vt:lpstr></vt:variant><vt:variant><vt:i4>1</vt:i4></vt:variant></vt:vector></HeadingPairs>
+  <AppVersion>16.0000</AppVersion>
+</Properties>"#;
+
+    #[test]
+    fn test_docx_properties() {
+        let docx = stored_zip(&[
+            ("[Content_Types].xml", b"<Types/>"),
+            ("docProps/core.xml", CORE.as_bytes()),
+            ("docProps/app.xml", APP.as_bytes()),
+        ]);
+        let entries = extract_ooxml_metadata(&mut Cursor::new(docx)).unwrap();
+        let get = |name: &str| {
+            let entry = entries
+                .iter()
+                .find(|e| e.name == name)
+                .unwrap_or_else(|| panic!("{name} missing"));
+            assert_eq!(entry.group, "XML");
+            (entry.value.clone(), entry.print().to_string())
+        };
+
+        assert_eq!(get("Title").0, TagValue::string("Report & Plan"));
+        assert_eq!(get("Creator").0, TagValue::string("Alice"));
+        assert_eq!(get("LastModifiedBy").0, TagValue::string("Bob"));
+        assert_eq!(get("RevisionNumber").0, TagValue::U32(3));
+        assert_eq!(get("CreateDate").1, "2023:05:01 10:20:30Z");
+        assert_eq!(get("ModifyDate").1, "2023:05:02 11:00:00Z");
+        assert!(entries.iter().all(|e| e.name != "Keywords"));
+
+        assert_eq!(get("Pages").0, TagValue::U32(2));
+        assert_eq!(get("Words").0, TagValue::U32(350));
+        assert_eq!(get("TotalEditTime").1, "1.6 hours");
+        assert_eq!(get("DocSecurity").1, "None");
+        assert_eq!(get("ScaleCrop").1, "No");
+        assert_eq!(get("AppVersion").0, TagValue::string("16.0000"));
+        assert_eq!(
+            get("HeadingPairs").0,
+            TagValue::Array(vec![TagValue::string("Title"), TagValue::U32(1)])
+        );
+    }
+
+    #[test]
+    fn test_zip_without_properties() {
+        let zip = stored_zip(&[("readme.txt", b"hello")]);
+        assert!(extract_ooxml_metadata(&mut Cursor::new(zip))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_convert_time_span() {
+        assert_eq!(convert_time_span("1", 60.0), "1 minute");
+        assert_eq!(convert_time_span("42", 60.0), "42 minutes");
+        assert_eq!(convert_time_span("3000", 60.0), "2.1 days");
+        assert_eq!(convert_time_span("0", 60.0), "0");
+    }
+}
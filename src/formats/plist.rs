@@ -0,0 +1,302 @@
+//! Binary property list (bplist00) parsing
+//!
+//! Apple stores several maker note values (e.g. RunTime, AEMatrix) as
+//! embedded binary plists. This module decodes them into `TagValue`s so the
+//! owning tag table can pick out the dictionary entries it knows about.
+//!
+//! ExifTool Reference: lib/Image/ExifTool/PLIST.pm ProcessBinaryPLIST / ExtractObject
+
+use crate::types::TagValue;
+use std::collections::BTreeMap;
+use tracing::debug;
+
+/// Maximum nesting depth for arrays/dictionaries.
+/// Malformed plists can reference their own container; ExifTool guards this
+/// with its `$$plistInfo{Objects}` recursion check.
+const MAX_DEPTH: usize = 32;
+
+/// Trailer layout values needed to locate objects
+/// ExifTool: PLIST.pm ProcessBinaryPLIST - last 32 bytes of the file
+struct Trailer {
+    offset_size: usize,
+    ref_size: usize,
+    num_objects: usize,
+    offset_table: usize,
+}
+
+/// Parse a binary plist and return its top-level object
+///
+/// Dictionaries become `TagValue::Object`, arrays become `TagValue::Array`.
+/// Returns `None` if the data is not a valid `bplist00` structure.
+///
+/// ExifTool: PLIST.pm ProcessBinaryPLIST
+pub fn parse_binary_plist(data: &[u8]) -> Option<TagValue> {
+    // ExifTool: return 0 unless $buff =~ /^bplist0/
+    if data.len() < 8 + 32 || !data.starts_with(b"bplist0") {
+        return None;
+    }
+
+    let trailer_start = data.len() - 32;
+    let trailer = &data[trailer_start..];
+    let offset_size = trailer[6] as usize;
+    let ref_size = trailer[7] as usize;
+    let num_objects = read_uint(&trailer[8..16])? as usize;
+    let top_object = read_uint(&trailer[16..24])? as usize;
+    let offset_table = read_uint(&trailer[24..32])? as usize;
+
+    // ExifTool: return 0 if $intSize < 1 or $intSize > 8 or $refSize < 1 or $refSize > 8
+    if !(1..=8).contains(&offset_size) || !(1..=8).contains(&ref_size) {
+        debug!("Invalid binary plist trailer sizes");
+        return None;
+    }
+    if top_object >= num_objects
+        || offset_table
+            .checked_add(num_objects.checked_mul(offset_size)?)
+            .is_none_or(|end| end > trailer_start)
+    {
+        debug!("Binary plist offset table out of range");
+        return None;
+    }
+
+    let trailer = Trailer {
+        offset_size,
+        ref_size,
+        num_objects,
+        offset_table,
+    };
+    extract_object(data, &trailer, top_object, 0)
+}
+
+/// Read a big-endian unsigned integer of 1-8 bytes
+fn read_uint(bytes: &[u8]) -> Option<u64> {
+    if bytes.is_empty() || bytes.len() > 8 {
+        return None;
+    }
+    Some(bytes.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64))
+}
+
+/// Look up the file offset of object `index` via the offset table
+fn object_offset(data: &[u8], trailer: &Trailer, index: usize) -> Option<usize> {
+    if index >= trailer.num_objects {
+        return None;
+    }
+    let pos = trailer.offset_table + index * trailer.offset_size;
+    let offset = read_uint(data.get(pos..pos + trailer.offset_size)?)? as usize;
+    (offset < data.len()).then_some(offset)
+}
+
+/// Read the element count that follows a marker byte
+/// ExifTool: a low nibble of 0x0f means the count is stored as a following int object
+fn read_count(data: &[u8], marker_pos: usize) -> Option<(usize, usize)> {
+    let low = (data[marker_pos] & 0x0f) as usize;
+    if low != 0x0f {
+        return Some((low, marker_pos + 1));
+    }
+    let int_marker = *data.get(marker_pos + 1)?;
+    if int_marker >> 4 != 0x1 {
+        return None;
+    }
+    let size = 1usize << (int_marker & 0x0f);
+    let start = marker_pos + 2;
+    let count = read_uint(data.get(start..start + size)?)? as usize;
+    Some((count, start + size))
+}
+
+/// Decode a single object by index
+/// ExifTool: PLIST.pm ExtractObject
+fn extract_object(data: &[u8], trailer: &Trailer, index: usize, depth: usize) -> Option<TagValue> {
+    if depth > MAX_DEPTH {
+        debug!("Binary plist nesting too deep");
+        return None;
+    }
+    let pos = object_offset(data, trailer, index)?;
+    let marker = data[pos];
+    let low = marker & 0x0f;
+
+    match marker >> 4 {
+        // null / bool / fill
+        0x0 => match low {
+            0x08 => Some(TagValue::Bool(false)),
+            0x09 => Some(TagValue::Bool(true)),
+            _ => Some(TagValue::Empty),
+        },
+        // int: 2**low bytes; 1, 2 and 4-byte ints are unsigned, 8-byte ints are signed
+        0x1 => {
+            let size = 1usize << low;
+            let bytes = data.get(pos + 1..pos + 1 + size)?;
+            match size {
+                1..=4 => Some(TagValue::U32(read_uint(bytes)? as u32)),
+                8 => {
+                    let value = read_uint(bytes)? as i64;
+                    Some(if value >= 0 {
+                        TagValue::U64(value as u64)
+                    } else if let Ok(v) = i32::try_from(value) {
+                        TagValue::I32(v)
+                    } else {
+                        TagValue::F64(value as f64)
+                    })
+                }
+                _ => None,
+            }
+        }
+        // real: 4-byte float or 8-byte double
+        0x2 => {
+            let size = 1usize << low;
+            let bytes = data.get(pos + 1..pos + 1 + size)?;
+            match size {
+                4 => Some(TagValue::F64(
+                    f32::from_be_bytes(bytes.try_into().ok()?) as f64
+                )),
+                8 => Some(TagValue::F64(f64::from_be_bytes(bytes.try_into().ok()?))),
+                _ => None,
+            }
+        }
+        // date: 8-byte double, seconds since 2001-01-01 00:00:00 UTC
+        0x3 => {
+            let bytes = data.get(pos + 1..pos + 9)?;
+            Some(TagValue::F64(f64::from_be_bytes(bytes.try_into().ok()?)))
+        }
+        // data
+        0x4 => {
+            let (count, start) = read_count(data, pos)?;
+            let bytes = data.get(start..start.checked_add(count)?)?;
+            Some(TagValue::Binary(bytes.to_vec()))
+        }
+        // ASCII string
+        0x5 => {
+            let (count, start) = read_count(data, pos)?;
+            let bytes = data.get(start..start.checked_add(count)?)?;
+            Some(TagValue::String(
+                String::from_utf8_lossy(bytes).into_owned(),
+            ))
+        }
+        // UTF-16BE string (count is in characters)
+        0x6 => {
+            let (count, start) = read_count(data, pos)?;
+            let bytes = data.get(start..start.checked_add(count.checked_mul(2)?)?)?;
+            let units: Vec<u16> = bytes
+                .chunks_exact(2)
+                .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                .collect();
+            Some(TagValue::String(String::from_utf16_lossy(&units)))
+        }
+        // UID
+        0x8 => {
+            let bytes = data.get(pos + 1..pos + 2 + low as usize)?;
+            Some(TagValue::U64(read_uint(bytes)?))
+        }
+        // array / set
+        0xa | 0xc => {
+            let (count, start) = read_count(data, pos)?;
+            let refs = read_refs(data, trailer, start, count)?;
+            let items = refs
+                .into_iter()
+                .filter_map(|r| extract_object(data, trailer, r, depth + 1))
+                .collect();
+            Some(TagValue::Array(items))
+        }
+        // dict: all key refs, then all value refs
+        0xd => {
+            let (count, start) = read_count(data, pos)?;
+            let refs = read_refs(data, trailer, start, count.checked_mul(2)?)?;
+            let (keys, values) = refs.split_at(count);
+            let mut dict = BTreeMap::new();
+            for (&key_ref, &value_ref) in keys.iter().zip(values) {
+                let Some(TagValue::String(key)) = extract_object(data, trailer, key_ref, depth + 1)
+                else {
+                    continue;
+                };
+                if let Some(value) = extract_object(data, trailer, value_ref, depth + 1) {
+                    dict.insert(key, value);
+                }
+            }
+            Some(TagValue::Object(dict))
+        }
+        _ => {
+            debug!("Unsupported binary plist object type {:#04x}", marker);
+            None
+        }
+    }
+}
+
+/// Read `count` object references starting at `start`
+fn read_refs(data: &[u8], trailer: &Trailer, start: usize, count: usize) -> Option<Vec<usize>> {
+    let end = start.checked_add(count.checked_mul(trailer.ref_size)?)?;
+    let bytes = data.get(start..end)?;
+    bytes
+        .chunks_exact(trailer.ref_size)
+        .map(|c| read_uint(c).map(|v| v as usize))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a bplist00 dict of ASCII keys to 1-byte ints
+    fn build_int_dict(entries: &[(&str, u8)]) -> Vec<u8> {
+        let mut data = b"bplist00".to_vec();
+        let mut offsets = Vec::new();
+        let n = entries.len();
+
+        // Object 0: dict referencing keys 1..=n and values n+1..=2n
+        offsets.push(data.len());
+        data.push(0xd0 | n as u8);
+        for i in 0..n {
+            data.push((1 + i) as u8);
+        }
+        for i in 0..n {
+            data.push((1 + n + i) as u8);
+        }
+        for (key, _) in entries {
+            offsets.push(data.len());
+            data.push(0x50 | key.len() as u8);
+            data.extend_from_slice(key.as_bytes());
+        }
+        for (_, value) in entries {
+            offsets.push(data.len());
+            data.push(0x10);
+            data.push(*value);
+        }
+
+        let offset_table = data.len();
+        for offset in &offsets {
+            data.push(*offset as u8);
+        }
+        let mut trailer = vec![0u8; 6];
+        trailer.push(1); // offset int size
+        trailer.push(1); // object ref size
+        trailer.extend_from_slice(&(offsets.len() as u64).to_be_bytes());
+        trailer.extend_from_slice(&0u64.to_be_bytes());
+        trailer.extend_from_slice(&(offset_table as u64).to_be_bytes());
+        data.extend_from_slice(&trailer);
+        data
+    }
+
+    #[test]
+    fn test_parse_int_dict() {
+        let data = build_int_dict(&[("flags", 1), ("value", 42)]);
+        let Some(TagValue::Object(dict)) = parse_binary_plist(&data) else {
+            panic!("expected dict");
+        };
+        assert_eq!(dict.get("flags"), Some(&TagValue::U32(1)));
+        assert_eq!(dict.get("value"), Some(&TagValue::U32(42)));
+    }
+
+    #[test]
+    fn test_rejects_non_plist() {
+        assert_eq!(parse_binary_plist(b"not a plist"), None);
+        let mut data = build_int_dict(&[("flags", 1)]);
+        data[0] = b'x';
+        assert_eq!(parse_binary_plist(&data), None);
+    }
+
+    #[test]
+    fn test_truncated_offset_table() {
+        let mut data = build_int_dict(&[("flags", 1)]);
+        // Point the offset table past the trailer
+        let len = data.len();
+        data[len - 1] = 0xff;
+        assert_eq!(parse_binary_plist(&data), None);
+    }
+}
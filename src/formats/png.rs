@@ -178,63 +178,130 @@ pub fn create_png_tag_entries(ihdr: &IhdrData) -> Vec<TagEntry> {
     vec![
         // PNG:ImageWidth - ExifTool PNG.pm:391-394
         TagEntry {
-            group: "PNG".to_string(),
-            group1: "PNG".to_string(),
-            name: "ImageWidth".to_string(),
+            group: "PNG".into(),
+            group1: "PNG".into(),
+            name: "ImageWidth".into(),
             value: TagValue::U32(ihdr.width),
-            print: TagValue::U32(ihdr.width),
+            print: TagValue::U32(ihdr.width).into(),
+            source: None,
         },
         // PNG:ImageHeight - ExifTool PNG.pm:395-398
         TagEntry {
-            group: "PNG".to_string(),
-            group1: "PNG".to_string(),
-            name: "ImageHeight".to_string(),
+            group: "PNG".into(),
+            group1: "PNG".into(),
+            name: "ImageHeight".into(),
             value: TagValue::U32(ihdr.height),
-            print: TagValue::U32(ihdr.height),
+            print: TagValue::U32(ihdr.height).into(),
+            source: None,
         },
         // PNG:BitDepth - ExifTool PNG.pm:399
         TagEntry {
-            group: "PNG".to_string(),
-            group1: "PNG".to_string(),
-            name: "BitDepth".to_string(),
+            group: "PNG".into(),
+            group1: "PNG".into(),
+            name: "BitDepth".into(),
             value: TagValue::U8(ihdr.bit_depth),
-            print: TagValue::U8(ihdr.bit_depth),
+            print: TagValue::U8(ihdr.bit_depth).into(),
+            source: None,
         },
         // PNG:ColorType - ExifTool PNG.pm:400-410 (with PrintConv)
         TagEntry {
-            group: "PNG".to_string(),
-            group1: "PNG".to_string(),
-            name: "ColorType".to_string(),
+            group: "PNG".into(),
+            group1: "PNG".into(),
+            name: "ColorType".into(),
             value: TagValue::String(ihdr.color_type.to_string()),
-            print: TagValue::String(ihdr.color_type_description().to_string()),
+            print: TagValue::String(ihdr.color_type_description().to_string()).into(),
+            source: None,
         },
         // PNG:Compression - ExifTool PNG.pm:411-414 (with PrintConv)
         TagEntry {
-            group: "PNG".to_string(),
-            group1: "PNG".to_string(),
-            name: "Compression".to_string(),
+            group: "PNG".into(),
+            group1: "PNG".into(),
+            name: "Compression".into(),
             value: TagValue::String(ihdr.compression.to_string()),
-            print: TagValue::String(ihdr.compression_description().to_string()),
+            print: TagValue::String(ihdr.compression_description().to_string()).into(),
+            source: None,
         },
         // PNG:Filter - ExifTool PNG.pm:415-418 (with PrintConv)
         TagEntry {
-            group: "PNG".to_string(),
-            group1: "PNG".to_string(),
-            name: "Filter".to_string(),
+            group: "PNG".into(),
+            group1: "PNG".into(),
+            name: "Filter".into(),
             value: TagValue::String(ihdr.filter.to_string()),
-            print: TagValue::String(ihdr.filter_description().to_string()),
+            print: TagValue::String(ihdr.filter_description().to_string()).into(),
+            source: None,
         },
         // PNG:Interlace - ExifTool PNG.pm:419-422 (with PrintConv)
         TagEntry {
-            group: "PNG".to_string(),
-            group1: "PNG".to_string(),
-            name: "Interlace".to_string(),
+            group: "PNG".into(),
+            group1: "PNG".into(),
+            name: "Interlace".into(),
             value: TagValue::String(ihdr.interlace.to_string()),
-            print: TagValue::String(ihdr.interlace_description().to_string()),
+            print: TagValue::String(ihdr.interlace_description().to_string()).into(),
+            source: None,
         },
     ]
 }
 
+/// Create PNG:Gamma and PNG:SRGBRendering from the gAMA and sRGB chunks
+///
+/// Both must come before the first IDAT chunk, so scanning stops there.
+///
+/// ExifTool reference: PNG.pm Main table `gAMA` (ValueConv
+/// `int(1/($val/100000)*1000+0.5)/1000`, the decoding gamma) and `sRGB`
+/// (rendering intent PrintConv)
+pub fn create_png_color_entries(data: &[u8]) -> Vec<TagEntry> {
+    let entry = |name: &str, value: TagValue, print: TagValue| TagEntry {
+        group: "PNG".into(),
+        group1: "PNG".into(),
+        name: name.into(),
+        value,
+        print: print.into(),
+        source: None,
+    };
+
+    let mut entries = Vec::new();
+    let mut offset = PNG_SIGNATURE.len();
+    while let Some(header) = data.get(offset..offset + 8) {
+        let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        let chunk_type = &header[4..8];
+        if chunk_type == b"IDAT" || chunk_type == b"IEND" {
+            break;
+        }
+        let Some(chunk) = data.get(offset + 8..offset + 8 + length) else {
+            break;
+        };
+        match (chunk_type, chunk) {
+            (b"gAMA", &[a, b, c, d]) => {
+                let encoding = u32::from_be_bytes([a, b, c, d]) as f64 / 100_000.0;
+                let gamma = if encoding == 0.0 {
+                    0.0
+                } else {
+                    (1.0 / encoding * 1000.0 + 0.5).floor() / 1000.0
+                };
+                entries.push(entry("Gamma", TagValue::F64(gamma), TagValue::F64(gamma)));
+            }
+            (b"sRGB", &[intent]) => {
+                let print = match intent {
+                    0 => "Perceptual".to_string(),
+                    1 => "Relative Colorimetric".to_string(),
+                    2 => "Saturation".to_string(),
+                    3 => "Absolute Colorimetric".to_string(),
+                    other => format!("Unknown ({other})"),
+                };
+                entries.push(entry(
+                    "SRGBRendering",
+                    TagValue::U8(intent),
+                    TagValue::String(print),
+                ));
+            }
+            _ => {}
+        }
+        // Chunk data + CRC
+        offset += 8 + length + 4;
+    }
+    entries
+}
+
 /// Hash PNG image data chunks (IDAT, JDAT, JDAA) into the provided hasher
 ///
 /// PNG files consist of chunks, each with:
@@ -255,6 +322,8 @@ pub fn hash_png_image_data(
     data: &[u8],
     hasher: &mut crate::hash::ImageDataHasher,
 ) -> Result<usize> {
+    let _stage = crate::stats::stage("ImageDataHash");
+    crate::stats::add_parsed_bytes(data.len() as u64);
     use tracing::debug;
 
     // Verify PNG signature
@@ -392,11 +461,34 @@ mod tests {
         // Check ColorType entry (should have PrintConv applied)
         let color_type_entry = entries.iter().find(|e| e.name == "ColorType").unwrap();
         assert_eq!(
-            color_type_entry.print,
-            TagValue::String("RGB with Alpha".to_string())
+            color_type_entry.print(),
+            &TagValue::String("RGB with Alpha".to_string())
         );
     }
 
+    #[test]
+    fn test_create_png_color_entries() {
+        let mut data = PNG_SIGNATURE.to_vec();
+        for (chunk_type, chunk) in [
+            (b"gAMA", &45455u32.to_be_bytes()[..]),
+            (b"sRGB", &[0][..]),
+            (b"IDAT", &[][..]),
+            (b"gAMA", &100_000u32.to_be_bytes()[..]),
+        ] {
+            data.extend_from_slice(&(chunk.len() as u32).to_be_bytes());
+            data.extend_from_slice(chunk_type);
+            data.extend_from_slice(chunk);
+            data.extend_from_slice(&[0; 4]); // CRC
+        }
+
+        let entries = create_png_color_entries(&data);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "Gamma");
+        assert_eq!(entries[0].value, TagValue::F64(2.2));
+        assert_eq!(entries[1].name, "SRGBRendering");
+        assert_eq!(entries[1].print(), &TagValue::string("Perceptual"));
+    }
+
     #[test]
     fn test_hash_png_image_data_minimal() {
         use crate::hash::{ImageDataHasher, ImageHashType};
@@ -15,18 +15,31 @@
 //! ExifTool reference: QuickTime.pm ProcessMOV:9932, atom header:9974/10036-10090.
 //!
 //! Scope (TPP Task 2): mvhd (MovieHeader:1343), tkhd (TrackHeader:1493),
-//! mdhd (MediaHeader:7239) — dates, durations, dimensions. Task 3's
-//! meta/keys/ilst and udta, and Task 4's Rotation from hdlr HandlerType +
-//! tkhd MatrixStructure, are left as clearly-marked TODO arms so the container
-//! structure is already in place.
+//! mdhd (MediaHeader:7239) — dates, durations, dimensions. The Apple
+//! meta/keys/ilst indirection (ProcessKeys:9779) yields the `Keys:*` tags
+//! (ContentIdentifier, Make, Model, Software, ...) whose conversions are
+//! function pointers or absent; tags needing Expression conversions
+//! (GPSCoordinates, CreationDate) are still skipped. Video sample entries
+//! (stsd) yield the Spherical Video V2 tags (st3d Stereoscopic3D, sv3d
+//! MetadataSource and projection). Chapters (a `tref`/`chap` text track, or a
+//! Nero `chpl` atom in moov/udta) and subtitle tracks are summarized by
+//! [`super::media_tracks`]. Track udta and Task 4's Rotation
+//! from hdlr HandlerType + tkhd MatrixStructure are left as clearly-marked TODO
+//! arms so the container structure is already in place.
 
 use std::io::{Read, Seek, SeekFrom};
 
 use indexmap::IndexMap;
 use tracing::trace;
 
+use super::media_tracks::{self, Chapter, MAX_CHAPTERS, UNDETERMINED_LANGUAGE};
+use crate::generated::QuickTime_pm::keys_tags::QUICK_TIME_KEYS_TAGS_BY_NAME;
+use crate::generated::QuickTime_pm::visual_sample_desc_tags::QUICK_TIME_VISUALSAMPLEDESC_TAGS_BY_NAME;
+use crate::hash::ImageDataHasher;
 use crate::implementations::quicktime as qt;
-use crate::types::{Result, TagEntry, TagValue};
+use crate::types::{
+    InternedStr, PrintConv, Result, ScanDepth, TagEntry, TagValue, Truncation, ValueConv,
+};
 
 /// Guard against pathologically deep / cyclic atom nesting (fuzz target, Task 5).
 const MAX_DEPTH: u32 = 16;
@@ -35,6 +48,23 @@ const MAX_DEPTH: u32 = 16;
 /// `QuickTime:CreateDate`, so Group0 is `QuickTime`.
 const GROUP: &str = "QuickTime";
 
+/// Family 1 group of QuickTime::Keys tags (Keys table GROUPS, QuickTime.pm:6578).
+const KEYS_GROUP: &str = "Keys";
+
+/// Size of the fixed VisualSampleDesc fields before the extension atoms
+/// (reserved, data reference index, dimensions, resolution, CompressorName, ...).
+const VISUAL_SAMPLE_DESC_SIZE: u64 = 78;
+
+/// UUID starting a Spherical Video V1 `uuid` box (QuickTime.pm Track
+/// SphericalVideoXML condition).
+#[cfg(feature = "xmp")]
+const SPHERICAL_VIDEO_V1_UUID: [u8; 16] = [
+    0xff, 0xcc, 0x82, 0x63, 0xf8, 0x55, 0x4a, 0x93, 0x88, 0x14, 0x58, 0x7a, 0x02, 0x52, 0x1f, 0xdd,
+];
+
+/// Domain prefix ExifTool strips from 'mdta' keys (ProcessKeys:9800).
+const APPLE_KEY_DOMAIN: &str = "com.apple.quicktime.";
+
 /// Which container's children we are iterating. Each variant's `match` arm cites
 /// the QuickTime.pm SubDirectory table it mirrors.
 #[derive(Clone, Copy, Debug)]
@@ -49,8 +79,26 @@ enum Container {
     Media,
     /// `minf` → QuickTime::MediaInfo (QuickTime.pm:7289): `stbl` (→ stsd, Task 3).
     MediaInfo,
-    /// `stbl` → QuickTime::SampleTable (QuickTime.pm:7365): `stsd` (Task 3).
+    /// `stbl` → QuickTime::SampleTable (QuickTime.pm:7365): `stsd`.
     SampleTable,
+    /// `stsd` entries (ProcessSampleDesc:9629); only video tracks are read.
+    SampleDesc,
+    /// Children of a video sample entry → QuickTime::VisualSampleDesc
+    /// (QuickTime.pm:7585): `st3d`, `sv3d`.
+    VisualSampleEntry,
+    /// `sv3d` → QuickTime::sv3d (Spherical Video V2): `svhd`, `proj`.
+    SphericalVideo,
+    /// `proj` → QuickTime::proj: `prhd`, `equi`, `cbmp`.
+    Projection,
+    /// `tref` → QuickTime::TrackRef (QuickTime.pm:1638): `chap`.
+    TrackRef,
+    /// moov `udta` → QuickTime::UserData (QuickTime.pm:1585): `chpl`.
+    UserData,
+    /// `meta` → QuickTime::Meta (QuickTime.pm:6237): `keys`, `ilst`.
+    Meta,
+    /// `ilst` after `keys` → QuickTime::Keys ItemList; children are 1-based key
+    /// indices (ProcessKeys:9779).
+    ItemList,
 }
 
 /// How a duplicate tag across tracks resolves. TrackHeader entries carry
@@ -65,33 +113,134 @@ enum Priority {
     Last,
 }
 
-/// Walk a QuickTime container and return its `QuickTime:*` TagEntries.
+/// Walk a QuickTime container and return its `QuickTime:*` TagEntries, and
+/// the first atom cut off by the end of the file.
 ///
-/// Best-effort: malformed or truncated atoms stop the walk (never panic) and we
-/// return whatever was decoded so far. Only hard reader I/O errors propagate.
-pub fn extract_quicktime_metadata<R: Read + Seek>(reader: &mut R) -> Result<Vec<TagEntry>> {
+/// Best-effort: malformed atoms stop the walk (never panic) and we return
+/// whatever was decoded so far; truncated atoms are walked as far as they go.
+/// Only hard reader I/O errors propagate. At [`ScanDepth::Fast2`] the walk
+/// stops at the first top-level `mdat`.
+pub fn extract_quicktime_metadata<R: Read + Seek>(
+    reader: &mut R,
+    scan_depth: ScanDepth,
+) -> Result<(Vec<TagEntry>, Option<Truncation>)> {
     let file_end = reader.seek(SeekFrom::End(0))?;
     let mut walker = Walker {
         reader,
         tags: IndexMap::new(),
         time_scale: None,
         handler_type: None,
+        keys: Vec::new(),
+        tracks: Vec::new(),
+        nero_chapters: Vec::new(),
+        scan_depth,
+        file_end,
+        truncation: None,
     };
     walker.process(Container::TopLevel, 0, file_end, 0)?;
-    Ok(walker.into_entries())
+    walker.add_track_summary()?;
+    let truncation = walker.truncation.take();
+    Ok((walker.into_entries(), truncation))
 }
 
 struct Walker<'a, R: Read + Seek> {
     reader: &'a mut R,
     /// Accumulated tags keyed by name (Group0 is always QuickTime), already
     /// priority-resolved so exactly one value per tag reaches the output.
-    tags: IndexMap<String, TagEntry>,
+    tags: IndexMap<InternedStr, TagEntry>,
     /// `$$self{TimeScale}` — the *movie* timescale from mvhd (MovieHeader idx 3).
     /// Used for both Duration and TrackDuration (%durationInfo:314).
     time_scale: Option<u32>,
     /// `$$self{HandlerType}` from the most recent hdlr (Handler idx 8). Captured
     /// for Task 4's Rotation (needs the first `vide` track's MatrixStructure).
     handler_type: Option<[u8; 4]>,
+    /// Key names from the most recent `keys` atom, indexed by ilst item ID - 1
+    /// (`$$self{Keys}` info built by ProcessKeys:9779).
+    keys: Vec<String>,
+    /// One entry per `trak`, for the chapter and subtitle summary.
+    tracks: Vec<TrackInfo>,
+    /// Chapters from a Nero `chpl` atom, used without a chapter track.
+    nero_chapters: Vec<Chapter>,
+    /// Fast2 stops at the top-level `mdat` (ExifTool -fast2).
+    scan_depth: ScanDepth,
+    /// File size; atoms running past it are truncated, not malformed.
+    file_end: u64,
+    /// First atom cut off by the end of the file.
+    truncation: Option<Truncation>,
+}
+
+/// What the chapter and subtitle summary needs from one `trak`
+#[derive(Debug, Default)]
+struct TrackInfo {
+    /// tkhd TrackID
+    id: u32,
+    /// hdlr HandlerType
+    handler: Option<[u8; 4]>,
+    /// mdhd language as an ISO 639-2 code
+    language: Option<String>,
+    /// mdhd time scale (`$$self{MediaTS}`)
+    media_time_scale: u32,
+    /// IDs of the chapter tracks from `tref`/`chap`
+    chapter_track_ids: Vec<u32>,
+    /// Sample tables, read for text tracks only: stts (count, duration),
+    /// stsz sizes, stsc (first chunk, samples per chunk), stco/co64 offsets
+    time_to_sample: Vec<(u32, u32)>,
+    sample_sizes: Vec<u32>,
+    sample_to_chunk: Vec<(u32, u32)>,
+    chunk_offsets: Vec<u64>,
+}
+
+impl TrackInfo {
+    /// Text and subtitle handlers whose sample tables are worth reading
+    fn is_text(&self) -> bool {
+        matches!(self.handler.as_ref(), Some(b"text" | b"sbtl" | b"subt"))
+    }
+
+    /// File offset, size and start time (in media time scale units) of each sample
+    fn samples(&self) -> Vec<(u64, u32, u64)> {
+        let mut samples = Vec::new();
+        let mut sample = 0usize;
+        for (i, &(first_chunk, per_chunk)) in self.sample_to_chunk.iter().enumerate() {
+            let next_chunk = self
+                .sample_to_chunk
+                .get(i + 1)
+                .map_or(self.chunk_offsets.len() as u64 + 1, |&(next, _)| {
+                    next as u64
+                });
+            for chunk in first_chunk as u64..next_chunk {
+                let Some(&chunk_offset) = self.chunk_offsets.get((chunk as usize).wrapping_sub(1))
+                else {
+                    return samples;
+                };
+                let mut offset = chunk_offset;
+                for _ in 0..per_chunk {
+                    let Some(&size) = self.sample_sizes.get(sample) else {
+                        return samples;
+                    };
+                    samples.push((offset, size, 0));
+                    offset += size as u64;
+                    sample += 1;
+                    if samples.len() >= MAX_CHAPTERS {
+                        return self.with_start_times(samples);
+                    }
+                }
+            }
+        }
+        self.with_start_times(samples)
+    }
+
+    fn with_start_times(&self, mut samples: Vec<(u64, u32, u64)>) -> Vec<(u64, u32, u64)> {
+        let mut starts = self
+            .time_to_sample
+            .iter()
+            .flat_map(|&(count, duration)| std::iter::repeat_n(duration as u64, count as usize));
+        let mut time = 0u64;
+        for sample in &mut samples {
+            sample.2 = time;
+            time += starts.next().unwrap_or(0);
+        }
+        samples
+    }
 }
 
 impl<R: Read + Seek> Walker<'_, R> {
@@ -141,6 +290,18 @@ impl<R: Read + Seek> Walker<'_, R> {
             let content_start = pos + header_size;
             let atom_end = match pos.checked_add(atom_size) {
                 Some(e) if e <= end => e,
+                // The file ends inside this atom: walk what is there
+                Some(_) if end == self.file_end => {
+                    self.truncation.get_or_insert_with(|| {
+                        Truncation::new(
+                            format!("QuickTime {} atom", fourcc(&atom_type)),
+                            pos,
+                            atom_size,
+                            end - pos,
+                        )
+                    });
+                    end
+                }
                 _ => {
                     trace!(
                         "quicktime: atom '{}' extends past container, stopping",
@@ -151,6 +312,14 @@ impl<R: Read + Seek> Walker<'_, R> {
             };
             let content_len = atom_end - content_start;
 
+            if matches!(kind, Container::TopLevel)
+                && &atom_type == b"mdat"
+                && self.scan_depth >= ScanDepth::Fast2
+            {
+                trace!("quicktime: stopping at mdat (fast scan)");
+                break;
+            }
+
             self.dispatch(
                 kind,
                 &atom_type,
@@ -189,12 +358,22 @@ impl<R: Read + Seek> Walker<'_, R> {
                 self.decode_mvhd(&buf);
             }
             (Container::Movie, b"trak") => {
+                self.tracks.push(TrackInfo::default());
                 self.process(Container::Track, content_start, atom_end, depth + 1)?;
             }
             (Container::Track, b"tkhd") => {
                 let buf = self.read_content(content_start, content_len)?;
                 self.decode_tkhd(&buf);
             }
+            (Container::Track, b"tref") => {
+                self.process(Container::TrackRef, content_start, atom_end, depth + 1)?;
+            }
+            (Container::TrackRef, b"chap") => {
+                let buf = self.read_content(content_start, content_len)?;
+                if let Some(track) = self.tracks.last_mut() {
+                    track.chapter_track_ids = buf.chunks_exact(4).map(|id| be_u32(id, 0)).collect();
+                }
+            }
             (Container::Track, b"mdia") => {
                 self.process(Container::Media, content_start, atom_end, depth + 1)?;
             }
@@ -212,22 +391,111 @@ impl<R: Read + Seek> Walker<'_, R> {
             (Container::MediaInfo, b"stbl") => {
                 self.process(Container::SampleTable, content_start, atom_end, depth + 1)?;
             }
+            (Container::SampleTable, b"stts" | b"stsz" | b"stsc" | b"stco" | b"co64")
+                if self.tracks.last().is_some_and(TrackInfo::is_text) =>
+            {
+                let buf = self.read_content(content_start, content_len)?;
+                self.decode_sample_table(atom_type, &buf);
+            }
 
-            // ----- TODO arms (structure ready for later tasks) -----
-            // Task 3: moov/meta (bare container, Movie:1218) + moov/trak/meta →
-            //   keys/ilst indirection (ProcessKeys:9779) → Make/Model/Software/
-            //   CreationDate/GPSCoordinates/LensModel.
+            // moov/meta and moov/trak/meta. QuickTime writes a bare container
+            // (Movie:1218) while MP4 uses a full box with 4 bytes of
+            // version/flags; ExifTool tells them apart by the hdlr that must
+            // come first (ProcessMOV:10121).
             (Container::Movie | Container::Track, b"meta") => {
-                trace!("quicktime: TODO Task 3 meta/keys/ilst");
+                let head = self.read_content(content_start, content_len.min(8))?;
+                let start = if head.get(4..8) == Some(b"hdlr".as_slice()) {
+                    content_start
+                } else {
+                    content_start + 4
+                };
+                self.process(Container::Meta, start, atom_end, depth + 1)?;
+            }
+            (Container::Meta, b"keys") => {
+                let buf = self.read_content(content_start, content_len)?;
+                self.decode_keys(&buf);
             }
-            // Task 3/5: udta (UserData:1585) → XMP_ atom:1711, Canon CNTH, etc.
-            (Container::Movie | Container::Track, b"udta") => {
+            (Container::Meta, b"ilst") if !self.keys.is_empty() => {
+                self.process(Container::ItemList, content_start, atom_end, depth + 1)?;
+            }
+            (Container::ItemList, index) => {
+                let buf = self.read_content(content_start, content_len)?;
+                self.decode_keys_item(u32::from_be_bytes(*index), &buf);
+            }
+
+            (Container::Movie, b"udta") => {
+                self.process(Container::UserData, content_start, atom_end, depth + 1)?;
+            }
+            (Container::UserData, b"chpl") => {
+                let buf = self.read_content(content_start, content_len)?;
+                self.nero_chapters = decode_chpl(&buf);
+            }
+
+            // ----- TODO arms (structure ready for later tasks) -----
+            // Task 3/5: track udta (UserData:1585) → XMP_ atom:1711, Canon CNTH, etc.
+            (Container::Track, b"udta") => {
                 trace!("quicktime: TODO Task 3/5 udta");
             }
-            // Task 3: stsd (SampleTable:7365 → ProcessSampleDesc:9629 →
-            //   VisualSampleDesc:7585) → CompressorName when HandlerType == 'vide'.
-            (Container::SampleTable, b"stsd") => {
-                trace!("quicktime: TODO Task 3 stsd/CompressorName");
+            // stsd (SampleTable:7365 → ProcessSampleDesc:9629): version/flags(4)
+            // and entry count(4), then one atom per sample entry. Only video
+            // entries (VisualSampleDesc:7585) are decoded.
+            (Container::SampleTable, b"stsd") if self.handler_type == Some(*b"vide") => {
+                self.process(
+                    Container::SampleDesc,
+                    content_start + 8,
+                    atom_end,
+                    depth + 1,
+                )?;
+            }
+            // The fixed VisualSampleDesc fields take 78 bytes; extension atoms
+            // follow. TODO Task 3: CompressorName (VisualSampleDesc idx 25).
+            (Container::SampleDesc, _) if content_len > VISUAL_SAMPLE_DESC_SIZE => {
+                self.process(
+                    Container::VisualSampleEntry,
+                    content_start + VISUAL_SAMPLE_DESC_SIZE,
+                    atom_end,
+                    depth + 1,
+                )?;
+            }
+            (Container::VisualSampleEntry, b"st3d") => {
+                let buf = self.read_content(content_start, content_len)?;
+                self.decode_st3d(&buf);
+            }
+            (Container::VisualSampleEntry, b"sv3d") => {
+                self.process(
+                    Container::SphericalVideo,
+                    content_start,
+                    atom_end,
+                    depth + 1,
+                )?;
+            }
+            (Container::SphericalVideo, b"svhd") => {
+                let buf = self.read_content(content_start, content_len)?;
+                self.decode_svhd(&buf);
+            }
+            (Container::SphericalVideo, b"proj") => {
+                self.process(Container::Projection, content_start, atom_end, depth + 1)?;
+            }
+            (Container::Projection, b"prhd") => {
+                let buf = self.read_content(content_start, content_len)?;
+                self.decode_prhd(&buf);
+            }
+            (Container::Projection, b"equi") => {
+                let buf = self.read_content(content_start, content_len)?;
+                self.decode_equi(&buf);
+            }
+            (Container::Projection, b"cbmp") => {
+                let buf = self.read_content(content_start, content_len)?;
+                self.decode_cbmp(&buf);
+            }
+            // Track uuid → SphericalVideoXML (Spherical Video V1), RDF/XML
+            // after the 16-byte UUID, read with XMP::Main
+            #[cfg(feature = "xmp")]
+            (Container::Track, b"uuid") => {
+                let buf = self.read_content(content_start, content_len)?;
+                if let Some(xml) = buf.strip_prefix(SPHERICAL_VIDEO_V1_UUID.as_slice()) {
+                    self.decode_spherical_video_xml(xml);
+                }
             }
 
             // ----- everything else (incl. mdat): seek past, never read -----
@@ -331,6 +599,10 @@ impl<R: Read + Seek> Walker<'_, R> {
                 be_u32(p, 92),
             )
         };
+        if let Some(track) = self.tracks.last_mut() {
+            // TrackID (idx 3) follows the dates
+            track.id = be_u32(p, if version == 0 { 12 } else { 20 });
+        }
         self.add_date("TrackCreateDate", create, Priority::First);
         self.add_date("TrackModifyDate", modify, Priority::First);
         // TrackDuration divides by the movie TimeScale, not a per-track one.
@@ -372,6 +644,14 @@ impl<R: Read + Seek> Walker<'_, R> {
             }
             (be_u64(p, 4), be_u64(p, 12), be_u32(p, 20), be_u64(p, 24))
         };
+        if let Some(track) = self.tracks.last_mut() {
+            // MediaLanguageCode (idx 5 v0) follows the duration
+            let language_offset = if version == 0 { 20 } else { 32 };
+            track.media_time_scale = media_ts;
+            track.language = p
+                .get(language_offset..language_offset + 2)
+                .and_then(|code| qt::media_language_code(u16::from_be_bytes([code[0], code[1]])));
+        }
         self.add_date("MediaCreateDate", create, Priority::Last);
         self.add_date("MediaModifyDate", modify, Priority::Last);
         // MediaDuration uses the SAME mdhd's MediaTS (MediaHeader:7270-7271).
@@ -390,18 +670,316 @@ impl<R: Read + Seek> Walker<'_, R> {
             // ExifTool skips 'alis'/'url ' when recording HandlerType (Handler:8412).
             if &ht != b"alis" && &ht != b"url " {
                 self.handler_type = Some(ht);
+                if let Some(track) = self.tracks.last_mut() {
+                    track.handler = Some(ht);
+                }
             }
         }
         // TODO Task 2+: HandlerDescription (byte 24, string, Handler:8453) with the
         // leading-length-byte Pascal-string strip (RawConv:8457).
     }
 
+    /// stts/stsz/stsc/stco/co64 of a text track (SampleTable:7365; ExifTool only
+    /// reads them with `-ee`). Tables are capped at [`MAX_CHAPTERS`] entries.
+    fn decode_sample_table(&mut self, atom_type: &[u8; 4], p: &[u8]) {
+        let Some(track) = self.tracks.last_mut() else {
+            return;
+        };
+        // version/flags(4), then the entry count (stsz: sample size and count)
+        let body = |skip: usize, width: usize| {
+            p.get(skip..)
+                .unwrap_or_default()
+                .chunks_exact(width)
+                .take(MAX_CHAPTERS)
+        };
+        match atom_type {
+            b"stts" => {
+                track.time_to_sample = body(8, 8).map(|e| (be_u32(e, 0), be_u32(e, 4))).collect();
+            }
+            b"stsz" => {
+                if p.len() < 12 {
+                    return;
+                }
+                let (size, count) = (be_u32(p, 4), be_u32(p, 8));
+                track.sample_sizes = if size != 0 {
+                    vec![size; (count as usize).min(MAX_CHAPTERS)]
+                } else {
+                    body(12, 4).map(|e| be_u32(e, 0)).collect()
+                };
+            }
+            b"stsc" => {
+                track.sample_to_chunk = body(8, 12).map(|e| (be_u32(e, 0), be_u32(e, 4))).collect();
+            }
+            b"stco" => {
+                track.chunk_offsets = body(8, 4).map(|e| be_u32(e, 0) as u64).collect();
+            }
+            _ => {
+                track.chunk_offsets = body(8, 8).map(|e| be_u64(e, 0)).collect();
+            }
+        }
+    }
+
+    /// Add the chapter and subtitle summary tags ([`media_tracks`])
+    ///
+    /// Chapter titles are the text samples of the track named by a `chap` track
+    /// reference: a 16-bit length, then UTF-8 text (or UTF-16 after a BOM).
+    fn add_track_summary(&mut self) -> Result<()> {
+        let chapter_ids: Vec<u32> = self
+            .tracks
+            .iter()
+            .flat_map(|track| track.chapter_track_ids.iter().copied())
+            .collect();
+
+        let mut chapters = Vec::new();
+        if let Some(track) = self
+            .tracks
+            .iter()
+            .find(|track| track.id != 0 && chapter_ids.contains(&track.id) && track.is_text())
+        {
+            let time_scale = track.media_time_scale.max(1) as f64;
+            for (offset, size, start) in track.samples() {
+                let sample = self.read_content(offset, size as u64)?;
+                if let Some(title) = decode_text_sample(&sample) {
+                    chapters.push(Chapter {
+                        start: start as f64 / time_scale,
+                        title,
+                    });
+                }
+            }
+        }
+        if chapters.is_empty() {
+            chapters = std::mem::take(&mut self.nero_chapters);
+        }
+
+        let subtitle_languages: Vec<String> = self
+            .tracks
+            .iter()
+            .filter(|track| match track.handler.as_ref() {
+                Some(b"sbtl" | b"subt" | b"clcp") => true,
+                Some(b"text") => !chapter_ids.contains(&track.id),
+                _ => false,
+            })
+            .map(|track| {
+                track
+                    .language
+                    .clone()
+                    .unwrap_or_else(|| UNDETERMINED_LANGUAGE.to_string())
+            })
+            .collect();
+
+        for entry in media_tracks::chapter_tags(GROUP, &chapters)
+            .into_iter()
+            .chain(media_tracks::subtitle_tags(GROUP, &subtitle_languages))
+        {
+            self.insert_entry(entry, Priority::Last);
+        }
+        Ok(())
+    }
+
+    /// st3d → VisualSampleDesc Stereoscopic3D: version/flags(4) then the
+    /// stereo mode (int8u; ValueConv `$val =~ s/.* //` keeps the last byte).
+    fn decode_st3d(&mut self, p: &[u8]) {
+        let (Some(&mode), Some(tag_info)) = (
+            p.get(4),
+            QUICK_TIME_VISUALSAMPLEDESC_TAGS_BY_NAME.get(b"st3d".as_slice()),
+        ) else {
+            return;
+        };
+        let value = TagValue::U8(mode);
+        let print = match &tag_info.print_conv {
+            Some(PrintConv::Simple(lookup)) => lookup
+                .get(&mode.to_string())
+                .map(|s| TagValue::string(*s))
+                .unwrap_or_else(|| value.clone()),
+            _ => value.clone(),
+        };
+        self.insert(tag_info.name, value, print, Priority::Last);
+    }
+
+    /// svhd → sv3d MetadataSource: version/flags(4) then a C string; the
+    /// ValueConv `$val=~tr/\0//d` deletes every NUL, flags included.
+    fn decode_svhd(&mut self, p: &[u8]) {
+        let source: Vec<u8> = p.iter().copied().filter(|&b| b != 0).collect();
+        let source = String::from_utf8_lossy(&source).into_owned();
+        self.add_scalar("MetadataSource", TagValue::String(source), Priority::Last);
+    }
+
+    /// prhd → QuickTime::prhd (FORMAT fixed32s): version/flags (idx 0), then
+    /// PoseYawDegrees, PosePitchDegrees, PoseRollDegrees.
+    fn decode_prhd(&mut self, p: &[u8]) {
+        if p.len() < 16 {
+            return;
+        }
+        for (index, name) in ["PoseYawDegrees", "PosePitchDegrees", "PoseRollDegrees"]
+            .into_iter()
+            .enumerate()
+        {
+            let raw = be_u32(p, 4 + index * 4) as i32;
+            self.add_scalar(name, TagValue::F64(qt::fixed32s(raw)), Priority::Last);
+        }
+    }
+
+    /// equi → QuickTime::equi (FORMAT int32u): version/flags (idx 0), then the
+    /// four ProjectionBounds as 0.32 fixed point (ValueConv `$val / 4294967296`).
+    fn decode_equi(&mut self, p: &[u8]) {
+        if p.len() < 20 {
+            return;
+        }
+        let names = [
+            "ProjectionBoundsTop",
+            "ProjectionBoundsBottom",
+            "ProjectionBoundsLeft",
+            "ProjectionBoundsRight",
+        ];
+        for (index, name) in names.into_iter().enumerate() {
+            let bound = be_u32(p, 4 + index * 4) as f64 / 4_294_967_296.0;
+            self.add_scalar(name, TagValue::F64(bound), Priority::Last);
+        }
+    }
+
+    /// cbmp → QuickTime::cbmp (FORMAT int32u): version/flags (idx 0), Layout,
+    /// Padding.
+    fn decode_cbmp(&mut self, p: &[u8]) {
+        if p.len() < 12 {
+            return;
+        }
+        self.add_scalar("Layout", TagValue::U32(be_u32(p, 4)), Priority::Last);
+        self.add_scalar("Padding", TagValue::U32(be_u32(p, 8)), Priority::Last);
+    }
+
+    /// SphericalVideoXML → GSpherical tags, with the Timestamp ValueConv
+    /// `ConvertUnixTime($val)` that XMP tables don't carry.
+    #[cfg(feature = "xmp")]
+    fn decode_spherical_video_xml(&mut self, xml: &[u8]) {
+        let entries = match crate::xmp::XmpProcessor::new().process_xmp_data_individual(xml) {
+            Ok(entries) => entries,
+            Err(e) => {
+                trace!("quicktime: unreadable SphericalVideoXML: {}", e);
+                return;
+            }
+        };
+        for mut entry in entries {
+            if entry.name == "TimeStamp" {
+                if let Some(unix) = entry.value.as_string().and_then(|s| s.parse().ok()) {
+                    let date = TagValue::String(qt::convert_unix_time(unix, false));
+                    entry.value = date.clone();
+                    entry.set_print(date);
+                }
+            }
+            self.insert_entry(entry, Priority::Last);
+        }
+    }
+
+    /// keys → key names for the following ilst (ProcessKeys:9779). Layout:
+    /// version/flags(4) count(4), then per key size(4) namespace(4) name.
+    fn decode_keys(&mut self, p: &[u8]) {
+        self.keys.clear();
+        if p.len() < 8 {
+            return;
+        }
+        let count = be_u32(p, 4) as usize;
+        let mut pos = 8;
+        for _ in 0..count {
+            if pos + 8 > p.len() {
+                break;
+            }
+            let size = be_u32(p, pos) as usize;
+            if size < 8 || pos + size > p.len() {
+                break;
+            }
+            let namespace = &p[pos + 4..pos + 8];
+            let raw = &p[pos + 8..pos + size];
+            // `$tag =~ s/\0.*//s` then strip the Apple domain for 'mdta' keys.
+            let raw = raw.split(|&b| b == 0).next().unwrap_or_default();
+            let mut key = String::from_utf8_lossy(raw).into_owned();
+            if namespace == b"mdta" {
+                if let Some(stripped) = key.strip_prefix(APPLE_KEY_DOMAIN) {
+                    key = stripped.to_string();
+                }
+            }
+            self.keys.push(key);
+            pos += size;
+        }
+    }
+
+    /// One ilst item keyed by a 1-based index into `keys`. The value lives in
+    /// a `data` child atom: size(4) 'data'(4) type(4) locale(4) value.
+    fn decode_keys_item(&mut self, index: u32, p: &[u8]) {
+        let Some(key) = (index as usize)
+            .checked_sub(1)
+            .and_then(|i| self.keys.get(i))
+        else {
+            trace!("quicktime: ilst item {} has no matching key", index);
+            return;
+        };
+        // Keys not in the table are created on the fly by ExifTool; like the
+        // other unknown tags we only emit the ones with a table entry.
+        let Some(tag_info) = QUICK_TIME_KEYS_TAGS_BY_NAME.get(key.as_bytes()) else {
+            trace!("quicktime: unknown key '{}', skipping", key);
+            return;
+        };
+        if p.len() < 16 || &p[4..8] != b"data" {
+            return;
+        }
+        let size = (be_u32(p, 0) as usize).min(p.len());
+        if size < 16 {
+            return;
+        }
+        // Well-known type is the low 24 bits of the type field (QuickTime.pm:9446).
+        let data_type = be_u32(p, 8) & 0x00ff_ffff;
+        let Some(raw) = qt::decode_item_data(data_type, &p[16..size]) else {
+            trace!(
+                "quicktime: key '{}' has unsupported data type {}",
+                key,
+                data_type
+            );
+            return;
+        };
+
+        let value = match &tag_info.value_conv {
+            None => raw,
+            Some(ValueConv::Function(f)) => match f(&raw, None) {
+                Ok(v) => v,
+                Err(_) => raw,
+            },
+            Some(_) => {
+                trace!("quicktime: TODO ValueConv for key '{}'", key);
+                return;
+            }
+        };
+        let print = match &tag_info.print_conv {
+            None => value.clone(),
+            Some(PrintConv::Function(f)) => f(&value, None),
+            Some(PrintConv::Simple(lookup)) => lookup
+                .get(&value.to_string())
+                .map(|s| TagValue::string(*s))
+                .unwrap_or_else(|| value.clone()),
+            Some(_) => {
+                trace!("quicktime: TODO PrintConv for key '{}'", key);
+                return;
+            }
+        };
+        // ItemList tags use the default priority: the last one wins.
+        self.insert_entry(
+            TagEntry {
+                group: GROUP.into(),
+                group1: KEYS_GROUP.into(),
+                name: tag_info.name.into(),
+                value,
+                print: print.into(),
+                source: None,
+            },
+            Priority::Last,
+        );
+    }
+
     // ----- tag accumulation with priority-resolved dedup -----
 
     fn add_date(&mut self, name: &str, raw: u64, priority: Priority) {
         // %timeInfo RawConv patch (QuickTime.pm:257) + ConvertUnixTime ValueConv
         // (ExifTool.pm:6784); PrintConv ConvertDateTime is identity without `-d`,
-        // so value == print. MOV: to_local = false (no -api QuickTimeUTC).
+        // so value == print. MOV: to_local = false; -api QuickTimeUTC is applied
+        // after extraction by quicktime_dates.
         let unix = qt::patch_time_zero(raw as i64);
         let value = TagValue::string(qt::convert_unix_time(unix, false));
         self.insert(name, value.clone(), value, priority);
@@ -432,18 +1010,23 @@ impl<R: Read + Seek> Walker<'_, R> {
     /// existing value (TrackHeader Priority => 0), `Last` overwrites (default).
     fn insert(&mut self, name: &str, value: TagValue, print: TagValue, priority: Priority) {
         let entry = TagEntry {
-            group: GROUP.to_string(),
-            group1: GROUP.to_string(),
-            name: name.to_string(),
+            group: GROUP.into(),
+            group1: GROUP.into(),
+            name: name.into(),
             value,
-            print,
+            print: print.into(),
+            source: None,
         };
+        self.insert_entry(entry, priority);
+    }
+
+    fn insert_entry(&mut self, entry: TagEntry, priority: Priority) {
         match priority {
             Priority::First => {
-                self.tags.entry(name.to_string()).or_insert(entry);
+                self.tags.entry(entry.name.clone()).or_insert(entry);
             }
             Priority::Last => {
-                self.tags.insert(name.to_string(), entry);
+                self.tags.insert(entry.name.clone(), entry);
             }
         }
     }
@@ -456,6 +1039,50 @@ impl<R: Read + Seek> Walker<'_, R> {
 /// Read exactly `buf.len()` bytes. Returns `Ok(Some(()))` on success,
 /// `Ok(None)` on clean EOF/short read (truncated atom — stop, don't panic), and
 /// propagates only genuine I/O errors.
+/// Hash the contents of the top-level `mdat` atoms for ImageDataHash
+///
+/// The media data is streamed through the hasher in 64KB chunks, never read
+/// into memory, so the hasher's progress callback fires throughout. Returns
+/// the number of bytes hashed; a malformed atom ends the walk.
+/// ExifTool: QuickTime.pm ProcessMOV, ImageDataHash($raf, $size) for 'mdat'
+pub fn hash_quicktime_media_data<R: Read + Seek>(
+    reader: &mut R,
+    hasher: &mut ImageDataHasher,
+) -> Result<u64> {
+    let _stage = crate::stats::stage("ImageDataHash");
+    let file_end = reader.seek(SeekFrom::End(0))?;
+    let mut bytes_hashed = 0;
+    let mut pos = 0u64;
+    while pos + 8 <= file_end {
+        reader.seek(SeekFrom::Start(pos))?;
+        let mut header = [0u8; 8];
+        if read_full(reader, &mut header)?.is_none() {
+            break;
+        }
+        let (atom_size, header_size) = match be_u32(&header, 0) {
+            1 => {
+                let mut ext = [0u8; 8];
+                if read_full(reader, &mut ext)?.is_none() {
+                    break;
+                }
+                (u64::from_be_bytes(ext), 16)
+            }
+            0 => (file_end - pos, 8),
+            n => (n as u64, 8),
+        };
+        if atom_size < header_size || pos.saturating_add(atom_size) > file_end {
+            trace!("quicktime: malformed atom at {pos} while hashing, stopping");
+            break;
+        }
+        if &header[4..8] == b"mdat" {
+            bytes_hashed +=
+                hasher.hash_at_offset(reader, pos + header_size, atom_size - header_size)?;
+        }
+        pos += atom_size;
+    }
+    Ok(bytes_hashed)
+}
+
 fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<Option<()>> {
     let mut filled = 0;
     while filled < buf.len() {
@@ -469,6 +1096,61 @@ fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<Option<()>> {
     Ok(Some(()))
 }
 
+/// Nero `chpl` chapter list: version(1) flags(3), reserved(4) in version 1,
+/// chapter count(1), then per chapter a start time in 100 ns units (int64u)
+/// and a Pascal string title
+fn decode_chpl(p: &[u8]) -> Vec<Chapter> {
+    let mut pos = if p.first().copied().unwrap_or(0) == 0 {
+        4
+    } else {
+        8
+    };
+    let Some(&count) = p.get(pos) else {
+        return Vec::new();
+    };
+    pos += 1;
+    let mut chapters = Vec::new();
+    for _ in 0..count {
+        let Some(&len) = p.get(pos + 8) else {
+            break;
+        };
+        let Some(title) = p.get(pos + 9..pos + 9 + len as usize) else {
+            break;
+        };
+        chapters.push(Chapter {
+            start: be_u64(p, pos) as f64 / 1e7,
+            title: String::from_utf8_lossy(title).into_owned(),
+        });
+        pos += 9 + len as usize;
+    }
+    chapters
+}
+
+/// Text of a QuickTime text or tx3g sample: a 16-bit length, then UTF-8 text,
+/// or UTF-16 text after a byte order mark
+fn decode_text_sample(p: &[u8]) -> Option<String> {
+    let len = u16::from_be_bytes([*p.first()?, *p.get(1)?]) as usize;
+    let text = p.get(2..2 + len)?;
+    let title = match text {
+        [0xfe, 0xff, rest @ ..] => {
+            let units: Vec<u16> = rest
+                .chunks_exact(2)
+                .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        [0xff, 0xfe, rest @ ..] => {
+            let units: Vec<u16> = rest
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .collect();
+            String::from_utf16_lossy(&units)
+        }
+        _ => String::from_utf8_lossy(text).into_owned(),
+    };
+    Some(title.trim_end_matches('\0').to_string())
+}
+
 #[inline]
 fn be_u32(p: &[u8], off: usize) -> u32 {
     u32::from_be_bytes([p[off], p[off + 1], p[off + 2], p[off + 3]])
@@ -497,10 +1179,33 @@ mod tests {
     use super::*;
     use std::io::Cursor;
 
+    #[test]
+    fn hashes_only_mdat_contents() {
+        use crate::hash::ImageHashType;
+
+        let mut data = Vec::new();
+        data.extend_from_slice(&16u32.to_be_bytes());
+        data.extend_from_slice(b"ftypisom\0\0\0\0");
+        data.extend_from_slice(&13u32.to_be_bytes());
+        data.extend_from_slice(b"mdatvideo");
+        data.extend_from_slice(&12u32.to_be_bytes());
+        data.extend_from_slice(b"free\0\0\0\0");
+
+        let mut hasher = ImageDataHasher::new(ImageHashType::Md5);
+        let bytes = hash_quicktime_media_data(&mut Cursor::new(data), &mut hasher).unwrap();
+        assert_eq!(bytes, 5);
+
+        let mut expected = ImageDataHasher::new(ImageHashType::Md5);
+        expected.update(b"video");
+        assert_eq!(hasher.finalize(), expected.finalize());
+    }
+
     #[test]
     fn empty_reader_yields_nothing() {
         let mut r = Cursor::new(Vec::<u8>::new());
-        let tags = extract_quicktime_metadata(&mut r).unwrap();
+        let tags = extract_quicktime_metadata(&mut r, ScanDepth::Full)
+            .unwrap()
+            .0;
         assert!(tags.is_empty());
     }
 
@@ -512,9 +1217,36 @@ mod tests {
         data.extend_from_slice(b"moov");
         data.extend_from_slice(&[0, 0, 0, 0]);
         let mut r = Cursor::new(data);
-        // Must not panic; returns no tags (atom extends past container → stop).
-        let tags = extract_quicktime_metadata(&mut r).unwrap();
+        // Must not panic; returns no tags (no complete child atom), and
+        // reports where the file was cut off.
+        let (tags, truncation) = extract_quicktime_metadata(&mut r, ScanDepth::Full).unwrap();
         assert!(tags.is_empty());
+        assert_eq!(
+            truncation,
+            Some(Truncation::new("QuickTime moov atom", 0, 1000, 12))
+        );
+    }
+
+    #[test]
+    fn truncated_moov_returns_complete_children() {
+        // mvhd v1: ver/flags(4) create(8) modify(8) timescale(4) duration(8)
+        let mut mvhd = vec![1u8, 0, 0, 0];
+        mvhd.extend_from_slice(&[0u8; 16]);
+        mvhd.extend_from_slice(&1000u32.to_be_bytes());
+        mvhd.extend_from_slice(&10_000u64.to_be_bytes());
+        // A trak after the mvhd was cut off mid-header
+        let mut moov = atom(
+            b"moov",
+            &[atom(b"mvhd", &mvhd), atom(b"trak", &[0; 64])].concat(),
+        );
+        moov.truncate(moov.len() - 60);
+
+        let (tags, truncation) =
+            extract_quicktime_metadata(&mut Cursor::new(&moov), ScanDepth::Full).unwrap();
+        assert!(tags.iter().any(|t| t.name == "Duration"));
+        let truncation = truncation.unwrap();
+        assert_eq!(truncation.context, "QuickTime moov atom");
+        assert_eq!(truncation.missing_length(), 60);
     }
 
     #[test]
@@ -525,10 +1257,35 @@ mod tests {
         data.extend_from_slice(b"free");
         data.extend_from_slice(&[0xAA; 16]);
         let mut r = Cursor::new(data);
-        let tags = extract_quicktime_metadata(&mut r).unwrap();
+        let tags = extract_quicktime_metadata(&mut r, ScanDepth::Full)
+            .unwrap()
+            .0;
         assert!(tags.is_empty());
     }
 
+    #[test]
+    fn fast2_stops_at_mdat() {
+        // mvhd v1: ver/flags(4) create(8) modify(8) timescale(4) duration(8)
+        let mut mvhd = vec![1u8, 0, 0, 0];
+        mvhd.extend_from_slice(&[0u8; 16]);
+        mvhd.extend_from_slice(&1000u32.to_be_bytes());
+        mvhd.extend_from_slice(&10_000u64.to_be_bytes());
+        let data = [
+            atom(b"mdat", b"video"),
+            atom(b"moov", &atom(b"mvhd", &mvhd)),
+        ]
+        .concat();
+
+        let full = extract_quicktime_metadata(&mut Cursor::new(&data), ScanDepth::Full)
+            .unwrap()
+            .0;
+        assert!(full.iter().any(|t| t.name == "Duration"));
+        let fast = extract_quicktime_metadata(&mut Cursor::new(&data), ScanDepth::Fast2)
+            .unwrap()
+            .0;
+        assert!(fast.is_empty());
+    }
+
     fn atom(fourcc: &[u8; 4], content: &[u8]) -> Vec<u8> {
         let mut a = Vec::with_capacity(8 + content.len());
         a.extend_from_slice(&((content.len() as u32) + 8).to_be_bytes());
@@ -584,29 +1341,314 @@ mod tests {
         let moov = atom(b"moov", &[atom(b"mvhd", &mvhd), trak].concat());
 
         let mut r = Cursor::new(moov);
-        let tags = extract_quicktime_metadata(&mut r).unwrap();
+        let tags = extract_quicktime_metadata(&mut r, ScanDepth::Full)
+            .unwrap()
+            .0;
         let get = |name: &str| {
             tags.iter()
                 .find(|t| t.name == name)
                 .unwrap_or_else(|| panic!("missing {name}"))
         };
 
-        assert_eq!(get("CreateDate").print.to_string(), "2020:01:01 00:00:00");
-        assert_eq!(get("ModifyDate").print.to_string(), "2020:01:01 01:00:00");
-        assert_eq!(get("Duration").print.to_string(), "10.00 s");
+        assert_eq!(get("CreateDate").print().to_string(), "2020:01:01 00:00:00");
+        assert_eq!(get("ModifyDate").print().to_string(), "2020:01:01 01:00:00");
+        assert_eq!(get("Duration").print().to_string(), "10.00 s");
         assert_eq!(
-            get("TrackCreateDate").print.to_string(),
+            get("TrackCreateDate").print().to_string(),
             "2020:01:01 00:00:00"
         );
-        assert_eq!(get("TrackDuration").print.to_string(), "10.00 s");
+        assert_eq!(get("TrackDuration").print().to_string(), "10.00 s");
         // MediaDuration divides by the SAME mdhd's timescale (500), not mvhd's.
-        assert_eq!(get("MediaDuration").print.to_string(), "10.00 s");
+        assert_eq!(get("MediaDuration").print().to_string(), "10.00 s");
         assert_eq!(
-            get("MediaCreateDate").print.to_string(),
+            get("MediaCreateDate").print().to_string(),
             "2020:01:01 00:00:00"
         );
         // v1 shifts width/height to bytes 88/92; 16.16 fixed via FixWrongFormat.
         assert_eq!(get("ImageWidth").value, TagValue::U32(1920));
         assert_eq!(get("ImageHeight").value, TagValue::U32(1080));
     }
+
+    /// Spherical Video V2 boxes in a video sample entry (stsd → avc1 →
+    /// st3d/sv3d). Only entries of 'vide' tracks are decoded.
+    #[test]
+    fn spherical_video_v2_sample_entry() {
+        let full_box = |fields: &[u32]| {
+            let mut b = vec![0u8; 4]; // version/flags
+            for field in fields {
+                b.extend_from_slice(&field.to_be_bytes());
+            }
+            b
+        };
+        let mut svhd = vec![0u8; 4];
+        svhd.extend_from_slice(b"Spherical Metadata Tool\0");
+        let proj = atom(
+            b"proj",
+            &[
+                atom(b"prhd", &full_box(&[90 << 16, (-10i32 << 16) as u32, 0])),
+                atom(b"equi", &full_box(&[0, 0x8000_0000, 0, 0])),
+            ]
+            .concat(),
+        );
+        let sv3d = atom(b"sv3d", &[atom(b"svhd", &svhd), proj].concat());
+        let st3d = atom(b"st3d", &[0, 0, 0, 0, 1]);
+        let avc1 = atom(b"avc1", &[vec![0u8; 78], st3d, sv3d].concat());
+        let mut stsd = full_box(&[1]);
+        stsd.extend_from_slice(&avc1);
+        let stbl = atom(b"stbl", &atom(b"stsd", &stsd));
+        let trak = |handler: &[u8; 4]| {
+            let mut hdlr = vec![0u8; 8];
+            hdlr.extend_from_slice(handler);
+            hdlr.extend_from_slice(&[0u8; 12]);
+            let minf = atom(b"minf", &stbl);
+            atom(
+                b"trak",
+                &atom(b"mdia", &[atom(b"hdlr", &hdlr), minf].concat()),
+            )
+        };
+
+        let moov = atom(b"moov", &trak(b"vide"));
+        let tags = extract_quicktime_metadata(&mut Cursor::new(moov), ScanDepth::Full)
+            .unwrap()
+            .0;
+        let get = |name: &str| {
+            tags.iter()
+                .find(|t| t.name == name)
+                .unwrap_or_else(|| panic!("missing {name}"))
+        };
+        assert_eq!(get("Stereoscopic3D").value, TagValue::U8(1));
+        assert_eq!(
+            get("Stereoscopic3D").print(),
+            &TagValue::string("Stereoscopic Top-Bottom")
+        );
+        assert_eq!(
+            get("MetadataSource").value,
+            TagValue::string("Spherical Metadata Tool")
+        );
+        assert_eq!(get("PoseYawDegrees").value, TagValue::F64(90.0));
+        assert_eq!(get("PosePitchDegrees").value, TagValue::F64(-10.0));
+        assert_eq!(get("ProjectionBoundsBottom").value, TagValue::F64(0.5));
+        assert_eq!(get("ProjectionBoundsTop").value, TagValue::F64(0.0));
+
+        // The same sample entry in a sound track is ignored
+        let moov = atom(b"moov", &trak(b"soun"));
+        let tags = extract_quicktime_metadata(&mut Cursor::new(moov), ScanDepth::Full)
+            .unwrap()
+            .0;
+        assert!(tags.is_empty());
+    }
+
+    /// Spherical Video V1: GSpherical RDF/XML in a track 'uuid' box.
+    #[cfg(feature = "xmp")]
+    #[test]
+    fn spherical_video_v1_uuid() {
+        let xml = br#"<?xml version="1.0"?><rdf:SphericalVideo
+ xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+ xmlns:GSpherical="http://ns.google.com/videos/1.0/spherical/">
+<GSpherical:Spherical>true</GSpherical:Spherical>
+<GSpherical:Stitched>true</GSpherical:Stitched>
+<GSpherical:StitchingSoftware>RICOH THETA</GSpherical:StitchingSoftware>
+<GSpherical:ProjectionType>equirectangular</GSpherical:ProjectionType>
+<GSpherical:StereoMode>mono</GSpherical:StereoMode>
+<GSpherical:Timestamp>1577836800</GSpherical:Timestamp>
+</rdf:SphericalVideo>"#;
+        let uuid = atom(b"uuid", &[SPHERICAL_VIDEO_V1_UUID.as_slice(), xml].concat());
+        let moov = atom(b"moov", &atom(b"trak", &uuid));
+        let tags = extract_quicktime_metadata(&mut Cursor::new(moov), ScanDepth::Full)
+            .unwrap()
+            .0;
+        let get = |name: &str| {
+            tags.iter()
+                .find(|t| t.name == name)
+                .unwrap_or_else(|| panic!("missing {name}"))
+        };
+        assert_eq!(
+            get("ProjectionType").value,
+            TagValue::string("equirectangular")
+        );
+        assert_eq!(get("ProjectionType").group, "XMP");
+        assert_eq!(
+            get("StitchingSoftware").value,
+            TagValue::string("RICOH THETA")
+        );
+        assert_eq!(get("StereoMode").value, TagValue::string("mono"));
+        assert_eq!(
+            get("TimeStamp").value,
+            TagValue::string("2020:01:01 00:00:00")
+        );
+    }
+
+    /// Apple Live Photo videos carry the pairing UUID as an 'mdta' key in a
+    /// bare (QuickTime-style) moov/meta; keys are resolved via the 1-based ilst
+    /// item index (ProcessKeys:9779).
+    #[test]
+    fn keys_item_list_yields_content_identifier() {
+        let key = |name: &str| {
+            let mut k = ((name.len() as u32) + 8).to_be_bytes().to_vec();
+            k.extend_from_slice(b"mdta");
+            k.extend_from_slice(name.as_bytes());
+            k
+        };
+        let mut keys = vec![0u8; 4];
+        keys.extend_from_slice(&2u32.to_be_bytes());
+        keys.extend_from_slice(&key("com.apple.quicktime.make"));
+        keys.extend_from_slice(&key("com.apple.quicktime.content.identifier"));
+
+        let data = |value: &str| {
+            let mut d = 1u32.to_be_bytes().to_vec(); // UTF-8
+            d.extend_from_slice(&[0u8; 4]); // locale
+            d.extend_from_slice(value.as_bytes());
+            atom(b"data", &d)
+        };
+        let ilst = atom(
+            b"ilst",
+            &[
+                atom(
+                    &2u32.to_be_bytes(),
+                    &data("8D3C7C70-4E37-4B3D-A3A0-8AB51C0C8F43"),
+                ),
+                atom(&1u32.to_be_bytes(), &data("Apple")),
+            ]
+            .concat(),
+        );
+        let hdlr = atom(b"hdlr", &[0u8; 24]);
+        let meta = atom(b"meta", &[hdlr, atom(b"keys", &keys), ilst].concat());
+        let moov = atom(b"moov", &meta);
+
+        let tags = extract_quicktime_metadata(&mut Cursor::new(moov), ScanDepth::Full)
+            .unwrap()
+            .0;
+        let content_id = tags.iter().find(|t| t.name == "ContentIdentifier").unwrap();
+        assert_eq!(content_id.group, "QuickTime");
+        assert_eq!(content_id.group1, "Keys");
+        assert_eq!(
+            content_id.value,
+            TagValue::string("8D3C7C70-4E37-4B3D-A3A0-8AB51C0C8F43")
+        );
+        let make = tags.iter().find(|t| t.name == "Make").unwrap();
+        assert_eq!(make.print(), &TagValue::string("Apple"));
+    }
+
+    fn hdlr(handler_type: &[u8; 4]) -> Vec<u8> {
+        atom(b"hdlr", &[&[0u8; 8][..], handler_type, &[0u8; 12]].concat())
+    }
+
+    fn tkhd(track_id: u32) -> Vec<u8> {
+        let mut tkhd = vec![0u8; 84];
+        tkhd[12..16].copy_from_slice(&track_id.to_be_bytes());
+        atom(b"tkhd", &tkhd)
+    }
+
+    /// mdhd v0 with a 1000 Hz time scale and a packed ISO 639-2 language
+    fn mdhd(language: u16) -> Vec<u8> {
+        let mut mdhd = vec![0u8; 24];
+        mdhd[12..16].copy_from_slice(&1000u32.to_be_bytes());
+        mdhd[20..22].copy_from_slice(&language.to_be_bytes());
+        atom(b"mdhd", &mdhd)
+    }
+
+    fn full_box_u32s(fourcc: &[u8; 4], values: &[u32]) -> Vec<u8> {
+        let mut content = vec![0u8; 4];
+        for value in values {
+            content.extend_from_slice(&value.to_be_bytes());
+        }
+        atom(fourcc, &content)
+    }
+
+    /// Chapter titles come from the text track named by the video track's
+    /// `tref`/`chap`, timed by its stts; the `sbtl` track is a subtitle track.
+    #[test]
+    fn chapter_track_and_subtitle_languages() {
+        // mdat at offset 0: two text samples at file offsets 8 and 15
+        let mdat = atom(b"mdat", b"\0\x05Intro\0\x04Main");
+        let stbl = atom(
+            b"stbl",
+            &[
+                full_box_u32s(b"stts", &[2, 1, 1500, 1, 1000]),
+                full_box_u32s(b"stsz", &[0, 2, 7, 6]),
+                full_box_u32s(b"stsc", &[1, 1, 2, 1]),
+                full_box_u32s(b"stco", &[1, 8]),
+            ]
+            .concat(),
+        );
+        let video = atom(
+            b"trak",
+            &[
+                tkhd(1),
+                atom(b"tref", &atom(b"chap", &2u32.to_be_bytes())),
+                atom(b"mdia", &[mdhd(0x15c7), hdlr(b"vide")].concat()),
+            ]
+            .concat(),
+        );
+        let chapters = atom(
+            b"trak",
+            &[
+                tkhd(2),
+                atom(
+                    b"mdia",
+                    &[mdhd(0x15c7), hdlr(b"text"), atom(b"minf", &stbl)].concat(),
+                ),
+            ]
+            .concat(),
+        );
+        // 'fre' = (6 << 10) | (18 << 5) | 5
+        let subtitles = atom(
+            b"trak",
+            &[
+                tkhd(3),
+                atom(b"mdia", &[mdhd(0x1a45), hdlr(b"sbtl")].concat()),
+            ]
+            .concat(),
+        );
+        let moov = atom(b"moov", &[video, chapters, subtitles].concat());
+
+        let tags =
+            extract_quicktime_metadata(&mut Cursor::new([mdat, moov].concat()), ScanDepth::Full)
+                .unwrap()
+                .0;
+        let get = |name: &str| {
+            tags.iter()
+                .find(|t| t.name == name)
+                .unwrap_or_else(|| panic!("missing {name}"))
+        };
+        assert_eq!(get("ChapterCount").value, TagValue::U32(2));
+        assert_eq!(
+            get("ChapterTitles").value,
+            TagValue::Array(vec![TagValue::string("Intro"), TagValue::string("Main")])
+        );
+        assert_eq!(
+            get("ChapterStartTimes").value,
+            TagValue::F64Array(vec![0.0, 1.5])
+        );
+        // The chapter text track is not a subtitle track
+        assert_eq!(get("SubtitleTrackCount").value, TagValue::U32(1));
+        assert_eq!(
+            get("SubtitleLanguages").value,
+            TagValue::Array(vec![TagValue::string("fre")])
+        );
+    }
+
+    #[test]
+    fn nero_chapter_list() {
+        let mut chpl = vec![1u8, 0, 0, 0, 0, 0, 0, 0, 2];
+        chpl.extend_from_slice(&0u64.to_be_bytes());
+        chpl.extend_from_slice(b"\x05Intro");
+        chpl.extend_from_slice(&15_000_000u64.to_be_bytes());
+        chpl.extend_from_slice(b"\x04Main");
+        let moov = atom(b"moov", &atom(b"udta", &atom(b"chpl", &chpl)));
+
+        let tags = extract_quicktime_metadata(&mut Cursor::new(moov), ScanDepth::Full)
+            .unwrap()
+            .0;
+        let get = |name: &str| tags.iter().find(|t| t.name == name).unwrap();
+        assert_eq!(
+            get("ChapterTitles").value,
+            TagValue::Array(vec![TagValue::string("Intro"), TagValue::string("Main")])
+        );
+        assert_eq!(
+            get("ChapterStartTimes").value,
+            TagValue::F64Array(vec![0.0, 1.5])
+        );
+        assert!(!tags.iter().any(|t| t.name == "SubtitleTrackCount"));
+    }
 }
@@ -0,0 +1,378 @@
+//! Time zone of QuickTime date/time tags
+//!
+//! The QuickTime specification says CreateDate, ModifyDate and the track and
+//! media dates are UTC, but many cameras and some phones store local time
+//! instead. ExifTool outputs the stored values unchanged unless the
+//! `QuickTimeUTC` API option is set, in which case they are converted from UTC
+//! to local time (QuickTime.pm:280, `ConvertUnixTime($val, $self->Options("QuickTimeUTC"))`).
+//!
+//! [`QuickTimeUtc::Auto`] decides per file, in order:
+//!
+//! 1. GPS: the GPS time stamp (UTC) within 15 minutes of CreateDate means
+//!    UTC; otherwise their difference, rounded to 15 minutes and at most 14
+//!    hours, is the local offset.
+//! 2. File modification time: recordings are written when they end, so a
+//!    modification time between 2 minutes before CreateDate and 10 minutes
+//!    after CreateDate + Duration picks UTC, or else local time in the zone
+//!    of the modification time.
+//! 3. Otherwise UTC, as in the specification.
+//!
+//! UTC dates become local times with their offset, as with ExifTool's option;
+//! local dates get the offset appended.
+//!
+//! ## Diagnostics
+//!
+//! - `Composite:QuickTimeCreateDateRaw`: QuickTime:CreateDate as stored
+//! - `Composite:QuickTimeDateSource`: the decision and its evidence, e.g.
+//!   `UTC (QuickTimeUTC)`, `UTC (GPS)`, `Local (File:FileModifyDate)` or
+//!   `UTC (assumed)`
+
+use chrono::{
+    DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+};
+
+use crate::composite_tags::{format_offset, parse_date};
+use crate::types::{QuickTimeUtc, TagEntry, TagValue};
+
+/// QuickTime date tags stored without a time zone
+const DATE_TAGS: &[&str] = &[
+    "CreateDate",
+    "ModifyDate",
+    "TrackCreateDate",
+    "TrackModifyDate",
+    "MediaCreateDate",
+    "MediaModifyDate",
+];
+
+/// Largest difference between CreateDate and GPS time that still means UTC
+const GPS_UTC_TOLERANCE_MINUTES: i64 = 15;
+
+/// Largest difference between local and UTC time taken as a time zone
+const MAX_OFFSET_MINUTES: i64 = 14 * 60;
+
+/// How far the file modification time may precede CreateDate
+const MTIME_BEFORE_START: Duration = Duration::minutes(2);
+
+/// How far the file modification time may follow the end of the recording
+const MTIME_AFTER_END: Duration = Duration::minutes(10);
+
+/// How the stored QuickTime dates are interpreted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Interpretation {
+    Utc,
+    /// Local time with this offset from UTC, in minutes
+    Local(i32),
+}
+
+/// Resolve the time zone of the QuickTime dates in `entries`
+///
+/// Rewrites the QuickTime date tags in place and returns the diagnostic
+/// Composite tags. `file_modified` is the file system modification time, with
+/// the offset of the local zone at that time. Nothing is changed when the mode
+/// is [`QuickTimeUtc::Off`], or without a QuickTime:CreateDate that lacks a
+/// zone.
+pub(crate) fn resolve_quicktime_dates(
+    entries: &mut [TagEntry],
+    mode: QuickTimeUtc,
+    file_modified: Option<DateTime<FixedOffset>>,
+) -> Vec<TagEntry> {
+    if mode == QuickTimeUtc::Off {
+        return Vec::new();
+    }
+    let Some(raw) = quicktime_text(entries, "CreateDate") else {
+        return Vec::new();
+    };
+    let Some((_, create_date, None)) = parse_date(&raw) else {
+        return Vec::new();
+    };
+
+    let (interpretation, evidence) = match mode {
+        QuickTimeUtc::Auto => gps_interpretation(entries, create_date)
+            .map(|i| (i, "GPS"))
+            .or_else(|| {
+                let duration = quicktime_duration(entries);
+                mtime_interpretation(create_date, duration, file_modified?)
+                    .map(|i| (i, "File:FileModifyDate"))
+            })
+            .unwrap_or((Interpretation::Utc, "assumed")),
+        _ => (Interpretation::Utc, "QuickTimeUTC"),
+    };
+
+    for entry in entries.iter_mut() {
+        if entry.group != "QuickTime" || !DATE_TAGS.contains(&entry.name.as_ref()) {
+            continue;
+        }
+        let Some((date_time, naive, None)) = parse_date(&entry.value.to_string()) else {
+            continue;
+        };
+        let converted = match interpretation {
+            Interpretation::Utc => Local
+                .from_utc_datetime(&naive)
+                .format("%Y:%m:%d %H:%M:%S%:z")
+                .to_string(),
+            Interpretation::Local(offset) => format!("{date_time}{}", format_offset(offset)),
+        };
+        entry.value = TagValue::String(converted.clone());
+        entry.set_print(TagValue::String(converted));
+    }
+
+    let source = match interpretation {
+        Interpretation::Utc => format!("UTC ({evidence})"),
+        Interpretation::Local(_) => format!("Local ({evidence})"),
+    };
+    vec![
+        composite_tag("QuickTimeCreateDateRaw", raw),
+        composite_tag("QuickTimeDateSource", source),
+    ]
+}
+
+/// Compare CreateDate with the GPS time stamp, which is always UTC
+fn gps_interpretation(entries: &[TagEntry], create_date: NaiveDateTime) -> Option<Interpretation> {
+    let gps = gps_date_time(entries)?;
+    let minutes = (create_date - gps).num_minutes();
+    if minutes.abs() <= GPS_UTC_TOLERANCE_MINUTES {
+        return Some(Interpretation::Utc);
+    }
+    let rounded = ((minutes as f64 / 15.0).round() * 15.0) as i64;
+    (rounded.abs() <= MAX_OFFSET_MINUTES).then_some(Interpretation::Local(rounded as i32))
+}
+
+/// Check which interpretation puts the recording just before the file was written
+fn mtime_interpretation(
+    create_date: NaiveDateTime,
+    duration: Duration,
+    file_modified: DateTime<FixedOffset>,
+) -> Option<Interpretation> {
+    let modified = file_modified.naive_utc();
+    let matches = |start: NaiveDateTime| {
+        modified >= start - MTIME_BEFORE_START && modified <= start + duration + MTIME_AFTER_END
+    };
+    if matches(create_date) {
+        return Some(Interpretation::Utc);
+    }
+    let offset = file_modified.offset().local_minus_utc() / 60;
+    (offset != 0 && matches(create_date - Duration::minutes(offset.into())))
+        .then_some(Interpretation::Local(offset))
+}
+
+/// UTC time from a GPSDateTime tag, or from GPS:GPSDateStamp and GPSTimeStamp
+fn gps_date_time(entries: &[TagEntry]) -> Option<NaiveDateTime> {
+    let find = |group: Option<&str>, name: &str| {
+        entries
+            .iter()
+            .find(|e| e.name == name && group.is_none_or(|group| e.group == group))
+            .map(|e| e.value.to_string())
+    };
+    if let Some((_, naive, zone)) = find(None, "GPSDateTime").and_then(|d| parse_date(&d)) {
+        return Some(naive - Duration::minutes(zone.unwrap_or(0).into()));
+    }
+    let date = NaiveDate::parse_from_str(find(Some("GPS"), "GPSDateStamp")?.trim(), "%Y:%m:%d");
+    let time = NaiveTime::parse_from_str(find(Some("GPS"), "GPSTimeStamp")?.trim(), "%H:%M:%S%.f");
+    Some(date.ok()?.and_time(time.ok()?))
+}
+
+/// QuickTime:Duration, zero if unknown
+fn quicktime_duration(entries: &[TagEntry]) -> Duration {
+    entries
+        .iter()
+        .find(|e| e.group == "QuickTime" && e.name == "Duration")
+        .and_then(|e| e.value.as_f64())
+        .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+        .map_or(Duration::zero(), |seconds| {
+            Duration::milliseconds((seconds * 1000.0) as i64)
+        })
+}
+
+/// Non-empty string value of a QuickTime tag
+fn quicktime_text(entries: &[TagEntry], name: &str) -> Option<String> {
+    entries
+        .iter()
+        .find(|e| e.group == "QuickTime" && e.name == name)
+        .map(|e| e.value.to_string())
+        .filter(|text| !text.trim().is_empty())
+}
+
+fn composite_tag(name: &str, value: String) -> TagEntry {
+    TagEntry {
+        group: "Composite".into(),
+        group1: "Composite".into(),
+        name: name.into(),
+        value: TagValue::String(value.clone()),
+        print: TagValue::String(value).into(),
+        source: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(group: &str, name: &str, value: TagValue) -> TagEntry {
+        TagEntry {
+            group: group.into(),
+            group1: group.into(),
+            name: name.into(),
+            value: value.clone(),
+            print: value.into(),
+            source: None,
+        }
+    }
+
+    fn video(create_date: &str) -> Vec<TagEntry> {
+        vec![
+            tag("QuickTime", "CreateDate", TagValue::string(create_date)),
+            tag(
+                "QuickTime",
+                "MediaCreateDate",
+                TagValue::string(create_date),
+            ),
+            tag("QuickTime", "Duration", TagValue::F64(60.0)),
+        ]
+    }
+
+    fn value(entries: &[TagEntry], group: &str, name: &str) -> String {
+        entries
+            .iter()
+            .find(|e| e.group == group && e.name == name)
+            .map(|e| e.value.to_string())
+            .unwrap()
+    }
+
+    fn local_time(utc: &str) -> String {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y:%m:%d %H:%M:%S").unwrap();
+        Local
+            .from_utc_datetime(&naive)
+            .format("%Y:%m:%d %H:%M:%S%:z")
+            .to_string()
+    }
+
+    fn modified(date: &str) -> Option<DateTime<FixedOffset>> {
+        DateTime::parse_from_str(date, "%Y:%m:%d %H:%M:%S%:z").ok()
+    }
+
+    #[test]
+    fn test_off_leaves_dates_unchanged() {
+        let mut entries = video("2024:06:01 19:00:00");
+        let tags = resolve_quicktime_dates(&mut entries, QuickTimeUtc::Off, None);
+        assert!(tags.is_empty());
+        assert_eq!(
+            value(&entries, "QuickTime", "CreateDate"),
+            "2024:06:01 19:00:00"
+        );
+    }
+
+    #[test]
+    fn test_on_converts_utc_to_local() {
+        let mut entries = video("2024:06:01 19:00:00");
+        let tags = resolve_quicktime_dates(&mut entries, QuickTimeUtc::On, None);
+        let expected = local_time("2024:06:01 19:00:00");
+        assert_eq!(value(&entries, "QuickTime", "CreateDate"), expected);
+        assert_eq!(value(&entries, "QuickTime", "MediaCreateDate"), expected);
+        assert_eq!(
+            value(&tags, "Composite", "QuickTimeCreateDateRaw"),
+            "2024:06:01 19:00:00"
+        );
+        assert_eq!(
+            value(&tags, "Composite", "QuickTimeDateSource"),
+            "UTC (QuickTimeUTC)"
+        );
+    }
+
+    #[test]
+    fn test_auto_gps_utc() {
+        let mut entries = video("2024:06:01 19:00:00");
+        entries.push(tag("GPS", "GPSDateStamp", TagValue::string("2024:06:01")));
+        entries.push(tag("GPS", "GPSTimeStamp", TagValue::string("19:00:30")));
+        let tags = resolve_quicktime_dates(&mut entries, QuickTimeUtc::Auto, None);
+        assert_eq!(
+            value(&tags, "Composite", "QuickTimeDateSource"),
+            "UTC (GPS)"
+        );
+        assert_eq!(
+            value(&entries, "QuickTime", "CreateDate"),
+            local_time("2024:06:01 19:00:00")
+        );
+    }
+
+    #[test]
+    fn test_auto_gps_local() {
+        // Stored 8 hours behind the GPS time: local time in UTC-08:00
+        let mut entries = video("2024:06:01 11:00:00");
+        entries.push(tag(
+            "Keys",
+            "GPSDateTime",
+            TagValue::string("2024:06:01 19:01:10Z"),
+        ));
+        let tags = resolve_quicktime_dates(&mut entries, QuickTimeUtc::Auto, None);
+        assert_eq!(
+            value(&tags, "Composite", "QuickTimeDateSource"),
+            "Local (GPS)"
+        );
+        assert_eq!(
+            value(&entries, "QuickTime", "CreateDate"),
+            "2024:06:01 11:00:00-08:00"
+        );
+        assert_eq!(
+            value(&entries, "QuickTime", "MediaCreateDate"),
+            "2024:06:01 11:00:00-08:00"
+        );
+    }
+
+    #[test]
+    fn test_auto_file_modify_date() {
+        // Written 61 seconds after the start of a 60 s recording
+        let mut entries = video("2024:06:01 19:00:00");
+        let tags = resolve_quicktime_dates(
+            &mut entries,
+            QuickTimeUtc::Auto,
+            modified("2024:06:01 12:01:01-07:00"),
+        );
+        assert_eq!(
+            value(&tags, "Composite", "QuickTimeDateSource"),
+            "UTC (File:FileModifyDate)"
+        );
+
+        let mut entries = video("2024:06:01 12:00:00");
+        let tags = resolve_quicktime_dates(
+            &mut entries,
+            QuickTimeUtc::Auto,
+            modified("2024:06:01 12:01:01-07:00"),
+        );
+        assert_eq!(
+            value(&tags, "Composite", "QuickTimeDateSource"),
+            "Local (File:FileModifyDate)"
+        );
+        assert_eq!(
+            value(&entries, "QuickTime", "CreateDate"),
+            "2024:06:01 12:00:00-07:00"
+        );
+    }
+
+    #[test]
+    fn test_auto_without_evidence_assumes_utc() {
+        // Copied a day later: the modification time says nothing
+        let mut entries = video("2024:06:01 19:00:00");
+        let tags = resolve_quicktime_dates(
+            &mut entries,
+            QuickTimeUtc::Auto,
+            modified("2024:06:02 12:00:00-07:00"),
+        );
+        assert_eq!(
+            value(&tags, "Composite", "QuickTimeDateSource"),
+            "UTC (assumed)"
+        );
+    }
+
+    #[test]
+    fn test_zoned_and_zero_dates_are_left_alone() {
+        let mut entries = video("2024:06:01 19:00:00+02:00");
+        assert!(resolve_quicktime_dates(&mut entries, QuickTimeUtc::On, None).is_empty());
+
+        let mut entries = video("0000:00:00 00:00:00");
+        assert!(resolve_quicktime_dates(&mut entries, QuickTimeUtc::On, None).is_empty());
+        assert_eq!(
+            value(&entries, "QuickTime", "CreateDate"),
+            "0000:00:00 00:00:00"
+        );
+    }
+}
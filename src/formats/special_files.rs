@@ -0,0 +1,152 @@
+//! Symbolic links and special files
+//!
+//! Opening a named pipe blocks until something writes to it, and a device
+//! may never run out of data, so paths that aren't regular files (or
+//! directories) are refused before they are opened. A FIFO left in a backup
+//! folder would otherwise hang a whole batch.
+//!
+//! Symbolic links are followed, as ExifTool does, unless
+//! [`SymlinkPolicy::Report`] asks for the link itself: its File:System tags,
+//! with FileType "LINK".
+
+use std::fs::{FileType, Metadata};
+use std::io;
+use std::path::Path;
+
+use chrono::{DateTime, Local};
+
+use crate::types::{ExifData, ExifError, FilterOptions, Result, TagEntry, TagValue};
+
+/// Refuse a path that would block or never end when read
+///
+/// Paths that don't exist are left for opening the file to report.
+pub fn check_regular_file(path: &Path) -> Result<()> {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return Ok(());
+    };
+    match special_file_kind(&metadata.file_type()) {
+        Some(kind) => Err(ExifError::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Not a regular file ({kind})"),
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Name of a file type that isn't a regular file or directory
+#[cfg(unix)]
+fn special_file_kind(file_type: &FileType) -> Option<&'static str> {
+    use std::os::unix::fs::FileTypeExt;
+
+    if file_type.is_fifo() {
+        Some("named pipe")
+    } else if file_type.is_socket() {
+        Some("socket")
+    } else if file_type.is_char_device() {
+        Some("character device")
+    } else if file_type.is_block_device() {
+        Some("block device")
+    } else {
+        None
+    }
+}
+
+#[cfg(not(unix))]
+fn special_file_kind(_file_type: &FileType) -> Option<&'static str> {
+    None
+}
+
+/// The File tags of the symbolic link at `path`, whose own (not followed)
+/// metadata is `link_metadata`
+pub(super) fn symlink_exif_data(
+    path: &Path,
+    link_metadata: &Metadata,
+    filter_opts: &FilterOptions,
+) -> ExifData {
+    let mut tags = Vec::new();
+    let mut add = |name: &str, group1: &str, value: TagValue| {
+        if filter_opts.should_extract_tag(name, "File") {
+            tags.push(TagEntry {
+                group: "File".into(),
+                group1: group1.into(),
+                name: name.into(),
+                value: value.clone(),
+                print: value.into(),
+                source: None,
+            });
+        }
+    };
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    add("FileName", "System", TagValue::string(file_name));
+    let directory = path.parent().unwrap_or_else(|| Path::new("."));
+    add(
+        "Directory",
+        "System",
+        TagValue::string(directory.to_string_lossy()),
+    );
+    // The length of the link's target path
+    add("FileSize", "System", TagValue::U64(link_metadata.len()));
+    let dates = [
+        ("FileModifyDate", link_metadata.modified()),
+        ("FileAccessDate", link_metadata.accessed()),
+    ];
+    for (name, time) in dates {
+        if let Ok(time) = time {
+            let time: DateTime<Local> = time.into();
+            let formatted = time.format("%Y:%m:%d %H:%M:%S%:z").to_string();
+            add(name, "System", TagValue::String(formatted));
+        }
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = link_metadata.permissions().mode();
+        add(
+            "FilePermissions",
+            "System",
+            TagValue::String(super::format_unix_permissions(mode)),
+        );
+    }
+    add("FileType", "File", TagValue::string("LINK"));
+
+    let mut exif_data = ExifData::new(
+        path.to_string_lossy().to_string(),
+        env!("CARGO_PKG_VERSION").to_string(),
+    );
+    exif_data.tags = tags;
+    exif_data
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_regular_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("photo.jpg");
+        std::fs::write(&file, b"\xff\xd8\xff\xd9").unwrap();
+        assert!(check_regular_file(&file).is_ok());
+        assert!(check_regular_file(&dir.path().join("missing.jpg")).is_ok());
+        assert!(check_regular_file(Path::new("/dev/null")).is_err());
+    }
+
+    #[test]
+    fn test_symlink_exif_data() {
+        let dir = tempfile::tempdir().unwrap();
+        let link = dir.path().join("link.jpg");
+        std::os::unix::fs::symlink("photo.jpg", &link).unwrap();
+        let link_metadata = std::fs::symlink_metadata(&link).unwrap();
+        let exif_data = symlink_exif_data(&link, &link_metadata, &FilterOptions::default());
+        let tag = |name: &str| {
+            exif_data
+                .get_tag_by_group("File", name)
+                .and_then(|tag| tag.value.as_string())
+                .map(str::to_string)
+        };
+        assert_eq!(tag("FileType").as_deref(), Some("LINK"));
+        assert_eq!(tag("FileName").as_deref(), Some("link.jpg"));
+        assert!(tag("FilePermissions").is_some_and(|p| p.starts_with('l')));
+    }
+}
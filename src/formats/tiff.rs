@@ -5,6 +5,7 @@
 
 use crate::types::{ExifError, Result};
 use std::io::{Read, Seek};
+use std::ops::Range;
 
 /// XMP tag in TIFF IFD0
 const TIFF_XMP_TAG: u16 = 0x02BC; // 700 decimal
@@ -89,9 +90,14 @@ pub fn get_tiff_endianness(data: &[u8]) -> Result<bool> {
 /// Scans the TIFF IFD0 for the XMP tag (0x02bc) and extracts the XMP packet.
 /// Returns the raw XMP data if found.
 pub fn extract_tiff_xmp(data: &[u8]) -> Result<Option<Vec<u8>>> {
+    Ok(locate_tiff_xmp(data).map(|range| data[range].to_vec()))
+}
+
+/// Byte range of the XMP packet in TIFF IFD0 tag 0x02bc, if there is one
+pub fn locate_tiff_xmp(data: &[u8]) -> Option<Range<usize>> {
     // Validate minimum TIFF header size
     if data.len() < 8 {
-        return Ok(None);
+        return None;
     }
 
     // Determine endianness and read IFD0 offset
@@ -106,12 +112,12 @@ pub fn extract_tiff_xmp(data: &[u8]) -> Result<Option<Vec<u8>>> {
             let offset = u32::from_be_bytes([data[4], data[5], data[6], data[7]]);
             (false, offset)
         }
-        _ => return Ok(None), // Invalid TIFF magic
+        _ => return None, // Invalid TIFF magic
     };
 
     // Check if IFD0 offset is valid
     if ifd0_offset as usize + 2 > data.len() {
-        return Ok(None);
+        return None;
     }
 
     // Read number of directory entries in IFD0
@@ -126,7 +132,7 @@ pub fn extract_tiff_xmp(data: &[u8]) -> Result<Option<Vec<u8>>> {
     let entries_end = entries_start + (entry_count as usize * 12);
 
     if entries_end > data.len() {
-        return Ok(None);
+        return None;
     }
 
     // Scan IFD entries for XMP tag (0x02bc)
@@ -187,13 +193,13 @@ pub fn extract_tiff_xmp(data: &[u8]) -> Result<Option<Vec<u8>>> {
                 let xmp_end = xmp_start + count as usize;
 
                 if xmp_end <= data.len() {
-                    return Ok(Some(data[xmp_start..xmp_end].to_vec()));
+                    return Some(xmp_start..xmp_end);
                 }
             }
         }
     }
 
-    Ok(None)
+    None
 }
 
 #[cfg(test)]
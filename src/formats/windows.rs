@@ -0,0 +1,212 @@
+//! Windows file attributes and owner
+//!
+//! The Windows counterparts of the Unix FilePermissions tag, in the
+//! File:System group:
+//!
+//! - FileAttributes: the FILE_ATTRIBUTE_* flags (read-only, hidden, system,
+//!   ...), printed as a list like ExifTool's BITMASK PrintConv.
+//! - FileOwner: the owner account, as "DOMAIN\name". ExifTool has no tag for
+//!   this, so it is only read when requested.
+//!
+//! ExifTool Reference: lib/Image/ExifTool.pm %Image::ExifTool::Extra
+//! FileAttributes (read with Win32API::File::GetFileAttributes)
+
+use std::fs::Metadata;
+use std::os::windows::ffi::OsStrExt;
+use std::os::windows::fs::MetadataExt;
+use std::path::Path;
+use std::ptr::{null, null_mut};
+
+use windows_sys::Win32::Foundation::{LocalFree, ERROR_SUCCESS};
+use windows_sys::Win32::Security::Authorization::{GetNamedSecurityInfoW, SE_FILE_OBJECT};
+use windows_sys::Win32::Security::{
+    LookupAccountSidW, OWNER_SECURITY_INFORMATION, PSECURITY_DESCRIPTOR, PSID,
+};
+
+use crate::types::{FilterOptions, TagEntry, TagValue};
+
+/// FILE_ATTRIBUTE_* flags, by bit number
+/// ExifTool: ExifTool.pm FileAttributes PrintConv
+const FILE_ATTRIBUTE_NAMES: &[(u32, &str)] = &[
+    (0, "Read-only"),
+    (1, "Hidden"),
+    (2, "System"),
+    (4, "Directory"),
+    (5, "Archive"),
+    (6, "Device"),
+    (7, "Normal"),
+    (8, "Temporary"),
+    (9, "Sparse File"),
+    (10, "Reparse Point"),
+    (11, "Compressed"),
+    (12, "Offline"),
+    (13, "Not Content Indexed"),
+    (14, "Encrypted"),
+    (15, "Integrity Stream"),
+    (16, "Virtual"),
+    (17, "No Scrub Data"),
+    (18, "Recall On Open"),
+    (19, "Pinned"),
+    (20, "Unpinned"),
+    (22, "Recall On Data Access"),
+];
+
+/// The Windows File:System tags selected by the filter
+pub(super) fn windows_file_tags(
+    path: &Path,
+    metadata: &Metadata,
+    filter_opts: &FilterOptions,
+) -> Vec<TagEntry> {
+    let mut entries = Vec::new();
+    if filter_opts.should_extract_tag("FileAttributes", "File") {
+        let attributes = metadata.file_attributes();
+        entries.push(system_tag(
+            "FileAttributes",
+            TagValue::U32(attributes),
+            TagValue::String(file_attributes_print(attributes)),
+        ));
+    }
+    // Looking up the account isn't free, so FileOwner isn't part of -all
+    if !filter_opts.extract_all && filter_opts.should_extract_tag("FileOwner", "File") {
+        if let Some(owner) = file_owner(path) {
+            let owner = TagValue::String(owner);
+            entries.push(system_tag("FileOwner", owner.clone(), owner));
+        }
+    }
+    entries
+}
+
+fn system_tag(name: &str, value: TagValue, print: TagValue) -> TagEntry {
+    TagEntry {
+        group: "File".into(),
+        group1: "System".into(),
+        name: name.into(),
+        value,
+        print: print.into(),
+        source: None,
+    }
+}
+
+/// FileAttributes PrintConv: the names of the set flags
+fn file_attributes_print(attributes: u32) -> String {
+    let names: Vec<String> = (0u32..32)
+        .filter(|&bit| attributes & (1 << bit) != 0)
+        .map(|bit| {
+            FILE_ATTRIBUTE_NAMES
+                .iter()
+                .find(|&&(number, _)| number == bit)
+                .map(|&(_, name)| name.to_string())
+                .unwrap_or_else(|| format!("[{bit}]"))
+        })
+        .collect();
+    if names.is_empty() {
+        "(none)".to_string()
+    } else {
+        names.join(", ")
+    }
+}
+
+/// The account owning the file, as "DOMAIN\name"
+fn file_owner(path: &Path) -> Option<String> {
+    let wide_path: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+    let mut owner: PSID = null_mut();
+    let mut descriptor: PSECURITY_DESCRIPTOR = null_mut();
+    // SAFETY: the path is NUL-terminated and the out pointers are valid. The
+    // owner SID points into the security descriptor, which is freed below.
+    let status = unsafe {
+        GetNamedSecurityInfoW(
+            wide_path.as_ptr(),
+            SE_FILE_OBJECT,
+            OWNER_SECURITY_INFORMATION,
+            &mut owner,
+            null_mut(),
+            null_mut(),
+            null_mut(),
+            &mut descriptor,
+        )
+    };
+    if status != ERROR_SUCCESS {
+        return None;
+    }
+    let account = account_name(owner);
+    // SAFETY: the descriptor was allocated by GetNamedSecurityInfoW and
+    // nothing refers to it any more
+    unsafe { LocalFree(descriptor) };
+    account
+}
+
+/// The account name of a SID
+fn account_name(sid: PSID) -> Option<String> {
+    let (mut name_len, mut domain_len) = (0u32, 0u32);
+    let mut sid_use = 0;
+    // Asking with empty buffers fails, giving the buffer sizes needed
+    // SAFETY: `sid` is a valid SID and the null buffers have zero length
+    unsafe {
+        LookupAccountSidW(
+            null(),
+            sid,
+            null_mut(),
+            &mut name_len,
+            null_mut(),
+            &mut domain_len,
+            &mut sid_use,
+        )
+    };
+    if name_len == 0 {
+        return None;
+    }
+    let mut name = vec![0u16; name_len as usize];
+    let mut domain = vec![0u16; domain_len as usize];
+    // SAFETY: the buffers are as long as the lengths passed with them
+    let found = unsafe {
+        LookupAccountSidW(
+            null(),
+            sid,
+            name.as_mut_ptr(),
+            &mut name_len,
+            domain.as_mut_ptr(),
+            &mut domain_len,
+            &mut sid_use,
+        )
+    };
+    if found == 0 {
+        return None;
+    }
+    // On success the lengths don't include the terminating NULs
+    let name = String::from_utf16_lossy(&name[..name_len as usize]);
+    let domain = String::from_utf16_lossy(&domain[..domain_len as usize]);
+    Some(if domain.is_empty() {
+        name
+    } else {
+        format!("{domain}\\{name}")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_attributes_print() {
+        assert_eq!(file_attributes_print(0), "(none)");
+        assert_eq!(file_attributes_print(0x20), "Archive");
+        assert_eq!(
+            file_attributes_print(0x1 | 0x2 | 0x4 | 0x8),
+            "Read-only, Hidden, System, [3]"
+        );
+    }
+
+    #[test]
+    fn test_windows_file_tags() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let metadata = std::fs::metadata(file.path()).unwrap();
+        let filter = FilterOptions::tags_only(vec!["FileAttributes".into(), "FileOwner".into()]);
+        let entries = windows_file_tags(file.path(), &metadata, &filter);
+        let names: Vec<_> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["FileAttributes", "FileOwner"]);
+        assert!(entries[1]
+            .value
+            .as_string()
+            .is_some_and(|owner| !owner.is_empty()));
+    }
+}
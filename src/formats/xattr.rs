@@ -0,0 +1,286 @@
+//! Extended attributes (XAttr tags)
+//!
+//! macOS keeps some file metadata outside the file, in extended attributes:
+//! the download source (`kMDItemWhereFroms`), the quarantine flag, Finder
+//! comments and the Finder tags, which hold the color labels applied in
+//! Finder. Each attribute becomes an `XAttr*` tag (family 0 group File,
+//! family 1 group XAttr), named from the attribute the way ExifTool does:
+//! `com.apple.metadata:kMDItemWhereFroms` is XAttrMDItemWhereFroms.
+//!
+//! Attributes are read on macOS, and on Linux from the `user.` namespace,
+//! where copies of macOS files keep them. Values stored as binary plists
+//! are decoded; the Spotlight-only MDItem tags ExifTool gets from `mdls`
+//! aren't read.
+//!
+//! ExifTool Reference: lib/Image/ExifTool/MacOS.pm ReadXAttr, %XAttr
+
+use std::path::Path;
+
+use chrono::{DateTime, Local};
+
+use super::plist::parse_binary_plist;
+use crate::types::{TagEntry, TagValue};
+
+/// Attributes whose tag names don't follow from the attribute name
+/// ExifTool: MacOS.pm %XAttr
+const XATTR_TAG_NAMES: &[(&str, &str)] = &[("com.apple.lastuseddate#PS", "XAttrLastUsedDate")];
+
+/// Seconds from the Unix epoch to the plist epoch (2001-01-01 00:00:00 UTC)
+const PLIST_EPOCH: i64 = 978_307_200;
+
+/// Read the XAttr tags of the file at `path`
+///
+/// Gives no tags on platforms without extended attributes.
+pub fn read_xattr_tags(path: &Path) -> std::io::Result<Vec<TagEntry>> {
+    Ok(read_xattrs(path)?
+        .into_iter()
+        .filter_map(|(name, value)| xattr_tag(&name, &value))
+        .collect())
+}
+
+/// The (name, value) pairs of the file's extended attributes
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+fn read_xattrs(path: &Path) -> std::io::Result<Vec<(String, Vec<u8>)>> {
+    use rustix::fs::{getxattr, listxattr};
+
+    let mut names = vec![0u8; listxattr(path, &mut [0u8; 0][..])?];
+    let len = listxattr(path, &mut names)?;
+    names.truncate(len);
+
+    let mut attributes = Vec::new();
+    for raw_name in names.split(|&b| b == 0).filter(|name| !name.is_empty()) {
+        let Ok(name) = std::str::from_utf8(raw_name) else {
+            continue;
+        };
+        // Linux keeps the macOS names in the user namespace
+        #[cfg(target_os = "linux")]
+        let Some(name) = name.strip_prefix("user.") else {
+            continue;
+        };
+        let Ok(size) = getxattr(path, raw_name, &mut [0u8; 0][..]) else {
+            continue;
+        };
+        let mut value = vec![0u8; size];
+        // The attribute may have shrunk since it was measured
+        let Ok(len) = getxattr(path, raw_name, &mut value) else {
+            continue;
+        };
+        value.truncate(len);
+        attributes.push((name.to_string(), value));
+    }
+    Ok(attributes)
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn read_xattrs(_path: &Path) -> std::io::Result<Vec<(String, Vec<u8>)>> {
+    Ok(Vec::new())
+}
+
+/// The tag for the extended attribute `name` with the given value
+///
+/// `name` is the macOS attribute name, like "com.apple.quarantine".
+/// Returns None for attributes without a usable name.
+pub fn xattr_tag(name: &str, value: &[u8]) -> Option<TagEntry> {
+    let tag_name = xattr_tag_name(name)?;
+    let value = xattr_value(&tag_name, value);
+    Some(TagEntry {
+        group: "File".into(),
+        group1: "XAttr".into(),
+        name: tag_name.into(),
+        value: value.clone(),
+        print: value.into(),
+        source: None,
+    })
+}
+
+/// Tag name for an attribute: "XAttr" and the attribute name without its
+/// "com.apple." or "com.apple.metadata:k" prefix, in CamelCase
+fn xattr_tag_name(name: &str) -> Option<String> {
+    if let Some(&(_, tag_name)) = XATTR_TAG_NAMES.iter().find(|&&(id, _)| id == name) {
+        return Some(tag_name.to_string());
+    }
+    let short = match name.strip_prefix("com.apple.metadata:") {
+        Some(key) => {
+            let key = key.trim_start_matches('_');
+            match key.strip_prefix('k') {
+                Some(rest) if rest.starts_with(|c: char| c.is_ascii_uppercase()) => rest,
+                _ => key,
+            }
+        }
+        None => name.strip_prefix("com.apple.").unwrap_or(name),
+    };
+    let words: String = short
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    (!words.is_empty()).then(|| format!("XAttr{words}"))
+}
+
+/// Decode an attribute value: binary plists, text, or raw bytes
+fn xattr_value(tag_name: &str, value: &[u8]) -> TagValue {
+    if tag_name == "XAttrLastUsedDate" {
+        // ExifTool: ConvertUnixTime(unpack("V",$val),1)
+        if let Some(seconds) = value.get(..4) {
+            let seconds = u32::from_le_bytes(seconds.try_into().unwrap());
+            return unix_date(seconds as f64).unwrap_or(TagValue::Empty);
+        }
+    }
+    if let Some(plist) = parse_binary_plist(value) {
+        return plist_value(tag_name, plist);
+    }
+    // Text is often written with a terminating null
+    match std::str::from_utf8(value).map(|text| text.trim_end_matches('\0')) {
+        Ok(text) if !text.is_empty() && !text.contains(|c: char| c.is_control()) => {
+            TagValue::String(text.to_string())
+        }
+        _ => TagValue::Binary(value.to_vec()),
+    }
+}
+
+/// Tidy a decoded plist value: a single-item array is reduced to the item,
+/// plist dates become date strings, and each Finder tag loses the color
+/// index stored after its name ("Red\n6" is the Red tag)
+fn plist_value(tag_name: &str, value: TagValue) -> TagValue {
+    let is_date = tag_name.ends_with("Date");
+    let tidy = |item: TagValue| match item {
+        TagValue::F64(seconds) if is_date => {
+            unix_date(seconds + PLIST_EPOCH as f64).unwrap_or(TagValue::F64(seconds))
+        }
+        TagValue::String(text) if tag_name == "XAttrMDItemUserTags" => {
+            TagValue::String(text.split('\n').next().unwrap_or_default().to_string())
+        }
+        item => item,
+    };
+    match value {
+        TagValue::Array(items) if items.len() == 1 => tidy(items.into_iter().next().unwrap()),
+        TagValue::Array(items) => TagValue::Array(items.into_iter().map(tidy).collect()),
+        value => tidy(value),
+    }
+}
+
+/// Local date/time for Unix seconds, like the File dates
+fn unix_date(seconds: f64) -> Option<TagValue> {
+    let date = DateTime::from_timestamp(seconds.floor() as i64, 0)?.with_timezone(&Local);
+    Some(TagValue::String(
+        date.format("%Y:%m:%d %H:%M:%S%:z").to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A bplist00 array of ASCII strings
+    fn string_array_plist(items: &[&str]) -> Vec<u8> {
+        let mut data = b"bplist00".to_vec();
+        let mut offsets = vec![data.len()];
+        data.push(0xa0 | items.len() as u8);
+        data.extend((1..=items.len()).map(|i| i as u8));
+        for item in items {
+            offsets.push(data.len());
+            if item.len() < 15 {
+                data.push(0x50 | item.len() as u8);
+            } else {
+                // Longer lengths follow as an int object
+                data.extend_from_slice(&[0x5f, 0x10, item.len() as u8]);
+            }
+            data.extend_from_slice(item.as_bytes());
+        }
+        let offset_table = data.len();
+        data.extend(offsets.iter().map(|&offset| offset as u8));
+        data.extend_from_slice(&[0, 0, 0, 0, 0, 0, 1, 1]);
+        data.extend_from_slice(&(offsets.len() as u64).to_be_bytes());
+        data.extend_from_slice(&0u64.to_be_bytes());
+        data.extend_from_slice(&(offset_table as u64).to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn test_xattr_tag_names() {
+        assert_eq!(
+            xattr_tag_name("com.apple.metadata:kMDItemWhereFroms").as_deref(),
+            Some("XAttrMDItemWhereFroms")
+        );
+        assert_eq!(
+            xattr_tag_name("com.apple.metadata:_kMDItemUserTags").as_deref(),
+            Some("XAttrMDItemUserTags")
+        );
+        assert_eq!(
+            xattr_tag_name("com.apple.quarantine").as_deref(),
+            Some("XAttrQuarantine")
+        );
+        assert_eq!(
+            xattr_tag_name("com.apple.lastuseddate#PS").as_deref(),
+            Some("XAttrLastUsedDate")
+        );
+        assert_eq!(
+            xattr_tag_name("org.example.some-thing").as_deref(),
+            Some("XAttrOrgExampleSomeThing")
+        );
+        assert_eq!(xattr_tag_name("com.apple.#"), None);
+    }
+
+    #[test]
+    fn test_xattr_values() {
+        let tags = xattr_tag(
+            "com.apple.metadata:_kMDItemUserTags",
+            &string_array_plist(&["Red\n6", "Work"]),
+        )
+        .unwrap();
+        assert_eq!(tags.group, "File");
+        assert_eq!(tags.group1, "XAttr");
+        assert_eq!(
+            tags.value,
+            TagValue::Array(vec![TagValue::string("Red"), TagValue::string("Work")])
+        );
+
+        let where_froms = xattr_tag(
+            "com.apple.metadata:kMDItemWhereFroms",
+            &string_array_plist(&["https://example.com/photo.jpg"]),
+        )
+        .unwrap();
+        assert_eq!(
+            where_froms.value,
+            TagValue::string("https://example.com/photo.jpg")
+        );
+
+        let quarantine = xattr_tag("com.apple.quarantine", b"0083;5e2b1b0c;Safari;").unwrap();
+        assert_eq!(quarantine.value, TagValue::string("0083;5e2b1b0c;Safari;"));
+
+        let finder_info = xattr_tag("com.apple.FinderInfo", &[0; 32]).unwrap();
+        assert_eq!(finder_info.name, "XAttrFinderInfo");
+        assert_eq!(finder_info.value, TagValue::Binary(vec![0; 32]));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_read_xattr_tags() {
+        use rustix::fs::{setxattr, XattrFlags};
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let path = file.path();
+        let tags = string_array_plist(&["Green\n2"]);
+        // Not every filesystem takes user attributes
+        if setxattr(
+            path,
+            "user.com.apple.metadata:_kMDItemUserTags",
+            &tags,
+            XattrFlags::empty(),
+        )
+        .is_err()
+        {
+            return;
+        }
+        let entries = read_xattr_tags(path).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "XAttrMDItemUserTags");
+        assert_eq!(entries[0].value, TagValue::string("Green"));
+    }
+}
@@ -0,0 +1,252 @@
+//! Minimal ZIP archive reader
+//!
+//! Enough of the ZIP format to pull small metadata parts out of ZIP-based
+//! documents: the central directory is read from the end of the file, and
+//! members are read stored or deflated. ZIP64, encrypted members and other
+//! compression methods are not supported.
+//!
+//! ExifTool reference: ZIP.pm ProcessZIP, which identifies the document type
+//! from the member names and hands the parts to OOXML.pm and friends.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use miniz_oxide::inflate::decompress_to_vec_with_limit;
+use tracing::debug;
+
+use crate::types::{ExifError, Result};
+
+/// End of central directory record signature (`PK\x05\x06`)
+const END_OF_CENTRAL_DIR: &[u8] = b"PK\x05\x06";
+const CENTRAL_DIR_ENTRY: &[u8] = b"PK\x01\x02";
+const LOCAL_FILE_HEADER: &[u8] = b"PK\x03\x04";
+
+/// Size of the end of central directory record without its comment
+const END_OF_CENTRAL_DIR_SIZE: usize = 22;
+
+/// Largest member read into memory
+const MAX_MEMBER_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Compression methods
+const STORED: u16 = 0;
+const DEFLATED: u16 = 8;
+
+/// A member listed in the central directory
+#[derive(Debug, Clone)]
+pub struct ZipEntry {
+    pub name: String,
+    method: u16,
+    encrypted: bool,
+    compressed_size: u64,
+    uncompressed_size: u64,
+    header_offset: u64,
+}
+
+/// The central directory of a ZIP file and the reader it came from
+pub struct ZipArchive<'a, R: Read + Seek> {
+    reader: &'a mut R,
+    pub entries: Vec<ZipEntry>,
+}
+
+impl<'a, R: Read + Seek> ZipArchive<'a, R> {
+    /// Read the central directory
+    pub fn open(reader: &'a mut R) -> Result<Self> {
+        let file_size = reader.seek(SeekFrom::End(0))?;
+        // The record is followed by a comment of up to 65535 bytes
+        let tail_size = file_size.min((END_OF_CENTRAL_DIR_SIZE + 0xffff) as u64);
+        reader.seek(SeekFrom::Start(file_size - tail_size))?;
+        let mut tail = vec![0u8; tail_size as usize];
+        reader.read_exact(&mut tail)?;
+
+        let record = tail
+            .windows(END_OF_CENTRAL_DIR.len())
+            .rposition(|window| window == END_OF_CENTRAL_DIR)
+            .and_then(|start| tail.get(start..start + END_OF_CENTRAL_DIR_SIZE))
+            .ok_or_else(|| ExifError::ParseError("No ZIP central directory".to_string()))?;
+        let count = u16::from_le_bytes([record[10], record[11]]);
+        let size = u32::from_le_bytes(record[12..16].try_into().unwrap()) as u64;
+        let offset = u32::from_le_bytes(record[16..20].try_into().unwrap()) as u64;
+        if offset == 0xffff_ffff || size > MAX_MEMBER_SIZE {
+            return Err(ExifError::ParseError(
+                "Unsupported ZIP64 central directory".to_string(),
+            ));
+        }
+
+        reader.seek(SeekFrom::Start(offset))?;
+        let mut directory = vec![0u8; size as usize];
+        reader.read_exact(&mut directory)?;
+        let entries = parse_central_directory(&directory, count);
+        Ok(Self { reader, entries })
+    }
+
+    /// The uncompressed contents of a member, or `None` if there is no
+    /// member with this name
+    pub fn read(&mut self, name: &str) -> Result<Option<Vec<u8>>> {
+        let Some(entry) = self.entries.iter().find(|entry| entry.name == name) else {
+            return Ok(None);
+        };
+        if entry.encrypted {
+            return Err(ExifError::ParseError(format!(
+                "ZIP member {name} is encrypted"
+            )));
+        }
+        if entry.compressed_size > MAX_MEMBER_SIZE || entry.uncompressed_size > MAX_MEMBER_SIZE {
+            return Err(ExifError::ParseError(format!(
+                "ZIP member {name} is too large"
+            )));
+        }
+
+        let mut header = [0u8; 30];
+        self.reader.seek(SeekFrom::Start(entry.header_offset))?;
+        self.reader.read_exact(&mut header)?;
+        if &header[..4] != LOCAL_FILE_HEADER {
+            return Err(ExifError::ParseError(format!(
+                "Bad local header for ZIP member {name}"
+            )));
+        }
+        // The local name and extra field lengths can differ from the central
+        // directory's
+        let name_len = u16::from_le_bytes([header[26], header[27]]) as i64;
+        let extra_len = u16::from_le_bytes([header[28], header[29]]) as i64;
+        self.reader.seek(SeekFrom::Current(name_len + extra_len))?;
+        let mut data = vec![0u8; entry.compressed_size as usize];
+        self.reader.read_exact(&mut data)?;
+
+        match entry.method {
+            STORED => Ok(Some(data)),
+            DEFLATED => decompress_to_vec_with_limit(&data, entry.uncompressed_size as usize)
+                .map(Some)
+                .map_err(|err| {
+                    ExifError::ParseError(format!(
+                        "Invalid deflate data in ZIP member {name}: {err}"
+                    ))
+                }),
+            method => Err(ExifError::ParseError(format!(
+                "Unsupported compression method {method} for ZIP member {name}"
+            ))),
+        }
+    }
+}
+
+/// Parse the central directory entries
+fn parse_central_directory(mut data: &[u8], count: u16) -> Vec<ZipEntry> {
+    let mut entries = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        if data.len() < 46 || &data[..4] != CENTRAL_DIR_ENTRY {
+            debug!("Truncated ZIP central directory");
+            break;
+        }
+        let u16_at = |pos: usize| u16::from_le_bytes([data[pos], data[pos + 1]]);
+        let u32_at = |pos: usize| u32::from_le_bytes(data[pos..pos + 4].try_into().unwrap());
+        let name_len = u16_at(28) as usize;
+        let extra_len = u16_at(30) as usize;
+        let comment_len = u16_at(32) as usize;
+        let Some(name) = data.get(46..46 + name_len) else {
+            break;
+        };
+        entries.push(ZipEntry {
+            name: String::from_utf8_lossy(name).into_owned(),
+            method: u16_at(10),
+            encrypted: u16_at(8) & 0x0001 != 0,
+            compressed_size: u32_at(20) as u64,
+            uncompressed_size: u32_at(24) as u64,
+            header_offset: u32_at(42) as u64,
+        });
+        data = data
+            .get(46 + name_len + extra_len + comment_len..)
+            .unwrap_or_default();
+    }
+    entries
+}
+
+/// Build a ZIP file of stored members, for tests of the ZIP-based formats
+#[cfg(test)]
+pub(crate) fn stored_zip(members: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut file = Vec::new();
+    let mut directory = Vec::new();
+    for (name, data) in members {
+        let offset = file.len() as u32;
+        let size = (data.len() as u32).to_le_bytes();
+        let name_len = (name.len() as u16).to_le_bytes();
+
+        file.extend(LOCAL_FILE_HEADER);
+        file.extend([20, 0, 0, 0, 0, 0, 0, 0, 0, 0]); // version, flags, method, time, date
+        file.extend([0; 4]); // CRC-32, not checked
+        file.extend(size);
+        file.extend(size);
+        file.extend(name_len);
+        file.extend([0, 0]);
+        file.extend(name.as_bytes());
+        file.extend(*data);
+
+        directory.extend(CENTRAL_DIR_ENTRY);
+        directory.extend([20, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        directory.extend([0; 4]);
+        directory.extend(size);
+        directory.extend(size);
+        directory.extend(name_len);
+        directory.extend([0; 12]); // extra, comment, disk, attributes
+        directory.extend(offset.to_le_bytes());
+        directory.extend(name.as_bytes());
+    }
+    let directory_offset = file.len() as u32;
+    let count = (members.len() as u16).to_le_bytes();
+    file.extend(&directory);
+    file.extend(END_OF_CENTRAL_DIR);
+    file.extend([0; 4]);
+    file.extend(count);
+    file.extend(count);
+    file.extend((directory.len() as u32).to_le_bytes());
+    file.extend(directory_offset.to_le_bytes());
+    file.extend([0, 0]);
+    file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_read_stored_members() {
+        let zip = stored_zip(&[("mimetype", b"text/plain"), ("docProps/core.xml", b"<x/>")]);
+        let mut cursor = Cursor::new(zip);
+        let mut archive = ZipArchive::open(&mut cursor).unwrap();
+        assert_eq!(archive.entries.len(), 2);
+        assert_eq!(archive.entries[1].name, "docProps/core.xml");
+        assert_eq!(
+            archive.read("docProps/core.xml").unwrap(),
+            Some(b"<x/>".to_vec())
+        );
+        assert_eq!(archive.read("missing").unwrap(), None);
+    }
+
+    #[test]
+    fn test_read_deflated_member() {
+        // Raw deflate of "hello hello hello hello"
+        let deflated = [0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0xc8, 0x40, 0x27, 0x01];
+        let mut zip = stored_zip(&[("content.xml", &deflated)]);
+        let central = zip.len() - 22 - 46 - "content.xml".len();
+        for (method, size) in [(8, 22), (central + 10, central + 24)] {
+            zip[method] = DEFLATED as u8;
+            zip[size..size + 4].copy_from_slice(&23u32.to_le_bytes());
+        }
+        let mut cursor = Cursor::new(zip.clone());
+        let mut archive = ZipArchive::open(&mut cursor).unwrap();
+        assert_eq!(
+            archive.read("content.xml").unwrap(),
+            Some(b"hello hello hello hello".to_vec())
+        );
+
+        // The output is limited to the uncompressed size in the directory
+        zip[central + 24..central + 28].copy_from_slice(&10u32.to_le_bytes());
+        let mut cursor = Cursor::new(zip);
+        let mut archive = ZipArchive::open(&mut cursor).unwrap();
+        assert!(archive.read("content.xml").is_err());
+    }
+
+    #[test]
+    fn test_not_a_zip() {
+        let mut cursor = Cursor::new(b"PK\x03\x04 but no directory".to_vec());
+        assert!(ZipArchive::open(&mut cursor).is_err());
+    }
+}
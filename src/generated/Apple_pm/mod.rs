@@ -8,3 +8,11 @@ pub mod run_time_tags;
 
 // Re-export commonly used items
 pub use main_tags::APPLE_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(composite_tags::APPLE_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(main_tags::APPLE_MAIN_TAGS),
+    crate::tables::lazy_table!(run_time_tags::APPLE_RUNTIME_TAGS),
+    crate::tables::lazy_table!(run_time_tags::APPLE_RUNTIME_TAGS_BY_NAME),
+];
@@ -9,6 +9,60 @@ use std::sync::LazyLock;
 /// Tag definitions for Apple::RunTime table
 pub static APPLE_RUNTIME_TAGS: LazyLock<HashMap<u16, TagInfo>> = LazyLock::new(HashMap::new);
 
+/// Byte-string keyed tag definitions for Apple::RunTime table
+/// Keys are the exact tag IDs ExifTool matches, case included.
+pub static APPLE_RUNTIME_TAGS_BY_NAME: LazyLock<HashMap<&'static [u8], TagInfo>> =
+    LazyLock::new(|| {
+        HashMap::from([
+            (
+                b"epoch".as_slice(),
+                TagInfo {
+                    name: "RunTimeEpoch",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"flags".as_slice(),
+                TagInfo {
+                    name: "RunTimeFlags",
+                    format: "unknown",
+                    print_conv: Some(PrintConv::Bitmask(&[
+                        (0, "Valid"),
+                        (1, "Has been rounded"),
+                        (2, "Positive infinity"),
+                        (3, "Negative infinity"),
+                        (4, "Indefinite"),
+                    ])),
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"timescale".as_slice(),
+                TagInfo {
+                    name: "RunTimeScale",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"value".as_slice(),
+                TagInfo {
+                    name: "RunTimeValue",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+        ])
+    });
+
 /// Apply ValueConv transformation for tags in this table
 pub fn apply_value_conv(
     tag_id: u32,
@@ -20,3 +20,23 @@ pub mod personal_func_values_tags;
 pub mod personal_funcs_tags;
 
 // Re-export commonly used items
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(disable_enable::DISABLE_ENABLE),
+    crate::tables::lazy_table!(enable_disable::ENABLE_DISABLE),
+    crate::tables::lazy_table!(funcs_unknown_tags::CANON_CUSTOM_FUNCSUNKNOWN_TAGS),
+    crate::tables::lazy_table!(functions10d_tags::CANON_CUSTOM_FUNCTIONS10D_TAGS),
+    crate::tables::lazy_table!(functions1d_tags::CANON_CUSTOM_FUNCTIONS1D_TAGS),
+    crate::tables::lazy_table!(functions20d_tags::CANON_CUSTOM_FUNCTIONS20D_TAGS),
+    crate::tables::lazy_table!(functions2_tags::CANON_CUSTOM_FUNCTIONS2_TAGS),
+    crate::tables::lazy_table!(functions30d_tags::CANON_CUSTOM_FUNCTIONS30D_TAGS),
+    crate::tables::lazy_table!(functions350d_tags::CANON_CUSTOM_FUNCTIONS350D_TAGS),
+    crate::tables::lazy_table!(functions400d_tags::CANON_CUSTOM_FUNCTIONS400D_TAGS),
+    crate::tables::lazy_table!(functions5d_tags::CANON_CUSTOM_FUNCTIONS5D_TAGS),
+    crate::tables::lazy_table!(functions_d30_tags::CANON_CUSTOM_FUNCTIONSD30_TAGS),
+    crate::tables::lazy_table!(off_on::OFF_ON),
+    crate::tables::lazy_table!(on_off::ON_OFF),
+    crate::tables::lazy_table!(personal_func_values_tags::CANON_CUSTOM_PERSONALFUNCVALUES_TAGS),
+    crate::tables::lazy_table!(personal_funcs_tags::CANON_CUSTOM_PERSONALFUNCS_TAGS),
+];
@@ -16,3 +16,19 @@ pub mod white_sample_tags;
 
 // Re-export commonly used items
 pub use main_tags::CANON_RAW_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(crw_tag_format::CRW_TAG_FORMAT),
+    crate::tables::lazy_table!(decoder_table_tags::CANON_RAW_DECODERTABLE_TAGS),
+    crate::tables::lazy_table!(exposure_info_tags::CANON_RAW_EXPOSUREINFO_TAGS),
+    crate::tables::lazy_table!(flash_info_tags::CANON_RAW_FLASHINFO_TAGS),
+    crate::tables::lazy_table!(image_format_tags::CANON_RAW_IMAGEFORMAT_TAGS),
+    crate::tables::lazy_table!(image_info_tags::CANON_RAW_IMAGEINFO_TAGS),
+    crate::tables::lazy_table!(main_tags::CANON_RAW_MAIN_TAGS),
+    crate::tables::lazy_table!(main_tags::CANON_RAW_MAIN_IMAGEDESCRIPTION_TAGS),
+    crate::tables::lazy_table!(make_model_tags::CANON_RAW_MAKEMODEL_TAGS),
+    crate::tables::lazy_table!(raw_jpg_info_tags::CANON_RAW_RAWJPGINFO_TAGS),
+    crate::tables::lazy_table!(time_stamp_tags::CANON_RAW_TIMESTAMP_TAGS),
+    crate::tables::lazy_table!(white_sample_tags::CANON_RAW_WHITESAMPLE_TAGS),
+];
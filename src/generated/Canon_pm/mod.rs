@@ -125,3 +125,127 @@ pub mod wb_info_tags;
 
 // Re-export commonly used items
 pub use main_tags::CANON_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(af_config_tags::CANON_AFCONFIG_TAGS),
+    crate::tables::lazy_table!(af_info2_tags::CANON_AFINFO2_TAGS),
+    crate::tables::lazy_table!(af_info_tags::CANON_AFINFO_TAGS),
+    crate::tables::lazy_table!(af_micro_adj_tags::CANON_AFMICROADJ_TAGS),
+    crate::tables::lazy_table!(ambience_tags::CANON_AMBIENCE_TAGS),
+    crate::tables::lazy_table!(aspect_info_tags::CANON_ASPECTINFO_TAGS),
+    crate::tables::lazy_table!(binary_data_attrs_tags::CANON_BINARYDATAATTRS_TAGS),
+    crate::tables::lazy_table!(camera_info1000d_tags::CANON_CAMERAINFO1000D_TAGS),
+    crate::tables::lazy_table!(camera_info1d_tags::CANON_CAMERAINFO1D_TAGS),
+    crate::tables::lazy_table!(camera_info1dmk_ii_tags::CANON_CAMERAINFO1DMKII_TAGS),
+    crate::tables::lazy_table!(camera_info1dmk_iii_tags::CANON_CAMERAINFO1DMKIII_TAGS),
+    crate::tables::lazy_table!(camera_info1dmk_iin_tags::CANON_CAMERAINFO1DMKIIN_TAGS),
+    crate::tables::lazy_table!(camera_info1dmk_iv_tags::CANON_CAMERAINFO1DMKIV_TAGS),
+    crate::tables::lazy_table!(camera_info1dx_tags::CANON_CAMERAINFO1DX_TAGS),
+    crate::tables::lazy_table!(camera_info40d_tags::CANON_CAMERAINFO40D_TAGS),
+    crate::tables::lazy_table!(camera_info450d_tags::CANON_CAMERAINFO450D_TAGS),
+    crate::tables::lazy_table!(camera_info500d_tags::CANON_CAMERAINFO500D_TAGS),
+    crate::tables::lazy_table!(camera_info50d_tags::CANON_CAMERAINFO50D_TAGS),
+    crate::tables::lazy_table!(camera_info550d_tags::CANON_CAMERAINFO550D_TAGS),
+    crate::tables::lazy_table!(camera_info5d_tags::CANON_CAMERAINFO5D_TAGS),
+    crate::tables::lazy_table!(camera_info5dmk_ii_tags::CANON_CAMERAINFO5DMKII_TAGS),
+    crate::tables::lazy_table!(camera_info5dmk_iii_tags::CANON_CAMERAINFO5DMKIII_TAGS),
+    crate::tables::lazy_table!(camera_info600d_tags::CANON_CAMERAINFO600D_TAGS),
+    crate::tables::lazy_table!(camera_info60d_tags::CANON_CAMERAINFO60D_TAGS),
+    crate::tables::lazy_table!(camera_info650d_tags::CANON_CAMERAINFO650D_TAGS),
+    crate::tables::lazy_table!(camera_info6d_tags::CANON_CAMERAINFO6D_TAGS),
+    crate::tables::lazy_table!(camera_info70d_tags::CANON_CAMERAINFO70D_TAGS),
+    crate::tables::lazy_table!(camera_info750d_tags::CANON_CAMERAINFO750D_TAGS),
+    crate::tables::lazy_table!(camera_info7d_tags::CANON_CAMERAINFO7D_TAGS),
+    crate::tables::lazy_table!(camera_info80d_tags::CANON_CAMERAINFO80D_TAGS),
+    crate::tables::lazy_table!(camera_info_g5xii_tags::CANON_CAMERAINFOG5XII_TAGS),
+    crate::tables::lazy_table!(camera_info_power_shot2_tags::CANON_CAMERAINFOPOWERSHOT2_TAGS),
+    crate::tables::lazy_table!(camera_info_power_shot_tags::CANON_CAMERAINFOPOWERSHOT_TAGS),
+    crate::tables::lazy_table!(camera_info_r6_tags::CANON_CAMERAINFOR6_TAGS),
+    crate::tables::lazy_table!(camera_info_r6m2_tags::CANON_CAMERAINFOR6M2_TAGS),
+    crate::tables::lazy_table!(camera_info_r6m3_tags::CANON_CAMERAINFOR6M3_TAGS),
+    crate::tables::lazy_table!(camera_info_unknown16_tags::CANON_CAMERAINFOUNKNOWN16_TAGS),
+    crate::tables::lazy_table!(camera_info_unknown32_tags::CANON_CAMERAINFOUNKNOWN32_TAGS),
+    crate::tables::lazy_table!(camera_info_unknown_tags::CANON_CAMERAINFOUNKNOWN_TAGS),
+    crate::tables::lazy_table!(camera_settings_tags::CANON_CAMERASETTINGS_TAGS),
+    crate::tables::lazy_table!(canon_image_size::CANON_IMAGE_SIZE),
+    crate::tables::lazy_table!(canon_lens_types::CANON_LENS_TYPES),
+    crate::tables::lazy_table!(canon_model_id::CANON_MODEL_ID),
+    crate::tables::lazy_table!(canon_quality::CANON_QUALITY),
+    crate::tables::lazy_table!(canon_white_balance::CANON_WHITE_BALANCE),
+    crate::tables::lazy_table!(cctp_tags::CANON_CCTP_TAGS),
+    crate::tables::lazy_table!(cdi1_tags::CANON_CDI1_TAGS),
+    crate::tables::lazy_table!(cmp1_tags::CANON_CMP1_TAGS),
+    crate::tables::lazy_table!(cnop_tags::CANON_CNOP_TAGS),
+    crate::tables::lazy_table!(cnth_tags::CANON_CNTH_TAGS),
+    crate::tables::lazy_table!(color_balance_tags::CANON_COLORBALANCE_TAGS),
+    crate::tables::lazy_table!(color_calib2_tags::CANON_COLORCALIB2_TAGS),
+    crate::tables::lazy_table!(color_calib_tags::CANON_COLORCALIB_TAGS),
+    crate::tables::lazy_table!(color_coefs2_tags::CANON_COLORCOEFS2_TAGS),
+    crate::tables::lazy_table!(color_coefs_tags::CANON_COLORCOEFS_TAGS),
+    crate::tables::lazy_table!(color_data10_tags::CANON_COLORDATA10_TAGS),
+    crate::tables::lazy_table!(color_data11_tags::CANON_COLORDATA11_TAGS),
+    crate::tables::lazy_table!(color_data12_tags::CANON_COLORDATA12_TAGS),
+    crate::tables::lazy_table!(color_data1_tags::CANON_COLORDATA1_TAGS),
+    crate::tables::lazy_table!(color_data2_tags::CANON_COLORDATA2_TAGS),
+    crate::tables::lazy_table!(color_data3_tags::CANON_COLORDATA3_TAGS),
+    crate::tables::lazy_table!(color_data4_tags::CANON_COLORDATA4_TAGS),
+    crate::tables::lazy_table!(color_data5_tags::CANON_COLORDATA5_TAGS),
+    crate::tables::lazy_table!(color_data6_tags::CANON_COLORDATA6_TAGS),
+    crate::tables::lazy_table!(color_data7_tags::CANON_COLORDATA7_TAGS),
+    crate::tables::lazy_table!(color_data8_tags::CANON_COLORDATA8_TAGS),
+    crate::tables::lazy_table!(color_data9_tags::CANON_COLORDATA9_TAGS),
+    crate::tables::lazy_table!(color_data_unknown_tags::CANON_COLORDATAUNKNOWN_TAGS),
+    crate::tables::lazy_table!(color_info_tags::CANON_COLORINFO_TAGS),
+    crate::tables::lazy_table!(composite_tags::CANON_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(contrast_info_tags::CANON_CONTRASTINFO_TAGS),
+    crate::tables::lazy_table!(crop_info_tags::CANON_CROPINFO_TAGS),
+    crate::tables::lazy_table!(ctmd_tags::CANON_CTMD_TAGS),
+    crate::tables::lazy_table!(exif_info_tags::CANON_EXIFINFO_TAGS),
+    crate::tables::lazy_table!(exposure_info_tags::CANON_EXPOSUREINFO_TAGS),
+    crate::tables::lazy_table!(face_detect1_tags::CANON_FACEDETECT1_TAGS),
+    crate::tables::lazy_table!(face_detect2_tags::CANON_FACEDETECT2_TAGS),
+    crate::tables::lazy_table!(face_detect3_tags::CANON_FACEDETECT3_TAGS),
+    crate::tables::lazy_table!(file_info_tags::CANON_FILEINFO_TAGS),
+    crate::tables::lazy_table!(filter_info_tags::CANON_FILTERINFO_TAGS),
+    crate::tables::lazy_table!(flags_tags::CANON_FLAGS_TAGS),
+    crate::tables::lazy_table!(flash_model::FLASH_MODEL),
+    crate::tables::lazy_table!(focal_info_tags::CANON_FOCALINFO_TAGS),
+    crate::tables::lazy_table!(focal_length_tags::CANON_FOCALLENGTH_TAGS),
+    crate::tables::lazy_table!(focus_bracketing_info_tags::CANON_FOCUSBRACKETINGINFO_TAGS),
+    crate::tables::lazy_table!(hdr_info_tags::CANON_HDRINFO_TAGS),
+    crate::tables::lazy_table!(iad1_tags::CANON_IAD1_TAGS),
+    crate::tables::lazy_table!(lens_info_tags::CANON_LENSINFO_TAGS),
+    crate::tables::lazy_table!(level_info_tags::CANON_LEVELINFO_TAGS),
+    crate::tables::lazy_table!(lighting_opt_tags::CANON_LIGHTINGOPT_TAGS),
+    crate::tables::lazy_table!(log_info_tags::CANON_LOGINFO_TAGS),
+    crate::tables::lazy_table!(main_tags::CANON_MAIN_TAGS),
+    crate::tables::lazy_table!(measured_color_tags::CANON_MEASUREDCOLOR_TAGS),
+    crate::tables::lazy_table!(modified_info_tags::CANON_MODIFIEDINFO_TAGS),
+    crate::tables::lazy_table!(movie_info_tags::CANON_MOVIEINFO_TAGS),
+    crate::tables::lazy_table!(multi_exp_tags::CANON_MULTIEXP_TAGS),
+    crate::tables::lazy_table!(my_colors_tags::CANON_MYCOLORS_TAGS),
+    crate::tables::lazy_table!(off_on::OFF_ON),
+    crate::tables::lazy_table!(panorama_tags::CANON_PANORAMA_TAGS),
+    crate::tables::lazy_table!(picture_styles::PICTURE_STYLES),
+    crate::tables::lazy_table!(preview_image_info_tags::CANON_PREVIEWIMAGEINFO_TAGS),
+    crate::tables::lazy_table!(processing_tags::CANON_PROCESSING_TAGS),
+    crate::tables::lazy_table!(ps_conv::PS_CONV),
+    crate::tables::lazy_table!(ps_info2_tags::CANON_PSINFO2_TAGS),
+    crate::tables::lazy_table!(ps_info_tags::CANON_PSINFO_TAGS),
+    crate::tables::lazy_table!(raw_burst_info_tags::CANON_RAWBURSTINFO_TAGS),
+    crate::tables::lazy_table!(sensor_info_tags::CANON_SENSORINFO_TAGS),
+    crate::tables::lazy_table!(serial_info_tags::CANON_SERIALINFO_TAGS),
+    crate::tables::lazy_table!(shot_info_tags::CANON_SHOTINFO_TAGS),
+    crate::tables::lazy_table!(skip_tags::CANON_SKIP_TAGS),
+    crate::tables::lazy_table!(time_info_tags::CANON_TIMEINFO_TAGS),
+    crate::tables::lazy_table!(unknown_d30_tags::CANON_UNKNOWND30_TAGS),
+    crate::tables::lazy_table!(unknown_ifd_tags::CANON_UNKNOWNIFD_TAGS),
+    crate::tables::lazy_table!(user_def_styles::USER_DEF_STYLES),
+    crate::tables::lazy_table!(uuid2_tags::CANON_UUID2_TAGS),
+    crate::tables::lazy_table!(uuid_tags::CANON_UUID_TAGS),
+    crate::tables::lazy_table!(vignetting_corr2_tags::CANON_VIGNETTINGCORR2_TAGS),
+    crate::tables::lazy_table!(vignetting_corr_tags::CANON_VIGNETTINGCORR_TAGS),
+    crate::tables::lazy_table!(vignetting_corr_unknown_tags::CANON_VIGNETTINGCORRUNKNOWN_TAGS),
+    crate::tables::lazy_table!(wb_info_tags::CANON_WBINFO_TAGS),
+];
@@ -11,3 +11,13 @@ pub mod type2_tags;
 
 // Re-export commonly used items
 pub use main_tags::CASIO_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(avi_tags::CASIO_AVI_TAGS),
+    crate::tables::lazy_table!(face_info1_tags::CASIO_FACEINFO1_TAGS),
+    crate::tables::lazy_table!(face_info2_tags::CASIO_FACEINFO2_TAGS),
+    crate::tables::lazy_table!(main_tags::CASIO_MAIN_TAGS),
+    crate::tables::lazy_table!(qvci_tags::CASIO_QVCI_TAGS),
+    crate::tables::lazy_table!(type2_tags::CASIO_TYPE2_TAGS),
+];
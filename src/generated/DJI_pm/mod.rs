@@ -18,3 +18,20 @@ pub mod xmp_tags;
 
 // Re-export commonly used items
 pub use main_tags::DJI_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(drone_info_tags::DJI_DRONEINFO_TAGS),
+    crate::tables::lazy_table!(frame_info_tags::DJI_FRAMEINFO_TAGS),
+    crate::tables::lazy_table!(gimbal_info_tags::DJI_GIMBALINFO_TAGS),
+    crate::tables::lazy_table!(glamour_tags::DJI_GLAMOUR_TAGS),
+    crate::tables::lazy_table!(gps_info_tags::DJI_GPSINFO_TAGS),
+    crate::tables::lazy_table!(info_tags::DJI_INFO_TAGS),
+    crate::tables::lazy_table!(known_protocol::KNOWN_PROTOCOL),
+    crate::tables::lazy_table!(main_tags::DJI_MAIN_TAGS),
+    crate::tables::lazy_table!(protobuf_tags::DJI_PROTOBUF_TAGS),
+    crate::tables::lazy_table!(thermal_params2_tags::DJI_THERMALPARAMS2_TAGS),
+    crate::tables::lazy_table!(thermal_params3_tags::DJI_THERMALPARAMS3_TAGS),
+    crate::tables::lazy_table!(thermal_params_tags::DJI_THERMALPARAMS_TAGS),
+    crate::tables::lazy_table!(xmp_tags::XMP_DRONE_DJI_TAGS),
+];
@@ -8,3 +8,11 @@ pub mod original_raw_tags;
 pub mod profile_dynamic_range_tags;
 
 // Re-export commonly used items
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(adobe_data_tags::DNG_ADOBEDATA_TAGS),
+    crate::tables::lazy_table!(image_seq_tags::DNG_IMAGESEQ_TAGS),
+    crate::tables::lazy_table!(original_raw_tags::DNG_ORIGINALRAW_TAGS),
+    crate::tables::lazy_table!(profile_dynamic_range_tags::DNG_PROFILEDYNAMICRANGE_TAGS),
+];
@@ -117,19 +117,25 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: .{4}\\x57\\x90\\x75\\x36
     if let Ok(regex) = RegexBuilder::new(r".{4}\x57\x90\x75\x36")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("AA", regex);
     }
 
     // Pattern: \\xff[\\xf0\\xf1]
-    if let Ok(regex) = RegexBuilder::new(r"\xff[\xf0\xf1]").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"\xff[\xf0\xf1]")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("AAC", regex);
     }
 
     // Pattern: (FORM....AIF[FC]|AT&TFORM)
     if let Ok(regex) = RegexBuilder::new(r"(FORM....AIF[FC]|AT&TFORM)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("AIFF", regex);
@@ -138,6 +144,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (MAC |APETAGEX|ID3)
     if let Ok(regex) = RegexBuilder::new(r"(MAC |APETAGEX|ID3)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("APE", regex);
@@ -146,6 +153,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (II\\x2b\\0|MM\\0\\x2b)
     if let Ok(regex) = RegexBuilder::new(r"(II\x2b\x00|MM\x00\x2b)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("BTF", regex);
@@ -154,6 +162,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: BZh[1-9]\\x31\\x41\\x59\\x26\\x53\\x59
     if let Ok(regex) = RegexBuilder::new(r"BZh[1-9]\x31\x41\x59\x26\x53\x59")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("BZ2", regex);
@@ -164,6 +173,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
         r"ITSF.{20}\x10\xfd\x01\x7c\xaa\x7b\xd0\x11\x9e\x0c\x00\xa0\xc9\x22\xe6\xec",
     )
     .unicode(false)
+    .dot_matches_new_line(true)
     .build()
     {
         map.insert("CHM", regex);
@@ -172,6 +182,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (II|MM).{4}HEAP(CCDR|JPGM)
     if let Ok(regex) = RegexBuilder::new(r"(II|MM).{4}HEAP(CCDR|JPGM)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("CRW", regex);
@@ -180,6 +191,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: ZISRAWFILE\\0{6}
     if let Ok(regex) = RegexBuilder::new(r"ZISRAWFILE\x00{6}")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("CZI", regex);
@@ -190,19 +202,25 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
         r"(.{128}DICM|\x00[\x02\x04\x06\x08]\x00[\x00-\x20]|[\x02\x04\x06\x08]\x00[\x00-\x20]\x00)",
     )
     .unicode(false)
+    .dot_matches_new_line(true)
     .build()
     {
         map.insert("DICOM", regex);
     }
 
     // Pattern: (SDPX|XPDS)
-    if let Ok(regex) = RegexBuilder::new(r"(SDPX|XPDS)").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"(SDPX|XPDS)")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("DPX", regex);
     }
 
     // Pattern: IIII[\\x04|\\x05]\\0\\x04\\0
     if let Ok(regex) = RegexBuilder::new(r"IIII[\x04|\x05]\x00\x04\x00")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("DR4", regex);
@@ -211,6 +229,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: DSD \\x1c\\0{7}.{16}fmt
     if let Ok(regex) = RegexBuilder::new(r"DSD \x1c\x00{7}.{16}fmt ")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("DSF", regex);
@@ -219,6 +238,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (\\x02dss|\\x03ds2)
     if let Ok(regex) = RegexBuilder::new(r"(\x02dss|\x03ds2)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("DSS", regex);
@@ -227,24 +247,34 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: \\x1f\\x07\\0[\\x3f\\xbf]
     if let Ok(regex) = RegexBuilder::new(r"\x1f\x07\x00[\x3f\xbf]")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("DV", regex);
     }
 
     // Pattern: \\(DWF V\\d
-    if let Ok(regex) = RegexBuilder::new(r"\(DWF V\d").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"\(DWF V\d")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("DWF", regex);
     }
 
     // Pattern: AC10\\d{2}\\0
-    if let Ok(regex) = RegexBuilder::new(r"AC10\d{2}\x00").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"AC10\d{2}\x00")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("DWG", regex);
     }
 
     // Pattern: (%!PS|%!Ad|\\xc5\\xd0\\xd3\\xc6)
     if let Ok(regex) = RegexBuilder::new(r"(%!PS|%!Ad|\xc5\xd0\xd3\xc6)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("EPS", regex);
@@ -252,7 +282,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
 
     // Pattern: (MZ|\\xca\\xfe\\xba\\xbe|\\xfe\\xed\\xfa[\\xce\\xcf]|[\\xce\\xcf]\\xfa\\xed\\xfe|Joy!peff|\\x7fELF|#!\\s*/\\S*bin/|!<arch>\\x0a)
     if let Ok(regex) = RegexBuilder::new(r"(MZ|\xca\xfe\xba\xbe|\xfe\xed\xfa[\xce\xcf]|[\xce\xcf]\xfa\xed\xfe|Joy!peff|\x7fELF|#!\s*/\S*bin/|!<arch>\x0a)")
-        .unicode(false)
+        .unicode(false).dot_matches_new_line(true)
         .build() {
         map.insert("EXE", regex);
     }
@@ -260,54 +290,77 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (II\\x2a\\0|MM\\0\\x2a)
     if let Ok(regex) = RegexBuilder::new(r"(II\x2a\x00|MM\x00\x2a)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("EXIF", regex);
     }
 
     // Pattern: .{8}\\.FIT
-    if let Ok(regex) = RegexBuilder::new(r".{8}\.FIT").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r".{8}\.FIT")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("FIT", regex);
     }
 
     // Pattern: SIMPLE  = {20}T
-    if let Ok(regex) = RegexBuilder::new(r"SIMPLE  = {20}T").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"SIMPLE  = {20}T")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("FITS", regex);
     }
 
     // Pattern: (fLaC|ID3)
-    if let Ok(regex) = RegexBuilder::new(r"(fLaC|ID3)").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"(fLaC|ID3)")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("FLAC", regex);
     }
 
     // Pattern: FLIF[0-\\x6f][0-2]
     if let Ok(regex) = RegexBuilder::new(r"FLIF[0-\x6f][0-2]")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("FLIF", regex);
     }
 
     // Pattern: [AF]FF\\0
-    if let Ok(regex) = RegexBuilder::new(r"[AF]FF\x00").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"[AF]FF\x00")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("FLIR", regex);
     }
 
     // Pattern: ((\\0\\x01\\0\\0|OTTO|true|typ1)[\\0\\x01]|ttcf\\0[\\x01\\x02]\\0\\0|\\0[\\x01\\x02]|(.{6})?%!(PS-(AdobeFont-|Bitstream )|FontType1-)|Start(Comp|Master)?FontMetrics|wOF[F2])
     if let Ok(regex) = RegexBuilder::new(r"((\x00\x01\x00\x00|OTTO|true|typ1)[\x00\x01]|ttcf\x00[\x01\x02]\x00\x00|\x00[\x01\x02]|(.{6})?%!(PS-(AdobeFont-|Bitstream )|FontType1-)|Start(Comp|Master)?FontMetrics|wOF[F2])")
-        .unicode(false)
+        .unicode(false).dot_matches_new_line(true)
         .build() {
         map.insert("Font", regex);
     }
 
     // Pattern: GIF8[79]a
-    if let Ok(regex) = RegexBuilder::new(r"GIF8[79]a").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"GIF8[79]a")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("GIF", regex);
     }
 
     // Pattern: #\\?(RADIANCE|RGBE)\\x0a
     if let Ok(regex) = RegexBuilder::new(r"#\?(RADIANCE|RGBE)\x0a")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("HDR", regex);
@@ -316,6 +369,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (\\xef\\xbb\\xbf)?\\s*(?i)<(!DOCTYPE\\s+HTML|HTML|\\?xml)
     if let Ok(regex) = RegexBuilder::new(r"(\xef\xbb\xbf)?\s*(?i)<(!DOCTYPE\s+HTML|HTML|\?xml)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("HTML", regex);
@@ -323,7 +377,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
 
     // Pattern: .{12}(scnr|mntr|prtr|link|spac|abst|nmcl|nkpf|cenc|mid |mlnk|mvis)(XYZ |Lab |Luv |YCbr|Yxy |RGB |GRAY|HSV |HLS |CMYK|CMY |[2-9A-F]CLR|nc..|\\0{4}){2}
     if let Ok(regex) = RegexBuilder::new(r".{12}(scnr|mntr|prtr|link|spac|abst|nmcl|nkpf|cenc|mid |mlnk|mvis)(XYZ |Lab |Luv |YCbr|Yxy |RGB |GRAY|HSV |HLS |CMYK|CMY |[2-9A-F]CLR|nc..|\x00{4}){2}")
-        .unicode(false)
+        .unicode(false).dot_matches_new_line(true)
         .build() {
         map.insert("ICC", regex);
     }
@@ -331,13 +385,18 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: \\0\\0[\\x01\\x02]\\0[^0]\\0
     if let Ok(regex) = RegexBuilder::new(r"\x00\x00[\x01\x02]\x00[^0]\x00")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("ICO", regex);
     }
 
     // Pattern: .{4}itch
-    if let Ok(regex) = RegexBuilder::new(r".{4}itch").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r".{4}itch")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("ITC", regex);
     }
 
@@ -345,6 +404,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     if let Ok(regex) =
         RegexBuilder::new(r"(\x00\x00\x00\x0cjP(  |\x1a\x1a)\x0d\x0a\x87\x0a|\xff\x4f\xff\x51\x00)")
             .unicode(false)
+            .dot_matches_new_line(true)
             .build()
     {
         map.insert("JP2", regex);
@@ -353,6 +413,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (\\xef\\xbb\\xbf)?\\s*(\\[\\s*)?\\{\\s*\"[^\"]*\"\\s*:
     if let Ok(regex) = RegexBuilder::new(r#"(\\xef\\xbb\\xbf)?\\s*(\\[\\s*)?\\{\\s*"[^"]*"\\s*:"#)
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("JSON", regex);
@@ -361,6 +422,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: .{4}jumb\\0.{3}jumd
     if let Ok(regex) = RegexBuilder::new(r".{4}jumb\x00.{3}jumd")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("JUMBF", regex);
@@ -369,6 +431,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (\\xff\\x0a|\\0\\0\\0\\x0cJXL \\x0d\\x0a......ftypjxl )
     if let Ok(regex) = RegexBuilder::new(r"(\xff\x0a|\x00\x00\x00\x0cJXL \x0d\x0a......ftypjxl )")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("JXL", regex);
@@ -378,6 +441,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     if let Ok(regex) =
         RegexBuilder::new(r".{2}\x00\x00[A-Z].{31}(CHAR|BOOL|[US](8|16|32|64)|FLOAT|DOUBLE)\x00")
             .unicode(false)
+            .dot_matches_new_line(true)
             .build()
     {
         map.insert("KVAR", regex);
@@ -386,6 +450,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: \\x70\\0{3}.{4}\\x2a.{4}<\\0
     if let Ok(regex) = RegexBuilder::new(r"\x70\x00{3}.{4}\x2a.{4}<\x00")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("LIF", regex);
@@ -396,6 +461,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
         r"(.{4}\x01\x14\x02\x00{5}\xc0\x00{6}\x46|\[[InternetShortcut\][\x0d\x0a])",
     )
     .unicode(false)
+    .dot_matches_new_line(true)
     .build()
     {
         map.insert("LNK", regex);
@@ -404,6 +470,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: .{0,191}?\\x47(.{187}|.{191})\\x47(.{187}|.{191})\\x47
     if let Ok(regex) = RegexBuilder::new(r".{0,191}?\x47(.{187}|.{191})\x47(.{187}|.{191})\x47")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("M2TS", regex);
@@ -412,6 +479,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: ~[\\x10\\x18]\\x04.0MIE
     if let Ok(regex) = RegexBuilder::new(r"~[\x10\x18]\x04.0MIE")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("MIE", regex);
@@ -421,19 +489,25 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     if let Ok(regex) =
         RegexBuilder::new(r".{4}(free|skip|wide|ftyp|pnot|PICT|pict|moov|mdat|junk|uuid)")
             .unicode(false)
+            .dot_matches_new_line(true)
             .build()
     {
         map.insert("MOV", regex);
     }
 
     // Pattern: (MP\\+|ID3)
-    if let Ok(regex) = RegexBuilder::new(r"(MP\+|ID3)").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"(MP\+|ID3)")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("MPC", regex);
     }
 
     // Pattern: \\0\\0\\x01[\\xb0-\\xbf]
     if let Ok(regex) = RegexBuilder::new(r"\x00\x00\x01[\xb0-\xbf]")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("MPEG", regex);
@@ -441,29 +515,41 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
 
     // Pattern: .{64}[\\x01\\x02\\x03]\\0\\0\\0[\\x01\\x02\\x03]\\0\\0\\0[\\x01\\x02\\x03]\\0\\0\\0.{132}MAP[\\0 ](\\x44\\x44|\\x44\\x41|\\x11\\x11)\\0\\0
     if let Ok(regex) = RegexBuilder::new(r".{64}[\x01\x02\x03]\x00\x00\x00[\x01\x02\x03]\x00\x00\x00[\x01\x02\x03]\x00\x00\x00.{132}MAP[\x00 ](\x44\x44|\x44\x41|\x11\x11)\x00\x00")
-        .unicode(false)
+        .unicode(false).dot_matches_new_line(true)
         .build() {
         map.insert("MRC", regex);
     }
 
     // Pattern: \\0MR[MI]
-    if let Ok(regex) = RegexBuilder::new(r"\x00MR[MI]").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"\x00MR[MI]")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("MRW", regex);
     }
 
     // Pattern: (OggS|ID3)
-    if let Ok(regex) = RegexBuilder::new(r"(OggS|ID3)").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"(OggS|ID3)")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("OGG", regex);
     }
 
     // Pattern: (II|MM)
-    if let Ok(regex) = RegexBuilder::new(r"(II|MM)").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"(II|MM)")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("ORF", regex);
     }
 
     // Pattern: \\xa1\\xb2(\\xc3\\xd4|\\x3c\\x4d)\\0.\\0.|(\\xd4\\xc3|\\x4d\\x3c)\\xb2\\xa1.\\0.\\0|\\x0a\\x0d\\x0d\\x0a.{4}(\\x1a\\x2b\\x3c\\x4d|\\x4d\\x3c\\x2b\\x1a)|GMBU\\0\\x02
     if let Ok(regex) = RegexBuilder::new(r"\xa1\xb2(\xc3\xd4|\x3c\x4d)\x00.\x00.|(\xd4\xc3|\x4d\x3c)\xb2\xa1.\x00.\x00|\x0a\x0d\x0d\x0a.{4}(\x1a\x2b\x3c\x4d|\x4d\x3c\x2b\x1a)|GMBU\x00\x02")
-        .unicode(false)
+        .unicode(false).dot_matches_new_line(true)
         .build() {
         map.insert("PCAP", regex);
     }
@@ -471,6 +557,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: \\x0a[\\0-\\x05]\\x01[\\x01\\x02\\x04\\x08].{64}[\\0-\\x02]
     if let Ok(regex) = RegexBuilder::new(r"\x0a[\x00-\x05]\x01[\x01\x02\x04\x08].{64}[\x00-\x02]")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("PCX", regex);
@@ -478,7 +565,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
 
     // Pattern: .{60}(\\.pdfADBE|TEXtREAd|BVokBDIC|DB99DBOS|PNRdPPrs|DataPPrs|vIMGView|PmDBPmDB|InfoINDB|ToGoToGo|SDocSilX|JbDbJBas|JfDbJFil|DATALSdb|Mdb1Mdb1|BOOKMOBI|DataPlkr|DataSprd|SM01SMem|TEXtTlDc|InfoTlIf|DataTlMl|DataTlPt|dataTDBP|TdatTide|ToRaTRPW|zTXTGPlm|BDOCWrdS)
     if let Ok(regex) = RegexBuilder::new(r".{60}(\.pdfADBE|TEXtREAd|BVokBDIC|DB99DBOS|PNRdPPrs|DataPPrs|vIMGView|PmDBPmDB|InfoINDB|ToGoToGo|SDocSilX|JbDbJBas|JfDbJFil|DATALSdb|Mdb1Mdb1|BOOKMOBI|DataPlkr|DataSprd|SM01SMem|TEXtTlDc|InfoTlIf|DataTlMl|DataTlPt|dataTDBP|TdatTide|ToRaTRPW|zTXTGPlm|BDOCWrdS)")
-        .unicode(false)
+        .unicode(false).dot_matches_new_line(true)
         .build() {
         map.insert("PDB", regex);
     }
@@ -486,6 +573,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: P[Ff]\\x0a\\d+ \\d+\\x0a[-+0-9.]+\\x0a
     if let Ok(regex) = RegexBuilder::new(r"P[Ff]\x0a\d+ \d+\x0a[-+0-9.]+\x0a")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("PFM", regex);
@@ -494,6 +582,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (.{10}|.{522})(\\x11\\x01|\\x00\\x11)
     if let Ok(regex) = RegexBuilder::new(r"(.{10}|.{522})(\x11\x01|\x00\x11)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("PICT", regex);
@@ -502,6 +591,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (bplist0|\\s*<|\\xfe\\xff\\x00)
     if let Ok(regex) = RegexBuilder::new(r"(bplist0|\s*<|\xfe\xff\x00)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("PLIST", regex);
@@ -510,6 +600,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: .{8}\\0{3}\\x7c.{112}\\xff\\xd8\\xff\\xdb
     if let Ok(regex) = RegexBuilder::new(r".{8}\x00{3}\x7c.{112}\xff\xd8\xff\xdb")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("PMP", regex);
@@ -518,19 +609,25 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (\\x89P|\\x8aM|\\x8bJ)NG\\r\\n\\x1a\\n
     if let Ok(regex) = RegexBuilder::new(r"(\x89P|\x8aM|\x8bJ)NG\r\n\x1a\n")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("PNG", regex);
     }
 
     // Pattern: P[1-6]\\s+
-    if let Ok(regex) = RegexBuilder::new(r"P[1-6]\s+").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"P[1-6]\s+")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("PPM", regex);
     }
 
     // Pattern: (%!PS|%!Ad|\\xc5\\xd0\\xd3\\xc6)
     if let Ok(regex) = RegexBuilder::new(r"(%!PS|%!Ad|\xc5\xd0\xd3\xc6)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("PS", regex);
@@ -539,6 +636,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: 8BPS\\0[\\x01\\x02]
     if let Ok(regex) = RegexBuilder::new(r"8BPS\x00[\x01\x02]")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("PSD", regex);
@@ -547,6 +645,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: Paint Shop Pro Image File\\x0a\\x1a\\0{5}
     if let Ok(regex) = RegexBuilder::new(r"Paint Shop Pro Image File\x0a\x1a\x00{5}")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("PSP", regex);
@@ -555,6 +654,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: .{4}(idsc|idat|iicc)
     if let Ok(regex) = RegexBuilder::new(r".{4}(idsc|idat|iicc)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("QTIF", regex);
@@ -563,6 +663,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: \\0\\0..RED(1|2)
     if let Ok(regex) = RegexBuilder::new(r"\x00\x00..RED(1|2)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("R3D", regex);
@@ -571,6 +672,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (.{25}ARECOYK|II|MM)
     if let Ok(regex) = RegexBuilder::new(r"(.{25}ARECOYK|II|MM)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("RAW", regex);
@@ -579,6 +681,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (RIFF|LA0[234]|OFR |LPAC|wvpk|RF64)
     if let Ok(regex) = RegexBuilder::new(r"(RIFF|LA0[234]|OFR |LPAC|wvpk|RF64)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("RIFF", regex);
@@ -587,6 +690,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (....)?\\0\\0\\x01\\0
     if let Ok(regex) = RegexBuilder::new(r"(....)?\x00\x00\x01\x00")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("RSRC", regex);
@@ -595,6 +699,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: [\\n\\r]*\\{[\\n\\r]*\\\\rtf
     if let Ok(regex) = RegexBuilder::new(r"[\n\r]*\{[\n\r]*\\rtf")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("RTF", regex);
@@ -603,26 +708,36 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (\\.RMF|\\.ra\\xfd|pnm://|rtsp://|http://)
     if let Ok(regex) = RegexBuilder::new(r"(\.RMF|\.ra\xfd|pnm://|rtsp://|http://)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("Real", regex);
     }
 
     // Pattern: [FC]WS[^\\0]
-    if let Ok(regex) = RegexBuilder::new(r"[FC]WS[^\x00]").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"[FC]WS[^\x00]")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("SWF", regex);
     }
 
     // Pattern: .{257}ustar(  )?\\0
     if let Ok(regex) = RegexBuilder::new(r".{257}ustar(  )?\x00")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("TAR", regex);
     }
 
     // Pattern: (II|MM)
-    if let Ok(regex) = RegexBuilder::new(r"(II|MM)").unicode(false).build() {
+    if let Ok(regex) = RegexBuilder::new(r"(II|MM)")
+        .unicode(false)
+        .dot_matches_new_line(true)
+        .build()
+    {
         map.insert("TIFF", regex);
     }
 
@@ -631,6 +746,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
         r"(\xff\xfe|(\x00\x00)?\xfe\xff|(\xef\xbb\xbf)?[\x07-\x0d\x20-\x7e\x80-\xfe]*$)",
     )
     .unicode(false)
+    .dot_matches_new_line(true)
     .build()
     {
         map.insert("TXT", regex);
@@ -639,6 +755,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (?i)BEGIN:(VCARD|VCALENDAR|VNOTE)\\r\\n
     if let Ok(regex) = RegexBuilder::new(r"(?i)BEGIN:(VCARD|VCALENDAR|VNOTE)\r\n")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("VCard", regex);
@@ -647,6 +764,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: (\\xd7\\xcd\\xc6\\x9a\\0\\0|\\x01\\0\\x09\\0\\0\\x03)
     if let Ok(regex) = RegexBuilder::new(r"(\xd7\xcd\xc6\x9a\x00\x00|\x01\x00\x09\x00\x00\x03)")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("WMF", regex);
@@ -655,6 +773,7 @@ pub static REGEX_MAGIC_NUMBERS: LazyLock<HashMap<&'static str, Regex>> = LazyLoc
     // Pattern: \\0{0,3}(\\xfe\\xff|\\xff\\xfe|\\xef\\xbb\\xbf)?\\0{0,3}\\s*<
     if let Ok(regex) = RegexBuilder::new(r"\x00{0,3}(\xfe\xff|\xff\xfe|\xef\xbb\xbf)?\x00{0,3}\s*<")
         .unicode(false)
+        .dot_matches_new_line(true)
         .build()
     {
         map.insert("XMP", regex);
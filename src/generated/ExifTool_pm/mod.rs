@@ -35,3 +35,31 @@ pub mod weak_magic;
 pub mod write_types;
 
 // Re-export commonly used items
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(all_groups_exif_tool::ALL_GROUPS_EXIF_TOOL),
+    crate::tables::lazy_table!(charset_name::CHARSET_NAME),
+    crate::tables::lazy_table!(composite_tags::EXIF_TOOL_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(create_types::CREATE_TYPES),
+    crate::tables::lazy_table!(extra_tags::EXIF_TOOL_EXTRA_TAGS),
+    crate::tables::lazy_table!(file_description::FILE_DESCRIPTION),
+    crate::tables::lazy_table!(file_type_ext::FILE_TYPE_EXT),
+    crate::tables::lazy_table!(file_type_lookup::FILE_TYPE_EXTENSIONS),
+    crate::tables::lazy_table!(is_pc::IS_P_C),
+    crate::tables::lazy_table!(is_rational::IS_RATIONAL),
+    crate::tables::lazy_table!(jpeg_marker::JPEG_MARKER),
+    crate::tables::lazy_table!(lang_name::LANG_NAME),
+    crate::tables::lazy_table!(magic_numbers::LITERAL_MAGIC_NUMBERS),
+    crate::tables::lazy_table!(magic_numbers::REGEX_MAGIC_NUMBERS),
+    crate::tables::lazy_table!(process_type::PROCESS_TYPE),
+    crate::tables::lazy_table!(read_value_proc::READ_VALUE_PROC),
+    crate::tables::lazy_table!(special_tags_tags::EXIF_TOOL_SPECIALTAGS_TAGS),
+    crate::tables::lazy_table!(system_tags_notes::SYSTEM_TAGS_NOTES),
+    crate::tables::lazy_table!(unpack_intel::UNPACK_INTEL),
+    crate::tables::lazy_table!(unpack_motorola::UNPACK_MOTOROLA),
+    crate::tables::lazy_table!(unpack_rev::UNPACK_REV),
+    crate::tables::lazy_table!(unpack_std::UNPACK_STD),
+    crate::tables::lazy_table!(user_param_tags::EXIF_TOOL_USERPARAM_TAGS),
+    crate::tables::lazy_table!(weak_magic::WEAK_MAGIC),
+];
@@ -44,6 +44,7 @@ use crate::generated::functions::hash_dd::{
 use crate::generated::functions::hash_e1::ast_value_e19b1ac6c7b6ad13;
 use crate::generated::functions::hash_e3::ast_value_e32785f4df88dda7;
 use crate::generated::functions::hash_e5::ast_print_e50dfd1aa09ed50e;
+use crate::implementations::print_conv::composite_image_exposure_times_print_conv;
 use crate::implementations::print_conv::lensinfo_print_conv;
 
 /// Tag definitions for Exif::Main table
@@ -1851,7 +1852,7 @@ pub static EXIF_MAIN_TAGS: LazyLock<HashMap<u16, TagInfo>> = LazyLock::new(|| {
         (42082, TagInfo {
             name: "CompositeImageExposureTimes",
             format: "unknown",
-            print_conv: Some(PrintConv::Expression("[Function: Image::ExifTool::Exif::__ANON__]".to_string())),
+            print_conv: Some(PrintConv::Function(composite_image_exposure_times_print_conv)),
             value_conv: None,
             is_offset: false,
         }),
@@ -3048,6 +3049,51 @@ pub static EXIF_MAIN_TAGS: LazyLock<HashMap<u16, TagInfo>> = LazyLock::new(|| {
     ])
 });
 
+/// Names of tags in Exif::Main with `Writable => 'string'`, in tag ID order
+pub static EXIF_MAIN_STRING_TAGS: &[&str] = &[
+    "InteropIndex",
+    "ProcessingSoftware",
+    "DocumentName",
+    "ImageDescription",
+    "Make",
+    "Model",
+    "PageName",
+    "Software",
+    "ModifyDate",
+    "Artist",
+    "HostComputer",
+    "TargetPrinter",
+    "RelatedImageFileFormat",
+    "Copyright",
+    "SpectralSensitivity",
+    "DateTimeOriginal",
+    "CreateDate",
+    "OffsetTime",
+    "OffsetTimeOriginal",
+    "OffsetTimeDigitized",
+    "SecurityClassification",
+    "ImageHistory",
+    "SubSecTime",
+    "SubSecTimeOriginal",
+    "SubSecTimeDigitized",
+    "RelatedSoundFile",
+    "ImageUniqueID",
+    "OwnerName",
+    "SerialNumber",
+    "LensMake",
+    "LensModel",
+    "LensSerialNumber",
+    "ImageTitle",
+    "Photographer",
+    "ImageEditor",
+    "CameraFirmware",
+    "RAWDevelopingSoftware",
+    "ImageEditingSoftware",
+    "MetadataEditingSoftware",
+    "UniqueCameraModel",
+    "CameraSerialNumber",
+];
+
 /// Tag overrides for IFD0 context (conditional tags)
 /// ExifTool uses these names when DIR_NAME eq 'IFD0'
 pub static EXIF_MAIN_IFD0_TAGS: LazyLock<HashMap<u16, TagInfo>> = LazyLock::new(|| {
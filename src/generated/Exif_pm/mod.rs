@@ -26,3 +26,24 @@ pub mod use_;
 
 // Re-export commonly used items
 pub use main_tags::EXIF_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(composite_tags::EXIF_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(compression::COMPRESSION),
+    crate::tables::lazy_table!(flash::FLASH),
+    crate::tables::lazy_table!(ind::IND),
+    crate::tables::lazy_table!(light_source::LIGHT_SOURCE),
+    crate::tables::lazy_table!(main_tags::EXIF_MAIN_TAGS),
+    crate::tables::lazy_table!(main_tags::EXIF_MAIN_IFD0_TAGS),
+    crate::tables::lazy_table!(main_tags::EXIF_MAIN_IFD1_TAGS),
+    crate::tables::lazy_table!(main_tags::EXIF_MAIN_IFD2_TAGS),
+    crate::tables::lazy_table!(main_tags::EXIF_MAIN_MAKERNOTES_TAGS),
+    crate::tables::lazy_table!(main_tags::EXIF_MAIN_SUBIFD_TAGS),
+    crate::tables::lazy_table!(orientation::ORIENTATION),
+    crate::tables::lazy_table!(photometric_interpretation::PHOTOMETRIC_INTERPRETATION),
+    crate::tables::lazy_table!(sample_format::SAMPLE_FORMAT),
+    crate::tables::lazy_table!(save_for_validate::SAVE_FOR_VALIDATE),
+    crate::tables::lazy_table!(unknown_tags::EXIF_UNKNOWN_TAGS),
+    crate::tables::lazy_table!(use_::USE_),
+];
@@ -19,3 +19,21 @@ pub mod tested_raf;
 
 // Re-export commonly used items
 pub use main_tags::FUJI_FILM_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(afc_settings_tags::FUJI_FILM_AFCSETTINGS_TAGS),
+    crate::tables::lazy_table!(drive_settings_tags::FUJI_FILM_DRIVESETTINGS_TAGS),
+    crate::tables::lazy_table!(face_rec_info_tags::FUJI_FILM_FACERECINFO_TAGS),
+    crate::tables::lazy_table!(ffmv_tags::FUJI_FILM_FFMV_TAGS),
+    crate::tables::lazy_table!(focus_settings_tags::FUJI_FILM_FOCUSSETTINGS_TAGS),
+    crate::tables::lazy_table!(ifd_tags::FUJI_FILM_IFD_TAGS),
+    crate::tables::lazy_table!(main_tags::FUJI_FILM_MAIN_TAGS),
+    crate::tables::lazy_table!(mov_tags::FUJI_FILM_MOV_TAGS),
+    crate::tables::lazy_table!(mraw_tags::FUJI_FILM_MRAW_TAGS),
+    crate::tables::lazy_table!(priority_settings_tags::FUJI_FILM_PRIORITYSETTINGS_TAGS),
+    crate::tables::lazy_table!(raf_data_tags::FUJI_FILM_RAFDATA_TAGS),
+    crate::tables::lazy_table!(raf_header_tags::FUJI_FILM_RAFHEADER_TAGS),
+    crate::tables::lazy_table!(raf_tags::FUJI_FILM_RAF_TAGS),
+    crate::tables::lazy_table!(tested_raf::TESTED_RAF),
+];
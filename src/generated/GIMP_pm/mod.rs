@@ -9,3 +9,11 @@ pub mod resolution_tags;
 
 // Re-export commonly used items
 pub use main_tags::GIMP_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(header_tags::GIMP_HEADER_TAGS),
+    crate::tables::lazy_table!(main_tags::GIMP_MAIN_TAGS),
+    crate::tables::lazy_table!(parasite_tags::GIMP_PARASITE_TAGS),
+    crate::tables::lazy_table!(resolution_tags::GIMP_RESOLUTION_TAGS),
+];
@@ -405,6 +405,24 @@ pub static GPS_MAIN_TAGS: LazyLock<HashMap<u16, TagInfo>> = LazyLock::new(|| {
     ])
 });
 
+/// Names of tags in GPS::Main with `Writable => 'string'`, in tag ID order
+pub static GPS_MAIN_STRING_TAGS: &[&str] = &[
+    "GPSLatitudeRef",
+    "GPSLongitudeRef",
+    "GPSSatellites",
+    "GPSStatus",
+    "GPSMeasureMode",
+    "GPSSpeedRef",
+    "GPSTrackRef",
+    "GPSImgDirectionRef",
+    "GPSMapDatum",
+    "GPSDestLatitudeRef",
+    "GPSDestLongitudeRef",
+    "GPSDestBearingRef",
+    "GPSDestDistanceRef",
+    "GPSDateStamp",
+];
+
 /// Apply ValueConv transformation for tags in this table
 pub fn apply_value_conv(
     tag_id: u32,
@@ -9,3 +9,11 @@ pub mod print_conv_lon_ref;
 
 // Re-export commonly used items
 pub use main_tags::GPS_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(composite_tags::GPS_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(main_tags::GPS_MAIN_TAGS),
+    crate::tables::lazy_table!(print_conv_lat_ref::PRINT_CONV_LAT_REF),
+    crate::tables::lazy_table!(print_conv_lon_ref::PRINT_CONV_LON_REF),
+];
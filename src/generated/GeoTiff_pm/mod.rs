@@ -9,3 +9,11 @@ pub mod main_tags;
 
 // Re-export commonly used items
 pub use main_tags::GEO_TIFF_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(epsg_units::EPSG_UNITS),
+    crate::tables::lazy_table!(epsg_vertcs::EPSG_VERTCS),
+    crate::tables::lazy_table!(geo_tiff_format::GEO_TIFF_FORMAT),
+    crate::tables::lazy_table!(main_tags::GEO_TIFF_MAIN_TAGS),
+];
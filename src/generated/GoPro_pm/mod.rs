@@ -13,3 +13,16 @@ pub mod kbat_tags;
 pub mod no_yes;
 
 // Re-export commonly used items
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(fdsc_tags::GO_PRO_FDSC_TAGS),
+    crate::tables::lazy_table!(glpi_tags::GO_PRO_GLPI_TAGS),
+    crate::tables::lazy_table!(go_pro_fmt::GO_PRO_FMT),
+    crate::tables::lazy_table!(gpmf_tags::GO_PRO_GPMF_TAGS),
+    crate::tables::lazy_table!(gpri_tags::GO_PRO_GPRI_TAGS),
+    crate::tables::lazy_table!(gps5_tags::GO_PRO_GPS5_TAGS),
+    crate::tables::lazy_table!(gps9_tags::GO_PRO_GPS9_TAGS),
+    crate::tables::lazy_table!(kbat_tags::GO_PRO_KBAT_TAGS),
+    crate::tables::lazy_table!(no_yes::NO_YES),
+];
@@ -14,3 +14,16 @@ pub mod shutter_tags;
 
 // Re-export commonly used items
 pub use main_tags::H264_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(camera1_tags::H264_CAMERA1_TAGS),
+    crate::tables::lazy_table!(camera2_tags::H264_CAMERA2_TAGS),
+    crate::tables::lazy_table!(conv_make::CONV_MAKE),
+    crate::tables::lazy_table!(frame_info_tags::H264_FRAMEINFO_TAGS),
+    crate::tables::lazy_table!(main_tags::H264_MAIN_TAGS),
+    crate::tables::lazy_table!(make_model_tags::H264_MAKEMODEL_TAGS),
+    crate::tables::lazy_table!(mdpm_tags::H264_MDPM_TAGS),
+    crate::tables::lazy_table!(rec_info_tags::H264_RECINFO_TAGS),
+    crate::tables::lazy_table!(shutter_tags::H264_SHUTTER_TAGS),
+];
@@ -10,3 +10,12 @@ pub mod type6_tags;
 
 // Re-export commonly used items
 pub use main_tags::HP_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(main_tags::HP_MAIN_TAGS),
+    crate::tables::lazy_table!(tdhd_tags::HP_TDHD_TAGS),
+    crate::tables::lazy_table!(type2_tags::HP_TYPE2_TAGS),
+    crate::tables::lazy_table!(type4_tags::HP_TYPE4_TAGS),
+    crate::tables::lazy_table!(type6_tags::HP_TYPE6_TAGS),
+];
@@ -0,0 +1,19 @@
+//! Generated module for ID3
+//!
+//! This file is auto-generated by codegen/src/main.rs. Do not edit manually.
+
+pub mod genre;
+pub mod v2_2_tags;
+pub mod v2_3_tags;
+pub mod v2_4_tags;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(genre::GENRE),
+    crate::tables::lazy_table!(v2_2_tags::ID3_V2_2_TAGS),
+    crate::tables::lazy_table!(v2_2_tags::ID3_V2_2_TAGS_BY_NAME),
+    crate::tables::lazy_table!(v2_3_tags::ID3_V2_3_TAGS),
+    crate::tables::lazy_table!(v2_3_tags::ID3_V2_3_TAGS_BY_NAME),
+    crate::tables::lazy_table!(v2_4_tags::ID3_V2_4_TAGS),
+    crate::tables::lazy_table!(v2_4_tags::ID3_V2_4_TAGS_BY_NAME),
+];
@@ -0,0 +1,234 @@
+//! Generated tag table definitions from ID3::v2_3
+//!
+//! This file is auto-generated by codegen/src/strategies/tag_kit.rs. Do not edit manually.
+
+use crate::types::{PrintConv, TagInfo, ValueConv};
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Tag definitions for ID3::v2_3 table
+pub static ID3_V2_3_TAGS: LazyLock<HashMap<u16, TagInfo>> = LazyLock::new(HashMap::new);
+
+/// Byte-string keyed tag definitions for ID3::v2_3 table
+/// Keys are the exact tag IDs ExifTool matches, case included.
+pub static ID3_V2_3_TAGS_BY_NAME: LazyLock<HashMap<&'static [u8], TagInfo>> = LazyLock::new(|| {
+    HashMap::from([
+        (
+            b"TALB".as_slice(),
+            TagInfo {
+                name: "Album",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TCOM".as_slice(),
+            TagInfo {
+                name: "Composer",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TCON".as_slice(),
+            TagInfo {
+                name: "Genre",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TCOP".as_slice(),
+            TagInfo {
+                name: "Copyright",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TENC".as_slice(),
+            TagInfo {
+                name: "EncodedBy",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TIT1".as_slice(),
+            TagInfo {
+                name: "Grouping",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TIT2".as_slice(),
+            TagInfo {
+                name: "Title",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TIT3".as_slice(),
+            TagInfo {
+                name: "Subtitle",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TPE1".as_slice(),
+            TagInfo {
+                name: "Artist",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TPE2".as_slice(),
+            TagInfo {
+                name: "Band",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TPOS".as_slice(),
+            TagInfo {
+                name: "PartOfSet",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TPUB".as_slice(),
+            TagInfo {
+                name: "Publisher",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TRCK".as_slice(),
+            TagInfo {
+                name: "Track",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TSSE".as_slice(),
+            TagInfo {
+                name: "EncoderSettings",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+        (
+            b"TYER".as_slice(),
+            TagInfo {
+                name: "Year",
+                format: "unknown",
+                print_conv: None,
+                value_conv: None,
+                is_offset: false,
+            },
+        ),
+    ])
+});
+
+/// Apply ValueConv transformation for tags in this table
+pub fn apply_value_conv(
+    tag_id: u32,
+    value: &crate::types::TagValue,
+    _errors: &mut Vec<String>,
+) -> Result<crate::types::TagValue, crate::types::ExifError> {
+    let tag_id_u16 = tag_id as u16;
+    if let Some(tag_def) = ID3_V2_3_TAGS.get(&tag_id_u16) {
+        if let Some(ref value_conv) = tag_def.value_conv {
+            match value_conv {
+                ValueConv::None => Ok(value.clone()),
+                ValueConv::Function(func) => func(value, None),
+                ValueConv::Expression(_expr) => {
+                    // Runtime expression evaluation removed - all Perl interpretation happens via PPI at build time
+                    Err(crate::types::ExifError::NotImplemented("Runtime expression evaluation not supported - should be handled by PPI at build time".to_string()))
+                }
+                _ => Ok(value.clone()),
+            }
+        } else {
+            Ok(value.clone())
+        }
+    } else {
+        Err(crate::types::ExifError::ParseError(format!(
+            "Tag 0x{:04x} not found in table",
+            tag_id
+        )))
+    }
+}
+
+/// Apply PrintConv transformation for tags in this table
+pub fn apply_print_conv(
+    tag_id: u32,
+    value: &crate::types::TagValue,
+    _errors: &mut Vec<String>,
+    _warnings: &mut Vec<String>,
+) -> crate::types::TagValue {
+    let tag_id_u16 = tag_id as u16;
+    if let Some(tag_def) = ID3_V2_3_TAGS.get(&tag_id_u16) {
+        if let Some(ref print_conv) = tag_def.print_conv {
+            match print_conv {
+                PrintConv::None => value.clone(),
+                PrintConv::Function(func) => func(value, None),
+                PrintConv::Simple(lookup) => {
+                    // Look up value in the hash map
+                    // ExifTool uses the stringified value as the key
+                    let key = value.to_string();
+                    if let Some(display_value) = lookup.get(&key) {
+                        crate::types::TagValue::String(display_value.to_string())
+                    } else {
+                        // Key not found - return original value
+                        value.clone()
+                    }
+                }
+                PrintConv::Expression(_expr) => {
+                    // Runtime expression evaluation removed - all Perl interpretation happens via PPI at build time
+                    value.clone() // Fallback to original value when expression not handled by PPI
+                }
+                _ => value.clone(),
+            }
+        } else {
+            value.clone()
+        }
+    } else {
+        value.clone()
+    }
+}
@@ -831,6 +831,10 @@ pub static IPTC_APPLICATIONRECORD_TAGS: LazyLock<HashMap<u16, TagInfo>> = LazyLo
     ])
 });
 
+/// IDs of tags in IPTC::ApplicationRecord with `List => 1`, in tag ID order
+pub static IPTC_APPLICATIONRECORD_LIST_TAGS: &[u16] =
+    &[4, 12, 20, 25, 26, 27, 45, 47, 50, 80, 85, 118, 122];
+
 /// Apply ValueConv transformation for tags in this table
 pub fn apply_value_conv(
     tag_id: u32,
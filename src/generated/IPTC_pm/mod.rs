@@ -16,3 +16,18 @@ pub mod pre_object_data_tags;
 
 // Re-export commonly used items
 pub use main_tags::IPTC_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(application_record_tags::IPTC_APPLICATIONRECORD_TAGS),
+    crate::tables::lazy_table!(composite_tags::IPTC_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(envelope_record_tags::IPTC_ENVELOPERECORD_TAGS),
+    crate::tables::lazy_table!(file_format::FILE_FORMAT),
+    crate::tables::lazy_table!(foto_station_tags::IPTC_FOTOSTATION_TAGS),
+    crate::tables::lazy_table!(iptc_charset::IPTC_CHARSET),
+    crate::tables::lazy_table!(main_tags::IPTC_MAIN_TAGS),
+    crate::tables::lazy_table!(news_photo_tags::IPTC_NEWSPHOTO_TAGS),
+    crate::tables::lazy_table!(object_data_tags::IPTC_OBJECTDATA_TAGS),
+    crate::tables::lazy_table!(post_object_data_tags::IPTC_POSTOBJECTDATA_TAGS),
+    crate::tables::lazy_table!(pre_object_data_tags::IPTC_PREOBJECTDATA_TAGS),
+];
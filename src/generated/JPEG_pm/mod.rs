@@ -20,3 +20,22 @@ pub mod y_cb_cr_sub_sampling;
 
 // Re-export commonly used items
 pub use main_tags::JPEG_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(adobe_cm_tags::JPEG_ADOBECM_TAGS),
+    crate::tables::lazy_table!(adobe_tags::JPEG_ADOBE_TAGS),
+    crate::tables::lazy_table!(avi1_tags::JPEG_AVI1_TAGS),
+    crate::tables::lazy_table!(eppim_tags::JPEG_EPPIM_TAGS),
+    crate::tables::lazy_table!(graph_conv_tags::JPEG_GRAPHCONV_TAGS),
+    crate::tables::lazy_table!(hdr_gain_info_tags::JPEG_HDRGAININFO_TAGS),
+    crate::tables::lazy_table!(hdr_tags::JPEG_HDR_TAGS),
+    crate::tables::lazy_table!(jps_tags::JPEG_JPS_TAGS),
+    crate::tables::lazy_table!(main_tags::JPEG_MAIN_TAGS),
+    crate::tables::lazy_table!(media_jukebox_tags::JPEG_MEDIAJUKEBOX_TAGS),
+    crate::tables::lazy_table!(nitf_tags::JPEG_NITF_TAGS),
+    crate::tables::lazy_table!(ocad_tags::JPEG_OCAD_TAGS),
+    crate::tables::lazy_table!(sof_tags::JPEG_SOF_TAGS),
+    crate::tables::lazy_table!(spiff_tags::JPEG_SPIFF_TAGS),
+    crate::tables::lazy_table!(y_cb_cr_sub_sampling::Y_CB_CR_SUB_SAMPLING),
+];
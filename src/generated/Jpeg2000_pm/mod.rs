@@ -18,3 +18,20 @@ pub mod uuid;
 
 // Re-export commonly used items
 pub use main_tags::JPEG2000_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(capture_resolution_tags::JPEG2000_CAPTURERESOLUTION_TAGS),
+    crate::tables::lazy_table!(color_spec_tags::JPEG2000_COLORSPEC_TAGS),
+    crate::tables::lazy_table!(display_resolution_tags::JPEG2000_DISPLAYRESOLUTION_TAGS),
+    crate::tables::lazy_table!(file_type_tags::JPEG2000_FILETYPE_TAGS),
+    crate::tables::lazy_table!(image_header_tags::JPEG2000_IMAGEHEADER_TAGS),
+    crate::tables::lazy_table!(is_image_data::IS_IMAGE_DATA),
+    crate::tables::lazy_table!(j2c_marker::J2C_MARKER),
+    crate::tables::lazy_table!(jp2map::JP2MAP),
+    crate::tables::lazy_table!(jumd_tags::JPEG2000_JUMD_TAGS),
+    crate::tables::lazy_table!(jxl_map::JXL_MAP),
+    crate::tables::lazy_table!(main_tags::JPEG2000_MAIN_TAGS),
+    crate::tables::lazy_table!(resolution_unit::RESOLUTION_UNIT),
+    crate::tables::lazy_table!(uuid::UUID),
+];
@@ -42,3 +42,44 @@ pub mod unknown_tags;
 
 // Re-export commonly used items
 pub use main_tags::KODAK_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(borders_tags::KODAK_BORDERS_TAGS),
+    crate::tables::lazy_table!(camera_info_tags::KODAK_CAMERAINFO_TAGS),
+    crate::tables::lazy_table!(composite_tags::KODAK_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(dc_em_tags::KODAK_DCEM_TAGS),
+    crate::tables::lazy_table!(dc_md_tags::KODAK_DCMD_TAGS),
+    crate::tables::lazy_table!(dc_me_tags::KODAK_DCME_TAGS),
+    crate::tables::lazy_table!(frea_tags::KODAK_FREA_TAGS),
+    crate::tables::lazy_table!(free_tags::KODAK_FREE_TAGS),
+    crate::tables::lazy_table!(ifd_tags::KODAK_IFD_TAGS),
+    crate::tables::lazy_table!(kdc_ifd_tags::KODAK_KDC_IFD_TAGS),
+    crate::tables::lazy_table!(main_tags::KODAK_MAIN_TAGS),
+    crate::tables::lazy_table!(meta_tags::KODAK_META_TAGS),
+    crate::tables::lazy_table!(mov_tags::KODAK_MOV_TAGS),
+    crate::tables::lazy_table!(pose_tags::KODAK_POSE_TAGS),
+    crate::tables::lazy_table!(processing_tags::KODAK_PROCESSING_TAGS),
+    crate::tables::lazy_table!(scene_mode_used::SCENE_MODE_USED),
+    crate::tables::lazy_table!(scrn_tags::KODAK_SCRN_TAGS),
+    crate::tables::lazy_table!(special_effects_tags::KODAK_SPECIALEFFECTS_TAGS),
+    crate::tables::lazy_table!(sub_ifd0_tags::KODAK_SUBIFD0_TAGS),
+    crate::tables::lazy_table!(sub_ifd1_tags::KODAK_SUBIFD1_TAGS),
+    crate::tables::lazy_table!(sub_ifd2_tags::KODAK_SUBIFD2_TAGS),
+    crate::tables::lazy_table!(sub_ifd3_tags::KODAK_SUBIFD3_TAGS),
+    crate::tables::lazy_table!(sub_ifd4_tags::KODAK_SUBIFD4_TAGS),
+    crate::tables::lazy_table!(sub_ifd5_tags::KODAK_SUBIFD5_TAGS),
+    crate::tables::lazy_table!(sub_ifd6_tags::KODAK_SUBIFD6_TAGS),
+    crate::tables::lazy_table!(textual_info_tags::KODAK_TEXTUALINFO_TAGS),
+    crate::tables::lazy_table!(type10_tags::KODAK_TYPE10_TAGS),
+    crate::tables::lazy_table!(type11_tags::KODAK_TYPE11_TAGS),
+    crate::tables::lazy_table!(type2_tags::KODAK_TYPE2_TAGS),
+    crate::tables::lazy_table!(type3_tags::KODAK_TYPE3_TAGS),
+    crate::tables::lazy_table!(type4_tags::KODAK_TYPE4_TAGS),
+    crate::tables::lazy_table!(type5_tags::KODAK_TYPE5_TAGS),
+    crate::tables::lazy_table!(type6_tags::KODAK_TYPE6_TAGS),
+    crate::tables::lazy_table!(type7_tags::KODAK_TYPE7_TAGS),
+    crate::tables::lazy_table!(type8_tags::KODAK_TYPE8_TAGS),
+    crate::tables::lazy_table!(type9_tags::KODAK_TYPE9_TAGS),
+    crate::tables::lazy_table!(unknown_tags::KODAK_UNKNOWN_TAGS),
+];
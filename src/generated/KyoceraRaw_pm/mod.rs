@@ -6,3 +6,7 @@ pub mod main_tags;
 
 // Re-export commonly used items
 pub use main_tags::KYOCERA_RAW_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] =
+    &[crate::tables::lazy_table!(main_tags::KYOCERA_RAW_MAIN_TAGS)];
@@ -29,3 +29,31 @@ pub mod video_tags;
 
 // Re-export commonly used items
 pub use main_tags::MIE_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(audio_tags::MIE_AUDIO_TAGS),
+    crate::tables::lazy_table!(camera_tags::MIE_CAMERA_TAGS),
+    crate::tables::lazy_table!(canon_tags::MIE_CANON_TAGS),
+    crate::tables::lazy_table!(doc_tags::MIE_DOC_TAGS),
+    crate::tables::lazy_table!(extender_tags::MIE_EXTENDER_TAGS),
+    crate::tables::lazy_table!(flash_tags::MIE_FLASH_TAGS),
+    crate::tables::lazy_table!(geo_tags::MIE_GEO_TAGS),
+    crate::tables::lazy_table!(gps_tags::MIE_GPS_TAGS),
+    crate::tables::lazy_table!(image_tags::MIE_IMAGE_TAGS),
+    crate::tables::lazy_table!(lens_tags::MIE_LENS_TAGS),
+    crate::tables::lazy_table!(main_tags::MIE_MAIN_TAGS),
+    crate::tables::lazy_table!(maker_notes_tags::MIE_MAKERNOTES_TAGS),
+    crate::tables::lazy_table!(meta_tags::MIE_META_TAGS),
+    crate::tables::lazy_table!(mie_format::MIE_FORMAT),
+    crate::tables::lazy_table!(mie_map::MIE_MAP),
+    crate::tables::lazy_table!(no_yes::NO_YES),
+    crate::tables::lazy_table!(off_on::OFF_ON),
+    crate::tables::lazy_table!(orient_tags::MIE_ORIENT_TAGS),
+    crate::tables::lazy_table!(preview_tags::MIE_PREVIEW_TAGS),
+    crate::tables::lazy_table!(table_defaults_tags::MIE_TABLEDEFAULTS_TAGS),
+    crate::tables::lazy_table!(thumbnail_tags::MIE_THUMBNAIL_TAGS),
+    crate::tables::lazy_table!(unknown_tags::MIE_UNKNOWN_TAGS),
+    crate::tables::lazy_table!(utm_tags::MIE_UTM_TAGS),
+    crate::tables::lazy_table!(video_tags::MIE_VIDEO_TAGS),
+];
@@ -9,3 +9,12 @@ pub mod regions_tags;
 pub mod s_region_struct_tags;
 
 // Re-export commonly used items
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(collections_tags::XMP_MWG_COLL_TAGS),
+    crate::tables::lazy_table!(composite_tags::MWG_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(keywords_tags::XMP_MWG_KW_TAGS),
+    crate::tables::lazy_table!(regions_tags::XMP_MWG_RS_TAGS),
+    crate::tables::lazy_table!(s_region_struct_tags::XMP_MWG_RS_TAGS),
+];
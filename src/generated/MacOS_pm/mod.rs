@@ -9,3 +9,11 @@ pub mod x_attr_tags;
 
 // Re-export commonly used items
 pub use main_tags::MAC_OS_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(del_xattr::DEL_XATTR),
+    crate::tables::lazy_table!(main_tags::MAC_OS_MAIN_TAGS),
+    crate::tables::lazy_table!(md_item_tags::MAC_OS_MDITEM_TAGS),
+    crate::tables::lazy_table!(x_attr_tags::MAC_OS_XATTR_TAGS),
+];
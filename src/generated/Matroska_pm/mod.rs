@@ -9,3 +9,11 @@ pub mod std_tag_tags;
 
 // Re-export commonly used items
 pub use main_tags::MATROSKA_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(main_tags::MATROSKA_MAIN_TAGS),
+    crate::tables::lazy_table!(no_yes::NO_YES),
+    crate::tables::lazy_table!(projection_tags::MATROSKA_PROJECTION_TAGS),
+    crate::tables::lazy_table!(std_tag_tags::MATROSKA_STDTAG_TAGS),
+];
@@ -11,3 +11,14 @@ pub mod xmp_tags;
 pub mod xtra_tags;
 
 // Re-export commonly used items
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(code_page::CODE_PAGE),
+    crate::tables::lazy_table!(mp1_tags::XMP_MP1_TAGS),
+    crate::tables::lazy_table!(mp_tags::XMP_MP_TAGS),
+    crate::tables::lazy_table!(s_regions_tags::XMP_MP_REG_TAGS),
+    crate::tables::lazy_table!(stitch_tags::MICROSOFT_STITCH_TAGS),
+    crate::tables::lazy_table!(xmp_tags::XMP_MICROSOFT_PHOTO_TAGS),
+    crate::tables::lazy_table!(xtra_tags::MICROSOFT_XTRA_TAGS),
+];
@@ -9,3 +9,11 @@ pub mod wbg_tags;
 
 // Re-export commonly used items
 pub use main_tags::MINOLTA_RAW_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(main_tags::MINOLTA_RAW_MAIN_TAGS),
+    crate::tables::lazy_table!(prd_tags::MINOLTA_RAW_PRD_TAGS),
+    crate::tables::lazy_table!(rif_tags::MINOLTA_RAW_RIF_TAGS),
+    crate::tables::lazy_table!(wbg_tags::MINOLTA_RAW_WBG_TAGS),
+];
@@ -208,3 +208,207 @@ pub mod xlat_1;
 
 // Re-export commonly used items
 pub use main_tags::NIKON_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(a_farea_mode_cd::A_FAREA_MODE_CD),
+    crate::tables::lazy_table!(a_farea_mode_pd::A_FAREA_MODE_PD),
+    crate::tables::lazy_table!(a_farea_mode_z9::A_FAREA_MODE_Z9),
+    crate::tables::lazy_table!(a_fdetection_method::A_FDETECTION_METHOD),
+    crate::tables::lazy_table!(active_dlighting_z7::ACTIVE_DLIGHTING_Z7),
+    crate::tables::lazy_table!(af_info2v0100_tags::NIKON_AFINFO2V0100_TAGS),
+    crate::tables::lazy_table!(af_info2v0101_tags::NIKON_AFINFO2V0101_TAGS),
+    crate::tables::lazy_table!(af_info2v0200_tags::NIKON_AFINFO2V0200_TAGS),
+    crate::tables::lazy_table!(af_info2v0300_tags::NIKON_AFINFO2V0300_TAGS),
+    crate::tables::lazy_table!(af_info2v0400_tags::NIKON_AFINFO2V0400_TAGS),
+    crate::tables::lazy_table!(af_info_tags::NIKON_AFINFO_TAGS),
+    crate::tables::lazy_table!(af_points105::AF_POINTS105),
+    crate::tables::lazy_table!(af_points135::AF_POINTS135),
+    crate::tables::lazy_table!(af_points153::AF_POINTS153),
+    crate::tables::lazy_table!(af_points39::AF_POINTS39),
+    crate::tables::lazy_table!(af_points51::AF_POINTS51),
+    crate::tables::lazy_table!(af_points81::AF_POINTS81),
+    crate::tables::lazy_table!(af_tune_tags::NIKON_AFTUNE_TAGS),
+    crate::tables::lazy_table!(ast_tags::XMP_AST_TAGS),
+    crate::tables::lazy_table!(auto_capture_info_tags::NIKON_AUTOCAPTUREINFO_TAGS),
+    crate::tables::lazy_table!(avi_tags::NIKON_AVI_TAGS),
+    crate::tables::lazy_table!(avi_tags_tags::NIKON_AVITAGS_TAGS),
+    crate::tables::lazy_table!(avi_vers_tags::NIKON_AVIVERS_TAGS),
+    crate::tables::lazy_table!(banks_z9::BANKS_Z9),
+    crate::tables::lazy_table!(barometer_info_tags::NIKON_BAROMETERINFO_TAGS),
+    crate::tables::lazy_table!(binary_data_attrs_tags::NIKON_BINARYDATAATTRS_TAGS),
+    crate::tables::lazy_table!(bracket_increment_z9::BRACKET_INCREMENT_Z9),
+    crate::tables::lazy_table!(bracket_program_z9::BRACKET_PROGRAM_Z9),
+    crate::tables::lazy_table!(bracket_set_z9::BRACKET_SET_Z9),
+    crate::tables::lazy_table!(bracketing_info_d500_tags::NIKON_BRACKETINGINFOD500_TAGS),
+    crate::tables::lazy_table!(bracketing_info_d810_tags::NIKON_BRACKETINGINFOD810_TAGS),
+    crate::tables::lazy_table!(capture_offsets_tags::NIKON_CAPTUREOFFSETS_TAGS),
+    crate::tables::lazy_table!(capture_output_tags::NIKON_CAPTUREOUTPUT_TAGS),
+    crate::tables::lazy_table!(color_balance1_tags::NIKON_COLORBALANCE1_TAGS),
+    crate::tables::lazy_table!(color_balance2_tags::NIKON_COLORBALANCE2_TAGS),
+    crate::tables::lazy_table!(color_balance3_tags::NIKON_COLORBALANCE3_TAGS),
+    crate::tables::lazy_table!(color_balance4_tags::NIKON_COLORBALANCE4_TAGS),
+    crate::tables::lazy_table!(color_balance_a_tags::NIKON_COLORBALANCEA_TAGS),
+    crate::tables::lazy_table!(color_balance_b_tags::NIKON_COLORBALANCEB_TAGS),
+    crate::tables::lazy_table!(color_balance_c_tags::NIKON_COLORBALANCEC_TAGS),
+    crate::tables::lazy_table!(color_balance_unknown2_tags::NIKON_COLORBALANCEUNKNOWN2_TAGS),
+    crate::tables::lazy_table!(color_balance_unknown_tags::NIKON_COLORBALANCEUNKNOWN_TAGS),
+    crate::tables::lazy_table!(composite_tags::NIKON_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(crop_hi_speed::CROP_HI_SPEED),
+    crate::tables::lazy_table!(custom_settings_d500_tags::NIKON_CUSTOMSETTINGSD500_TAGS),
+    crate::tables::lazy_table!(
+        dials_frame_advance_zoom_position_z9::DIALS_FRAME_ADVANCE_ZOOM_POSITION_Z9
+    ),
+    crate::tables::lazy_table!(distort_info_tags::NIKON_DISTORTINFO_TAGS),
+    crate::tables::lazy_table!(distortion_info_tags::NIKON_DISTORTIONINFO_TAGS),
+    crate::tables::lazy_table!(dynamic_af_area_modes_z9::DYNAMIC_AF_AREA_MODES_Z9),
+    crate::tables::lazy_table!(face_detect_tags::NIKON_FACEDETECT_TAGS),
+    crate::tables::lazy_table!(file_info_tags::NIKON_FILEINFO_TAGS),
+    crate::tables::lazy_table!(flash_color_filter::FLASH_COLOR_FILTER),
+    crate::tables::lazy_table!(flash_control_mode::FLASH_CONTROL_MODE),
+    crate::tables::lazy_table!(flash_control_mode_z7::FLASH_CONTROL_MODE_Z7),
+    crate::tables::lazy_table!(flash_firmware::FLASH_FIRMWARE),
+    crate::tables::lazy_table!(flash_group_options_mode::FLASH_GROUP_OPTIONS_MODE),
+    crate::tables::lazy_table!(flash_info0100_tags::NIKON_FLASHINFO0100_TAGS),
+    crate::tables::lazy_table!(flash_info0102_tags::NIKON_FLASHINFO0102_TAGS),
+    crate::tables::lazy_table!(flash_info0103_tags::NIKON_FLASHINFO0103_TAGS),
+    crate::tables::lazy_table!(flash_info0106_tags::NIKON_FLASHINFO0106_TAGS),
+    crate::tables::lazy_table!(flash_info0107_tags::NIKON_FLASHINFO0107_TAGS),
+    crate::tables::lazy_table!(flash_info0300_tags::NIKON_FLASHINFO0300_TAGS),
+    crate::tables::lazy_table!(flash_info_unknown_tags::NIKON_FLASHINFOUNKNOWN_TAGS),
+    crate::tables::lazy_table!(flash_remote_control_z7::FLASH_REMOTE_CONTROL_Z7),
+    crate::tables::lazy_table!(flash_wireless_option_z7::FLASH_WIRELESS_OPTION_Z7),
+    crate::tables::lazy_table!(focus_mode_z7::FOCUS_MODE_Z7),
+    crate::tables::lazy_table!(gem_tags::NIKON_GEM_TAGS),
+    crate::tables::lazy_table!(h_dmi_output_resolution_z9::H_DMI_OUTPUT_RESOLUTION_Z9),
+    crate::tables::lazy_table!(hdr_info2_tags::NIKON_HDRINFO2_TAGS),
+    crate::tables::lazy_table!(hdr_info_tags::NIKON_HDRINFO_TAGS),
+    crate::tables::lazy_table!(hdr_level_z8::HDR_LEVEL_Z8),
+    crate::tables::lazy_table!(high_frame_rate_z9::HIGH_FRAME_RATE_Z9),
+    crate::tables::lazy_table!(i_so_auto_hi_limit_z6iii::I_SO_AUTO_HI_LIMIT_Z6III),
+    crate::tables::lazy_table!(i_so_auto_shutter_time_z9::I_SO_AUTO_SHUTTER_TIME_Z9),
+    crate::tables::lazy_table!(image_area_d6::IMAGE_AREA_D6),
+    crate::tables::lazy_table!(image_area_z9::IMAGE_AREA_Z9),
+    crate::tables::lazy_table!(image_area_z9b::IMAGE_AREA_Z9B),
+    crate::tables::lazy_table!(interval_info_d6_tags::NIKON_INTERVALINFOD6_TAGS),
+    crate::tables::lazy_table!(interval_info_z7ii_tags::NIKON_INTERVALINFOZ7II_TAGS),
+    crate::tables::lazy_table!(iso_auto_info_d810_tags::NIKON_ISOAUTOINFOD810_TAGS),
+    crate::tables::lazy_table!(iso_info_tags::NIKON_ISOINFO_TAGS),
+    crate::tables::lazy_table!(jpg_info_d500_tags::NIKON_JPGINFOD500_TAGS),
+    crate::tables::lazy_table!(language_z9::LANGUAGE_Z9),
+    crate::tables::lazy_table!(lens_data00_tags::NIKON_LENSDATA00_TAGS),
+    crate::tables::lazy_table!(lens_data01_tags::NIKON_LENSDATA01_TAGS),
+    crate::tables::lazy_table!(lens_data0204_tags::NIKON_LENSDATA0204_TAGS),
+    crate::tables::lazy_table!(lens_data0400_tags::NIKON_LENSDATA0400_TAGS),
+    crate::tables::lazy_table!(lens_data0402_tags::NIKON_LENSDATA0402_TAGS),
+    crate::tables::lazy_table!(lens_data0403_tags::NIKON_LENSDATA0403_TAGS),
+    crate::tables::lazy_table!(lens_data0800_tags::NIKON_LENSDATA0800_TAGS),
+    crate::tables::lazy_table!(lens_data_unknown_tags::NIKON_LENSDATAUNKNOWN_TAGS),
+    crate::tables::lazy_table!(location_info_tags::NIKON_LOCATIONINFO_TAGS),
+    crate::tables::lazy_table!(main_tags::NIKON_MAIN_TAGS),
+    crate::tables::lazy_table!(maker_notes0x51_tags::NIKON_MAKERNOTES0X51_TAGS),
+    crate::tables::lazy_table!(maker_notes0x56_tags::NIKON_MAKERNOTES0X56_TAGS),
+    crate::tables::lazy_table!(menu_info_z7ii_tags::NIKON_MENUINFOZ7II_TAGS),
+    crate::tables::lazy_table!(menu_info_z8_tags::NIKON_MENUINFOZ8_TAGS),
+    crate::tables::lazy_table!(menu_info_z9_tags::NIKON_MENUINFOZ9_TAGS),
+    crate::tables::lazy_table!(menu_settings_d850_tags::NIKON_MENUSETTINGSD850_TAGS),
+    crate::tables::lazy_table!(menu_settings_z6iii_tags::NIKON_MENUSETTINGSZ6III_TAGS),
+    crate::tables::lazy_table!(menu_settings_z7ii_tags::NIKON_MENUSETTINGSZ7II_TAGS),
+    crate::tables::lazy_table!(menu_settings_z8_tags::NIKON_MENUSETTINGSZ8_TAGS),
+    crate::tables::lazy_table!(menu_settings_z8v1_tags::NIKON_MENUSETTINGSZ8V1_TAGS),
+    crate::tables::lazy_table!(menu_settings_z8v2_tags::NIKON_MENUSETTINGSZ8V2_TAGS),
+    crate::tables::lazy_table!(menu_settings_z9_tags::NIKON_MENUSETTINGSZ9_TAGS),
+    crate::tables::lazy_table!(menu_settings_z9v3_tags::NIKON_MENUSETTINGSZ9V3_TAGS),
+    crate::tables::lazy_table!(menu_settings_z9v4_tags::NIKON_MENUSETTINGSZ9V4_TAGS),
+    crate::tables::lazy_table!(metering_mode_z7::METERING_MODE_Z7),
+    crate::tables::lazy_table!(monitor_brightness_z9::MONITOR_BRIGHTNESS_Z9),
+    crate::tables::lazy_table!(more_settings_d850_tags::NIKON_MORESETTINGSD850_TAGS),
+    crate::tables::lazy_table!(mov_tags::NIKON_MOV_TAGS),
+    crate::tables::lazy_table!(movie_flicker_reduction_z9::MOVIE_FLICKER_REDUCTION_Z9),
+    crate::tables::lazy_table!(movie_frame_rate_z6iii::MOVIE_FRAME_RATE_Z6III),
+    crate::tables::lazy_table!(movie_frame_rate_z7::MOVIE_FRAME_RATE_Z7),
+    crate::tables::lazy_table!(movie_frame_size_z9::MOVIE_FRAME_SIZE_Z9),
+    crate::tables::lazy_table!(movie_slow_motion::MOVIE_SLOW_MOTION),
+    crate::tables::lazy_table!(movie_tone_map_z9::MOVIE_TONE_MAP_Z9),
+    crate::tables::lazy_table!(movie_type_z9::MOVIE_TYPE_Z9),
+    crate::tables::lazy_table!(multi_exposure2_tags::NIKON_MULTIEXPOSURE2_TAGS),
+    crate::tables::lazy_table!(multi_exposure_tags::NIKON_MULTIEXPOSURE_TAGS),
+    crate::tables::lazy_table!(multiple_exposure_mode_z9::MULTIPLE_EXPOSURE_MODE_Z9),
+    crate::tables::lazy_table!(ncdb_tags::NIKON_NCDB_TAGS),
+    crate::tables::lazy_table!(ncdt_tags::NIKON_NCDT_TAGS),
+    crate::tables::lazy_table!(nctg_tags::NIKON_NCTG_TAGS),
+    crate::tables::lazy_table!(nef_compression::NEF_COMPRESSION),
+    crate::tables::lazy_table!(nef_info_tags::NIKON_NEFINFO_TAGS),
+    crate::tables::lazy_table!(nikon_lens_ids::NIKON_LENS_IDS),
+    crate::tables::lazy_table!(nikon_text_encoding::NIKON_TEXT_ENCODING),
+    crate::tables::lazy_table!(nine_edits_tags::NIKON_NINEEDITS_TAGS),
+    crate::tables::lazy_table!(nine_tags::XMP_NINE_TAGS),
+    crate::tables::lazy_table!(no_yes::NO_YES),
+    crate::tables::lazy_table!(off_low_normal_high_z7::OFF_LOW_NORMAL_HIGH_Z7),
+    crate::tables::lazy_table!(off_on::OFF_ON),
+    crate::tables::lazy_table!(offset13info_z9_tags::NIKON_OFFSET13INFOZ9_TAGS),
+    crate::tables::lazy_table!(on_off::ON_OFF),
+    crate::tables::lazy_table!(orientation_info_tags::NIKON_ORIENTATIONINFO_TAGS),
+    crate::tables::lazy_table!(other_info_d500_tags::NIKON_OTHERINFOD500_TAGS),
+    crate::tables::lazy_table!(picture_control2_tags::NIKON_PICTURECONTROL2_TAGS),
+    crate::tables::lazy_table!(picture_control3_tags::NIKON_PICTURECONTROL3_TAGS),
+    crate::tables::lazy_table!(picture_control_tags::NIKON_PICTURECONTROL_TAGS),
+    crate::tables::lazy_table!(picture_control_unknown_tags::NIKON_PICTURECONTROLUNKNOWN_TAGS),
+    crate::tables::lazy_table!(pixel_shift_delay::PIXEL_SHIFT_DELAY),
+    crate::tables::lazy_table!(pixel_shift_number_shots::PIXEL_SHIFT_NUMBER_SHOTS),
+    crate::tables::lazy_table!(portrait_impression_balance_z8::PORTRAIT_IMPRESSION_BALANCE_Z8),
+    crate::tables::lazy_table!(portrait_info_z7ii_tags::NIKON_PORTRAITINFOZ7II_TAGS),
+    crate::tables::lazy_table!(preview_ifd_tags::NIKON_PREVIEWIFD_TAGS),
+    crate::tables::lazy_table!(release_mode_z7::RELEASE_MODE_Z7),
+    crate::tables::lazy_table!(retouch_info_tags::NIKON_RETOUCHINFO_TAGS),
+    crate::tables::lazy_table!(retouch_values::RETOUCH_VALUES),
+    crate::tables::lazy_table!(roc_tags::NIKON_ROC_TAGS),
+    crate::tables::lazy_table!(rotation_info_d500_tags::NIKON_ROTATIONINFOD500_TAGS),
+    crate::tables::lazy_table!(scan_tags::NIKON_SCAN_TAGS),
+    crate::tables::lazy_table!(sdc_tags::XMP_SDC_TAGS),
+    crate::tables::lazy_table!(secondary_slot_function_z9::SECONDARY_SLOT_FUNCTION_Z9),
+    crate::tables::lazy_table!(seq_info_d6_tags::NIKON_SEQINFOD6_TAGS),
+    crate::tables::lazy_table!(seq_info_z9_tags::NIKON_SEQINFOZ9_TAGS),
+    crate::tables::lazy_table!(settings_info_d810_tags::NIKON_SETTINGSINFOD810_TAGS),
+    crate::tables::lazy_table!(shooting_menu_d500_tags::NIKON_SHOOTINGMENUD500_TAGS),
+    crate::tables::lazy_table!(shot_info_d300a_tags::NIKON_SHOTINFOD300A_TAGS),
+    crate::tables::lazy_table!(shot_info_d300b_tags::NIKON_SHOTINFOD300B_TAGS),
+    crate::tables::lazy_table!(shot_info_d300s_tags::NIKON_SHOTINFOD300S_TAGS),
+    crate::tables::lazy_table!(shot_info_d3a_tags::NIKON_SHOTINFOD3A_TAGS),
+    crate::tables::lazy_table!(shot_info_d3b_tags::NIKON_SHOTINFOD3B_TAGS),
+    crate::tables::lazy_table!(shot_info_d3s_tags::NIKON_SHOTINFOD3S_TAGS),
+    crate::tables::lazy_table!(shot_info_d3x_tags::NIKON_SHOTINFOD3X_TAGS),
+    crate::tables::lazy_table!(shot_info_d40_tags::NIKON_SHOTINFOD40_TAGS),
+    crate::tables::lazy_table!(shot_info_d4_tags::NIKON_SHOTINFOD4_TAGS),
+    crate::tables::lazy_table!(shot_info_d4s_tags::NIKON_SHOTINFOD4S_TAGS),
+    crate::tables::lazy_table!(shot_info_d5000_tags::NIKON_SHOTINFOD5000_TAGS),
+    crate::tables::lazy_table!(shot_info_d500_tags::NIKON_SHOTINFOD500_TAGS),
+    crate::tables::lazy_table!(shot_info_d5100_tags::NIKON_SHOTINFOD5100_TAGS),
+    crate::tables::lazy_table!(shot_info_d5200_tags::NIKON_SHOTINFOD5200_TAGS),
+    crate::tables::lazy_table!(shot_info_d610_tags::NIKON_SHOTINFOD610_TAGS),
+    crate::tables::lazy_table!(shot_info_d6_tags::NIKON_SHOTINFOD6_TAGS),
+    crate::tables::lazy_table!(shot_info_d7000_tags::NIKON_SHOTINFOD7000_TAGS),
+    crate::tables::lazy_table!(shot_info_d700_tags::NIKON_SHOTINFOD700_TAGS),
+    crate::tables::lazy_table!(shot_info_d7500_tags::NIKON_SHOTINFOD7500_TAGS),
+    crate::tables::lazy_table!(shot_info_d780_tags::NIKON_SHOTINFOD780_TAGS),
+    crate::tables::lazy_table!(shot_info_d800_tags::NIKON_SHOTINFOD800_TAGS),
+    crate::tables::lazy_table!(shot_info_d80_tags::NIKON_SHOTINFOD80_TAGS),
+    crate::tables::lazy_table!(shot_info_d810_tags::NIKON_SHOTINFOD810_TAGS),
+    crate::tables::lazy_table!(shot_info_d850_tags::NIKON_SHOTINFOD850_TAGS),
+    crate::tables::lazy_table!(shot_info_d90_tags::NIKON_SHOTINFOD90_TAGS),
+    crate::tables::lazy_table!(shot_info_tags::NIKON_SHOTINFO_TAGS),
+    crate::tables::lazy_table!(shot_info_z6iii_tags::NIKON_SHOTINFOZ6III_TAGS),
+    crate::tables::lazy_table!(shot_info_z7ii_tags::NIKON_SHOTINFOZ7II_TAGS),
+    crate::tables::lazy_table!(shot_info_z8_tags::NIKON_SHOTINFOZ8_TAGS),
+    crate::tables::lazy_table!(shot_info_z9_tags::NIKON_SHOTINFOZ9_TAGS),
+    crate::tables::lazy_table!(subject_detection_area_mz6iii::SUBJECT_DETECTION_AREA_MZ6III),
+    crate::tables::lazy_table!(subject_detection_z9::SUBJECT_DETECTION_Z9),
+    crate::tables::lazy_table!(time_zone_z9::TIME_ZONE_Z9),
+    crate::tables::lazy_table!(type2_tags::NIKON_TYPE2_TAGS),
+    crate::tables::lazy_table!(unknown_info2_tags::NIKON_UNKNOWNINFO2_TAGS),
+    crate::tables::lazy_table!(unknown_info_tags::NIKON_UNKNOWNINFO_TAGS),
+    crate::tables::lazy_table!(v_rmode_z9::V_RMODE_Z9),
+    crate::tables::lazy_table!(vignette_info_tags::NIKON_VIGNETTEINFO_TAGS),
+    crate::tables::lazy_table!(vr_info_tags::NIKON_VRINFO_TAGS),
+    crate::tables::lazy_table!(world_time_tags::NIKON_WORLDTIME_TAGS),
+];
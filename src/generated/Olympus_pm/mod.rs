@@ -42,3 +42,44 @@ pub mod wav_tags;
 
 // Re-export commonly used items
 pub use main_tags::OLYMPUS_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(af_info_tags::OLYMPUS_AFINFO_TAGS),
+    crate::tables::lazy_table!(af_target_info_tags::OLYMPUS_AFTARGETINFO_TAGS),
+    crate::tables::lazy_table!(avi_tags::OLYMPUS_AVI_TAGS),
+    crate::tables::lazy_table!(camera_settings_tags::OLYMPUS_CAMERASETTINGS_TAGS),
+    crate::tables::lazy_table!(composite_tags::OLYMPUS_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(dss_tags::OLYMPUS_DSS_TAGS),
+    crate::tables::lazy_table!(equipment_tags::OLYMPUS_EQUIPMENT_TAGS),
+    crate::tables::lazy_table!(fe_tags_tags::OLYMPUS_FETAGS_TAGS),
+    crate::tables::lazy_table!(filters::FILTERS),
+    crate::tables::lazy_table!(focus_info_tags::OLYMPUS_FOCUSINFO_TAGS),
+    crate::tables::lazy_table!(image_processing_tags::OLYMPUS_IMAGEPROCESSING_TAGS),
+    crate::tables::lazy_table!(main_tags::OLYMPUS_MAIN_TAGS),
+    crate::tables::lazy_table!(mov1_tags::OLYMPUS_MOV1_TAGS),
+    crate::tables::lazy_table!(mov2_tags::OLYMPUS_MOV2_TAGS),
+    crate::tables::lazy_table!(mov3_tags::OLYMPUS_MOV3_TAGS),
+    crate::tables::lazy_table!(movable_info_tags::OLYMPUS_MOVABLEINFO_TAGS),
+    crate::tables::lazy_table!(mp4_tags::OLYMPUS_MP4_TAGS),
+    crate::tables::lazy_table!(off_on::OFF_ON),
+    crate::tables::lazy_table!(olym2_tags::OLYMPUS_OLYM2_TAGS),
+    crate::tables::lazy_table!(olym_tags::OLYMPUS_OLYM_TAGS),
+    crate::tables::lazy_table!(olympus_camera_types::OLYMPUS_CAMERA_TYPES),
+    crate::tables::lazy_table!(olympus_lens_types::OLYMPUS_LENS_TYPES),
+    crate::tables::lazy_table!(prms_tags::OLYMPUS_PRMS_TAGS),
+    crate::tables::lazy_table!(raw_dev_sub_ifd_tags::OLYMPUS_RAWDEVSUBIFD_TAGS),
+    crate::tables::lazy_table!(raw_development2_tags::OLYMPUS_RAWDEVELOPMENT2_TAGS),
+    crate::tables::lazy_table!(raw_development_tags::OLYMPUS_RAWDEVELOPMENT_TAGS),
+    crate::tables::lazy_table!(raw_info_tags::OLYMPUS_RAWINFO_TAGS),
+    crate::tables::lazy_table!(scrn2_tags::OLYMPUS_SCRN2_TAGS),
+    crate::tables::lazy_table!(scrn_tags::OLYMPUS_SCRN_TAGS),
+    crate::tables::lazy_table!(subject_detect_info_tags::OLYMPUS_SUBJECTDETECTINFO_TAGS),
+    crate::tables::lazy_table!(text_info_tags::OLYMPUS_TEXTINFO_TAGS),
+    crate::tables::lazy_table!(thmb2_tags::OLYMPUS_THMB2_TAGS),
+    crate::tables::lazy_table!(thmb_tags::OLYMPUS_THMB_TAGS),
+    crate::tables::lazy_table!(thumbnail_tags::OLYMPUS_THUMBNAIL_TAGS),
+    crate::tables::lazy_table!(tone_level_type::TONE_LEVEL_TYPE),
+    crate::tables::lazy_table!(unknown_info_tags::OLYMPUS_UNKNOWNINFO_TAGS),
+    crate::tables::lazy_table!(wav_tags::OLYMPUS_WAV_TAGS),
+];
@@ -20,3 +20,22 @@ pub mod virtual_page_tags;
 
 // Re-export commonly used items
 pub use main_tags::PNG_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(animation_control_tags::PNG_ANIMATIONCONTROL_TAGS),
+    crate::tables::lazy_table!(ci_code_points_tags::PNG_CICODEPOINTS_TAGS),
+    crate::tables::lazy_table!(image_header_tags::PNG_IMAGEHEADER_TAGS),
+    crate::tables::lazy_table!(is_txt_chunk::IS_TXT_CHUNK),
+    crate::tables::lazy_table!(main_tags::PNG_MAIN_TAGS),
+    crate::tables::lazy_table!(physical_pixel_tags::PNG_PHYSICALPIXEL_TAGS),
+    crate::tables::lazy_table!(png_map::PNG_MAP),
+    crate::tables::lazy_table!(primary_chromaticities_tags::PNG_PRIMARYCHROMATICITIES_TAGS),
+    crate::tables::lazy_table!(std_case::STD_CASE),
+    crate::tables::lazy_table!(stereo_image_tags::PNG_STEREOIMAGE_TAGS),
+    crate::tables::lazy_table!(subject_scale_tags::PNG_SUBJECTSCALE_TAGS),
+    crate::tables::lazy_table!(textual_data_tags::PNG_TEXTUALDATA_TAGS),
+    crate::tables::lazy_table!(tz_conv::TZ_CONV),
+    crate::tables::lazy_table!(unreg::UNREG),
+    crate::tables::lazy_table!(virtual_page_tags::PNG_VIRTUALPAGE_TAGS),
+];
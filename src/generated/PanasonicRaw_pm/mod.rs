@@ -13,3 +13,15 @@ pub mod wb_info_tags;
 
 // Re-export commonly used items
 pub use main_tags::PANASONIC_RAW_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(camera_ifd_tags::PANASONIC_RAW_CAMERAIFD_TAGS),
+    crate::tables::lazy_table!(composite_tags::PANASONIC_RAW_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(distortion_info_tags::PANASONIC_RAW_DISTORTIONINFO_TAGS),
+    crate::tables::lazy_table!(jpg_from_raw_map::JPG_FROM_RAW_MAP),
+    crate::tables::lazy_table!(main_tags::PANASONIC_RAW_MAIN_TAGS),
+    crate::tables::lazy_table!(panasonic_white_balance::PANASONIC_WHITE_BALANCE),
+    crate::tables::lazy_table!(wb_info2_tags::PANASONIC_RAW_WBINFO2_TAGS),
+    crate::tables::lazy_table!(wb_info_tags::PANASONIC_RAW_WBINFO_TAGS),
+];
@@ -27,3 +27,29 @@ pub mod type2_tags;
 
 // Re-export commonly used items
 pub use main_tags::PANASONIC_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(composite_tags::PANASONIC_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(data1_tags::PANASONIC_DATA1_TAGS),
+    crate::tables::lazy_table!(data2_tags::PANASONIC_DATA2_TAGS),
+    crate::tables::lazy_table!(dsa_tags::XMP_XMP_DSA_TAGS),
+    crate::tables::lazy_table!(face_det_info_tags::PANASONIC_FACEDETINFO_TAGS),
+    crate::tables::lazy_table!(face_rec_info_tags::PANASONIC_FACERECINFO_TAGS),
+    crate::tables::lazy_table!(focus_info_tags::PANASONIC_FOCUSINFO_TAGS),
+    crate::tables::lazy_table!(leica2_tags::PANASONIC_LEICA2_TAGS),
+    crate::tables::lazy_table!(leica3_tags::PANASONIC_LEICA3_TAGS),
+    crate::tables::lazy_table!(leica4_tags::PANASONIC_LEICA4_TAGS),
+    crate::tables::lazy_table!(leica5_tags::PANASONIC_LEICA5_TAGS),
+    crate::tables::lazy_table!(leica6_tags::PANASONIC_LEICA6_TAGS),
+    crate::tables::lazy_table!(leica9_tags::PANASONIC_LEICA9_TAGS),
+    crate::tables::lazy_table!(leica_lens_types::LEICA_LENS_TYPES),
+    crate::tables::lazy_table!(main_tags::PANASONIC_MAIN_TAGS),
+    crate::tables::lazy_table!(pana_tags::PANASONIC_PANA_TAGS),
+    crate::tables::lazy_table!(serial_info_tags::PANASONIC_SERIALINFO_TAGS),
+    crate::tables::lazy_table!(shooting_mode::SHOOTING_MODE),
+    crate::tables::lazy_table!(shot_info_tags::PANASONIC_SHOTINFO_TAGS),
+    crate::tables::lazy_table!(subdir_tags::PANASONIC_SUBDIR_TAGS),
+    crate::tables::lazy_table!(time_info_tags::PANASONIC_TIMEINFO_TAGS),
+    crate::tables::lazy_table!(type2_tags::PANASONIC_TYPE2_TAGS),
+];
@@ -63,3 +63,64 @@ pub mod wb_levels_tags;
 
 // Re-export commonly used items
 pub use main_tags::PENTAX_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(ae_info2_tags::PENTAX_AEINFO2_TAGS),
+    crate::tables::lazy_table!(ae_info3_tags::PENTAX_AEINFO3_TAGS),
+    crate::tables::lazy_table!(ae_info_tags::PENTAX_AEINFO_TAGS),
+    crate::tables::lazy_table!(ae_info_unknown_tags::PENTAX_AEINFOUNKNOWN_TAGS),
+    crate::tables::lazy_table!(af_info_k3iii_tags::PENTAX_AFINFOK3III_TAGS),
+    crate::tables::lazy_table!(af_info_tags::PENTAX_AFINFO_TAGS),
+    crate::tables::lazy_table!(af_point_info_tags::PENTAX_AFPOINTINFO_TAGS),
+    crate::tables::lazy_table!(avi_tags::PENTAX_AVI_TAGS),
+    crate::tables::lazy_table!(awb_info_tags::PENTAX_AWBINFO_TAGS),
+    crate::tables::lazy_table!(battery_info_tags::PENTAX_BATTERYINFO_TAGS),
+    crate::tables::lazy_table!(binary_data_attrs_tags::PENTAX_BINARYDATAATTRS_TAGS),
+    crate::tables::lazy_table!(caf_point_info_tags::PENTAX_CAFPOINTINFO_TAGS),
+    crate::tables::lazy_table!(camera_info_tags::PENTAX_CAMERAINFO_TAGS),
+    crate::tables::lazy_table!(camera_settings_tags::PENTAX_CAMERASETTINGS_TAGS),
+    crate::tables::lazy_table!(camera_settings_unknown_tags::PENTAX_CAMERASETTINGSUNKNOWN_TAGS),
+    crate::tables::lazy_table!(color_info_tags::PENTAX_COLORINFO_TAGS),
+    crate::tables::lazy_table!(ev_step_info_tags::PENTAX_EVSTEPINFO_TAGS),
+    crate::tables::lazy_table!(face_info_k3iii_tags::PENTAX_FACEINFOK3III_TAGS),
+    crate::tables::lazy_table!(face_info_tags::PENTAX_FACEINFO_TAGS),
+    crate::tables::lazy_table!(face_pos_tags::PENTAX_FACEPOS_TAGS),
+    crate::tables::lazy_table!(face_size_tags::PENTAX_FACESIZE_TAGS),
+    crate::tables::lazy_table!(filter_info_tags::PENTAX_FILTERINFO_TAGS),
+    crate::tables::lazy_table!(flash_info_tags::PENTAX_FLASHINFO_TAGS),
+    crate::tables::lazy_table!(flash_info_unknown_tags::PENTAX_FLASHINFOUNKNOWN_TAGS),
+    crate::tables::lazy_table!(junk2_tags::PENTAX_JUNK2_TAGS),
+    crate::tables::lazy_table!(junk_tags::PENTAX_JUNK_TAGS),
+    crate::tables::lazy_table!(kelvin_wb_tags::PENTAX_KELVINWB_TAGS),
+    crate::tables::lazy_table!(lens_corr_tags::PENTAX_LENSCORR_TAGS),
+    crate::tables::lazy_table!(lens_data_tags::PENTAX_LENSDATA_TAGS),
+    crate::tables::lazy_table!(lens_info2_tags::PENTAX_LENSINFO2_TAGS),
+    crate::tables::lazy_table!(lens_info3_tags::PENTAX_LENSINFO3_TAGS),
+    crate::tables::lazy_table!(lens_info4_tags::PENTAX_LENSINFO4_TAGS),
+    crate::tables::lazy_table!(lens_info5_tags::PENTAX_LENSINFO5_TAGS),
+    crate::tables::lazy_table!(lens_info_q_tags::PENTAX_LENSINFOQ_TAGS),
+    crate::tables::lazy_table!(lens_info_tags::PENTAX_LENSINFO_TAGS),
+    crate::tables::lazy_table!(lens_rec_tags::PENTAX_LENSREC_TAGS),
+    crate::tables::lazy_table!(level_info_k3iii_tags::PENTAX_LEVELINFOK3III_TAGS),
+    crate::tables::lazy_table!(level_info_tags::PENTAX_LEVELINFO_TAGS),
+    crate::tables::lazy_table!(main_tags::PENTAX_MAIN_TAGS),
+    crate::tables::lazy_table!(mov_tags::PENTAX_MOV_TAGS),
+    crate::tables::lazy_table!(no_yes::NO_YES),
+    crate::tables::lazy_table!(pent_tags::PENTAX_PENT_TAGS),
+    crate::tables::lazy_table!(pentax_cities::PENTAX_CITIES),
+    crate::tables::lazy_table!(pentax_lens_types::PENTAX_LENS_TYPES),
+    crate::tables::lazy_table!(pentax_model_id::PENTAX_MODEL_ID),
+    crate::tables::lazy_table!(pixel_shift_info_tags::PENTAX_PIXELSHIFTINFO_TAGS),
+    crate::tables::lazy_table!(pxth_tags::PENTAX_PXTH_TAGS),
+    crate::tables::lazy_table!(s1_tags::PENTAX_S1_TAGS),
+    crate::tables::lazy_table!(shot_info_tags::PENTAX_SHOTINFO_TAGS),
+    crate::tables::lazy_table!(sr_info2_tags::PENTAX_SRINFO2_TAGS),
+    crate::tables::lazy_table!(sr_info_tags::PENTAX_SRINFO_TAGS),
+    crate::tables::lazy_table!(temp_info_tags::PENTAX_TEMPINFO_TAGS),
+    crate::tables::lazy_table!(time_info_tags::PENTAX_TIMEINFO_TAGS),
+    crate::tables::lazy_table!(type2_tags::PENTAX_TYPE2_TAGS),
+    crate::tables::lazy_table!(type4_tags::PENTAX_TYPE4_TAGS),
+    crate::tables::lazy_table!(unknown_info_tags::PENTAX_UNKNOWNINFO_TAGS),
+    crate::tables::lazy_table!(wb_levels_tags::PENTAX_WBLEVELS_TAGS),
+];
@@ -20,3 +20,22 @@ pub mod version_info_tags;
 
 // Re-export commonly used items
 pub use main_tags::PHOTOSHOP_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(channel_options_tags::PHOTOSHOP_CHANNELOPTIONS_TAGS),
+    crate::tables::lazy_table!(document_data_tags::PHOTOSHOP_DOCUMENTDATA_TAGS),
+    crate::tables::lazy_table!(header_tags::PHOTOSHOP_HEADER_TAGS),
+    crate::tables::lazy_table!(image_data_tags::PHOTOSHOP_IMAGEDATA_TAGS),
+    crate::tables::lazy_table!(jpeg_quality_tags::PHOTOSHOP_JPEG_QUALITY_TAGS),
+    crate::tables::lazy_table!(layers_tags::PHOTOSHOP_LAYERS_TAGS),
+    crate::tables::lazy_table!(main_tags::PHOTOSHOP_MAIN_TAGS),
+    crate::tables::lazy_table!(pixel_info_tags::PHOTOSHOP_PIXELINFO_TAGS),
+    crate::tables::lazy_table!(print_flags::PRINT_FLAGS),
+    crate::tables::lazy_table!(print_scale_info_tags::PHOTOSHOP_PRINTSCALEINFO_TAGS),
+    crate::tables::lazy_table!(psd_map::PSD_MAP),
+    crate::tables::lazy_table!(resolution_tags::PHOTOSHOP_RESOLUTION_TAGS),
+    crate::tables::lazy_table!(slice_info_tags::PHOTOSHOP_SLICEINFO_TAGS),
+    crate::tables::lazy_table!(unknown_tags::PHOTOSHOP_UNKNOWN_TAGS),
+    crate::tables::lazy_table!(version_info_tags::PHOTOSHOP_VERSIONINFO_TAGS),
+];
@@ -106,3 +106,161 @@ pub mod wave_tags;
 
 // Re-export commonly used items
 pub use main_tags::QUICK_TIME_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(audio_header_tags::QUICK_TIME_AUDIOHEADER_TAGS),
+    crate::tables::lazy_table!(audio_keys_tags::QUICK_TIME_AUDIOKEYS_TAGS),
+    crate::tables::lazy_table!(audio_keys_tags::QUICK_TIME_AUDIOKEYS_TAGS_BY_NAME),
+    crate::tables::lazy_table!(audio_prof_tags::QUICK_TIME_AUDIOPROF_TAGS),
+    crate::tables::lazy_table!(audio_sample_desc_tags::QUICK_TIME_AUDIOSAMPLEDESC_TAGS),
+    crate::tables::lazy_table!(audio_sample_desc_tags::QUICK_TIME_AUDIOSAMPLEDESC_TAGS_BY_NAME),
+    crate::tables::lazy_table!(av1config_tags::QUICK_TIME_AV1CONFIG_TAGS),
+    crate::tables::lazy_table!(av1config_tags::QUICK_TIME_AV1CONFIG_TAGS_BY_NAME),
+    crate::tables::lazy_table!(av_type::AV_TYPE),
+    crate::tables::lazy_table!(bitrate_tags::QUICK_TIME_BITRATE_TAGS),
+    crate::tables::lazy_table!(c_movie_tags::QUICK_TIME_CMOVIE_TAGS),
+    crate::tables::lazy_table!(c_movie_tags::QUICK_TIME_CMOVIE_TAGS_BY_NAME),
+    crate::tables::lazy_table!(cbmp_tags::QUICK_TIME_CBMP_TAGS),
+    crate::tables::lazy_table!(channel_label::CHANNEL_LABEL),
+    crate::tables::lazy_table!(channel_layout_tags::QUICK_TIME_CHANNELLAYOUT_TAGS),
+    crate::tables::lazy_table!(clean_aperture_tags::QUICK_TIME_CLEANAPERTURE_TAGS),
+    crate::tables::lazy_table!(color_rep_tags::QUICK_TIME_COLORREP_TAGS),
+    crate::tables::lazy_table!(composite_tags::QUICK_TIME_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(content_light_level_tags::QUICK_TIME_CONTENTLIGHTLEVEL_TAGS),
+    crate::tables::lazy_table!(data_info_tags::QUICK_TIME_DATAINFO_TAGS),
+    crate::tables::lazy_table!(data_info_tags::QUICK_TIME_DATAINFO_TAGS_BY_NAME),
+    crate::tables::lazy_table!(data_ref_tags::QUICK_TIME_DATAREF_TAGS),
+    crate::tables::lazy_table!(data_ref_tags::QUICK_TIME_DATAREF_TAGS_BY_NAME),
+    crate::tables::lazy_table!(decode_config_tags::QUICK_TIME_DECODECONFIG_TAGS),
+    crate::tables::lazy_table!(dup_dir_ok::DUP_DIR_O_K),
+    crate::tables::lazy_table!(dup_tag_ok::DUP_TAG_O_K),
+    crate::tables::lazy_table!(ee_std::EE_STD),
+    crate::tables::lazy_table!(encoding_params_tags::QUICK_TIME_ENCODINGPARAMS_TAGS),
+    crate::tables::lazy_table!(encoding_params_tags::QUICK_TIME_ENCODINGPARAMS_TAGS_BY_NAME),
+    crate::tables::lazy_table!(equi_tags::QUICK_TIME_EQUI_TAGS),
+    crate::tables::lazy_table!(face_info_tags::QUICK_TIME_FACEINFO_TAGS),
+    crate::tables::lazy_table!(face_info_tags::QUICK_TIME_FACEINFO_TAGS_BY_NAME),
+    crate::tables::lazy_table!(face_rec_tags::QUICK_TIME_FACEREC_TAGS),
+    crate::tables::lazy_table!(face_rec_tags::QUICK_TIME_FACEREC_TAGS_BY_NAME),
+    crate::tables::lazy_table!(file_prof_tags::QUICK_TIME_FILEPROF_TAGS),
+    crate::tables::lazy_table!(file_type_tags::QUICK_TIME_FILETYPE_TAGS),
+    crate::tables::lazy_table!(flip_tags::QUICK_TIME_FLIP_TAGS),
+    crate::tables::lazy_table!(ftyp_lookup::FTYP_LOOKUP),
+    crate::tables::lazy_table!(gen_media_header_tags::QUICK_TIME_GENMEDIAHEADER_TAGS),
+    crate::tables::lazy_table!(gen_media_header_tags::QUICK_TIME_GENMEDIAHEADER_TAGS_BY_NAME),
+    crate::tables::lazy_table!(gen_media_info_tags::QUICK_TIME_GENMEDIAINFO_TAGS),
+    crate::tables::lazy_table!(graphics_mode::GRAPHICS_MODE),
+    crate::tables::lazy_table!(grpl_tags::QUICK_TIME_GRPL_TAGS),
+    crate::tables::lazy_table!(handler_tags::QUICK_TIME_HANDLER_TAGS),
+    crate::tables::lazy_table!(hevc_config_tags::QUICK_TIME_HEVCCONFIG_TAGS),
+    crate::tables::lazy_table!(hevc_config_tags::QUICK_TIME_HEVCCONFIG_TAGS_BY_NAME),
+    crate::tables::lazy_table!(hint_header_tags::QUICK_TIME_HINTHEADER_TAGS),
+    crate::tables::lazy_table!(hint_info_tags::QUICK_TIME_HINTINFO_TAGS),
+    crate::tables::lazy_table!(hint_info_tags::QUICK_TIME_HINTINFO_TAGS_BY_NAME),
+    crate::tables::lazy_table!(hint_sample_desc_tags::QUICK_TIME_HINTSAMPLEDESC_TAGS),
+    crate::tables::lazy_table!(hint_sample_desc_tags::QUICK_TIME_HINTSAMPLEDESC_TAGS_BY_NAME),
+    crate::tables::lazy_table!(hint_track_info_tags::QUICK_TIME_HINTTRACKINFO_TAGS),
+    crate::tables::lazy_table!(hint_track_info_tags::QUICK_TIME_HINTTRACKINFO_TAGS_BY_NAME),
+    crate::tables::lazy_table!(htc_binary_tags::QUICK_TIME_HTCBINARY_TAGS),
+    crate::tables::lazy_table!(htc_info_tags::QUICK_TIME_HTCINFO_TAGS),
+    crate::tables::lazy_table!(htc_info_tags::QUICK_TIME_HTCINFO_TAGS_BY_NAME),
+    crate::tables::lazy_table!(i_tunes_info_tags::QUICK_TIME_ITUNESINFO_TAGS),
+    crate::tables::lazy_table!(i_tunes_info_tags::QUICK_TIME_ITUNESINFO_TAGS_BY_NAME),
+    crate::tables::lazy_table!(image_file_tags::QUICK_TIME_IMAGEFILE_TAGS),
+    crate::tables::lazy_table!(image_file_tags::QUICK_TIME_IMAGEFILE_TAGS_BY_NAME),
+    crate::tables::lazy_table!(is_image_data::IS_IMAGE_DATA),
+    crate::tables::lazy_table!(item_info_tags::QUICK_TIME_ITEMINFO_TAGS),
+    crate::tables::lazy_table!(item_info_tags::QUICK_TIME_ITEMINFO_TAGS_BY_NAME),
+    crate::tables::lazy_table!(item_list_tags::QUICK_TIME_ITEMLIST_TAGS),
+    crate::tables::lazy_table!(item_list_tags::QUICK_TIME_ITEMLIST_TAGS_BY_NAME),
+    crate::tables::lazy_table!(item_prop_cont_tags::QUICK_TIME_ITEMPROPCONT_TAGS),
+    crate::tables::lazy_table!(item_prop_cont_tags::QUICK_TIME_ITEMPROPCONT_TAGS_BY_NAME),
+    crate::tables::lazy_table!(item_prop_tags::QUICK_TIME_ITEMPROP_TAGS),
+    crate::tables::lazy_table!(item_prop_tags::QUICK_TIME_ITEMPROP_TAGS_BY_NAME),
+    crate::tables::lazy_table!(item_ref_tags::QUICK_TIME_ITEMREF_TAGS),
+    crate::tables::lazy_table!(item_ref_tags::QUICK_TIME_ITEMREF_TAGS_BY_NAME),
+    crate::tables::lazy_table!(keys_tags::QUICK_TIME_KEYS_TAGS),
+    crate::tables::lazy_table!(keys_tags::QUICK_TIME_KEYS_TAGS_BY_NAME),
+    crate::tables::lazy_table!(main_tags::QUICK_TIME_MAIN_TAGS),
+    crate::tables::lazy_table!(main_tags::QUICK_TIME_MAIN_TAGS_BY_NAME),
+    crate::tables::lazy_table!(media_header_tags::QUICK_TIME_MEDIAHEADER_TAGS),
+    crate::tables::lazy_table!(media_info_tags::QUICK_TIME_MEDIAINFO_TAGS),
+    crate::tables::lazy_table!(media_info_tags::QUICK_TIME_MEDIAINFO_TAGS_BY_NAME),
+    crate::tables::lazy_table!(media_tags::QUICK_TIME_MEDIA_TAGS),
+    crate::tables::lazy_table!(media_tags::QUICK_TIME_MEDIA_TAGS_BY_NAME),
+    crate::tables::lazy_table!(meta_data_tags::QUICK_TIME_METADATA_TAGS),
+    crate::tables::lazy_table!(meta_relation_tags::QUICK_TIME_METARELATION_TAGS),
+    crate::tables::lazy_table!(meta_sample_desc_tags::QUICK_TIME_METASAMPLEDESC_TAGS),
+    crate::tables::lazy_table!(meta_sample_desc_tags::QUICK_TIME_METASAMPLEDESC_TAGS_BY_NAME),
+    crate::tables::lazy_table!(meta_tags::QUICK_TIME_META_TAGS),
+    crate::tables::lazy_table!(meta_tags::QUICK_TIME_META_TAGS_BY_NAME),
+    crate::tables::lazy_table!(mime_lookup::MIME_LOOKUP),
+    crate::tables::lazy_table!(movie_frag_hdr_tags::QUICK_TIME_MOVIEFRAGHDR_TAGS),
+    crate::tables::lazy_table!(movie_fragment_tags::QUICK_TIME_MOVIEFRAGMENT_TAGS),
+    crate::tables::lazy_table!(movie_fragment_tags::QUICK_TIME_MOVIEFRAGMENT_TAGS_BY_NAME),
+    crate::tables::lazy_table!(movie_header_tags::QUICK_TIME_MOVIEHEADER_TAGS),
+    crate::tables::lazy_table!(movie_tags::QUICK_TIME_MOVIE_TAGS),
+    crate::tables::lazy_table!(movie_tags::QUICK_TIME_MOVIE_TAGS_BY_NAME),
+    crate::tables::lazy_table!(nextbase_tags::QUICK_TIME_NEXTBASE_TAGS),
+    crate::tables::lazy_table!(nextbase_tags::QUICK_TIME_NEXTBASE_TAGS_BY_NAME),
+    crate::tables::lazy_table!(other_meta_tags::QUICK_TIME_OTHERMETA_TAGS),
+    crate::tables::lazy_table!(other_meta_tags::QUICK_TIME_OTHERMETA_TAGS_BY_NAME),
+    crate::tables::lazy_table!(other_sample_desc_tags::QUICK_TIME_OTHERSAMPLEDESC_TAGS),
+    crate::tables::lazy_table!(other_sample_desc_tags::QUICK_TIME_OTHERSAMPLEDESC_TAGS_BY_NAME),
+    crate::tables::lazy_table!(pittasoft_tags::QUICK_TIME_PITTASOFT_TAGS),
+    crate::tables::lazy_table!(pittasoft_tags::QUICK_TIME_PITTASOFT_TAGS_BY_NAME),
+    crate::tables::lazy_table!(preview_tags::QUICK_TIME_PREVIEW_TAGS),
+    crate::tables::lazy_table!(prhd_tags::QUICK_TIME_PRHD_TAGS),
+    crate::tables::lazy_table!(profile_tags::QUICK_TIME_PROFILE_TAGS),
+    crate::tables::lazy_table!(profile_tags::QUICK_TIME_PROFILE_TAGS_BY_NAME),
+    crate::tables::lazy_table!(proj_tags::QUICK_TIME_PROJ_TAGS),
+    crate::tables::lazy_table!(proj_tags::QUICK_TIME_PROJ_TAGS_BY_NAME),
+    crate::tables::lazy_table!(protection_info_tags::QUICK_TIME_PROTECTIONINFO_TAGS),
+    crate::tables::lazy_table!(protection_info_tags::QUICK_TIME_PROTECTIONINFO_TAGS_BY_NAME),
+    crate::tables::lazy_table!(qt_flags::QT_FLAGS),
+    crate::tables::lazy_table!(rights_tags::QUICK_TIME_RIGHTS_TAGS),
+    crate::tables::lazy_table!(rights_tags::QUICK_TIME_RIGHTS_TAGS_BY_NAME),
+    crate::tables::lazy_table!(sample_table_tags::QUICK_TIME_SAMPLETABLE_TAGS),
+    crate::tables::lazy_table!(sample_table_tags::QUICK_TIME_SAMPLETABLE_TAGS_BY_NAME),
+    crate::tables::lazy_table!(scheme_info_tags::QUICK_TIME_SCHEMEINFO_TAGS),
+    crate::tables::lazy_table!(scheme_info_tags::QUICK_TIME_SCHEMEINFO_TAGS_BY_NAME),
+    crate::tables::lazy_table!(scheme_type_tags::QUICK_TIME_SCHEMETYPE_TAGS),
+    crate::tables::lazy_table!(skip_info_tags::QUICK_TIME_SKIPINFO_TAGS),
+    crate::tables::lazy_table!(skip_info_tags::QUICK_TIME_SKIPINFO_TAGS_BY_NAME),
+    crate::tables::lazy_table!(spatial_audio_tags::QUICK_TIME_SPATIALAUDIO_TAGS),
+    crate::tables::lazy_table!(string_encoding::STRING_ENCODING),
+    crate::tables::lazy_table!(sv3d_tags::QUICK_TIME_SV3D_TAGS),
+    crate::tables::lazy_table!(sv3d_tags::QUICK_TIME_SV3D_TAGS_BY_NAME),
+    crate::tables::lazy_table!(tc_media_info_tags::QUICK_TIME_TCMEDIAINFO_TAGS),
+    crate::tables::lazy_table!(time_code_tags::QUICK_TIME_TIMECODE_TAGS),
+    crate::tables::lazy_table!(time_code_tags::QUICK_TIME_TIMECODE_TAGS_BY_NAME),
+    crate::tables::lazy_table!(tom_tom_tags::QUICK_TIME_TOMTOM_TAGS),
+    crate::tables::lazy_table!(tom_tom_tags::QUICK_TIME_TOMTOM_TAGS_BY_NAME),
+    crate::tables::lazy_table!(track_aperture_tags::QUICK_TIME_TRACKAPERTURE_TAGS),
+    crate::tables::lazy_table!(track_aperture_tags::QUICK_TIME_TRACKAPERTURE_TAGS_BY_NAME),
+    crate::tables::lazy_table!(track_fragment_tags::QUICK_TIME_TRACKFRAGMENT_TAGS),
+    crate::tables::lazy_table!(track_fragment_tags::QUICK_TIME_TRACKFRAGMENT_TAGS_BY_NAME),
+    crate::tables::lazy_table!(track_header_tags::QUICK_TIME_TRACKHEADER_TAGS),
+    crate::tables::lazy_table!(track_path::TRACK_PATH),
+    crate::tables::lazy_table!(track_ref_tags::QUICK_TIME_TRACKREF_TAGS),
+    crate::tables::lazy_table!(track_ref_tags::QUICK_TIME_TRACKREF_TAGS_BY_NAME),
+    crate::tables::lazy_table!(track_tags::QUICK_TIME_TRACK_TAGS),
+    crate::tables::lazy_table!(track_tags::QUICK_TIME_TRACK_TAGS_BY_NAME),
+    crate::tables::lazy_table!(use_ext::USE_EXT),
+    crate::tables::lazy_table!(user_data_tags::QUICK_TIME_USERDATA_TAGS),
+    crate::tables::lazy_table!(user_data_tags::QUICK_TIME_USERDATA_TAGS_BY_NAME),
+    crate::tables::lazy_table!(user_defined::USER_DEFINED),
+    crate::tables::lazy_table!(user_media_tags::QUICK_TIME_USERMEDIA_TAGS),
+    crate::tables::lazy_table!(user_media_tags::QUICK_TIME_USERMEDIA_TAGS_BY_NAME),
+    crate::tables::lazy_table!(vendor_id::VENDOR_ID),
+    crate::tables::lazy_table!(video_header_tags::QUICK_TIME_VIDEOHEADER_TAGS),
+    crate::tables::lazy_table!(video_keys_tags::QUICK_TIME_VIDEOKEYS_TAGS),
+    crate::tables::lazy_table!(video_keys_tags::QUICK_TIME_VIDEOKEYS_TAGS_BY_NAME),
+    crate::tables::lazy_table!(video_prof_tags::QUICK_TIME_VIDEOPROF_TAGS),
+    crate::tables::lazy_table!(video_tags::QUICK_TIME_VIDEO_TAGS),
+    crate::tables::lazy_table!(visual_sample_desc_tags::QUICK_TIME_VISUALSAMPLEDESC_TAGS),
+    crate::tables::lazy_table!(visual_sample_desc_tags::QUICK_TIME_VISUALSAMPLEDESC_TAGS_BY_NAME),
+    crate::tables::lazy_table!(wave_tags::QUICK_TIME_WAVE_TAGS),
+    crate::tables::lazy_table!(wave_tags::QUICK_TIME_WAVE_TAGS_BY_NAME),
+];
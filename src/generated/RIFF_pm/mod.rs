@@ -37,3 +37,39 @@ pub mod vp8x_tags;
 
 // Re-export commonly used items
 pub use main_tags::RIFF_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(acidizer_tags::RIFF_ACIDIZER_TAGS),
+    crate::tables::lazy_table!(alph_tags::RIFF_ALPH_TAGS),
+    crate::tables::lazy_table!(anim_tags::RIFF_ANIM_TAGS),
+    crate::tables::lazy_table!(anmf_tags::RIFF_ANMF_TAGS),
+    crate::tables::lazy_table!(audio_encoding::AUDIO_ENCODING),
+    crate::tables::lazy_table!(audio_format_tags::RIFF_AUDIOFORMAT_TAGS),
+    crate::tables::lazy_table!(avi_header_tags::RIFF_AVIHEADER_TAGS),
+    crate::tables::lazy_table!(broadcast_ext_tags::RIFF_BROADCASTEXT_TAGS),
+    crate::tables::lazy_table!(code2charset::CODE2CHARSET),
+    crate::tables::lazy_table!(composite_tags::RIFF_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(cset_tags::RIFF_CSET_TAGS),
+    crate::tables::lazy_table!(ds64_tags::RIFF_DS64_TAGS),
+    crate::tables::lazy_table!(exif_tags::RIFF_EXIF_TAGS),
+    crate::tables::lazy_table!(ext_avi_hdr_tags::RIFF_EXTAVIHDR_TAGS),
+    crate::tables::lazy_table!(hdrl_tags::RIFF_HDRL_TAGS),
+    crate::tables::lazy_table!(info_tags::RIFF_INFO_TAGS),
+    crate::tables::lazy_table!(instrument_tags::RIFF_INSTRUMENT_TAGS),
+    crate::tables::lazy_table!(is_image_data::IS_IMAGE_DATA),
+    crate::tables::lazy_table!(junk_tags::RIFF_JUNK_TAGS),
+    crate::tables::lazy_table!(main_tags::RIFF_MAIN_TAGS),
+    crate::tables::lazy_table!(open_dml_tags::RIFF_OPENDML_TAGS),
+    crate::tables::lazy_table!(riff_mime_type::RIFF_MIME_TYPE),
+    crate::tables::lazy_table!(riff_type::RIFF_TYPE),
+    crate::tables::lazy_table!(sampler_tags::RIFF_SAMPLER_TAGS),
+    crate::tables::lazy_table!(stream_data_tags::RIFF_STREAMDATA_TAGS),
+    crate::tables::lazy_table!(stream_header_tags::RIFF_STREAMHEADER_TAGS),
+    crate::tables::lazy_table!(stream_tags::RIFF_STREAM_TAGS),
+    crate::tables::lazy_table!(tdat_tags::RIFF_TDAT_TAGS),
+    crate::tables::lazy_table!(user_text_tags::RIFF_USERTEXT_TAGS),
+    crate::tables::lazy_table!(vp8_tags::RIFF_VP8_TAGS),
+    crate::tables::lazy_table!(vp8l_tags::RIFF_VP8L_TAGS),
+    crate::tables::lazy_table!(vp8x_tags::RIFF_VP8X_TAGS),
+];
@@ -9,3 +9,11 @@ pub mod red_format;
 
 // Re-export commonly used items
 pub use main_tags::RED_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(main_tags::RED_MAIN_TAGS),
+    crate::tables::lazy_table!(red1_tags::RED_RED1_TAGS),
+    crate::tables::lazy_table!(red2_tags::RED_RED2_TAGS),
+    crate::tables::lazy_table!(red_format::RED_FORMAT),
+];
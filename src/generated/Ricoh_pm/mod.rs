@@ -23,3 +23,25 @@ pub mod type2_tags;
 
 // Re-export commonly used items
 pub use main_tags::RICOH_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(avi_tags::RICOH_AVI_TAGS),
+    crate::tables::lazy_table!(composite_tags::RICOH_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(face_info_tags::RICOH_FACEINFO_TAGS),
+    crate::tables::lazy_table!(firmware_info_tags::RICOH_FIRMWAREINFO_TAGS),
+    crate::tables::lazy_table!(image_info_tags::RICOH_IMAGEINFO_TAGS),
+    crate::tables::lazy_table!(main_tags::RICOH_MAIN_TAGS),
+    crate::tables::lazy_table!(rdta_tags::RICOH_RDTA_TAGS),
+    crate::tables::lazy_table!(rdtb_tags::RICOH_RDTB_TAGS),
+    crate::tables::lazy_table!(rdtc_tags::RICOH_RDTC_TAGS),
+    crate::tables::lazy_table!(rdtg_tags::RICOH_RDTG_TAGS),
+    crate::tables::lazy_table!(rdtl_tags::RICOH_RDTL_TAGS),
+    crate::tables::lazy_table!(ricoh_lens_ids::RICOH_LENS_IDS),
+    crate::tables::lazy_table!(rmeta_tags::RICOH_RMETA_TAGS),
+    crate::tables::lazy_table!(serial_info_tags::RICOH_SERIALINFO_TAGS),
+    crate::tables::lazy_table!(subdir_tags::RICOH_SUBDIR_TAGS),
+    crate::tables::lazy_table!(text_tags::RICOH_TEXT_TAGS),
+    crate::tables::lazy_table!(theta_subdir_tags::RICOH_THETASUBDIR_TAGS),
+    crate::tables::lazy_table!(type2_tags::RICOH_TYPE2_TAGS),
+];
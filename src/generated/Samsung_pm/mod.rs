@@ -29,3 +29,31 @@ pub mod type2_tags;
 
 // Re-export commonly used items
 pub use main_tags::SAMSUNG_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(app5_tags::SAMSUNG_APP5_TAGS),
+    crate::tables::lazy_table!(clip_info_tags::SAMSUNG_CLIPINFO_TAGS),
+    crate::tables::lazy_table!(composite_tags::SAMSUNG_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(dual_shot_extra_tags::SAMSUNG_DUALSHOTEXTRA_TAGS),
+    crate::tables::lazy_table!(effect_info_tags::SAMSUNG_EFFECTINFO_TAGS),
+    crate::tables::lazy_table!(ifd_tags::SAMSUNG_IFD_TAGS),
+    crate::tables::lazy_table!(info_tags::SAMSUNG_INFO_TAGS),
+    crate::tables::lazy_table!(main_tags::SAMSUNG_MAIN_TAGS),
+    crate::tables::lazy_table!(mp4_tags::SAMSUNG_MP4_TAGS),
+    crate::tables::lazy_table!(orientation_info_tags::SAMSUNG_ORIENTATIONINFO_TAGS),
+    crate::tables::lazy_table!(p_eginfo_tags::SAMSUNG_PEGINFO_TAGS),
+    crate::tables::lazy_table!(picture_wizard_tags::SAMSUNG_PICTUREWIZARD_TAGS),
+    crate::tables::lazy_table!(portrait_effect_tags::SAMSUNG_PORTRAITEFFECT_TAGS),
+    crate::tables::lazy_table!(re_edit_data_tags::SAMSUNG_REEDITDATA_TAGS),
+    crate::tables::lazy_table!(samsung_lens_types::SAMSUNG_LENS_TYPES),
+    crate::tables::lazy_table!(sec_tags::SAMSUNG_SEC_TAGS),
+    crate::tables::lazy_table!(single_shot_meta_tags::SAMSUNG_SINGLESHOTMETA_TAGS),
+    crate::tables::lazy_table!(smta_tags::SAMSUNG_SMTA_TAGS),
+    crate::tables::lazy_table!(svss_tags::SAMSUNG_SVSS_TAGS),
+    crate::tables::lazy_table!(thumbnail2_tags::SAMSUNG_THUMBNAIL2_TAGS),
+    crate::tables::lazy_table!(thumbnail_tags::SAMSUNG_THUMBNAIL_TAGS),
+    crate::tables::lazy_table!(tone_info_tags::SAMSUNG_TONEINFO_TAGS),
+    crate::tables::lazy_table!(trailer_tags::SAMSUNG_TRAILER_TAGS),
+    crate::tables::lazy_table!(type2_tags::SAMSUNG_TYPE2_TAGS),
+];
@@ -11,3 +11,13 @@ pub mod thumbnail_tags;
 
 // Re-export commonly used items
 pub use main_tags::SANYO_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(face_info_tags::SANYO_FACEINFO_TAGS),
+    crate::tables::lazy_table!(main_tags::SANYO_MAIN_TAGS),
+    crate::tables::lazy_table!(mov_tags::SANYO_MOV_TAGS),
+    crate::tables::lazy_table!(mp4_tags::SANYO_MP4_TAGS),
+    crate::tables::lazy_table!(off_on::OFF_ON),
+    crate::tables::lazy_table!(thumbnail_tags::SANYO_THUMBNAIL_TAGS),
+];
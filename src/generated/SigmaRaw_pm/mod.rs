@@ -10,3 +10,12 @@ pub mod properties_tags;
 
 // Re-export commonly used items
 pub use main_tags::SIGMA_RAW_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(header4_tags::SIGMA_RAW_HEADER4_TAGS),
+    crate::tables::lazy_table!(header_ext_tags::SIGMA_RAW_HEADEREXT_TAGS),
+    crate::tables::lazy_table!(header_tags::SIGMA_RAW_HEADER_TAGS),
+    crate::tables::lazy_table!(main_tags::SIGMA_RAW_MAIN_TAGS),
+    crate::tables::lazy_table!(properties_tags::SIGMA_RAW_PROPERTIES_TAGS),
+];
@@ -9,3 +9,11 @@ pub mod wb_settings_tags;
 
 // Re-export commonly used items
 pub use main_tags::SIGMA_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(main_tags::SIGMA_MAIN_TAGS),
+    crate::tables::lazy_table!(sigma_lens_types::SIGMA_LENS_TYPES),
+    crate::tables::lazy_table!(wb_settings2_tags::SIGMA_WBSETTINGS2_TAGS),
+    crate::tables::lazy_table!(wb_settings_tags::SIGMA_WBSETTINGS_TAGS),
+];
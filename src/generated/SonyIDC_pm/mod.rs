@@ -7,3 +7,9 @@ pub mod main_tags;
 
 // Re-export commonly used items
 pub use main_tags::SONY_IDC_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(composite_tags::SONY_IDC_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(main_tags::SONY_IDC_MAIN_TAGS),
+];
@@ -0,0 +1,108 @@
+//! ProcessBinaryData tables of Sony, for the generic binary data processor
+//!
+//! This file is auto-generated by codegen/src/strategies/tag_kit.rs. Do not edit manually.
+
+use crate::types::{BinaryTableProc, GeneratedBinaryTable};
+
+/// Every ProcessBinaryData table in Sony
+pub static BINARY_TABLES: &[GeneratedBinaryTable] = &[
+    GeneratedBinaryTable {
+        table: "Tag2010a",
+        tags: &super::tag2010a_tags::SONY_TAG2010A_TAGS,
+        format: "int8u",
+        first_entry: 0,
+        proc: BinaryTableProc::Enciphered,
+        hooks: &[],
+        data_members: &[],
+        value_conv: super::tag2010a_tags::apply_value_conv,
+        print_conv: super::tag2010a_tags::apply_print_conv,
+    },
+    GeneratedBinaryTable {
+        table: "Tag2010b",
+        tags: &super::tag2010b_tags::SONY_TAG2010B_TAGS,
+        format: "int8u",
+        first_entry: 0,
+        proc: BinaryTableProc::Enciphered,
+        hooks: &[],
+        data_members: &[],
+        value_conv: super::tag2010b_tags::apply_value_conv,
+        print_conv: super::tag2010b_tags::apply_print_conv,
+    },
+    GeneratedBinaryTable {
+        table: "Tag2010c",
+        tags: &super::tag2010c_tags::SONY_TAG2010C_TAGS,
+        format: "int8u",
+        first_entry: 0,
+        proc: BinaryTableProc::Enciphered,
+        hooks: &[],
+        data_members: &[],
+        value_conv: super::tag2010c_tags::apply_value_conv,
+        print_conv: super::tag2010c_tags::apply_print_conv,
+    },
+    GeneratedBinaryTable {
+        table: "Tag2010d",
+        tags: &super::tag2010d_tags::SONY_TAG2010D_TAGS,
+        format: "int8u",
+        first_entry: 0,
+        proc: BinaryTableProc::Enciphered,
+        hooks: &[],
+        data_members: &[],
+        value_conv: super::tag2010d_tags::apply_value_conv,
+        print_conv: super::tag2010d_tags::apply_print_conv,
+    },
+    GeneratedBinaryTable {
+        table: "Tag2010e",
+        tags: &super::tag2010e_tags::SONY_TAG2010E_TAGS,
+        format: "int8u",
+        first_entry: 0,
+        proc: BinaryTableProc::Enciphered,
+        hooks: &[],
+        data_members: &[],
+        value_conv: super::tag2010e_tags::apply_value_conv,
+        print_conv: super::tag2010e_tags::apply_print_conv,
+    },
+    GeneratedBinaryTable {
+        table: "Tag2010f",
+        tags: &super::tag2010f_tags::SONY_TAG2010F_TAGS,
+        format: "int8u",
+        first_entry: 0,
+        proc: BinaryTableProc::Enciphered,
+        hooks: &[],
+        data_members: &[],
+        value_conv: super::tag2010f_tags::apply_value_conv,
+        print_conv: super::tag2010f_tags::apply_print_conv,
+    },
+    GeneratedBinaryTable {
+        table: "Tag2010g",
+        tags: &super::tag2010g_tags::SONY_TAG2010G_TAGS,
+        format: "int8u",
+        first_entry: 0,
+        proc: BinaryTableProc::Enciphered,
+        hooks: &[],
+        data_members: &[],
+        value_conv: super::tag2010g_tags::apply_value_conv,
+        print_conv: super::tag2010g_tags::apply_print_conv,
+    },
+    GeneratedBinaryTable {
+        table: "Tag2010h",
+        tags: &super::tag2010h_tags::SONY_TAG2010H_TAGS,
+        format: "int8u",
+        first_entry: 0,
+        proc: BinaryTableProc::Enciphered,
+        hooks: &[],
+        data_members: &[],
+        value_conv: super::tag2010h_tags::apply_value_conv,
+        print_conv: super::tag2010h_tags::apply_print_conv,
+    },
+    GeneratedBinaryTable {
+        table: "Tag2010i",
+        tags: &super::tag2010i_tags::SONY_TAG2010I_TAGS,
+        format: "int8u",
+        first_entry: 0,
+        proc: BinaryTableProc::Enciphered,
+        hooks: &[],
+        data_members: &[],
+        value_conv: super::tag2010i_tags::apply_value_conv,
+        print_conv: super::tag2010i_tags::apply_print_conv,
+    },
+];
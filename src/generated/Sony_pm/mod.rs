@@ -12,6 +12,7 @@ pub mod af_status15_tags;
 pub mod af_status19_tags;
 pub mod af_status79_tags;
 pub mod binary_data_attrs_tags;
+pub mod binary_tables;
 pub mod camera_info2_tags;
 pub mod camera_info3_tags;
 pub mod camera_info_tags;
@@ -90,3 +91,92 @@ pub mod white_balance_setting;
 
 // Re-export commonly used items
 pub use main_tags::SONY_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(af_info_tags::SONY_AFINFO_TAGS),
+    crate::tables::lazy_table!(af_point15::AF_POINT15),
+    crate::tables::lazy_table!(af_point19::AF_POINT19),
+    crate::tables::lazy_table!(af_points79::AF_POINTS79),
+    crate::tables::lazy_table!(af_points79_940e::AF_POINTS79_940E),
+    crate::tables::lazy_table!(af_points99m2::AF_POINTS99M2),
+    crate::tables::lazy_table!(af_status15_tags::SONY_AFSTATUS15_TAGS),
+    crate::tables::lazy_table!(af_status19_tags::SONY_AFSTATUS19_TAGS),
+    crate::tables::lazy_table!(af_status79_tags::SONY_AFSTATUS79_TAGS),
+    crate::tables::lazy_table!(binary_data_attrs_tags::SONY_BINARYDATAATTRS_TAGS),
+    crate::tables::lazy_table!(camera_info2_tags::SONY_CAMERAINFO2_TAGS),
+    crate::tables::lazy_table!(camera_info3_tags::SONY_CAMERAINFO3_TAGS),
+    crate::tables::lazy_table!(camera_info_tags::SONY_CAMERAINFO_TAGS),
+    crate::tables::lazy_table!(camera_info_unknown_tags::SONY_CAMERAINFOUNKNOWN_TAGS),
+    crate::tables::lazy_table!(camera_settings2_tags::SONY_CAMERASETTINGS2_TAGS),
+    crate::tables::lazy_table!(camera_settings3_tags::SONY_CAMERASETTINGS3_TAGS),
+    crate::tables::lazy_table!(camera_settings_tags::SONY_CAMERASETTINGS_TAGS),
+    crate::tables::lazy_table!(camera_settings_unknown_tags::SONY_CAMERASETTINGSUNKNOWN_TAGS),
+    crate::tables::lazy_table!(composite_tags::SONY_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(ericsson_tags::SONY_ERICSSON_TAGS),
+    crate::tables::lazy_table!(extra_info2_tags::SONY_EXTRAINFO2_TAGS),
+    crate::tables::lazy_table!(extra_info3_tags::SONY_EXTRAINFO3_TAGS),
+    crate::tables::lazy_table!(extra_info_tags::SONY_EXTRAINFO_TAGS),
+    crate::tables::lazy_table!(face_info1_tags::SONY_FACEINFO1_TAGS),
+    crate::tables::lazy_table!(face_info2_tags::SONY_FACEINFO2_TAGS),
+    crate::tables::lazy_table!(face_info_a_tags::SONY_FACEINFOA_TAGS),
+    crate::tables::lazy_table!(face_info_tags::SONY_FACEINFO_TAGS),
+    crate::tables::lazy_table!(focus_info_tags::SONY_FOCUSINFO_TAGS),
+    crate::tables::lazy_table!(hidden_info_tags::SONY_HIDDENINFO_TAGS),
+    crate::tables::lazy_table!(iso_info_tags::SONY_ISOINFO_TAGS),
+    crate::tables::lazy_table!(main_tags::SONY_MAIN_TAGS),
+    crate::tables::lazy_table!(meter_info9_tags::SONY_METERINFO9_TAGS),
+    crate::tables::lazy_table!(meter_info_tags::SONY_METERINFO_TAGS),
+    crate::tables::lazy_table!(more_info0201_tags::SONY_MOREINFO0201_TAGS),
+    crate::tables::lazy_table!(more_info0401_tags::SONY_MOREINFO0401_TAGS),
+    crate::tables::lazy_table!(more_info_tags::SONY_MOREINFO_TAGS),
+    crate::tables::lazy_table!(more_settings_tags::SONY_MORESETTINGS_TAGS),
+    crate::tables::lazy_table!(panorama_tags::SONY_PANORAMA_TAGS),
+    crate::tables::lazy_table!(pic_tags::SONY_PIC_TAGS),
+    crate::tables::lazy_table!(pmp_tags::SONY_PMP_TAGS),
+    crate::tables::lazy_table!(rtmd_tags::SONY_RTMD_TAGS),
+    crate::tables::lazy_table!(shot_info_tags::SONY_SHOTINFO_TAGS),
+    crate::tables::lazy_table!(sony_exposure_program::SONY_EXPOSURE_PROGRAM),
+    crate::tables::lazy_table!(sony_exposure_program2::SONY_EXPOSURE_PROGRAM2),
+    crate::tables::lazy_table!(sony_exposure_program3::SONY_EXPOSURE_PROGRAM3),
+    crate::tables::lazy_table!(sony_lens_types::SONY_LENS_TYPES),
+    crate::tables::lazy_table!(sony_lens_types2::SONY_LENS_TYPES2),
+    crate::tables::lazy_table!(sr2data_ifd_tags::SONY_SR2DATAIFD_TAGS),
+    crate::tables::lazy_table!(sr2private_tags::SONY_SR2PRIVATE_TAGS),
+    crate::tables::lazy_table!(sr2sub_ifd_tags::SONY_SR2SUBIFD_TAGS),
+    crate::tables::lazy_table!(srf2_tags::SONY_SRF2_TAGS),
+    crate::tables::lazy_table!(srf_tags::SONY_SRF_TAGS),
+    crate::tables::lazy_table!(tag2010a_tags::SONY_TAG2010A_TAGS),
+    crate::tables::lazy_table!(tag2010b_tags::SONY_TAG2010B_TAGS),
+    crate::tables::lazy_table!(tag2010c_tags::SONY_TAG2010C_TAGS),
+    crate::tables::lazy_table!(tag2010d_tags::SONY_TAG2010D_TAGS),
+    crate::tables::lazy_table!(tag2010e_tags::SONY_TAG2010E_TAGS),
+    crate::tables::lazy_table!(tag2010f_tags::SONY_TAG2010F_TAGS),
+    crate::tables::lazy_table!(tag2010g_tags::SONY_TAG2010G_TAGS),
+    crate::tables::lazy_table!(tag2010h_tags::SONY_TAG2010H_TAGS),
+    crate::tables::lazy_table!(tag2010i_tags::SONY_TAG2010I_TAGS),
+    crate::tables::lazy_table!(tag202a_tags::SONY_TAG202A_TAGS),
+    crate::tables::lazy_table!(tag900b_tags::SONY_TAG900B_TAGS),
+    crate::tables::lazy_table!(tag9050a_tags::SONY_TAG9050A_TAGS),
+    crate::tables::lazy_table!(tag9050b_tags::SONY_TAG9050B_TAGS),
+    crate::tables::lazy_table!(tag9050c_tags::SONY_TAG9050C_TAGS),
+    crate::tables::lazy_table!(tag9050d_tags::SONY_TAG9050D_TAGS),
+    crate::tables::lazy_table!(tag9400a_tags::SONY_TAG9400A_TAGS),
+    crate::tables::lazy_table!(tag9400b_tags::SONY_TAG9400B_TAGS),
+    crate::tables::lazy_table!(tag9400c_tags::SONY_TAG9400C_TAGS),
+    crate::tables::lazy_table!(tag9401_tags::SONY_TAG9401_TAGS),
+    crate::tables::lazy_table!(tag9402_tags::SONY_TAG9402_TAGS),
+    crate::tables::lazy_table!(tag9403_tags::SONY_TAG9403_TAGS),
+    crate::tables::lazy_table!(tag9404a_tags::SONY_TAG9404A_TAGS),
+    crate::tables::lazy_table!(tag9404b_tags::SONY_TAG9404B_TAGS),
+    crate::tables::lazy_table!(tag9404c_tags::SONY_TAG9404C_TAGS),
+    crate::tables::lazy_table!(tag9405a_tags::SONY_TAG9405A_TAGS),
+    crate::tables::lazy_table!(tag9405b_tags::SONY_TAG9405B_TAGS),
+    crate::tables::lazy_table!(tag9406_tags::SONY_TAG9406_TAGS),
+    crate::tables::lazy_table!(tag9406b_tags::SONY_TAG9406B_TAGS),
+    crate::tables::lazy_table!(tag940a_tags::SONY_TAG940A_TAGS),
+    crate::tables::lazy_table!(tag940c_tags::SONY_TAG940C_TAGS),
+    crate::tables::lazy_table!(tag940e_tags::SONY_TAG940E_TAGS),
+    crate::tables::lazy_table!(tag9416_tags::SONY_TAG9416_TAGS),
+    crate::tables::lazy_table!(white_balance_setting::WHITE_BALANCE_SETTING),
+];
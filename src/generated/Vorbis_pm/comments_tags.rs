@@ -9,6 +9,294 @@ use std::sync::LazyLock;
 /// Tag definitions for Vorbis::Comments table
 pub static VORBIS_COMMENTS_TAGS: LazyLock<HashMap<u16, TagInfo>> = LazyLock::new(HashMap::new);
 
+/// Byte-string keyed tag definitions for Vorbis::Comments table
+/// Keys are the exact tag IDs ExifTool matches, case included.
+pub static VORBIS_COMMENTS_TAGS_BY_NAME: LazyLock<HashMap<&'static [u8], TagInfo>> =
+    LazyLock::new(|| {
+        HashMap::from([
+            (
+                b"ACTOR".as_slice(),
+                TagInfo {
+                    name: "Actor",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"ALBUM".as_slice(),
+                TagInfo {
+                    name: "Album",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"ARTIST".as_slice(),
+                TagInfo {
+                    name: "Artist",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"COMMENT".as_slice(),
+                TagInfo {
+                    name: "Comment",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"COMPOSER".as_slice(),
+                TagInfo {
+                    name: "Composer",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"CONTACT".as_slice(),
+                TagInfo {
+                    name: "Contact",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"COPYRIGHT".as_slice(),
+                TagInfo {
+                    name: "Copyright",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"COVERARTMIME".as_slice(),
+                TagInfo {
+                    name: "CoverArtMIMEType",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"DATE".as_slice(),
+                TagInfo {
+                    name: "Date",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"DESCRIPTION".as_slice(),
+                TagInfo {
+                    name: "Description",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"DIRECTOR".as_slice(),
+                TagInfo {
+                    name: "Director",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"ENCODEDBY".as_slice(),
+                TagInfo {
+                    name: "EncodedBy",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"ENCODED_USING".as_slice(),
+                TagInfo {
+                    name: "EncodedUsing",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"GENRE".as_slice(),
+                TagInfo {
+                    name: "Genre",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"ISRC".as_slice(),
+                TagInfo {
+                    name: "ISRCNumber",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"LICENSE".as_slice(),
+                TagInfo {
+                    name: "License",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"LOCATION".as_slice(),
+                TagInfo {
+                    name: "Location",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"ORGANIZATION".as_slice(),
+                TagInfo {
+                    name: "Organization",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"PERFORMER".as_slice(),
+                TagInfo {
+                    name: "Performer",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"PRODUCER".as_slice(),
+                TagInfo {
+                    name: "Producer",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"REPLAYGAIN_ALBUM_GAIN".as_slice(),
+                TagInfo {
+                    name: "ReplayGainAlbumGain",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"REPLAYGAIN_ALBUM_PEAK".as_slice(),
+                TagInfo {
+                    name: "ReplayGainAlbumPeak",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"REPLAYGAIN_TRACK_GAIN".as_slice(),
+                TagInfo {
+                    name: "ReplayGainTrackGain",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"REPLAYGAIN_TRACK_PEAK".as_slice(),
+                TagInfo {
+                    name: "ReplayGainTrackPeak",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"TITLE".as_slice(),
+                TagInfo {
+                    name: "Title",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"TRACKNUMBER".as_slice(),
+                TagInfo {
+                    name: "TrackNumber",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"VERSION".as_slice(),
+                TagInfo {
+                    name: "Version",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+            (
+                b"vendor".as_slice(),
+                TagInfo {
+                    name: "vendor",
+                    format: "unknown",
+                    print_conv: None,
+                    value_conv: None,
+                    is_offset: false,
+                },
+            ),
+        ])
+    });
+
 /// Apply ValueConv transformation for tags in this table
 pub fn apply_value_conv(
     tag_id: u32,
@@ -9,3 +9,12 @@ pub mod main_tags;
 
 // Re-export commonly used items
 pub use main_tags::VORBIS_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(comments_tags::VORBIS_COMMENTS_TAGS),
+    crate::tables::lazy_table!(comments_tags::VORBIS_COMMENTS_TAGS_BY_NAME),
+    crate::tables::lazy_table!(composite_tags::VORBIS_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(identification_tags::VORBIS_IDENTIFICATION_TAGS),
+    crate::tables::lazy_table!(main_tags::VORBIS_MAIN_TAGS),
+];
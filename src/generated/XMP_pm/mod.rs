@@ -97,3 +97,97 @@ pub mod xmp_tpg_tags;
 
 // Re-export commonly used items
 pub use main_tags::XMP_MAIN_TAGS;
+
+/// Lookup tables in this module, for `crate::tables`
+pub static TABLES: &[crate::tables::LazyTable] = &[
+    crate::tables::lazy_table!(aas_tags::XMP_AAS_TAGS),
+    crate::tables::lazy_table!(acd_see_regions_tags::XMP_ACDSEE_RS_TAGS),
+    crate::tables::lazy_table!(acdsee_tags::XMP_ACDSEE_TAGS),
+    crate::tables::lazy_table!(album_tags::XMP_ALBUM_TAGS),
+    crate::tables::lazy_table!(apdi_tags::XMP_APDI_TAGS),
+    crate::tables::lazy_table!(apple_fi_tags::XMP_APPLE_FI_TAGS),
+    crate::tables::lazy_table!(aux_tags::XMP_AUX_TAGS),
+    crate::tables::lazy_table!(cc_tags::XMP_CC_TAGS),
+    crate::tables::lazy_table!(cell_tags::XMP_CELL_TAGS),
+    crate::tables::lazy_table!(char_name::CHAR_NAME),
+    crate::tables::lazy_table!(composite_tags::XMP_COMPOSITE_TAGS),
+    crate::tables::lazy_table!(crd_tags::XMP_CRD_TAGS),
+    crate::tables::lazy_table!(creator_atom_tags::XMP_CREATOR_ATOM_TAGS),
+    crate::tables::lazy_table!(crs_tags::XMP_CRS_TAGS),
+    crate::tables::lazy_table!(dc_tags::XMP_DC_TAGS),
+    crate::tables::lazy_table!(dex_tags::XMP_DEX_TAGS),
+    crate::tables::lazy_table!(dicom_tags::XMP_DICOM_TAGS),
+    crate::tables::lazy_table!(digi_kam_tags::XMP_DIGI_KAM_TAGS),
+    crate::tables::lazy_table!(exif_ex_tags::XMP_EXIF_EX_TAGS),
+    crate::tables::lazy_table!(exif_tags::XMP_EXIF_TAGS),
+    crate::tables::lazy_table!(exif_tool_tags::XMP_ET_TAGS),
+    crate::tables::lazy_table!(expression_media_tags::XMP_EXPRESSIONMEDIA_TAGS),
+    crate::tables::lazy_table!(extensis_tags::XMP_EXTENSIS_TAGS),
+    crate::tables::lazy_table!(fpv_tags::XMP_FPV_TAGS),
+    crate::tables::lazy_table!(getty_images_tags::XMP_GETTY_IMAGES_GIFT_TAGS),
+    crate::tables::lazy_table!(hdr_gain_map_tags::XMP_HDR_GAIN_MAP_TAGS),
+    crate::tables::lazy_table!(hdr_tags::XMP_HDR_METADATA_TAGS),
+    crate::tables::lazy_table!(hdrgm_tags::XMP_HDRGM_TAGS),
+    crate::tables::lazy_table!(ics_tags::XMP_ICS_TAGS),
+    crate::tables::lazy_table!(ignore_et_prop::IGNORE_ET_PROP),
+    crate::tables::lazy_table!(ignore_namespace::IGNORE_NAMESPACE),
+    crate::tables::lazy_table!(iptc_core_tags::XMP_IPTC4XMP_CORE_TAGS),
+    crate::tables::lazy_table!(iptc_ext_tags::XMP_IPTC4XMP_EXT_TAGS),
+    crate::tables::lazy_table!(l_image_tags::XMP_L_IMAGE_TAGS),
+    crate::tables::lazy_table!(lightroom_tags::XMP_LR_TAGS),
+    crate::tables::lazy_table!(main_tags::XMP_MAIN_TAGS),
+    crate::tables::lazy_table!(media_pro_tags::XMP_MEDIAPRO_TAGS),
+    crate::tables::lazy_table!(ns_uri::NS_URI),
+    crate::tables::lazy_table!(other_svg_tags::XMP_OTHERSVG_TAGS),
+    crate::tables::lazy_table!(other_tags::XMP_OTHER_TAGS),
+    crate::tables::lazy_table!(panorama_tags::XMP_PANORAMA_TAGS),
+    crate::tables::lazy_table!(pdf_tags::XMP_PDF_TAGS),
+    crate::tables::lazy_table!(pdfx_tags::XMP_PDFX_TAGS),
+    crate::tables::lazy_table!(photoshop_tags::XMP_PHOTOSHOP_TAGS),
+    crate::tables::lazy_table!(pixel_live_tags::XMP_PIXEL_LIVE_TAGS),
+    crate::tables::lazy_table!(pmi_tags::XMP_PMI_TAGS),
+    crate::tables::lazy_table!(prism_tags::XMP_PRISM_TAGS),
+    crate::tables::lazy_table!(prl_tags::XMP_PRL_TAGS),
+    crate::tables::lazy_table!(prm_tags::XMP_PRM_TAGS),
+    crate::tables::lazy_table!(pur_tags::XMP_PUR_TAGS),
+    crate::tables::lazy_table!(rdf_tags::XMP_RDF_TAGS),
+    crate::tables::lazy_table!(s_area_models_tags::XMP_CRS_TAGS),
+    crate::tables::lazy_table!(s_area_tags::XMP_ST_AREA_TAGS),
+    crate::tables::lazy_table!(s_colorant_tags::XMP_XMP_G_TAGS),
+    crate::tables::lazy_table!(s_corr_range_mask_tags::XMP_CRS_TAGS),
+    crate::tables::lazy_table!(s_correction_tags::XMP_CRS_TAGS),
+    crate::tables::lazy_table!(s_dimensions_tags::XMP_ST_DIM_TAGS),
+    crate::tables::lazy_table!(s_font_tags::XMP_ST_FNT_TAGS),
+    crate::tables::lazy_table!(s_job_ref_tags::XMP_ST_JOB_TAGS),
+    crate::tables::lazy_table!(s_manifest_item_tags::XMP_ST_MFS_TAGS),
+    crate::tables::lazy_table!(s_map_info_tags::XMP_CRS_TAGS),
+    crate::tables::lazy_table!(s_oecf_tags::XMP_EXIF_TAGS),
+    crate::tables::lazy_table!(s_page_info_tags::XMP_XMP_GIMG_TAGS),
+    crate::tables::lazy_table!(s_range_mask_tags::XMP_CRS_TAGS),
+    crate::tables::lazy_table!(s_resource_event_tags::XMP_ST_EVT_TAGS),
+    crate::tables::lazy_table!(s_resource_ref_tags::XMP_ST_REF_TAGS),
+    crate::tables::lazy_table!(s_retouch_area_tags::XMP_CRS_TAGS),
+    crate::tables::lazy_table!(s_swatch_group_tags::XMP_XMP_G_TAGS),
+    crate::tables::lazy_table!(s_thumbnail_tags::XMP_XMP_GIMG_TAGS),
+    crate::tables::lazy_table!(s_version_tags::XMP_ST_VER_TAGS),
+    crate::tables::lazy_table!(seal_tags::XMP_SEAL_TAGS),
+    crate::tables::lazy_table!(special_struct_tags::XMP_SPECIALSTRUCT_TAGS),
+    crate::tables::lazy_table!(std_path::STD_PATH),
+    crate::tables::lazy_table!(std_xlat_ns::STD_XLAT_NS),
+    crate::tables::lazy_table!(svg_tags::XMP_SVG_TAGS),
+    crate::tables::lazy_table!(swf_tags::XMP_SWF_TAGS),
+    crate::tables::lazy_table!(tiff_tags::XMP_TIFF_TAGS),
+    crate::tables::lazy_table!(uri2ns::URI2NS),
+    crate::tables::lazy_table!(x_tags::XMP_X_TAGS),
+    crate::tables::lazy_table!(xml_tags::XMP_XML_TAGS),
+    crate::tables::lazy_table!(xmp_bj_tags::XMP_XMP_BJ_TAGS),
+    crate::tables::lazy_table!(xmp_dm_tags::XMP_XMP_DM_TAGS),
+    crate::tables::lazy_table!(xmp_mm_tags::XMP_XMP_MM_TAGS),
+    crate::tables::lazy_table!(xmp_note_tags::XMP_XMP_NOTE_TAGS),
+    crate::tables::lazy_table!(xmp_ns::XMP_NS),
+    crate::tables::lazy_table!(xmp_plus_tags::XMP_XMP_PLUS_TAGS),
+    crate::tables::lazy_table!(xmp_rights_tags::XMP_XMP_RIGHTS_TAGS),
+    crate::tables::lazy_table!(xmp_table_defaults_tags::XMP_XMPTABLEDEFAULTS_TAGS),
+    crate::tables::lazy_table!(xmp_tags::XMP_XMP_TAGS),
+    crate::tables::lazy_table!(xmp_tpg_tags::XMP_XMP_TPG_TAGS),
+];
@@ -3185,11 +3185,131 @@ pub static COMPOSITE_TAGS: LazyLock<HashMap<&'static str, &'static CompositeTagD
         ])
     });
 
+/// Every composite tag definition, sorted by name then module
+///
+/// Unlike `COMPOSITE_TAGS`, keeps each module's definition of a name (e.g.
+/// both the Canon and the Samsung WB_RGGBLevels).
+pub static COMPOSITE_TAG_DEFS: &[&CompositeTagDef] = &[
+    &COMPOSITE_PANASONIC_ADVANCEDSCENEMODE,
+    &COMPOSITE_EXIF_APERTURE,
+    &COMPOSITE_NIKON_AUTOFOCUS,
+    &COMPOSITE_QUICKTIME_AVGBITRATE,
+    &COMPOSITE_EXIF_BLUEBALANCE,
+    &COMPOSITE_QUICKTIME_CDDBDISCPLAYTIME,
+    &COMPOSITE_QUICKTIME_CDDBDISCTRACKS,
+    &COMPOSITE_EXIF_CFAPATTERN,
+    &COMPOSITE_EXIF_CIRCLEOFCONFUSION,
+    &COMPOSITE_CANON_CONDITIONALFEC,
+    &COMPOSITE_NIKON_CONTRASTDETECTAF,
+    &COMPOSITE_EXIF_DOF,
+    &COMPOSITE_KODAK_DATECREATED,
+    &COMPOSITE_IPTC_DATETIMECREATED,
+    &COMPOSITE_EXIF_DATETIMEORIGINAL,
+    &COMPOSITE_SAMSUNG_DEPTHMAPTIFF,
+    &COMPOSITE_IPTC_DIGITALCREATIONDATETIME,
+    &COMPOSITE_CANON_DIGITALZOOM,
+    &COMPOSITE_CANON_DRIVEMODE,
+    &COMPOSITE_RIFF_DURATION,
+    &COMPOSITE_VORBIS_DURATION,
+    &COMPOSITE_RIFF_DURATION2,
+    &COMPOSITE_OLYMPUS_EXTENDERSTATUS,
+    &COMPOSITE_EXIF_FOV,
+    &COMPOSITE_CANON_FILENUMBER,
+    &COMPOSITE_XMP_FLASH,
+    &COMPOSITE_CANON_FLASHTYPE,
+    &COMPOSITE_EXIF_FOCALLENGTH35EFL,
+    &COMPOSITE_SONY_FOCUSDISTANCE,
+    &COMPOSITE_SONY_FOCUSDISTANCE2,
+    &COMPOSITE_GPS_GPSALTITUDE,
+    &COMPOSITE_QUICKTIME_GPSALTITUDE,
+    &COMPOSITE_QUICKTIME_GPSALTITUDE2,
+    &COMPOSITE_QUICKTIME_GPSALTITUDEREF,
+    &COMPOSITE_QUICKTIME_GPSALTITUDEREF2,
+    &COMPOSITE_GPS_GPSDATETIME,
+    &COMPOSITE_SONY_GPSDATETIME,
+    &COMPOSITE_GPS_GPSDESTLATITUDE,
+    &COMPOSITE_XMP_GPSDESTLATITUDEREF,
+    &COMPOSITE_GPS_GPSDESTLONGITUDE,
+    &COMPOSITE_XMP_GPSDESTLONGITUDEREF,
+    &COMPOSITE_GPS_GPSLATITUDE,
+    &COMPOSITE_QUICKTIME_GPSLATITUDE,
+    &COMPOSITE_SONY_GPSLATITUDE,
+    &COMPOSITE_QUICKTIME_GPSLATITUDE2,
+    &COMPOSITE_XMP_GPSLATITUDEREF,
+    &COMPOSITE_GPS_GPSLONGITUDE,
+    &COMPOSITE_QUICKTIME_GPSLONGITUDE,
+    &COMPOSITE_SONY_GPSLONGITUDE,
+    &COMPOSITE_QUICKTIME_GPSLONGITUDE2,
+    &COMPOSITE_XMP_GPSLONGITUDEREF,
+    &COMPOSITE_EXIF_GPSPOSITION,
+    &COMPOSITE_SONY_HIDDENDATA,
+    &COMPOSITE_EXIF_HYPERFOCALDISTANCE,
+    &COMPOSITE_SONYIDC_IDCPREVIEWIMAGE,
+    &COMPOSITE_CANON_ISO,
+    &COMPOSITE_PANASONICRAW_IMAGEHEIGHT,
+    &COMPOSITE_EXIF_IMAGESIZE,
+    &COMPOSITE_PANASONICRAW_IMAGEWIDTH,
+    &COMPOSITE_EXIF_JPGFROMRAW,
+    &COMPOSITE_CANON_LENS,
+    &COMPOSITE_CANON_LENS35EFL,
+    &COMPOSITE_EXIF_LENSID,
+    &COMPOSITE_NIKON_LENSID,
+    &COMPOSITE_RICOH_LENSID,
+    &COMPOSITE_XMP_LENSID,
+    &COMPOSITE_EXIF_LENSID_2,
+    &COMPOSITE_NIKON_LENSSPEC,
+    &COMPOSITE_OLYMPUS_LENSTYPE,
+    &COMPOSITE_EXIF_LIGHTVALUE,
+    &COMPOSITE_EXIF_MEGAPIXELS,
+    &COMPOSITE_CANON_ORIGINALDECISIONDATA,
+    &COMPOSITE_EXIF_OTHERIMAGE,
+    &COMPOSITE_NIKON_PHASEDETECTAF,
+    &COMPOSITE_EXIF_PREVIEWIMAGE,
+    &COMPOSITE_EXIF_PREVIEWIMAGESIZE,
+    &COMPOSITE_EXIF_PREVIEWJXL,
+    &COMPOSITE_EXIF_REDBALANCE,
+    &COMPOSITE_CANON_REDEYEREDUCTION,
+    &COMPOSITE_RICOH_RICOHPITCH,
+    &COMPOSITE_RICOH_RICOHROLL,
+    &COMPOSITE_QUICKTIME_ROTATION,
+    &COMPOSITE_APPLE_RUNTIMESINCEPOWERUP,
+    &COMPOSITE_EXIF_SCALEFACTOR35EFL,
+    &COMPOSITE_CANON_SHOOTINGMODE,
+    &COMPOSITE_CANON_SHUTTERCURTAINHACK,
+    &COMPOSITE_EXIF_SHUTTERSPEED,
+    &COMPOSITE_SAMSUNG_SINGLESHOTDEPTHMAPTIFF,
+    &COMPOSITE_EXIF_SUBSECCREATEDATE,
+    &COMPOSITE_EXIF_SUBSECDATETIMEORIGINAL,
+    &COMPOSITE_EXIF_SUBSECMODIFYDATE,
+    &COMPOSITE_EXIF_THUMBNAILIMAGE,
+    &COMPOSITE_EXIF_THUMBNAILTIFF,
+    &COMPOSITE_KODAK_WB_RGBLEVELS,
+    &COMPOSITE_KODAK_WB_RGBLEVELS2,
+    &COMPOSITE_CANON_WB_RGGBLEVELS,
+    &COMPOSITE_SAMSUNG_WB_RGGBLEVELS,
+    &COMPOSITE_OLYMPUS_ZOOMEDPREVIEWIMAGE,
+];
+
+/// Composite tag definitions by name, every module's definition of each
+pub static COMPOSITE_TAG_LOOKUP: LazyLock<HashMap<&'static str, Vec<&'static CompositeTagDef>>> =
+    LazyLock::new(|| {
+        let mut lookup: HashMap<&'static str, Vec<&'static CompositeTagDef>> = HashMap::new();
+        for def in COMPOSITE_TAG_DEFS {
+            lookup.entry(def.name).or_default().push(def);
+        }
+        lookup
+    });
+
 /// Look up a composite tag definition by name
 pub fn lookup_composite_tag(name: &str) -> Option<&'static CompositeTagDef> {
     COMPOSITE_TAGS.get(name).copied()
 }
 
+/// Every module's definition of a composite tag
+pub fn lookup_composite_definitions(name: &str) -> &'static [&'static CompositeTagDef] {
+    COMPOSITE_TAG_LOOKUP.get(name).map_or(&[], Vec::as_slice)
+}
+
 /// Get all composite tag names
 pub fn all_composite_tag_names() -> Vec<&'static str> {
     COMPOSITE_TAGS.keys().copied().collect()
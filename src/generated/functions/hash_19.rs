@@ -48,7 +48,6 @@ pub fn ast_value_19012828a00d9b7(
     Ok(crate::core::negate(val) / 6i32)
 }
 
-/// PLACEHOLDER: Unsupported expression (missing implementation)
 /// Original perl expression:
 /// ``` perl
 /// Image::ExifTool::Nikon::PrintPC($val,"None","%.2f",4)
@@ -67,15 +66,6 @@ pub fn ast_value_19012828a00d9b7(
 /// - Nikon::PictureControl3.Saturation
 /// - Nikon::PictureControl3.Sharpness
 /// - Nikon::PictureControl3.ToningSaturation
-///
-/// TODO: Add support for this expression pattern
 pub fn ast_print_19075ce26854d0a(val: &TagValue, ctx: Option<&ExifContext>) -> TagValue {
-    tracing::warn!("Missing implementation for expression in {}", file!());
-    crate::core::missing::missing_print_conv(
-        0,                                                           // tag_id will be filled at runtime
-        "UnknownTag",   // tag_name will be filled at runtime
-        "UnknownGroup", // group will be filled at runtime
-        "Image::ExifTool::Nikon::PrintPC($val,\"None\",\"%.2f\",4)", // original expression
-        val,
-    )
+    crate::implementations::print_conv::nikon_pc2_print_conv(val, ctx)
 }
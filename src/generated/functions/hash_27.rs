@@ -29,22 +29,12 @@ pub fn ast_print_270a5dae063a1f32(val: &TagValue, ctx: Option<&ExifContext>) ->
     Into::<TagValue>::into(format!("{} Hz", val))
 }
 
-/// PLACEHOLDER: Unsupported expression (missing implementation)
 /// Original perl expression:
 /// ``` perl
 /// Image::ExifTool::Nikon::PrintPC($val,"No Sharpening","%d")
 /// ```
 /// Used by:
 /// - Nikon::PictureControl.Sharpness
-///
-/// TODO: Add support for this expression pattern
 pub fn ast_print_27f655efdb8691f1(val: &TagValue, ctx: Option<&ExifContext>) -> TagValue {
-    tracing::warn!("Missing implementation for expression in {}", file!());
-    crate::core::missing::missing_print_conv(
-        0,              // tag_id will be filled at runtime
-        "UnknownTag",   // tag_name will be filled at runtime
-        "UnknownGroup", // group will be filled at runtime
-        "Image::ExifTool::Nikon::PrintPC($val,\"No Sharpening\",\"%d\")", // original expression
-        val,
-    )
+    crate::implementations::print_conv::nikon_pc_sharpness_print_conv(val, ctx)
 }
@@ -36,8 +36,7 @@ pub fn ast_value_31a8ba0fed532190(
     val: &TagValue,
     ctx: Option<&ExifContext>,
 ) -> Result<TagValue, crate::core::types::ExifError> {
-    Ok(crate::core::exp(crate::implementations::canon::canon_ev(
-        val * 4i32 - 32i32,
-        ctx,
-    )))
+    Ok(crate::core::exp(
+        crate::implementations::value_conv::canon_ev(val * 4i32 - 32i32, ctx),
+    ))
 }
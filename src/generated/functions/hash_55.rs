@@ -46,7 +46,7 @@ pub fn ast_value_55048b24234b7885(
 ) -> Result<TagValue, crate::core::types::ExifError> {
     Ok(crate::core::exp(
         4i32 * crate::core::log(2i32)
-            * (1i32 - crate::implementations::canon::canon_ev(val - 24i32, ctx)),
+            * (1i32 - crate::implementations::value_conv::canon_ev(val - 24i32, ctx)),
     ))
 }
 
@@ -12,22 +12,12 @@
 use crate::core::{abs, atan2, cos, exp, int, log, power, sin, sqrt};
 use crate::types::{ExifContext, TagValue};
 
-/// PLACEHOLDER: Unsupported expression (missing implementation)
 /// Original perl expression:
 /// ``` perl
 /// Image::ExifTool::Nikon::PrintPC($val,"None")
 /// ```
 /// Used by:
 /// - Nikon::PictureControl.HueAdjustment
-///
-/// TODO: Add support for this expression pattern
 pub fn ast_print_6066204f5d926f8(val: &TagValue, ctx: Option<&ExifContext>) -> TagValue {
-    tracing::warn!("Missing implementation for expression in {}", file!());
-    crate::core::missing::missing_print_conv(
-        0,                                                // tag_id will be filled at runtime
-        "UnknownTag",                                     // tag_name will be filled at runtime
-        "UnknownGroup",                                   // group will be filled at runtime
-        "Image::ExifTool::Nikon::PrintPC($val,\"None\")", // original expression
-        val,
-    )
+    crate::implementations::print_conv::nikon_pc_hue_print_conv(val, ctx)
 }
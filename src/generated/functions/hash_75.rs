@@ -25,10 +25,9 @@ pub fn ast_value_75ea21c3208d8187(
     val: &TagValue,
     ctx: Option<&ExifContext>,
 ) -> Result<TagValue, crate::core::types::ExifError> {
-    Ok(crate::core::exp(crate::implementations::canon::canon_ev(
-        val.clone(),
-        ctx,
-    )))
+    Ok(crate::core::exp(
+        crate::implementations::value_conv::canon_ev(val.clone(), ctx),
+    ))
 }
 
 /// PLACEHOLDER: Unsupported expression (missing implementation)
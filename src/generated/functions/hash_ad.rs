@@ -12,7 +12,6 @@
 use crate::core::{abs, atan2, cos, exp, int, log, power, sin, sqrt};
 use crate::types::{ExifContext, TagValue};
 
-/// PLACEHOLDER: Unsupported expression (missing implementation)
 /// Original perl expression:
 /// ``` perl
 /// Image::ExifTool::Nikon::PrintPC($val,undef,"%.2f",4)
@@ -20,17 +19,8 @@ use crate::types::{ExifContext, TagValue};
 /// Used by:
 /// - Nikon::PictureControl2.Brightness
 /// - Nikon::PictureControl3.Brightness
-///
-/// TODO: Add support for this expression pattern
 pub fn ast_print_ad3a21a0c80cff63(val: &TagValue, ctx: Option<&ExifContext>) -> TagValue {
-    tracing::warn!("Missing implementation for expression in {}", file!());
-    crate::core::missing::missing_print_conv(
-        0,                                                        // tag_id will be filled at runtime
-        "UnknownTag",   // tag_name will be filled at runtime
-        "UnknownGroup", // group will be filled at runtime
-        "Image::ExifTool::Nikon::PrintPC($val,undef,\"%.2f\",4)", // original expression
-        val,
-    )
+    crate::implementations::print_conv::nikon_pc2_brightness_print_conv(val, ctx)
 }
 
 /// PLACEHOLDER: Unsupported expression (missing implementation)
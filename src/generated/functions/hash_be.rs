@@ -78,7 +78,6 @@ pub fn ast_value_beccf4b4cbb850ea(
     })
 }
 
-/// PLACEHOLDER: Unsupported expression (missing implementation)
 /// Original perl expression:
 /// ``` perl
 /// Image::ExifTool::Nikon::PrintPC($val)
@@ -90,15 +89,6 @@ pub fn ast_value_beccf4b4cbb850ea(
 /// - Nikon::PictureControl.Saturation
 /// - Nikon::PictureControl2.PictureControlQuickAdjust
 /// - Nikon::PictureControl3.PictureControlQuickAdjust
-///
-/// TODO: Add support for this expression pattern
 pub fn ast_print_be7ee827727a50d1(val: &TagValue, ctx: Option<&ExifContext>) -> TagValue {
-    tracing::warn!("Missing implementation for expression in {}", file!());
-    crate::core::missing::missing_print_conv(
-        0,                                       // tag_id will be filled at runtime
-        "UnknownTag",                            // tag_name will be filled at runtime
-        "UnknownGroup",                          // group will be filled at runtime
-        "Image::ExifTool::Nikon::PrintPC($val)", // original expression
-        val,
-    )
+    crate::implementations::print_conv::nikon_pc_print_conv(val, ctx)
 }
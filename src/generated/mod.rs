@@ -5,63 +5,200 @@
 //! This module re-exports all generated code for easy access.
 
 pub mod Apple_pm;
+#[cfg(feature = "canon")]
 pub mod CanonCustom_pm;
+#[cfg(feature = "canon")]
 pub mod CanonRaw_pm;
+#[cfg(feature = "canon")]
 pub mod Canon_pm;
+#[cfg(feature = "other-makers")]
 pub mod Casio_pm;
+#[cfg(feature = "other-makers")]
 pub mod DJI_pm;
 pub mod DNG_pm;
 pub mod ExifTool_pm;
 pub mod Exif_pm;
+#[cfg(feature = "fujifilm")]
 pub mod FujiFilm_pm;
 pub mod GIMP_pm;
 pub mod GPS_pm;
 pub mod GeoTiff_pm;
+#[cfg(feature = "video")]
 pub mod GoPro_pm;
+#[cfg(feature = "video")]
 pub mod H264_pm;
+#[cfg(feature = "other-makers")]
 pub mod HP_pm;
+pub mod ID3_pm;
 pub mod IPTC_pm;
 pub mod JPEG_pm;
 pub mod Jpeg2000_pm;
+#[cfg(feature = "other-makers")]
 pub mod Kodak_pm;
+#[cfg(feature = "raw-formats")]
 pub mod KyoceraRaw_pm;
 pub mod MIE_pm;
+#[cfg(feature = "xmp")]
 pub mod MWG_pm;
 pub mod MacOS_pm;
+#[cfg(feature = "video")]
 pub mod Matroska_pm;
 pub mod Microsoft_pm;
+#[cfg(feature = "raw-formats")]
 pub mod MinoltaRaw_pm;
+#[cfg(feature = "other-makers")]
 pub mod Motorola_pm;
+#[cfg(feature = "nikon")]
 pub mod Nikon_pm;
+#[cfg(feature = "other-makers")]
 pub mod Nintendo_pm;
+#[cfg(feature = "video")]
 pub mod Ogg_pm;
+#[cfg(feature = "olympus")]
 pub mod Olympus_pm;
 pub mod PNG_pm;
+#[cfg(feature = "raw-formats")]
 pub mod PanasonicRaw_pm;
+#[cfg(feature = "other-makers")]
 pub mod Panasonic_pm;
+#[cfg(feature = "other-makers")]
 pub mod Pentax_pm;
 pub mod Photoshop_pm;
+#[cfg(feature = "video")]
 pub mod QuickTime_pm;
+#[cfg(feature = "video")]
 pub mod RIFF_pm;
+#[cfg(feature = "video")]
 pub mod Red_pm;
+#[cfg(feature = "other-makers")]
 pub mod Ricoh_pm;
+#[cfg(feature = "other-makers")]
 pub mod Samsung_pm;
+#[cfg(feature = "other-makers")]
 pub mod Sanyo_pm;
+#[cfg(feature = "raw-formats")]
 pub mod SigmaRaw_pm;
+#[cfg(feature = "other-makers")]
 pub mod Sigma_pm;
+#[cfg(feature = "sony")]
 pub mod SonyIDC_pm;
+#[cfg(feature = "sony")]
 pub mod Sony_pm;
 pub mod Vorbis_pm;
+#[cfg(feature = "xmp")]
 pub mod XMP_pm;
 pub mod composite_tags;
 pub mod functions;
 
 // Re-export commonly used types and functions
 pub use composite_tags::{
-    all_composite_tag_names, composite_tag_count, lookup_composite_tag, CompositeTagDef,
-    COMPOSITE_TAGS,
+    all_composite_tag_names, composite_tag_count, lookup_composite_definitions,
+    lookup_composite_tag, CompositeTagDef, COMPOSITE_TAGS, COMPOSITE_TAG_DEFS,
+    COMPOSITE_TAG_LOOKUP,
 };
 
+/// Lookup tables of every generated module, by ExifTool module name
+pub static TABLE_GROUPS: &[(&str, &[crate::tables::LazyTable])] = &[
+    ("Apple", Apple_pm::TABLES),
+    #[cfg(feature = "canon")]
+    ("CanonCustom", CanonCustom_pm::TABLES),
+    #[cfg(feature = "canon")]
+    ("CanonRaw", CanonRaw_pm::TABLES),
+    #[cfg(feature = "canon")]
+    ("Canon", Canon_pm::TABLES),
+    #[cfg(feature = "other-makers")]
+    ("Casio", Casio_pm::TABLES),
+    #[cfg(feature = "other-makers")]
+    ("DJI", DJI_pm::TABLES),
+    ("DNG", DNG_pm::TABLES),
+    ("ExifTool", ExifTool_pm::TABLES),
+    ("Exif", Exif_pm::TABLES),
+    #[cfg(feature = "fujifilm")]
+    ("FujiFilm", FujiFilm_pm::TABLES),
+    ("GIMP", GIMP_pm::TABLES),
+    ("GPS", GPS_pm::TABLES),
+    ("GeoTiff", GeoTiff_pm::TABLES),
+    #[cfg(feature = "video")]
+    ("GoPro", GoPro_pm::TABLES),
+    #[cfg(feature = "video")]
+    ("H264", H264_pm::TABLES),
+    #[cfg(feature = "other-makers")]
+    ("HP", HP_pm::TABLES),
+    ("ID3", ID3_pm::TABLES),
+    ("IPTC", IPTC_pm::TABLES),
+    ("JPEG", JPEG_pm::TABLES),
+    ("Jpeg2000", Jpeg2000_pm::TABLES),
+    #[cfg(feature = "other-makers")]
+    ("Kodak", Kodak_pm::TABLES),
+    #[cfg(feature = "raw-formats")]
+    ("KyoceraRaw", KyoceraRaw_pm::TABLES),
+    ("MIE", MIE_pm::TABLES),
+    #[cfg(feature = "xmp")]
+    ("MWG", MWG_pm::TABLES),
+    ("MacOS", MacOS_pm::TABLES),
+    #[cfg(feature = "video")]
+    ("Matroska", Matroska_pm::TABLES),
+    ("Microsoft", Microsoft_pm::TABLES),
+    #[cfg(feature = "raw-formats")]
+    ("MinoltaRaw", MinoltaRaw_pm::TABLES),
+    #[cfg(feature = "other-makers")]
+    ("Motorola", Motorola_pm::TABLES),
+    #[cfg(feature = "nikon")]
+    ("Nikon", Nikon_pm::TABLES),
+    #[cfg(feature = "other-makers")]
+    ("Nintendo", Nintendo_pm::TABLES),
+    #[cfg(feature = "video")]
+    ("Ogg", Ogg_pm::TABLES),
+    #[cfg(feature = "olympus")]
+    ("Olympus", Olympus_pm::TABLES),
+    ("PNG", PNG_pm::TABLES),
+    #[cfg(feature = "raw-formats")]
+    ("PanasonicRaw", PanasonicRaw_pm::TABLES),
+    #[cfg(feature = "other-makers")]
+    ("Panasonic", Panasonic_pm::TABLES),
+    #[cfg(feature = "other-makers")]
+    ("Pentax", Pentax_pm::TABLES),
+    ("Photoshop", Photoshop_pm::TABLES),
+    #[cfg(feature = "video")]
+    ("QuickTime", QuickTime_pm::TABLES),
+    #[cfg(feature = "video")]
+    ("RIFF", RIFF_pm::TABLES),
+    #[cfg(feature = "video")]
+    ("Red", Red_pm::TABLES),
+    #[cfg(feature = "other-makers")]
+    ("Ricoh", Ricoh_pm::TABLES),
+    #[cfg(feature = "other-makers")]
+    ("Samsung", Samsung_pm::TABLES),
+    #[cfg(feature = "other-makers")]
+    ("Sanyo", Sanyo_pm::TABLES),
+    #[cfg(feature = "raw-formats")]
+    ("SigmaRaw", SigmaRaw_pm::TABLES),
+    #[cfg(feature = "other-makers")]
+    ("Sigma", Sigma_pm::TABLES),
+    #[cfg(feature = "sony")]
+    ("SonyIDC", SonyIDC_pm::TABLES),
+    #[cfg(feature = "sony")]
+    ("Sony", Sony_pm::TABLES),
+    ("Vorbis", Vorbis_pm::TABLES),
+    #[cfg(feature = "xmp")]
+    ("XMP", XMP_pm::TABLES),
+    (
+        "Composite",
+        &[
+            crate::tables::lazy_table!(composite_tags::COMPOSITE_TAGS),
+            crate::tables::lazy_table!(composite_tags::COMPOSITE_TAG_LOOKUP),
+        ],
+    ),
+];
+
+/// ProcessBinaryData tables of every generated module, by ExifTool module name
+pub static BINARY_TABLE_GROUPS: &[(&str, &[crate::types::GeneratedBinaryTable])] = &[
+    #[cfg(feature = "sony")]
+    ("Sony", Sony_pm::binary_tables::BINARY_TABLES),
+];
+
 /// Initialize all lazy static data structures
 /// This can be called during startup to avoid lazy initialization costs later
-pub fn initialize_all() {}
+pub fn initialize_all() {
+    crate::tables::initialize_all();
+}
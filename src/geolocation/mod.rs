@@ -0,0 +1,406 @@
+//! Reverse geocoding of GPS positions to the nearest city
+//!
+//! ExifTool 12.78+ resolves GPS coordinates to the nearest city from its
+//! Geolocation.dat database (`-api geolocation`), producing GeolocationCity,
+//! GeolocationRegion, GeolocationCountryCode and related tags. exif-oxide does
+//! the same when `FilterOptions::geolocation` is set (CLI `--geolocation`).
+//!
+//! The compact built-in database of capitals and major cities (`cities.csv`)
+//! is embedded with the `geolocation` cargo feature. It was compiled by hand
+//! for exif-oxide rather than derived from ExifTool's Geolocation.dat, and is
+//! distributed under the crate's license (AGPL-3.0-or-later). Applications that
+//! need better coverage can install their own with [`set_database`], e.g.
+//! built from GeoNames `cities1000.txt` (CC BY 4.0) via
+//! [`GeolocationDatabase::from_csv`]. The custom database replaces the
+//! built-in one.
+//!
+//! Positions farther than [`DEFAULT_MAX_DISTANCE_KM`] from every city aren't
+//! geolocated; [`GeolocationDatabase::with_max_distance`] changes the limit.
+//!
+//! With the `timezone` feature, nearby cities' time zone is also used to infer
+//! the local time zone of files without a recorded offset (see
+//! [`time_zone_at`]).
+
+#[cfg(feature = "timezone")]
+mod time_zone;
+
+#[cfg(feature = "timezone")]
+pub(crate) use time_zone::infer_time_zone;
+#[cfg(feature = "timezone")]
+pub use time_zone::{time_zone_at, MAX_TIME_ZONE_DISTANCE_KM};
+
+use std::sync::{Arc, RwLock};
+
+use crate::types::{ExifError, Result, TagEntry, TagValue};
+
+/// Group 0 and 1 of the tags produced by geolocation
+pub const GEOLOCATION_GROUP: &str = "Geolocation";
+
+/// Mean Earth radius used for great-circle distances
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Default maximum distance from a position to its nearest city, in km
+///
+/// ExifTool has no limit unless `-api GeolocMaxDist` is set, but its database
+/// has every city of 2000 people or more. With the few hundred cities of the
+/// built-in database, a position at sea or in a remote area would otherwise
+/// be placed in a city thousands of km away.
+pub const DEFAULT_MAX_DISTANCE_KM: f64 = 250.0;
+
+/// A city in a geolocation database
+#[derive(Debug, Clone, PartialEq)]
+pub struct City {
+    pub name: String,
+    /// First-level administrative region (state, province, ...)
+    pub region: String,
+    /// ISO 3166-1 alpha-2 country code
+    pub country_code: String,
+    pub country: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub population: u64,
+    /// IANA time zone name (e.g., "Europe/Paris")
+    pub time_zone: String,
+}
+
+/// The city nearest to a GPS position
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeolocationMatch<'a> {
+    pub city: &'a City,
+    /// Great-circle distance from the position to the city, in km
+    pub distance_km: f64,
+    /// Compass bearing from the position to the city, in degrees
+    pub bearing: f64,
+}
+
+/// A searchable set of cities
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeolocationDatabase {
+    cities: Vec<City>,
+    max_distance_km: f64,
+}
+
+impl Default for GeolocationDatabase {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl GeolocationDatabase {
+    pub fn new(cities: Vec<City>) -> Self {
+        Self {
+            cities,
+            max_distance_km: DEFAULT_MAX_DISTANCE_KM,
+        }
+    }
+
+    /// Set the maximum distance from a position to its nearest city, in km
+    /// ExifTool equivalent: `-api GeolocMaxDist`
+    pub fn with_max_distance(mut self, max_distance_km: f64) -> Self {
+        self.max_distance_km = max_distance_km;
+        self
+    }
+
+    /// Parse a database from CSV text
+    ///
+    /// One city per line: `City,Region,CountryCode,Country,Latitude,Longitude,Population,TimeZone`.
+    /// Blank lines and lines starting with `#` are ignored; fields can't contain commas.
+    pub fn from_csv(csv: &str) -> Result<Self> {
+        let cities = csv
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|(index, line)| {
+                parse_city(line).ok_or_else(|| {
+                    ExifError::ParseError(format!(
+                        "Invalid geolocation database line {}: {line}",
+                        index + 1
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(cities))
+    }
+
+    /// The compact database embedded with the `geolocation` feature
+    #[cfg(feature = "geolocation")]
+    pub fn builtin() -> Arc<GeolocationDatabase> {
+        use std::sync::LazyLock;
+        static BUILTIN: LazyLock<Arc<GeolocationDatabase>> = LazyLock::new(|| {
+            Arc::new(
+                GeolocationDatabase::from_csv(include_str!("cities.csv"))
+                    .expect("Invalid built-in geolocation database"),
+            )
+        });
+        BUILTIN.clone()
+    }
+
+    pub fn cities(&self) -> &[City] {
+        &self.cities
+    }
+
+    /// Find the city nearest to a position given in signed decimal degrees
+    ///
+    /// None if no city is within the maximum distance.
+    pub fn nearest(&self, latitude: f64, longitude: f64) -> Option<GeolocationMatch<'_>> {
+        self.within(latitude, longitude, self.max_distance_km)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(city, distance_km)| GeolocationMatch {
+                city,
+                distance_km,
+                bearing: bearing(latitude, longitude, city),
+            })
+    }
+
+    /// The cities within `max_distance_km` of a position, with their distances
+    pub(crate) fn within(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        max_distance_km: f64,
+    ) -> impl Iterator<Item = (&City, f64)> {
+        let valid = (-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude);
+        self.cities
+            .iter()
+            .filter(move |_| valid)
+            .map(move |city| (city, distance_km(latitude, longitude, city)))
+            .filter(move |(_, distance)| *distance <= max_distance_km)
+    }
+}
+
+impl GeolocationMatch<'_> {
+    /// Build the Geolocation tags for this match
+    /// ExifTool: lib/Image/ExifTool/Geolocation.pm tag names
+    pub fn tags(&self) -> Vec<TagEntry> {
+        let city = self.city;
+        let distance = (self.distance_km * 100.0).round() / 100.0;
+        let strings = [
+            ("GeolocationCity", &city.name),
+            ("GeolocationRegion", &city.region),
+            ("GeolocationCountryCode", &city.country_code),
+            ("GeolocationCountry", &city.country),
+            ("GeolocationTimeZone", &city.time_zone),
+        ];
+        strings
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(name, value)| geolocation_tag(name, value.as_str().into(), None))
+            .chain([
+                geolocation_tag(
+                    "GeolocationPopulation",
+                    TagValue::U64(city.population),
+                    None,
+                ),
+                geolocation_tag(
+                    "GeolocationPosition",
+                    format!("{} {}", city.latitude, city.longitude).into(),
+                    None,
+                ),
+                geolocation_tag(
+                    "GeolocationDistance",
+                    TagValue::F64(distance),
+                    Some(format!("{distance:.2} km").into()),
+                ),
+                geolocation_tag(
+                    "GeolocationBearing",
+                    TagValue::U16(self.bearing.round() as u16 % 360),
+                    None,
+                ),
+            ])
+            .collect()
+    }
+}
+
+fn geolocation_tag(name: &str, value: TagValue, print: Option<TagValue>) -> TagEntry {
+    TagEntry {
+        group: GEOLOCATION_GROUP.into(),
+        group1: GEOLOCATION_GROUP.into(),
+        name: name.into(),
+        print: print.unwrap_or_else(|| value.clone()).into(),
+        value,
+        source: None,
+    }
+}
+
+fn parse_city(line: &str) -> Option<City> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    let [name, region, country_code, country, latitude, longitude, population, time_zone] =
+        fields.as_slice()
+    else {
+        return None;
+    };
+    Some(City {
+        name: name.to_string(),
+        region: region.to_string(),
+        country_code: country_code.to_string(),
+        country: country.to_string(),
+        latitude: latitude.parse().ok()?,
+        longitude: longitude.parse().ok()?,
+        population: population.parse().ok()?,
+        time_zone: time_zone.to_string(),
+    })
+}
+
+/// Haversine great-circle distance
+fn distance_km(latitude: f64, longitude: f64, city: &City) -> f64 {
+    let (lat1, lat2) = (latitude.to_radians(), city.latitude.to_radians());
+    let dlat = lat2 - lat1;
+    let dlon = (city.longitude - longitude).to_radians();
+    let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().atan2((1.0 - a).sqrt())
+}
+
+/// Initial compass bearing from the position to the city
+fn bearing(latitude: f64, longitude: f64, city: &City) -> f64 {
+    let (lat1, lat2) = (latitude.to_radians(), city.latitude.to_radians());
+    let dlon = (city.longitude - longitude).to_radians();
+    let y = dlon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlon.cos();
+    y.atan2(x).to_degrees().rem_euclid(360.0)
+}
+
+static CUSTOM_DATABASE: RwLock<Option<Arc<GeolocationDatabase>>> = RwLock::new(None);
+
+/// Install a custom geolocation database, replacing the built-in one
+///
+/// Pass `None` to go back to the built-in database (if the `geolocation`
+/// feature is enabled). ExifTool equivalent: `-api GeoDir=DIR`
+pub fn set_database(database: Option<GeolocationDatabase>) {
+    let mut custom = CUSTOM_DATABASE
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *custom = database.map(Arc::new);
+}
+
+/// The database used for geolocation: the custom one, else the built-in one
+///
+/// Inside [`ExifSession`](crate::session::ExifSession) extraction, the
+/// session's database takes the place of the custom one.
+pub fn database() -> Option<Arc<GeolocationDatabase>> {
+    let custom = match crate::session::current() {
+        Some(session) => session.geolocation.clone(),
+        None => CUSTOM_DATABASE
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone(),
+    };
+    #[cfg(feature = "geolocation")]
+    let custom = custom.or_else(|| Some(GeolocationDatabase::builtin()));
+    custom
+}
+
+/// Composite:GPSLatitude/GPSLongitude of a file, in signed decimal degrees
+fn gps_position(tags: &[TagEntry]) -> Option<(f64, f64)> {
+    let coordinate = |name: &str| {
+        tags.iter()
+            .find(|tag| tag.group == "Composite" && tag.name == name)
+            .and_then(|tag| tag.value.as_f64())
+    };
+    Some((coordinate("GPSLatitude")?, coordinate("GPSLongitude")?))
+}
+
+/// Geolocation tags for the Composite:GPSLatitude/GPSLongitude of a file
+///
+/// Empty if there's no GPS position or no database.
+pub(crate) fn geolocate(tags: &[TagEntry]) -> Vec<TagEntry> {
+    let Some((latitude, longitude)) = gps_position(tags) else {
+        return Vec::new();
+    };
+    database()
+        .and_then(|database| {
+            database
+                .nearest(latitude, longitude)
+                .map(|found| found.tags())
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSV: &str = "\
+# City,Region,CountryCode,Country,Latitude,Longitude,Population,TimeZone
+Paris,Ile-de-France,FR,France,48.8566,2.3522,2138551,Europe/Paris
+
+Lyon,Auvergne-Rhone-Alpes,FR,France,45.7640,4.8357,522969,Europe/Paris
+";
+
+    fn get<'a>(tags: &'a [TagEntry], name: &str) -> &'a TagEntry {
+        tags.iter().find(|tag| tag.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_nearest_city() {
+        let database = GeolocationDatabase::from_csv(CSV).unwrap();
+        assert_eq!(database.cities().len(), 2);
+
+        // Versailles, ~17 km WSW of Paris
+        let found = database.nearest(48.8049, 2.1204).unwrap();
+        assert_eq!(found.city.name, "Paris");
+        assert!(
+            (found.distance_km - 17.8).abs() < 0.5,
+            "{}",
+            found.distance_km
+        );
+        assert!((found.bearing - 71.0).abs() < 2.0, "{}", found.bearing);
+
+        let tags = found.tags();
+        assert_eq!(
+            get(&tags, "GeolocationCity").value,
+            TagValue::string("Paris")
+        );
+        assert_eq!(
+            get(&tags, "GeolocationRegion").value,
+            TagValue::string("Ile-de-France")
+        );
+        assert_eq!(
+            get(&tags, "GeolocationCountryCode").value,
+            TagValue::string("FR")
+        );
+        assert_eq!(
+            get(&tags, "GeolocationTimeZone").value,
+            TagValue::string("Europe/Paris")
+        );
+        assert_eq!(
+            get(&tags, "GeolocationPopulation").value,
+            TagValue::U64(2138551)
+        );
+        let distance = get(&tags, "GeolocationDistance");
+        assert_eq!(
+            distance.print(),
+            &TagValue::string(format!("{:.2} km", found.distance_km))
+        );
+        assert!(tags.iter().all(|tag| tag.group == GEOLOCATION_GROUP));
+
+        // Near Lyon
+        assert_eq!(database.nearest(45.75, 4.85).unwrap().city.name, "Lyon");
+        // Out of range
+        assert!(database.nearest(91.0, 0.0).is_none());
+
+        // Bay of Biscay, ~550 km from Paris
+        assert!(database.nearest(45.5, -3.0).is_none());
+        let database = database.with_max_distance(1000.0);
+        assert_eq!(database.nearest(45.5, -3.0).unwrap().city.name, "Paris");
+    }
+
+    #[test]
+    fn test_invalid_csv() {
+        let err = GeolocationDatabase::from_csv("Paris,FR,48.8566").unwrap_err();
+        assert!(err.to_string().contains("line 1"), "{err}");
+    }
+
+    #[test]
+    fn test_empty_database() {
+        assert!(GeolocationDatabase::default().nearest(0.0, 0.0).is_none());
+    }
+
+    #[cfg(feature = "geolocation")]
+    #[test]
+    fn test_builtin_database() {
+        let database = GeolocationDatabase::builtin();
+        assert!(database.cities().len() > 100);
+        let found = database.nearest(40.7484, -73.9857).unwrap();
+        assert_eq!(found.city.name, "New York City");
+    }
+}
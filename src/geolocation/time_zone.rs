@@ -0,0 +1,223 @@
+//! Time zone inference from the GPS position
+//!
+//! Many cameras have no time zone setting and write local times without
+//! OffsetTime tags. For files with a GPS position, the zone of the nearest
+//! city in the geolocation database gives the local zone, and the tz database
+//! embedded with the `timezone` feature gives its UTC offset on the capture
+//! date (daylight saving time included).
+//!
+//! The geolocation database has no zone boundaries, so a zone is only
+//! inferred when the nearest city is within [`MAX_TIME_ZONE_DISTANCE_KM`] and
+//! every city that close shares its country and zone. Positions far from any
+//! city, or near a border, get nothing rather than a neighbour's zone.
+//!
+//! - `Composite:InferredTimeZone`: IANA zone name, e.g. `America/New_York`
+//! - `Composite:InferredDateTime`: Composite:BestDateTime as local time in
+//!   that zone, with its offset
+//!
+//! Nothing is inferred when Composite:BestDateTime has an offset recorded with
+//! the date (OffsetTimeOriginal, a zone in an XMP date, ...). Offsets derived
+//! from the GPS time stamp, or assumed (UTC QuickTime dates, the file system
+//! date), are replaced: a UTC instant is converted to local time, and a local
+//! time without a zone gets the zone's offset.
+
+use chrono::{Duration, Offset, TimeZone};
+use chrono_tz::Tz;
+
+use super::{database, gps_position, GeolocationDatabase};
+use crate::composite_tags::{format_offset, is_recorded_zone, parse_date};
+use crate::types::{TagEntry, TagValue};
+
+/// Maximum distance from a position to the city whose zone it takes, in km
+pub const MAX_TIME_ZONE_DISTANCE_KM: f64 = 100.0;
+
+/// The time zone at a position, from the nearby cities in the geolocation
+/// database
+///
+/// None unless the nearest city is within [`MAX_TIME_ZONE_DISTANCE_KM`] and
+/// every city that close is in the same country and zone.
+pub fn time_zone_at(latitude: f64, longitude: f64) -> Option<Tz> {
+    time_zone_in(&*database()?, latitude, longitude)
+}
+
+fn time_zone_in(database: &GeolocationDatabase, latitude: f64, longitude: f64) -> Option<Tz> {
+    let nearby: Vec<_> = database
+        .within(latitude, longitude, MAX_TIME_ZONE_DISTANCE_KM)
+        .map(|(city, _)| city)
+        .collect();
+    let city = nearby.first()?;
+    let same_zone = nearby
+        .iter()
+        .all(|other| other.country_code == city.country_code && other.time_zone == city.time_zone);
+    if !same_zone {
+        return None;
+    }
+    city.time_zone.parse().ok()
+}
+
+/// Composite:InferredTimeZone and Composite:InferredDateTime for a file
+///
+/// Empty if there's no GPS position, no database, or the capture date has a
+/// recorded offset.
+pub(crate) fn infer_time_zone(tags: &[TagEntry]) -> Vec<TagEntry> {
+    let composite = |name: &str| {
+        tags.iter()
+            .find(|tag| tag.group == "Composite" && tag.name == name)
+            .and_then(|tag| tag.value.as_string())
+    };
+    if composite("BestDateTimeZoneSource").is_some_and(is_recorded_zone) {
+        return Vec::new();
+    }
+    let Some(time_zone) = gps_position(tags).and_then(|(lat, lon)| time_zone_at(lat, lon)) else {
+        return Vec::new();
+    };
+
+    let mut inferred = vec![composite_tag(
+        "InferredTimeZone",
+        TagValue::string(time_zone.name()),
+    )];
+    if let Some(date_time) = composite("BestDateTime").and_then(|date| local_time(date, time_zone))
+    {
+        inferred.push(composite_tag(
+            "InferredDateTime",
+            TagValue::String(date_time),
+        ));
+    }
+    inferred
+}
+
+/// `date` as local time in `time_zone`, with its offset
+fn local_time(date: &str, time_zone: Tz) -> Option<String> {
+    let (date_time, naive, zone) = parse_date(date)?;
+    let local = match zone {
+        Some(minutes) => time_zone.from_utc_datetime(&(naive - Duration::minutes(minutes.into()))),
+        // Ambiguous (DST fall-back) times take the earlier offset; times in a
+        // spring-forward gap don't exist locally
+        None => time_zone.from_local_datetime(&naive).earliest()?,
+    };
+    let offset_minutes = local.offset().fix().local_minus_utc() / 60;
+    // Keep the sub-seconds of the original
+    let sub_seconds = date_time.get(19..).unwrap_or("");
+    Some(format!(
+        "{}{sub_seconds}{}",
+        local.format("%Y:%m:%d %H:%M:%S"),
+        format_offset(offset_minutes)
+    ))
+}
+
+fn composite_tag(name: &str, value: TagValue) -> TagEntry {
+    TagEntry {
+        group: "Composite".into(),
+        group1: "Composite".into(),
+        name: name.into(),
+        print: value.clone().into(),
+        value,
+        source: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geolocation::{set_database, GeolocationDatabase};
+
+    fn composite(name: &str, value: &str) -> TagEntry {
+        composite_tag(name, TagValue::string(value))
+    }
+
+    fn values(tags: &[TagEntry]) -> Vec<(&str, &TagValue)> {
+        tags.iter()
+            .map(|tag| (tag.name.as_ref(), &tag.value))
+            .collect()
+    }
+
+    fn position(latitude: f64, longitude: f64) -> Vec<TagEntry> {
+        vec![
+            composite_tag("GPSLatitude", TagValue::F64(latitude)),
+            composite_tag("GPSLongitude", TagValue::F64(longitude)),
+        ]
+    }
+
+    #[test]
+    fn test_local_time() {
+        let new_york: Tz = "America/New_York".parse().unwrap();
+        // Local time without a zone gets the DST offset on that date
+        assert_eq!(
+            local_time("2024:07:04 12:00:00.25", new_york).as_deref(),
+            Some("2024:07:04 12:00:00.25-04:00")
+        );
+        assert_eq!(
+            local_time("2024:01:04 12:00:00", new_york).as_deref(),
+            Some("2024:01:04 12:00:00-05:00")
+        );
+        // UTC instants are converted
+        assert_eq!(
+            local_time("2024:07:04 12:00:00+00:00", new_york).as_deref(),
+            Some("2024:07:04 08:00:00-04:00")
+        );
+        // Skipped by the spring-forward change
+        assert_eq!(local_time("2024:03:10 02:30:00", new_york), None);
+    }
+
+    #[test]
+    fn test_time_zone_in() {
+        let database = GeolocationDatabase::from_csv(
+            "\
+Chicago,Illinois,US,United States,41.8781,-87.6298,2746388,America/Chicago
+Detroit,Michigan,US,United States,42.3314,-83.0458,639111,America/Detroit
+Windsor,Ontario,CA,Canada,42.3149,-83.0364,229660,America/Toronto
+",
+        )
+        .unwrap();
+        let zone = |latitude, longitude| {
+            time_zone_in(&database, latitude, longitude).map(|zone| zone.name().to_string())
+        };
+
+        // Evanston, next to Chicago
+        assert_eq!(zone(42.0451, -87.6877).as_deref(), Some("America/Chicago"));
+        // Indianapolis is ~260 km from Chicago, and in another zone
+        assert_eq!(zone(39.7684, -86.1581), None);
+        // Detroit and Windsor are in different countries
+        assert_eq!(zone(42.33, -83.04), None);
+    }
+
+    #[test]
+    fn test_infer_time_zone() {
+        // Tests share the global custom database, so this is the only one
+        // that installs it
+        set_database(Some(
+            GeolocationDatabase::from_csv(
+                "Paris,Ile-de-France,FR,France,48.8566,2.3522,2138551,Europe/Paris\n",
+            )
+            .unwrap(),
+        ));
+
+        let mut tags = position(48.85, 2.35);
+        tags.push(composite("BestDateTime", "2024:07:01 10:00:00+00:00"));
+        tags.push(composite("BestDateTimeZoneSource", "QuickTime:CreateDate"));
+        assert_eq!(
+            values(&infer_time_zone(&tags)),
+            [
+                ("InferredTimeZone", &TagValue::string("Europe/Paris")),
+                (
+                    "InferredDateTime",
+                    &TagValue::string("2024:07:01 12:00:00+02:00")
+                ),
+            ]
+        );
+
+        // A recorded offset wins
+        tags[3] = composite("BestDateTimeZoneSource", "EXIF:OffsetTimeOriginal");
+        assert!(infer_time_zone(&tags).is_empty());
+
+        // No date: just the zone
+        assert_eq!(
+            values(&infer_time_zone(&position(48.85, 2.35))),
+            [("InferredTimeZone", &TagValue::string("Europe/Paris"))]
+        );
+        // No position
+        assert!(infer_time_zone(&[composite("BestDateTime", "2024:07:01 10:00:00")]).is_empty());
+
+        set_database(None);
+    }
+}
@@ -17,8 +17,11 @@
 //! ## Supported Hash Algorithms
 //!
 //! - **MD5** (default): 32-character hex string, matches ExifTool default
+//! - **SHA1**: 40-character hex string
 //! - **SHA256**: 64-character hex string
 //! - **SHA512**: 128-character hex string
+//! - **XXH3**: 16-character hex string (64-bit XXH3, not cryptographic).
+//!   Not an ExifTool option; much faster for deduplicating large videos
 //!
 //! ## What Gets Hashed
 //!
@@ -27,6 +30,22 @@
 //! - **JPEG**: SOS marker through EOI (scan data + RST markers + stuffed bytes)
 //! - **PNG**: IDAT, JDAT, fdAT chunk data (not headers or CRC)
 //! - **TIFF**: Data at StripOffsets/TileOffsets/JpgFromRawStart (tags with IsImageData)
+//! - **MOV/MP4**: Contents of the `mdat` atoms, streamed in 64KB chunks
+//!
+//! ## Progress Reporting
+//!
+//! Hashing a multi-gigabyte video `mdat` takes a while, so a callback set with
+//! [`ImageDataHasher::with_progress`] is called after every chunk read by
+//! [`ImageDataHasher::hash_from_reader`] with the total bytes hashed so far.
+//!
+//! ## Chunk Digests
+//!
+//! [`ImageDataHasher::with_chunk_size`] also hashes the image data stream in
+//! fixed-size chunks, like the pieces of a torrent, so a sync tool can verify
+//! part of a large video without reading all of it. The chunks are cut at
+//! offsets into the hashed data (not the file), and each is hashed with the
+//! same algorithm as the whole. This is an exif-oxide extension; the CLI
+//! reports the digests as `File:ImageDataHashChunks`.
 //!
 //! ## ExifTool Source References
 //!
@@ -38,9 +57,11 @@
 
 use digest::{Digest, DynDigest};
 use md5::Md5;
+use sha1::Sha1;
 use sha2::{Sha256, Sha512};
 use std::fmt;
 use std::io::{Read, Seek, SeekFrom};
+use xxhash_rust::xxh3::Xxh3;
 
 /// Hash algorithm selection for ImageDataHash
 ///
@@ -50,20 +71,32 @@ pub enum ImageHashType {
     /// MD5 hash (32 hex chars) - ExifTool default
     #[default]
     Md5,
+    /// SHA-1 hash (40 hex chars)
+    Sha1,
     /// SHA-256 hash (64 hex chars)
     Sha256,
     /// SHA-512 hash (128 hex chars)
     Sha512,
+    /// 64-bit XXH3 hash (16 hex chars, not cryptographic)
+    Xxh3,
 }
 
 impl ImageHashType {
     /// Parse from string (case-insensitive), matching ExifTool API
     /// Note: Using parse_str instead of from_str to avoid FromStr trait expectation
     pub fn parse_str(s: &str) -> Self {
+        Self::parse_known(s).unwrap_or_default() // Default to MD5
+    }
+
+    /// Parse from string (case-insensitive), or None for an unknown algorithm
+    pub fn parse_known(s: &str) -> Option<Self> {
         match s.to_uppercase().as_str() {
-            "SHA256" => Self::Sha256,
-            "SHA512" => Self::Sha512,
-            _ => Self::Md5, // Default to MD5
+            "MD5" => Some(Self::Md5),
+            "SHA1" => Some(Self::Sha1),
+            "SHA256" => Some(Self::Sha256),
+            "SHA512" => Some(Self::Sha512),
+            "XXH3" => Some(Self::Xxh3),
+            _ => None,
         }
     }
 
@@ -72,8 +105,10 @@ impl ImageHashType {
     pub fn empty_hash(&self) -> &'static str {
         match self {
             Self::Md5 => "d41d8cd98f00b204e9800998ecf8427e",
+            Self::Sha1 => "da39a3ee5e6b4b0d3255bfef95601890afd80709",
             Self::Sha256 => "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
             Self::Sha512 => "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e",
+            Self::Xxh3 => "2d06800538d394c2",
         }
     }
 }
@@ -82,10 +117,70 @@ impl fmt::Display for ImageHashType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Md5 => write!(f, "MD5"),
+            Self::Sha1 => write!(f, "SHA1"),
             Self::Sha256 => write!(f, "SHA256"),
             Self::Sha512 => write!(f, "SHA512"),
+            Self::Xxh3 => write!(f, "XXH3"),
+        }
+    }
+}
+
+/// Progress callback, called with the total number of bytes hashed so far
+pub type HashProgress = Box<dyn FnMut(u64) + Send>;
+
+/// Hash algorithm implementation
+enum HashState {
+    /// RustCrypto digests (MD5, SHA1, SHA256, SHA512)
+    Digest(Box<dyn DynDigest + Send>),
+    /// XXH3 isn't a RustCrypto digest
+    Xxh3(Box<Xxh3>),
+}
+
+impl HashState {
+    fn new(hash_type: ImageHashType) -> Self {
+        match hash_type {
+            ImageHashType::Md5 => Self::Digest(Box::new(Md5::new())),
+            ImageHashType::Sha1 => Self::Digest(Box::new(Sha1::new())),
+            ImageHashType::Sha256 => Self::Digest(Box::new(Sha256::new())),
+            ImageHashType::Sha512 => Self::Digest(Box::new(Sha512::new())),
+            ImageHashType::Xxh3 => Self::Xxh3(Box::new(Xxh3::new())),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Digest(hasher) => hasher.update(data),
+            Self::Xxh3(hasher) => hasher.update(data),
         }
     }
+
+    fn finalize(self) -> String {
+        match self {
+            Self::Digest(hasher) => hex_encode(&hasher.finalize()),
+            // Canonical (big-endian) form, as printed by xxhsum
+            Self::Xxh3(hasher) => format!("{:016x}", hasher.digest()),
+        }
+    }
+}
+
+/// Digests of consecutive fixed-size chunks of the image data
+///
+/// Every chunk is `chunk_size` bytes except the last, which holds the rest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkDigests {
+    /// Chunk size in bytes
+    pub chunk_size: u64,
+    /// Hex digest of each chunk, in stream order
+    pub digests: Vec<String>,
+}
+
+/// The chunk being hashed and the digests of the finished ones
+struct ChunkState {
+    chunk_size: u64,
+    hasher: HashState,
+    /// Bytes in the current chunk
+    filled: u64,
+    digests: Vec<String>,
 }
 
 /// Streaming hasher for image data
@@ -94,35 +189,74 @@ impl fmt::Display for ImageHashType {
 /// Follows ExifTool's approach of maintaining a hash object throughout
 /// file parsing and finalizing at the end.
 pub struct ImageDataHasher {
-    /// The underlying hasher (boxed for type erasure)
-    hasher: Box<dyn DynDigest + Send>,
+    /// The underlying hasher
+    hasher: HashState,
     /// Algorithm type for empty hash detection
     hash_type: ImageHashType,
     /// Total bytes hashed (for verbose output)
     bytes_hashed: u64,
+    /// Called after each chunk read by [`Self::hash_from_reader`]
+    progress: Option<HashProgress>,
+    /// Per-chunk digests, when enabled with [`Self::with_chunk_size`]
+    chunks: Option<ChunkState>,
 }
 
 impl ImageDataHasher {
     /// Create a new hasher with the specified algorithm
     pub fn new(hash_type: ImageHashType) -> Self {
-        let hasher: Box<dyn DynDigest + Send> = match hash_type {
-            ImageHashType::Md5 => Box::new(Md5::new()),
-            ImageHashType::Sha256 => Box::new(Sha256::new()),
-            ImageHashType::Sha512 => Box::new(Sha512::new()),
-        };
-
         Self {
-            hasher,
+            hasher: HashState::new(hash_type),
             hash_type,
             bytes_hashed: 0,
+            progress: None,
+            chunks: None,
         }
     }
 
+    /// Report progress while hashing from a reader
+    ///
+    /// The callback receives the total bytes hashed so far after every 64KB
+    /// chunk, which is useful for showing progress on large video files.
+    pub fn with_progress(mut self, progress: impl FnMut(u64) + Send + 'static) -> Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Also compute a digest of every `chunk_size` bytes of image data
+    ///
+    /// A `chunk_size` of 0 disables chunk digests. See
+    /// [`Self::finalize_chunked`].
+    pub fn with_chunk_size(mut self, chunk_size: u64) -> Self {
+        self.chunks = (chunk_size > 0).then(|| ChunkState {
+            chunk_size,
+            hasher: HashState::new(self.hash_type),
+            filled: 0,
+            digests: Vec::new(),
+        });
+        self
+    }
+
     /// Add data to the hash
     #[inline]
     pub fn update(&mut self, data: &[u8]) {
         self.hasher.update(data);
         self.bytes_hashed += data.len() as u64;
+
+        let Some(chunks) = self.chunks.as_mut() else {
+            return;
+        };
+        let mut data = data;
+        while !data.is_empty() {
+            let room = (chunks.chunk_size - chunks.filled).min(data.len() as u64) as usize;
+            chunks.hasher.update(&data[..room]);
+            chunks.filled += room as u64;
+            data = &data[room..];
+            if chunks.filled == chunks.chunk_size {
+                let full = std::mem::replace(&mut chunks.hasher, HashState::new(self.hash_type));
+                chunks.digests.push(full.finalize());
+                chunks.filled = 0;
+            }
+        }
     }
 
     /// Hash data from a reader with optional size limit
@@ -131,6 +265,7 @@ impl ImageDataHasher {
     /// - Reads in 64KB chunks for memory efficiency
     /// - If size is None, reads until EOF
     /// - Returns bytes read
+    /// - Calls the progress callback after each chunk
     ///
     /// # Arguments
     /// * `reader` - The reader positioned at the start of data to hash
@@ -161,6 +296,9 @@ impl ImageDataHasher {
 
             self.update(&buffer[..n]);
             bytes_read += n as u64;
+            if let Some(progress) = self.progress.as_mut() {
+                progress(self.bytes_hashed);
+            }
 
             if let Some(ref mut r) = remaining {
                 *r -= n as u64;
@@ -199,11 +337,11 @@ impl ImageDataHasher {
     /// Returns None if the hash equals the empty hash (no data was hashed),
     /// following ExifTool's behavior of suppressing empty hashes.
     pub fn finalize(self) -> Option<String> {
-        let result = self.hasher.finalize();
-        let hex = hex_encode(&result);
+        let hash_type = self.hash_type;
+        let hex = self.finalize_unchecked();
 
         // Suppress empty hashes (ExifTool behavior)
-        if hex == self.hash_type.empty_hash() {
+        if hex == hash_type.empty_hash() {
             None
         } else {
             Some(hex)
@@ -214,8 +352,25 @@ impl ImageDataHasher {
     ///
     /// Use this when you need the hash regardless of whether data was hashed.
     pub fn finalize_unchecked(self) -> String {
-        let result = self.hasher.finalize();
-        hex_encode(&result)
+        self.hasher.finalize()
+    }
+
+    /// Finalize the hash and the chunk digests
+    ///
+    /// The hash is suppressed like [`Self::finalize`]; the chunk digests are
+    /// `None` unless enabled with [`Self::with_chunk_size`] and some data was
+    /// hashed. A final partial chunk gets its own digest.
+    pub fn finalize_chunked(mut self) -> (Option<String>, Option<ChunkDigests>) {
+        let chunks = self.chunks.take().and_then(|mut chunks| {
+            if chunks.filled > 0 {
+                chunks.digests.push(chunks.hasher.finalize());
+            }
+            (!chunks.digests.is_empty()).then_some(ChunkDigests {
+                chunk_size: chunks.chunk_size,
+                digests: chunks.digests,
+            })
+        });
+        (self.finalize(), chunks)
     }
 }
 
@@ -224,6 +379,8 @@ impl fmt::Debug for ImageDataHasher {
         f.debug_struct("ImageDataHasher")
             .field("hash_type", &self.hash_type)
             .field("bytes_hashed", &self.bytes_hashed)
+            .field("progress", &self.progress.is_some())
+            .field("chunk_size", &self.chunks.as_ref().map(|c| c.chunk_size))
             .finish()
     }
 }
@@ -267,6 +424,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sha1_hello_world() {
+        let mut hasher = ImageDataHasher::new(ImageHashType::Sha1);
+        hasher.update(b"Hello, World!");
+        assert_eq!(
+            hasher.finalize().unwrap(),
+            "0a0a9f2a6772942557ab5355d76af442f8f65e01"
+        );
+    }
+
+    #[test]
+    fn test_xxh3_hash() {
+        let mut hasher = ImageDataHasher::new(ImageHashType::Xxh3);
+        hasher.update(b"Hello, ");
+        hasher.update(b"World!");
+        let expected = format!("{:016x}", xxhash_rust::xxh3::xxh3_64(b"Hello, World!"));
+        assert_eq!(hasher.finalize().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_empty_hashes_are_suppressed() {
+        for hash_type in [ImageHashType::Sha1, ImageHashType::Xxh3] {
+            assert_eq!(
+                ImageDataHasher::new(hash_type).finalize_unchecked(),
+                hash_type.empty_hash()
+            );
+            assert!(ImageDataHasher::new(hash_type).finalize().is_none());
+        }
+    }
+
+    #[test]
+    fn test_progress_callback() {
+        use std::sync::{Arc, Mutex};
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&reports);
+        let mut hasher = ImageDataHasher::new(ImageHashType::Xxh3)
+            .with_progress(move |bytes| sink.lock().unwrap().push(bytes));
+
+        let data = vec![0u8; 150_000];
+        hasher
+            .hash_from_reader(&mut Cursor::new(data), None)
+            .unwrap();
+        assert_eq!(*reports.lock().unwrap(), vec![65536, 131072, 150_000]);
+    }
+
+    #[test]
+    fn test_chunk_digests() {
+        let data = (0..=255u8).cycle().take(250).collect::<Vec<_>>();
+        let mut hasher = ImageDataHasher::new(ImageHashType::Md5).with_chunk_size(100);
+        // Updates that straddle chunk boundaries
+        hasher.update(&data[..30]);
+        hasher.update(&data[30..210]);
+        hasher.update(&data[210..]);
+        let (hash, chunks) = hasher.finalize_chunked();
+
+        let md5 = |bytes: &[u8]| {
+            let mut hasher = ImageDataHasher::new(ImageHashType::Md5);
+            hasher.update(bytes);
+            hasher.finalize_unchecked()
+        };
+        assert_eq!(hash, Some(md5(&data)));
+        assert_eq!(
+            chunks,
+            Some(ChunkDigests {
+                chunk_size: 100,
+                digests: vec![md5(&data[..100]), md5(&data[100..200]), md5(&data[200..])],
+            })
+        );
+    }
+
+    #[test]
+    fn test_chunk_digests_exact_and_empty() {
+        let mut hasher = ImageDataHasher::new(ImageHashType::Xxh3).with_chunk_size(4);
+        hasher.update(b"abcdefgh");
+        let (_, chunks) = hasher.finalize_chunked();
+        assert_eq!(chunks.unwrap().digests.len(), 2);
+
+        let hasher = ImageDataHasher::new(ImageHashType::Xxh3).with_chunk_size(4);
+        assert_eq!(hasher.finalize_chunked(), (None, None));
+
+        let mut hasher = ImageDataHasher::new(ImageHashType::Xxh3).with_chunk_size(0);
+        hasher.update(b"abcd");
+        assert!(hasher.finalize_chunked().1.is_none());
+    }
+
     #[test]
     fn test_sha512_empty_hash_suppression() {
         let hasher = ImageDataHasher::new(ImageHashType::Sha512);
@@ -327,6 +570,9 @@ mod tests {
         assert_eq!(ImageHashType::parse_str("SHA256"), ImageHashType::Sha256);
         assert_eq!(ImageHashType::parse_str("sha256"), ImageHashType::Sha256);
         assert_eq!(ImageHashType::parse_str("SHA512"), ImageHashType::Sha512);
+        assert_eq!(ImageHashType::parse_str("sha1"), ImageHashType::Sha1);
+        assert_eq!(ImageHashType::parse_str("xxh3"), ImageHashType::Xxh3);
+        assert_eq!(ImageHashType::parse_known("CRC32"), None);
         assert_eq!(ImageHashType::parse_str("unknown"), ImageHashType::Md5); // Default
     }
 
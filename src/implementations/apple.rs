@@ -0,0 +1,333 @@
+//! Apple (iPhone/iPad) MakerNote processing
+//!
+//! Apple maker notes start with an "Apple iOS\0" header followed by a
+//! standard IFD whose value offsets are relative to the start of the maker
+//! note. The RunTime tag (0x0003) holds a binary plist describing the time
+//! since boot, which ExifTool expands into the RunTime* tags.
+//!
+//! ExifTool Reference: lib/Image/ExifTool/MakerNotes.pm MakerNoteApple,
+//! lib/Image/ExifTool/Apple.pm
+
+use crate::exif::ExifReader;
+use crate::formats::parse_binary_plist;
+use crate::generated::Apple_pm::main_tags::APPLE_MAIN_TAGS;
+use crate::generated::Apple_pm::run_time_tags::APPLE_RUNTIME_TAGS_BY_NAME;
+use crate::implementations::print_conv::decode_bits;
+use crate::tiff_types::{ByteOrder, IfdEntry, TiffFormat};
+use crate::types::{ExifError, PrintConv, Result, TagInfo, TagSourceInfo, TagValue};
+use crate::value_extraction;
+use tracing::{debug, trace};
+
+/// Maker note signature
+/// ExifTool: MakerNotes.pm Condition => '$$valPt =~ /^Apple iOS\0/'
+const APPLE_SIGNATURE: &[u8] = b"Apple iOS\0";
+
+/// Size of the maker note header preceding the IFD
+/// ExifTool: Start => '$valuePtr + 14', Base => '$start - 14'
+const APPLE_HEADER_SIZE: usize = 14;
+
+/// RunTime subdirectory tag in Apple::Main
+const RUN_TIME_TAG: u16 = 0x0003;
+
+/// Synthetic ID of the first Apple::RunTime plist entry; the entries are
+/// numbered from here in key order
+const RUN_TIME_FIRST_ID: u16 = 0xF400;
+
+/// Apple::RunTime tag definitions with their synthetic IDs, in key order
+/// ExifTool: Apple.pm %Image::ExifTool::Apple::RunTime (keyed by plist dict key)
+fn run_time_tags() -> impl Iterator<Item = (u16, &'static [u8], &'static TagInfo)> {
+    let mut tags: Vec<_> = APPLE_RUNTIME_TAGS_BY_NAME.iter().collect();
+    tags.sort_by_key(|(key, _)| **key);
+    (RUN_TIME_FIRST_ID..)
+        .zip(tags)
+        .map(|(id, (key, info))| (id, *key, info))
+}
+
+/// Apple::RunTime tag definition for a synthetic ID
+pub fn run_time_tag(tag_id: u16) -> Option<&'static TagInfo> {
+    run_time_tags()
+        .find(|(id, _, _)| *id == tag_id)
+        .map(|(_, _, info)| info)
+}
+
+/// Check for the Apple maker note signature
+/// ExifTool: MakerNotes.pm MakerNoteApple condition
+pub fn is_apple_makernote(maker_note_data: &[u8]) -> bool {
+    maker_note_data.starts_with(APPLE_SIGNATURE)
+}
+
+/// Get Apple tag name from the generated Apple::Main table
+pub fn get_apple_tag_name(tag_id: u16) -> Option<String> {
+    APPLE_MAIN_TAGS.get(&tag_id).map(|def| def.name.to_string())
+}
+
+/// Tags ExifTool marks `Unknown => 1`, which are hidden unless -u is used
+/// ExifTool: Apple.pm AEMatrix and the Apple_0x#### placeholders
+fn is_unknown_tag(tag_id: u16, name: &str) -> bool {
+    tag_id == 0x0002 || name.starts_with("Apple_0x")
+}
+
+/// Process Apple MakerNotes
+///
+/// `offset` and `size` locate the complete maker note (including the
+/// "Apple iOS" header) within the reader's data.
+///
+/// ExifTool: MakerNotes.pm MakerNoteApple -> Apple.pm Main table via ProcessExif
+pub fn process_apple_makernotes(reader: &mut ExifReader, offset: usize, size: usize) -> Result<()> {
+    debug!("Processing Apple MakerNotes at offset {:#x}", offset);
+
+    let end = offset
+        .checked_add(size)
+        .filter(|&end| end <= reader.get_data().len())
+        .ok_or_else(|| {
+            ExifError::ParseError(format!(
+                "Apple MakerNotes at {offset:#x} + {size} beyond data bounds"
+            ))
+        })?;
+
+    // Copy the maker note so offsets are relative to its start (Base => '$start - 14')
+    let data = reader.get_data()[offset..end].to_vec();
+    if data.len() < APPLE_HEADER_SIZE + 2 || !is_apple_makernote(&data) {
+        debug!("Apple MakerNotes too short or missing signature");
+        return Ok(());
+    }
+
+    // ExifTool: ByteOrder => 'Unknown' - header bytes 12-13 hold "MM" in practice
+    let byte_order = match &data[12..14] {
+        b"II" => ByteOrder::LittleEndian,
+        _ => ByteOrder::BigEndian,
+    };
+
+    let num_entries = byte_order.read_u16(&data, APPLE_HEADER_SIZE)? as usize;
+    debug!("Apple MakerNotes IFD has {} entries", num_entries);
+
+    for index in 0..num_entries {
+        let entry_offset = APPLE_HEADER_SIZE + 2 + 12 * index;
+        if entry_offset + 12 > data.len() {
+            reader
+                .warnings
+                .push("Apple MakerNotes directory truncated".to_string());
+            break;
+        }
+
+        let entry = match IfdEntry::parse(&data, entry_offset, byte_order) {
+            Ok(entry) => entry,
+            Err(e) => {
+                trace!("Skipping Apple entry {}: {:?}", index, e);
+                continue;
+            }
+        };
+
+        if entry.tag_id == RUN_TIME_TAG {
+            let raw = match extract_apple_value(&data, &entry, byte_order) {
+                Ok(TagValue::Binary(bytes)) => bytes,
+                _ => continue,
+            };
+            process_run_time(reader, &raw);
+            continue;
+        }
+
+        // Tags not in Apple::Main are only shown with -u
+        let Some(tag_def) = APPLE_MAIN_TAGS.get(&entry.tag_id) else {
+            trace!("Unknown Apple tag {:#06x}, skipping", entry.tag_id);
+            continue;
+        };
+        if is_unknown_tag(entry.tag_id, tag_def.name) {
+            continue;
+        }
+
+        match extract_apple_value(&data, &entry, byte_order) {
+            Ok(value) => {
+                trace!(
+                    "Apple tag {:#06x} ({}): {:?}",
+                    entry.tag_id,
+                    tag_def.name,
+                    value
+                );
+                let source_info = reader.create_tag_source_info("Apple");
+                reader.store_tag_with_precedence(entry.tag_id, value, source_info);
+            }
+            Err(e) => {
+                debug!(
+                    "Failed to extract Apple tag {:#06x} ({}): {:?}",
+                    entry.tag_id, tag_def.name, e
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Expand the RunTime binary plist into RunTime* tags
+/// ExifTool: Apple.pm RunTime SubDirectory with ProcessProc => ProcessBinaryPLIST
+fn process_run_time(reader: &mut ExifReader, plist: &[u8]) {
+    let Some(TagValue::Object(dict)) = parse_binary_plist(plist) else {
+        debug!("Apple RunTime is not a binary plist dictionary");
+        return;
+    };
+
+    for (synthetic_id, key, info) in run_time_tags() {
+        let key = String::from_utf8_lossy(key);
+        if let Some(value) = dict.get(key.as_ref()) {
+            let source_info = TagSourceInfo::new(
+                "Apple".to_string(),
+                "Apple".to_string(),
+                "Apple::RunTime".to_string(),
+            );
+            reader.store_tag_with_precedence(synthetic_id, value.clone(), source_info);
+            reader
+                .synthetic_tag_names
+                .insert(synthetic_id, format!("Apple:{}", info.name));
+        }
+    }
+}
+
+/// Extract a tag value using the entry's TIFF format
+/// ExifTool: Apple::Main is processed by ProcessExif, so standard formats apply
+fn extract_apple_value(data: &[u8], entry: &IfdEntry, byte_order: ByteOrder) -> Result<TagValue> {
+    match entry.format {
+        TiffFormat::Ascii => Ok(TagValue::String(value_extraction::extract_ascii_value(
+            data,
+            entry,
+            byte_order,
+            entry.tag_id,
+        )?)),
+        TiffFormat::Short if entry.count == 1 => Ok(TagValue::U16(
+            value_extraction::extract_short_value(data, entry, byte_order)?,
+        )),
+        TiffFormat::Long if entry.count == 1 => Ok(TagValue::U32(
+            value_extraction::extract_long_value(data, entry, byte_order)?,
+        )),
+        TiffFormat::Long => Ok(TagValue::U32Array(value_extraction::extract_long_array(
+            data, entry, byte_order,
+        )?)),
+        // int32s: MakerNoteVersion, AEStable, AETarget, AEAverage, ...
+        TiffFormat::SLong => {
+            let values: Vec<TagValue> =
+                value_extraction::extract_long_array(data, entry, byte_order)?
+                    .into_iter()
+                    .map(|v| TagValue::I32(v as i32))
+                    .collect();
+            match values.as_slice() {
+                [single] => Ok(single.clone()),
+                _ => Ok(TagValue::Array(values)),
+            }
+        }
+        TiffFormat::Rational => value_extraction::extract_rational_value(data, entry, byte_order),
+        // rational64s: AccelerationVector, FocusDistanceRange
+        TiffFormat::SRational => value_extraction::extract_srational_value(data, entry, byte_order),
+        _ => {
+            let size = entry.data_size() as usize;
+            let bytes = if entry.is_inline() {
+                let raw = match byte_order {
+                    ByteOrder::BigEndian => entry.value_or_offset.to_be_bytes(),
+                    ByteOrder::LittleEndian => entry.value_or_offset.to_le_bytes(),
+                };
+                raw[..size].to_vec()
+            } else {
+                let start = entry.value_or_offset as usize;
+                data.get(start..start.saturating_add(size))
+                    .ok_or_else(|| {
+                        ExifError::ParseError(format!(
+                            "Apple tag {:#06x} value at {start:#x} + {size} beyond data bounds",
+                            entry.tag_id
+                        ))
+                    })?
+                    .to_vec()
+            };
+            Ok(TagValue::Binary(bytes))
+        }
+    }
+}
+
+/// PrintConv of an Apple::RunTime tag
+/// ExifTool: Apple.pm RunTime flags => { PrintConv => { BITMASK => { ... } } }
+pub fn run_time_print_conv(info: &TagInfo, value: &TagValue) -> TagValue {
+    match &info.print_conv {
+        Some(PrintConv::Bitmask(bits)) => decode_bits(value, bits),
+        _ => value.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build an Apple maker note with a single big-endian int32s entry
+    fn build_makernote(tag_id: u16, format: u16, value: u32) -> Vec<u8> {
+        let mut data = b"Apple iOS\0\0\x01MM".to_vec();
+        data.extend_from_slice(&1u16.to_be_bytes());
+        data.extend_from_slice(&tag_id.to_be_bytes());
+        data.extend_from_slice(&format.to_be_bytes());
+        data.extend_from_slice(&1u32.to_be_bytes());
+        data.extend_from_slice(&value.to_be_bytes());
+        data.extend_from_slice(&0u32.to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn test_apple_signature() {
+        assert!(is_apple_makernote(b"Apple iOS\0\0\x01MM"));
+        assert!(!is_apple_makernote(b"Apple iOS "));
+        assert!(!is_apple_makernote(b"Nikon\0"));
+    }
+
+    #[test]
+    fn test_process_int32s_entry() {
+        let mut reader = ExifReader::new();
+        let data = build_makernote(0x0004, 9, 1); // AEStable = 1
+        let size = data.len();
+        reader.set_test_data(data);
+
+        process_apple_makernotes(&mut reader, 0, size).unwrap();
+
+        let key = (0x0004, "Apple".to_string());
+        assert_eq!(reader.extracted_tags.get(&key), Some(&TagValue::I32(1)));
+        assert_eq!(reader.tag_sources[&key].get_group1(), "Apple");
+
+        let entries = reader.get_all_tag_entries();
+        let entry = entries.iter().find(|e| e.name == "AEStable").unwrap();
+        assert_eq!(entry.group, "MakerNotes");
+        assert_eq!(entry.group1, "Apple");
+        assert_eq!(entry.print(), &TagValue::String("Yes".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_tags_hidden() {
+        let mut reader = ExifReader::new();
+        let data = build_makernote(0x0002, 9, 7); // AEMatrix is Unknown => 1
+        let size = data.len();
+        reader.set_test_data(data);
+
+        process_apple_makernotes(&mut reader, 0, size).unwrap();
+        assert!(reader.extracted_tags.is_empty());
+    }
+
+    #[test]
+    fn test_run_time_print_conv() {
+        let (flags_id, _, flags) = run_time_tags()
+            .find(|(_, key, _)| *key == b"flags")
+            .unwrap();
+        assert_eq!(flags.name, "RunTimeFlags");
+        assert!(std::ptr::eq(run_time_tag(flags_id).unwrap(), flags));
+        assert_eq!(
+            run_time_print_conv(flags, &TagValue::U32(3)),
+            TagValue::String("Valid, Has been rounded".to_string())
+        );
+        assert_eq!(
+            run_time_print_conv(flags, &TagValue::U32(0)),
+            TagValue::String("(none)".to_string())
+        );
+
+        let scale = run_time_tags()
+            .find(|(_, key, _)| *key == b"timescale")
+            .map(|(_, _, info)| info)
+            .unwrap();
+        assert_eq!(scale.name, "RunTimeScale");
+        assert_eq!(
+            run_time_print_conv(scale, &TagValue::U32(1000)),
+            TagValue::U32(1000)
+        );
+    }
+}
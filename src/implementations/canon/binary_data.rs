@@ -13,9 +13,11 @@
 use crate::generated::Canon_pm::main_tags::CANON_MAIN_TAGS;
 use crate::tiff_types::ByteOrder;
 use crate::types::{
-    BinaryDataFormat, BinaryDataTable, BinaryDataTag, ExifError, PrintConv, Result, TagValue,
+    BinaryDataFormat, BinaryDataTable, BinaryDataTag, DeferredPrintConv, ExifError, PrintConv,
+    Result, TagValue,
 };
 use std::collections::HashMap;
+use std::sync::LazyLock;
 use tracing::debug;
 
 /// Canon-specific PrintConv application using the generated tag table
@@ -36,7 +38,7 @@ fn apply_canon_print_conv(
                 // Fallback to original value when expression not handled by PPI
                 value.clone()
             }
-            Some(PrintConv::Complex) => {
+            Some(PrintConv::Complex) | Some(PrintConv::Bitmask(_)) => {
                 debug!(
                     "Complex PrintConv for tag {}, using generated module",
                     tag_id
@@ -202,9 +204,55 @@ pub fn create_camera_settings_table() -> HashMap<u32, CanonCameraSettingsTag> {
     table
 }
 
+/// The CameraSettings table, built once
+static CAMERA_SETTINGS_TABLE: LazyLock<HashMap<u32, CanonCameraSettingsTag>> =
+    LazyLock::new(create_camera_settings_table);
+
+/// Apply the PrintConv of the CameraSettings tag at `index`
+fn camera_settings_print_conv_fn(
+    index: u32,
+    value: &TagValue,
+    _errors: &mut Vec<String>,
+    _warnings: &mut Vec<String>,
+) -> TagValue {
+    let Some(tag_def) = CAMERA_SETTINGS_TABLE.get(&index) else {
+        return value.clone();
+    };
+    match (tag_def.name.as_str(), value) {
+        // FocalUnits should be formatted for display
+        // ExifTool shows it as "1/mm" or similar based on the value
+        ("FocalUnits", TagValue::I16(units)) if *units > 0 => {
+            TagValue::String(format!("{units}/mm"))
+        }
+        (_, TagValue::I16(raw_value)) => tag_def
+            .print_conv
+            .as_ref()
+            .and_then(|print_conv| print_conv.get(raw_value))
+            .map_or_else(
+                || value.clone(),
+                |converted| TagValue::String(converted.clone()),
+            ),
+        _ => value.clone(),
+    }
+}
+
+/// The deferred PrintConv of a tag returned by [`extract_camera_settings`],
+/// if it has one in the local table
+pub fn camera_settings_print_conv(tag_name: &str) -> Option<DeferredPrintConv> {
+    let name = tag_name.strip_prefix("MakerNotes:").unwrap_or(tag_name);
+    CAMERA_SETTINGS_TABLE
+        .values()
+        .find(|tag_def| tag_def.name == name)
+        .filter(|tag_def| tag_def.print_conv.is_some() || tag_def.name == "FocalUnits")
+        .map(|tag_def| DeferredPrintConv::new(camera_settings_print_conv_fn, tag_def.index))
+}
+
 /// Extract Canon CameraSettings binary data
 /// ExifTool: ProcessBinaryData with Canon CameraSettings table parameters
 ///
+/// Values are returned after ValueConv; [`camera_settings_print_conv`] gives
+/// the PrintConv of each tag.
+///
 /// Table parameters from Canon.pm:2166-2171:
 /// - FORMAT => 'int16s' (signed 16-bit integers)
 /// - FIRST_ENTRY => 1 (1-indexed)
@@ -215,7 +263,7 @@ pub fn extract_camera_settings(
     size: usize,
     byte_order: ByteOrder,
 ) -> Result<HashMap<String, TagValue>> {
-    let table = create_camera_settings_table();
+    let table = &*CAMERA_SETTINGS_TABLE;
     let mut results = HashMap::new();
 
     // ExifTool: Canon.pm:2168 FORMAT => 'int16s'
@@ -235,7 +283,7 @@ pub fn extract_camera_settings(
     );
 
     // Process defined tags
-    for (&index, tag_def) in &table {
+    for (&index, tag_def) in table {
         // ExifTool: Canon.pm:2169 FIRST_ENTRY => 1 (1-indexed)
         let entry_offset = (index - 1) as usize * format_size;
 
@@ -270,49 +318,18 @@ pub fn extract_camera_settings(
                 );
                 TagValue::F64(converted)
             }
-            "FocalUnits" => {
-                // FocalUnits should be formatted for display
-                // ExifTool shows it as "1/mm" or similar based on the value
-                if raw_value == 1 {
-                    TagValue::String("1/mm".to_string())
-                } else if raw_value > 0 {
-                    TagValue::String(format!("{}/mm", raw_value))
-                } else {
-                    TagValue::I16(raw_value)
-                }
-            }
             _ => TagValue::I16(raw_value), // Other tags use raw values
         };
 
-        // Apply PrintConv from the local table for non-focal-length tags
-        let final_value = match tag_def.name.as_str() {
-            "MinFocalLength" | "MaxFocalLength" => {
-                // These use the converted F64 value directly - PrintConv will be applied by registry
-                converted_value
-            }
-            _ => {
-                // Apply PrintConv lookup table for other tags
-                if let Some(print_conv) = &tag_def.print_conv {
-                    if let Some(converted) = print_conv.get(&raw_value) {
-                        TagValue::String(converted.clone())
-                    } else {
-                        converted_value
-                    }
-                } else {
-                    converted_value
-                }
-            }
-        };
-
         debug!(
             "Extracted Canon {} = {:?} (raw: {}) at index {}",
-            tag_def.name, final_value, raw_value, index
+            tag_def.name, converted_value, raw_value, index
         );
 
         // Store with MakerNotes group prefix like ExifTool
         // ExifTool: Canon.pm:2171 GROUPS => { 0 => 'MakerNotes', 2 => 'Camera' }
         let tag_name = format!("MakerNotes:{}", tag_def.name);
-        results.insert(tag_name, final_value);
+        results.insert(tag_name, converted_value);
     }
 
     Ok(results)
@@ -522,6 +539,32 @@ pub fn extract_shot_info(
         );
     }
 
+    // Extract TargetExposureTime (index 5)
+    // ExifTool: Canon.pm:2744 Name => 'TargetExposureTime'
+    // RawConv => '$val > -1000 ? $val : undef'
+    // ValueConv => 'exp(-Canon::CanonEv($val)*log(2))'
+    if size >= 10 {
+        let target_exposure_raw = byte_order.read_u16(data, offset + 8)? as i16;
+
+        if target_exposure_raw > -1000 {
+            let ev = crate::implementations::value_conv::canon_ev(
+                TagValue::I16(target_exposure_raw),
+                None,
+            );
+            let target_exposure_time = (2.0_f64).powf(-ev.as_f64().unwrap_or(0.0));
+            let final_value = crate::implementations::print_conv::exposuretime_print_conv(
+                &TagValue::F64(target_exposure_time),
+                None,
+            );
+
+            debug!(
+                "Extracted Canon TargetExposureTime = {:?} (raw: {})",
+                final_value, target_exposure_raw
+            );
+            results.insert("MakerNotes:TargetExposureTime".to_string(), final_value);
+        }
+    }
+
     // Extract WhiteBalance (index 7)
     // ExifTool: Canon.pm:2753 Name => 'WhiteBalance'
     // FIRST_ENTRY => 1, so actual offset is (7-1)*2 = 12 bytes
@@ -1130,108 +1173,7 @@ pub fn extract_binary_value(
     format: BinaryDataFormat,
     _count: usize,
 ) -> Result<TagValue> {
-    let data = reader.get_data();
-    let byte_order = if let Some(header) = reader.get_header() {
-        header.byte_order
-    } else {
-        // Default to little-endian when no header is available (common for test scenarios)
-        ByteOrder::LittleEndian
-    };
-
-    match format {
-        BinaryDataFormat::Int8u => {
-            if offset >= data.len() {
-                return Err(ExifError::ParseError(
-                    "Offset beyond data bounds".to_string(),
-                ));
-            }
-            Ok(TagValue::U8(data[offset]))
-        }
-        BinaryDataFormat::Int8s => {
-            if offset >= data.len() {
-                return Err(ExifError::ParseError(
-                    "Offset beyond data bounds".to_string(),
-                ));
-            }
-            // TagValue doesn't have I8, so store as I16
-            Ok(TagValue::I16(data[offset] as i8 as i16))
-        }
-        BinaryDataFormat::Int16u => {
-            if offset + 2 > data.len() {
-                return Err(ExifError::ParseError(
-                    "Offset beyond data bounds for int16u".to_string(),
-                ));
-            }
-            let value = byte_order.read_u16(data, offset)?;
-            Ok(TagValue::U16(value))
-        }
-        BinaryDataFormat::Int16s => {
-            if offset + 2 > data.len() {
-                return Err(ExifError::ParseError(
-                    "Offset beyond data bounds for int16s".to_string(),
-                ));
-            }
-            let value = byte_order.read_u16(data, offset)? as i16;
-            Ok(TagValue::I16(value))
-        }
-        BinaryDataFormat::Int32u => {
-            if offset + 4 > data.len() {
-                return Err(ExifError::ParseError(
-                    "Offset beyond data bounds for int32u".to_string(),
-                ));
-            }
-            let value = byte_order.read_u32(data, offset)?;
-            Ok(TagValue::U32(value))
-        }
-        BinaryDataFormat::Int32s => {
-            if offset + 4 > data.len() {
-                return Err(ExifError::ParseError(
-                    "Offset beyond data bounds for int32s".to_string(),
-                ));
-            }
-            let value = byte_order.read_u32(data, offset)? as i32;
-            Ok(TagValue::I32(value))
-        }
-        BinaryDataFormat::String => {
-            // Extract null-terminated string
-            if offset >= data.len() {
-                return Err(ExifError::ParseError(
-                    "Offset beyond data bounds for string".to_string(),
-                ));
-            }
-
-            let mut end = offset;
-            while end < data.len() && data[end] != 0 {
-                end += 1;
-            }
-
-            let string_bytes = &data[offset..end];
-            let string_value = String::from_utf8_lossy(string_bytes).to_string();
-            Ok(TagValue::String(string_value))
-        }
-        BinaryDataFormat::PString => {
-            // Pascal string: first byte is length
-            if offset >= data.len() {
-                return Err(ExifError::ParseError(
-                    "Offset beyond data bounds for pstring".to_string(),
-                ));
-            }
-
-            let length = data[offset] as usize;
-            if offset + 1 + length > data.len() {
-                return Err(ExifError::ParseError(
-                    "Pascal string length exceeds data bounds".to_string(),
-                ));
-            }
-
-            let string_bytes = &data[offset + 1..offset + 1 + length];
-            let string_value = String::from_utf8_lossy(string_bytes).to_string();
-            Ok(TagValue::String(string_value))
-        }
-        _ => Err(ExifError::ParseError(format!(
-            "Binary format {format:?} not yet implemented"
-        ))),
-    }
+    reader.extract_binary_value(offset, format)
 }
 
 /// Extract binary data tags from ExifReader using a binary data table
@@ -1904,20 +1846,11 @@ mod tests {
         assert!(tags.contains_key("MakerNotes:MacroMode"));
         assert!(tags.contains_key("MakerNotes:SelfTimer"));
 
-        // MacroMode value 1 should be converted to "Macro"
-        if let Some(value) = tags.get("MakerNotes:MacroMode") {
-            println!("MacroMode value: {:?}", value);
-            if let TagValue::String(s) = value {
-                assert_eq!(s, "Macro");
-            } else {
-                panic!(
-                    "MacroMode should be converted to string, but got: {:?}",
-                    value
-                );
-            }
-        } else {
-            panic!("MacroMode tag not found in results");
-        }
+        // MacroMode value 1 is printed as "Macro"
+        let macro_mode = tags.get("MakerNotes:MacroMode").unwrap();
+        assert_eq!(macro_mode, &TagValue::I16(1));
+        let print_conv = camera_settings_print_conv("MakerNotes:MacroMode").unwrap();
+        assert_eq!(print_conv.apply(macro_mode), TagValue::string("Macro"));
 
         // SelfTimer value 2 should remain as I16 (no PrintConv for value 2)
         if let Some(TagValue::I16(value)) = tags.get("MakerNotes:SelfTimer") {
@@ -19,9 +19,9 @@ pub mod tiff_footer;
 
 // Re-export commonly used binary_data functions for easier access
 pub use binary_data::{
-    create_canon_camera_settings_table, extract_binary_data_tags, extract_binary_value,
-    extract_camera_settings, extract_focal_length, extract_my_colors, extract_panorama,
-    extract_shot_info, find_canon_camera_settings_tag,
+    camera_settings_print_conv, create_canon_camera_settings_table, extract_binary_data_tags,
+    extract_binary_value, extract_camera_settings, extract_focal_length, extract_my_colors,
+    extract_panorama, extract_shot_info, find_canon_camera_settings_tag,
 };
 // Re-export offset scheme functions
 pub use offset_schemes::{detect_canon_signature, detect_offset_scheme, CanonOffsetScheme};
@@ -29,57 +29,13 @@ pub use offset_schemes::{detect_canon_signature, detect_offset_scheme, CanonOffs
 pub use tags::get_canon_tag_name;
 
 use crate::tiff_types::ByteOrder;
-use crate::types::{ExifContext, Result, TagValue};
+use crate::types::Result;
 use tracing::debug;
 
 // CameraSettings functions are provided by the binary_data module
 
 // extract_camera_settings function is provided by the binary_data module
 
-/// Convert Canon hex-based EV (modulo 0x20) to real number
-/// ExifTool: lib/Image/ExifTool/Canon.pm line 10478 sub CanonEv
-///
-/// Examples:
-/// - 0x00 -> 0
-/// - 0x0c -> 0.33333 (1/3)
-/// - 0x10 -> 0.5
-/// - 0x14 -> 0.66666 (2/3)
-/// - 0x20 -> 1
-#[allow(dead_code)]
-pub fn canon_ev(val: TagValue, _ctx: Option<&ExifContext>) -> TagValue {
-    let val_i64 = match &val {
-        TagValue::I16(v) => *v as i64,
-        TagValue::I32(v) => *v as i64,
-        TagValue::U8(v) => *v as i64,
-        TagValue::U16(v) => *v as i64,
-        TagValue::U32(v) => *v as i64,
-        TagValue::U64(v) => *v as i64,
-        TagValue::F64(v) => *v as i64,
-        _ => return val.clone(),
-    };
-
-    // Temporarily make the number positive
-    let sign = if val_i64 < 0 { -1.0 } else { 1.0 };
-    let abs_val = val_i64.abs();
-
-    // Extract fractional part (bottom 5 bits = modulo 0x20)
-    let frac_code = abs_val & 0x1f;
-    let int_part = abs_val - frac_code;
-
-    // Convert 1/3 and 2/3 codes
-    // ExifTool: Canon.pm line 10492-10496
-    let frac = if frac_code == 0x0c {
-        0x20 as f64 / 3.0 // 1/3 EV
-    } else if frac_code == 0x14 {
-        0x40 as f64 / 3.0 // 2/3 EV
-    } else {
-        frac_code as f64
-    };
-
-    let result = sign * (int_part as f64 + frac) / 0x20 as f64;
-    TagValue::F64(result)
-}
-
 /// Process Canon MakerNotes data
 /// ExifTool: lib/Image/ExifTool/Canon.pm Canon MakerNote processing
 /// This function processes Canon MakerNotes as an IFD structure to extract Canon-specific tags
@@ -863,9 +819,20 @@ fn apply_canon_main_table_print_conv(exif_reader: &mut crate::exif::ExifReader)
                             }
                         }
                     }
-                    0x4001 | 0x4002 | 0x4003 | 0x4004 | 0x4005 | 0x4008 | 0x4009 | 0x4010
-                    | 0x4011 | 0x4012 | 0x4013 | 0x4015 | 0x4016 | 0x4018 | 0x4019 | 0x4020
-                    | 0x4021 | 0x4024 | 0x4025 | 0x4028 => {
+                    0x4008 | 0x4009 => {
+                        // PictureStyleUserDef / PictureStylePC - base style of
+                        // each of the three user-defined picture styles
+                        // ExifTool: Canon.pm PrintConv => [ \%userDefStyles x 3 ]
+                        if let TagValue::U16Array(styles) = tag_value {
+                            tags_to_update.push((
+                                (tag_id, namespace.clone()),
+                                TagValue::String(user_def_styles_print_conv(styles)),
+                            ));
+                        }
+                    }
+                    0x4001 | 0x4002 | 0x4003 | 0x4004 | 0x4005 | 0x4011 | 0x4012 | 0x4013
+                    | 0x4015 | 0x4016 | 0x4018 | 0x4019 | 0x4020 | 0x4021 | 0x4024 | 0x4025
+                    | 0x4028 => {
                         // ColorData and other subdirectory tags
                         // These are handled separately via subdirectory processing
                         debug!(
@@ -890,6 +857,21 @@ fn apply_canon_main_table_print_conv(exif_reader: &mut crate::exif::ExifReader)
     Ok(())
 }
 
+/// PrintConv for PictureStyleUserDef and PictureStylePC, e.g. "Standard; Portrait; Landscape"
+/// ExifTool: Canon.pm %userDefStyles, applied to each of the 3 values
+fn user_def_styles_print_conv(styles: &[u16]) -> String {
+    use crate::generated::Canon_pm::user_def_styles::lookup_user_def_styles;
+
+    styles
+        .iter()
+        .map(|style| match lookup_user_def_styles(&style.to_string()) {
+            Some(name) => name.to_string(),
+            None => format!("Unknown ({style})"),
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 /// Find Canon tag ID by name from the tag kit system
 /// Used for applying PrintConv to subdirectory-extracted tags
 #[allow(dead_code)]
@@ -1025,6 +1007,7 @@ pub fn apply_camera_settings_print_conv(
 #[cfg(test)]
 mod tests {
 
+    use super::apply_canon_main_table_print_conv;
     use crate::exif::ExifReader;
     use crate::tiff_types::ByteOrder;
     use crate::types::TagValue;
@@ -1106,6 +1089,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_picture_style_user_def_print_conv() {
+        let mut exif_reader = ExifReader::new();
+        for (tag_id, styles) in [(0x4008u16, vec![129, 130, 131]), (0x4009, vec![65, 66, 7])] {
+            let key = (tag_id, "Canon".to_string());
+            exif_reader
+                .extracted_tags
+                .insert(key.clone(), TagValue::U16Array(styles));
+            exif_reader.tag_sources.insert(
+                key,
+                crate::types::TagSourceInfo::new(
+                    "Canon".to_string(),
+                    "Canon".to_string(),
+                    "Canon::Main".to_string(),
+                ),
+            );
+        }
+
+        apply_canon_main_table_print_conv(&mut exif_reader).unwrap();
+
+        assert_eq!(
+            exif_reader.extracted_tags[&(0x4008, "Canon".to_string())],
+            TagValue::string("Standard; Portrait; Landscape")
+        );
+        assert_eq!(
+            exif_reader.extracted_tags[&(0x4009, "Canon".to_string())],
+            TagValue::string("PC 1; PC 2; Unknown (7)")
+        );
+    }
+
     #[test]
     fn test_synthetic_id_collision_detection() {
         // This test verifies that the debug assertion would catch collisions
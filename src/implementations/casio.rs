@@ -0,0 +1,262 @@
+//! Casio MakerNote processing
+//!
+//! Casio maker notes are a standard IFD with TIFF-relative value offsets.
+//! Older models write it directly (Casio::Main); later ones put a "QVC\0" or
+//! "DCI\0" header and two pad bytes in front (Casio::Type2). Neither declares
+//! a byte order, so it is guessed from the entry count.
+//!
+//! ExifTool Reference: lib/Image/ExifTool/MakerNotes.pm MakerNoteCasio and
+//! MakerNoteCasio2, lib/Image/ExifTool/Casio.pm
+
+use crate::exif::ExifReader;
+use crate::generated::Casio_pm::{main_tags, type2_tags};
+use crate::implementations::maker_table::{self, MakerTable};
+use crate::types::{ExifError, Result, TagValue};
+use tracing::debug;
+
+/// First synthetic tag ID for Casio maker note tags
+const CASIO_SYNTHETIC_BASE: u16 = 0xF600;
+
+/// Size of the "QVC\0" header and padding before the Type2 IFD
+/// ExifTool: MakerNoteCasio2 Start => '$valuePtr + 6'
+const TYPE2_HEADER_SIZE: usize = 6;
+
+/// FirmwareDate tag IDs in Casio::Main and Casio::Type2
+const MAIN_FIRMWARE_DATE: u32 = 0x0015;
+const TYPE2_FIRMWARE_DATE: u32 = 0x2001;
+
+/// Subdirectories that aren't decoded here: PrintIM, and FaceInfo whose
+/// layout depends on the model
+/// ExifTool: Casio.pm 0x0e00 PrintIM, Type2 0x2089 FaceInfo1/FaceInfo2
+const SKIPPED_TAGS: &[u16] = &[0x0e00, 0x2089];
+
+/// Casio maker note layouts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CasioMakerNote {
+    /// Casio::Main IFD at the start of the maker note
+    Type1,
+    /// Casio::Type2 IFD after a "QVC\0" or "DCI\0" header
+    Type2,
+}
+
+/// Identify the Casio maker note layout
+/// ExifTool: MakerNoteCasio `$$self{Make}=~/^CASIO/ and $$valPt!~/^(QVC|DCI)\0/`,
+/// MakerNoteCasio2 `$$valPt =~ /^(QVC|DCI)\0/`
+pub fn detect_casio_makernote(make: &str, data: &[u8]) -> Option<CasioMakerNote> {
+    if data.starts_with(b"QVC\0") || data.starts_with(b"DCI\0") {
+        Some(CasioMakerNote::Type2)
+    } else if make.starts_with("CASIO") {
+        Some(CasioMakerNote::Type1)
+    } else {
+        None
+    }
+}
+
+/// Process Casio MakerNotes
+///
+/// `offset` is the start of the complete maker note within the reader's data.
+pub fn process_casio_makernotes(
+    reader: &mut ExifReader,
+    offset: usize,
+    layout: CasioMakerNote,
+) -> Result<()> {
+    debug!("Processing Casio {:?} MakerNotes at {:#x}", layout, offset);
+    let (dir_start, table) = match layout {
+        CasioMakerNote::Type1 => (
+            offset,
+            MakerTable {
+                tags: &main_tags::CASIO_MAIN_TAGS,
+                value_conv: main_tags::apply_value_conv,
+                print_conv: main_print_conv,
+            },
+        ),
+        CasioMakerNote::Type2 => (
+            offset + TYPE2_HEADER_SIZE,
+            MakerTable {
+                tags: &type2_tags::CASIO_TYPE2_TAGS,
+                value_conv: type2_tags::apply_value_conv,
+                print_conv: type2_print_conv,
+            },
+        ),
+    };
+
+    let header_order = reader
+        .get_header()
+        .map(|header| header.byte_order)
+        .ok_or_else(|| ExifError::ParseError("Casio MakerNotes without TIFF header".into()))?;
+    let unknown = reader.unknown_tags;
+    let data = reader.get_data();
+    let byte_order = maker_table::guess_ifd_byte_order(data, dir_start, header_order);
+    let tags = table.read_ifd(
+        data,
+        dir_start,
+        byte_order,
+        SKIPPED_TAGS,
+        ("Casio", unknown),
+    )?;
+
+    maker_table::store_tags(reader, CASIO_SYNTHETIC_BASE, "Casio", "Casio", tags);
+    Ok(())
+}
+
+fn main_print_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> TagValue {
+    match tag_id {
+        MAIN_FIRMWARE_DATE => firmware_date_print_conv(value),
+        _ => main_tags::apply_print_conv(tag_id, value, errors, warnings),
+    }
+}
+
+fn type2_print_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> TagValue {
+    match tag_id {
+        TYPE2_FIRMWARE_DATE => firmware_date_print_conv(value),
+        _ => type2_tags::apply_print_conv(tag_id, value, errors, warnings),
+    }
+}
+
+/// Decode the "YYMM\0\0DDHH\0\0MMSS\0\0" firmware date
+/// ExifTool: Casio.pm FirmwareDate PrintConv
+fn firmware_date_print_conv(value: &TagValue) -> TagValue {
+    let TagValue::Binary(bytes) = value else {
+        return value.clone();
+    };
+    let digits = |range: std::ops::Range<usize>| bytes[range].iter().all(u8::is_ascii_digit);
+    let is_date = bytes.len() == 18
+        && [4, 5, 10, 11, 16, 17].iter().all(|&i| bytes[i] == 0)
+        && digits(0..4)
+        && digits(6..10)
+        && digits(12..14);
+    if is_date {
+        let field = |i: usize| String::from_utf8_lossy(&bytes[i..i + 2]).into_owned();
+        let yy: u32 = field(0).parse().unwrap_or(0);
+        let year = yy + if yy < 70 { 2000 } else { 1900 };
+        let mut date = format!(
+            "{year}:{}:{} {}:{}",
+            field(2),
+            field(6),
+            field(8),
+            field(12)
+        );
+        if digits(14..16) {
+            date.push(':');
+            date.push_str(&field(14));
+        }
+        return TagValue::String(date);
+    }
+    // ExifTool: tr/\0/./; s/\.+$//
+    let text: String = bytes
+        .iter()
+        .map(|&b| if b == 0 { '.' } else { b as char })
+        .collect();
+    TagValue::String(format!("Unknown ({})", text.trim_end_matches('.')))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tiff_types::{ByteOrder, TiffHeader};
+
+    fn tag_value(reader: &mut ExifReader, name: &str) -> Option<TagValue> {
+        reader
+            .get_all_tag_entries()
+            .into_iter()
+            .find(|entry| entry.group == "MakerNotes" && entry.name == name)
+            .map(|entry| entry.print().clone())
+    }
+
+    /// Build an IFD of int16u entries preceded by `header`
+    fn casio_ifd(header: &[u8], entries: &[(u16, u16)]) -> Vec<u8> {
+        let mut data = header.to_vec();
+        data.extend_from_slice(&(entries.len() as u16).to_be_bytes());
+        for &(tag, value) in entries {
+            data.extend_from_slice(&tag.to_be_bytes());
+            data.extend_from_slice(&3u16.to_be_bytes());
+            data.extend_from_slice(&1u32.to_be_bytes());
+            data.extend_from_slice(&value.to_be_bytes());
+            data.extend_from_slice(&[0, 0]);
+        }
+        data.extend_from_slice(&0u32.to_be_bytes());
+        data
+    }
+
+    fn reader_with(data: Vec<u8>) -> ExifReader {
+        let mut reader = ExifReader::new();
+        reader.set_test_data(data);
+        reader.set_test_header(TiffHeader {
+            byte_order: ByteOrder::BigEndian,
+            magic: 42,
+            ifd0_offset: 8,
+        });
+        reader
+    }
+
+    #[test]
+    fn test_detect_casio_makernote() {
+        assert_eq!(
+            detect_casio_makernote("CASIO COMPUTER CO.,LTD.", b"QVC\0\0\0"),
+            Some(CasioMakerNote::Type2)
+        );
+        assert_eq!(
+            detect_casio_makernote("CASIO", b"\0\x10"),
+            Some(CasioMakerNote::Type1)
+        );
+        assert_eq!(detect_casio_makernote("NIKON", b"\0\x10"), None);
+    }
+
+    #[test]
+    fn test_casio_type1() {
+        // RecordingMode = Single Shutter, Quality = Fine
+        let data = casio_ifd(&[], &[(0x0001, 1), (0x0002, 3)]);
+        let mut reader = reader_with(data);
+        process_casio_makernotes(&mut reader, 0, CasioMakerNote::Type1).unwrap();
+
+        assert_eq!(
+            tag_value(&mut reader, "RecordingMode"),
+            Some(TagValue::String("Single Shutter".to_string()))
+        );
+        assert_eq!(
+            tag_value(&mut reader, "Quality"),
+            Some(TagValue::String("Fine".to_string()))
+        );
+        let entries = reader.get_all_tag_entries();
+        let entry = entries.iter().find(|e| e.name == "Quality").unwrap();
+        assert_eq!(entry.group1, "Casio");
+    }
+
+    #[test]
+    fn test_casio_type2() {
+        // FocusMode = Macro; PrintIM is skipped
+        let data = casio_ifd(b"QVC\0\0\0", &[(0x000d, 1), (0x0e00, 0)]);
+        let mut reader = reader_with(data);
+        process_casio_makernotes(&mut reader, 0, CasioMakerNote::Type2).unwrap();
+
+        assert_eq!(
+            tag_value(&mut reader, "FocusMode"),
+            Some(TagValue::String("Macro".to_string()))
+        );
+        assert!(tag_value(&mut reader, "PrintIM").is_none());
+    }
+
+    #[test]
+    fn test_firmware_date_print_conv() {
+        let date = TagValue::Binary(b"0510\x00\x000712\x00\x001530\x00\x00".to_vec());
+        assert_eq!(
+            firmware_date_print_conv(&date),
+            TagValue::String("2005:10:07 12:15:30".to_string())
+        );
+        let other = TagValue::Binary(b"V1.00\0\0".to_vec());
+        assert_eq!(
+            firmware_date_print_conv(&other),
+            TagValue::String("Unknown (V1.00)".to_string())
+        );
+    }
+}
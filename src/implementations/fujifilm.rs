@@ -0,0 +1,147 @@
+//! FujiFilm MakerNote processing
+//!
+//! FujiFilm maker notes start with "FUJIFILM" ("GENERALE" on GE cameras) and
+//! the offset of an IFD that is always little-endian, whatever the TIFF
+//! header says. Value offsets are relative to the start of the maker note.
+//! JPEGs and the JPEG embedded in RAF files both carry it.
+//!
+//! ExifTool Reference: lib/Image/ExifTool/MakerNotes.pm MakerNoteFujiFilm,
+//! lib/Image/ExifTool/FujiFilm.pm Main
+
+use crate::exif::ExifReader;
+use crate::generated::FujiFilm_pm::main_tags;
+use crate::implementations::maker_table::{self, MakerTable};
+use crate::tiff_types::ByteOrder;
+use crate::types::{ExifError, Result};
+use tracing::debug;
+
+/// First synthetic tag ID for FujiFilm maker note tags
+const FUJIFILM_SYNTHETIC_BASE: u16 = 0xFC00;
+
+/// Subdirectories that aren't decoded here: the bit-packed PrioritySettings,
+/// FocusSettings, AFCSettings and DriveSettings, and FaceRecInfo
+/// ExifTool: FujiFilm.pm Main 0x102b, 0x102d, 0x102e, 0x1103, 0x4282
+const SKIPPED_TAGS: &[u16] = &[0x102b, 0x102d, 0x102e, 0x1103, 0x4282];
+
+/// Identify a FujiFilm maker note
+/// ExifTool: MakerNoteFujiFilm `$$valPt =~ /^(FUJIFILM|GENERALE)/`
+pub fn detect_fujifilm_makernote(data: &[u8]) -> bool {
+    data.starts_with(b"FUJIFILM") || data.starts_with(b"GENERALE")
+}
+
+/// Process FujiFilm MakerNotes
+///
+/// `offset` and `size` locate the complete maker note within the reader's
+/// data.
+/// ExifTool: MakerNoteFujiFilm OffsetPt => '$valuePtr+8', ByteOrder => 'LittleEndian'
+pub fn process_fujifilm_makernotes(
+    reader: &mut ExifReader,
+    offset: usize,
+    size: usize,
+) -> Result<()> {
+    debug!("Processing FujiFilm MakerNotes at {:#x}", offset);
+    let unknown = reader.unknown_tags;
+    let data = reader
+        .get_data()
+        .get(offset..offset.saturating_add(size))
+        .ok_or_else(|| ExifError::ParseError("FujiFilm MakerNotes beyond the data".into()))?;
+    let dir_start = ByteOrder::LittleEndian.read_u32(data, 8)? as usize;
+
+    let table = MakerTable {
+        tags: &main_tags::FUJI_FILM_MAIN_TAGS,
+        value_conv: main_tags::apply_value_conv,
+        print_conv: main_tags::apply_print_conv,
+    };
+    let tags = table.read_ifd(
+        data,
+        dir_start,
+        ByteOrder::LittleEndian,
+        SKIPPED_TAGS,
+        ("FujiFilm", unknown),
+    )?;
+
+    maker_table::store_tags(
+        reader,
+        FUJIFILM_SYNTHETIC_BASE,
+        "FujiFilm",
+        "FujiFilm",
+        tags,
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TagValue;
+
+    /// A FujiFilm maker note with the given int16u entries, after `pad`
+    /// bytes of TIFF data
+    fn makernote(pad: usize, entries: &[(u16, u16)]) -> Vec<u8> {
+        let mut data = vec![0u8; pad];
+        data.extend_from_slice(b"FUJIFILM");
+        data.extend_from_slice(&12u32.to_le_bytes());
+        data.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        for &(tag, value) in entries {
+            data.extend_from_slice(&tag.to_le_bytes());
+            data.extend_from_slice(&3u16.to_le_bytes());
+            data.extend_from_slice(&1u32.to_le_bytes());
+            data.extend_from_slice(&(value as u32).to_le_bytes());
+        }
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data
+    }
+
+    fn decoded(reader: &mut ExifReader, name: &str) -> Option<TagValue> {
+        reader
+            .get_all_tag_entries()
+            .into_iter()
+            .find(|entry| entry.group == "MakerNotes" && entry.name == name)
+            .map(|entry| entry.print().clone())
+    }
+
+    #[test]
+    fn test_detect_fujifilm_makernote() {
+        assert!(detect_fujifilm_makernote(b"FUJIFILM\x0c\0\0\0"));
+        assert!(detect_fujifilm_makernote(b"GENERALE\x0c\0\0\0"));
+        assert!(!detect_fujifilm_makernote(b"OLYMPUS\0II"));
+    }
+
+    #[test]
+    fn test_film_simulation_and_dynamic_range() {
+        let data = makernote(
+            16,
+            &[
+                (0x1400, 1),     // DynamicRange: Standard
+                (0x1401, 0x600), // FilmMode: Classic Chrome
+                (0x1402, 1),     // DynamicRangeSetting: Manual
+                (0x1047, 32),    // GrainEffectRoughness: Weak
+                (0x104c, 16),    // GrainEffectSize: Small
+            ],
+        );
+        let mut reader = ExifReader::new();
+        reader.set_test_data(data.clone());
+        process_fujifilm_makernotes(&mut reader, 16, data.len() - 16).unwrap();
+
+        assert_eq!(
+            decoded(&mut reader, "FilmMode"),
+            Some(TagValue::string("Classic Chrome"))
+        );
+        assert_eq!(
+            decoded(&mut reader, "DynamicRange"),
+            Some(TagValue::string("Standard"))
+        );
+        assert_eq!(
+            decoded(&mut reader, "DynamicRangeSetting"),
+            Some(TagValue::string("Manual"))
+        );
+        assert_eq!(
+            decoded(&mut reader, "GrainEffectRoughness"),
+            Some(TagValue::string("Weak"))
+        );
+        assert_eq!(
+            decoded(&mut reader, "GrainEffectSize"),
+            Some(TagValue::string("Small"))
+        );
+    }
+}
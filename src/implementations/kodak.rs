@@ -0,0 +1,276 @@
+//! Kodak MakerNote processing
+//!
+//! Early Kodak DC and EasyShare cameras write one of several fixed-layout
+//! binary maker notes, chosen by a "KDK" header, the camera model or the data
+//! itself. Kodak::Main and the Type2, Type5 and Type6 layouts are decoded
+//! from their generated tables; the IFD-based layouts (Type8 and later) and
+//! the remaining binary types still get generic processing.
+//!
+//! ExifTool Reference: lib/Image/ExifTool/MakerNotes.pm MakerNoteKodak*,
+//! lib/Image/ExifTool/Kodak.pm
+
+use crate::exif::ExifReader;
+use crate::generated::Kodak_pm::{main_tags, type2_tags, type5_tags, type6_tags};
+use crate::implementations::maker_table::{self, MakerTable};
+use crate::tiff_types::ByteOrder;
+use crate::types::{ExifError, Result, TagValue};
+use tracing::debug;
+
+/// First synthetic tag ID for Kodak maker note tags
+const KODAK_SYNTHETIC_BASE: u16 = 0xF500;
+
+/// Size of the "KDK INFO" header before the Kodak::Main data
+/// ExifTool: MakerNoteKodak1a/1b Start => '$valuePtr + 8'
+const KDK_HEADER_SIZE: usize = 8;
+
+/// Models using the Kodak::Type5 layout
+/// ExifTool: MakerNoteKodak5 `$$self{Model}=~/CX(4200|4210|4230|4300|4310|6200|6230)/`
+const TYPE5_MODELS: &[&str] = &[
+    "CX4200", "CX4210", "CX4230", "CX4300", "CX4310", "CX6200", "CX6230",
+];
+
+/// Kodak maker note layouts handled here
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KodakMakerNote {
+    /// Kodak::Main after a "KDK" header, in the given byte order
+    Main(ByteOrder),
+    /// Kodak::Type2, also written by some HP, Pentax and Minolta models
+    Type2,
+    /// Kodak::Type5 (CX4200 series)
+    Type5,
+    /// Kodak::Type6 (DX3215 big-endian, DX3700 little-endian)
+    Type6(ByteOrder),
+}
+
+/// Identify the Kodak maker note layout
+/// ExifTool: MakerNotes.pm MakerNoteKodak1a, 1b, 2, 5, 6a and 6b conditions
+pub fn detect_kodak_makernote(make: &str, model: &str, data: &[u8]) -> Option<KodakMakerNote> {
+    let is_kodak = make.starts_with("EASTMAN KODAK");
+    if is_kodak && data.starts_with(b"KDK INFO") {
+        return Some(KodakMakerNote::Main(ByteOrder::BigEndian));
+    }
+    if is_kodak && data.starts_with(b"KDK") {
+        return Some(KodakMakerNote::Main(ByteOrder::LittleEndian));
+    }
+    if is_type2_signature(data) {
+        return Some(KodakMakerNote::Type2);
+    }
+    if !is_kodak {
+        return None;
+    }
+    if TYPE5_MODELS.iter().any(|m| model.contains(m)) {
+        Some(KodakMakerNote::Type5)
+    } else if model.contains("DX3215") {
+        Some(KodakMakerNote::Type6(ByteOrder::BigEndian))
+    } else if model.contains("DX3700") {
+        Some(KodakMakerNote::Type6(ByteOrder::LittleEndian))
+    } else {
+        None
+    }
+}
+
+/// ExifTool: MakerNoteKodak2 `$$valPt =~ /^.{8}Eastman Kodak/s or
+/// $$valPt =~ /^\x01\0[\0\x01]\0\0\0\x04\0[a-zA-Z]{4}/`
+fn is_type2_signature(data: &[u8]) -> bool {
+    if data
+        .get(8..)
+        .is_some_and(|rest| rest.starts_with(b"Eastman Kodak"))
+    {
+        return true;
+    }
+    data.len() >= 12
+        && data[0] == 0x01
+        && data[1] == 0
+        && data[2] <= 0x01
+        && data[3..7] == [0, 0, 0, 0x04]
+        && data[7] == 0
+        && data[8..12].iter().all(u8::is_ascii_alphabetic)
+}
+
+/// Process Kodak MakerNotes
+///
+/// `offset` and `size` locate the complete maker note within the reader's data.
+pub fn process_kodak_makernotes(
+    reader: &mut ExifReader,
+    offset: usize,
+    size: usize,
+    layout: KodakMakerNote,
+) -> Result<()> {
+    debug!("Processing Kodak {:?} MakerNotes at {:#x}", layout, offset);
+    let data = reader
+        .get_data()
+        .get(offset..offset.saturating_add(size))
+        .ok_or_else(|| {
+            ExifError::ParseError(format!(
+                "Kodak MakerNotes at {offset:#x} + {size} beyond data bounds"
+            ))
+        })?
+        .to_vec();
+
+    let tags = match layout {
+        KodakMakerNote::Main(byte_order) => {
+            let table = MakerTable {
+                tags: &main_tags::KODAK_MAIN_TAGS,
+                value_conv: main_value_conv,
+                print_conv: main_tags::apply_print_conv,
+            };
+            let block = data.get(KDK_HEADER_SIZE..).unwrap_or_default();
+            table.read_binary(block, byte_order, "int8u")
+        }
+        KodakMakerNote::Type2 => MakerTable {
+            tags: &type2_tags::KODAK_TYPE2_TAGS,
+            value_conv: type2_tags::apply_value_conv,
+            print_conv: type2_tags::apply_print_conv,
+        }
+        .read_binary(&data, ByteOrder::BigEndian, "int8u"),
+        KodakMakerNote::Type5 => MakerTable {
+            tags: &type5_tags::KODAK_TYPE5_TAGS,
+            value_conv: type5_value_conv,
+            print_conv: type5_tags::apply_print_conv,
+        }
+        .read_binary(&data, ByteOrder::BigEndian, "int8u"),
+        KodakMakerNote::Type6(byte_order) => MakerTable {
+            tags: &type6_tags::KODAK_TYPE6_TAGS,
+            value_conv: type6_value_conv,
+            print_conv: type6_tags::apply_print_conv,
+        }
+        .read_binary(&data, byte_order, "int8u"),
+    };
+
+    maker_table::store_tags(reader, KODAK_SYNTHETIC_BASE, "Kodak", "Kodak", tags);
+    Ok(())
+}
+
+/// Kodak::Main ValueConvs the generator can't translate yet
+fn main_value_conv(tag_id: u32, value: &TagValue, errors: &mut Vec<String>) -> Result<TagValue> {
+    match tag_id {
+        // ExifTool: MonthDayCreated ValueConv => 'sprintf("%.2d:%.2d",split(" ", $val))'
+        18 => Ok(join_two_digit(value, ":", ":")),
+        // ExifTool: TimeCreated ValueConv => 'sprintf("%.2d:%.2d:%.2d.%.2d",split(" ", $val))'
+        20 => Ok(join_two_digit(value, ":", ".")),
+        32 => Ok(exposure_time_value_conv(value)),
+        _ => main_tags::apply_value_conv(tag_id, value, errors),
+    }
+}
+
+fn type5_value_conv(tag_id: u32, value: &TagValue, errors: &mut Vec<String>) -> Result<TagValue> {
+    match tag_id {
+        20 => Ok(exposure_time_value_conv(value)),
+        _ => type5_tags::apply_value_conv(tag_id, value, errors),
+    }
+}
+
+fn type6_value_conv(tag_id: u32, value: &TagValue, errors: &mut Vec<String>) -> Result<TagValue> {
+    match tag_id {
+        16 => Ok(exposure_time_value_conv(value)),
+        _ => type6_tags::apply_value_conv(tag_id, value, errors),
+    }
+}
+
+/// ExifTool: ExposureTime `ValueConv => '$val / 1e5'`
+fn exposure_time_value_conv(value: &TagValue) -> TagValue {
+    match value.as_f64() {
+        Some(v) => TagValue::F64(v / 1e5),
+        None => value.clone(),
+    }
+}
+
+/// Format bytes as two-digit fields, using `last_sep` before the final one
+fn join_two_digit(value: &TagValue, sep: &str, last_sep: &str) -> TagValue {
+    let TagValue::U8Array(bytes) = value else {
+        return value.clone();
+    };
+    let mut out = String::new();
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 {
+            out.push_str(if i + 1 == bytes.len() { last_sep } else { sep });
+        }
+        out.push_str(&format!("{byte:02}"));
+    }
+    TagValue::String(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag_value(reader: &mut ExifReader, name: &str) -> Option<TagValue> {
+        reader
+            .get_all_tag_entries()
+            .into_iter()
+            .find(|entry| entry.group == "MakerNotes" && entry.name == name)
+            .map(|entry| entry.print().clone())
+    }
+
+    #[test]
+    fn test_detect_kodak_makernote() {
+        let make = "EASTMAN KODAK COMPANY";
+        assert_eq!(
+            detect_kodak_makernote(make, "DC240", b"KDK INFO\0\0"),
+            Some(KodakMakerNote::Main(ByteOrder::BigEndian))
+        );
+        assert_eq!(
+            detect_kodak_makernote(make, "DC4800", b"KDK\0\0\0\0\0"),
+            Some(KodakMakerNote::Main(ByteOrder::LittleEndian))
+        );
+        assert_eq!(
+            detect_kodak_makernote(make, "KODAK CX4230 ZOOM DIGITAL CAMERA", &[0; 16]),
+            Some(KodakMakerNote::Type5)
+        );
+        assert_eq!(
+            detect_kodak_makernote("Hewlett-Packard", "", b"\x01\0\0\0\0\0\x04\0ABCD"),
+            Some(KodakMakerNote::Type2)
+        );
+        assert_eq!(detect_kodak_makernote("Canon", "", b"KDK INFO"), None);
+    }
+
+    #[test]
+    fn test_kodak_main() {
+        let mut block = vec![0u8; 108];
+        block[0..5].copy_from_slice(b"DC240");
+        block[9] = 1; // Quality
+        block[18..20].copy_from_slice(&[5, 7]); // MonthDayCreated
+        block[20..24].copy_from_slice(&[13, 4, 9, 50]); // TimeCreated
+        block[30..32].copy_from_slice(&400u16.to_be_bytes()); // FNumber
+        block[32..36].copy_from_slice(&1000u32.to_be_bytes()); // ExposureTime
+        let mut data = b"KDK INFO".to_vec();
+        data.extend_from_slice(&block);
+
+        let mut reader = ExifReader::new();
+        let size = data.len();
+        reader.set_test_data(data);
+        process_kodak_makernotes(
+            &mut reader,
+            0,
+            size,
+            KodakMakerNote::Main(ByteOrder::BigEndian),
+        )
+        .unwrap();
+
+        assert_eq!(
+            tag_value(&mut reader, "KodakModel"),
+            Some(TagValue::String("DC240".to_string()))
+        );
+        assert_eq!(
+            tag_value(&mut reader, "Quality"),
+            Some(TagValue::String("Fine".to_string()))
+        );
+        assert_eq!(
+            tag_value(&mut reader, "MonthDayCreated"),
+            Some(TagValue::String("05:07".to_string()))
+        );
+        assert_eq!(
+            tag_value(&mut reader, "TimeCreated"),
+            Some(TagValue::String("13:04:09.50".to_string()))
+        );
+        assert_eq!(
+            tag_value(&mut reader, "ExposureTime"),
+            Some(TagValue::String("1/100".to_string()))
+        );
+
+        let entries = reader.get_all_tag_entries();
+        let entry = entries.iter().find(|e| e.name == "KodakModel").unwrap();
+        assert_eq!(entry.group, "MakerNotes");
+        assert_eq!(entry.group1, "Kodak");
+    }
+}
@@ -0,0 +1,414 @@
+//! Leica MakerNote processing
+//!
+//! Leica bodies write an IFD after an 8-byte "LEICA" header whose trailing
+//! bytes pick the tag table and offset base. ExifTool keeps these tables in
+//! Panasonic.pm (Leica2-Leica9); the ones carrying camera tags are decoded
+//! here. Leica3 and Leica4 hold only subdirectories, and rebadged Panasonic
+//! models use Panasonic::Main, so both still get generic processing.
+//!
+//! ExifTool Reference: lib/Image/ExifTool/MakerNotes.pm MakerNoteLeica*,
+//! lib/Image/ExifTool/Panasonic.pm Leica2-Leica9
+
+use crate::exif::ExifReader;
+use crate::generated::Panasonic_pm::leica_lens_types::lookup_leica_lens_types;
+use crate::generated::Panasonic_pm::{leica2_tags, leica5_tags, leica6_tags, leica9_tags};
+use crate::implementations::maker_table::{self, MakerTable};
+use crate::tiff_types::{ByteOrder, IfdEntry};
+use crate::types::{ExifError, Result, TagValue};
+use tracing::debug;
+
+/// First synthetic tag ID for Leica maker note tags
+const LEICA_SYNTHETIC_BASE: u16 = 0xF900;
+
+/// Size of the "LEICA\0xx" header before the IFD
+/// ExifTool: MakerNoteLeica2-9 Start => '$valuePtr + 8'
+const LEICA_HEADER_SIZE: usize = 8;
+
+/// Leica2 LensType, Leica5 InternalSerialNumber, Leica6 LensType and
+/// FirmwareVersion
+const LEICA2_LENS_TYPE: u32 = 0x0310;
+const LEICA5_INTERNAL_SERIAL: u32 = 0x0500;
+const LEICA6_LENS_TYPE: u32 = 0x0303;
+const LEICA6_FIRMWARE_VERSION: u32 = 0x0320;
+
+/// Subdirectories that aren't decoded here
+/// ExifTool: Leica5 FocusInfo, ShotInfo and CameraIFD; Leica6 PreviewImage
+/// and UnknownBlock
+const LEICA5_SKIPPED_TAGS: &[u16] = &[0x040a, 0x0410, 0x05ff];
+const LEICA6_SKIPPED_TAGS: &[u16] = &[0x0300, 0x0301];
+
+/// Leica maker note layouts handled here
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeicaMakerNote {
+    /// Panasonic::Leica2 (M8)
+    Leica2,
+    /// Panasonic::Leica5 (X series, T, Q, SL, CL and their successors)
+    Leica5,
+    /// Panasonic::Leica6 (S2, M Typ 240, M Monochrom Typ 246)
+    Leica6,
+    /// Panasonic::Leica9 (S Typ 007, M10 and later M bodies)
+    Leica9,
+}
+
+/// Identify the Leica maker note layout from its header
+/// ExifTool: MakerNotes.pm MakerNoteLeica2 and MakerNoteLeica5-9 conditions
+pub fn detect_leica_makernote(make: &str, model: &str, data: &[u8]) -> Option<LeicaMakerNote> {
+    let header = data.get(..LEICA_HEADER_SIZE)?;
+    if !header.starts_with(b"LEICA\0") {
+        return None;
+    }
+    match (header[6], header[7]) {
+        (0x00, 0x00) if make == "Leica Camera AG" => Some(LeicaMakerNote::Leica2),
+        // ExifTool: MakerNoteLeica5 and MakerNoteLeica8 use the same table
+        (0x01 | 0x04..=0x0a | 0x10 | 0x1a, 0x00) => Some(LeicaMakerNote::Leica5),
+        (0x02, 0x00) => Some(LeicaMakerNote::Leica6),
+        // ExifTool: MakerNoteLeica7 is the M Monochrom (Typ 246)
+        (0x02, 0xff) if model.contains("Typ 246") => Some(LeicaMakerNote::Leica6),
+        (0x02, 0xff) | (0x03, 0x00) => Some(LeicaMakerNote::Leica9),
+        _ => None,
+    }
+}
+
+/// Process Leica MakerNotes
+///
+/// `offset` and `size` locate the complete maker note within the reader's data.
+pub fn process_leica_makernotes(
+    reader: &mut ExifReader,
+    offset: usize,
+    size: usize,
+    layout: LeicaMakerNote,
+) -> Result<()> {
+    debug!("Processing Leica {:?} MakerNotes at {:#x}", layout, offset);
+    let header_order = reader
+        .get_header()
+        .map(|header| header.byte_order)
+        .ok_or_else(|| ExifError::ParseError("Leica MakerNotes without TIFF header".into()))?;
+    let unknown = reader.unknown_tags;
+    let data = reader.get_data();
+    let maker_note = data
+        .get(offset..offset.saturating_add(size))
+        .ok_or_else(|| {
+            ExifError::ParseError(format!(
+                "Leica MakerNotes at {offset:#x} + {size} beyond data bounds"
+            ))
+        })?;
+
+    // ExifTool: Leica5 and Leica9 use Base => '$start - 8' (offsets relative
+    // to the maker note), Leica6 keeps the TIFF base, and Leica2 depends on
+    // where the image was written
+    let relative = match layout {
+        LeicaMakerNote::Leica5 | LeicaMakerNote::Leica9 => true,
+        LeicaMakerNote::Leica6 => false,
+        LeicaMakerNote::Leica2 => {
+            let byte_order =
+                maker_table::guess_ifd_byte_order(maker_note, LEICA_HEADER_SIZE, header_order);
+            leica2_offsets_are_relative(maker_note, byte_order)
+        }
+    };
+    let (data, dir_start) = if relative {
+        (maker_note, LEICA_HEADER_SIZE)
+    } else {
+        (data, offset + LEICA_HEADER_SIZE)
+    };
+    let byte_order = maker_table::guess_ifd_byte_order(data, dir_start, header_order);
+
+    let tags = match layout {
+        LeicaMakerNote::Leica2 => MakerTable {
+            tags: &leica2_tags::PANASONIC_LEICA2_TAGS,
+            value_conv: leica2_value_conv,
+            print_conv: leica2_print_conv,
+        }
+        .read_ifd(
+            data,
+            dir_start,
+            byte_order,
+            &[],
+            ("Panasonic_Leica2", unknown),
+        )?,
+        LeicaMakerNote::Leica5 => MakerTable {
+            tags: &leica5_tags::PANASONIC_LEICA5_TAGS,
+            value_conv: leica5_tags::apply_value_conv,
+            print_conv: leica5_print_conv,
+        }
+        .read_ifd(
+            data,
+            dir_start,
+            byte_order,
+            LEICA5_SKIPPED_TAGS,
+            ("Panasonic_Leica5", unknown),
+        )?,
+        LeicaMakerNote::Leica6 => MakerTable {
+            tags: &leica6_tags::PANASONIC_LEICA6_TAGS,
+            value_conv: leica6_value_conv,
+            print_conv: leica6_print_conv,
+        }
+        .read_ifd(
+            data,
+            dir_start,
+            byte_order,
+            LEICA6_SKIPPED_TAGS,
+            ("Panasonic_Leica6", unknown),
+        )?,
+        LeicaMakerNote::Leica9 => MakerTable {
+            tags: &leica9_tags::PANASONIC_LEICA9_TAGS,
+            value_conv: leica9_tags::apply_value_conv,
+            print_conv: leica9_tags::apply_print_conv,
+        }
+        .read_ifd(
+            data,
+            dir_start,
+            byte_order,
+            &[],
+            ("Panasonic_Leica9", unknown),
+        )?,
+    };
+
+    maker_table::store_tags(reader, LEICA_SYNTHETIC_BASE, "Leica", "Leica", tags);
+    Ok(())
+}
+
+/// Decide whether M8 value offsets are relative to the maker note
+///
+/// The M8 writes maker-note-relative offsets in JPEG images and TIFF-relative
+/// ones in DNG images. Relative offsets point inside the maker note itself.
+/// ExifTool: MakerNotes.pm FixLeicaBase()
+fn leica2_offsets_are_relative(maker_note: &[u8], byte_order: ByteOrder) -> bool {
+    let Ok(num_entries) = byte_order.read_u16(maker_note, LEICA_HEADER_SIZE) else {
+        return false;
+    };
+    (0..num_entries as usize)
+        .map_while(|index| {
+            IfdEntry::parse(maker_note, LEICA_HEADER_SIZE + 2 + 12 * index, byte_order).ok()
+        })
+        .find(|entry| !entry.is_inline())
+        .is_some_and(|entry| {
+            let start = entry.value_or_offset as usize;
+            start >= LEICA_HEADER_SIZE
+                && start.saturating_add(entry.data_size() as usize) <= maker_note.len()
+        })
+}
+
+/// Leica ValueConvs the generator can't translate yet
+fn leica2_value_conv(tag_id: u32, value: &TagValue, errors: &mut Vec<String>) -> Result<TagValue> {
+    match tag_id {
+        // ExifTool: LensType ValueConv => '($val >> 2) . " " . ($val & 0x3)'
+        LEICA2_LENS_TYPE => Ok(match value.as_u32() {
+            Some(v) => TagValue::String(format!("{} {}", v >> 2, v & 0x3)),
+            None => value.clone(),
+        }),
+        _ => leica2_tags::apply_value_conv(tag_id, value, errors),
+    }
+}
+
+fn leica6_value_conv(tag_id: u32, value: &TagValue, errors: &mut Vec<String>) -> Result<TagValue> {
+    match tag_id {
+        // ExifTool: LensType ValueConv => '$val=~s/ +$//; $val'
+        LEICA6_LENS_TYPE => Ok(match value {
+            TagValue::String(s) => TagValue::String(s.trim_end_matches(' ').to_string()),
+            _ => value.clone(),
+        }),
+        _ => leica6_tags::apply_value_conv(tag_id, value, errors),
+    }
+}
+
+fn leica2_print_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> TagValue {
+    match tag_id {
+        LEICA2_LENS_TYPE => lens_type_print_conv(value),
+        _ => leica2_tags::apply_print_conv(tag_id, value, errors, warnings),
+    }
+}
+
+fn leica5_print_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> TagValue {
+    match tag_id {
+        LEICA5_INTERNAL_SERIAL => internal_serial_print_conv(value),
+        _ => leica5_tags::apply_print_conv(tag_id, value, errors, warnings),
+    }
+}
+
+fn leica6_print_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> TagValue {
+    match tag_id {
+        // ExifTool: FirmwareVersion PrintConv => '$val =~ tr/ /./; $val'
+        LEICA6_FIRMWARE_VERSION => match value {
+            TagValue::String(s) => TagValue::String(s.replace(' ', ".")),
+            _ => value.clone(),
+        },
+        _ => leica6_tags::apply_print_conv(tag_id, value, errors, warnings),
+    }
+}
+
+/// Look up a "code bits" lens value, falling back to the code alone
+/// ExifTool: Panasonic.pm Leica2 LensType PrintConv OTHER
+fn lens_type_print_conv(value: &TagValue) -> TagValue {
+    let TagValue::String(key) = value else {
+        return value.clone();
+    };
+    let code = key.split(' ').next().unwrap_or_default();
+    match lookup_leica_lens_types(key).or_else(|| lookup_leica_lens_types(code)) {
+        Some(lens) => TagValue::String(lens.to_string()),
+        None => TagValue::String(format!("Unknown ({key})")),
+    }
+}
+
+/// Decode the "XXXYYMMDDNNNN" internal serial number
+/// ExifTool: Panasonic.pm Leica5 InternalSerialNumber PrintConv
+fn internal_serial_print_conv(value: &TagValue) -> TagValue {
+    let text = match value {
+        TagValue::String(s) => s.clone(),
+        TagValue::Binary(bytes) => String::from_utf8_lossy(bytes)
+            .trim_end_matches('\0')
+            .to_string(),
+        _ => return value.clone(),
+    };
+    let chars: Vec<char> = text.chars().collect();
+    let is_serial = chars.len() >= 13 && chars[3..13].iter().all(char::is_ascii_digit);
+    if !is_serial {
+        return TagValue::String(text);
+    }
+    let field = |range: std::ops::Range<usize>| chars[range].iter().collect::<String>();
+    let yy: u32 = field(3..5).parse().unwrap_or(0);
+    let year = yy + if yy < 70 { 2000 } else { 1900 };
+    TagValue::String(format!(
+        "({}) {year}:{}:{} no. {}",
+        field(0..3),
+        field(5..7),
+        field(7..9),
+        field(9..13)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tiff_types::TiffHeader;
+
+    /// Build a little-endian maker note with one inline entry per tag
+    fn leica_makernote(header: &[u8; 8], entries: &[(u16, u16, [u8; 4])]) -> Vec<u8> {
+        let mut data = header.to_vec();
+        data.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        for &(tag, format, value) in entries {
+            let count = match format {
+                2 | 7 => 4,
+                3 => 2,
+                _ => 1,
+            };
+            data.extend_from_slice(&tag.to_le_bytes());
+            data.extend_from_slice(&format.to_le_bytes());
+            data.extend_from_slice(&(count as u32).to_le_bytes());
+            data.extend_from_slice(&value);
+        }
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data
+    }
+
+    fn process(data: Vec<u8>, layout: LeicaMakerNote) -> ExifReader {
+        let size = data.len();
+        let mut reader = ExifReader::new();
+        reader.set_test_data(data);
+        reader.set_test_header(TiffHeader {
+            byte_order: ByteOrder::LittleEndian,
+            magic: 42,
+            ifd0_offset: 8,
+        });
+        process_leica_makernotes(&mut reader, 0, size, layout).unwrap();
+        reader
+    }
+
+    #[test]
+    fn test_detect_leica_makernote() {
+        let make = "Leica Camera AG";
+        assert_eq!(
+            detect_leica_makernote(make, "LEICA M8 Digital Camera", b"LEICA\0\0\0"),
+            Some(LeicaMakerNote::Leica2)
+        );
+        assert_eq!(
+            detect_leica_makernote("LEICA CAMERA AG", "LEICA Q2", b"LEICA\0\x08\0"),
+            Some(LeicaMakerNote::Leica5)
+        );
+        assert_eq!(
+            detect_leica_makernote("LEICA CAMERA AG", "LEICA SL2", b"LEICA\0\x0a\0"),
+            Some(LeicaMakerNote::Leica5)
+        );
+        assert_eq!(
+            detect_leica_makernote(make, "LEICA M (Typ 240)", b"LEICA\0\x02\0"),
+            Some(LeicaMakerNote::Leica6)
+        );
+        assert_eq!(
+            detect_leica_makernote(make, "LEICA M10", b"LEICA\0\x02\xff"),
+            Some(LeicaMakerNote::Leica9)
+        );
+        assert_eq!(
+            detect_leica_makernote(make, "LEICA M MONOCHROM (Typ 246)", b"LEICA\0\x02\xff"),
+            Some(LeicaMakerNote::Leica6)
+        );
+        assert_eq!(
+            detect_leica_makernote(make, "LEICA M9", b"LEICA0\x03\0"),
+            None
+        );
+        assert_eq!(
+            detect_leica_makernote("Panasonic", "", b"Panasonic\0"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_leica5_lens_type() {
+        // LensType "SL1" (string), ExposureMode = Manual
+        let data = leica_makernote(
+            b"LEICA\0\x08\0",
+            &[(0x0303, 2, *b"SL1\0"), (0x040d, 1, [3, 0, 0, 0])],
+        );
+        let mut reader = process(data, LeicaMakerNote::Leica5);
+
+        let entries = reader.get_all_tag_entries();
+        let lens = entries.iter().find(|e| e.name == "LensType").unwrap();
+        assert_eq!(lens.group, "MakerNotes");
+        assert_eq!(lens.group1, "Leica");
+        assert_eq!(lens.print(), &TagValue::String("SL1".to_string()));
+        assert!(entries.iter().any(|e| e.name == "ExposureMode"));
+    }
+
+    #[test]
+    fn test_leica2_lens_type() {
+        // Summilux-M 35mm f/1.4 ASPH. is code 29; low bits 0 select the
+        // ASPHERICAL variant, low bits 1 fall back to the code alone
+        let data = leica_makernote(
+            b"LEICA\0\0\0",
+            &[(0x0310, 4, (29u32 << 2 | 1).to_le_bytes())],
+        );
+        let mut reader = process(data, LeicaMakerNote::Leica2);
+
+        let entries = reader.get_all_tag_entries();
+        let lens = entries.iter().find(|e| e.name == "LensType").unwrap();
+        assert_eq!(
+            lens.print(),
+            &TagValue::String("Summilux-M 35mm f/1.4 ASPH.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_internal_serial_print_conv() {
+        assert_eq!(
+            internal_serial_print_conv(&TagValue::String("AAA1907150123".to_string())),
+            TagValue::String("(AAA) 2019:07:15 no. 0123".to_string())
+        );
+        assert_eq!(
+            internal_serial_print_conv(&TagValue::String("short".to_string())),
+            TagValue::String("short".to_string())
+        );
+    }
+}
@@ -0,0 +1,537 @@
+//! Maker notes described by a single generated tag table
+//!
+//! Many 2000s-era maker notes (Kodak, Casio, Minolta) are one
+//! ProcessBinaryData block or one IFD with no further subdirectories, so they
+//! can be decoded straight from the generated `TagInfo` map of their table.
+//! The decoded tags are stored under synthetic IDs with pre-converted values,
+//! like the CRW heap tags. The PrintConv is deferred until a tag's printed
+//! value is read.
+//!
+//! ExifTool References:
+//! - lib/Image/ExifTool.pm ProcessBinaryData()
+//! - lib/Image/ExifTool/Exif.pm ProcessExif()
+
+use crate::tiff_types::ByteOrder;
+use crate::types::{
+    DataMemberValue, DeferredPrintConv, ExpressionEvaluator, HookState, Result, TagInfo, TagValue,
+};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use tracing::{debug, trace};
+
+// Reading IFDs and storing tags are only needed by some makers
+#[cfg(any(
+    feature = "other-makers",
+    feature = "fujifilm",
+    feature = "nikon",
+    feature = "olympus",
+    feature = "sony"
+))]
+use crate::{exif::ExifReader, types::TagSourceInfo};
+#[cfg(any(feature = "other-makers", feature = "olympus", feature = "fujifilm"))]
+use crate::{tiff_types::IfdEntry, types::UnknownTags};
+
+/// Generated table conversion functions, keyed by tag ID
+pub(crate) type ValueConvFn = fn(u32, &TagValue, &mut Vec<String>) -> Result<TagValue>;
+pub(crate) type PrintConvFn = fn(u32, &TagValue, &mut Vec<String>, &mut Vec<String>) -> TagValue;
+
+/// A generated tag table together with its conversion functions
+pub(crate) struct MakerTable {
+    pub tags: &'static HashMap<u16, TagInfo>,
+    pub value_conv: ValueConvFn,
+    pub print_conv: PrintConvFn,
+}
+
+/// What a ProcessBinaryData block shares with later entries and blocks
+#[derive(Debug, Default)]
+pub(crate) struct BinaryDataState<'a> {
+    /// Hook code by entry index
+    pub hooks: &'a [(u16, &'a str)],
+    /// DataMember names by entry index
+    pub data_members: &'a [(u16, &'a str)],
+    /// `$$self{...}` values: those known before the block (e.g. Model) and
+    /// the DataMembers read from it
+    pub members: HashMap<String, DataMemberValue>,
+}
+
+/// A decoded tag with its ValueConv value
+#[derive(Debug)]
+pub(crate) struct MakerTag {
+    /// Table name, or `<prefix>_0x<id>` for an unknown tag
+    pub name: Cow<'static, str>,
+    pub value: TagValue,
+    /// The table PrintConv, applied when the printed value is read
+    pub print_conv: Option<DeferredPrintConv>,
+}
+
+impl MakerTable {
+    /// Apply the table's ValueConv, keeping the value on failure, and defer
+    /// its PrintConv
+    pub fn convert(&self, tag_id: u16, name: Cow<'static, str>, value: TagValue) -> MakerTag {
+        let mut errors = Vec::new();
+        let value = (self.value_conv)(tag_id as u32, &value, &mut errors).unwrap_or(value);
+        MakerTag {
+            name,
+            value,
+            print_conv: Some(DeferredPrintConv::new(self.print_conv, tag_id as u32)),
+        }
+    }
+
+    /// Decode a ProcessBinaryData block
+    ///
+    /// Entries without an explicit format use `default_format`; entries that
+    /// run past the end of the block are skipped, as in ExifTool.
+    /// ExifTool: ProcessBinaryData()
+    #[cfg(feature = "other-makers")]
+    pub fn read_binary(
+        &self,
+        data: &[u8],
+        byte_order: ByteOrder,
+        default_format: &str,
+    ) -> Vec<MakerTag> {
+        self.read_binary_entries(
+            data,
+            byte_order,
+            default_format,
+            &mut BinaryDataState::default(),
+        )
+    }
+
+    /// Decode a ProcessBinaryData block with the table's Hooks and DataMembers
+    ///
+    /// Table keys are indices in units of `default_format`, shifted by
+    /// `$varSize` once a `var_` format or a Hook changes the size of an
+    /// entry. Formats like `int16u[$val{0}]` are sized from earlier entries.
+    /// Decoding stops at the first Hook or count that can't be evaluated, as
+    /// every later offset depends on it.
+    /// ExifTool: ProcessBinaryData()
+    pub fn read_binary_entries(
+        &self,
+        data: &[u8],
+        byte_order: ByteOrder,
+        default_format: &str,
+        state: &mut BinaryDataState,
+    ) -> Vec<MakerTag> {
+        let mut indices: Vec<u16> = self.tags.keys().copied().collect();
+        indices.sort_unstable();
+        let increment = format_size(split_format(default_format).0);
+
+        let mut var_size: isize = 0;
+        // ExifTool: %val, the raw values of this block by index
+        let mut vals: HashMap<u32, DataMemberValue> = HashMap::new();
+        let mut tags = Vec::new();
+        for index in indices {
+            let tag = &self.tags[&index];
+            let mut format = match tag.format {
+                "unknown" => default_format,
+                format => format,
+            }
+            .to_string();
+
+            if let Some((_, code)) = state.hooks.iter().find(|(hook, _)| *hook == index) {
+                let evaluator = ExpressionEvaluator::new(vals.clone(), &state.members);
+                let mut hook_state = HookState {
+                    format,
+                    var_size,
+                    size: data.len(),
+                };
+                if let Err(e) = evaluator.apply_hook(code, &mut hook_state) {
+                    debug!("Stopping binary data at {}: {}", tag.name, e);
+                    break;
+                }
+                format = hook_state.format;
+                var_size = hook_state.var_size;
+            }
+
+            let Some(start) = (index as usize * increment).checked_add_signed(var_size) else {
+                debug!("Stopping binary data at {}: negative offset", tag.name);
+                break;
+            };
+            let (variable, format) = match format.strip_prefix("var_") {
+                Some(format) => (true, format),
+                None => (false, format.as_str()),
+            };
+            let (base, count) = match format.strip_suffix(']').and_then(|f| f.split_once('[')) {
+                Some((base, expr)) if expr.contains('$') => {
+                    let evaluator = ExpressionEvaluator::new(vals.clone(), &state.members);
+                    match evaluator.evaluate_count_expression(expr) {
+                        Ok(count) => (base, count),
+                        Err(e) => {
+                            debug!("Stopping binary data at {}: {}", tag.name, e);
+                            break;
+                        }
+                    }
+                }
+                Some(_) => split_format(format),
+                // ExifTool: a var_string runs to its null terminator, and a
+                // string without a count to the end of the block
+                None if format == "string" => {
+                    let rest = data.get(start..).unwrap_or_default();
+                    match rest.iter().position(|&b| b == 0) {
+                        Some(end) if variable => ("string", end + 1),
+                        _ => ("string", rest.len()),
+                    }
+                }
+                None => (format, 1),
+            };
+            let size = format_size(base) * count;
+            if variable {
+                var_size += size as isize - increment as isize;
+            }
+
+            let Some(bytes) = data.get(start..start + size) else {
+                trace!("{} at offset {} is beyond the block", tag.name, start);
+                continue;
+            };
+            let value = read_value(bytes, base, count, byte_order);
+            if let Some(member) = DataMemberValue::from_tag_value(&value) {
+                if let Some((_, name)) = state.data_members.iter().find(|(i, _)| *i == index) {
+                    state.members.insert(name.to_string(), member.clone());
+                }
+                vals.insert(index as u32, member);
+            }
+            tags.push(self.convert(index, Cow::Borrowed(tag.name), value));
+        }
+        tags
+    }
+
+    /// Read a tag's unconverted value from `bytes` in the table's format
+    ///
+    /// For directories that record each value's size rather than its format
+    /// and count. Returns None for tags missing from the table.
+    #[cfg(all(feature = "fujifilm", feature = "raw-formats"))]
+    pub fn read_raw(&self, tag_id: u16, bytes: &[u8], byte_order: ByteOrder) -> Option<TagValue> {
+        let tag = self.tags.get(&tag_id)?;
+        let format = match split_format(tag.format).0 {
+            "unknown" => "undef",
+            format => format,
+        };
+        let count = bytes.len() / format_size(format);
+        Some(read_value(bytes, format, count, byte_order))
+    }
+
+    /// Decode an IFD at `dir_start` whose value offsets are relative to `data`
+    ///
+    /// Tags missing from the table are kept unconverted as `<prefix>_0x<id>`
+    /// when `unknown` includes them, and skipped otherwise. The IDs in `skip`,
+    /// which name subdirectories that aren't decoded here, are always
+    /// skipped. A table format overrides the entry's own format.
+    /// ExifTool: ProcessExif()
+    #[cfg(any(feature = "other-makers", feature = "olympus", feature = "fujifilm"))]
+    pub fn read_ifd(
+        &self,
+        data: &[u8],
+        dir_start: usize,
+        byte_order: ByteOrder,
+        skip: &[u16],
+        (prefix, unknown): (&str, UnknownTags),
+    ) -> Result<Vec<MakerTag>> {
+        let num_entries = byte_order.read_u16(data, dir_start)? as usize;
+        let mut tags = Vec::new();
+        for index in 0..num_entries {
+            let entry_offset = dir_start + 2 + 12 * index;
+            let entry = match IfdEntry::parse(data, entry_offset, byte_order) {
+                Ok(entry) => entry,
+                Err(e) => {
+                    debug!("Stopping maker note IFD at entry {}: {}", index, e);
+                    break;
+                }
+            };
+            if skip.contains(&entry.tag_id) {
+                continue;
+            }
+            let Some(tag) = self.tags.get(&entry.tag_id) else {
+                trace!("Tag {:#06x} not in maker note table", entry.tag_id);
+                if let Some(bytes) = entry_bytes(data, &entry, byte_order) {
+                    let format = entry.format.name();
+                    let count = bytes.len() / format_size(format);
+                    let value = read_value(&bytes, format, count, byte_order);
+                    if unknown.includes(&value) {
+                        tags.push(MakerTag {
+                            name: Cow::Owned(format!("{prefix}_0x{:04x}", entry.tag_id)),
+                            value,
+                            print_conv: None,
+                        });
+                    }
+                }
+                continue;
+            };
+            let Some(bytes) = entry_bytes(data, &entry, byte_order) else {
+                debug!("{} value is beyond the data", tag.name);
+                continue;
+            };
+            let format = match tag.format {
+                "unknown" => entry.format.name(),
+                format => split_format(format).0,
+            };
+            let count = bytes.len() / format_size(format);
+            let value = read_value(&bytes, format, count, byte_order);
+            tags.push(self.convert(entry.tag_id, Cow::Borrowed(tag.name), value));
+        }
+        Ok(tags)
+    }
+}
+
+/// Pick the byte order of a maker note IFD that doesn't declare one
+///
+/// Real entry counts are small, so read in the wrong order they come out as a
+/// multiple of 256. Keeps `preferred` (normally the TIFF header order) unless
+/// the other order gives a smaller, non-zero count.
+/// ExifTool: SubDirectory ByteOrder => 'Unknown'
+#[cfg(any(feature = "other-makers", feature = "olympus"))]
+pub(crate) fn guess_ifd_byte_order(
+    data: &[u8],
+    dir_start: usize,
+    preferred: ByteOrder,
+) -> ByteOrder {
+    let other = match preferred {
+        ByteOrder::LittleEndian => ByteOrder::BigEndian,
+        ByteOrder::BigEndian => ByteOrder::LittleEndian,
+    };
+    let count = |order: ByteOrder| order.read_u16(data, dir_start).unwrap_or(0);
+    match (count(preferred), count(other)) {
+        (0, n) if n > 0 => other,
+        (p, n) if n > 0 && n < p => other,
+        _ => preferred,
+    }
+}
+
+/// Store decoded tags under synthetic IDs starting at `base_id`
+///
+/// A repeated tag name reuses its ID, so the first occurrence wins.
+#[cfg(any(
+    feature = "other-makers",
+    feature = "fujifilm",
+    feature = "nikon",
+    feature = "olympus",
+    feature = "sony"
+))]
+pub(crate) fn store_tags(
+    reader: &mut ExifReader,
+    base_id: u16,
+    group1: &str,
+    processor: &str,
+    tags: Vec<MakerTag>,
+) {
+    store_group_tags(reader, base_id, ("MakerNotes", group1), processor, tags);
+}
+
+/// Store decoded tags like [`store_tags`], in family 0 group `group0`
+/// rather than MakerNotes
+#[cfg(any(
+    feature = "other-makers",
+    feature = "fujifilm",
+    feature = "nikon",
+    feature = "olympus",
+    feature = "sony"
+))]
+pub(crate) fn store_group_tags(
+    reader: &mut ExifReader,
+    base_id: u16,
+    (group0, group1): (&str, &str),
+    processor: &str,
+    tags: Vec<MakerTag>,
+) {
+    let mut ids: HashMap<Cow<'static, str>, u16> = HashMap::new();
+    for tag in tags {
+        let next_id = base_id + ids.len() as u16;
+        let id = *ids.entry(tag.name.clone()).or_insert(next_id);
+        reader
+            .synthetic_tag_names
+            .insert(id, format!("{group0}:{}", tag.name));
+        let source_info = TagSourceInfo::new(
+            group0.to_string(),
+            group1.to_string(),
+            processor.to_string(),
+        )
+        .with_print_conv(tag.print_conv);
+        reader.store_tag_with_precedence(id, tag.value, source_info);
+    }
+}
+
+/// Raw value bytes of an IFD entry, inline or at its offset
+#[cfg(any(feature = "other-makers", feature = "olympus", feature = "fujifilm"))]
+fn entry_bytes(data: &[u8], entry: &IfdEntry, byte_order: ByteOrder) -> Option<Vec<u8>> {
+    let size = entry.data_size() as usize;
+    if entry.is_inline() {
+        let raw = match byte_order {
+            ByteOrder::BigEndian => entry.value_or_offset.to_be_bytes(),
+            ByteOrder::LittleEndian => entry.value_or_offset.to_le_bytes(),
+        };
+        Some(raw[..size].to_vec())
+    } else {
+        let start = entry.value_or_offset as usize;
+        data.get(start..start.checked_add(size)?)
+            .map(<[u8]>::to_vec)
+    }
+}
+
+/// Split a format like "int8u[4]" into its base format and count
+fn split_format(format: &str) -> (&str, usize) {
+    match format.strip_suffix(']').and_then(|f| f.split_once('[')) {
+        Some((base, count)) => (base, count.parse().unwrap_or(1)),
+        None => (format, 1),
+    }
+}
+
+/// Size in bytes of one value of an ExifTool format
+fn format_size(format: &str) -> usize {
+    match format {
+        "int16u" | "int16s" => 2,
+        "int32u" | "int32s" | "float" => 4,
+        "rational64u" | "rational64s" | "double" => 8,
+        _ => 1,
+    }
+}
+
+/// Read `count` values of `format`
+/// ExifTool: ReadValue()
+fn read_value(data: &[u8], format: &str, count: usize, byte_order: ByteOrder) -> TagValue {
+    let count = count.min(data.len() / format_size(format));
+    let u16s = || {
+        (0..count)
+            .filter_map(|i| byte_order.read_u16(data, i * 2).ok())
+            .collect::<Vec<_>>()
+    };
+    let u32s = |n: usize| {
+        (0..n)
+            .filter_map(|i| byte_order.read_u32(data, i * 4).ok())
+            .collect::<Vec<_>>()
+    };
+    let signed = |values: Vec<TagValue>| match values.len() {
+        1 => values.into_iter().next().unwrap_or(TagValue::Empty),
+        _ => TagValue::Array(values),
+    };
+    match format {
+        "string" => {
+            let end = data.iter().position(|&b| b == 0).unwrap_or(data.len());
+            TagValue::String(String::from_utf8_lossy(&data[..end]).into_owned())
+        }
+        "int8u" if count == 1 => TagValue::U8(data[0]),
+        "int8u" => TagValue::U8Array(data[..count].to_vec()),
+        "int8s" => signed(
+            data[..count]
+                .iter()
+                .map(|&b| TagValue::I16(b as i8 as i16))
+                .collect(),
+        ),
+        "int16u" if count == 1 => TagValue::U16(u16s()[0]),
+        "int16u" => TagValue::U16Array(u16s()),
+        "int16s" => signed(
+            u16s()
+                .into_iter()
+                .map(|v| TagValue::I16(v as i16))
+                .collect(),
+        ),
+        "int32u" if count == 1 => TagValue::U32(u32s(1)[0]),
+        "int32u" => TagValue::U32Array(u32s(count)),
+        "int32s" => signed(
+            u32s(count)
+                .into_iter()
+                .map(|v| TagValue::I32(v as i32))
+                .collect(),
+        ),
+        "rational64u" => {
+            let pairs: Vec<(u32, u32)> = u32s(count * 2).chunks(2).map(|p| (p[0], p[1])).collect();
+            match pairs.as_slice() {
+                [(num, den)] => TagValue::Rational(*num, *den),
+                _ => TagValue::RationalArray(pairs),
+            }
+        }
+        "rational64s" => {
+            let pairs: Vec<(i32, i32)> = u32s(count * 2)
+                .chunks(2)
+                .map(|p| (p[0] as i32, p[1] as i32))
+                .collect();
+            match pairs.as_slice() {
+                [(num, den)] => TagValue::SRational(*num, *den),
+                _ => TagValue::SRationalArray(pairs),
+            }
+        }
+        // ExifTool: 'undef' values (e.g. FirmwareDate) stay raw
+        _ => TagValue::Binary(data.to_vec()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_format() {
+        assert_eq!(split_format("int8u[4]"), ("int8u", 4));
+        assert_eq!(split_format("string[32]"), ("string", 32));
+        assert_eq!(split_format("int16u"), ("int16u", 1));
+    }
+
+    #[test]
+    fn test_read_value() {
+        let be = ByteOrder::BigEndian;
+        assert_eq!(
+            read_value(&[0x01, 0x02], "int16u", 1, be),
+            TagValue::U16(258)
+        );
+        assert_eq!(
+            read_value(&[0xff, 0xfe], "int16s", 1, be),
+            TagValue::I16(-2)
+        );
+        assert_eq!(
+            read_value(b"DC240\0\0\0", "string", 8, be),
+            TagValue::String("DC240".to_string())
+        );
+        assert_eq!(
+            read_value(&[0, 0, 0, 1, 0, 0, 0, 4], "rational64u", 1, be),
+            TagValue::Rational(1, 4)
+        );
+    }
+
+    #[cfg(any(feature = "other-makers", feature = "olympus"))]
+    #[test]
+    fn test_guess_ifd_byte_order() {
+        let data = [0x05, 0x00];
+        assert_eq!(
+            guess_ifd_byte_order(&data, 0, ByteOrder::BigEndian),
+            ByteOrder::LittleEndian
+        );
+        assert_eq!(
+            guess_ifd_byte_order(&[0x00, 0x05], 0, ByteOrder::BigEndian),
+            ByteOrder::BigEndian
+        );
+        assert_eq!(
+            guess_ifd_byte_order(&data, 0, ByteOrder::LittleEndian),
+            ByteOrder::LittleEndian
+        );
+    }
+
+    #[cfg(any(feature = "other-makers", feature = "olympus", feature = "fujifilm"))]
+    #[test]
+    fn test_read_ifd_unknown_tags() {
+        let table = MakerTable {
+            tags: Box::leak(Box::default()),
+            value_conv: |_, value, _| Ok(value.clone()),
+            print_conv: |_, value, _, _| value.clone(),
+        };
+        // Two entries: SHORT 0x0021 = 5 and UNDEFINED[4] 0x0022
+        let mut data = vec![2, 0];
+        data.extend([0x21, 0, 3, 0, 1, 0, 0, 0, 5, 0, 0, 0]);
+        data.extend([0x22, 0, 7, 0, 4, 0, 0, 0, 1, 2, 3, 4]);
+        let read = |unknown| {
+            table
+                .read_ifd(&data, 0, ByteOrder::LittleEndian, &[], ("Casio", unknown))
+                .unwrap()
+        };
+
+        assert!(read(UnknownTags::Hide).is_empty());
+        let tags = read(UnknownTags::Include);
+        assert_eq!(tags.len(), 1);
+        assert_eq!(
+            (tags[0].name.as_ref(), &tags[0].value),
+            ("Casio_0x0021", &TagValue::U16(5))
+        );
+        let tags = read(UnknownTags::IncludeBinary);
+        assert_eq!(
+            (tags[1].name.as_ref(), &tags[1].value),
+            ("Casio_0x0022", &TagValue::Binary(vec![1, 2, 3, 4]))
+        );
+    }
+}
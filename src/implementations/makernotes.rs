@@ -2,6 +2,7 @@
 // Implements ExifTool's signature-based manufacturer detection
 // Based on third-party/exiftool/lib/Image/ExifTool/MakerNotes.pm
 
+#[cfg(feature = "fujifilm")]
 use crate::generated::FujiFilm_pm::main_tags::FUJI_FILM_MAIN_TAGS;
 use crate::tiff_types::ByteOrder;
 use crate::types::{Result, TagValue};
@@ -28,6 +29,12 @@ pub fn process_tag_0x927c_subdirectory(
 /// Reference: third-party/exiftool/lib/Image/ExifTool/MakerNotes.pm:34-1102
 /// The @Image::ExifTool::MakerNotes::Main array contains conditional entries
 /// that are evaluated sequentially until first match
+///
+/// Makers whose cargo feature is disabled never match.
+#[cfg_attr(
+    not(any(feature = "olympus", feature = "fujifilm")),
+    allow(unused_variables)
+)]
 pub fn process_makernotes_conditional_dispatch(
     data: &[u8],
     byte_order: ByteOrder,
@@ -50,12 +57,14 @@ pub fn process_makernotes_conditional_dispatch(
     // Following the order from MakerNotes.pm:557-586 for Olympus variants
 
     // Check for Olympus signature patterns
+    #[cfg(feature = "olympus")]
     if let Some(olympus_tags) = check_olympus_patterns(&signature_str, data, byte_order)? {
         debug!("MakerNotes: Matched Olympus signature, delegating to Olympus processor");
         return Ok(olympus_tags);
     }
 
     // Check for other manufacturer patterns (stub for now)
+    #[cfg(feature = "fujifilm")]
     if let Some(other_tags) = check_other_manufacturer_patterns(&signature_str, data, byte_order)? {
         debug!("MakerNotes: Matched other manufacturer signature");
         return Ok(other_tags);
@@ -68,6 +77,7 @@ pub fn process_makernotes_conditional_dispatch(
 
 /// Check for Olympus maker note signature patterns
 /// Based on MakerNotes.pm:557-586
+#[cfg(feature = "olympus")]
 fn check_olympus_patterns(
     signature: &str,
     data: &[u8],
@@ -99,6 +109,7 @@ fn check_olympus_patterns(
 
 /// Process Olympus maker notes with calculated start offset
 /// Delegates to the Olympus implementation
+#[cfg(feature = "olympus")]
 fn process_olympus_makernotes(
     data: &[u8],
     byte_order: ByteOrder,
@@ -142,6 +153,7 @@ fn process_olympus_makernotes(
 ///
 /// Reference: third-party/exiftool/lib/Image/ExifTool/Olympus.pm:1620-1640 (Equipment dual format)
 /// Reference: third-party/exiftool/lib/Image/ExifTool/Olympus.pm:4270-4350 (Equipment tag table)
+#[cfg(feature = "olympus")]
 fn process_olympus_ifd_for_equipment(
     data: &[u8],
     byte_order: ByteOrder,
@@ -262,6 +274,7 @@ fn process_olympus_ifd_for_equipment(
 /// Extract Equipment tag value based on tag ID, format, and data location
 ///
 /// Reference: third-party/exiftool/lib/Image/ExifTool/Olympus.pm:4270-4350
+#[cfg(feature = "olympus")]
 fn extract_equipment_tag_value(
     tag_id: u16,
     format_id: u16,
@@ -349,6 +362,7 @@ fn extract_equipment_tag_value(
 ///
 /// Maps Equipment tag IDs to their proper names for namespace storage
 /// Reference: third-party/exiftool/lib/Image/ExifTool/Olympus.pm:4270-4350
+#[cfg(feature = "olympus")]
 fn get_equipment_tag_name(tag_id: u16) -> String {
     match tag_id {
         0x100 => "CameraType2".to_string(),
@@ -367,6 +381,7 @@ fn get_equipment_tag_name(tag_id: u16) -> String {
 
 /// Check for other manufacturer signature patterns
 /// TODO: Implement Canon, Nikon, Sony, etc. when needed
+#[cfg(feature = "fujifilm")]
 fn check_other_manufacturer_patterns(
     signature: &str,
     data: &[u8],
@@ -390,6 +405,7 @@ fn check_other_manufacturer_patterns(
 
 /// Process FujiFilm maker notes with ExifTool-exact specifications
 /// Reference: MakerNotes.pm - MakerNoteFujiFilm SubDirectory settings
+#[cfg(feature = "fujifilm")]
 fn process_fujifilm_makernotes(
     data: &[u8],
     _byte_order: ByteOrder,
@@ -422,6 +438,7 @@ fn process_fujifilm_makernotes(
 }
 
 /// Parse FujiFilm IFD and resolve tag names using the generated tag kit
+#[cfg(feature = "fujifilm")]
 fn parse_fujifilm_ifd(data: &[u8], byte_order: ByteOrder) -> Result<Vec<(String, TagValue)>> {
     debug!("Parsing FujiFilm IFD: {} bytes", data.len());
 
@@ -535,6 +552,7 @@ fn parse_fujifilm_ifd(data: &[u8], byte_order: ByteOrder) -> Result<Vec<(String,
 }
 
 /// Get FujiFilm tag name from tag ID using the generated tag kit
+#[cfg(feature = "fujifilm")]
 fn get_fujifilm_tag_name(tag_id: u32) -> String {
     if let Some(tag_kit) = FUJI_FILM_MAIN_TAGS.get(&(tag_id as u16)) {
         tag_kit.name.to_string()
@@ -544,6 +562,7 @@ fn get_fujifilm_tag_name(tag_id: u32) -> String {
 }
 
 /// Extract IFD tag value based on format and data location (simplified)
+#[cfg(feature = "fujifilm")]
 fn extract_ifd_tag_value(
     format_id: u16,
     count: u32,
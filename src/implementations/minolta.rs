@@ -0,0 +1,111 @@
+//! Minolta MakerNote processing
+//!
+//! Early Minolta DiMAGE models write an Olympus-style maker note with a
+//! "MINOL\0" or "CAMER\0" header, which ExifTool reads with the
+//! Olympus::Main table. The Minolta::Main IFD used by later Minolta and
+//! Konica Minolta models has no generated table yet, so it still gets
+//! generic processing.
+//!
+//! ExifTool Reference: lib/Image/ExifTool/MakerNotes.pm MakerNoteMinolta2,
+//! lib/Image/ExifTool/Olympus.pm
+
+use crate::exif::ExifReader;
+use crate::generated::Olympus_pm::main_tags;
+use crate::implementations::maker_table::{self, MakerTable};
+use crate::types::{ExifError, Result};
+use tracing::debug;
+
+/// First synthetic tag ID for Minolta maker note tags
+const MINOLTA_SYNTHETIC_BASE: u16 = 0xF700;
+
+/// Size of the "MINOL\0" header and version before the IFD
+/// ExifTool: MakerNoteMinolta2 Start => '$valuePtr + 8'
+const MINOLTA_HEADER_SIZE: usize = 8;
+
+/// Olympus::Main subdirectories that aren't decoded here: the Minolta
+/// camera settings, TextInfo, PrintIM and the Olympus sub-IFDs
+const SKIPPED_TAGS: &[u16] = &[
+    0x0001, 0x0003, 0x0208, 0x0e00, 0x2010, 0x2020, 0x2030, 0x2031, 0x2040, 0x2050, 0x2100, 0x2200,
+    0x2300, 0x2400, 0x2500, 0x2600, 0x2700, 0x2800, 0x2900, 0x3000, 0x4000, 0x5000,
+];
+
+/// Check for the Olympus-style Minolta maker note
+/// ExifTool: MakerNoteMinolta2 `$$valPt =~ /^(MINOL|CAMER)\0/`
+pub fn is_minolta_olympus_makernote(data: &[u8]) -> bool {
+    data.starts_with(b"MINOL\0") || data.starts_with(b"CAMER\0")
+}
+
+/// Process an Olympus-style Minolta maker note starting at `offset`
+/// ExifTool: MakerNoteMinolta2 -> Olympus::Main, ByteOrder => 'Unknown'
+pub fn process_minolta_makernotes(reader: &mut ExifReader, offset: usize) -> Result<()> {
+    debug!("Processing Minolta MakerNotes at {:#x}", offset);
+    let table = MakerTable {
+        tags: &main_tags::OLYMPUS_MAIN_TAGS,
+        value_conv: main_tags::apply_value_conv,
+        print_conv: main_tags::apply_print_conv,
+    };
+
+    let dir_start = offset + MINOLTA_HEADER_SIZE;
+    let header_order = reader
+        .get_header()
+        .map(|header| header.byte_order)
+        .ok_or_else(|| ExifError::ParseError("Minolta MakerNotes without TIFF header".into()))?;
+    let unknown = reader.unknown_tags;
+    let data = reader.get_data();
+    let byte_order = maker_table::guess_ifd_byte_order(data, dir_start, header_order);
+    let tags = table.read_ifd(
+        data,
+        dir_start,
+        byte_order,
+        SKIPPED_TAGS,
+        ("Olympus", unknown),
+    )?;
+
+    // ExifTool: tags from Olympus::Main keep Group1 'Olympus'
+    maker_table::store_tags(reader, MINOLTA_SYNTHETIC_BASE, "Olympus", "Olympus", tags);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tiff_types::{ByteOrder, TiffHeader};
+    use crate::types::TagValue;
+
+    #[test]
+    fn test_minolta_signature() {
+        assert!(is_minolta_olympus_makernote(b"MINOL\0\x01\0"));
+        assert!(is_minolta_olympus_makernote(b"CAMER\0\x01\0"));
+        assert!(!is_minolta_olympus_makernote(b"OLYMP\0\x01\0"));
+    }
+
+    #[test]
+    fn test_minolta_olympus_ifd() {
+        // Little-endian IFD with Macro (0x0202) = 1 and a skipped PrintIM
+        let mut data = b"MINOL\0\x01\0".to_vec();
+        data.extend_from_slice(&2u16.to_le_bytes());
+        for (tag, value) in [(0x0202u16, 1u16), (0x0e00, 0)] {
+            data.extend_from_slice(&tag.to_le_bytes());
+            data.extend_from_slice(&3u16.to_le_bytes());
+            data.extend_from_slice(&1u32.to_le_bytes());
+            data.extend_from_slice(&value.to_le_bytes());
+            data.extend_from_slice(&[0, 0]);
+        }
+
+        let mut reader = ExifReader::new();
+        reader.set_test_data(data);
+        reader.set_test_header(TiffHeader {
+            byte_order: ByteOrder::BigEndian,
+            magic: 42,
+            ifd0_offset: 8,
+        });
+        process_minolta_makernotes(&mut reader, 0).unwrap();
+
+        let entries = reader.get_all_tag_entries();
+        let entry = entries.iter().find(|e| e.name == "Macro").unwrap();
+        assert_eq!(entry.group, "MakerNotes");
+        assert_eq!(entry.group1, "Olympus");
+        assert_eq!(entry.print(), &TagValue::String("On".to_string()));
+        assert!(!entries.iter().any(|e| e.name == "PrintIM"));
+    }
+}
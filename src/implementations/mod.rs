@@ -3,18 +3,44 @@
 //! This module contains manual implementations of ExifTool's conversion functions.
 //! All implementations are direct translations from ExifTool source code.
 
+pub mod apple;
+#[cfg(feature = "canon")]
 pub mod canon;
+#[cfg(feature = "other-makers")]
+pub mod casio;
+#[cfg(feature = "fujifilm")]
+pub mod fujifilm;
 pub mod generic;
+#[cfg(feature = "other-makers")]
+pub mod kodak;
+#[cfg(feature = "other-makers")]
+pub mod leica;
+#[cfg(any(
+    feature = "other-makers",
+    feature = "canon",
+    feature = "fujifilm",
+    feature = "nikon",
+    feature = "olympus",
+    feature = "sony"
+))]
+pub(crate) mod maker_table;
 pub mod makernotes;
+#[cfg(feature = "olympus")]
+pub mod minolta;
+#[cfg(feature = "raw-formats")]
 pub mod minolta_raw;
 pub mod missing;
+#[cfg(feature = "nikon")]
 pub mod nikon;
+#[cfg(feature = "olympus")]
 pub mod olympus;
+#[cfg(feature = "raw-formats")]
 pub mod panasonic_raw;
 pub mod print_conv;
 pub mod quicktime;
 pub mod raw_conv;
 pub mod ricoh;
+#[cfg(feature = "sony")]
 pub mod sony;
 pub mod value_conv;
 
@@ -86,6 +112,10 @@ pub fn register_all_conversions() {
     );
     registry::register_print_conv("focallength_print_conv", print_conv::focallength_print_conv);
     registry::register_print_conv("lensinfo_print_conv", print_conv::lensinfo_print_conv);
+    registry::register_print_conv(
+        "composite_image_exposure_times_print_conv",
+        print_conv::composite_image_exposure_times_print_conv,
+    );
     registry::register_print_conv("iso_print_conv", print_conv::iso_print_conv);
 
     // EXIF version and component PrintConv functions
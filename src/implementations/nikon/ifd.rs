@@ -11,7 +11,7 @@
 //! - Model-specific tag table selection
 
 use crate::exif::ExifReader;
-use crate::implementations::nikon::{af_processing, encryption, tags};
+use crate::implementations::nikon::{af_processing, encryption, lens_data, picture_control, tags};
 use crate::tiff_types::{ByteOrder, IfdEntry, TiffFormat};
 use crate::types::{ExifError, Result, TagValue};
 use crate::value_extraction;
@@ -414,6 +414,39 @@ pub fn process_standard_nikon_tags(
                         debug!("Failed to extract AF Info binary data: {:?}", e);
                     }
                 }
+            } else if entry.tag_id == 0x0098 {
+                // LensData, decrypted with the pre-scanned keys when needed
+                // ExifTool: Nikon.pm LensData tables, chosen by version
+                match extract_raw_data(&data, &entry) {
+                    Ok(lens_data) => {
+                        if let Err(e) =
+                            lens_data::process_nikon_lens_data(reader, &lens_data, byte_order, keys)
+                        {
+                            debug!("LensData processing failed: {:?}", e);
+                        }
+                    }
+                    Err(e) => {
+                        debug!("Failed to extract LensData: {:?}", e);
+                    }
+                }
+            } else if matches!(entry.tag_id, 0x0023 | 0x00BD) {
+                // PictureControlData / PictureControlData2 subdirectories
+                // ExifTool: Nikon.pm PictureControl tables, chosen by version
+                match extract_raw_data(&data, &entry) {
+                    Ok(pc_data) => {
+                        if let Err(e) = picture_control::process_nikon_picture_control(
+                            reader,
+                            entry.tag_id,
+                            &pc_data,
+                            byte_order,
+                        ) {
+                            debug!("Picture Control processing failed: {:?}", e);
+                        }
+                    }
+                    Err(e) => {
+                        debug!("Failed to extract Picture Control data: {:?}", e);
+                    }
+                }
             } else {
                 // Standard tag processing
                 match extract_tag_value(&data, &entry, byte_order) {
@@ -0,0 +1,220 @@
+//! Nikon LensData processing
+//!
+//! LensData (0x0098) records the mounted lens and its state at capture:
+//! exit pupil position, AF aperture, focus position and distance, focal
+//! length and the lens ID bytes. Versions 0100 and 0101 are plain binary
+//! data; from 0201 everything after the 4-byte version is encrypted with
+//! the SerialNumber and ShutterCount keys.
+//!
+//! Only the layouts with a focus distance byte are decoded here. The 0400
+//! and 0402/0403 layouts hold little more than the lens name, and 0800's
+//! NewLensData fields depend on conditions the generated table can't
+//! express yet.
+//!
+//! ExifTool References:
+//! - lib/Image/ExifTool/Nikon.pm Main 0x0098 SubDirectory conditions
+//! - lib/Image/ExifTool/Nikon.pm LensData00, LensData01 and LensData0204
+
+use crate::exif::ExifReader;
+use crate::generated::Nikon_pm::{lens_data00_tags, lens_data01_tags, lens_data0204_tags};
+use crate::implementations::maker_table::{self, BinaryDataState, MakerTable, MakerTag};
+use crate::implementations::nikon::encryption::{decrypt_nikon_data, NikonEncryptionKeys};
+use crate::tiff_types::ByteOrder;
+use crate::types::{Result, TagValue};
+use tracing::debug;
+
+/// Synthetic tag IDs for tags decoded from LensData
+const LENS_DATA_SYNTHETIC_BASE: u16 = 0xFA80;
+
+/// ExifTool: LensData0201-0204 `DecryptStart => 4`
+const DECRYPT_START: usize = 4;
+
+/// Decode a LensData block, decrypting it first when the version requires
+/// ExifTool: Nikon.pm Main 0x0098 SubDirectory
+pub fn process_nikon_lens_data(
+    reader: &mut ExifReader,
+    data: &[u8],
+    byte_order: ByteOrder,
+    keys: &NikonEncryptionKeys,
+) -> Result<()> {
+    let Some((table, encrypted)) = select_table(data) else {
+        debug!(
+            "Nikon LensData version {:?} not decoded",
+            data.get(..4).map(String::from_utf8_lossy)
+        );
+        return Ok(());
+    };
+
+    let data = if encrypted {
+        let (Some(serial), Some(count)) = (keys.get_serial_key_numeric(), keys.get_count_key())
+        else {
+            debug!("Nikon LensData is encrypted and the decryption keys are missing");
+            return Ok(());
+        };
+        decrypt_nikon_data(
+            data,
+            DECRYPT_START,
+            None,
+            Some(serial),
+            Some(count),
+            &mut None,
+        )?
+    } else {
+        data.to_vec()
+    };
+
+    let tags = table
+        .read_binary_entries(&data, byte_order, "int8u", &mut BinaryDataState::default())
+        .into_iter()
+        .filter_map(format_tag)
+        .collect::<Vec<_>>();
+    debug!("Decoded {} Nikon LensData tags", tags.len());
+
+    maker_table::store_tags(reader, LENS_DATA_SYNTHETIC_BASE, "Nikon", "Nikon", tags);
+    Ok(())
+}
+
+/// The LensData table for the block's version, and whether it's encrypted
+/// ExifTool: Nikon.pm Main 0x0098 Conditions `$$valPt =~ /^0100/` etc.
+fn select_table(data: &[u8]) -> Option<(MakerTable, bool)> {
+    let lens_data01 = MakerTable {
+        tags: &lens_data01_tags::NIKON_LENSDATA01_TAGS,
+        value_conv: lens_data01_tags::apply_value_conv,
+        print_conv: lens_data01_tags::apply_print_conv,
+    };
+    let selected = match data.get(..4)? {
+        b"0100" => (
+            MakerTable {
+                tags: &lens_data00_tags::NIKON_LENSDATA00_TAGS,
+                value_conv: lens_data00_tags::apply_value_conv,
+                print_conv: lens_data00_tags::apply_print_conv,
+            },
+            false,
+        ),
+        b"0101" => (lens_data01, false),
+        b"0201" | b"0202" | b"0203" => (lens_data01, true),
+        b"0204" => (
+            MakerTable {
+                tags: &lens_data0204_tags::NIKON_LENSDATA0204_TAGS,
+                value_conv: lens_data0204_tags::apply_value_conv,
+                print_conv: lens_data0204_tags::apply_print_conv,
+            },
+            true,
+        ),
+        _ => return None,
+    };
+    Some(selected)
+}
+
+/// Show the version as text, and drop FocalLength: it's the same value as
+/// EXIF:FocalLength, which composites should keep using
+fn format_tag(tag: MakerTag) -> Option<MakerTag> {
+    let value = match (tag.name.as_ref(), tag.value) {
+        ("FocalLength", _) => return None,
+        // undef[4] like "0100"
+        ("LensDataVersion", TagValue::Binary(bytes)) => {
+            TagValue::String(String::from_utf8_lossy(&bytes).into_owned())
+        }
+        (_, value) => value,
+    };
+    Some(MakerTag { value, ..tag })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decoded(reader: &mut ExifReader, name: &str) -> Option<TagValue> {
+        reader
+            .get_all_tag_entries()
+            .into_iter()
+            .find(|entry| entry.group == "MakerNotes" && entry.name == name)
+            .map(|entry| entry.print().clone())
+    }
+
+    /// A LensData 0101 block focused at raw distance byte `distance`
+    fn lens_data_0101(distance: u8) -> Vec<u8> {
+        let mut data = b"0101".to_vec();
+        // ExitPupilPosition, AFAperture, 2 unknown bytes, FocusPosition
+        data.extend([0x40, 0x0c, 0, 0, 0x06]);
+        // FocusDistance, FocalLength, LensIDNumber, LensFStops
+        data.extend([distance, 0x50, 0x7f, 0x48]);
+        // Min/MaxFocalLength, MaxApertureAtMin/MaxFocal, MCUVersion,
+        // EffectiveMaxAperture
+        data.extend([0x50, 0x50, 0x14, 0x14, 0x62, 0x14]);
+        data
+    }
+
+    #[test]
+    fn test_plain_lens_data() {
+        let mut reader = ExifReader::new();
+        // 0.01 * 10**(80/40) = 1 m
+        process_nikon_lens_data(
+            &mut reader,
+            &lens_data_0101(80),
+            ByteOrder::BigEndian,
+            &NikonEncryptionKeys::new("NIKON D700".to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            decoded(&mut reader, "LensDataVersion"),
+            Some(TagValue::string("0101"))
+        );
+        assert_eq!(
+            decoded(&mut reader, "FocusDistance"),
+            Some(TagValue::string("1.00 m"))
+        );
+        assert_eq!(decoded(&mut reader, "FocalLength"), None);
+    }
+
+    #[test]
+    fn test_encrypted_lens_data_needs_keys() {
+        let mut data = lens_data_0101(80);
+        data[..4].copy_from_slice(b"0204");
+        let mut reader = ExifReader::new();
+        process_nikon_lens_data(
+            &mut reader,
+            &data,
+            ByteOrder::BigEndian,
+            &NikonEncryptionKeys::new("NIKON D700".to_string()),
+        )
+        .unwrap();
+        assert!(reader.synthetic_tag_names.is_empty());
+    }
+
+    #[test]
+    fn test_encrypted_lens_data() {
+        let mut keys = NikonEncryptionKeys::new("NIKON D700".to_string());
+        keys.store_serial_key("12345".to_string());
+        keys.store_count_key(1000);
+
+        // The cipher is an XOR stream, so encrypting is decrypting
+        let mut data = lens_data_0101(120);
+        data[..4].copy_from_slice(b"0201");
+        let encrypted =
+            decrypt_nikon_data(&data, 4, None, Some(12345), Some(1000), &mut None).unwrap();
+        assert_ne!(encrypted, data);
+
+        let mut reader = ExifReader::new();
+        process_nikon_lens_data(&mut reader, &encrypted, ByteOrder::BigEndian, &keys).unwrap();
+        // 0.01 * 10**(120/40) = 10 m
+        assert_eq!(
+            decoded(&mut reader, "FocusDistance"),
+            Some(TagValue::string("10.00 m"))
+        );
+    }
+
+    #[test]
+    fn test_unknown_lens_data_version() {
+        let mut reader = ExifReader::new();
+        process_nikon_lens_data(
+            &mut reader,
+            b"0400",
+            ByteOrder::BigEndian,
+            &NikonEncryptionKeys::new("NIKON D700".to_string()),
+        )
+        .unwrap();
+        assert!(reader.synthetic_tag_names.is_empty());
+    }
+}
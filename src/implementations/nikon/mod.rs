@@ -24,6 +24,7 @@
 //! - `ifd.rs` - IFD parsing, tag extraction, and standard tag processing
 //! - `tags.rs` - Primary tag ID mappings and model-specific tables
 //! - `lens_database.rs` - 618-entry lens ID lookup system
+//! - `picture_control.rs` - Picture Control name and adjustment blocks
 //! - `tests.rs` - Comprehensive unit tests for all components
 
 pub mod af_processing;
@@ -32,8 +33,10 @@ pub mod detection;
 pub mod encrypted_processing;
 pub mod encryption;
 pub mod ifd;
+pub mod lens_data;
 pub mod lens_database;
 pub mod offset_schemes;
+pub mod picture_control;
 pub mod tags;
 
 // Re-export commonly used functions for easier access
@@ -47,6 +50,7 @@ pub use encryption::{process_encrypted_sections, NikonEncryptionKeys};
 pub use ifd::{prescan_for_encryption_keys, process_standard_nikon_tags};
 pub use lens_database::lookup_nikon_lens;
 pub use offset_schemes::calculate_nikon_base_offset;
+pub use picture_control::process_nikon_picture_control;
 pub use tags::{get_nikon_tag_name, select_nikon_tag_table};
 
 use crate::exif::ExifReader;
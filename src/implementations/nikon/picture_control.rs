@@ -0,0 +1,241 @@
+//! Nikon Picture Control processing
+//!
+//! PictureControlData (0x0023) and PictureControlData2 (0x00bd) record the
+//! Picture Control an image was shot with: its name, the base control a
+//! custom one was derived from, and the Quick Adjust, sharpening, contrast,
+//! brightness, saturation, hue, filter and toning settings. The layout is
+//! chosen by the version string at the start of the block.
+//!
+//! ExifTool References:
+//! - lib/Image/ExifTool/Nikon.pm PictureControl, PictureControl2 and
+//!   PictureControl3 tables
+//! - lib/Image/ExifTool/Nikon.pm FormatString() and PrintPC()
+
+use crate::exif::ExifReader;
+use crate::generated::Nikon_pm::{
+    picture_control2_tags, picture_control3_tags, picture_control_tags,
+};
+use crate::implementations::maker_table::{self, BinaryDataState, MakerTable, MakerTag};
+use crate::tiff_types::ByteOrder;
+use crate::types::{Result, TagValue};
+use tracing::debug;
+
+/// Synthetic tag IDs for tags decoded from PictureControlData
+const PICTURE_CONTROL_SYNTHETIC_BASE: u16 = 0xFA00;
+/// Synthetic tag IDs for tags decoded from PictureControlData2
+const PICTURE_CONTROL2_SYNTHETIC_BASE: u16 = 0xFA40;
+
+/// Decode a PictureControlData or PictureControlData2 block
+/// ExifTool: Nikon.pm Main 0x0023 and 0x00bd SubDirectory
+pub fn process_nikon_picture_control(
+    reader: &mut ExifReader,
+    tag_id: u16,
+    data: &[u8],
+    byte_order: ByteOrder,
+) -> Result<()> {
+    let Some(table) = select_table(data) else {
+        // ExifTool: PictureControlUnknown has no known tags
+        debug!("Unknown Nikon Picture Control version in tag {:#x}", tag_id);
+        return Ok(());
+    };
+
+    let tags = table
+        .read_binary_entries(data, byte_order, "int8u", &mut BinaryDataState::default())
+        .into_iter()
+        .map(format_tag)
+        .collect::<Vec<_>>();
+    debug!(
+        "Decoded {} Nikon Picture Control tags from {:#x}",
+        tags.len(),
+        tag_id
+    );
+
+    let base_id = match tag_id {
+        0x00bd => PICTURE_CONTROL2_SYNTHETIC_BASE,
+        _ => PICTURE_CONTROL_SYNTHETIC_BASE,
+    };
+    maker_table::store_tags(reader, base_id, "Nikon", "Nikon", tags);
+    Ok(())
+}
+
+/// The Picture Control table for the block's version
+/// ExifTool: Nikon.pm Main 0x0023 Conditions `$$valPt =~ /^01/` etc.
+fn select_table(data: &[u8]) -> Option<MakerTable> {
+    let table = match data.get(..2)? {
+        b"01" => MakerTable {
+            tags: &picture_control_tags::NIKON_PICTURECONTROL_TAGS,
+            value_conv: picture_control_value_conv,
+            print_conv: picture_control_tags::apply_print_conv,
+        },
+        b"02" => MakerTable {
+            tags: &picture_control2_tags::NIKON_PICTURECONTROL2_TAGS,
+            value_conv: picture_control2_value_conv,
+            print_conv: picture_control2_tags::apply_print_conv,
+        },
+        b"03" => MakerTable {
+            tags: &picture_control3_tags::NIKON_PICTURECONTROL3_TAGS,
+            value_conv: picture_control3_value_conv,
+            print_conv: picture_control3_tags::apply_print_conv,
+        },
+        _ => return None,
+    };
+    Some(table)
+}
+
+/// Widen a byte before the generated `$val - 0x80` ValueConv, which would
+/// otherwise underflow an unsigned value for negative settings
+fn widen(value: &TagValue) -> TagValue {
+    match value {
+        TagValue::U8(byte) => TagValue::I32(*byte as i32),
+        value => value.clone(),
+    }
+}
+
+fn picture_control_value_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+) -> Result<TagValue> {
+    picture_control_tags::apply_value_conv(tag_id, &widen(value), errors)
+}
+
+fn picture_control2_value_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+) -> Result<TagValue> {
+    picture_control2_tags::apply_value_conv(tag_id, &widen(value), errors)
+}
+
+fn picture_control3_value_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+) -> Result<TagValue> {
+    picture_control3_tags::apply_value_conv(tag_id, &widen(value), errors)
+}
+
+/// Conversions the generated tables leave as raw values
+fn format_tag(tag: MakerTag) -> MakerTag {
+    let value = match (tag.name.as_ref(), tag.value) {
+        // undef[4] like "0100", shown as text
+        ("PictureControlVersion", TagValue::Binary(bytes)) => {
+            TagValue::String(String::from_utf8_lossy(&bytes).into_owned())
+        }
+        ("PictureControlName" | "PictureControlBase", TagValue::String(name)) => {
+            TagValue::String(format_string(&name))
+        }
+        (_, value) => value,
+    };
+    MakerTag { value, ..tag }
+}
+
+/// Trim trailing blanks and convert all-caps words to mixed case
+/// ExifTool: Nikon.pm FormatString(), e.g. "STANDARD" -> "Standard"
+fn format_string(name: &str) -> String {
+    let name = name.trim_end();
+    let mut formatted = String::with_capacity(name.len());
+    let mut word = String::new();
+    let flush = |word: &mut String, formatted: &mut String| {
+        let has_vowel = word.chars().any(|c| "AEIOUY".contains(c));
+        let all_caps = !word.chars().any(|c| c.is_ascii_lowercase());
+        if has_vowel && all_caps {
+            let mut chars = word.chars();
+            formatted.extend(chars.next());
+            formatted.push_str(&chars.as_str().to_ascii_lowercase());
+        } else {
+            formatted.push_str(word);
+        }
+        word.clear();
+    };
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            word.push(c);
+        } else {
+            flush(&mut word, &mut formatted);
+            formatted.push(c);
+        }
+    }
+    flush(&mut word, &mut formatted);
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A PictureControl version 0100 block
+    fn picture_control_v1() -> Vec<u8> {
+        let mut data = b"0100".to_vec();
+        let mut name = b"VIVID".to_vec();
+        name.resize(20, 0);
+        data.extend(&name);
+        let mut base = b"STANDARD".to_vec();
+        // PictureControlBase is string[20], followed by 4 unknown bytes
+        base.resize(24, 0);
+        data.extend(&base);
+        // Adjust, QuickAdjust, Sharpness, Contrast, Brightness, Saturation,
+        // HueAdjustment, FilterEffect, ToningEffect, ToningSaturation
+        data.extend([2, 0xff, 0x83, 0x7f, 0x80, 0x81, 0x80, 0xff, 0xff, 0xff]);
+        data
+    }
+
+    fn decoded(reader: &mut ExifReader) -> Vec<(String, TagValue)> {
+        let mut tags = reader
+            .get_all_tag_entries()
+            .into_iter()
+            .filter(|entry| entry.group == "MakerNotes")
+            .map(|entry| (entry.name.to_string(), entry.print().clone()))
+            .collect::<Vec<_>>();
+        tags.sort_by(|a, b| a.0.cmp(&b.0));
+        tags
+    }
+
+    #[test]
+    fn test_picture_control_v1() {
+        let mut reader = ExifReader::new();
+        process_nikon_picture_control(
+            &mut reader,
+            0x0023,
+            &picture_control_v1(),
+            ByteOrder::BigEndian,
+        )
+        .unwrap();
+
+        let tags = decoded(&mut reader);
+        let get = |name: &str| {
+            tags.iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| v.clone())
+                .unwrap_or_else(|| panic!("{name} missing"))
+        };
+        assert_eq!(get("PictureControlVersion"), TagValue::string("0100"));
+        assert_eq!(get("PictureControlName"), TagValue::string("Vivid"));
+        assert_eq!(get("PictureControlBase"), TagValue::string("Standard"));
+        assert_eq!(
+            get("PictureControlAdjust"),
+            TagValue::string("Full Control")
+        );
+        assert_eq!(get("PictureControlQuickAdjust"), TagValue::string("n/a"));
+        assert_eq!(get("Sharpness"), TagValue::string("3"));
+        assert_eq!(get("Contrast"), TagValue::string("-1"));
+        assert_eq!(get("Brightness"), TagValue::string("Normal"));
+        assert_eq!(get("Saturation"), TagValue::string("+1"));
+        assert_eq!(get("HueAdjustment"), TagValue::string("None"));
+        assert_eq!(get("FilterEffect"), TagValue::string("n/a"));
+    }
+
+    #[test]
+    fn test_unknown_picture_control_version() {
+        let mut reader = ExifReader::new();
+        process_nikon_picture_control(&mut reader, 0x00bd, b"0900", ByteOrder::BigEndian).unwrap();
+        assert!(reader.synthetic_tag_names.is_empty());
+    }
+
+    #[test]
+    fn test_format_string() {
+        assert_eq!(format_string("STANDARD  "), "Standard");
+        assert_eq!(format_string("MY CUSTOM_LOOK"), "My Custom_Look");
+        assert_eq!(format_string("Flat"), "Flat");
+    }
+}
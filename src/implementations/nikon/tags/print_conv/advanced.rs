@@ -292,6 +292,37 @@ pub fn nikon_group_area_illumination_conv(
     Ok(illumination_map.get(&val).unwrap_or(&"Unknown").to_string())
 }
 
+/// PrintConv function for Nikon CropHiSpeed tag
+/// ExifTool: Nikon.pm 0x001b - crop mode, then the sensor size and the cropped
+/// area: "Off (6048x4024 cropped to 6048x4024 at pixel 0,0)"
+pub fn nikon_crop_hi_speed_conv(value: &crate::types::TagValue) -> Result<String, String> {
+    use crate::generated::Nikon_pm::main_tags::NIKON_MAIN_TAGS;
+    use crate::types::{PrintConv, TagValue};
+
+    // ExifTool: my @a = split ' ', $val
+    let a: Vec<String> = match value {
+        TagValue::U16Array(values) => values.iter().map(u16::to_string).collect(),
+        TagValue::U32Array(values) => values.iter().map(u32::to_string).collect(),
+        TagValue::String(s) => s.split_whitespace().map(str::to_string).collect(),
+        _ => vec![value.to_string()],
+    };
+    if a.len() != 7 {
+        return Ok(format!("Unknown ({})", a.join(" ")));
+    }
+    // Crop mode names from the generated PrintConv hash
+    let mode = NIKON_MAIN_TAGS
+        .get(&0x001b)
+        .and_then(|tag| match &tag.print_conv {
+            Some(PrintConv::Simple(map)) => map.get(&a[0]).map(|name| name.to_string()),
+            _ => None,
+        })
+        .unwrap_or_else(|| format!("Unknown ({})", a[0]));
+    Ok(format!(
+        "{mode} ({}x{} cropped to {}x{} at pixel {},{})",
+        a[1], a[2], a[3], a[4], a[5], a[6]
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -398,4 +429,18 @@ mod tests {
         let result = nikon_hdr_conv(&unknown_hdr).unwrap();
         assert_eq!(result, "Unknown");
     }
+
+    #[test]
+    fn test_nikon_crop_hi_speed_conv() {
+        let dx = TagValue::U16Array(vec![2, 6048, 4024, 4032, 2680, 1008, 672]);
+        assert_eq!(
+            nikon_crop_hi_speed_conv(&dx).unwrap(),
+            "DX Crop (6048x4024 cropped to 4032x2680 at pixel 1008,672)"
+        );
+        let short = TagValue::U16Array(vec![0, 6048]);
+        assert_eq!(
+            nikon_crop_hi_speed_conv(&short).unwrap(),
+            "Unknown (0 6048)"
+        );
+    }
 }
@@ -33,6 +33,7 @@ pub static NIKON_MAIN_TAGS: NikonTagTable = NikonTagTable {
         (0x0012, "FlashExposureComp", None),
         (0x0013, "ISOSetting", None),
         (0x0014, "ColorBalanceA", None),
+        (0x001B, "CropHiSpeed", Some(nikon_crop_hi_speed_conv)),
         // Encryption key tags (critical for Phase 2)
         (0x001D, "SerialNumber", None), // Encryption key source
         (0x001E, "ColorSpace", Some(nikon_color_space_conv)),
@@ -634,6 +634,30 @@ pub fn lensinfo_print_conv(val: &TagValue, _ctx: Option<&ExifContext>) -> TagVal
     TagValue::string(result)
 }
 
+/// EXIF CompositeImageExposureTimes PrintConv
+/// ExifTool: lib/Image/ExifTool/Exif.pm 0xa462 PrintConv
+/// Formats every value except the two sequence counts (items 8 and 9) as an exposure time
+pub fn composite_image_exposure_times_print_conv(
+    val: &TagValue,
+    ctx: Option<&ExifContext>,
+) -> TagValue {
+    let Some(text) = val.as_string() else {
+        return val.clone();
+    };
+    let printed: Vec<String> = text
+        .split_whitespace()
+        .enumerate()
+        .map(|(i, item)| match item.parse::<f64>() {
+            // ExifTool: PrintExposureTime returns non-numeric values unchanged
+            Ok(secs) if i != 7 && i != 8 => {
+                exposuretime_print_conv(&TagValue::F64(secs), ctx).to_string()
+            }
+            _ => item.to_string(),
+        })
+        .collect();
+    TagValue::string(printed.join(" "))
+}
+
 /// Generic decimal formatting functions for sprintf patterns
 /// Complex expression placeholder - delegates to appropriate function
 /// This is used when tag_kit.pl can't determine the exact function
@@ -897,6 +921,70 @@ pub fn canon_selftimer_print_conv(val: &TagValue, _ctx: Option<&ExifContext>) ->
     }
 }
 
+/// Nikon PictureControl adjustment PrintConv
+/// ExifTool: lib/Image/ExifTool/Nikon.pm PrintPC sub
+/// The value has already had 0x80 subtracted by its ValueConv. Zero prints as
+/// `norm`, other settings through `number` (the sprintf format in ExifTool).
+fn nikon_print_pc(val: &TagValue, norm: &str, number: impl Fn(f64) -> String) -> TagValue {
+    let Some(v) = val.as_f64() else {
+        return val.clone();
+    };
+    let text = match v as i64 {
+        0 => norm.to_string(),
+        0x7f => "n/a".to_string(),
+        -128 => "Auto".to_string(),
+        -127 => "User".to_string(),
+        _ => number(v),
+    };
+    TagValue::string(text)
+}
+
+/// Nikon PictureControl PrintConv: `PrintPC($val)`
+pub fn nikon_pc_print_conv(val: &TagValue, _ctx: Option<&ExifContext>) -> TagValue {
+    nikon_print_pc(val, "Normal", |v| format!("{:+}", v as i64))
+}
+
+/// Nikon PictureControl Sharpness PrintConv: `PrintPC($val,"No Sharpening","%d")`
+pub fn nikon_pc_sharpness_print_conv(val: &TagValue, _ctx: Option<&ExifContext>) -> TagValue {
+    nikon_print_pc(val, "No Sharpening", |v| format!("{}", v as i64))
+}
+
+/// Nikon PictureControl HueAdjustment PrintConv: `PrintPC($val,"None")`
+pub fn nikon_pc_hue_print_conv(val: &TagValue, _ctx: Option<&ExifContext>) -> TagValue {
+    nikon_print_pc(val, "None", |v| format!("{:+}", v as i64))
+}
+
+/// Nikon PictureControl2/3 PrintConv: `PrintPC($val,"None","%.2f",4)`
+/// These tables store quarter steps.
+pub fn nikon_pc2_print_conv(val: &TagValue, _ctx: Option<&ExifContext>) -> TagValue {
+    nikon_print_pc(val, "None", |v| format!("{:.2}", v / 4.0))
+}
+
+/// Nikon PictureControl2/3 Brightness PrintConv: `PrintPC($val,undef,"%.2f",4)`
+pub fn nikon_pc2_brightness_print_conv(val: &TagValue, _ctx: Option<&ExifContext>) -> TagValue {
+    nikon_print_pc(val, "Normal", |v| format!("{:.2}", v / 4.0))
+}
+
+/// PrintConv of a BITMASK hash: the names of the set bits, `[N]` for bits
+/// without a name and `(none)` when no bit is set
+/// ExifTool: lib/Image/ExifTool.pm DecodeBits
+pub fn decode_bits(val: &TagValue, bits: &[(u32, &str)]) -> TagValue {
+    let Some(value) = val.as_u32() else {
+        return val.clone();
+    };
+    if value == 0 {
+        return TagValue::string("(none)");
+    }
+    let names: Vec<String> = (0..32)
+        .filter(|bit| value & (1 << bit) != 0)
+        .map(|bit| match bits.iter().find(|(b, _)| *b == bit) {
+            Some((_, name)) => name.to_string(),
+            None => format!("[{bit}]"),
+        })
+        .collect();
+    TagValue::string(names.join(", "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1515,4 +1603,64 @@ mod tests {
             assert!(s.starts_with("Unknown"));
         }
     }
+
+    #[test]
+    fn test_nikon_print_pc() {
+        let pc = |v: i32| nikon_pc_print_conv(&TagValue::I32(v), None);
+        assert_eq!(pc(0), TagValue::string("Normal"));
+        assert_eq!(pc(2), TagValue::string("+2"));
+        assert_eq!(pc(-1), TagValue::string("-1"));
+        assert_eq!(pc(-128), TagValue::string("Auto"));
+        assert_eq!(pc(0x7f), TagValue::string("n/a"));
+        assert_eq!(
+            nikon_pc_sharpness_print_conv(&TagValue::I32(3), None),
+            TagValue::string("3")
+        );
+        assert_eq!(
+            nikon_pc_sharpness_print_conv(&TagValue::I32(0), None),
+            TagValue::string("No Sharpening")
+        );
+        assert_eq!(
+            nikon_pc2_print_conv(&TagValue::I32(10), None),
+            TagValue::string("2.50")
+        );
+        assert_eq!(
+            nikon_pc2_print_conv(&TagValue::I32(-6), None),
+            TagValue::string("-1.50")
+        );
+        assert_eq!(
+            nikon_pc2_brightness_print_conv(&TagValue::I32(0), None),
+            TagValue::string("Normal")
+        );
+    }
+
+    #[test]
+    fn test_composite_image_exposure_times_print_conv() {
+        let val = TagValue::string("2 0.5 0.25 0.125 0.125 0.004 0.004 1 2 0.125 undef");
+        assert_eq!(
+            composite_image_exposure_times_print_conv(&val, None),
+            TagValue::string("2 0.5 1/4 1/8 1/8 1/250 1/250 1 2 1/8 undef")
+        );
+    }
+
+    #[test]
+    fn test_decode_bits() {
+        let bits = &[(0, "Valid"), (1, "Has been rounded")];
+        assert_eq!(
+            decode_bits(&TagValue::U32(1), bits),
+            TagValue::string("Valid")
+        );
+        assert_eq!(
+            decode_bits(&TagValue::U32(3), bits),
+            TagValue::string("Valid, Has been rounded")
+        );
+        assert_eq!(
+            decode_bits(&TagValue::U32(4), bits),
+            TagValue::string("[2]")
+        );
+        assert_eq!(
+            decode_bits(&TagValue::U32(0), bits),
+            TagValue::string("(none)")
+        );
+    }
 }
@@ -40,8 +40,9 @@ pub const QT_EPOCH_OFFSET: i64 = (66 * 365 + 17) * 24 * 3600; // 2082844800
 ///
 /// Accepted divergences from the Perl (review-vetted 2026-07-03):
 /// - The RawConv also subtracts when the `QuickTimeUTC` *option* is set
-///   (`$val >= $offset or $$self{OPTIONS}{QuickTimeUTC}`); exif-oxide exposes no
-///   such option, so that branch is unreachable and deliberately not ported.
+///   (`$val >= $offset or $$self{OPTIONS}{QuickTimeUTC}`). exif-oxide applies
+///   that option after extraction (see `formats::quicktime_dates`), so 1970-epoch
+///   values are never shifted into 1904; this branch is deliberately not ported.
 ///   (Note: CR3's forced-UTC behavior lives in the ValueConv's
 ///   `FileType eq "CR3"` test, NOT in this RawConv branch.)
 /// - Raw values above `i64::MAX` (v1 boxes are int64u) wrap negative and render
@@ -67,7 +68,8 @@ pub fn patch_time_zero(raw: i64) -> i64 {
 ///
 /// `to_local` is threaded through for the future CR3 path (which forces UTC
 /// conversion to local time); the MOV walker always passes `false`, matching the
-/// snapshots (tools/generate_exiftool_json.sh omits QuickTimeUTC).
+/// snapshots (tools/generate_exiftool_json.sh omits QuickTimeUTC). The
+/// `FilterOptions::quicktime_utc` option converts the walker's dates afterwards.
 pub fn convert_unix_time(unix: i64, to_local: bool) -> String {
     // ExifTool.pm:6787
     if unix == 0 {
@@ -87,6 +89,23 @@ pub fn convert_unix_time(unix: i64, to_local: bool) -> String {
     }
 }
 
+/// MediaHeader MediaLanguageCode (QuickTime.pm:7276) as an ISO 639-2 code.
+///
+/// `RawConv => '$val ? $val : undef'`, then ValueConv `$val < 0x400 ? $val :
+/// pack "C*", map { (($val>>$_)&0x1f)+0x60 } 10, 5, 0`. Values below 0x400 are
+/// Macintosh language numbers, which have no ISO code here, so they give `None`
+/// like the zero value.
+pub fn media_language_code(val: u16) -> Option<String> {
+    if val < 0x400 {
+        return None;
+    }
+    let code: String = [10, 5, 0]
+        .iter()
+        .map(|shift| (((val >> shift) & 0x1f) as u8 + 0x60) as char)
+        .collect();
+    code.chars().all(|c| c.is_ascii_lowercase()).then_some(code)
+}
+
 /// Port of `ConvertDuration` (ExifTool.pm:6877-6895).
 ///
 /// Renders a duration already divided into seconds (the `%durationInfo`/
@@ -146,6 +165,59 @@ pub fn fix_wrong_format(val: u32) -> Option<u32> {
     }
 }
 
+/// Port of `GetFixed32s` (ExifTool.pm), the `fixed32s` format: a 16.16
+/// signed fixed-point value rounded to 5 decimal places ("remove
+/// insignificant digits": `int($val * 1e5 + ($val>0 ? 0.5 : -0.5)) / 1e5`).
+pub fn fixed32s(raw: i32) -> f64 {
+    let val = raw as f64 / 65536.0;
+    let half = if val > 0.0 { 0.5 } else { -0.5 };
+    (val * 1e5 + half).trunc() / 1e5
+}
+
+/// Decode the value of an ItemList `data` atom by its well-known type
+/// (ProcessMOV:10330-10365, QuickTime.pm:9446 `%dataType`).
+///
+/// 1 = UTF-8, 2 = UTF-16, 21/22 = big-endian signed/unsigned int of 1, 2, 4 or
+/// 8 bytes, 23/24 = float/double. Everything else (images, binary) returns
+/// `None` and the caller skips the tag.
+pub fn decode_item_data(data_type: u32, p: &[u8]) -> Option<TagValue> {
+    match data_type {
+        1 => Some(TagValue::String(
+            String::from_utf8_lossy(p)
+                .trim_end_matches('\0')
+                .to_string(),
+        )),
+        2 => {
+            let units: Vec<u16> = p
+                .chunks_exact(2)
+                .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                .collect();
+            Some(TagValue::String(
+                String::from_utf16_lossy(&units)
+                    .trim_end_matches('\0')
+                    .to_string(),
+            ))
+        }
+        21 => match p.len() {
+            1 => Some(TagValue::I16(p[0] as i8 as i16)),
+            2 => Some(TagValue::I16(i16::from_be_bytes([p[0], p[1]]))),
+            4 => Some(TagValue::I32(i32::from_be_bytes(p.try_into().ok()?))),
+            8 => Some(TagValue::F64(i64::from_be_bytes(p.try_into().ok()?) as f64)),
+            _ => None,
+        },
+        22 => match p.len() {
+            1 => Some(TagValue::U8(p[0])),
+            2 => Some(TagValue::U16(u16::from_be_bytes([p[0], p[1]]))),
+            4 => Some(TagValue::U32(u32::from_be_bytes(p.try_into().ok()?))),
+            8 => Some(TagValue::U64(u64::from_be_bytes(p.try_into().ok()?))),
+            _ => None,
+        },
+        23 if p.len() == 4 => Some(TagValue::F64(f32::from_be_bytes(p.try_into().ok()?) as f64)),
+        24 if p.len() == 8 => Some(TagValue::F64(f64::from_be_bytes(p.try_into().ok()?))),
+        _ => None,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // (val, ctx) registry wrappers — resolve the generated conversion stubs.
 // ---------------------------------------------------------------------------
@@ -244,6 +316,15 @@ mod tests {
         assert_eq!(convert_unix_time(0, false), "0000:00:00 00:00:00");
     }
 
+    #[test]
+    fn media_language_code_iso_and_macintosh() {
+        // 'eng' = (5 << 10) | (14 << 5) | 7
+        assert_eq!(media_language_code(0x15c7).as_deref(), Some("eng"));
+        assert_eq!(media_language_code(0x55c4).as_deref(), Some("und"));
+        assert_eq!(media_language_code(0), None);
+        assert_eq!(media_language_code(3), None);
+    }
+
     #[test]
     fn convert_duration_seconds_and_hms() {
         assert_eq!(convert_duration(15000.0 / 2000.0), "7.50 s");
@@ -267,4 +348,12 @@ mod tests {
         // Small raw value (Pentax's wrong format): high bits clear -> returned as-is.
         assert_eq!(fix_wrong_format(320), Some(320));
     }
+
+    #[test]
+    fn fixed32s_rounds_to_five_places() {
+        assert_eq!(fixed32s(90 << 16), 90.0);
+        assert_eq!(fixed32s(-(45 << 16) - 0x8000), -45.5);
+        // 1/65536 = 0.0000152... -> 0.00002
+        assert_eq!(fixed32s(1), 0.00002);
+    }
 }
@@ -3,6 +3,8 @@
 //! RawConv functions are applied to raw tag values before ValueConv/PrintConv,
 //! typically used for decoding or special processing of raw data.
 
+use crate::core::math::round_float;
+use crate::tiff_types::ByteOrder;
 use crate::types::{ExifContext, Result, TagValue};
 use tracing::debug;
 
@@ -97,6 +99,42 @@ pub fn convert_exif_text(value: &TagValue, _ctx: Option<&ExifContext>) -> Result
     }
 }
 
+/// Decode EXIF 2.32 CompositeImageExposureTimes (0xa462) into a space-separated list
+/// ExifTool: lib/Image/ExifTool/Exif.pm 0xa462 RawConv
+///
+/// The UNDEFINED payload is a run of rational64u values, except for the two
+/// int16u sequence counts at byte offsets 56 and 58. Decoding stops at the first
+/// value that doesn't fit. Needs the IFD byte order, so it isn't registered with
+/// the RawConv registry.
+pub fn composite_image_exposure_times(data: &[u8], byte_order: ByteOrder) -> TagValue {
+    let mut values = Vec::new();
+    let mut pos = 0;
+    loop {
+        if pos == 56 || pos == 58 {
+            let Ok(count) = byte_order.read_u16(data, pos) else {
+                break;
+            };
+            values.push(count.to_string());
+            pos += 2;
+        } else {
+            let (Ok(num), Ok(den)) = (
+                byte_order.read_u32(data, pos),
+                byte_order.read_u32(data, pos + 4),
+            ) else {
+                break;
+            };
+            // ExifTool: lib/Image/ExifTool.pm GetRational64u
+            values.push(match (num, den) {
+                (0, 0) => "undef".to_string(),
+                (_, 0) => "inf".to_string(),
+                _ => round_float(num as f64 / den as f64, 10).to_string(),
+            });
+            pos += 8;
+        }
+    }
+    TagValue::String(values.join(" "))
+}
+
 /// Placeholder for missing RawConv implementations
 /// ExifTool: Various modules have RawConv functions we haven't implemented yet
 pub fn missing_raw_conv(_tag_id: u16, raw_conv_expr: &str, value: &TagValue) -> Result<TagValue> {
@@ -106,3 +144,31 @@ pub fn missing_raw_conv(_tag_id: u16, raw_conv_expr: &str, value: &TagValue) ->
     );
     Ok(value.clone())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_composite_image_exposure_times() {
+        let mut data = Vec::new();
+        // Seven rationals: total period, totals, and min/max exposures
+        for (num, den) in [(2, 1), (1, 2), (1, 4), (1, 8), (1, 8), (1, 250), (1, 250)] {
+            data.extend_from_slice(&u32::to_be_bytes(num));
+            data.extend_from_slice(&u32::to_be_bytes(den));
+        }
+        data.extend_from_slice(&1u16.to_be_bytes());
+        data.extend_from_slice(&2u16.to_be_bytes());
+        for (num, den) in [(1, 8), (1, 250)] {
+            data.extend_from_slice(&u32::to_be_bytes(num));
+            data.extend_from_slice(&u32::to_be_bytes(den));
+        }
+        // Trailing bytes too short for another rational are ignored
+        data.extend_from_slice(&[0, 0, 0]);
+
+        assert_eq!(
+            composite_image_exposure_times(&data, ByteOrder::BigEndian),
+            TagValue::string("2 0.5 0.25 0.125 0.125 0.004 0.004 1 2 0.125 0.004")
+        );
+    }
+}
@@ -3,7 +3,40 @@
 //! RICOH cameras use different MakerNote formats depending on the model.
 //! This module implements signature detection following ExifTool's logic.
 //!
-//! ExifTool Reference: lib/Image/ExifTool/MakerNotes.pm lines 873-924
+//! The standard "Ricoh" IFD is decoded from the generated Ricoh::Main
+//! table, including the ThetaSubdir that Theta 360° cameras write with
+//! their orientation sensor readings. The other layouts still get generic
+//! processing.
+//!
+//! ExifTool Reference: lib/Image/ExifTool/MakerNotes.pm lines 873-924,
+//! lib/Image/ExifTool/Ricoh.pm Main and ThetaSubdir
+
+#[cfg(feature = "other-makers")]
+use crate::exif::ExifReader;
+#[cfg(feature = "other-makers")]
+use crate::generated::Ricoh_pm::{main_tags, theta_subdir_tags};
+#[cfg(feature = "other-makers")]
+use crate::implementations::maker_table::{self, MakerTable};
+#[cfg(feature = "other-makers")]
+use crate::tiff_types::IfdEntry;
+#[cfg(feature = "other-makers")]
+use crate::types::{ExifError, Result};
+#[cfg(feature = "other-makers")]
+use tracing::debug;
+
+/// First synthetic tag ID for Ricoh maker note tags
+#[cfg(feature = "other-makers")]
+const RICOH_SYNTHETIC_BASE: u16 = 0xF300;
+
+/// ThetaSubdir, an IFD whose offset is relative to the TIFF header
+/// ExifTool: Ricoh.pm Main 0x4001 SubDirectory Start => '$val'
+#[cfg(feature = "other-makers")]
+const THETA_SUBDIR: u16 = 0x4001;
+
+/// Subdirectories of Ricoh::Main that aren't decoded here: PrintIM and
+/// RicohRR1Subdir, plus ThetaSubdir, which is read separately
+#[cfg(feature = "other-makers")]
+const RICOH_SKIPPED_TAGS: &[u16] = &[0x0e00, 0x2001, THETA_SUBDIR];
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RicohSignature {
@@ -104,6 +137,61 @@ pub fn detect_ricoh_signature(make: &str, maker_note_data: &[u8]) -> Option<Rico
     None
 }
 
+/// Process a standard "Ricoh" IFD maker note and its ThetaSubdir
+///
+/// `offset` locates the maker note (header included) within the reader's
+/// data. Value offsets are relative to the TIFF header, as ExifTool's
+/// MakerNoteRicoh sets no Base.
+/// ExifTool: MakerNotes.pm MakerNoteRicoh, Ricoh.pm Main and ThetaSubdir
+#[cfg(feature = "other-makers")]
+pub fn process_ricoh_makernotes(reader: &mut ExifReader, offset: usize) -> Result<()> {
+    debug!("Processing Ricoh MakerNotes at {:#x}", offset);
+    let header_order = reader
+        .get_header()
+        .map(|header| header.byte_order)
+        .ok_or_else(|| ExifError::ParseError("Ricoh MakerNotes without TIFF header".into()))?;
+    let unknown = reader.unknown_tags;
+    let data = reader.get_data();
+    let dir_start = offset + RicohSignature::StandardIfd.data_offset();
+    // ExifTool: MakerNoteRicoh ByteOrder => 'Unknown'
+    let byte_order = maker_table::guess_ifd_byte_order(data, dir_start, header_order);
+
+    let mut tags = MakerTable {
+        tags: &main_tags::RICOH_MAIN_TAGS,
+        value_conv: main_tags::apply_value_conv,
+        print_conv: main_tags::apply_print_conv,
+    }
+    .read_ifd(
+        data,
+        dir_start,
+        byte_order,
+        RICOH_SKIPPED_TAGS,
+        ("Ricoh", unknown),
+    )?;
+
+    let num_entries = byte_order.read_u16(data, dir_start)? as usize;
+    let theta_subdir = (0..num_entries)
+        .map_while(|index| IfdEntry::parse(data, dir_start + 2 + 12 * index, byte_order).ok())
+        .find(|entry| entry.tag_id == THETA_SUBDIR);
+    if let Some(entry) = theta_subdir {
+        let theta_start = entry.value_or_offset as usize;
+        debug!("Processing Ricoh ThetaSubdir at {:#x}", theta_start);
+        match (MakerTable {
+            tags: &theta_subdir_tags::RICOH_THETASUBDIR_TAGS,
+            value_conv: theta_subdir_tags::apply_value_conv,
+            print_conv: theta_subdir_tags::apply_print_conv,
+        })
+        .read_ifd(data, theta_start, byte_order, &[], ("Ricoh", unknown))
+        {
+            Ok(theta_tags) => tags.extend(theta_tags),
+            Err(e) => debug!("Skipping Ricoh ThetaSubdir: {}", e),
+        }
+    }
+
+    maker_table::store_tags(reader, RICOH_SYNTHETIC_BASE, "Ricoh", "Ricoh", tags);
+    Ok(())
+}
+
 /// Check if the Make field indicates a RICOH camera
 /// ExifTool: Various conditions check for RICOH make
 pub fn is_ricoh_makernote(make: &str) -> bool {
@@ -165,6 +253,55 @@ mod tests {
         assert_eq!(detect_ricoh_signature("Canon", ricoh_data), None);
     }
 
+    #[cfg(feature = "other-makers")]
+    #[test]
+    fn test_theta_subdir() {
+        use crate::tiff_types::{ByteOrder, TiffHeader};
+        use crate::types::TagValue;
+
+        // "Ricoh" maker note IFD at 8 with MakerNoteType and a ThetaSubdir
+        // pointer to 38, whose Compass rational is stored at 68
+        let entry = |tag: u16, format: u16, count: u32, value: [u8; 4]| {
+            [
+                &tag.to_le_bytes()[..],
+                &format.to_le_bytes(),
+                &count.to_le_bytes(),
+                &value,
+            ]
+            .concat()
+        };
+        let mut data = b"Ricoh\0\0\0".to_vec();
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend(entry(0x0001, 2, 4, *b"Rv1\0"));
+        data.extend(entry(THETA_SUBDIR, 4, 1, 38u32.to_le_bytes()));
+        data.extend_from_slice(&0u32.to_le_bytes());
+        assert_eq!(data.len(), 38);
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend(entry(0x0004, 5, 1, 68u32.to_le_bytes()));
+        data.extend(entry(0x000a, 2, 4, *b"+09\0"));
+        data.extend_from_slice(&0u32.to_le_bytes());
+        assert_eq!(data.len(), 68);
+        data.extend_from_slice(&1234u32.to_le_bytes());
+        data.extend_from_slice(&10u32.to_le_bytes());
+
+        let mut reader = ExifReader::new();
+        reader.set_test_data(data);
+        reader.set_test_header(TiffHeader {
+            byte_order: ByteOrder::LittleEndian,
+            magic: 42,
+            ifd0_offset: 8,
+        });
+        process_ricoh_makernotes(&mut reader, 0).unwrap();
+
+        let entries = reader.get_all_tag_entries();
+        let get = |name: &str| entries.iter().find(|e| e.name == name).unwrap();
+        assert_eq!(get("MakerNoteType").value, TagValue::string("Rv1"));
+        assert_eq!(get("Compass").group1, "Ricoh");
+        assert_eq!(get("Compass").value.as_f64(), Some(123.4));
+        assert_eq!(get("TimeZone").value, TagValue::string("+09"));
+        assert!(!entries.iter().any(|e| e.name == "ThetaSubdir"));
+    }
+
     #[test]
     fn test_signature_offsets() {
         assert_eq!(RicohSignature::StandardIfd.data_offset(), 8);
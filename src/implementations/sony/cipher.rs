@@ -0,0 +1,36 @@
+//! Sony's substitution cipher
+//!
+//! Tag 0x2010, 0x9050 and the 0x940x tags are enciphered by replacing every
+//! byte `b` below 249 with `b³ mod 249`; 249-255 are left alone.
+//!
+//! ExifTool: lib/Image/ExifTool/Sony.pm Decipher()
+
+use std::sync::LazyLock;
+
+/// Enciphered byte to plain byte
+static DECIPHER: LazyLock<[u8; 256]> = LazyLock::new(|| {
+    let mut table = [0u8; 256];
+    for b in 0..=255u32 {
+        let enciphered = if b < 249 { b * b * b % 249 } else { b };
+        table[enciphered as usize] = b as u8;
+    }
+    table
+});
+
+/// Decipher enciphered Sony data
+pub fn decipher(data: &[u8]) -> Vec<u8> {
+    data.iter().map(|&b| DECIPHER[b as usize]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decipher() {
+        // 2³ = 8 and 3³ = 27; bytes from 249 up are left alone
+        assert_eq!(decipher(&[8, 27, 0, 1, 250]), [2, 3, 0, 1, 250]);
+        // 50³ mod 249 = 2 and 177³ mod 249 = 3
+        assert_eq!(decipher(&[2, 3]), [0x32, 0xb1]);
+    }
+}
@@ -0,0 +1,221 @@
+//! Sony lens correction and E-mount lens data
+//!
+//! Tag 0x9405 records whether the camera corrected distortion, and the
+//! distortion, vignetting and chromatic aberration correction parameters it
+//! used, which raw developers need to know whether in-camera corrections
+//! apply. Tag 0x940c identifies the E-mount lens, adapter and their firmware.
+//! Both are enciphered (see [`super::cipher`]).
+//!
+//! ExifTool References:
+//! - lib/Image/ExifTool/Sony.pm Main 0x9405 and 0x940c
+//! - lib/Image/ExifTool/Sony.pm Tag9405a, Tag9405b and Tag940c tables
+
+use crate::exif::ExifReader;
+use crate::generated::Sony_pm::{tag9405a_tags, tag9405b_tags, tag940c_tags};
+use crate::implementations::maker_table::{self, BinaryDataState, MakerTable};
+use crate::tiff_types::ByteOrder;
+use crate::types::{Result, TagValue};
+use tracing::debug;
+
+/// Synthetic tag IDs for tags decoded from Tag9405
+const TAG9405_SYNTHETIC_BASE: u16 = 0xFB00;
+/// Synthetic tag IDs for tags decoded from Tag940c
+const TAG940C_SYNTHETIC_BASE: u16 = 0xFB40;
+
+/// Decode Tag9405 and Tag940c from the Sony maker note IFD
+///
+/// Tables with model-specific offsets (Tag9405b's VignettingCorrParams and
+/// ChromaticAberrationCorrParams) use their first variant, as conditions
+/// aren't evaluated.
+pub fn process_sony_lens_correction(reader: &mut ExifReader) -> Result<()> {
+    let byte_order = reader
+        .header
+        .as_ref()
+        .map_or(ByteOrder::LittleEndian, |header| header.byte_order);
+    let model = reader
+        .get_tag_across_namespaces(0x0110)
+        .and_then(|v| v.as_string())
+        .unwrap_or_default()
+        .to_string();
+
+    if let Some(data) = sony_bytes(reader, 0x9405) {
+        if let Some(table) = select_tag9405_table(&data) {
+            decode(reader, &table, &data, byte_order, TAG9405_SYNTHETIC_BASE);
+        } else {
+            debug!("Unknown Sony Tag9405 version {:#04x}", data[0]);
+        }
+    }
+
+    // ExifTool: Sony.pm Main 0x940c Condition, E-mount cameras only
+    if is_e_mount_model(&model) {
+        if let Some(data) = sony_bytes(reader, 0x940c) {
+            let table = MakerTable {
+                tags: &tag940c_tags::SONY_TAG940C_TAGS,
+                value_conv: tag940c_tags::apply_value_conv,
+                print_conv: tag940c_tags::apply_print_conv,
+            };
+            decode(reader, &table, &data, byte_order, TAG940C_SYNTHETIC_BASE);
+        }
+    }
+    Ok(())
+}
+
+/// The enciphered bytes of a Sony maker note tag
+fn sony_bytes(reader: &ExifReader, tag_id: u16) -> Option<Vec<u8>> {
+    match reader.extracted_tags.get(&(tag_id, "Sony".to_string()))? {
+        TagValue::Binary(bytes) | TagValue::U8Array(bytes) if !bytes.is_empty() => {
+            Some(bytes.clone())
+        }
+        _ => None,
+    }
+}
+
+/// The Tag9405 table for the block's first (enciphered) byte
+/// ExifTool: Sony.pm Main 0x9405 Conditions `$$valPt =~ /^[\x1b\x40\x7d]/` etc.
+fn select_tag9405_table(data: &[u8]) -> Option<MakerTable> {
+    let table = match data.first()? {
+        0x1b | 0x40 | 0x7d => MakerTable {
+            tags: &tag9405a_tags::SONY_TAG9405A_TAGS,
+            value_conv: tag9405a_tags::apply_value_conv,
+            print_conv: tag9405a_tags::apply_print_conv,
+        },
+        0x23 | 0x24 | 0x26 | 0x28 | 0x31 | 0x32 | 0x33 => MakerTable {
+            tags: &tag9405b_tags::SONY_TAG9405B_TAGS,
+            value_conv: tag9405b_tags::apply_value_conv,
+            print_conv: tag9405b_tags::apply_print_conv,
+        },
+        _ => return None,
+    };
+    Some(table)
+}
+
+/// NEX, ILCE, ILME and ZV-E models
+fn is_e_mount_model(model: &str) -> bool {
+    ["NEX-", "ILCE-", "ILME-", "Lunar", "ZV-E"]
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+}
+
+fn decode(
+    reader: &mut ExifReader,
+    table: &MakerTable,
+    data: &[u8],
+    byte_order: ByteOrder,
+    base_id: u16,
+) {
+    let data = super::decipher(data);
+    let tags =
+        table.read_binary_entries(&data, byte_order, "int8u", &mut BinaryDataState::default());
+    debug!("Decoded {} Sony lens tags at {:#x}", tags.len(), base_id);
+    maker_table::store_tags(reader, base_id, "Sony", "Sony", tags);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TagSourceInfo;
+
+    /// Sony's cipher, the inverse of [`super::super::decipher`]
+    fn encipher(data: &[u8]) -> Vec<u8> {
+        data.iter()
+            .map(|&b| {
+                let b = b as u32;
+                if b < 249 {
+                    (b * b * b % 249) as u8
+                } else {
+                    b as u8
+                }
+            })
+            .collect()
+    }
+
+    fn reader_with(model: &str, tags: &[(u16, Vec<u8>)]) -> ExifReader {
+        let mut reader = ExifReader::new();
+        reader.store_tag_with_precedence(
+            0x0110,
+            TagValue::string(model),
+            TagSourceInfo::new("EXIF".into(), "IFD0".into(), "Exif".into()),
+        );
+        for (tag_id, data) in tags {
+            reader.store_tag_with_precedence(
+                *tag_id,
+                TagValue::Binary(data.clone()),
+                TagSourceInfo::new("Sony".into(), "Sony".into(), "Sony".into()),
+            );
+        }
+        reader
+    }
+
+    fn decoded(reader: &mut ExifReader, name: &str) -> Option<TagValue> {
+        reader
+            .get_all_tag_entries()
+            .into_iter()
+            .find(|entry| entry.group == "MakerNotes" && entry.name == name)
+            .map(|entry| entry.print().clone())
+    }
+
+    #[test]
+    fn test_tag9405b_lens_correction() {
+        let mut plain = vec![0u8; 0x400];
+        // SonyFNumber and SonyMaxApertureValue: F4 and F2.8
+        plain[20..24].copy_from_slice(&[0x00, 0x18, 0x00, 0x16]);
+        // DistortionCorrParamsPresent and DistortionCorrection
+        plain[90] = 1;
+        plain[91] = 1;
+        // DistortionCorrParams, 16 int16s
+        plain[100] = 5;
+        plain[102] = 0xfe;
+        plain[103] = 0xff;
+        let mut data = encipher(&plain);
+        // The version byte selects the table before deciphering
+        data[0] = 0x24;
+
+        let mut reader = reader_with("ILCE-7M3", &[(0x9405, data)]);
+        process_sony_lens_correction(&mut reader).unwrap();
+
+        assert_eq!(
+            decoded(&mut reader, "DistortionCorrParamsPresent"),
+            Some(TagValue::string("Yes"))
+        );
+        assert_eq!(
+            decoded(&mut reader, "DistortionCorrection"),
+            Some(TagValue::string("Applied"))
+        );
+        let Some(TagValue::Array(params)) = decoded(&mut reader, "DistortionCorrParams") else {
+            panic!("DistortionCorrParams missing");
+        };
+        assert_eq!(params.len(), 16);
+        assert_eq!(
+            params[..3],
+            [TagValue::I16(5), TagValue::I16(-2), TagValue::I16(0)]
+        );
+        assert!(decoded(&mut reader, "VignettingCorrParams").is_some());
+        assert!(decoded(&mut reader, "ChromaticAberrationCorrParams").is_some());
+    }
+
+    #[test]
+    fn test_tag940c_e_mount_only() {
+        let mut plain = vec![0u8; 0x20];
+        // LensMount2: E-mount
+        plain[8] = 4;
+        let data = encipher(&plain);
+
+        let mut reader = reader_with("ILCE-7M3", &[(0x940c, data.clone())]);
+        process_sony_lens_correction(&mut reader).unwrap();
+        assert_eq!(
+            decoded(&mut reader, "LensMount2"),
+            Some(TagValue::string("E-mount"))
+        );
+
+        let mut reader = reader_with("SLT-A99V", &[(0x940c, data)]);
+        process_sony_lens_correction(&mut reader).unwrap();
+        assert!(reader.synthetic_tag_names.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_tag9405_version() {
+        let mut reader = reader_with("ILCE-7M3", &[(0x9405, vec![0x99; 0x100])]);
+        process_sony_lens_correction(&mut reader).unwrap();
+        assert!(reader.synthetic_tag_names.is_empty());
+    }
+}
@@ -3,10 +3,14 @@
 //! This module provides Sony-specific EXIF processing implementations,
 //! following ExifTool's Sony.pm logic exactly.
 
+pub mod cipher;
+pub mod lens_correction;
 pub mod makernote_detection;
 pub mod tags;
 
 // Re-export key functions for use by other modules
+pub use cipher::decipher;
+pub use lens_correction::process_sony_lens_correction;
 pub use makernote_detection::{detect_sony_signature, is_sony_makernote, SonySignature};
 pub use tags::{get_sony_namespace, get_sony_tag_name, is_sony_tag};
 
@@ -52,6 +56,9 @@ pub fn process_sony_subdirectory_tags(_exif_reader: &mut ExifReader) -> Result<(
     //     find_sony_tag_id_by_name,
     // )?;
 
+    // Enciphered lens correction and E-mount lens blocks
+    process_sony_lens_correction(_exif_reader)?;
+
     debug!("Sony subdirectory processing completed");
     Ok(())
 }
@@ -54,7 +54,7 @@ pub fn get_sony_tag_name(tag_id: u16) -> Option<String> {
         // ExifTool: Sony.pm lines 2045-2073
         0xb000 => Some("FileFormat".to_string()),
         0xb001 => Some("SonyModelID".to_string()),
-        0xb020 => Some("ColorReproduction".to_string()),
+        0xb020 => Some("CreativeStyle".to_string()),
         0xb021 => Some("ColorTemperature".to_string()),
         0xb022 => Some("ColorCompensationFilter".to_string()),
         0xb023 => Some("SceneMode".to_string()),
@@ -281,6 +281,49 @@ pub fn canon_directory_number_value_conv(
     }
 }
 
+/// Convert Canon hex-based EV (modulo 0x20) to real number
+/// ExifTool: lib/Image/ExifTool/Canon.pm line 10478 sub CanonEv
+///
+/// Examples:
+/// - 0x00 -> 0
+/// - 0x0c -> 0.33333 (1/3)
+/// - 0x10 -> 0.5
+/// - 0x14 -> 0.66666 (2/3)
+/// - 0x20 -> 1
+pub fn canon_ev(val: TagValue, _ctx: Option<&ExifContext>) -> TagValue {
+    let val_i64 = match &val {
+        TagValue::I16(v) => *v as i64,
+        TagValue::I32(v) => *v as i64,
+        TagValue::U8(v) => *v as i64,
+        TagValue::U16(v) => *v as i64,
+        TagValue::U32(v) => *v as i64,
+        TagValue::U64(v) => *v as i64,
+        TagValue::F64(v) => *v as i64,
+        _ => return val.clone(),
+    };
+
+    // Temporarily make the number positive
+    let sign = if val_i64 < 0 { -1.0 } else { 1.0 };
+    let abs_val = val_i64.abs();
+
+    // Extract fractional part (bottom 5 bits = modulo 0x20)
+    let frac_code = abs_val & 0x1f;
+    let int_part = abs_val - frac_code;
+
+    // Convert 1/3 and 2/3 codes
+    // ExifTool: Canon.pm line 10492-10496
+    let frac = if frac_code == 0x0c {
+        0x20 as f64 / 3.0 // 1/3 EV
+    } else if frac_code == 0x14 {
+        0x40 as f64 / 3.0 // 2/3 EV
+    } else {
+        frac_code as f64
+    };
+
+    let result = sign * (int_part as f64 + frac) / 0x20 as f64;
+    TagValue::F64(result)
+}
+
 /// White balance ValueConv (placeholder)
 ///
 /// ExifTool: lib/Image/ExifTool/Exif.pm WhiteBalance
@@ -456,6 +499,7 @@ pub fn exif_time_value_conv(value: &TagValue, _ctx: Option<&ExifContext>) -> Res
 /// Delegates to the exact ConvertXMPDate port (XMP.pm:3383-3394) so any
 /// codegen-wired ValueConv using this expression converts for real. Was a
 /// pass-through stub before the XMP value-conversion work landed.
+#[cfg(feature = "xmp")]
 pub fn xmp_date_value_conv(value: &TagValue, _ctx: Option<&ExifContext>) -> Result<TagValue> {
     match value {
         TagValue::String(s) => Ok(crate::xmp::value_conversion::convert_xmp_date(s)),
@@ -701,6 +745,7 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "xmp")]
     #[test]
     fn test_xmp_date_value_conv_delegates_to_convert_xmp_date() {
         // Was a pass-through stub; must now apply ConvertXMPDate (XMP.pm:3383)
@@ -19,7 +19,22 @@
 //! Integration tests compare our output against ExifTool reference data and require
 //! test images and the ExifTool submodule to be available. They are automatically
 //! excluded from published crates to keep package size manageable.
+//!
+//! ## API stability
+//!
+//! The supported API is [`prelude`]. Generated tables change with every
+//! ExifTool sync, so `generated` is hidden, not re-exported, and isn't covered
+//! by semver.
+//!
+//! ## `no_std` core
+//!
+//! [`TagValue`], [`ExifError`], TIFF header/IFD entry parsing and value
+//! extraction live in the `exif-oxide-core` crate, which builds for
+//! `no_std` + `alloc` targets with `default-features = false`. This crate
+//! re-exports them and adds everything that needs `std`.
 
+pub mod burst;
+pub mod cache;
 pub mod compat;
 pub mod composite_tags;
 pub mod core;
@@ -29,29 +44,54 @@ pub mod file_detection;
 pub mod file_types;
 pub mod fmt;
 pub mod formats;
+// Regenerated from ExifTool on every sync: internal, not covered by semver
+#[doc(hidden)]
 pub mod generated;
+pub mod geolocation;
 pub mod hash;
 
 pub mod implementations;
+pub mod live_photo;
+pub mod metrics;
+pub mod organize;
+pub mod orientation;
+pub mod output;
+pub mod prelude;
 pub mod processor_registry;
+#[cfg(feature = "raw-formats")]
 pub mod raw;
 pub mod registry;
 pub mod runtime;
-pub mod tiff_types;
+pub mod schema;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod session;
+pub mod stats;
+pub mod summary;
+pub mod tables;
 pub mod tiff_utils;
 pub mod types;
+pub mod user_config;
 pub mod utils;
-pub mod value_extraction;
+pub mod validation;
+pub mod write;
+#[cfg(feature = "xmp")]
 pub mod xmp;
 
-pub use file_detection::{FileDetectionError, FileTypeDetectionResult, FileTypeDetector};
-pub use generated::*;
+// TIFF structures and value extraction live in the no_std exif-oxide-core
+// crate; kept under their original module names
+pub use exif_oxide_core::{tiff as tiff_types, value_extraction};
+
+pub use burst::group_bursts;
+pub use file_detection::{
+    detect_file_type_from_bytes, FileDetectionError, FileTypeDetectionResult, FileTypeDetector,
+};
 pub use hash::{ImageDataHasher, ImageHashType};
+pub use live_photo::is_live_photo_pair;
 pub use registry::Registry;
 pub use types::{ExifData, ExifError, FilterOptions, TagValue};
 
-// TODO P07: COMPOSITE_TAG_LOOKUP doesn't exist yet, using lookup_composite_tag function instead
-// pub use generated::COMPOSITE_TAG_LOOKUP as COMPOSITE_TAG_BY_NAME;
+pub use composite_tags::composite_tag_by_name;
 
 // Initialize all conversion implementations when library is loaded
 use std::sync::LazyLock;
@@ -81,6 +121,7 @@ pub fn extract_metadata_json(file_path: &str) -> Result<Value, ExifError> {
 
     // Prepare for serialization (converts TagEntry to legacy format with PrintConv)
     exif_data.prepare_for_serialization(None);
+    exif_data.format_binary_values(&FilterOptions::default());
 
     // Convert ExifData to JSON
     let json = serde_json::to_value(&exif_data)
@@ -164,6 +205,9 @@ pub fn extract_metadata_json_with_filter(
     });
 
     exif_data.prepare_for_serialization(numeric_tags_ref);
+    let options = filter_options.unwrap_or_default();
+    exif_data.format_binary_values(&options);
+    exif_data.apply_tag_order(options.tag_order);
 
     // Convert ExifData to JSON
     let json = serde_json::to_value(&exif_data)
@@ -172,6 +216,60 @@ pub fn extract_metadata_json_with_filter(
     Ok(json)
 }
 
+/// Extract metadata from a file with tag filtering and serialize it as `format`
+///
+/// Produces the same document as the CLI with `--format` for a single file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use exif_oxide::extract_metadata_formatted;
+/// use exif_oxide::output::OutputFormat;
+///
+/// // Like exiftool -X image.jpg
+/// let rdf_xml = extract_metadata_formatted("image.jpg", None, OutputFormat::Xml)?;
+/// # Ok::<(), exif_oxide::ExifError>(())
+/// ```
+pub fn extract_metadata_formatted(
+    file_path: &str,
+    filter_options: Option<FilterOptions>,
+    format: output::OutputFormat,
+) -> Result<String, ExifError> {
+    init();
+
+    let path = Path::new(file_path);
+    let mut exif_data = formats::extract_metadata(path, false, false, filter_options.clone())?;
+
+    let numeric_tags_ref = filter_options
+        .as_ref()
+        .map(|f| &f.numeric_tags)
+        .filter(|tags| !tags.is_empty());
+    exif_data.prepare_for_serialization(numeric_tags_ref);
+    let options = filter_options.unwrap_or_default();
+    exif_data.format_binary_values(&options);
+    exif_data.apply_tag_order(options.tag_order);
+
+    Ok(format.serializer().serialize_to_string(&[exif_data]))
+}
+
+/// Extract the [`summary::FileSummary`] profile of a file
+///
+/// # Examples
+///
+/// ```no_run
+/// let summary = exif_oxide::extract_summary("image.jpg")?;
+/// println!("{:?} taken {:?}", summary.model, summary.capture_date);
+/// # Ok::<(), exif_oxide::ExifError>(())
+/// ```
+pub fn extract_summary(file_path: &str) -> Result<summary::FileSummary, ExifError> {
+    init();
+
+    let filter = summary::FileSummary::filter_options();
+    let exif_data = formats::extract_metadata(Path::new(file_path), false, false, Some(filter))?;
+
+    Ok(summary::FileSummary::from_exif_data(&exif_data))
+}
+
 /// Extract metadata from a file with tag filtering and return structured data
 ///
 /// This function provides direct access to the TagEntry structure without JSON conversion,
@@ -0,0 +1,149 @@
+//! Live Photo pairing helpers
+//!
+//! An iPhone Live Photo is stored as two files: a still image (JPEG/HEIC) and a
+//! short QuickTime video. Apple links them with a shared UUID, surfaced by
+//! exif-oxide as `Composite:ContentIdentifier` (built in
+//! `composite_tags/live_photo.rs`). The still carries it in its Apple maker
+//! notes, the video in its QuickTime Keys.
+
+use std::path::Path;
+
+use crate::types::{ExifData, ExifError, FilterOptions};
+
+/// Which half of a Live Photo a file is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LivePhotoComponent {
+    /// Still image with an `Apple:ContentIdentifier` maker note tag
+    Still,
+    /// QuickTime video with a `Keys:ContentIdentifier` tag
+    Video,
+}
+
+/// Return the Live Photo pairing identifier (`Composite:ContentIdentifier`)
+pub fn content_identifier(exif_data: &ExifData) -> Option<&str> {
+    exif_data
+        .get_tag_by_group("Composite", "ContentIdentifier")
+        .and_then(|tag| tag.value.as_string())
+}
+
+/// Determine whether extracted metadata belongs to a Live Photo still or video
+pub fn live_photo_component(exif_data: &ExifData) -> Option<LivePhotoComponent> {
+    if exif_data
+        .get_tag_by_group("Keys", "ContentIdentifier")
+        .is_some()
+    {
+        Some(LivePhotoComponent::Video)
+    } else if exif_data
+        .get_tag_by_group("Apple", "ContentIdentifier")
+        .is_some()
+    {
+        Some(LivePhotoComponent::Still)
+    } else {
+        None
+    }
+}
+
+/// Check whether two extracted files form a Live Photo pair
+///
+/// They do when one is the still and the other the video, and both carry the
+/// same ContentIdentifier.
+pub fn is_live_photo_pair_data(first: &ExifData, second: &ExifData) -> bool {
+    let (Some(first_id), Some(second_id)) = (content_identifier(first), content_identifier(second))
+    else {
+        return false;
+    };
+    let components = (live_photo_component(first), live_photo_component(second));
+    first_id == second_id
+        && matches!(
+            components,
+            (
+                Some(LivePhotoComponent::Still),
+                Some(LivePhotoComponent::Video)
+            ) | (
+                Some(LivePhotoComponent::Video),
+                Some(LivePhotoComponent::Still)
+            )
+        )
+}
+
+/// Check whether two files form a Live Photo pair
+///
+/// Extracts only the ContentIdentifier tags from each file and compares them
+/// with [`is_live_photo_pair_data`]. The order of the arguments does not matter.
+///
+/// # Example
+/// ```no_run
+/// use std::path::Path;
+///
+/// let paired = exif_oxide::is_live_photo_pair(
+///     Path::new("IMG_3755.JPG"),
+///     Path::new("IMG_3755.MOV"),
+/// )?;
+/// # Ok::<(), exif_oxide::ExifError>(())
+/// ```
+pub fn is_live_photo_pair(first: &Path, second: &Path) -> Result<bool, ExifError> {
+    let extract = |path: &Path| {
+        let filter = FilterOptions::tags_only(vec!["ContentIdentifier".to_string()]);
+        crate::extract_metadata_with_filter(path, Some(filter))
+    };
+    Ok(is_live_photo_pair_data(&extract(first)?, &extract(second)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{TagEntry, TagValue};
+
+    const UUID: &str = "8D3C7C70-4E37-4B3D-A3A0-8AB51C0C8F43";
+
+    fn entry(group: &str, group1: &str, value: &str) -> TagEntry {
+        TagEntry {
+            group: group.into(),
+            group1: group1.into(),
+            name: "ContentIdentifier".into(),
+            value: TagValue::string(value),
+            print: TagValue::string(value).into(),
+            source: None,
+        }
+    }
+
+    fn file(source_group: (&str, &str), value: &str) -> ExifData {
+        let mut data = ExifData::new("test".to_string(), String::new());
+        data.tags = vec![
+            entry(source_group.0, source_group.1, value),
+            entry("Composite", "Composite", value),
+        ];
+        data
+    }
+
+    #[test]
+    fn test_still_and_video_pair() {
+        let still = file(("MakerNotes", "Apple"), UUID);
+        let video = file(("QuickTime", "Keys"), UUID);
+        assert_eq!(content_identifier(&still), Some(UUID));
+        assert_eq!(
+            live_photo_component(&still),
+            Some(LivePhotoComponent::Still)
+        );
+        assert_eq!(
+            live_photo_component(&video),
+            Some(LivePhotoComponent::Video)
+        );
+        assert!(is_live_photo_pair_data(&still, &video));
+        assert!(is_live_photo_pair_data(&video, &still));
+    }
+
+    #[test]
+    fn test_mismatched_or_same_kind_is_not_pair() {
+        let still = file(("MakerNotes", "Apple"), UUID);
+        let other_video = file(
+            ("QuickTime", "Keys"),
+            "00000000-0000-0000-0000-000000000000",
+        );
+        assert!(!is_live_photo_pair_data(&still, &other_video));
+        assert!(!is_live_photo_pair_data(&still, &still));
+
+        let plain = ExifData::new("plain".to_string(), String::new());
+        assert!(!is_live_photo_pair_data(&still, &plain));
+    }
+}
@@ -4,19 +4,72 @@ use std::path::PathBuf;
 use tracing::{debug, error, info};
 
 // Import our library modules
-use exif_oxide::formats::extract_metadata;
+use exif_oxide::cache::{CacheKeyMode, MetadataCache};
+use exif_oxide::formats::{
+    binary_tag_value, embedded_image_location, embedded_image_source_tags, extract_metadata,
+};
 use exif_oxide::hash::ImageHashType;
-use exif_oxide::types::FilterOptions;
+use exif_oxide::output::{JsonSerializer, OutputFormat, OutputSerializer, TextOut, TextOutMode};
+use exif_oxide::schema::json_schema;
+use exif_oxide::summary::{FileSummary, SummarySerializer};
+use exif_oxide::types::{
+    expand_tag_name, ApiOptions, ExifCharset, ExifError, FilterOptions, GroupFamily, QuickTimeUtc,
+    ScanDepth, SymlinkPolicy, TagOrder, UnknownTags,
+};
+use exif_oxide::user_config::{set_config, UserConfig};
+use exif_oxide::write::copy::copy_tags_from;
+use exif_oxide::write::WriteOptions;
+
+/// Exit status when any file couldn't be read (ExifTool exits 1 too)
+const EXIT_FILE_ERROR: i32 = 1;
+
+/// Exit status for a bad command line: unknown options, missing files, ...
+const EXIT_USAGE: i32 = 2;
+
+/// The mode of an ExifTool `-w` option (`-w`, `-w!`, `-w+`, or `-textOut`
+/// with the same suffixes), or `None` for other arguments
+fn parse_text_out_mode(arg: &str) -> Option<TextOutMode> {
+    let name = arg.strip_prefix('-')?;
+    let (name, mode) = match name.strip_suffix('!') {
+        Some(name) => (name, TextOutMode::Overwrite),
+        None => match name.strip_suffix('+') {
+            Some(name) => (name, TextOutMode::Append),
+            None => (name, TextOutMode::Create),
+        },
+    };
+    (name == "w" || name.eq_ignore_ascii_case("textOut")).then_some(mode)
+}
+
+/// Parse an image hash algorithm name, failing with [`EXIT_USAGE`] on an unknown one
+fn parse_image_hash_type(name: &str) -> Result<ImageHashType, i32> {
+    ImageHashType::parse_known(name).ok_or_else(|| {
+        eprintln!("Error: Invalid hash type '{name}'. Use MD5, SHA1, SHA256, SHA512 or XXH3.");
+        EXIT_USAGE
+    })
+}
+
+/// Parse a QuickTimeUTC mode: `auto`, or an ExifTool boolean (`1`, `on`, `0`, ...)
+fn parse_quicktime_utc(mode: &str) -> QuickTimeUtc {
+    match mode.to_lowercase().as_str() {
+        "auto" => QuickTimeUtc::Auto,
+        "" | "0" | "off" | "false" | "no" => QuickTimeUtc::Off,
+        _ => QuickTimeUtc::On,
+    }
+}
 
 /// Parse command line arguments into file paths and filter options
 /// Supports ExifTool-style tag filtering patterns:
 /// - `-TagName` - extract specific tag
 /// - `-TagName#` - extract tag with numeric value (ValueConv)  
+/// - `-n`, `-all#` - numeric values for all tags
+/// - `-fast`, `-fast2` - stop scanning early (see [`ScanDepth`])
+/// - `-e` - don't build composite tags
 /// - `-GroupName:all` - extract all tags from group
 /// - `-all` - extract all tags
 ///
-/// Returns (file_paths, filter_options) or exits on error
-fn parse_exiftool_args(args: Vec<&String>) -> (Vec<&String>, FilterOptions) {
+/// Returns (file_paths, filter_options), or the exit status for an unknown
+/// option or `-ver`
+fn parse_exiftool_args(args: Vec<&String>) -> Result<(Vec<&String>, FilterOptions), i32> {
     let mut file_paths = Vec::new();
     let mut requested_tags = Vec::new();
     let requested_groups = Vec::new();
@@ -24,6 +77,13 @@ fn parse_exiftool_args(args: Vec<&String>) -> (Vec<&String>, FilterOptions) {
     let mut glob_patterns = Vec::new();
     let mut numeric_tags = HashSet::new();
     let mut extract_all = false;
+    let mut numeric = false;
+    let mut tag_order = TagOrder::default();
+    let mut group_family = GroupFamily::default();
+    let mut unknown_tags = UnknownTags::default();
+    let mut allow_duplicates = false;
+    let mut scan_depth = ScanDepth::default();
+    let mut composites = true;
 
     // Debug: print all received arguments
     debug!("CLI args received: {:?}", args);
@@ -32,10 +92,62 @@ fn parse_exiftool_args(args: Vec<&String>) -> (Vec<&String>, FilterOptions) {
         if arg == "-all" || arg == "--all" {
             // Special case: extract all tags
             extract_all = true;
+        } else if arg == "-n" || arg == "-all#" {
+            // Numeric output for every tag (ValueConv instead of PrintConv)
+            // ExifTool: -n (--printConv) disables print conversion; -all# is -TAG# for all tags
+            if arg == "-all#" {
+                extract_all = true;
+            }
+            numeric = true;
+        } else if arg == "-sort" || arg == "-sort0" {
+            // Sorted output instead of ExifTool's usual group order
+            // ExifTool: -sort sorts by tag name (with -s), -sort0 by family 0 group first
+            tag_order = if arg == "-sort0" {
+                TagOrder::Group
+            } else {
+                TagOrder::Name
+            };
+        } else if arg == "-G0" || arg == "-G1" {
+            // Group family of the output keys
+            // ExifTool: -G1 prefixes tags with their family 1 group (IFD0, ExifIFD, GPS, ...)
+            group_family = if arg == "-G1" {
+                GroupFamily::Family1
+            } else {
+                GroupFamily::Family0
+            };
+        } else if arg == "-a" {
+            // Keep duplicate tags, serialized as "Tag (1)", "Tag (2)", ...
+            // ExifTool: -a (-duplicates)
+            allow_duplicates = true;
+        } else if arg == "-e" {
+            // Skip composite tags
+            // ExifTool: -e (--composite)
+            composites = false;
+        } else if arg == "-u" || arg == "-U" {
+            // Unknown tags, named like Exif_0x9999
+            // ExifTool: -u extracts unknown tags, -U unknown binary data too
+            unknown_tags = if arg == "-U" {
+                UnknownTags::IncludeBinary
+            } else {
+                UnknownTags::Include
+            };
+        } else if let Some(level) = arg.strip_prefix("-fast") {
+            // Partial scanning: -fast is -fast1
+            // ExifTool: -fast[NUM] sets the FastScan option
+            match level {
+                "" => scan_depth = ScanDepth::Fast1,
+                level => match level.parse() {
+                    Ok(level) => scan_depth = ScanDepth::from_level(level),
+                    Err(_) => {
+                        eprintln!("Unknown option {}", arg);
+                        return Err(EXIT_USAGE);
+                    }
+                },
+            }
         } else if arg == "-ver" {
             // Version flag - print version and exit
             println!("{}", env!("CARGO_PKG_VERSION"));
-            std::process::exit(0);
+            return Err(0);
         } else if arg == "-j" || arg == "-struct" || arg == "-G" {
             // ExifTool compatibility flags - ignore these (no-op)
             // -j: JSON output format (we always output JSON)
@@ -50,7 +162,7 @@ fn parse_exiftool_args(args: Vec<&String>) -> (Vec<&String>, FilterOptions) {
             // Check for invalid short options (1-2 characters or unknown single-char flags)
             if filter_arg.len() <= 2 {
                 eprintln!("Unknown option {}", arg);
-                std::process::exit(1);
+                return Err(EXIT_USAGE);
             }
 
             if filter_arg.ends_with('#') && filter_arg.len() > 1 {
@@ -63,7 +175,7 @@ fn parse_exiftool_args(args: Vec<&String>) -> (Vec<&String>, FilterOptions) {
                 } else {
                     // Regular numeric tag: -TagName#
                     requested_tags.push(tag_name.to_string());
-                    numeric_tags.insert(tag_name.to_string());
+                    numeric_tags.extend(expand_tag_name(tag_name));
                 }
             } else if filter_arg.ends_with(":all") {
                 // Group all pattern: -GroupName:all
@@ -100,6 +212,24 @@ fn parse_exiftool_args(args: Vec<&String>) -> (Vec<&String>, FilterOptions) {
             glob_patterns: Vec::new(),
             compute_image_hash: false,
             image_hash_type: ImageHashType::default(),
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(FilterOptions::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric,
+            tag_order,
+            group_family,
+            unknown_tags,
+            allow_duplicates,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth,
+            symlinks: SymlinkPolicy::default(),
+            composites,
         }
     } else if requested_tags.is_empty()
         && requested_groups.is_empty()
@@ -116,6 +246,24 @@ fn parse_exiftool_args(args: Vec<&String>) -> (Vec<&String>, FilterOptions) {
             glob_patterns: Vec::new(),
             compute_image_hash: false,
             image_hash_type: ImageHashType::default(),
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(FilterOptions::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric,
+            tag_order,
+            group_family,
+            unknown_tags,
+            allow_duplicates,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth,
+            symlinks: SymlinkPolicy::default(),
+            composites,
         }
     } else {
         // Specific filters requested
@@ -128,13 +276,31 @@ fn parse_exiftool_args(args: Vec<&String>) -> (Vec<&String>, FilterOptions) {
             glob_patterns,
             compute_image_hash: false,
             image_hash_type: ImageHashType::default(),
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(FilterOptions::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric,
+            tag_order,
+            group_family,
+            unknown_tags,
+            allow_duplicates,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth,
+            symlinks: SymlinkPolicy::default(),
+            composites,
         }
     };
 
     // Debug: print final filter options
     debug!("Final FilterOptions: {:?}", filter_options);
 
-    (file_paths, filter_options)
+    Ok((file_paths, filter_options))
 }
 
 /// Main CLI application for exif-oxide
@@ -154,6 +320,23 @@ fn main() {
 
     info!("Starting exif-oxide");
 
+    let args: Vec<String> = std::env::args().collect();
+    let result = if let Some(stay_open) = StayOpen::from_args(&args) {
+        stay_open.run()
+    } else if let Some(copy) = TagsFromFile::from_args(&args) {
+        copy.run()
+    } else {
+        run(args)
+    };
+    if let Err(status) = result {
+        std::process::exit(status);
+    }
+}
+
+/// Run one command: `args` is a full command line, program name first
+///
+/// Returns the exit status as the error, once any message has been printed.
+fn run(args: Vec<String>) -> Result<(), i32> {
     // Build CLI interface using clap
     // Clap is Rust's most popular CLI argument parsing library
     let matches = Command::new("exif-oxide")
@@ -174,24 +357,90 @@ fn main() {
             "TAG FILTERING:\n",
             "  -TagName         Extract specific tag (case-insensitive)\n",
             "  -TagName#        Extract tag with numeric value (ValueConv)\n",
+            "  -n, -all#        Numeric values for all tags (skips PrintConv)\n",
             "  -Group:all       Extract all tags from group (File, EXIF, GPS, etc.)\n",
             "  -Pattern*        Prefix wildcard (e.g., -GPS*, -Canon*)\n",
             "  -*Pattern        Suffix wildcard (e.g., -*tude for latitude/longitude)\n",
             "  -*Pattern*       Middle wildcard (e.g., -*Date* for date-related tags)\n",
             "  -all             Extract all available tags\n",
+            "  -a               Include duplicate tags as \"Tag (1)\", \"Tag (2)\", ...\n",
+            "  -u               Include unknown tags (Exif_0x9999, Casio_0x0021, ...)\n",
+            "  -U               Include unknown tags, including binary data\n",
+            "  -e               Don't generate composite tags\n",
+            "\n",
+            "SCANNING:\n",
+            "  -fast            Don't scan past the image data for trailers\n",
+            "  -fast2           Also stop at QuickTime mdat and skip embedded RAW previews\n",
+            "  --symlinks report\n",
+            "                   Report symbolic links themselves (FileType LINK) instead of\n",
+            "                   reading the files they point to\n",
+            "\n",
+            "OUTPUT ORDER:\n",
+            "  -sort            Sort tags alphabetically by name\n",
+            "  -sort0           Sort tags by group, then by name\n",
+            "  -G1              Prefix tags with their family 1 group (IFD0, ExifIFD, GPS, ...)\n",
+            "\n",
+            "OUTPUT FORMAT:\n",
+            "  --format FMT     json (default), xml (ExifTool -X RDF/XML) or yaml\n",
+            "  -X, -xmlFormat   Same as --format xml\n",
+            "\n",
+            "  -w EXT, -w FMT   Write each file's output to its own file instead of stdout:\n",
+            "                   EXT names %d%f.EXT; FMT may use %d (directory), %f (name),\n",
+            "                   %e (extension) and %c (copy number). -w! overwrites, -w+ appends\n",
+            "                   Example: exif-oxide -w .json photos/*.jpg\n",
+            "\n",
+            "  --summary        Output only the summary profile (date, size, rotation, camera, ...)\n",
+            "\n",
+            "  --warning-output stderr\n",
+            "                   Write warnings to stderr as JSON lines instead of ExifTool:Warning\n",
+            "\n",
+            "JSON SCHEMA:\n",
+            "  --schema         Print the JSON Schema of the output and exit (add -n for -n output)\n",
+            "  --typed          Output JSON that conforms to the schema (string tags stay strings)\n",
             "\n",
             "BINARY EXTRACTION:\n",
             "  -b, --binary     Extract binary data (use with tag filters, outputs to stdout)\n",
             "                   Example: exif-oxide -b -ThumbnailImage image.jpg > thumb.jpg\n",
+            "                   Example: exif-oxide -b -ICC_Profile image.jpg > profile.icc\n",
             "\n",
             "IMAGE DATA HASH:\n",
             "  --image-hash           Compute hash of image data (excludes metadata)\n",
-            "  --image-hash-type ALG  Hash algorithm: MD5 (default), SHA256, SHA512\n",
+            "  --image-hash-type ALG  Hash algorithm: MD5 (default), SHA1, SHA256, SHA512, XXH3\n",
+            "  --image-hash-chunks MB Also hash every MB megabytes of image data separately\n",
+            "  --parallel-hash MB     Hash videos of MB megabytes or more on a separate thread\n",
+            "                         (default 256, 0 disables)\n",
             "                         Example: exif-oxide --image-hash --image-hash-type SHA256 image.jpg\n",
+            "  -api OPT=VAL           ExifTool API options: requestall=3, requesttags=imagedatahash,\n",
+            "                         imagehashtype=ALG, geolocation, quicktimeutc[=auto], duplicates,\n",
+            "                         charsetexif=UTF8|Latin, largefilesupport\n",
+            "                         Example: exif-oxide -api requesttags=imagedatahash -api imagehashtype=XXH3 video.mp4\n",
+            "\n",
+            "USER-DEFINED TAGS:\n",
+            "  -config FILE     Load XMP namespaces, composites and renames from a JSON config\n",
+            "                   (also --config FILE; see the user_config module docs for the format)\n",
+            "\n",
+            "VIDEO DATES:\n",
+            "  --quicktime-utc[=auto]  Treat QuickTime dates as UTC and convert them to local time;\n",
+            "                          auto decides per file from GPS time and the file date\n",
+            "\n",
+            "EXIT STATUS:\n",
+            "  0 if every file was read, 1 if any file had an error, 2 for a bad command line\n",
+            "  --warnings-as-errors  Also exit with 1 if any file has warnings\n",
+            "\n",
+            "COPYING TAGS:\n",
+            "  -tagsFromFile SRCFILE [-TAG...] [-DSTTAG<SRCTAG...] [--TAG...] FILE...\n",
+            "                   Copy tags from SRCFILE to each FILE (IPTC in JPEG files so far),\n",
+            "                   keeping FILE_original unless -overwrite_original; -P keeps mtimes\n",
+            "\n",
+            "BATCH MODE:\n",
+            "  -stay_open True -@ ARGFILE [-common_args ARGS...]\n",
+            "                   Read commands from ARGFILE (- for stdin), one argument per line,\n",
+            "                   each ended by -execute[NUM]. Output is followed by {ready[NUM]};\n",
+            "                   -stay_open False (or the end of stdin) exits\n",
             "\n",
             "EXIFTOOL COMPATIBILITY:\n",
             "  -ver             Print version number and exit\n",
-            "  -j, -struct, -G  Ignored (we always output JSON with structure and groups)\n",
+            "  -j, -struct, -G  Ignored (JSON is the default; we always include structure and groups)\n",
             "\n",
             "Multiple filters can be combined:\n",
             "  exif-oxide -Orientation# -GPS* -EXIF:all image.jpg\n"
@@ -202,9 +451,57 @@ fn main() {
                 .value_name("ARG")
                 .num_args(1..) // Accept one or more arguments
                 .allow_hyphen_values(true) // Allow -TagName# format
-                .required(true)
+                .required_unless_present("schema")
                 .trailing_var_arg(true), // Allow mixed positional arguments
         )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .help("Output format: json (default), xml or yaml")
+                .long_help(
+                    "json: JSON array with one object per file (ExifTool -j)\n\
+                     xml: RDF/XML with one rdf:Description per file (ExifTool -X)\n\
+                     yaml: the JSON structure as YAML"
+                )
+                .value_name("FORMAT")
+                .value_parser(["json", "xml", "yaml"])
+                .default_value("json"),
+        )
+        .arg(
+            Arg::new("summary")
+                .long("summary")
+                .help("Output the file summary profile instead of tags")
+                .long_help(
+                    "Output one JSON object per file with CaptureDate, CaptureDateSource,\n\
+                     ImageWidth, ImageHeight, Rotation, Make, Model, Lens, GPSLatitude,\n\
+                     GPSLongitude, MIMEType and Duration. Only the tags these need are\n\
+                     extracted; tag filters on the command line are ignored."
+                )
+                .conflicts_with_all(["format", "typed", "binary"])
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("schema")
+                .long("schema")
+                .help("Print the JSON Schema of the JSON output and exit")
+                .long_help(
+                    "Print the JSON Schema (draft 2020-12) of the JSON output, with the type\n\
+                     of each known tag. With -n, describes the numeric output instead.\n\
+                     The default schema is published as docs/output.schema.json."
+                )
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("typed")
+                .long("typed")
+                .help("Output JSON that conforms to the --schema types")
+                .long_help(
+                    "ExifTool writes strings that look like numbers as JSON numbers, so\n\
+                     e.g. a Software tag of \"1.0\" is output as 1.0. With --typed, tags that\n\
+                     the schema types as strings are always output as strings."
+                )
+                .action(clap::ArgAction::SetTrue),
+        )
         .arg(
             Arg::new("show-missing")
                 .long("show-missing")
@@ -217,6 +514,30 @@ fn main() {
                 .help("Include parsing warnings in output (suppressed by default)")
                 .action(clap::ArgAction::SetTrue), // Boolean flag
         )
+        .arg(
+            Arg::new("warnings-as-errors")
+                .long("warnings-as-errors")
+                .help("Exit with status 1 if any file has warnings")
+                .long_help(
+                    "Count a file with warnings (a wrong extension, a truncated or corrupt\n\
+                     metadata block, ...) as failed, so the exit status is 1. Add --warnings\n\
+                     to also count the parsers' minor diagnostics."
+                )
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("warning-output")
+                .long("warning-output")
+                .help("Where to report warnings: inline (default) or stderr")
+                .long_help(
+                    "inline: an ExifTool:Warning tag in each file's output, like ExifTool\n\
+                     stderr: one JSON line per file with warnings, on stderr:\n\
+                     {\"SourceFile\":\"a.jpg\",\"Warnings\":[{\"Code\":..,\"Message\":..,\"Context\":..}]}"
+                )
+                .value_name("WHERE")
+                .value_parser(["inline", "stderr"])
+                .default_value("inline"),
+        )
         .arg(
             Arg::new("binary")
                 .short('b')
@@ -224,6 +545,102 @@ fn main() {
                 .help("Extract binary data for specified tag (outputs raw binary to stdout)")
                 .action(clap::ArgAction::SetTrue), // Boolean flag
         )
+        .arg(
+            Arg::new("binary-threshold")
+                .long("binary-threshold")
+                .help("Summarize binary values larger than this many bytes (default: 0)")
+                .long_help(
+                    "Binary values (maker note blobs, ICC profiles, ...) larger than this\n\
+                     many bytes are output as \"(Binary data N bytes, use -b option to extract)\".\n\
+                     Smaller values are output as byte arrays. The default of 0 matches ExifTool."
+                )
+                .value_name("BYTES")
+                .value_parser(clap::value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("include-binary")
+                .long("include-binary")
+                .help("Output binary values as base64 instead of a placeholder")
+                .long_help(
+                    "Output binary values as \"base64:...\" strings in the JSON output.\n\
+                     ThumbnailImage, PreviewImage and OtherImage contain the embedded\n\
+                     image bytes, so they round-trip through JSON losslessly.\n\n\
+                     ExifTool equivalent: -b -j"
+                )
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("tag-sources")
+                .long("tag-sources")
+                .help("Include where each tag was read from in the output")
+                .long_help(
+                    "Add a \"TagSources\" object with the table, tag ID, file offset,\n\
+                     raw format and count of each EXIF and maker note tag, and an\n\
+                     \"XMPPacket\" object with the location, padding and xpacket\n\
+                     wrapper of the XMP packet.\n\n\
+                     Similar to the per-tag details of exiftool -v"
+                )
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("stats")
+                .long("stats")
+                .help("Include per-stage timing and bytes read in the output")
+                .long_help(
+                    "Add a \"Statistics\" object with the time spent and bytes read in\n\
+                     each extraction stage (detection, EXIF, maker notes, XMP,\n\
+                     composites, ...) and the number of output tags in each group.\n\
+                     Results answered from the metadata cache have no statistics."
+                )
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("validate")
+                .long("validate")
+                .help("Add forensic consistency checks as Validation tags")
+                .long_help(
+                    "Check the metadata for signs of editing and output the results as\n\
+                     Validation group tags: ModifyDate earlier than DateTimeOriginal,\n\
+                     invalid or future dates, missing maker notes, image and thumbnail\n\
+                     size mismatches, and editing software. Validation:Validate\n\
+                     summarizes the result as \"OK\" or the number of warnings."
+                )
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("geolocation")
+                .long("geolocation")
+                .help("Add Geolocation tags for the city nearest to the GPS position")
+                .long_help(
+                    "Resolve the GPS position to the nearest city and add GeolocationCity,\n\
+                     GeolocationRegion, GeolocationCountryCode and related tags. Positions\n\
+                     more than 250 km from every city in the database get no tags.\n\
+                     Requires the `geolocation` cargo feature.\n\n\
+                     ExifTool equivalent: -api geolocation"
+                )
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("quicktime-utc")
+                .long("quicktime-utc")
+                .help("Interpret QuickTime dates as UTC (on), or decide per file (auto)")
+                .long_help(
+                    "QuickTime CreateDate and ModifyDate are UTC by specification, but many\n\
+                     cameras and phones store local time.\n\
+                     on    Treat them as UTC and convert them to local time\n\
+                     auto  Compare with the GPS time stamp and the file modification\n\
+                           time to decide between UTC and local time\n\
+                     off   Output the stored values (default)\n\
+                     on and auto add Composite:QuickTimeCreateDateRaw with the stored\n\
+                     value and Composite:QuickTimeDateSource with the decision.\n\n\
+                     ExifTool equivalent: -api QuickTimeUTC (on)"
+                )
+                .value_name("MODE")
+                .value_parser(["on", "auto", "off"])
+                .num_args(0..=1)
+                .require_equals(true)
+                .default_missing_value("on"),
+        )
         .arg(
             Arg::new("image-hash")
                 .long("image-hash")
@@ -242,53 +659,311 @@ fn main() {
                 .help("Hash algorithm for --image-hash (default: MD5)")
                 .long_help(
                     "Select the hash algorithm for ImageDataHash computation.\n\
-                     Options: MD5 (default), SHA256, SHA512\n\n\
+                     Options: MD5 (default), SHA1, SHA256, SHA512, XXH3\n\
+                     XXH3 isn't cryptographic, but is much faster on large videos.\n\n\
                      ExifTool equivalent: -api imagehashtype=MD5|SHA256|SHA512"
                 )
                 .value_name("ALGORITHM")
-                .value_parser(["MD5", "SHA256", "SHA512", "md5", "sha256", "sha512"])
+                .value_parser([
+                    "MD5", "SHA1", "SHA256", "SHA512", "XXH3", "md5", "sha1", "sha256", "sha512",
+                    "xxh3",
+                ])
                 .default_value("MD5"),
         )
-        .get_matches();
+        .arg(
+            Arg::new("image-hash-chunks")
+                .long("image-hash-chunks")
+                .help("Also output a digest of every MB megabytes of image data (implies --image-hash)")
+                .long_help(
+                    "Hash the image data in chunks of this many megabytes (MiB) as well as\n\
+                     whole, like torrent pieces, so a large video can be verified in\n\
+                     parts. Output as File:ImageDataHashChunkSize (bytes) and the\n\
+                     File:ImageDataHashChunks array. Implies --image-hash."
+                )
+                .value_name("MB")
+                .value_parser(clap::value_parser!(u64).range(1..)),
+        )
+        .arg(
+            Arg::new("parallel-hash")
+                .long("parallel-hash")
+                .help("Hash videos of at least MB megabytes on a separate thread (0 disables)")
+                .long_help(
+                    "Hash the media data of MOV/MP4 files of at least this many megabytes\n\
+                     (MiB) on a separate thread while the metadata is parsed. Default 256;\n\
+                     0 always hashes on the main thread."
+                )
+                .value_name("MB")
+                .value_parser(clap::value_parser!(u64)),
+        )
+        .arg(
+            Arg::new("symlinks")
+                .long("symlinks")
+                .help("Follow symbolic links (default) or report the links themselves")
+                .long_help(
+                    "follow  Read the file a symbolic link points to, like ExifTool\n\
+                     report  Output the link's own File tags (FileName, FileSize,\n\
+                             dates, FilePermissions) with FileType LINK\n\
+                     Named pipes, sockets and devices are never read: they are\n\
+                     reported as errors instead of blocking."
+                )
+                .value_name("MODE")
+                .value_parser(["follow", "report"])
+                .default_value("follow"),
+        )
+        .arg(
+            Arg::new("api")
+                .long("api")
+                .help("Set an ExifTool API option (OPT=VAL, may be repeated)")
+                .long_help(
+                    "Set an ExifTool API option. Supported options:\n\
+                     requesttags=imagedatahash  Same as --image-hash\n\
+                     imagehashtype=ALG          Same as --image-hash-type\n\
+                     geolocation                Same as --geolocation\n\
+                     quicktimeutc[=auto]        Same as --quicktime-utc\n\
+                     requestall=3               Also computes ImageDataHash\n\
+                     duplicates                 Same as -a\n\
+                     charsetexif=UTF8|Latin     Character set of EXIF strings\n\
+                     largefilesupport           Accepted; large files are always read\n\
+                     Other options are ignored. ExifTool's single-dash -api also works."
+                )
+                .value_name("OPT=VAL")
+                .action(clap::ArgAction::Append),
+        )
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .help("Load user-defined tags from a JSON config file")
+                .long_help(
+                    "Load user-defined tags from a JSON config file: XMP namespaces and\n\
+                     tag names, template composites and output renames. ExifTool's\n\
+                     single-dash -config also works."
+                )
+                .value_name("FILE"),
+        )
+        .arg(
+            Arg::new("cache-dir")
+                .long("cache-dir")
+                .help("Reuse results for unchanged files from a cache directory")
+                .long_help(
+                    "Store extraction results in DIR and answer later runs from it for\n\
+                     files that haven't changed. Results are keyed by the command line\n\
+                     options and the file identity selected by --cache-key."
+                )
+                .value_name("DIR"),
+        )
+        .arg(
+            Arg::new("cache-key")
+                .long("cache-key")
+                .help("How --cache-dir detects changed files (default: stat)")
+                .long_help(
+                    "stat: file path, device, inode, modification time, change time and\n\
+                     size (fast)\n\
+                     content: SHA-256 of the file content, its extension and modification\n\
+                     time (reads every file; moved files and copies made with cp -p share\n\
+                     an entry)"
+                )
+                .value_name("MODE")
+                .value_parser(["stat", "content"])
+                .default_value("stat")
+                .requires("cache-dir"),
+        )
+        .arg(
+            Arg::new("clear-cache")
+                .long("clear-cache")
+                .help("Remove all entries from --cache-dir before processing")
+                .requires("cache-dir")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .try_get_matches_from(args);
+    let matches = match matches {
+        Ok(matches) => matches,
+        Err(e) => {
+            // --help and --version are "errors" that print to stdout
+            let _ = e.print();
+            return match e.exit_code() {
+                0 => Ok(()),
+                status => Err(status),
+            };
+        }
+    };
 
     // Extract all arguments and parse ExifTool-style filters
-    let args: Vec<&String> = matches.get_many::<String>("args").unwrap().collect();
+    let mut args: Vec<&String> = matches
+        .get_many::<String>("args")
+        .map(|args| args.collect())
+        .unwrap_or_default();
+
+    // ExifTool's -X (-xmlFormat) selects RDF/XML output
+    let mut output_format = matches
+        .get_one::<String>("format")
+        .and_then(|format| format.parse().ok())
+        .unwrap_or_default();
+    let arg_count = args.len();
+    args.retain(|arg| *arg != "-X" && *arg != "-xmlFormat");
+    if args.len() != arg_count {
+        output_format = OutputFormat::Xml;
+    }
     let show_missing = matches.get_flag("show-missing");
     let show_warnings = matches.get_flag("warnings");
+    let warnings_to_stderr = matches
+        .get_one::<String>("warning-output")
+        .map(|s| s.as_str())
+        == Some("stderr");
+    let warnings_as_errors = matches.get_flag("warnings-as-errors");
     let binary_extraction = matches.get_flag("binary");
-    let compute_image_hash = matches.get_flag("image-hash");
+    let binary_threshold = matches.get_one::<usize>("binary-threshold").copied();
+    let include_binary = matches.get_flag("include-binary");
+    let include_tag_sources = matches.get_flag("tag-sources");
+    let typed = matches.get_flag("typed");
+    let validate = matches.get_flag("validate");
+    let stats = matches.get_flag("stats");
+    let geolocation = matches.get_flag("geolocation");
+    let symlinks = match matches.get_one::<String>("symlinks").map(|s| s.as_str()) {
+        Some("report") => SymlinkPolicy::Report,
+        _ => SymlinkPolicy::Follow,
+    };
+    let quicktime_utc = matches
+        .get_one::<String>("quicktime-utc")
+        .map_or(QuickTimeUtc::Off, |mode| parse_quicktime_utc(mode));
+    let mut compute_image_hash = matches.get_flag("image-hash");
     let image_hash_type_str = matches
         .get_one::<String>("image-hash-type")
         .map(|s| s.as_str())
         .unwrap_or("MD5");
 
     // Parse hash type from string
-    let image_hash_type = match image_hash_type_str.to_uppercase().as_str() {
-        "MD5" => ImageHashType::Md5,
-        "SHA256" => ImageHashType::Sha256,
-        "SHA512" => ImageHashType::Sha512,
-        _ => {
-            eprintln!(
-                "Error: Invalid hash type '{}'. Use MD5, SHA256, or SHA512.",
-                image_hash_type_str
-            );
-            std::process::exit(1);
+    let image_hash_type = parse_image_hash_type(image_hash_type_str)?;
+    let image_hash_chunk_size = matches
+        .get_one::<u64>("image-hash-chunks")
+        .map(|megabytes| megabytes * 1024 * 1024);
+    if image_hash_chunk_size.is_some() {
+        compute_image_hash = true;
+    }
+    let parallel_hash_min_size = matches
+        .get_one::<u64>("parallel-hash")
+        .map(|&megabytes| (megabytes > 0).then(|| megabytes * 1024 * 1024));
+
+    // ExifTool's -api takes its OPT=VAL as the next argument, which lands in
+    // the trailing args
+    let mut api_options: Vec<String> = matches
+        .get_many::<String>("api")
+        .map(|options| options.cloned().collect())
+        .unwrap_or_default();
+    while let Some(index) = args
+        .iter()
+        .position(|arg| *arg == "-api" || *arg == "--api")
+    {
+        args.remove(index);
+        if index < args.len() {
+            api_options.push(args.remove(index).clone());
         }
-    };
+    }
+    let mut api = ApiOptions::default();
+    for option in &api_options {
+        let (name, value) = option.split_once('=').unwrap_or((option, "1"));
+        match api.set(name, value) {
+            Ok(()) => {}
+            Err(ExifError::Unsupported(_)) => debug!("Ignoring unsupported API option: {}", option),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                return Err(EXIT_USAGE);
+            }
+        }
+    }
+
+    // ExifTool's -w EXT (or -textOut): -w! overwrites, -w+ appends
+    let mut text_out = None;
+    if let Some(index) = args
+        .iter()
+        .position(|arg| parse_text_out_mode(arg).is_some())
+    {
+        let mode = parse_text_out_mode(args.remove(index)).unwrap_or_default();
+        if index >= args.len() {
+            eprintln!("Error: -w requires an extension or file name format");
+            return Err(EXIT_USAGE);
+        }
+        text_out = Some(TextOut::new(args.remove(index), mode));
+    }
+
+    // ExifTool's -config takes its file as the next argument
+    let mut config_file = matches.get_one::<String>("config").cloned();
+    if let Some(index) = args.iter().position(|arg| *arg == "-config") {
+        args.remove(index);
+        if index < args.len() {
+            config_file = Some(args.remove(index).clone());
+        }
+    }
+    if let Some(file) = config_file {
+        match UserConfig::from_file(file.as_ref()) {
+            Ok(config) => set_config(Some(config)),
+            Err(e) => {
+                eprintln!("Error: Can't load config file '{file}': {e}");
+                return Err(EXIT_FILE_ERROR);
+            }
+        }
+    }
+
+    let cache = matches
+        .get_one::<String>("cache-dir")
+        .map(|dir| {
+            let key_mode = match matches.get_one::<String>("cache-key").map(|s| s.as_str()) {
+                Some("content") => CacheKeyMode::ContentHash,
+                _ => CacheKeyMode::FileStat,
+            };
+            let cache = match MetadataCache::open_dir(dir) {
+                Ok(cache) => cache.with_key_mode(key_mode),
+                Err(e) => {
+                    eprintln!("Error: Can't open cache directory '{dir}': {e}");
+                    return Err(EXIT_FILE_ERROR);
+                }
+            };
+            if matches.get_flag("clear-cache") {
+                if let Err(e) = cache.clear() {
+                    eprintln!("Error: Can't clear cache directory '{dir}': {e}");
+                    return Err(EXIT_FILE_ERROR);
+                }
+            }
+            Ok(cache)
+        })
+        .transpose()?;
 
     // Parse arguments into files and filter options using ExifTool patterns
-    let (file_paths, mut filter_options) = parse_exiftool_args(args);
+    let (file_paths, mut filter_options) = parse_exiftool_args(args)?;
 
     // Apply image hash options to filter_options
+    filter_options.image_hash_type = image_hash_type;
     if compute_image_hash {
         filter_options.compute_image_hash = true;
-        filter_options.image_hash_type = image_hash_type;
+        filter_options.image_hash_chunk_size = image_hash_chunk_size;
+    }
+    if let Some(min_size) = parallel_hash_min_size {
+        filter_options.parallel_hash_min_size = min_size;
+    }
+
+    // Apply binary output options to filter_options
+    if let Some(threshold) = binary_threshold {
+        filter_options.binary_threshold = threshold;
+    }
+    filter_options.include_binary = include_binary;
+    filter_options.include_tag_sources = include_tag_sources;
+    filter_options.validate = validate;
+    filter_options.stats = stats;
+    filter_options.geolocation = geolocation;
+    filter_options.quicktime_utc = quicktime_utc;
+    filter_options.symlinks = symlinks;
+    // -api options win over the equivalent command line options
+    api.apply(&mut filter_options);
+
+    if matches.get_flag("schema") {
+        let schema = json_schema(filter_options.numeric);
+        println!("{}", serde_json::to_string_pretty(&schema).unwrap());
+        return Ok(());
     }
 
     // Validate we have at least one file
     if file_paths.is_empty() {
         eprintln!("Error: No files specified");
-        std::process::exit(1);
+        return Err(EXIT_USAGE);
     }
 
     // Convert strings to PathBufs for proper file handling
@@ -309,30 +984,271 @@ fn main() {
         // Binary extraction requires exactly one tag and one file for simplicity
         if filter_options.requested_tags.len() != 1 {
             eprintln!("Error: Binary extraction requires exactly one tag (e.g., -b -ThumbnailImage image.jpg)");
-            std::process::exit(1);
+            return Err(EXIT_USAGE);
         }
         if paths.len() != 1 {
             eprintln!("Error: Binary extraction requires exactly one file");
-            std::process::exit(1);
+            return Err(EXIT_USAGE);
+        }
+        // Request the image's offset/length tags too, so the one extraction
+        // has what extract_binary_data needs. Other tags (ICC_Profile) are
+        // extracted with their bytes
+        let tag_name = filter_options.requested_tags[0].clone();
+        if let Some(source_tags) = embedded_image_source_tags(&tag_name) {
+            for (offset_name, length_name) in source_tags {
+                filter_options.requested_tags.push(offset_name.to_string());
+                filter_options.requested_tags.push(length_name.to_string());
+            }
         }
     }
 
+    let serializer: Box<dyn OutputSerializer> = if matches.get_flag("summary") {
+        filter_options = FileSummary::filter_options();
+        Box::new(SummarySerializer)
+    } else {
+        match output_format {
+            OutputFormat::Json => Box::new(JsonSerializer {
+                typed,
+                numeric: filter_options.numeric,
+            }),
+            format => format.serializer(),
+        }
+    };
+
     // Process all files - this will output a JSON array like ExifTool (or binary data if -b)
     match process_files(
         &paths,
         show_missing,
         show_warnings,
+        warnings_to_stderr,
+        warnings_as_errors,
         binary_extraction,
+        serializer.as_ref(),
         filter_options,
+        cache.as_ref(),
+        text_out.as_ref(),
     ) {
-        Ok(()) => {
+        Ok(0) => {
             // Success - output has already been printed
+            Ok(())
+        }
+        Ok(failed) => {
+            // Output for the other files has been printed, but scripts need
+            // to know some files failed
+            info!("{} of {} files failed", failed, paths.len());
+            Err(EXIT_FILE_ERROR)
         }
         Err(e) => {
             // Rust error handling - print to stderr and exit with error code
             error!("Fatal error: {}", e);
             eprintln!("Error: {e}");
-            std::process::exit(1);
+            Err(EXIT_FILE_ERROR)
+        }
+    }
+}
+
+/// ExifTool's `-stay_open True -@ ARGFILE` batch mode
+///
+/// Commands are read from the argument file, one argument per line, and run
+/// when `-execute[NUM]` is read. Each command's output is followed by
+/// `{ready[NUM]}` on stdout, so callers can keep one process alive and stream
+/// commands to it instead of starting one per file.
+struct StayOpen {
+    /// Where commands are read from: `-` for stdin
+    argfile: String,
+    /// Added to every command: the arguments after `-common_args`, and any
+    /// other options on the command line
+    common_args: Vec<String>,
+}
+
+impl StayOpen {
+    /// The batch mode of a command line with `-stay_open True -@ ARGFILE`
+    fn from_args(args: &[String]) -> Option<Self> {
+        let mut stay_open = false;
+        let mut argfile = None;
+        let mut common_args = Vec::new();
+        let mut args = args.iter().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.to_lowercase().as_str() {
+                "-stay_open" => {
+                    stay_open = args
+                        .next()
+                        .is_some_and(|flag| flag == "1" || flag.eq_ignore_ascii_case("true"));
+                }
+                "-@" => argfile = args.next().cloned(),
+                "-common_args" => common_args.extend(args.by_ref().cloned()),
+                _ => common_args.push(arg.clone()),
+            }
+        }
+        stay_open.then(|| Self {
+            argfile: argfile.unwrap_or_else(|| "-".to_string()),
+            common_args,
+        })
+    }
+
+    /// Read and run commands until `-stay_open False`, or the end of stdin
+    ///
+    /// Like ExifTool, an argument file (rather than stdin) is polled for
+    /// more commands when its end is reached.
+    fn run(self) -> Result<(), i32> {
+        use std::io::{BufRead, BufReader, Write};
+
+        let from_stdin = self.argfile == "-";
+        let mut reader: Box<dyn BufRead> = if from_stdin {
+            Box::new(std::io::stdin().lock())
+        } else {
+            match std::fs::File::open(&self.argfile) {
+                Ok(file) => Box::new(BufReader::new(file)),
+                Err(e) => {
+                    eprintln!("Error: Can't open argument file '{}': {e}", self.argfile);
+                    return Err(EXIT_FILE_ERROR);
+                }
+            }
+        };
+
+        let mut command = Vec::new();
+        let mut reading_stay_open_flag = false;
+        let mut line = String::new();
+        loop {
+            if let Err(e) = reader.read_line(&mut line) {
+                eprintln!("Error: Can't read argument file '{}': {e}", self.argfile);
+                return Err(EXIT_FILE_ERROR);
+            }
+            if !line.ends_with('\n') {
+                if !from_stdin {
+                    // Wait for the rest of the line
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                    continue;
+                }
+                if line.is_empty() {
+                    return Ok(());
+                }
+            }
+            let arg = line.trim().to_string();
+            line.clear();
+
+            // ExifTool: blank lines and #comments are ignored
+            if arg.is_empty() || arg.starts_with('#') {
+                continue;
+            }
+            if reading_stay_open_flag {
+                if arg == "0" || arg.eq_ignore_ascii_case("false") {
+                    return Ok(());
+                }
+                reading_stay_open_flag = false;
+            } else if arg.eq_ignore_ascii_case("-stay_open") {
+                reading_stay_open_flag = true;
+            } else if let Some(number) = arg.strip_prefix("-execute") {
+                let mut args = vec!["exif-oxide".to_string()];
+                args.append(&mut command);
+                args.extend(self.common_args.iter().cloned());
+                if let Err(status) = run(args) {
+                    debug!("Command exited with status {}", status);
+                }
+
+                let mut stdout = std::io::stdout().lock();
+                if writeln!(stdout, "{{ready{number}}}")
+                    .and_then(|()| stdout.flush())
+                    .is_err()
+                {
+                    // The caller went away
+                    return Ok(());
+                }
+            } else {
+                command.push(arg);
+            }
+        }
+    }
+}
+
+/// ExifTool's `-tagsFromFile SRCFILE [-TAG...] [--TAG...] FILE...`
+///
+/// Copies tags from the source file to each destination file; see
+/// [`exif_oxide::write::copy`] for the selectors. Like ExifTool, the
+/// originals are kept as `FILE_original` unless `-overwrite_original` is
+/// given, and `-P` preserves their modification times.
+struct TagsFromFile {
+    source: PathBuf,
+    selectors: Vec<String>,
+    dest_files: Vec<PathBuf>,
+    options: WriteOptions,
+}
+
+impl TagsFromFile {
+    /// The copy requested by a command line with `-tagsFromFile SRCFILE`
+    fn from_args(args: &[String]) -> Option<Self> {
+        let position = args
+            .iter()
+            .position(|arg| arg.eq_ignore_ascii_case("-tagsFromFile"))?;
+        let source = PathBuf::from(args.get(position + 1)?);
+        let mut selectors = Vec::new();
+        let mut dest_files = Vec::new();
+        let mut backup = true;
+        let mut preserve_mtime = false;
+        for (index, arg) in args.iter().enumerate().skip(1) {
+            if index == position || index == position + 1 {
+                continue;
+            }
+            match arg.as_str() {
+                "-overwrite_original" => backup = false,
+                "-P" => preserve_mtime = true,
+                selector if selector.starts_with('-') && selector.len() > 1 => {
+                    selectors.push(selector.to_string())
+                }
+                file => dest_files.push(PathBuf::from(file)),
+            }
+        }
+        Some(Self {
+            source,
+            selectors,
+            dest_files,
+            options: WriteOptions::new()
+                .with_backup(backup)
+                .with_preserved_mtime(preserve_mtime),
+        })
+    }
+
+    /// Copy to every destination, printing ExifTool's summary
+    fn run(self) -> Result<(), i32> {
+        if self.dest_files.is_empty() {
+            eprintln!("Error: No destination file for -tagsFromFile");
+            return Err(EXIT_USAGE);
+        }
+        let selectors: Vec<&str> = self.selectors.iter().map(String::as_str).collect();
+        let (mut updated, mut unchanged, mut failed) = (0, 0, 0);
+        for dest in &self.dest_files {
+            match copy_tags_from(&self.source, dest, &selectors, &self.options) {
+                Ok(report) => {
+                    for warning in &report.warnings {
+                        eprintln!("Warning: {warning} - {}", dest.display());
+                    }
+                    if report.copied.is_empty() {
+                        unchanged += 1;
+                    } else {
+                        updated += 1;
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Error: {e} - {}", dest.display());
+                    failed += 1;
+                }
+            }
+        }
+
+        // ExifTool: "    1 image files updated"
+        for (count, what) in [
+            (updated, "updated"),
+            (unchanged, "unchanged"),
+            (failed, "weren't updated due to errors"),
+        ] {
+            if count > 0 {
+                println!("{count:>5} image files {what}");
+            }
+        }
+        if failed > 0 {
+            Err(EXIT_FILE_ERROR)
+        } else {
+            Ok(())
         }
     }
 }
@@ -341,14 +1257,21 @@ fn main() {
 ///
 /// This function matches ExifTool's behavior of outputting a JSON array
 /// containing one object per file, even for a single file.
-/// Result<T, E> means either Ok(T) for success or Err(E) for errors.
+/// Returns the number of files that failed: those with errors, and with
+/// `warnings_as_errors`, those with warnings.
+#[allow(clippy::too_many_arguments)]
 fn process_files(
     paths: &[PathBuf],
     show_missing: bool,
     show_warnings: bool,
+    warnings_to_stderr: bool,
+    warnings_as_errors: bool,
     binary_extraction: bool,
+    serializer: &dyn OutputSerializer,
     filter_options: FilterOptions,
-) -> Result<(), Box<dyn std::error::Error>> {
+    cache: Option<&MetadataCache>,
+    text_out: Option<&TextOut>,
+) -> Result<usize, Box<dyn std::error::Error>> {
     use exif_oxide::types::ExifData;
 
     let mut results = Vec::new();
@@ -356,37 +1279,16 @@ fn process_files(
     // Process each file
     for path in paths {
         debug!("Processing file: {}", path.display());
-        match process_single_file(path, show_missing, show_warnings, &filter_options) {
+        match process_single_file(path, show_missing, show_warnings, &filter_options, cache) {
             Ok(metadata) => {
                 info!("Successfully processed: {}", path.display());
 
-                // Handle binary extraction if requested
+                // Handle binary extraction if requested. The filter also
+                // requested the image's offset/length tags, so this metadata
+                // locates the image without a second extraction
                 if binary_extraction {
                     let tag_name = &filter_options.requested_tags[0]; // We validated exactly one tag
-                                                                      // For binary extraction, we need full metadata to find offset/length tags
-                                                                      // Extract metadata again without filtering to get all tags
-                    let no_filters = FilterOptions {
-                        requested_tags: Vec::new(),
-                        requested_groups: Vec::new(),
-                        group_all_patterns: Vec::new(),
-                        extract_all: true,
-                        numeric_tags: std::collections::HashSet::new(),
-                        glob_patterns: Vec::new(),
-                        compute_image_hash: false,
-                        image_hash_type: ImageHashType::default(),
-                    };
-                    match process_single_file(path, show_missing, show_warnings, &no_filters) {
-                        Ok(full_metadata) => {
-                            return extract_binary_data(&full_metadata, tag_name, path);
-                        }
-                        Err(e) => {
-                            return Err(format!(
-                                "Failed to extract full metadata for binary extraction: {}",
-                                e
-                            )
-                            .into());
-                        }
-                    }
+                    return extract_binary_data(&metadata, tag_name, path).map(|()| 0);
                 }
 
                 results.push(metadata);
@@ -401,7 +1303,13 @@ fn process_files(
                     tags: vec![],
                     legacy_tags: indexmap::IndexMap::new(),
                     errors: vec![format!("Error processing file: {e}")],
+                    warnings: vec![],
+                    truncation: None,
                     missing_implementations: None,
+                    tag_sources: indexmap::IndexMap::new(),
+                    xmp_packet: None,
+                    xmp_provenance: None,
+                    stats: None,
                 };
                 results.push(error_metadata);
             }
@@ -416,14 +1324,57 @@ fn process_files(
         Some(&filter_options.numeric_tags)
     };
 
+    // Counted before the warnings are moved to stderr or into the tags
+    let mut failed = results
+        .iter()
+        .filter(|result| {
+            !result.errors.is_empty() || (warnings_as_errors && !result.warnings.is_empty())
+        })
+        .count();
+
     for result in &mut results {
-        result.prepare_for_serialization(numeric_tags_ref);
+        // Taken out before serialization so they don't also appear inline
+        if warnings_to_stderr && !result.warnings.is_empty() {
+            let warnings = std::mem::take(&mut result.warnings);
+            let line = serde_json::json!({
+                "SourceFile": result.source_file,
+                "Warnings": warnings,
+            });
+            eprintln!("{line}");
+        }
+        result.prepare_for_serialization_with_groups(numeric_tags_ref, filter_options.group_family);
+        result.format_binary_values(&filter_options);
+        result.apply_tag_order(filter_options.tag_order);
+    }
+
+    // -w: one output file per file, and a count on stdout like ExifTool
+    if let Some(text_out) = text_out {
+        let mut written = 0;
+        // ExifTool writes nothing for files it couldn't read
+        for result in results.iter().filter(|result| result.errors.is_empty()) {
+            match text_out.write(serializer, result) {
+                Ok(path) => {
+                    debug!("Wrote {}", path.display());
+                    written += 1;
+                }
+                Err(e) => {
+                    eprintln!("Error: {e} - {}", result.source_file);
+                    failed += 1;
+                }
+            }
+        }
+        let what = match text_out.mode() {
+            TextOutMode::Append => "appended",
+            _ => "created",
+        };
+        println!("{written:>5} output files {what}");
+        return Ok(failed);
     }
 
-    // Output as JSON array matching ExifTool format
-    println!("{}", serde_json::to_string_pretty(&results)?);
+    // Output as JSON array matching ExifTool format (or -X RDF/XML, YAML)
+    serializer.write(&results, &mut std::io::stdout().lock())?;
 
-    Ok(())
+    Ok(failed)
 }
 
 /// Process a single image file and return metadata
@@ -435,19 +1386,29 @@ fn process_single_file(
     show_missing: bool,
     show_warnings: bool,
     filter_options: &FilterOptions,
+    cache: Option<&MetadataCache>,
 ) -> Result<exif_oxide::types::ExifData, Box<dyn std::error::Error>> {
-    // Verify file exists
-    if !path.exists() {
+    // Verify file exists (a dangling link can still be reported)
+    let is_reported_link = filter_options.symlinks == SymlinkPolicy::Report && path.is_symlink();
+    if !path.exists() && !is_reported_link {
         return Err(format!("File not found: {}", path.display()).into());
     }
 
     // Extract metadata using our library with filtering
-    let metadata = extract_metadata(
-        path,
-        show_missing,
-        show_warnings,
-        Some(filter_options.clone()),
-    )?;
+    let metadata = match cache {
+        Some(cache) => cache.extract_metadata(
+            path,
+            show_missing,
+            show_warnings,
+            Some(filter_options.clone()),
+        )?,
+        None => extract_metadata(
+            path,
+            show_missing,
+            show_warnings,
+            Some(filter_options.clone()),
+        )?,
+    };
 
     Ok(metadata)
 }
@@ -469,46 +1430,29 @@ fn extract_binary_data(
 
     debug!("Extracting binary data for tag: {}", requested_tag);
 
-    // Map binary tag names to their offset/length counterparts
-    // Based on ExifTool's composite tag definitions and format-specific naming
-    let (offset_pattern, length_pattern) = match requested_tag.to_lowercase().as_str() {
-        "thumbnailimage" => {
-            // Try multiple patterns for thumbnail data
-            if let (Some(offset), Some(length)) =
-                find_tag_pair(metadata, "ThumbnailOffset", "ThumbnailLength")
-            {
-                (Some(offset), Some(length))
-            } else {
-                find_tag_pair(metadata, "OtherImageStart", "OtherImageLength")
-            }
-        }
-        "previewimage" => {
-            // Try multiple patterns for preview data
-            if let (Some(offset), Some(length)) =
-                find_tag_pair(metadata, "PreviewImageStart", "PreviewImageLength")
-            {
-                (Some(offset), Some(length))
-            } else {
-                find_tag_pair(metadata, "OtherImageStart", "OtherImageLength")
-            }
-        }
-        "otherimage" => find_tag_pair(metadata, "OtherImageStart", "OtherImageLength"),
-        _ => {
+    // Binary tags that aren't embedded images, like ICC_Profile, were
+    // extracted with their bytes
+    if embedded_image_source_tags(requested_tag).is_none() {
+        let Some(data) = binary_tag_value(metadata, requested_tag) else {
             return Err(
                 format!("Binary extraction not supported for tag: {}", requested_tag).into(),
             );
-        }
-    };
+        };
+        let mut handle = io::stdout().lock();
+        handle.write_all(data)?;
+        handle.flush()?;
+        return Ok(());
+    }
 
-    let (offset_value, length_value) = match (offset_pattern, length_pattern) {
-        (Some(offset), Some(length)) => (offset, length),
-        _ => {
-            return Err(format!(
-                "Required offset/length tags not found for: {}",
-                requested_tag
-            )
-            .into());
-        }
+    // The offset/length tags were requested along with the image, so they
+    // are in the filtered metadata
+    let Some((offset_value, length_value)) = embedded_image_location(&metadata.tags, requested_tag)
+    else {
+        return Err(format!(
+            "Required offset/length tags not found for: {}",
+            requested_tag
+        )
+        .into());
     };
 
     debug!("Found offset: {}, length: {}", offset_value, length_value);
@@ -545,47 +1489,6 @@ fn extract_binary_data(
     Ok(())
 }
 
-/// Find a pair of offset/length tags in metadata
-/// Returns (offset_value, length_value) if both found, otherwise (None, None)
-fn find_tag_pair(
-    metadata: &exif_oxide::types::ExifData,
-    offset_name: &str,
-    length_name: &str,
-) -> (Option<u32>, Option<u32>) {
-    let mut offset_value = None;
-    let mut length_value = None;
-
-    debug!("Looking for tags: {} and {}", offset_name, length_name);
-    debug!(
-        "Available tags: {:?}",
-        metadata.tags.iter().map(|t| &t.name).collect::<Vec<_>>()
-    );
-
-    // Search through all tags for the offset and length values
-    for tag_entry in &metadata.tags {
-        // Check if tag name matches (with or without group prefix)
-        if tag_entry.name.ends_with(&format!(":{}", offset_name)) || tag_entry.name == offset_name {
-            if let Some(val) = tag_entry.value.as_u32() {
-                debug!("Found offset tag {}: {}", tag_entry.name, val);
-                offset_value = Some(val);
-            }
-        } else if tag_entry.name.ends_with(&format!(":{}", length_name))
-            || tag_entry.name == length_name
-        {
-            if let Some(val) = tag_entry.value.as_u32() {
-                debug!("Found length tag {}: {}", tag_entry.name, val);
-                length_value = Some(val);
-            }
-        }
-    }
-
-    debug!(
-        "Result: offset={:?}, length={:?}",
-        offset_value, length_value
-    );
-    (offset_value, length_value)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -598,7 +1501,7 @@ mod tests {
         let exposure = "-ExposureTime#".to_string();
         let args = vec![&image1, &image2, &fnumber, &exposure];
 
-        let (files, filter_opts) = parse_exiftool_args(args);
+        let (files, filter_opts) = parse_exiftool_args(args).unwrap();
 
         assert_eq!(files, vec!["image1.jpg", "image2.png"]);
         assert!(filter_opts.requested_tags.contains(&"FNumber".to_string()));
@@ -617,7 +1520,7 @@ mod tests {
         let exif_all = "-EXIF:all".to_string();
         let args = vec![&image, &file_all, &exif_all];
 
-        let (files, filter_opts) = parse_exiftool_args(args);
+        let (files, filter_opts) = parse_exiftool_args(args).unwrap();
 
         assert_eq!(files, vec!["image.jpg"]);
         assert!(filter_opts
@@ -636,7 +1539,7 @@ mod tests {
         let all_flag = "-all".to_string();
         let args = vec![&image, &all_flag];
 
-        let (files, filter_opts) = parse_exiftool_args(args);
+        let (files, filter_opts) = parse_exiftool_args(args).unwrap();
 
         assert_eq!(files, vec!["image.jpg"]);
         assert!(filter_opts.extract_all);
@@ -651,7 +1554,7 @@ mod tests {
         let fnumber_norm = "-FNumber".to_string();
         let args = vec![&image, &orientation_num, &fnumber_norm];
 
-        let (files, filter_opts) = parse_exiftool_args(args);
+        let (files, filter_opts) = parse_exiftool_args(args).unwrap();
 
         assert_eq!(files, vec!["image.jpg"]);
         assert!(filter_opts
@@ -662,20 +1565,50 @@ mod tests {
         assert!(!filter_opts.numeric_tags.contains("FNumber"));
     }
 
+    #[test]
+    fn test_parse_exiftool_args_numeric_all() {
+        let image = "image.jpg".to_string();
+        let numeric = "-n".to_string();
+        let make = "-Make".to_string();
+        let (files, filter_opts) = parse_exiftool_args(vec![&numeric, &make, &image]).unwrap();
+        assert_eq!(files, vec!["image.jpg"]);
+        assert!(filter_opts.numeric);
+        assert!(!filter_opts.extract_all);
+        assert!(filter_opts.should_use_numeric("Orientation"));
+
+        let all_numeric = "-all#".to_string();
+        let (_, filter_opts) = parse_exiftool_args(vec![&all_numeric, &image]).unwrap();
+        assert!(filter_opts.numeric);
+        assert!(filter_opts.extract_all);
+    }
+
+    #[test]
+    fn test_parse_exiftool_args_no_composites() {
+        let image = "image.jpg".to_string();
+        let (_, filter_opts) = parse_exiftool_args(vec![&image]).unwrap();
+        assert!(filter_opts.composites);
+
+        let no_composites = "-e".to_string();
+        let (files, filter_opts) = parse_exiftool_args(vec![&no_composites, &image]).unwrap();
+        assert_eq!(files, vec!["image.jpg"]);
+        assert!(!filter_opts.composites);
+        assert!(filter_opts.extract_all);
+    }
+
     #[test]
     fn test_parse_exiftool_args_edge_cases() {
         // Test with stdin marker "-"
         let dash = "-".to_string();
         let fnumber = "-FNumber".to_string();
         let args = vec![&dash, &fnumber];
-        let (files, filter_opts) = parse_exiftool_args(args);
+        let (files, filter_opts) = parse_exiftool_args(args).unwrap();
         assert_eq!(files, vec!["-"]);
         assert!(filter_opts.requested_tags.contains(&"FNumber".to_string()));
 
         // Test with no filters (should default to extract_all)
         let image = "image.jpg".to_string();
         let args = vec![&image];
-        let (files, filter_opts) = parse_exiftool_args(args);
+        let (files, filter_opts) = parse_exiftool_args(args).unwrap();
         assert_eq!(files, vec!["image.jpg"]);
         assert!(filter_opts.extract_all);
     }
@@ -690,7 +1623,7 @@ mod tests {
         let fnumber = "-FNumber".to_string();
         let args = vec![&image, &json_flag, &struct_flag, &group_flag, &fnumber];
 
-        let (files, filter_opts) = parse_exiftool_args(args);
+        let (files, filter_opts) = parse_exiftool_args(args).unwrap();
 
         // Should have only the image file, compatibility flags ignored
         assert_eq!(files, vec!["image.jpg"]);
@@ -709,7 +1642,7 @@ mod tests {
         let group_flag = "-G".to_string();
         let args = vec![&image, &json_flag, &struct_flag, &group_flag];
 
-        let (files, filter_opts) = parse_exiftool_args(args);
+        let (files, filter_opts) = parse_exiftool_args(args).unwrap();
 
         // Should have only the image file
         assert_eq!(files, vec!["image.jpg"]);
@@ -725,7 +1658,7 @@ mod tests {
         let three_char = "-abc".to_string(); // 3 chars - should be accepted
         let args = vec![&image, &three_char];
 
-        let (files, filter_opts) = parse_exiftool_args(args);
+        let (files, filter_opts) = parse_exiftool_args(args).unwrap();
 
         // Should have only the image file
         assert_eq!(files, vec!["image.jpg"]);
@@ -745,7 +1678,7 @@ mod tests {
         let valid_tag = "-MIMEType".to_string();
         let args = vec![&image, &j_flag, &struct_flag, &g_flag, &valid_tag];
 
-        let (files, filter_opts) = parse_exiftool_args(args);
+        let (files, filter_opts) = parse_exiftool_args(args).unwrap();
 
         // Should have only the image file
         assert_eq!(files, vec!["image.jpg"]);
@@ -754,4 +1687,63 @@ mod tests {
         assert!(filter_opts.requested_tags.contains(&"MIMEType".to_string()));
         assert!(!filter_opts.extract_all);
     }
+
+    #[test]
+    fn test_stay_open_args() {
+        let args = |args: &[&str]| -> Vec<String> { args.iter().map(|s| s.to_string()).collect() };
+
+        assert!(StayOpen::from_args(&args(&["exif-oxide", "image.jpg"])).is_none());
+        assert!(StayOpen::from_args(&args(&["exif-oxide", "-stay_open", "False"])).is_none());
+
+        let stay_open = StayOpen::from_args(&args(&[
+            "exif-oxide",
+            "-n",
+            "-stay_open",
+            "True",
+            "-@",
+            "-",
+            "-common_args",
+            "-G1",
+            "-@",
+        ]))
+        .unwrap();
+        assert_eq!(stay_open.argfile, "-");
+        // Everything after -common_args is common, even option names
+        assert_eq!(stay_open.common_args, ["-n", "-G1", "-@"]);
+    }
+
+    #[test]
+    fn test_tags_from_file_args() {
+        let args = |args: &[&str]| -> Vec<String> { args.iter().map(|s| s.to_string()).collect() };
+
+        assert!(TagsFromFile::from_args(&args(&["exif-oxide", "image.jpg"])).is_none());
+
+        let copy = TagsFromFile::from_args(&args(&[
+            "exif-oxide",
+            "-overwrite_original",
+            "-TagsFromFile",
+            "src.jpg",
+            "-IPTC:By-line<EXIF:Artist",
+            "--Credit",
+            "a.jpg",
+            "b.jpg",
+        ]))
+        .unwrap();
+        assert_eq!(copy.source, PathBuf::from("src.jpg"));
+        assert_eq!(copy.selectors, ["-IPTC:By-line<EXIF:Artist", "--Credit"]);
+        assert_eq!(
+            copy.dest_files,
+            [PathBuf::from("a.jpg"), PathBuf::from("b.jpg")]
+        );
+    }
+
+    #[test]
+    fn test_text_out_mode() {
+        assert_eq!(parse_text_out_mode("-w"), Some(TextOutMode::Create));
+        assert_eq!(parse_text_out_mode("-w!"), Some(TextOutMode::Overwrite));
+        assert_eq!(parse_text_out_mode("-textout+"), Some(TextOutMode::Append));
+        assert_eq!(parse_text_out_mode("-W"), None);
+        assert_eq!(parse_text_out_mode("-wm"), None);
+        assert_eq!(parse_text_out_mode("w"), None);
+    }
 }
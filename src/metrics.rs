@@ -0,0 +1,185 @@
+//! Metrics hooks
+//!
+//! Extraction reports counters and histograms to the [`MetricsRecorder`]
+//! installed with [`set_recorder`], so applications can export them
+//! (Prometheus, StatsD, ...) without patching the crate. Nothing is recorded
+//! until one is installed.
+//!
+//! | Metric                                    | Kind      | Labels                          |
+//! |-------------------------------------------|-----------|---------------------------------|
+//! | [`EXTRACTIONS_TOTAL`] (`exif_oxide_extractions_total`)   | counter   | `file_type`, `result` (`ok`, `error`) |
+//! | [`EXTRACTION_SECONDS`] (`exif_oxide_extraction_seconds`) | histogram | `file_type`                     |
+//! | [`STAGE_SECONDS`] (`exif_oxide_stage_seconds`)           | histogram | `stage` (Detection, EXIF, MakerNotes, XMP, Composites, ...) |
+//! | [`WARNINGS_TOTAL`] (`exif_oxide_warnings_total`)         | counter   | `code`                          |
+//!
+//! Failed extractions are labeled `file_type="unknown"`. Stage times include
+//! nested stages (MakerNotes runs inside EXIF), unlike the per-file
+//! [`stats`](crate::stats).
+//!
+//! The stages are also `stage` tracing spans, inside one `extract_metadata`
+//! span per file, for applications that collect traces instead.
+//!
+//! ```
+//! use exif_oxide::metrics::{self, MetricsRecorder};
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//! use std::sync::Arc;
+//!
+//! #[derive(Default)]
+//! struct ExtractionCount(AtomicU64);
+//!
+//! impl MetricsRecorder for ExtractionCount {
+//!     fn increment_counter(&self, name: &str, value: u64, _labels: &[(&str, &str)]) {
+//!         if name == metrics::EXTRACTIONS_TOTAL {
+//!             self.0.fetch_add(value, Ordering::Relaxed);
+//!         }
+//!     }
+//! }
+//!
+//! metrics::set_recorder(Some(Arc::new(ExtractionCount::default())));
+//! ```
+
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
+use crate::types::ExtractionWarning;
+
+/// Files extracted, by `file_type` and `result`
+pub const EXTRACTIONS_TOTAL: &str = "exif_oxide_extractions_total";
+
+/// Seconds per successful extraction, by `file_type`
+pub const EXTRACTION_SECONDS: &str = "exif_oxide_extraction_seconds";
+
+/// Seconds per extraction stage, by `stage`
+pub const STAGE_SECONDS: &str = "exif_oxide_stage_seconds";
+
+/// Warnings reported with extracted files, by warning `code`
+pub const WARNINGS_TOTAL: &str = "exif_oxide_warnings_total";
+
+/// Receives the metrics of every extraction in the process
+///
+/// Called on the extracting thread, so implementations should be quick
+/// (atomics, or a metrics library's handles). Both methods do nothing by
+/// default.
+pub trait MetricsRecorder: Send + Sync {
+    /// Add `value` to the counter `name`
+    fn increment_counter(&self, _name: &str, _value: u64, _labels: &[(&str, &str)]) {}
+
+    /// Record one observation of the histogram `name`
+    fn record_histogram(&self, _name: &str, _value: f64, _labels: &[(&str, &str)]) {}
+}
+
+static RECORDER: RwLock<Option<Arc<dyn MetricsRecorder>>> = RwLock::new(None);
+
+/// Install the metrics recorder, replacing any installed before
+///
+/// Pass `None` to stop recording metrics.
+pub fn set_recorder(recorder: Option<Arc<dyn MetricsRecorder>>) {
+    *RECORDER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = recorder;
+}
+
+/// The installed metrics recorder, if any
+pub fn recorder() -> Option<Arc<dyn MetricsRecorder>> {
+    RECORDER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// Times one extraction, which counts as failed unless finished
+pub(crate) struct ExtractionTimer {
+    recorder: Option<Arc<dyn MetricsRecorder>>,
+    start: Instant,
+}
+
+impl ExtractionTimer {
+    pub(crate) fn start() -> Self {
+        Self {
+            recorder: recorder(),
+            start: Instant::now(),
+        }
+    }
+
+    /// Record a successful extraction of a `file_type` file
+    pub(crate) fn finish(mut self, file_type: &str, warnings: &[ExtractionWarning]) {
+        let Some(recorder) = self.recorder.take() else {
+            return;
+        };
+        let labels = [("file_type", file_type)];
+        recorder.increment_counter(EXTRACTIONS_TOTAL, 1, &[labels[0], ("result", "ok")]);
+        recorder.record_histogram(
+            EXTRACTION_SECONDS,
+            self.start.elapsed().as_secs_f64(),
+            &labels,
+        );
+        for warning in warnings {
+            recorder.increment_counter(WARNINGS_TOTAL, 1, &[("code", &warning.code)]);
+        }
+    }
+}
+
+impl Drop for ExtractionTimer {
+    fn drop(&mut self) {
+        if let Some(recorder) = self.recorder.take() {
+            let labels = [("file_type", "unknown"), ("result", "error")];
+            recorder.increment_counter(EXTRACTIONS_TOTAL, 1, &labels);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::extract_metadata;
+    use std::sync::Mutex;
+
+    /// Metrics as strings. The recorder is process-wide, so other tests'
+    /// extractions may be recorded too
+    #[derive(Default)]
+    struct Recorded(Mutex<Vec<String>>);
+
+    impl MetricsRecorder for Recorded {
+        fn increment_counter(&self, name: &str, value: u64, labels: &[(&str, &str)]) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("{name} {labels:?} {value}"));
+        }
+
+        fn record_histogram(&self, name: &str, _value: f64, labels: &[(&str, &str)]) {
+            self.0.lock().unwrap().push(format!("{name} {labels:?}"));
+        }
+    }
+
+    #[test]
+    fn test_extraction_metrics() {
+        let dir = tempfile::tempdir().unwrap();
+        let jpeg = dir.path().join("a.jpg");
+        std::fs::write(
+            &jpeg,
+            b"\xff\xd8\xff\xe0\0\x10JFIF\0\x01\x01\0\0\x01\0\x01\0\0\xff\xd9",
+        )
+        .unwrap();
+        let missing = dir.path().join("missing.jpg");
+
+        let recorded = Arc::new(Recorded::default());
+        set_recorder(Some(recorded.clone()));
+        extract_metadata(&jpeg, false, false, None).unwrap();
+        assert!(extract_metadata(&missing, false, false, None).is_err());
+        set_recorder(None);
+
+        let recorded = recorded.0.lock().unwrap();
+        let has = |metric: &str| recorded.iter().any(|recorded| recorded == metric);
+        assert!(has(
+            r#"exif_oxide_extractions_total [("file_type", "JPEG"), ("result", "ok")] 1"#
+        ));
+        assert!(has(
+            r#"exif_oxide_extractions_total [("file_type", "unknown"), ("result", "error")] 1"#
+        ));
+        assert!(has(
+            r#"exif_oxide_extraction_seconds [("file_type", "JPEG")]"#
+        ));
+        assert!(has(r#"exif_oxide_stage_seconds [("stage", "Detection")]"#));
+    }
+}
@@ -0,0 +1,502 @@
+//! File organization helpers
+//!
+//! Ingestion pipelines file photos into folders by date and camera, the way
+//! `exiftool -d '%Y/%m/%d/%%f.%%e' '-FileName<DateTimeOriginal'` does.
+//! [`PathTemplate`] renders such names from extracted metadata, so callers
+//! don't have to re-implement ExifTool's tokens on top of our JSON:
+//!
+//! | Token                 | Expands to                                             |
+//! |-----------------------|--------------------------------------------------------|
+//! | `$Model`, `${Model}`  | the tag's printed value; `${Tag#}` for the raw value   |
+//! | `${IPTC:By-line}`     | a tag from a group (family 0 or 1)                     |
+//! | `%Y`, `%m`, `%d`, ... | strftime codes, applied to the capture date            |
+//! | `%%f`, `%%e`, `%%d`   | the original file name, extension and directory        |
+//! | `%%c`, `%%-c`, `%%+c` | a copy number, added only when the name is taken       |
+//! | `$$`, `%%`            | `$` and `%`                                            |
+//!
+//! As in ExifTool's `-d` option, the file tokens are written with a doubled
+//! `%`, since `%d` and `%e` are date codes. The capture date is
+//! Composite:BestDateTime, falling back to DateTimeOriginal, CreateDate and
+//! the file modification date. Characters that aren't allowed in file names
+//! (`/\?*:|"<>` and control characters) are removed from tag values, as
+//! ExifTool's `${Tag;}` does, so a value can't add directories.
+//!
+//! A rendered name without a directory stays in the original file's
+//! directory; relative directories are relative to the working directory.
+//!
+//! ExifTool References:
+//! - lib/Image/ExifTool.pm InsertTagValues(): `$tag` interpolation
+//! - lib/Image/ExifTool/Writer.pl SetFileName() and NextFreeTagKey(): `%c`
+//! - exiftool FilenameSPrintf(): `%f`, `%e`, `%d` and `%c`
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::NaiveDateTime;
+use tracing::debug;
+
+use crate::composite_tags::parse_date;
+use crate::types::{ExifData, ExifError, Result, TagValue};
+
+/// Sources of the capture date, in order of preference
+const DATE_SOURCES: &[(&str, &str)] = &[
+    ("Composite", "BestDateTime"),
+    ("EXIF", "DateTimeOriginal"),
+    ("EXIF", "CreateDate"),
+    ("QuickTime", "CreateDate"),
+    ("File", "FileModifyDate"),
+];
+
+/// Copy numbers tried before giving up on a name
+pub(crate) const MAX_COPY_NUMBER: u32 = 9999;
+
+/// ExifTool's `${Tag;}` filter: characters removed from tag values
+const ILLEGAL_CHARACTERS: &[char] = &['/', '\\', '?', '*', ':', '|', '"', '<', '>'];
+
+/// A token of the original file's name: `f`, `e`, `d`, `c`, `-c` or `+c`
+/// after a `%` (or the `%%` of a template)
+///
+/// Shared with [`crate::output::TextOut`], whose `-w` formats use the same tokens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum FileToken {
+    Name,
+    Extension,
+    Directory,
+    /// The copy number, with the character shown before a non-zero number
+    Copy(Option<char>),
+}
+
+impl FileToken {
+    /// The token at the start of `chars`, consumed only if there is one
+    /// ExifTool: exiftool FilenameSPrintf()
+    pub(crate) fn parse(chars: &mut std::iter::Peekable<std::str::Chars>) -> Option<Self> {
+        let mut lookahead = chars.clone();
+        let prefix = lookahead.next_if(|c| matches!(c, '-' | '+'));
+        let token = match (lookahead.next()?, prefix) {
+            ('f', None) => FileToken::Name,
+            ('e', None) => FileToken::Extension,
+            ('d', None) => FileToken::Directory,
+            ('c', prefix) => FileToken::Copy(prefix.map(|p| if p == '+' { '_' } else { p })),
+            _ => return None,
+        };
+        *chars = lookahead;
+        Some(token)
+    }
+
+    /// Append the token's text for `source` and copy number `copy` (0 for
+    /// the first, which adds nothing)
+    pub(crate) fn render(&self, source: &Path, copy: u32, rendered: &mut String) {
+        match self {
+            FileToken::Name => {
+                rendered.push_str(&source.file_stem().unwrap_or_default().to_string_lossy())
+            }
+            FileToken::Extension => {
+                rendered.push_str(&source.extension().unwrap_or_default().to_string_lossy())
+            }
+            FileToken::Directory => {
+                let dir = source.parent().unwrap_or(Path::new(""));
+                if !dir.as_os_str().is_empty() {
+                    rendered.push_str(&dir.to_string_lossy());
+                    rendered.push('/');
+                }
+            }
+            FileToken::Copy(prefix) => {
+                if copy > 0 {
+                    rendered.extend(*prefix);
+                    rendered.push_str(&copy.to_string());
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Literal(String),
+    Tag {
+        name: String,
+        raw: bool,
+    },
+    /// A strftime specification like `%Y`
+    Date(String),
+    File(FileToken),
+}
+
+/// A parsed file name template (see the module documentation)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathTemplate {
+    tokens: Vec<Token>,
+}
+
+impl PathTemplate {
+    /// Parse a template, checking its tokens
+    ///
+    /// ```
+    /// use exif_oxide::organize::PathTemplate;
+    ///
+    /// let template = PathTemplate::parse("%Y/%m/%d/${Model}_%%f%%-c.%%e")?;
+    /// # Ok::<(), exif_oxide::ExifError>(())
+    /// ```
+    pub fn parse(template: &str) -> Result<Self> {
+        let mut tokens = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let token = match (c, chars.peek().copied()) {
+                ('$', Some('$')) => {
+                    chars.next();
+                    literal.push('$');
+                    continue;
+                }
+                ('$', Some('{')) => {
+                    chars.next();
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => name.push(c),
+                            None => {
+                                return Err(ExifError::ParseError(format!(
+                                    "Unterminated '${{{name}' in file name template"
+                                )))
+                            }
+                        }
+                    }
+                    tag_token(name.trim())?
+                }
+                ('$', Some(next)) if next.is_alphanumeric() || next == '_' => {
+                    let mut name = String::new();
+                    while let Some(&c) = chars.peek() {
+                        if !(c.is_alphanumeric() || c == '_' || c == ':') {
+                            break;
+                        }
+                        name.push(c);
+                        chars.next();
+                    }
+                    if chars.next_if_eq(&'#').is_some() {
+                        name.push('#');
+                    }
+                    tag_token(&name)?
+                }
+                ('%', Some('%')) => {
+                    chars.next();
+                    match FileToken::parse(&mut chars) {
+                        Some(token) => Token::File(token),
+                        None => {
+                            literal.push('%');
+                            continue;
+                        }
+                    }
+                }
+                ('%', Some(_)) => {
+                    let mut spec = String::from('%');
+                    if let Some(flag) = chars.next_if(|c| matches!(c, '-' | '_' | '0')) {
+                        spec.push(flag);
+                    }
+                    spec.extend(chars.next());
+                    check_date_spec(&spec)?;
+                    Token::Date(spec)
+                }
+                (c, _) => {
+                    literal.push(c);
+                    continue;
+                }
+            };
+            if !literal.is_empty() {
+                tokens.push(Token::Literal(std::mem::take(&mut literal)));
+            }
+            tokens.push(token);
+        }
+        if !literal.is_empty() {
+            tokens.push(Token::Literal(literal));
+        }
+        Ok(Self { tokens })
+    }
+
+    /// Whether the template has a copy number to make names unique
+    fn has_copy_number(&self) -> bool {
+        self.tokens
+            .iter()
+            .any(|token| matches!(token, Token::File(FileToken::Copy(_))))
+    }
+
+    /// The path for a file, with the given copy number (0 for the first)
+    ///
+    /// Fails when a tag or the capture date is missing.
+    pub fn render(&self, exif_data: &ExifData, copy: u32) -> Result<PathBuf> {
+        let source = Path::new(&exif_data.source_file);
+        let mut date = None;
+        let mut rendered = String::new();
+
+        for token in &self.tokens {
+            match token {
+                Token::Literal(text) => rendered.push_str(text),
+                Token::Tag { name, raw } => {
+                    let value = tag_value(exif_data, name, *raw).ok_or_else(|| {
+                        ExifError::ParseError(format!("Tag '{name}' not defined"))
+                    })?;
+                    rendered.extend(
+                        value
+                            .chars()
+                            .filter(|c| !(c.is_control() || ILLEGAL_CHARACTERS.contains(c))),
+                    );
+                }
+                Token::Date(spec) => {
+                    let date = match date {
+                        Some(date) => date,
+                        None => *date.insert(capture_date(exif_data).ok_or_else(|| {
+                            ExifError::ParseError("No date/time for the file name".to_string())
+                        })?),
+                    };
+                    rendered.push_str(&date.format(spec).to_string());
+                }
+                Token::File(token) => token.render(source, copy, &mut rendered),
+            }
+        }
+
+        let path = PathBuf::from(rendered);
+        if path.parent().is_some_and(|dir| !dir.as_os_str().is_empty()) {
+            Ok(path)
+        } else {
+            Ok(source.parent().unwrap_or(Path::new("")).join(path))
+        }
+    }
+
+    /// The path for a file that doesn't exist yet and isn't in `reserved`
+    ///
+    /// Tries copy numbers from 0 upwards. A file whose name doesn't change
+    /// keeps it. Fails if the name is taken and the template has no copy
+    /// number, like ExifTool.
+    pub fn target(&self, exif_data: &ExifData, reserved: &HashSet<PathBuf>) -> Result<PathBuf> {
+        let source = Path::new(&exif_data.source_file);
+        for copy in 0..=MAX_COPY_NUMBER {
+            let path = self.render(exif_data, copy)?;
+            if path == source || (!path.exists() && !reserved.contains(&path)) {
+                return Ok(path);
+            }
+            if !self.has_copy_number() {
+                return Err(ExifError::IoError(format!(
+                    "'{}' already exists",
+                    path.display()
+                )));
+            }
+        }
+        Err(ExifError::IoError(format!(
+            "No free file name for '{}'",
+            source.display()
+        )))
+    }
+
+    /// The new paths for a batch of files, in order
+    ///
+    /// Names given to earlier files count as taken, so files that render to
+    /// the same name get different copy numbers.
+    pub fn plan(&self, files: &[ExifData]) -> Vec<Result<PathBuf>> {
+        let mut reserved = HashSet::new();
+        files
+            .iter()
+            .map(|exif_data| {
+                let target = self.target(exif_data, &reserved)?;
+                reserved.insert(target.clone());
+                Ok(target)
+            })
+            .collect()
+    }
+}
+
+/// A `${Tag}` token; a trailing `#` asks for the raw value
+fn tag_token(name: &str) -> Result<Token> {
+    let (name, raw) = match name.strip_suffix('#') {
+        Some(name) => (name, true),
+        None => (name, false),
+    };
+    if name.is_empty() {
+        return Err(ExifError::ParseError(
+            "Empty tag name in file name template".to_string(),
+        ));
+    }
+    Ok(Token::Tag {
+        name: name.to_string(),
+        raw,
+    })
+}
+
+/// Reject strftime codes chrono doesn't know, which would panic when used
+fn check_date_spec(spec: &str) -> Result<()> {
+    if StrftimeItems::new(spec).any(|item| matches!(item, Item::Error)) {
+        return Err(ExifError::ParseError(format!(
+            "Unknown date code '{spec}' in file name template"
+        )));
+    }
+    Ok(())
+}
+
+/// A tag's value as text, matching names case-insensitively like ExifTool
+fn tag_value(exif_data: &ExifData, name: &str, raw: bool) -> Option<String> {
+    let tag = exif_data.get_tag_exiftool_style(name).or_else(|| {
+        let (group, name) = match name.split_once(':') {
+            Some((group, name)) => (Some(group), name),
+            None => (None, name),
+        };
+        exif_data.tags.iter().find(|tag| {
+            tag.name.as_str().eq_ignore_ascii_case(name)
+                && group.is_none_or(|group| {
+                    tag.group.as_str().eq_ignore_ascii_case(group)
+                        || tag.group1.as_str().eq_ignore_ascii_case(group)
+                })
+        })
+    })?;
+    let value = if raw { &tag.value } else { tag.print() };
+    match value {
+        TagValue::String(s) => Some(s.trim().to_string()),
+        TagValue::Empty | TagValue::Binary(_) => None,
+        value => Some(value.to_string()),
+    }
+}
+
+/// The capture date, from the first source that parses
+fn capture_date(exif_data: &ExifData) -> Option<NaiveDateTime> {
+    DATE_SOURCES.iter().find_map(|(group, name)| {
+        let tag = exif_data.get_tag_by_group(group, name)?;
+        let (_, date, _) = parse_date(tag.value.as_string()?)?;
+        Some(date)
+    })
+}
+
+/// Move a file to a path from [`PathTemplate::target`], creating its directory
+///
+/// Never replaces an existing file. Falls back to copying when the target
+/// is on another file system, as ExifTool does.
+pub fn rename_file(source: &Path, target: &Path) -> Result<()> {
+    if source == target {
+        return Ok(());
+    }
+    if target.exists() {
+        return Err(ExifError::IoError(format!(
+            "'{}' already exists",
+            target.display()
+        )));
+    }
+    if let Some(dir) = target.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir)?;
+    }
+    if let Err(e) = fs::rename(source, target) {
+        debug!("Rename failed ({e}), copying {}", source.display());
+        fs::copy(source, target)?;
+        fs::remove_file(source)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{InternedStr, TagEntry};
+
+    fn exif_data(source_file: &str, tags: &[(&str, &str, TagValue)]) -> ExifData {
+        let mut data = ExifData::new(source_file.to_string(), "13.30".to_string());
+        data.tags = tags
+            .iter()
+            .map(|(group, name, value)| TagEntry {
+                group: InternedStr::from(*group),
+                group1: InternedStr::from(*group),
+                name: InternedStr::from(*name),
+                value: value.clone(),
+                print: value.clone().into(),
+                source: None,
+            })
+            .collect();
+        data
+    }
+
+    fn photo(source_file: &str) -> ExifData {
+        exif_data(
+            source_file,
+            &[
+                (
+                    "EXIF",
+                    "DateTimeOriginal",
+                    TagValue::string("2024:05:01 12:30:00"),
+                ),
+                ("EXIF", "Model", TagValue::string("Canon EOS R5 ")),
+                ("MakerNotes", "FileNumber", TagValue::string("100-0042")),
+                ("IPTC", "By-line", TagValue::string("A/B")),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_render() {
+        let render = |template: &str| {
+            PathTemplate::parse(template)
+                .unwrap()
+                .render(&photo("in/IMG_0042.CR3"), 0)
+                .unwrap()
+        };
+        assert_eq!(
+            render("%Y/%m/%d/${Model}_${FileNumber}.jpg"),
+            PathBuf::from("2024/05/01/Canon EOS R5_100-0042.jpg")
+        );
+        // No directory: stays next to the original
+        assert_eq!(
+            render("$model-%%f.%%e"),
+            PathBuf::from("in/Canon EOS R5-IMG_0042.CR3")
+        );
+        assert_eq!(
+            render("%%d%Y%m%d_%H%M ${IPTC:By-line} 100%%.x$$"),
+            PathBuf::from("in/20240501_1230 AB 100%.x$")
+        );
+    }
+
+    #[test]
+    fn test_template_errors() {
+        assert!(PathTemplate::parse("${Model").is_err());
+        assert!(PathTemplate::parse("%Q").is_err());
+
+        let no_date = exif_data("a.jpg", &[]);
+        let template = PathTemplate::parse("%Y/%%f").unwrap();
+        assert!(template.render(&no_date, 0).is_err());
+        let template = PathTemplate::parse("$Lens").unwrap();
+        assert!(template.render(&photo("a.jpg"), 0).is_err());
+    }
+
+    #[test]
+    fn test_copy_numbers() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = dir.path().join("IMG_1.JPG");
+        let second = dir.path().join("IMG_2.JPG");
+        std::fs::write(&first, b"1").unwrap();
+        std::fs::write(&second, b"2").unwrap();
+        std::fs::write(dir.path().join("20240501.JPG"), b"taken").unwrap();
+        let files = [
+            photo(first.to_str().unwrap()),
+            photo(second.to_str().unwrap()),
+        ];
+
+        let template = PathTemplate::parse("%Y%m%d%%-c.%%e").unwrap();
+        let targets: Vec<_> = template
+            .plan(&files)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            targets,
+            [
+                dir.path().join("20240501-1.JPG"),
+                dir.path().join("20240501-2.JPG")
+            ]
+        );
+
+        // Without %c a taken name is an error
+        let template = PathTemplate::parse("%Y%m%d.%%e").unwrap();
+        assert!(template.plan(&files)[0].is_err());
+
+        rename_file(&first, &targets[0]).unwrap();
+        assert!(!first.exists());
+        assert_eq!(std::fs::read(&targets[0]).unwrap(), b"1");
+        assert!(rename_file(&second, &targets[0]).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+//! EXIF Orientation helpers
+//!
+//! Orientation (IFD0 0x0112) says how the stored pixels must be transformed
+//! for display. [`display_transform`] turns it into a mirror + clockwise
+//! rotation, and `Composite:DisplayImageSize` (built in
+//! `composite_tags/orientation.rs`) is `Composite:ImageSize` after that
+//! transform. Once a caller has rotated the pixels themselves,
+//! [`clear_orientation`] makes the metadata describe the new pixels.
+
+use crate::types::{ExifData, TagValue};
+
+/// Where the Orientation value is read from, in order
+const ORIENTATION_SOURCES: &[(&str, &str)] = &[("EXIF", "Orientation"), ("XMP", "Orientation")];
+
+/// ExifTool: Exif.pm Orientation PrintConv for 1
+const NORMAL_ORIENTATION: &str = "Horizontal (normal)";
+
+/// How to transform stored pixels for display: mirror left-right first,
+/// then rotate clockwise
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OrientationTransform {
+    /// Mirror horizontally before rotating
+    pub mirror: bool,
+    /// Clockwise rotation in degrees: 0, 90, 180 or 270
+    pub rotation: u16,
+}
+
+impl OrientationTransform {
+    /// Transform for an EXIF Orientation value (1-8)
+    ///
+    /// ExifTool: Exif.pm Orientation PrintConv; 4 ("Mirror vertical") is a
+    /// horizontal mirror rotated 180°
+    pub fn from_orientation(orientation: u16) -> Option<Self> {
+        let (mirror, rotation) = match orientation {
+            1 => (false, 0),
+            2 => (true, 0),
+            3 => (false, 180),
+            4 => (true, 180),
+            5 => (true, 270),
+            6 => (false, 90),
+            7 => (true, 90),
+            8 => (false, 270),
+            _ => return None,
+        };
+        Some(Self { mirror, rotation })
+    }
+
+    /// Whether the pixels display as stored
+    pub fn is_identity(&self) -> bool {
+        !self.mirror && self.rotation == 0
+    }
+
+    /// Whether display width and height are the stored height and width
+    pub fn swaps_dimensions(&self) -> bool {
+        self.rotation % 180 == 90
+    }
+
+    /// Display size of a stored `width` × `height` image
+    pub fn display_size(&self, width: u32, height: u32) -> (u32, u32) {
+        if self.swaps_dimensions() {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+}
+
+/// The Orientation value (1-8), from EXIF or else XMP
+pub fn orientation(exif_data: &ExifData) -> Option<u16> {
+    ORIENTATION_SOURCES.iter().find_map(|&(group, name)| {
+        let value = &exif_data.get_tag_by_group(group, name)?.value;
+        value
+            .as_u16()
+            .or_else(|| value.as_string()?.trim().parse().ok())
+    })
+}
+
+/// Transform to apply for display; the identity without a valid Orientation
+pub fn display_transform(exif_data: &ExifData) -> OrientationTransform {
+    orientation(exif_data)
+        .and_then(OrientationTransform::from_orientation)
+        .unwrap_or_default()
+}
+
+/// Record that the caller has applied [`display_transform`] to the pixels
+///
+/// Every Orientation tag becomes 1 ("Horizontal (normal)") and
+/// `Composite:ImageSize` becomes the display size, so the metadata matches
+/// the transformed pixels. Only the extracted data changes; the file is
+/// untouched.
+pub fn clear_orientation(exif_data: &mut ExifData) {
+    let transform = display_transform(exif_data);
+    for tag in &mut exif_data.tags {
+        if tag.name == "Orientation" {
+            tag.value = TagValue::U16(1);
+            tag.set_print(TagValue::string(NORMAL_ORIENTATION));
+        } else if tag.group == "Composite" && tag.name == "ImageSize" {
+            if let Some((width, height)) = parse_image_size(&tag.value) {
+                let (width, height) = transform.display_size(width, height);
+                tag.value = TagValue::string(format!("{width}x{height}"));
+                tag.set_print(tag.value.clone());
+            }
+        }
+    }
+}
+
+/// Composite:ImageSize ValueConv is "W H" (PrintConv "WxH")
+pub(crate) fn parse_image_size(value: &TagValue) -> Option<(u32, u32)> {
+    let size = value.as_string()?;
+    let (width, height) = size.split_once([' ', 'x'])?;
+    let width = width.trim().parse::<f64>().ok()?;
+    let height = height.trim().parse::<f64>().ok()?;
+    Some((width as u32, height as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TagEntry;
+
+    fn entry(group: &str, name: &str, value: TagValue) -> TagEntry {
+        TagEntry {
+            group: group.into(),
+            group1: group.into(),
+            name: name.into(),
+            value: value.clone(),
+            print: value.into(),
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_orientation_transforms() {
+        let transforms: Vec<_> = (1..=8)
+            .filter_map(OrientationTransform::from_orientation)
+            .map(|t| (t.mirror, t.rotation, t.swaps_dimensions()))
+            .collect();
+        assert_eq!(
+            transforms,
+            [
+                (false, 0, false),
+                (true, 0, false),
+                (false, 180, false),
+                (true, 180, false),
+                (true, 270, true),
+                (false, 90, true),
+                (true, 90, true),
+                (false, 270, true),
+            ]
+        );
+        assert_eq!(OrientationTransform::from_orientation(9), None);
+    }
+
+    #[test]
+    fn test_clear_orientation() {
+        let mut data = ExifData::new("photo.jpg".to_string(), String::new());
+        data.tags = vec![
+            entry("EXIF", "Orientation", TagValue::U16(6)),
+            entry("XMP", "Orientation", TagValue::string("6")),
+            entry("Composite", "ImageSize", TagValue::string("6000x4000")),
+        ];
+        assert_eq!(orientation(&data), Some(6));
+        assert_eq!(display_transform(&data).rotation, 90);
+
+        clear_orientation(&mut data);
+        assert!(display_transform(&data).is_identity());
+        assert_eq!(
+            data.get_tag_by_group("XMP", "Orientation").unwrap().print(),
+            &TagValue::string("Horizontal (normal)")
+        );
+        assert_eq!(
+            data.get_tag_by_group("Composite", "ImageSize")
+                .unwrap()
+                .value,
+            TagValue::string("4000x6000")
+        );
+    }
+}
@@ -0,0 +1,34 @@
+//! JSON output (ExifTool `-j`)
+
+use std::io::{self, Write};
+
+use super::OutputSerializer;
+use crate::schema::TypedExifData;
+use crate::types::ExifData;
+
+/// Pretty-printed JSON array with one object per file
+///
+/// With `typed`, tags are written as [`TypedExifData`] so the output conforms
+/// to [`crate::schema::json_schema`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonSerializer {
+    /// Write tags typed as strings as JSON strings (CLI: `--typed`)
+    pub typed: bool,
+    /// Must match the `-n` setting the data was extracted with (for `typed`)
+    pub numeric: bool,
+}
+
+impl OutputSerializer for JsonSerializer {
+    fn write(&self, files: &[ExifData], out: &mut dyn Write) -> io::Result<()> {
+        if self.typed {
+            let typed: Vec<TypedExifData> = files
+                .iter()
+                .map(|data| TypedExifData::new(data, self.numeric))
+                .collect();
+            serde_json::to_writer_pretty(&mut *out, &typed)?;
+        } else {
+            serde_json::to_writer_pretty(&mut *out, files)?;
+        }
+        writeln!(out)
+    }
+}
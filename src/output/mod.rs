@@ -0,0 +1,95 @@
+//! Output serializers
+//!
+//! Extraction results are written as one document for all files by an
+//! [`OutputSerializer`]. The format is selected with [`OutputFormat`]:
+//!
+//! - [`JsonSerializer`]: ExifTool `-j` output (the default)
+//! - [`XmlSerializer`]: ExifTool `-X` RDF/XML output
+//! - [`YamlSerializer`]: the JSON structure as a YAML sequence
+//!
+//! Serializers expect [`ExifData`] that has been through
+//! `prepare_for_serialization` (and `apply_tag_order`, if used).
+//!
+//! CLI: `--format json|xml|yaml`, or ExifTool's `-X`/`-xmlFormat`.
+//!
+//! [`TextOut`] writes each file's document to its own file instead (`-w`).
+
+mod json;
+mod text_out;
+mod xml;
+mod yaml;
+
+pub use json::JsonSerializer;
+pub use text_out::{TextOut, TextOutMode};
+pub use xml::XmlSerializer;
+pub use yaml::YamlSerializer;
+
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use crate::types::ExifData;
+
+/// Writes extraction results for a set of files
+pub trait OutputSerializer {
+    /// Write `files` to `out` as a single document
+    fn write(&self, files: &[ExifData], out: &mut dyn Write) -> io::Result<()>;
+
+    /// Serialize `files` into a string
+    fn serialize_to_string(&self, files: &[ExifData]) -> String {
+        let mut buf = Vec::new();
+        self.write(files, &mut buf)
+            .expect("writing to a Vec<u8> can't fail");
+        String::from_utf8(buf).expect("serializers write UTF-8")
+    }
+}
+
+/// Output format selection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// JSON array of per-file objects (ExifTool `-j`)
+    #[default]
+    Json,
+    /// RDF/XML (ExifTool `-X`)
+    Xml,
+    /// YAML sequence of per-file mappings
+    Yaml,
+}
+
+impl OutputFormat {
+    /// Serializer for this format with default options
+    pub fn serializer(self) -> Box<dyn OutputSerializer> {
+        match self {
+            OutputFormat::Json => Box::new(JsonSerializer::default()),
+            OutputFormat::Xml => Box::new(XmlSerializer),
+            OutputFormat::Yaml => Box::new(YamlSerializer),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(OutputFormat::Json),
+            "xml" | "rdf" => Ok(OutputFormat::Xml),
+            "yaml" | "yml" => Ok(OutputFormat::Yaml),
+            _ => Err(format!(
+                "Unknown output format '{s}' (use json, xml or yaml)"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_output_format() {
+        assert_eq!("json".parse(), Ok(OutputFormat::Json));
+        assert_eq!("XML".parse(), Ok(OutputFormat::Xml));
+        assert_eq!("yml".parse(), Ok(OutputFormat::Yaml));
+        assert!("csv".parse::<OutputFormat>().is_err());
+    }
+}
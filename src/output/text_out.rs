@@ -0,0 +1,223 @@
+//! Per-file output files (ExifTool `-w`)
+//!
+//! `exiftool -w EXT` writes each file's output next to it instead of to
+//! stdout. [`TextOut`] takes the same argument: a plain extension (`txt`,
+//! `.json`) names `%d%f.EXT`, and anything with a `%` is a format:
+//!
+//! | Token           | Expands to                                           |
+//! |-----------------|------------------------------------------------------|
+//! | `%d`            | the source file's directory, with a trailing `/`     |
+//! | `%f`            | the source file's name without extension             |
+//! | `%e`            | the source file's extension                          |
+//! | `%c`            | a copy number, added only when the name is taken     |
+//! | `%-c`, `%+c`    | the copy number after a `-` or `_`                   |
+//! | `%%`            | `%`                                                  |
+//!
+//! As in ExifTool, existing files aren't replaced unless [`TextOutMode`] is
+//! `Overwrite` (`-w!`) or `Append` (`-w+`), and missing directories are
+//! created. ExifTool's field widths and character ranges (`%.3f`, `%:1d`)
+//! aren't supported.
+//!
+//! ExifTool Reference: exiftool script, -w option and FilenameSPrintf()
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use super::OutputSerializer;
+use crate::organize::{FileToken, MAX_COPY_NUMBER};
+use crate::types::ExifData;
+
+/// What to do when an output file already exists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TextOutMode {
+    /// Skip the file with an error, or pick a new copy number (`-w`)
+    #[default]
+    Create,
+    /// Replace the file (`-w!`)
+    Overwrite,
+    /// Add to the end of the file (`-w+`)
+    Append,
+}
+
+/// A piece of a format: a character, or a file token after `%`
+enum Part {
+    Char(char),
+    File(FileToken),
+}
+
+/// Where and how to write each file's output (see the module docs)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextOut {
+    format: String,
+    mode: TextOutMode,
+}
+
+impl TextOut {
+    /// From a `-w` argument: an extension or a format with `%` tokens
+    pub fn new(spec: &str, mode: TextOutMode) -> Self {
+        let format = if spec.contains('%') {
+            spec.to_string()
+        } else {
+            format!("%d%f.{}", spec.trim_start_matches('.'))
+        };
+        Self { format, mode }
+    }
+
+    /// What happens when an output file exists
+    pub fn mode(&self) -> TextOutMode {
+        self.mode
+    }
+
+    /// Whether the format has a copy number to make names unique
+    fn has_copy_number(&self) -> bool {
+        self.parts()
+            .iter()
+            .any(|part| matches!(part, Part::File(FileToken::Copy(_))))
+    }
+
+    /// The format's characters and file tokens, in order
+    fn parts(&self) -> Vec<Part> {
+        let mut parts = Vec::new();
+        let mut chars = self.format.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '%' {
+                if chars.next_if_eq(&'%').is_some() {
+                    parts.push(Part::Char('%'));
+                    continue;
+                }
+                if let Some(token) = FileToken::parse(&mut chars) {
+                    parts.push(Part::File(token));
+                    continue;
+                }
+            }
+            parts.push(Part::Char(c));
+        }
+        parts
+    }
+
+    /// The output path for `source` with the given copy number (0 for the
+    /// first, which adds nothing)
+    pub fn render(&self, source: &Path, copy: u32) -> PathBuf {
+        let mut rendered = String::new();
+        for part in self.parts() {
+            match part {
+                Part::Char(c) => rendered.push(c),
+                Part::File(token) => token.render(source, copy, &mut rendered),
+            }
+        }
+        PathBuf::from(rendered)
+    }
+
+    /// The output path for `source`
+    ///
+    /// In `Create` mode this is a name that doesn't exist yet, trying copy
+    /// numbers if the format has one; otherwise a taken name is an
+    /// [`io::ErrorKind::AlreadyExists`] error.
+    pub fn path(&self, source: &Path) -> io::Result<PathBuf> {
+        if self.mode != TextOutMode::Create {
+            return Ok(self.render(source, 0));
+        }
+        for copy in 0..=MAX_COPY_NUMBER {
+            let path = self.render(source, copy);
+            if !path.exists() {
+                return Ok(path);
+            }
+            if !self.has_copy_number() {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!("Output file {} already exists", path.display()),
+                ));
+            }
+        }
+        Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("No free output file name for {}", source.display()),
+        ))
+    }
+
+    /// Write one file's output with `serializer`, returning the path written
+    pub fn write(
+        &self,
+        serializer: &dyn OutputSerializer,
+        exif_data: &ExifData,
+    ) -> io::Result<PathBuf> {
+        let path = self.path(Path::new(&exif_data.source_file))?;
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let mut options = OpenOptions::new();
+        match self.mode {
+            TextOutMode::Create => options.write(true).create_new(true),
+            TextOutMode::Overwrite => options.write(true).create(true).truncate(true),
+            TextOutMode::Append => options.append(true).create(true),
+        };
+        let mut out = BufWriter::new(options.open(&path)?);
+        serializer.write(std::slice::from_ref(exif_data), &mut out)?;
+        out.flush()?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::JsonSerializer;
+
+    #[test]
+    fn test_render() {
+        let source = Path::new("photos/IMG_1234.JPG");
+        assert_eq!(
+            TextOut::new("txt", TextOutMode::Create).render(source, 0),
+            PathBuf::from("photos/IMG_1234.txt")
+        );
+        assert_eq!(
+            TextOut::new(".json", TextOutMode::Create).render(source, 0),
+            PathBuf::from("photos/IMG_1234.json")
+        );
+        let text_out = TextOut::new("out/%f_%e%-c.json", TextOutMode::Create);
+        assert_eq!(
+            text_out.render(source, 0),
+            PathBuf::from("out/IMG_1234_JPG.json")
+        );
+        assert_eq!(
+            text_out.render(source, 2),
+            PathBuf::from("out/IMG_1234_JPG-2.json")
+        );
+        assert_eq!(
+            TextOut::new("%f 100%%.%x", TextOutMode::Create).render(Path::new("a.jpg"), 0),
+            PathBuf::from("a 100%.%x")
+        );
+    }
+
+    #[test]
+    fn test_write_modes() {
+        let dir = tempfile::tempdir().unwrap();
+        let source_file = dir.path().join("photo.jpg").to_string_lossy().to_string();
+        let exif_data = ExifData::new(source_file, String::new());
+        let serializer = JsonSerializer::default();
+
+        let text_out = TextOut::new("json", TextOutMode::Create);
+        let path = text_out.write(&serializer, &exif_data).unwrap();
+        assert_eq!(path, dir.path().join("photo.json"));
+        let written = fs::read_to_string(&path).unwrap();
+        assert!(written.contains("photo.jpg"));
+        let err = text_out.write(&serializer, &exif_data).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        let text_out = TextOut::new("%d%f%+c.json", TextOutMode::Create);
+        assert_eq!(
+            text_out.write(&serializer, &exif_data).unwrap(),
+            dir.path().join("photo_1.json")
+        );
+
+        TextOut::new("json", TextOutMode::Append)
+            .write(&serializer, &exif_data)
+            .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap().len(), written.len() * 2);
+        TextOut::new("json", TextOutMode::Overwrite)
+            .write(&serializer, &exif_data)
+            .unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), written);
+    }
+}
@@ -0,0 +1,271 @@
+//! RDF/XML output (ExifTool `-X`)
+//!
+//! Mirrors the layout of `exiftool -X`: one `rdf:Description` per file, with
+//! each tag as an element in a namespace named after its group, lists as
+//! `rdf:Bag` and structures as `rdf:parseType='Resource'` elements. Binary
+//! values and strings with characters that XML can't contain are written as
+//! base64 with an `rdf:datatype` of `xsd:base64Binary`, as ExifTool does.
+//!
+//! Our tag keys carry family 0 groups, so namespaces are
+//! `http://ns.exiftool.org/<Group>/1.0/` (ExifTool's URI for a family 1 group
+//! with the same name as its family 0 group). `TagSources` isn't written.
+
+use std::io::{self, Write};
+
+use base64::prelude::{Engine, BASE64_STANDARD};
+use indexmap::IndexSet;
+use quick_xml::escape::escape;
+use serde_json::Value;
+
+use super::OutputSerializer;
+use crate::core::tag_value::BASE64_PREFIX;
+use crate::types::ExifData;
+
+const BASE64_DATATYPE: &str = "http://www.w3.org/2001/XMLSchema#base64Binary";
+
+/// Group used for tags without one and for ExifTool's own tags
+const EXIFTOOL_GROUP: &str = "ExifTool";
+
+/// ExifTool `-X` style RDF/XML
+#[derive(Debug, Clone, Copy, Default)]
+pub struct XmlSerializer;
+
+impl OutputSerializer for XmlSerializer {
+    fn write(&self, files: &[ExifData], out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "<?xml version='1.0' encoding='UTF-8'?>")?;
+        writeln!(
+            out,
+            "<rdf:RDF xmlns:rdf='http://www.w3.org/1999/02/22-rdf-syntax-ns#'>"
+        )?;
+        for data in files {
+            write_description(data, out)?;
+        }
+        writeln!(out, "</rdf:RDF>")
+    }
+}
+
+/// Split a `Group:TagName` key
+fn split_key(key: &str) -> (&str, &str) {
+    key.split_once(':').unwrap_or((EXIFTOOL_GROUP, key))
+}
+
+/// Strip the ` (n)` suffix of a duplicate tag's key, since `-a -X` writes
+/// each copy as another element with the same name
+fn element_name(name: &str) -> &str {
+    name.strip_suffix(')')
+        .and_then(|rest| rest.rsplit_once(" ("))
+        .filter(|(_, n)| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+        .map_or(name, |(name, _)| name)
+}
+
+fn write_description(data: &ExifData, out: &mut dyn Write) -> io::Result<()> {
+    // Namespaces in order of first use, as ExifTool declares them
+    let mut groups = IndexSet::new();
+    if !data.exif_tool_version.is_empty()
+        || !data.errors.is_empty()
+        || data.missing_implementations.is_some()
+    {
+        groups.insert(EXIFTOOL_GROUP);
+    }
+    for key in data.legacy_tags.keys() {
+        groups.insert(split_key(key).0);
+    }
+
+    writeln!(out)?;
+    write!(
+        out,
+        "<rdf:Description rdf:about='{}'",
+        escape(data.source_file.as_str())
+    )?;
+    write!(
+        out,
+        "\n  xmlns:et='http://ns.exiftool.org/1.0/' et:toolkit='exif-oxide {}'",
+        env!("CARGO_PKG_VERSION")
+    )?;
+    for group in &groups {
+        write!(
+            out,
+            "\n  xmlns:{group}='http://ns.exiftool.org/{group}/1.0/'"
+        )?;
+    }
+    writeln!(out, ">")?;
+
+    if !data.exif_tool_version.is_empty() {
+        let version = Value::String(data.exif_tool_version.clone());
+        write_element(out, EXIFTOOL_GROUP, "ExifToolVersion", &version, 1)?;
+    }
+    for (key, value) in &data.legacy_tags {
+        let (group, name) = split_key(key);
+        let value = serde_json::to_value(value)?;
+        write_element(out, group, element_name(name), &value, 1)?;
+    }
+    for error in &data.errors {
+        let error = Value::String(error.clone());
+        write_element(out, EXIFTOOL_GROUP, "Error", &error, 1)?;
+    }
+    if let Some(missing) = &data.missing_implementations {
+        let missing = serde_json::to_value(missing)?;
+        write_element(out, EXIFTOOL_GROUP, "MissingImplementations", &missing, 1)?;
+    }
+    writeln!(out, "</rdf:Description>")
+}
+
+/// Write `<group:name>` for `value`, recursing into lists and structures
+fn write_element(
+    out: &mut dyn Write,
+    group: &str,
+    name: &str,
+    value: &Value,
+    depth: usize,
+) -> io::Result<()> {
+    let indent = " ".repeat(depth);
+    let tag = if name == "rdf:li" {
+        name.to_string()
+    } else {
+        format!("{group}:{name}")
+    };
+    match value {
+        Value::Array(items) => {
+            writeln!(out, "{indent}<{tag}>")?;
+            writeln!(out, "{indent} <rdf:Bag>")?;
+            for item in items {
+                write_element(out, group, "rdf:li", item, depth + 2)?;
+            }
+            writeln!(out, "{indent} </rdf:Bag>")?;
+            writeln!(out, "{indent}</{tag}>")
+        }
+        Value::Object(fields) => {
+            writeln!(out, "{indent}<{tag} rdf:parseType='Resource'>")?;
+            for (field, field_value) in fields {
+                write_element(out, group, field, field_value, depth + 1)?;
+            }
+            writeln!(out, "{indent}</{tag}>")
+        }
+        Value::Null => writeln!(out, "{indent}<{tag}/>"),
+        Value::String(s) => {
+            if let Some(encoded) = s.strip_prefix(BASE64_PREFIX) {
+                writeln!(
+                    out,
+                    "{indent}<{tag} rdf:datatype='{BASE64_DATATYPE}'>{encoded}</{tag}>"
+                )
+            } else if s.chars().any(is_invalid_xml_char) {
+                let encoded = BASE64_STANDARD.encode(s.as_bytes());
+                writeln!(
+                    out,
+                    "{indent}<{tag} rdf:datatype='{BASE64_DATATYPE}'>{encoded}</{tag}>"
+                )
+            } else {
+                writeln!(out, "{indent}<{tag}>{}</{tag}>", escape(s.as_str()))
+            }
+        }
+        Value::Number(n) => writeln!(out, "{indent}<{tag}>{n}</{tag}>"),
+        Value::Bool(b) => writeln!(out, "{indent}<{tag}>{b}</{tag}>"),
+    }
+}
+
+/// Characters outside the XML 1.0 `Char` production
+fn is_invalid_xml_char(c: char) -> bool {
+    matches!(c, '\0'..='\x08' | '\x0B' | '\x0C' | '\x0E'..='\x1F' | '\u{FFFE}' | '\u{FFFF}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TagValue;
+    use quick_xml::events::Event;
+
+    #[test]
+    fn test_rdf_xml_output() {
+        let mut exif_data = ExifData::new("a&b.jpg".to_string(), "0.1".to_string());
+        let tags = [
+            ("File:FileType", TagValue::string("JPEG")),
+            ("EXIF:Make", TagValue::string("Tom's <Camera>")),
+            ("EXIF:ISO", TagValue::U16(100)),
+            (
+                "XMP:Subject",
+                TagValue::Array(vec![TagValue::string("a"), TagValue::string("b")]),
+            ),
+            ("EXIF:ThumbnailImage", TagValue::string("base64:/9j/")),
+        ];
+        for (key, value) in tags {
+            exif_data.legacy_tags.insert(key.to_string(), value);
+        }
+
+        let xml = XmlSerializer.serialize_to_string(&[exif_data]);
+        let expected = format!(
+            "<?xml version='1.0' encoding='UTF-8'?>
+<rdf:RDF xmlns:rdf='http://www.w3.org/1999/02/22-rdf-syntax-ns#'>
+
+<rdf:Description rdf:about='a&amp;b.jpg'
+  xmlns:et='http://ns.exiftool.org/1.0/' et:toolkit='exif-oxide {}'
+  xmlns:ExifTool='http://ns.exiftool.org/ExifTool/1.0/'
+  xmlns:File='http://ns.exiftool.org/File/1.0/'
+  xmlns:EXIF='http://ns.exiftool.org/EXIF/1.0/'
+  xmlns:XMP='http://ns.exiftool.org/XMP/1.0/'>
+ <ExifTool:ExifToolVersion>0.1</ExifTool:ExifToolVersion>
+ <File:FileType>JPEG</File:FileType>
+ <EXIF:Make>Tom&apos;s &lt;Camera&gt;</EXIF:Make>
+ <EXIF:ISO>100</EXIF:ISO>
+ <XMP:Subject>
+  <rdf:Bag>
+   <rdf:li>a</rdf:li>
+   <rdf:li>b</rdf:li>
+  </rdf:Bag>
+ </XMP:Subject>
+ <EXIF:ThumbnailImage rdf:datatype='http://www.w3.org/2001/XMLSchema#base64Binary'>/9j/</EXIF:ThumbnailImage>
+</rdf:Description>
+</rdf:RDF>
+",
+            env!("CARGO_PKG_VERSION")
+        );
+        assert_eq!(xml, expected);
+    }
+
+    #[test]
+    fn test_invalid_xml_chars_are_base64() {
+        let mut exif_data = ExifData::new("a.jpg".to_string(), String::new());
+        exif_data
+            .legacy_tags
+            .insert("EXIF:Artist".to_string(), TagValue::string("a\u{1}"));
+
+        let xml = XmlSerializer.serialize_to_string(&[exif_data]);
+        assert!(xml.contains(
+            " <EXIF:Artist rdf:datatype='http://www.w3.org/2001/XMLSchema#base64Binary'>YQE=</EXIF:Artist>\n"
+        ));
+        assert!(!xml.contains("xmlns:ExifTool"));
+    }
+
+    #[test]
+    fn test_duplicate_tags_are_repeated_elements() {
+        let mut exif_data = ExifData::new("a.tif".to_string(), String::new());
+        let tags = [
+            ("EXIF:ResolutionUnit", "inches"),
+            ("EXIF:ResolutionUnit (1)", "cm"),
+            ("EXIF:ResolutionUnit (2)", "None"),
+        ];
+        for (key, value) in tags {
+            exif_data
+                .legacy_tags
+                .insert(key.to_string(), TagValue::string(value));
+        }
+
+        let xml = XmlSerializer.serialize_to_string(&[exif_data]);
+        let mut reader = quick_xml::Reader::from_str(&xml);
+        let mut values = Vec::new();
+        let mut in_element = false;
+        loop {
+            match reader.read_event().expect("well-formed XML") {
+                Event::Start(e) => {
+                    in_element = e.name().as_ref() == b"EXIF:ResolutionUnit";
+                }
+                Event::Text(text) if in_element => {
+                    values.push(text.decode().unwrap().into_owned());
+                }
+                Event::End(_) => in_element = false,
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        assert_eq!(values, ["inches", "cm", "None"]);
+    }
+}
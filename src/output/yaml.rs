@@ -0,0 +1,184 @@
+//! YAML output
+//!
+//! The same structure and values as the JSON output, as a block-style
+//! sequence with one mapping per file. Strings are always double-quoted (JSON
+//! string syntax is valid YAML), so values like `"no"` or `"1.0"` can't be
+//! read back as booleans or numbers.
+
+use std::io::{self, Write};
+
+use serde_json::Value;
+
+use super::OutputSerializer;
+use crate::types::ExifData;
+
+/// YAML sequence with one mapping per file
+#[derive(Debug, Clone, Copy, Default)]
+pub struct YamlSerializer;
+
+impl OutputSerializer for YamlSerializer {
+    fn write(&self, files: &[ExifData], out: &mut dyn Write) -> io::Result<()> {
+        if files.is_empty() {
+            return writeln!(out, "[]");
+        }
+        for data in files {
+            let entries = file_entries(data)?;
+            write!(out, "- ")?;
+            write_mapping(out, entries.iter().map(|(k, v)| (k.as_str(), v)), 2, true)?;
+        }
+        Ok(())
+    }
+}
+
+/// Fields of `data` in the order of the JSON output
+fn file_entries(data: &ExifData) -> io::Result<Vec<(String, Value)>> {
+    let mut entries = vec![(
+        "SourceFile".to_string(),
+        Value::String(data.source_file.clone()),
+    )];
+    if !data.exif_tool_version.is_empty() {
+        entries.push((
+            "ExifToolVersion".to_string(),
+            Value::String(data.exif_tool_version.clone()),
+        ));
+    }
+    for (key, value) in &data.legacy_tags {
+        entries.push((key.clone(), serde_json::to_value(value)?));
+    }
+    if !data.errors.is_empty() {
+        entries.push(("errors".to_string(), serde_json::to_value(&data.errors)?));
+    }
+    if let Some(missing) = &data.missing_implementations {
+        entries.push((
+            "MissingImplementations".to_string(),
+            serde_json::to_value(missing)?,
+        ));
+    }
+    if !data.tag_sources.is_empty() {
+        entries.push((
+            "TagSources".to_string(),
+            serde_json::to_value(&data.tag_sources)?,
+        ));
+    }
+    Ok(entries)
+}
+
+/// Write mapping entries at `indent`, the first on the current line if
+/// `inline_first` (after a `- ` sequence marker)
+fn write_mapping<'a>(
+    out: &mut dyn Write,
+    entries: impl IntoIterator<Item = (&'a str, &'a Value)>,
+    indent: usize,
+    inline_first: bool,
+) -> io::Result<()> {
+    for (i, (key, value)) in entries.into_iter().enumerate() {
+        if i > 0 || !inline_first {
+            write!(out, "{:indent$}", "")?;
+        }
+        write!(out, "{}:", format_key(key))?;
+        write_node(out, value, indent + 2)?;
+    }
+    Ok(())
+}
+
+/// Write `value` after a `key:` or `-`, with nested lines at `indent`
+fn write_node(out: &mut dyn Write, value: &Value, indent: usize) -> io::Result<()> {
+    match value {
+        Value::Array(items) if items.is_empty() => writeln!(out, " []"),
+        Value::Object(fields) if fields.is_empty() => writeln!(out, " {{}}"),
+        Value::Array(items) => {
+            writeln!(out)?;
+            for item in items {
+                write!(out, "{:indent$}-", "")?;
+                match item {
+                    Value::Object(fields) if !fields.is_empty() => {
+                        write!(out, " ")?;
+                        let entries = fields.iter().map(|(k, v)| (k.as_str(), v));
+                        write_mapping(out, entries, indent + 2, true)?;
+                    }
+                    _ => write_node(out, item, indent + 2)?,
+                }
+            }
+            Ok(())
+        }
+        Value::Object(fields) => {
+            writeln!(out)?;
+            let entries = fields.iter().map(|(k, v)| (k.as_str(), v));
+            write_mapping(out, entries, indent, false)
+        }
+        Value::String(s) => writeln!(out, " {}", serde_json::to_string(s)?),
+        Value::Number(n) => writeln!(out, " {n}"),
+        Value::Bool(b) => writeln!(out, " {b}"),
+        Value::Null => writeln!(out, " null"),
+    }
+}
+
+/// Tag keys like `EXIF:Make` are valid plain scalars; quote anything else
+fn format_key(key: &str) -> String {
+    let plain = key.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+        && !key.ends_with(':')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'));
+    if plain {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TagValue;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_yaml_output() {
+        let mut exif_data = ExifData::new("a.jpg".to_string(), "0.1".to_string());
+        let contact = BTreeMap::from([("CiAdrCity".to_string(), TagValue::string("Paris"))]);
+        let tags = [
+            ("EXIF:Make", TagValue::string("no")),
+            ("EXIF:ISO", TagValue::U16(100)),
+            (
+                "XMP:Subject",
+                TagValue::Array(vec![TagValue::string("a"), TagValue::string("b")]),
+            ),
+            ("XMP:Keywords", TagValue::Array(vec![])),
+            (
+                "XMP:CreatorContactInfo",
+                TagValue::Array(vec![TagValue::Object(contact)]),
+            ),
+        ];
+        for (key, value) in tags {
+            exif_data.legacy_tags.insert(key.to_string(), value);
+        }
+        exif_data.errors.push("Bad \"IFD\"".to_string());
+
+        let yaml = YamlSerializer.serialize_to_string(&[exif_data]);
+        assert_eq!(
+            yaml,
+            r#"- SourceFile: "a.jpg"
+  ExifToolVersion: "0.1"
+  EXIF:Make: "no"
+  EXIF:ISO: 100
+  XMP:Subject:
+    - "a"
+    - "b"
+  XMP:Keywords: []
+  XMP:CreatorContactInfo:
+    - CiAdrCity: "Paris"
+  errors:
+    - "Bad \"IFD\""
+"#
+        );
+    }
+
+    #[test]
+    fn test_yaml_keys() {
+        assert_eq!(format_key("EXIF:Make"), "EXIF:Make");
+        assert_eq!(format_key("Tag Name"), "\"Tag Name\"");
+        assert_eq!(format_key("-x"), "\"-x\"");
+        assert_eq!(YamlSerializer.serialize_to_string(&[]), "[]\n");
+    }
+}
@@ -0,0 +1,42 @@
+//! Supported public API
+//!
+//! ```no_run
+//! use exif_oxide::prelude::*;
+//! use std::path::Path;
+//!
+//! let metadata = extract_metadata_with_filter(Path::new("photo.jpg"), None)?;
+//! for tag in &metadata.tags {
+//!     println!("{}: {}", tag.name, tag.print());
+//! }
+//! # Ok::<(), ExifError>(())
+//! ```
+//!
+//! Everything re-exported here follows semver. The rest of the crate is public
+//! so the CLI, tests and codegen can reach it, but may change in any release:
+//! in particular [`crate::generated`], which is regenerated from ExifTool's
+//! tables on every sync, and the format processors behind
+//! [`extract_metadata_with_filter`].
+
+pub use crate::burst::{group_bursts, Burst};
+pub use crate::composite_tags::{
+    composite_dependencies, composite_source_tags, CompositeDependencies,
+};
+pub use crate::file_detection::{
+    detect_file_type_from_bytes, FileDetectionError, FileTypeDetectionResult, FileTypeDetector,
+};
+pub use crate::formats::{
+    extract_binary_tag, extract_embedded_image, EmbeddedImage, EmbeddedImageInfo,
+};
+pub use crate::geolocation::{set_database, City, GeolocationDatabase};
+pub use crate::hash::{ImageDataHasher, ImageHashType};
+pub use crate::live_photo::is_live_photo_pair;
+pub use crate::organize::{rename_file, PathTemplate};
+pub use crate::orientation::{clear_orientation, display_transform, OrientationTransform};
+pub use crate::output::{OutputFormat, OutputSerializer};
+pub use crate::session::ExifSession;
+pub use crate::summary::FileSummary;
+pub use crate::types::{ExifData, ExifError, FilterOptions, TagEntry, TagValue};
+pub use crate::{
+    extract_metadata_formatted, extract_metadata_json, extract_metadata_json_with_filter,
+    extract_metadata_with_filter, extract_summary, init,
+};
@@ -753,6 +753,46 @@ impl DispatchRule for TableDispatchRule {
     }
 }
 
+/// Key variant of processors decoding a generated ProcessBinaryData table
+/// (`processors::binary_table`)
+pub const GENERATED_VARIANT: &str = "Generated";
+
+/// Prefers the processor of the directory's own generated table
+///
+/// Generated-table processors only accept their exact table name, so one
+/// among the candidates is more specific than the manufacturer rules' name
+/// patterns (e.g. "Tag2010e" against Sony's "Tag2010" processor).
+pub struct GeneratedTableDispatchRule;
+
+impl DispatchRule for GeneratedTableDispatchRule {
+    fn applies_to(&self, _context: &ProcessorContext) -> bool {
+        true
+    }
+
+    fn select_processor(
+        &self,
+        candidates: &[(
+            ProcessorKey,
+            Arc<dyn BinaryDataProcessor>,
+            ProcessorCapability,
+        )],
+        _context: &ProcessorContext,
+    ) -> Option<(ProcessorKey, Arc<dyn BinaryDataProcessor>)> {
+        candidates
+            .iter()
+            .find(|(key, _, _)| key.variant.as_deref() == Some(GENERATED_VARIANT))
+            .map(|(key, processor, _)| (key.clone(), processor.clone()))
+    }
+
+    fn description(&self) -> &str {
+        "Generated table processor for the exact table name"
+    }
+
+    fn priority(&self) -> u8 {
+        110 // Above the manufacturer rules
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -39,6 +39,13 @@ pub mod traits;
 pub use capability::ProcessorCapability;
 pub use context::ProcessorContext;
 pub use dispatch::DispatchRule;
+#[cfg(any(
+    feature = "canon",
+    feature = "fujifilm",
+    feature = "nikon",
+    feature = "olympus",
+    feature = "sony"
+))]
 pub use processors::*;
 pub use registry::ProcessorRegistry;
 pub use traits::{
@@ -60,108 +67,139 @@ static PROCESSOR_REGISTRY: LazyLock<ProcessorRegistry> = LazyLock::new(|| {
     registry.register_standard_processors();
 
     // Register Canon processors
-    registry.register_processor(
-        ProcessorKey::new("Canon".to_string(), "Main".to_string()),
-        CanonMainProcessor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::new("Canon".to_string(), "SerialData".to_string()),
-        CanonSerialDataProcessor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::new("Canon".to_string(), "CameraSettings".to_string()),
-        CanonCameraSettingsProcessor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::with_variant(
-            "Canon".to_string(),
-            "SerialData".to_string(),
-            "MkII".to_string(),
-        ),
-        CanonSerialDataMkIIProcessor,
-    );
+    #[cfg(feature = "canon")]
+    {
+        registry.register_processor(
+            ProcessorKey::new("Canon".to_string(), "Main".to_string()),
+            CanonMainProcessor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::new("Canon".to_string(), "SerialData".to_string()),
+            CanonSerialDataProcessor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::new("Canon".to_string(), "CameraSettings".to_string()),
+            CanonCameraSettingsProcessor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::with_variant(
+                "Canon".to_string(),
+                "SerialData".to_string(),
+                "MkII".to_string(),
+            ),
+            CanonSerialDataMkIIProcessor,
+        );
+    }
 
     // Register Nikon processors
-    registry.register_processor(
-        ProcessorKey::new("Nikon".to_string(), "EncryptedData".to_string()),
-        NikonEncryptedDataProcessor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::new("Nikon".to_string(), "AFInfo".to_string()),
-        NikonAFInfoProcessor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::new("Nikon".to_string(), "LensData".to_string()),
-        NikonLensDataProcessor,
-    );
+    #[cfg(feature = "nikon")]
+    {
+        registry.register_processor(
+            ProcessorKey::new("Nikon".to_string(), "EncryptedData".to_string()),
+            NikonEncryptedDataProcessor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::new("Nikon".to_string(), "AFInfo".to_string()),
+            NikonAFInfoProcessor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::new("Nikon".to_string(), "LensData".to_string()),
+            NikonLensDataProcessor,
+        );
+    }
 
     // Register Olympus processors
-    registry.register_processor(
-        ProcessorKey::new("Olympus".to_string(), "Equipment".to_string()),
-        OlympusEquipmentProcessor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::new("Olympus".to_string(), "CameraSettings".to_string()),
-        OlympusCameraSettingsProcessor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::new("Olympus".to_string(), "FocusInfo".to_string()),
-        OlympusFocusInfoProcessor,
-    );
+    #[cfg(feature = "olympus")]
+    {
+        registry.register_processor(
+            ProcessorKey::new("Olympus".to_string(), "Equipment".to_string()),
+            OlympusEquipmentProcessor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::new("Olympus".to_string(), "CameraSettings".to_string()),
+            OlympusCameraSettingsProcessor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::new("Olympus".to_string(), "FocusInfo".to_string()),
+            OlympusFocusInfoProcessor,
+        );
+    }
 
     // Register FujiFilm processors (demonstrates generated ProcessBinaryData table integration)
-    registry.register_processor(
-        ProcessorKey::new("FUJIFILM".to_string(), "FFMV".to_string()),
-        FujiFilmFFMVProcessor::new(),
-    );
+    #[cfg(feature = "fujifilm")]
+    {
+        registry.register_processor(
+            ProcessorKey::new("FUJIFILM".to_string(), "FFMV".to_string()),
+            FujiFilmFFMVProcessor::new(),
+        );
+    }
 
     // Register Sony processors - Critical for Sony RAW ProcessBinaryData integration
-    registry.register_processor(
-        ProcessorKey::new("Sony".to_string(), "CameraInfo".to_string()),
-        SonyCameraInfoProcessor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::new("Sony".to_string(), "CameraSettings".to_string()),
-        SonyCameraSettingsProcessor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::new("Sony".to_string(), "ShotInfo".to_string()),
-        SonyShotInfoProcessor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::new("Sony".to_string(), "Tag9050".to_string()),
-        SonyTag9050Processor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::new("Sony".to_string(), "AFInfo".to_string()),
-        SonyAFInfoProcessor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::new("Sony".to_string(), "Tag2010".to_string()),
-        SonyTag2010Processor,
-    );
-
-    registry.register_processor(
-        ProcessorKey::new("Sony".to_string(), "General".to_string()),
-        SonyGeneralProcessor,
-    );
+    #[cfg(feature = "sony")]
+    {
+        registry.register_processor(
+            ProcessorKey::new("Sony".to_string(), "CameraInfo".to_string()),
+            SonyCameraInfoProcessor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::new("Sony".to_string(), "CameraSettings".to_string()),
+            SonyCameraSettingsProcessor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::new("Sony".to_string(), "ShotInfo".to_string()),
+            SonyShotInfoProcessor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::new("Sony".to_string(), "Tag9050".to_string()),
+            SonyTag9050Processor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::new("Sony".to_string(), "AFInfo".to_string()),
+            SonyAFInfoProcessor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::new("Sony".to_string(), "Tag2010".to_string()),
+            SonyTag2010Processor,
+        );
+
+        registry.register_processor(
+            ProcessorKey::new("Sony".to_string(), "General".to_string()),
+            SonyGeneralProcessor,
+        );
+    }
+
+    // Generated ProcessBinaryData tables without a hand-written processor
+    #[cfg(any(
+        feature = "canon",
+        feature = "fujifilm",
+        feature = "nikon",
+        feature = "olympus",
+        feature = "sony"
+    ))]
+    register_generated_binary_tables(&mut registry);
 
     // Add dispatch rules for sophisticated processor selection
+    // An exact generated table beats the manufacturer rules' name patterns
+    registry.add_dispatch_rule(GeneratedTableDispatchRule);
+    #[cfg(feature = "canon")]
     registry.add_dispatch_rule(CanonDispatchRule);
+    #[cfg(feature = "nikon")]
     registry.add_dispatch_rule(NikonDispatchRule);
+    #[cfg(feature = "olympus")]
     registry.add_dispatch_rule(OlympusDispatchRule);
+    #[cfg(feature = "sony")]
     registry.add_dispatch_rule(SonyDispatchRule);
     registry.add_dispatch_rule(FormatDispatchRule);
     registry.add_dispatch_rule(TableDispatchRule);
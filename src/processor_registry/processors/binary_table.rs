@@ -0,0 +1,280 @@
+//! Generic processor for generated ProcessBinaryData tables
+//!
+//! Codegen writes every ProcessBinaryData table of a module to its
+//! `binary_tables.rs` (see [`GeneratedBinaryTable`]), and
+//! `generated::BINARY_TABLE_GROUPS` lists them all. Each table without a
+//! hand-written processor of the same name is registered here, so a directory
+//! whose table name matches is decoded straight from the generated
+//! definitions.
+//!
+//! ## ExifTool Reference
+//!
+//! lib/Image/ExifTool.pm ProcessBinaryData(). Entries are read in index
+//! order at `index × size(FORMAT)`, shifted by the Hooks and `var_` formats
+//! of earlier entries; DataMembers feed later Hooks and count expressions.
+//! Conditions aren't evaluated, so conditional tags use their default
+//! variant.
+
+use super::super::dispatch::GENERATED_VARIANT;
+use super::super::{
+    BinaryDataProcessor, ProcessorCapability, ProcessorContext, ProcessorKey, ProcessorMetadata,
+    ProcessorRegistry, ProcessorResult,
+};
+use crate::implementations::maker_table::{BinaryDataState, MakerTable};
+use crate::tiff_types::ByteOrder;
+use crate::types::{BinaryTableProc, DataMemberValue, GeneratedBinaryTable, Result};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use tracing::debug;
+
+/// Decodes one generated ProcessBinaryData table
+pub struct GeneratedBinaryDataProcessor {
+    /// ExifTool module, e.g. "Sony"
+    module: &'static str,
+    table: &'static GeneratedBinaryTable,
+}
+
+impl GeneratedBinaryDataProcessor {
+    pub fn new(module: &'static str, table: &'static GeneratedBinaryTable) -> Self {
+        Self { module, table }
+    }
+
+    /// Registry key: the module and table name
+    pub fn key(&self) -> ProcessorKey {
+        ProcessorKey::with_variant(
+            self.module.to_string(),
+            self.table.table.to_string(),
+            GENERATED_VARIANT.to_string(),
+        )
+    }
+
+    /// Whether the camera is from this table's module
+    ///
+    /// Makes are like "SONY" or "NIKON CORPORATION", so compare the start.
+    fn is_module_make(&self, context: &ProcessorContext) -> bool {
+        context.manufacturer.as_ref().is_some_and(|make| {
+            make.to_ascii_lowercase()
+                .starts_with(&self.module.to_ascii_lowercase())
+        })
+    }
+}
+
+impl BinaryDataProcessor for GeneratedBinaryDataProcessor {
+    fn can_process(&self, context: &ProcessorContext) -> ProcessorCapability {
+        // Only the exact table, bare ("Tag2010e") or qualified ("Sony:Tag2010e")
+        let matches = match context.table_name.split_once(':') {
+            Some((module, table)) => module == self.module && table == self.table.table,
+            None => context.table_name == self.table.table && self.is_module_make(context),
+        };
+        if matches {
+            ProcessorCapability::Perfect
+        } else {
+            ProcessorCapability::Incompatible
+        }
+    }
+
+    fn process_data(&self, data: &[u8], context: &ProcessorContext) -> Result<ProcessorResult> {
+        debug!(
+            "Processing {}:{} from generated table with {} bytes",
+            self.module,
+            self.table.table,
+            data.len()
+        );
+
+        let data = match self.table.proc {
+            BinaryTableProc::BinaryData => Cow::Borrowed(data),
+            #[cfg(feature = "sony")]
+            BinaryTableProc::Enciphered => Cow::Owned(crate::implementations::sony::decipher(data)),
+            #[cfg(not(feature = "sony"))]
+            BinaryTableProc::Enciphered => {
+                return Err(crate::types::ExifError::NotImplemented(
+                    "Sony deciphering needs the sony feature".to_string(),
+                ))
+            }
+        };
+
+        let maker_table = MakerTable {
+            tags: self.table.tags,
+            value_conv: self.table.value_conv,
+            print_conv: self.table.print_conv,
+        };
+        // Hooks and Formats may use $$self{Make}, $$self{Model} and the tags
+        // extracted before this block
+        let mut members: HashMap<String, DataMemberValue> = context
+            .parent_tags
+            .iter()
+            .filter_map(|(name, value)| {
+                DataMemberValue::from_tag_value(value).map(|member| (name.clone(), member))
+            })
+            .collect();
+        for (name, value) in [("Make", &context.manufacturer), ("Model", &context.model)] {
+            if let Some(value) = value {
+                members.insert(name.to_string(), DataMemberValue::String(value.clone()));
+            }
+        }
+        let mut state = BinaryDataState {
+            hooks: self.table.hooks,
+            data_members: self.table.data_members,
+            members,
+        };
+        let tags = maker_table.read_binary_entries(
+            &data,
+            context.byte_order.unwrap_or(ByteOrder::LittleEndian),
+            self.table.format,
+            &mut state,
+        );
+
+        let mut result = ProcessorResult::new();
+        for tag in tags {
+            result.add_tag_with_print_conv(tag.name.to_string(), tag.value, tag.print_conv);
+        }
+        Ok(result)
+    }
+
+    fn get_metadata(&self) -> ProcessorMetadata {
+        ProcessorMetadata::new(
+            format!("{}:{} (generated)", self.module, self.table.table),
+            "Decodes a ProcessBinaryData table from its generated definition".to_string(),
+        )
+        .with_manufacturer(self.module.to_string())
+        .with_example_condition(format!("table == '{}:{}'", self.module, self.table.table))
+    }
+}
+
+/// Register a processor for every generated table without a hand-written one
+pub fn register_generated_binary_tables(registry: &mut ProcessorRegistry) {
+    for &(module, tables) in crate::generated::BINARY_TABLE_GROUPS {
+        for table in tables {
+            let hand_written = ProcessorKey::new(module.to_string(), table.table.to_string());
+            if registry.find_processor(&hand_written).is_some() {
+                continue;
+            }
+            let processor = GeneratedBinaryDataProcessor::new(module, table);
+            registry.register_processor(processor.key(), processor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::FileFormat;
+    use crate::types::{TagInfo, TagValue};
+    use std::collections::HashMap;
+    use std::sync::LazyLock;
+
+    static TEST_TAGS: LazyLock<HashMap<u16, TagInfo>> = LazyLock::new(|| {
+        let tag = |name, format| TagInfo {
+            name,
+            format,
+            print_conv: None,
+            value_conv: None,
+            is_offset: false,
+        };
+        HashMap::from([
+            (0, tag("First", "unknown")),
+            (1, tag("Pair", "int16u[2]")),
+            (3, tag("Hooked", "unknown")),
+            (4, tag("Counted", "int16u[$val{0}]")),
+        ])
+    });
+
+    fn value_conv(_: u32, value: &TagValue, _: &mut Vec<String>) -> Result<TagValue> {
+        Ok(value.clone())
+    }
+
+    fn print_conv(
+        tag_id: u32,
+        value: &TagValue,
+        _: &mut Vec<String>,
+        _: &mut Vec<String>,
+    ) -> TagValue {
+        match tag_id {
+            0 => TagValue::String(format!("first {value}")),
+            _ => value.clone(),
+        }
+    }
+
+    static TEST_TABLE: GeneratedBinaryTable = GeneratedBinaryTable {
+        table: "TestInfo",
+        tags: &TEST_TAGS,
+        format: "int16u",
+        first_entry: 0,
+        proc: BinaryTableProc::BinaryData,
+        hooks: &[(
+            3,
+            "$varSize += 2 if $$self{Model} =~ /^TEST X1/ and $$self{NumValues} == 2",
+        )],
+        data_members: &[(0, "NumValues")],
+        value_conv,
+        print_conv,
+    };
+
+    #[test]
+    fn test_generated_table_processing() {
+        let processor = GeneratedBinaryDataProcessor::new("Test", &TEST_TABLE);
+        let mut context = ProcessorContext::new(FileFormat::Jpeg, "Test:TestInfo".to_string());
+        assert_eq!(
+            processor.can_process(&context),
+            ProcessorCapability::Perfect
+        );
+        context.table_name = "TestInfo".to_string();
+        assert_eq!(
+            processor.can_process(&context),
+            ProcessorCapability::Incompatible
+        );
+        context.manufacturer = Some("TEST CORPORATION".to_string());
+        assert_eq!(
+            processor.can_process(&context),
+            ProcessorCapability::Perfect
+        );
+
+        // Indices are in units of the table's int16u format
+        let data = [2, 0, 1, 0, 2, 0, 0xff, 0xff, 9, 0, 5, 0, 6, 0];
+        let context = context.with_byte_order(ByteOrder::LittleEndian);
+        let result = processor.process_data(&data, &context).unwrap();
+        assert_eq!(
+            result.print_convs["First"].apply(&result.extracted_tags["First"]),
+            TagValue::String("first 2".to_string())
+        );
+        assert_eq!(
+            result.extracted_tags["Pair"],
+            TagValue::U16Array(vec![1, 2])
+        );
+        assert_eq!(result.extracted_tags["Hooked"], TagValue::U16(0xffff));
+        assert_eq!(
+            result.extracted_tags["Counted"],
+            TagValue::U16Array(vec![9, 5])
+        );
+
+        // The Hook skips the padding for this model, moving every later entry
+        let context = context.with_model("TEST X1".to_string());
+        let result = processor.process_data(&data, &context).unwrap();
+        assert_eq!(result.extracted_tags["Hooked"], TagValue::U16(9));
+        assert_eq!(
+            result.extracted_tags["Counted"],
+            TagValue::U16Array(vec![5, 6])
+        );
+    }
+
+    #[test]
+    fn test_generated_tables_are_registered() {
+        let registry = crate::processor_registry::get_global_registry();
+        for &(module, tables) in crate::generated::BINARY_TABLE_GROUPS {
+            for table in tables {
+                let generated = ProcessorKey::with_variant(
+                    module.to_string(),
+                    table.table.to_string(),
+                    GENERATED_VARIANT.to_string(),
+                );
+                let hand_written = ProcessorKey::new(module.to_string(), table.table.to_string());
+                assert!(
+                    registry.find_processor(&generated).is_some()
+                        || registry.find_processor(&hand_written).is_some(),
+                    "{module}:{} has no processor",
+                    table.table
+                );
+            }
+        }
+    }
+}
@@ -12,6 +12,7 @@ use super::super::{
     BinaryDataProcessor, ProcessorCapability, ProcessorContext, ProcessorMetadata, ProcessorResult,
 };
 use crate::implementations::canon;
+#[cfg(feature = "nikon")]
 use crate::implementations::canon::tags::get_canon_tag_name;
 use crate::types::{Result, TagValue};
 use std::collections::HashMap;
@@ -78,7 +79,8 @@ impl BinaryDataProcessor for CanonSerialDataProcessor {
             Ok(tags) => {
                 // Convert HashMap<String, TagValue> to ProcessorResult format
                 for (tag_name, tag_value) in tags {
-                    result.add_tag(tag_name, tag_value);
+                    let print_conv = canon::binary_data::camera_settings_print_conv(&tag_name);
+                    result.add_tag_with_print_conv(tag_name, tag_value, print_conv);
                 }
 
                 debug!(
@@ -163,7 +165,8 @@ impl BinaryDataProcessor for CanonCameraSettingsProcessor {
         )?;
 
         for (tag_name, tag_value) in extracted_tags {
-            result.add_tag(tag_name, tag_value);
+            let print_conv = canon::binary_data::camera_settings_print_conv(&tag_name);
+            result.add_tag_with_print_conv(tag_name, tag_value, print_conv);
         }
 
         if result.extracted_tags.is_empty() {
@@ -393,6 +396,8 @@ impl BinaryDataProcessor for CanonMainProcessor {
 ///     crate::generated::Canon_pm::process_subdirectory
 /// )?;
 /// ```
+// Reads entries with Nikon's IFD value extractor
+#[cfg(feature = "nikon")]
 #[allow(dead_code)]
 fn extract_makernotes_via_tag_kit(
     data: &[u8],
@@ -13,18 +13,46 @@
 //!
 //! - **Canon processors**: Delegate to `implementations::canon` modules
 //! - **Nikon processors**: Delegate to `implementations::nikon` modules
+//! - **Generated tables**: `binary_table` decodes every other generated
+//!   ProcessBinaryData table
 //! - **Capability assessment**: Model-specific and context-aware evaluation
 //! - **Parameter passing**: Rich context through ProcessorContext system
 
+#[cfg(any(
+    feature = "canon",
+    feature = "fujifilm",
+    feature = "nikon",
+    feature = "olympus",
+    feature = "sony"
+))]
+pub mod binary_table;
+#[cfg(feature = "canon")]
 pub mod canon;
+#[cfg(feature = "fujifilm")]
 pub mod fujifilm;
+#[cfg(feature = "nikon")]
 pub mod nikon;
+#[cfg(feature = "olympus")]
 pub mod olympus;
+#[cfg(feature = "sony")]
 pub mod sony;
 
 // Re-export processor implementations
+#[cfg(any(
+    feature = "canon",
+    feature = "fujifilm",
+    feature = "nikon",
+    feature = "olympus",
+    feature = "sony"
+))]
+pub use binary_table::*;
+#[cfg(feature = "canon")]
 pub use canon::*;
+#[cfg(feature = "fujifilm")]
 pub use fujifilm::*;
+#[cfg(feature = "nikon")]
 pub use nikon::*;
+#[cfg(feature = "olympus")]
 pub use olympus::*;
+#[cfg(feature = "sony")]
 pub use sony::*;
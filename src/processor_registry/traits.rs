@@ -3,7 +3,7 @@
 //! This module defines the fundamental traits that enable ExifTool's sophisticated
 //! processor dispatch system while maintaining Rust's type safety and performance.
 
-use crate::types::{Result, TagValue};
+use crate::types::{DeferredPrintConv, Result, TagValue};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -70,6 +70,9 @@ pub struct ProcessorResult {
     /// Maps tag names to their extracted values
     pub extracted_tags: HashMap<String, TagValue>,
 
+    /// Table PrintConvs of extracted tags, applied when the printed value is read
+    pub print_convs: HashMap<String, DeferredPrintConv>,
+
     /// Warnings generated during processing
     /// Used for non-fatal issues like data corruption or unexpected formats
     pub warnings: Vec<String>,
@@ -85,6 +88,7 @@ impl ProcessorResult {
     pub fn new() -> Self {
         Self {
             extracted_tags: HashMap::new(),
+            print_convs: HashMap::new(),
             warnings: Vec::new(),
             next_processors: Vec::new(),
         }
@@ -94,6 +98,7 @@ impl ProcessorResult {
     pub fn with_tags(extracted_tags: HashMap<String, TagValue>) -> Self {
         Self {
             extracted_tags,
+            print_convs: HashMap::new(),
             warnings: Vec::new(),
             next_processors: Vec::new(),
         }
@@ -109,6 +114,19 @@ impl ProcessorResult {
         self.extracted_tags.insert(name, value);
     }
 
+    /// Add a tag whose table PrintConv is deferred until its printed value is read
+    pub fn add_tag_with_print_conv(
+        &mut self,
+        name: String,
+        value: TagValue,
+        print_conv: Option<DeferredPrintConv>,
+    ) {
+        if let Some(print_conv) = print_conv {
+            self.print_convs.insert(name.clone(), print_conv);
+        }
+        self.add_tag(name, value);
+    }
+
     /// Add a nested processor for recursive processing
     pub fn add_nested_processor(&mut self, key: ProcessorKey, context: ProcessorContext) {
         self.next_processors.push((key, context));
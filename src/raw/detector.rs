@@ -33,11 +33,14 @@ pub enum RawFormat {
     /// ExifTool: lib/Image/ExifTool/Sony.pm - Complex format with encryption, IDC corruption handling, and 139 ProcessBinaryData sections
     Sony,
 
+    /// FujiFilm RAW format (RAF)
+    /// ExifTool: lib/Image/ExifTool/FujiFilm.pm - Big-endian header pointing to an embedded JPEG and RAF directories
+    Fujifilm,
+
     /// Unknown or unsupported RAW format
     Unknown,
     // Future formats will be added here as we implement them:
     // Nikon,     // NEF, NRW formats
-    // Fujifilm,  // RAF format
 }
 
 impl RawFormat {
@@ -50,6 +53,7 @@ impl RawFormat {
             RawFormat::Olympus => "Olympus",
             RawFormat::Canon => "Canon",
             RawFormat::Sony => "Sony",
+            RawFormat::Fujifilm => "FujiFilm",
             RawFormat::Unknown => "Unknown",
         }
     }
@@ -103,9 +107,14 @@ pub fn detect_raw_format(detection_result: &FileTypeDetectionResult) -> RawForma
         return RawFormat::Sony;
     }
 
+    // Check for FujiFilm RAF format
+    // ExifTool: FujiFilm.pm ProcessRAF - 'FUJIFILM' magic
+    if detection_result.file_type == "RAF" {
+        return RawFormat::Fujifilm;
+    }
+
     // Future format detection will be added here:
     // if detection_result.file_type == "NEF" || detection_result.file_type == "NRW" { return RawFormat::Nikon; }
-    // if detection_result.file_type == "RAF" { return RawFormat::Fujifilm; }
 
     RawFormat::Unknown
 }
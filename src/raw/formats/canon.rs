@@ -162,9 +162,9 @@ impl CanonRawHandler {
     fn process_crw(&mut self, _exif_reader: &mut ExifReader) -> Result<()> {
         debug!("Processing Canon CRW format (legacy)");
 
-        // TODO: Implement CRW processing
-        // CRW files have a custom structure, not TIFF-based
-        debug!("CRW format processing not yet implemented");
+        // CRW files have a custom heap structure, not TIFF-based. Walking the
+        // heap needs the file data, so it happens in RawFormatHandler::process_raw
+        // via canon_raw::process_crw()
         Ok(())
     }
 
@@ -179,6 +179,16 @@ impl CanonRawHandler {
         Ok(())
     }
 
+    /// Format of `data`, letting a CIFF signature override the configured format
+    /// The RAW processor registers a single Canon handler for CR2, CRW and CR3
+    fn format_of(&self, data: &[u8]) -> CanonFormat {
+        if super::canon_raw::is_ciff(data) {
+            CanonFormat::CRW
+        } else {
+            self.format
+        }
+    }
+
     /// Auto-detect Canon format from data
     /// ExifTool: Canon.pm format detection based on magic bytes and structure
     #[allow(dead_code)]
@@ -196,11 +206,11 @@ impl CanonRawHandler {
         }
 
         // Check for CRW magic bytes
-        // ExifTool: Canon.pm CRW files have specific header structure
-        if data.len() >= 16 {
-            // CRW files start with specific patterns
-            // TODO: Add CRW magic detection when we implement CRW support
-            debug!("No TIFF magic found - format detection incomplete");
+        // ExifTool: CanonRaw.pm ProcessCRW HEAP signature
+        if super::canon_raw::is_ciff(data) {
+            debug!("Detected CIFF heap signature - CRW format");
+            self.format = CanonFormat::CRW;
+            return Ok(());
         }
 
         // Default to CR2 if we can't determine format
@@ -214,9 +224,10 @@ impl RawFormatHandler for CanonRawHandler {
     /// Process Canon RAW data
     /// ExifTool: Canon.pm main processing entry point
     fn process_raw(&self, reader: &mut ExifReader, data: &[u8]) -> Result<()> {
-        debug!("Processing Canon RAW format: {}", self.format.name());
+        let format = self.format_of(data);
+        debug!("Processing Canon RAW format: {}", format.name());
 
-        match self.format {
+        match format {
             CanonFormat::CR2 => {
                 debug!("Processing Canon CR2 format data ({} bytes)", data.len());
 
@@ -234,9 +245,9 @@ impl RawFormatHandler for CanonRawHandler {
                 Ok(())
             }
             CanonFormat::CRW => {
-                // TODO: Implement CRW processing
-                debug!("CRW format processing not yet implemented");
-                Ok(())
+                // ExifTool: CanonRaw.pm ProcessCRW() walks the CIFF heap
+                debug!("Processing Canon CRW format data ({} bytes)", data.len());
+                super::canon_raw::process_crw(reader, data)
             }
             CanonFormat::CR3 => {
                 // TODO: Implement CR3 processing
@@ -254,7 +265,7 @@ impl RawFormatHandler for CanonRawHandler {
     /// Validate Canon format data
     /// ExifTool: Canon.pm format validation logic
     fn validate_format(&self, data: &[u8]) -> bool {
-        match self.format {
+        match self.format_of(data) {
             CanonFormat::CR2 => {
                 // CR2 files are TIFF-based - validate TIFF header
                 if data.len() < 8 {
@@ -266,12 +277,8 @@ impl RawFormatHandler for CanonRawHandler {
 
                 is_tiff_be || is_tiff_le
             }
-            CanonFormat::CRW => {
-                // TODO: Implement CRW validation
-                // For now, accept any data for CRW
-                debug!("CRW validation not yet implemented - accepting all data");
-                true
-            }
+            // ExifTool: CanonRaw.pm ProcessCRW `$sig =~ /^HEAP(CCDR|JPGM)/`
+            CanonFormat::CRW => super::canon_raw::is_ciff(data),
             CanonFormat::CR3 => {
                 // TODO: Implement CR3 validation
                 // For now, accept any data for CR3
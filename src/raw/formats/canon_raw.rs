@@ -0,0 +1,650 @@
+//! Canon CRW (CIFF) heap processing
+//!
+//! CRW files are a heap of blocks. The last 4 bytes of each block point at
+//! its directory, and each directory entry either holds a small value inline,
+//! points at value data within the block, or points at a nested heap block.
+//!
+//! Primary ExifTool References:
+//! - lib/Image/ExifTool/CanonRaw.pm ProcessCRW() / ProcessCanonRaw()
+//! - lib/Image/ExifTool/CanonRaw.pm %CanonRaw::Main and its binary sub-tables
+
+use crate::exif::ExifReader;
+use crate::generated::CanonRaw_pm::crw_tag_format::lookup_crw_tag_format;
+use crate::generated::CanonRaw_pm::{
+    exposure_info_tags, flash_info_tags, image_format_tags, image_info_tags, main_tags,
+    raw_jpg_info_tags, time_stamp_tags,
+};
+use crate::implementations::canon::{
+    camera_settings_print_conv, extract_camera_settings, extract_focal_length, extract_shot_info,
+};
+use crate::tiff_types::ByteOrder;
+use crate::types::{
+    DeferredPrintConv, ExifError, Result, TablePrintConvFn, TagSourceInfo, TagValue,
+};
+use std::collections::HashMap;
+use tracing::debug;
+
+/// First synthetic tag ID for CIFF tags (clear of the Exif::Main IDs)
+const CIFF_SYNTHETIC_BASE: u16 = 0xF800;
+
+/// Values larger than this are only read for embedded images
+/// ExifTool: CanonRaw.pm ProcessCanonRaw `$size <= 512`
+const MAX_VALUE_SIZE: usize = 512;
+
+/// Heap nesting limit, guarding against directories that point at themselves
+const MAX_NESTING: usize = 20;
+
+/// One ProcessBinaryData entry: index, name and format override
+type BinaryEntry = (usize, &'static str, Option<&'static str>);
+
+/// Generated table conversion functions, keyed by tag ID (the table index)
+type ValueConvFn = fn(u32, &TagValue, &mut Vec<String>) -> Result<TagValue>;
+
+/// ExifTool: CanonRaw.pm %CanonRaw::ImageFormat (FORMAT => 'int32u')
+const IMAGE_FORMAT: &[BinaryEntry] = &[
+    (0, "FileFormat", None),
+    (1, "TargetCompressionRatio", Some("float")),
+];
+
+/// ExifTool: CanonRaw.pm %CanonRaw::TimeStamp (FORMAT => 'int32u')
+const TIME_STAMP: &[BinaryEntry] = &[
+    (0, "DateTimeOriginal", None),
+    (1, "TimeZoneCode", Some("int32s")),
+    (2, "TimeZoneInfo", None),
+];
+
+/// ExifTool: CanonRaw.pm %CanonRaw::ImageInfo (FORMAT => 'int32u')
+const IMAGE_INFO: &[BinaryEntry] = &[
+    (0, "ImageWidth", None),
+    (1, "ImageHeight", None),
+    (2, "PixelAspectRatio", Some("float")),
+    (3, "Rotation", Some("int32s")),
+    (4, "ComponentBitDepth", None),
+    (5, "ColorBitDepth", None),
+    (6, "ColorBW", None),
+];
+
+/// ExifTool: CanonRaw.pm %CanonRaw::FlashInfo (FORMAT => 'float')
+const FLASH_INFO: &[BinaryEntry] = &[(0, "FlashGuideNumber", None), (1, "FlashThreshold", None)];
+
+/// ExifTool: CanonRaw.pm %CanonRaw::ExposureInfo (FORMAT => 'float')
+const EXPOSURE_INFO: &[BinaryEntry] = &[
+    (0, "ExposureCompensation", None),
+    (1, "ShutterSpeedValue", None),
+    (2, "ApertureValue", None),
+];
+
+/// ExifTool: CanonRaw.pm %CanonRaw::RawJpgInfo (FORMAT => 'int16u', FIRST_ENTRY => 1)
+const RAW_JPG_INFO: &[BinaryEntry] = &[
+    (1, "RawJpgQuality", None),
+    (2, "RawJpgSize", None),
+    (3, "RawJpgWidth", None),
+    (4, "RawJpgHeight", None),
+];
+
+/// Check for the CIFF header signature
+/// ExifTool: CanonRaw.pm ProcessCRW `$sig =~ /^HEAP(CCDR|JPGM)/`
+pub fn is_ciff(data: &[u8]) -> bool {
+    data.len() >= 14
+        && (data.starts_with(b"II") || data.starts_with(b"MM"))
+        && &data[6..10] == b"HEAP"
+        && matches!(&data[10..14], b"CCDR" | b"JPGM")
+}
+
+/// Process a CRW file, storing its tags in `reader`
+/// ExifTool: CanonRaw.pm ProcessCRW()
+pub fn process_crw(reader: &mut ExifReader, data: &[u8]) -> Result<()> {
+    if !is_ciff(data) {
+        return Err(ExifError::ParseError(
+            "Not a CRW file (missing HEAP signature)".to_string(),
+        ));
+    }
+    let byte_order = if data.starts_with(b"II") {
+        ByteOrder::LittleEndian
+    } else {
+        ByteOrder::BigEndian
+    };
+    let header_len = byte_order.read_u32(data, 2)? as usize;
+
+    let mut parser = CiffParser {
+        data,
+        byte_order,
+        tags: Vec::new(),
+        warnings: Vec::new(),
+    };
+    // The root block runs from the end of the header to the end of the file
+    if !parser.process_heap(header_len, data.len().saturating_sub(header_len), "CRW", 0) {
+        parser.warnings.push("CRW file format error".to_string());
+    }
+
+    debug!("Extracted {} CRW tags", parser.tags.len());
+    reader.warnings.extend(parser.warnings);
+    store_tags(reader, parser.tags);
+    Ok(())
+}
+
+/// Store CIFF tags under synthetic IDs, reusing the ID of a repeated tag so
+/// the first occurrence wins
+fn store_tags(reader: &mut ExifReader, tags: Vec<CiffTag>) {
+    let mut ids: HashMap<(&'static str, String), u16> = HashMap::new();
+    for tag in tags {
+        let next_id = CIFF_SYNTHETIC_BASE + ids.len() as u16;
+        let id = *ids.entry((tag.group1, tag.name.clone())).or_insert(next_id);
+        reader
+            .synthetic_tag_names
+            .insert(id, format!("MakerNotes:{}", tag.name));
+        // ExifTool: CanonRaw tables are Group1 'CanonRaw'; Canon sub-tables keep 'Canon'
+        let source_info = TagSourceInfo::new(
+            "MakerNotes".to_string(),
+            tag.group1.to_string(),
+            "CanonRaw".to_string(),
+        )
+        .with_print_conv(tag.print_conv);
+        reader.store_tag_with_precedence(id, tag.value, source_info);
+    }
+}
+
+/// A tag found while walking the heap
+struct CiffTag {
+    group1: &'static str,
+    name: String,
+    value: TagValue,
+    /// Table PrintConv, applied when the printed value is read
+    print_conv: Option<DeferredPrintConv>,
+}
+
+/// CIFF heap walker
+/// ExifTool: CanonRaw.pm ProcessCanonRaw()
+struct CiffParser<'a> {
+    data: &'a [u8],
+    byte_order: ByteOrder,
+    tags: Vec<CiffTag>,
+    warnings: Vec<String>,
+}
+
+impl CiffParser<'_> {
+    /// Process one heap block, returning false if its directory can't be read
+    fn process_heap(
+        &mut self,
+        block_start: usize,
+        block_size: usize,
+        dir_name: &str,
+        nesting: usize,
+    ) -> bool {
+        if nesting > MAX_NESTING {
+            debug!("CRW heap nesting too deep in {}", dir_name);
+            return true;
+        }
+        let Some(block_end) = block_start
+            .checked_add(block_size)
+            .filter(|&end| end <= self.data.len() && block_size >= 4)
+        else {
+            return false;
+        };
+
+        // 4 bytes at end of block give directory position within block
+        let Ok(dir_offset) = self.byte_order.read_u32(self.data, block_end - 4) else {
+            return false;
+        };
+        let dir_offset = block_start + dir_offset as usize;
+        let Ok(entries) = self.byte_order.read_u16(self.data, dir_offset) else {
+            return false;
+        };
+        if dir_offset + 2 + 10 * entries as usize > self.data.len() {
+            return false;
+        }
+        debug!("CIFF directory {} with {} entries", dir_name, entries);
+
+        for index in 0..entries as usize {
+            let pt = dir_offset + 2 + 10 * index;
+            // Bounds were checked against the directory size above
+            let tag = self.byte_order.read_u16(self.data, pt).unwrap_or(0);
+            let size = self.byte_order.read_u32(self.data, pt + 2).unwrap_or(0) as usize;
+            let value_ptr = self.byte_order.read_u32(self.data, pt + 6).unwrap_or(0) as usize;
+            // All pointers are relative to the block start
+            let ptr = value_ptr + block_start;
+
+            if tag & 0x8000 != 0 {
+                self.warnings.push("Bad CRW directory entry".to_string());
+                return true;
+            }
+            let tag_id = tag & 0x3fff;
+            let tag_type = ((tag >> 8) & 0x38) as u8;
+            let value_in_dir = tag & 0x4000 != 0;
+            let tag_info = main_tags::get_tag_info_with_context(tag_id, dir_name);
+
+            if (tag_type == 0x28 || tag_type == 0x30) && !value_in_dir {
+                // This type of tag specifies a raw subdirectory
+                let name = tag_info
+                    .map(|info| info.name.to_string())
+                    .unwrap_or_else(|| format!("CanonRaw_0x{tag:04x}"));
+                self.process_heap(ptr, size, &name, nesting + 1);
+                continue;
+            }
+
+            // Don't extract information from unknown tags
+            let Some(tag_info) = tag_info else {
+                continue;
+            };
+
+            let value = if value_in_dir {
+                // The value is stored in the 'size' and 'ptr' fields
+                &self.data[pt + 2..pt + 10]
+            } else if size <= MAX_VALUE_SIZE || matches!(tag_id, 0x2007 | 0x2008) {
+                match self.data.get(ptr..ptr.saturating_add(size)) {
+                    Some(value) => value,
+                    None => {
+                        self.warnings
+                            .push(format!("Error reading {size} bytes from 0x{ptr:x}"));
+                        continue;
+                    }
+                }
+            } else {
+                debug!("Skipping {} ({} bytes of binary data)", tag_info.name, size);
+                continue;
+            };
+
+            self.process_entry(tag_id, tag_info.name, tag_type, value, value_in_dir);
+        }
+        true
+    }
+
+    /// Extract one directory entry, dispatching sub-tables
+    /// ExifTool: CanonRaw.pm %CanonRaw::Main SubDirectory entries
+    fn process_entry(
+        &mut self,
+        tag_id: u16,
+        name: &'static str,
+        tag_type: u8,
+        value: &[u8],
+        value_in_dir: bool,
+    ) {
+        let byte_order = self.byte_order;
+        match tag_id {
+            // NullRecord, FreeBytes and RawData carry no metadata
+            0x0000 | 0x0001 | 0x2005 => {}
+            0x080a => self.process_make_model(value),
+            0x1029 => self.add_canon_tags(
+                extract_focal_length(value, 0, value.len(), byte_order),
+                |_| None,
+            ),
+            0x102a => self
+                .add_canon_tags(extract_shot_info(value, 0, value.len(), byte_order), |_| {
+                    None
+                }),
+            0x102d => self.add_canon_tags(
+                extract_camera_settings(value, 0, value.len(), byte_order),
+                camera_settings_print_conv,
+            ),
+            0x10b5 => self.process_binary(
+                value,
+                "int16u",
+                RAW_JPG_INFO,
+                raw_jpg_info_tags::apply_value_conv,
+                raw_jpg_info_tags::apply_print_conv,
+            ),
+            0x1803 => self.process_binary(
+                value,
+                "int32u",
+                IMAGE_FORMAT,
+                image_format_tags::apply_value_conv,
+                image_format_tags::apply_print_conv,
+            ),
+            0x180e => self.process_binary(
+                value,
+                "int32u",
+                TIME_STAMP,
+                time_stamp_value_conv,
+                time_stamp_print_conv,
+            ),
+            0x1810 => self.process_binary(
+                value,
+                "int32u",
+                IMAGE_INFO,
+                image_info_tags::apply_value_conv,
+                image_info_tags::apply_print_conv,
+            ),
+            0x1813 => self.process_binary(
+                value,
+                "float",
+                FLASH_INFO,
+                flash_info_tags::apply_value_conv,
+                flash_info_tags::apply_print_conv,
+            ),
+            0x1818 => self.process_binary(
+                value,
+                "float",
+                EXPOSURE_INFO,
+                exposure_info_tags::apply_value_conv,
+                exposure_info_tags::apply_print_conv,
+            ),
+            // Sub-tables without a translation yet (colour data, AF, custom functions)
+            0x0032 | 0x102c | 0x1030 | 0x1031 | 0x1033 | 0x1038 | 0x1093 | 0x10a9 | 0x1835 => {
+                debug!("Skipping unsupported CRW subdirectory {}", name);
+            }
+            // JpgFromRaw, ThumbnailImage
+            0x2007 | 0x2008 => self.add("CanonRaw", name, TagValue::Binary(value.to_vec())),
+            _ => {
+                let info = main_tags::CANON_RAW_MAIN_TAGS.get(&tag_id);
+                let format = info
+                    .map(|info| info.format)
+                    .filter(|&format| format != "unknown")
+                    .or_else(|| lookup_crw_tag_format(tag_type));
+                let raw = match format {
+                    Some(format) => {
+                        // Values in the directory are single values unless they are strings
+                        let count = if value_in_dir && format != "string" {
+                            1
+                        } else {
+                            value.len() / format_size(format)
+                        };
+                        read_value(value, format, count, byte_order)
+                    }
+                    None => TagValue::Binary(value.to_vec()),
+                };
+                let value = apply_value_conv(main_tags::apply_value_conv, tag_id as u32, raw);
+                let print_conv = DeferredPrintConv::new(main_tags::apply_print_conv, tag_id as u32);
+                self.add_with_print_conv("CanonRaw", name, value, Some(print_conv));
+            }
+        }
+    }
+
+    /// ExifTool: CanonRaw.pm %CanonRaw::MakeModel (Make string[6], Model string)
+    fn process_make_model(&mut self, value: &[u8]) {
+        if value.len() < 6 {
+            return;
+        }
+        self.add(
+            "CanonRaw",
+            "Make",
+            TagValue::String(read_string(&value[..6])),
+        );
+        self.add(
+            "CanonRaw",
+            "Model",
+            TagValue::String(read_string(&value[6..])),
+        );
+    }
+
+    /// Process a CanonRaw ProcessBinaryData sub-table
+    fn process_binary(
+        &mut self,
+        value: &[u8],
+        table_format: &'static str,
+        entries: &[BinaryEntry],
+        value_conv: ValueConvFn,
+        print_conv: TablePrintConvFn,
+    ) {
+        let increment = format_size(table_format);
+        for &(index, name, format) in entries {
+            let format = format.unwrap_or(table_format);
+            let offset = index * increment;
+            let Some(bytes) = value.get(offset..offset + format_size(format)) else {
+                break;
+            };
+            let raw = read_value(bytes, format, 1, self.byte_order);
+            let value = apply_value_conv(value_conv, index as u32, raw);
+            let print_conv = DeferredPrintConv::new(print_conv, index as u32);
+            self.add_with_print_conv("CanonRaw", name, value, Some(print_conv));
+        }
+    }
+
+    /// Add tags decoded by a Canon maker note sub-table, with the PrintConv
+    /// `print_conv` gives each
+    fn add_canon_tags(
+        &mut self,
+        result: Result<HashMap<String, TagValue>>,
+        print_conv: impl Fn(&str) -> Option<DeferredPrintConv>,
+    ) {
+        match result {
+            Ok(tags) => {
+                for (key, value) in tags {
+                    let name = key.strip_prefix("MakerNotes:").unwrap_or(&key);
+                    let print_conv = print_conv(name);
+                    self.add_with_print_conv("Canon", name, value, print_conv);
+                }
+            }
+            Err(e) => debug!("Failed to extract Canon sub-table from CRW: {}", e),
+        }
+    }
+
+    fn add(&mut self, group1: &'static str, name: &str, value: TagValue) {
+        self.add_with_print_conv(group1, name, value, None);
+    }
+
+    fn add_with_print_conv(
+        &mut self,
+        group1: &'static str,
+        name: &str,
+        value: TagValue,
+        print_conv: Option<DeferredPrintConv>,
+    ) {
+        self.tags.push(CiffTag {
+            group1,
+            name: name.to_string(),
+            value,
+            print_conv,
+        });
+    }
+}
+
+/// ExifTool: CanonRaw.pm TimeStamp DateTimeOriginal `ValueConv => 'ConvertUnixTime($val)'`
+fn time_stamp_value_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+) -> Result<TagValue> {
+    match (tag_id, value.as_u32()) {
+        (0, Some(time)) => Ok(TagValue::String(
+            crate::implementations::quicktime::convert_unix_time(time as i64, false),
+        )),
+        (0, None) => Ok(value.clone()),
+        _ => time_stamp_tags::apply_value_conv(tag_id, value, errors),
+    }
+}
+
+/// ExifTool: DateTimeOriginal `PrintConv => '$self->ConvertDateTime($val)'`, a
+/// no-op without the DateFormat option
+fn time_stamp_print_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> TagValue {
+    if tag_id == 0 {
+        value.clone()
+    } else {
+        time_stamp_tags::apply_print_conv(tag_id, value, errors, warnings)
+    }
+}
+
+/// Apply a generated table's ValueConv, keeping the value on failure
+fn apply_value_conv(value_conv: ValueConvFn, tag_id: u32, value: TagValue) -> TagValue {
+    let mut errors = Vec::new();
+    value_conv(tag_id, &value, &mut errors).unwrap_or(value)
+}
+
+/// Size in bytes of one value of a CIFF format
+fn format_size(format: &str) -> usize {
+    match format {
+        "int16u" | "int16s" => 2,
+        "int32u" | "int32s" | "float" => 4,
+        _ => 1,
+    }
+}
+
+/// Read `count` values of `format`
+/// ExifTool: ReadValue() for the formats used by CanonRaw.pm
+fn read_value(data: &[u8], format: &str, count: usize, byte_order: ByteOrder) -> TagValue {
+    let count = count.min(data.len() / format_size(format));
+    let u16s = || {
+        (0..count)
+            .filter_map(|i| byte_order.read_u16(data, i * 2).ok())
+            .collect::<Vec<_>>()
+    };
+    let u32s = || {
+        (0..count)
+            .filter_map(|i| byte_order.read_u32(data, i * 4).ok())
+            .collect::<Vec<_>>()
+    };
+    match format {
+        "string" => TagValue::String(read_string(data)),
+        "int8u" => single_or(data[..count].to_vec(), TagValue::U8, TagValue::U8Array),
+        "int16u" => single_or(u16s(), TagValue::U16, TagValue::U16Array),
+        "int32u" => single_or(u32s(), TagValue::U32, TagValue::U32Array),
+        "int16s" => single_or(
+            u16s()
+                .into_iter()
+                .map(|v| TagValue::I16(v as i16))
+                .collect(),
+            |v| v,
+            TagValue::Array,
+        ),
+        "int32s" => single_or(
+            u32s()
+                .into_iter()
+                .map(|v| TagValue::I32(v as i32))
+                .collect(),
+            |v| v,
+            TagValue::Array,
+        ),
+        "float" => single_or(
+            u32s()
+                .into_iter()
+                .map(|v| f32::from_bits(v) as f64)
+                .collect(),
+            TagValue::F64,
+            TagValue::F64Array,
+        ),
+        _ => TagValue::Binary(data.to_vec()),
+    }
+}
+
+/// Unwrap single values; ExifTool only returns a list for counts above 1
+fn single_or<T>(
+    mut values: Vec<T>,
+    single: impl Fn(T) -> TagValue,
+    many: impl Fn(Vec<T>) -> TagValue,
+) -> TagValue {
+    if values.len() == 1 {
+        single(values.remove(0))
+    } else {
+        many(values)
+    }
+}
+
+/// Read a null-terminated string
+fn read_string(data: &[u8]) -> String {
+    let end = data.iter().position(|&b| b == 0).unwrap_or(data.len());
+    String::from_utf8_lossy(&data[..end]).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a heap block: values, then the directory, then its offset
+    fn heap_block(entries: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut block = Vec::new();
+        let mut directory = (entries.len() as u16).to_le_bytes().to_vec();
+        for (tag, value) in entries {
+            directory.extend_from_slice(&tag.to_le_bytes());
+            directory.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            directory.extend_from_slice(&(block.len() as u32).to_le_bytes());
+            block.extend_from_slice(value);
+        }
+        let dir_offset = block.len() as u32;
+        block.extend_from_slice(&directory);
+        block.extend_from_slice(&dir_offset.to_le_bytes());
+        block
+    }
+
+    fn crw_file(entries: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut data = b"II".to_vec();
+        data.extend_from_slice(&26u32.to_le_bytes());
+        data.extend_from_slice(b"HEAPCCDR");
+        data.extend_from_slice(&[0; 12]);
+        data.extend_from_slice(&heap_block(entries));
+        data
+    }
+
+    fn int16u(values: &[u16]) -> Vec<u8> {
+        values.iter().flat_map(|v| v.to_le_bytes()).collect()
+    }
+
+    fn tag_value<'a>(reader: &'a ExifReader, name: &str) -> Option<&'a TagValue> {
+        let (&id, _) = reader
+            .synthetic_tag_names
+            .iter()
+            .find(|(_, full_name)| full_name.as_str() == format!("MakerNotes:{name}"))?;
+        reader.extracted_tags.get(&(id, "MakerNotes".to_string()))
+    }
+
+    #[test]
+    fn test_is_ciff() {
+        assert!(is_ciff(&crw_file(&[])));
+        assert!(!is_ciff(b"II*\0\x08\0\0\0HEAPCCDR"));
+        assert!(!is_ciff(b"II\x1a\0\0\0HEAP"));
+    }
+
+    #[test]
+    fn test_crw_heap_tags() {
+        let thumbnail = [&[0xFF, 0xD8][..], &[0u8; 600], &[0xFF, 0xD9]].concat();
+        // ShotInfo index 5 (TargetExposureTime) = 189 -> CanonEv 5.906 -> 1/60 s
+        let shot_info = int16u(&[0, 0, 0, 0, 189, 0]);
+        let time_stamp = [1_000_000_000u32, 3600, 0]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        let image_props = heap_block(&[
+            (0x0815, b"CRW:EOS D30 CMOS RAW\0".to_vec()),
+            (0x080a, b"Canon\0Canon EOS D30\0".to_vec()),
+            (0x180e, time_stamp),
+        ]);
+        let data = crw_file(&[
+            (0x300a, image_props),
+            (0x1028, int16u(&[0, 16, 32, 48])),
+            (0x102a, shot_info),
+            (0x2008, thumbnail.clone()),
+            (0x2005, vec![0; 1024]),
+        ]);
+
+        let mut reader = ExifReader::new();
+        process_crw(&mut reader, &data).unwrap();
+
+        assert_eq!(
+            tag_value(&reader, "CanonImageType"),
+            Some(&TagValue::string("CRW:EOS D30 CMOS RAW"))
+        );
+        assert_eq!(tag_value(&reader, "Make"), Some(&TagValue::string("Canon")));
+        assert_eq!(
+            tag_value(&reader, "Model"),
+            Some(&TagValue::string("Canon EOS D30"))
+        );
+        assert_eq!(
+            tag_value(&reader, "DateTimeOriginal"),
+            Some(&TagValue::string("2001:09:09 01:46:40"))
+        );
+        assert_eq!(
+            tag_value(&reader, "CanonFlashInfo"),
+            Some(&TagValue::U16Array(vec![0, 16, 32, 48]))
+        );
+        assert_eq!(
+            tag_value(&reader, "TargetExposureTime"),
+            Some(&TagValue::string("1/60"))
+        );
+        assert_eq!(
+            tag_value(&reader, "ThumbnailImage"),
+            Some(&TagValue::Binary(thumbnail))
+        );
+        assert_eq!(tag_value(&reader, "RawData"), None);
+    }
+
+    #[test]
+    fn test_bad_directory_entry_warns() {
+        let data = crw_file(&[(0x8815, b"bad\0".to_vec())]);
+        let mut reader = ExifReader::new();
+        process_crw(&mut reader, &data).unwrap();
+        assert_eq!(reader.warnings, vec!["Bad CRW directory entry".to_string()]);
+    }
+}
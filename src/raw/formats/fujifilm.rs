@@ -0,0 +1,345 @@
+//! FujiFilm RAF format handler
+//!
+//! RAF files start with a big-endian header giving the position of a full
+//! JPEG, whose EXIF carries the camera settings and FujiFilm maker notes,
+//! and of the RAF directories describing the sensor: raw image sizes, the
+//! X-Trans color filter layout and white balance levels. A RAF directory is
+//! an entry count followed by tag, size and value triplets.
+//!
+//! ExifTool Reference: lib/Image/ExifTool/FujiFilm.pm ProcessRAF(),
+//! ProcessFujiDir(), RAFHeader and RAF tables
+
+use crate::exif::ExifReader;
+use crate::generated::FujiFilm_pm::{raf_header_tags, raf_tags};
+use crate::implementations::maker_table::{self, BinaryDataState, MakerTable, MakerTag};
+use crate::raw::RawFormatHandler;
+use crate::tiff_types::ByteOrder;
+use crate::types::{ExifError, Result, TagValue};
+use std::borrow::Cow;
+use std::io::Cursor;
+use tracing::debug;
+
+/// First synthetic tag ID for RAF header and directory tags
+const RAF_SYNTHETIC_BASE: u16 = 0xFD00;
+
+/// Header bytes read by ExifTool, up to the last directory pointer
+const HEADER_SIZE: usize = 0x94;
+
+/// Header positions of the RAF directory pointers; 0x64 and 0x80 point to
+/// FujiIFD raw data directories, which aren't decoded
+/// ExifTool: ProcessRAF `foreach $offset (0x5c, 0x64, 0x78, 0x80)`
+const RAF_DIR_POINTERS: [usize; 2] = [0x5c, 0x78];
+
+/// Most entries ExifTool accepts in a RAF directory
+const MAX_DIR_ENTRIES: usize = 256;
+
+/// RAF table tag IDs with conversions the generator doesn't implement
+const RAW_IMAGE_FULL_SIZE: u32 = 0x100;
+const RAW_IMAGE_CROPPED_SIZE: u32 = 0x111;
+const RAW_IMAGE_ASPECT_RATIO: u32 = 0x115;
+const RAW_ZOOM_TOP_LEFT: u32 = 0x118;
+const RAW_ZOOM_SIZE: u32 = 0x119;
+const RAW_IMAGE_SIZE: u32 = 0x121;
+const FUJI_LAYOUT: u32 = 0x130;
+const XTRANS_LAYOUT: u32 = 0x131;
+
+/// FirmwareVersion in the RAF header
+const HEADER_FIRMWARE_VERSION: u32 = 0x3c;
+
+/// FujiFilm RAF format handler
+/// ExifTool: lib/Image/ExifTool/FujiFilm.pm ProcessRAF()
+#[derive(Default)]
+pub struct FujifilmRawHandler;
+
+impl FujifilmRawHandler {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Decode the EXIF of the embedded JPEG, including its maker notes
+    /// ExifTool: ProcessRAF extracts information from the embedded JPEG
+    fn process_jpeg(&self, reader: &mut ExifReader, jpeg: &[u8]) -> Result<()> {
+        let exif = crate::formats::extract_jpeg_exif(Cursor::new(jpeg))?;
+        reader.parse_exif_data(&exif)
+    }
+}
+
+impl RawFormatHandler for FujifilmRawHandler {
+    fn process_raw(&self, reader: &mut ExifReader, data: &[u8]) -> Result<()> {
+        let header = data
+            .get(..HEADER_SIZE)
+            .ok_or_else(|| ExifError::ParseError("RAF header is truncated".into()))?;
+        let order = ByteOrder::BigEndian;
+        let jpeg_start = order.read_u32(header, 84)? as usize;
+        let jpeg_length = order.read_u32(header, 88)? as usize;
+
+        let header_table = MakerTable {
+            tags: &raf_header_tags::FUJI_FILM_RAFHEADER_TAGS,
+            value_conv: raf_header_tags::apply_value_conv,
+            print_conv: header_print_conv,
+        };
+        let mut tags = header_table.read_binary_entries(
+            header,
+            order,
+            "int8u",
+            &mut BinaryDataState::default(),
+        );
+
+        match data.get(jpeg_start..jpeg_start.saturating_add(jpeg_length)) {
+            Some(jpeg) if jpeg_start > 0 => {
+                if let Err(e) = self.process_jpeg(reader, jpeg) {
+                    debug!("No EXIF from the RAF embedded JPEG: {}", e);
+                }
+            }
+            _ => debug!("RAF embedded JPEG at {:#x} is beyond the file", jpeg_start),
+        }
+
+        for pointer in RAF_DIR_POINTERS {
+            // ExifTool: `last if $offset >= $jpos`
+            if pointer >= jpeg_start {
+                break;
+            }
+            let start = order.read_u32(header, pointer)? as usize;
+            if start > 0 {
+                tags.extend(read_raf_dir(data, start));
+            }
+        }
+
+        maker_table::store_group_tags(reader, RAF_SYNTHETIC_BASE, ("RAF", "RAF"), "RAF", tags);
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "FujiFilm"
+    }
+
+    fn validate_format(&self, data: &[u8]) -> bool {
+        // ExifTool: ProcessRAF `$buff =~ /^FUJIFILM/`
+        data.starts_with(b"FUJIFILM")
+    }
+}
+
+/// Decode a RAF directory at `start`, stopping at the first entry that runs
+/// past the file
+/// ExifTool: ProcessFujiDir()
+fn read_raf_dir(data: &[u8], start: usize) -> Vec<MakerTag> {
+    let order = ByteOrder::BigEndian;
+    let table = MakerTable {
+        tags: &raf_tags::FUJI_FILM_RAF_TAGS,
+        value_conv: raf_value_conv,
+        print_conv: raf_print_conv,
+    };
+    let Ok(entries) = order.read_u32(data, start) else {
+        return Vec::new();
+    };
+    if entries as usize >= MAX_DIR_ENTRIES {
+        debug!("RAF directory at {:#x} has {} entries", start, entries);
+        return Vec::new();
+    }
+
+    let mut raw = Vec::new();
+    let mut pos = start + 4;
+    for _ in 0..entries {
+        let (Ok(tag_id), Ok(size)) = (order.read_u16(data, pos), order.read_u16(data, pos + 2))
+        else {
+            break;
+        };
+        let Some(bytes) = data.get(pos + 4..pos + 4 + size as usize) else {
+            break;
+        };
+        pos += 4 + size as usize;
+        if let Some(value) = table.read_raw(tag_id, bytes, order) {
+            raw.push((tag_id, value));
+        }
+    }
+
+    // ExifTool: FujiLayout's RawConv sets $$self{FujiLayout}, which
+    // RawImageSize's ValueConv reads: `$$self{FujiLayout} and $v[0]/=2, $v[1]*=2`
+    let fuji_layout = raw.iter().any(|(tag_id, value)| {
+        *tag_id as u32 == FUJI_LAYOUT && first_u8(value).is_some_and(|layout| layout & 0x80 != 0)
+    });
+    raw.into_iter()
+        .map(|(tag_id, value)| {
+            let value = match (tag_id as u32, value) {
+                (RAW_IMAGE_SIZE, TagValue::U16Array(v)) if fuji_layout && v.len() == 2 => {
+                    // Stored height first: halve the width, double the height
+                    TagValue::U16Array(vec![v[0].saturating_mul(2), v[1] / 2])
+                }
+                (_, value) => value,
+            };
+            let name = table.tags[&tag_id].name;
+            table.convert(tag_id, Cow::Borrowed(name), value)
+        })
+        .collect()
+}
+
+fn first_u8(value: &TagValue) -> Option<u8> {
+    match value {
+        TagValue::U8(v) => Some(*v),
+        TagValue::U8Array(v) => v.first().copied(),
+        _ => None,
+    }
+}
+
+/// RAF ValueConv, with the height/width swap of the size tags
+fn raf_value_conv(tag_id: u32, value: &TagValue, errors: &mut Vec<String>) -> Result<TagValue> {
+    match (tag_id, value) {
+        // ExifTool: `my @v=reverse split(" ",$val);"@v"`
+        (
+            RAW_IMAGE_FULL_SIZE
+            | RAW_IMAGE_CROPPED_SIZE
+            | RAW_IMAGE_ASPECT_RATIO
+            | RAW_ZOOM_TOP_LEFT
+            | RAW_ZOOM_SIZE
+            | RAW_IMAGE_SIZE,
+            TagValue::U16Array(v),
+        ) => Ok(TagValue::U16Array(v.iter().rev().copied().collect())),
+        _ => raf_tags::apply_value_conv(tag_id, value, errors),
+    }
+}
+
+/// RAF PrintConv, with the size, aspect ratio and X-Trans layout formats
+fn raf_print_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> TagValue {
+    match (tag_id, value) {
+        // ExifTool: `$val =~ tr/ /x/; $val`
+        (
+            RAW_IMAGE_FULL_SIZE
+            | RAW_IMAGE_CROPPED_SIZE
+            | RAW_ZOOM_TOP_LEFT
+            | RAW_ZOOM_SIZE
+            | RAW_IMAGE_SIZE,
+            TagValue::U16Array(_),
+        ) => join_u16s(value, "x"),
+        // ExifTool: `$val=~tr/ /:/; $val`
+        (RAW_IMAGE_ASPECT_RATIO, TagValue::U16Array(_)) => join_u16s(value, ":"),
+        // ExifTool: `$val =~ tr/012 /RGB/d; join " ", $val =~ /....../g`
+        (XTRANS_LAYOUT, TagValue::U8Array(colors)) => {
+            let colors: String = colors
+                .iter()
+                .map(|&color| ['R', 'G', 'B'].get(color as usize).copied().unwrap_or('?'))
+                .collect();
+            let rows: Vec<&str> = colors
+                .as_bytes()
+                .chunks_exact(6)
+                .filter_map(|row| std::str::from_utf8(row).ok())
+                .collect();
+            TagValue::String(rows.join(" "))
+        }
+        _ => raf_tags::apply_print_conv(tag_id, value, errors, warnings),
+    }
+}
+
+fn join_u16s(value: &TagValue, separator: &str) -> TagValue {
+    let TagValue::U16Array(values) = value else {
+        return value.clone();
+    };
+    let values: Vec<String> = values.iter().map(u16::to_string).collect();
+    TagValue::String(values.join(separator))
+}
+
+/// RAFHeader PrintConv; FirmwareVersion is four ASCII digits
+fn header_print_conv(
+    tag_id: u32,
+    value: &TagValue,
+    errors: &mut Vec<String>,
+    warnings: &mut Vec<String>,
+) -> TagValue {
+    match (tag_id, value) {
+        (HEADER_FIRMWARE_VERSION, TagValue::Binary(bytes)) => {
+            TagValue::String(String::from_utf8_lossy(bytes).into_owned())
+        }
+        _ => raf_header_tags::apply_print_conv(tag_id, value, errors, warnings),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A RAF file with a JPEG without EXIF and one RAF directory
+    fn raf(entries: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut data = vec![0u8; HEADER_SIZE];
+        data[..16].copy_from_slice(b"FUJIFILMCCD-RAW ");
+        data[0x3c..0x40].copy_from_slice(b"0100");
+        let dir_start = data.len();
+        data[0x5c..0x60].copy_from_slice(&(dir_start as u32).to_be_bytes());
+        data.extend_from_slice(&(entries.len() as u32).to_be_bytes());
+        for (tag_id, value) in entries {
+            data.extend_from_slice(&tag_id.to_be_bytes());
+            data.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            data.extend_from_slice(value);
+        }
+        let jpeg_start = data.len();
+        data.extend_from_slice(b"\xff\xd8\xff\xd9");
+        data[84..88].copy_from_slice(&(jpeg_start as u32).to_be_bytes());
+        data[88..92].copy_from_slice(&4u32.to_be_bytes());
+        data
+    }
+
+    fn decoded(reader: &mut ExifReader, name: &str) -> Option<TagValue> {
+        reader
+            .get_all_tag_entries()
+            .into_iter()
+            .find(|entry| entry.group == "RAF" && entry.name == name)
+            .map(|entry| entry.print().clone())
+    }
+
+    #[test]
+    fn test_raf_directory() {
+        // X-Trans layout, two rows of six repeated three times
+        let layout: Vec<u8> = [1, 1, 0, 1, 1, 2, 1, 1, 2, 1, 1, 0]
+            .iter()
+            .cycle()
+            .take(36)
+            .copied()
+            .collect();
+        let data = raf(&[
+            // RawImageFullSize, height then width
+            (0x100, vec![0x10, 0x30, 0x18, 0x60]),
+            (0x130, vec![0, 0, 0, 0]),
+            (0x131, layout),
+        ]);
+
+        let handler = FujifilmRawHandler::new();
+        assert!(handler.validate_format(&data));
+        let mut reader = ExifReader::new();
+        handler.process_raw(&mut reader, &data).unwrap();
+
+        assert_eq!(
+            decoded(&mut reader, "FirmwareVersion"),
+            Some(TagValue::string("0100"))
+        );
+        assert_eq!(
+            decoded(&mut reader, "RawImageFullSize"),
+            Some(TagValue::string("6240x4144"))
+        );
+        assert_eq!(
+            decoded(&mut reader, "XTransLayout"),
+            Some(TagValue::string(
+                "GGRGGB GGBGGR GGRGGB GGBGGR GGRGGB GGBGGR"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_raw_image_size_fuji_layout() {
+        let data = raf(&[
+            (0x121, vec![0x08, 0x00, 0x10, 0x00]),
+            (0x130, vec![0x80, 0, 0, 0]),
+        ]);
+        let mut reader = ExifReader::new();
+        FujifilmRawHandler::new()
+            .process_raw(&mut reader, &data)
+            .unwrap();
+        // 4096 wide and 2048 high, rotated 45 degrees
+        assert_eq!(
+            decoded(&mut reader, "RawImageSize"),
+            Some(TagValue::string("2048x4096"))
+        );
+    }
+}
@@ -4,13 +4,19 @@
 //! All handlers follow the Trust ExifTool principle by implementing exact
 //! translations of ExifTool's processing logic.
 
+#[cfg(feature = "canon")]
 pub mod canon;
+#[cfg(feature = "canon")]
+pub mod canon_raw;
+#[cfg(feature = "fujifilm")]
+pub mod fujifilm;
 pub mod kyocera;
 pub mod minolta;
+#[cfg(feature = "olympus")]
 pub mod olympus;
 pub mod panasonic;
+#[cfg(feature = "sony")]
 pub mod sony;
 
 // Future format modules will be added here:
 // pub mod nikon;
-// pub mod fujifilm;
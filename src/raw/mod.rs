@@ -13,7 +13,7 @@
 //! - **Canon** (CR2, CRW, CR3) - Milestone 17d: Complex TIFF-based with 169 ProcessBinaryData sections
 //! - **Nikon** (NEF, NRW) - Future: Integration with existing Nikon implementation
 //! - **Sony** (ARW, SR2, SRF) - Future: Advanced offset management
-//! - **Fujifilm** (RAF) - Future: Non-TIFF format
+//! - **Fujifilm** (RAF) - Embedded JPEG and RAF directories
 //!
 //! ## Architecture
 //!
@@ -45,11 +45,14 @@ pub use processor::{RawFormatHandler, RawProcessor};
 pub mod formats;
 
 // Re-export format handlers and utility functions
+#[cfg(feature = "canon")]
 pub use formats::canon::get_canon_tag_name;
 pub use formats::kyocera::get_kyocera_tag_name;
 pub use formats::minolta::get_minolta_tag_name;
+#[cfg(feature = "olympus")]
 pub use formats::olympus::get_olympus_tag_name;
 pub use formats::panasonic::get_panasonic_tag_name;
+#[cfg(feature = "sony")]
 pub use formats::sony::get_sony_tag_name;
 
 #[cfg(test)]
@@ -7,6 +7,7 @@ use crate::exif::ExifReader;
 use crate::file_detection::FileTypeDetectionResult;
 use crate::types::{ExifError, Result};
 use std::collections::HashMap;
+use std::sync::LazyLock;
 
 use super::{detector::detect_raw_format, RawFormat};
 
@@ -26,6 +27,9 @@ pub trait RawFormatHandler: Send + Sync {
     fn validate_format(&self, data: &[u8]) -> bool;
 }
 
+/// The processor used for extraction, built on first use
+static SHARED: LazyLock<RawProcessor> = LazyLock::new(RawProcessor::new);
+
 /// Central RAW processor that routes to manufacturer-specific handlers
 /// ExifTool: Main ExifTool dispatcher routes to manufacturer modules
 pub struct RawProcessor {
@@ -61,6 +65,7 @@ impl RawProcessor {
 
         // Register Olympus handler
         // ExifTool: Olympus.pm module registration
+        #[cfg(feature = "olympus")]
         handlers.insert(
             RawFormat::Olympus,
             Box::new(super::formats::olympus::OlympusRawHandler::new()),
@@ -68,6 +73,7 @@ impl RawProcessor {
 
         // Register Canon handler
         // ExifTool: Canon.pm module registration
+        #[cfg(feature = "canon")]
         handlers.insert(
             RawFormat::Canon,
             Box::new(super::formats::canon::CanonRawHandler::new()),
@@ -75,17 +81,34 @@ impl RawProcessor {
 
         // Register Sony handler
         // ExifTool: Sony.pm module registration
+        #[cfg(feature = "sony")]
         handlers.insert(
             RawFormat::Sony,
             Box::new(super::formats::sony::SonyRawHandler::new()),
         );
 
+        // Register FujiFilm handler
+        // ExifTool: FujiFilm.pm module registration
+        #[cfg(feature = "fujifilm")]
+        handlers.insert(
+            RawFormat::Fujifilm,
+            Box::new(super::formats::fujifilm::FujifilmRawHandler::new()),
+        );
+
         // Future handlers will be registered here:
         // handlers.insert(RawFormat::Nikon, Box::new(NikonRawHandler::new()));
 
         Self { handlers }
     }
 
+    /// The processor with all supported handlers, shared by every extraction
+    ///
+    /// The handlers are stateless, so there is no need to register them again
+    /// for each file.
+    pub fn shared() -> &'static Self {
+        &SHARED
+    }
+
     /// Process RAW file data
     /// ExifTool: Main entry point that detects format and dispatches to appropriate module
     pub fn process_raw(
@@ -185,7 +208,17 @@ mod tests {
         assert!(supported.contains(&RawFormat::Panasonic));
         assert!(supported.contains(&RawFormat::Olympus));
         assert!(supported.contains(&RawFormat::Canon));
-        assert_eq!(supported.len(), 6); // Should have exactly 6 supported formats
+        assert!(supported.contains(&RawFormat::Fujifilm));
+        assert_eq!(supported.len(), 7); // Should have exactly 7 supported formats
+    }
+
+    #[test]
+    fn test_shared_raw_processor() {
+        assert!(std::ptr::eq(RawProcessor::shared(), RawProcessor::shared()));
+        assert_eq!(
+            RawProcessor::shared().supported_formats().len(),
+            RawProcessor::new().supported_formats().len()
+        );
     }
 
     #[test]
@@ -204,3 +204,80 @@ fn create_mock_rw2_with_sensor_borders(left: u16, right: u16, top: u16, bottom:
 
     data
 }
+
+#[test]
+fn test_full_resolution_subifd_dimensions_win() {
+    use crate::exif::ExifReader;
+    use crate::types::TagValue;
+
+    // NEF/ARW/DNG layout: IFD0 is a 160x120 thumbnail (SubfileType 1), SubIFD0
+    // a preview, and SubIFD1 the full-resolution raw image (SubfileType 0)
+    let data = create_mock_raw_with_subifds();
+
+    let mut reader = ExifReader::new();
+    reader.parse_exif_data(&data).unwrap();
+    assert_eq!(
+        reader.extracted_tags.get(&(0x0100, "EXIF".to_string())),
+        Some(&TagValue::U16(160))
+    );
+
+    extract_tiff_dimensions(&mut reader, &data).unwrap();
+    assert_eq!(
+        reader.extracted_tags.get(&(0x0100, "EXIF".to_string())),
+        Some(&TagValue::U32(6048))
+    );
+    assert_eq!(
+        reader.extracted_tags.get(&(0x0101, "EXIF".to_string())),
+        Some(&TagValue::U32(4024))
+    );
+    let source = &reader.tag_sources[&(0x0100, "EXIF".to_string())];
+    assert_eq!(source.get_group1(), "SubIFD1");
+
+    // DefaultCropSize of the raw image
+    assert_eq!(
+        reader.extracted_tags.get(&(0xc620, "EXIF".to_string())),
+        Some(&TagValue::U32Array(vec![6000, 4000]))
+    );
+}
+
+/// Little-endian TIFF with a thumbnail IFD0 and two SubIFDs
+fn create_mock_raw_with_subifds() -> Vec<u8> {
+    fn entry(tag: u16, format: u16, count: u32, value: u32) -> Vec<u8> {
+        [
+            &tag.to_le_bytes()[..],
+            &format.to_le_bytes(),
+            &count.to_le_bytes(),
+            &value.to_le_bytes(),
+        ]
+        .concat()
+    }
+    fn ifd(entries: &[Vec<u8>]) -> Vec<u8> {
+        let mut out = (entries.len() as u16).to_le_bytes().to_vec();
+        entries.iter().for_each(|e| out.extend_from_slice(e));
+        out.extend_from_slice(&[0, 0, 0, 0]); // Next IFD = 0
+        out
+    }
+
+    // IFD0 at 8, SubIFD pointers at 62, SubIFD0 at 70, SubIFD1 at 88 and the
+    // DefaultCropSize values at 142
+    let mut data = b"II*\0\x08\0\0\0".to_vec();
+    data.extend(ifd(&[
+        entry(0x00fe, 4, 1, 1),
+        entry(0x0100, 3, 1, 160),
+        entry(0x0101, 3, 1, 120),
+        entry(0x014a, 4, 2, 62),
+    ]));
+    data.extend_from_slice(&70u32.to_le_bytes());
+    data.extend_from_slice(&88u32.to_le_bytes());
+    data.extend(ifd(&[entry(0x00fe, 4, 1, 1)]));
+    data.extend(ifd(&[
+        entry(0x00fe, 4, 1, 0),
+        entry(0x0100, 4, 1, 6048),
+        entry(0x0101, 4, 1, 4024),
+        entry(0xc620, 4, 2, 142),
+    ]));
+    assert_eq!(data.len(), 142);
+    data.extend_from_slice(&6000u32.to_le_bytes());
+    data.extend_from_slice(&4000u32.to_le_bytes());
+    data
+}
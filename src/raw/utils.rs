@@ -788,6 +788,118 @@ fn extract_all_subifd_pointers(
     Ok(Vec::new())
 }
 
+/// Crop tags read from the full-resolution image IFD: DefaultCropOrigin and
+/// DefaultCropSize (DNG, and Nikon/Pentax raw SubIFDs), SonyCropTopLeft and
+/// SonyCropSize (ARW). ExifTool: Exif.pm tags 0xc61f, 0xc620, 0x74c7, 0x74c8
+const CROP_TAGS: [u16; 4] = [0xc61f, 0xc620, 0x74c7, 0x74c8];
+
+/// SubfileType, dimensions and crop tags of one image IFD
+#[derive(Debug, Default)]
+struct ImageIfdInfo {
+    subfile_type: Option<u32>,
+    width: Option<u32>,
+    height: Option<u32>,
+    crop_tags: Vec<(u16, crate::types::TagValue)>,
+}
+
+impl ImageIfdInfo {
+    /// Full-resolution image (or a page of one)
+    /// ExifTool: Exif.pm SubfileType RawConv - `if ($val == ($val & 0x02))`
+    fn is_full_resolution(&self) -> bool {
+        matches!(self.subfile_type, Some(value) if value == value & 0x02)
+    }
+
+    fn dimensions(&self) -> Option<(u32, u32)> {
+        Some((self.width?, self.height?))
+    }
+}
+
+/// Read SubfileType (0x00fe), ImageWidth/ImageHeight and the crop tags of an IFD
+fn scan_image_ifd(data: &[u8], ifd_offset: usize, is_little_endian: bool) -> ImageIfdInfo {
+    use crate::types::TagValue;
+
+    let mut info = ImageIfdInfo::default();
+    let read_u16 = |at: usize| -> Option<u16> {
+        let bytes = [*data.get(at)?, *data.get(at + 1)?];
+        Some(if is_little_endian {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        })
+    };
+    let read_u32 = |at: usize| -> Option<u32> {
+        let bytes: [u8; 4] = data.get(at..at + 4)?.try_into().ok()?;
+        Some(if is_little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    };
+
+    let Some(entry_count) = read_u16(ifd_offset) else {
+        return info;
+    };
+    for i in 0..entry_count as usize {
+        let entry_offset = ifd_offset + 2 + i * 12;
+        let (Some(tag_id), Some(data_type), Some(count)) = (
+            read_u16(entry_offset),
+            read_u16(entry_offset + 2),
+            read_u32(entry_offset + 4),
+        ) else {
+            break;
+        };
+        let single = || match data_type {
+            3 => read_u16(entry_offset + 8).map(u32::from),
+            4 => read_u32(entry_offset + 8),
+            _ => None,
+        };
+        match tag_id {
+            0x00fe if count == 1 => info.subfile_type = single(),
+            0x0100 if count == 1 => info.width = single(),
+            0x0101 if count == 1 => info.height = single(),
+            tag if CROP_TAGS.contains(&tag) && count == 2 => {
+                // SHORT pairs fit in the entry; LONG and RATIONAL pairs are at an offset
+                let value = match data_type {
+                    3 => (0..2)
+                        .map(|n| read_u16(entry_offset + 8 + n * 2))
+                        .collect::<Option<Vec<_>>>()
+                        .map(TagValue::U16Array),
+                    4 => read_u32(entry_offset + 8).and_then(|at| {
+                        (0..2)
+                            .map(|n| read_u32(at as usize + n * 4))
+                            .collect::<Option<Vec<_>>>()
+                            .map(TagValue::U32Array)
+                    }),
+                    5 => read_u32(entry_offset + 8).and_then(|at| {
+                        (0..2)
+                            .map(|n| {
+                                let at = at as usize + n * 8;
+                                Some((read_u32(at)?, read_u32(at + 4)?))
+                            })
+                            .collect::<Option<Vec<_>>>()
+                            .map(TagValue::RationalArray)
+                    }),
+                    _ => None,
+                };
+                if let Some(value) = value {
+                    info.crop_tags.push((tag, value));
+                }
+            }
+            _ => {}
+        }
+    }
+    info
+}
+
+/// ExifTool Group1 name of the SubIFD at `index`: SubIFD, SubIFD1, SubIFD2, ...
+fn subifd_name(index: usize) -> String {
+    if index == 0 {
+        "SubIFD".to_string()
+    } else {
+        format!("SubIFD{index}")
+    }
+}
+
 /// Extract TIFF dimension tags (ImageWidth/ImageHeight) from IFD0 for TIFF-based RAW files
 /// ExifTool: lib/Image/ExifTool/Exif.pm:351-473 (tags 0x0100, 0x0101)
 /// Used by both Sony ARW and Canon CR2 files which are TIFF-based
@@ -816,13 +928,52 @@ pub fn extract_tiff_dimensions(
     let (image_width, image_height, sub_ifd_offset, sensor_borders) =
         scan_ifd_for_dimensions(data, ifd0_offset, is_little_endian)?;
 
+    // Extract all SubIFD pointers from the SubIFD tag (0x014a)
+    let all_sub_offsets = if sub_ifd_offset.is_some() {
+        extract_all_subifd_pointers(data, ifd0_offset, is_little_endian)?
+    } else {
+        Vec::new()
+    };
+
+    // The full-resolution image wins over the IFD0 thumbnail or preview: ExifTool
+    // sets PRIORITY_DIR from SubfileType, so NEF/ARW/DNG report the raw image
+    // size from the SubIFD even though IFD0 holds a smaller ImageWidth
+    let ifd0_is_full_resolution =
+        scan_image_ifd(data, ifd0_offset, is_little_endian).is_full_resolution();
+    let full_resolution_subifd = if ifd0_is_full_resolution {
+        None
+    } else {
+        all_sub_offsets
+            .iter()
+            .enumerate()
+            .find_map(|(index, offset)| {
+                let info = scan_image_ifd(data, *offset, is_little_endian);
+                (info.is_full_resolution() && info.dimensions().is_some()).then_some((index, info))
+            })
+    };
+    if let Some((index, info)) = &full_resolution_subifd {
+        let ifd_name = subifd_name(*index);
+        if let Some((width, height)) = info.dimensions() {
+            debug!(
+                "Using {} full-resolution dimensions: {}x{}",
+                ifd_name, width, height
+            );
+            reader.insert_priority_dir_tag(0x0100, TagValue::U32(width), &ifd_name);
+            reader.insert_priority_dir_tag(0x0101, TagValue::U32(height), &ifd_name);
+        }
+        // Crop area of the full-resolution image (the sensor size stays in
+        // ImageWidth/ImageHeight, as in ExifTool)
+        for (tag_id, value) in &info.crop_tags {
+            let source_info = reader.create_tag_source_info(&ifd_name);
+            reader.store_tag_with_precedence(*tag_id, value.clone(), source_info);
+        }
+    }
+
     // Check all SubIFDs if available for full-resolution dimensions
     // ExifTool processes all SubIFDs sequentially (SubIFD0, SubIFD1, etc.)
     // NEF files often store dimensions in SubIFD1, not SubIFD0
-    let (sub_width, sub_height) = if let Some(_first_sub_offset) = sub_ifd_offset {
-        // Extract all SubIFD pointers from the SubIFD tag (0x014a)
-        let all_sub_offsets = extract_all_subifd_pointers(data, ifd0_offset, is_little_endian)?;
-
+    let (sub_width, sub_height) = if full_resolution_subifd.is_none() && !all_sub_offsets.is_empty()
+    {
         debug!(
             "Found {} SubIFD pointers: {:?}",
             all_sub_offsets.len(),
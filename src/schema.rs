@@ -0,0 +1,391 @@
+//! JSON Schema for the JSON output
+//!
+//! [`json_schema`] describes the array of per-file objects that the CLI prints
+//! and [`crate::extract_metadata_json`] returns: `SourceFile`, the
+//! `Group:TagName` tag keys, `errors`, `MissingImplementations`, `TagSources`,
+//! `XMPPacket` and `Statistics`. Tag types come from the generated EXIF, GPS and composite tag
+//! tables plus the File group tags; tags of other groups are allowed but
+//! untyped. The schema for the default (PrintConv) output is published as
+//! `docs/output.schema.json` for code generators like
+//! `json-schema-to-typescript`.
+//!
+//! ExifTool's JSON output writes any string that looks like a number as a JSON
+//! number (a Software tag of "1.0" becomes `1.0`), so a tag's JSON type can
+//! change from file to file. [`TypedExifData`] serializes tags so that they
+//! conform to the schema instead: tags typed as strings are always strings.
+//!
+//! CLI: `--schema` prints the schema, `--typed` selects the conforming output.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::LazyLock;
+
+use serde::ser::{SerializeMap, Serializer};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::composite_tags::MANUAL_COMPOSITES;
+use crate::generated::composite_tags::COMPOSITE_TAGS;
+use crate::generated::Exif_pm::main_tags::{EXIF_MAIN_STRING_TAGS, EXIF_MAIN_TAGS};
+use crate::generated::GPS_pm::main_tags::{GPS_MAIN_STRING_TAGS, GPS_MAIN_TAGS};
+use crate::types::{ExifData, PrintConv, TagInfo, TagValue};
+
+/// JSON type of a tag's value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagType {
+    /// Always a JSON string
+    String,
+    /// Depends on the file (number, string, array, ...)
+    Any,
+}
+
+impl TagType {
+    /// Combine the types of two table entries with the same tag name
+    fn merge(self, other: Self) -> Self {
+        if self == other {
+            self
+        } else {
+            TagType::Any
+        }
+    }
+
+    fn to_schema(self) -> Value {
+        match self {
+            TagType::String => json!({ "type": "string" }),
+            TagType::Any => json!({}),
+        }
+    }
+}
+
+/// Types of one tag with and without PrintConv
+#[derive(Debug, Clone, Copy)]
+struct TagTypes {
+    print: TagType,
+    numeric: TagType,
+}
+
+impl TagTypes {
+    fn get(self, numeric: bool) -> TagType {
+        if numeric {
+            self.numeric
+        } else {
+            self.print
+        }
+    }
+
+    fn merge(self, other: Self) -> Self {
+        Self {
+            print: self.print.merge(other.print),
+            numeric: self.numeric.merge(other.numeric),
+        }
+    }
+}
+
+/// File group tags that are always strings (see `formats::extract_metadata`)
+const FILE_STRING_TAGS: &[&str] = &[
+    "FileName",
+    "Directory",
+    "FileModifyDate",
+    "FileAccessDate",
+    "FileInodeChangeDate",
+    "FileCreateDate",
+    "FilePermissions",
+    "FileType",
+    "FileTypeExtension",
+    "MIMEType",
+    "ExifByteOrder",
+];
+
+/// Value types of a generated tag table entry
+///
+/// Lookup PrintConvs always print a string ("Unknown (N)" for values missing
+/// from the table). Without PrintConv, only string and undefined formats are
+/// known to stay strings; the rest are numbers or arrays depending on count.
+fn tag_info_types(info: &TagInfo) -> TagTypes {
+    let numeric = match info.format {
+        "string" | "undef" | "binary" => TagType::String,
+        _ => TagType::Any,
+    };
+    let print = match &info.print_conv {
+        Some(PrintConv::Simple(_)) => TagType::String,
+        None | Some(PrintConv::None) => numeric,
+        Some(_) => TagType::Any,
+    };
+    TagTypes { print, numeric }
+}
+
+/// Known tags by `Group:TagName` key
+static TAG_TYPES: LazyLock<BTreeMap<String, TagTypes>> = LazyLock::new(|| {
+    let mut types: BTreeMap<String, TagTypes> = BTreeMap::new();
+    let mut add = |key: String, tag_types: TagTypes| {
+        types
+            .entry(key)
+            .and_modify(|existing| *existing = existing.merge(tag_types))
+            .or_insert(tag_types);
+    };
+
+    let string = TagTypes {
+        print: TagType::String,
+        numeric: TagType::String,
+    };
+    let any = TagTypes {
+        print: TagType::Any,
+        numeric: TagType::Any,
+    };
+
+    for name in FILE_STRING_TAGS {
+        add(format!("File:{name}"), string);
+    }
+    add("File:FileSize".to_string(), any);
+
+    // GPS tags have Group0="EXIF" (GPS.pm:52)
+    let tables: [&HashMap<u16, TagInfo>; 2] = [&EXIF_MAIN_TAGS, &GPS_MAIN_TAGS];
+    for info in tables.into_iter().flat_map(|table| table.values()) {
+        add(format!("EXIF:{}", info.name), tag_info_types(info));
+    }
+
+    // Composite values are computed by ValueConv/PrintConv code
+    for def in COMPOSITE_TAGS.values().chain(MANUAL_COMPOSITES) {
+        add(format!("Composite:{}", def.name), any);
+    }
+
+    // ASCII tags (`Writable => 'string'`) override what the table entries
+    // imply: most have no explicit Format, so their TagInfo format is "unknown"
+    for name in EXIF_MAIN_STRING_TAGS.iter().chain(GPS_MAIN_STRING_TAGS) {
+        types.insert(format!("EXIF:{name}"), string);
+    }
+
+    types
+});
+
+/// JSON type of the tag output under `key` ("Group:TagName")
+///
+/// `numeric` selects the types for `-n` output. Unknown tags are
+/// [`TagType::Any`].
+pub fn tag_type(key: &str, numeric: bool) -> TagType {
+    TAG_TYPES
+        .get(key)
+        .map_or(TagType::Any, |types| types.get(numeric))
+}
+
+/// JSON Schema (draft 2020-12) for the JSON output
+///
+/// `numeric` describes `-n` output, where lookup tags are numbers instead of
+/// strings.
+pub fn json_schema(numeric: bool) -> Value {
+    let mut properties = serde_json::Map::new();
+    properties.insert("SourceFile".to_string(), json!({ "type": "string" }));
+    properties.insert("ExifToolVersion".to_string(), json!({ "type": "string" }));
+    for (key, types) in TAG_TYPES.iter() {
+        properties.insert(key.clone(), types.get(numeric).to_schema());
+    }
+    properties.insert(
+        "errors".to_string(),
+        json!({ "type": "array", "items": { "type": "string" } }),
+    );
+    properties.insert(
+        "MissingImplementations".to_string(),
+        json!({ "type": "array", "items": { "type": "string" } }),
+    );
+    properties.insert(
+        "TagSources".to_string(),
+        json!({
+            "type": "object",
+            "additionalProperties": { "$ref": "#/$defs/tagSource" }
+        }),
+    );
+    properties.insert(
+        "XMPPacket".to_string(),
+        json!({ "$ref": "#/$defs/xmpPacket" }),
+    );
+    properties.insert(
+        "Statistics".to_string(),
+        json!({ "$ref": "#/$defs/statistics" }),
+    );
+
+    let title = if numeric {
+        "exif-oxide JSON output (-n)"
+    } else {
+        "exif-oxide JSON output"
+    };
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": title,
+        "description": "One object per file. Tags are keyed as Group:TagName; \
+                        tags not listed here may also be present.",
+        "type": "array",
+        "items": { "$ref": "#/$defs/file" },
+        "$defs": {
+            "file": {
+                "type": "object",
+                "required": ["SourceFile"],
+                "properties": properties,
+                "additionalProperties": true
+            },
+            "tagSource": {
+                "type": "object",
+                "required": ["Table", "Processor"],
+                "properties": {
+                    "Table": { "type": "string" },
+                    "Processor": { "type": "string" },
+                    "TagID": { "type": "string" },
+                    "Offset": { "type": "integer", "minimum": 0 },
+                    "Format": { "type": "string" },
+                    "Count": { "type": "integer", "minimum": 0 }
+                },
+                "additionalProperties": false
+            },
+            "xmpPacket": {
+                "type": "object",
+                "required": [
+                    "Container", "Offset", "Length", "HasWrapper", "Padding", "Writable", "Extended"
+                ],
+                "properties": {
+                    "Container": { "type": "string" },
+                    "Offset": { "type": "integer", "minimum": 0 },
+                    "Length": { "type": "integer", "minimum": 0 },
+                    "HasWrapper": { "type": "boolean" },
+                    "Padding": { "type": "integer", "minimum": 0 },
+                    "Writable": { "type": "boolean" },
+                    "Extended": { "type": "boolean" }
+                },
+                "additionalProperties": false
+            },
+            "statistics": {
+                "type": "object",
+                "required": ["TotalMicroseconds", "BytesRead", "Stages", "TagCounts"],
+                "properties": {
+                    "TotalMicroseconds": { "type": "integer", "minimum": 0 },
+                    "BytesRead": { "type": "integer", "minimum": 0 },
+                    "Stages": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "required": ["Stage", "Calls", "Microseconds", "BytesRead"],
+                            "properties": {
+                                "Stage": { "type": "string" },
+                                "Calls": { "type": "integer", "minimum": 0 },
+                                "Microseconds": { "type": "integer", "minimum": 0 },
+                                "BytesRead": { "type": "integer", "minimum": 0 }
+                            },
+                            "additionalProperties": false
+                        }
+                    },
+                    "TagCounts": {
+                        "type": "object",
+                        "additionalProperties": { "type": "integer", "minimum": 0 }
+                    }
+                },
+                "additionalProperties": false
+            }
+        }
+    })
+}
+
+/// Serializes [`ExifData`] so that it conforms to [`json_schema`]
+///
+/// Same fields and order as serializing the `ExifData` itself, after
+/// `prepare_for_serialization`, except that tags typed as strings are always
+/// written as JSON strings.
+pub struct TypedExifData<'a> {
+    data: &'a ExifData,
+    numeric: bool,
+}
+
+impl<'a> TypedExifData<'a> {
+    /// `numeric` must match the `-n` setting the data was extracted with
+    pub fn new(data: &'a ExifData, numeric: bool) -> Self {
+        Self { data, numeric }
+    }
+}
+
+impl Serialize for TypedExifData<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let data = self.data;
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("SourceFile", &data.source_file)?;
+        if !data.exif_tool_version.is_empty() {
+            map.serialize_entry("ExifToolVersion", &data.exif_tool_version)?;
+        }
+        for (key, value) in &data.legacy_tags {
+            match (tag_type(key, self.numeric), value) {
+                (TagType::String, TagValue::String(s)) => map.serialize_entry(key, s)?,
+                // e.g. a lookup tag requested with -TAG#
+                (TagType::String, value) => map.serialize_entry(key, &value.to_string())?,
+                (TagType::Any, value) => map.serialize_entry(key, value)?,
+            }
+        }
+        if !data.errors.is_empty() {
+            map.serialize_entry("errors", &data.errors)?;
+        }
+        if let Some(missing) = &data.missing_implementations {
+            map.serialize_entry("MissingImplementations", missing)?;
+        }
+        if !data.tag_sources.is_empty() {
+            map.serialize_entry("TagSources", &data.tag_sources)?;
+        }
+        if let Some(xmp_packet) = &data.xmp_packet {
+            map.serialize_entry("XMPPacket", xmp_packet)?;
+        }
+        if let Some(stats) = &data.stats {
+            map.serialize_entry("Statistics", stats)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_types() {
+        // Lookup PrintConv
+        assert_eq!(tag_type("EXIF:Orientation", false), TagType::String);
+        assert_eq!(tag_type("EXIF:Orientation", true), TagType::Any);
+        // string format
+        assert_eq!(tag_type("EXIF:Make", false), TagType::String);
+        assert_eq!(tag_type("EXIF:Make", true), TagType::String);
+        assert_eq!(tag_type("EXIF:GPSLatitudeRef", true), TagType::String);
+        assert_eq!(tag_type("File:MIMEType", true), TagType::String);
+        assert_eq!(tag_type("Composite:ImageSize", false), TagType::Any);
+        assert_eq!(tag_type("XMP:Rating", false), TagType::Any);
+    }
+
+    #[test]
+    fn test_typed_output_keeps_strings_quoted() {
+        let mut exif_data = ExifData::new("test.jpg".to_string(), "0.1".to_string());
+        let tags = [
+            ("EXIF:Make", TagValue::string("1234")),
+            ("EXIF:Orientation", TagValue::U16(1)),
+            ("EXIF:ISO", TagValue::string("100")),
+        ];
+        for (key, value) in tags {
+            exif_data.legacy_tags.insert(key.to_string(), value);
+        }
+
+        assert_eq!(
+            serde_json::to_string(&exif_data).unwrap(),
+            r#"{"SourceFile":"test.jpg","ExifToolVersion":"0.1","EXIF:Make":1234,"EXIF:Orientation":1,"EXIF:ISO":100}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&TypedExifData::new(&exif_data, false)).unwrap(),
+            r#"{"SourceFile":"test.jpg","ExifToolVersion":"0.1","EXIF:Make":"1234","EXIF:Orientation":"1","EXIF:ISO":100}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&TypedExifData::new(&exif_data, true)).unwrap(),
+            r#"{"SourceFile":"test.jpg","ExifToolVersion":"0.1","EXIF:Make":"1234","EXIF:Orientation":1,"EXIF:ISO":100}"#
+        );
+    }
+
+    #[test]
+    fn test_published_schema_is_current() {
+        let published: Value =
+            serde_json::from_str(include_str!("../docs/output.schema.json")).unwrap();
+        assert!(
+            published == json_schema(false),
+            "docs/output.schema.json is out of date; regenerate it with \
+             `exif-oxide --schema > docs/output.schema.json`"
+        );
+    }
+}
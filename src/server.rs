@@ -0,0 +1,432 @@
+//! HTTP extraction server (the `server` feature)
+//!
+//! Serves extraction over HTTP for deployments that would otherwise wrap the
+//! CLI in their own shim. The `exif-oxide-server` binary runs it; embedders
+//! can mount [`router`] in their own axum application instead.
+//!
+//! | Endpoint              | Request                          | Response                              |
+//! |-----------------------|----------------------------------|---------------------------------------|
+//! | `POST /extract`       | file bytes (`?filename=a.jpg`)   | JSON array with one object, like the CLI |
+//! | `POST /extract/paths` | file paths, one per line         | NDJSON, one object per file, streamed |
+//! | `GET /health`         | -                                | `ok`                                  |
+//!
+//! ```no_run
+//! use exif_oxide::server::{serve, ServerConfig};
+//! use exif_oxide::session::ExifSession;
+//!
+//! # async fn run() -> std::io::Result<()> {
+//! let listener = tokio::net::TcpListener::bind("127.0.0.1:8080").await?;
+//! let config = ServerConfig {
+//!     path_root: Some("/srv/photos".into()),
+//!     ..ServerConfig::default()
+//! };
+//! serve(listener, ExifSession::new().with_max_file_size(1 << 30), config).await
+//! # }
+//! ```
+//!
+//! Uploads are reported like ExifTool reading from a pipe: `SourceFile` is
+//! the `filename` parameter (or `-`), and the file system tags of the
+//! temporary copy (FileName, FileModifyDate, ...) are left out. Path requests
+//! are refused unless [`ServerConfig::path_root`] is set, and can't leave
+//! that directory.
+//!
+//! Failed extractions are `{"SourceFile": .., "Error": ..}` objects: the
+//! response to an upload (with status 422, or 504 after the timeout), or a
+//! line of the NDJSON stream.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::body::{Body, Bytes};
+use axum::extract::{DefaultBodyLimit, Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::Router;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tempfile::NamedTempFile;
+use tokio::sync::{mpsc, Semaphore};
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::debug;
+
+use crate::session::ExifSession;
+use crate::types::ExifData;
+
+/// Limits of a server
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    /// Extractions running at once. Further requests wait their turn
+    pub max_concurrent: usize,
+
+    /// How long an extraction may wait for its turn and run
+    pub timeout: Duration,
+
+    /// Largest upload `POST /extract` accepts, in bytes
+    pub max_body_size: usize,
+
+    /// Directory `POST /extract/paths` reads from. Without one, path
+    /// requests are refused
+    pub path_root: Option<PathBuf>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent: std::thread::available_parallelism().map_or(4, |n| n.get()),
+            timeout: Duration::from_secs(30),
+            max_body_size: 256 << 20,
+            path_root: None,
+        }
+    }
+}
+
+/// File system tags, which describe an upload's temporary copy rather than
+/// the uploaded file
+const UPLOAD_HIDDEN_TAGS: &[&str] = &[
+    "File:FileName",
+    "File:Directory",
+    "File:FileModifyDate",
+    "File:FileAccessDate",
+    "File:FileInodeChangeDate",
+    "File:FileCreateDate",
+    "File:FilePermissions",
+];
+
+struct ServerState {
+    session: ExifSession,
+    config: ServerConfig,
+    extractions: Arc<Semaphore>,
+}
+
+/// An extraction that didn't produce metadata
+enum Failure {
+    Error(String),
+    TimedOut,
+}
+
+impl ServerState {
+    /// Run `extract` on a blocking thread, within the concurrency limit and
+    /// the timeout
+    async fn extract<F>(&self, extract: F) -> Result<Value, Failure>
+    where
+        F: FnOnce(&ExifSession) -> crate::types::Result<Value> + Send + 'static,
+    {
+        let extractions = self.extractions.clone();
+        let session = self.session.clone();
+        let run = async move {
+            let permit = extractions
+                .acquire_owned()
+                .await
+                .expect("the semaphore is never closed");
+            tokio::task::spawn_blocking(move || {
+                // Held until the extraction finishes, even if the request
+                // timed out
+                let _permit = permit;
+                extract(&session)
+            })
+            .await
+        };
+        match tokio::time::timeout(self.config.timeout, run).await {
+            Err(_) => Err(Failure::TimedOut),
+            Ok(Err(panic)) => Err(Failure::Error(format!("Extraction failed: {panic}"))),
+            Ok(Ok(result)) => result.map_err(|e| Failure::Error(e.to_string())),
+        }
+    }
+}
+
+/// Routes serving extraction with `session`'s options and `config`'s limits
+pub fn router(session: ExifSession, config: ServerConfig) -> Router {
+    let state = ServerState {
+        session,
+        extractions: Arc::new(Semaphore::new(config.max_concurrent.max(1))),
+        config,
+    };
+    let max_body_size = state.config.max_body_size;
+    Router::new()
+        .route("/extract", post(extract_upload))
+        .route("/extract/paths", post(extract_paths))
+        .route("/health", get(|| async { "ok" }))
+        .layer(DefaultBodyLimit::max(max_body_size))
+        .with_state(Arc::new(state))
+}
+
+/// Serve [`router`] on `listener` until the process ends
+pub async fn serve(
+    listener: tokio::net::TcpListener,
+    session: ExifSession,
+    config: ServerConfig,
+) -> std::io::Result<()> {
+    axum::serve(listener, router(session, config)).await
+}
+
+#[derive(Debug, Deserialize)]
+struct UploadParams {
+    /// Reported as SourceFile; its extension guides file type detection
+    filename: Option<String>,
+}
+
+async fn extract_upload(
+    State(state): State<Arc<ServerState>>,
+    Query(params): Query<UploadParams>,
+    body: Bytes,
+) -> Response {
+    let source_file = params.filename.unwrap_or_else(|| "-".to_string());
+    let extension = upload_extension(&source_file, &body);
+    let name = source_file.clone();
+    let result = state
+        .extract(move |session| {
+            let upload = Upload::write(&body, &extension)?;
+            let mut exif_data = session.extract(upload.path())?;
+            exif_data.source_file = name;
+            session.prepare_for_serialization(&mut exif_data);
+            for key in UPLOAD_HIDDEN_TAGS {
+                exif_data.legacy_tags.shift_remove(*key);
+                exif_data.tag_sources.shift_remove(*key);
+            }
+            to_json(&exif_data)
+        })
+        .await;
+
+    match result {
+        Ok(metadata) => json_response(StatusCode::OK, &Value::Array(vec![metadata])),
+        Err(failure) => {
+            let status = match failure {
+                Failure::Error(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                Failure::TimedOut => StatusCode::GATEWAY_TIMEOUT,
+            };
+            json_response(status, &failure_json(&source_file, failure))
+        }
+    }
+}
+
+async fn extract_paths(State(state): State<Arc<ServerState>>, body: String) -> Response {
+    let Some(root) = state.config.path_root.clone() else {
+        let error = json!({ "Error": "Path requests are disabled on this server" });
+        return json_response(StatusCode::FORBIDDEN, &error);
+    };
+
+    let paths: Vec<String> = body
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect();
+    // Extracted one at a time, each line sent as soon as it's ready
+    let (lines, receiver) = mpsc::channel::<Result<Bytes, std::io::Error>>(1);
+    tokio::spawn(async move {
+        for requested in paths {
+            let line = match resolve_path(&root, &requested) {
+                None => failure_json(&requested, Failure::Error("File not found".to_string())),
+                Some(path) => {
+                    let source_file = requested.clone();
+                    let result = state
+                        .extract(move |session| {
+                            let mut exif_data = session.extract(&path)?;
+                            exif_data.source_file = source_file;
+                            session.prepare_for_serialization(&mut exif_data);
+                            to_json(&exif_data)
+                        })
+                        .await;
+                    result.unwrap_or_else(|failure| failure_json(&requested, failure))
+                }
+            };
+            let mut line = line.to_string();
+            line.push('\n');
+            if lines.send(Ok(Bytes::from(line))).await.is_err() {
+                debug!("Client went away, stopping path extraction");
+                return;
+            }
+        }
+    });
+
+    (
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(ReceiverStream::new(receiver)),
+    )
+        .into_response()
+}
+
+/// `requested`, if it names a file inside `root`
+fn resolve_path(root: &Path, requested: &str) -> Option<PathBuf> {
+    let root = root.canonicalize().ok()?;
+    let path = root.join(requested).canonicalize().ok()?;
+    (path.starts_with(&root) && path.is_file()).then_some(path)
+}
+
+/// File name extension for an upload's temporary copy: the one of the
+/// `filename` parameter, or else the one of the type its content is
+fn upload_extension(filename: &str, data: &[u8]) -> String {
+    use crate::generated::ExifTool_pm::file_type_ext::lookup_file_type_ext;
+
+    let named = Path::new(filename)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .filter(|ext| ext.chars().all(|c| c.is_ascii_alphanumeric()));
+    if let Some(ext) = named {
+        return ext.to_string();
+    }
+    crate::file_detection::detect_file_type_from_bytes(data).map_or_else(
+        |_| "bin".to_string(),
+        |detected| {
+            lookup_file_type_ext(&detected.file_type)
+                .map_or_else(|| detected.file_type.to_lowercase(), str::to_string)
+        },
+    )
+}
+
+/// An upload written to a temporary file (extraction reads from paths),
+/// removed on drop
+///
+/// The file gets a random name and is created exclusively, so another user
+/// of the temp directory can't plant a file or link where it will be written.
+struct Upload {
+    file: NamedTempFile,
+}
+
+impl Upload {
+    fn write(data: &[u8], extension: &str) -> std::io::Result<Self> {
+        let mut file = tempfile::Builder::new()
+            .prefix("exif-oxide-upload-")
+            .suffix(&format!(".{extension}"))
+            .tempfile()?;
+        file.write_all(data)?;
+        file.flush()?;
+        Ok(Self { file })
+    }
+
+    fn path(&self) -> &Path {
+        self.file.path()
+    }
+}
+
+fn to_json(exif_data: &ExifData) -> crate::types::Result<Value> {
+    serde_json::to_value(exif_data).map_err(|e| {
+        crate::types::ExifError::ParseError(format!("Failed to serialize to JSON: {e}"))
+    })
+}
+
+fn failure_json(source_file: &str, failure: Failure) -> Value {
+    let message = match failure {
+        Failure::Error(message) => message,
+        Failure::TimedOut => "Extraction timed out".to_string(),
+    };
+    json!({ "SourceFile": source_file, "Error": message })
+}
+
+fn json_response(status: StatusCode, value: &Value) -> Response {
+    (
+        status,
+        [(header::CONTENT_TYPE, "application/json")],
+        value.to_string(),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JPEG: &[u8] = b"\xff\xd8\xff\xe0\0\x10JFIF\0\x01\x01\0\0\x01\0\x01\0\0\xff\xd9";
+
+    /// Start a server and send it one HTTP/1.1 request, returning the
+    /// status code and body
+    async fn request(config: ServerConfig, request_line: &str, body: &[u8]) -> (u16, String) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(serve(listener, ExifSession::new(), config));
+
+        let head = format!(
+            "{request_line} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        );
+        let request = [head.as_bytes(), body].concat();
+        let response = tokio::task::spawn_blocking(move || {
+            use std::io::{Read, Write};
+
+            let mut stream = std::net::TcpStream::connect(address).unwrap();
+            stream.write_all(&request).unwrap();
+            let mut response = Vec::new();
+            stream.read_to_end(&mut response).unwrap();
+            response
+        })
+        .await
+        .unwrap();
+
+        let response = String::from_utf8_lossy(&response).to_string();
+        let status = response[9..12].parse().unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        // The NDJSON stream is chunked: keep just the lines
+        let body = if head.contains("chunked") {
+            body.lines()
+                .filter(|line| line.starts_with('{'))
+                .collect::<Vec<_>>()
+                .join("\n")
+        } else {
+            body.to_string()
+        };
+        (status, body)
+    }
+
+    #[tokio::test]
+    async fn extracts_uploads() {
+        let (status, body) = request(
+            ServerConfig::default(),
+            "POST /extract?filename=a.png",
+            JPEG,
+        )
+        .await;
+        assert_eq!(status, 200);
+        let metadata: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(metadata[0]["SourceFile"], "a.png");
+        assert_eq!(metadata[0]["File:MIMEType"], "image/jpeg");
+        assert!(metadata[0].get("File:FileName").is_none());
+
+        let (status, body) = request(ServerConfig::default(), "POST /extract", b"").await;
+        assert_eq!(status, 422);
+        assert!(body.contains("\"Error\""));
+
+        let config = ServerConfig {
+            max_body_size: 8,
+            ..ServerConfig::default()
+        };
+        let (status, _) = request(config, "POST /extract", JPEG).await;
+        assert_eq!(status, 413);
+    }
+
+    #[test]
+    fn uploads_are_temporary() {
+        let upload = Upload::write(JPEG, "jpg").unwrap();
+        let path = upload.path().to_path_buf();
+        assert_eq!(path.extension(), Some("jpg".as_ref()));
+        assert_eq!(std::fs::read(&path).unwrap(), JPEG);
+        drop(upload);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn streams_paths_inside_the_root() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("a.jpg"), JPEG).unwrap();
+
+        let (status, _) = request(ServerConfig::default(), "POST /extract/paths", b"a.jpg").await;
+        assert_eq!(status, 403);
+
+        let config = ServerConfig {
+            path_root: Some(root.path().to_path_buf()),
+            ..ServerConfig::default()
+        };
+        let (status, body) = request(config, "POST /extract/paths", b"a.jpg\n../a.jpg\n").await;
+        assert_eq!(status, 200);
+        let lines: Vec<Value> = body
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["SourceFile"], "a.jpg");
+        assert_eq!(lines[0]["File:FileType"], "JPEG");
+        assert_eq!(lines[1]["Error"], "File not found");
+    }
+}
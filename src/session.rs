@@ -0,0 +1,264 @@
+//! Extraction sessions with their own configuration
+//!
+//! The free functions ([`crate::extract_metadata_with_filter`] and friends)
+//! read process-wide state: the user config from
+//! [`user_config::set_config`](crate::user_config::set_config), the database
+//! from [`geolocation::set_database`](crate::geolocation::set_database) and
+//! the handlers from [`register_format_handler`](crate::formats::register_format_handler).
+//! An [`ExifSession`] carries all of that itself, so callers with different
+//! needs (tests, or a server with per-tenant settings) don't step on each
+//! other:
+//!
+//! ```no_run
+//! use exif_oxide::session::ExifSession;
+//! use exif_oxide::types::{ExifContext, FilterOptions, TagValue};
+//! use std::path::Path;
+//!
+//! fn orientation_as_degrees(value: &TagValue, _ctx: Option<&ExifContext>) -> TagValue {
+//!     match value.as_u16() {
+//!         Some(6) => TagValue::string("90"),
+//!         Some(8) => TagValue::string("270"),
+//!         _ => TagValue::string("0"),
+//!     }
+//! }
+//!
+//! let session = ExifSession::new()
+//!     .with_options(FilterOptions::tags_only(vec!["Orientation".to_string()]))
+//!     .with_print_conv("Orientation", orientation_as_degrees)
+//!     .with_max_file_size(100 << 20);
+//! let metadata = session.extract(Path::new("photo.jpg"))?;
+//! # Ok::<(), exif_oxide::ExifError>(())
+//! ```
+//!
+//! While a session extracts a file, its settings replace the process-wide
+//! ones entirely: a session without a user config uses none, even if one is
+//! installed globally. The PrintConv/ValueConv implementations themselves
+//! are static and shared by every session.
+
+use std::cell::RefCell;
+use std::path::Path;
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use crate::formats::FormatHandler;
+use crate::geolocation::GeolocationDatabase;
+use crate::output::OutputFormat;
+use crate::registry::PrintConvFn;
+use crate::types::{ExifData, ExifError, FilterOptions, Result, TagEntry};
+use crate::user_config::UserConfig;
+
+/// Settings for a series of extractions
+///
+/// Cheap to clone and safe to share between threads.
+#[derive(Clone, Default)]
+pub struct ExifSession {
+    state: Arc<SessionState>,
+}
+
+/// The settings an [`ExifSession`] installs while it extracts
+#[derive(Clone, Default)]
+pub(crate) struct SessionState {
+    options: Option<FilterOptions>,
+    pub(crate) user_config: Option<Arc<UserConfig>>,
+    pub(crate) geolocation: Option<Arc<GeolocationDatabase>>,
+    pub(crate) format_handlers: Vec<Arc<dyn FormatHandler>>,
+    print_convs: IndexMap<String, PrintConvFn>,
+    max_file_size: Option<u64>,
+}
+
+impl ExifSession {
+    /// A session with default options and nothing configured
+    pub fn new() -> Self {
+        crate::init();
+        Self::default()
+    }
+
+    /// Filter and formatting options for every extraction
+    pub fn with_options(mut self, options: FilterOptions) -> Self {
+        self.state_mut().options = Some(options);
+        self
+    }
+
+    /// User-defined tags, as [`user_config::set_config`](crate::user_config::set_config)
+    pub fn with_user_config(mut self, config: UserConfig) -> Self {
+        self.state_mut().user_config = Some(Arc::new(config));
+        self
+    }
+
+    /// Geolocation database, as [`geolocation::set_database`](crate::geolocation::set_database)
+    pub fn with_geolocation_database(mut self, database: GeolocationDatabase) -> Self {
+        self.state_mut().geolocation = Some(Arc::new(database));
+        self
+    }
+
+    /// Add a format handler, replacing any with the same name
+    pub fn with_format_handler(mut self, handler: impl FormatHandler + 'static) -> Self {
+        let handlers = &mut self.state_mut().format_handlers;
+        handlers.retain(|registered| registered.name() != handler.name());
+        handlers.push(Arc::new(handler));
+        self
+    }
+
+    /// Replace the PrintConv of a tag, matched as `Tag` or `Group:Tag`
+    ///
+    /// The function receives the tag's ValueConv value. Tags requested as
+    /// numeric (`-n`, `Tag#`) are unaffected.
+    pub fn with_print_conv(mut self, tag: impl Into<String>, print_conv: PrintConvFn) -> Self {
+        self.state_mut().print_convs.insert(tag.into(), print_conv);
+        self
+    }
+
+    /// Refuse files larger than `bytes`
+    pub fn with_max_file_size(mut self, bytes: u64) -> Self {
+        self.state_mut().max_file_size = Some(bytes);
+        self
+    }
+
+    /// Extract metadata from a file
+    pub fn extract(&self, path: &Path) -> Result<ExifData> {
+        if let Some(limit) = self.state.max_file_size {
+            let size = std::fs::metadata(path)?.len();
+            if size > limit {
+                return Err(ExifError::Unsupported(format!(
+                    "File is {size} bytes, larger than the session limit of {limit}"
+                )));
+            }
+        }
+        self.scoped(|| {
+            crate::formats::extract_metadata(path, false, false, self.state.options.clone())
+        })
+    }
+
+    /// Extract metadata from a file as JSON, like the CLI
+    pub fn extract_json(&self, path: &Path) -> Result<Value> {
+        let mut exif_data = self.extract(path)?;
+        self.prepare_for_serialization(&mut exif_data);
+        serde_json::to_value(&exif_data)
+            .map_err(|e| ExifError::ParseError(format!("Failed to serialize to JSON: {e}")))
+    }
+
+    /// Extract metadata from a file and serialize it as `format`
+    pub fn extract_formatted(&self, path: &Path, format: OutputFormat) -> Result<String> {
+        let mut exif_data = self.extract(path)?;
+        self.prepare_for_serialization(&mut exif_data);
+        Ok(format.serializer().serialize_to_string(&[exif_data]))
+    }
+
+    pub(crate) fn prepare_for_serialization(&self, exif_data: &mut ExifData) {
+        let options = self.state.options.as_ref();
+        let numeric_tags = options
+            .map(|options| &options.numeric_tags)
+            .filter(|tags| !tags.is_empty());
+        exif_data.prepare_for_serialization(numeric_tags);
+        let default_options = FilterOptions::default();
+        let options = options.unwrap_or(&default_options);
+        exif_data.format_binary_values(options);
+        exif_data.apply_tag_order(options.tag_order);
+    }
+
+    fn state_mut(&mut self) -> &mut SessionState {
+        Arc::make_mut(&mut self.state)
+    }
+
+    /// Run `f` with this session's settings installed on the current thread
+    fn scoped<T>(&self, f: impl FnOnce() -> T) -> T {
+        struct Restore(Option<Arc<SessionState>>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                ACTIVE_SESSION.with(|active| *active.borrow_mut() = self.0.take());
+            }
+        }
+
+        let previous = ACTIVE_SESSION.with(|active| active.replace(Some(self.state.clone())));
+        let _restore = Restore(previous);
+        f()
+    }
+}
+
+impl SessionState {
+    /// Apply the session's PrintConv overrides to extracted tags
+    pub(crate) fn apply_print_convs(&self, tags: &mut [TagEntry]) {
+        if self.print_convs.is_empty() {
+            return;
+        }
+        for tag in tags {
+            let qualified = format!("{}:{}", tag.group, tag.name);
+            let print_conv = self
+                .print_convs
+                .get(&qualified)
+                .or_else(|| self.print_convs.get(tag.name.as_ref()));
+            if let Some(print_conv) = print_conv {
+                tag.set_print(print_conv(&tag.value, None));
+            }
+        }
+    }
+}
+
+// Extraction runs on the caller's thread, so a thread-local is enough to make
+// the active session visible to the code that reads the process-wide settings
+thread_local! {
+    static ACTIVE_SESSION: RefCell<Option<Arc<SessionState>>> = const { RefCell::new(None) };
+}
+
+/// The session extracting on this thread, if any
+pub(crate) fn current() -> Option<Arc<SessionState>> {
+    ACTIVE_SESSION.with(|active| active.borrow().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TagValue;
+
+    fn degrees(value: &TagValue, _ctx: Option<&crate::types::ExifContext>) -> TagValue {
+        TagValue::string(format!("{} deg", value))
+    }
+
+    #[test]
+    fn test_installs_settings_only_while_extracting() {
+        let config = UserConfig::from_json(r#"{"rename": {"Make": "Maker"}}"#).unwrap();
+        let session = ExifSession::new()
+            .with_user_config(config)
+            .with_print_conv("EXIF:Orientation", degrees);
+
+        assert!(current().is_none());
+        let seen = session.scoped(|| {
+            let state = current().unwrap();
+            assert!(crate::user_config::config().is_some());
+            let mut tags = vec![TagEntry {
+                group: "EXIF".into(),
+                group1: "IFD0".into(),
+                name: "Orientation".into(),
+                value: TagValue::U16(6),
+                print: TagValue::string("Rotate 90 CW").into(),
+                source: None,
+            }];
+            state.apply_print_convs(&mut tags);
+            tags[0].print().clone()
+        });
+        assert_eq!(seen, TagValue::string("6 deg"));
+        assert!(current().is_none());
+
+        // Without a session, the process-wide (here: absent) config applies
+        let bare = ExifSession::new();
+        bare.scoped(|| assert!(crate::user_config::config().is_none()));
+    }
+
+    #[test]
+    fn test_rejects_files_over_the_size_limit() {
+        let dir = std::env::temp_dir().join("exif_oxide_session_limit");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("big.bin");
+        std::fs::write(&path, [0u8; 64]).unwrap();
+
+        let session = ExifSession::new().with_max_file_size(16);
+        assert!(matches!(
+            session.extract(&path),
+            Err(ExifError::Unsupported(_))
+        ));
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -0,0 +1,346 @@
+//! Per-stage extraction statistics
+//!
+//! With [`FilterOptions::stats`](crate::types::FilterOptions::stats) set,
+//! [`crate::formats::extract_metadata`] reports how long each stage of the
+//! extraction took and how many bytes it read, plus the number of output tags
+//! in each group, as [`ExifData::stats`](crate::types::ExifData::stats):
+//!
+//! ```json
+//! "Statistics": {
+//!   "TotalMicroseconds": 1830,
+//!   "BytesRead": 65536,
+//!   "Stages": [
+//!     { "Stage": "Detection", "Calls": 1, "Microseconds": 41, "BytesRead": 8192 },
+//!     { "Stage": "EXIF", "Calls": 1, "Microseconds": 420, "BytesRead": 24576 },
+//!     { "Stage": "MakerNotes", "Calls": 1, "Microseconds": 910, "BytesRead": 14208 },
+//!     ...
+//!   ],
+//!   "TagCounts": { "File": 13, "EXIF": 52, "MakerNotes": 131, "Composite": 14 }
+//! }
+//! ```
+//!
+//! Stages nest: the time and bytes of a stage exclude those of the stages
+//! started inside it, so a file's stages add up to its total (less what is
+//! spent outside any stage). `BytesRead` counts bytes read from the file;
+//! stages that parse data already read into memory (EXIF, maker notes, XMP)
+//! count the size of that data instead.
+//!
+//! The recorder is per thread and only active while `extract_metadata` runs
+//! with statistics requested. Whether or not it is, every [`stage`] is also a
+//! tracing span and reports its time to the [`metrics`](crate::metrics)
+//! recorder, if one is installed.
+
+use std::cell::RefCell;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
+use std::time::Instant;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::metrics::MetricsRecorder;
+use crate::types::TagEntry;
+
+/// Time and bytes read by one stage, summed over its calls
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct StageStats {
+    /// Stage name: Detection, Format, EXIF, MakerNotes, XMP, ImageDataHash,
+    /// Composites or Output
+    pub stage: String,
+
+    /// Number of times the stage ran
+    pub calls: u64,
+
+    /// Time spent in the stage, excluding stages nested inside it
+    pub microseconds: u64,
+
+    /// Bytes read from the file (or parsed from memory) by the stage
+    pub bytes_read: u64,
+}
+
+/// Statistics for the extraction of one file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExtractionStats {
+    /// Wall-clock time of the whole extraction
+    pub total_microseconds: u64,
+
+    /// Bytes read from the file
+    pub bytes_read: u64,
+
+    /// Stages in the order they first ran
+    pub stages: Vec<StageStats>,
+
+    /// Number of output tags per family 0 group
+    pub tag_counts: IndexMap<String, usize>,
+}
+
+/// A stage that has started and not yet finished
+struct OpenStage {
+    index: usize,
+    start: Instant,
+    /// File bytes read when the stage started
+    bytes_at_start: u64,
+    /// Time, file bytes and parsed bytes of the stages nested inside this one
+    nested_micros: u64,
+    nested_bytes: u64,
+    nested_parsed_bytes: u64,
+    /// In-memory data parsed by the stage, including that of nested stages
+    parsed_bytes: u64,
+}
+
+struct Recorder {
+    start: Instant,
+    bytes_read: u64,
+    stages: Vec<StageStats>,
+    open: Vec<OpenStage>,
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Records statistics on this thread until it is finished or dropped
+pub(crate) struct StatsCollector {
+    _private: (),
+}
+
+impl StatsCollector {
+    /// Start recording, discarding any unfinished recording
+    pub(crate) fn start() -> Self {
+        RECORDER.with(|recorder| {
+            *recorder.borrow_mut() = Some(Recorder {
+                start: Instant::now(),
+                bytes_read: 0,
+                stages: Vec::new(),
+                open: Vec::new(),
+            })
+        });
+        Self { _private: () }
+    }
+
+    /// Stop recording and summarize it, counting `tags` by group
+    pub(crate) fn finish(self, tags: &[TagEntry]) -> ExtractionStats {
+        let recorder = RECORDER.with(|recorder| recorder.borrow_mut().take());
+        let mut tag_counts = IndexMap::new();
+        for tag in tags {
+            *tag_counts.entry(tag.group.to_string()).or_insert(0) += 1;
+        }
+        match recorder {
+            Some(recorder) => ExtractionStats {
+                total_microseconds: recorder.start.elapsed().as_micros() as u64,
+                bytes_read: recorder.bytes_read,
+                stages: recorder.stages,
+                tag_counts,
+            },
+            None => ExtractionStats {
+                total_microseconds: 0,
+                bytes_read: 0,
+                stages: Vec::new(),
+                tag_counts,
+            },
+        }
+    }
+}
+
+impl Drop for StatsCollector {
+    fn drop(&mut self) {
+        RECORDER.with(|recorder| recorder.borrow_mut().take());
+    }
+}
+
+/// Time a stage until the returned guard is dropped
+///
+/// Enters a `stage` tracing span, and times the stage for the metrics
+/// recorder and the statistics recording on this thread, if there are any.
+pub(crate) fn stage(name: &'static str) -> StageGuard {
+    let active = RECORDER.with(|recorder| {
+        let mut recorder = recorder.borrow_mut();
+        let Some(recorder) = recorder.as_mut() else {
+            return false;
+        };
+        let index = match recorder.stages.iter().position(|s| s.stage == name) {
+            Some(index) => index,
+            None => {
+                recorder.stages.push(StageStats {
+                    stage: name.to_string(),
+                    calls: 0,
+                    microseconds: 0,
+                    bytes_read: 0,
+                });
+                recorder.stages.len() - 1
+            }
+        };
+        recorder.stages[index].calls += 1;
+        let bytes_at_start = recorder.bytes_read;
+        recorder.open.push(OpenStage {
+            index,
+            start: Instant::now(),
+            bytes_at_start,
+            nested_micros: 0,
+            nested_bytes: 0,
+            nested_parsed_bytes: 0,
+            parsed_bytes: 0,
+        });
+        true
+    });
+    StageGuard {
+        active,
+        metrics: crate::metrics::recorder().map(|recorder| (recorder, name, Instant::now())),
+        _span: tracing::info_span!("stage", stage = name).entered(),
+    }
+}
+
+/// Count `bytes` of in-memory data parsed by the innermost open stage
+pub(crate) fn add_parsed_bytes(bytes: u64) {
+    RECORDER.with(|recorder| {
+        if let Some(open) = recorder
+            .borrow_mut()
+            .as_mut()
+            .and_then(|recorder| recorder.open.last_mut())
+        {
+            open.parsed_bytes += bytes;
+        }
+    });
+}
+
+/// Ends a stage started by [`stage`] when dropped
+#[must_use = "the stage ends when the guard is dropped"]
+pub(crate) struct StageGuard {
+    /// Whether statistics are being recorded
+    active: bool,
+    metrics: Option<(Arc<dyn MetricsRecorder>, &'static str, Instant)>,
+    _span: tracing::span::EnteredSpan,
+}
+
+impl Drop for StageGuard {
+    fn drop(&mut self) {
+        if let Some((recorder, name, start)) = self.metrics.take() {
+            let seconds = start.elapsed().as_secs_f64();
+            recorder.record_histogram(crate::metrics::STAGE_SECONDS, seconds, &[("stage", name)]);
+        }
+        if !self.active {
+            return;
+        }
+        RECORDER.with(|recorder| {
+            let mut recorder = recorder.borrow_mut();
+            let Some(recorder) = recorder.as_mut() else {
+                return;
+            };
+            let Some(open) = recorder.open.pop() else {
+                return;
+            };
+            let micros = open.start.elapsed().as_micros() as u64;
+            let bytes = recorder.bytes_read - open.bytes_at_start;
+            let stats = &mut recorder.stages[open.index];
+            stats.microseconds += micros.saturating_sub(open.nested_micros);
+            stats.bytes_read += bytes.saturating_sub(open.nested_bytes)
+                + open.parsed_bytes.saturating_sub(open.nested_parsed_bytes);
+            if let Some(parent) = recorder.open.last_mut() {
+                parent.nested_micros += micros;
+                parent.nested_bytes += bytes;
+                parent.nested_parsed_bytes += open.parsed_bytes;
+            }
+        });
+    }
+}
+
+/// Counts the bytes read through it towards the recording, if any
+pub(crate) struct CountingReader<R> {
+    inner: R,
+}
+
+impl<R> CountingReader<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        Self { inner }
+    }
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        RECORDER.with(|recorder| {
+            if let Some(recorder) = recorder.borrow_mut().as_mut() {
+                recorder.bytes_read += count as u64;
+            }
+        });
+        Ok(count)
+    }
+}
+
+impl<R: Seek> Seek for CountingReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TagValue;
+    use std::io::Cursor;
+
+    fn tag(group: &str, name: &str) -> TagEntry {
+        TagEntry {
+            group: group.into(),
+            group1: group.into(),
+            name: name.into(),
+            value: TagValue::U32(1),
+            print: TagValue::U32(1).into(),
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_nested_stages() {
+        let collector = StatsCollector::start();
+        let mut reader = CountingReader::new(Cursor::new(vec![0u8; 100]));
+        {
+            let _exif = stage("EXIF");
+            reader.read_exact(&mut [0; 40]).unwrap();
+            add_parsed_bytes(32);
+            for _ in 0..2 {
+                let _maker_notes = stage("MakerNotes");
+                add_parsed_bytes(8);
+            }
+        }
+        {
+            let _xmp = stage("XMP");
+            reader.read_exact(&mut [0; 10]).unwrap();
+        }
+        let tags = [
+            tag("EXIF", "Make"),
+            tag("EXIF", "Model"),
+            tag("MakerNotes", "FileNumber"),
+        ];
+        let stats = collector.finish(&tags);
+
+        assert_eq!(stats.bytes_read, 50);
+        let names: Vec<_> = stats.stages.iter().map(|s| s.stage.as_str()).collect();
+        assert_eq!(names, ["EXIF", "MakerNotes", "XMP"]);
+        // The maker notes are part of the EXIF data
+        assert_eq!(
+            (stats.stages[0].calls, stats.stages[0].bytes_read),
+            (1, 40 + 16)
+        );
+        assert_eq!((stats.stages[1].calls, stats.stages[1].bytes_read), (2, 16));
+        assert_eq!(stats.stages[2].bytes_read, 10);
+        let total: u64 = stats.stages.iter().map(|s| s.microseconds).sum();
+        assert!(total <= stats.total_microseconds);
+        assert_eq!(stats.tag_counts["EXIF"], 2);
+        assert_eq!(stats.tag_counts["MakerNotes"], 1);
+    }
+
+    #[test]
+    fn test_stages_without_collector_are_ignored() {
+        {
+            let _stage = stage("EXIF");
+            add_parsed_bytes(10);
+        }
+        let collector = StatsCollector::start();
+        drop(collector);
+        let _stage = stage("EXIF");
+        assert!(RECORDER.with(|recorder| recorder.borrow().is_none()));
+    }
+}
@@ -0,0 +1,329 @@
+//! File summary profile
+//!
+//! The handful of fields a media manager needs for every file, extracted with
+//! one call ([`crate::extract_summary`], CLI `--summary`) instead of assembling
+//! the tag filters and fallbacks by hand:
+//!
+//! | Field | Source, in order of preference |
+//! |-------|--------------------------------|
+//! | `CaptureDate` | Composite:BestDateTime (the date precedence ladder) |
+//! | `CaptureDateSource` | Composite:BestDateTimeSource |
+//! | `ImageWidth`, `ImageHeight` | Composite:ImageSize |
+//! | `Rotation` | EXIF:Orientation, or QuickTime/Composite Rotation, in degrees clockwise |
+//! | `Make`, `Model` | EXIF, QuickTime, then XMP |
+//! | `Lens` | Composite:LensID, then LensModel |
+//! | `GPSLatitude`, `GPSLongitude` | Composite GPS position, signed decimal degrees |
+//! | `MIMEType` | File:MIMEType |
+//! | `Duration` | Composite or QuickTime Duration, in seconds |
+//!
+//! Dates are ExifTool's `YYYY:mm:dd HH:MM:SS[.ss][+-HH:MM]` strings. Fields
+//! without a source are omitted. This is a stable profile: fields may be added,
+//! but existing fields keep their names and meaning.
+//!
+//! Only the tags listed in [`SUMMARY_TAGS`] are output from extraction, and
+//! all but `LensID` skip PrintConv.
+
+use std::io::{self, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::orientation::{parse_image_size, OrientationTransform};
+use crate::output::OutputSerializer;
+use crate::types::{ExifData, FilterOptions, TagEntry, TagValue};
+
+const ROTATION_SOURCES: &[(&str, &str)] = &[("Composite", "Rotation"), ("QuickTime", "Rotation")];
+
+const MAKE_SOURCES: &[(&str, &str)] = &[("EXIF", "Make"), ("QuickTime", "Make"), ("XMP", "Make")];
+
+const MODEL_SOURCES: &[(&str, &str)] =
+    &[("EXIF", "Model"), ("QuickTime", "Model"), ("XMP", "Model")];
+
+const LENS_MODEL_SOURCES: &[(&str, &str)] = &[
+    ("EXIF", "LensModel"),
+    ("XMP", "LensModel"),
+    ("MakerNotes", "LensModel"),
+];
+
+const DURATION_SOURCES: &[(&str, &str)] = &[("Composite", "Duration"), ("QuickTime", "Duration")];
+
+/// Tags extracted for the summary
+pub const SUMMARY_TAGS: &[&str] = &[
+    "BestDateTime",
+    "BestDateTimeSource",
+    // File tags are only read when requested; BestDateTime falls back to it
+    "FileModifyDate",
+    "ImageSize",
+    "Orientation",
+    "Rotation",
+    "Make",
+    "Model",
+    "LensID",
+    "LensModel",
+    "GPSLatitude",
+    "GPSLongitude",
+    "MIMEType",
+    "Duration",
+];
+
+/// Summary of one file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct FileSummary {
+    pub source_file: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_date_source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_height: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub make: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lens: Option<String>,
+    #[serde(rename = "GPSLatitude", skip_serializing_if = "Option::is_none")]
+    pub gps_latitude: Option<f64>,
+    #[serde(rename = "GPSLongitude", skip_serializing_if = "Option::is_none")]
+    pub gps_longitude: Option<f64>,
+    #[serde(rename = "MIMEType", skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+    /// Errors from extraction
+    #[serde(rename = "errors", skip_serializing_if = "Vec::is_empty", default)]
+    pub errors: Vec<String>,
+}
+
+impl FileSummary {
+    /// Filter that extracts only [`SUMMARY_TAGS`]
+    pub fn filter_options() -> FilterOptions {
+        let mut filter =
+            FilterOptions::tags_only(SUMMARY_TAGS.iter().map(|tag| tag.to_string()).collect());
+        // The lens name is LensID's PrintConv; everything else is read as ValueConv
+        filter.numeric_tags = SUMMARY_TAGS
+            .iter()
+            .filter(|tag| **tag != "LensID")
+            .map(|tag| tag.to_string())
+            .collect();
+        filter
+    }
+
+    /// Summarize tags extracted with [`Self::filter_options`] (or a superset)
+    pub fn from_exif_data(data: &ExifData) -> Self {
+        let tags = &data.tags;
+
+        let (image_width, image_height) = find_tag(tags, &[("Composite", "ImageSize")])
+            .and_then(|tag| parse_image_size(&tag.value))
+            .unzip();
+
+        let rotation = find_tag(tags, &[("EXIF", "Orientation")])
+            .and_then(|tag| tag.value.as_u16())
+            .and_then(OrientationTransform::from_orientation)
+            .map(|transform| transform.rotation)
+            .or_else(|| {
+                let rotation = value_f64(&find_tag(tags, ROTATION_SOURCES)?.value)?;
+                Some(rotation.rem_euclid(360.0) as u16)
+            });
+
+        let lens = find_tag(tags, &[("Composite", "LensID")])
+            .map(|tag| tag.print())
+            .or_else(|| find_tag(tags, LENS_MODEL_SOURCES).map(|tag| &tag.value))
+            .and_then(value_string)
+            .filter(|lens| !lens.is_empty() && !lens.starts_with("Unknown"));
+
+        Self {
+            source_file: data.source_file.clone(),
+            capture_date: find_tag(tags, &[("Composite", "BestDateTime")])
+                .and_then(|tag| value_string(&tag.value)),
+            capture_date_source: find_tag(tags, &[("Composite", "BestDateTimeSource")])
+                .and_then(|tag| value_string(&tag.value)),
+            image_width,
+            image_height,
+            rotation,
+            make: find_tag(tags, MAKE_SOURCES).and_then(|tag| value_string(&tag.value)),
+            model: find_tag(tags, MODEL_SOURCES).and_then(|tag| value_string(&tag.value)),
+            lens,
+            gps_latitude: find_tag(tags, &[("Composite", "GPSLatitude")])
+                .and_then(|tag| value_f64(&tag.value)),
+            gps_longitude: find_tag(tags, &[("Composite", "GPSLongitude")])
+                .and_then(|tag| value_f64(&tag.value)),
+            mime_type: find_tag(tags, &[("File", "MIMEType")])
+                .and_then(|tag| value_string(&tag.value)),
+            duration: find_tag(tags, DURATION_SOURCES).and_then(|tag| value_f64(&tag.value)),
+            errors: data.errors.clone(),
+        }
+    }
+}
+
+/// First tag found from `sources`, in order
+fn find_tag<'a>(tags: &'a [TagEntry], sources: &[(&str, &str)]) -> Option<&'a TagEntry> {
+    sources.iter().find_map(|&(group, name)| {
+        tags.iter()
+            .find(|tag| tag.group == group && tag.name == name)
+    })
+}
+
+fn value_string(value: &TagValue) -> Option<String> {
+    match value {
+        TagValue::String(s) => Some(s.trim().to_string()),
+        TagValue::Empty | TagValue::Binary(_) => None,
+        value => Some(value.to_string()),
+    }
+}
+
+fn value_f64(value: &TagValue) -> Option<f64> {
+    match value {
+        TagValue::String(s) => s.trim().parse().ok(),
+        TagValue::I16(v) => Some(*v as f64),
+        TagValue::I32(v) => Some(*v as f64),
+        TagValue::Rational(n, d) if *d != 0 => Some(*n as f64 / *d as f64),
+        TagValue::SRational(n, d) if *d != 0 => Some(*n as f64 / *d as f64),
+        value => value.as_f64(),
+    }
+}
+
+/// JSON array of [`FileSummary`] objects (CLI: `--summary`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SummarySerializer;
+
+impl OutputSerializer for SummarySerializer {
+    fn write(&self, files: &[ExifData], out: &mut dyn Write) -> io::Result<()> {
+        let summaries: Vec<FileSummary> = files.iter().map(FileSummary::from_exif_data).collect();
+        serde_json::to_writer_pretty(&mut *out, &summaries)?;
+        writeln!(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(group: &str, name: &str, value: TagValue, print: TagValue) -> TagEntry {
+        TagEntry {
+            group: group.into(),
+            group1: group.into(),
+            name: name.into(),
+            value,
+            print: print.into(),
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_summary_fields() {
+        let mut data = ExifData::new("a.jpg".to_string(), String::new());
+        let date = TagValue::string("2024:05:06 07:08:09+02:00");
+        let date_source = TagValue::string("EXIF:DateTimeOriginal");
+        data.tags = vec![
+            tag("File", "MIMEType", "image/jpeg".into(), "image/jpeg".into()),
+            tag("Composite", "BestDateTime", date.clone(), date.clone()),
+            tag(
+                "Composite",
+                "BestDateTimeSource",
+                date_source.clone(),
+                date_source.clone(),
+            ),
+            tag(
+                "EXIF",
+                "Orientation",
+                TagValue::U16(6),
+                "Rotate 90 CW".into(),
+            ),
+            tag("EXIF", "Make", "Canon".into(), "Canon".into()),
+            tag(
+                "EXIF",
+                "Model",
+                "Canon EOS R5".into(),
+                "Canon EOS R5".into(),
+            ),
+            tag(
+                "EXIF",
+                "LensModel",
+                "RF24-105mm".into(),
+                "RF24-105mm".into(),
+            ),
+            tag(
+                "Composite",
+                "LensID",
+                TagValue::U16(61182),
+                "Canon RF 24-105mm F4 L IS USM".into(),
+            ),
+            tag(
+                "Composite",
+                "ImageSize",
+                "8192 5464".into(),
+                "8192x5464".into(),
+            ),
+            tag(
+                "Composite",
+                "GPSLatitude",
+                TagValue::F64(-33.5),
+                "33.5 S".into(),
+            ),
+            tag(
+                "Composite",
+                "GPSLongitude",
+                TagValue::F64(151.25),
+                "151.25 E".into(),
+            ),
+        ];
+
+        let summary = FileSummary::from_exif_data(&data);
+        assert_eq!(
+            summary,
+            FileSummary {
+                source_file: "a.jpg".to_string(),
+                capture_date: Some("2024:05:06 07:08:09+02:00".to_string()),
+                capture_date_source: Some("EXIF:DateTimeOriginal".to_string()),
+                image_width: Some(8192),
+                image_height: Some(5464),
+                rotation: Some(90),
+                make: Some("Canon".to_string()),
+                model: Some("Canon EOS R5".to_string()),
+                lens: Some("Canon RF 24-105mm F4 L IS USM".to_string()),
+                gps_latitude: Some(-33.5),
+                gps_longitude: Some(151.25),
+                mime_type: Some("image/jpeg".to_string()),
+                duration: None,
+                errors: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_summary_video_fallbacks() {
+        let mut data = ExifData::new("a.mov".to_string(), String::new());
+        data.tags = vec![
+            tag("Composite", "Rotation", TagValue::I32(-90), "-90".into()),
+            tag(
+                "QuickTime",
+                "Duration",
+                TagValue::F64(12.5),
+                "12.50 s".into(),
+            ),
+        ];
+
+        let summary = FileSummary::from_exif_data(&data);
+        assert_eq!(summary.rotation, Some(270));
+        assert_eq!(summary.duration, Some(12.5));
+        assert_eq!(
+            serde_json::to_string(&summary).unwrap(),
+            r#"{"SourceFile":"a.mov","Rotation":270,"Duration":12.5}"#
+        );
+    }
+
+    #[test]
+    fn test_summary_filter_options() {
+        let filter = FileSummary::filter_options();
+        assert!(!filter.extract_all);
+        assert!(filter.should_extract_tag("BestDateTime", "Composite"));
+        assert!(!filter.should_extract_tag("ExposureTime", "EXIF"));
+        assert!(filter.should_use_numeric("Orientation"));
+        assert!(!filter.should_use_numeric("LensID"));
+    }
+}
@@ -0,0 +1,196 @@
+//! Warming and memory reporting for the generated lookup tables
+//!
+//! Every tag table, PrintConv lookup and XMP namespace table generated from
+//! ExifTool is a `LazyLock`, built the first time it's used. That keeps
+//! start-up cheap and never builds tables for cameras a process doesn't see,
+//! but the first file from a new manufacturer pays for building them. Servers
+//! can move that cost to start-up instead:
+//!
+//! ```no_run
+//! use exif_oxide::tables;
+//!
+//! // Warm what this deployment sees most, in the foreground
+//! tables::initialize_group("Exif")?;
+//! tables::initialize_group("Canon")?;
+//! // ...and everything else in the background
+//! let warming = tables::initialize_all_async();
+//!
+//! for usage in tables::memory_report() {
+//!     println!("{}: {}/{} tables, {} bytes", usage.group, usage.loaded, usage.tables, usage.bytes);
+//! }
+//! # warming.join().unwrap();
+//! # Ok::<(), exif_oxide::ExifError>(())
+//! ```
+//!
+//! Groups are ExifTool module names ("Canon", "Nikon", "QuickTime", "XMP"),
+//! plus "Composite". Modules behind a disabled Cargo feature aren't compiled
+//! in, so they aren't groups either.
+
+use std::collections::{HashMap, HashSet};
+use std::mem::size_of;
+use std::thread::JoinHandle;
+
+use crate::generated::TABLE_GROUPS;
+use crate::types::{ExifError, Result};
+
+/// One generated lookup table
+pub struct LazyTable {
+    /// Path of the table's static within its module
+    pub name: &'static str,
+    load: fn(),
+    loaded_bytes: fn() -> Option<usize>,
+}
+
+impl LazyTable {
+    #[doc(hidden)]
+    pub const fn new(name: &'static str, load: fn(), loaded_bytes: fn() -> Option<usize>) -> Self {
+        Self {
+            name,
+            load,
+            loaded_bytes,
+        }
+    }
+
+    /// Build the table if it hasn't been built yet
+    pub fn load(&self) {
+        (self.load)()
+    }
+
+    /// Estimated heap size of the table, or `None` if it isn't built yet
+    pub fn loaded_bytes(&self) -> Option<usize> {
+        (self.loaded_bytes)()
+    }
+}
+
+/// Describe a generated `LazyLock` table; used by the generated `TABLES` lists
+macro_rules! lazy_table {
+    ($table:path) => {
+        $crate::tables::LazyTable::new(
+            stringify!($table),
+            || {
+                std::sync::LazyLock::force(&$table);
+            },
+            || std::sync::LazyLock::get(&$table).map($crate::tables::heap_size),
+        )
+    };
+}
+pub(crate) use lazy_table;
+
+/// Memory used by the tables of one group
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableGroupUsage {
+    /// ExifTool module name
+    pub group: &'static str,
+    /// Number of tables in the group
+    pub tables: usize,
+    /// Number of those that have been built
+    pub loaded: usize,
+    /// Estimated heap bytes of the built tables
+    pub bytes: usize,
+}
+
+/// Names of the table groups compiled into this build
+pub fn groups() -> Vec<&'static str> {
+    TABLE_GROUPS.iter().map(|(group, _)| *group).collect()
+}
+
+/// Build every table of a group, returning how many there are
+///
+/// `group` is matched case-insensitively.
+pub fn initialize_group(group: &str) -> Result<usize> {
+    let (_, tables) = TABLE_GROUPS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(group))
+        .ok_or_else(|| ExifError::Unsupported(format!("Unknown table group: {group}")))?;
+    tables.iter().for_each(LazyTable::load);
+    Ok(tables.len())
+}
+
+/// Build every table, returning how many there are
+pub fn initialize_all() -> usize {
+    TABLE_GROUPS
+        .iter()
+        .flat_map(|(_, tables)| tables.iter())
+        .inspect(|table| table.load())
+        .count()
+}
+
+/// Build every table on a background thread
+///
+/// Extraction can proceed meanwhile; a table that's being built blocks only
+/// the threads that need it.
+pub fn initialize_all_async() -> JoinHandle<usize> {
+    std::thread::Builder::new()
+        .name("exif-oxide-tables".to_string())
+        .spawn(initialize_all)
+        .expect("failed to spawn table initialization thread")
+}
+
+/// Tables built so far and their estimated memory, by group
+pub fn memory_report() -> Vec<TableGroupUsage> {
+    TABLE_GROUPS
+        .iter()
+        .map(|(group, tables)| {
+            let sizes: Vec<usize> = tables.iter().filter_map(LazyTable::loaded_bytes).collect();
+            TableGroupUsage {
+                group,
+                tables: tables.len(),
+                loaded: sizes.len(),
+                bytes: sizes.iter().sum(),
+            }
+        })
+        .collect()
+}
+
+/// Heap size estimate of a table
+///
+/// Counts the table's own allocation (entries plus hashbrown's control
+/// bytes), not the `'static` data its entries point to.
+#[doc(hidden)]
+pub fn heap_size<T: TableSize>(table: &T) -> usize {
+    table.heap_size()
+}
+
+/// Collections the generated tables are built from
+#[doc(hidden)]
+pub trait TableSize {
+    fn heap_size(&self) -> usize;
+}
+
+impl<K, V, S> TableSize for HashMap<K, V, S> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * (size_of::<(K, V)>() + 1)
+    }
+}
+
+impl<K, S> TableSize for HashSet<K, S> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * (size_of::<K>() + 1)
+    }
+}
+
+impl<T> TableSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warms_groups_and_reports_memory() {
+        assert!(groups().contains(&"Exif"));
+        assert!(initialize_group("nosuchmaker").is_err());
+
+        let count = initialize_group("gps").unwrap();
+        assert!(count > 0);
+        let gps = memory_report()
+            .into_iter()
+            .find(|usage| usage.group == "GPS")
+            .unwrap();
+        assert_eq!(gps.loaded, gps.tables);
+        assert!(gps.bytes > 0);
+    }
+}
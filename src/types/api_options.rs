@@ -0,0 +1,219 @@
+//! ExifTool API options
+//!
+//! Scripts migrated from Image::ExifTool (or `exiftool -api OPT=VAL`) carry
+//! a set of API options. [`ApiOptions`] takes them by their ExifTool names
+//! and applies them to [`FilterOptions`]:
+//!
+//! | ExifTool option    | Effect here                                            |
+//! |--------------------|--------------------------------------------------------|
+//! | `RequestAll`       | 3 or more computes ImageDataHash; see below            |
+//! | `RequestTags`      | `ImageDataHash` computes it; other tags are extracted anyway |
+//! | `Duplicates`       | [`FilterOptions::allow_duplicates`]                    |
+//! | `CharsetEXIF`      | [`FilterOptions::charset_exif`] (`UTF8`, `Latin`)      |
+//! | `QuickTimeUTC`     | [`FilterOptions::quicktime_utc`], plus `auto`          |
+//! | `LargeFileSupport` | accepted; files over 2 GB are always read              |
+//! | `ImageHashType`    | [`FilterOptions::image_hash_type`]                     |
+//! | `Geolocation`      | [`FilterOptions::geolocation`]                         |
+//!
+//! Differences from ExifTool:
+//!
+//! - `RequestAll` 1 and 2 make ExifTool generate tags it otherwise only
+//!   builds on request. Every such tag exif-oxide supports is already
+//!   extracted by default, so only level 3 (ImageDataHash) changes anything.
+//! - `RequestTags` doesn't filter the output, as in ExifTool; use
+//!   [`FilterOptions::requested_tags`] for that.
+//! - `Duplicates` defaults to on in the ExifTool API but off in the
+//!   `exiftool` application and here. Option values that aren't set leave
+//!   the [`FilterOptions`] setting unchanged.
+//! - `CharsetEXIF` undefined (ExifTool's default) passes the bytes through;
+//!   here strings are always UTF-8, so invalid sequences become U+FFFD.
+//!   Character sets other than UTF8 and Latin (cp1252) are rejected.
+//! - `LargeFileSupport=0` makes ExifTool refuse files over 2 GB; exif-oxide
+//!   reads them regardless.
+//!
+//! Group names are chosen with [`FilterOptions::group_family`] (`-G0`,
+//! `-G1`), which ExifTool sets per call rather than as an API option.
+//!
+//! ExifTool Reference: lib/Image/ExifTool.pm Options(), %defaultOptions
+
+use crate::hash::ImageHashType;
+use crate::types::{ExifCharset, ExifError, FilterOptions, QuickTimeUtc, Result};
+
+/// ExifTool API options, by their ExifTool names (see the module docs)
+///
+/// `None` means the option wasn't set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiOptions {
+    /// ExifTool: RequestAll (0-3)
+    pub request_all: Option<u8>,
+    /// ExifTool: RequestTags
+    pub request_tags: Vec<String>,
+    /// ExifTool: Duplicates
+    pub duplicates: Option<bool>,
+    /// ExifTool: CharsetEXIF
+    pub charset_exif: Option<ExifCharset>,
+    /// ExifTool: QuickTimeUTC
+    pub quicktime_utc: Option<QuickTimeUtc>,
+    /// ExifTool: LargeFileSupport. Recorded only; see the module docs
+    pub large_file_support: Option<bool>,
+    /// ExifTool: ImageHashType
+    pub image_hash_type: Option<ImageHashType>,
+    /// ExifTool: Geolocation
+    pub geolocation: Option<bool>,
+}
+
+/// A Perl truth value, as ExifTool reads option values: `""` and `"0"` are
+/// false. `off`, `false` and `no` are also accepted as false.
+fn parse_flag(value: &str) -> bool {
+    !matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "" | "0" | "off" | "false" | "no"
+    )
+}
+
+impl ApiOptions {
+    /// Set an option from its ExifTool name (any case) and value
+    ///
+    /// `exiftool -api OPT` without a value sets it to 1. Unknown options
+    /// are [`ExifError::Unsupported`], bad values [`ExifError::ParseError`].
+    pub fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        let bad_value =
+            || ExifError::ParseError(format!("Invalid value '{value}' for API option {name}"));
+        match name.to_ascii_lowercase().as_str() {
+            "requestall" => {
+                let level = if value.trim().is_empty() {
+                    0
+                } else {
+                    value.trim().parse().map_err(|_| bad_value())?
+                };
+                self.request_all = Some(level);
+            }
+            "requesttags" => self.request_tags.extend(
+                value
+                    .split([',', ' '])
+                    .filter(|tag| !tag.is_empty())
+                    .map(str::to_string),
+            ),
+            "duplicates" => self.duplicates = Some(parse_flag(value)),
+            "charsetexif" => {
+                self.charset_exif = Some(ExifCharset::parse(value).ok_or_else(bad_value)?)
+            }
+            "quicktimeutc" => {
+                self.quicktime_utc = Some(if value.trim().eq_ignore_ascii_case("auto") {
+                    QuickTimeUtc::Auto
+                } else if parse_flag(value) {
+                    QuickTimeUtc::On
+                } else {
+                    QuickTimeUtc::Off
+                })
+            }
+            "largefilesupport" => self.large_file_support = Some(parse_flag(value)),
+            "imagehashtype" => {
+                self.image_hash_type =
+                    Some(ImageHashType::parse_known(value).ok_or_else(bad_value)?)
+            }
+            "geolocation" => self.geolocation = Some(parse_flag(value)),
+            _ => {
+                return Err(ExifError::Unsupported(format!(
+                    "API option {name} isn't supported"
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse `OPT=VAL` options, like `exiftool -api OPT=VAL ...`
+    pub fn from_args<'a>(options: impl IntoIterator<Item = &'a str>) -> Result<Self> {
+        let mut api_options = Self::default();
+        for option in options {
+            let (name, value) = option.split_once('=').unwrap_or((option, "1"));
+            api_options.set(name, value)?;
+        }
+        Ok(api_options)
+    }
+
+    /// Whether ImageDataHash is requested, by RequestTags or RequestAll 3
+    /// ExifTool: Extra.pm ImageDataHash notes
+    pub fn requests_image_hash(&self) -> bool {
+        self.request_all.is_some_and(|level| level >= 3)
+            || self
+                .request_tags
+                .iter()
+                .any(|tag| tag.eq_ignore_ascii_case("ImageDataHash"))
+    }
+
+    /// Apply the options that were set to `filter`
+    pub fn apply(&self, filter: &mut FilterOptions) {
+        if self.requests_image_hash() {
+            filter.compute_image_hash = true;
+        }
+        if let Some(image_hash_type) = self.image_hash_type {
+            filter.image_hash_type = image_hash_type;
+        }
+        if let Some(duplicates) = self.duplicates {
+            filter.allow_duplicates = duplicates;
+        }
+        if let Some(charset) = self.charset_exif {
+            filter.charset_exif = charset;
+        }
+        if let Some(quicktime_utc) = self.quicktime_utc {
+            filter.quicktime_utc = quicktime_utc;
+        }
+        if let Some(geolocation) = self.geolocation {
+            filter.geolocation = geolocation;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_options() {
+        let api_options = ApiOptions::from_args([
+            "RequestAll=3",
+            "duplicates",
+            "CharsetEXIF=Latin",
+            "QuickTimeUTC=auto",
+            "LargeFileSupport=0",
+        ])
+        .unwrap();
+        assert_eq!(api_options.large_file_support, Some(false));
+
+        let mut filter = FilterOptions::default();
+        api_options.apply(&mut filter);
+        assert!(filter.compute_image_hash);
+        assert!(filter.allow_duplicates);
+        assert_eq!(filter.charset_exif, ExifCharset::Latin);
+        assert_eq!(filter.quicktime_utc, QuickTimeUtc::Auto);
+        // Options that weren't set are left alone
+        assert!(!filter.geolocation);
+
+        let mut filter = FilterOptions::default();
+        ApiOptions::from_args(["requesttags=imagedatahash", "imagehashtype=SHA256"])
+            .unwrap()
+            .apply(&mut filter);
+        assert!(filter.compute_image_hash);
+        assert_eq!(filter.image_hash_type, ImageHashType::Sha256);
+    }
+
+    #[test]
+    fn test_invalid_api_options() {
+        assert!(matches!(
+            ApiOptions::from_args(["NoSuchOption=1"]),
+            Err(ExifError::Unsupported(_))
+        ));
+        assert!(matches!(
+            ApiOptions::from_args(["CharsetEXIF=Klingon"]),
+            Err(ExifError::ParseError(_))
+        ));
+        assert!(ApiOptions::from_args(["RequestAll=x"]).is_err());
+    }
+
+    #[test]
+    fn test_latin_charset() {
+        assert_eq!(ExifCharset::Latin.decode(b"Caf\xe9 \x80"), "Café €");
+        assert_eq!(ExifCharset::Utf8.decode(b"Caf\xe9"), "Caf\u{fffd}");
+    }
+}
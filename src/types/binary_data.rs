@@ -4,7 +4,7 @@
 //! functionality, including format definitions and table structures.
 
 use crate::processor_registry::ProcessorContext;
-use crate::types::{DataMemberValue, ExifError, TagValue};
+use crate::types::{DataMemberValue, ExifError, TagInfo, TagValue};
 use regex::Regex;
 use std::collections::HashMap;
 use std::sync::LazyLock;
@@ -473,6 +473,162 @@ impl<'a> ExpressionEvaluator<'a> {
     pub fn set_val(&mut self, index: u32, value: DataMemberValue) {
         self.val_hash.insert(index, value);
     }
+
+    /// Run the Hook of a ProcessBinaryData entry
+    ///
+    /// Supports the statements ExifTool tables use: `$varSize += N`,
+    /// `$varSize -= N`, `$varSize = N` and `$format = 'int16u'`, each with an
+    /// optional `if`/`unless` condition comparing `$$self{Name}`, `$val{N}` or
+    /// `$size`. Anything else is an error, after which the caller can't know
+    /// where later entries are.
+    /// ExifTool: lib/Image/ExifTool.pm ProcessBinaryData() `eval $$tagInfo{Hook}`
+    pub fn apply_hook(
+        &self,
+        code: &str,
+        state: &mut HookState,
+    ) -> std::result::Result<(), ExifError> {
+        static STATEMENT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(
+                r#"^\$(varSize|format)\s*([-+]?=)\s*(-?\d+|'[^']*'|"[^"]*")(?:\s+(if|unless)\s+(.+))?$"#,
+            )
+            .unwrap()
+        });
+
+        for statement in code.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            let captures = STATEMENT_REGEX.captures(statement).ok_or_else(|| {
+                ExifError::ParseError(format!("Unsupported Hook statement: {statement}"))
+            })?;
+            if let Some(keyword) = captures.get(4) {
+                let condition = self.evaluate_hook_condition(&captures[5], state)?;
+                if condition != (keyword.as_str() == "if") {
+                    continue;
+                }
+            }
+
+            let value = &captures[3];
+            match (&captures[1], &captures[2]) {
+                ("format", "=") => {
+                    state.format = value[1..value.len() - 1].to_string();
+                }
+                ("varSize", op) => {
+                    let value: isize = value.parse().map_err(|_| {
+                        ExifError::ParseError(format!("Non-numeric $varSize in Hook: {statement}"))
+                    })?;
+                    match op {
+                        "+=" => state.var_size += value,
+                        "-=" => state.var_size -= value,
+                        _ => state.var_size = value,
+                    }
+                }
+                _ => {
+                    return Err(ExifError::ParseError(format!(
+                        "Unsupported Hook statement: {statement}"
+                    )))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Condition of a Hook statement: comparisons joined by `and`/`or`
+    /// (no parentheses), `&&` and `||` included
+    fn evaluate_hook_condition(
+        &self,
+        condition: &str,
+        state: &HookState,
+    ) -> std::result::Result<bool, ExifError> {
+        static COMPARISON_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(
+                r#"^(\$\$self\{(\w+)\}|\$val\{(\d+)\}|\$size)\s*(=~|!~|==|!=|<=|>=|<|>|eq|ne)\s*(/(.*)/|-?\d+|'[^']*'|"[^"]*")$"#,
+            )
+            .unwrap()
+        });
+
+        static OR_REGEX: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"\s+(?:or|\|\|)\s+").unwrap());
+        static AND_REGEX: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new(r"\s+(?:and|&&)\s+").unwrap());
+
+        for alternative in OR_REGEX.split(condition.trim()) {
+            let mut matched = true;
+            for comparison in AND_REGEX.split(alternative.trim()) {
+                let captures = COMPARISON_REGEX.captures(comparison).ok_or_else(|| {
+                    ExifError::ParseError(format!("Unsupported Hook condition: {condition}"))
+                })?;
+                let lhs = if let Some(name) = captures.get(2) {
+                    self.data_members.get(name.as_str()).cloned()
+                } else if let Some(index) = captures.get(3) {
+                    index
+                        .as_str()
+                        .parse()
+                        .ok()
+                        .and_then(|index: u32| self.val_hash.get(&index).cloned())
+                } else {
+                    Some(DataMemberValue::U32(state.size as u32))
+                };
+                matched &= compare_hook_operand(lhs.as_ref(), &captures[4], &captures[5])?;
+            }
+            if matched {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// Variables a Hook may change
+/// ExifTool: `$format`, `$varSize` and `$size` in ProcessBinaryData()
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookState {
+    /// Format of the entry being read
+    pub format: String,
+    /// Bytes added to the offsets of this and every later entry
+    pub var_size: isize,
+    /// Size of the binary data block
+    pub size: usize,
+}
+
+/// `lhs op rhs` for a Hook condition; a missing DataMember compares as
+/// undefined (empty string / 0) as in Perl
+fn compare_hook_operand(
+    lhs: Option<&DataMemberValue>,
+    op: &str,
+    rhs: &str,
+) -> std::result::Result<bool, ExifError> {
+    let text = match lhs {
+        Some(DataMemberValue::String(s)) => s.clone(),
+        Some(value) => value.as_u32().unwrap_or_default().to_string(),
+        None => String::new(),
+    };
+    match op {
+        "=~" | "!~" => {
+            let pattern = rhs.strip_prefix('/').and_then(|r| r.strip_suffix('/'));
+            let pattern = pattern.ok_or_else(|| {
+                ExifError::ParseError(format!("Expected a regex after {op}: {rhs}"))
+            })?;
+            let regex = Regex::new(pattern)
+                .map_err(|e| ExifError::ParseError(format!("Invalid regex: {e}")))?;
+            Ok(regex.is_match(&text) == (op == "=~"))
+        }
+        "eq" | "ne" => {
+            let rhs = rhs.trim_matches(|c| c == '\'' || c == '"');
+            Ok((text == rhs) == (op == "eq"))
+        }
+        _ => {
+            let lhs: f64 = text.trim().parse().unwrap_or(0.0);
+            let rhs: f64 = rhs.parse().map_err(|_| {
+                ExifError::ParseError(format!("Expected a number after {op}: {rhs}"))
+            })?;
+            Ok(match op {
+                "==" => lhs == rhs,
+                "!=" => lhs != rhs,
+                "<" => lhs < rhs,
+                ">" => lhs > rhs,
+                "<=" => lhs <= rhs,
+                _ => lhs >= rhs,
+            })
+        }
+    }
 }
 
 /// Binary data table configuration
@@ -495,6 +651,45 @@ pub struct BinaryDataTable {
     pub dependency_order: Vec<u32>,
 }
 
+/// How the data of a generated binary table is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryTableProc {
+    /// ExifTool: PROCESS_PROC => \&ProcessBinaryData
+    BinaryData,
+    /// ProcessBinaryData after Sony's substitution cipher
+    /// ExifTool: Sony.pm PROCESS_PROC => \&ProcessEnciphered
+    Enciphered,
+}
+
+/// A ProcessBinaryData table as emitted by codegen into each module's
+/// `binary_tables.rs`, with everything the generic executor needs
+/// (`processor_registry::processors::binary_table`)
+#[derive(Debug)]
+pub struct GeneratedBinaryTable {
+    /// ExifTool table name, e.g. "Tag2010e"
+    pub table: &'static str,
+    /// The table's tags, keyed by index
+    pub tags: &'static LazyLock<HashMap<u16, TagInfo>>,
+    /// Format of entries without their own, and the unit of the indices
+    /// ExifTool: FORMAT key, default int8u
+    pub format: &'static str,
+    /// First index of the table's entries
+    /// ExifTool: FIRST_ENTRY key; only matters for unknown (-u) tags
+    pub first_entry: u32,
+    pub proc: BinaryTableProc,
+    /// Hook code by entry index, run before the entry is read; it may change
+    /// the entry's format and the offsets of every later one
+    /// ExifTool: Hook key
+    pub hooks: &'static [(u16, &'static str)],
+    /// DataMember names by entry index, for Formats and Hooks of later entries
+    /// ExifTool: DataMember key
+    pub data_members: &'static [(u16, &'static str)],
+    /// The table's `apply_value_conv`
+    pub value_conv: fn(u32, &TagValue, &mut Vec<String>) -> Result<TagValue, ExifError>,
+    /// The table's `apply_print_conv`
+    pub print_conv: fn(u32, &TagValue, &mut Vec<String>, &mut Vec<String>) -> TagValue,
+}
+
 /// Individual tag definition in binary data table
 /// ExifTool: Tag info hash structure
 #[derive(Debug, Clone)]
@@ -904,6 +1099,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_apply_hook() {
+        let data_members = HashMap::from([(
+            "Model".to_string(),
+            DataMemberValue::String("DSC-RX100M7".to_string()),
+        )]);
+        let evaluator =
+            ExpressionEvaluator::new(HashMap::from([(2, DataMemberValue::U8(3))]), &data_members);
+        let mut state = HookState {
+            format: "int16u".to_string(),
+            var_size: 0,
+            size: 64,
+        };
+
+        evaluator
+            .apply_hook("$varSize += 4 if $$self{Model} =~ /RX100M[67]/", &mut state)
+            .unwrap();
+        evaluator
+            .apply_hook("$varSize -= 1 unless $size >= 64", &mut state)
+            .unwrap();
+        evaluator
+            .apply_hook(
+                "$format = 'int32u' if $val{2} == 3 or $size < 8",
+                &mut state,
+            )
+            .unwrap();
+        assert_eq!(state.var_size, 4);
+        assert_eq!(state.format, "int32u");
+
+        // Code the executor can't follow is an error, not a guess
+        assert!(evaluator
+            .apply_hook("$varSize += $val{2} * 2", &mut state)
+            .is_err());
+    }
+
     #[test]
     fn test_simple_tag_creation() {
         // Test the simple tag creation helper
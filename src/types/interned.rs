@@ -0,0 +1,241 @@
+//! Interned strings for tag names and groups
+//!
+//! Every [`TagEntry`](super::TagEntry) carries a group, a family 1 group and a
+//! name. They come from a small vocabulary ("EXIF", "ExifIFD", "FNumber", ...),
+//! so instead of allocating a new `String` for each of them, entries share one
+//! reference-counted copy per distinct string. A RAW file with thousands of tags
+//! then allocates a few hundred strings rather than tens of thousands, and
+//! batch workloads reuse them across files.
+
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::{Arc, LazyLock, RwLock};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Upper bound on the number of distinct interned strings
+///
+/// Names can come from file content (e.g., XMP properties, TAG_PREFIX names
+/// built from unknown tag IDs). Past this many, strings are still shared by
+/// clones but no longer added to the global table, so a long-running process
+/// can't grow it without bound.
+const MAX_INTERNED: usize = 65_536;
+
+static INTERNER: LazyLock<RwLock<HashSet<Arc<str>>>> =
+    LazyLock::new(|| RwLock::new(HashSet::new()));
+
+/// A cheaply clonable, interned string used for tag names and groups
+///
+/// Dereferences to `str` and compares equal to `str`, `&str` and `String`, so
+/// it can be used mostly like the `String` it replaces.
+#[derive(Clone)]
+pub struct InternedStr(Arc<str>);
+
+impl InternedStr {
+    /// Intern `s`, reusing the shared copy if it has been seen before
+    pub fn new(s: &str) -> Self {
+        let interner = INTERNER.read().unwrap_or_else(|e| e.into_inner());
+        if let Some(existing) = interner.get(s) {
+            return Self(existing.clone());
+        }
+        let full = interner.len() >= MAX_INTERNED;
+        drop(interner);
+        if full {
+            return Self(Arc::from(s));
+        }
+
+        let mut interner = INTERNER.write().unwrap_or_else(|e| e.into_inner());
+        // Another thread may have interned it between the locks
+        if let Some(existing) = interner.get(s) {
+            return Self(existing.clone());
+        }
+        let interned: Arc<str> = Arc::from(s);
+        interner.insert(interned.clone());
+        Self(interned)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for InternedStr {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl Deref for InternedStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for InternedStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for InternedStr {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for InternedStr {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0 == other.0
+    }
+}
+
+impl Eq for InternedStr {}
+
+impl Hash for InternedStr {
+    // Same as str's Hash, as required by Borrow<str>
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialOrd for InternedStr {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InternedStr {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl fmt::Display for InternedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for InternedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl From<&str> for InternedStr {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<&String> for InternedStr {
+    fn from(s: &String) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<String> for InternedStr {
+    fn from(s: String) -> Self {
+        Self::new(&s)
+    }
+}
+
+impl From<InternedStr> for String {
+    fn from(s: InternedStr) -> Self {
+        s.as_str().to_string()
+    }
+}
+
+impl PartialEq<str> for InternedStr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for InternedStr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for InternedStr {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<InternedStr> for str {
+    fn eq(&self, other: &InternedStr) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<InternedStr> for &str {
+    fn eq(&self, other: &InternedStr) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialEq<InternedStr> for String {
+    fn eq(&self, other: &InternedStr) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl Serialize for InternedStr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for InternedStr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        // Borrowed when possible (e.g., serde_json from a &str), owned otherwise
+        let s = std::borrow::Cow::<'de, str>::deserialize(deserializer)?;
+        Ok(Self::new(&s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interned_strings_are_shared() {
+        let a = InternedStr::new("ExposureTime");
+        let b = InternedStr::from("ExposureTime".to_string());
+        assert!(Arc::ptr_eq(&a.0, &b.0));
+        assert_eq!(a, b);
+        assert_eq!(a, "ExposureTime");
+        assert_eq!("ExposureTime", a);
+        assert_eq!(a, "ExposureTime".to_string());
+        assert_ne!(a, InternedStr::new("ExposureMode"));
+        assert!(a.starts_with("Exposure"));
+        assert_eq!(format!("{a}"), "ExposureTime");
+        assert_eq!(format!("{a:?}"), "\"ExposureTime\"");
+    }
+
+    #[test]
+    fn test_lookup_by_str() {
+        let mut map = std::collections::HashMap::new();
+        map.insert(InternedStr::new("GPS"), 1);
+        assert_eq!(map.get("GPS"), Some(&1));
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let name = InternedStr::new("FNumber");
+        let json = serde_json::to_string(&name).unwrap();
+        assert_eq!(json, "\"FNumber\"");
+        let back: InternedStr = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, name);
+        // Escaped JSON strings can't be borrowed
+        let back: InternedStr = serde_json::from_str("\"F\\u004eumber\"").unwrap();
+        assert_eq!(back, name);
+    }
+}
@@ -4,10 +4,13 @@
 //! ExifData, and TagSourceInfo that represent extracted EXIF information.
 
 use crate::hash::ImageHashType;
-use crate::types::TagValue;
+use crate::stats::ExtractionStats;
+use crate::tiff_types::TiffFormat;
+use crate::types::{InternedStr, TagValue, XmpProvenance};
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
 
 /// Configuration for filtering which tags to extract and how to format them
 ///
@@ -76,10 +79,301 @@ pub struct FilterOptions {
 
     /// Hash algorithm for ImageDataHash computation
     ///
-    /// ExifTool equivalent: `-api imagehashtype=MD5|SHA256|SHA512`
+    /// ExifTool equivalent: `-api imagehashtype=MD5|SHA256|SHA512`; SHA1 and
+    /// XXH3 are additional exif-oxide choices
     ///
     /// Default: MD5 (matches ExifTool default)
     pub image_hash_type: ImageHashType,
+
+    /// Also hash the image data in chunks of this many bytes
+    ///
+    /// Adds `File:ImageDataHashChunkSize` and the per-chunk digests as the
+    /// `File:ImageDataHashChunks` array, for partial verification of large
+    /// files. Only used with [`Self::compute_image_hash`]; see
+    /// [`crate::hash::ImageDataHasher::with_chunk_size`].
+    ///
+    /// Default: None (no chunk digests). Not an ExifTool option
+    pub image_hash_chunk_size: Option<u64>,
+
+    /// Hash the media data of MOV/MP4 files at least this many bytes long on
+    /// a separate thread, while the atoms are parsed
+    ///
+    /// For multi-GB videos the hash pass dominates; reading the metadata
+    /// atoms alongside it saves their share of the wall-clock time. `None`
+    /// hashes on the calling thread. Only used with
+    /// [`Self::compute_image_hash`].
+    ///
+    /// Default: [`Self::DEFAULT_PARALLEL_HASH_MIN_SIZE`]. Not an ExifTool option
+    pub parallel_hash_min_size: Option<u64>,
+
+    /// Binary values larger than this many bytes are serialized as ExifTool's
+    /// `(Binary data N bytes, use -b option to extract)` placeholder
+    ///
+    /// Smaller binary values are output unchanged, and [`ExifData::tags`]
+    /// always holds the bytes. Default: 0, so every binary
+    /// value is summarized, matching ExifTool's JSON output.
+    pub binary_threshold: usize,
+
+    /// Output binary values as `base64:...` strings instead of the placeholder
+    ///
+    /// ExifTool equivalent: `-b` combined with `-j`
+    pub include_binary: bool,
+
+    /// Record where each tag was read from ([`TagEntry::source`])
+    ///
+    /// Adds a `TagSources` object to the JSON output with the table, tag ID,
+    /// file offset and raw format of each tag, similar to what `exiftool -v`
+    /// prints, and an `XMPPacket` object describing where the XMP is stored.
+    /// Useful for forensics and for debugging mismatches against ExifTool.
+    pub include_tag_sources: bool,
+
+    /// Run the forensic consistency checks and add their `Validation` group tags
+    ///
+    /// See [`crate::validation`]. Validation tags are always output when
+    /// enabled, regardless of the tag and group filters.
+    pub validate: bool,
+
+    /// Report the time and bytes read of each extraction stage in [`ExifData::stats`]
+    ///
+    /// See [`crate::stats`]. Not an ExifTool option
+    pub stats: bool,
+
+    /// Add Geolocation tags for the city nearest to the GPS position
+    ///
+    /// Uses the database from [`crate::geolocation::database`]; without the
+    /// `geolocation` feature or a custom database no tags are added.
+    /// ExifTool equivalent: `-api geolocation`
+    pub geolocation: bool,
+
+    /// Output ValueConv (numeric) values for every tag instead of PrintConv
+    ///
+    /// PrintConv is then skipped during extraction, except for tags that
+    /// composite tags read as `$prt[n]`. ExifTool equivalent: `-n` or `-all#`
+    pub numeric: bool,
+
+    /// Order of tags in the serialized output
+    ///
+    /// See [`ExifData::apply_tag_order`]. ExifTool equivalent: `-sort`, `-sort0`
+    pub tag_order: TagOrder,
+
+    /// Which family of group names prefixes the serialized tag keys
+    ///
+    /// See [`GroupFamily`]. ExifTool equivalent: `-G1`
+    pub group_family: GroupFamily,
+
+    /// Which unrecognized tags to output
+    ///
+    /// See [`UnknownTags`]. ExifTool equivalent: `-u`, `-U`
+    pub unknown_tags: UnknownTags,
+
+    /// Keep every copy of a tag instead of only the highest priority one
+    ///
+    /// Copies after the first are serialized with index suffixes, like
+    /// `IPTC:Keywords (1)`. ExifTool equivalent: `-a`
+    pub allow_duplicates: bool,
+
+    /// How QuickTime date/time values are interpreted
+    ///
+    /// See [`QuickTimeUtc`]. ExifTool equivalent: `-api QuickTimeUTC`
+    pub quicktime_utc: QuickTimeUtc,
+
+    /// Encoding of EXIF "ASCII" string values
+    ///
+    /// See [`ExifCharset`]. ExifTool equivalent: `-api CharsetEXIF`
+    pub charset_exif: ExifCharset,
+
+    /// How much of the file to scan for metadata
+    ///
+    /// See [`ScanDepth`]. ExifTool equivalent: `-fast`, `-fast2`
+    pub scan_depth: ScanDepth,
+
+    /// Whether symbolic links are read through or reported themselves
+    ///
+    /// See [`SymlinkPolicy`]
+    pub symlinks: SymlinkPolicy,
+
+    /// Build Composite tags
+    ///
+    /// When filtering, only the composites the filter selects (and those they
+    /// are computed from) are built; see [`Self::requested_composites`].
+    /// ExifTool equivalent: `-e` clears it
+    pub composites: bool,
+}
+
+/// Order of tags in serialized output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TagOrder {
+    /// Groups in ExifTool's usual output order (File, JFIF, EXIF, MakerNotes,
+    /// other groups, then Composite), alphabetical by tag name within a group
+    #[default]
+    GroupPriority,
+    /// Alphabetical by tag name, regardless of group
+    /// ExifTool: -sort (with -s, which sorts by name instead of description)
+    Name,
+    /// Alphabetical by group, then by tag name
+    /// ExifTool: -sort0 (sort by family 0 group first)
+    Group,
+}
+
+/// Group name family used in serialized tag keys
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GroupFamily {
+    /// Family 0, the general location: `EXIF:Make`, `MakerNotes:LensType`
+    #[default]
+    Family0,
+    /// Family 1, the specific location: `IFD0:Make`, `ExifIFD:ISO`,
+    /// `GPS:GPSLatitude`, `Canon:LensType`, `System:FileSize`
+    ///
+    /// Tags with the same family 0 key, like the `ImageWidth` of IFD0 and
+    /// IFD1, get separate keys.
+    /// ExifTool: -G1
+    Family1,
+}
+
+/// Output of tags whose IDs aren't in the tag table
+///
+/// Unknown entries are named after the table's prefix and the tag ID, like
+/// ExifTool's TAG_PREFIX names: `Exif_0x9999` in the EXIF IFDs,
+/// `Casio_0x0021` in a maker note.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnknownTags {
+    /// Leave unknown tags out (ExifTool default)
+    #[default]
+    Hide,
+    /// Output unknown tags, except those holding binary data
+    /// ExifTool: -u
+    Include,
+    /// Output unknown tags, including binary data
+    /// ExifTool: -U
+    IncludeBinary,
+}
+
+impl UnknownTags {
+    /// Whether an unknown tag with this value is output
+    pub fn includes(self, value: &TagValue) -> bool {
+        match self {
+            UnknownTags::Hide => false,
+            UnknownTags::Include => !matches!(value, TagValue::Binary(_)),
+            UnknownTags::IncludeBinary => true,
+        }
+    }
+}
+
+/// Interpretation of QuickTime CreateDate/ModifyDate values
+///
+/// The QuickTime specification says these are UTC, but many cameras and some
+/// phones write local time instead, so ExifTool leaves them unconverted unless
+/// the `QuickTimeUTC` API option is set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuickTimeUtc {
+    /// Output stored values unchanged, without a time zone (ExifTool default)
+    #[default]
+    Off,
+    /// Treat stored values as UTC and convert them to the local time zone
+    /// ExifTool: -api QuickTimeUTC
+    On,
+    /// Decide per file from GPS time stamps and the file modification time,
+    /// adding `Composite:QuickTimeDateSource` and the raw `CreateDate`
+    Auto,
+}
+
+/// Encoding of the bytes in EXIF "ASCII" strings
+///
+/// The EXIF specification only allows ASCII, but cameras and editors write
+/// UTF-8 or Windows Latin in Artist, Copyright and ImageDescription.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExifCharset {
+    /// Decode as UTF-8, replacing invalid sequences with U+FFFD
+    ///
+    /// ExifTool's default (CharsetEXIF undefined) passes the bytes through
+    /// unchanged, which output as UTF-8 has the same effect except for
+    /// invalid sequences. ExifTool: `-api CharsetEXIF=UTF8`
+    #[default]
+    Utf8,
+    /// Decode as Windows Latin 1 (cp1252)
+    /// ExifTool: `-api CharsetEXIF=Latin`
+    Latin,
+}
+
+impl ExifCharset {
+    /// Windows cp1252 characters for bytes 0x80-0x9F; the rest of the
+    /// range matches Unicode. Unassigned bytes map to the C1 controls, as in
+    /// ExifTool's Charset/Latin.pm
+    const CP1252_HIGH: [char; 32] = [
+        '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8d}', 'Ž',
+        '\u{8f}', '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9d}',
+        'ž', 'Ÿ',
+    ];
+
+    /// Parse an ExifTool character set name (`UTF8`, `Latin`, `cp1252`)
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().replace('-', "").as_str() {
+            "utf8" => Some(Self::Utf8),
+            "latin" | "latin1" | "cp1252" => Some(Self::Latin),
+            _ => None,
+        }
+    }
+
+    /// Decode string bytes in this character set
+    pub fn decode(self, bytes: &[u8]) -> String {
+        match self {
+            Self::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+            Self::Latin => bytes
+                .iter()
+                .map(|&byte| match byte {
+                    0x80..=0x9f => Self::CP1252_HIGH[(byte - 0x80) as usize],
+                    byte => byte as char,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// How far into a file to look for metadata
+///
+/// Deeper scans find metadata stored after the image or media data, at the
+/// cost of seeking (or reading) past it, which matters most on network
+/// storage. Levels are ordered, so `depth >= ScanDepth::Fast2` means "at
+/// least Fast2".
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ScanDepth {
+    /// Scan the whole file (ExifTool default)
+    #[default]
+    Full,
+    /// Don't scan past the image data for trailers
+    ///
+    /// ExifTool: -fast (-fast1). JPEG segment scanning always stops at the
+    /// first SOS and trailers aren't parsed yet, so this currently reads the
+    /// same data as Full.
+    Fast1,
+    /// Also stop at the top-level `mdat` atom of QuickTime files (missing a
+    /// `moov` stored after it) and skip parsing embedded RAW previews
+    /// (RW2 File:ImageWidth/ImageHeight come from the JpgFromRaw preview)
+    ///
+    /// ExifTool: -fast2
+    Fast2,
+}
+
+impl ScanDepth {
+    /// Level for ExifTool's `-fastNUM`; levels above 2 are treated as 2
+    pub fn from_level(level: u8) -> Self {
+        match level {
+            0 => Self::Full,
+            1 => Self::Fast1,
+            _ => Self::Fast2,
+        }
+    }
+}
+
+/// How a path that is a symbolic link is read
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SymlinkPolicy {
+    /// Read the file the link points to (ExifTool default)
+    #[default]
+    Follow,
+    /// Report the link itself: its File:System tags, with FileType "LINK",
+    /// without reading the file it points to
+    Report,
 }
 
 impl Default for FilterOptions {
@@ -93,11 +387,33 @@ impl Default for FilterOptions {
             glob_patterns: Vec::new(),
             compute_image_hash: false, // Only compute when explicitly requested
             image_hash_type: ImageHashType::default(), // MD5, matching ExifTool default
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(Self::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0, // Summarize every binary value, like ExifTool
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric: false,
+            tag_order: TagOrder::default(),
+            group_family: GroupFamily::default(),
+            unknown_tags: UnknownTags::default(),
+            allow_duplicates: false,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth: ScanDepth::default(),
+            symlinks: SymlinkPolicy::default(),
+            composites: true,
         }
     }
 }
 
 impl FilterOptions {
+    /// Default [`Self::parallel_hash_min_size`]: 256 MiB, below which a
+    /// second thread isn't worth starting
+    pub const DEFAULT_PARALLEL_HASH_MIN_SIZE: u64 = 256 * 1024 * 1024;
+
     /// Create FilterOptions that extracts all tags (backward compatibility)
     pub fn extract_all() -> Self {
         Self::default()
@@ -114,6 +430,24 @@ impl FilterOptions {
             glob_patterns: Vec::new(),
             compute_image_hash: false,
             image_hash_type: ImageHashType::default(),
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(Self::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric: false,
+            tag_order: TagOrder::default(),
+            group_family: GroupFamily::default(),
+            unknown_tags: UnknownTags::default(),
+            allow_duplicates: false,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth: ScanDepth::default(),
+            symlinks: SymlinkPolicy::default(),
+            composites: true,
         }
     }
 
@@ -128,6 +462,24 @@ impl FilterOptions {
             glob_patterns: Vec::new(),
             compute_image_hash: false,
             image_hash_type: ImageHashType::default(),
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(Self::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric: false,
+            tag_order: TagOrder::default(),
+            group_family: GroupFamily::default(),
+            unknown_tags: UnknownTags::default(),
+            allow_duplicates: false,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth: ScanDepth::default(),
+            symlinks: SymlinkPolicy::default(),
+            composites: true,
         }
     }
 
@@ -142,6 +494,49 @@ impl FilterOptions {
             glob_patterns: Vec::new(),
             compute_image_hash: true,
             image_hash_type: hash_type,
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(Self::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric: false,
+            tag_order: TagOrder::default(),
+            group_family: GroupFamily::default(),
+            unknown_tags: UnknownTags::default(),
+            allow_duplicates: false,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth: ScanDepth::default(),
+            symlinks: SymlinkPolicy::default(),
+            composites: true,
+        }
+    }
+
+    /// Apply the binary output settings to a value about to be returned
+    ///
+    /// Non-binary values are returned unchanged. Binary values become
+    /// `base64:...` strings when [`Self::include_binary`] is set, and
+    /// otherwise ExifTool's placeholder once they exceed
+    /// [`Self::binary_threshold`] bytes.
+    ///
+    /// ExifTool: exiftool script ConvertBinary (placeholder) and -b JSON output
+    /// (`base64:` prefix)
+    pub fn format_binary_value(&self, value: TagValue) -> TagValue {
+        let TagValue::Binary(data) = &value else {
+            return value;
+        };
+        if self.include_binary {
+            TagValue::String(value.to_base64_marker().unwrap_or_default())
+        } else if data.len() > self.binary_threshold {
+            TagValue::String(format!(
+                "(Binary data {} bytes, use -b option to extract)",
+                data.len()
+            ))
+        } else {
+            value
         }
     }
 
@@ -158,6 +553,17 @@ impl FilterOptions {
             || !self.glob_patterns.is_empty()
     }
 
+    /// Whether `tag_name` is requested by name, optionally group-qualified
+    ///
+    /// ExifTool only generates some tags, like ICC_Profile, when they are
+    /// asked for by name: `-all`, groups and wildcards don't include them.
+    pub fn requests_tag(&self, tag_name: &str) -> bool {
+        self.requested_tags.iter().any(|tag| {
+            let name = tag.rsplit(':').next().unwrap_or(tag);
+            name.eq_ignore_ascii_case(tag_name)
+        })
+    }
+
     /// Check if a tag should be extracted based on current filters
     /// Uses case-insensitive matching to match ExifTool behavior
     pub fn should_extract_tag(&self, tag_name: &str, tag_group: &str) -> bool {
@@ -168,11 +574,20 @@ impl FilterOptions {
         let tag_name_lower = tag_name.to_lowercase();
         let tag_group_lower = tag_group.to_lowercase();
 
-        // Check specific tag requests (case-insensitive)
+        // Check specific tag requests (case-insensitive), optionally
+        // group-qualified like ExifTool's -Composite:ImageSize, after
+        // expanding shortcuts and EXIF specification names
         if self
             .requested_tags
             .iter()
-            .any(|t| t.to_lowercase() == tag_name_lower)
+            .flat_map(|t| super::expand_tag_name(t))
+            .any(|t| {
+                let t = t.to_lowercase();
+                match t.split_once(':') {
+                    Some((group, name)) => group == tag_group_lower && name == tag_name_lower,
+                    None => t == tag_name_lower,
+                }
+            })
         {
             return true;
         }
@@ -212,9 +627,50 @@ impl FilterOptions {
         false
     }
 
+    /// This filter widened by the source tags of the composites it selects
+    ///
+    /// Composite tags are computed from other extracted tags, so a filter that
+    /// asks only for `Composite:ImageSize` must still let ImageWidth and
+    /// ImageHeight through extraction. Like ExifTool, those sources are
+    /// extracted but not output: use the widened filter to decide what to
+    /// extract and the original one to filter the output.
+    /// See [`crate::composite_tags::composite_source_tags`].
+    pub fn with_composite_sources(&self) -> FilterOptions {
+        let mut widened = self.clone();
+        if self.extract_all {
+            return widened;
+        }
+
+        let composites = self.requested_composites().unwrap_or_default();
+        widened
+            .requested_tags
+            .extend(crate::composite_tags::composite_source_tags(&composites));
+        widened
+    }
+
+    /// Names of the composite tags this filter selects
+    ///
+    /// `None` when every composite is selected (no filtering), and an empty
+    /// list when composites are disabled with [`Self::composites`].
+    pub fn requested_composites(&self) -> Option<Vec<&'static str>> {
+        if !self.composites {
+            return Some(Vec::new());
+        }
+        if self.extract_all {
+            return None;
+        }
+        Some(
+            crate::composite_tags::all_composite_dependencies()
+                .into_iter()
+                .map(|deps| deps.name)
+                .filter(|name| self.should_extract_tag(name, "Composite"))
+                .collect(),
+        )
+    }
+
     /// Check if a tag should use numeric output (ValueConv instead of PrintConv)
     pub fn should_use_numeric(&self, tag_name: &str) -> bool {
-        self.numeric_tags.contains(tag_name)
+        self.numeric || self.numeric_tags.contains(tag_name)
     }
 
     /// Check if a string matches a glob pattern (case-insensitive)
@@ -271,6 +727,8 @@ impl FilterOptions {
                     | "fileinodechangedate"
                     | "filecreatedate"
                     | "filepermissions"
+                    | "fileattributes"
+                    | "fileowner"
                     | "filetype"
                     | "filetypeextension"
                     | "mimetype"
@@ -299,6 +757,8 @@ impl FilterOptions {
                             | "fileinodechangedate*"
                             | "filecreatedate*"
                             | "filepermissions*"
+                            | "fileattributes*"
+                            | "fileowner*"
                             | "filetype*"
                             | "filetypeextension*"
                             | "mimetype*"
@@ -313,7 +773,9 @@ impl FilterOptions {
 ///
 /// This structure provides access to both the logical value (after ValueConv)
 /// and the human-readable display string (after PrintConv), allowing consumers
-/// to choose the most appropriate representation.
+/// to choose the most appropriate representation. The PrintConv of tags read
+/// from generated tag tables runs the first time [`TagEntry::print`] is
+/// called, so callers that only read `value` don't pay for it.
 ///
 /// # Examples
 ///
@@ -321,28 +783,28 @@ impl FilterOptions {
 /// use exif_oxide::types::{TagEntry, TagValue};
 ///
 /// // A typical EXIF tag entry
-/// let entry = TagEntry {
-///     group: "EXIF".to_string(),
-///     group1: "ExifIFD".to_string(),  // Located in ExifIFD subdirectory
-///     name: "FNumber".to_string(),
-///     value: TagValue::F64(4.0),      // Post-ValueConv: 4/1 → 4.0
-///     print: TagValue::String("4.0".to_string()),       // Post-PrintConv: formatted for display
-/// };
+/// let entry = TagEntry::new(
+///     "EXIF",
+///     "ExifIFD", // Located in ExifIFD subdirectory
+///     "FNumber",
+///     TagValue::F64(4.0),                  // Post-ValueConv: 4/1 → 4.0
+///     TagValue::String("4.0".to_string()), // Post-PrintConv: formatted for display
+/// );
 ///
 /// assert_eq!(entry.name, "FNumber");
 ///
 /// // A tag with units in the display string
-/// let focal_entry = TagEntry {
-///     group: "EXIF".to_string(),
-///     group1: "ExifIFD".to_string(),
-///     name: "FocalLength".to_string(),
-///     value: TagValue::F64(24.0),     // Numeric value
-///     print: TagValue::String("24 mm".to_string()),     // Human-readable with units
-/// };
+/// let focal_entry = TagEntry::new(
+///     "EXIF",
+///     "ExifIFD",
+///     "FocalLength",
+///     TagValue::F64(24.0),                   // Numeric value
+///     TagValue::String("24 mm".to_string()), // Human-readable with units
+/// );
 ///
-/// assert_eq!(focal_entry.print, TagValue::String("24 mm".to_string()));
+/// assert_eq!(focal_entry.print(), &TagValue::String("24 mm".to_string()));
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct TagEntry {
     /// Tag group name (e.g., "EXIF", "GPS", "Canon", "MakerNotes")
     ///
@@ -352,7 +814,7 @@ pub struct TagEntry {
     /// - Sub-groups: "Canon::CameraSettings", etc.
     ///
     /// This corresponds to ExifTool's Group0 (format family).
-    pub group: String,
+    pub group: InternedStr,
 
     /// ExifTool Group1 (subdirectory location)
     ///
@@ -364,12 +826,12 @@ pub struct TagEntry {
     /// - "MakerNotes" - Manufacturer-specific subdirectory (tag 0x927c)
     ///
     /// This field enables ExifTool-compatible group-based tag access patterns.
-    pub group1: String,
+    pub group1: InternedStr,
 
     /// Tag name without group prefix (e.g., "FNumber", "ExposureTime")
     ///
     /// Names match ExifTool's tag naming exactly for compatibility.
-    pub name: String,
+    pub name: InternedStr,
 
     /// The logical value after ValueConv processing.
     ///
@@ -385,6 +847,37 @@ pub struct TagEntry {
     /// - Make: `TagValue::String("Canon")` (no ValueConv needed)
     pub value: TagValue,
 
+    /// The display value after PrintConv processing, read with
+    /// [`TagEntry::print`]
+    pub(crate) print: LazyPrint,
+
+    /// Where the tag was read from
+    ///
+    /// Only populated when [`FilterOptions::include_tag_sources`] is set, and
+    /// only for tags read from a TIFF-style directory (EXIF and maker notes).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<TagSource>,
+}
+
+impl TagEntry {
+    /// Create a tag entry with an already converted display value
+    pub fn new(
+        group: impl Into<InternedStr>,
+        group1: impl Into<InternedStr>,
+        name: impl Into<InternedStr>,
+        value: TagValue,
+        print: TagValue,
+    ) -> Self {
+        Self {
+            group: group.into(),
+            group1: group1.into(),
+            name: name.into(),
+            value,
+            print: print.into(),
+            source: None,
+        }
+    }
+
     /// The display value after PrintConv processing.
     ///
     /// This can be either:
@@ -395,14 +888,304 @@ pub struct TagEntry {
     /// - Display-oriented tags return strings
     /// - Data-oriented tags may pass through numeric values
     ///
-    /// If no PrintConv exists, this equals the original `value`.
+    /// If no PrintConv exists, this equals the original `value`. A deferred
+    /// PrintConv runs on the first call and its result is kept.
     ///
     /// # Design Note
     ///
     /// This differs from ExifTool where PrintConv always returns strings.
     /// We chose this approach to avoid regex-based type guessing during JSON serialization.
     /// See docs/design/PRINTCONV-DESIGN-DECISIONS.md for details.
-    pub print: TagValue,
+    pub fn print(&self) -> &TagValue {
+        match self.print.print_conv {
+            Some(print_conv) => self
+                .print
+                .value
+                .get_or_init(|| print_conv.apply(&self.value)),
+            None => self.print.value.get().unwrap_or(&self.value),
+        }
+    }
+
+    /// Replace the display value, dropping any deferred PrintConv
+    pub fn set_print(&mut self, print: TagValue) {
+        self.print = print.into();
+    }
+}
+
+impl Serialize for TagEntry {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("TagEntry", 6)?;
+        state.serialize_field("group", &self.group)?;
+        state.serialize_field("group1", &self.group1)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("value", &self.value)?;
+        state.serialize_field("print", self.print())?;
+        match &self.source {
+            Some(source) => state.serialize_field("source", source)?,
+            None => state.skip_field("source")?,
+        }
+        state.end()
+    }
+}
+
+/// Signature of the generated tag tables' `apply_print_conv` functions
+pub type TablePrintConvFn = fn(u32, &TagValue, &mut Vec<String>, &mut Vec<String>) -> TagValue;
+
+/// A tag table's PrintConv for one tag, run when the tag's display value is
+/// first read
+#[derive(Debug, Clone, Copy)]
+pub struct DeferredPrintConv {
+    print_conv: TablePrintConvFn,
+    tag_id: u32,
+}
+
+impl DeferredPrintConv {
+    /// Defer `print_conv` for the tag with `tag_id` in its table
+    pub fn new(print_conv: TablePrintConvFn, tag_id: u32) -> Self {
+        Self { print_conv, tag_id }
+    }
+
+    /// Run the PrintConv on a ValueConv'd value
+    pub fn apply(&self, value: &TagValue) -> TagValue {
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+        (self.print_conv)(self.tag_id, value, &mut errors, &mut warnings)
+    }
+}
+
+/// Storage for [`TagEntry::print`]: a converted display value, a PrintConv
+/// still to run, or neither when the display value is the value itself
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LazyPrint {
+    value: OnceLock<TagValue>,
+    print_conv: Option<DeferredPrintConv>,
+}
+
+impl LazyPrint {
+    /// A display value converted from the tag's value on first use
+    pub(crate) fn deferred(print_conv: DeferredPrintConv) -> Self {
+        Self {
+            value: OnceLock::new(),
+            print_conv: Some(print_conv),
+        }
+    }
+}
+
+impl From<TagValue> for LazyPrint {
+    fn from(value: TagValue) -> Self {
+        Self {
+            value: OnceLock::from(value),
+            print_conv: None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LazyPrint {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        TagValue::deserialize(deserializer).map(Self::from)
+    }
+}
+
+/// Provenance of a tag: the directory it came from and its raw encoding
+///
+/// Mirrors the per-tag details shown by `exiftool -v`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TagSource {
+    /// Directory or table the tag was read from (e.g., "IFD0", "ExifIFD", "Canon")
+    pub table: String,
+
+    /// Processor that decoded the directory (e.g., "Exif", "Canon")
+    pub processor: String,
+
+    /// Tag ID within the table, formatted like ExifTool (e.g., "0x010f")
+    #[serde(rename = "TagID", skip_serializing_if = "Option::is_none")]
+    pub tag_id: Option<String>,
+
+    /// Absolute file offset of the tag's value data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+
+    /// Raw format type using ExifTool's names (e.g., "int16u", "rational64u")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    /// Number of values of that format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+}
+
+/// Key under which [`ExifData::prepare_for_serialization`] outputs warnings
+/// ExifTool: `exiftool -j -G` reports them as "ExifTool:Warning"
+pub const WARNING_TAG_KEY: &str = "ExifTool:Warning";
+
+/// A problem found while reading a file that didn't stop extraction
+///
+/// Kept apart from the tags so callers can inspect warnings without parsing
+/// tag keys. [`ExifData::prepare_for_serialization`] writes them as
+/// ExifTool's `ExifTool:Warning` tag.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExtractionWarning {
+    /// Stable identifier of the kind of problem (e.g., "ExifParseError")
+    pub code: String,
+
+    /// Human-readable description
+    pub message: String,
+
+    /// Where in the file it happened (e.g., "JPEG APP1", "XMP")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+impl ExtractionWarning {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            context: None,
+        }
+    }
+
+    pub fn with_context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+}
+
+/// Where extraction stopped because the file ends early
+///
+/// Tags found before that point are still returned. This records what was
+/// cut off, so recovery tools can decide whether to fetch the original again.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Truncation {
+    /// Structure that was cut off (e.g., "IFD0 directory", "QuickTime moov atom")
+    pub context: String,
+
+    /// File offset where that structure starts
+    pub offset: u64,
+
+    /// Length the structure declares, in bytes
+    pub expected_length: u64,
+
+    /// Bytes of it present in the file
+    pub available_length: u64,
+}
+
+impl Truncation {
+    pub fn new(
+        context: impl Into<String>,
+        offset: u64,
+        expected_length: u64,
+        available_length: u64,
+    ) -> Self {
+        Self {
+            context: context.into(),
+            offset,
+            expected_length,
+            available_length,
+        }
+    }
+
+    /// Bytes missing from the structure
+    pub fn missing_length(&self) -> u64 {
+        self.expected_length.saturating_sub(self.available_length)
+    }
+
+    /// The `FileTruncated` warning reported alongside this truncation
+    pub fn to_warning(&self) -> ExtractionWarning {
+        ExtractionWarning::new(
+            "FileTruncated",
+            format!(
+                "{} at offset {:#x} is truncated ({} of {} bytes present)",
+                self.context, self.offset, self.available_length, self.expected_length
+            ),
+        )
+    }
+}
+
+/// Where a file's XMP packet is stored, and whether it can be edited in place
+///
+/// XMP writers pad the packet with whitespace inside an `<?xpacket ...?>`
+/// wrapper so it can grow without moving the rest of the file. ExifTool
+/// rewrites such packets in place when the new XMP fits in the padding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct XmpPacketLocation {
+    /// Segment, box or tag holding the packet (e.g., "JPEG APP1", "IFD0 ApplicationNotes")
+    pub container: String,
+
+    /// Absolute file offset of the first packet byte
+    pub offset: u64,
+
+    /// Bytes the packet occupies, including padding
+    pub length: u64,
+
+    /// Whether the packet is wrapped in `<?xpacket begin=...?>` / `<?xpacket end=...?>`
+    pub has_wrapper: bool,
+
+    /// Whitespace bytes between the XMP and the `<?xpacket end` marker
+    pub padding: u64,
+
+    /// Whether the end marker is `end="w"`, allowing in-place modification
+    pub writable: bool,
+
+    /// Whether part of the XMP continues outside this packet (JPEG Extended XMP)
+    pub extended: bool,
+}
+
+impl XmpPacketLocation {
+    /// Describe `packet`, stored in `container` at file offset `offset`
+    pub fn new(container: impl Into<String>, offset: u64, packet: &[u8]) -> Self {
+        let end_marker = find_bytes(packet, b"<?xpacket end=");
+        let has_wrapper = find_bytes(packet, b"<?xpacket begin=").is_some() && end_marker.is_some();
+        let (padding, writable) = match end_marker.filter(|_| has_wrapper) {
+            Some(end) => {
+                let padding = packet[..end]
+                    .iter()
+                    .rev()
+                    .take_while(|b| b.is_ascii_whitespace())
+                    .count();
+                // <?xpacket end="w"?> or end='w'
+                let mode = packet.get(end + b"<?xpacket end=".len() + 1);
+                (padding as u64, mode == Some(&b'w'))
+            }
+            None => (0, false),
+        };
+        Self {
+            container: container.into(),
+            offset,
+            length: packet.len() as u64,
+            has_wrapper,
+            padding,
+            writable,
+            extended: false,
+        }
+    }
+
+    /// Mark the packet as continued in Extended XMP segments
+    pub fn with_extended(mut self, extended: bool) -> Self {
+        self.extended = extended;
+        self
+    }
+
+    /// Whether a replacement packet of `new_length` bytes (before padding)
+    /// can overwrite this one without moving other data in the file
+    ///
+    /// Requires a writable wrapper, and no Extended XMP whose GUID would
+    /// have to change along with the packet.
+    pub fn can_rewrite_in_place(&self, new_length: u64) -> bool {
+        self.has_wrapper && self.writable && !self.extended && new_length <= self.length
+    }
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
 }
 
 /// Represents extracted EXIF data from an image
@@ -431,12 +1214,50 @@ pub struct ExifData {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<String>,
 
+    /// Non-fatal problems found during extraction, in the order they occurred
+    /// Serialized as the `ExifTool:Warning` tag by `prepare_for_serialization`
+    #[serde(skip)]
+    pub warnings: Vec<ExtractionWarning>,
+
+    /// Set when the file ends inside a structure being parsed; the tags are
+    /// then what could be read before that point
+    #[serde(skip)]
+    pub truncation: Option<Truncation>,
+
     /// Missing implementations (only included with --show-missing)
     #[serde(
         rename = "MissingImplementations",
         skip_serializing_if = "Option::is_none"
     )]
     pub missing_implementations: Option<Vec<String>>,
+
+    /// Provenance of each output tag, keyed like `legacy_tags`
+    /// Populated during serialization from [`TagEntry::source`]
+    #[serde(
+        rename = "TagSources",
+        skip_serializing_if = "IndexMap::is_empty",
+        default
+    )]
+    pub tag_sources: IndexMap<String, TagSource>,
+
+    /// Where the XMP packet is stored
+    /// Only populated when [`FilterOptions::include_tag_sources`] is set
+    #[serde(rename = "XMPPacket", skip_serializing_if = "Option::is_none", default)]
+    pub xmp_packet: Option<XmpPacketLocation>,
+
+    /// Document IDs and editing history from the XMP (xmpMM), when present
+    /// Unlike the flattened XMP:History* tags, each history event is kept whole
+    #[serde(skip)]
+    pub xmp_provenance: Option<XmpProvenance>,
+
+    /// Per-stage timing and bytes read
+    /// Only populated when [`FilterOptions::stats`] is set, and not for cached results
+    #[serde(
+        rename = "Statistics",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub stats: Option<ExtractionStats>,
 }
 
 impl ExifData {
@@ -448,7 +1269,13 @@ impl ExifData {
             tags: Vec::new(),
             legacy_tags: IndexMap::new(),
             errors: Vec::new(),
+            warnings: Vec::new(),
+            truncation: None,
             missing_implementations: None,
+            tag_sources: IndexMap::new(),
+            xmp_packet: None,
+            xmp_provenance: None,
+            stats: None,
         }
     }
 
@@ -471,7 +1298,8 @@ impl ExifData {
                 | "APP15" => 3,
                 "EXIF" => 4,
                 "MakerNotes" => 5,
-                "Composite" => 255, // Always last
+                "Composite" => 255,  // Always last
+                "Validation" => 255, // Analysis results (sorts after Composite)
                 // Other groups (XMP, IPTC, Photoshop, PrintIM, MPF, ICC_Profile, etc.)
                 _ => 50,
             }
@@ -486,43 +1314,89 @@ impl ExifData {
     pub fn prepare_for_serialization(
         &mut self,
         numeric_tags: Option<&std::collections::HashSet<String>>,
+    ) {
+        self.prepare_for_serialization_with_groups(numeric_tags, GroupFamily::Family0);
+    }
+
+    /// [`Self::prepare_for_serialization`] with tag keys prefixed by the
+    /// `family` group names
+    ///
+    /// Groups stay in the family 0 priority order, so `IFD0:`, `ExifIFD:`
+    /// and `GPS:` keys sort together where `EXIF:` keys would.
+    pub fn prepare_for_serialization_with_groups(
+        &mut self,
+        numeric_tags: Option<&std::collections::HashSet<String>>,
+        family: GroupFamily,
     ) {
         use tracing::debug;
 
-        // Preserve existing legacy_tags (like System: and Warning: tags) before clearing
-        let existing_legacy_tags = self.legacy_tags.clone();
-        self.legacy_tags.clear();
+        // Preserve existing legacy_tags (like System: tags) before clearing
+        let existing_legacy_tags = std::mem::take(&mut self.legacy_tags);
+
+        // ExifTool reports warnings as a Warning tag in its own group. One
+        // warning is a string, several become a list (ExifTool -a would give
+        // each its own key, which JSON can't represent)
+        let warning_value = match self.warnings.as_slice() {
+            [] => None,
+            [warning] => Some(TagValue::String(warning.message.clone())),
+            warnings => Some(TagValue::Array(
+                warnings
+                    .iter()
+                    .map(|w| TagValue::String(w.message.clone()))
+                    .collect(),
+            )),
+        };
+        if let Some(value) = warning_value {
+            self.legacy_tags.insert(WARNING_TAG_KEY.to_string(), value);
+        }
 
         // Re-add preserved legacy tags that don't come from TagEntry
         for (key, value) in existing_legacy_tags {
-            if key.starts_with("System:") || key.starts_with("Warning:") {
+            if key.starts_with("System:") {
                 self.legacy_tags.insert(key, value);
             }
         }
 
         // Create a sorted list of (tag_key, tag_entry) pairs for ordered insertion
-        let mut tag_pairs: Vec<(String, &TagEntry)> = self
+        let mut tag_pairs: Vec<(u8, String, &TagEntry)> = self
             .tags
             .iter()
-            .map(|entry| (format!("{}:{}", entry.group, entry.name), entry))
+            .map(|entry| {
+                let key0 = format!("{}:{}", entry.group, entry.name);
+                let priority = Self::get_group_priority(&key0);
+                let key = match family {
+                    GroupFamily::Family0 => key0,
+                    GroupFamily::Family1 => format!("{}:{}", entry.group1, entry.name),
+                };
+                (priority, key, entry)
+            })
             .collect();
 
         // Sort by group priority first, then alphabetically within group
-        tag_pairs.sort_by(|(key_a, _), (key_b, _)| {
-            let priority_a = Self::get_group_priority(key_a);
-            let priority_b = Self::get_group_priority(key_b);
+        tag_pairs.sort_by(|(priority_a, key_a, _), (priority_b, key_b, _)| {
+            priority_a.cmp(priority_b).then_with(|| key_a.cmp(key_b))
+        });
 
-            match priority_a.cmp(&priority_b) {
-                std::cmp::Ordering::Equal => key_a.cmp(key_b), // Alphabetical within group
-                other => other,
+        // Insert tags in the sorted order. Copies of a key, kept by
+        // FilterOptions::allow_duplicates, are numbered like ExifTool's
+        // duplicate tag keys: "IPTC:Keywords", "IPTC:Keywords (1)", ...
+        self.tag_sources.clear();
+        let mut copies: HashMap<String, usize> = HashMap::new();
+        for (_, key, entry) in tag_pairs {
+            let copy = copies.entry(key.clone()).or_insert(0);
+            let key = match *copy {
+                0 => key,
+                n => format!("{key} ({n})"),
+            };
+            *copy += 1;
+
+            if let Some(source) = &entry.source {
+                self.tag_sources.insert(key.clone(), source.clone());
             }
-        });
 
-        // Insert tags in the sorted order
-        for (key, entry) in tag_pairs {
             // Determine whether to use value or print field
             let should_use_value = numeric_tags
-                .map(|set| set.contains(&entry.name))
+                .map(|set| set.contains(entry.name.as_str()))
                 .unwrap_or(false);
 
             if should_use_value {
@@ -532,8 +1406,52 @@ impl ExifData {
             } else {
                 // Use PrintConv result directly - it already has the correct type
                 // (string for display values, numeric for data values)
-                debug!("Tag {}: using print value {:?}", key, entry.print);
-                self.legacy_tags.insert(key, entry.print.clone());
+                debug!("Tag {}: using print value {:?}", key, entry.print());
+                self.legacy_tags.insert(key, entry.print().clone());
+            }
+        }
+    }
+
+    /// Summarize (or base64-encode) the serialized binary values
+    ///
+    /// Call after [`Self::prepare_for_serialization`]. `tags` keeps the
+    /// bytes; only `legacy_tags` gets the placeholders, so maker note blobs
+    /// and ICC data don't explode the output. See
+    /// [`FilterOptions::format_binary_value`].
+    pub fn format_binary_values(&mut self, options: &FilterOptions) {
+        for value in self.legacy_tags.values_mut() {
+            if matches!(value, TagValue::Binary(_)) {
+                *value = options.format_binary_value(std::mem::replace(value, TagValue::Empty));
+            }
+        }
+    }
+
+    /// Reorder the serialized tags (`legacy_tags` and `tag_sources`)
+    ///
+    /// Call after [`Self::prepare_for_serialization`], which already produces
+    /// [`TagOrder::GroupPriority`]. The other orders also sort the `System:` and
+    /// `ExifTool:Warning` entries that `prepare_for_serialization` keeps at the front, so
+    /// the output depends only on the tags found, not on the order in which
+    /// they were extracted.
+    pub fn apply_tag_order(&mut self, order: TagOrder) {
+        fn split_key(key: &str) -> (&str, &str) {
+            key.split_once(':').unwrap_or(("", key))
+        }
+
+        let compare = |a: &String, b: &String| {
+            let (group_a, name_a) = split_key(a);
+            let (group_b, name_b) = split_key(b);
+            match order {
+                TagOrder::Name => (name_a, group_a).cmp(&(name_b, group_b)),
+                _ => (group_a, name_a).cmp(&(group_b, name_b)),
+            }
+        };
+
+        match order {
+            TagOrder::GroupPriority => {}
+            TagOrder::Name | TagOrder::Group => {
+                self.legacy_tags.sort_by(|a, _, b, _| compare(a, b));
+                self.tag_sources.sort_by(|a, _, b, _| compare(a, b));
             }
         }
     }
@@ -666,6 +1584,21 @@ pub enum DataMemberValue {
 }
 
 impl DataMemberValue {
+    /// A raw tag value as a DataMember, if it's an integer or string
+    ///
+    /// Signed values keep their bit pattern.
+    pub fn from_tag_value(value: &TagValue) -> Option<Self> {
+        match value {
+            TagValue::U8(v) => Some(DataMemberValue::U8(*v)),
+            TagValue::U16(v) => Some(DataMemberValue::U16(*v)),
+            TagValue::U32(v) => Some(DataMemberValue::U32(*v)),
+            TagValue::I16(v) => Some(DataMemberValue::U16(*v as u16)),
+            TagValue::I32(v) => Some(DataMemberValue::U32(*v as u32)),
+            TagValue::String(s) => Some(DataMemberValue::String(s.clone())),
+            _ => None,
+        }
+    }
+
     pub fn as_u16(&self) -> Option<u16> {
         match self {
             DataMemberValue::U16(v) => Some(*v),
@@ -714,7 +1647,7 @@ impl SourcePriority {
     /// Matches ExifTool's group hierarchy behavior
     pub fn from_namespace(namespace: &str) -> Self {
         match namespace {
-            "EXIF" | "IFD0" | "IFD1" | "ExifIFD" | "SubIFD" => SourcePriority::Exif,
+            "EXIF" | "IFD0" | "IFD1" | "ExifIFD" | "InteropIFD" | "SubIFD" => SourcePriority::Exif,
             "GPS" => SourcePriority::Gps,
             "MakerNotes" => SourcePriority::MakerNotes,
             _ => SourcePriority::Unknown,
@@ -738,12 +1671,289 @@ pub struct TagSourceInfo {
     /// Processor name that handled this tag
     /// ExifTool: PROCESS_PROC information
     pub processor_name: String,
+    /// Absolute file offset of the value data, when read from an IFD entry
+    pub value_offset: Option<u64>,
+    /// Raw IFD entry format
+    pub format: Option<TiffFormat>,
+    /// Raw IFD entry count
+    pub count: Option<u32>,
+    /// PrintConv of a tag decoded from its own table (maker note binary data,
+    /// CRW heap tags), run when the tag's display value is read
+    pub print_conv: Option<DeferredPrintConv>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_tag_sources_serialization() {
+        let source = TagSourceInfo::new("EXIF".to_string(), "IFD0".to_string(), "Exif".to_string())
+            .with_entry(38, TiffFormat::Ascii, 6)
+            .to_tag_source(0x010f);
+        let mut exif_data = ExifData::new("test.tif".to_string(), String::new());
+        exif_data.tags = vec![TagEntry {
+            group: "EXIF".into(),
+            group1: "IFD0".into(),
+            name: "Make".into(),
+            value: TagValue::string("Canon"),
+            print: TagValue::string("Canon").into(),
+            source: Some(source),
+        }];
+        exif_data.prepare_for_serialization(None);
+
+        let json = serde_json::to_value(&exif_data).unwrap();
+        assert_eq!(
+            json["TagSources"]["EXIF:Make"],
+            serde_json::json!({
+                "Table": "IFD0",
+                "Processor": "Exif",
+                "TagID": "0x010f",
+                "Offset": 38,
+                "Format": "string",
+                "Count": 6
+            })
+        );
+    }
+
+    #[test]
+    fn test_deferred_print_conv_runs_once_when_read() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        fn print_conv(
+            _: u32,
+            value: &TagValue,
+            _: &mut Vec<String>,
+            _: &mut Vec<String>,
+        ) -> TagValue {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            TagValue::string(format!("{value} printed"))
+        }
+
+        let entry = TagEntry {
+            group: "EXIF".into(),
+            group1: "IFD0".into(),
+            name: "Orientation".into(),
+            value: TagValue::U16(1),
+            print: LazyPrint::deferred(DeferredPrintConv::new(print_conv, 0x0112)),
+            source: None,
+        };
+        assert_eq!(CALLS.load(Ordering::SeqCst), 0);
+        assert_eq!(entry.print(), &TagValue::string("1 printed"));
+        assert_eq!(entry.print(), &TagValue::string("1 printed"));
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+        // Replacing the display value drops the PrintConv without running it
+        let mut numeric = entry.clone();
+        let mut unread = TagEntry {
+            print: LazyPrint::deferred(DeferredPrintConv::new(print_conv, 0x0112)),
+            ..entry
+        };
+        unread.set_print(unread.value.clone());
+        numeric.set_print(numeric.value.clone());
+        assert_eq!(unread.print(), &TagValue::U16(1));
+        assert_eq!(numeric.print(), &TagValue::U16(1));
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_warnings_serialize_as_exiftool_warning() {
+        let mut exif_data = ExifData::new("test.jpg".to_string(), String::new());
+        exif_data.prepare_for_serialization(None);
+        assert!(!exif_data.legacy_tags.contains_key(WARNING_TAG_KEY));
+
+        exif_data.warnings = vec![
+            ExtractionWarning::new("ExifParseError", "Bad EXIF").with_context("JPEG"),
+            ExtractionWarning::new("XmpParseError", "Bad XMP"),
+        ];
+        exif_data.prepare_for_serialization(None);
+        let json = serde_json::to_value(&exif_data).unwrap();
+        assert_eq!(
+            json[WARNING_TAG_KEY],
+            serde_json::json!(["Bad EXIF", "Bad XMP"])
+        );
+        assert!(json.get("warnings").is_none());
+
+        assert_eq!(
+            serde_json::to_value(&exif_data.warnings[1]).unwrap(),
+            serde_json::json!({"Code": "XmpParseError", "Message": "Bad XMP"})
+        );
+    }
+
+    #[test]
+    fn test_apply_tag_order() {
+        let entry = |group: &str, name: &str| TagEntry {
+            group: group.into(),
+            group1: group.into(),
+            name: name.into(),
+            value: TagValue::U16(1),
+            print: TagValue::U16(1).into(),
+            source: None,
+        };
+        let keys = |exif_data: &ExifData| -> Vec<String> {
+            exif_data.legacy_tags.keys().cloned().collect()
+        };
+
+        let mut exif_data = ExifData::new("test.jpg".to_string(), String::new());
+        exif_data.tags = vec![
+            entry("Composite", "Aperture"),
+            entry("XMP", "Make"),
+            entry("EXIF", "Make"),
+            entry("File", "FileSize"),
+            entry("EXIF", "ISO"),
+        ];
+        exif_data.warnings.push(ExtractionWarning::new("Bad", "x"));
+        exif_data.prepare_for_serialization(None);
+
+        let mut by_priority = exif_data.clone();
+        by_priority.apply_tag_order(TagOrder::GroupPriority);
+        assert_eq!(
+            keys(&by_priority),
+            [
+                "ExifTool:Warning",
+                "File:FileSize",
+                "EXIF:ISO",
+                "EXIF:Make",
+                "XMP:Make",
+                "Composite:Aperture"
+            ]
+        );
+
+        let mut by_name = exif_data.clone();
+        by_name.apply_tag_order(TagOrder::Name);
+        assert_eq!(
+            keys(&by_name),
+            [
+                "Composite:Aperture",
+                "File:FileSize",
+                "EXIF:ISO",
+                "EXIF:Make",
+                "XMP:Make",
+                "ExifTool:Warning"
+            ]
+        );
+
+        // Shuffling the extracted tags doesn't change the sorted output
+        exif_data.tags.reverse();
+        exif_data.prepare_for_serialization(None);
+        exif_data.apply_tag_order(TagOrder::Group);
+        assert_eq!(
+            keys(&exif_data),
+            [
+                "Composite:Aperture",
+                "EXIF:ISO",
+                "EXIF:Make",
+                "ExifTool:Warning",
+                "File:FileSize",
+                "XMP:Make"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_family1_keys() {
+        let entry = |group: &str, group1: &str, name: &str, value: u16| TagEntry {
+            group: group.into(),
+            group1: group1.into(),
+            name: name.into(),
+            value: TagValue::U16(value),
+            print: TagValue::U16(value).into(),
+            source: None,
+        };
+        let mut exif_data = ExifData::new("test.tif".to_string(), String::new());
+        exif_data.tags = vec![
+            entry("Composite", "Composite", "ImageSize", 1),
+            entry("EXIF", "IFD1", "ImageWidth", 160),
+            entry("EXIF", "IFD0", "ImageWidth", 6000),
+            entry("EXIF", "GPS", "GPSAltitude", 12),
+            entry("MakerNotes", "Canon", "LensType", 61),
+            entry("File", "System", "FileSize", 100),
+        ];
+        exif_data.prepare_for_serialization_with_groups(None, GroupFamily::Family1);
+
+        // Family 0 priority order, then family 1 keys alphabetically
+        let keys: Vec<_> = exif_data.legacy_tags.keys().cloned().collect();
+        assert_eq!(
+            keys,
+            [
+                "System:FileSize",
+                "GPS:GPSAltitude",
+                "IFD0:ImageWidth",
+                "IFD1:ImageWidth",
+                "Canon:LensType",
+                "Composite:ImageSize"
+            ]
+        );
+        assert_eq!(exif_data.legacy_tags["IFD1:ImageWidth"], TagValue::U16(160));
+    }
+
+    #[test]
+    fn test_subifd_pointers_take_subifd_group1() {
+        let ifd0 = TagSourceInfo::new("EXIF".to_string(), "IFD0".to_string(), "Exif".to_string());
+        assert_eq!(ifd0.get_group1_with_tag_override(0x010f), "IFD0");
+        assert_eq!(ifd0.get_group1_with_tag_override(0x8769), "ExifIFD");
+        assert_eq!(ifd0.get_group1_with_tag_override(0x8825), "GPS");
+        let exif_ifd = TagSourceInfo::new(
+            "EXIF".to_string(),
+            "ExifIFD".to_string(),
+            "Exif".to_string(),
+        );
+        assert_eq!(exif_ifd.get_group1_with_tag_override(0xa005), "InteropIFD");
+    }
+
+    #[test]
+    fn test_format_binary_value() {
+        let blob = TagValue::Binary(vec![0xde, 0xad, 0xbe, 0xef]);
+
+        let default = FilterOptions::default();
+        assert_eq!(
+            default.format_binary_value(blob.clone()),
+            TagValue::string("(Binary data 4 bytes, use -b option to extract)")
+        );
+        assert_eq!(
+            default.format_binary_value(TagValue::U16(7)),
+            TagValue::U16(7)
+        );
+
+        let small_allowed = FilterOptions {
+            binary_threshold: 4,
+            ..FilterOptions::default()
+        };
+        assert_eq!(small_allowed.format_binary_value(blob.clone()), blob);
+
+        let base64 = FilterOptions {
+            include_binary: true,
+            ..FilterOptions::default()
+        };
+        assert_eq!(
+            base64.format_binary_value(blob),
+            TagValue::string("base64:3q2+7w==")
+        );
+    }
+
+    #[test]
+    fn test_format_binary_values_keeps_tag_bytes() {
+        let icc = TagValue::Binary(vec![0; 16]);
+        let mut exif_data = ExifData::new("test.jpg".to_string(), String::new());
+        exif_data.tags = vec![TagEntry {
+            group: "ICC_Profile".into(),
+            group1: "ICC_Profile".into(),
+            name: "ICC_Profile".into(),
+            value: icc.clone(),
+            print: icc.clone().into(),
+            source: None,
+        }];
+        exif_data.prepare_for_serialization(None);
+        exif_data.format_binary_values(&FilterOptions::default());
+
+        assert_eq!(
+            exif_data.legacy_tags["ICC_Profile:ICC_Profile"],
+            TagValue::string("(Binary data 16 bytes, use -b option to extract)")
+        );
+        assert_eq!(exif_data.tags[0].value, icc);
+    }
+
     #[test]
     fn test_matches_glob_pattern() {
         // Test prefix wildcard
@@ -780,6 +1990,24 @@ mod tests {
             glob_patterns: vec!["GPS*".to_string()],
             compute_image_hash: false,
             image_hash_type: ImageHashType::default(),
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(FilterOptions::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric: false,
+            tag_order: TagOrder::default(),
+            group_family: GroupFamily::default(),
+            unknown_tags: UnknownTags::default(),
+            allow_duplicates: false,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth: ScanDepth::default(),
+            symlinks: SymlinkPolicy::default(),
+            composites: true,
         };
 
         // Should match GPS tags
@@ -795,6 +2023,37 @@ mod tests {
         assert!(!filter_opts.should_extract_tag("Altitude", "EXIF")); // Different group
     }
 
+    #[test]
+    fn test_should_extract_tag_with_aliases() {
+        let filter_opts = FilterOptions::tags_only(vec![
+            "allDates".to_string(),
+            "EXIF:ISOSpeedRatings".to_string(),
+        ]);
+        assert!(filter_opts.should_extract_tag("DateTimeOriginal", "EXIF"));
+        assert!(filter_opts.should_extract_tag("ModifyDate", "EXIF"));
+        assert!(filter_opts.should_extract_tag("ISO", "EXIF"));
+        assert!(!filter_opts.should_extract_tag("ISO", "MakerNotes"));
+        assert!(!filter_opts.should_extract_tag("Make", "EXIF"));
+    }
+
+    #[test]
+    fn test_with_composite_sources() {
+        let filter = FilterOptions::tags_only(vec!["Composite:ImageSize".to_string()]);
+        assert!(filter.should_extract_tag("ImageSize", "Composite"));
+        assert!(!filter.should_extract_tag("ImageSize", "EXIF"));
+        assert!(!filter.should_extract_tag("ImageWidth", "File"));
+
+        let extraction = filter.with_composite_sources();
+        assert!(extraction.should_extract_tag("ImageSize", "Composite"));
+        assert!(extraction.should_extract_tag("ImageWidth", "File"));
+        assert!(extraction.should_extract_tag("ImageHeight", "EXIF"));
+        assert!(!extraction.should_extract_tag("Make", "EXIF"));
+
+        // Filters that select no composite are unchanged
+        let make_only = FilterOptions::tags_only(vec!["Make".to_string()]);
+        assert_eq!(make_only.with_composite_sources(), make_only);
+    }
+
     #[test]
     fn test_is_file_group_only_with_glob_patterns() {
         // GPS glob pattern should NOT be file-only
@@ -807,6 +2066,24 @@ mod tests {
             glob_patterns: vec!["GPS*".to_string()],
             compute_image_hash: false,
             image_hash_type: ImageHashType::default(),
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(FilterOptions::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric: false,
+            tag_order: TagOrder::default(),
+            group_family: GroupFamily::default(),
+            unknown_tags: UnknownTags::default(),
+            allow_duplicates: false,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth: ScanDepth::default(),
+            symlinks: SymlinkPolicy::default(),
+            composites: true,
         };
         assert!(!gps_filter.is_file_group_only());
 
@@ -820,6 +2097,24 @@ mod tests {
             glob_patterns: vec!["File*".to_string()],
             compute_image_hash: false,
             image_hash_type: ImageHashType::default(),
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(FilterOptions::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric: false,
+            tag_order: TagOrder::default(),
+            group_family: GroupFamily::default(),
+            unknown_tags: UnknownTags::default(),
+            allow_duplicates: false,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth: ScanDepth::default(),
+            symlinks: SymlinkPolicy::default(),
+            composites: true,
         };
         assert!(file_filter.is_file_group_only());
 
@@ -833,9 +2128,53 @@ mod tests {
             glob_patterns: vec!["MIMEType*".to_string()],
             compute_image_hash: false,
             image_hash_type: ImageHashType::default(),
+            image_hash_chunk_size: None,
+            parallel_hash_min_size: Some(FilterOptions::DEFAULT_PARALLEL_HASH_MIN_SIZE),
+            binary_threshold: 0,
+            include_binary: false,
+            include_tag_sources: false,
+            validate: false,
+            stats: false,
+            geolocation: false,
+            numeric: false,
+            tag_order: TagOrder::default(),
+            group_family: GroupFamily::default(),
+            unknown_tags: UnknownTags::default(),
+            allow_duplicates: false,
+            quicktime_utc: QuickTimeUtc::default(),
+            charset_exif: ExifCharset::default(),
+            scan_depth: ScanDepth::default(),
+            symlinks: SymlinkPolicy::default(),
+            composites: true,
         };
         assert!(mime_filter.is_file_group_only());
     }
+
+    #[test]
+    fn test_xmp_packet_location() {
+        let packet = b"<?xpacket begin='\xef\xbb\xbf' id='W5M0MpCehiHzreSzNTczkc9d'?>\n\
+            <x:xmpmeta xmlns:x='adobe:ns:meta/'/>\n          \n<?xpacket end='w'?>";
+        let location = XmpPacketLocation::new("JPEG APP1", 20, packet);
+        assert!(location.has_wrapper);
+        assert!(location.writable);
+        assert_eq!(location.padding, 12);
+        assert_eq!(location.length, packet.len() as u64);
+        assert!(location.can_rewrite_in_place(packet.len() as u64));
+        assert!(!location.can_rewrite_in_place(packet.len() as u64 + 1));
+        assert!(!location
+            .clone()
+            .with_extended(true)
+            .can_rewrite_in_place(10));
+
+        let read_only =
+            XmpPacketLocation::new("XMP file", 0, b"<?xpacket begin=''?><?xpacket end=\"r\"?>");
+        assert!(read_only.has_wrapper && !read_only.writable);
+
+        let bare = XmpPacketLocation::new("XMP file", 0, b"<x:xmpmeta/>   ");
+        assert!(!bare.has_wrapper);
+        assert_eq!(bare.padding, 0);
+        assert!(!bare.can_rewrite_in_place(1));
+    }
 }
 
 impl TagSourceInfo {
@@ -847,6 +2186,38 @@ impl TagSourceInfo {
             ifd_name,
             priority,
             processor_name,
+            value_offset: None,
+            format: None,
+            count: None,
+            print_conv: None,
+        }
+    }
+
+    /// Record the IFD entry the tag was read from
+    /// ExifTool: lib/Image/ExifTool/Exif.pm:6390-6420 value offset and format handling
+    pub fn with_entry(mut self, value_offset: u64, format: TiffFormat, count: u32) -> Self {
+        self.value_offset = Some(value_offset);
+        self.format = Some(format);
+        self.count = Some(count);
+        self
+    }
+
+    /// Defer the PrintConv of a tag decoded from its own table
+    pub fn with_print_conv(mut self, print_conv: Option<DeferredPrintConv>) -> Self {
+        self.print_conv = print_conv;
+        self
+    }
+
+    /// Build the public [`TagSource`] for a tag with this source
+    pub fn to_tag_source(&self, tag_id: u16) -> TagSource {
+        TagSource {
+            table: self.ifd_name.clone(),
+            processor: self.processor_name.clone(),
+            // IDs at 0xC000 and above are synthetic (Canon binary data tags)
+            tag_id: (tag_id < 0xC000).then(|| format!("0x{tag_id:04x}")),
+            offset: self.value_offset,
+            format: self.format.map(|format| format.name().to_string()),
+            count: self.count,
         }
     }
 
@@ -865,7 +2236,10 @@ impl TagSourceInfo {
             "InteropIFD" => "InteropIFD".to_string(),
             "MakerNotes" => "MakerNotes".to_string(),
             "IFD1" => "IFD1".to_string(),
+            // TIFF SubIFDs: SubIFD, SubIFD1, SubIFD2, ...
+            name if name.starts_with("SubIFD") => name.to_string(),
             "KyoceraRaw" => "KyoceraRaw".to_string(),
+            "CanonRaw" => "CanonRaw".to_string(),
             // Canon MakerNote subdirectory processing
             // ExifTool: MakerNotes.pm MakerNoteCanon -> Canon.pm Main table
             // The directory name becomes Group1 per ExifTool's SetGroup logic
@@ -877,6 +2251,14 @@ impl TagSourceInfo {
             name if name.starts_with("Panasonic") => "Panasonic".to_string(),
             name if name.starts_with("Pentax") => "Pentax".to_string(),
             name if name.starts_with("Fujifilm") => "Fujifilm".to_string(),
+            name if name.starts_with("Apple") => "Apple".to_string(),
+            name if name.starts_with("Casio") => "Casio".to_string(),
+            name if name.starts_with("Kodak") => "Kodak".to_string(),
+            name if name.starts_with("Leica") => "Leica".to_string(),
+            name if name.starts_with("Minolta") => "Minolta".to_string(),
+            name if name.starts_with("Samsung") => "Samsung".to_string(),
+            name if name.starts_with("Sigma") => "Sigma".to_string(),
+            name if name.starts_with("Ricoh") => "Ricoh".to_string(),
             // Default to IFD0 for main IFD and unknown IFDs
             _ => "IFD0".to_string(),
         }
@@ -895,9 +2277,11 @@ impl TagSourceInfo {
             0xA001 => "ExifIFD".to_string(), // ColorSpace - Always in ExifIFD
             0xA002 => "ExifIFD".to_string(), // ExifImageWidth - Always in ExifIFD
             0xA003 => "ExifIFD".to_string(), // ExifImageHeight - Always in ExifIFD
-            0xA005 => "ExifIFD".to_string(), // InteropIFD pointer - Always in ExifIFD
-            // GPS IFD pointer should always have GPS group1
-            0x8825 => "GPS".to_string(), // GPSInfo - Always GPS context
+            // Sub-IFD pointers take the group of the IFD they point to
+            // ExifTool: Exif.pm 0x8769, 0x8825 and 0xa005 Groups => { 1 => ... }
+            0x8769 => "ExifIFD".to_string(),    // ExifOffset
+            0x8825 => "GPS".to_string(),        // GPSInfo
+            0xA005 => "InteropIFD".to_string(), // InteropOffset
             // For all other tags, use normal context-based assignment
             _ => self.get_group1(),
         }
@@ -6,16 +6,24 @@
 // Re-export core types from crate::core for API compatibility
 pub use crate::core::{ExifContext, TagValue};
 
+mod api_options;
 pub mod binary_data;
 mod context;
 mod errors;
+mod interned;
 mod metadata;
+mod tag_aliases;
 mod tag_info;
+mod xmp_provenance;
 
 // Re-export everything for backwards compatibility
+pub use api_options::ApiOptions;
 pub use binary_data::*;
 #[allow(unused_imports)]
 pub use context::*;
 pub use errors::{ExifError, Result}; // ExifError comes from crate::core via errors module
+pub use interned::InternedStr;
 pub use metadata::*;
+pub use tag_aliases::expand_tag_name;
 pub use tag_info::*;
+pub use xmp_provenance::{HistoryEvent, ResourceRef, XmpProvenance};
@@ -0,0 +1,145 @@
+//! Alternate spellings accepted in tag requests
+//!
+//! Tag requests already match names case-insensitively, like ExifTool. On
+//! top of that, scripts migrated from ExifTool use its shortcut tags
+//! (`-AllDates`, `-Common`), and scripts from libraries that follow the
+//! EXIF specification ask for `ISOSpeedRatings` or `DateTimeDigitized`
+//! where ExifTool says `ISO` and `CreateDate`. Both kinds expand here to the
+//! names this crate extracts.
+//!
+//! ExifTool Reference: lib/Image/ExifTool/Shortcuts.pm
+
+/// ExifTool shortcut tags
+///
+/// Group qualifiers in Shortcuts.pm (`IFD0:Make`, `ExifIFD:ColorSpace`)
+/// are family 1 groups, which requests don't match, so they are dropped.
+/// ExifTool: Shortcuts.pm %Image::ExifTool::Shortcuts::Main
+const SHORTCUTS: &[(&str, &[&str])] = &[
+    (
+        "AllDates",
+        &["DateTimeOriginal", "CreateDate", "ModifyDate"],
+    ),
+    (
+        "Common",
+        &[
+            "FileName",
+            "FileSize",
+            "Model",
+            "DateTimeOriginal",
+            "ImageSize",
+            "Quality",
+            "FocalLength",
+            "ShutterSpeed",
+            "Aperture",
+            "ISO",
+            "WhiteBalance",
+            "Flash",
+        ],
+    ),
+    (
+        "CommonIFD0",
+        &[
+            "ImageDescription",
+            "Make",
+            "Model",
+            "Software",
+            "ModifyDate",
+            "Artist",
+            "Copyright",
+            "Rating",
+            "RatingPercent",
+            "DNGLensInfo",
+            "PanasonicTitle",
+            "PanasonicTitle2",
+            "XPTitle",
+            "XPComment",
+            "XPAuthor",
+            "XPKeywords",
+            "XPSubject",
+        ],
+    ),
+    (
+        "ColorSpaceTags",
+        &["ColorSpace", "Gamma", "InteropIndex", "ICC_Profile"],
+    ),
+];
+
+/// EXIF specification field names that ExifTool names differently
+/// ExifTool: Exif.pm Main, e.g. 0x8827 "ISO (called ISOSpeedRatings by the
+/// EXIF spec)"
+const SPEC_NAMES: &[(&str, &str)] = &[
+    ("BodySerialNumber", "SerialNumber"),
+    ("CameraOwnerName", "OwnerName"),
+    ("DateTime", "ModifyDate"),
+    ("DateTimeDigitized", "CreateDate"),
+    ("ExifIFDPointer", "ExifOffset"),
+    ("ExposureBiasValue", "ExposureCompensation"),
+    ("FocalLengthIn35mmFilm", "FocalLengthIn35mmFormat"),
+    ("GPSInfoIFDPointer", "GPSInfo"),
+    ("ImageLength", "ImageHeight"),
+    ("InteroperabilityIFDPointer", "InteropOffset"),
+    ("InteroperabilityIndex", "InteropIndex"),
+    ("InteroperabilityVersion", "InteropVersion"),
+    ("ISOSpeedRatings", "ISO"),
+    ("JPEGInterchangeFormat", "ThumbnailOffset"),
+    ("JPEGInterchangeFormatLength", "ThumbnailLength"),
+    ("LensSpecification", "LensInfo"),
+    ("OECF", "Opto-ElectricConvFactor"),
+    ("PhotographicSensitivity", "ISO"),
+    ("PixelXDimension", "ExifImageWidth"),
+    ("PixelYDimension", "ExifImageHeight"),
+];
+
+/// The tag names a requested name stands for
+///
+/// Shortcuts are replaced by the names they stand for. EXIF specification
+/// names also match the ExifTool name, keeping any group prefix
+/// (`EXIF:ISOSpeedRatings` adds `EXIF:ISO`), as some formats have tags
+/// named `DateTime`. Any other name is returned unchanged. Matching is
+/// case-insensitive.
+pub fn expand_tag_name(requested: &str) -> Vec<String> {
+    let (group, name) = match requested.split_once(':') {
+        Some((group, name)) => (Some(group), name),
+        None => (None, requested),
+    };
+    let qualify = |name: &str| match group {
+        Some(group) => format!("{group}:{name}"),
+        None => name.to_string(),
+    };
+
+    if let Some((_, names)) = SHORTCUTS
+        .iter()
+        .find(|(shortcut, _)| shortcut.eq_ignore_ascii_case(name))
+    {
+        return names.iter().map(|name| qualify(name)).collect();
+    }
+    if let Some((_, exiftool_name)) = SPEC_NAMES
+        .iter()
+        .find(|(spec_name, _)| spec_name.eq_ignore_ascii_case(name))
+    {
+        return vec![requested.to_string(), qualify(exiftool_name)];
+    }
+    vec![requested.to_string()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_tag_name() {
+        assert_eq!(
+            expand_tag_name("alldates"),
+            ["DateTimeOriginal", "CreateDate", "ModifyDate"]
+        );
+        assert_eq!(
+            expand_tag_name("ISOSpeedRatings"),
+            ["ISOSpeedRatings", "ISO"]
+        );
+        assert_eq!(
+            expand_tag_name("EXIF:dateTimeDigitized"),
+            ["EXIF:dateTimeDigitized", "EXIF:CreateDate"]
+        );
+        assert_eq!(expand_tag_name("dateTimeOriginal"), ["dateTimeOriginal"]);
+    }
+}
@@ -81,6 +81,9 @@ pub enum PrintConv {
 
     /// Complex conversion requiring custom logic
     Complex,
+
+    /// Names of the set bits, by bit number (ExifTool's BITMASK hash)
+    Bitmask(&'static [(u32, &'static str)]),
 }
 
 /// Value conversion type  
@@ -106,3 +109,107 @@ pub enum ValueConv {
     /// Complex conversion requiring custom logic
     Complex,
 }
+
+/// Generated predicate for a tag variant's ExifTool `Condition`
+pub type TagConditionFn = fn(&crate::types::TagValue, Option<&crate::types::ExifContext>) -> bool;
+
+/// One definition of a tag whose ExifTool entry is a list of conditional
+/// variants (e.g. Canon ShotInfo tags that differ by model)
+#[derive(Debug, Clone)]
+pub struct TagVariant {
+    /// Predicate generated from the Condition; `None` for variants without one
+    pub condition: Option<TagConditionFn>,
+
+    /// Original Perl Condition (for debugging/reference)
+    pub condition_expr: Option<&'static str>,
+
+    /// Tag definition used when the condition holds
+    pub info: TagInfo,
+}
+
+impl TagVariant {
+    /// Whether this variant applies to a value
+    ///
+    /// A variant without a Condition always applies. One whose Condition has
+    /// no generated predicate never does.
+    pub fn matches(
+        &self,
+        val: &crate::types::TagValue,
+        ctx: Option<&crate::types::ExifContext>,
+    ) -> bool {
+        match (self.condition, self.condition_expr) {
+            (Some(condition), _) => condition(val, ctx),
+            (None, None) => true,
+            (None, Some(_)) => false,
+        }
+    }
+}
+
+/// The first variant whose condition holds, as in ExifTool's GetTagInfo
+pub fn select_tag_variant<'a>(
+    variants: &'a [TagVariant],
+    val: &crate::types::TagValue,
+    ctx: Option<&crate::types::ExifContext>,
+) -> Option<&'a TagInfo> {
+    variants
+        .iter()
+        .find(|variant| variant.matches(val, ctx))
+        .map(|variant| &variant.info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ExifContext, TagValue};
+
+    fn info(name: &'static str) -> TagInfo {
+        TagInfo {
+            name,
+            format: "int16u",
+            print_conv: None,
+            value_conv: None,
+            is_offset: false,
+        }
+    }
+
+    fn is_20d(_val: &TagValue, ctx: Option<&ExifContext>) -> bool {
+        ctx.and_then(|ctx| ctx.get_data_member("Model"))
+            .is_some_and(|model| model.to_string().contains("20D"))
+    }
+
+    #[test]
+    fn selects_first_matching_variant() {
+        let variants = [
+            TagVariant {
+                condition: Some(is_20d),
+                condition_expr: Some("$$self{Model} =~ /20D/"),
+                info: info("ExposureTime20D"),
+            },
+            TagVariant {
+                condition: None,
+                condition_expr: Some("$count == 4"),
+                info: info("Untranslated"),
+            },
+            TagVariant {
+                condition: None,
+                condition_expr: None,
+                info: info("ExposureTime"),
+            },
+        ];
+
+        let mut ctx = ExifContext::new();
+        ctx.set_data_member("Model", TagValue::string("Canon EOS 20D"));
+        let val = TagValue::U16(1);
+        assert_eq!(
+            select_tag_variant(&variants, &val, Some(&ctx))
+                .unwrap()
+                .name,
+            "ExposureTime20D"
+        );
+        assert_eq!(
+            select_tag_variant(&variants, &val, None).unwrap().name,
+            "ExposureTime"
+        );
+        assert!(select_tag_variant(&variants[..2], &val, None).is_none());
+    }
+}
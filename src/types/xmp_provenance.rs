@@ -0,0 +1,136 @@
+//! XMP Media Management provenance (xmpMM)
+//!
+//! ExifTool flattens xmpMM:History into parallel lists (HistoryAction,
+//! HistoryWhen, ...) that skip fields an event doesn't have, so once events
+//! record different fields the lists no longer line up. [`XmpProvenance`]
+//! keeps each event whole, along with the document IDs that link a file to
+//! the one it was derived from:
+//!
+//! - `DocumentID` identifies a document across saves; an export or a
+//!   "save as" gets a new one.
+//! - `InstanceID` changes on every save.
+//! - `OriginalDocumentID` is the DocumentID of the first document in the
+//!   chain, so it is shared by everything derived from the same original.
+//! - `DerivedFrom` holds the document and instance IDs of the source.
+//!
+//! Field names follow the stRef and stEvt structure fields in ExifTool's
+//! XMP tables (lib/Image/ExifTool/XMP.pm, %sResourceRef and %sResourceEvent).
+
+use serde::{Deserialize, Serialize};
+
+/// A reference to another document (stRef), as used by xmpMM:DerivedFrom
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceRef {
+    #[serde(rename = "DocumentID", skip_serializing_if = "Option::is_none")]
+    pub document_id: Option<String>,
+    #[serde(rename = "InstanceID", skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "OriginalDocumentID", skip_serializing_if = "Option::is_none")]
+    pub original_document_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendition_class: Option<String>,
+}
+
+/// One xmpMM:History entry (stEvt)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HistoryEvent {
+    /// "created", "saved", "converted", "derived", ...
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// Parts of the document that changed, like "/metadata"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changed: Option<String>,
+    /// InstanceID of the document after this event
+    #[serde(rename = "InstanceID", skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<String>,
+    /// Free-form details, like "converted from image/tiff to image/jpeg"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub software_agent: Option<String>,
+    /// The XMP date as written, like "2024-01-15T10:30:00+01:00"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub when: Option<String>,
+}
+
+/// Document identity and editing history from an XMP packet
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct XmpProvenance {
+    #[serde(rename = "DocumentID", skip_serializing_if = "Option::is_none")]
+    pub document_id: Option<String>,
+    #[serde(rename = "InstanceID", skip_serializing_if = "Option::is_none")]
+    pub instance_id: Option<String>,
+    #[serde(rename = "OriginalDocumentID", skip_serializing_if = "Option::is_none")]
+    pub original_document_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub derived_from: Option<ResourceRef>,
+    /// Events in document order (oldest first, as written by Adobe apps)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<HistoryEvent>,
+}
+
+impl XmpProvenance {
+    /// Whether the packet had no xmpMM provenance at all
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Document IDs from the original to this file, without repeats
+    ///
+    /// Two files sharing an ID here come from the same document, which is
+    /// what duplicate detection usually wants to know.
+    pub fn lineage(&self) -> Vec<&str> {
+        let derived_from = self.derived_from.as_ref();
+        let ids = [
+            self.original_document_id.as_deref(),
+            derived_from.and_then(|r| r.original_document_id.as_deref()),
+            derived_from.and_then(|r| r.document_id.as_deref()),
+            self.document_id.as_deref(),
+        ];
+        let mut lineage = Vec::new();
+        for id in ids.into_iter().flatten() {
+            if !lineage.contains(&id) {
+                lineage.push(id);
+            }
+        }
+        lineage
+    }
+
+    /// The last software agent recorded in the history
+    pub fn last_software_agent(&self) -> Option<&str> {
+        self.history
+            .iter()
+            .rev()
+            .find_map(|event| event.software_agent.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lineage() {
+        let provenance = XmpProvenance {
+            document_id: Some("xmp.did:child".into()),
+            original_document_id: Some("xmp.did:orig".into()),
+            derived_from: Some(ResourceRef {
+                document_id: Some("xmp.did:parent".into()),
+                original_document_id: Some("xmp.did:orig".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            provenance.lineage(),
+            ["xmp.did:orig", "xmp.did:parent", "xmp.did:child"]
+        );
+        assert!(XmpProvenance::default().is_empty());
+        assert!(XmpProvenance::default().lineage().is_empty());
+    }
+}
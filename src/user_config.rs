@@ -0,0 +1,376 @@
+//! User-defined tags loaded from a config file
+//!
+//! ExifTool users extend it with `.ExifTool_config`, a Perl file that adds
+//! XMP namespaces, composite tags and so on. exif-oxide reads the same kinds
+//! of definition from JSON:
+//!
+//! ```json
+//! {
+//!   "xmp": {
+//!     "xxx": {
+//!       "uri": "http://ns.myname.com/xxx/1.0/",
+//!       "tags": { "NewXMPxxxTag1": {}, "rating2": { "name": "SecondRating" } }
+//!     }
+//!   },
+//!   "composites": {
+//!     "CameraName": { "template": "$Make $Model" },
+//!     "Credit": { "template": "(c) ${XMP:Creator}" }
+//!   },
+//!   "rename": { "EXIF:Artist": "Photographer" }
+//! }
+//! ```
+//!
+//! - `xmp` declares namespaces by prefix. Every property in a declared
+//!   namespace is extracted; `tags` entries may give a property its own tag
+//!   name (ExifTool's `Name`), otherwise the name is the property with its
+//!   first letter capitalized.
+//! - `composites` builds `Composite:<Name>` from a template over the print
+//!   values of other tags (ExifTool `-p` syntax: `$Tag`, `${Tag}`,
+//!   `$Group:Tag`, `$$` for a dollar sign). Like a `Require`d tag, a missing
+//!   reference means the composite is not built.
+//! - `rename` renames output tags, matched as `Tag` or `Group:Tag`.
+//!
+//! Install a config with [`set_config`]; the CLI does so for `-config FILE`.
+
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use crate::types::{ExifError, Result, TagEntry, TagValue};
+
+/// A parsed user config
+#[derive(Debug, Clone, Default)]
+pub struct UserConfig {
+    xmp: IndexMap<String, XmpNamespace>,
+    composites: IndexMap<String, UserComposite>,
+    rename: IndexMap<String, String>,
+    /// The JSON the config was read from, for cache keys
+    source: String,
+}
+
+/// A user-defined XMP namespace
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct XmpNamespace {
+    uri: String,
+    #[serde(default)]
+    tags: IndexMap<String, XmpTag>,
+}
+
+/// A user-defined XMP property
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct XmpTag {
+    name: Option<String>,
+}
+
+/// A user-defined composite tag
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UserComposite {
+    template: String,
+}
+
+/// The on-disk layout of a config file
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    #[serde(default)]
+    xmp: IndexMap<String, XmpNamespace>,
+    #[serde(default)]
+    composites: IndexMap<String, UserComposite>,
+    #[serde(default)]
+    rename: IndexMap<String, String>,
+}
+
+impl UserConfig {
+    /// Parse a config from JSON
+    pub fn from_json(json: &str) -> Result<Self> {
+        let file: ConfigFile = serde_json::from_str(json)
+            .map_err(|e| ExifError::ParseError(format!("Invalid config: {e}")))?;
+        for (name, composite) in &file.composites {
+            parse_template(&composite.template).map_err(|e| {
+                ExifError::ParseError(format!("Invalid template for composite {name}: {e}"))
+            })?;
+        }
+        Ok(Self {
+            xmp: file.xmp,
+            composites: file.composites,
+            rename: file.rename,
+            source: json.to_string(),
+        })
+    }
+
+    /// Read a config file
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Self::from_json(&json)
+    }
+
+    /// The JSON this config was read from
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// (prefix, URI) of each user-defined XMP namespace
+    pub fn xmp_namespaces(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.xmp
+            .iter()
+            .map(|(prefix, namespace)| (prefix.as_str(), namespace.uri.as_str()))
+    }
+
+    /// Tag name for a property in a user-defined XMP namespace
+    pub fn xmp_tag_name(&self, prefix: &str, property: &str) -> Option<String> {
+        let namespace = self.xmp.get(prefix)?;
+        let name = namespace
+            .tags
+            .get(property)
+            .and_then(|tag| tag.name.clone())
+            .unwrap_or_else(|| ucfirst(property));
+        Some(name)
+    }
+
+    /// Build the user-defined composites from the extracted tags
+    pub fn build_composites(&self, tags: &[TagEntry]) -> Vec<TagEntry> {
+        self.composites
+            .iter()
+            .filter_map(|(name, composite)| {
+                let value = expand_template(&composite.template, tags)?;
+                Some(TagEntry {
+                    group: "Composite".into(),
+                    group1: "Composite".into(),
+                    name: name.as_str().into(),
+                    value: TagValue::string(value.clone()),
+                    print: TagValue::string(value).into(),
+                    source: None,
+                })
+            })
+            .collect()
+    }
+
+    /// Apply the `rename` section to the output tags
+    pub fn apply_renames(&self, tags: &mut [TagEntry]) {
+        if self.rename.is_empty() {
+            return;
+        }
+        for tag in tags {
+            let qualified = format!("{}:{}", tag.group, tag.name);
+            let renamed = self
+                .rename
+                .get(&qualified)
+                .or_else(|| self.rename.get(tag.name.as_ref()));
+            if let Some(new_name) = renamed {
+                tag.name = new_name.as_str().into();
+            }
+        }
+    }
+}
+
+static USER_CONFIG: RwLock<Option<Arc<UserConfig>>> = RwLock::new(None);
+
+/// Install a user config, or remove it with `None`
+///
+/// ExifTool equivalent: `-config FILE`
+pub fn set_config(config: Option<UserConfig>) {
+    let mut installed = USER_CONFIG
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *installed = config.map(Arc::new);
+}
+
+/// The installed user config, if any
+///
+/// Inside [`ExifSession`](crate::session::ExifSession) extraction, the
+/// session's config instead.
+pub fn config() -> Option<Arc<UserConfig>> {
+    if let Some(session) = crate::session::current() {
+        return session.user_config.clone();
+    }
+    USER_CONFIG
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// A piece of a composite template
+#[derive(Debug, PartialEq)]
+enum TemplatePart<'a> {
+    Literal(&'a str),
+    Tag(&'a str),
+}
+
+/// Split a template into literal text and `$Tag` references
+fn parse_template(template: &str) -> std::result::Result<Vec<TemplatePart<'_>>, String> {
+    let mut parts = Vec::new();
+    let mut rest = template;
+    while let Some(dollar) = rest.find('$') {
+        if dollar > 0 {
+            parts.push(TemplatePart::Literal(&rest[..dollar]));
+        }
+        let after = &rest[dollar + 1..];
+        if let Some(after_dollar) = after.strip_prefix('$') {
+            parts.push(TemplatePart::Literal("$"));
+            rest = after_dollar;
+        } else if let Some(braced) = after.strip_prefix('{') {
+            let end = braced
+                .find('}')
+                .ok_or_else(|| format!("unterminated ${{ at offset {dollar}"))?;
+            parts.push(TemplatePart::Tag(braced[..end].trim()));
+            rest = &braced[end + 1..];
+        } else {
+            // ExifTool tag names are word characters, plus ':' for a group
+            // and '-' in group names such as XMP-dc
+            let end = after
+                .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | ':' | '-')))
+                .unwrap_or(after.len());
+            if end == 0 {
+                return Err(format!("'$' without a tag name at offset {dollar}"));
+            }
+            parts.push(TemplatePart::Tag(&after[..end]));
+            rest = &after[end..];
+        }
+    }
+    if !rest.is_empty() {
+        parts.push(TemplatePart::Literal(rest));
+    }
+    Ok(parts)
+}
+
+/// Expand a template, or `None` if a referenced tag is missing
+fn expand_template(template: &str, tags: &[TagEntry]) -> Option<String> {
+    let mut out = String::new();
+    for part in parse_template(template).ok()? {
+        match part {
+            TemplatePart::Literal(text) => out.push_str(text),
+            TemplatePart::Tag(name) => out.push_str(&template_value(find_tag(tags, name)?.print())),
+        }
+    }
+    Some(out)
+}
+
+/// A value as `-p` prints it: list items are joined with ", "
+fn template_value(value: &TagValue) -> String {
+    match value {
+        TagValue::Array(items) => items
+            .iter()
+            .map(template_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+/// Find a tag by `Tag` or `Group:Tag` (either group family), ignoring case
+fn find_tag<'a>(tags: &'a [TagEntry], reference: &str) -> Option<&'a TagEntry> {
+    let (group, name) = match reference.rsplit_once(':') {
+        Some((group, name)) => (Some(group), name),
+        None => (None, reference),
+    };
+    tags.iter().find(|tag| {
+        tag.name.eq_ignore_ascii_case(name)
+            && group.is_none_or(|group| {
+                tag.group.eq_ignore_ascii_case(group) || tag.group1.eq_ignore_ascii_case(group)
+            })
+    })
+}
+
+/// Capitalize the first letter, as ExifTool names undefined XMP properties
+fn ucfirst(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(group: &str, name: &str, print: TagValue) -> TagEntry {
+        TagEntry {
+            group: group.into(),
+            group1: group.into(),
+            name: name.into(),
+            value: print.clone(),
+            print: print.into(),
+            source: None,
+        }
+    }
+
+    const CONFIG: &str = r#"{
+        "xmp": {
+            "xxx": {
+                "uri": "http://ns.myname.com/xxx/1.0/",
+                "tags": { "rating2": { "name": "SecondRating" } }
+            }
+        },
+        "composites": {
+            "CameraName": { "template": "$Make ${Model}" },
+            "Price": { "template": "$$$EXIF:ISO" },
+            "Missing": { "template": "$Make $LensModel" },
+            "Credit": { "template": "(c) $XMP:Creator" }
+        },
+        "rename": { "EXIF:Artist": "Photographer", "Model": "CameraModel" }
+    }"#;
+
+    #[test]
+    fn builds_template_composites() {
+        let config = UserConfig::from_json(CONFIG).unwrap();
+        let tags = vec![
+            tag("EXIF", "Make", TagValue::string("Canon")),
+            tag("EXIF", "Model", TagValue::string("Canon EOS R5")),
+            tag("EXIF", "ISO", TagValue::U16(400)),
+            tag(
+                "XMP",
+                "Creator",
+                TagValue::Array(vec![TagValue::string("A"), TagValue::string("B")]),
+            ),
+        ];
+        let composites = config.build_composites(&tags);
+        let names: Vec<_> = composites.iter().map(|t| t.name.as_ref()).collect();
+        assert_eq!(names, ["CameraName", "Price", "Credit"]);
+        assert_eq!(
+            composites[0].print(),
+            &TagValue::string("Canon Canon EOS R5")
+        );
+        assert_eq!(composites[1].print(), &TagValue::string("$400"));
+        assert_eq!(composites[2].print(), &TagValue::string("(c) A, B"));
+    }
+
+    #[test]
+    fn renames_plain_and_qualified_names() {
+        let config = UserConfig::from_json(CONFIG).unwrap();
+        let mut tags = vec![
+            tag("EXIF", "Artist", TagValue::string("A")),
+            tag("XMP", "Artist", TagValue::string("B")),
+            tag("EXIF", "Model", TagValue::string("C")),
+        ];
+        config.apply_renames(&mut tags);
+        let names: Vec<_> = tags.iter().map(|t| t.name.as_ref()).collect();
+        assert_eq!(names, ["Photographer", "Artist", "CameraModel"]);
+    }
+
+    #[test]
+    fn names_xmp_properties() {
+        let config = UserConfig::from_json(CONFIG).unwrap();
+        assert_eq!(
+            config.xmp_tag_name("xxx", "rating2").as_deref(),
+            Some("SecondRating")
+        );
+        assert_eq!(
+            config.xmp_tag_name("xxx", "newTag").as_deref(),
+            Some("NewTag")
+        );
+        assert_eq!(config.xmp_tag_name("dc", "title"), None);
+    }
+
+    #[test]
+    fn rejects_bad_config() {
+        assert!(UserConfig::from_json(r#"{"composites": {"A": {"template": "${Make"}}}"#).is_err());
+        assert!(UserConfig::from_json(r#"{"composites": {"A": {"template": "$ x"}}}"#).is_err());
+        assert!(UserConfig::from_json(r#"{"tags": {}}"#).is_err());
+    }
+}
@@ -0,0 +1,351 @@
+//! Forensic consistency checks
+//!
+//! An opt-in analysis pass over the extracted tags (`FilterOptions::validate`,
+//! CLI `--validate`) that reports inconsistencies hinting at an edited or
+//! re-saved file as tags in the `Validation` group:
+//!
+//! - `DateOrder`: ModifyDate earlier than DateTimeOriginal
+//! - `InvalidDate`: date/time tags that don't parse, or lie in the future
+//! - `MissingMakerNotes`: no maker notes from a make whose cameras always write them
+//! - `ImageSizeMismatch`: EXIF image size differs from the actual image size
+//! - `ThumbnailSizeMismatch`: thumbnail orientation differs from the main image
+//! - `EditingSoftware`: a Software/CreatorTool tag naming an image editor
+//! - `Validate`: summary, `OK` or the number of warnings
+//!
+//! ExifTool's `-validate` checks conformance to the specifications instead;
+//! these heuristics are what its users otherwise script by hand.
+
+use crate::types::{TagEntry, TagValue};
+use chrono::NaiveDateTime;
+
+/// Group 0 and 1 of every tag produced by [`validate`]
+pub const VALIDATION_GROUP: &str = "Validation";
+
+/// Date/time tags checked for plausibility
+const DATE_TAGS: &[(&str, &str)] = &[
+    ("EXIF", "DateTimeOriginal"),
+    ("EXIF", "CreateDate"),
+    ("EXIF", "ModifyDate"),
+    ("EXIF", "GPSDateStamp"),
+    ("XMP", "DateCreated"),
+    ("XMP", "ModifyDate"),
+];
+
+/// Makes whose cameras always write maker notes that this crate decodes
+const MAKER_NOTE_MAKES: &[&str] = &["Canon", "Nikon", "Sony", "Olympus", "OM Digital", "Apple"];
+
+/// Software tags that name the program that last wrote the file
+const SOFTWARE_TAGS: &[(&str, &str)] = &[
+    ("EXIF", "Software"),
+    ("EXIF", "ProcessingSoftware"),
+    ("XMP", "CreatorTool"),
+    ("XMP", "HistorySoftwareAgent"),
+];
+
+/// Image editors, matched case-insensitively against the software tags
+const EDITORS: &[&str] = &[
+    "acdsee",
+    "affinity photo",
+    "canva",
+    "capture one",
+    "darktable",
+    "dxo",
+    "facetune",
+    "fotor",
+    "gimp",
+    "lightroom",
+    "luminar",
+    "paint.net",
+    "photoscape",
+    "photoshop",
+    "picasa",
+    "pixelmator",
+    "rawtherapee",
+    "snapseed",
+    "vsco",
+];
+
+/// Run all consistency checks over the extracted tags
+///
+/// Returns one `Validation` tag per failed check, followed by the
+/// `Validation:Validate` summary.
+pub fn validate(tags: &[TagEntry]) -> Vec<TagEntry> {
+    let warnings: Vec<(&str, String)> = [
+        ("DateOrder", check_date_order(tags)),
+        ("InvalidDate", check_invalid_dates(tags)),
+        ("MissingMakerNotes", check_maker_notes(tags)),
+        ("ImageSizeMismatch", check_image_size(tags)),
+        ("ThumbnailSizeMismatch", check_thumbnail_size(tags)),
+        ("EditingSoftware", check_editing_software(tags)),
+    ]
+    .into_iter()
+    .filter_map(|(name, warning)| warning.map(|warning| (name, warning)))
+    .collect();
+
+    let summary = match warnings.len() {
+        0 => "OK".to_string(),
+        1 => "1 Warning".to_string(),
+        n => format!("{n} Warnings"),
+    };
+
+    warnings
+        .into_iter()
+        .chain(std::iter::once(("Validate", summary)))
+        .map(|(name, message)| validation_tag(name, message))
+        .collect()
+}
+
+fn validation_tag(name: &str, message: String) -> TagEntry {
+    let value = TagValue::String(message);
+    TagEntry {
+        group: VALIDATION_GROUP.into(),
+        group1: VALIDATION_GROUP.into(),
+        name: name.into(),
+        value: value.clone(),
+        print: value.into(),
+        source: None,
+    }
+}
+
+fn find<'a>(tags: &'a [TagEntry], group: &str, name: &str) -> Option<&'a TagValue> {
+    tags.iter()
+        .find(|tag| tag.group == group && tag.name == name)
+        .map(|tag| &tag.value)
+}
+
+fn find_string<'a>(tags: &'a [TagEntry], group: &str, name: &str) -> Option<&'a str> {
+    find(tags, group, name)
+        .and_then(|value| value.as_string())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+fn find_u32(tags: &[TagEntry], group: &str, name: &str) -> Option<u32> {
+    find(tags, group, name)
+        .and_then(|value| value.as_u32())
+        .filter(|&v| v > 0)
+}
+
+/// Parse the date and time part of an EXIF/XMP date, ignoring sub-seconds and time zone
+///
+/// ExifTool writes both as "YYYY:MM:DD HH:MM:SS"; XMP dates may use '-' and 'T'.
+fn parse_date_time(value: &str) -> Option<NaiveDateTime> {
+    let normalized: String = value
+        .chars()
+        .take(19)
+        .enumerate()
+        .map(|(i, c)| match (i, c) {
+            (4 | 7, '-') => ':',
+            (10, 'T') => ' ',
+            _ => c,
+        })
+        .collect();
+    NaiveDateTime::parse_from_str(&normalized, "%Y:%m:%d %H:%M:%S").ok()
+}
+
+/// ExifTool writes unknown dates as all zeros or blanks ("0000:00:00 00:00:00")
+fn is_unset_date(value: &str) -> bool {
+    value.chars().all(|c| matches!(c, '0' | ':' | ' '))
+}
+
+fn check_date_order(tags: &[TagEntry]) -> Option<String> {
+    let original = find_string(tags, "EXIF", "DateTimeOriginal")?;
+    let modify = find_string(tags, "EXIF", "ModifyDate")?;
+    (parse_date_time(modify)? < parse_date_time(original)?)
+        .then(|| format!("ModifyDate ({modify}) is earlier than DateTimeOriginal ({original})"))
+}
+
+fn check_invalid_dates(tags: &[TagEntry]) -> Option<String> {
+    let now = chrono::Local::now().naive_local();
+    let problems: Vec<String> = DATE_TAGS
+        .iter()
+        .filter_map(|&(group, name)| {
+            let value = find_string(tags, group, name)?;
+            if is_unset_date(value) {
+                return None;
+            }
+            // GPSDateStamp has no time part
+            let parsed = if name == "GPSDateStamp" {
+                parse_date_time(&format!("{value} 00:00:00"))
+            } else {
+                parse_date_time(value)
+            };
+            match parsed {
+                None => Some(format!("{name} ({value}) is not a valid date")),
+                Some(date) if date > now => Some(format!("{name} ({value}) is in the future")),
+                Some(_) => None,
+            }
+        })
+        .collect();
+    (!problems.is_empty()).then(|| problems.join("; "))
+}
+
+fn check_maker_notes(tags: &[TagEntry]) -> Option<String> {
+    let make = find_string(tags, "EXIF", "Make")?;
+    let expected = MAKER_NOTE_MAKES
+        .iter()
+        .any(|m| make.to_lowercase().starts_with(&m.to_lowercase()));
+    let has_maker_notes = tags.iter().any(|tag| tag.group == "MakerNotes");
+    (expected && !has_maker_notes).then(|| format!("No maker notes for {make} camera"))
+}
+
+fn check_image_size(tags: &[TagEntry]) -> Option<String> {
+    let exif_width = find_u32(tags, "EXIF", "ExifImageWidth")?;
+    let exif_height = find_u32(tags, "EXIF", "ExifImageHeight")?;
+    let width = find_u32(tags, "File", "ImageWidth")?;
+    let height = find_u32(tags, "File", "ImageHeight")?;
+    (exif_width != width || exif_height != height).then(|| {
+        format!(
+            "ExifImageWidth/Height ({exif_width}x{exif_height}) differs from image size ({width}x{height})"
+        )
+    })
+}
+
+fn check_thumbnail_size(tags: &[TagEntry]) -> Option<String> {
+    let thumbnail = |name| {
+        tags.iter()
+            .find(|tag| tag.group1 == "IFD1" && tag.name == name)
+            .and_then(|tag| tag.value.as_u32())
+            .filter(|&v| v > 0)
+    };
+    let (thumb_width, thumb_height) = (thumbnail("ImageWidth")?, thumbnail("ImageHeight")?);
+    let width = find_u32(tags, "File", "ImageWidth")?;
+    let height = find_u32(tags, "File", "ImageHeight")?;
+    // Thumbnails are often letterboxed to 160x120, so only a portrait/landscape
+    // flip is meaningful: the main image was rotated without updating the thumbnail
+    let landscape = |w: u32, h: u32| w > h;
+    (width != height
+        && thumb_width != thumb_height
+        && landscape(thumb_width, thumb_height) != landscape(width, height))
+    .then(|| {
+        format!(
+            "Thumbnail ({thumb_width}x{thumb_height}) and image ({width}x{height}) orientations differ"
+        )
+    })
+}
+
+fn check_editing_software(tags: &[TagEntry]) -> Option<String> {
+    let editors: Vec<&str> = SOFTWARE_TAGS
+        .iter()
+        .filter_map(|&(group, name)| find_string(tags, group, name))
+        .filter(|software| {
+            let software = software.to_lowercase();
+            EDITORS.iter().any(|editor| software.contains(editor))
+        })
+        .collect();
+    let mut unique: Vec<&str> = Vec::new();
+    for editor in editors {
+        if !unique.contains(&editor) {
+            unique.push(editor);
+        }
+    }
+    (!unique.is_empty()).then(|| unique.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(group: &str, name: &str, value: TagValue) -> TagEntry {
+        TagEntry {
+            group: group.into(),
+            group1: group.into(),
+            name: name.into(),
+            value: value.clone(),
+            print: value.into(),
+            source: None,
+        }
+    }
+
+    fn get<'a>(validation: &'a [TagEntry], name: &str) -> Option<&'a str> {
+        validation
+            .iter()
+            .find(|tag| tag.name == name)
+            .and_then(|tag| tag.value.as_string())
+    }
+
+    #[test]
+    fn test_consistent_file_is_ok() {
+        let tags = vec![
+            tag("EXIF", "Make", TagValue::string("Canon")),
+            tag("MakerNotes", "ShutterCount", TagValue::U32(1234)),
+            tag("EXIF", "DateTimeOriginal", "2023:05:01 10:00:00".into()),
+            tag("EXIF", "ModifyDate", "2023:05:01 10:00:00".into()),
+            tag("EXIF", "Software", "Firmware Version 1.0.0".into()),
+            tag("EXIF", "ExifImageWidth", TagValue::U16(6000)),
+            tag("EXIF", "ExifImageHeight", TagValue::U16(4000)),
+            tag("File", "ImageWidth", TagValue::U16(6000)),
+            tag("File", "ImageHeight", TagValue::U16(4000)),
+        ];
+        let validation = validate(&tags);
+        assert_eq!(validation.len(), 1);
+        assert_eq!(get(&validation, "Validate"), Some("OK"));
+        assert_eq!(validation[0].group, VALIDATION_GROUP);
+    }
+
+    #[test]
+    fn test_edited_file_warnings() {
+        let mut thumb_width = tag("EXIF", "ImageWidth", TagValue::U16(160));
+        thumb_width.group1 = "IFD1".into();
+        let mut thumb_height = tag("EXIF", "ImageHeight", TagValue::U16(120));
+        thumb_height.group1 = "IFD1".into();
+        let tags = vec![
+            tag("EXIF", "Make", TagValue::string("NIKON CORPORATION")),
+            tag("EXIF", "DateTimeOriginal", "2023:05:01 10:00:00".into()),
+            tag("EXIF", "ModifyDate", "2022:12:31 23:59:59".into()),
+            tag("EXIF", "CreateDate", "2999:01:01 00:00:00".into()),
+            tag("XMP", "DateCreated", "yesterday".into()),
+            tag("EXIF", "Software", "Adobe Photoshop 25.0 (Windows)".into()),
+            tag(
+                "XMP",
+                "CreatorTool",
+                "Adobe Photoshop 25.0 (Windows)".into(),
+            ),
+            tag("EXIF", "ExifImageWidth", TagValue::U16(6000)),
+            tag("EXIF", "ExifImageHeight", TagValue::U16(4000)),
+            tag("File", "ImageWidth", TagValue::U16(800)),
+            tag("File", "ImageHeight", TagValue::U16(1200)),
+            thumb_width,
+            thumb_height,
+        ];
+        let validation = validate(&tags);
+        assert_eq!(
+            get(&validation, "DateOrder"),
+            Some(
+                "ModifyDate (2022:12:31 23:59:59) is earlier than DateTimeOriginal (2023:05:01 10:00:00)"
+            )
+        );
+        assert_eq!(
+            get(&validation, "InvalidDate"),
+            Some(
+                "CreateDate (2999:01:01 00:00:00) is in the future; DateCreated (yesterday) is not a valid date"
+            )
+        );
+        assert_eq!(
+            get(&validation, "MissingMakerNotes"),
+            Some("No maker notes for NIKON CORPORATION camera")
+        );
+        assert_eq!(
+            get(&validation, "ImageSizeMismatch"),
+            Some("ExifImageWidth/Height (6000x4000) differs from image size (800x1200)")
+        );
+        assert_eq!(
+            get(&validation, "ThumbnailSizeMismatch"),
+            Some("Thumbnail (160x120) and image (800x1200) orientations differ")
+        );
+        assert_eq!(
+            get(&validation, "EditingSoftware"),
+            Some("Adobe Photoshop 25.0 (Windows)")
+        );
+        assert_eq!(get(&validation, "Validate"), Some("6 Warnings"));
+    }
+
+    #[test]
+    fn test_date_parsing() {
+        assert!(parse_date_time("2023:05:01 10:00:00.123+02:00").is_some());
+        assert!(parse_date_time("2023-05-01T10:00:00Z").is_some());
+        assert!(parse_date_time("2023:13:01 10:00:00").is_none());
+        assert!(is_unset_date("0000:00:00 00:00:00"));
+        assert!(is_unset_date("    :  :     :  :  "));
+    }
+}
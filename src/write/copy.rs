@@ -0,0 +1,535 @@
+//! Copying tags between files, like ExifTool's `-tagsFromFile`
+//!
+//! [`copy_tags_from`] reads the source with the normal extraction pipeline
+//! and writes the selected tags to the destination, which is replaced
+//! safely (see [`crate::write::write_file`]). Selectors use ExifTool's
+//! argument syntax, with or without the leading `-`:
+//!
+//! | Selector                     | Copies                                           |
+//! |------------------------------|--------------------------------------------------|
+//! | `Keywords`, `IPTC:Keywords`  | the tag, to the tag of the same name             |
+//! | `IPTC:all`, `all`            | every tag of the group, or of the file           |
+//! | `IPTC:Keywords<XMP:Subject`  | XMP:Subject to IPTC:Keywords (or `SRC>DST`)      |
+//! | `XMP-dc:Creator<EXIF:Artist` | a tag remapped to another group                  |
+//! | `--Credit`, `--EXIF:all`     | nothing: excludes tags from the others           |
+//!
+//! No selectors means `all`. Values are copied as printed (PrintConv), list
+//! values item by item, except that a destination with a PrintConv of its
+//! own (IPTC:ObjectCycle, XMP:Orientation) gets the unconverted value. When
+//! a tag is selected more than once, the last selector wins.
+//!
+//! IPTC and XMP can be written so far, to JPEG files (see
+//! [`crate::write::iptc`] and [`crate::write::xmp`]); an XMP destination
+//! may name its namespace (`XMP-dc`). Tags explicitly copied to another
+//! group, or to a tag that can't be set, are reported in
+//! [`CopyReport::warnings`], as ExifTool does for tags that aren't writable;
+//! tags picked up by an `all` selector are skipped.
+//!
+//! ## ExifTool Reference
+//!
+//! lib/Image/ExifTool/Writer.pl SetNewValuesFromFile(): selector parsing,
+//! exclusions and precedence
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use tracing::debug;
+
+use crate::formats::extract_metadata;
+use crate::types::{Result, TagEntry, TagValue};
+use crate::write::iptc::{self, write_jpeg_iptc, IptcChanges};
+#[cfg(feature = "xmp")]
+use crate::write::xmp::{self, write_jpeg_xmp, XmpChanges};
+use crate::write::{write_bytes, WriteOptions};
+
+/// The groups that can be written so far, for warnings
+#[cfg(feature = "xmp")]
+const WRITABLE_GROUPS: &str = "IPTC and XMP are";
+#[cfg(not(feature = "xmp"))]
+const WRITABLE_GROUPS: &str = "IPTC is";
+
+/// A group [`copy_tags_from`] can write
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Target {
+    Iptc,
+    #[cfg(feature = "xmp")]
+    Xmp,
+}
+
+impl Target {
+    /// The writer of a destination group: IPTC, or XMP and its namespaces
+    fn for_group(group: &str) -> Option<Self> {
+        if group.eq_ignore_ascii_case("IPTC") {
+            return Some(Self::Iptc);
+        }
+        #[cfg(feature = "xmp")]
+        if group.eq_ignore_ascii_case("XMP")
+            || group
+                .get(..4)
+                .is_some_and(|g| g.eq_ignore_ascii_case("XMP-"))
+        {
+            return Some(Self::Xmp);
+        }
+        None
+    }
+
+    fn group(self) -> &'static str {
+        match self {
+            Self::Iptc => "IPTC",
+            #[cfg(feature = "xmp")]
+            Self::Xmp => "XMP",
+        }
+    }
+
+    fn is_writable(self, name: &str) -> bool {
+        match self {
+            Self::Iptc => iptc::is_writable(name),
+            #[cfg(feature = "xmp")]
+            Self::Xmp => xmp::is_writable(name),
+        }
+    }
+
+    fn has_print_conv(self, name: &str) -> bool {
+        match self {
+            Self::Iptc => iptc::has_print_conv(name),
+            #[cfg(feature = "xmp")]
+            Self::Xmp => xmp::has_print_conv(name),
+        }
+    }
+
+    /// `Group:Name`, unless `name` already carries its namespace
+    fn qualify(self, name: &str) -> String {
+        if name.contains(':') {
+            name.to_string()
+        } else {
+            format!("{}:{name}", self.group())
+        }
+    }
+}
+
+/// What [`copy_tags_from`] did
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CopyReport {
+    /// Destination tags written, as `Group:Name`
+    pub copied: Vec<String>,
+    /// Selected tags that couldn't be copied, and why
+    pub warnings: Vec<String>,
+}
+
+/// A tag or `all`, optionally group-qualified
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TagSpec {
+    group: Option<String>,
+    name: String,
+}
+
+impl TagSpec {
+    fn parse(spec: &str) -> Self {
+        match spec.rsplit_once(':') {
+            Some((group, name)) => Self {
+                group: Some(group.to_string()),
+                name: name.to_string(),
+            },
+            None => Self {
+                group: None,
+                name: spec.to_string(),
+            },
+        }
+    }
+
+    fn is_all(&self) -> bool {
+        self.name.eq_ignore_ascii_case("all")
+    }
+
+    /// Whether a source tag is selected; groups match family 0 or 1
+    fn matches(&self, entry: &TagEntry) -> bool {
+        let group_matches = self.group.as_deref().is_none_or(|group| {
+            group.eq_ignore_ascii_case(entry.group.as_str())
+                || group.eq_ignore_ascii_case(entry.group1.as_str())
+        });
+        group_matches && (self.is_all() || self.name.eq_ignore_ascii_case(entry.name.as_str()))
+    }
+}
+
+/// One parsed selector
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Selector {
+    Copy {
+        source: TagSpec,
+        dest: Option<TagSpec>,
+    },
+    Exclude(TagSpec),
+}
+
+impl Selector {
+    /// ExifTool: SetNewValuesFromFile() `-DSTTAG<SRCTAG`, `-SRCTAG>DSTTAG`, `--TAG`
+    fn parse(selector: &str) -> Self {
+        if let Some(excluded) = selector.strip_prefix("--") {
+            return Self::Exclude(TagSpec::parse(excluded));
+        }
+        let selector = selector.strip_prefix('-').unwrap_or(selector);
+        let (source, dest) = if let Some((dest, source)) = selector.split_once('<') {
+            (source, Some(dest))
+        } else if let Some((source, dest)) = selector.split_once('>') {
+            (source, Some(dest))
+        } else {
+            (selector, None)
+        };
+        Self::Copy {
+            source: TagSpec::parse(source.trim()),
+            dest: dest.map(|dest| TagSpec::parse(dest.trim())),
+        }
+    }
+}
+
+/// Values to write, one per list item
+fn values(value: &TagValue) -> Vec<String> {
+    match value {
+        TagValue::Array(items) => items.iter().map(ToString::to_string).collect(),
+        value => vec![value.to_string()],
+    }
+}
+
+/// Copy tags selected by `selectors` from `source` to `dest`
+///
+/// `dest` is only rewritten when there is something to copy. Errors come
+/// from reading `source` or writing `dest`; tags that can't be copied are
+/// listed in the report's warnings instead.
+pub fn copy_tags_from(
+    source: &Path,
+    dest: &Path,
+    selectors: &[&str],
+    options: &WriteOptions,
+) -> Result<CopyReport> {
+    let metadata = extract_metadata(source, false, false, None)?;
+    let selectors: Vec<Selector> = if selectors.is_empty() {
+        vec![Selector::parse("all")]
+    } else {
+        selectors.iter().map(|s| Selector::parse(s)).collect()
+    };
+
+    let mut report = CopyReport::default();
+    let changes = plan_changes(&metadata.tags, &selectors, &mut report);
+    if changes.is_empty() {
+        debug!("Nothing to copy from {}", source.display());
+        return Ok(report);
+    }
+
+    let mut iptc = IptcChanges::new();
+    #[cfg(feature = "xmp")]
+    let mut xmp = XmpChanges::new();
+    let mut targets = Vec::new();
+    for ((target, name), values) in changes {
+        let result = match target {
+            Target::Iptc => iptc.set(&name, values),
+            #[cfg(feature = "xmp")]
+            Target::Xmp => xmp.set(&name, values),
+        };
+        match result {
+            Ok(()) => {
+                report.copied.push(target.qualify(&name));
+                targets.push(target);
+            }
+            Err(e) => report.warnings.push(e.to_string()),
+        }
+    }
+    if targets.is_empty() {
+        return Ok(report);
+    }
+
+    // One rewrite of `dest` for every group, so a backup keeps the original
+    let mut jpeg = std::fs::read(dest)?;
+    if targets.contains(&Target::Iptc) {
+        jpeg = write_jpeg_iptc(&jpeg, &iptc)?;
+    }
+    #[cfg(feature = "xmp")]
+    if targets.contains(&Target::Xmp) {
+        jpeg = write_jpeg_xmp(&jpeg, &xmp)?;
+    }
+    write_bytes(dest, &jpeg, options)?;
+    Ok(report)
+}
+
+/// The values to write, by destination group and tag name
+///
+/// XMP names keep a namespace the destination gave (`XMP-dc:Creator`).
+fn plan_changes(
+    tags: &[TagEntry],
+    selectors: &[Selector],
+    report: &mut CopyReport,
+) -> BTreeMap<(Target, String), Vec<String>> {
+    let excluded = |entry: &TagEntry| {
+        selectors
+            .iter()
+            .any(|s| matches!(s, Selector::Exclude(spec) if spec.matches(entry)))
+    };
+
+    let mut changes: BTreeMap<(Target, String), Vec<String>> = BTreeMap::new();
+    for selector in selectors {
+        let Selector::Copy { source, dest } = selector else {
+            continue;
+        };
+        let mut found = false;
+        for entry in tags.iter().filter(|e| source.matches(e) && !excluded(e)) {
+            found = true;
+            let dest_group = dest
+                .as_ref()
+                .and_then(|d| d.group.as_deref())
+                .unwrap_or(entry.group.as_str());
+            let dest_name = match dest {
+                Some(dest) if !dest.is_all() => dest.name.as_str(),
+                _ => entry.name.as_str(),
+            };
+            // Like ExifTool, `all` quietly skips what can't be written
+            let explicit = !source.is_all() || dest.as_ref().is_some_and(|d| !d.is_all());
+            let Some(target) = Target::for_group(dest_group) else {
+                if explicit {
+                    report.warnings.push(format!(
+                        "{dest_group}:{dest_name} isn't writable yet (only {WRITABLE_GROUPS})"
+                    ));
+                }
+                continue;
+            };
+            let dest_name = if dest_group.contains('-') {
+                format!("{dest_group}:{dest_name}")
+            } else {
+                dest_name.to_string()
+            };
+            if !target.is_writable(&dest_name) {
+                if explicit {
+                    report
+                        .warnings
+                        .push(format!("{} isn't writable", target.qualify(&dest_name)));
+                }
+                continue;
+            }
+            // There's no PrintConvInv yet, so a destination with a PrintConv
+            // takes the value before conversion
+            let value = if target.has_print_conv(&dest_name) {
+                &entry.value
+            } else {
+                entry.print()
+            };
+            changes.insert((target, dest_name), values(value));
+        }
+        if !found && !source.is_all() {
+            report
+                .warnings
+                .push(format!("{} not found in the source file", source.name));
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::InternedStr;
+
+    fn entry(group: &str, group1: &str, name: &str, print: TagValue) -> TagEntry {
+        TagEntry {
+            group: InternedStr::from(group),
+            group1: InternedStr::from(group1),
+            name: InternedStr::from(name),
+            value: print.clone(),
+            print: print.into(),
+            source: None,
+        }
+    }
+
+    fn source_tags() -> Vec<TagEntry> {
+        vec![
+            entry(
+                "IPTC",
+                "IPTC",
+                "Keywords",
+                TagValue::Array(vec![TagValue::string("a"), TagValue::string("b")]),
+            ),
+            entry("IPTC", "IPTC", "Credit", TagValue::string("Agency")),
+            entry("EXIF", "IFD0", "Artist", TagValue::string("Jo Smith")),
+            entry(
+                "EXIF",
+                "ExifIFD",
+                "DateTimeOriginal",
+                TagValue::string("2024:05:01 12:30:00"),
+            ),
+        ]
+    }
+
+    /// Planned values by `Group:Name`
+    fn plan_for(
+        tags: &[TagEntry],
+        selectors: &[&str],
+    ) -> (BTreeMap<String, Vec<String>>, CopyReport) {
+        let selectors: Vec<_> = selectors.iter().map(|s| Selector::parse(s)).collect();
+        let mut report = CopyReport::default();
+        let changes = plan_changes(tags, &selectors, &mut report)
+            .into_iter()
+            .map(|((target, name), values)| (target.qualify(&name), values))
+            .collect();
+        (changes, report)
+    }
+
+    fn plan(selectors: &[&str]) -> (BTreeMap<String, Vec<String>>, CopyReport) {
+        plan_for(&source_tags(), selectors)
+    }
+
+    #[test]
+    fn test_parse_selectors() {
+        assert_eq!(
+            Selector::parse("-IPTC:By-line<EXIF:Artist"),
+            Selector::Copy {
+                source: TagSpec::parse("EXIF:Artist"),
+                dest: Some(TagSpec::parse("IPTC:By-line")),
+            }
+        );
+        assert_eq!(
+            Selector::parse("EXIF:Artist>IPTC:By-line"),
+            Selector::parse("IPTC:By-line<EXIF:Artist")
+        );
+        assert_eq!(
+            Selector::parse("--Credit"),
+            Selector::Exclude(TagSpec::parse("Credit"))
+        );
+    }
+
+    #[test]
+    fn test_all_with_exclusion() {
+        let (changes, report) = plan(&["all", "--Credit"]);
+        assert_eq!(changes.keys().collect::<Vec<_>>(), ["IPTC:Keywords"]);
+        assert_eq!(changes["IPTC:Keywords"], ["a", "b"]);
+        // EXIF tags are skipped quietly
+        assert!(report.warnings.is_empty());
+    }
+
+    #[cfg(feature = "xmp")]
+    #[test]
+    fn test_group_remapping() {
+        let (changes, report) = plan(&[
+            "IPTC:By-line<EXIF:Artist",
+            "IPTC:DateCreated<DateTimeOriginal",
+            "XMP-dc:Creator<EXIF:Artist",
+            "XMP:DateCreated<DateTimeOriginal",
+            "XMP:Subject<IPTC:Keywords",
+            "EXIF:Copyright<EXIF:Artist",
+            "XMP:NoSuchTag<Credit",
+            "Missing",
+        ]);
+        assert_eq!(changes["IPTC:By-line"], ["Jo Smith"]);
+        // IptcChanges keeps the date part
+        assert_eq!(changes["IPTC:DateCreated"], ["2024:05:01 12:30:00"]);
+        assert_eq!(changes["XMP-dc:Creator"], ["Jo Smith"]);
+        assert_eq!(changes["XMP:DateCreated"], ["2024:05:01 12:30:00"]);
+        assert_eq!(changes["XMP:Subject"], ["a", "b"]);
+        assert_eq!(
+            report.warnings,
+            [
+                "EXIF:Copyright isn't writable yet (only IPTC and XMP are)",
+                "XMP:NoSuchTag isn't writable",
+                "Missing not found in the source file"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unwritable_and_converted_tags() {
+        let mut object_cycle = entry("IPTC", "IPTC", "ObjectCycle", TagValue::string("Morning"));
+        object_cycle.value = TagValue::string("a");
+        let tags = vec![
+            entry("IPTC", "IPTC", "ApplicationRecordVersion", TagValue::U16(4)),
+            object_cycle,
+        ];
+
+        let (changes, report) = plan_for(&tags, &["IPTC:all"]);
+        assert_eq!(changes.keys().collect::<Vec<_>>(), ["IPTC:ObjectCycle"]);
+        assert_eq!(changes["IPTC:ObjectCycle"], ["a"]);
+        assert!(report.warnings.is_empty());
+
+        let (changes, report) = plan_for(&tags, &["ApplicationRecordVersion"]);
+        assert!(changes.is_empty());
+        assert_eq!(
+            report.warnings,
+            ["IPTC:ApplicationRecordVersion isn't writable"]
+        );
+    }
+
+    #[test]
+    fn test_copy_between_jpegs() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.jpg");
+        let dest = dir.path().join("dest.jpg");
+        let jpeg = [
+            &[0xff, 0xd8][..],
+            &[0xff, 0xdb, 0, 3, 0],
+            &[0xff, 0xda, 0, 2, 0x12, 0x34, 0xff, 0xd9],
+        ]
+        .concat();
+        let mut changes = IptcChanges::new();
+        changes.set("Keywords", ["harbour", "storm"]).unwrap();
+        changes.set("Credit", ["Agency"]).unwrap();
+        let tagged = crate::write::iptc::write_jpeg_iptc(&jpeg, &changes).unwrap();
+        std::fs::write(&source, tagged).unwrap();
+        std::fs::write(&dest, &jpeg).unwrap();
+
+        let report = copy_tags_from(
+            &source,
+            &dest,
+            &["-IPTC:all", "--Credit"],
+            &WriteOptions::new(),
+        )
+        .unwrap();
+        assert_eq!(report.copied, ["IPTC:Keywords"]);
+        // ApplicationRecordVersion is left to the writer, without a warning
+        assert!(report.warnings.is_empty());
+
+        let copied = extract_metadata(&dest, false, false, None).unwrap();
+        let keywords = copied
+            .tags
+            .iter()
+            .find(|t| t.group.as_str() == "IPTC" && t.name.as_str() == "Keywords")
+            .map(|t| values(t.print()));
+        assert_eq!(keywords, Some(vec!["harbour".into(), "storm".into()]));
+        assert!(!copied.tags.iter().any(|t| t.name.as_str() == "Credit"));
+    }
+
+    #[cfg(feature = "xmp")]
+    #[test]
+    fn test_copy_to_xmp() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.jpg");
+        let dest = dir.path().join("dest.jpg");
+        let jpeg = [
+            &[0xff, 0xd8][..],
+            &[0xff, 0xdb, 0, 3, 0],
+            &[0xff, 0xda, 0, 2, 0x12, 0x34, 0xff, 0xd9],
+        ]
+        .concat();
+        let mut changes = IptcChanges::new();
+        changes.set("Keywords", ["harbour", "storm"]).unwrap();
+        changes.set("Credit", ["Agency"]).unwrap();
+        let tagged = write_jpeg_iptc(&jpeg, &changes).unwrap();
+        std::fs::write(&source, tagged).unwrap();
+        std::fs::write(&dest, &jpeg).unwrap();
+
+        let report = copy_tags_from(
+            &source,
+            &dest,
+            &["-XMP:Subject<IPTC:Keywords", "-XMP-photoshop:Credit<Credit"],
+            &WriteOptions::new(),
+        )
+        .unwrap();
+        assert_eq!(report.copied, ["XMP:Subject", "XMP-photoshop:Credit"]);
+        assert!(report.warnings.is_empty());
+
+        let copied = extract_metadata(&dest, false, false, None).unwrap();
+        let xmp = |name: &str| {
+            copied
+                .tags
+                .iter()
+                .find(|t| t.group.as_str() == "XMP" && t.name.as_str() == name)
+                .map(|t| values(t.print()))
+        };
+        assert_eq!(xmp("Subject"), Some(vec!["harbour".into(), "storm".into()]));
+        assert_eq!(xmp("Credit"), Some(vec!["Agency".into()]));
+    }
+}
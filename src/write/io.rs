@@ -0,0 +1,240 @@
+//! Safe file replacement for writers
+//!
+//! Writers never modify a file in place. [`write_file`] has them write the
+//! new file to `<file>_exiftool_tmp` in the same directory, fsyncs it, and
+//! renames it over the original, so a crash or failed write leaves either
+//! the old file or the new one, never a mix:
+//!
+//! ```no_run
+//! use exif_oxide::write::{write_file, WriteOptions};
+//! use std::io::Write;
+//!
+//! let options = WriteOptions::new().with_backup(true).with_preserved_mtime(true);
+//! write_file(std::path::Path::new("photo.jpg"), &options, |file| {
+//!     file.write_all(b"...")?;
+//!     Ok(())
+//! })
+//! .unwrap();
+//! ```
+//!
+//! ## ExifTool Reference
+//!
+//! lib/Image/ExifTool.pm WriteInfo(): the `_exiftool_tmp` file, the
+//! `_original` backup (not replaced if one already exists), and `-P`
+//! (`PreserveTime`).
+
+use std::ffi::OsString;
+use std::fs::{self, File, FileTimes, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+use crate::types::{ExifError, Result};
+
+/// Suffix of the file being written
+/// ExifTool: WriteInfo() `"${file}_exiftool_tmp"`
+pub const TEMP_SUFFIX: &str = "_exiftool_tmp";
+
+/// Suffix of the backup of the original file
+/// ExifTool: WriteInfo() `"${file}_original"`
+pub const BACKUP_SUFFIX: &str = "_original";
+
+/// How [`write_file`] replaces a file
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WriteOptions {
+    backup: bool,
+    preserve_mtime: bool,
+}
+
+impl WriteOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep the original as `<file>_original` (default off, like
+    /// `-overwrite_original`). An existing backup is left alone, so it keeps
+    /// the first original.
+    pub fn with_backup(mut self, backup: bool) -> Self {
+        self.backup = backup;
+        self
+    }
+
+    /// Give the new file the original's modification and access times
+    /// (ExifTool `-P`)
+    pub fn with_preserved_mtime(mut self, preserve: bool) -> Self {
+        self.preserve_mtime = preserve;
+        self
+    }
+}
+
+/// `<path><suffix>`, e.g. `photo.jpg_original`
+pub fn suffixed_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Replace `path` with what `write` writes, atomically
+///
+/// `write` gets the new, empty temp file; it can seek, e.g. to patch
+/// offsets. If it fails, the temp file is removed and `path` is untouched.
+/// `path` needn't exist yet, in which case there's nothing to back up or
+/// preserve.
+pub fn write_file<F>(path: &Path, options: &WriteOptions, write: F) -> Result<()>
+where
+    F: FnOnce(&mut File) -> Result<()>,
+{
+    let original = match fs::metadata(path) {
+        Ok(metadata) => Some(metadata),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e.into()),
+    };
+
+    let temp_path = suffixed_path(path, TEMP_SUFFIX);
+    let mut temp = match OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&temp_path)
+    {
+        Ok(file) => file,
+        // Another writer may be using it; ExifTool refuses too
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            return Err(ExifError::IoError(format!(
+                "Temporary file already exists: {}",
+                temp_path.display()
+            )))
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let result = write(&mut temp)
+        .and_then(|()| finish_temp(&temp, original.as_ref(), options))
+        .and_then(|()| {
+            drop(temp);
+            replace(path, &temp_path, original.is_some() && options.backup)
+        });
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// Replace `path` with `data`; see [`write_file`]
+pub fn write_bytes(path: &Path, data: &[u8], options: &WriteOptions) -> Result<()> {
+    write_file(path, options, |file| {
+        io::Write::write_all(file, data)?;
+        Ok(())
+    })
+}
+
+/// Copy the original's permissions (and times, if asked) and flush to disk
+fn finish_temp(temp: &File, original: Option<&fs::Metadata>, options: &WriteOptions) -> Result<()> {
+    if let Some(original) = original {
+        temp.set_permissions(original.permissions())?;
+        if options.preserve_mtime {
+            let mut times = FileTimes::new().set_modified(original.modified()?);
+            if let Ok(accessed) = original.accessed() {
+                times = times.set_accessed(accessed);
+            }
+            temp.set_times(times)?;
+        }
+    }
+    temp.sync_all()?;
+    Ok(())
+}
+
+/// Move the finished temp file over `path`, backing `path` up first
+fn replace(path: &Path, temp_path: &Path, backup: bool) -> Result<()> {
+    if backup {
+        let backup_path = suffixed_path(path, BACKUP_SUFFIX);
+        if backup_path.exists() {
+            debug!("Keeping existing backup {}", backup_path.display());
+        } else if fs::hard_link(path, &backup_path).is_err() {
+            // No hard links on this filesystem: move the original aside,
+            // as ExifTool does, at the cost of a moment without `path`
+            fs::rename(path, &backup_path)?;
+        }
+    }
+
+    fs::rename(temp_path, path)?;
+    sync_parent_dir(path)
+}
+
+/// Make the rename itself durable
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) -> Result<()> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    File::open(dir)?.sync_all()?;
+    Ok(())
+}
+
+/// Directories can't be opened for syncing here; the rename is still atomic
+#[cfg(not(unix))]
+fn sync_parent_dir(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Seek, SeekFrom, Write};
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_write_file_replaces_atomically() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("photo.jpg");
+        fs::write(&path, b"original").unwrap();
+        let mtime = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(mtime)
+            .unwrap();
+
+        let options = WriteOptions::new()
+            .with_backup(true)
+            .with_preserved_mtime(true);
+        write_file(&path, &options, |file| {
+            file.write_all(b"new ????")?;
+            file.seek(SeekFrom::Start(4))?;
+            file.write_all(b"file")?;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"new file");
+        assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), mtime);
+        let backup = suffixed_path(&path, BACKUP_SUFFIX);
+        assert_eq!(fs::read(&backup).unwrap(), b"original");
+
+        // A second write keeps the first backup
+        write_bytes(&path, b"newer", &options).unwrap();
+        assert_eq!(fs::read(&backup).unwrap(), b"original");
+
+        // A failed write leaves the file and no temp file behind
+        let result = write_file(&path, &WriteOptions::new(), |file| {
+            file.write_all(b"partial")?;
+            Err(ExifError::FormatError("bad segment".to_string()))
+        });
+        assert!(result.is_err());
+        assert_eq!(fs::read(&path).unwrap(), b"newer");
+        assert!(!suffixed_path(&path, TEMP_SUFFIX).exists());
+    }
+
+    #[test]
+    fn test_write_file_refuses_leftover_temp_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("new.jpg");
+        fs::write(suffixed_path(&path, TEMP_SUFFIX), b"").unwrap();
+        assert!(write_bytes(&path, b"data", &WriteOptions::new()).is_err());
+
+        fs::remove_file(suffixed_path(&path, TEMP_SUFFIX)).unwrap();
+        write_bytes(&path, b"data", &WriteOptions::new().with_backup(true)).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"data");
+        assert!(!suffixed_path(&path, BACKUP_SUFFIX).exists());
+    }
+}
@@ -0,0 +1,716 @@
+//! IPTC IIM editing in JPEG APP13
+//!
+//! JPEG files carry IPTC IIM as Photoshop image resource 0x0404, inside an
+//! APP13 segment that starts with `Photoshop 3.0\0`. [`write_jpeg_iptc`]
+//! rewrites the ApplicationRecord (record 2) datasets named in
+//! [`IptcChanges`] and rebuilds everything around them:
+//!
+//! - the IIM stream, with the 2:00 ApplicationRecordVersion first and the
+//!   other record 2 datasets in dataset order. Unchanged datasets, the
+//!   EnvelopeRecord and later records are kept as they were.
+//! - the IPTC resource, with the resource size and even padding updated,
+//!   and IPTCDigest (0x0425), if present, set to the MD5 of the new IIM.
+//!   Other resources are kept byte for byte.
+//! - the APP13 segment. It is created after the APP0-APP12 segments if the
+//!   file has none, and Photoshop data spread over several APP13 segments
+//!   is merged into the first.
+//!
+//! IIM strings have no encoding of their own. Values with non-ASCII text
+//! are written as UTF-8, adding the 1:90 CodedCharacterSet `ESC % G` if
+//! it's missing. In that case any existing non-ASCII record 2 text, which
+//! was Latin-1, is converted too.
+//!
+//! ```no_run
+//! use exif_oxide::write::iptc::{update_jpeg_iptc_file, IptcChanges};
+//! use exif_oxide::write::WriteOptions;
+//!
+//! let mut changes = IptcChanges::new();
+//! changes.set("Keywords", ["harbour", "storm"]).unwrap();
+//! changes.set("Caption-Abstract", ["Waves over the breakwater"]).unwrap();
+//! changes.delete("Credit").unwrap();
+//! update_jpeg_iptc_file(std::path::Path::new("photo.jpg"), &changes, &WriteOptions::new())
+//!     .unwrap();
+//! ```
+//!
+//! ## ExifTool Reference
+//!
+//! - lib/Image/ExifTool/WriteIPTC.pl WriteIPTC(): dataset order, the
+//!   ApplicationRecordVersion and CodedCharacterSet handling
+//! - lib/Image/ExifTool/WritePhotoshop.pl WritePhotoshop(): resource
+//!   padding and the IPTCDigest update
+//! - lib/Image/ExifTool/WriteExif.pl WriteJPEG: where a new APP13 goes
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use md5::{Digest, Md5};
+use tracing::debug;
+
+use crate::formats::RESOURCE_SIGNATURES;
+use crate::generated::IPTC_pm::application_record_tags::{
+    IPTC_APPLICATIONRECORD_LIST_TAGS, IPTC_APPLICATIONRECORD_TAGS,
+};
+use crate::types::{ExifError, Result};
+use crate::write::jpeg::{split_segments, write_segment, MAX_SEGMENT_DATA};
+use crate::write::{write_bytes, WriteOptions};
+
+/// APP13 identifier of Photoshop image resources
+const PHOTOSHOP_SIGNATURE: &[u8] = b"Photoshop 3.0\0";
+
+/// Photoshop resource ID of the IPTC IIM stream
+const IPTC_RESOURCE: u16 = 0x0404;
+
+/// Photoshop resource ID of the MD5 of the IPTC IIM stream
+const IPTC_DIGEST_RESOURCE: u16 = 0x0425;
+
+/// CodedCharacterSet value for UTF-8
+/// ExifTool: IPTC.pm %iptcCharset `"\x1b%G" => 'UTF8'`
+const UTF8_CHARSET: &[u8] = b"\x1b%G";
+
+/// ApplicationRecordVersion written when the record has none
+/// ExifTool: WriteIPTC.pl, version 4 of the IIM
+const RECORD_VERSION: [u8; 2] = [0, 4];
+
+/// New values for ApplicationRecord datasets
+#[derive(Debug, Clone, Default)]
+pub struct IptcChanges {
+    /// Encoded values by dataset number; no values deletes the dataset
+    datasets: BTreeMap<u8, Vec<String>>,
+}
+
+impl IptcChanges {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace every value of the ApplicationRecord tag `name`
+    ///
+    /// Names are ExifTool's (`Keywords`, `Caption-Abstract`, `Credit`),
+    /// matched case-insensitively. Only list tags like Keywords take more
+    /// than one value. Values must fit the tag's length limits; `digits`
+    /// tags such as DateCreated keep only their digits, so `2024:05:01`
+    /// and `2024:05:01 12:30:00` are written as `20240501`.
+    pub fn set<I, S>(&mut self, name: &str, values: I) -> Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let (dataset, format) = lookup(name)?;
+        let values = values
+            .into_iter()
+            .map(|value| check_value(name, format, value.into()))
+            .collect::<Result<Vec<_>>>()?;
+        if values.len() > 1 && !IPTC_APPLICATIONRECORD_LIST_TAGS.contains(&u16::from(dataset)) {
+            return Err(ExifError::InvalidFormat(format!(
+                "IPTC:{name} takes a single value"
+            )));
+        }
+        self.datasets.insert(dataset, values);
+        Ok(())
+    }
+
+    /// Remove every value of the ApplicationRecord tag `name`
+    pub fn delete(&mut self, name: &str) -> Result<()> {
+        let (dataset, _) = lookup(name)?;
+        self.datasets.insert(dataset, Vec::new());
+        Ok(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.datasets.is_empty()
+    }
+
+    fn has_non_ascii(&self) -> bool {
+        self.datasets
+            .values()
+            .flatten()
+            .any(|value| !value.is_ascii())
+    }
+}
+
+/// The dataset number and format of an ApplicationRecord tag
+fn lookup(name: &str) -> Result<(u8, &'static str)> {
+    IPTC_APPLICATIONRECORD_TAGS
+        .iter()
+        .find(|(_, tag)| tag.name.eq_ignore_ascii_case(name))
+        .and_then(|(&id, tag)| Some((u8::try_from(id).ok()?, tag.format)))
+        .ok_or_else(|| ExifError::InvalidFormat(format!("Unknown IPTC tag {name}")))
+}
+
+/// Whether [`IptcChanges::set`] can write the ApplicationRecord tag `name`
+///
+/// Numeric and binary datasets can't be set: ApplicationRecordVersion is
+/// managed by the writer, ObjectPreviewData isn't text.
+pub fn is_writable(name: &str) -> bool {
+    lookup(name)
+        .is_ok_and(|(_, format)| format.starts_with("string") || format.starts_with("digits"))
+}
+
+/// Whether the ApplicationRecord tag `name` has a PrintConv, so values
+/// must be written unconverted (ObjectCycle "a", not "Morning")
+pub fn has_print_conv(name: &str) -> bool {
+    IPTC_APPLICATIONRECORD_TAGS
+        .values()
+        .any(|tag| tag.name.eq_ignore_ascii_case(name) && tag.print_conv.is_some())
+}
+
+/// Check a value against a format like `string[0,64]` or `digits[8]`
+/// ExifTool: WriteIPTC.pl CheckIPTC()
+fn check_value(name: &str, format: &str, value: String) -> Result<String> {
+    let invalid = |message: String| ExifError::InvalidFormat(format!("IPTC:{name} {message}"));
+    let (kind, limits) = format
+        .strip_suffix(']')
+        .and_then(|format| format.split_once('['))
+        .ok_or_else(|| invalid(format!("has unsupported format {format}")))?;
+    let (min, max) = match limits.split_once(',') {
+        Some((min, max)) => (min.parse().ok(), max.parse().ok()),
+        None => (limits.parse().ok(), limits.parse().ok()),
+    };
+    let (Some(min), Some(max)) = (min, max) else {
+        return Err(invalid(format!("has unsupported format {format}")));
+    };
+
+    let value = match kind {
+        "string" => value,
+        // A date/time keeps the date
+        "digits" => value
+            .split(' ')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect(),
+        _ => return Err(invalid(format!("has unsupported format {format}"))),
+    };
+    let len = value.len();
+    if len < min || len > max {
+        return Err(invalid(format!("must be {min} to {max} bytes, not {len}")));
+    }
+    Ok(value)
+}
+
+/// One IIM dataset
+#[derive(Debug, Clone)]
+struct Dataset {
+    record: u8,
+    number: u8,
+    data: Vec<u8>,
+}
+
+/// Split an IIM stream into datasets, stopping at the first byte that
+/// isn't a dataset tag marker (trailing padding)
+/// ExifTool: IPTC.pm ProcessIPTC()
+fn parse_datasets(data: &[u8]) -> Result<Vec<Dataset>> {
+    let truncated = || ExifError::InvalidFormat("Truncated IPTC dataset".to_string());
+    let mut datasets = Vec::new();
+    let mut pos = 0;
+    while data.get(pos) == Some(&0x1c) {
+        let header = data.get(pos + 1..pos + 5).ok_or_else(truncated)?;
+        let (record, number) = (header[0], header[1]);
+        let mut len = u16::from_be_bytes([header[2], header[3]]) as usize;
+        pos += 5;
+        if len & 0x8000 != 0 {
+            // Extended dataset: the low bits give the size of the length
+            let size = len & 0x7fff;
+            let bytes = data.get(pos..pos + size).ok_or_else(truncated)?;
+            if size > 4 {
+                return Err(ExifError::InvalidFormat(
+                    "IPTC dataset length too large".to_string(),
+                ));
+            }
+            len = bytes.iter().fold(0, |len, &b| (len << 8) | b as usize);
+            pos += size;
+        }
+        let value = data.get(pos..pos + len).ok_or_else(truncated)?;
+        datasets.push(Dataset {
+            record,
+            number,
+            data: value.to_vec(),
+        });
+        pos += len;
+    }
+    Ok(datasets)
+}
+
+fn write_dataset(out: &mut Vec<u8>, dataset: &Dataset) {
+    out.extend([0x1c, dataset.record, dataset.number]);
+    let len = dataset.data.len();
+    if len < 0x8000 {
+        out.extend((len as u16).to_be_bytes());
+    } else {
+        out.extend(0x8004u16.to_be_bytes());
+        out.extend((len as u32).to_be_bytes());
+    }
+    out.extend(&dataset.data);
+}
+
+/// Whether an ApplicationRecord dataset holds text, which has a character set
+/// (ObjectPreviewData and the other `undef` datasets don't)
+fn is_text_dataset(number: u8) -> bool {
+    IPTC_APPLICATIONRECORD_TAGS
+        .get(&u16::from(number))
+        .is_some_and(|tag| tag.format.starts_with("string"))
+}
+
+/// Latin-1 text as UTF-8
+fn latin1_to_utf8(data: &[u8]) -> Vec<u8> {
+    data.iter()
+        .map(|&b| b as char)
+        .collect::<String>()
+        .into_bytes()
+}
+
+/// Apply `changes` to an IIM stream
+fn edit_iptc(iptc: &[u8], changes: &IptcChanges) -> Result<Vec<u8>> {
+    let mut datasets = parse_datasets(iptc)?;
+
+    let charset = datasets
+        .iter()
+        .find(|d| (d.record, d.number) == (1, 90))
+        .map(|d| d.data.clone());
+    if changes.has_non_ascii() {
+        match charset.as_deref() {
+            Some(UTF8_CHARSET) => {}
+            Some(_) => {
+                return Err(ExifError::Unsupported(
+                    "Writing non-ASCII IPTC with a CodedCharacterSet other than UTF-8".to_string(),
+                ))
+            }
+            None => {
+                let text = datasets
+                    .iter_mut()
+                    .filter(|d| d.record == 2 && is_text_dataset(d.number));
+                for dataset in text {
+                    dataset.data = latin1_to_utf8(&dataset.data);
+                }
+                datasets.push(Dataset {
+                    record: 1,
+                    number: 90,
+                    data: UTF8_CHARSET.to_vec(),
+                });
+            }
+        }
+    }
+
+    let mut application: Vec<Dataset> = datasets
+        .iter()
+        .filter(|d| d.record == 2 && !changes.datasets.contains_key(&d.number))
+        .cloned()
+        .collect();
+    for (&number, values) in &changes.datasets {
+        application.extend(values.iter().map(|value| Dataset {
+            record: 2,
+            number,
+            data: value.as_bytes().to_vec(),
+        }));
+    }
+    if !application.iter().any(|d| d.number == 0) {
+        application.push(Dataset {
+            record: 2,
+            number: 0,
+            data: RECORD_VERSION.to_vec(),
+        });
+    }
+    // Stable, so repeated values keep their order
+    application.sort_by_key(|d| d.number);
+
+    let mut envelope: Vec<&Dataset> = datasets.iter().filter(|d| d.record == 1).collect();
+    envelope.sort_by_key(|d| d.number);
+
+    let mut out = Vec::with_capacity(iptc.len());
+    for dataset in envelope {
+        write_dataset(&mut out, dataset);
+    }
+    for dataset in &application {
+        write_dataset(&mut out, dataset);
+    }
+    for dataset in datasets.iter().filter(|d| d.record > 2) {
+        write_dataset(&mut out, dataset);
+    }
+    Ok(out)
+}
+
+/// One Photoshop image resource
+#[derive(Debug)]
+struct Resource {
+    /// Block signature, written back unchanged
+    signature: [u8; 4],
+    id: u16,
+    /// Pascal string, including its length byte and padding
+    name: Vec<u8>,
+    data: Vec<u8>,
+}
+
+/// Split Photoshop image resources, stopping at anything that isn't one;
+/// the bytes from there on are returned to be copied verbatim
+/// ExifTool: Photoshop.pm ProcessPhotoshop()
+fn parse_resources(data: &[u8]) -> Result<(Vec<Resource>, &[u8])> {
+    let truncated = || ExifError::InvalidFormat("Truncated Photoshop resource".to_string());
+    let mut resources = Vec::new();
+    let mut pos = 0;
+    while let Some(&signature) = data
+        .get(pos..pos + 4)
+        .and_then(|sig| RESOURCE_SIGNATURES.iter().find(|&&known| known == sig))
+    {
+        let id_bytes = data.get(pos + 4..pos + 6).ok_or_else(truncated)?;
+        let id = u16::from_be_bytes([id_bytes[0], id_bytes[1]]);
+        pos += 6;
+        // Pascal string padded to an even length, length byte included
+        let name_len = (*data.get(pos).ok_or_else(truncated)? as usize + 2) & !1;
+        let name = data
+            .get(pos..pos + name_len)
+            .ok_or_else(truncated)?
+            .to_vec();
+        pos += name_len;
+        let size_bytes = data.get(pos..pos + 4).ok_or_else(truncated)?;
+        let size = u32::from_be_bytes(size_bytes.try_into().unwrap()) as usize;
+        pos += 4;
+        let value = data.get(pos..pos + size).ok_or_else(truncated)?.to_vec();
+        pos += (size + 1) & !1;
+        resources.push(Resource {
+            signature: *signature,
+            id,
+            name,
+            data: value,
+        });
+    }
+    Ok((resources, data.get(pos..).unwrap_or_default()))
+}
+
+impl Resource {
+    /// Whether this is the Photoshop (8BIM) resource with this ID
+    fn is_8bim(&self, id: u16) -> bool {
+        &self.signature == b"8BIM" && self.id == id
+    }
+}
+
+fn write_resources(resources: &[Resource]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for resource in resources {
+        out.extend(resource.signature);
+        out.extend(resource.id.to_be_bytes());
+        out.extend(&resource.name);
+        out.extend((resource.data.len() as u32).to_be_bytes());
+        out.extend(&resource.data);
+        if resource.data.len() % 2 == 1 {
+            out.push(0);
+        }
+    }
+    out
+}
+
+/// Apply `changes` to the Photoshop resources of an APP13 segment
+fn edit_photoshop(photoshop: &[u8], changes: &IptcChanges) -> Result<Vec<u8>> {
+    let (mut resources, trailing) = parse_resources(photoshop)?;
+    let iptc = match resources.iter().position(|r| r.is_8bim(IPTC_RESOURCE)) {
+        Some(index) => {
+            let iptc = edit_iptc(&resources[index].data, changes)?;
+            resources[index].data = iptc.clone();
+            iptc
+        }
+        None => {
+            let iptc = edit_iptc(&[], changes)?;
+            resources.push(Resource {
+                signature: *b"8BIM",
+                id: IPTC_RESOURCE,
+                name: vec![0, 0],
+                data: iptc.clone(),
+            });
+            iptc
+        }
+    };
+
+    if let Some(digest) = resources
+        .iter_mut()
+        .find(|r| r.is_8bim(IPTC_DIGEST_RESOURCE))
+    {
+        digest.data = Md5::digest(&iptc).to_vec();
+    }
+    let mut out = write_resources(&resources);
+    out.extend(trailing);
+    Ok(out)
+}
+
+/// Apply `changes` to the IPTC of a JPEG file, returning the new file
+pub fn write_jpeg_iptc(jpeg: &[u8], changes: &IptcChanges) -> Result<Vec<u8>> {
+    if changes.is_empty() {
+        return Ok(jpeg.to_vec());
+    }
+    let (segments, scan) = split_segments(jpeg)?;
+
+    // Photoshop resources may continue in later APP13 segments
+    let photoshop: Vec<u8> = segments
+        .iter()
+        .filter(|s| s.is(0xed, PHOTOSHOP_SIGNATURE))
+        .flat_map(|s| &s.data[PHOTOSHOP_SIGNATURE.len()..])
+        .copied()
+        .collect();
+    let mut app13 = PHOTOSHOP_SIGNATURE.to_vec();
+    app13.extend(edit_photoshop(&photoshop, changes)?);
+    if app13.len() > MAX_SEGMENT_DATA {
+        return Err(ExifError::Unsupported(format!(
+            "Photoshop APP13 data of {} bytes needs more than one segment",
+            app13.len()
+        )));
+    }
+
+    let insert_at = segments
+        .iter()
+        .position(|s| s.is(0xed, PHOTOSHOP_SIGNATURE))
+        .unwrap_or_else(|| {
+            segments
+                .iter()
+                .position(|s| !(0xe0..=0xec).contains(&s.marker))
+                .unwrap_or(segments.len())
+        });
+    debug!(
+        "Writing {} byte Photoshop APP13 as segment {}",
+        app13.len(),
+        insert_at
+    );
+
+    let mut out = Vec::with_capacity(jpeg.len() + app13.len());
+    out.extend([0xff, 0xd8]);
+    for (index, segment) in segments.iter().enumerate() {
+        if index == insert_at {
+            write_segment(&mut out, 0xed, &app13);
+        }
+        if !segment.is(0xed, PHOTOSHOP_SIGNATURE) {
+            write_segment(&mut out, segment.marker, segment.data);
+        }
+    }
+    if insert_at == segments.len() {
+        write_segment(&mut out, 0xed, &app13);
+    }
+    out.extend(scan);
+    Ok(out)
+}
+
+/// Apply `changes` to the IPTC of the JPEG file at `path`, replacing it
+/// safely; see [`crate::write::write_file`]
+pub fn update_jpeg_iptc_file(
+    path: &Path,
+    changes: &IptcChanges,
+    options: &WriteOptions,
+) -> Result<()> {
+    let jpeg = std::fs::read(path)?;
+    let updated = write_jpeg_iptc(&jpeg, changes)?;
+    write_bytes(path, &updated, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::parse_iptc_from_app13;
+    use crate::types::TagValue;
+
+    fn iim(datasets: &[(u8, u8, &[u8])]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for &(record, number, data) in datasets {
+            write_dataset(
+                &mut out,
+                &Dataset {
+                    record,
+                    number,
+                    data: data.to_vec(),
+                },
+            );
+        }
+        out
+    }
+
+    /// A JPEG with an APP0 and, optionally, Photoshop resources in APP13
+    fn jpeg(resources: Option<&[Resource]>) -> Vec<u8> {
+        let mut out = vec![0xff, 0xd8];
+        write_segment(&mut out, 0xe0, b"JFIF\0\x01\x02\0\0\x01\0\x01\0\0");
+        if let Some(resources) = resources {
+            let mut app13 = PHOTOSHOP_SIGNATURE.to_vec();
+            app13.extend(write_resources(resources));
+            write_segment(&mut out, 0xed, &app13);
+        }
+        write_segment(&mut out, 0xdb, &[0; 65]);
+        out.extend([0xff, 0xda, 0, 2, 0x12, 0x34, 0xff, 0xd9]);
+        out
+    }
+
+    /// The IPTC tags and Photoshop resources of a JPEG's only APP13
+    fn read_back(jpeg: &[u8]) -> (Vec<Resource>, std::collections::HashMap<String, TagValue>) {
+        let (segments, scan) = split_segments(jpeg).unwrap();
+        assert!(scan.starts_with(&[0xff, 0xda]));
+        let app13: Vec<_> = segments
+            .iter()
+            .filter(|s| s.is(0xed, PHOTOSHOP_SIGNATURE))
+            .collect();
+        assert_eq!(app13.len(), 1);
+        let (resources, trailing) =
+            parse_resources(&app13[0].data[PHOTOSHOP_SIGNATURE.len()..]).unwrap();
+        assert!(trailing.is_empty());
+        (resources, parse_iptc_from_app13(app13[0].data).unwrap())
+    }
+
+    #[test]
+    fn test_update_keywords_caption_and_credit() {
+        let old = iim(&[
+            (2, 0, &[0, 4]),
+            (2, 25, b"old"),
+            (2, 110, b"Old Credit"),
+            (2, 116, b"(c) Agency"),
+        ]);
+        let resources = [
+            Resource {
+                signature: *b"8BIM",
+                id: 0x03ed,
+                name: vec![0, 0],
+                data: vec![1, 2, 3],
+            },
+            Resource {
+                signature: *b"8BIM",
+                id: IPTC_RESOURCE,
+                name: vec![0, 0],
+                data: old,
+            },
+            Resource {
+                signature: *b"8BIM",
+                id: IPTC_DIGEST_RESOURCE,
+                name: vec![0, 0],
+                data: vec![0; 16],
+            },
+        ];
+
+        let mut changes = IptcChanges::new();
+        changes.set("keywords", ["harbour", "storm"]).unwrap();
+        changes
+            .set("Caption-Abstract", ["Waves over the breakwater"])
+            .unwrap();
+        changes.delete("Credit").unwrap();
+        let updated = write_jpeg_iptc(&jpeg(Some(&resources)), &changes).unwrap();
+
+        let (resources, tags) = read_back(&updated);
+        assert_eq!(
+            tags.get("IPTC:Keywords"),
+            Some(&TagValue::Array(vec![
+                TagValue::string("harbour"),
+                TagValue::string("storm")
+            ]))
+        );
+        assert_eq!(
+            tags.get("IPTC:Caption-Abstract"),
+            Some(&TagValue::string("Waves over the breakwater"))
+        );
+        assert_eq!(
+            tags.get("IPTC:CopyrightNotice"),
+            Some(&TagValue::string("(c) Agency"))
+        );
+        assert!(!tags.contains_key("IPTC:Credit"));
+
+        // Other resources are kept, with odd sizes padded, and the digest
+        // matches the new IIM
+        assert_eq!(resources.len(), 3);
+        assert_eq!(resources[0].data, [1, 2, 3]);
+        let iptc = &resources[1].data;
+        assert_eq!(resources[2].data, Md5::digest(iptc).to_vec());
+        let numbers: Vec<u8> = parse_datasets(iptc)
+            .unwrap()
+            .iter()
+            .map(|d| d.number)
+            .collect();
+        assert_eq!(numbers, [0, 25, 25, 116, 120]);
+    }
+
+    #[test]
+    fn test_keep_non_8bim_resources() {
+        let resources = [
+            Resource {
+                signature: *b"PHUT",
+                id: IPTC_RESOURCE,
+                name: vec![0, 0],
+                data: b"ImageReady".to_vec(),
+            },
+            Resource {
+                signature: *b"8BIM",
+                id: IPTC_RESOURCE,
+                name: vec![0, 0],
+                data: iim(&[(2, 0, &[0, 4]), (2, 110, b"Old Credit")]),
+            },
+            Resource {
+                signature: *b"MeSa",
+                id: 0x0bb7,
+                name: vec![0, 0],
+                data: vec![1, 2, 3],
+            },
+        ];
+        let mut changes = IptcChanges::new();
+        changes.set("Credit", ["New Credit"]).unwrap();
+        let updated = write_jpeg_iptc(&jpeg(Some(&resources)), &changes).unwrap();
+
+        let (resources, tags) = read_back(&updated);
+        let signatures: Vec<&[u8; 4]> = resources.iter().map(|r| &r.signature).collect();
+        assert_eq!(signatures, [b"PHUT", b"8BIM", b"MeSa"]);
+        assert_eq!(resources[0].data, b"ImageReady");
+        assert_eq!(resources[2].data, [1, 2, 3]);
+        assert_eq!(
+            tags.get("IPTC:Credit"),
+            Some(&TagValue::string("New Credit"))
+        );
+
+        // Bytes after the last resource are copied as they are
+        let mut photoshop = write_resources(&resources);
+        photoshop.extend(b"\0\0junk");
+        let edited = edit_photoshop(&photoshop, &IptcChanges::new()).unwrap();
+        assert!(edited.ends_with(b"\0\0junk"));
+        assert_eq!(parse_resources(&edited).unwrap().0.len(), 3);
+    }
+
+    #[test]
+    fn test_create_app13() {
+        let original = jpeg(None);
+        let mut changes = IptcChanges::new();
+        changes.set("Credit", ["Rémi Dupont"]).unwrap();
+        let updated = write_jpeg_iptc(&original, &changes).unwrap();
+
+        let (segments, _) = split_segments(&updated).unwrap();
+        let markers: Vec<u8> = segments.iter().map(|s| s.marker).collect();
+        assert_eq!(markers, [0xe0, 0xed, 0xdb]);
+
+        let (resources, tags) = read_back(&updated);
+        assert_eq!(resources.len(), 1);
+        let datasets = parse_datasets(&resources[0].data).unwrap();
+        assert_eq!((datasets[0].record, datasets[0].number), (1, 90));
+        assert_eq!(datasets[0].data, UTF8_CHARSET);
+        assert_eq!(
+            tags.get("IPTC:Credit"),
+            Some(&TagValue::string("Rémi Dupont"))
+        );
+        assert!(updated.ends_with(&[0xff, 0xda, 0, 2, 0x12, 0x34, 0xff, 0xd9]));
+    }
+
+    #[test]
+    fn test_utf8_conversion_skips_binary_datasets() {
+        let preview: &[u8] = &[0xff, 0xd8, 0xe9, 0x80];
+        let old = iim(&[(2, 0, &[0, 4]), (2, 90, b"Z\xfcrich"), (2, 202, preview)]);
+        let mut changes = IptcChanges::new();
+        changes.set("Credit", ["Rémi Dupont"]).unwrap();
+        let updated = parse_datasets(&edit_iptc(&old, &changes).unwrap()).unwrap();
+
+        let data = |number| {
+            updated
+                .iter()
+                .find(|d| (d.record, d.number) == (2, number))
+                .map(|d| d.data.as_slice())
+        };
+        assert_eq!(data(90), Some("Zürich".as_bytes()));
+        assert_eq!(data(202), Some(preview));
+    }
+
+    #[test]
+    fn test_invalid_changes() {
+        let mut changes = IptcChanges::new();
+        assert!(changes.set("NoSuchTag", ["x"]).is_err());
+        // Credit is limited to 32 bytes and isn't a list
+        assert!(changes.set("Credit", ["x".repeat(33)]).is_err());
+        assert!(changes.set("Credit", ["a", "b"]).is_err());
+        changes.set("DateCreated", ["2024:05:01 12:30:00"]).unwrap();
+        assert_eq!(changes.datasets[&55], ["20240501"]);
+        assert!(write_jpeg_iptc(b"GIF89a", &changes).is_err());
+    }
+}
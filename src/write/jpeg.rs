@@ -0,0 +1,57 @@
+//! JPEG segment splitting shared by the JPEG writers
+
+use crate::types::{ExifError, Result};
+
+/// Largest APPn payload after the 2-byte segment length
+pub(crate) const MAX_SEGMENT_DATA: usize = 0xffff - 2;
+
+/// A JPEG segment before the scan data
+pub(crate) struct Segment<'a> {
+    pub(crate) marker: u8,
+    pub(crate) data: &'a [u8],
+}
+
+impl Segment<'_> {
+    /// Whether this is a `marker` segment whose data starts with `signature`
+    pub(crate) fn is(&self, marker: u8, signature: &[u8]) -> bool {
+        self.marker == marker && self.data.starts_with(signature)
+    }
+}
+
+/// The segments before SOS, and the rest of the file from SOS on
+pub(crate) fn split_segments(jpeg: &[u8]) -> Result<(Vec<Segment<'_>>, &[u8])> {
+    if !jpeg.starts_with(&[0xff, 0xd8]) {
+        return Err(ExifError::InvalidFormat("Not a JPEG file".to_string()));
+    }
+    let truncated = || ExifError::InvalidFormat("Truncated JPEG segment".to_string());
+    let mut segments = Vec::new();
+    let mut pos = 2;
+    loop {
+        // Markers may be preceded by fill bytes
+        while jpeg.get(pos) == Some(&0xff) && jpeg.get(pos + 1) == Some(&0xff) {
+            pos += 1;
+        }
+        if jpeg.get(pos) != Some(&0xff) {
+            return Err(ExifError::InvalidFormat(format!(
+                "Expected a JPEG marker at offset {pos}"
+            )));
+        }
+        let marker = *jpeg.get(pos + 1).ok_or_else(truncated)?;
+        if matches!(marker, 0xda | 0xd9) {
+            return Ok((segments, &jpeg[pos..]));
+        }
+        let len_bytes = jpeg.get(pos + 2..pos + 4).ok_or_else(truncated)?;
+        let len = u16::from_be_bytes([len_bytes[0], len_bytes[1]]) as usize;
+        let data = jpeg
+            .get(pos + 4..(pos + 2 + len).max(pos + 4))
+            .ok_or_else(truncated)?;
+        segments.push(Segment { marker, data });
+        pos += 2 + len;
+    }
+}
+
+pub(crate) fn write_segment(out: &mut Vec<u8>, marker: u8, data: &[u8]) {
+    out.extend([0xff, marker]);
+    out.extend(((data.len() + 2) as u16).to_be_bytes());
+    out.extend(data);
+}
@@ -0,0 +1,21 @@
+//! Metadata writing
+//!
+//! Shared plumbing for the format writers:
+//! - [`io`]: replacing a file safely (temp file, fsync, atomic rename), with
+//!   ExifTool's `_original` backup and `-P` mtime preservation
+//!
+//! Format writers:
+//! - [`iptc`]: IPTC IIM records in the Photoshop resources of JPEG APP13
+//! - [`xmp`]: XMP properties in the packet of JPEG APP1
+//!
+//! [`copy`] copies tags from one file to another on top of them, like
+//! ExifTool's `-tagsFromFile`.
+
+pub mod copy;
+pub mod io;
+pub mod iptc;
+mod jpeg;
+#[cfg(feature = "xmp")]
+pub mod xmp;
+
+pub use io::*;
@@ -0,0 +1,708 @@
+//! XMP editing in JPEG APP1
+//!
+//! JPEG files carry the standard XMP packet in an APP1 segment that starts
+//! with `http://ns.adobe.com/xap/1.0/\0`. [`write_jpeg_xmp`] replaces the
+//! properties named in [`XmpChanges`]:
+//!
+//! - their old values are removed, whether written as elements or as
+//!   attributes of a top-level `rdf:Description`
+//! - the new values go in an `rdf:Description` of their own at the end of
+//!   `rdf:RDF`: list tags as `rdf:Bag` or `rdf:Seq`, `lang-alt` tags as an
+//!   `rdf:Alt` with an `x-default` item
+//! - everything else in the packet is kept as it was
+//!
+//! Tags are named as ExifTool names them (`Creator`, `Subject`,
+//! `DateCreated`), optionally with a namespace (`XMP-dc:Creator` or
+//! `dc:Creator`); without one, the first namespace in [`NAMESPACES`] that
+//! has the tag is used. Values are checked against the tag's `Writable`
+//! type, and dates are written in XMP's ISO 8601 form.
+//!
+//! The APP1 segment is created after the APP0 and EXIF APP1 segments if the
+//! file has no XMP. Extended XMP isn't written: a packet that outgrows one
+//! segment is an error.
+//!
+//! ```no_run
+//! use exif_oxide::write::xmp::{update_jpeg_xmp_file, XmpChanges};
+//! use exif_oxide::write::WriteOptions;
+//!
+//! let mut changes = XmpChanges::new();
+//! changes.set("Subject", ["harbour", "storm"]).unwrap();
+//! changes.set("XMP-dc:Creator", ["Jo Smith"]).unwrap();
+//! changes.delete("Rating").unwrap();
+//! update_jpeg_xmp_file(std::path::Path::new("photo.jpg"), &changes, &WriteOptions::new())
+//!     .unwrap();
+//! ```
+//!
+//! ## ExifTool Reference
+//!
+//! - lib/Image/ExifTool/WriteXMP.pl WriteXMP(): list and lang-alt
+//!   structure; CheckXMP(): value checks by `Writable` type
+//! - lib/Image/ExifTool/XMP.pm FormatXMPDate(): date format
+//! - lib/Image/ExifTool/WriteExif.pl WriteJPEG: where a new XMP APP1 goes
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::name::{Namespace, ResolveResult};
+use quick_xml::reader::NsReader;
+use quick_xml::Writer;
+use tracing::debug;
+
+use crate::core::{XmpListType, XmpTagInfo};
+use crate::generated::XMP_pm::ns_uri::NS_URI;
+use crate::types::{ExifError, Result};
+use crate::write::jpeg::{split_segments, write_segment, MAX_SEGMENT_DATA};
+use crate::write::{write_bytes, WriteOptions};
+use crate::xmp::xmp_lookup::find_xmp_property;
+
+/// APP1 identifier of the standard XMP packet
+const XMP_SIGNATURE: &[u8] = b"http://ns.adobe.com/xap/1.0/\0";
+
+/// APP1 identifier of EXIF, which a new XMP segment follows
+const EXIF_SIGNATURE: &[u8] = b"Exif\0";
+
+const RDF_NS: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+
+/// Packet edited when the file has no XMP
+/// ExifTool: WriteXMP.pl $pktOpen, $rdfOpen and their closing tags
+const EMPTY_PACKET: &str = "<?xpacket begin='\u{feff}' id='W5M0MpCehiHzreSzNTczkc9d'?>\n\
+<x:xmpmeta xmlns:x='adobe:ns:meta/'>\n\
+<rdf:RDF xmlns:rdf='http://www.w3.org/1999/02/22-rdf-syntax-ns#'>\n\
+</rdf:RDF>\n\
+</x:xmpmeta>\n\
+<?xpacket end='w'?>";
+
+/// Namespaces searched, in order, for a tag named without one
+pub const NAMESPACES: &[&str] = &[
+    "dc",
+    "xmp",
+    "xmpRights",
+    "photoshop",
+    "Iptc4xmpCore",
+    "Iptc4xmpExt",
+    "exif",
+    "exifEX",
+    "tiff",
+    "aux",
+    "xmpMM",
+    "xmpBJ",
+    "xmpTPg",
+    "lr",
+    "crs",
+    "cc",
+    "pdf",
+    "GPano",
+];
+
+/// An XMP property to set
+#[derive(Debug, Clone, Copy)]
+struct Property {
+    prefix: &'static str,
+    /// Property name as written, like `creator` for Creator
+    name: &'static str,
+    info: &'static XmpTagInfo,
+}
+
+impl Property {
+    fn uri(&self) -> &'static str {
+        NS_URI.get(self.prefix).copied().unwrap_or_default()
+    }
+}
+
+/// New values for XMP properties
+#[derive(Debug, Clone, Default)]
+pub struct XmpChanges {
+    /// Formatted values by property; no values deletes the property
+    properties: BTreeMap<(&'static str, &'static str), (Property, Vec<String>)>,
+}
+
+impl XmpChanges {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace every value of the XMP tag `name`
+    ///
+    /// Only list tags like Subject take more than one value. Values must
+    /// suit the tag's type: `integer`, `real`, `rational` and `boolean`
+    /// tags take numbers (or True/False), and `date` tags take EXIF-style
+    /// dates such as `2024:05:01 12:30:00`, written as `2024-05-01T12:30:00`.
+    pub fn set<I, S>(&mut self, name: &str, values: I) -> Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let property = lookup(name)?;
+        let values = values
+            .into_iter()
+            .map(|value| check_value(&property, value.into()))
+            .collect::<Result<Vec<_>>>()?;
+        if values.len() > 1 && property.info.list.is_none() {
+            return Err(ExifError::InvalidFormat(format!(
+                "XMP-{}:{} takes a single value",
+                property.prefix, property.info.name
+            )));
+        }
+        self.properties
+            .insert((property.prefix, property.name), (property, values));
+        Ok(())
+    }
+
+    /// Remove every value of the XMP tag `name`
+    pub fn delete(&mut self, name: &str) -> Result<()> {
+        let property = lookup(name)?;
+        self.properties
+            .insert((property.prefix, property.name), (property, Vec::new()));
+        Ok(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
+    /// Whether a property of this namespace URI is being changed
+    fn changes(&self, uri: &[u8], name: &[u8]) -> bool {
+        self.properties.values().any(|(property, _)| {
+            property.uri().as_bytes() == uri && property.name.as_bytes() == name
+        })
+    }
+}
+
+/// The namespace, property and tag info of an XMP tag
+fn lookup(name: &str) -> Result<Property> {
+    let (namespaces, tag) = match name.rsplit_once(':') {
+        Some((group, tag)) if !group.eq_ignore_ascii_case("XMP") => {
+            let group = group.strip_prefix("XMP-").unwrap_or(group);
+            let prefix = NAMESPACES
+                .iter()
+                .find(|prefix| prefix.eq_ignore_ascii_case(group))
+                .ok_or_else(|| {
+                    ExifError::InvalidFormat(format!("Unknown XMP namespace {group}"))
+                })?;
+            (std::slice::from_ref(prefix), tag)
+        }
+        Some((_, tag)) => (NAMESPACES, tag),
+        None => (NAMESPACES, name),
+    };
+    namespaces
+        .iter()
+        .find_map(|&prefix| {
+            let (name, info) = find_xmp_property(prefix, tag)?;
+            // Structures have no Writable type of their own
+            info.writable
+                .is_some()
+                .then_some(Property { prefix, name, info })
+        })
+        .ok_or_else(|| ExifError::InvalidFormat(format!("Unknown XMP tag {name}")))
+}
+
+/// Whether [`XmpChanges::set`] can write the XMP tag `name`
+pub fn is_writable(name: &str) -> bool {
+    lookup(name).is_ok()
+}
+
+/// Whether the XMP tag `name` has a PrintConv, so values must be written
+/// unconverted (Orientation 6, not "Rotate 90 CW")
+pub fn has_print_conv(name: &str) -> bool {
+    lookup(name).is_ok_and(|property| property.info.print_conv.is_some())
+}
+
+/// Check and format a value for the tag's `Writable` type
+/// ExifTool: WriteXMP.pl CheckXMP()
+fn check_value(property: &Property, value: String) -> Result<String> {
+    let invalid = |what: &str| {
+        ExifError::InvalidFormat(format!(
+            "XMP-{}:{} {what}, not {value:?}",
+            property.prefix, property.info.name
+        ))
+    };
+    let trimmed = value.trim();
+    match property.info.writable.unwrap_or("string") {
+        "integer" => trimmed
+            .parse::<i64>()
+            .map(|int| int.to_string())
+            .map_err(|_| invalid("must be an integer")),
+        "real" => trimmed
+            .parse::<f64>()
+            .map(|_| trimmed.to_string())
+            .map_err(|_| invalid("must be a number")),
+        "rational" => format_rational(trimmed).ok_or_else(|| invalid("must be a rational")),
+        "boolean" => match trimmed.to_ascii_lowercase().as_str() {
+            "" | "0" | "false" | "no" => Ok("False".to_string()),
+            "1" | "true" | "yes" => Ok("True".to_string()),
+            _ => Err(invalid("must be True or False")),
+        },
+        "date" => format_date(trimmed).ok_or_else(|| invalid("must be a date")),
+        _ => Ok(value),
+    }
+}
+
+/// `n/d`, or a decimal number as the nearest fraction
+/// ExifTool: WriteXMP.pl CheckXMP() rational, Exif.pm Rationalize()
+fn format_rational(value: &str) -> Option<String> {
+    if let Some((num, den)) = value.split_once('/') {
+        let (num, den) = (num.parse::<i64>().ok()?, den.parse::<u64>().ok()?);
+        return Some(format!("{num}/{den}"));
+    }
+    let val = value.parse::<f64>().ok()?;
+    if !val.is_finite() {
+        return None;
+    }
+    // Continued fraction expansion until the fraction is exact enough
+    let (mut num, mut den) = (1.0_f64, 0.0_f64);
+    let (mut prev_num, mut prev_den) = (0.0_f64, 1.0_f64);
+    let mut frac = val.abs();
+    loop {
+        let int = frac.floor();
+        (num, prev_num) = (int * num + prev_num, num);
+        (den, prev_den) = (int * den + prev_den, den);
+        if (num / den - val.abs()).abs() < 1e-9 * val.abs().max(1.0)
+            || num > i32::MAX as f64
+            || den > i32::MAX as f64
+            || frac == int
+        {
+            break;
+        }
+        frac = 1.0 / (frac - int);
+    }
+    let sign = if val < 0.0 { "-" } else { "" };
+    Some(format!("{sign}{}/{}", num as i64, den as i64))
+}
+
+/// `YYYY:MM:DD HH:MM:SS[.ss][zone]` as `YYYY-MM-DDTHH:MM:SS[.ss][zone]`;
+/// the date may be shortened to `YYYY:MM` or `YYYY`, and the seconds left out
+/// ExifTool: XMP.pm FormatXMPDate()
+fn format_date(value: &str) -> Option<String> {
+    let (date, time) = match value.split_once([' ', 'T']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (value, None),
+    };
+    let parts: Vec<&str> = date.split([':', '-']).collect();
+    let digits =
+        |part: &str, len: usize| part.len() == len && part.bytes().all(|b| b.is_ascii_digit());
+    let valid = match parts.as_slice() {
+        [year] => digits(year, 4),
+        [year, month] => digits(year, 4) && digits(month, 2),
+        [year, month, day] => digits(year, 4) && digits(month, 2) && digits(day, 2),
+        _ => false,
+    };
+    if !valid {
+        return None;
+    }
+    let mut out = parts.join("-");
+    if let Some(time) = time {
+        let clock_len = time.find(['+', '-', 'Z']).unwrap_or(time.len());
+        let (clock, zone) = time.split_at(clock_len);
+        let mut fields = clock.splitn(3, ':');
+        let (hour, minute) = (fields.next()?, fields.next()?);
+        if !digits(hour, 2) || !digits(minute, 2) {
+            return None;
+        }
+        out.push('T');
+        out.push_str(clock);
+        out.push_str(zone);
+    }
+    Some(out)
+}
+
+/// Where an element sits in the RDF tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Element {
+    Rdf,
+    /// An `rdf:Description` directly in `rdf:RDF`, whose children and
+    /// namespaced attributes are top-level properties
+    Description,
+    Other,
+}
+
+fn xml_error(e: impl std::fmt::Display) -> ExifError {
+    ExifError::ParseError(format!("Invalid XMP: {e}"))
+}
+
+/// Apply `changes` to an XMP packet; an empty packet starts a new one
+fn edit_xmp(packet: &[u8], changes: &XmpChanges) -> Result<Vec<u8>> {
+    let packet = if packet.is_empty() {
+        EMPTY_PACKET.as_bytes()
+    } else {
+        packet
+    };
+    let mut reader = NsReader::from_reader(packet);
+    let mut writer = Writer::new(Vec::with_capacity(packet.len() + 1024));
+    let mut buf = Vec::new();
+    let mut open: Vec<Element> = Vec::new();
+    // Depth inside a removed property element
+    let mut skip = 0usize;
+    // Indentation before a property, dropped with the property if it's removed
+    let mut indent: Vec<u8> = Vec::new();
+    let mut wrote_values = false;
+
+    loop {
+        buf.clear();
+        let (ns, event) = reader
+            .read_resolved_event_into(&mut buf)
+            .map_err(xml_error)?;
+        let uri = match ns {
+            ResolveResult::Bound(Namespace(uri)) => uri.to_vec(),
+            _ => Vec::new(),
+        };
+        if skip > 0 {
+            match event {
+                Event::Start(_) => skip += 1,
+                Event::End(_) => skip -= 1,
+                Event::Eof => break,
+                _ => {}
+            }
+            continue;
+        }
+        let removed = match &event {
+            Event::Start(element) | Event::Empty(element) => {
+                open.last() == Some(&Element::Description)
+                    && changes.changes(&uri, element.local_name().as_ref())
+            }
+            _ => false,
+        };
+        if removed {
+            if matches!(event, Event::Start(_)) {
+                skip = 1;
+            }
+            indent.clear();
+            continue;
+        }
+        if let Event::Text(text) = &event {
+            if open.last() == Some(&Element::Description)
+                && text.iter().all(u8::is_ascii_whitespace)
+            {
+                indent.extend_from_slice(text);
+                continue;
+            }
+        }
+        writer.get_mut().append(&mut indent);
+        match event {
+            Event::Start(element) => {
+                let kind = element_kind(&uri, &element, open.last());
+                let element = if kind == Element::Description {
+                    remove_changed_attributes(&reader, &element, changes)?
+                } else {
+                    element.into_owned()
+                };
+                open.push(kind);
+                writer
+                    .write_event(Event::Start(element))
+                    .map_err(xml_error)?;
+            }
+            Event::Empty(element) => {
+                let element = if element_kind(&uri, &element, open.last()) == Element::Description {
+                    remove_changed_attributes(&reader, &element, changes)?
+                } else {
+                    element.into_owned()
+                };
+                writer
+                    .write_event(Event::Empty(element))
+                    .map_err(xml_error)?;
+            }
+            Event::End(element) => {
+                if open.pop() == Some(Element::Rdf) {
+                    writer.get_mut().extend(new_description(changes).as_bytes());
+                    wrote_values = true;
+                }
+                writer.write_event(Event::End(element)).map_err(xml_error)?;
+            }
+            Event::Eof => break,
+            event => writer.write_event(event).map_err(xml_error)?,
+        }
+    }
+    if !wrote_values {
+        return Err(ExifError::ParseError(
+            "Invalid XMP: no rdf:RDF element".to_string(),
+        ));
+    }
+    Ok(writer.into_inner())
+}
+
+fn element_kind(uri: &[u8], element: &BytesStart, parent: Option<&Element>) -> Element {
+    if uri != RDF_NS.as_bytes() {
+        return Element::Other;
+    }
+    match element.local_name().as_ref() {
+        b"RDF" => Element::Rdf,
+        b"Description" if parent == Some(&Element::Rdf) => Element::Description,
+        _ => Element::Other,
+    }
+}
+
+/// An `rdf:Description` without the attributes of changed properties
+fn remove_changed_attributes(
+    reader: &NsReader<&[u8]>,
+    element: &BytesStart,
+    changes: &XmpChanges,
+) -> Result<BytesStart<'static>> {
+    let name = String::from_utf8_lossy(element.name().as_ref()).into_owned();
+    let mut kept = BytesStart::new(name);
+    for attr in element.attributes() {
+        let attr = attr.map_err(xml_error)?;
+        let (ns, local) = reader.resolver().resolve_attribute(attr.key);
+        if let ResolveResult::Bound(Namespace(uri)) = ns {
+            if changes.changes(uri, local.as_ref()) {
+                continue;
+            }
+        }
+        kept.push_attribute(attr);
+    }
+    Ok(kept)
+}
+
+/// The `rdf:Description` holding the new values, if any
+fn new_description(changes: &XmpChanges) -> String {
+    let properties: Vec<_> = changes
+        .properties
+        .values()
+        .filter(|(_, values)| !values.is_empty())
+        .collect();
+    if properties.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::from(" <rdf:Description rdf:about=''");
+    let mut prefixes: Vec<&Property> = properties.iter().map(|(p, _)| p).collect();
+    prefixes.dedup_by_key(|p| p.prefix);
+    for property in prefixes {
+        out.push_str(&format!(
+            "\n  xmlns:{}='{}'",
+            property.prefix,
+            property.uri()
+        ));
+    }
+    out.push_str(">\n");
+
+    for (property, values) in properties {
+        let tag = format!("{}:{}", property.prefix, property.name);
+        let container = match property.info.list {
+            Some(XmpListType::Bag) => Some("Bag"),
+            Some(XmpListType::Seq) => Some("Seq"),
+            Some(XmpListType::Alt) => Some("Alt"),
+            None if property.info.writable == Some("lang-alt") => Some("Alt"),
+            None => None,
+        };
+        match container {
+            Some(container) => {
+                out.push_str(&format!("  <{tag}>\n   <rdf:{container}>\n"));
+                let lang = if container == "Alt" {
+                    " xml:lang='x-default'"
+                } else {
+                    ""
+                };
+                for value in values {
+                    out.push_str(&format!("    <rdf:li{lang}>{}</rdf:li>\n", escape(value)));
+                }
+                out.push_str(&format!("   </rdf:{container}>\n  </{tag}>\n"));
+            }
+            None if property.info.resource => {
+                out.push_str(&format!(
+                    "  <{tag} rdf:resource='{}'/>\n",
+                    escape(&values[0])
+                ));
+            }
+            None => out.push_str(&format!("  <{tag}>{}</{tag}>\n", escape(&values[0]))),
+        }
+    }
+    out.push_str(" </rdf:Description>\n");
+    out
+}
+
+/// Apply `changes` to the XMP of a JPEG file, returning the new file
+pub fn write_jpeg_xmp(jpeg: &[u8], changes: &XmpChanges) -> Result<Vec<u8>> {
+    if changes.is_empty() {
+        return Ok(jpeg.to_vec());
+    }
+    let (segments, scan) = split_segments(jpeg)?;
+    let existing = segments.iter().position(|s| s.is(0xe1, XMP_SIGNATURE));
+    let packet = existing.map_or(&[][..], |index| {
+        &segments[index].data[XMP_SIGNATURE.len()..]
+    });
+
+    let mut app1 = XMP_SIGNATURE.to_vec();
+    app1.extend(edit_xmp(packet, changes)?);
+    if app1.len() > MAX_SEGMENT_DATA {
+        return Err(ExifError::Unsupported(format!(
+            "XMP APP1 data of {} bytes needs Extended XMP",
+            app1.len()
+        )));
+    }
+
+    let insert_at = existing.unwrap_or_else(|| {
+        segments
+            .iter()
+            .position(|s| !(s.marker == 0xe0 || s.is(0xe1, EXIF_SIGNATURE)))
+            .unwrap_or(segments.len())
+    });
+    debug!(
+        "Writing {} byte XMP APP1 as segment {}",
+        app1.len(),
+        insert_at
+    );
+
+    let mut out = Vec::with_capacity(jpeg.len() + app1.len());
+    out.extend([0xff, 0xd8]);
+    for (index, segment) in segments.iter().enumerate() {
+        if index == insert_at {
+            write_segment(&mut out, 0xe1, &app1);
+        }
+        if Some(index) != existing {
+            write_segment(&mut out, segment.marker, segment.data);
+        }
+    }
+    if insert_at == segments.len() {
+        write_segment(&mut out, 0xe1, &app1);
+    }
+    out.extend(scan);
+    Ok(out)
+}
+
+/// Apply `changes` to the XMP of the JPEG file at `path`, replacing it
+/// safely; see [`crate::write::write_file`]
+pub fn update_jpeg_xmp_file(
+    path: &Path,
+    changes: &XmpChanges,
+    options: &WriteOptions,
+) -> Result<()> {
+    let jpeg = std::fs::read(path)?;
+    let updated = write_jpeg_xmp(&jpeg, changes)?;
+    write_bytes(path, &updated, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TagValue;
+    use crate::xmp::XmpProcessor;
+
+    /// A JPEG with an APP0 and, optionally, an XMP APP1
+    fn jpeg(packet: Option<&str>) -> Vec<u8> {
+        let mut out = vec![0xff, 0xd8];
+        write_segment(&mut out, 0xe0, b"JFIF\0\x01\x02\0\0\x01\0\x01\0\0");
+        if let Some(packet) = packet {
+            write_segment(&mut out, 0xe1, &[XMP_SIGNATURE, packet.as_bytes()].concat());
+        }
+        write_segment(&mut out, 0xdb, &[0; 65]);
+        out.extend([0xff, 0xda, 0, 2, 0x12, 0x34, 0xff, 0xd9]);
+        out
+    }
+
+    /// The XMP tags of a JPEG's only XMP APP1, by name
+    fn read_back(jpeg: &[u8]) -> BTreeMap<String, TagValue> {
+        let (segments, scan) = split_segments(jpeg).unwrap();
+        assert!(scan.starts_with(&[0xff, 0xda]));
+        let app1: Vec<_> = segments
+            .iter()
+            .filter(|s| s.is(0xe1, XMP_SIGNATURE))
+            .collect();
+        assert_eq!(app1.len(), 1);
+        XmpProcessor::new()
+            .process_xmp_data_individual(&app1[0].data[XMP_SIGNATURE.len()..])
+            .unwrap()
+            .into_iter()
+            .map(|entry| (entry.name.to_string(), entry.value))
+            .collect()
+    }
+
+    #[test]
+    fn test_create_app1() {
+        let mut changes = XmpChanges::new();
+        changes.set("Subject", ["harbour", "storm"]).unwrap();
+        changes.set("XMP-dc:Creator", ["Jo & Sam"]).unwrap();
+        changes.set("Rights", ["(c) Agency"]).unwrap();
+        changes.set("CreateDate", ["2024:05:01 12:30:00"]).unwrap();
+        changes.set("Rating", ["3"]).unwrap();
+        let updated = write_jpeg_xmp(&jpeg(None), &changes).unwrap();
+
+        let (segments, _) = split_segments(&updated).unwrap();
+        let markers: Vec<u8> = segments.iter().map(|s| s.marker).collect();
+        assert_eq!(markers, [0xe0, 0xe1, 0xdb]);
+
+        let tags = read_back(&updated);
+        assert_eq!(
+            tags.get("Subject"),
+            Some(&TagValue::Array(vec![
+                TagValue::string("harbour"),
+                TagValue::string("storm")
+            ]))
+        );
+        assert_eq!(
+            tags.get("Creator"),
+            Some(&TagValue::Array(vec![TagValue::string("Jo & Sam")]))
+        );
+        assert_eq!(tags.get("Rights"), Some(&TagValue::string("(c) Agency")));
+        assert_eq!(
+            tags.get("CreateDate"),
+            Some(&TagValue::string("2024:05:01 12:30:00"))
+        );
+        assert!(tags.contains_key("Rating"));
+    }
+
+    #[test]
+    fn test_replace_existing_properties() {
+        let packet = r#"<?xpacket begin='' id='W5M0MpCehiHzreSzNTczkc9d'?>
+<x:xmpmeta xmlns:x='adobe:ns:meta/'>
+<rdf:RDF xmlns:rdf='http://www.w3.org/1999/02/22-rdf-syntax-ns#'>
+ <rdf:Description rdf:about='' xmlns:xmp='http://ns.adobe.com/xap/1.0/'
+  xmp:Rating='5' xmp:Label='Red'/>
+ <rdf:Description rdf:about='' xmlns:purl='http://purl.org/dc/elements/1.1/'>
+  <purl:subject><rdf:Bag><rdf:li>old</rdf:li></rdf:Bag></purl:subject>
+  <purl:title><rdf:Alt><rdf:li xml:lang='x-default'>Harbour</rdf:li></rdf:Alt></purl:title>
+ </rdf:Description>
+</rdf:RDF>
+</x:xmpmeta>
+<?xpacket end='w'?>"#;
+        let mut changes = XmpChanges::new();
+        changes.set("Subject", ["new"]).unwrap();
+        changes.delete("Rating").unwrap();
+        let updated = write_jpeg_xmp(&jpeg(Some(packet)), &changes).unwrap();
+
+        let tags = read_back(&updated);
+        assert_eq!(
+            tags.get("Subject"),
+            Some(&TagValue::Array(vec![TagValue::string("new")]))
+        );
+        assert!(!tags.contains_key("Rating"));
+        // Properties that weren't changed are kept, however they're written
+        assert_eq!(tags.get("Label"), Some(&TagValue::string("Red")));
+        assert_eq!(tags.get("Title"), Some(&TagValue::string("Harbour")));
+    }
+
+    #[test]
+    fn test_check_values() {
+        let mut changes = XmpChanges::new();
+        assert!(changes.set("NoSuchTag", ["x"]).is_err());
+        assert!(changes.set("XMP-nope:Creator", ["x"]).is_err());
+        assert!(changes.set("Rating", ["high"]).is_err());
+        assert!(changes.set("Label", ["a", "b"]).is_err());
+        assert!(changes.set("CreateDate", ["yesterday"]).is_err());
+
+        let set = |name: &str, value: &str| {
+            let mut changes = XmpChanges::new();
+            changes.set(name, [value]).unwrap();
+            changes.properties.into_values().next().unwrap().1[0].clone()
+        };
+        assert_eq!(set("DateCreated", "2024:05:01"), "2024-05-01");
+        assert_eq!(
+            set("ModifyDate", "2024:05:01 12:30:00.25+02:00"),
+            "2024-05-01T12:30:00.25+02:00"
+        );
+        assert_eq!(set("FNumber", "4.5"), "9/2");
+        assert_eq!(set("ExposureTime", "1/200"), "1/200");
+        assert_eq!(set("XMP-xmpRights:Marked", "yes"), "True");
+
+        assert!(is_writable("XMP:Subject"));
+        assert!(!is_writable("NoSuchTag"));
+        assert!(has_print_conv("Orientation"));
+    }
+
+    #[test]
+    fn test_packet_without_rdf() {
+        let mut changes = XmpChanges::new();
+        changes.set("Label", ["Red"]).unwrap();
+        assert!(edit_xmp(b"<x:xmpmeta xmlns:x='adobe:ns:meta/'/>", &changes).is_err());
+    }
+}
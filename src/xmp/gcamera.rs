@@ -0,0 +1,74 @@
+//! Google camera and container XMP namespaces (GCamera, GContainer)
+//!
+//! Hand-written port of `%Image::ExifTool::XMP::GCamera` and
+//! `%Image::ExifTool::XMP::GContainer` (XMP2.pl), which the XMP codegen
+//! strategy doesn't extract yet. Android motion photos are a JPEG with an
+//! MP4 appended; these properties say where the video is:
+//!
+//! - Motion Photo v1 (`MicroVideo`): `MicroVideoOffset` is the video's
+//!   distance from the end of the file.
+//! - Motion Photo v2 (`MotionPhoto`): the `Container:Directory` lists the
+//!   primary image followed by each appended item, with its `Length`.
+//!
+//! The GContainer directory items use their own namespace, which ExifTool
+//! declares inline in the struct rather than in `%nsURI` (see
+//! [`GCONTAINER_ITEM_NS`]). Flattened, they are the DirectoryItem* tags.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use crate::core::XmpTagInfo;
+
+/// Namespace of the GContainer directory item fields (prefix "Item")
+pub const GCONTAINER_ITEM_NS: &str = "http://ns.google.com/photos/1.0/container/item/";
+
+/// (property, Writable) pairs, XMP2.pl order
+const GCAMERA_PROPERTIES: &[(&str, &str)] = &[
+    ("BurstID", "string"),
+    ("BurstPrimary", "string"),
+    ("DisableAutoCreation", "string"),
+    ("MicroVideo", "integer"),
+    ("MicroVideoVersion", "integer"),
+    ("MicroVideoOffset", "integer"),
+    ("MicroVideoPresentationTimestampUs", "integer"),
+    ("MotionPhoto", "integer"),
+    ("MotionPhotoVersion", "integer"),
+    ("MotionPhotoPresentationTimestampUs", "integer"),
+    ("SpecialTypeID", "string"),
+];
+
+/// Flattened Directory/Item fields, as (flattened ID, Writable) pairs
+const GCONTAINER_PROPERTIES: &[(&str, &str)] = &[
+    ("DirectoryItemMime", "string"),
+    ("DirectoryItemSemantic", "string"),
+    ("DirectoryItemLength", "integer"),
+    ("DirectoryItemLabel", "string"),
+    ("DirectoryItemPadding", "integer"),
+    ("DirectoryItemURI", "string"),
+];
+
+fn table(properties: &'static [(&str, &str)]) -> HashMap<&'static str, XmpTagInfo> {
+    properties
+        .iter()
+        .map(|&(name, writable)| {
+            (
+                name,
+                XmpTagInfo {
+                    name,
+                    writable: Some(writable),
+                    list: None,
+                    resource: false,
+                    print_conv: None,
+                },
+            )
+        })
+        .collect()
+}
+
+/// XMP tag definitions for the GCamera namespace
+pub static XMP_GCAMERA_TAGS: LazyLock<HashMap<&'static str, XmpTagInfo>> =
+    LazyLock::new(|| table(GCAMERA_PROPERTIES));
+
+/// XMP tag definitions for the GContainer namespace
+pub static XMP_GCONTAINER_TAGS: LazyLock<HashMap<&'static str, XmpTagInfo>> =
+    LazyLock::new(|| table(GCONTAINER_PROPERTIES));
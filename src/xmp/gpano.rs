@@ -0,0 +1,67 @@
+//! Google Photo Sphere XMP namespace (GPano)
+//!
+//! Hand-written port of `%Image::ExifTool::XMP::GPano` (XMP2.pl), which the
+//! XMP codegen strategy doesn't extract yet. Photo spheres and 360° panoramas
+//! carry these properties; `UsePanoramaViewer` and `ProjectionType`
+//! ("equirectangular") identify them, and the full/cropped pixel sizes and
+//! pose angles are what a viewer needs to render them.
+//!
+//! Property names are the tag names, so the table mostly supplies the
+//! `Writable` formats (`date` values get ConvertXMPDate like any other XMP
+//! date).
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use crate::core::XmpTagInfo;
+
+/// (property, Writable) pairs, XMP2.pl order
+const GPANO_PROPERTIES: &[(&str, &str)] = &[
+    ("AutoPhoto", "boolean"),
+    ("CaptureSoftware", "string"),
+    ("CroppedAreaImageHeightPixels", "integer"),
+    ("CroppedAreaImageWidthPixels", "integer"),
+    ("CroppedAreaLeftPixels", "integer"),
+    ("CroppedAreaTopPixels", "integer"),
+    ("ExposureLockUsed", "boolean"),
+    ("FirstPhotoDate", "date"),
+    ("FullPanoHeightPixels", "integer"),
+    ("FullPanoWidthPixels", "integer"),
+    ("InitialCameraDolly", "real"),
+    ("InitialHorizontalFOVDegrees", "real"),
+    ("InitialVerticalFOVDegrees", "real"),
+    ("InitialViewHeadingDegrees", "integer"),
+    ("InitialViewPitchDegrees", "integer"),
+    ("InitialViewRollDegrees", "integer"),
+    ("LargestValidInteriorRectHeight", "integer"),
+    ("LargestValidInteriorRectLeft", "integer"),
+    ("LargestValidInteriorRectTop", "integer"),
+    ("LargestValidInteriorRectWidth", "integer"),
+    ("LastPhotoDate", "date"),
+    ("PoseHeadingDegrees", "real"),
+    ("PosePitchDegrees", "real"),
+    ("PoseRollDegrees", "real"),
+    ("ProjectionType", "string"),
+    ("SourcePhotosCount", "integer"),
+    ("StitchingSoftware", "string"),
+    ("UsePanoramaViewer", "boolean"),
+];
+
+/// XMP tag definitions for the GPano namespace
+pub static XMP_GPANO_TAGS: LazyLock<HashMap<&'static str, XmpTagInfo>> = LazyLock::new(|| {
+    GPANO_PROPERTIES
+        .iter()
+        .map(|&(name, writable)| {
+            (
+                name,
+                XmpTagInfo {
+                    name,
+                    writable: Some(writable),
+                    list: None,
+                    resource: false,
+                    print_conv: None,
+                },
+            )
+        })
+        .collect()
+});
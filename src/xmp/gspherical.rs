@@ -0,0 +1,66 @@
+//! Google Spherical Video V1 namespace (GSpherical)
+//!
+//! Hand-written port of `%Image::ExifTool::XMP::GSpherical` (XMP2.pl),
+//! which the XMP codegen strategy doesn't extract yet. These aren't really
+//! XMP: 360° MP4 videos carry them as RDF/XML in a `uuid` box of the video
+//! track (the Spherical Video V1 format), with the same projection and
+//! pano size properties as GPano photo spheres.
+//!
+//! `Timestamp` is renamed TimeStamp as in ExifTool; its ConvertUnixTime
+//! ValueConv is applied by the QuickTime reader.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use crate::core::XmpTagInfo;
+
+/// (property, tag name, Writable), XMP2.pl order
+const GSPHERICAL_PROPERTIES: &[(&str, &str, &str)] = &[
+    ("Spherical", "Spherical", "boolean"),
+    ("Stitched", "Stitched", "boolean"),
+    ("StitchingSoftware", "StitchingSoftware", "string"),
+    ("ProjectionType", "ProjectionType", "string"),
+    ("StereoMode", "StereoMode", "string"),
+    ("SourceCount", "SourceCount", "integer"),
+    (
+        "InitialViewHeadingDegrees",
+        "InitialViewHeadingDegrees",
+        "real",
+    ),
+    ("InitialViewPitchDegrees", "InitialViewPitchDegrees", "real"),
+    ("InitialViewRollDegrees", "InitialViewRollDegrees", "real"),
+    ("Timestamp", "TimeStamp", "integer"),
+    ("FullPanoWidthPixels", "FullPanoWidthPixels", "integer"),
+    ("FullPanoHeightPixels", "FullPanoHeightPixels", "integer"),
+    (
+        "CroppedAreaImageWidthPixels",
+        "CroppedAreaImageWidthPixels",
+        "integer",
+    ),
+    (
+        "CroppedAreaImageHeightPixels",
+        "CroppedAreaImageHeightPixels",
+        "integer",
+    ),
+    ("CroppedAreaLeftPixels", "CroppedAreaLeftPixels", "integer"),
+    ("CroppedAreaTopPixels", "CroppedAreaTopPixels", "integer"),
+];
+
+/// XMP tag definitions for the GSpherical namespace
+pub static XMP_GSPHERICAL_TAGS: LazyLock<HashMap<&'static str, XmpTagInfo>> = LazyLock::new(|| {
+    GSPHERICAL_PROPERTIES
+        .iter()
+        .map(|&(property, name, writable)| {
+            (
+                property,
+                XmpTagInfo {
+                    name,
+                    writable: Some(writable),
+                    list: None,
+                    resource: false,
+                    print_conv: None,
+                },
+            )
+        })
+        .collect()
+});
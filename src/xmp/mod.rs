@@ -9,11 +9,17 @@
 //! - Structured output preserving hierarchical data
 //! - RDF container mapping (Bag/Seq → Array, Alt → Object)
 //! - Language alternative support
+//! - RDF shorthand (properties written as attributes)
 //! - Generated tag tables for 719 XMP tags across 40 namespaces
 
+mod gcamera;
+mod gpano;
+mod gspherical;
 pub mod processor;
+pub mod provenance;
 pub mod value_conversion;
 pub mod xmp_lookup;
 
 pub use processor::XmpProcessor;
+pub use provenance::parse_xmp_provenance;
 pub use xmp_lookup::{get_xmp_tag_name, lookup_xmp_tag};
@@ -3,23 +3,49 @@
 //! Processes XMP packets from various sources (standalone .xmp files, JPEG APP1,
 //! TIFF IFD0) and produces structured TagValue output.
 
-use crate::types::{TagEntry, TagValue};
+use crate::types::{TagEntry, TagValue, XmpProvenance};
 use anyhow::{Context, Result};
+use quick_xml::escape::resolve_predefined_entity;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::name::{Namespace, ResolveResult};
 use quick_xml::reader::NsReader;
-use std::collections::HashMap;
+use quick_xml::XmlVersion;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::LazyLock;
 
 // Import generated namespace tables
 // P07: Use the actual generated name NS_URI instead of NAMESPACE_URIS
 use crate::generated::XMP_pm::ns_uri::NS_URI as NAMESPACE_URIS;
 
+/// URI to namespace prefix reverse lookup (following ExifTool's %uri2ns),
+/// built once from the generated tables rather than per packet
+static URI_TO_PREFIX: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    // Add special case for ExifTool namespace (same as ExifTool)
+    let mut uri_to_prefix = HashMap::from([
+        ("http://ns.exiftool.ca/1.0/", "et"),
+        ("http://ns.exiftool.org/1.0/", "et"),
+        // Declared inline in the GContainer Item struct, not in %nsURI (XMP2.pl)
+        (super::gcamera::GCONTAINER_ITEM_NS, "Item"),
+    ]);
+    // This follows ExifTool's %uri2ns pattern (XMP.pm:215-221)
+    for (prefix, uri) in NAMESPACE_URIS.iter() {
+        uri_to_prefix.insert(*uri, *prefix);
+    }
+    uri_to_prefix
+});
+
 /// XMP processor for structured metadata extraction
+///
+/// Cheap to create: the namespace tables are shared, and only the user
+/// config's namespaces are copied into each processor.
 pub struct XmpProcessor {
-    /// URI to namespace prefix reverse lookup (following ExifTool's %uri2ns)
-    uri_to_prefix: HashMap<String, String>,
+    /// Namespaces added by the user config, consulted before [`URI_TO_PREFIX`]
+    user_uri_to_prefix: HashMap<String, String>,
     /// Current namespace mappings discovered in this XMP document
     current_ns_map: HashMap<String, String>,
+    /// xmpMM provenance of the last packet processed, if it had any
+    provenance: Option<XmpProvenance>,
 }
 
 impl Default for XmpProcessor {
@@ -31,22 +57,18 @@ impl Default for XmpProcessor {
 impl XmpProcessor {
     /// Create a new XMP processor
     pub fn new() -> Self {
-        // Build reverse namespace lookup (URI -> prefix) from generated tables
-        // This follows ExifTool's %uri2ns pattern (XMP.pm:215-221)
-        let mut uri_to_prefix = HashMap::new();
-
-        // Add special case for ExifTool namespace (same as ExifTool)
-        uri_to_prefix.insert("http://ns.exiftool.ca/1.0/".to_string(), "et".to_string());
-        uri_to_prefix.insert("http://ns.exiftool.org/1.0/".to_string(), "et".to_string());
-
-        // Build reverse mapping from generated NAMESPACE_URIS
-        for (prefix, uri) in NAMESPACE_URIS.iter() {
-            uri_to_prefix.insert(uri.to_string(), prefix.to_string());
+        // Namespaces added by the user config (ExifTool's UserDefined XMP tables)
+        let mut user_uri_to_prefix = HashMap::new();
+        if let Some(config) = crate::user_config::config() {
+            for (prefix, uri) in config.xmp_namespaces() {
+                user_uri_to_prefix.insert(uri.to_string(), prefix.to_string());
+            }
         }
 
         Self {
-            uri_to_prefix,
+            user_uri_to_prefix,
             current_ns_map: HashMap::new(),
+            provenance: None,
         }
     }
 
@@ -67,11 +89,12 @@ impl XmpProcessor {
 
         // Create TagEntry with structured data
         Ok(TagEntry {
-            group: "XMP".to_string(),
-            group1: "XMP".to_string(),
-            name: "XMP".to_string(),
+            group: "XMP".into(),
+            group1: "XMP".into(),
+            name: "XMP".into(),
             value: TagValue::Object(xmp_structure.clone()),
-            print: TagValue::Object(xmp_structure),
+            print: TagValue::Object(xmp_structure).into(),
+            source: None,
         })
     }
 
@@ -85,6 +108,9 @@ impl XmpProcessor {
     /// - Handles RDF containers: Bag/Seq → arrays, Alt → extract x-default
     /// - Uses generated namespace tables for property resolution
     pub fn process_xmp_data_individual(&mut self, data: &[u8]) -> Result<Vec<TagEntry>> {
+        let _stage = crate::stats::stage("XMP");
+        crate::stats::add_parsed_bytes(data.len() as u64);
+
         // Detect and handle BOM if present, and convert UTF-16 if needed
         let processed_data = self.strip_bom(data);
 
@@ -98,9 +124,20 @@ impl XmpProcessor {
         // Flatten structured XMP into individual TagEntry objects
         let flattened_tags = self.flatten_xmp_structure(&xmp_structure)?;
 
+        // Keep History events whole, which the flattened tags don't
+        self.provenance = super::provenance::parse_xmp_provenance(xmp_str)
+            .ok()
+            .filter(|provenance| !provenance.is_empty());
+
         Ok(flattened_tags)
     }
 
+    /// Take the xmpMM provenance of the last packet passed to
+    /// [`Self::process_xmp_data_individual`]
+    pub fn take_provenance(&mut self) -> Option<XmpProvenance> {
+        self.provenance.take()
+    }
+
     /// Flatten structured XMP data into individual TagEntry objects
     ///
     /// This method converts the nested namespace structure into individual XMP tags
@@ -112,7 +149,7 @@ impl XmpProcessor {
     /// - Apply PrintConv from generated tables for human-readable output
     fn flatten_xmp_structure(
         &self,
-        xmp_structure: &HashMap<String, TagValue>,
+        xmp_structure: &BTreeMap<String, TagValue>,
     ) -> Result<Vec<TagEntry>> {
         let mut flattened_tags = Vec::new();
 
@@ -167,11 +204,12 @@ impl XmpProcessor {
 
                     // Create individual TagEntry with XMP group
                     flattened_tags.push(TagEntry {
-                        group: "XMP".to_string(),
-                        group1: "XMP".to_string(),
-                        name: tag_name,
+                        group: "XMP".into(),
+                        group1: "XMP".into(),
+                        name: tag_name.into(),
                         value: final_value,
-                        print: print_value,
+                        print: print_value.into(),
+                        source: None,
                     });
                 }
             }
@@ -244,7 +282,8 @@ impl XmpProcessor {
             crate::types::PrintConv::None
             | crate::types::PrintConv::Function(_)
             | crate::types::PrintConv::Expression(_)
-            | crate::types::PrintConv::Complex => value.clone(),
+            | crate::types::PrintConv::Complex
+            | crate::types::PrintConv::Bitmask(_) => value.clone(),
         }
     }
 
@@ -404,23 +443,29 @@ impl XmpProcessor {
     }
 
     /// Parse XMP XML and build structured representation
-    fn parse_xmp_xml(&mut self, xml: &str) -> Result<HashMap<String, TagValue>> {
+    fn parse_xmp_xml(&mut self, xml: &str) -> Result<BTreeMap<String, TagValue>> {
+        // Text isn't trimmed by the reader: a value split around an entity
+        // reference keeps its inner spaces, and flush_text() trims the whole value
         let mut reader = NsReader::from_str(xml);
-        reader.config_mut().trim_text(true);
 
         let mut buf = Vec::new();
-        let mut root_object = HashMap::new();
-        let mut namespace_objects: HashMap<String, HashMap<String, TagValue>> = HashMap::new();
+        let mut root_object = BTreeMap::new();
+        let mut namespace_objects: HashMap<String, BTreeMap<String, TagValue>> = HashMap::new();
 
         // Clear current namespace mappings for this document
         self.current_ns_map.clear();
 
         // Stack to track our position in the XML tree
         let mut element_stack: Vec<ElementContext> = Vec::new();
+        // Text of the current element. quick-xml reports entity references
+        // (`&amp;`) as separate events, so one value can arrive in pieces
+        let mut text = String::new();
 
         loop {
             match reader.read_resolved_event_into(&mut buf) {
                 Ok((ns_result, Event::Start(e))) => {
+                    self.flush_text(&mut text, &mut element_stack, &mut namespace_objects)?;
+
                     let element_local_name = e.local_name();
                     let local_name = std::str::from_utf8(element_local_name.as_ref())
                         .context("Invalid UTF-8 in element name")?;
@@ -446,16 +491,18 @@ impl XmpProcessor {
                     )?;
                 }
                 Ok((_, Event::Text(e))) => {
-                    let text = e.decode()?.into_owned();
-                    if !text.trim().is_empty() {
-                        self.process_text_content(
-                            text,
-                            &mut element_stack,
-                            &mut namespace_objects,
-                        )?;
+                    text.push_str(&e.decode()?);
+                }
+                Ok((_, Event::GeneralRef(e))) => {
+                    if let Some(ch) = e.resolve_char_ref()? {
+                        text.push(ch);
+                    } else if let Some(entity) = resolve_predefined_entity(&e.decode()?) {
+                        text.push_str(entity);
                     }
                 }
                 Ok((_, Event::End(_))) => {
+                    self.flush_text(&mut text, &mut element_stack, &mut namespace_objects)?;
+
                     // Check if element has rdf:resource value but no text content
                     // Following ExifTool XMP.pm:4136-4143: use rdf:resource as value for empty elements
                     if let Some(current) = element_stack.last() {
@@ -477,6 +524,8 @@ impl XmpProcessor {
                 // Handle self-closing elements like <cc:license rdf:resource="..."/>
                 // These generate Event::Empty instead of Start+End
                 Ok((ns_result, Event::Empty(e))) => {
+                    self.flush_text(&mut text, &mut element_stack, &mut namespace_objects)?;
+
                     let element_local_name = e.local_name();
                     let local_name = std::str::from_utf8(element_local_name.as_ref())
                         .context("Invalid UTF-8 in element name")?;
@@ -534,6 +583,22 @@ impl XmpProcessor {
         Ok(root_object)
     }
 
+    /// Store the text collected for the current element, if it isn't just
+    /// the whitespace between elements
+    fn flush_text(
+        &self,
+        text: &mut String,
+        element_stack: &mut [ElementContext],
+        namespace_objects: &mut HashMap<String, BTreeMap<String, TagValue>>,
+    ) -> Result<()> {
+        let value = text.trim().to_string();
+        text.clear();
+        if value.is_empty() {
+            return Ok(());
+        }
+        self.process_text_content(value, element_stack, namespace_objects)
+    }
+
     /// Process start element
     fn process_start_element(
         &mut self,
@@ -542,7 +607,7 @@ impl XmpProcessor {
         namespace_uri: Option<&str>,
         reader: &NsReader<&[u8]>,
         element_stack: &mut Vec<ElementContext>,
-        _namespace_objects: &mut HashMap<String, HashMap<String, TagValue>>,
+        namespace_objects: &mut HashMap<String, BTreeMap<String, TagValue>>,
     ) -> Result<()> {
         // Process namespace declarations from attributes
         for attr in element.attributes() {
@@ -620,6 +685,40 @@ impl XmpProcessor {
 
         element_stack.push(context);
 
+        // RDF shorthand: namespaced attributes are properties nested in this
+        // element (ExifTool ParseXMPElement), e.g. the GPano:ProjectionType="..."
+        // attributes written by most panorama tools
+        for attr in element.attributes() {
+            let attr = attr?;
+            let key = std::str::from_utf8(attr.key.as_ref())?;
+            if key.starts_with("xmlns") {
+                continue;
+            }
+            let (attr_ns, attr_local) = reader.resolver().resolve_attribute(attr.key);
+            let ResolveResult::Bound(Namespace(ns_bytes)) = attr_ns else {
+                continue;
+            };
+            let Some(prefix) = self.get_namespace_prefix(std::str::from_utf8(ns_bytes)?) else {
+                continue;
+            };
+            if prefix == "rdf" || prefix == "xml" {
+                continue;
+            }
+            element_stack.push(ElementContext {
+                local_name: std::str::from_utf8(attr_local.as_ref())?.to_string(),
+                namespace_prefix: Some(prefix),
+                container_type: None,
+                language: None,
+                rdf_resource: None,
+                has_text_content: false,
+                is_rdf_li: false,
+            });
+            let value = attr.normalized_value(XmlVersion::Implicit1_0)?.into_owned();
+            let result = self.process_text_content(value, element_stack, namespace_objects);
+            element_stack.pop();
+            result?;
+        }
+
         Ok(())
     }
 
@@ -634,7 +733,7 @@ impl XmpProcessor {
         &self,
         text: String,
         element_stack: &mut [ElementContext],
-        namespace_objects: &mut HashMap<String, HashMap<String, TagValue>>,
+        namespace_objects: &mut HashMap<String, BTreeMap<String, TagValue>>,
     ) -> Result<()> {
         if element_stack.len() < 2 {
             return Ok(()); // Not enough context
@@ -648,9 +747,7 @@ impl XmpProcessor {
 
         // Look up the flattened ID in generated tables to get canonical tag name
         // Example: "KeywordsHierarchyKeyword" → "HierarchicalKeywords1"
-        let tag_name = super::xmp_lookup::lookup_xmp_tag(&root_ns, &flattened_id)
-            .map(|info| info.name.to_string())
-            .unwrap_or_else(|| Self::ucfirst(&flattened_id));
+        let tag_name = Self::flattened_tag_name(&root_ns, &flattened_id);
 
         // Find container element for array/alt handling
         let container_element = element_stack
@@ -670,15 +767,13 @@ impl XmpProcessor {
                         .entry(tag_name)
                         .or_insert_with(|| TagValue::Array(Vec::new()));
 
-                    if let Some(arr) = array.as_array_mut() {
-                        arr.push(TagValue::string(text));
-                    }
+                    array.append(TagValue::string(text));
                 }
                 Some(RdfContainerType::Alt) => {
                     // Add to language alternatives object
                     let alt_object = ns_object
                         .entry(tag_name)
-                        .or_insert_with(|| TagValue::Object(HashMap::new()));
+                        .or_insert_with(|| TagValue::Object(BTreeMap::new()));
 
                     if let Some(obj) = alt_object.as_object_mut() {
                         let current = &element_stack[element_stack.len() - 1];
@@ -691,8 +786,14 @@ impl XmpProcessor {
                 }
             }
         } else {
-            // Simple property without container
-            ns_object.insert(tag_name, TagValue::string(text));
+            // Simple property without container. Repeated properties (the
+            // same property in several rdf:Descriptions) are all kept
+            match ns_object.entry(tag_name) {
+                Entry::Occupied(mut entry) => entry.get_mut().append(TagValue::string(text)),
+                Entry::Vacant(entry) => {
+                    entry.insert(TagValue::string(text));
+                }
+            }
         }
 
         // Mark this element as having received text content
@@ -711,7 +812,7 @@ impl XmpProcessor {
         &self,
         resource_value: String,
         element_stack: &[ElementContext],
-        namespace_objects: &mut HashMap<String, HashMap<String, TagValue>>,
+        namespace_objects: &mut HashMap<String, BTreeMap<String, TagValue>>,
     ) {
         if element_stack.len() < 2 {
             return; // Not enough context
@@ -723,9 +824,7 @@ impl XmpProcessor {
         };
 
         // Look up the flattened ID in generated tables to get canonical tag name
-        let tag_name = super::xmp_lookup::lookup_xmp_tag(&root_ns, &flattened_id)
-            .map(|info| info.name.to_string())
-            .unwrap_or_else(|| Self::ucfirst(&flattened_id));
+        let tag_name = Self::flattened_tag_name(&root_ns, &flattened_id);
 
         // Find container element for array/alt handling
         let container_element = element_stack
@@ -745,15 +844,13 @@ impl XmpProcessor {
                         .entry(tag_name)
                         .or_insert_with(|| TagValue::Array(Vec::new()));
 
-                    if let Some(arr) = array.as_array_mut() {
-                        arr.push(TagValue::string(resource_value));
-                    }
+                    array.append(TagValue::string(resource_value));
                 }
                 Some(RdfContainerType::Alt) => {
                     // Add to language alternatives object
                     let alt_object = ns_object
                         .entry(tag_name)
-                        .or_insert_with(|| TagValue::Object(HashMap::new()));
+                        .or_insert_with(|| TagValue::Object(BTreeMap::new()));
 
                     if let Some(obj) = alt_object.as_object_mut() {
                         let current = &element_stack[element_stack.len() - 1];
@@ -764,24 +861,36 @@ impl XmpProcessor {
                 None => {}
             }
         } else {
-            // Simple property without container
-            ns_object.insert(tag_name, TagValue::string(resource_value));
+            // Simple property without container. Repeated properties (the
+            // same property in several rdf:Descriptions) are all kept
+            match ns_object.entry(tag_name) {
+                Entry::Occupied(mut entry) => {
+                    entry.get_mut().append(TagValue::string(resource_value))
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(TagValue::string(resource_value));
+                }
+            }
         }
     }
 
     /// Get namespace prefix from URI
     /// Following ExifTool's approach, uses the generated reverse lookup table
     fn get_namespace_prefix(&self, uri: &str) -> Option<String> {
-        // Check our reverse URI to prefix mapping
-        // This includes all standard namespaces from generated tables
-        self.uri_to_prefix.get(uri).cloned()
+        // User-defined namespaces override the standard ones from the
+        // generated tables
+        match self.user_uri_to_prefix.get(uri) {
+            Some(prefix) => Some(prefix.clone()),
+            None => URI_TO_PREFIX.get(uri).map(|prefix| prefix.to_string()),
+        }
     }
 
     /// Build ExifTool-style flattened tag ID from element stack
     ///
     /// Following ExifTool's GetXMPTagID() (XMP.pm:2990-3043):
     /// - Concatenates property names with ucfirst() for PascalCase
-    /// - Skips RDF structural elements (li, Description, RDF, containers)
+    /// - Skips RDF structural elements (li, Description, RDF, containers) and
+    ///   anything else in the rdf namespace
     /// - Example: [xmpMM:History, stEvt:when] → "HistoryWhen"
     /// - Example: [mwg-kw:Keywords, Hierarchy, Keyword] → "KeywordsHierarchyKeyword"
     fn build_flattened_tag_id(element_stack: &[ElementContext]) -> Option<(String, String)> {
@@ -802,6 +911,12 @@ impl XmpProcessor {
                 continue;
             }
 
+            // Skip other RDF elements too, like the rdf:SphericalVideo typed
+            // node of Spherical Video V1 XML (ExifTool %ignoreNamespace)
+            if elem.namespace_prefix.as_deref() == Some("rdf") {
+                continue;
+            }
+
             // Skip container elements (Bag, Seq, Alt)
             if elem.container_type.is_some() {
                 continue;
@@ -834,6 +949,21 @@ impl XmpProcessor {
         }
     }
 
+    /// Tag name for a flattened property ID
+    ///
+    /// User-defined tags (see [`crate::user_config`]) take precedence over the
+    /// generated tables, as ExifTool's UserDefined tables do.
+    fn flattened_tag_name(namespace_prefix: &str, flattened_id: &str) -> String {
+        if let Some(name) = crate::user_config::config()
+            .and_then(|config| config.xmp_tag_name(namespace_prefix, flattened_id))
+        {
+            return name;
+        }
+        super::xmp_lookup::lookup_xmp_tag(namespace_prefix, flattened_id)
+            .map(|info| info.name.to_string())
+            .unwrap_or_else(|| Self::ucfirst(flattened_id))
+    }
+
     /// Capitalize first letter of a string (ExifTool's ucfirst equivalent)
     fn ucfirst(s: &str) -> String {
         let mut chars = s.chars();
@@ -908,6 +1038,24 @@ enum RdfContainerType {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_shared_namespace_prefixes() {
+        let processor = XmpProcessor::new();
+        assert_eq!(
+            processor
+                .get_namespace_prefix("http://purl.org/dc/elements/1.1/")
+                .as_deref(),
+            Some("dc")
+        );
+        assert_eq!(
+            processor
+                .get_namespace_prefix("http://ns.exiftool.org/1.0/")
+                .as_deref(),
+            Some("et")
+        );
+        assert_eq!(processor.get_namespace_prefix("http://example.com/"), None);
+    }
+
     #[test]
     fn test_minimal_xmp() {
         let xmp_data = r#"<?xml version="1.0"?>
@@ -999,8 +1147,8 @@ mod tests {
         assert!(!result.is_empty(), "Should have individual XMP tag entries");
 
         // Create a map for easier lookup
-        let tag_map: std::collections::HashMap<String, &TagEntry> =
-            result.iter().map(|tag| (tag.name.clone(), tag)).collect();
+        let tag_map: std::collections::HashMap<&str, &TagEntry> =
+            result.iter().map(|tag| (tag.name.as_str(), tag)).collect();
 
         // Validate Dublin Core namespace tags
         assert!(
@@ -1228,6 +1376,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_repeated_properties_and_entities() {
+        let xmp_data = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/">
+  <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <rdf:Description xmlns:dc="http://purl.org/dc/elements/1.1/"
+        xmlns:photoshop="http://ns.adobe.com/photoshop/1.0/">
+      <dc:subject><rdf:Bag><rdf:li>Salt &amp; Pepper</rdf:li><rdf:li>Sea</rdf:li></rdf:Bag></dc:subject>
+      <photoshop:City>Paris</photoshop:City>
+    </rdf:Description>
+    <rdf:Description xmlns:photoshop="http://ns.adobe.com/photoshop/1.0/">
+      <photoshop:City>Lyon</photoshop:City>
+    </rdf:Description>
+  </rdf:RDF>
+</x:xmpmeta>"#;
+
+        let mut processor = XmpProcessor::new();
+        let tags = processor
+            .process_xmp_data_individual(xmp_data.as_bytes())
+            .unwrap();
+        let value = |name: &str| &tags.iter().find(|t| t.name == name).unwrap().value;
+
+        assert_eq!(
+            value("Subject"),
+            &TagValue::Array(vec![
+                TagValue::string("Salt & Pepper"),
+                TagValue::string("Sea")
+            ])
+        );
+        assert_eq!(
+            value("City"),
+            &TagValue::Array(vec![TagValue::string("Paris"), TagValue::string("Lyon")])
+        );
+    }
+
     #[test]
     fn test_rdf_resource_extraction() {
         // Test extraction of rdf:resource attribute values
@@ -1279,4 +1461,39 @@ mod tests {
             panic!("Expected Object, got {:?}", result.value);
         }
     }
+
+    #[test]
+    fn test_gpano_photo_sphere() {
+        // Attribute form, as written by Google Camera and most stitchers
+        let xmp_data = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/">
+  <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+    <rdf:Description xmlns:GPano="http://ns.google.com/photos/1.0/panorama/"
+        GPano:UsePanoramaViewer="True"
+        GPano:ProjectionType="equirectangular"
+        GPano:FullPanoWidthPixels="8192"
+        GPano:PoseHeadingDegrees="350.5"
+        GPano:FirstPhotoDate="2024-05-06T07:08:09.123Z"/>
+  </rdf:RDF>
+</x:xmpmeta>"#;
+
+        let mut processor = XmpProcessor::new();
+        let tags = processor
+            .process_xmp_data_individual(xmp_data.as_bytes())
+            .unwrap();
+        let get = |name: &str| {
+            tags.iter()
+                .find(|t| t.name == name)
+                .map(|t| t.value.clone())
+                .unwrap_or_else(|| panic!("missing {name}"))
+        };
+        assert_eq!(get("UsePanoramaViewer"), TagValue::string("True"));
+        assert_eq!(get("ProjectionType"), TagValue::string("equirectangular"));
+        assert_eq!(get("FullPanoWidthPixels"), TagValue::string("8192"));
+        assert_eq!(get("PoseHeadingDegrees"), TagValue::string("350.5"));
+        // Writable 'date' goes through ConvertXMPDate
+        assert_eq!(
+            get("FirstPhotoDate"),
+            TagValue::string("2024:05:06 07:08:09.123Z")
+        );
+    }
 }
@@ -0,0 +1,258 @@
+//! xmpMM provenance parsing
+//!
+//! Reads the [`XmpProvenance`] properties from an XMP packet. The generic
+//! [`super::XmpProcessor`] flattens structures into tags; this walks the
+//! packet again keeping each xmpMM:History entry and the xmpMM:DerivedFrom
+//! reference together, in any of the RDF forms writers use (child elements,
+//! `rdf:parseType="Resource"`, nested `rdf:Description`, or properties
+//! written as attributes).
+
+use anyhow::Result;
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::name::{Namespace, ResolveResult};
+use quick_xml::reader::NsReader;
+use quick_xml::XmlVersion;
+
+use crate::types::{HistoryEvent, ResourceRef, XmpProvenance};
+
+const XMP_MM_NS: &str = "http://ns.adobe.com/xap/1.0/mm/";
+const ST_REF_NS: &str = "http://ns.adobe.com/xap/1.0/sType/ResourceRef#";
+const ST_EVT_NS: &str = "http://ns.adobe.com/xap/1.0/sType/ResourceEvent#";
+const RDF_NS: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const X_NS: &str = "adobe:ns:meta/";
+
+/// A property element or attribute, by namespace URI and local name
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Property {
+    namespace: String,
+    name: String,
+}
+
+impl Property {
+    fn is(&self, namespace: &str, name: &str) -> bool {
+        self.namespace == namespace && self.name == name
+    }
+}
+
+/// Read the xmpMM provenance from an XMP packet
+///
+/// Packets without the xmpMM namespace aren't parsed and give an empty
+/// [`XmpProvenance`].
+pub fn parse_xmp_provenance(xml: &str) -> Result<XmpProvenance> {
+    let mut provenance = XmpProvenance::default();
+    if !xml.contains(XMP_MM_NS) {
+        return Ok(provenance);
+    }
+
+    let mut reader = NsReader::from_str(xml);
+    let mut buf = Vec::new();
+    // Property path of each open element; None for RDF and wrapper elements
+    let mut stack: Vec<Option<Property>> = Vec::new();
+    let mut text = String::new();
+
+    loop {
+        match reader.read_resolved_event_into(&mut buf)? {
+            (ns, Event::Start(e)) => {
+                text.clear();
+                let property = element_property(ns, &e)?;
+                start_element(&reader, &e, property, &mut stack, &mut provenance)?;
+            }
+            (ns, Event::Empty(e)) => {
+                let property = element_property(ns, &e)?;
+                start_element(&reader, &e, property, &mut stack, &mut provenance)?;
+                stack.pop();
+            }
+            (_, Event::Text(e)) => text.push_str(&e.decode()?),
+            (_, Event::GeneralRef(e)) => {
+                if let Some(ch) = e.resolve_char_ref()? {
+                    text.push(ch);
+                } else if let Some(entity) = resolve_predefined_entity(&e.decode()?) {
+                    text.push_str(entity);
+                }
+            }
+            (_, Event::End(_)) => {
+                let value = text.trim();
+                if !value.is_empty() {
+                    set_property(&mut provenance, &path(&stack), value);
+                }
+                text.clear();
+                stack.pop();
+            }
+            (_, Event::Eof) => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(provenance)
+}
+
+/// The property an element names, or None for RDF structure and wrappers
+fn element_property(ns: ResolveResult, element: &BytesStart) -> Result<Option<Property>> {
+    let ResolveResult::Bound(Namespace(ns_bytes)) = ns else {
+        return Ok(None);
+    };
+    let namespace = std::str::from_utf8(ns_bytes)?;
+    if namespace == RDF_NS || namespace == X_NS {
+        return Ok(None);
+    }
+    Ok(Some(Property {
+        namespace: namespace.to_string(),
+        name: std::str::from_utf8(element.local_name().as_ref())?.to_string(),
+    }))
+}
+
+/// Push an element, starting a new history event at each xmpMM:History
+/// list item, and store the properties written as its attributes
+fn start_element(
+    reader: &NsReader<&[u8]>,
+    element: &BytesStart,
+    property: Option<Property>,
+    stack: &mut Vec<Option<Property>>,
+    provenance: &mut XmpProvenance,
+) -> Result<()> {
+    let is_list_item = property.is_none() && element.local_name().as_ref() == b"li";
+    stack.push(property);
+    let path = path(stack);
+    if is_list_item && path.len() == 1 && path[0].is(XMP_MM_NS, "History") {
+        provenance.history.push(HistoryEvent::default());
+    }
+
+    for attr in element.attributes() {
+        let attr = attr?;
+        let (attr_ns, attr_local) = reader.resolver().resolve_attribute(attr.key);
+        let ResolveResult::Bound(Namespace(ns_bytes)) = attr_ns else {
+            continue;
+        };
+        let namespace = std::str::from_utf8(ns_bytes)?;
+        if namespace == RDF_NS || namespace == X_NS {
+            continue;
+        }
+        let mut attr_path = path.clone();
+        attr_path.push(Property {
+            namespace: namespace.to_string(),
+            name: std::str::from_utf8(attr_local.as_ref())?.to_string(),
+        });
+        let value = attr.normalized_value(XmlVersion::Implicit1_0)?;
+        set_property(provenance, &attr_path, value.trim());
+    }
+    Ok(())
+}
+
+/// The property path of the innermost element
+fn path(stack: &[Option<Property>]) -> Vec<Property> {
+    stack.iter().flatten().cloned().collect()
+}
+
+fn set_property(provenance: &mut XmpProvenance, path: &[Property], value: &str) {
+    let value = Some(value.to_string());
+    match path {
+        [property] if property.namespace == XMP_MM_NS => match property.name.as_str() {
+            "DocumentID" => provenance.document_id = value,
+            "InstanceID" => provenance.instance_id = value,
+            "OriginalDocumentID" => provenance.original_document_id = value,
+            _ => {}
+        },
+        [parent, field] if parent.is(XMP_MM_NS, "DerivedFrom") && field.namespace == ST_REF_NS => {
+            let derived_from = provenance
+                .derived_from
+                .get_or_insert_with(ResourceRef::default);
+            match field.name.as_str() {
+                "documentID" => derived_from.document_id = value,
+                "instanceID" => derived_from.instance_id = value,
+                "originalDocumentID" => derived_from.original_document_id = value,
+                "filePath" => derived_from.file_path = value,
+                "renditionClass" => derived_from.rendition_class = value,
+                _ => {}
+            }
+        }
+        [parent, field] if parent.is(XMP_MM_NS, "History") && field.namespace == ST_EVT_NS => {
+            let Some(event) = provenance.history.last_mut() else {
+                return;
+            };
+            match field.name.as_str() {
+                "action" => event.action = value,
+                "changed" => event.changed = value,
+                "instanceID" => event.instance_id = value,
+                "parameters" => event.parameters = value,
+                "softwareAgent" => event.software_agent = value,
+                "when" => event.when = value,
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_xmp_provenance() {
+        let xml = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/">
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+ <rdf:Description rdf:about=""
+   xmlns:stEvt="http://ns.adobe.com/xap/1.0/sType/ResourceEvent#"
+   xmlns:stRef="http://ns.adobe.com/xap/1.0/sType/ResourceRef#"
+   xmlns:xmpMM="http://ns.adobe.com/xap/1.0/mm/"
+   xmpMM:DocumentID="xmp.did:child" xmpMM:OriginalDocumentID="xmp.did:orig">
+  <xmpMM:InstanceID>xmp.iid:2</xmpMM:InstanceID>
+  <xmpMM:DerivedFrom stRef:documentID="xmp.did:parent" stRef:instanceID="xmp.iid:1"/>
+  <xmpMM:History>
+   <rdf:Seq>
+    <rdf:li rdf:parseType="Resource">
+     <stEvt:action>created</stEvt:action>
+     <stEvt:softwareAgent>Adobe Photoshop Lightroom Classic 13.0 (Macintosh)</stEvt:softwareAgent>
+    </rdf:li>
+    <rdf:li stEvt:action="saved" stEvt:changed="/metadata" stEvt:when="2024-01-16T11:00:00Z"/>
+    <rdf:li>
+     <rdf:Description stEvt:action="derived">
+      <stEvt:parameters>converted from image/tiff to image/jpeg</stEvt:parameters>
+     </rdf:Description>
+    </rdf:li>
+   </rdf:Seq>
+  </xmpMM:History>
+ </rdf:Description>
+</rdf:RDF>
+</x:xmpmeta>"#;
+        let provenance = parse_xmp_provenance(xml).unwrap();
+        assert_eq!(provenance.document_id.as_deref(), Some("xmp.did:child"));
+        assert_eq!(provenance.instance_id.as_deref(), Some("xmp.iid:2"));
+        assert_eq!(
+            provenance.derived_from,
+            Some(ResourceRef {
+                document_id: Some("xmp.did:parent".into()),
+                instance_id: Some("xmp.iid:1".into()),
+                ..Default::default()
+            })
+        );
+        let actions: Vec<_> = provenance
+            .history
+            .iter()
+            .map(|event| event.action.as_deref())
+            .collect();
+        assert_eq!(actions, [Some("created"), Some("saved"), Some("derived")]);
+        // Fields stay with their own event
+        assert_eq!(provenance.history[0].when, None);
+        assert_eq!(
+            provenance.history[1].when.as_deref(),
+            Some("2024-01-16T11:00:00Z")
+        );
+        assert_eq!(
+            provenance.history[2].parameters.as_deref(),
+            Some("converted from image/tiff to image/jpeg")
+        );
+        assert_eq!(
+            provenance.last_software_agent(),
+            Some("Adobe Photoshop Lightroom Classic 13.0 (Macintosh)")
+        );
+
+        assert!(
+            parse_xmp_provenance("<x:xmpmeta xmlns:x='adobe:ns:meta/'/>")
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -27,6 +27,11 @@ use crate::generated::XMP_pm::{
 // Import MWG namespace tables
 use crate::generated::MWG_pm::{keywords_tags::XMP_MWG_KW_TAGS, regions_tags::XMP_MWG_RS_TAGS};
 
+// Hand-written tables for namespaces codegen doesn't cover yet
+use super::gcamera::{XMP_GCAMERA_TAGS, XMP_GCONTAINER_TAGS};
+use super::gpano::XMP_GPANO_TAGS;
+use super::gspherical::XMP_GSPHERICAL_TAGS;
+
 /// Look up XMP tag information from generated tables
 ///
 /// Maps namespace prefix + property name to XmpTagInfo.
@@ -55,6 +60,20 @@ pub fn lookup_xmp_tag_by_name(namespace: &str, name: &str) -> Option<&'static Xm
         .find(|info| info.name == name)
 }
 
+/// Find the property of a namespace whose tag name is `name`, ignoring case
+///
+/// Returns the property name as written in XMP with its tag info, for
+/// writers that are given ExifTool tag names (`Creator` is `dc:creator`).
+pub fn find_xmp_property(
+    namespace: &str,
+    name: &str,
+) -> Option<(&'static str, &'static XmpTagInfo)> {
+    namespace_table(namespace)?
+        .iter()
+        .find(|(_, info)| info.name.eq_ignore_ascii_case(name))
+        .map(|(property, info)| (*property, info))
+}
+
 /// Resolve a namespace prefix to its generated tag table.
 fn namespace_table(namespace: &str) -> Option<&'static HashMap<&'static str, XmpTagInfo>> {
     let table: &'static LazyLock<HashMap<&'static str, XmpTagInfo>> = match namespace {
@@ -88,6 +107,16 @@ fn namespace_table(namespace: &str) -> Option<&'static HashMap<&'static str, Xmp
         // iView MediaPro (from XMP2.pl)
         "mediapro" => &XMP_MEDIAPRO_TAGS,
 
+        // Google Photo Sphere (from XMP2.pl, hand-written)
+        "GPano" => &XMP_GPANO_TAGS,
+
+        // Google Spherical Video V1 (from XMP2.pl, hand-written)
+        "GSpherical" => &XMP_GSPHERICAL_TAGS,
+
+        // Google camera and motion photo container (from XMP2.pl, hand-written)
+        "GCamera" => &XMP_GCAMERA_TAGS,
+        "GContainer" => &XMP_GCONTAINER_TAGS,
+
         // Metadata Working Group (from MWG.pm)
         "mwg-rs" => &XMP_MWG_RS_TAGS,
         "mwg-kw" => &XMP_MWG_KW_TAGS,
@@ -0,0 +1,190 @@
+//! CLI binary extraction (`-b -PreviewImage`) tests
+//!
+//! Binary mode extracts the file once, with the image's offset/length tags
+//! requested alongside it, and streams the image bytes from that extraction.
+//! Other binary tags, like ICC_Profile and an Android motion photo's
+//! MotionPhotoVideo, keep their bytes in the extraction.
+
+mod common;
+
+use std::io::Write;
+use std::process::Command;
+
+use tempfile::NamedTempFile;
+
+/// Run `exif-oxide -b -<tag> <path>` and return its stdout
+fn extract_binary(tag: &str, path: &std::path::Path) -> Vec<u8> {
+    let output = Command::new(env!("CARGO_BIN_EXE_exif-oxide"))
+        .arg("-b")
+        .arg(format!("-{tag}"))
+        .arg(path)
+        .output()
+        .expect("failed to run exif-oxide");
+    assert!(
+        output.status.success(),
+        "exif-oxide -b -{tag} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    output.stdout
+}
+
+/// A little-endian TIFF whose IFD1 points at a small JPEG thumbnail
+fn tiff_with_thumbnail(thumbnail: &[u8]) -> Vec<u8> {
+    let short = |tag: u16, value: u16| {
+        let mut entry = tag.to_le_bytes().to_vec();
+        entry.extend([3, 0, 1, 0, 0, 0]);
+        entry.extend(value.to_le_bytes());
+        entry.extend([0, 0]);
+        entry
+    };
+    let long = |tag: u16, value: u32| {
+        let mut entry = tag.to_le_bytes().to_vec();
+        entry.extend([4, 0, 1, 0, 0, 0]);
+        entry.extend(value.to_le_bytes());
+        entry
+    };
+    // Header, IFD0 with one entry, IFD1 with three, then the thumbnail
+    let ifd1_offset = 8 + 2 + 12 + 4;
+    let thumbnail_offset = ifd1_offset + 2 + 3 * 12 + 4;
+
+    let mut tiff = b"II\x2a\0\x08\0\0\0".to_vec();
+    tiff.extend(1u16.to_le_bytes());
+    tiff.extend(short(0x0112, 1)); // Orientation
+    tiff.extend((ifd1_offset as u32).to_le_bytes());
+    tiff.extend(3u16.to_le_bytes());
+    tiff.extend(short(0x0103, 6)); // Compression: JPEG
+    tiff.extend(long(0x0201, thumbnail_offset as u32)); // ThumbnailOffset
+    tiff.extend(long(0x0202, thumbnail.len() as u32)); // ThumbnailLength
+    tiff.extend([0; 4]);
+    tiff.extend(thumbnail);
+    tiff
+}
+
+#[test]
+fn test_thumbnail_from_single_extraction() {
+    let thumbnail = b"\xff\xd8\xff\xdbthumbnail bytes\xff\xd9";
+    let mut file = NamedTempFile::with_suffix(".tif").unwrap();
+    file.write_all(&tiff_with_thumbnail(thumbnail)).unwrap();
+
+    assert_eq!(extract_binary("ThumbnailImage", file.path()), thumbnail);
+}
+
+/// A JPEG whose ICC profile is split across APP2 segments of `chunk_size`
+fn jpeg_with_icc_profile(profile: &[u8], chunk_size: usize) -> Vec<u8> {
+    let chunks: Vec<&[u8]> = profile.chunks(chunk_size).collect();
+    let mut jpeg = vec![0xFF, 0xD8];
+    for (index, chunk) in chunks.iter().enumerate() {
+        let length = 2 + 14 + chunk.len();
+        jpeg.extend([0xFF, 0xE2, (length >> 8) as u8, length as u8]);
+        jpeg.extend(b"ICC_PROFILE\0");
+        jpeg.extend([index as u8 + 1, chunks.len() as u8]);
+        jpeg.extend(*chunk);
+    }
+    // SOF0: 8 bits, 1x1, one component
+    jpeg.extend([0xFF, 0xC0, 0, 11, 8, 0, 1, 0, 1, 1, 1, 0x11, 0]);
+    jpeg.extend([0xFF, 0xD9]);
+    jpeg
+}
+
+#[test]
+fn test_icc_profile_from_chunks() {
+    let profile: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8).collect();
+    let mut file = NamedTempFile::with_suffix(".jpg").unwrap();
+    file.write_all(&jpeg_with_icc_profile(&profile, 300))
+        .unwrap();
+
+    assert_eq!(extract_binary("ICC_Profile", file.path()), profile);
+    assert_eq!(
+        exif_oxide::formats::extract_binary_tag(file.path(), "ICC_Profile").unwrap(),
+        profile
+    );
+}
+
+/// A Motion Photo v2 JPEG: the XMP container directory gives the length of
+/// the MP4 appended after the image
+#[cfg(feature = "xmp")]
+fn motion_photo(video: &[u8]) -> Vec<u8> {
+    let xmp = format!(
+        r#"<x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+<rdf:Description rdf:about="" xmlns:GCamera="http://ns.google.com/photos/1.0/camera/"
+ xmlns:Container="http://ns.google.com/photos/1.0/container/"
+ xmlns:Item="http://ns.google.com/photos/1.0/container/item/" GCamera:MotionPhoto="1">
+<Container:Directory><rdf:Seq>
+<rdf:li rdf:parseType="Resource"><Container:Item Item:Mime="image/jpeg" Item:Semantic="Primary"/></rdf:li>
+<rdf:li rdf:parseType="Resource"><Container:Item Item:Mime="video/mp4" Item:Semantic="MotionPhoto" Item:Length="{}"/></rdf:li>
+</rdf:Seq></Container:Directory></rdf:Description></rdf:RDF></x:xmpmeta>"#,
+        video.len()
+    );
+    let length = 2 + 29 + xmp.len();
+    let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE1, (length >> 8) as u8, length as u8];
+    jpeg.extend(b"http://ns.adobe.com/xap/1.0/\0");
+    jpeg.extend(xmp.as_bytes());
+    jpeg.extend([0xFF, 0xC0, 0, 11, 8, 0, 1, 0, 1, 1, 1, 0x11, 0]);
+    jpeg.extend([0xFF, 0xD9]);
+    jpeg.extend(video);
+    jpeg
+}
+
+#[cfg(feature = "xmp")]
+#[test]
+fn test_motion_photo_video() {
+    let mut video = vec![0, 0, 0, 16];
+    video.extend(b"ftypisom\0\0\0\0");
+    video.extend((0..500u32).map(|i| (i % 251) as u8));
+    let mut file = NamedTempFile::with_suffix(".jpg").unwrap();
+    file.write_all(&motion_photo(&video)).unwrap();
+
+    assert_eq!(extract_binary("MotionPhotoVideo", file.path()), video);
+    assert_eq!(
+        exif_oxide::formats::extract_binary_tag(file.path(), "MotionPhotoVideo").unwrap(),
+        video
+    );
+}
+
+#[test]
+fn test_unsupported_binary_tag() {
+    let mut file = NamedTempFile::with_suffix(".tif").unwrap();
+    file.write_all(&tiff_with_thumbnail(b"\xff\xd8\xff\xd9"))
+        .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_exif-oxide"))
+        .args(["-b", "-Make"])
+        .arg(file.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("not supported"));
+}
+
+/// The preview of a RAW file is a complete JPEG
+#[cfg(feature = "integration-tests")]
+fn assert_jpeg_preview(path: &str) {
+    let preview = extract_binary("PreviewImage", std::path::Path::new(path));
+    assert!(preview.len() > 1024, "{path}: preview too small");
+    assert!(preview.starts_with(b"\xff\xd8"), "{path}: no JPEG SOI");
+    assert!(preview.ends_with(b"\xff\xd9"), "{path}: no JPEG EOI");
+}
+
+#[cfg(feature = "integration-tests")]
+#[test]
+fn test_canon_cr2_preview() {
+    assert_jpeg_preview(common::CANON_T3I_CR2);
+}
+
+#[cfg(feature = "integration-tests")]
+#[test]
+fn test_nikon_nef_preview() {
+    assert_jpeg_preview(common::NIKON_Z8_NEF);
+}
+
+#[cfg(feature = "integration-tests")]
+#[test]
+fn test_sony_arw_preview() {
+    assert_jpeg_preview(common::SONY_A7C_ARW);
+}
+
+#[cfg(feature = "integration-tests")]
+#[test]
+fn test_panasonic_rw2_preview() {
+    assert_jpeg_preview(common::PANASONIC_G9_II_RW2);
+}
@@ -70,15 +70,15 @@ fn test_compatibility_flags_ignored() {
 
 #[test]
 fn test_short_invalid_filters_error() {
-    // Test that short invalid filters like -a, -xy cause errors
+    // Test that short invalid filters like -xy cause errors
     let test_file = "/tmp/test_nonexistent.jpg";
 
-    let output = run_exif_oxide(&[test_file, "-a"]);
+    let output = run_exif_oxide(&[test_file, "-xy"]);
 
     // Should fail due to the short invalid flag
     assert!(!output.status.success());
     let stderr = String::from_utf8(output.stderr).expect("Invalid UTF-8");
-    assert!(stderr.contains("Unknown option -a"));
+    assert!(stderr.contains("Unknown option -xy"));
 }
 
 #[test]
@@ -189,6 +189,110 @@ fn test_help_flag_exits_successfully() {
     assert!(stdout.contains("Options:"));
 }
 
+#[test]
+fn test_exit_status() {
+    use std::io::Write;
+
+    // Bad command lines exit with 2
+    let output = run_exif_oxide(&["/tmp/test_nonexistent.jpg", "-xy"]);
+    assert_eq!(output.status.code(), Some(2));
+
+    // A file that can't be read exits with 1, after the other files' output
+    let mut mislabeled = tempfile::Builder::new().suffix(".png").tempfile().unwrap();
+    mislabeled
+        .write_all(b"\xff\xd8\xff\xe0\0\x10JFIF\0\x01\x01\0\0\x01\0\x01\0\0\xff\xd9")
+        .unwrap();
+    let path = mislabeled.path().to_str().unwrap();
+    let output = run_exif_oxide(&[path, "/tmp/test_nonexistent.jpg"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).expect("Invalid UTF-8");
+    assert!(stdout.contains("image/jpeg"));
+
+    // Warnings (here, the wrong extension) only fail with --warnings-as-errors
+    let output = run_exif_oxide(&[path]);
+    assert_eq!(output.status.code(), Some(0));
+    let output = run_exif_oxide(&["--warnings-as-errors", path]);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_stay_open_commands() {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let mut image = tempfile::Builder::new().suffix(".jpg").tempfile().unwrap();
+    image
+        .write_all(b"\xff\xd8\xff\xe0\0\x10JFIF\0\x01\x01\0\0\x01\0\x01\0\0\xff\xd9")
+        .unwrap();
+    let path = image.path().to_str().unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_exif-oxide"))
+        .args(["-stay_open", "True", "-@", "-", "-common_args", "-MIMEType"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute exif-oxide");
+    let commands = format!(
+        "{path}\n-execute\n-FileType\n{path}\n-execute7\n-xy\n-execute\n-stay_open\nFalse\n"
+    );
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(commands.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).expect("Invalid UTF-8");
+    let outputs: Vec<_> = stdout.split("{ready").collect();
+    let ready: Vec<_> = stdout
+        .lines()
+        .filter(|line| line.starts_with("{ready"))
+        .collect();
+    assert_eq!(ready, ["{ready}", "{ready7}", "{ready}"], "{stdout}");
+    assert!(outputs[0].contains("image/jpeg") && !outputs[0].contains("FileType"));
+    assert!(outputs[1].contains("\"File:FileType\": \"JPEG\""));
+    // The bad command is reported, and the process carries on
+    let stderr = String::from_utf8(output.stderr).expect("Invalid UTF-8");
+    assert!(stderr.contains("Unknown option -xy"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_symlinks_and_special_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let image = dir.path().join("photo.jpg");
+    std::fs::write(
+        &image,
+        b"\xff\xd8\xff\xe0\0\x10JFIF\0\x01\x01\0\0\x01\0\x01\0\0\xff\xd9",
+    )
+    .unwrap();
+    let link = dir.path().join("link.jpg");
+    std::os::unix::fs::symlink(&image, &link).unwrap();
+    let link = link.to_str().unwrap();
+
+    // Links are followed by default, and reported themselves on request
+    let output = run_exif_oxide_stdout(&["-FileType", link]);
+    assert!(output.contains("\"File:FileType\": \"JPEG\""), "{output}");
+    let output = run_exif_oxide_stdout(&["--symlinks", "report", "-FileType", link]);
+    assert!(output.contains("\"File:FileType\": \"LINK\""), "{output}");
+
+    // A named pipe is reported as an error instead of blocking the batch
+    let fifo = dir.path().join("pipe.jpg");
+    let status = Command::new("mkfifo").arg(&fifo).status().unwrap();
+    assert!(status.success());
+    let output = run_exif_oxide(&[fifo.to_str().unwrap(), image.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).expect("Invalid UTF-8");
+    assert!(
+        stdout.contains("Not a regular file (named pipe)"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("image/jpeg"));
+}
+
 #[cfg(test)]
 mod edge_cases {
     use super::*;
@@ -60,7 +60,7 @@ fn test_numeric_value_control() {
     assert_eq!(result.tags.len(), 1);
     assert_eq!(result.tags[0].name, "Orientation");
     // Should be numeric, not "Rotate 270 CW"
-    match &result.tags[0].print {
+    match result.tags[0].print() {
         TagValue::U8(8) | TagValue::U16(8) => (), // Expected numeric value 8
         other => panic!("Expected numeric value 8, got: {:?}", other),
     }
@@ -226,7 +226,7 @@ fn test_middle_wildcard_date_pattern() {
     }
 
     // Verify we get specific expected date tags
-    let tag_names: Vec<String> = result.tags.iter().map(|t| t.name.clone()).collect();
+    let tag_names: Vec<String> = result.tags.iter().map(|t| t.name.to_string()).collect();
     assert!(tag_names.contains(&"CreateDate".to_string()));
     assert!(tag_names.contains(&"DateTimeOriginal".to_string()));
     assert!(tag_names.contains(&"ModifyDate".to_string()));
@@ -264,7 +264,7 @@ fn test_complex_filtering_combination() {
         .iter()
         .find(|t| t.name == "Orientation")
         .unwrap();
-    match &orientation_tag.print {
+    match orientation_tag.print() {
         TagValue::U8(_) | TagValue::U16(_) => (), // Expected numeric value
         other => panic!("Expected numeric Orientation, got: {:?}", other),
     }
@@ -126,7 +126,7 @@ fn test_group0_assignments_match_exiftool() {
     let mut group0_mismatches = Vec::new();
 
     for tag in &exif_data.tags {
-        if let Some(exiftool_group0) = exiftool_groups.group0_assignments.get(&tag.name) {
+        if let Some(exiftool_group0) = exiftool_groups.group0_assignments.get(tag.name.as_str()) {
             total_compared += 1;
 
             if &tag.group == exiftool_group0 {
@@ -192,7 +192,7 @@ fn test_group1_assignments_match_exiftool() {
     let mut exif_ifd_mismatches = 0;
 
     for tag in &exif_data.tags {
-        if let Some(exiftool_group1) = exiftool_groups.group1_assignments.get(&tag.name) {
+        if let Some(exiftool_group1) = exiftool_groups.group1_assignments.get(tag.name.as_str()) {
             total_compared += 1;
 
             if &tag.group1 == exiftool_group1 {
@@ -279,7 +279,7 @@ fn test_group2_full_hierarchy_compatibility() {
     let mut hierarchy_patterns = HashMap::new();
 
     for tag in &exif_data.tags {
-        if let Some(exiftool_group2) = exiftool_groups.group2_assignments.get(&tag.name) {
+        if let Some(exiftool_group2) = exiftool_groups.group2_assignments.get(tag.name.as_str()) {
             let our_hierarchy = format!("{}:{}", tag.group, tag.group1);
 
             *hierarchy_patterns
@@ -84,7 +84,7 @@ fn test_group1_assignments_match_exiftool_canon() {
         // Count our group1 assignments for statistics
         *our_group1_stats.entry(tag.group1.clone()).or_insert(0) += 1;
 
-        if let Some(exiftool_group1) = exiftool_group1.get(&tag.name) {
+        if let Some(exiftool_group1) = exiftool_group1.get(tag.name.as_str()) {
             total_compared += 1;
 
             if &tag.group1 != exiftool_group1 {
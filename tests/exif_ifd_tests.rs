@@ -244,7 +244,7 @@ fn test_exif_ifd_vs_main_ifd_distinction() {
 
     for tag in &exif_data.tags {
         tags_by_group1
-            .entry(tag.group1.clone())
+            .entry(tag.group1.to_string())
             .or_default()
             .push(&tag.name);
     }
@@ -12,13 +12,14 @@ fn test_exposuretime_printconv_json_formatting() {
         exif_oxide::types::ExifData::new("test.jpg".to_string(), "0.1.0-oxide".to_string());
 
     // Add an ExposureTime tag entry with rational value
-    let exposure_entry = exif_oxide::types::TagEntry {
-        group: "EXIF".to_string(),
-        group1: "ExifIFD".to_string(),
-        name: "ExposureTime".to_string(),
-        value: TagValue::Rational(1, 100),
-        print: TagValue::string("1/100"), // PrintConv produces a string for ExposureTime
-    };
+    // PrintConv produces a string for ExposureTime
+    let exposure_entry = exif_oxide::types::TagEntry::new(
+        "EXIF",
+        "ExifIFD",
+        "ExposureTime",
+        TagValue::Rational(1, 100),
+        TagValue::string("1/100"),
+    );
 
     exif_data.tags = vec![exposure_entry];
 
@@ -5,6 +5,8 @@
 //! This test specifically validates that canonLensTypes lookup functionality works,
 //! which was identified as the critical blocking issue that needed to be resolved.
 
+#![cfg(feature = "canon")]
+
 #[test]
 fn test_canon_lens_lookup_functionality() {
     // Test that canonLensTypes lookup works for the specific example mentioned in TPP
@@ -1,5 +1,6 @@
 use exif_oxide::exif::ExifReader;
 
+#[cfg(feature = "canon")]
 #[test]
 fn test_canon_lens_lookup() {
     // P07: Unified completion - see docs/todo/P07-unified-codegen-completion.md
@@ -101,7 +101,7 @@ fn test_nikon_xlat_arrays_generated() {
 }
 
 #[test]
-#[cfg(feature = "integration-tests")]
+#[cfg(all(feature = "integration-tests", feature = "nikon"))]
 fn test_xlat_arrays_can_be_imported() {
     // This test actually imports the generated constants to verify they work
     // It requires the test-helpers feature to access the generated modules
@@ -45,7 +45,7 @@ fn test_sony_tag_prefix_behavior() {
             .tags
             .iter()
             .find(|tag| format!("{}:{}", tag.group, tag.name) == name)
-            .map(|tag| tag.print.to_string())
+            .map(|tag| tag.print().to_string())
     };
 
     // Test 1: Count generic Tag_ entries
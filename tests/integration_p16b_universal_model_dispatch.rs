@@ -37,7 +37,7 @@ fn test_canon_t3i_model_based_camerainfo_dispatch() {
             .tags
             .iter()
             .find(|tag| format!("{}:{}", tag.group, tag.name) == name)
-            .map(|tag| tag.print.to_string())
+            .map(|tag| tag.print().to_string())
     };
 
     // Verify model is extracted correctly for dispatch
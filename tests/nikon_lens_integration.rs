@@ -3,6 +3,8 @@
 //! This test validates that the generated Nikon lens database works
 //! correctly with actual lens metadata extracted from a real Nikon image.
 
+#![cfg(feature = "nikon")]
+
 use exif_oxide::implementations::nikon::lens_database::{get_database_stats, lookup_nikon_lens};
 
 #[test]
@@ -78,7 +80,7 @@ fn test_nikon_z8_lens_extraction() {
                 if !lens_tags.is_empty() {
                     println!("Found {} lens-related tags:", lens_tags.len());
                     for tag in &lens_tags {
-                        println!("  {}: {} = {}", tag.group, tag.name, tag.print);
+                        println!("  {}: {} = {}", tag.group, tag.name, tag.print());
                     }
 
                     // The Z8 should have lens information extracted
@@ -94,7 +96,7 @@ fn test_nikon_z8_lens_extraction() {
                     // Show what tags we did extract for debugging
                     println!("Tags extracted so far:");
                     for tag in entries.iter().take(10) {
-                        println!("  {}: {} = {}", tag.group, tag.name, tag.print);
+                        println!("  {}: {} = {}", tag.group, tag.name, tag.print());
                     }
                     if entries.len() > 10 {
                         println!("  ... and {} more tags", entries.len() - 10);
@@ -9,7 +9,7 @@
 //! Note: These tests require the `integration-tests` feature to be enabled and
 //! external test assets to be available. They are automatically skipped in published crates.
 
-#![cfg(feature = "integration-tests")]
+#![cfg(all(feature = "integration-tests", feature = "canon"))]
 
 use exif_oxide::exif::ExifReader;
 use exif_oxide::types::{BinaryDataFormat, BinaryDataTable, TagValue};
@@ -3,6 +3,8 @@
 //! This test verifies that generated ProcessBinaryData tables are correctly used
 //! by binary data processors instead of hardcoded offset mapping logic.
 
+#![cfg(feature = "fujifilm")]
+
 use exif_oxide::formats::FileFormat;
 use exif_oxide::processor_registry::processors::FujiFilmFFMVProcessor;
 use exif_oxide::processor_registry::{BinaryDataProcessor, ProcessorCapability, ProcessorContext};
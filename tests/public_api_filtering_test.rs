@@ -163,10 +163,10 @@ fn test_extract_metadata_with_filter_no_filter() {
     );
 
     // Should contain common EXIF tags
-    let tag_names: Vec<_> = result.tags.iter().map(|t| &t.name).collect();
-    assert!(tag_names.contains(&&"Make".to_string()));
-    assert!(tag_names.contains(&&"Model".to_string()));
-    assert!(tag_names.contains(&&"MIMEType".to_string()));
+    let tag_names: Vec<_> = result.tags.iter().map(|t| t.name.as_str()).collect();
+    assert!(tag_names.contains(&"Make"));
+    assert!(tag_names.contains(&"Model"));
+    assert!(tag_names.contains(&"MIMEType"));
 }
 
 #[test]
@@ -10,7 +10,7 @@
 //! Note: These tests require the `integration-tests` feature to be enabled and
 //! external test assets to be available. They are automatically skipped in published crates.
 
-#![cfg(feature = "integration-tests")]
+#![cfg(all(feature = "integration-tests", feature = "raw-formats"))]
 
 use exif_oxide::formats::extract_metadata;
 use exif_oxide::types::TagValue;
@@ -1,3 +1,5 @@
+#![cfg(feature = "nikon")]
+
 use std::process::Command;
 
 #[cfg(test)]
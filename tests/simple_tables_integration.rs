@@ -3,6 +3,8 @@
 //! These tests validate that the simple table extraction framework
 //! generates working lookup tables with correct data.
 
+#![cfg(all(feature = "canon", feature = "nikon"))]
+
 #[cfg(test)]
 mod simple_table_tests {
 
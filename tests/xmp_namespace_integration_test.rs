@@ -5,7 +5,7 @@
 //!
 //! TPP: docs/todo/P03d-unknown-tags-research.md - Extraction tests for all researched tags
 
-#![cfg(feature = "integration-tests")]
+#![cfg(all(feature = "integration-tests", feature = "xmp"))]
 
 use exif_oxide::formats;
 use std::path::Path;
@@ -484,6 +484,25 @@ fn test_history_exiftool_compatible_names() {
     );
 }
 
+/// The history events are also kept whole in ExifData::xmp_provenance
+#[test]
+fn test_history_provenance() {
+    let path = Path::new("test-resources/xmp-history.xmp");
+    let data = extract_or_panic(path);
+
+    let provenance = data
+        .xmp_provenance
+        .expect("xmp_provenance should be set for xmpMM:History");
+    assert_eq!(provenance.history.len(), 1);
+    let event = &provenance.history[0];
+    assert_eq!(event.action.as_deref(), Some("created"));
+    assert_eq!(
+        event.software_agent.as_deref(),
+        Some("Test Application 1.0")
+    );
+    assert_eq!(event.when.as_deref(), Some("2024-01-15T10:30:00+00:00"));
+}
+
 // =============================================================================
 // MediaPro Tags - XMP2.pl
 // =============================================================================